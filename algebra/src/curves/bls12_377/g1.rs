@@ -5,6 +5,9 @@ use crate::{
     fields::{bls12_377::*, Field},
 };
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::SWUParameters;
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Bls12_377G1Parameters;
 
@@ -55,6 +58,206 @@ impl SWModelParameters for Bls12_377G1Parameters {
     }
 }
 
+/// The isogenous curve `y^2 = x^3 - 15 * x + 22` is the image of G1 by the 2-isogeny with
+/// kernel `{O, (-1, 0)}`, and it is carried back to G1 by the 2-isogeny with kernel `{O, (2, 0)}`,
+/// composed with the isomorphism `(x, y) -> (x/4, y/8)`.
+#[cfg(feature = "hash_to_curve")]
+impl SWUParameters for Bls12_377G1Parameters {
+    /// SWU_Z = -11
+    const SWU_Z: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0xf54900000000068c,
+            0xde669a6a40000366,
+            0xa166d075f4c36096,
+            0x1c2ae2ea447c0cfe,
+            0xca69a7c72feb6eef,
+            0xa582e39e2ac109,
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_A = -15
+    const ISOGENOUS_COEFF_A: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0x6f19c000000008ed,
+            0xb06f79a0700004a3,
+            0x426363ff84d7a8cd,
+            0x49135dff8b7f9477,
+            0x5cd256899b9aa488,
+            0x1e23a229f7ff8b,
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_B = 22
+    const ISOGENOUS_COEFF_B: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0x9a76bffffffff2e9,
+            0x5a3e286faffff932,
+            0xdc25c143d08286d2,
+            0xe1cd141e77fcf991,
+            0x3167b6320cca6b5c,
+            0x63347edb6f8ed7,
+        ])
+    );
+
+    /// ISOGENY_X_NUM = [
+    ///     193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093632,
+    ///     129332213006484547005326366847446766768196756377457330269942131333360234174170411387484444069786680062220160729088,
+    ///     193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633,
+    /// ]
+    const ISOGENY_X_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xc2ee400000000073,
+                0x5a1ae5a19000003b,
+                0xe7551b70922b580a,
+                0xbda7e8296e17c94a,
+                0xc839570cc73e58c,
+                0x1442d7cad869977,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x3a1c0000000004d,
+                0xee6b0d8270000028,
+                0x4f34885af4caa806,
+                0xdc7b8e1749b6e20c,
+                0x9fc0bae0ac8306c6,
+                0x16787152646169d,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x40b37fffffffffda,
+                0x945027e0dfffffec,
+                0x67df6cea629f4ffc,
+                0x1ed3a5eddb9f18c1,
+                0x933d256fe00f213a,
+                0x23599878bf7d26,
+            ])
+        ),
+    ];
+
+    /// ISOGENY_X_DEN = [
+    ///     -2,
+    ///     1,
+    /// ]
+    const ISOGENY_X_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x7f6cc00000000131,
+                0x748a1e3d3000009e,
+                0xdff7fadca50ec81b,
+                0x2385aa8423fc4d83,
+                0x2c51da416c283f6a,
+                0x936d8251c927b6,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a,
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_NUM = [
+    ///     32333053251621136751331591711861691692049189094364332567485532833340058543542602846871111017446670015555040182273,
+    ///     129332213006484547005326366847446766768196756377457330269942131333360234174170411387484444069786680062220160729088,
+    ///     226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905,
+    /// ]
+    const ISOGENY_Y_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xe2743fffffffff7b,
+                0x7188b930fffffba,
+                0xeb8dfd34592d97f4,
+                0x6be4c4c080acd6a4,
+                0x35603079034f44b,
+                0x7bb995a69e3607,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x3a1c0000000004d,
+                0xee6b0d8270000028,
+                0x4f34885af4caa806,
+                0xdc7b8e1749b6e20c,
+                0x9fc0bae0ac8306c6,
+                0x16787152646169d,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x2059bfffffffffed,
+                0x4a2813f06ffffff6,
+                0xb3efb675314fa7fe,
+                0xf69d2f6edcf8c60,
+                0x499e92b7f007909d,
+                0x11accc3c5fbe93,
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_DEN = [
+    ///     4,
+    ///     -4,
+    ///     1,
+    /// ]
+    const ISOGENY_Y_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x862f3ffffffffd9f,
+                0x2df720c9cffffec3,
+                0x5f036c766febb7c9,
+                0xd31784eab8fc7887,
+                0x6d97513d9450ca66,
+                0x875f417432c17e,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xfed9800000000262,
+                0xe9143c7a6000013c,
+                0xbfeff5b94a1d9036,
+                0x470b550847f89b07,
+                0x58a3b482d8507ed4,
+                0x126db04a3924f6c,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x2cdffffffffff68,
+                0x51409f837fffffb1,
+                0x9f7db3a98a7d3ff2,
+                0x7b4e97b76e7c6305,
+                0x4cf495bf803c84e8,
+                0x8d6661e2fdf49a,
+            ])
+        ),
+    ];
+
+    /// H_EFF = x - 1 = 0x8508c00000000000, which clears the cofactor `(x - 1)^2/3` of G1
+    const H_EFF: &'static [u64] = &[0x8508c00000000000];
+}
+
 /// G1_GENERATOR_X =
 /// 81937999373150964239938255573465948239988671502647976594219695644855304257327692006745978603320413799295628339695
 pub const G1_GENERATOR_X: Fq = field_new!(
@@ -6,6 +6,9 @@ use crate::{
     fields::{bls12_377::*, Field},
 };
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::SvdWParameters;
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Bls12_377G2Parameters;
 
@@ -79,6 +82,26 @@ impl SWModelParameters for Bls12_377G2Parameters {
     }
 }
 
+#[cfg(feature = "hash_to_curve")]
+impl SvdWParameters for Bls12_377G2Parameters {
+    /// SVDW_Z = [2, 0]
+    const SVDW_Z: Fq2 = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x59bfffffffffed0,
+                0xa2813f06ffffff62,
+                0x3efb675314fa7fe4,
+                0xf69d2f6edcf8c60b,
+                0x99e92b7f007909d0,
+                0x11accc3c5fbe934,
+            ])
+        ),
+        field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+    );
+}
+
 pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field_new!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
@@ -146,3 +146,17 @@ fn bls12_377_unique() {
     use std::str::FromStr;
     println!("{}", Fq::from_str("155198655607781456406391640216936120121836107652948796323930557600032281009004493664981332883744016074664192874906").unwrap());
 }
+
+#[cfg(feature = "hash_to_curve")]
+#[test]
+fn test_hash_to_curve() {
+    use crate::hash_to_curve::{
+        tests::{hash_to_curve_test, map_to_curve_test},
+        SWUMap, SvdWMap,
+    };
+
+    map_to_curve_test::<Bls12_377G1Parameters, SWUMap<Bls12_377G1Parameters>>();
+    hash_to_curve_test::<Bls12_377G1Parameters, SWUMap<Bls12_377G1Parameters>>();
+    map_to_curve_test::<Bls12_377G2Parameters, SvdWMap<Bls12_377G2Parameters>>();
+    hash_to_curve_test::<Bls12_377G2Parameters, SvdWMap<Bls12_377G2Parameters>>();
+}
@@ -9,6 +9,9 @@ use crate::{
     fields::{bls12_381::*, Field},
 };
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::SWUParameters;
+
 pub type G2Affine = Bls12G2Affine<Bls12_381Parameters>;
 pub type G2Projective = Bls12G2Projective<Bls12_381Parameters>;
 
@@ -71,6 +74,427 @@ impl SWModelParameters for Bls12_381G2Parameters {
     }
 }
 
+/// The isogenous curve, the 3-isogeny to G2 and the effective cofactor are the ones of the
+/// `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite of the hash to curve draft.
+#[cfg(feature = "hash_to_curve")]
+impl SWUParameters for Bls12_381G2Parameters {
+    /// SWU_Z = [-2, -1]
+    const SWU_Z: Fq2 = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x87ebfffffff9555c,
+                0x656fffe5da8ffffa,
+                0xfd0749345d33ad2,
+                0xd951e663066576f4,
+                0xde291a3d41e980d3,
+                0x815664c7dfe040d,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x43f5fffffffcaaae,
+                0x32b7fff2ed47fffd,
+                0x7e83a49a2e99d69,
+                0xeca8f3318332bb7a,
+                0xef148d1ea0f4c069,
+                0x40ab3263eff0206,
+            ])
+        ),
+    );
+
+    /// ISOGENOUS_COEFF_A = [0, 240]
+    const ISOGENOUS_COEFF_A: Fq2 = field_new!(
+        Fq2,
+        field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xe53a000003135242,
+                0x1080c0fdef80285,
+                0xe7889edbe340f6bd,
+                0xb51375126310601,
+                0x2d6985717c744ab,
+                0x1220b4e979ea5467,
+            ])
+        ),
+    );
+
+    /// ISOGENOUS_COEFF_B = [1012, 1012]
+    const ISOGENOUS_COEFF_B: Fq2 = field_new!(
+        Fq2,
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x22ea00000cf89db2,
+                0x6ec832df71380aa4,
+                0x6e1b94403db5a66e,
+                0x75bf3c53a79473ba,
+                0x3dd3a569412c0a34,
+                0x125cdb5e74dc4fd1,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x22ea00000cf89db2,
+                0x6ec832df71380aa4,
+                0x6e1b94403db5a66e,
+                0x75bf3c53a79473ba,
+                0x3dd3a569412c0a34,
+                0x125cdb5e74dc4fd1,
+            ])
+        ),
+    );
+
+    /// ISOGENY_X_NUM = [
+    ///     [889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542, 889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542],
+    ///     [0, 2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706522],
+    ///     [2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706526, 1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853261],
+    ///     [3557697382419259905260257622876359250272784728834673675850718343221361467102966990615722337003569479144794908942033, 0],
+    /// ]
+    const ISOGENY_X_NUM: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x47f671c71ce05e62,
+                    0x6dd57071206393e,
+                    0x7c80cd2af3fd71a2,
+                    0x48103ea9e6cd062,
+                    0xc54516acc8d037f6,
+                    0x13808f550920ea41,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x47f671c71ce05e62,
+                    0x6dd57071206393e,
+                    0x7c80cd2af3fd71a2,
+                    0x48103ea9e6cd062,
+                    0xc54516acc8d037f6,
+                    0x13808f550920ea41,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x5fe55555554c71d0,
+                    0x873fffdd236aaaa3,
+                    0x6a6b4619b26ef918,
+                    0x21c2888408874945,
+                    0x2836cda7028cabc5,
+                    0xac73310a7fd5abd,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0xa0c5555555971c3,
+                    0xdb0c00101f9eaaae,
+                    0xb1fb2f941d797997,
+                    0xd3960742ef416e1c,
+                    0xb70040e2c20556f4,
+                    0x149d7861e581393b,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0xaff2aaaaaaa638e8,
+                    0x439fffee91b55551,
+                    0xb535a30cd9377c8c,
+                    0x90e144420443a4a2,
+                    0x941b66d3814655e2,
+                    0x563998853fead5e,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x40aac71c71c725ed,
+                    0x190955557a84e38e,
+                    0xd817050a8f41abc3,
+                    0xd86485d4c87f6fb1,
+                    0x696eb479f885d059,
+                    0x198e1a74328002d2,
+                ])
+            ),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+        ),
+    ];
+
+    /// ISOGENY_X_DEN = [
+    ///     [0, -72],
+    ///     [12, -12],
+    ///     [1, 0],
+    /// ]
+    const ISOGENY_X_DEN: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x1f3affffff13ab97,
+                    0xf25bfc611da3ff3e,
+                    0xca3757cb3819b208,
+                    0x3e6427366f8cec18,
+                    0x3977bc86095b089,
+                    0x4f69db13f39a952,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x447600000027552e,
+                    0xdcb8009a43480020,
+                    0x6f7ee9ce4a6e8b59,
+                    0xb10330b7c0a95bc6,
+                    0x6140b1fcfb1e54b7,
+                    0x381be097f0bb4e1,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x7588ffffffd8557d,
+                    0x41f3ff646e0bffdf,
+                    0xf7b1e8d2ac426aca,
+                    0xb3741acd32dbb6f8,
+                    0xe9daf5b9482d581f,
+                    0x167f53e0ba7431b8,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x760900000002fffd,
+                    0xebf4000bc40c0002,
+                    0x5f48985753c758ba,
+                    0x77ce585370525745,
+                    0x5c071a97a256ec6d,
+                    0x15f65ec3fa80e493,
+                ])
+            ),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+        ),
+    ];
+
+    /// ISOGENY_Y_NUM = [
+    ///     [3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558, 3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558],
+    ///     [0, 889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235518],
+    ///     [2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706524, 1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853263],
+    ///     [2816510427748580758331037284777117739799287910327449993381818688383577828123182200904113516794492504322962636245776, 0],
+    /// ]
+    const ISOGENY_Y_NUM: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x96d8f684bdfc77be,
+                    0xb530e4f43b66d0e2,
+                    0x184a88ff379652fd,
+                    0x57cb23ecfae804e1,
+                    0xfd2e39eada3eba9,
+                    0x8c8055e31c5d5c3,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x96d8f684bdfc77be,
+                    0xb530e4f43b66d0e2,
+                    0x184a88ff379652fd,
+                    0x57cb23ecfae804e1,
+                    0xfd2e39eada3eba9,
+                    0x8c8055e31c5d5c3,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0xbf0a71c71c91b406,
+                    0x4d6d55d28b7638fd,
+                    0x9d82f98e5f205aee,
+                    0xa27aa27b1d1a18d5,
+                    0x2c3b2b2d2938e86,
+                    0xc7d13420b09807f,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0xd7f9555555531c74,
+                    0x21cffff748daaaa8,
+                    0x5a9ad1866c9bbe46,
+                    0x4870a2210221d251,
+                    0x4a0db369c0a32af1,
+                    0x2b1ccc429ff56af,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0xe205aaaaaaac8e37,
+                    0xfcdc000768795556,
+                    0xc96011a8a1537dd,
+                    0x1c06a963f163406e,
+                    0x10df44c82a881e6,
+                    0x174f45260f808feb,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0xa470bda12f67f35c,
+                    0xc0fe38e23327b425,
+                    0xc9d3d0f2c6f0678d,
+                    0x1c55c9935b5a982e,
+                    0x27f6c0e2f0746764,
+                    0x117c5e6e28aa9054,
+                ])
+            ),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+        ),
+    ];
+
+    /// ISOGENY_Y_DEN = [
+    ///     [-432, -432],
+    ///     [0, -216],
+    ///     [18, -18],
+    ///     [1, 0],
+    /// ]
+    const ISOGENY_Y_DEN: &'static [Fq2] = &[
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x162fffffa765adf,
+                    0x8f7bea480083fb75,
+                    0x561b3c2259e93611,
+                    0x11e19fc1a9c875d5,
+                    0xca713efc00367660,
+                    0x3c6a03d41da1151,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x162fffffa765adf,
+                    0x8f7bea480083fb75,
+                    0x561b3c2259e93611,
+                    0x11e19fc1a9c875d5,
+                    0xca713efc00367660,
+                    0x3c6a03d41da1151,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x5db0fffffd3b02c5,
+                    0xd713f52358ebfdba,
+                    0x5ea60761a84d161a,
+                    0xbb2c75a34ea6c44a,
+                    0xac6735921c1119b,
+                    0xee3d913bdacfbf6,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x66b10000003affc5,
+                    0xcb1400e764ec0030,
+                    0xa73e5eb56fa5d106,
+                    0x8984c913a0fe09a9,
+                    0x11e10afb78ad7f13,
+                    0x5429d0e3e918f52,
+                ])
+            ),
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x534dffffffc4aae6,
+                    0x5397ff174c67ffcf,
+                    0xbff273eb870b251d,
+                    0xdaf2827152870915,
+                    0x393a9cbaca9e2dc3,
+                    0x14be74dbfaee5748,
+                ])
+            ),
+        ),
+        field_new!(
+            Fq2,
+            field_new!(
+                Fq,
+                BigInteger384([
+                    0x760900000002fffd,
+                    0xebf4000bc40c0002,
+                    0x5f48985753c758ba,
+                    0x77ce585370525745,
+                    0x5c071a97a256ec6d,
+                    0x15f65ec3fa80e493,
+                ])
+            ),
+            field_new!(Fq, BigInteger384([0x0, 0x0, 0x0, 0x0, 0x0, 0x0,])),
+        ),
+    ];
+
+    /// H_EFF = 3 * (x^2 - 1) * COFACTOR, the scalar equivalent to the clearing by the
+    /// endomorphism psi of Budroni and Pintore
+    const H_EFF: &'static [u64] = &[
+        0xe8020005aaa95551,
+        0x59894c0adebbf6b4,
+        0xe954cbc06689f6a3,
+        0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0,
+        0x329c2f178731db95,
+        0x9986ff031508ffe1,
+        0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528,
+        0xbc69f08f2ee75b3,
+    ];
+}
+
 pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field_new!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
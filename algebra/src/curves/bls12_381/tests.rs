@@ -927,3 +927,80 @@ fn test_g2_doubling_correctness() {
         )
     );
 }
+
+#[cfg(feature = "hash_to_curve")]
+#[test]
+fn test_hash_to_curve() {
+    use crate::hash_to_curve::{
+        tests::{hash_to_curve_test, map_to_curve_test},
+        HashToCurve, MapToCurveBasedHasher, SWUMap,
+    };
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    map_to_curve_test::<Bls12_381G1Parameters, SWUMap<Bls12_381G1Parameters>>();
+    hash_to_curve_test::<Bls12_381G1Parameters, SWUMap<Bls12_381G1Parameters>>();
+    map_to_curve_test::<Bls12_381G2Parameters, SWUMap<Bls12_381G2Parameters>>();
+    hash_to_curve_test::<Bls12_381G2Parameters, SWUMap<Bls12_381G2Parameters>>();
+
+    // Test vectors of the BLS12381G1_XMD:SHA-256_SSWU_RO_ and BLS12381G2_XMD:SHA-256_SSWU_RO_
+    // suites from the draft
+    let fq = |bytes: &[u8]| Fq::from_be_bytes_mod_order(bytes);
+    let g1_hasher = MapToCurveBasedHasher::<G1Affine, SWUMap<Bls12_381G1Parameters>, Sha256>::new(
+        b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+    )
+    .unwrap();
+    let g1_vectors = [
+        (
+            "",
+            hex!("052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1"),
+            hex!("08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265"),
+        ),
+        (
+            "abc",
+            hex!("03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903"),
+            hex!("0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d"),
+        ),
+    ];
+    for (msg, x, y) in g1_vectors.iter() {
+        let expected = G1Affine::new(fq(x), fq(y), false);
+        assert_eq!(g1_hasher.hash(msg.as_bytes()).unwrap(), expected);
+    }
+
+    let g2_hasher = MapToCurveBasedHasher::<G2Affine, SWUMap<Bls12_381G2Parameters>, Sha256>::new(
+        b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_",
+    )
+    .unwrap();
+    let g2_vectors = [
+        (
+            "",
+            [
+                hex!("0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a"),
+                hex!("05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d"),
+            ],
+            [
+                hex!("0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92"),
+                hex!("12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6"),
+            ],
+        ),
+        (
+            "abc",
+            [
+                hex!("02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6"),
+                hex!("139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8"),
+            ],
+            [
+                hex!("1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48"),
+                hex!("00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16"),
+            ],
+        ),
+    ];
+    for (msg, x, y) in g2_vectors.iter() {
+        let expected = G2Affine::new(
+            Fq2::new(fq(&x[0]), fq(&x[1])),
+            Fq2::new(fq(&y[0]), fq(&y[1])),
+            false,
+        );
+        assert_eq!(g2_hasher.hash(msg.as_bytes()).unwrap(), expected);
+    }
+}
@@ -6,6 +6,9 @@ use crate::{
     Field,
 };
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::SWUParameters;
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct Bn382G1Parameters;
 
@@ -57,6 +60,250 @@ impl SWModelParameters for Bn382G1Parameters {
     }
 }
 
+/// The isogenous curve has been obtained by Velu's formulas from the kernel of a
+/// 3-isogeny of G1, choosing among the admissible ones the curve with the smallest `A'`.
+#[cfg(feature = "hash_to_curve")]
+impl SWUParameters for Bn382G1Parameters {
+    /// SWU_Z = -14
+    const SWU_Z: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0x64,
+            0x9604b96960,
+            0x972f8d72d2c9600,
+            0x85fd1fdfdc8f8496,
+            0x43579948fd9ed166,
+            0x11c59cf17c94f3a4,
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_A = 790074420990799282472527076978278047028643125224890433899272857547597820123433723384410302019970610725865881966991
+    const ISOGENOUS_COEFF_A: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0x861f209c10379e0,
+            0xb9bd60ab59663c53,
+            0xeb3cb9338a8e2e07,
+            0x4efd6955eef13a53,
+            0x710b9f21765301b,
+            0x8f0fe63261335b2,
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_B = 3542
+    const ISOGENOUS_COEFF_B: Fq = field_new!(
+        Fq,
+        BigInteger384([
+            0xffffffffffff9da9,
+            0xffff6c78daaa9fd7,
+            0x352fca6553677d7f,
+            0xbc53e79ad9dd5d74,
+            0xc69b02789711936d,
+            0x5eae985bc856736,
+        ])
+    );
+
+    /// ISOGENY_X_NUM = [
+    ///     4311715617308373266070941117688321300100403893137416180571997915047607094699446604564383184855427812164732635316343,
+    ///     3009422327438185738666630703748732931769964468249248099357073286560573879399352248490797003820799795062803223810613,
+    ///     5043140448377236610301593770205430089324372895983929540669862555555616759368964771643302055713621746071667737223174,
+    ///     4927674991209569446938218420215224342971890163585618492082283331482979536799367548073580782691917499616837297504257,
+    /// ]
+    const ISOGENY_X_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xe38e38e38e38e046,
+                0x38e3894c668724c8,
+                0x94297b4d565b4c8e,
+                0x1d0a81d6487d54a1,
+                0xa5c462725324078a,
+                0x20032a49c6f8843,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xa20a4a1c56087ea6,
+                0xa011ce9301e1f2f2,
+                0xe3d96846d5cd3960,
+                0xe0ba094837b45227,
+                0x956b5968bf709310,
+                0x17e99740394bdf76,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xb6e5970d646fc7d5,
+                0x22ecf439a12bb161,
+                0x4afac764347ab689,
+                0x46ff2dc456533583,
+                0x13b2662744616141,
+                0x20b4cd6b4911fe71,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x71c71c71c71c71c7,
+                0x1c71c71c471b19c4,
+                0xf1c46c619c1c1c47,
+                0x4145f9fdc29970c6,
+                0xba854e9eec60400d,
+                0x18714615597629d3,
+            ])
+        ),
+    ];
+
+    /// ISOGENY_X_DEN = [
+    ///     3643521729280296154722088882826005755981770566256207432345016266278567039192471827092621775763893847730499607194505,
+    ///     1039189114509004470270378149911851717172344591584799437288213016653735003126375012127491457195338218093473957470254,
+    ///     1,
+    /// ]
+    const ISOGENY_X_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x1715d0fd12e55b71,
+                0xe1e0c967b20705ff,
+                0x8652c5ced971f3b0,
+                0xb67163472db9111b,
+                0x3bfe6b077665cab3,
+                0x2155d688829f910c,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x6e124f7887ee0675,
+                0x3a5495faaa287baf,
+                0xa20f7df9c0385ed2,
+                0xdaa6eb45c4149991,
+                0x26c3b413e27e6f05,
+                0x636eec6baddb586,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xfffffffffffffff9,
+                0xfffffff57fab5757,
+                0x7f56ac056aeaf57f,
+                0x10388572e3c2c0f5,
+                0xe6ce591c2bafc343,
+                0x3e03f4104144b1a,
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_NUM = [
+    ///     2669157286905183450424868310949913185776440505275543349877903471219947249099657421873189590624788645625786869481375,
+    ///     3643036047125660204703651616755850902032034471535481959519177053497634919265240255689217839971722999138355874382529,
+    ///     2521570224188618305150796885102715044662186447991964770334931277777808379684482385821651027856810873035833868611587,
+    ///     5338314573810366900849736621899826371552881010551086699755806942439894498199314843746379181249577291251573738962945,
+    /// ]
+    const ISOGENY_Y_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x684bda12f684c057,
+                0xda12fa95974bd157,
+                0xd485b4712e54eacb,
+                0xdb7890a2589f7b5c,
+                0xbbb7498daaf05204,
+                0x225e3294316f1732,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xa77b0fbd3b747b09,
+                0xf596a01786caed41,
+                0xc33a0d0588e9798c,
+                0x92bcf92f182b6d8e,
+                0x70ce6589772cb1a9,
+                0x3a33fabd3724c66,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xdb72cb86b237e3eb,
+                0x91767a1d909be4bc,
+                0x65897bfadbbedc04,
+                0xfdc4b1ec3f771f42,
+                0xc28151487a7f9064,
+                0x225cab5591f542ff,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xd097b425ed097b43,
+                0xb425ed0a6d1118a6,
+                0xa5fc44818b60b517,
+                0x8e1e220cb1452b98,
+                0x89b7427b6f33ea5f,
+                0x2028c831afb7bda5,
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_DEN = [
+    ///     5543634365110765627805495722742127385843376434033820803592568747918351978899288491582778380528407187068941959690777,
+    ///     5386930822730122836360770925735889882101935264734801493442480050917349138678126989695086946763274356122556861891226,
+    ///     1558783671763506705405567224867777575758516887377199155932319524980602504689562518191237185793007327140210936205381,
+    ///     1,
+    /// ]
+    const ISOGENY_Y_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x29fb,
+                0x3efa7bb77788,
+                0x777eeca6ef2ff880,
+                0x262c4044a47311fc,
+                0xf35828e96e57aff3,
+                0xa6dcba84002b8ce,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x454172f738b01251,
+                0xa5a25c3415fce1cd,
+                0x92c7f049864fd812,
+                0xba3fbdad37f5214f,
+                0xd15ac84301f5e109,
+                0x1bf87119d22da407,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0x251b7734cbe509b0,
+                0x577ee0f8bf42c593,
+                0x3323553f61d60efb,
+                0x223f7bf2ba6c6adb,
+                0x72cdac52ac0c864d,
+                0x1b54aaca05b8d410,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger384([
+                0xfffffffffffffff9,
+                0xfffffff57fab5757,
+                0x7f56ac056aeaf57f,
+                0x10388572e3c2c0f5,
+                0xe6ce591c2bafc343,
+                0x3e03f4104144b1a,
+            ])
+        ),
+    ];
+}
+
 /// G1_GENERATOR_X =
 /// 1
 pub const G1_GENERATOR_X: Fq = field_new!(
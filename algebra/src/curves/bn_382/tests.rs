@@ -196,3 +196,30 @@ fn test_g1_addition_correctness() {
         )
     );
 }
+
+#[cfg(feature = "hash_to_curve")]
+#[test]
+fn test_hash_to_curve() {
+    use crate::hash_to_curve::{
+        tests::{hash_to_curve_test, map_to_curve_test},
+        HashToField, SWUMap, SWUParameters,
+    };
+    use g1::Bn382G1Parameters;
+
+    // Points of the isogenous curve, whose y coordinate has the sign of the input,
+    // are carried to G1
+    let u = Fq::from(7u8);
+    let (x, y) = SWUMap::<Bn382G1Parameters>::map_to_isogenous_curve(&u);
+    assert_eq!(y.sgn0(), u.sgn0());
+    assert_eq!(
+        y.square(),
+        (x.square() + &Bn382G1Parameters::ISOGENOUS_COEFF_A) * &x
+            + &Bn382G1Parameters::ISOGENOUS_COEFF_B
+    );
+    assert!(SWUMap::<Bn382G1Parameters>::apply_isogeny(&x, &y)
+        .unwrap()
+        .is_on_curve());
+
+    map_to_curve_test::<Bn382G1Parameters, SWUMap<Bn382G1Parameters>>();
+    hash_to_curve_test::<Bn382G1Parameters, SWUMap<Bn382G1Parameters>>();
+}
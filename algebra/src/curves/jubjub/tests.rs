@@ -112,3 +112,15 @@ fn test_bytes() {
 fn test_montgomery_conversion() {
     montgomery_conversion_test::<JubJubParameters>();
 }
+
+#[test]
+fn test_compression() {
+    use crate::{Field, FromCompressedBits, ToCompressedBits};
+
+    let a: JubJubAffine = rand::random();
+    for p in [a, -a, JubJubAffine::prime_subgroup_generator(), JubJubAffine::zero()].iter() {
+        let compressed = p.compress();
+        assert_eq!(compressed[compressed.len() - 1], p.y.is_odd());
+        assert_eq!(&JubJubAffine::decompress(compressed).unwrap(), p);
+    }
+}
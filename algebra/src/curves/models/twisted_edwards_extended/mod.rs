@@ -333,7 +333,7 @@ impl<P: Parameters> FromCompressedBits for GroupAffine<P> {
         let parity_flag_set = compressed[len];
 
         //Mask away the flag bits and try to get the x coordinate
-        let x = P::BaseField::read_bits(compressed[0..(len - 1)].to_vec())?;
+        let x = P::BaseField::read_bits(compressed[..len].to_vec())?;
 
         //Attempt to get the y coordinate from its parity and x
         match Self::get_point_from_x_and_parity(x, parity_flag_set) {
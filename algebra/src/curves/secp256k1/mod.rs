@@ -8,6 +8,9 @@ use crate::curves::{
 use crate::fields::secp256k1::{fq::Fq, fr::Fr};
 use crate::{field_new, Field};
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::SWUParameters;
+
 #[cfg(test)]
 mod tests;
 
@@ -63,6 +66,179 @@ impl SWModelParameters for Secp256k1Parameters {
     }
 }
 
+/// The isogenous curve and the 3-isogeny to secp256k1 are the ones of the
+/// `secp256k1_XMD:SHA-256_SSWU_RO_` suite of the hash to curve draft.
+#[cfg(feature = "hash_to_curve")]
+impl SWUParameters for Secp256k1Parameters {
+    /// SWU_Z = -11
+    const SWU_Z: Fq = field_new!(
+        Fq,
+        BigInteger320([
+            0xfffffffefffffc2f,
+            0xfffffff4ffffd604,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x0,
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_A = 28734576633528757162648956269730739219262246272443394170905244663053633733939
+    const ISOGENOUS_COEFF_A: Fq = field_new!(
+        Fq,
+        BigInteger320([
+            0x3d40628ed4537ca9,
+            0xdb714ce801def198,
+            0x4458ce38a32a19a2,
+            0xa0e58ae2837bfbf0,
+            0x0,
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_B = 1771
+    const ISOGENOUS_COEFF_B: Fq =
+        field_new!(Fq, BigInteger320([0x0, 0x6eb001a66db, 0x0, 0x0, 0x0,]));
+
+    /// ISOGENY_X_NUM = [
+    ///     64328938465175664124206102782604393251816658147578091133031991115504908150983,
+    ///     3540463234204664767867377763959255381561641196938647754971861192896365225345,
+    ///     37676595701789655284650173187508961899444205326770530105295841645151729341026,
+    ///     64328938465175664124206102782604393251816658147578091133031991115504908150924,
+    /// ]
+    const ISOGENY_X_NUM: &'static [Fq] = &[
+        field_new!(Fq, BigInteger320([0x0, 0x3b1c72a8b4, 0x0, 0x0, 0x0,])),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0x3835543cab26e3c9,
+                0xd5bd51a266611412,
+                0x2cc06f7c86b86bcd,
+                0x50b37e74f3294a00,
+                0x0,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0x446f0466b1e55c15,
+                0x48c18b1b16e0b36f,
+                0x5a3f74c29bfccce3,
+                0xbe55a02e5e8bd357,
+                0x0,
+            ])
+        ),
+        field_new!(Fq, BigInteger320([0x0, 0x1c71c789, 0x0, 0x0, 0x0,])),
+    ];
+
+    /// ISOGENY_X_DEN = [
+    ///     95592507323525948732419199626899895302164312317343489384240252208201861084315,
+    ///     107505182841474506714709588670204841388457878609653642868747406790547894725908,
+    ///     1,
+    /// ]
+    const ISOGENY_X_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger320([
+                0xed9fe26e0d1a24bb,
+                0x8af79c20cc3d1deb,
+                0xb84bc22235735eb5,
+                0x82ee5655a55ace04,
+                0x0,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0x67e727a2411053a3,
+                0x8ecde3f3cde64ee9,
+                0x2c3b1ad77be333fd,
+                0xb102a1a152ea6e12,
+                0x0,
+            ])
+        ),
+        field_new!(Fq, BigInteger320([0x0, 0x1000003d1, 0x0, 0x0, 0x0,])),
+    ];
+
+    /// ISOGENY_Y_NUM = [
+    ///     34308767181427020866243254817389009734302217678708315270950395261602617680444,
+    ///     90176424683627901097894375140309208301239340832535417794535213712559228940707,
+    ///     18838297850894827642325086593754480949722102663385265052647920822575864670513,
+    ///     21442979488391888041402034260868131083938886049192697044343997038501636050308,
+    /// ]
+    const ISOGENY_Y_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger320([
+                0xfffffffefffffc2f,
+                0xffffffcf425e1693,
+                0xffffffffffffffff,
+                0xffffffffffffffff,
+                0x0,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0x78c38a34c8e3f1b2,
+                0xba60d5fe220e9eed,
+                0x4ec198c898a435f2,
+                0x27e77a577b9764ab,
+                0x0,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0xa2378232d8f2ac22,
+                0xa460c58d8b7059b7,
+                0xad1fba614dfe6671,
+                0xdf2ad0172f45e9ab,
+                0x0,
+            ])
+        ),
+        field_new!(Fq, BigInteger320([0x0, 0x97b4283, 0x0, 0x0, 0x0,])),
+    ];
+
+    /// ISOGENY_Y_DEN = [
+    ///     115792089237316195423570985008687907853269984665640564039457584007908834670907,
+    ///     55193343495945455350115628863323870199952967620749340073805588608787913909619,
+    ///     45465685024895564648493397996619354229416833248839900263663526177913007417199,
+    ///     1,
+    /// ]
+    const ISOGENY_Y_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger320([
+                0xfffffffefffffc2f,
+                0xfffffd0bfff4bacb,
+                0xffffffffffffffff,
+                0xffffffffffffffff,
+                0x0,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0xc8dfa74b274e7202,
+                0xa0e6d46264b759c3,
+                0x28e34666a05a1c20,
+                0x88cb0300f0106a0e,
+                0x0,
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger320([
+                0x1bdabb73e1987f5d,
+                0x5634d5edb4d9765e,
+                0x4258a84339d4cdfc,
+                0x8983f271fc5fa51b,
+                0x0,
+            ])
+        ),
+        field_new!(Fq, BigInteger320([0x0, 0x1000003d1, 0x0, 0x0, 0x0,])),
+    ];
+}
+
 /// G_GENERATOR_X =
 /// = 55066263022277343669578718895168534326250603453777594175500187360389116729240
 pub const G_GENERATOR_X: Fq = field_new!(
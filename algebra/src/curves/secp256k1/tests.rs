@@ -321,3 +321,38 @@ fn test_secp256k1_mul_bits_correctness() {
         );
     }
 }
+
+#[cfg(feature = "hash_to_curve")]
+#[test]
+fn test_hash_to_curve() {
+    use crate::hash_to_curve::{
+        tests::{hash_to_curve_test, map_to_curve_test},
+        HashToCurve, MapToCurveBasedHasher, SWUMap,
+    };
+    use sha2::Sha256;
+
+    map_to_curve_test::<Secp256k1Parameters, SWUMap<Secp256k1Parameters>>();
+    hash_to_curve_test::<Secp256k1Parameters, SWUMap<Secp256k1Parameters>>();
+
+    // Test vectors of the secp256k1_XMD:SHA-256_SSWU_RO_ suite from the draft
+    let hasher = MapToCurveBasedHasher::<Affine, SWUMap<Secp256k1Parameters>, Sha256>::new(
+        b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_",
+    )
+    .unwrap();
+    let vectors = [
+        (
+            "",
+            hex!("c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346").to_vec(),
+            hex!("64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067").to_vec(),
+        ),
+        (
+            "abc",
+            hex!("3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b").to_vec(),
+            hex!("7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6").to_vec(),
+        ),
+    ];
+    for (msg, x, y) in vectors.iter() {
+        let expected = to_internal_repr(x.clone(), y.clone()).into_affine();
+        assert_eq!(hasher.hash(msg.as_bytes()).unwrap(), expected);
+    }
+}
@@ -9,6 +9,9 @@ use crate::{
     Field,
 };
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::{SWUParameters, SvdWParameters};
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct TweedledeeParameters;
 
@@ -59,6 +62,1225 @@ impl SWModelParameters for TweedledeeParameters {
     }
 }
 
+#[cfg(feature = "hash_to_curve")]
+impl SvdWParameters for TweedledeeParameters {
+    /// SVDW_Z = 1
+    const SVDW_Z: Fq = field_new!(
+        Fq,
+        BigInteger256([
+            0x7379f083fffffffd,
+            0xf5601c89c3d86ba3,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ])
+    );
+}
+
+/// The isogenous curve is the codomain of a 23-isogeny from the curve, the least degree of an
+/// isogeny to a curve with `A' * B' != 0`. The isogeny back to the curve is its dual, composed
+/// with the isomorphism `(x, y) -> (l^2 * x, l^3 * y)`, with `l` the least sixth root of
+/// `5/B''` and `B''` the coefficient of the codomain `y^2 = x^3 + B''` given by Velu's formulas.
+#[cfg(feature = "hash_to_curve")]
+impl SWUParameters for TweedledeeParameters {
+    /// SWU_Z = -5
+    const SWU_Z: Fq = field_new!(
+        Fq,
+        BigInteger256([0x537dbc9000000014, 0x46d497143bb287be, 0x0, 0x0])
+    );
+
+    /// ISOGENOUS_COEFF_A = 794453731766355344647867887888211538371330247090498400319868542581552708437
+    const ISOGENOUS_COEFF_A: Fq = field_new!(
+        Fq,
+        BigInteger256([
+            0x68263254a75c9b50,
+            0xb92c79d6d6a0de24,
+            0x493b58b73acf414a,
+            0x3c65e6801c10b01b
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_B = 18769324027421328915793253708220313229244620042434881561823007321830753555969
+    const ISOGENOUS_COEFF_B: Fq = field_new!(
+        Fq,
+        BigInteger256([
+            0x14c06eb646cd7bee,
+            0x5047efdc8fc37c3c,
+            0xaaf9112a828b5355,
+            0x3d9cbbf5fb523e06
+        ])
+    );
+
+    /// ISOGENY_X_NUM = [
+    ///     10721923071762997098835972397646828243514705088257498067244426703750390173758,
+    ///     21420742482907279239002115851405678755782780568857704896113533573600553959833,
+    ///     28056075771335268866121190990139491788635967009427520017554601956376354872914,
+    ///     313017519559220831742312478393260960535446510010201596781686525700655011259,
+    ///     6423086212519527507538607922722181609239335009525669842050295628274644960978,
+    ///     22878355600302044445332629859895355135380633939912450237069368800932701007614,
+    ///     4528851519648544517637153653434017563935086173552143776482842721873885304044,
+    ///     28205401553502738612293553929375846079941497246378519241163654223487875006637,
+    ///     12967424250003389591252039439734684311882274606733994392872514247074388723137,
+    ///     6514519542612988944831856926032138278043443946668658802812630616128913115897,
+    ///     23219483239880437728950196914245127247033275539319968119555038190511464402407,
+    ///     182980650698004405314188135454491077835650620081534810275812528033269860667,
+    ///     19237232201862933708637525789172996705020845395982207796533967318826239109727,
+    ///     24216773917673775400081960261915896094870268645094514823644142609429076638201,
+    ///     10384421520302882430980975917134027225582312241244905136058903243114329546634,
+    ///     2002133677045565312128754924846639058183523062940192081805622307553656016532,
+    ///     3604524136587149848212064053074182123135294230793221010168097665539576161280,
+    ///     17120898013595638847662189488003590933890948515462449862009008922914297152768,
+    ///     19253346380524282218702570966217612879794034885169673255948503132536549291592,
+    ///     23709951381213139008203687809233796449496173278171697306998034081592196278403,
+    ///     18350392646769024274684923108056814448901904248889954787254192723993812884620,
+    ///     685686752029609278083226191141768399801489209060093255036522257660772027129,
+    ///     24524694967055627996562228023499876636305342242383142234431040054477760184012,
+    ///     25492638671526503198874326202878297443964804125005648280016495597149787458542,
+    /// ]
+    const ISOGENY_X_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x6e10be4cb4386872,
+                0x16291380f9705858,
+                0x889bf9fb2b252bd0,
+                0x36636bf8baf180d4
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x732dfec9f41bce9a,
+                0x8340ea13ebb73424,
+                0x471d191c061ba934,
+                0x358c58a8d1d08c2e
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x966f743f80cc3b99,
+                0xdc2f480195a338ff,
+                0xac5da88bda68d5d0,
+                0x742028ac810e858
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xbda5797639629799,
+                0x13241f797e0aa3f0,
+                0x64f0a86698928dc6,
+                0xf98fd1d4fb98446
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xea2e8c0b2eccb140,
+                0xbcc88f0917d28bb2,
+                0xeeda226ab123f618,
+                0x30e915af32839738
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x226d0738f1fb5f8,
+                0x32b88d11e5b4629e,
+                0x2c4ff4d06a500e39,
+                0x8650ec1d663d4d2
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x48a0eab2d63aaeef,
+                0xb5acb753a6beee95,
+                0x6af4a6ffa6aeb7d7,
+                0xa65b6a37ea774b9
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xabf15db86e82b121,
+                0xe7e53ac6acefeddc,
+                0xcbd3b1d87db23af3,
+                0x3c314f28be42a74
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x2e5538ff64cb52cd,
+                0x82c881838039f3d4,
+                0x7409188cef07c07d,
+                0x1ab1400c8255f182
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xdd4507246712a3d1,
+                0x883f411ce45ac655,
+                0x8b30cae2af7632c7,
+                0x3094eb94dbac884a
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc386be8222f23e34,
+                0xd39d5aa832e7f900,
+                0x5e58e7baa39e6797,
+                0x1173ef79377d5502
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x4fd0490d56c2a019,
+                0x36004e056c3605,
+                0x15f68ee5de3e0aac,
+                0xf340dd195599ada
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xf5263eba33ec8cbc,
+                0xc6e034a3be791235,
+                0xcf245340c3071557,
+                0xb819bdde5d8dd75
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xa17f390a1a847f8b,
+                0x9e6e77cb1798854d,
+                0xdb391457429e5e9b,
+                0x1e80942604f2bc67
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xee2de4a5599d69c0,
+                0x78e594fb5aaa2f24,
+                0x4dafea2d201c0f1,
+                0xbf9737c184b29a7
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x3dbf634e45d0ba7,
+                0x1942bbdf43749ae,
+                0xe335cdc09b374254,
+                0x2bd2be49752cc868
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x16d9461678b80797,
+                0x2cb3b326c6ad58ea,
+                0x499a56bd169937a0,
+                0x3f3ed63d08aa9ff1
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x124c6035b5f24558,
+                0xf93d9223c6cf0c1f,
+                0x4b8e90bc38546ad1,
+                0x2786152d81aec7b1
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x5d7f3732749dc548,
+                0xaf9fe1b779e5de6c,
+                0x21f39ed0199fa318,
+                0x3eae343a36e9d320
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xa79b4ae187a2d12e,
+                0x161013ddbe54020d,
+                0xa8cc751e0e8975ca,
+                0x3fce9609300186d8
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x4106908470265076,
+                0xe50ca8cb356765d1,
+                0xaef27ef899c8e8ab,
+                0x3cfffe0ae290f888
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x74e014d8434c284d,
+                0x53228b501cb249d4,
+                0x6294aad97d8adb73,
+                0x28ae9bafba1afd64
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xf59bc3af6eef4b28,
+                0x143545f626899330,
+                0x8411bc0b15015213,
+                0xe38214d81fb18c7
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xee039f8aa2ef261b,
+                0xea575adfd998b05a,
+                0xaeb38ab5a8a6397e,
+                0x955937c896c9940
+            ])
+        ),
+    ];
+
+    /// ISOGENY_X_DEN = [
+    ///     27149816122528016132537817235221553407818954914864630558995595261936647535131,
+    ///     21899878994436687338611419556554797477610641581333032551927978333944220207602,
+    ///     11281438031144081294809325344615512982717595860080177602837674818240448123107,
+    ///     24803211259951868372147922875642469307088782445574702833223657004283450492465,
+    ///     10322518665474751708328826886820583904174126812112290119260156132222738813741,
+    ///     10925446970052961320473128975984809385049655762122092339019037152516207381590,
+    ///     6710919211267225347050044720977039578574755287815081700324482480291232022933,
+    ///     1109854184736983216609097349747721957419145497528545710167167979794486224614,
+    ///     11032995598631180476108596579921886905642144008867375220642095650857408420487,
+    ///     26748300709893674672933511655700254364803237193205409781942985684086452372630,
+    ///     22265834943126588603247196661419888132123156742448542808615853954781814316476,
+    ///     17191338365615845941309158390189014984033571721277282539441507206719160555863,
+    ///     23113922132358392578997544257983786790211588720591326134555581708508006201747,
+    ///     19689899522454368992405543957669303844835164798608349887088073013949894318904,
+    ///     28682700872863939308127620748053758507596867525056338329803266147013546226091,
+    ///     24207331108304872638181899745952168082102237113230582182229860677985292520786,
+    ///     25291923221987376398192517919636724864262683568581950058190615648226233371901,
+    ///     17525029859617057346413411951737359320725868181530921781156991599445547193580,
+    ///     6262694701772734011880110812583356744837068326476612657449570401812538987634,
+    ///     18332013564843342390975205706723529646053220142295767694168781176899443796329,
+    ///     26048221762376864756234394240142234739282144422693074677461740600666394571162,
+    ///     17311462095417336660343115163152622390719891858783660759909248132996849411873,
+    ///     1,
+    /// ]
+    const ISOGENY_X_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xbc3ec563b4928a5,
+                0x591f4ade159f660d,
+                0xac6dab0e97b27d7a,
+                0x2a671e748bc2cde1
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x4da1eb6c21eb9465,
+                0xe96e0e068c779fc1,
+                0x7de2d8e8c441cc13,
+                0x33ca9f18479fc86d
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc75f572a9fcd9e5f,
+                0xd5888294cb3ea38,
+                0x387b11709548a595,
+                0xfd5e56fdb3e4b7f
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x37ee6a9d06ae9eec,
+                0xdd4559883c3cc947,
+                0x958b525ff8d36f3a,
+                0x388ecae4dcd62d80
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x4924b6b05963499,
+                0x34b2f91e73b409c6,
+                0x3e3f57496da991d4,
+                0x18e0daf361e651d5
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x97c25df4bc4fb59b,
+                0xafe165d67b7ce13f,
+                0xec190371838e95a8,
+                0xf40fa058f8d9722
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x6fc76d4dbfdaaddd,
+                0x4cdc25591a4c0669,
+                0x1c358067c2be26df,
+                0x3a1205d1749c1f4e
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x626be503121f74db,
+                0xb5ddccb754c8cff3,
+                0xa8599ed125c36e56,
+                0x87801972238410d
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xbe3971e57c7a7eb6,
+                0xa2007aca8e3c5a7d,
+                0x94516d25b36e6de5,
+                0x1f7fb0c7d515da7d
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x5dd831ccdcbb54a,
+                0x7b8a2cca355e9dd5,
+                0x7cf0303d9c0846e1,
+                0x3556d75573061087
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x545e679896e76a1c,
+                0xcb5d596cfb13c6d8,
+                0x3215b3284b0337c4,
+                0x3131f84e4225a7ef
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x243465f1e648f38a,
+                0x22ad9bad70684e04,
+                0xe0ced96c94241726,
+                0x181a8c503f721fa4
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x80c6880fe49315c5,
+                0x322b4e0294830b85,
+                0xba08d3d1882d9896,
+                0x17f1a3c5d5a19232
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xfa2a1840ff6a616c,
+                0xbc3c97edd3b09e9d,
+                0x8c372d2a7d940bff,
+                0x2a3d43250b3f8d29
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xb7a3c8834899d62b,
+                0x39882b7adc1f688e,
+                0x2a33dc3b6185266c,
+                0x38059c915b96444a
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x2d7edb22352bfe02,
+                0xf7fbb886743cf634,
+                0xeda92ecf6e8880d,
+                0x2e7f3f0c72eb9de3
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x19045244320b433,
+                0x80d45c395bf9651c,
+                0x9abb002572a96a51,
+                0x3c3e42e1c7f096d2
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x3831fff6ed25b20,
+                0x4b02deee1dbc07ce,
+                0xfc2850112fd43c3d,
+                0x2d7c19840f7ad1ad
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xcebc8ae34046df85,
+                0x6889b97c7200814e,
+                0xe91aff4635a0b13b,
+                0x635bb7e8d95f7d3
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x44b6b66717358e3f,
+                0x4e7704510835b945,
+                0xb3e586c44ada0bcb,
+                0x1a10d4ba0cbf03cc
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xe0dd69cc19ab9ed,
+                0x6bf406bb8a81327b,
+                0x83fde99897197ac,
+                0x3b0c5aa0f6ccc90b
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x395e6459078a5550,
+                0xa000717ec5d5ab34,
+                0xc1eab69c7d331156,
+                0x2334185943a16d4f
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x7379f083fffffffd,
+                0xf5601c89c3d86ba3,
+                0xffffffffffffffff,
+                0x3fffffffffffffff
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_NUM = [
+    ///     2510222543018546210733279878649936390150276101977610543812052922768979571558,
+    ///     8862141166898185276110378759887574679087750728499615415283131196171288627658,
+    ///     19083082637567783210211309249536387932263101653260011175136809782046624251327,
+    ///     22583039072049768751210096430543466663080532333098870359290846426858691187573,
+    ///     14310732920033542840975850857803335058819205429565275566028288212089428738199,
+    ///     13784728963354852677187652077045732075265791840938380174239127596212076869398,
+    ///     7742904129797154573121047718853825024886943359556978531315637736783329201450,
+    ///     15727489177509121588167655569787927683731154884304536114914204755411496808205,
+    ///     11714308038459573375980238538266617090271100994703234780834352854635833561055,
+    ///     11688438309951041330484548173453275869430065848850529348315447862715716089852,
+    ///     24957777363238007146541903656264070861517390608667581488280957659391842388184,
+    ///     17996714561467762424626764103986203230945558993206836745550014221896852064939,
+    ///     15961385882897761421245790541346855081024663511881509643609698151552561302702,
+    ///     3634047506769695204482082864955751611008917953883712943685705738816051258636,
+    ///     25735139387887001372377433944176128850279454925011433372137835515730751020171,
+    ///     14896145996219191403308738767687062845024493069878108031052629045309351169252,
+    ///     10435745584865168144230288194019044977578454945388237540225107880714325551607,
+    ///     3570776631610661201639650396915330631651570297651305248535648428243058220133,
+    ///     11882860504128261017756773700270838274322263194128350342473193610569337604018,
+    ///     14204337874629618964697359978874900199321302044045583926793215955786849114054,
+    ///     26037254859387971046560823653903469092481642392118581061589387557455249863244,
+    ///     22588736296604730750159828588623358005412467166403902819383359408071558841510,
+    ///     8297094399851559855120133360161095531452032855441770810623290301058773219125,
+    ///     7564552333843625099265797854630337296155364305968428326555693361683286005668,
+    ///     13169804026863451207103660036843852843318725913811630893595534456375770008591,
+    ///     27504332606702226002183792919682701106903098474884911543227651820783581364687,
+    ///     14826465911166909598217968351201948612806599132708396357310365649063384084615,
+    ///     3131085201981821024338779021117099344149435427090715353624871460678680491338,
+    ///     9582800042802419373595588723085562278981332211745146504976926363010332815747,
+    ///     2715629628484480547043025661555829022956699260193132414111505634393025907764,
+    ///     8194265144725903110664294086278401813900629707114572027226317372312013050981,
+    ///     20858970494812172083477377452765409047442344402100789098584708391835132140244,
+    ///     1628824040429188541606309105212390998173032856773944967319993604385022205732,
+    ///     23373499726049854192511739885044587958221199039705461579611091711528570198121,
+    /// ]
+    const ISOGENY_Y_NUM: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x953eb57a6d9dd6c9,
+                0xe378efdb1817f5f6,
+                0x933576fb8136f953,
+                0x3c5f976d53b5cb22
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xe791cd8b96e91ebe,
+                0x95045b1d6afaeaf8,
+                0x32b6515af8b69fa0,
+                0x36ae1dd2faa69c21
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x1a6b0d2064b0785,
+                0x43718d1f8fdef55f,
+                0x51dab871b57dda58,
+                0x254cc1d1f8573d0f
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x6510949fe7eebde0,
+                0x79c051c89831f348,
+                0x1ec8660e34e744c3,
+                0x60f3eec72e3a798
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x6bec7c697134c7dc,
+                0xac1462c92e2d1d37,
+                0xb47ebe6826cfeada,
+                0x1f7ba91675394edf
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x3abae19b26dc2814,
+                0x5e17b8e03fe52c02,
+                0x52b097f1db5a67c9,
+                0x3b41b42aabb6b402
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc24be772fcf77986,
+                0xe508ef2ce3a54440,
+                0xb89e7059d524d5f9,
+                0x18d7785c6278ee81
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x6bdd1a7c3e911f23,
+                0x894c2f323e3d28ff,
+                0xa0b07a57d86d805d,
+                0x36ccdf17399d5551
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc3f0cbb060bbf144,
+                0xf494d6f6625cda5b,
+                0x5c962803a6709bb7,
+                0x14b306810753bab0
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x1db4d1b5743a8ec4,
+                0x8804522e053e7304,
+                0x906c623aa361bb2a,
+                0x396a8892fb960270
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x36fb174940d70d22,
+                0x8b897a40ab74db00,
+                0x30ed6c28a8ce8cd2,
+                0x2af47e2c2ac8d9ae
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x1683f924a7ef5430,
+                0x8ec16bee91abb548,
+                0x8dc4aaf2713fa62b,
+                0x62dc3bc9267b510
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xe216e91e01569dd8,
+                0xd4ef201a2e85b997,
+                0x3423ffbfdb61f0a3,
+                0xa8ea9ba0fec09e8
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x4b3d4a089b811b05,
+                0x77f283eff2fb4d5a,
+                0x88b2172203cf5ab2,
+                0x3d91159136331bb8
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xd666b49525774bca,
+                0x8ecc3559a6995515,
+                0x37bdbd02149d657c,
+                0x1bb7017a0c7aaff8
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x1ba331b37c35910,
+                0x7b257bfabaf5ee7d,
+                0x23ce12823cef1b39,
+                0x2e6c2300cc1c7ed0
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xf4fd559e248b9efa,
+                0x9cc42e7e491ac250,
+                0x86d39464d323d538,
+                0x3cd66a40fc3f8539
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x9b4eed5a9f3db244,
+                0xc706633cbffd7aba,
+                0x88ad3db2071eb981,
+                0x3095104177c709c
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x4006f50103abc968,
+                0xc5e4b1ebabd51fa2,
+                0x1e50546e88e6d8d8,
+                0x393729eeb7633827
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xe3408802118a53d3,
+                0x72d73b4879ada503,
+                0x4f1e9483acf2ec42,
+                0xe4e5c181a143385
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xd744075bac099abe,
+                0x92cc91adb795a007,
+                0x9604341d314cd239,
+                0x3c04174d706a7798
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x7ceda1236b49b901,
+                0x3803af5fe23823e5,
+                0x83b86dbd3dfd4a86,
+                0x188d8fd852ced7d4
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x761e3db2ea7e354,
+                0x72880d51aef2596e,
+                0xfc416cf62fd90a67,
+                0x3881227c074c9223
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xe6ca14ee3e22491f,
+                0x46715d561117fa92,
+                0xa3d0bffc3a821915,
+                0xe77d2c31eda2b8a
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x86c02cc4f9e6cd90,
+                0x8c99cccf66d3b9c7,
+                0x7f73ea82df5ed99c,
+                0x3449752519e5653f
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xab752a01ace448d9,
+                0xbe2d8678e928f654,
+                0x3f95bb9943c768c4,
+                0x299176d378e08991
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x7c206f09be113843,
+                0x796eca5a6144a840,
+                0x5af52bc3944b35c2,
+                0x222f391fba4b25aa
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc86e3fc2030eca3c,
+                0x26c582c0b733d115,
+                0xe0448f711d920ff0,
+                0x2af455d113bf973b
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xb3fb3486a7387efc,
+                0xbff3f0dd858de3df,
+                0x16ef01d2f9e6325b,
+                0x33f13d59437e9006
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xcc4d6c9509f310b6,
+                0x536be1dd0f7d5840,
+                0x467624ec431ba679,
+                0x172154adaeb158ee
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x81e211ae71a316a8,
+                0x7bacc59df8f2f15f,
+                0x7e8368a725f8aca1,
+                0x3046c419ff6cca66
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x7ae72c825cc51d49,
+                0xba449ef8589d8ed5,
+                0xe100bcbf70b0d0c2,
+                0x2447e76bf93eb692
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x603c7d617f68f18f,
+                0x395d2ddd234fa377,
+                0xa6e14891393198c3,
+                0x2fa2fbe4e388cbcb
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x66b711837b5c4e05,
+                0xe6f2dd58351fe924,
+                0x38488c36ca1d7fb1,
+                0x150906c10695ef4b
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_DEN = [
+    ///     729579432511486426065829600451530056557035332812202678200198965045747482993,
+    ///     18460308689931935300820570610664328013000723773103991111454841361471085394802,
+    ///     25496739267934711014277735634615728840445470824248034526083680123526176647900,
+    ///     19827102556763204762708043428445829270077648091278134169371719293265179003116,
+    ///     19793296580433737737079107055674136215163230261274657109988812720055415369314,
+    ///     24624661550062965683016899582134083632456181056493449909458342195031477051963,
+    ///     26787316501933777761712585422605147636133803431243655755370243955908654946491,
+    ///     6532420811587392895540003855256246363390018057993552021806722389658066224406,
+    ///     13453567246936502745890824893212765505732357194203423882746609543466941030509,
+    ///     2018144037962179235319399769983645679621130115271635021604369720747121192022,
+    ///     5498582562344806727019368329613642635677758627963103873379181778589561441607,
+    ///     11146645199118410492351821441442303479391339089377940909617015221777385880240,
+    ///     19611177969390853511775253658568066151056325637760101634953571817055446148296,
+    ///     27999808529541050956352157835472628361819877598539589413143225231912824297491,
+    ///     28552450278359127910432595408182811958094873061817731349524905401311767429223,
+    ///     1203144102511870763403369460910060348670285556154361394224781300554038318818,
+    ///     14080034932224106455219718554167208299879411410384500071837563831983014741524,
+    ///     13641127560055458282005585444676007539488466529478778693756099470216277698752,
+    ///     3334205408725598938692649045140050735657617571662448602031487139336612270572,
+    ///     27811958920072149360975980412789297469194665199808410812808158643542529825342,
+    ///     10051038459439278196901914476101394045680267310445875578411854373710741914920,
+    ///     23703803756999122029923572653225878561858654058047898937935185625964871109373,
+    ///     2583305790736283425540022589488295912646598736580461419485159265929039451915,
+    ///     16288888052776527026125913970858103359269051113519825672821709479079904898067,
+    ///     20120264627750894708802798389410997671898000191596053834333726216584883215792,
+    ///     20457878627944192205441784075206550977381678993417620733159171082475436644537,
+    ///     4909694591516042774101173672218688662146124192174619625355844507813372185357,
+    ///     4647069875010526411536963689424495194195200827546631074203891522911656834604,
+    ///     16931704314499368049829734435404394721553616493896410384461252057869169452745,
+    ///     11235296124763855715614786174645816453361644647094898466548942065840479129253,
+    ///     27206131849328751298284564395192323423241928285159113366772646716832834390136,
+    ///     19534884101890090096098695574305381093087140366395907646559742519924719272632,
+    ///     11493181988461480562568299618642945104418735960198274576390330217910984948401,
+    ///     1,
+    /// ]
+    const ISOGENY_Y_DEN: &'static [Fq] = &[
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x47789b072f779e58,
+                0x8f59eafe63283163,
+                0x5bf2c79a570f6a4a,
+                0xd5ec6e9fd864801
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xdedfdd5df7ab718e,
+                0x7725cedf80853e6e,
+                0xb5b68b8a8e9b3805,
+                0x243532e36a67131c
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x7a30a59dd766008f,
+                0xfa7b9d94f0f132ac,
+                0x1b74fc09b5dc02f7,
+                0x2e4d2d524e70a813
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x65e808fcdd924f6d,
+                0xb77af71022585577,
+                0xb620102ed919ddaf,
+                0x406d5aee34e9b81
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x290b93f2a5179f6b,
+                0xac1abbb72d0dfbf,
+                0x75605ee3b2826928,
+                0x23f201ca1b6cf411
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xe121cacc7f0d1f36,
+                0x9bd5e087bcd0f84c,
+                0xfcbc961cd08535b1,
+                0x2556eed9db03b9f7
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x5703630c5f50608d,
+                0x8d69ada1f9d24f81,
+                0xe97bf697f3e0b090,
+                0x38731a3ed983b14
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xccd89005df38033e,
+                0x3730aee1205b3c35,
+                0x2bc226612b4d29fb,
+                0x2f150b30ae507cf6
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xf675353f2013018,
+                0xc5ec530e6e1f6f5e,
+                0xed3a158ab23db718,
+                0x3c4035cc2ffaf956
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x8e80607a5dbe8cb5,
+                0x92fc4846484e9c4b,
+                0x730654d677edf110,
+                0xa2e81511cf2e87b
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc6d8943ff674499e,
+                0xe9350c4bdb516d27,
+                0xdc861bedb83956de,
+                0xed00896fa5e980a
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xa75275b045a6e54e,
+                0x8c73fd5addaedc46,
+                0x6b2f6cdc0c6c772f,
+                0x20884be7a1e8e3dc
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xcf280583c54536a,
+                0xcd85b6dd083a4cfa,
+                0x2cd46b24c0851c0d,
+                0x30d8f6bf947d1352
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x9d428772957ed973,
+                0x8325a0ca63af3132,
+                0xbc5c407bec794845,
+                0x1d9e16b5942825f5
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xfc98863f482e3ee8,
+                0x3ca272a3e0e503d6,
+                0xfa98ecbf76ddc14f,
+                0x378374782adb8de1
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x2037d1ec950f96ce,
+                0x425ade66c9e0af17,
+                0x3b1de22a8748393d,
+                0xb3c8a959c77d056
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xc71b62b15addd2c7,
+                0xc0e6c675f05ae91d,
+                0x65a0df66a07d60eb,
+                0x1e75cb233b87a58e
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xbbfa4422c96345a8,
+                0xd74f7543c2357250,
+                0xe0ba4e9e7bfafca5,
+                0x203c8828443d073b
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x49a97d3fdc33b824,
+                0x863c0c62defa48a2,
+                0xcf33f73a1616c74a,
+                0x154a2e46e17c3f5d
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xdd85ede481e6d30d,
+                0xdfc2a06202453ea8,
+                0x86b05c9551d3334b,
+                0x6d93b7232300f53
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x538dc7dfa68de083,
+                0xb297c8951932108d,
+                0xe20f78703966cf5b,
+                0x9729d8f11f7c476
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x22aadec75bd5f168,
+                0x776176695c47d039,
+                0xe11124e7ea94632b,
+                0x27496235b76866cb
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xa269ac365ac0385b,
+                0x156340e57114bc39,
+                0x7799354390d5348,
+                0x3e47c5e9b65ad193
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x49a943fda906fcab,
+                0x7194549a0203662a,
+                0xebf7503eaf30f8a,
+                0x37796e6d16ed3eb5
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x5f16874e7bfdc190,
+                0x49fe45275ec98ad,
+                0x54bd860c85ed90fa,
+                0xc8431761e84163b
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x833ad2352974ec65,
+                0x2a47ef2ce61d915a,
+                0x56cba0eb8f72a15c,
+                0x333afffad1c0151f
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x2bd2e49ad6c61d71,
+                0x91e508e378dbeb5b,
+                0x4c2e0402a7b86f82,
+                0x22285ed4f78a01f8
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x5cd59d2bfe5047f5,
+                0x38d579148878bf86,
+                0x300118aa65276fd6,
+                0x236a84a4abb3efb4
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xb55b3d3f64ed76bf,
+                0x9da81753188bee6a,
+                0xbfe5dffd3c1e3285,
+                0x2c928dc080514997
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x93c04ed0de5a6e46,
+                0xad8e8afd624d3f24,
+                0x6fa5c70cd4661d5c,
+                0x10ecb2bd1e39bb3c
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xf7ecadb35f478bf9,
+                0xac3cbf024a2d8df8,
+                0x2419ad1d8f3699a0,
+                0x3287143807334a27
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0xb494b3f69ebfc7e9,
+                0xbda929085fb0544a,
+                0xde3463195b6469be,
+                0x1d12eb004538e474
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x560d96858b4f7ff8,
+                0xf000aa3e28c080ce,
+                0xa2e011eabbcc9a01,
+                0x34ce2485e57223f7
+            ])
+        ),
+        field_new!(
+            Fq,
+            BigInteger256([
+                0x7379f083fffffffd,
+                0xf5601c89c3d86ba3,
+                0xffffffffffffffff,
+                0x3fffffffffffffff
+            ])
+        ),
+    ];
+}
+
 impl EndoMulParameters for TweedledeeParameters {
     const ENDO_COEFF: Self::BaseField = field_new!(
         Fq,
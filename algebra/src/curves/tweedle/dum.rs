@@ -9,6 +9,9 @@ use crate::{
     Field,
 };
 
+#[cfg(feature = "hash_to_curve")]
+use crate::hash_to_curve::{SWUParameters, SvdWParameters};
+
 #[derive(Copy, Clone, Default, PartialEq, Eq)]
 pub struct TweedledumParameters;
 
@@ -59,6 +62,1225 @@ impl SWModelParameters for TweedledumParameters {
     }
 }
 
+#[cfg(feature = "hash_to_curve")]
+impl SvdWParameters for TweedledumParameters {
+    /// SVDW_Z = 1
+    const SVDW_Z: Fr = field_new!(
+        Fr,
+        BigInteger256([
+            0x1c3ed159fffffffd,
+            0xf5601c89bb41f2d3,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ])
+    );
+}
+
+/// The isogenous curve is the codomain of a 23-isogeny from the curve, the least degree of an
+/// isogeny to a curve with `A' * B' != 0`. The isogeny back to the curve is its dual, composed
+/// with the isomorphism `(x, y) -> (l^2 * x, l^3 * y)`, with `l` the least sixth root of
+/// `5/B''` and `B''` the coefficient of the codomain `y^2 = x^3 + B''` given by Velu's formulas.
+#[cfg(feature = "hash_to_curve")]
+impl SWUParameters for TweedledumParameters {
+    /// SWU_Z = -5
+    const SWU_Z: Fr = field_new!(
+        Fr,
+        BigInteger256([0x9907e1a800000014, 0x46d4971474f30280, 0x0, 0x0])
+    );
+
+    /// ISOGENOUS_COEFF_A = 253384682844531540944979714361390672941624870202274566888014312960348989436
+    const ISOGENOUS_COEFF_A: Fr = field_new!(
+        Fr,
+        BigInteger256([
+            0xceba537e9cc3d93,
+            0x19b712bab0e819d7,
+            0x12cb603b66e96047,
+            0x10bff3eb3efadcc0
+        ])
+    );
+
+    /// ISOGENOUS_COEFF_B = 27300470585298203721247487758082204959446870440391554783166817182950527790109
+    const ISOGENOUS_COEFF_B: Fr = field_new!(
+        Fr,
+        BigInteger256([
+            0x11660422ae16ce47,
+            0x2bd5cc96ffb29d9f,
+            0x7ffcdd1d212ca0e4,
+            0x3aacf68878e308a3
+        ])
+    );
+
+    /// ISOGENY_X_NUM = [
+    ///     11969764374505222888787715036931400498728929289286224432426981658560506077393,
+    ///     3950503023655012681806075800667895165972067764905464516053615496480340842111,
+    ///     19358642799736123769807503049115720214816420765691655751150994747705396216548,
+    ///     19872456794827025068153600577146703815548474051576641140966787955845433241330,
+    ///     4806505591411888159501277121485236308361543031886598209886990365709020229631,
+    ///     4376705558543224579855903812931320447560107689736561682694232418605975814324,
+    ///     27121786566756101542481470047321835083975826584176837040434120493311411653519,
+    ///     20013050607110732113754990790849071878524501702643846319456991695458795130980,
+    ///     20161325908228601990548051345515674417419521640882472121319542217767187563146,
+    ///     5531493603098808650093714775390194028545154666026612097334737286440467740195,
+    ///     18341355487651135338251419924919868623038846891195127592547883719819114845594,
+    ///     7598607524658870083418891913625168985787509153105179942331667848869144377386,
+    ///     6272626689007821211061895277744683331076491923259418929074034005051735660647,
+    ///     14429945834229045836733999917197781193604377884615889284685394618283408713035,
+    ///     27225207940082632072262810847975333822129761925854173177418326833332702300385,
+    ///     28247784441203621325360924233724945332911274627408349523507999660481479127164,
+    ///     1547970305361414075997346037785254113943207418120084290952495068334528969889,
+    ///     23144936667829654915275891799766014390110942725576523062852385635793112193168,
+    ///     24557815712137281097905620871341421442545906879356749231904618984677276203610,
+    ///     26489656930106740708444216253983885054630131405398151050590972371185466044317,
+    ///     20354371612699240620904468933922179585470375827069267224599374854572354857242,
+    ///     10807661733514740276805428750689950324670283301898317791888963843055618403253,
+    ///     4297042030670012442248926419457957640501466082809876649565484809159354749962,
+    ///     26795874812694521363140152923142361327770721033015681493882227179878316819729,
+    /// ]
+    const ISOGENY_X_NUM: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x2dcd692aef3253d6,
+                0xf2f0958fbcb0603b,
+                0xf04a4f4084367ad7,
+                0x2c7ba8dcf8c5126c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x618b3b001f78879e,
+                0xd789da0103a7afe5,
+                0x5f43181d2243e649,
+                0xeec145a4089b18f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xa34f63d6ff6b1b70,
+                0x47d18db9747b75d6,
+                0x349c33c96dba34f5,
+                0x2d60f5b1904c9b4f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd6917a32b427bdee,
+                0xb225c6dce1ff63cb,
+                0x7efb694e20b9e1ec,
+                0x8366ede3ae92b67
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x7fad96e085cace88,
+                0xeec503afaf9afec0,
+                0x34b7cbb5e499a1d5,
+                0x3172fb92285c8907
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd40b573226fc3714,
+                0x1c9e87c0625c6573,
+                0x30178c80d61544dc,
+                0x153ec38057b999ef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x3de06a5da8828e94,
+                0x5c63f6f00cd7f2a6,
+                0xc4f53879e331941e,
+                0xce5187de8441092
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x86c594a5be494d83,
+                0x91fa0c609c441a92,
+                0x6b90d67edb9e038d,
+                0x5b79ab9c7ef507b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x85f309456724e314,
+                0xc0722ab79734bc33,
+                0xfa523af767714744,
+                0x3d72e7484477cbd7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x565067d9e757651a,
+                0xb5b8078ef686322d,
+                0x3310997496d33fef,
+                0x21367ffc299024db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xfdb624a087da61c5,
+                0xb579c2b81ef7506,
+                0x212229b3819dec86,
+                0x25c51dd54350b261
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xbe62cd04b6793d08,
+                0x89cdddfc27bc73cc,
+                0x5f2951ce84262b81,
+                0xeebd0c20af1ba71
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x8afb0f9ac891c30b,
+                0xdf00b8139ec8dc29,
+                0x5a45594effd2b749,
+                0x1ee2892157b21a82
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xe4d482bb34e2fa01,
+                0xfbd22453e0f2834e,
+                0xbbda6ea9bb00b811,
+                0x1bc86595980f9fe5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd50091978e1a55a2,
+                0x848ce7cf74d0d709,
+                0xa5df3bfb0ec1b7e8,
+                0x1dd43ef85d9f9f97
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xc38c89bcf27ce1d0,
+                0xf19031e136d9a353,
+                0xd5bf164afc6b8b58,
+                0x1e595e5922da56a1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x1904faa5b25ec55a,
+                0xb3ac8465a9796da6,
+                0x212c078927799309,
+                0x1c862d4a0c40c09a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4a80a200062e8ec4,
+                0x577ca76be44f3cec,
+                0x76e4217784a94d6d,
+                0x16117927190e6709
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x1c87da4a78a6de6f,
+                0xd2884a22baf185d8,
+                0xfe567bc7117cad6,
+                0x27e92a7ccf3dc66
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xc167079d1b388f45,
+                0x6629db2515802b5b,
+                0xad883405d78634be,
+                0x1d12be108c38f8f3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x515a178bf474216,
+                0x691d64110daa78f7,
+                0xe28abe4617f985e0,
+                0x3b1145fdf16dbb5e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xc3e9463e4992327,
+                0xc8fdd84d23da9697,
+                0x8e029b8b0fe3c184,
+                0x15c1b2b6c2cd07a8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x6ad91bd64c656012,
+                0x2854e4bd7674ada0,
+                0x9b73d57571961082,
+                0x1cff5d016a9b8790
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x94f40cb27b76c668,
+                0xf92fc768d19f28f7,
+                0xd58034184c96b171,
+                0x3b1c5cb8bf7b98c1
+            ])
+        ),
+    ];
+
+    /// ISOGENY_X_DEN = [
+    ///     13771831679718777392580095317693799116222173704605059223757594981448940309591,
+    ///     12139386635790539740215708713137201700859896172508885411815236080514958449609,
+    ///     9447677865442172798098500749124554135660816423887670908683599921821293749100,
+    ///     10659349936807089302575738367345386120943641057215359307336910575362290519139,
+    ///     27331942891832569200942642137709338048146060540545124340576554450818196535947,
+    ///     24748596039013145255907838466250078418655078105420584960235325905642036918120,
+    ///     7243441959420661208286635581912682465886726663982759071142832241355200432868,
+    ///     25146650762921511868100705898237603187965320894288408396332213992646820467878,
+    ///     27255936281187184752919245140016354488025328573237473279459683384974780938302,
+    ///     11865123641086099601852563584846497500169505434975963102140210502675293048663,
+    ///     11051497327833504164703832110492453131277567384761224504239849864718842459784,
+    ///     17509005408208646059292991270353640481790452780017460143667411713954723422689,
+    ///     28313747757845809380485262851717982590420675335613713195697953498040727604835,
+    ///     10652982211956235010988434495739301365868551136319784306886705042064297318349,
+    ///     8973052342369649546882073610860557740444916230460921791100286410122699928287,
+    ///     4615949506383849902881966164617989291508017236089068327428653533100523466032,
+    ///     4120687884435251350089080748046596001914698734472168271557347314848348210162,
+    ///     7833153188662335576225920287085161560142502839525210721454125951676033938155,
+    ///     19329174202306015878576537259365949197812294583481005770699959594575907372069,
+    ///     4023014197907580675535227957671479910779248569224998221937780659003754663123,
+    ///     20718698420028470133379046717477632374125383887998510589317579289930617792605,
+    ///     26156635459623796972122918564772491724489627721768613957936863467690392977767,
+    ///     1,
+    /// ]
+    const ISOGENY_X_DEN: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x7f55a6afd34010ea,
+                0x12864f39330132e,
+                0x57450518c4aca707,
+                0xf5d29c62f99cabb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x6ff3cefe25f04870,
+                0xe8ce7cedb72efc14,
+                0xd8ee5ae875e8e58d,
+                0x305fd22aa4b6b3e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xbde6defb4f697515,
+                0x54b1d2d07d9c104c,
+                0x8902ba2dec9068bb,
+                0x13ceea8d7d15843e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x289d5db8bc3c2b51,
+                0x50d640f6bdf89b7,
+                0xea2432360ac6bddb,
+                0x3ea71dc41f9b5dda
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x60b40e215171135c,
+                0x537579c3557f4a7e,
+                0xfb34138e887f9453,
+                0x6cda038c813641a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4c4883e35ba167d1,
+                0xea3ea645f4de99d3,
+                0x95dd8b48cdad6078,
+                0x10c58b3029138c15
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xf4842669d437e1cb,
+                0xe4dc1b12597050dc,
+                0x4e1fe1f45e38c076,
+                0x17a9eee958f2a1d7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xbc35cf0c06c3995c,
+                0x96662057d1d209b5,
+                0xc5b22224f15c110f,
+                0x1103748cce86c037
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xaaacf0e3d47045d2,
+                0xa1a85941dabe30a7,
+                0x2c0cf57d5d5b5dfa,
+                0x28ba65e7333127cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x8647d263f967bf80,
+                0x4c3f5eb9fc4ccfdd,
+                0x250f47892676c8b5,
+                0x38a86177a54e1abf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x5d42f7ada2b9baad,
+                0x4c7f104f83e6cc5,
+                0x670c7697f4ad6cdf,
+                0x203a9e66d09c9951
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd9794291e9200140,
+                0xdc6525311226b696,
+                0x4083ddefce3e1478,
+                0x298729bacd90a229
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x77f5ed84a3f5eb57,
+                0xd806384fb995831,
+                0xa37e7e80aa470834,
+                0x36148a7e52a566a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x93f9e8a191e1d327,
+                0x2e49ffa9008c361e,
+                0x4e9ae55d022dc67,
+                0x2ff0974aff454f29
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x2e56429db76d2f8,
+                0x59810a7ddf3c86b0,
+                0x397853165c9593cb,
+                0x19d8aca066839351
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x6bbb26770771b64f,
+                0x3df193256a3ba85f,
+                0xa104ff785a6ef506,
+                0xfbf53642b413885
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x1bae0eb7d8c42916,
+                0xe8506163d652fede,
+                0xf0cd5c9eab2e7318,
+                0xd910e16a6e724fb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x70789acc9dbfe729,
+                0xe86d1dfd1e4f6da1,
+                0xfe5b60d322a7dbe9,
+                0x106d82f56e358cf5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x9ddce319a10329ef,
+                0xde30470d20f07b28,
+                0x9a8bd65999c7240,
+                0x1bbf7075f6b0e855
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xcfd904c7beaadc75,
+                0x78d1a2778a9d6d88,
+                0x61bb37715d2cb09,
+                0x1499d6b123c865b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xbd0793a6f522c38e,
+                0x168331e6f466aacb,
+                0x1d76be534e9a6b93,
+                0x2853fd30873635cc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x2712204d48aa95eb,
+                0x4d36e5504d6fb118,
+                0xf755d725ad75d9ea,
+                0x3bc5d92685419ba6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x1c3ed159fffffffd,
+                0xf5601c89bb41f2d3,
+                0xffffffffffffffff,
+                0x3fffffffffffffff
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_NUM = [
+    ///     46814087801604380532650607346744073845532304272619438638385932446700917942,
+    ///     23547273661917806541563248005101596200125870838115103446676315993811948081191,
+    ///     26112973154231557011993678489221074974513054130411571645080962957786516118786,
+    ///     13056816600079771825111572884184424990136975460282684722943480701524774968208,
+    ///     7016821378803008549076068049803897016946308006934642786917120032496576036085,
+    ///     1471203774470488740862483401983126941347747176515305975144708637670119305837,
+    ///     12249115479330576637492157985261698661405426702011408095361934553779142393420,
+    ///     3355336783681533331210319616010262850320784189777834474574431646933068853552,
+    ///     1079311739515231856955060645495010739425284562671872448065242526837251152083,
+    ///     13940944828359917931344942895539879466076497143223255294192055402790520618127,
+    ///     19494531270098819438175175884695549376764091155337430776960589435538135319153,
+    ///     20155885396062320916103297308829918540429578559587747494725933546195669463563,
+    ///     20225978214926192868857090684167352334805698074938032807171371201006839945861,
+    ///     6834677816471486605554640443516894514773106880587757184966854876300187679160,
+    ///     2210522017619690927004718728264781874979344150800242844389377500248658116867,
+    ///     22940891343078409940244273725368338336605429618652692205818833457968434403659,
+    ///     18424819881842797369125200849226279924701994556374676535849015792946417307756,
+    ///     937693487291650422587630108928079011692877069375134177811359630020579809618,
+    ///     12818846690857334370383754554994839992011739547396220854783019833144955466853,
+    ///     13752896590115920671745265531586229358824425855479863965800675030640722491535,
+    ///     9899396502585299439081332124029389255685459761607391778853590110625673840128,
+    ///     254348567048065198580276409151187320859761674699224713397030383238140360778,
+    ///     19480261072008619560310737292193284903125434324412230220932041738742067837806,
+    ///     22373018105813991895544887592976872150931427867336252969875387025162361962348,
+    ///     16871975370275390903749210793733030647915578585479171574315938273301679837714,
+    ///     1329341011128415003822080326901731404385789293739259768442614438142564422877,
+    ///     19829651200813141766876637458105679723597678863507549554427872769933209291758,
+    ///     3070124698553675579537838414717815521284390980861392550484970536353903942736,
+    ///     4693215570571023233933153795872101971509894118773785570874854041227533225711,
+    ///     6359317294925481749929088926770337870784878275660915706741583820411397176834,
+    ///     278587360652172681795532469855466030636931923562034571596790623541316181963,
+    ///     23974884911644503635550572893985997075957500070271606379560577214965408691405,
+    ///     24706209479768833828997775279369187804306403519959013162092740949180124065169,
+    ///     502016331656811811341610048426751634688993586866653433137106098597495560478,
+    /// ]
+    const ISOGENY_Y_NUM: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4efc05e493944934,
+                0xd598f4171e244072,
+                0x81cfd52e8ac74c2d,
+                0x12709e3a52f2373a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x30a48f8d93a65aec,
+                0xe255e88fd4037299,
+                0x531371ff86efb116,
+                0x1f916b0e1d3a7b6e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4ee865d65c38fa81,
+                0x8625aa9c1e521fa7,
+                0xaf87c207548750f0,
+                0x35695f979132abd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x57771dacd343a554,
+                0xe2a6777bf620e262,
+                0xe460b93b4c8b20d8,
+                0x1114eb7a4f4e4d5a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xc15d9c4ea6bf7769,
+                0xf5291a0eb382e3f8,
+                0x620e13a2927db7db,
+                0x46205140744dc3f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xb0959c8bd15aca24,
+                0xc19063dfde301d1b,
+                0xaa0b8b50e2f778b7,
+                0x15bb2f9d8f849404
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd2bc012233517946,
+                0xb8d3fb5a98b3eeab,
+                0x9373718e198badf7,
+                0x1355c7afde1e577e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xfbfe01fa9b002ea5,
+                0x23c9aa1927867f15,
+                0x2bfb8f83860c7db3,
+                0x1f6535a6f8384d54
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x7e5727ccd3b87e5e,
+                0xeb95236930a0ef63,
+                0xcf935dbe2552aacf,
+                0x373643d58ab751d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x790cad33453136cd,
+                0x9f3cd51a76fc0a2d,
+                0x48a052cbd0e7e423,
+                0x10e4c26d068e5eb9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4daf500d4efca2b9,
+                0x4ad9272c53f6be9d,
+                0x38cb9e0d5fb54346,
+                0x1fdedebc6a72f7ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xb7c66eeebdd4c1fb,
+                0x8e544d881556ab3d,
+                0x2678244d596aa276,
+                0x896dd3ec60f1f15
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xfa5050b3f1deeaa4,
+                0x89f2712653a15dcb,
+                0xff706257d8373e7c,
+                0x15262b18548d4540
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4799bd949b0a9335,
+                0x23ef3cf4bd0503c8,
+                0xc6c8210f7329a862,
+                0x4edfc8c2f4370e6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xe634f13f0c98ca70,
+                0x7216840ede36362,
+                0x93b5ea9088e3feeb,
+                0x371e7535d7881606
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x10d91f05d15673fb,
+                0x534f4d83501bbac7,
+                0x6564e5cac77bf0c4,
+                0x47d6a59dfc7851
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x39e419a6199fd268,
+                0x99963f6457367c49,
+                0x762ae55206e6b531,
+                0x12f136fb65e971cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xdbbb9244046539b5,
+                0x70c8f7f3c8f2c743,
+                0x59746fb4ad8e9915,
+                0x1882d05c7f62ab77
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x32c8c2936c45f6d8,
+                0x6f6f6a4799ffefa9,
+                0xc7beb825b677dba2,
+                0x13f5ebd329f3b7a6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xb86480c8278500a2,
+                0xd30371cc8a879094,
+                0x9da679868301d532,
+                0x150a8e28e806cfdf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xcc1f7b67b7aa731a,
+                0xc1841aa5c173a92c,
+                0x103ac4c87cbeb008,
+                0x241498b660666561
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x549cb7366684fb63,
+                0xedc8211665def750,
+                0x3256eb6deb4bc643,
+                0x1327e4a8bceb2a19
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xe30e011d3a0313f8,
+                0xe3990a603478cb03,
+                0x42be6f77294fd6fa,
+                0x3fc5853c03649e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x365fe84f1c9866e9,
+                0xca817861b77ba138,
+                0x4b2af1989eea9b38,
+                0x7b9a5f679fccf17
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x34e61e9943d203ca,
+                0x85a2c0f4701c0a79,
+                0x5587b13a548b84c3,
+                0x1b7e8bc5525f9ead
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x8adfe18c4a91b248,
+                0x9414ea1202b64046,
+                0xa3dff4d59c24790d,
+                0x298d598369892d1a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xe89d3b9e6079073b,
+                0x51b2a919679fdf5c,
+                0xc28727ee91d2ebf7,
+                0x24d4b74ad746b855
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x51d1e78a9ae34136,
+                0xb11e6410e779a700,
+                0xa13082458c87fdae,
+                0x31f2e72db4288878
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4a73afec255a3ab1,
+                0xb7aa784fbc378636,
+                0xa6ba31d0e4855116,
+                0xdfaa1524c2ff668
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xb8134f2e9b166dde,
+                0xb1de8ff40e510d45,
+                0x182071958ca67f33,
+                0x2e983f06cb3a9591
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd59c6efed8afe54,
+                0xcbacd9739b8e11a9,
+                0xd911092dd3129090,
+                0x1c934a0a1b57343c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4cb1aa9a18c7c1b4,
+                0x5f4e902ec69e4a65,
+                0xfab6b476644b4725,
+                0x384b312cf580bc2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xf850c28ccea4945,
+                0xd41baa198494b701,
+                0xd0f709639c97fe21,
+                0x3fa337ee349b1cf6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x61fdc413c39d1c0d,
+                0xcf21999cc9eb9c6c,
+                0x43aa9353623233e2,
+                0x3dba458f595bed10
+            ])
+        ),
+    ];
+
+    /// ISOGENY_Y_DEN = [
+    ///     12593583706049004441650097148233354659366429847765592122390455747450320954505,
+    ///     3771186522641887084480163947343596922228198143123535039867422127419059618495,
+    ///     1433119445310508289272500733265854462962297540383885521525404028310791252425,
+    ///     28712567579428750286048010866098781931466595224686588583994896600344579433525,
+    ///     17087731673474316497889187900409265342479937497000288584507954951365921983299,
+    ///     3654161562683929343969630341979728067919855445014765972080491264882252223835,
+    ///     22857723349308000857378525067073929242166100454303084298325655263781492160703,
+    ///     18854274965337694792452482505895095584115940386098529079222873644750801073778,
+    ///     14203127162366875650718638934654722786111151497251965300900631580035290468222,
+    ///     27577255211092903126427525643098454025514394424806669632560665971170065167755,
+    ///     4200097629444877875388575874779196543549154249947834681006775505186403480347,
+    ///     15899705570962660942187159986924565997635121036133877624964226673697862473607,
+    ///     11218114700183643140534572133042097312517170492231105553328404868139859012535,
+    ///     14457236140600388117271024970107596627546303768512213140461750466394848728223,
+    ///     15034636590013142823717182546415885792003988348213599879940241799865101693470,
+    ///     13979655715407122782253363558858890983419433014049464866937826370008004063613,
+    ///     5184522777037136745972335681153904891183812272286652166842464924868274151843,
+    ///     13444490005088693128310028780303950071822992070892914858204032344608565700662,
+    ///     742625445511264068745277323842557914557990298056991471012838690555260116341,
+    ///     19067447435223421570967887577322003053525804445968653535297406598052534040742,
+    ///     12148973440853722291909338972292712721164062208114756952767279128551299720769,
+    ///     21959834974374998810828606537932159575564425392558740205929110736230573966317,
+    ///     17001575100031941790380054566255367494609104049348143284790512387923523365113,
+    ///     14992199369677358354159327205118228351029367126761402999558453684476977458204,
+    ///     24103122980480774565763474364657051463909853640614518836472766624599305305884,
+    ///     12474142621915300929558271627709383389872512907778727336183878702161980507325,
+    ///     24225532704160666689577965089419072491834932167995624681550914327725290314543,
+    ///     6706164710715137688354733953203700819467540873338988306446667933700328823172,
+    ///     23320377953171912887924160451126469656907389140496688555691107302122671260351,
+    ///     14716565307862196820690800922333897535047249014676725342492729897880796328838,
+    ///     22027511445707136269502006732801958880207321145774400214617400359527602173005,
+    ///     18425976990450112937929676453126505142860092503537897527260063183631479036584,
+    ///     24760942034771171030238004721072749105073339754675261408518636666853768413722,
+    ///     1,
+    /// ]
+    const ISOGENY_Y_DEN: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xc9f8a3903e029ce2,
+                0x5dc2f8bf2085339f,
+                0x1d6e8e266ba8c496,
+                0x2065acdbc1b7293e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x9089793fe33bd951,
+                0xbebbb79db14cd623,
+                0x3ccd83b5b230c220,
+                0x19321a3a423a5700
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x8dd2f66f7a0b178f,
+                0xf798820061aa126c,
+                0x99ae81ae0eed6d5e,
+                0x136d8d9726f5b3f6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x7a1a227076727fe9,
+                0x669add4f6eeba95c,
+                0x1a3c697af2d39076,
+                0x32e9ab4207303145
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xaa7a383a9bff5433,
+                0xceb381df9deb4248,
+                0x8f4a01cb1e580603,
+                0x240655b35cc85ef0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x467e9cec150710db,
+                0x3f97edef88a49e65,
+                0xe7d08aaaf30c88b0,
+                0x5fd95bdae7be3bd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x313369ff9f9bf32e,
+                0xa92423edb7592812,
+                0x3d213964dc037c67,
+                0x147ad69d40877e96
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x932183eda56f5c7d,
+                0x3358831e98b60404,
+                0x240279fc86edbd16,
+                0x3266174f510cd5e2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd42ecad7efe2ab9c,
+                0xfeb51841ebc6908c,
+                0x493062296448be18,
+                0x38f83313ee2273aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4f8a6e3434868147,
+                0x7761c46ab3f4b617,
+                0x6e554ca6a7013321,
+                0x32a38dba1289f532
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xdfed044a86d67fc1,
+                0xe8451f08300204fe,
+                0x4bf722b4f4cceb3a,
+                0xf8ec8caddeb3fcd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x214e491e07f298f1,
+                0x12810cdbcaacd814,
+                0xcd0cc2263b25c40b,
+                0x168069f1a0adf496
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x975d23873040d732,
+                0xc253a57d183e3e7c,
+                0xa7634919ad0d5d60,
+                0x3377b64f6cf2a056
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x9a643dc705aa242d,
+                0xce0236161bffeb86,
+                0x11655bdd7070bb67,
+                0x23a508c3b0134b99
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x22b6ad751db4595c,
+                0x6a1bef94ed4a057,
+                0x53d9755ec4f80abb,
+                0x3fa3890c0a899288
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x7ff31fc5ba7aa449,
+                0xbb06cd1bc1ff818c,
+                0x3eb910136f419626,
+                0x2607b1414cd85543
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x497cf6d7499ad3d9,
+                0x278da45aa54fa2f0,
+                0x81f51440a3ed370b,
+                0x96106c7128a56cd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x8073fccb5e89773,
+                0xa8db5ea6df1a0249,
+                0xeb5cb6d4c1982a99,
+                0x1196aa29a628bd94
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd0a8b40c35005949,
+                0xe77318a277fff991,
+                0xc3b43b95d960ceb2,
+                0x15f09efa090b2696
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xc0978f2d12ff57bc,
+                0x205d083f9db492aa,
+                0x4ff2118e1474a626,
+                0x316fb0e298bbdfed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4977f2501d78d45,
+                0xced3d11825932e3,
+                0x5a628ac74bf8b0f0,
+                0x399e8e5a2133def4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x4f1f4687925950ee,
+                0x6de1325fd4a4161f,
+                0x58eefaf016a9ab27,
+                0x3bef588a170076e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x595d02265a83f1ea,
+                0x8fb3217e390b2c84,
+                0x392cca9edfc62bc3,
+                0x312e2f7a5a706fe7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xf9eae43f3d97b147,
+                0xd8256a42141db629,
+                0xd8fe2e19b2af3d23,
+                0x3726263511a31102
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xe1ca17ac63077b4b,
+                0x7b8524a82e138e9f,
+                0x14282850383f1327,
+                0x22f1575adbfbdf99
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xe3b331c997f1bbac,
+                0xd532351e5ff4d40b,
+                0x25810e03cd64b24d,
+                0x7b6668952f6349a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xb8c0f1d4fb7ab650,
+                0x67183f116971eec9,
+                0x6179d08ac0262708,
+                0xe7be7315ed06beb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x67c6a2b53474a4e7,
+                0x3330ddd79660a2ed,
+                0xdcedda3fd8f3c5a2,
+                0x1e15a34eeca722cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xdf3fa144d8a38d86,
+                0x61febb1eda94deb4,
+                0x1f7146fb88896cb0,
+                0x116ab16c54b06905
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xd6f10edee42f666e,
+                0x37b4132d58a9ed03,
+                0x86e5a8a369e82682,
+                0xc2ceac9351607f0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0xbd27a6917004389f,
+                0xff13ee58bf4f6d3f,
+                0x1677b19982c4d10e,
+                0x3dc3205ceaeef543
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x1f008b29d741a9cf,
+                0x6671e954458ce09b,
+                0x757a92ccc539187c,
+                0x31e51a98f167f919
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x69fafe02ecffe0e0,
+                0x720d0764be07dcc7,
+                0x7300c2b88430c6df,
+                0x39a8c5b9c7e2697a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger256([
+                0x1c3ed159fffffffd,
+                0xf5601c89bb41f2d3,
+                0xffffffffffffffff,
+                0x3fffffffffffffff
+            ])
+        ),
+    ];
+}
+
 impl EndoMulParameters for TweedledumParameters {
     const ENDO_COEFF: Self::BaseField = field_new!(
         Fr,
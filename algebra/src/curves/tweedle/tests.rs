@@ -246,3 +246,22 @@ fn test_dum_endo_mul() {
         assert_eq!(p_mul, pe_mul);
     }
 }
+
+#[cfg(feature = "hash_to_curve")]
+#[test]
+fn test_hash_to_curve() {
+    use crate::hash_to_curve::{
+        tests::{hash_to_curve_test, map_to_curve_test},
+        SWUMap, SvdWMap,
+    };
+
+    map_to_curve_test::<TweedledeeParameters, SWUMap<TweedledeeParameters>>();
+    hash_to_curve_test::<TweedledeeParameters, SWUMap<TweedledeeParameters>>();
+    map_to_curve_test::<TweedledumParameters, SWUMap<TweedledumParameters>>();
+    hash_to_curve_test::<TweedledumParameters, SWUMap<TweedledumParameters>>();
+
+    map_to_curve_test::<TweedledeeParameters, SvdWMap<TweedledeeParameters>>();
+    hash_to_curve_test::<TweedledeeParameters, SvdWMap<TweedledeeParameters>>();
+    map_to_curve_test::<TweedledumParameters, SvdWMap<TweedledumParameters>>();
+    hash_to_curve_test::<TweedledumParameters, SvdWMap<TweedledumParameters>>();
+}
@@ -0,0 +1,267 @@
+//! Hashing to elliptic curves according to the IETF draft
+//! [draft-irtf-cfrg-hash-to-curve](https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/).
+//! Messages are first hashed to field elements by means of `expand_message_xmd`, which are
+//! then mapped to the curve either by the simplified SWU map, composed with an isogeny for
+//! curves with `A * B = 0`, or by the Shallue-van de Woestijne map. The sign of field elements
+//! is `HashToField::sgn0`, which differs from `Field::is_odd` on extension fields, and the
+//! mapped points are sent to the prime order subgroup by the effective cofactor `h_eff`.
+use crate::{AffineCurve, BitIterator, Error, Field, FpParameters, PrimeField, ProjectiveCurve};
+use crate::{QuadExtField, QuadExtParameters};
+use digest::{BlockInput, Digest};
+use std::marker::PhantomData;
+
+pub mod swu;
+pub use self::swu::*;
+
+pub mod svdw;
+pub use self::svdw::*;
+
+#[cfg(test)]
+pub mod tests;
+
+/// The security level `k` in bits, which determines the number of bytes hashed to
+/// obtain a field element with bias at most `2^-k`.
+pub const SECURITY_BITS: usize = 128;
+
+#[derive(Debug)]
+pub enum HashToCurveError {
+    InvalidDomainSeparationTag(String),
+    InvalidOutputLength(String),
+}
+
+impl std::fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            HashToCurveError::InvalidDomainSeparationTag(s) => {
+                format!("invalid domain separation tag: {}", s)
+            }
+            HashToCurveError::InvalidOutputLength(s) => format!("invalid output length: {}", s),
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl std::error::Error for HashToCurveError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        None
+    }
+}
+
+/// Check that `dst` is a valid domain separation tag, i.e. non empty and at most
+/// 255 bytes long.
+pub fn check_domain_separation_tag(dst: &[u8]) -> Result<(), Error> {
+    if dst.is_empty() || dst.len() > 255 {
+        return Err(Box::new(HashToCurveError::InvalidDomainSeparationTag(
+            format!("length must be between 1 and 255, found {}", dst.len()),
+        )));
+    }
+    Ok(())
+}
+
+/// `expand_message_xmd` of the draft: expand `msg` to `len_in_bytes` uniformly distributed
+/// bytes by means of the Merkle-Damgard hash function `D`, domain separated by `dst`.
+pub fn expand_message_xmd<D: Digest + BlockInput>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, Error> {
+    check_domain_separation_tag(dst)?;
+    let b_in_bytes = <D as Digest>::output_size();
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    if len_in_bytes == 0 || ell > 255 || len_in_bytes > 65535 {
+        return Err(Box::new(HashToCurveError::InvalidOutputLength(format!(
+            "cannot expand to {} bytes",
+            len_in_bytes
+        ))));
+    }
+    let s_in_bytes = <D::BlockSize as digest::generic_array::typenum::Unsigned>::to_usize();
+
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let b_0 = D::new()
+        .chain(vec![0u8; s_in_bytes])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    let mut b_i = D::new()
+        .chain(&b_0)
+        .chain([1u8])
+        .chain(&dst_prime)
+        .finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xored = b_0
+            .iter()
+            .zip(b_i.iter())
+            .map(|(b0, bi)| b0 ^ bi)
+            .collect::<Vec<_>>();
+        b_i = D::new()
+            .chain(xored)
+            .chain([i as u8])
+            .chain(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// Fields whose elements can be sampled from uniformly distributed bytes, as needed by
+/// `hash_to_field`, and have the sign `sgn0` of the draft.
+pub trait HashToField: Field {
+    /// The number of bytes reduced to an element, `L * m` in the draft.
+    fn uniform_bytes_len() -> usize;
+
+    /// Read an element from `Self::uniform_bytes_len()` bytes, each coordinate over the
+    /// prime field being reduced from `L` big endian bytes.
+    fn from_uniform_bytes(bytes: &[u8]) -> Self;
+
+    /// `sgn0` of the draft: the parity of the first non zero coordinate over the prime
+    /// field, starting from the constant one.
+    fn sgn0(&self) -> bool;
+}
+
+impl<F: PrimeField> HashToField for F {
+    fn uniform_bytes_len() -> usize {
+        (F::Params::MODULUS_BITS as usize + SECURITY_BITS + 7) / 8
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        F::from_be_bytes_mod_order(bytes)
+    }
+
+    fn sgn0(&self) -> bool {
+        self.is_odd()
+    }
+}
+
+impl<P: QuadExtParameters> HashToField for QuadExtField<P>
+where
+    P::BaseField: HashToField,
+{
+    fn uniform_bytes_len() -> usize {
+        2 * P::BaseField::uniform_bytes_len()
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        let (c0_bytes, c1_bytes) = bytes.split_at(P::BaseField::uniform_bytes_len());
+        QuadExtField::new(
+            P::BaseField::from_uniform_bytes(c0_bytes),
+            P::BaseField::from_uniform_bytes(c1_bytes),
+        )
+    }
+
+    /// Unlike `QuadExtField::is_odd`, which looks at `c1` first.
+    fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+}
+
+/// `hash_to_field` of the draft: hash `msg` to `count` elements of `F`, using
+/// `expand_message_xmd` with the hash function `D`.
+pub fn hash_to_field<F: HashToField, D: Digest + BlockInput>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<F>, Error> {
+    let len = F::uniform_bytes_len();
+    let uniform_bytes = expand_message_xmd::<D>(msg, dst, count * len)?;
+    Ok(uniform_bytes
+        .chunks(len)
+        .map(|chunk| F::from_uniform_bytes(chunk))
+        .collect())
+}
+
+/// A deterministic map from the base field of `G` to the points of `G`, not necessarily
+/// in the prime order subgroup.
+pub trait MapToCurve<G: AffineCurve> {
+    /// The effective cofactor `h_eff` of the draft, as little endian 64 bit limbs: the
+    /// multiplication by `h_eff` sends the points of `G` to its prime order subgroup.
+    const H_EFF: &'static [u64];
+
+    fn map_to_curve(u: &G::BaseField) -> G;
+}
+
+/// Hashing of arbitrary messages to the prime order subgroup of `G`.
+pub trait HashToCurve<G: AffineCurve>: Sized {
+    /// Initialize the hasher with the domain separation tag `dst`.
+    fn new(dst: &[u8]) -> Result<Self, Error>;
+
+    /// `hash_to_curve` of the draft, whose output is indistinguishable from a random
+    /// oracle to `G`.
+    fn hash(&self, msg: &[u8]) -> Result<G, Error>;
+
+    /// `encode_to_curve` of the draft, cheaper than `hash` but whose output is not
+    /// uniformly distributed.
+    fn encode(&self, msg: &[u8]) -> Result<G, Error>;
+}
+
+/// `clear_cofactor` of the draft, i.e. the multiplication of `p` by `h_eff`, which may
+/// exceed the order of the prime order subgroup.
+pub fn clear_cofactor<G: AffineCurve>(p: &G, h_eff: &[u64]) -> G {
+    let mut result = G::Projective::zero();
+    for bit in BitIterator::new(h_eff) {
+        result.double_in_place();
+        if bit {
+            result.add_assign_mixed(p);
+        }
+    }
+    result.into_affine()
+}
+
+/// The hash to curve construction of the draft: `msg` is hashed to field elements by
+/// `hash_to_field` with the hash function `D`, which are mapped to `G` by `M`, then
+/// the (sum of the) resulting points is multiplied by the effective cofactor of `M`.
+pub struct MapToCurveBasedHasher<G: AffineCurve, M: MapToCurve<G>, D: Digest + BlockInput> {
+    dst: Vec<u8>,
+    _group: PhantomData<G>,
+    _map: PhantomData<M>,
+    _digest: PhantomData<D>,
+}
+
+impl<G, M, D> HashToCurve<G> for MapToCurveBasedHasher<G, M, D>
+where
+    G: AffineCurve,
+    G::BaseField: HashToField,
+    M: MapToCurve<G>,
+    D: Digest + BlockInput,
+{
+    fn new(dst: &[u8]) -> Result<Self, Error> {
+        check_domain_separation_tag(dst)?;
+        Ok(Self {
+            dst: dst.to_vec(),
+            _group: PhantomData,
+            _map: PhantomData,
+            _digest: PhantomData,
+        })
+    }
+
+    fn hash(&self, msg: &[u8]) -> Result<G, Error> {
+        let u = hash_to_field::<G::BaseField, D>(msg, &self.dst, 2)?;
+        let mut q = M::map_to_curve(&u[0]).into_projective();
+        q.add_assign_mixed(&M::map_to_curve(&u[1]));
+        Ok(clear_cofactor(&q.into_affine(), M::H_EFF))
+    }
+
+    fn encode(&self, msg: &[u8]) -> Result<G, Error> {
+        let u = hash_to_field::<G::BaseField, D>(msg, &self.dst, 1)?;
+        Ok(clear_cofactor(&M::map_to_curve(&u[0]), M::H_EFF))
+    }
+}
+
+/// Evaluate the polynomial with coefficients `coeffs`, from the constant term on, at `x`.
+pub(crate) fn evaluate_polynomial<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
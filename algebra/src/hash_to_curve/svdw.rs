@@ -0,0 +1,83 @@
+use crate::curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine};
+use crate::hash_to_curve::{HashToField, MapToCurve};
+use crate::{Field, SquareRootField};
+use std::marker::PhantomData;
+
+/// Parameters of the Shallue-van de Woestijne map to a short Weierstrass curve, which,
+/// unlike the simplified SWU map, applies to any curve.
+pub trait SvdWParameters: SWModelParameters {
+    /// The `Z` of the draft, such that `g(Z) != 0`, `-(3 * Z^2 + 4 * A)/(4 * g(Z))` is a
+    /// non zero square, and at least one of `g(Z)` and `g(-Z/2)` is a square, with
+    /// `g(x) = x^3 + A * x + B`.
+    const SVDW_Z: Self::BaseField;
+
+    /// The effective cofactor `h_eff`, by default the cofactor of the curve.
+    const H_EFF: &'static [u64] = Self::COFACTOR;
+
+    /// The constants `(c1, c2, c3, c4)` derived from `Z`:
+    ///     `c1 = g(Z)`,
+    ///     `c2 = -Z/2`,
+    ///     `c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A))`, the one with `sgn0(c3) = 0`,
+    ///     `c4 = -4 * g(Z)/(3 * Z^2 + 4 * A)`.
+    fn svdw_constants() -> (
+        Self::BaseField,
+        Self::BaseField,
+        Self::BaseField,
+        Self::BaseField,
+    )
+    where
+        Self::BaseField: HashToField,
+    {
+        let z = Self::SVDW_Z;
+        let g_z = (z.square() + &Self::COEFF_A) * &z + &Self::COEFF_B;
+        let three_z2_plus_four_a =
+            z.square() * &Self::BaseField::from(3u8) + &Self::COEFF_A.double().double();
+
+        let c1 = g_z;
+        let c2 = -z * &Self::BaseField::from(2u8).inverse().unwrap();
+        let mut c3 = (-g_z * &three_z2_plus_four_a).sqrt().unwrap();
+        if c3.sgn0() {
+            c3 = -c3;
+        }
+        let c4 = -g_z.double().double() * &three_z2_plus_four_a.inverse().unwrap();
+        (c1, c2, c3, c4)
+    }
+}
+
+/// The Shallue-van de Woestijne map.
+pub struct SvdWMap<P: SvdWParameters>(PhantomData<P>);
+
+impl<P: SvdWParameters> MapToCurve<GroupAffine<P>> for SvdWMap<P>
+where
+    P::BaseField: HashToField,
+{
+    const H_EFF: &'static [u64] = P::H_EFF;
+
+    fn map_to_curve(u: &P::BaseField) -> GroupAffine<P> {
+        let (c1, c2, c3, c4) = P::svdw_constants();
+        let g = |x: &P::BaseField| (x.square() + &P::COEFF_A) * x + &P::COEFF_B;
+        let one = P::BaseField::one();
+
+        let tv1 = u.square() * &c1;
+        let tv2 = one + &tv1;
+        let tv1 = one - &tv1;
+        let tv3 = (tv1 * &tv2).inverse().unwrap_or_else(P::BaseField::zero);
+        let tv4 = *u * &tv1 * &tv3 * &c3;
+
+        // At least one among g(x1), g(x2) and g(x3) is a square: the first one is taken.
+        let x1 = c2 - &tv4;
+        let x2 = c2 + &tv4;
+        let x3 = (tv2.square() * &tv3).square() * &c4 + &P::SVDW_Z;
+        let x = [x1, x2]
+            .iter()
+            .find(|x| !g(x).legendre().is_qnr())
+            .cloned()
+            .unwrap_or(x3);
+
+        let mut y = g(&x).sqrt().unwrap();
+        if y.sgn0() != u.sgn0() {
+            y = -y;
+        }
+        GroupAffine::new(x, y, false)
+    }
+}
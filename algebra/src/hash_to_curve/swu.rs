@@ -0,0 +1,106 @@
+use crate::curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine};
+use crate::hash_to_curve::{evaluate_polynomial, HashToField, MapToCurve};
+use crate::{AffineCurve, Field, SquareRootField};
+use std::marker::PhantomData;
+
+/// Parameters of the simplified SWU map to a short Weierstrass curve `E`. As the map needs
+/// `A * B != 0`, it is computed on an isogenous curve `E': y^2 = x^3 + A' * x + B'`, with
+/// `A' * B' != 0`, and then carried to `E` by the isogeny
+///     `(x, y) -> (x_num(x)/x_den(x), y * y_num(x)/y_den(x))`.
+/// Curves with `A * B != 0` may set `E' = E` and use the identity as isogeny.
+pub trait SWUParameters: SWModelParameters {
+    /// The non square `Z` of the draft, such that `g'(x) - Z` is irreducible and
+    /// `g'(B'/(Z * A'))` is a square, with `g'(x) = x^3 + A' * x + B'`.
+    const SWU_Z: Self::BaseField;
+
+    /// The coefficient `A'` of the isogenous curve.
+    const ISOGENOUS_COEFF_A: Self::BaseField;
+
+    /// The coefficient `B'` of the isogenous curve.
+    const ISOGENOUS_COEFF_B: Self::BaseField;
+
+    /// Coefficients of `x_num`, from the constant term on.
+    const ISOGENY_X_NUM: &'static [Self::BaseField];
+
+    /// Coefficients of `x_den`, from the constant term on.
+    const ISOGENY_X_DEN: &'static [Self::BaseField];
+
+    /// Coefficients of `y_num`, from the constant term on.
+    const ISOGENY_Y_NUM: &'static [Self::BaseField];
+
+    /// Coefficients of `y_den`, from the constant term on.
+    const ISOGENY_Y_DEN: &'static [Self::BaseField];
+
+    /// The effective cofactor `h_eff`, by default the cofactor of the curve.
+    const H_EFF: &'static [u64] = Self::COFACTOR;
+}
+
+/// The simplified SWU map, composed with the isogeny to the target curve.
+pub struct SWUMap<P: SWUParameters>(PhantomData<P>);
+
+impl<P: SWUParameters> SWUMap<P>
+where
+    P::BaseField: HashToField,
+{
+    /// Map `u` to a point `(x, y)` of the isogenous curve `E'`.
+    pub fn map_to_isogenous_curve(u: &P::BaseField) -> (P::BaseField, P::BaseField) {
+        let a = P::ISOGENOUS_COEFF_A;
+        let b = P::ISOGENOUS_COEFF_B;
+        let z = P::SWU_Z;
+        let g = |x: &P::BaseField| (x.square() + &a) * x + &b;
+
+        // tv1 = inv0(Z^2 * u^4 + Z * u^2)
+        let z_u2 = z * &u.square();
+        let tv1 = (z_u2.square() + &z_u2)
+            .inverse()
+            .unwrap_or_else(P::BaseField::zero);
+
+        // x1 = (-B / A) * (1 + tv1), or B / (Z * A) if tv1 == 0
+        let x1 = if tv1.is_zero() {
+            b * &(z * &a).inverse().unwrap()
+        } else {
+            -b * &a.inverse().unwrap() * &(P::BaseField::one() + &tv1)
+        };
+
+        // If g(x1) is square, then x = x1, otherwise x = Z * u^2 * x1, in which case
+        // g(x) is guaranteed to be square.
+        let gx1 = g(&x1);
+        let (x, gx) = if gx1.legendre().is_qnr() {
+            let x2 = z_u2 * &x1;
+            (x2, g(&x2))
+        } else {
+            (x1, gx1)
+        };
+        let mut y = gx.sqrt().unwrap();
+        if y.sgn0() != u.sgn0() {
+            y = -y;
+        }
+        (x, y)
+    }
+
+    /// Carry a point `(x, y)` of the isogenous curve to the target curve. Returns
+    /// `None` if `(x, y)` is in the kernel of the isogeny.
+    pub fn apply_isogeny(x: &P::BaseField, y: &P::BaseField) -> Option<GroupAffine<P>> {
+        let x_den = evaluate_polynomial(P::ISOGENY_X_DEN, x).inverse()?;
+        let y_den = evaluate_polynomial(P::ISOGENY_Y_DEN, x).inverse()?;
+        let x_num = evaluate_polynomial(P::ISOGENY_X_NUM, x);
+        let y_num = evaluate_polynomial(P::ISOGENY_Y_NUM, x);
+        Some(GroupAffine::new(
+            x_num * &x_den,
+            *y * &y_num * &y_den,
+            false,
+        ))
+    }
+}
+
+impl<P: SWUParameters> MapToCurve<GroupAffine<P>> for SWUMap<P>
+where
+    P::BaseField: HashToField,
+{
+    const H_EFF: &'static [u64] = P::H_EFF;
+
+    fn map_to_curve(u: &P::BaseField) -> GroupAffine<P> {
+        let (x, y) = Self::map_to_isogenous_curve(u);
+        Self::apply_isogeny(&x, &y).unwrap_or_else(GroupAffine::zero)
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine},
+    hash_to_curve::{
+        expand_message_xmd, hash_to_field, HashToCurve, HashToField, MapToCurve,
+        MapToCurveBasedHasher,
+    },
+    AffineCurve, Field, UniformRand,
+};
+use hex_literal::hex;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use sha2::{Sha256, Sha512};
+
+const TEST_DST: &[u8] = b"GINGER-V01-TEST-HASH-TO-CURVE";
+
+/// Check that `M` maps to points on the curve.
+pub(crate) fn map_to_curve_test<P: SWModelParameters, M: MapToCurve<GroupAffine<P>>>() {
+    let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+    let mut inputs = vec![
+        P::BaseField::zero(),
+        P::BaseField::one(),
+        -P::BaseField::one(),
+    ];
+    for _ in 0..20 {
+        inputs.push(P::BaseField::rand(rng));
+    }
+
+    for u in inputs.iter() {
+        assert!(M::map_to_curve(u).is_on_curve());
+    }
+}
+
+/// Check that the hash to curve construction with `M` and SHA-256 outputs valid
+/// points of the prime order subgroup, depending both on the message and on the
+/// domain separation tag.
+pub(crate) fn hash_to_curve_test<P, M>()
+where
+    P: SWModelParameters,
+    P::BaseField: HashToField,
+    M: MapToCurve<GroupAffine<P>>,
+{
+    let hasher = MapToCurveBasedHasher::<GroupAffine<P>, M, Sha256>::new(TEST_DST).unwrap();
+    let other_hasher =
+        MapToCurveBasedHasher::<GroupAffine<P>, M, Sha256>::new(b"GINGER-V01-OTHER-DST").unwrap();
+
+    let messages: [&[u8]; 4] = [b"", b"abc", b"abcdef0123456789", &[0xa5u8; 200]];
+    let mut outputs = vec![];
+    for msg in messages.iter() {
+        for p in [hasher.hash(msg).unwrap(), hasher.encode(msg).unwrap()].iter() {
+            assert!(p.is_on_curve());
+            assert!(p.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!p.is_zero());
+        }
+        let p = hasher.hash(msg).unwrap();
+        assert_eq!(p, hasher.hash(msg).unwrap());
+        assert_ne!(p, other_hasher.hash(msg).unwrap());
+        outputs.push(p);
+    }
+    for (i, p) in outputs.iter().enumerate() {
+        assert!(outputs[i + 1..].iter().all(|q| p != q));
+    }
+
+    // The domain separation tag must be non empty and at most 255 bytes long
+    assert!(MapToCurveBasedHasher::<GroupAffine<P>, M, Sha256>::new(b"").is_err());
+    assert!(MapToCurveBasedHasher::<GroupAffine<P>, M, Sha256>::new(&[0u8; 256]).is_err());
+}
+
+#[test]
+fn test_expand_message_xmd_sha256() {
+    // Test vectors of expand_message_xmd with SHA-256 from the draft
+    let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    assert_eq!(
+        expand_message_xmd::<Sha256>(b"", dst, 0x20).unwrap(),
+        hex!("68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235").to_vec()
+    );
+    assert_eq!(
+        expand_message_xmd::<Sha256>(b"abc", dst, 0x20).unwrap(),
+        hex!("d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615").to_vec()
+    );
+}
+
+#[test]
+fn test_expand_message_xmd_lengths() {
+    let expanded = expand_message_xmd::<Sha512>(b"abc", TEST_DST, 255 * 64).unwrap();
+    assert_eq!(expanded.len(), 255 * 64);
+
+    // The output length is hashed too, so shorter outputs are not prefixes of longer ones
+    let shorter = expand_message_xmd::<Sha512>(b"abc", TEST_DST, 100).unwrap();
+    assert_eq!(shorter.len(), 100);
+    assert_ne!(shorter[..], expanded[..100]);
+
+    // At most 255 blocks of output, and a non empty output
+    assert!(expand_message_xmd::<Sha256>(b"abc", TEST_DST, 255 * 32 + 1).is_err());
+    assert!(expand_message_xmd::<Sha256>(b"abc", TEST_DST, 0).is_err());
+    assert!(expand_message_xmd::<Sha256>(b"abc", b"", 32).is_err());
+}
+
+#[cfg(feature = "tweedle")]
+#[test]
+fn test_hash_to_field() {
+    use crate::fields::tweedle::Fq;
+
+    // Elements are reduced from 48 bytes, i.e. (255 + 128)/8 rounded up
+    assert_eq!(Fq::uniform_bytes_len(), 48);
+    let elements = hash_to_field::<Fq, Sha256>(b"abc", TEST_DST, 3).unwrap();
+    let expanded = expand_message_xmd::<Sha256>(b"abc", TEST_DST, 3 * 48).unwrap();
+    for (element, bytes) in elements.iter().zip(expanded.chunks(48)) {
+        assert_eq!(*element, Fq::from_uniform_bytes(bytes));
+    }
+    assert!(elements[0] != elements[1] && elements[1] != elements[2]);
+}
+
+#[cfg(feature = "bls12_381")]
+#[test]
+fn test_sgn0() {
+    use crate::fields::bls12_381::{Fq, Fq2};
+
+    // On prime fields sgn0 is the parity
+    assert!(!Fq::zero().sgn0());
+    assert!(Fq::one().sgn0());
+    assert!(!(-Fq::one()).sgn0());
+
+    // On extension fields the first non zero coordinate, starting from c0, decides,
+    // differently from `Field::is_odd`
+    let two = Fq::from(2u8);
+    assert!(Fq2::new(Fq::one(), two).sgn0());
+    assert!(!Fq2::new(Fq::one(), two).is_odd());
+    assert!(!Fq2::new(two, Fq::one()).sgn0());
+    assert!(Fq2::new(two, Fq::one()).is_odd());
+    assert!(Fq2::new(Fq::zero(), Fq::one()).sgn0());
+    assert!(!Fq2::new(Fq::zero(), two).sgn0());
+    assert!(!Fq2::zero().sgn0());
+}
@@ -72,6 +72,9 @@ pub mod fft;
 #[cfg(feature = "fft")]
 pub use self::fft::*;
 
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
+
 pub type Error = Box<dyn std::error::Error>;
 
 /// Returns the ceiling of the base-2 logarithm of `x`.
@@ -0,0 +1,117 @@
+//! A fixed-length CRH to the prime order subgroup of a short Weierstrass curve, based on the
+//! hash to curve construction of `algebra::hash_to_curve` with SHA-256, e.g. to be used as
+//! group hash of `FieldBasedEcVrf`.
+use crate::{crh::FixedLengthCRH, CryptoError, Error};
+use algebra::{
+    curves::{
+        models::SWModelParameters,
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    },
+    hash_to_curve::{HashToCurve, HashToField, MapToCurve, MapToCurveBasedHasher},
+    AffineCurve,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::marker::PhantomData;
+
+/// The domain separation tag used by default.
+pub const HASH_TO_CURVE_CRH_DST: &[u8] = b"GINGER-V01-HASH-TO-CURVE-CRH_XMD:SHA-256_RO_";
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct HashToCurveCRHParameters {
+    /// The domain separation tag of the hash to curve construction.
+    pub dst: Vec<u8>,
+}
+
+impl Default for HashToCurveCRHParameters {
+    fn default() -> Self {
+        Self {
+            dst: HASH_TO_CURVE_CRH_DST.to_vec(),
+        }
+    }
+}
+
+/// Hash inputs of at most `INPUT_SIZE_BITS` to the curve defined by `P`, by means of the
+/// map `M` (the simplified SWU or the Shallue-van de Woestijne map).
+pub struct HashToCurveCRH<P: SWModelParameters, M: MapToCurve<GroupAffine<P>>> {
+    _parameters: PhantomData<P>,
+    _map: PhantomData<M>,
+}
+
+impl<P, M> FixedLengthCRH for HashToCurveCRH<P, M>
+where
+    P: SWModelParameters,
+    P::BaseField: HashToField,
+    M: MapToCurve<GroupAffine<P>>,
+{
+    /// Enough to hash the byte serialization of a few field elements.
+    const INPUT_SIZE_BITS: usize = 1024;
+    type Output = GroupProjective<P>;
+    type Parameters = HashToCurveCRHParameters;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(HashToCurveCRHParameters::default())
+    }
+
+    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "HashToCurveCRH::Eval");
+
+        if input.len() * 8 > Self::INPUT_SIZE_BITS {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "HashToCurveCRH input".to_owned(),
+                input.len(),
+            )));
+        }
+        let hasher = MapToCurveBasedHasher::<GroupAffine<P>, M, Sha256>::new(&parameters.dst)?;
+        let result = hasher.hash(input)?.into_projective();
+
+        end_timer!(eval_time);
+        Ok(result)
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::{HashToCurveCRH, HashToCurveCRHParameters};
+    use crate::crh::FixedLengthCRH;
+    use algebra::{
+        curves::tweedle::dee::{Affine, TweedledeeParameters},
+        hash_to_curve::{HashToCurve, MapToCurveBasedHasher, SWUMap},
+        ProjectiveCurve,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use sha2::Sha256;
+
+    type TweedleDeeCRH = HashToCurveCRH<TweedledeeParameters, SWUMap<TweedledeeParameters>>;
+
+    #[test]
+    fn test_hash_to_curve_crh() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let params = TweedleDeeCRH::setup(rng).unwrap();
+
+        let input = b"Hi, I am a hash to curve input!";
+        let output = TweedleDeeCRH::evaluate(&params, input).unwrap();
+        let expected =
+            MapToCurveBasedHasher::<Affine, SWUMap<TweedledeeParameters>, Sha256>::new(&params.dst)
+                .unwrap()
+                .hash(input)
+                .unwrap();
+        assert_eq!(output.into_affine(), expected);
+
+        // The domain separation tag matters
+        let other_params = HashToCurveCRHParameters {
+            dst: b"GINGER-V01-OTHER-DST".to_vec(),
+        };
+        assert_ne!(
+            TweedleDeeCRH::evaluate(&other_params, input).unwrap(),
+            output
+        );
+
+        // Inputs longer than `INPUT_SIZE_BITS` and empty tags are rejected
+        assert!(TweedleDeeCRH::evaluate(&params, &[0u8; 129]).is_err());
+        let empty_params = HashToCurveCRHParameters { dst: vec![] };
+        assert!(TweedleDeeCRH::evaluate(&empty_params, input).is_err());
+    }
+}
@@ -0,0 +1,61 @@
+//! A fixed-length CRH from the original [Keccak-256](https://keccak.team/keccak.html) hash
+//! function, i.e. Keccak-f[1600] with rate 1088 bits and the `0x01` padding byte (as used by
+//! Ethereum), which differs from the standardized SHA3-256 only in the domain separation.
+use crate::{crh::FixedLengthCRH, CryptoError, Error};
+use rand::Rng;
+use sha3::{Digest, Keccak256};
+
+/// Byte-oriented Keccak-256 CRH. The input is hashed as it is, i.e. without any zero padding,
+/// and the output is the 32 bytes long digest.
+pub struct Keccak256CRH;
+
+impl FixedLengthCRH for Keccak256CRH {
+    /// Enough to hash the concatenation of two digests, e.g. in a Merkle tree.
+    const INPUT_SIZE_BITS: usize = 512;
+    type Output = [u8; 32];
+    type Parameters = ();
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(())
+    }
+
+    fn evaluate(_parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "Keccak256CRH::Eval");
+
+        if input.len() * 8 > Self::INPUT_SIZE_BITS {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "Keccak256CRH input".to_owned(),
+                input.len(),
+            )));
+        }
+        let mut result = [0u8; 32];
+        result.copy_from_slice(&Keccak256::digest(input));
+
+        end_timer!(eval_time);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Keccak256CRH;
+    use crate::crh::FixedLengthCRH;
+
+    #[test]
+    fn test_keccak256_crh_test_vectors() {
+        let test_inputs = ["", "abc", "The quick brown fox jumps over the lazy dog"];
+        let test_outputs = [
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+        ];
+
+        for (input, output) in test_inputs.iter().zip(test_outputs.iter()) {
+            let digest = Keccak256CRH::evaluate(&(), input.as_bytes()).unwrap();
+            assert_eq!(hex::encode(digest), *output);
+        }
+
+        // Inputs longer than `INPUT_SIZE_BITS` are rejected
+        assert!(Keccak256CRH::evaluate(&(), &[0u8; 65]).is_err());
+    }
+}
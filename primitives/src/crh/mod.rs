@@ -13,6 +13,16 @@ pub use self::sbox::*;
 pub mod poseidon;
 pub use self::poseidon::*;
 
+pub mod rescue;
+
+#[cfg(feature = "digest_crh")]
+pub mod keccak;
+#[cfg(feature = "digest_crh")]
+pub mod sha512;
+
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
+
 use crate::{CryptoError, Error};
 use rayon::prelude::*;
 
@@ -64,6 +74,27 @@ pub trait FieldBasedHash {
     fn reset(&mut self, personalization: Option<&[Self::Data]>) -> &mut Self;
 }
 
+/// A duplex sponge working with field arithmetics, built on top of a FieldBasedHash: it allows
+/// to absorb and squeeze an arbitrary number of field elements, interleaving the two operations
+/// as needed (e.g. by Fiat-Shamir transcripts or key derivation).
+/// NOTE: Mixing the FieldBasedHash and the FieldBasedSponge interfaces on the same instance
+///       is not supported.
+pub trait FieldBasedSponge: FieldBasedHash {
+    /// Initialize the sponge to a null state, or with `personalization` if specified:
+    /// sponges with different personalizations are domain separated.
+    fn init(personalization: Option<&[Self::Data]>) -> Self;
+
+    /// Absorb `elems` into the sponge.
+    fn absorb(&mut self, elems: &[Self::Data]) -> &mut Self;
+
+    /// Squeeze `num` field elements out of the sponge.
+    fn squeeze(&mut self, num: usize) -> Vec<Self::Data>;
+
+    /// Squeeze `num_bits` bits out of the sponge: for each squeezed field element,
+    /// only the `CAPACITY` least significant bits, in big-endian order, are taken.
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool>;
+}
+
 /// Helper allowing to hash the implementor of this trait into a Field
 pub trait FieldHasher<F: Field, H: FieldBasedHash<Data = F>> {
     /// Hash `self`, given some optional `personalization` into a Field
@@ -0,0 +1,1054 @@
+//! Generation of Poseidon parameters for arbitrary prime fields.
+//!
+//! This is a Rust port of the scripts in `./parameters/scripts`:
+//! - the number of rounds is computed as in `calc_round_numbers.py`, i.e. by searching the
+//!   round numbers minimizing the number of S-Boxes while satisfying the security inequalities
+//!   of the Poseidon paper;
+//! - the round constants and the MDS matrix are sampled from the Grain LFSR exactly as
+//!   `generate_parameters_grain.sage` does, including the security checks on the MDS matrix
+//!   (Algorithms 1, 2 and 3 of "Proving Resistance Against Infinitely Long Subspace Trails:
+//!   How to Choose the Linear Layer", Grassi et al.).
+//!
+//! Therefore, for the same inputs, the generated parameters coincide with the ones produced
+//! by the scripts and hardcoded in `./parameters`.
+use crate::{crh::PoseidonParameters, CryptoError, Error};
+use algebra::{BigInteger, BitIterator, Field, FpParameters, PrimeField};
+use std::{fmt::Write, marker::PhantomData};
+
+/// The S-Box of a Poseidon instance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PoseidonSBoxType {
+    /// The power map x -> x^alpha, with alpha coprime to p - 1.
+    Exponent(u64),
+    /// The inversion map x -> x^(-1), mapping 0 to 0.
+    Inverse,
+}
+
+impl PoseidonSBoxType {
+    /// The identifier of the S-Box used to initialize the Grain LFSR.
+    fn grain_id(&self) -> u64 {
+        match self {
+            PoseidonSBoxType::Exponent(_) => 0,
+            PoseidonSBoxType::Inverse => 1,
+        }
+    }
+
+    fn apply<F: Field>(&self, x: &mut F) {
+        match self {
+            PoseidonSBoxType::Exponent(alpha) => *x = x.pow([*alpha]),
+            PoseidonSBoxType::Inverse => *x = x.inverse().unwrap_or_else(F::zero),
+        }
+    }
+}
+
+/// Poseidon parameters generated at runtime by a `PoseidonParametersGenerator`.
+/// The conventions are the ones of `PoseidonParameters`: `r_f` is half the number
+/// of full rounds and the MDS matrix is stored row by row.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct GeneratedPoseidonParameters<F: PrimeField> {
+    pub t: usize,
+    pub r_f: usize,
+    pub r_p: usize,
+    pub sbox: PoseidonSBoxType,
+    pub round_cst: Vec<F>,
+    pub mds: Vec<F>,
+    pub after_zero_perm: Vec<F>,
+}
+
+impl<F: PrimeField> GeneratedPoseidonParameters<F> {
+    /// Apply the Poseidon permutation defined by these parameters to `state`.
+    pub fn permute(&self, state: &mut [F]) {
+        assert_eq!(state.len(), self.t);
+        let num_rounds = 2 * self.r_f + self.r_p;
+        for (round, round_cst) in self.round_cst.chunks(self.t).take(num_rounds).enumerate() {
+            state
+                .iter_mut()
+                .zip(round_cst.iter())
+                .for_each(|(s, c)| *s += c);
+
+            if round < self.r_f || round >= self.r_f + self.r_p {
+                state.iter_mut().for_each(|s| self.sbox.apply(s));
+            } else {
+                self.sbox.apply(&mut state[0]);
+            }
+
+            let new_state = self
+                .mds
+                .chunks(self.t)
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(F::zero(), |acc, (m, s)| acc + &(*m * s))
+                })
+                .collect::<Vec<_>>();
+            state.copy_from_slice(new_state.as_slice());
+        }
+    }
+
+    /// Return true if these parameters coincide with the ones hardcoded in `P`.
+    pub fn matches<P: PoseidonParameters<Fr = F>>(&self) -> bool {
+        P::T == self.t
+            && P::R_F as usize == self.r_f
+            && P::R_P as usize == self.r_p
+            && P::ROUND_CST == self.round_cst.as_slice()
+            && P::MDS_CST == self.mds.as_slice()
+            && P::AFTER_ZERO_PERM == self.after_zero_perm.as_slice()
+    }
+
+    /// Emit the Rust source of a `PoseidonParameters` implementation, named `params_name`,
+    /// for the field `field_name` whose `BigInteger` type is imported as `BigInteger`,
+    /// in the same format of the hardcoded parameters in `./parameters`.
+    pub fn to_rust_source(&self, params_name: &str, field_name: &str) -> String {
+        let field_elem = |f: &F| -> String {
+            let limbs = f
+                .into_repr_raw()
+                .as_ref()
+                .iter()
+                .map(|limb| format!("0x{:x}", limb))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("field_new!({}, BigInteger([{}]))", field_name, limbs)
+        };
+        let field_elems = |elems: &[F]| -> String {
+            elems
+                .iter()
+                .map(|f| format!("        {},\n", field_elem(f)))
+                .collect::<String>()
+        };
+
+        let sbox = match self.sbox {
+            PoseidonSBoxType::Exponent(alpha) => format!("x^{}", alpha),
+            PoseidonSBoxType::Inverse => "x^(-1)".to_owned(),
+        };
+
+        let mut src = String::new();
+        writeln!(src, "#[derive(Clone)]").unwrap();
+        writeln!(
+            src,
+            "/// {}-POSEIDON parameters of rate {} for the field {}.",
+            sbox,
+            self.t - 1,
+            field_name
+        )
+        .unwrap();
+        writeln!(src, "///").unwrap();
+        writeln!(
+            src,
+            "/// Generated by `PoseidonParametersGenerator`, reproducing the scripts in ./scripts."
+        )
+        .unwrap();
+        writeln!(src, "pub struct {};\n", params_name).unwrap();
+
+        writeln!(src, "impl FieldBasedHashParameters for {} {{", params_name).unwrap();
+        writeln!(src, "    type Fr = {};", field_name).unwrap();
+        writeln!(
+            src,
+            "    const R: usize = {}; // The rate of the hash function",
+            self.t - 1
+        )
+        .unwrap();
+        writeln!(src, "}}\n").unwrap();
+
+        writeln!(src, "impl PoseidonParameters for {} {{", params_name).unwrap();
+        writeln!(
+            src,
+            "    const T: usize = {}; // Size of the internal state (in field elements)",
+            self.t
+        )
+        .unwrap();
+        writeln!(
+            src,
+            "    const R_F: i32 = {}; // Half number of full rounds (the R_f in the paper)",
+            self.r_f
+        )
+        .unwrap();
+        writeln!(
+            src,
+            "    const R_P: i32 = {}; // Number of partial rounds",
+            self.r_p
+        )
+        .unwrap();
+        writeln!(src).unwrap();
+        writeln!(src, "    // The zero element of the field").unwrap();
+        writeln!(
+            src,
+            "    const ZERO: {} = {};",
+            field_name,
+            field_elem(&F::zero())
+        )
+        .unwrap();
+        writeln!(src).unwrap();
+        writeln!(
+            src,
+            "    // State vector after permutation of zero state vector (Montgomery rep.)"
+        )
+        .unwrap();
+        writeln!(
+            src,
+            "    const AFTER_ZERO_PERM: &'static [{}] = &[\n{}    ];",
+            field_name,
+            field_elems(&self.after_zero_perm)
+        )
+        .unwrap();
+        writeln!(src).unwrap();
+        writeln!(src, "    // Array of round constants").unwrap();
+        writeln!(
+            src,
+            "    const ROUND_CST: &'static [{}] = &[\n        // Constants converted to Montgomery representation.\n        // For rounds {} + {} + {} = {}\n{}    ];",
+            field_name,
+            self.r_f,
+            self.r_p,
+            self.r_f,
+            2 * self.r_f + self.r_p,
+            field_elems(&self.round_cst)
+        )
+        .unwrap();
+        writeln!(src).unwrap();
+        writeln!(src, "    // The MDS matrix constants").unwrap();
+        writeln!(
+            src,
+            "    const MDS_CST: &'static [{}] = &[\n        // Constants in Montgomery representation\n{}    ];",
+            field_name,
+            field_elems(&self.mds)
+        )
+        .unwrap();
+        writeln!(src, "}}").unwrap();
+        src
+    }
+}
+
+/// Generator of Poseidon parameters for the prime field `F`, given the width `t` of
+/// the state, the S-Box and the security level (in bits).
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct PoseidonParametersGenerator<F: PrimeField> {
+    t: usize,
+    sbox: PoseidonSBoxType,
+    security_level: usize,
+    security_margin: bool,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField> PoseidonParametersGenerator<F> {
+    /// Create a generator for a state of `t` field elements. By default, the
+    /// security margin of the Poseidon paper (+2 full rounds, +7.5% partial rounds)
+    /// is added to the round numbers.
+    pub fn new(t: usize, sbox: PoseidonSBoxType, security_level: usize) -> Self {
+        Self {
+            t,
+            sbox,
+            security_level,
+            security_margin: true,
+            _field: PhantomData,
+        }
+    }
+
+    /// Enable or disable the security margin on the round numbers.
+    pub fn with_security_margin(mut self, security_margin: bool) -> Self {
+        self.security_margin = security_margin;
+        self
+    }
+
+    fn check_inputs(&self) -> Result<(), Error> {
+        if self.t < 2 {
+            return Err(Box::new(CryptoError::InitializationError(format!(
+                "Invalid state size {}: must be at least 2",
+                self.t
+            ))));
+        }
+        if let PoseidonSBoxType::Exponent(alpha) = self.sbox {
+            // x^alpha is a permutation iff gcd(alpha, p - 1) = 1
+            let mut p_minus_one = F::Params::MODULUS;
+            p_minus_one.sub_noborrow(&F::BigInt::from(1));
+            if alpha < 3 || gcd(alpha, bigint_mod_u64(&p_minus_one, alpha)) != 1 {
+                return Err(Box::new(CryptoError::InitializationError(format!(
+                    "x^{} is not a permutation of the field",
+                    alpha
+                ))));
+            }
+        }
+        Ok(())
+    }
+
+    /// Compute the round numbers, returned as (half number of full rounds, number of
+    /// partial rounds), following `calc_round_numbers.py`.
+    pub fn round_numbers(&self) -> Result<(usize, usize), Error> {
+        self.check_inputs()?;
+
+        let t = self.t as f64;
+        let m = self.security_level as f64;
+        let log2_p = log2_modulus::<F>();
+        let n = log2_p.ceil();
+
+        let sat_inequiv = |r_f: usize, r_p: usize| -> bool {
+            let (r_f, r_p) = (r_f as f64, r_p as f64);
+            match self.sbox {
+                PoseidonSBoxType::Exponent(alpha) => {
+                    let alpha = alpha as f64;
+                    let log_alpha_2 = 2f64.ln() / alpha.ln();
+                    // Statistical
+                    let r_f_1: f64 = if m <= (log2_p - (alpha - 1.0) / 2.0).floor() * (t + 1.0) {
+                        6.0
+                    } else {
+                        10.0
+                    };
+                    // Interpolation
+                    let r_f_2 =
+                        1.0 + (log_alpha_2 * m.min(n)).ceil() + (t.ln() / alpha.ln()).ceil() - r_p;
+                    // Groebner 1
+                    let r_f_3 = 1.0 + (log_alpha_2 * (m / 3.0).min(log2_p / 2.0)) - r_p;
+                    // Groebner 2
+                    let r_f_4 = t - 1.0
+                        + ((log_alpha_2 * m) / (t + 1.0)).min((log_alpha_2 * log2_p) / 2.0)
+                        - r_p;
+                    let r_f_max = r_f_1
+                        .ceil()
+                        .max(r_f_2.ceil())
+                        .max(r_f_3.ceil())
+                        .max(r_f_4.ceil());
+                    r_f >= r_f_max
+                }
+                PoseidonSBoxType::Inverse => {
+                    let log_2_t = t.ln() / 2f64.ln();
+                    // Statistical
+                    let r_f_1: f64 = if m <= (log2_p - 2.0).floor() * (t + 1.0) {
+                        6.0
+                    } else {
+                        10.0
+                    };
+                    // Interpolation
+                    let r_p_1 =
+                        1.0 + (0.5 * m.min(n)).ceil() + log_2_t.ceil() - (r_f * log_2_t).floor();
+                    // Groebner 2
+                    let r_p_3 = t - 1.0
+                        + log_2_t.ceil()
+                        + (m / (t + 1.0)).ceil().min((0.5 * log2_p).ceil())
+                        - (r_f * log_2_t).floor();
+                    r_f >= r_f_1 && r_p >= r_p_1.ceil().max(r_p_3.ceil())
+                }
+            }
+        };
+
+        // Brute-force search of the round numbers minimizing the number of S-Boxes.
+        let mut best: Option<(usize, usize)> = None;
+        let mut min_cost = usize::MAX;
+        for r_p_start in 1..500 {
+            // As in the reference script, the security margin on the partial rounds
+            // is carried over the remaining iterations of the inner loop.
+            let mut r_p = r_p_start;
+            for r_f_start in (4..100).step_by(2) {
+                let mut r_f = r_f_start;
+                if sat_inequiv(r_f, r_p) {
+                    if self.security_margin {
+                        r_f += 2;
+                        r_p = (r_p as f64 * 1.075).ceil() as usize;
+                    }
+                    let cost = self.t * r_f + r_p;
+                    if cost < min_cost
+                        || (cost == min_cost
+                            && matches!(best, Some((best_r_f, _)) if r_f < best_r_f))
+                    {
+                        best = Some((r_f, r_p));
+                        min_cost = cost;
+                    }
+                }
+            }
+        }
+
+        best.map(|(r_f, r_p)| (r_f / 2, r_p)).ok_or_else(|| {
+            Box::new(CryptoError::InitializationError(
+                "Unable to find suitable round numbers".to_owned(),
+            )) as Error
+        })
+    }
+
+    /// Compute the round numbers and generate the corresponding parameters.
+    pub fn generate(&self) -> Result<GeneratedPoseidonParameters<F>, Error> {
+        let (r_f, r_p) = self.round_numbers()?;
+        self.generate_with_round_numbers(r_f, r_p)
+    }
+
+    /// Generate round constants and MDS matrix for the given round numbers,
+    /// `r_f` being half the number of full rounds.
+    pub fn generate_with_round_numbers(
+        &self,
+        r_f: usize,
+        r_p: usize,
+    ) -> Result<GeneratedPoseidonParameters<F>, Error> {
+        self.check_inputs()?;
+
+        let t = self.t;
+        let n = F::Params::MODULUS_BITS as usize;
+        if n >= 1 << 12 || t >= 1 << 12 || 2 * r_f >= 1 << 10 || r_p >= 1 << 10 {
+            return Err(Box::new(CryptoError::InitializationError(
+                "Parameters too big to initialize the Grain LFSR".to_owned(),
+            )));
+        }
+
+        let mut grain = GrainLfsr::new(self.sbox.grain_id(), n, t, 2 * r_f, r_p);
+
+        // Round constants first, then the first Cauchy matrix passing the security checks
+        let round_cst = grain.sample_field_elements::<F>((2 * r_f + r_p) * t);
+        let mds = loop {
+            let matrix = grain.sample_cauchy_matrix::<F>(t);
+            if algorithm_1(&matrix) && algorithm_2(&matrix) && algorithm_3(&matrix) {
+                break matrix;
+            }
+        };
+
+        let mut params = GeneratedPoseidonParameters {
+            t,
+            r_f,
+            r_p,
+            sbox: self.sbox,
+            round_cst,
+            mds: mds.into_iter().flatten().collect(),
+            after_zero_perm: vec![F::zero(); t],
+        };
+        let mut state = vec![F::zero(); t];
+        params.permute(&mut state);
+        params.after_zero_perm = state;
+
+        Ok(params)
+    }
+}
+
+/// log2 of the modulus of `F`, as a float.
+fn log2_modulus<F: PrimeField>() -> f64 {
+    let num_bits = F::Params::MODULUS_BITS as usize;
+    let top_bits = num_bits.min(64);
+    let top = (num_bits - top_bits..num_bits).rev().fold(0f64, |acc, i| {
+        2.0 * acc
+            + if F::Params::MODULUS.get_bit(i) {
+                1.0
+            } else {
+                0.0
+            }
+    });
+    top.log2() + (num_bits - top_bits) as f64
+}
+
+fn bigint_mod_u64<B: BigInteger>(value: &B, modulus: u64) -> u64 {
+    value.as_ref().iter().rev().fold(0u64, |acc, limb| {
+        ((((acc as u128) << 64) + *limb as u128) % modulus as u128) as u64
+    })
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The Grain LFSR used to sample the round constants and the MDS matrix.
+struct GrainLfsr {
+    state: [bool; 80],
+    head: usize,
+}
+
+impl GrainLfsr {
+    fn new(sbox: u64, field_size: usize, t: usize, r_f: usize, r_p: usize) -> Self {
+        let mut state = [true; 80];
+        // The prime field is identified by 1, the remaining 30 bits are set to 1
+        let init = [
+            (1, 2),
+            (sbox, 4),
+            (field_size as u64, 12),
+            (t as u64, 12),
+            (r_f as u64, 10),
+            (r_p as u64, 10),
+        ];
+        let mut idx = 0;
+        for (value, num_bits) in init.iter() {
+            for i in (0..*num_bits).rev() {
+                state[idx] = (value >> i) & 1 == 1;
+                idx += 1;
+            }
+        }
+        let mut grain = Self { state, head: 0 };
+        for _ in 0..160 {
+            grain.step();
+        }
+        grain
+    }
+
+    fn step(&mut self) -> bool {
+        let bit = |i: usize| self.state[(self.head + i) % 80];
+        let new_bit = bit(62) ^ bit(51) ^ bit(38) ^ bit(23) ^ bit(13) ^ bit(0);
+        self.state[self.head] = new_bit;
+        self.head = (self.head + 1) % 80;
+        new_bit
+    }
+
+    fn next_bit(&mut self) -> bool {
+        let mut bit = self.step();
+        while !bit {
+            self.step();
+            bit = self.step();
+        }
+        self.step()
+    }
+
+    /// Sample a `num_bits` integer, taking the bits starting from the most significant one.
+    fn next_bigint<F: PrimeField>(&mut self, num_bits: usize) -> F::BigInt {
+        let bits = (0..num_bits).map(|_| self.next_bit()).collect::<Vec<_>>();
+        F::BigInt::from_bits(bits.as_slice())
+    }
+
+    /// Sample `num` field elements as `MODULUS_BITS` integers, rejecting the ones
+    /// not smaller than the modulus (as done for the round constants).
+    fn sample_field_elements<F: PrimeField>(&mut self, num: usize) -> Vec<F> {
+        let n = F::Params::MODULUS_BITS as usize;
+        (0..num)
+            .map(|_| loop {
+                let value = self.next_bigint::<F>(n);
+                if value < F::Params::MODULUS {
+                    break F::from_repr(value);
+                }
+            })
+            .collect()
+    }
+
+    /// Sample a t x t Cauchy matrix M[i][j] = 1/(x_i + y_j), with the 2t distinct
+    /// x_i, y_j being `MODULUS_BITS` integers reduced modulo p. Such a matrix is MDS.
+    fn sample_cauchy_matrix<F: PrimeField>(&mut self, t: usize) -> Vec<Vec<F>> {
+        let n = F::Params::MODULUS_BITS as usize;
+        loop {
+            let mut elems: Vec<F>;
+            loop {
+                elems = (0..2 * t)
+                    .map(|_| {
+                        let mut value = self.next_bigint::<F>(n);
+                        if value >= F::Params::MODULUS {
+                            value.sub_noborrow(&F::Params::MODULUS);
+                        }
+                        F::from_repr(value)
+                    })
+                    .collect();
+                let distinct = (0..2 * t).all(|i| (i + 1..2 * t).all(|j| elems[i] != elems[j]));
+                if distinct {
+                    break;
+                }
+            }
+            let (xs, ys) = elems.split_at(t);
+            let matrix = xs
+                .iter()
+                .map(|x| {
+                    ys.iter()
+                        .map(|y| (*x + y).inverse())
+                        .collect::<Option<Vec<_>>>()
+                })
+                .collect::<Option<Vec<_>>>();
+            if let Some(matrix) = matrix {
+                break matrix;
+            }
+        }
+    }
+}
+
+// Linear algebra over F, with matrices represented as vectors of rows and
+// subspaces represented by a basis of row vectors.
+
+fn mat_mul<F: Field>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|j| {
+                    row.iter()
+                        .zip(b.iter())
+                        .fold(F::zero(), |acc, (x, b_row)| acc + &(*x * &b_row[j]))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn mat_vec<F: Field>(a: &[Vec<F>], v: &[F]) -> Vec<F> {
+    a.iter()
+        .map(|row| {
+            row.iter()
+                .zip(v.iter())
+                .fold(F::zero(), |acc, (x, y)| acc + &(*x * y))
+        })
+        .collect()
+}
+
+fn identity<F: Field>(t: usize) -> Vec<Vec<F>> {
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| if i == j { F::one() } else { F::zero() })
+                .collect()
+        })
+        .collect()
+}
+
+fn mat_pow<F: Field>(a: &[Vec<F>], exp: usize) -> Vec<Vec<F>> {
+    let mut res = identity(a.len());
+    for _ in 0..exp {
+        res = mat_mul(&res, a);
+    }
+    res
+}
+
+/// Reduced row echelon form of `rows`: returns the non-zero rows and the pivot columns.
+fn rref<F: Field>(rows: &[Vec<F>]) -> (Vec<Vec<F>>, Vec<usize>) {
+    let mut rows = rows.to_vec();
+    let mut pivots = Vec::new();
+    if rows.is_empty() {
+        return (rows, pivots);
+    }
+    let num_cols = rows[0].len();
+    let mut r = 0;
+    for c in 0..num_cols {
+        if r == rows.len() {
+            break;
+        }
+        let pivot = match (r..rows.len()).find(|&i| !rows[i][c].is_zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+        rows.swap(r, pivot);
+        let inv = rows[r][c].inverse().unwrap();
+        rows[r].iter_mut().for_each(|x| *x *= &inv);
+        for i in 0..rows.len() {
+            if i != r && !rows[i][c].is_zero() {
+                let f = rows[i][c];
+                let pivot_row = rows[r].clone();
+                rows[i]
+                    .iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x -= &(f * y));
+            }
+        }
+        pivots.push(c);
+        r += 1;
+    }
+    rows.truncate(r);
+    (rows, pivots)
+}
+
+fn rank<F: Field>(rows: &[Vec<F>]) -> usize {
+    rref(rows).0.len()
+}
+
+/// A basis of the right kernel of the matrix `rows`, having `num_cols` columns.
+fn right_kernel<F: Field>(rows: &[Vec<F>], num_cols: usize) -> Vec<Vec<F>> {
+    let (reduced, pivots) = rref(rows);
+    (0..num_cols)
+        .filter(|c| !pivots.contains(c))
+        .map(|free| {
+            let mut v = vec![F::zero(); num_cols];
+            v[free] = F::one();
+            for (row, &c) in reduced.iter().zip(pivots.iter()) {
+                v[c] = -row[free];
+            }
+            v
+        })
+        .collect()
+}
+
+fn subspace_eq<F: Field>(a: &[Vec<F>], b: &[Vec<F>]) -> bool {
+    let rank_a = rank(a);
+    rank_a == rank(b) && rank(&[a, b].concat()) == rank_a
+}
+
+fn intersection<F: Field>(a: &[Vec<F>], b: &[Vec<F>], t: usize) -> Vec<Vec<F>> {
+    let a = rref(a).0;
+    let b = rref(b).0;
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    // Solve sum_i x_i a_i - sum_j y_j b_j = 0
+    let system = (0..t)
+        .map(|k| {
+            a.iter()
+                .map(|v| v[k])
+                .chain(b.iter().map(|v| -v[k]))
+                .collect()
+        })
+        .collect::<Vec<Vec<F>>>();
+    let vecs = right_kernel(&system, a.len() + b.len())
+        .into_iter()
+        .map(|coeffs| {
+            (0..t)
+                .map(|k| {
+                    a.iter()
+                        .zip(coeffs.iter())
+                        .fold(F::zero(), |acc, (v, c)| acc + &(v[k] * c))
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+    rref(&vecs).0
+}
+
+// Polynomials over F, with coefficients in increasing degree order.
+
+fn poly_trim<F: Field>(mut a: Vec<F>) -> Vec<F> {
+    while matches!(a.last(), Some(c) if c.is_zero()) {
+        a.pop();
+    }
+    a
+}
+
+fn poly_sub<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let len = a.len().max(b.len());
+    poly_trim(
+        (0..len)
+            .map(|i| *a.get(i).unwrap_or(&F::zero()) - b.get(i).unwrap_or(&F::zero()))
+            .collect(),
+    )
+}
+
+/// Quotient and remainder of the division of `a` by the non-zero polynomial `b`.
+fn poly_div_rem<F: Field>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut rem = poly_trim(a.to_vec());
+    let b = poly_trim(b.to_vec());
+    let inv = b.last().unwrap().inverse().unwrap();
+    let mut quot = vec![F::zero(); rem.len().saturating_sub(b.len()) + 1];
+    while rem.len() >= b.len() {
+        let f = *rem.last().unwrap() * &inv;
+        let shift = rem.len() - b.len();
+        quot[shift] = f;
+        for (i, c) in b.iter().enumerate() {
+            rem[shift + i] -= &(f * c);
+        }
+        rem = poly_trim(rem);
+    }
+    (poly_trim(quot), rem)
+}
+
+fn poly_mul_mod<F: Field>(a: &[F], b: &[F], modulus: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![F::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            res[i + j] += &(*x * y);
+        }
+    }
+    poly_div_rem(&res, modulus).1
+}
+
+fn poly_pow_mod<F: Field, S: AsRef<[u64]>>(a: &[F], exp: S, modulus: &[F]) -> Vec<F> {
+    let a = poly_div_rem(a, modulus).1;
+    let mut res = poly_div_rem(&[F::one()], modulus).1;
+    for bit in BitIterator::new(exp) {
+        res = poly_mul_mod(&res, &res, modulus);
+        if bit {
+            res = poly_mul_mod(&res, &a, modulus);
+        }
+    }
+    res
+}
+
+/// Monic gcd of `a` and `b`.
+fn poly_gcd<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut a = poly_trim(a.to_vec());
+    let mut b = poly_trim(b.to_vec());
+    while !b.is_empty() {
+        let rem = poly_div_rem(&a, &b).1;
+        a = b;
+        b = rem;
+    }
+    if let Some(lead) = a.last() {
+        let inv = lead.inverse().unwrap();
+        a.iter_mut().for_each(|c| *c *= &inv);
+    }
+    a
+}
+
+/// Characteristic polynomial of `m`, computed with the Faddeev-LeVerrier algorithm.
+fn char_poly<F: PrimeField>(m: &[Vec<F>]) -> Vec<F> {
+    let t = m.len();
+    let mut coeffs = vec![F::zero(); t + 1];
+    coeffs[t] = F::one();
+    let mut m_k = vec![vec![F::zero(); t]; t];
+    for k in 1..=t {
+        m_k = if k > 1 {
+            mat_mul(m, &m_k)
+        } else {
+            vec![vec![F::zero(); t]; t]
+        };
+        m_k.iter_mut()
+            .enumerate()
+            .for_each(|(i, row)| row[i] += &coeffs[t - k + 1]);
+        let trace = mat_mul(m, &m_k)
+            .iter()
+            .enumerate()
+            .fold(F::zero(), |acc, (i, row)| acc + &row[i]);
+        coeffs[t - k] = -(trace * &F::from(k as u64).inverse().unwrap());
+    }
+    coeffs
+}
+
+/// The distinct roots in F of the polynomial `f`, found by Cantor-Zassenhaus splitting.
+fn poly_roots<F: PrimeField>(f: &[F]) -> Vec<F> {
+    fn split<F: PrimeField>(g: Vec<F>, roots: &mut Vec<F>) {
+        match g.len() {
+            0 | 1 => {}
+            2 => roots.push(-g[0]),
+            _ => {
+                // g is a product of distinct linear factors: for a suitable a,
+                // (x + a)^((p-1)/2) - 1 shares with g some, but not all, of them.
+                let mut a = F::zero();
+                loop {
+                    let h = poly_sub(
+                        &poly_pow_mod(&[a, F::one()], F::Params::MODULUS_MINUS_ONE_DIV_TWO, &g),
+                        &[F::one()],
+                    );
+                    let d = poly_gcd(&h, &g);
+                    if d.len() > 1 && d.len() < g.len() {
+                        let quot = poly_div_rem(&g, &d).0;
+                        split(d, roots);
+                        split(quot, roots);
+                        return;
+                    }
+                    a += &F::one();
+                }
+            }
+        }
+    }
+
+    let f = poly_trim(f.to_vec());
+    let x = vec![F::zero(), F::one()];
+    let x_p = poly_pow_mod(&x, F::Params::MODULUS, &f);
+    let mut roots = Vec::new();
+    split(poly_gcd(&poly_sub(&x_p, &x), &f), &mut roots);
+    roots
+}
+
+// Security checks on the MDS matrix, ported from generate_parameters_grain.sage
+// (with s = 1, as in Poseidon a single S-Box is applied in partial rounds).
+
+/// The subspace of inputs not activating any S-Box in the first `round_num` partial rounds.
+fn generate_vectorspace<F: Field>(
+    round_num: usize,
+    m_round: &[Vec<Vec<F>>],
+    t: usize,
+) -> Vec<Vec<F>> {
+    let unit = |i: usize| {
+        (0..t)
+            .map(|j| if i == j { F::one() } else { F::zero() })
+            .collect::<Vec<F>>()
+    };
+    match round_num {
+        0 => (0..t).map(unit).collect(),
+        1 => (1..t).map(unit).collect(),
+        _ => {
+            let rows = m_round[..round_num - 1]
+                .iter()
+                .map(|m| m[0][1..].to_vec())
+                .collect::<Vec<_>>();
+            let extended = right_kernel(&rows, t - 1)
+                .into_iter()
+                .map(|v| [vec![F::zero()], v].concat())
+                .collect::<Vec<_>>();
+            rref(&extended).0
+        }
+    }
+}
+
+/// Return true if no invariant subspace trail exists for up to t - 1 rounds.
+fn algorithm_1<F: PrimeField>(m: &[Vec<F>]) -> bool {
+    let t = m.len();
+    let m_round = (0..=t).map(|j| mat_pow(m, j + 1)).collect::<Vec<_>>();
+
+    for i in 1..t {
+        let mat_test = &m_round[i - 1];
+        let entry = mat_test[0][0];
+        let is_scalar = (0..t)
+            .all(|a| (0..t).all(|b| mat_test[a][b] == if a == b { entry } else { F::zero() }));
+        if is_scalar {
+            return false;
+        }
+
+        let s = generate_vectorspace(i, &m_round, t);
+        let mut basis_vectors = Vec::new();
+        for lambda in poly_roots(&char_poly(mat_test)) {
+            let mut shifted = mat_test.clone();
+            (0..t).for_each(|a| shifted[a][a] -= &lambda);
+            let eigenspace = right_kernel(&shifted, t);
+            basis_vectors.extend(intersection(&s, &eigenspace, t));
+        }
+        let dim = rank(&basis_vectors);
+        if dim >= 1 && dim != t {
+            return false;
+        }
+
+        for m_j in m_round[..i].iter() {
+            let s_mul = s.iter().map(|v| mat_vec(m_j, v)).collect::<Vec<_>>();
+            if subspace_eq(&s, &s_mul) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Return true if the first unit vector is not contained in a proper invariant subspace of `m`.
+fn algorithm_2<F: Field>(m: &[Vec<F>]) -> bool {
+    let t = m.len();
+    let mut v = (0..t)
+        .map(|j| if j == 0 { F::one() } else { F::zero() })
+        .collect::<Vec<_>>();
+    let mut subspace = vec![v.clone()];
+    loop {
+        let delta = subspace.len();
+        v = mat_vec(m, &v);
+        subspace.push(v.clone());
+        subspace = rref(&subspace).0;
+        if subspace.len() == t {
+            return true;
+        }
+        if subspace.len() <= delta {
+            return false;
+        }
+    }
+}
+
+/// Return true if Algorithm 2 succeeds for all the powers of `m` up to 4t.
+fn algorithm_3<F: Field>(m: &[Vec<F>]) -> bool {
+    let t = m.len();
+    let mut m_r = mat_mul(m, m);
+    for _ in 2..=4 * t {
+        if !algorithm_2(&m_r) {
+            return false;
+        }
+        m_r = mat_mul(&m_r, m);
+    }
+    true
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::*;
+    use crate::crh::poseidon::parameters::{
+        TweedleFqPoseidonParameters, TweedleFrPoseidonParameters, TweedleFrPoseidonParametersRate4,
+        TweedleFrPoseidonParametersRate8,
+    };
+    use algebra::fields::tweedle::{Fq as TweedleFq, Fr as TweedleFr};
+
+    #[test]
+    fn test_round_numbers() {
+        let quintic = PoseidonSBoxType::Exponent(5);
+        for &(t, r_f, r_p) in [(3, 4, 56), (5, 4, 56), (9, 4, 57)].iter() {
+            let generator = PoseidonParametersGenerator::<TweedleFr>::new(t, quintic, 128);
+            assert_eq!(generator.round_numbers().unwrap(), (r_f, r_p));
+        }
+
+        let generator = PoseidonParametersGenerator::<TweedleFr>::new(3, quintic, 128)
+            .with_security_margin(false);
+        assert_eq!(generator.round_numbers().unwrap(), (3, 52));
+
+        // Invalid inputs
+        assert!(
+            PoseidonParametersGenerator::<TweedleFr>::new(1, quintic, 128)
+                .round_numbers()
+                .is_err()
+        );
+        assert!(PoseidonParametersGenerator::<TweedleFr>::new(
+            3,
+            PoseidonSBoxType::Exponent(2),
+            128
+        )
+        .round_numbers()
+        .is_err());
+    }
+
+    #[cfg(feature = "mnt4_753")]
+    #[test]
+    fn test_generate_inverse() {
+        use crate::crh::poseidon::parameters::MNT4753PoseidonParameters;
+        use algebra::fields::mnt4753::Fr as MNT4753Fr;
+
+        for &(t, r_f, r_p) in [(3, 4, 63), (5, 4, 60), (9, 4, 54)].iter() {
+            let generator =
+                PoseidonParametersGenerator::<MNT4753Fr>::new(t, PoseidonSBoxType::Inverse, 128);
+            assert_eq!(generator.round_numbers().unwrap(), (r_f, r_p));
+        }
+
+        // The MNT4 matrix has been generated with short Montgomery friendly entries
+        // (see PoseidonShortParameters), so only the round constants coincide.
+        let params =
+            PoseidonParametersGenerator::<MNT4753Fr>::new(3, PoseidonSBoxType::Inverse, 128)
+                .generate()
+                .unwrap();
+        assert_eq!(
+            params.round_cst.as_slice(),
+            MNT4753PoseidonParameters::ROUND_CST
+        );
+    }
+
+    #[test]
+    fn test_generate_tweedle() {
+        let params =
+            PoseidonParametersGenerator::<TweedleFr>::new(3, PoseidonSBoxType::Exponent(5), 128)
+                .generate()
+                .unwrap();
+        assert!(params.matches::<TweedleFrPoseidonParameters>());
+
+        let params =
+            PoseidonParametersGenerator::<TweedleFr>::new(5, PoseidonSBoxType::Exponent(5), 128)
+                .generate()
+                .unwrap();
+        assert!(params.matches::<TweedleFrPoseidonParametersRate4>());
+
+        let params =
+            PoseidonParametersGenerator::<TweedleFr>::new(9, PoseidonSBoxType::Exponent(5), 128)
+                .generate()
+                .unwrap();
+        assert!(params.matches::<TweedleFrPoseidonParametersRate8>());
+
+        let params =
+            PoseidonParametersGenerator::<TweedleFq>::new(3, PoseidonSBoxType::Exponent(5), 128)
+                .generate()
+                .unwrap();
+        assert!(params.matches::<TweedleFqPoseidonParameters>());
+
+        // The emitted source contains the hardcoded constants
+        let src = params.to_rust_source("TweedleFqPoseidonParameters", "TweedleFq");
+        assert!(src.contains("impl PoseidonParameters for TweedleFqPoseidonParameters"));
+        assert!(src.contains("const R_P: i32 = 56;"));
+        for c in params.round_cst.iter().chain(params.mds.iter()) {
+            let limbs = c
+                .into_repr_raw()
+                .as_ref()
+                .iter()
+                .map(|limb| format!("0x{:x}", limb))
+                .collect::<Vec<_>>()
+                .join(", ");
+            assert!(src.contains(&limbs));
+        }
+    }
+
+    #[test]
+    fn test_security_checks() {
+        let one = TweedleFr::one();
+        let zero = TweedleFr::zero();
+
+        // The identity has every subspace invariant
+        let id = identity::<TweedleFr>(3);
+        assert!(!algorithm_1(&id));
+        assert!(!algorithm_2(&id));
+
+        // A permutation matrix of order 3 fails Algorithm 3, as its cube is the identity
+        let perm = vec![
+            vec![zero, one, zero],
+            vec![zero, zero, one],
+            vec![one, zero, zero],
+        ];
+        assert!(algorithm_2(&perm));
+        assert!(!algorithm_3(&perm));
+    }
+}
@@ -1,17 +1,20 @@
 extern crate rand;
 extern crate rayon;
 
-use algebra::{Field, PrimeField};
+use algebra::{Field, FpParameters, PrimeField};
 
 use std::{marker::PhantomData, ops::Mul};
 
 use crate::{
-    crh::{FieldBasedHash, FieldBasedHashParameters, SBox},
+    crh::{FieldBasedHash, FieldBasedHashParameters, FieldBasedSponge, SBox},
     CryptoError, Error,
 };
 
 pub mod batched_crh;
 
+pub mod generator;
+pub use self::generator::*;
+
 pub mod parameters;
 pub use self::parameters::*;
 
@@ -88,6 +91,8 @@ pub struct PoseidonHash<
     input_size: Option<usize>,
     updates_ctr: usize,
     mod_rate: bool,
+    // Index of the next element of the state to be squeezed, if squeezing
+    squeeze_idx: Option<usize>,
     _parameters: PhantomData<P>,
     _sbox: PhantomData<SB>,
 }
@@ -110,6 +115,7 @@ where
             input_size: constant_size,
             updates_ctr: 0,
             mod_rate,
+            squeeze_idx: None,
             _parameters: PhantomData,
             _sbox: PhantomData,
         };
@@ -293,14 +299,97 @@ where
     }
 }
 
+impl<F, P, SB> PoseidonHash<F, P, SB>
+where
+    F: PrimeField,
+    P: PoseidonParameters<Fr = F>,
+    SB: SBox<Field = F, Parameters = P>,
+{
+    /// Return the current state of the permutation. The elements absorbed
+    /// since the last permutation are not included.
+    pub fn get_state(&self) -> &[F] {
+        self.state.as_slice()
+    }
+}
+
+impl<F, P, SB> FieldBasedSponge for PoseidonHash<F, P, SB>
+where
+    F: PrimeField,
+    P: PoseidonParameters<Fr = F>,
+    SB: SBox<Field = F, Parameters = P>,
+{
+    fn init(personalization: Option<&[Self::Data]>) -> Self {
+        Self::_init(None, false, personalization)
+    }
+
+    fn absorb(&mut self, elems: &[Self::Data]) -> &mut Self {
+        if elems.is_empty() {
+            return self;
+        }
+
+        // Elements absorbed after squeezing are added to the rate part of the current state
+        self.squeeze_idx = None;
+        for &elem in elems.iter() {
+            self.update(elem);
+        }
+        self
+    }
+
+    // The first element squeezed after having absorbed n elements is equal to the
+    // constant length hash of the same n elements.
+    fn squeeze(&mut self, num: usize) -> Vec<Self::Data> {
+        // Switch to squeezing mode, absorbing the pending elements (zero padded) if any
+        let mut squeeze_idx = match self.squeeze_idx {
+            Some(squeeze_idx) => squeeze_idx,
+            None => {
+                if !self.pending.is_empty() {
+                    for (input, state) in self.pending.iter().zip(self.state.iter_mut()) {
+                        *state += input;
+                    }
+                    Self::poseidon_perm(&mut self.state);
+                    self.pending.clear();
+                }
+                0
+            }
+        };
+
+        let mut outputs = Vec::with_capacity(num);
+        for _ in 0..num {
+            if squeeze_idx == P::R {
+                Self::poseidon_perm(&mut self.state);
+                squeeze_idx = 0;
+            }
+            outputs.push(self.state[squeeze_idx]);
+            squeeze_idx += 1;
+        }
+        self.squeeze_idx = Some(squeeze_idx);
+
+        outputs
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        let capacity = F::Params::CAPACITY as usize;
+        let skipped_bits = F::Params::MODULUS_BITS as usize - capacity;
+        let num_elems = (num_bits + capacity - 1) / capacity;
+
+        let mut bits = self
+            .squeeze(num_elems)
+            .into_iter()
+            .flat_map(|elem| elem.write_bits().into_iter().skip(skipped_bits))
+            .collect::<Vec<_>>();
+        bits.truncate(num_bits);
+        bits
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::crh::{
         test::{constant_length_field_based_hash_test, variable_length_field_based_hash_test},
-        FieldBasedHash, SBox,
+        FieldBasedHash, FieldBasedSponge, SBox,
     };
     use crate::{FieldBasedHashParameters, PoseidonHash, PoseidonParameters};
-    use algebra::{Field, PrimeField};
+    use algebra::{Field, FpParameters, PrimeField};
 
     fn generate_inputs<F: PrimeField>(num: usize) -> Vec<F> {
         let mut inputs = Vec::with_capacity(num);
@@ -362,6 +451,79 @@ mod test {
         }
     }
 
+    fn sponge_test<F: PrimeField, H: FieldBasedSponge<Data = F> + Clone>() {
+        let rate = <H::Parameters as FieldBasedHashParameters>::R;
+        let inputs = generate_inputs::<F>(3 * rate + 1);
+
+        for i in 0..=inputs.len() {
+            let mut sponge = H::init(None);
+            sponge.absorb(&inputs[..i]);
+
+            // The first squeezed element is the constant length hash of the absorbed ones
+            let mut digest = H::init_constant_length(i, None);
+            inputs[..i].iter().for_each(|&input| {
+                digest.update(input);
+            });
+            let outputs = sponge.clone().squeeze(2 * rate + 1);
+            assert_eq!(outputs[0], digest.finalize().unwrap());
+
+            // Squeezing in more steps gives the same outputs
+            let mut outputs_in_steps = sponge.squeeze(1);
+            outputs_in_steps.append(&mut sponge.squeeze(0));
+            outputs_in_steps.append(&mut sponge.squeeze(rate));
+            outputs_in_steps.append(&mut sponge.squeeze(rate));
+            assert_eq!(outputs, outputs_in_steps);
+
+            // Absorbing nothing doesn't change the squeezed elements
+            sponge.absorb(&[]);
+            let next_output = sponge.squeeze(1)[0];
+            assert!(!outputs.contains(&next_output));
+
+            // Absorbing in more steps gives the same outputs
+            let mut sponge_in_steps = H::init(None);
+            for chunk in inputs[..i].chunks(2) {
+                sponge_in_steps.absorb(chunk);
+            }
+            assert_eq!(sponge_in_steps.squeeze(2 * rate + 1), outputs);
+
+            // Duplex: the elements absorbed after squeezing affect the outputs
+            sponge.absorb(&inputs[..1]);
+            let mut other_sponge = sponge.clone();
+            other_sponge.absorb(&inputs[1..2]);
+            assert_ne!(sponge.squeeze(1), other_sponge.squeeze(1));
+
+            // Personalization
+            let personalization = &inputs[..1];
+            let mut personalized_sponge = H::init(Some(personalization));
+            personalized_sponge.absorb(&inputs[..i]);
+            let personalized_outputs = personalized_sponge.squeeze(2 * rate + 1);
+            assert_ne!(personalized_outputs, outputs);
+
+            let mut digest = H::init_constant_length(i, Some(personalization));
+            inputs[..i].iter().for_each(|&input| {
+                digest.update(input);
+            });
+            assert_eq!(personalized_outputs[0], digest.finalize().unwrap());
+
+            // Squeeze bits
+            let capacity = F::Params::CAPACITY as usize;
+            let mut sponge = H::init(None);
+            sponge.absorb(&inputs[..i]);
+            let bits = sponge.clone().squeeze_bits(2 * capacity + 1);
+            assert_eq!(bits.len(), 2 * capacity + 1);
+            let expected_bits = sponge
+                .squeeze(3)
+                .into_iter()
+                .flat_map(|elem| {
+                    let elem_bits = elem.write_bits();
+                    elem_bits[elem_bits.len() - capacity..].to_vec()
+                })
+                .take(2 * capacity + 1)
+                .collect::<Vec<_>>();
+            assert_eq!(bits, expected_bits);
+        }
+    }
+
     #[cfg(feature = "mnt4_753")]
     #[test]
     fn test_poseidon_hash_mnt4() {
@@ -779,7 +941,8 @@ mod test {
             MNT4753PoseidonParameters,
             MNT4InversePoseidonSBox,
         >(start_states, end_states);
-        test_routine::<MNT4753Fr, MNT4PoseidonHash>(3)
+        test_routine::<MNT4753Fr, MNT4PoseidonHash>(3);
+        sponge_test::<MNT4753Fr, MNT4PoseidonHash>()
     }
 
     #[cfg(feature = "mnt6_753")]
@@ -1199,7 +1362,8 @@ mod test {
             MNT6753PoseidonParameters,
             MNT6InversePoseidonSBox,
         >(start_states, end_states);
-        test_routine::<MNT6753Fr, MNT6PoseidonHash>(3)
+        test_routine::<MNT6753Fr, MNT6PoseidonHash>(3);
+        sponge_test::<MNT6753Fr, MNT6PoseidonHash>()
     }
 
     #[cfg(feature = "bn_382")]
@@ -1457,7 +1621,8 @@ mod test {
             BN382FrPoseidonParameters,
             BN382FrQuinticSbox,
         >(start_states, end_states);
-        test_routine::<BN382Fr, BN382FrPoseidonHash>(3)
+        test_routine::<BN382Fr, BN382FrPoseidonHash>(3);
+        sponge_test::<BN382Fr, BN382FrPoseidonHash>()
     }
 
     #[cfg(feature = "bn_382")]
@@ -1715,7 +1880,8 @@ mod test {
             BN382FqPoseidonParameters,
             BN382FqQuinticSbox,
         >(start_states, end_states);
-        test_routine::<BN382Fq, BN382FqPoseidonHash>(3)
+        test_routine::<BN382Fq, BN382FqPoseidonHash>(3);
+        sponge_test::<BN382Fq, BN382FqPoseidonHash>()
     }
 
     #[cfg(feature = "tweedle")]
@@ -1919,7 +2085,9 @@ mod test {
             TweedleFrPoseidonParameters,
             TweedleFrQuinticSbox,
         >(start_states, end_states);
-        test_routine::<TweedleFr, TweedleFrPoseidonHash>(3)
+        test_routine::<TweedleFr, TweedleFrPoseidonHash>(3);
+        sponge_test::<TweedleFr, TweedleFrPoseidonHash>();
+        sponge_test::<TweedleFr, crate::crh::TweedleFrPoseidonHashRate4>()
     }
 
     #[cfg(feature = "tweedle")]
@@ -2123,6 +2291,7 @@ mod test {
             TweedleFqPoseidonParameters,
             TweedleFqQuinticSbox,
         >(start_states, end_states);
-        test_routine::<TweedleFq, TweedleFqPoseidonHash>(3)
+        test_routine::<TweedleFq, TweedleFqPoseidonHash>(3);
+        sponge_test::<TweedleFq, TweedleFqPoseidonHash>()
     }
 }
@@ -0,0 +1,6781 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonInverseSBox,
+    PoseidonParameters, PoseidonShortParameters,
+};
+
+use algebra::{biginteger::BigInteger768 as BigInteger, field_new, fields::mnt4753::Fr, MulShort};
+
+pub type MNT4InversePoseidonSBoxRate4 = PoseidonInverseSBox<Fr, MNT4753PoseidonParametersRate4>;
+pub type MNT4PoseidonHashRate4 =
+    PoseidonHash<Fr, MNT4753PoseidonParametersRate4, MNT4InversePoseidonSBoxRate4>;
+pub type MNT4BatchPoseidonHashRate4 =
+    PoseidonBatchHash<Fr, MNT4753PoseidonParametersRate4, MNT4InversePoseidonSBoxRate4>;
+
+#[derive(Debug, Clone)]
+/// x^{-1}-POSEIDON-128 parameters of rate 4 for scalar field Fr of MNT4-753, with an MDS matrix
+/// supporting short Montgomery multiplication.
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_short_grain.
+pub struct MNT4753PoseidonParametersRate4;
+
+impl FieldBasedHashParameters for MNT4753PoseidonParametersRate4 {
+    type Fr = Fr;
+    const R: usize = 4; // The rate of the hash function
+}
+
+impl PoseidonShortParameters for MNT4753PoseidonParametersRate4 {
+    const MDS_CST_SHORT: &'static [Fr] = &[
+        // These constants are in Partial Montgomery representation with R = 2^64
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1389c599fb8c64d3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x60f700232e3266d6,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0d39a3dd9dd6155,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9307270c1c93985b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x379c5590338b94fd,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf8c6cc1711fce1a4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x31a7dd96271651c9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa31dd09ce4c6a23,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xacadd059424055ca,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeaf32b1bbb0ee6f6,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x86cb4ff82372a4ba,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc44bf9a48fb3634d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3f3169bc5fbc72b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2311de38452d9c5,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb943e30c20df70df,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a69291c88d24a71,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ec2ca7d4248f55,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xacef061c896eed90,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdde72258e24dff12,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x712a0399b29a2f2a,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3de9b09130705bb1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcbf26a7cb5c48ed3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8275ca152cc3e286,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1529d9e06cd7b8f4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdf0902600c6173b2,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+    ];
+}
+
+impl PoseidonParameters for MNT4753PoseidonParametersRate4 {
+    const T: usize = 5; // Size of the internal state (in field elements)
+                        // Number of rounds including security margin. Without such, R_f = 3, R_p = 55.
+    const R_F: i32 = 4; // Half number of full rounds (R_f in the Poseidon paper)
+    const R_P: i32 = 60; // Number of partial rounds
+
+    // The zero element of the field
+    const ZERO: Fr = field_new!(
+        Fr,
+        BigInteger([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])
+    );
+
+    // State vector after permutation of zero state vector
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                8266848926375311483,
+                3117206920012769424,
+                15910030213968795773,
+                4776593133840954581,
+                997796525342704240,
+                3208235022954939025,
+                8123884847044683969,
+                3454760156535855209,
+                1237492542457088876,
+                7747310528809028268,
+                14830997923804265128,
+                230227593561884
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                1782250477699619253,
+                13992765190928719562,
+                1503837492893060448,
+                15738103822797027907,
+                5838203190774739612,
+                10247767415035507714,
+                399891735832531431,
+                14890626348783913827,
+                14741029133514801338,
+                6849454786039779803,
+                16429403333371994486,
+                259238104620194
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                6643345821294714170,
+                1150671044874661746,
+                6633970929523332379,
+                5594091943959053769,
+                14952675622732267554,
+                16360520391637408074,
+                1980290500729289790,
+                3965771705516223387,
+                3759750532796207518,
+                17100790248818039506,
+                10139378248116759653,
+                22438322060846
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                10790632704085006969,
+                1190521211274043466,
+                4725000460901776584,
+                3174400850975147855,
+                4202614978965966516,
+                6618478612604273462,
+                11081166310128484772,
+                1209678055462118236,
+                14479130083112803271,
+                13641388511595395920,
+                13909344670186262379,
+                7288588305465
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                9221513699313044935,
+                18267384676885344974,
+                5175500075786332818,
+                5322750767252763615,
+                4626046314538027741,
+                10442623657460292145,
+                4471370696985889232,
+                1622466842018023361,
+                16087285989117074874,
+                17436397486826786127,
+                2776991389249018271,
+                251531688643394
+            ])
+        ),
+    ];
+
+    // Array of round constants (in Montgomery representation)
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x351bf33626634e10,
+                0x4a839b45cdc63b99,
+                0xbc357e92bc896b6,
+                0xcfe4f1f3918e6611,
+                0xeda3106c54053333,
+                0xd0caf0dd859fade4,
+                0x891c5c777c7cc79f,
+                0xdf8b4de5be3c3b8b,
+                0xb4e24baf499108e6,
+                0x8d916566a807212c,
+                0x2a6b82ee72e85163,
+                0x134e79e86b793
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe12f5e8c736803c6,
+                0x8ebc1226aa045862,
+                0x583ed21a77defca,
+                0x3f776b5a0c2d330d,
+                0x137c965f3f7a6732,
+                0xa59787cc8a67dba8,
+                0x1d6f680c37797771,
+                0xd0c72fc662701f7e,
+                0xe49aa2c7699aec6d,
+                0x3ed66b83809ad614,
+                0xfaaf04d24325f105,
+                0xea8c0222f9fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x75ac2e7bd0e30dc7,
+                0x6a596336f5aa8213,
+                0x6aa7a8d3af383156,
+                0x1cc46b325e0c9f48,
+                0xbb0ad26aeb98e7ff,
+                0x8971a6bc0456c39a,
+                0xa1a66b2daf4211a7,
+                0x33f1681ffc90bff6,
+                0x71dd89dfd587b914,
+                0x608b3df8643b545,
+                0x457922cfcfb295bb,
+                0xc47f72e8ffbe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ec5640acdfed1f9,
+                0x48b53497609c56d1,
+                0xb574a40dadcabbf7,
+                0xcb9ad0b27c044c5,
+                0xfb1449e31fa9acab,
+                0x1cf99ca623a34de3,
+                0x83e030316ba7c683,
+                0xe9e1d29a4d5e27ca,
+                0xcb455f96e93955c2,
+                0x1bb37d15e3cbe7fb,
+                0x87a15472732b9aba,
+                0x140c10b6e505b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbef8d108ebb42b5,
+                0xc1b112267ba64296,
+                0xa7aa37b93ad264b6,
+                0x376bee05de31eb29,
+                0xb6385b19365e9560,
+                0x54d2c944c6cf0736,
+                0x836854dc7624160a,
+                0x167a41af132e7c24,
+                0x76244b98aebc2bea,
+                0x4d173448033b45c1,
+                0xde5e4901d104bb8f,
+                0xd5636a80c4f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe80ef019550f043e,
+                0xeb61b0e3ed8e52ec,
+                0xebb4cf81f8644ad5,
+                0x111411a8b43355bd,
+                0x4ba342e6e26fbe78,
+                0x5b20196a912657fd,
+                0xe5cc79b821c290,
+                0x72bf4a01a0236238,
+                0xf1551317da0e47e4,
+                0x2e12bb7bb019aea,
+                0x816d20e6b0f26652,
+                0x11a7b88647f37
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc4e336c31427e696,
+                0x7a29a4002bb2bbbd,
+                0xb70e96cd840cdc1e,
+                0x2f91aa3e608ae76c,
+                0x1cabaeed361c7fcc,
+                0x80fcb8da9c960f64,
+                0x6c1682d741248c2c,
+                0x9a0526c8cefa456c,
+                0x6d97331d19777306,
+                0xb4489ab0b12ee31a,
+                0x48cf5b1581574a9b,
+                0x55a246bee7fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd43d01025a5db54a,
+                0xf81620a14cfe611e,
+                0xc1fdc0712d42a6cf,
+                0x55a2b031f73f0696,
+                0x4228c5a3f107a100,
+                0xd7454257a5add58a,
+                0x6ed98da96dffcbb7,
+                0x60aaf298279bbb06,
+                0x5feafeb2d6804973,
+                0x50d7a6b0c74ff1e7,
+                0x66b8b2c1b4853b64,
+                0x1323920fa68d6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7eb67c7896892f1c,
+                0xf3602d9a9147f2b4,
+                0xca80709f8db1c481,
+                0x51fa185c06aed355,
+                0x3a77e05569745f18,
+                0x4beadb67fe710b86,
+                0x6f4c6b70bba00530,
+                0x7eccfbf2ec59c9b1,
+                0x58163df34dd8596f,
+                0xbf84d27e3749cfb8,
+                0x16e61620a398a65,
+                0x2b70479731db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5939c383f2684d77,
+                0x3089d10ef7ab6768,
+                0x49a16ce0e5a5f3,
+                0x12370c3c0f6881c8,
+                0x4d9b37eb4fa31db1,
+                0xd9d7c26253307080,
+                0x23fc38eda1c35e8,
+                0x8dd9a63ec260d53e,
+                0xf926137fddde3115,
+                0x59179c054795f9f0,
+                0x25076ec94d28fe4b,
+                0xe0c575c59929
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xab2ddaf20c46dd3f,
+                0xd1f3c9dc8ea747fa,
+                0xd7651435a6fef8d7,
+                0xbe0837b8d172e37c,
+                0x14a7436f5d1d1f2,
+                0x3736496c655a3110,
+                0xb9cac9b0d5d93cc3,
+                0xe19d4b3f1100d438,
+                0x4b362a2cf505d0fe,
+                0x746728525c91b814,
+                0xc240a69f52bda9b,
+                0x11893a36325a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98a85f2cbce2e976,
+                0x1312a17e92580a64,
+                0xc4f82256aa018699,
+                0x8696c213a4117bd7,
+                0xd05cbaad7f54fd3c,
+                0xec875238460b75f8,
+                0xf34a84a5a434bbf1,
+                0x1a74b5145a3903e,
+                0x9eb6f4a125e6159b,
+                0xf8a6f599c3832f6c,
+                0xb063b3e460d2c680,
+                0x11815bb214248
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9017a7b9c6f1150d,
+                0xfb4536621bb9e1d5,
+                0x8c0eeb2531ac1d34,
+                0xc085e64485af74af,
+                0xf2cefeade03b4698,
+                0x882e3cba7e630810,
+                0x305835b3bd96a4b2,
+                0xb508b99e6d367205,
+                0x4296fcf4c89745d8,
+                0x6540e6abecae83cd,
+                0x5e829d3f1ab0d7ff,
+                0xe64dc04936b0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x85852226b296e13d,
+                0xd99f799a8f84512d,
+                0x37ec227e718274a1,
+                0xceef4b082bcc44b2,
+                0xdf6b5b26b6506afe,
+                0xe9b919bbfd0ade7d,
+                0x58faeaca0a0159ff,
+                0x1cfde745a1f28d6b,
+                0x9be46608122856b3,
+                0xa9375f76d44c5849,
+                0xde3e3da1800fd02a,
+                0x15728c31482d1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x487a787c1794c793,
+                0xb145b5501b4a8fee,
+                0xd600b73ffe0c317e,
+                0x626bdb82162a676d,
+                0x54e283b1f6fa83f4,
+                0x671c7093536e8670,
+                0x1dbaae22481a9e61,
+                0x57ebcb275be0afb1,
+                0xda2ddd039a15a1c9,
+                0xd287f1e83cd615a1,
+                0x2a4ab67d2432c286,
+                0x8b1070b3073f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4281a8271ef6330a,
+                0x991bec1a934bc42c,
+                0x8a2cd263f6fdeb51,
+                0xfea7d9961f2f506,
+                0xa00f969bdae3f6d9,
+                0x1f7a70cc2312f65d,
+                0x2140b07714428854,
+                0x69e5e444cc0d7191,
+                0x273fcd8093be42e,
+                0x9123a129551db8ea,
+                0xc33ab96af8d5db2b,
+                0x34aa5d69b0da
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbcc105cbcb4c8a28,
+                0x5c06feb41cb9307,
+                0x6be9dc45291e0de0,
+                0x2dec5d6602c81da2,
+                0x9cdc522ac301f7c,
+                0xf34b447142d4403c,
+                0xc7f416135a9c6aa9,
+                0x814de37f59809877,
+                0x1cf3e47cc69e1b50,
+                0x4fcc1571dfad0483,
+                0x8c723327d0771bc,
+                0x10db761f65e05
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x92a1286fbf93dde9,
+                0xdcf7fb27e2a6ef39,
+                0xe84e9e72b2692af,
+                0xf576c92c9b4f7282,
+                0x79cefaa2c4736363,
+                0x6f7bca8be43aa82d,
+                0x596aa8bc4a745696,
+                0xe25b1694895f66c2,
+                0x22bea8feb419f132,
+                0xa3d39d03f18b8435,
+                0xcd84a67a213515eb,
+                0xa9d88457ac02
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb71e14190f38a764,
+                0x5f44dd42974b4d77,
+                0xd4a35bbe7fb33836,
+                0x85ca09b45e5ab2f0,
+                0xe8941c92ce88e0,
+                0x21d054f7f3e15eed,
+                0x41d00abb372cab2d,
+                0x4f96f638bdccab12,
+                0xafef551d2fa6fc68,
+                0xacd9f2b21b289df0,
+                0x5fe5c59e7afad2fb,
+                0xf17cb222c2aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf48a338cba165846,
+                0xb0746bb2c5691e47,
+                0x3a9f1b9245234d68,
+                0x79a2aa85bdad937e,
+                0xe4b28d284e035f53,
+                0xe887241b359f6183,
+                0x30db5f6a610b9b8d,
+                0xec20db7c05fd424c,
+                0x6e187282d0e01f4d,
+                0x71bb80a49f0ea7ae,
+                0xbc1822775a8ebaa,
+                0x97d99b828b9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2eea87361ecc2718,
+                0x74f2bbeb1d2a6980,
+                0xe6d456b2e73ce371,
+                0xf7e1b156a8f2c6e0,
+                0x23084a2203a33f7,
+                0x7c7791f0ee10ce59,
+                0xac39f1b59d127542,
+                0x3c97e1aab827b03e,
+                0xd5193d6745f59d73,
+                0x9b2ca861fc7bd03,
+                0x9e1796794d3e1a8e,
+                0x1c25ea3536913
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x91d774b7ba7f5cf5,
+                0xdc50e822674c14f3,
+                0xdc13a1328682531,
+                0x6cd5251a4e3c6de8,
+                0x4aff6f14e8108e38,
+                0xede21da236ef6526,
+                0x63adee79c4179a51,
+                0x4e07940ca4f1e4a8,
+                0x4b708b9e2de0b82,
+                0x4bc78ece2244e539,
+                0xa432d9dffda7a21b,
+                0xcec76e85133c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x490c77e6eddaa473,
+                0x5e946068a75acf25,
+                0x753f6d1d9fe35e37,
+                0xc38b7dedfc59fcad,
+                0x1699c04ba550c2d3,
+                0x7a9d0adc2b6e4089,
+                0xc0d63b9af4125298,
+                0xd45976e2e05572e4,
+                0x4b9c207894affe07,
+                0x236020279cb399cb,
+                0xc4420331091376dd,
+                0x1706587f697ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa4035098b5292e58,
+                0xe629aae3d79c2020,
+                0xf0c41bd8fa0af63,
+                0x655ae36b50e7b51b,
+                0x4110fa565e141258,
+                0x26a9f8061191be58,
+                0xc1242bf3667ebc67,
+                0xd38d8d943472a236,
+                0xb1cc801ab4b93876,
+                0x93a28d5c0b54a4ab,
+                0xa9e1f466f0bdf2ae,
+                0x5e85907f744b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2c5e88aed24bc389,
+                0x22b62f46be4b1ab7,
+                0xc8100423f69912e9,
+                0x5b855d1ce82dd5fe,
+                0xb562ba6ae26f5553,
+                0xbbed2df8cd88526a,
+                0x983ee440183453d1,
+                0x13a1927d8ad64051,
+                0xf9e9abf1be9e15d3,
+                0x765028165afb6f1e,
+                0xd4cd796ed3d74217,
+                0x1192667502a18
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4fa71967dc8a5174,
+                0x64ebd7b6a9730fc2,
+                0xdec3fb572d5327ce,
+                0xdf8fbaad749c9375,
+                0x90fb704363fbdaa0,
+                0xb22cb9f3c812cb8d,
+                0x1f119d2fad63bd2d,
+                0x873a7ba2d529dd4c,
+                0x5498d3cf1067a5aa,
+                0xa385a1091d9edf71,
+                0xe808f299ea677dc8,
+                0x1456061e8e353
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a743b05dab69ff4,
+                0x8c0c79bf76ba2b65,
+                0x89d87057cbdefc38,
+                0x652a36c3d419b1b3,
+                0xb89fc881ced7c239,
+                0x51a29189b826558c,
+                0x86bfd4520baefffa,
+                0xdd89eaa5deaa3a9c,
+                0x5e4098979c90127b,
+                0x2068d5e93c870ec6,
+                0xb208534982ab828a,
+                0xc4431128bf14
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6857769e8fd6135,
+                0x42fc595b5b078ed,
+                0xb1f2d3756f462045,
+                0xcb96fc8def915e5f,
+                0xefb8a5e308d23f71,
+                0xff07289bf890e191,
+                0x44cb7264a6ac247a,
+                0x1b04a35937e62326,
+                0xa0677048a11a7c8a,
+                0x534621ecbab7b60,
+                0xeceda28e42045dcc,
+                0x953647e1f316
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xccb9d2e7c1b4a330,
+                0x2c0718ed26e7d139,
+                0xae129b09ba408b06,
+                0xc507b4b3f736e340,
+                0x90792eb4f98a2172,
+                0x3891e86f0562df4b,
+                0x70dd44f381c8a113,
+                0x68c3fbfdb29edabb,
+                0x22509edfa7164c5f,
+                0x33622d987229b898,
+                0x66579385265c764,
+                0x1edcab0907e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7831b8ad1dbc3832,
+                0xf993d0ae28fd82d2,
+                0x4974117583d87025,
+                0xa15dba3e9b77fb67,
+                0x1e5e503eec8574bc,
+                0x4e4417ecf0118143,
+                0xa0a4e53e253811,
+                0xc297624d19256dc6,
+                0xef37337a3ca42a3a,
+                0x85f3dccee120ed43,
+                0x3b60dd63186a4c53,
+                0x10e3442d6bad9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2fbfcfc3162a4c9,
+                0x4062062fc106f220,
+                0xf09253e7e38e72c9,
+                0xac96d0c3bf1a9903,
+                0x928f413e146aa18c,
+                0xb902e0969990dad1,
+                0xa974b0b8b4113ead,
+                0xa56bc72077013172,
+                0xe21f35b1d238be7c,
+                0x699a6ff81c4c9c84,
+                0x35414e8efe154d11,
+                0x13d17e35c558c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf2952d929aef4d32,
+                0x83caf6af47ae99fd,
+                0xf5d6d0184f3f9110,
+                0xe2032034bc861f00,
+                0xd54a0d1497f33453,
+                0xd355c1153be262ef,
+                0xe83cda1e0fbc3b6e,
+                0x33c1879551c27f21,
+                0x7024ceb5707fc6d,
+                0x1109cdb4791a4d5,
+                0x79c40b344421e6d6,
+                0x171525ba092a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcadb53333c500175,
+                0x10429bf6bd95d75a,
+                0x38b821c2ea20b25,
+                0x9d48ec5a5fb36aea,
+                0xc454c468039dee4,
+                0x71705094788c5ec0,
+                0x6149157fac790c00,
+                0xa5b1bc9ca493739,
+                0x6600099ecc646304,
+                0xa1321dd953b3b1c6,
+                0x207762c19c96f268,
+                0xb5e89ecd0e7b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x987e7219d9124d48,
+                0x744ad9ac4c4a3499,
+                0xcb8ef2973c4797f0,
+                0xae38d1a3609910a,
+                0x69dacae9b763ff45,
+                0x56d4133e64658311,
+                0x66f4a7c29eb3ec10,
+                0xb7b9f9b42f044aa6,
+                0x5f181be5dc69f7f3,
+                0xd8ceb20ed4cb406d,
+                0x5ad9d47f4c7c55f6,
+                0xcb4e6380d777
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf172c01ae8473458,
+                0x54ffb738431a2775,
+                0x6c45f3595e0edeca,
+                0x7c651e3aa7fa219b,
+                0x2ed9165eeec3d00b,
+                0x8fc0d04d099461f3,
+                0x2a011b11dc46977,
+                0xfcd4bcb075b802c1,
+                0x7309f7cc8c522c3,
+                0xf8984814b3e5a39b,
+                0x2c55908388151c77,
+                0x181c3fb5c8265
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e2b4610ef572805,
+                0x3df4afac2eef872e,
+                0x5a324541651ce832,
+                0x13ed23ad5dc08339,
+                0xf6bf327f1e7860cd,
+                0x5d68cd8dab9789e1,
+                0x5060b85e2e6792dc,
+                0xdfe6ed4910f6489d,
+                0x8e0c76ae8df39cbd,
+                0xd2a0be8561ce21a5,
+                0x6245643966f346eb,
+                0xffafc823405d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc60ff3829d23d43e,
+                0xbb52d0ad9bde8702,
+                0xe8e7bd1d390e9a03,
+                0xaf23672b2a251953,
+                0xb576af129af456df,
+                0xcdc3da6df54427a7,
+                0x5bf96dd9e4274b54,
+                0xbd76636d542e14da,
+                0xd4880edbf900150d,
+                0x781428260cfda917,
+                0xfb45edbb95c7c4b6,
+                0x107821c7bf8fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x136e534ca8455f7c,
+                0xe5b04f32ba44506,
+                0x6a70bcfe39faf32c,
+                0xe61d39c1cb542c94,
+                0x4ed5376c7e50890e,
+                0xc05b2a842a2e167,
+                0x9953e2fd9b57b9ce,
+                0x637d69e463ec588,
+                0x445c24ed7ca0b1b1,
+                0x9184a5126eb162f3,
+                0x1111eec384357dd2,
+                0x15fee6d31b2ea
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3571e28c2dbadc34,
+                0x1319ddb087849567,
+                0x3b53663414a3b26d,
+                0x66a9e1ea65b0820b,
+                0x9f914c4d790aed34,
+                0xbdf1a63af00efd29,
+                0xa353019629b000ca,
+                0x982cf2b3c3dce834,
+                0x43b2df266bffe588,
+                0xc2beec4d6d060c07,
+                0x9275439f7a0a59e8,
+                0x1720f9a9e3072
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xff53ec48af3b2c1b,
+                0x6659807b79e1121a,
+                0xee52bffc6dc743f3,
+                0x4027e8efbadc053d,
+                0x51eda5ccf4796758,
+                0x8056e7f19fb670ce,
+                0x382b65699169c3d3,
+                0x2dcbd032f7c5b266,
+                0x3fb138aee5d1d825,
+                0xcfef7c26e36b1824,
+                0xc02f2b0f98e2d476,
+                0x17c7dec756122
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x91b40108b4219f,
+                0x917ded293a58d68f,
+                0x1109188c6f24986a,
+                0x19ca5845150530bc,
+                0xbd71739ba4630e94,
+                0xde3c367cf1cccbb,
+                0x2b68fe2a715e77cc,
+                0xa28867ed50d8b67c,
+                0xca4e592fdd84de57,
+                0x316d2ffa66bb9eff,
+                0x7633328d7e9ff4af,
+                0x15dcee583c44e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeb4e99e815af9ace,
+                0x80f4763e3a0ee213,
+                0x80c96d59605ea4ab,
+                0x98431f959db4c473,
+                0x7c757c42ff58ed2b,
+                0x4941af6dd0140275,
+                0xeee6773790384fe2,
+                0xc72f4e5941e9ccf7,
+                0xba240de3be99b4e5,
+                0x48e740ac6aa7cea4,
+                0x7d9dcb622d1e9b53,
+                0x19f2375e93dd5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb328bbdf3a869268,
+                0xc2898a65cd40bc82,
+                0xc40967547f92c3a4,
+                0xe3af34265c223cad,
+                0x2e759a6b98d90c94,
+                0xaa4160289028da34,
+                0xca2ebaad8ee71a0e,
+                0x6a3cde79daeb0377,
+                0xc14d050bfb442fef,
+                0x6c47ea41e6bd2e13,
+                0x60aa98b42bef11de,
+                0x17859a1ad3dc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x697341d08e88a2e5,
+                0x81978f86a7619298,
+                0x89ad4be2ca3cb342,
+                0xda2b7fa83a7cb3d0,
+                0x2eeb1425c12b1db6,
+                0xe1e0c82ba1e40bfa,
+                0x2e936e658f8c4d54,
+                0x9d84e33878194fc9,
+                0x52bdda4eb777611a,
+                0x14ba235421b2bc20,
+                0xca075655abe80ee7,
+                0x1afc4fc10fb27
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4a5c50d3b0fe0de5,
+                0x5c17d5a92a913e32,
+                0x90ecd13235dd66de,
+                0x6999106d9f9069d4,
+                0xecc072c7f076b42e,
+                0xfded72efdb2a8352,
+                0x7bd814ae8841909d,
+                0xe7dd1e4732722e5b,
+                0xabd51f4fb8db45df,
+                0xbdbefeec853bd04f,
+                0xa960bb8444d82b56,
+                0x133b7459b9c3d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b18cc8890fb44ad,
+                0xee149c57926bcce9,
+                0xd781fb6c6e5f1794,
+                0x7144f40b10525d27,
+                0x4c44478ae09f9f3b,
+                0xda88adb213ef7595,
+                0x41125e671463af69,
+                0x310fb28103ecc691,
+                0x3866060bf3fd5f5a,
+                0xa85685eccf4ce54d,
+                0x794f08c560a969a2,
+                0xb23c0f41744f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbac9be31f0fddcf0,
+                0x5dda67417df51a58,
+                0x609dc8d15d292286,
+                0x5e2e24202af706cb,
+                0x22d32556ef64d909,
+                0x6a72b5d5d47c8d44,
+                0xdd335722091eddd0,
+                0x421d3eefe8a77911,
+                0xd39855d0bd171a9d,
+                0x9772ff69ff3cf6b1,
+                0x4701a27671be4de,
+                0x360be62f85d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b382efa63f6265,
+                0xf04bd28ebcedc436,
+                0xbf81bd292970eda0,
+                0x33d2d97d63a573aa,
+                0x94bda3b9a6a68c4d,
+                0x68efdf05eedfb0d6,
+                0x7e9c03a727000e4d,
+                0x821d7c3d28439e7c,
+                0xa1946565d4637716,
+                0x69cb278c0972bc12,
+                0x6ae94128b742a034,
+                0x373e85f9f1a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0348f68b5c41002,
+                0x905d817a3f2a4a80,
+                0x4cb8ae7cf1e46112,
+                0xe9405e030b24feff,
+                0x6fac6d2cec9e1b70,
+                0x4400cdc967798c13,
+                0x823e5490c976bb35,
+                0x47b04d0da60ee217,
+                0xfd0775628bdc27f1,
+                0x7777f28a51a092fd,
+                0x49aacbd2262e7973,
+                0x1c475356a6bfb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b06972f2b2db5df,
+                0x19595324a173b4f5,
+                0x10e8d3cf760f3c4,
+                0x4c03cfa70149baa8,
+                0x1a4d0666bc3edaf6,
+                0xa9b40dd012a3e125,
+                0x9510221e1c46ee73,
+                0x5806e3636fe7a644,
+                0xa032433be8d6af52,
+                0xab6c0b50a58a5b78,
+                0x13c15404dfbde3,
+                0xa0d3a3d61a2d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x21a70e5e54ca427f,
+                0xe8f8c96ec3afaf4d,
+                0x6192b55824b41d43,
+                0x161aa701ec2c5b49,
+                0x1a59252babde8d35,
+                0x9af34603295c958b,
+                0xe22a25f36e35a13e,
+                0x80ca11f070d38a79,
+                0x2b2b9987ecf43e06,
+                0xe5bb1b598428321f,
+                0xf467f2b2b997c400,
+                0x8f0c206d875d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb74545488d921cef,
+                0xe41a56dc2a6f5f66,
+                0xab1170788d5316ef,
+                0xbda9c641d3ce322,
+                0x577cb81f2e97afa9,
+                0xd811122fd403a29e,
+                0x2e92e9f7c76f369d,
+                0x82ac5b6b01b2ece6,
+                0x3aaa63d9deb8e5e3,
+                0x2e6b64bf813a494e,
+                0xf897c13347009d4b,
+                0x9dd47dbb8553
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc2db56b2f3b1fa23,
+                0x407adca2c3a77524,
+                0x4268866bcf4b38d,
+                0xa4813800b7b82d41,
+                0x101dd33fe984c372,
+                0xa2a9ea627c10a8a9,
+                0xb7cb7f734507af91,
+                0x3560ebd2b3dfcb12,
+                0xc9e56fadad6bc426,
+                0x37e26fec39aca92e,
+                0x73ae429710ec3ec4,
+                0x1570e876a963c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba83b7aee430ec0e,
+                0x519550bf260ef773,
+                0xfe6206ffc35ae6c4,
+                0x1a51a38827b3cffb,
+                0x5fa4264da80b08cf,
+                0xc3125fb98249cc44,
+                0xec10d181bdd7769a,
+                0xd4e5394c762869a1,
+                0xdc142c16c267fcf,
+                0x57ad11bcce050364,
+                0xf354f74d1da878b,
+                0x1c2ac610666c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6b0f68a9f377556,
+                0x4094c779c2ca89ec,
+                0x201e66a11e3c9ad,
+                0x3f1c88fe5fb436e,
+                0x1ad6c20a81863709,
+                0xf3c1101871bfabee,
+                0xf876652692fc5f7a,
+                0xb4b14812a27966,
+                0x998b5f49cb6bf96f,
+                0x4af7b5f147ed9ff8,
+                0x53e95df92d5030c4,
+                0x14ad18f6d2612
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x587f7d24aa8adccf,
+                0x973904e4975ee1c3,
+                0x117dc9ed9b22225f,
+                0x2e7c0903da061c4b,
+                0xc798642bf6f214d0,
+                0xc8fb857bce399560,
+                0xc61aed77d27d4352,
+                0x9a61e097cf4af4e5,
+                0x7dc15ee53f342351,
+                0xf7b08399ac9d1bfe,
+                0xef89a7a0fd2bf526,
+                0x69a9e3f1c838
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x36f52f834246d4e5,
+                0xbb90d1bb1b585301,
+                0xaf592cbb8d49af91,
+                0x2790b682a1329ed0,
+                0x32642eb6d5b220b,
+                0x412d79f6623fded5,
+                0x89c85484ca99df68,
+                0x526cecd4bdb92737,
+                0x3ed0b02ea8bdd9b,
+                0x9cada35c78f60f47,
+                0x8c571bfb333f893d,
+                0xd03865b5125b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9fc9f145cae93b1,
+                0xdcae5b8500c12dbe,
+                0xcd5e961d618192ca,
+                0x4e3317b2017fd02d,
+                0x3a5128bfa2d40aa5,
+                0x10c417dc0b174a2c,
+                0x20132a3ae96e8e19,
+                0xc019e77cd00746fd,
+                0xd7748d56b9c1a762,
+                0xf78e9b784c5abe0c,
+                0xd1af58d67dde6130,
+                0x1a48c954b3fdb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd791198bcb6acee,
+                0x203cae9289339777,
+                0x1da1d9720e05a1a7,
+                0x91846fd427ee622b,
+                0x570a6c8c2d01ed5c,
+                0x5b820d738696b6d6,
+                0x8417429bbf9bf5a6,
+                0x21596a2827bf4301,
+                0x73c6d83110d2c477,
+                0xed0d3eaebdff2f6a,
+                0x75a14a4565fd7bfe,
+                0x1512472cc11e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1228ca661fd85b6,
+                0x6defd9a003c4b1b3,
+                0xb44b28f781ca3273,
+                0x875b1bca68d2472a,
+                0xb003811a0be5534f,
+                0xcd50cb33176b3d69,
+                0x6eb2cfbaa3816b61,
+                0x28ba94cb6132d68,
+                0x6d2da0f05c8a3db0,
+                0xe5144d7d95459ee7,
+                0x1c4a61ef1b9fd71c,
+                0x16379981de178
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8288eefa4c6b4b42,
+                0xafb340d504328c70,
+                0xd1b283884f5d8b5e,
+                0x87e9d7176415f8a,
+                0x5d7a9f32bab424e1,
+                0x69fd5b1acabdaa19,
+                0x3480dcacb9f6f66,
+                0xf344e0f5db44941d,
+                0x91c4a78adf6bcd90,
+                0xbd4583e5d824d88c,
+                0x6e0fc6a55c2808cc,
+                0x14d2584e47b02
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ff3423d434cc04b,
+                0x170d40f3c2a97274,
+                0x30860742048538be,
+                0x7741a8ab206f40f5,
+                0x1ee6f75d0db6b135,
+                0xb1e3018bffb921c4,
+                0xf7382a8eae4465ce,
+                0xf1e34a5f83a6fb32,
+                0x26b8c262f477630,
+                0x38c478ccb0ac05c8,
+                0x5861b064b89c0273,
+                0xe66d467b1e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66f5d5fe32739d73,
+                0x9dd2444aba67b37c,
+                0xddc0bda2980ae59f,
+                0x9d9484fadb5fd373,
+                0xcae34005ef0d28a4,
+                0x10e718e980e00503,
+                0x7a9fc6522beab532,
+                0x926fdea108bedcbf,
+                0x424bc94ba414709a,
+                0x4e29732a07a10bff,
+                0x61bac053c8b655fc,
+                0x15be18f3ceb3c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6654f975b98e0ebc,
+                0x223462813cff3beb,
+                0x6a9d6c62d9308507,
+                0x8d5007df7d7531a4,
+                0xdf5ac10f3cb78a6f,
+                0xdf816dffbb0af182,
+                0xa36d0256a0aadb54,
+                0xa480089c3591be19,
+                0x65169ad410df11f4,
+                0xc41b94bb07e97b9e,
+                0xbf52870b21d02ee6,
+                0xedaf13334271
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x112ebef1e53a92ff,
+                0x8bde151c7d9c39c4,
+                0xcc5736ad62147b30,
+                0x22321605e23b3dc1,
+                0x59d55ee0ceb5b506,
+                0x449685445f9be97e,
+                0xfba7f02703e84539,
+                0xace0500e6300bce0,
+                0x7c3aa9b612c22291,
+                0xa83463d5f15b58e,
+                0x1b8fc6656fd07884,
+                0x7b40dda8b565
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1cc0118b36c7408b,
+                0x986298118b9476cd,
+                0xa28b9f32b7a45fde,
+                0xd33149c5ddbc085c,
+                0x23033c775407d4e3,
+                0xe98c1360f4084be1,
+                0xd227aa4c0747efdb,
+                0x4dc4514dc26c2334,
+                0x781c192378344780,
+                0x5112ee29304f35b5,
+                0xfef279fe889151a6,
+                0x159c2943542b3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x384bccee44c71317,
+                0xacf60f1131e6db2b,
+                0xd5a5bcf3b130fd62,
+                0xbaf25d121b504226,
+                0xe07b70e967c772aa,
+                0x7e7075a283d24012,
+                0x926f3f4bbbef87a6,
+                0x8b66625ad8d06bfc,
+                0x41ff417919e45122,
+                0xef20ac92adae9b51,
+                0x3cf42e70bdb56f88,
+                0x17b3c7897284a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba20491c23cf327c,
+                0xbc319890aa1594f3,
+                0x5425bea2398b9412,
+                0xa8bcf851acd08abb,
+                0x949755906101a47d,
+                0x3161ff143bb79ce0,
+                0x3a4ff092f3dbabc8,
+                0x9387632bf50c124c,
+                0xa83afc5180e9c795,
+                0xfc2c685e7876f4bd,
+                0x67ec57d15c3e37e6,
+                0x19e3875e3b47d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x650d997f0df09fcb,
+                0xa934848f39333aa0,
+                0xde0b197ea9e436ba,
+                0x350817d7e3dbf930,
+                0xa0ae377b74a54bb3,
+                0x7fa2036451ffafaa,
+                0x335dff6a539c1baf,
+                0x137f661cf8dc7ea,
+                0xc3969a149dc933e7,
+                0xa777f24edc218936,
+                0xd1d9330bb1141c9c,
+                0x133681f3582ed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc63326415b1d618,
+                0x24c28725f940e3ab,
+                0xf88de9fb9aa20158,
+                0x7af7c7fe2135471f,
+                0xb8df593975e572c2,
+                0x27732e56766286e7,
+                0x2ac43b98bbece41e,
+                0x794a56c5f17b7115,
+                0x33d9f5fc6c301b04,
+                0xc5152887a49cf96d,
+                0x9fa9d2aaae3e4857,
+                0x8a276ddbece3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfec6b15d02b0c4fb,
+                0xd72b770ac913bc0,
+                0xbaa446196b51c7f8,
+                0x38919449d32bb092,
+                0xa95418d5ae38dc2a,
+                0xf81703edb63d6b71,
+                0x355ae4ffb6e20777,
+                0x2f8164cffc8d7be4,
+                0x5905aab191d58f35,
+                0xae2f8c7a910cd073,
+                0xbd2a50200a6c1ffd,
+                0x6768ba0a8052
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e3297759cb71314,
+                0xf402b7afcdba8bb8,
+                0x78970978ee5070e9,
+                0xb498d4402d3d92c7,
+                0x101ba5ce9e9a164f,
+                0x60ce9c1b5d143d4f,
+                0x7f8f2580c6e7937a,
+                0x7c668c4bd298c9b6,
+                0xf96703c97d8da593,
+                0xb779c045ef3dcd23,
+                0x91a78cf21224885e,
+                0x173507b08b0a1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe496d6f16533cddc,
+                0xfe03d0bae195df91,
+                0x1405303daf1ca869,
+                0x5f60aaa296c81cd5,
+                0xeded4965dbce9021,
+                0xcbe4acd23136509c,
+                0x3bcb8d352af2680,
+                0x4e3fd1c40879709b,
+                0x7d5db5948f0405d7,
+                0x59450526fc92fcdb,
+                0x557d6a948692cc02,
+                0x902c7535af82
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x62ab3b9d4a679623,
+                0xd3aba9172d61c4,
+                0xca2cad026116606d,
+                0xc718c2f14cf65ef3,
+                0x3b00514283d00ed7,
+                0x47db68595ec4d21a,
+                0x559f4647aab0938f,
+                0x6bf66e2b4f8a1290,
+                0x642b109c48613dc7,
+                0x570ab97f62961e1d,
+                0x3a074c26c8835222,
+                0x11179955731e0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x484e89732383104d,
+                0x98cbf40e401b63ec,
+                0x739dd455ea055219,
+                0xce97a76026f1d6cf,
+                0x64d529bd730d8067,
+                0x1b9e5abfa954e815,
+                0x504c93bc488fa2b8,
+                0xcd36b48808cd58f9,
+                0xd99610ae6c96ac8e,
+                0x2a79d66ca373b7f4,
+                0xa48c2a3595f35e63,
+                0x142d12cbe7bfb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x738c32ec588b5d14,
+                0x4b746b562221c22a,
+                0x471967b9931e100f,
+                0x5f058cb1e38c261,
+                0x5a468b4e079b6fe1,
+                0xbe0e031054584dff,
+                0x9b9e691cf17220b4,
+                0x44c7c6d59c68e239,
+                0xcdab085c167e883c,
+                0x43ea638473697b99,
+                0x9fd1dbb23ecb8afe,
+                0x4a953270f2b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9de70c5efd4da3ab,
+                0xfd78ec38d3a2483f,
+                0x2c015d8348b92c62,
+                0x806ff62c57ad26d6,
+                0x5894b31eb1c93b80,
+                0xd161c58e04b1f0de,
+                0x219d91b20b0bdf1a,
+                0x9ab8982fc678fec0,
+                0x19db754e1458d2de,
+                0x54bdb959771c51bf,
+                0x8f7d3b8c30cc5abb,
+                0x11a39f8c987cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee4522d048a90dff,
+                0x7a34815b3ef619a8,
+                0x621bca2e26372dd3,
+                0xbf9f1a201d2baad8,
+                0x874e75ad1c4eed71,
+                0x53375f1ed3698f4f,
+                0x45207434d960044a,
+                0xc93bf9364da07585,
+                0xa3cf2190e91b7fd2,
+                0xfd5e18c0c2f16047,
+                0xc5f4703caceaba01,
+                0x1b8f3e105ed59
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf52c75580101c5b,
+                0x155098a6571c5f58,
+                0xb9a3f9ec4cd577b7,
+                0x9d27be3dfad7ed3c,
+                0x3d5e3f6c4b4a0532,
+                0x9c2773c15934481f,
+                0x868d0c7dd8cc179a,
+                0x4d35e28064fad1e3,
+                0x49d5dd9e7f909f15,
+                0x66adfac601461b4c,
+                0x6cca8db44dc151f3,
+                0x132c2cc4543d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98592000826d252d,
+                0xbc1d3ccadc6c8b37,
+                0xc75d97b4d778dbb0,
+                0xe8e89d574f794dd,
+                0x48f50790246fa941,
+                0x9c95a3e4666b29e5,
+                0xd81cb5716cdeb9f1,
+                0x603bde3418a8eeb1,
+                0xe556f2b5f5d9e345,
+                0x1870daf5078f69ca,
+                0x42de35d8affb75b3,
+                0x3c8ca535157f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e62f613fe84a06f,
+                0x4e5e0a519e3cac25,
+                0x9e96be25fb38ebe1,
+                0xc68e4a0928c43707,
+                0xe889cb1a45e38921,
+                0xab7324330068e540,
+                0xc61c2acc32f129a8,
+                0x70f42342870bf773,
+                0x45e4c94f6a5af614,
+                0xa6997bd21386d9a6,
+                0xb373d3dfb343f477,
+                0x12de26b2976c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x774b22a115c493e3,
+                0x4650e2ffc3df4b06,
+                0xe969a516ff65a718,
+                0x9e6a432d36c23f88,
+                0x609139698678193f,
+                0xbe37bef4caf5ffce,
+                0xb61f6596e97dae22,
+                0x594c4d1f1b7dddb1,
+                0x6659468b8592f9bd,
+                0x487ad35809faa611,
+                0x2d3a39e44511e022,
+                0x110785b9ade4e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8f0d65c745f27d38,
+                0x1cfcc1b99ff5ffa6,
+                0x386108f6d73b9147,
+                0xcbb0602619b69f40,
+                0xc717517feeb38d95,
+                0x9392bd0815a48ee,
+                0xcab0c8d6741561a,
+                0xc410fb050a13849,
+                0x8c5a499d0f1f97e2,
+                0x7170c63b86c78eab,
+                0xc84153e7301b712a,
+                0xff743e19d0fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x38f59c58882a7a5,
+                0xa1b15607195d114e,
+                0x75f498ec4729650c,
+                0x5d3a90326b195b7f,
+                0xd165d39070252bb4,
+                0xb256e3e523cefbf9,
+                0x6321b2144dc52a1a,
+                0x787681b0780e9a8,
+                0xc0a1524604f691f6,
+                0x7a97aa456cc1040,
+                0x66bf8650d44e0b0b,
+                0xe02e1ac34e83
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdf013feee7655216,
+                0x90008ea8ab1ee4e6,
+                0xe7d37d645227afb0,
+                0xa8c872bd574adcfa,
+                0x390bc27561fd974e,
+                0xa3a5c5865d0fbf88,
+                0x987520a1513d6f45,
+                0x7ba53f4c4145de63,
+                0xfc38a3c0a2fc11c5,
+                0x5f6268ff17e39672,
+                0x4d6c7248cfddb08c,
+                0x14249e6cd47b9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa452c8d7df7b9f5b,
+                0x44099a1410fec6d2,
+                0x4ba839fb98e27229,
+                0x66dca408159122f6,
+                0x3ab50ad8a0015d96,
+                0x3cf173a556bd246b,
+                0x912ac75f32167ea7,
+                0x43dc641d811633ec,
+                0x9d8ad8b907593dd7,
+                0xa7995e958d747e44,
+                0xa495665354270950,
+                0x195441d319d12
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe27992891f62a463,
+                0xe71e8058b878a943,
+                0x27f70b7f2c3a62a5,
+                0xb9c9a7e8a48a5a6d,
+                0x50f135d56e99929,
+                0x9af382a5eea236e4,
+                0xe0bbfc55e856f909,
+                0x2397d609d60154a9,
+                0xfd6e1374586c1e13,
+                0xe32d5f16227de9a2,
+                0x6f4272fcf1700394,
+                0xd0d4a892fec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8f354511f8d702a0,
+                0xaee1c1d4abaa56f9,
+                0x8ddae7e6d160b6f,
+                0xf51bf12a1da326af,
+                0x73d80d435d46fbce,
+                0x4f1ec2a4e3ff55f9,
+                0xd08c4709dbb70989,
+                0xb712bc63deff855e,
+                0x9b0da748e1921b9c,
+                0x406beae22a4a62a1,
+                0x2aee31caa90b8c39,
+                0x17569d9d02353
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4e07eb348c42998,
+                0xb5b7aedd20e0d14a,
+                0xc3f02ec93400a39b,
+                0x539e20082469125d,
+                0xa887087a2985d337,
+                0x73196cc15d822a33,
+                0x37a817eadd7dfae0,
+                0x73684f5cc41d971b,
+                0x88183a7a2cbcfddf,
+                0xd6819d5addcced26,
+                0x68765a0db1081c9d,
+                0x3ce2ea622d2f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x899e92782c832f97,
+                0xc751f6f40a89bb4a,
+                0xe3a1366d71fe35e8,
+                0xdcfd78ad22246a71,
+                0xf7b21b26a46fc88c,
+                0xe85e42531b78a79f,
+                0xe23eb91d143b42ce,
+                0x2b79eec436e0940b,
+                0xb11ca60c663b6360,
+                0x87f4ae1f9fdfa7d0,
+                0x6a97e466487c49f2,
+                0x505d86bf0062
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa0a331eb5a8d9ed,
+                0x88bb650c7114d946,
+                0x59032fb69992362c,
+                0xe6ec582b6d67b1bb,
+                0x26b076ceedc21df7,
+                0x1d3c1ff118a89b04,
+                0x68fde0cb3b40e792,
+                0x37529c7677fa6538,
+                0xe964a33f0a7359cd,
+                0xab9c296141b85b69,
+                0x886cf983ff5e8ad7,
+                0xc9c5cd3a0438
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x77970e6edbb7abae,
+                0x2dfbc8d8657cb554,
+                0xa9eae875871690da,
+                0x49d6f91218bd3690,
+                0x128fd02ab0eb4992,
+                0xc7f943d4427c1887,
+                0x5d3f5a396f88aca,
+                0x49308eb7aae007bc,
+                0xcc05891948c1c053,
+                0x29e9abaabeaa9874,
+                0xaa7943d0cbaa8fab,
+                0x9f0e046225af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae35def82c6934a8,
+                0xae662b69e5aedadb,
+                0xa861b78f2d6b7e9d,
+                0x75a54abbab0c5a2b,
+                0x152a516ad722980b,
+                0x98db9a2c2060fc11,
+                0x330f6d3f2a0faae6,
+                0xd295eed61fbee79,
+                0xc80a3edcb0031626,
+                0x13c568a384cb4761,
+                0xf6b0080f7d549dbe,
+                0x9dcf805851af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf74aaee22bb3d7be,
+                0xc0ccf1dab6b56835,
+                0xb48a9e5750e7f71b,
+                0xbd87d8a6337ea406,
+                0x9744108be7ba1219,
+                0x74017997b32be531,
+                0xd7c76c52f5d9d793,
+                0xf7bca111e900dc34,
+                0xd65605ce9fa3734e,
+                0xd2bc64366258371d,
+                0xd40f1193e379558a,
+                0x102545c27328b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44a734919426ef3f,
+                0xabffa9842982a8db,
+                0xf80e8bbb11b599e3,
+                0x6ed3192168bda48d,
+                0xe0b21e4c0cdba7cc,
+                0xa560504a186c77a3,
+                0x9b1fe117d27a7f12,
+                0xe70b6dbb3a5c280d,
+                0xb0ad41c944f3981d,
+                0x4fbce83c64e820ac,
+                0x70f1ad1adb1d4a93,
+                0x5f5b37f9d755
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5a8beb38df4a975a,
+                0x689bb03c695f32e,
+                0x78a25b4981102bde,
+                0xe7b9053175642bd0,
+                0x5a9251246ae63416,
+                0xfa37d1b81aa18ecc,
+                0xdc88e7dfcb40fc02,
+                0x669b47fab2c9032e,
+                0xffdf2916448d56d9,
+                0x32da8cfbe77388c7,
+                0x16add848f9f9002d,
+                0x1600adf5e0ba2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x287f7e38005c7419,
+                0x674596d2d3191444,
+                0xbe3df0686e2b0313,
+                0xf02ac484078bbd9b,
+                0x76d1c481bea1a75b,
+                0xb78e6d8ace042d06,
+                0x8104bc1dcdb84bac,
+                0xe205228756e4c532,
+                0x90d57e94a190c0b8,
+                0x53cab85ba01e991,
+                0x5913435b63714135,
+                0x1ae819802f2e6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b2b73f2abf747cb,
+                0x3133e51509bade32,
+                0x98ac32bc790ce1a5,
+                0x86dc9a0eb8d1beba,
+                0x472303fbac0ab5a2,
+                0x271b43784a48435a,
+                0x45b934b9c7e670e5,
+                0x81983879fa058c27,
+                0x9bbc86147b1bc376,
+                0x45d99fd8857abb25,
+                0xe700698c706ea092,
+                0xb0581b7581f3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x55047c76df3c96c0,
+                0xcc2cdfd3068a8987,
+                0x60d03c78a4cfa9f7,
+                0x554967ed78eea05f,
+                0xcb04882ae546b11c,
+                0x8c951b942a52f970,
+                0x19c6078ba6125f6e,
+                0x2493bff6cec17ef9,
+                0xcb8d715f12df660f,
+                0x79f1be6d1c67bcc,
+                0xdd63b985039abf7,
+                0xa923b7651a48
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x124a51dcecb8f1ed,
+                0x482c68d89225bb70,
+                0x2726e8ebcbd411f1,
+                0x6215a9def3fa4148,
+                0x8e512fcee6e293c,
+                0xa4c6f119c25ab265,
+                0x216889b472222056,
+                0xbda2496bbbfa65f8,
+                0xf0a8a6e44d2155bf,
+                0x1552c7bb6f2b379f,
+                0x1649264ebe9238a9,
+                0x1327461e9724a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0be6bb0797de891,
+                0xb78e077555cc3f86,
+                0x56a1f4c2349ea52b,
+                0xc25f66f1bd62ea07,
+                0x11d499e5d66885bf,
+                0x7b4c9bbfc70fe85f,
+                0xff1d2ceea03ef29c,
+                0x140daf7537409f84,
+                0x8ea14548bc5b20e3,
+                0x2ff26c98ae78b215,
+                0x3b01cc78430d35b,
+                0x1be8eae920fb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd46546c85eabe50c,
+                0x1e9c598d3191313,
+                0x2766bb1617afc272,
+                0x365e4b898c5ea8df,
+                0x2472f33a2348e51f,
+                0x56b81c6643662ddf,
+                0x233a06ceb80403a1,
+                0xc68a3fb05e334336,
+                0x235c605809d29de8,
+                0x806198d2d1cff435,
+                0x4915ada714444694,
+                0x110c1b6c7d6d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf5e74f70d7166005,
+                0xd53d78d6ecb775e6,
+                0x501a0f01fd684559,
+                0x678068a7c2c382c,
+                0x3c763cbe2b4be36a,
+                0xd72b15914d5b3565,
+                0x426e4a120a40caed,
+                0xcafc67e244e480ec,
+                0x75b53ab2f29d1631,
+                0x14a957813cb46512,
+                0x5cae85f7cfb5e470,
+                0x1919c9a6d170
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9d957ebad8731c47,
+                0x1f2bb74cb4207fc,
+                0xc15fe2154487421d,
+                0xb447a50c9631def2,
+                0x86f55e7f23b7ec44,
+                0x44be5570cfae76bf,
+                0xfbfe064624d96a54,
+                0x7ed85779cd6d74fa,
+                0xc610532af006596f,
+                0xc9d0c4c798c5e152,
+                0x69abc1f00253d669,
+                0x16eafcd03eec1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xadf66204d70467c3,
+                0x45d59b06aa75e087,
+                0x431766bc4ebb627,
+                0xe59677b53905682d,
+                0xf3b8da766c211c84,
+                0x38a46ce310731e0c,
+                0x713e3d96920e994f,
+                0x27381343ddc891c2,
+                0xc22e82153566a26f,
+                0xb568cf9c263bb195,
+                0xc8c8cfcad5f989c4,
+                0x8431e76d89af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x907262ef4ce94a96,
+                0x6aeac7bb431bc0fa,
+                0xf90256706a7cc6e9,
+                0xd78e653b4b17f9f2,
+                0xabfd8deba5eae963,
+                0xc3c4e7ff4d64d6ca,
+                0xfbb887c093adea18,
+                0x57a36b65c1241346,
+                0x9f2d511c4d600eb0,
+                0x911a446284893a03,
+                0x585e6dffdfbaed0d,
+                0x12656ecd1e0c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x390fc87521a19e3,
+                0xec7e5bda6aa8fec9,
+                0x8c3519357d196c37,
+                0x50081a9eca55e76,
+                0xc8bcba59add120a2,
+                0x1db37388563633af,
+                0x6edd0425cede2b20,
+                0xf180c8c6af7e4fa8,
+                0x6af8a09a60c1d392,
+                0x4570d86748ca64b3,
+                0x9c8b7122e8fa01f2,
+                0x1abf0b7044139
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf1a0d526b038d081,
+                0x9ef379703f4b6ae2,
+                0x29769f765df40ab6,
+                0x4798051728932173,
+                0xc9857c0900ec7788,
+                0x53ecfdcb170d9cf1,
+                0xc7a8a34d53804257,
+                0x648d7a4f95a77e11,
+                0x4367264c2f40782e,
+                0x49263a37cddbaf7f,
+                0x2568313dd027a90b,
+                0xb8080cb7bb19
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x586610474391fa35,
+                0x272bda417d5ec24f,
+                0x4577cf54752a20f6,
+                0xa0be35846f45b766,
+                0xf91776b50f5cf727,
+                0xe8f90d7d8bf15e63,
+                0xe430f02234d5599b,
+                0xb3573ea6f5bccd52,
+                0x2d079ee0ff84d254,
+                0x7f38b54f516483b3,
+                0x9f2fa33178ea1a14,
+                0x1a8f083e9b3c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x38d219064959ac7d,
+                0x85668169437e55c8,
+                0xee97f4baa143d76a,
+                0xbd065e5cf55961fe,
+                0xf4cad327b679c116,
+                0xebd0feb2929945d2,
+                0xb5a56da1184a07ee,
+                0x5d0527aefce58aaa,
+                0xe62c973fad8350e9,
+                0x86e1b52150c8e96a,
+                0x7ad98eebfab2fd60,
+                0x1996d7a5f34d4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaff9b2fb26759c5f,
+                0x990eda94c5351282,
+                0x6c590bed5cfce243,
+                0xbc2998bc48f1a7ba,
+                0x640dfbc019aa58e3,
+                0xa319aac8e0caf78,
+                0x8ba090ca2e4174e6,
+                0xb17ac8047251c312,
+                0x8d4c76411feebc8e,
+                0x3a317b68c1b0b829,
+                0xf6a32ac6da1c186f,
+                0x10857e0c4157
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x26f02588b42b20cc,
+                0xd447ca4ede0212a7,
+                0x8a3f285b94d5ce6c,
+                0x9de8deb740669442,
+                0x2c6752ef955841cf,
+                0x29d766e711fd54d8,
+                0x8b24e4882871d677,
+                0x42993ee6f121aa31,
+                0x197bcd652ee7b44d,
+                0xf5c7deb0bad1a3ad,
+                0xa03f64e7a5cdede1,
+                0xfbb5604657f2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbbc89ed3742548c6,
+                0x8e74f625ea346603,
+                0x733f592d67baa648,
+                0xcdc4c51e920c6f7e,
+                0x36a5ac7dcf3d28e1,
+                0xb938bc6799b0994d,
+                0xd242103c981becf1,
+                0xd23b0f6462542735,
+                0xff4b8b33fbf05b3a,
+                0xdf0d5cf4fe15b089,
+                0xfc858a848fae96b6,
+                0x11466e57a6b79
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa7cb7ac1bef4348,
+                0xfa032c00b03af15c,
+                0xa252351b7e64b513,
+                0xcbcbef3d9ef01ca3,
+                0x6f1eec346da0ef72,
+                0x297c24cf84766965,
+                0x9f357863888c9c48,
+                0xaff0fcf170e5400e,
+                0xa4765841f56a1813,
+                0x55457322e6989174,
+                0x565ef3baf6941e4b,
+                0xe44a89b5d520
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f516125040b50ee,
+                0xe4d1886a1ffe4e2d,
+                0x6ba84a489b4610ba,
+                0x20e1916d8f0e3cca,
+                0xfe576b929def509d,
+                0x63cb9dfe26b15220,
+                0xa179f481abc82f70,
+                0x3dc12fddae868e6d,
+                0xb267d2cb1de54596,
+                0xfd3583005d6a1302,
+                0x4ec64a35fb1b2bd8,
+                0x18abd8d51c8fb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x765347fc73d12eeb,
+                0xd34504a66127f979,
+                0x1b3b10f5d35dba4a,
+                0x242ab7974a17edf7,
+                0xea63917b7750cb3b,
+                0x8cd77846aca5ca1e,
+                0x6f35b79fca4103c7,
+                0xd5bcf462c38c59df,
+                0x5a1f62e670b219f4,
+                0xf9104a8736ec52bd,
+                0xcc7ed2147a341a03,
+                0x15a74878679b9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc1c3bb2d5c7d2a97,
+                0xa388d06d6e2a3d10,
+                0xab368351d28854f6,
+                0x6d44af3f87792d3b,
+                0xa86462dce19f23ee,
+                0xf63df637ddf31b19,
+                0x5b950be753419238,
+                0xf2f3330077996b65,
+                0x61f141e147e0e5c,
+                0xb8f9bd8ed94f0dd1,
+                0xa98b671ec9e85aa3,
+                0x1514ef67e6831
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6db99b50aa2de053,
+                0x9a0b9597644e6d02,
+                0x2a1a678c3b51ec89,
+                0xc1b8a220904fa535,
+                0x6ce219e965b42760,
+                0xab391974b052b191,
+                0x7965b359494b9dca,
+                0xe633b244f1e224ab,
+                0x35d9768d501d7843,
+                0x72423d003fd6ac0f,
+                0xf57cf5ba926abfd6,
+                0x1297e99767bec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb70b9beacfacf6ca,
+                0x5830f204f23ba9c6,
+                0xabfd2154e8f63ec9,
+                0x75798508668582d4,
+                0xdc96e70de1b2ee5f,
+                0x77503dce1002b21c,
+                0xef2f70667656aa3f,
+                0x4e41ec67fc784653,
+                0x6d8e965a81098cfa,
+                0xaa39571d4319fc6d,
+                0x17afaf582853d6f8,
+                0x122b28aebb04d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c20326d2636deb1,
+                0x2d431c045a24727e,
+                0xc31c29e9c91e5fc7,
+                0x350603801963ab0,
+                0xc6d2a35eebdbfde4,
+                0x11c68d29ba039a24,
+                0x6566b2239a000c29,
+                0x56fc61211aadcba7,
+                0xc2d019982b33fb16,
+                0x61c6c121fb2ca20e,
+                0x667478f2f97fb8fa,
+                0x133d67774522f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1aab6c4b53466d02,
+                0xe3f694cd83e22b10,
+                0xa3a46ea6a4ea5b5,
+                0x64f196c157e2814a,
+                0x19bf4ff45e40042b,
+                0x835b9d2ba53fce5f,
+                0x5fd287985b38c5c3,
+                0xa03dcc7b1dc1f5be,
+                0xac019954b65fe997,
+                0x1402d382df3814b3,
+                0xfb37aaa26067e017,
+                0x124b87df37569
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b45cde2696d31fc,
+                0x6c9e1229b6b82362,
+                0x240c139536dd6a,
+                0x7df8c24707af3ad3,
+                0x7a4ea7ec76116b9e,
+                0xbf44a667cf7c8aef,
+                0x1d7884437eaccee4,
+                0xeda91b7d8f0d6fda,
+                0x1a6352edcc6040a1,
+                0xc1eec7cc29a5572c,
+                0xa7d413c46741aadc,
+                0x7e48c42d66b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b4346747e296fbd,
+                0x37939cd45e2efeb1,
+                0xd381535f87e5b553,
+                0xd802bfba5b0eb425,
+                0x5697bb974c685107,
+                0xf4513947ab9e6886,
+                0x1664ded5b2b59a69,
+                0xf810f2546b2cc915,
+                0x65b4405e00a8c796,
+                0x3fc6ad754d655199,
+                0xb44d37f64354d,
+                0x16e00a59540c4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcd88d1f6ab098856,
+                0x1fa0f75a35eee27b,
+                0xa705cda15645699a,
+                0x1b5bb2cb71d941b6,
+                0x111fd488d21bf507,
+                0x94e68216022f7f2c,
+                0xcdffc81cbf463292,
+                0x236110ee62003ee5,
+                0x7c7b8848856a524f,
+                0xb5b5a4bf208c2519,
+                0x6d638a7056e80142,
+                0x4ddb0d77a66b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb5a8e224e592838,
+                0xb0a7c43b5ee07a52,
+                0x6b455a96f30b1e1f,
+                0x4b702ae0e51b8f6d,
+                0x828eee0b47d2b6f,
+                0x35365a3510bed53c,
+                0x9a52724795731dc2,
+                0xc7fa778fa2792360,
+                0x5294487a82520ab4,
+                0xeb949789f4e9100c,
+                0xae2426a6b19ca21d,
+                0x1ade142e43088
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47a4eac3f6bbe45f,
+                0x23e1ab504e07f194,
+                0x720c5fb18cbfde60,
+                0x798ceb197c3e0fc,
+                0x794c668bb405adf8,
+                0xdb43fed5c5786648,
+                0xf22d5f54de09ec55,
+                0xeb1255142f1e7732,
+                0x2eb07f19259f395c,
+                0x337c0347dec20caa,
+                0xff3aaa84d96eac98,
+                0x1858affb8bea
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b192b6cf15c686b,
+                0x8434b7cf0a14dd6f,
+                0xd1623d2e09a1d61f,
+                0x7fbe3febd051b14d,
+                0x5d3e7f9ecec47851,
+                0x460d2c28d7a0c510,
+                0x35e233a358a6c351,
+                0xa9df51d57428186c,
+                0x56ce0aa54fb43e2c,
+                0x4365c1844d4b4c83,
+                0x4736386804031e72,
+                0x4936b6956d85
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaaea69ba75a88f1,
+                0xae5780223aac2a8e,
+                0x3388fe32e57f8917,
+                0x35e7be0694416eb0,
+                0x9e08531b9d887d9c,
+                0x336a28cdef31c4bd,
+                0x657505342d1fca0f,
+                0x9c488f35f7bb812f,
+                0x268442a18ac04d57,
+                0xb60724a9139d233a,
+                0xa2cca7fe0fab2b33,
+                0x80e2bd51d7e8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1d275c2e01d4400f,
+                0x911872a486a8efa7,
+                0xfd632edddb011b73,
+                0xc4a5fcac8f500d91,
+                0x367377b84dd762e5,
+                0xd47aded2e58a3c99,
+                0x2a8bc75e62ecd588,
+                0xa1e157c8930a4bf7,
+                0x57566f4ea361e11f,
+                0x90f4ec50056b0722,
+                0xe2fd1c80bc98e23,
+                0x4b8d72b4489a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb041b75a53ca758e,
+                0x544ad187330082fe,
+                0xbac9e169fd84b6ae,
+                0x6b421a390102b13d,
+                0x7232bd52ff65838f,
+                0x4f3ebe9b52744636,
+                0x2524338d0bc2e615,
+                0x42b9461412b2708c,
+                0xfb566ddf10d8005c,
+                0x6dff6e23ba0fcccd,
+                0x78813c96cf395210,
+                0x5ca62c0d7fa1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x58c09557b177f1e5,
+                0xf5a15b212b884d65,
+                0x45d222368b44efe4,
+                0xaeaa4308dbd19312,
+                0x91a08caab5bb84c0,
+                0x49482d9a2238bd65,
+                0x29758f69cc58745a,
+                0x63a2cc61d5354808,
+                0x57cd7564cbb701b2,
+                0xc9e861c35fb51e7c,
+                0xb3291875eea1846b,
+                0x127120211b201
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e0ac31ee607fe37,
+                0x4e20a3d92c7e8941,
+                0x85de6738b3de7bfb,
+                0xc1f2c0523cd115fd,
+                0x66ce777d299d2076,
+                0xc30367b061cae666,
+                0xf96d4ec55e4da2f0,
+                0x2a615a60de946c11,
+                0x7878d27cd63f7eb0,
+                0x91dba7097c495e3e,
+                0xd0987ca387709ac,
+                0x4b918183527f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0cb9479fec70047,
+                0x8e98f50766c18de4,
+                0xabfe22d56471ef28,
+                0x92365ee67d052b04,
+                0xfbd783faa76c93ed,
+                0x347b9f17806511b8,
+                0xa69690c3208e7cf9,
+                0xfb5266e6b42b5afa,
+                0x62fea25e1b107798,
+                0x1e732f7047f4fb3c,
+                0xdeeb49b4c1c01d4d,
+                0x364a323a798f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a88ac73bb537e1c,
+                0x6da5deb58cdce66,
+                0xd23dc5aa693dda8f,
+                0x68803a9a5321ea7d,
+                0x88b930ca52698a3e,
+                0x17c3220b1b0b4347,
+                0x5753dae9c216a98f,
+                0xba2a671806608f2c,
+                0x7486b66dbb70022c,
+                0x1af0def9d7d7b704,
+                0x798a5aeca9c32d83,
+                0x19a5d35a9d50b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2df17a2b3ae30e8a,
+                0x3855f5927bbec160,
+                0x7cd3150104fc264,
+                0x2eb604a56f6ff2f,
+                0xc97b296eb07762c5,
+                0xc6f3c27986f40ace,
+                0xb5f1af2643343a7d,
+                0x806e6e64ab797065,
+                0xe9577c810d8a9047,
+                0x19e5ac65f863b824,
+                0x9f404d659d0feec4,
+                0x44fa2549528e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd7ee3ad94fa906af,
+                0x181a007f18373eff,
+                0x5248defe45899bc1,
+                0x68a60f1b0042ff08,
+                0x656daa24b83e5a71,
+                0x983bef4c0901f5f2,
+                0x94ef68f72198e78e,
+                0x98872b0b0a0e1ed2,
+                0x9521cc332813e562,
+                0x7937d7e05a91145,
+                0xaf8d94310aa4f970,
+                0x1c3f71b67d494
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdce701737e90e37c,
+                0xc0d3aa47b4cf09d4,
+                0x456ba61b2974b585,
+                0x2b677115dc266ad2,
+                0xf2186625dce29497,
+                0x591690cd74c34e46,
+                0x232c631a4a97f61e,
+                0xdb6ea228b5fc9e47,
+                0xc4eae2dddfe166cd,
+                0x8fa3241680e37174,
+                0xbca7d6494ffefbc5,
+                0xe9bc9e2c8438
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35c2fdb50bce22c5,
+                0x24fe0890cbe3e5bb,
+                0xae7a0b6a31ea4d9,
+                0x1b6ae9ba37901e6,
+                0x978fe4c12e075302,
+                0x495ba827646fc095,
+                0xf3ec79c346d9a3ad,
+                0x303d4f4e62f182e5,
+                0x66ae11d669c4efc0,
+                0x980b87212e423242,
+                0x4b5616b0c26de690,
+                0xcdec8d41d4e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x21ba356293ae2304,
+                0x9b3b2ec16a0e9ffe,
+                0xd4c4ad42c29a578c,
+                0xa7dfcbcd85af3f55,
+                0x86153d3fded016bf,
+                0x92017eb321f5e5e4,
+                0x27490c8bf606b9c3,
+                0xb794842f767b0192,
+                0x305f96250b8368d2,
+                0x6d7afc56f7673d92,
+                0x16c93e1675357959,
+                0x179b67fe05a49
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc1fd92c1933bfa06,
+                0x406cbe1d9e4feb30,
+                0x7f2ba6b56e88b0fb,
+                0x92c72710b7ae7604,
+                0x1706854ae4abc744,
+                0x6748d58b194ce561,
+                0xd5ee1ea522b1a73e,
+                0xd432ca83f12dac1d,
+                0xbaf5be4321ca14bd,
+                0x801b87886593e980,
+                0xc8b09b8a551ef4d5,
+                0x26c032d5daac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaf95e9830e26fa6a,
+                0x964ddc8f5c176335,
+                0xb14c6d750ed0b2f0,
+                0x87c0901c579334ab,
+                0x7719a8f85386a2b0,
+                0x6e5683195b13f3f6,
+                0x32a80d0cf6133aea,
+                0x5d36e1be484edfb0,
+                0x28a02a2e5d286010,
+                0xf497e1f70eb217ba,
+                0x13bdd5671bfefe34,
+                0xb56407f2ffbc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x26af66498a0adca7,
+                0x3a6303718cf66f4c,
+                0xf265c012f56c1ab4,
+                0x9eeeb8edd676333c,
+                0x53598da1b0307ec1,
+                0x1c1a2da439631efa,
+                0x2c637c2577d6c82e,
+                0x7a7eb6c556b9d466,
+                0x46f38b532c68c9d2,
+                0x3ae60dbaaf13eee4,
+                0x3a3eab06c84fe45a,
+                0x16795b7b9941
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0ca52c5e7d4f00c,
+                0x4072a49a56c92fe2,
+                0x35b11399edc605ea,
+                0xf9cabe2d24dc8290,
+                0x79136a0ee3852167,
+                0x7acd529d77b0868e,
+                0xea0f2422f5339317,
+                0x806b7d112c0ce015,
+                0xd0988a1d3481aa73,
+                0x19dd7516822534b9,
+                0x285f8d37a3c6a502,
+                0xec1a98c0100b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x27a5f1fd15d0e9b0,
+                0x3761a6747edeae1c,
+                0x361ce0beae0c8016,
+                0x7531f6ac9eff5309,
+                0x91ee9cf875f988d8,
+                0xbcdb41031206fa7d,
+                0xce737b4f7969c706,
+                0x4366952a054d0b2b,
+                0x326d83b0f51273de,
+                0xb2a802438efd1346,
+                0xe7d01e2e86a0fb43,
+                0x189137de57a33
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x94eec27a7eca8703,
+                0x89f98f1ab6bdb132,
+                0x4a04d18109e748a7,
+                0xbe1a2f6131d73383,
+                0x3390b6a76346b485,
+                0xfdb5e41451caa1d4,
+                0xcf291a8399f39964,
+                0xf31244a1f89e0d79,
+                0x606921050ac195e4,
+                0xb1b152d0636f3aa4,
+                0x507d92c3ed7fc9bb,
+                0x19725fd268a47
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa5c0f224ecae4e37,
+                0xb153fa10bc4e5627,
+                0x64dcb89d603aa566,
+                0xd8c0fa1110f6caba,
+                0xe455cbcb42465eec,
+                0x2d8f13a7dba9b04,
+                0x2fbdae9c475c6c3a,
+                0x675c35c4a4e8acb3,
+                0x33a366e6080bafbe,
+                0xdb4f87a97be25685,
+                0x8dcb7d825c1cd5b3,
+                0x9f0fe9e833ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66c5a55dc7e7eefb,
+                0x65c15169b78d83c5,
+                0xd3b531c7ad497f1f,
+                0x9f69c2b6dcde6f8c,
+                0xff5d08543b1f07f4,
+                0xcaf40169658d4cdf,
+                0x33f0bf146cb213f8,
+                0x52fd52eee782b9e0,
+                0x813d763d6a16bdee,
+                0x4647bdde3ccbaef0,
+                0x926c3832d8ce8c91,
+                0x1565e2d24a522
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x68204f4f7cd522e5,
+                0xea79b8edcea2a1a2,
+                0x77e5c089b0e71afd,
+                0xad75d04492606e85,
+                0xa90d944fd176d7c,
+                0x8e1b1d0c71a77506,
+                0x40a25041d5bdea23,
+                0xe950f3e336bca29,
+                0xe5cbd1acfb3d5583,
+                0xa05be0680b259d7,
+                0xd9a2bb870811f423,
+                0x16bbb70a616e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45137d97b1f1b3a7,
+                0x530dec84f81bdd5d,
+                0xc0ecc437c0c498d1,
+                0xe674f4654ca7bcc2,
+                0xd355320b285a0d47,
+                0xd569b46e65f2512f,
+                0xc36b0cf77f263abb,
+                0x2cc3d16c16f75930,
+                0xcea0e4412aeb160e,
+                0x333ba4baf95f4fd6,
+                0xa07e859df2be2505,
+                0x184e3afabbac5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1ed6a192db820dfc,
+                0xf783650322af1051,
+                0xde92fa0fa42c35a1,
+                0xa4572d5bc5f2f12,
+                0xe4463e81c9531c3b,
+                0xa682708dcf9ee115,
+                0x58cea82519159dbb,
+                0x789f23a1cbca8610,
+                0x858d8f82bc16ccea,
+                0xf541b1464a24629c,
+                0xcc19575eea96a60a,
+                0x25ae5574e948
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe413db911d998418,
+                0xc44b41b6f47cfb8f,
+                0xe6f8663c527357e1,
+                0x2b1859e55c4ce241,
+                0xaccc5a4339064534,
+                0x8d0190e4044c4e3f,
+                0x2b2e0f802e98481a,
+                0xed0619993953b390,
+                0xab807e0addff279f,
+                0x53876460cd03ef31,
+                0x143fed33e4771fc9,
+                0x30563e14bb8d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2ebe35a90c69dd85,
+                0xe975edf18527bd0c,
+                0xd4b14000cc37e1,
+                0x9c86c9f1ef92c6fa,
+                0xaa9d3efec7097f9b,
+                0x66dd05feb6a77eae,
+                0xd4d276ee1ebd68f4,
+                0xd187fbd8eb89c397,
+                0x450d115779f18f22,
+                0x36823977bbb161bb,
+                0x4e2119746c64e013,
+                0xd0847e8e267e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x980a347c0d33a410,
+                0x236aebcd7877c76,
+                0xb582c52e9266d636,
+                0x6a13e0b1f724cfac,
+                0xc18be60de10a9c23,
+                0x5cf3673be2e2197,
+                0x3c6a57e0dd83f3db,
+                0x7ce2d067fc039a85,
+                0xa7c1d75b44dd07dd,
+                0x680fc046ed30922e,
+                0xed6d0384e6af78ed,
+                0x2d4332fecd91
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb25cceca339c86fd,
+                0x8b0ad1823b2e318e,
+                0xaf72d02037588d38,
+                0x752ad4d05caf15ed,
+                0x902edf87396ad640,
+                0xa2f00899cd612b29,
+                0x799f5535e118b68b,
+                0xb6d7b412c0e4675,
+                0xc76bb34024af1bc8,
+                0x9efee84600ab408f,
+                0x47e3c192f1623faa,
+                0xf2d8acd89e6d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ffbc14a35b9852f,
+                0x25a1d7efc164ef7,
+                0x724bbd6d271ee278,
+                0x1e6cc53d165c5c7b,
+                0xf973829fea1214a6,
+                0x80ead8008ef667f9,
+                0x39982bf1aa42ba4c,
+                0xd1b25b90d6a18092,
+                0x74db81097ea07524,
+                0x6b9e26065e697218,
+                0x5627a71dda601083,
+                0x4d2e8f583b91
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcc91df0c88d1d80c,
+                0xa36793df5f489076,
+                0xe0d1de7b78e38cee,
+                0xe9d7213c33fea6fe,
+                0x52c5c2c7eb30b149,
+                0xbb16343d6e87ef9a,
+                0x4027a5f336ae97d8,
+                0x3c4368cd1181f768,
+                0x87145351e69cf714,
+                0x4663cd972c517436,
+                0xaf1f53db6176e2dc,
+                0x140b80c0b561a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7153530360ed42c1,
+                0xe6a67257a84c1d9a,
+                0x3848d26a838a2a4e,
+                0x3806170241241a44,
+                0xedb0e292727b107c,
+                0x1f9faed3594608e7,
+                0xbd878d9a233991b7,
+                0xb06fead90267df5e,
+                0xc7cd436063bca2a7,
+                0x97a61495cacc8caf,
+                0xa6e35a1790570930,
+                0x103499ffc2beb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x73edd2efcf24920,
+                0x7f77c7183d884b75,
+                0xfd04536e8404b2f2,
+                0x30218de20b0b79ad,
+                0xcd995f9fb18617dd,
+                0xe63b1cb43ea5bbf8,
+                0xb3216dcc5eea67ff,
+                0x1521339233ce5eb,
+                0xd54a2f1fee8cfb2f,
+                0x80ddfdc961d59d0f,
+                0xc1487536ca095c16,
+                0xbb70fc017ab5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc328f4a0ec9345c,
+                0x5db6e3b09733332b,
+                0xfe605d513d34fd33,
+                0x192499ba63d7d745,
+                0x84310b75b374a07,
+                0x56520b0d4752dc85,
+                0x614126974b21d078,
+                0x287019806237bf9b,
+                0xbdbf2733244d1b92,
+                0xa5f9ad4f61071967,
+                0x7c301fd0bf2c1ce7,
+                0x19b8654f19537
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7c915c9cd727289b,
+                0x2755a0b26be728f0,
+                0xca7dc90492e5eb7f,
+                0x959e3586532dbd8,
+                0xf38a8a9f61911e38,
+                0x35841f5fa97952a0,
+                0x70e71274a76d2608,
+                0xfa99256046cbeb4,
+                0xdac22e279a485e5c,
+                0x7bbdbf9c6e69cb1e,
+                0xa41000b9a76a00bb,
+                0x22a56c5eefcb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe255d1560b14b1f0,
+                0xda5c5abd214815fe,
+                0x343c43f847f0f95f,
+                0x2bcb7b50751c74d3,
+                0x95155bdd6dd4db03,
+                0x1d3bb394b719c66e,
+                0x9f5f9c24d209067c,
+                0xfc71f0fce6c3ce28,
+                0x63ebe7ac20b0622b,
+                0xf0033d14e30401e6,
+                0x4abd848d8fd60bd3,
+                0x17e74258422af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbaa60cb0d58b3412,
+                0xacddfc46422148e,
+                0x137a16cf7f9895e3,
+                0x62f5c39499d01195,
+                0x89b37d8bcd73eca6,
+                0x9bc2dc461dfb3422,
+                0xc3a9df8dd119e21b,
+                0x845aff957d80e5a6,
+                0x84e84e24c5595f9d,
+                0xc4e68ccb82c2ecde,
+                0x617b6dc891f0ce69,
+                0x1135988443046
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe24991a8c5442510,
+                0xae3be63a444aa616,
+                0x7a6b03e36ee499d9,
+                0xf4bf883b063dd6c9,
+                0x6fa02ded3ad09726,
+                0x98929a43c587390c,
+                0x85015d197f095eee,
+                0x3b0b1f7095b79cb0,
+                0x15d051505c03d13b,
+                0x3a0705b602783f3e,
+                0x74c7b7dbe25c658,
+                0x2396330c5a97
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x77eaeb9d451f2b90,
+                0x64d73b3cd0a54f76,
+                0xf341f26b85f3a311,
+                0x83f9c8c596948de1,
+                0x96d01bac07abf982,
+                0xc1e8e1373c1f3b4,
+                0xb530edbe2359a720,
+                0xfd8221c85197b684,
+                0x6cbc44a9d2c2a8a0,
+                0xa364e8c1de86a049,
+                0x5d6aa52bad713e51,
+                0x12caffb00f792
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4eb1fce2deac3c2e,
+                0x577e2c032aa16f05,
+                0xa81fbcec40ba86bc,
+                0x8c8ec1eb87d1413b,
+                0x88fe827cf0f7ed2,
+                0x8978035ce0889beb,
+                0x27ba0473de6cffb7,
+                0xddcff84aa5d823d,
+                0xc754f11f55b9cabe,
+                0x8c1bd43581547843,
+                0x28c2bc9fbd214458,
+                0x11cc898489f05
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8f01166b8dd4550f,
+                0xf1d4a88be1ba4460,
+                0x5b604500702cf2b7,
+                0xf6eabe0d6b63f8b2,
+                0x41bf2c04fd8f403a,
+                0x4f01ca720ebcb59c,
+                0xb56473c986225207,
+                0x96b9cb0fe1874f45,
+                0x443f2fdbd4094c92,
+                0x6aff0ebfc31af1de,
+                0xc02ea5e7354aa201,
+                0x5d14938570d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40f984abba8acb06,
+                0xf0d8739abb7f8d5d,
+                0xcd069e207998b277,
+                0x1e87940f76bf4da5,
+                0xba7ae1f3725e7356,
+                0x52fe2a02bdbb4849,
+                0x232676473dd485b4,
+                0x38351dbe05aab869,
+                0x8de7c5045baafb17,
+                0x998cf336cc1dbe10,
+                0xb851630c3a527aab,
+                0x15627abc12d8b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbd075106d118d9ea,
+                0xc10f27161cc4898b,
+                0x8fb2d4dffa2c32c2,
+                0x311876fb95298d56,
+                0x8c0755d79c9ccc79,
+                0xf2633d074ddf2168,
+                0xfa282ed5fb7a3d54,
+                0x74e96e78303b403e,
+                0xfde5b5683ae15814,
+                0x64fe751f5d84f493,
+                0x246db62c7ba27336,
+                0x1515f90fc16bd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb6c6a099d7d647c,
+                0xda53ef31bf3fd3f3,
+                0x3337b813af617873,
+                0x3fd16f4c498c7620,
+                0xbb723c8a31dd8c06,
+                0x6910dca3c9d01fea,
+                0xd45e75c31b2aa853,
+                0xcfbe066c975e938c,
+                0xf14a7140749f7ab7,
+                0xcd696db0e83eba55,
+                0xb2156cab55c7f4f7,
+                0x13481f48637db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda4a77b9d6b3ad69,
+                0xb7815d7b8793730c,
+                0xae6d295e72a7a395,
+                0x4ecd1d68dad601c,
+                0x1351c182fb2bdc06,
+                0x44309f86aea2c3c7,
+                0x17e8d52729557828,
+                0xf1dd2f26afcd6763,
+                0x41c51a6220ec592f,
+                0xa8a151308b85daef,
+                0x8b079267e4b98291,
+                0xb14e62268ef5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66cf26753e84efcf,
+                0xdb2cd3f0cd687b84,
+                0x878eddf6c5d13d25,
+                0x341e4a2bfc74299d,
+                0xd004648a9c72be71,
+                0xeb67f5056c3b591e,
+                0x42095f1c02e74979,
+                0x552f16a68fbffd24,
+                0x4a576b00d8864641,
+                0x83bd135ed72583cd,
+                0x326bebad2afec655,
+                0xcf89ddac3a51
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb70752a9767c2e68,
+                0x4f599b915f707157,
+                0x9dd9a60e5a09b0ec,
+                0x65aead1b34361a5d,
+                0x3c252fbfcc2ce3fb,
+                0xd172971ad127bfa2,
+                0xbabf90b60e7cbddd,
+                0xa445cc4a04c4d557,
+                0x1d48fb1224f298ec,
+                0xb6bbecdfcb44901e,
+                0xb0067ec6d66cc61,
+                0x10d2c3dd157d0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3946ac3a0a6d575,
+                0xa6f2e258721e33ce,
+                0xae2c8a2c3abe3d5f,
+                0x7f9c4958d058738,
+                0x50f1cf807f3f8a3d,
+                0xcdd622bf6fc73fbc,
+                0x4678b2163c7f9ba5,
+                0xfebbd0f2eeb130aa,
+                0x5ccce42623cb4df5,
+                0x1de99b846ce05178,
+                0x443355921ecfa4ee,
+                0xf57819ef34a6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x222a27efd077d52d,
+                0x92a28def00ec6998,
+                0x71376c6a5dd94189,
+                0xd04e79e3b4a1c971,
+                0x4a4e4b9f58cdd05a,
+                0x844133c34bdbc11b,
+                0x60b868cc66d9caa3,
+                0xd07fc3e8e78b6a1a,
+                0xaff5b95eac768f46,
+                0xab069d202b1dc99a,
+                0x887d322ea14c8b3e,
+                0x36a2da960c79
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed504f1bcf57f21b,
+                0xaae5d8bfdb1c2f2b,
+                0x1ab95c00545afca4,
+                0x7f72e1ed1f880555,
+                0x482400910ec06292,
+                0x7425392c97f2c571,
+                0x3751adfbd923078e,
+                0x149a420c39a1dfcd,
+                0x2a3469858f956c09,
+                0x744fdba74b190b10,
+                0x21f8e44b8665feef,
+                0x9fe5d798e48d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbf1fcfeecc627316,
+                0x168f45d26055eb62,
+                0xe53fcf61a5333983,
+                0xa8afe90489037d80,
+                0x6180b79680a54e51,
+                0x2f586682ac819e1b,
+                0x7fcf7b9bd707a61a,
+                0x6daf0b61ec52e528,
+                0xf227cd4286193d11,
+                0x14a866d2a77f12e6,
+                0x5995c23849b11118,
+                0x91fefb488603
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x667788d2f003df36,
+                0xba7b074ad9e2a1,
+                0xb88c958ca1a3220d,
+                0x6c487f5acdbe1827,
+                0x1e48022869d29b22,
+                0x54780e3b404658e2,
+                0x25bc2672188367c5,
+                0x1a2495bb43356d19,
+                0x317c9ac324cf0450,
+                0x928147455fd1bd0d,
+                0x220840854cf29731,
+                0x4305441aba2a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdced5e111c3e9a28,
+                0x56604091f3a3cd64,
+                0x9821442499ae61fe,
+                0xcf0dcd5cf86ce2be,
+                0xde39cfa0eff399ba,
+                0x1a717cbe22c5dadc,
+                0x99805504f6724e6a,
+                0xe0508b0715c51634,
+                0xb1c30440f16de3b2,
+                0xca4c9f24436da3d2,
+                0xae9dd333591a328,
+                0x163de0ebde6af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x23ee271797cc509,
+                0x988ea18744a7ecac,
+                0xc722f55c3cbbe07a,
+                0x665ad7758e9054aa,
+                0xc6b64a60e09dbca7,
+                0x7fed0a1a38c79934,
+                0x4352dc24da2f4552,
+                0xe4bbe34abb5e7ebe,
+                0x6f6d7be02a8943e8,
+                0x6bdeaea8289dc583,
+                0xbd388e90ce95009e,
+                0x9344ca24d9fe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeb7a39d9efce8b93,
+                0xc9efdddf5af7e2ab,
+                0xb5debec2b25083aa,
+                0x7eb0add29b0a46ff,
+                0x3bb3ba57191338bf,
+                0x32a16de763bb97ad,
+                0xc9a7e324509467a0,
+                0x4ad185cc5948d34e,
+                0x9d507d389574b84c,
+                0x57d5b51dda795c25,
+                0xcc539ab719eae93d,
+                0x778ffb904f00
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe5b44dc80de32553,
+                0xdffd029ee9ab0454,
+                0xa692e243e6a5c7f2,
+                0x650f90eaee06fdb7,
+                0x90cb5baf23cad2d8,
+                0x83b2ddbd33ade459,
+                0x3b6625345e672220,
+                0x45839b078950cec9,
+                0xeddb0c3a3b39234,
+                0x777efa40c7ec7594,
+                0xe95a5fe83eedaac1,
+                0x5871e950aa7a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb8b58c220c3bf68,
+                0x5c7964976dc9152f,
+                0xe8ed9f4ea3071a49,
+                0x792a362778ecae26,
+                0xe9f79cbe0bb5d1c1,
+                0xf941bc073139be02,
+                0xe2b1678cb6301776,
+                0xad9ea11ea8c86696,
+                0x64361fd8eeb8d62,
+                0xe3350250c34c83e6,
+                0x2543a6fe076c9213,
+                0x13c32528f485e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa9d99d2e381e03d,
+                0x5768595123617166,
+                0xf58e9f750e06a030,
+                0xd7cf4f921395c983,
+                0x836d25563f9d35f0,
+                0x2c9e1fc19b3c0731,
+                0x142d654de8b807d8,
+                0xf18269beddcf6b80,
+                0xa2653f0c32d2d335,
+                0x1a8a2cb4caccfd64,
+                0x1f8255790bfb77f6,
+                0xf2b8bc8274ff
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4abcd76881da977,
+                0xc3dd6b9910008424,
+                0x2a6851922d4d3d3e,
+                0xe8b9d0b7fa468dab,
+                0xbaf27c73f5b7bf81,
+                0x5edc8eb2937c6bf7,
+                0x7e46f37cd67124ed,
+                0x8f6a4b87acb3214b,
+                0xce67bdd36a9086c3,
+                0x49653406f6bc260b,
+                0xa6cfd818eb0f6845,
+                0x43b05c877861
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeaafb2d4eb48fe96,
+                0x5ae1b18a11ce2cb4,
+                0x4c4e2080f9b869ef,
+                0xf8be7b5ba2176aa,
+                0x66264e590ea13dd4,
+                0xff1db7b868fc1cce,
+                0xf0208de27540fc8c,
+                0x5d28e30a0cff1af9,
+                0x5d331d93bd5c0948,
+                0x1ce904b6e0fe511d,
+                0x17d2ad4f2fdb0526,
+                0xdd6022f6a464
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x799755ff2bf21667,
+                0x76fe999183462d03,
+                0xf124f8a15f5655c4,
+                0x787f7b4bc80a7f5a,
+                0x8912d32cd6b8ae99,
+                0xf1a3f156289e8bff,
+                0xe128e4db933e456,
+                0x32ece046c570b2d9,
+                0x280dcd3c329aa463,
+                0xdcea3771613323,
+                0xcf72fff635e86d3c,
+                0x116d178d5bfad
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x826d65495c11a101,
+                0x2a682728c261cf4c,
+                0xfc64755d47e890b6,
+                0xd57999d8f9c65804,
+                0xd13f6d1880c06081,
+                0x8a26453370970fa5,
+                0xe632b08c2645ab31,
+                0xb8938400a05cd119,
+                0x10826214b5dbb9c1,
+                0x604edf74f0244369,
+                0x38f5d13d5c28b7fc,
+                0x1871f4a757ae8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe1573df61bc97f29,
+                0xd38df58fb3811469,
+                0x2dfeda36f3c87edb,
+                0x40931afc515a90a1,
+                0x55a57934032cf71,
+                0x700b006ff576aa0f,
+                0xe98ac6cad4b5530a,
+                0x1e42eff94a7075e9,
+                0x3fc63d81a6a1261a,
+                0xbd502ed23c94e0ec,
+                0x90a8b2b0f2a70cef,
+                0x124e8e777029c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce2113215214a12a,
+                0xcd69c041d369f645,
+                0x4afb9202e68c9e56,
+                0x14aaed8a23679d39,
+                0xecc2be06d84c99e7,
+                0x29855ebeca03b699,
+                0x92f78cf14aa9d49,
+                0x4f0be886a5596879,
+                0x3d9cbb34ce6f21d0,
+                0x1a6a42c1bb95f85c,
+                0xdeda612958be96c2,
+                0x9829dfdb3414
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c8c0824042ee30f,
+                0xef42f8ff4720e12c,
+                0x6394fe3bcfe91e7d,
+                0xc2041a7bbb625144,
+                0x2750872fad9f67f2,
+                0x9442747b5e756972,
+                0xbc63a054e1e75ae3,
+                0xf9685b0c95ac45e,
+                0x7a11e75d6b229fe0,
+                0xd4c793afaaf05f18,
+                0x58d6f2f852c3d7a6,
+                0x1234e678f2ee3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x56e3be3476afb86f,
+                0x30308edee31929c3,
+                0xd52dfa03be78428f,
+                0x1a25067427287dc6,
+                0xe9b18c30b15db003,
+                0x7e8c9ec245593703,
+                0x1ebba62305181adf,
+                0xe75b5e1c5b542bbb,
+                0xf3a50da4191fd2e2,
+                0x8d2c5557501efdf3,
+                0xaabce0257fffcdfa,
+                0xd65a3cebfd86
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2acb4e0f70ac41e3,
+                0x4ddb352283920eca,
+                0x2922f3c073114d75,
+                0xb6293ddc1189f187,
+                0x4a6a837fd61e17e1,
+                0xa34b720da7b2dd04,
+                0x72ddfdea6ff29046,
+                0x6f40fac329186633,
+                0xafc3e2830622005f,
+                0xec53dc5227c86351,
+                0x86ad42798d77d82b,
+                0x2d51de8e324b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x349403ecdcc5a0f0,
+                0xa8fb754528914416,
+                0x1bbae331677cce86,
+                0x25861a6b6d69e564,
+                0x5f37a0d423501168,
+                0x526bac6aafd3b7e3,
+                0x235c9632d368aeac,
+                0x6d36cd489bc3672e,
+                0xb52c22da1f11594b,
+                0xa261cd5819be6f4f,
+                0x2aab8dc43bf319c6,
+                0x19192e1aadac4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd82da71264f5ded5,
+                0xed7168aad3b018a6,
+                0xe2eaf6ae6d93b996,
+                0xc2cbda989eea14cf,
+                0x67b8c947f2e20bb9,
+                0xd405afcc30ce355c,
+                0x9b96d0ed095eb85c,
+                0xc5f31b08546ef038,
+                0x3117f67906046726,
+                0x5b02010c74e863b4,
+                0x36ef2fd4786d0bb,
+                0x19d3cda4f8eed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb99b2d9bcb70430,
+                0x2753fa34f5358d50,
+                0x8da8360e06f96ac1,
+                0x448f19ec2b24abdf,
+                0xa9d34c8c396ceb5b,
+                0xe77a3012d83ccece,
+                0xedd656f0add7c6aa,
+                0x9942ded5e38483fd,
+                0x1823090bc6b08c22,
+                0x4e534bd721c841c1,
+                0x86053d9428744e26,
+                0xc51ee6b774c0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b765312bf51e916,
+                0x3d6150248ba85bf5,
+                0x542837985af93205,
+                0xd213168a86ba9a85,
+                0x8986f45086a4f506,
+                0xdf93ed57ee650f28,
+                0x70018ecb35ac3372,
+                0xeaf5e9ad5df41984,
+                0x12b97e4653426f92,
+                0x78db2b9dc37af97b,
+                0x7152d88c41372188,
+                0x144b4daab79ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9009bfad47b0318,
+                0x18ee3fdc8cc083d8,
+                0x63a4be68b2887db1,
+                0xc1fa6e81d8a291cb,
+                0x6a358223627590bd,
+                0x16658859f359b177,
+                0x3c43f438310f0132,
+                0x424fc50a87ece4f1,
+                0xfcc7e190ed418555,
+                0x90d37250fbeb19cb,
+                0xa0c12d2907f224a7,
+                0xc7c5f4c8763
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1aabad1243f3aff7,
+                0xe7d1bf7c0fa45a4b,
+                0x547d5af98195af7e,
+                0xdd93536e47308e2e,
+                0xa2e0024f395c557d,
+                0x3b17d75a40fab913,
+                0x49ed78f54245c58d,
+                0xcb8000158fb6de22,
+                0xcd0ada98b28de54a,
+                0xadb6911bbad07048,
+                0x23b5ea81f64a3dd5,
+                0x5082e482b76
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x959c99723312beb1,
+                0x2e0e2b6660308559,
+                0xe889549942606db8,
+                0xec3be994bf267143,
+                0x6ec83e1ba99727f8,
+                0xe30a0e8e2acb9b0f,
+                0x6b4f0e415e1534d5,
+                0x88ae0e20b21220e0,
+                0x8483025d8991a516,
+                0x7d6aaf0db1ca470b,
+                0x505bc05df64c8062,
+                0xf8fdb93e470f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e5ae938323b2611,
+                0x9d4cf034a1528cee,
+                0x8d841af72c0d38c0,
+                0x3876f804790aec2b,
+                0x79601301f2cb5996,
+                0x5c451cfc8767a9d,
+                0x5178837ad5d8fc7e,
+                0xc3e981a8761f81dc,
+                0x83ecaccfc54ba3d5,
+                0x41d825c32f736f6f,
+                0x859a4d2740c80603,
+                0x961b5cbbcc1c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1044c62e369bf80e,
+                0x2862d0f9725be8b4,
+                0x64a3ea6c95681a25,
+                0x934e4d1cbd5f068,
+                0x1f35e8bc2ecd1a58,
+                0x217a6db094399d26,
+                0x1b872142d1fb55c2,
+                0xcba07a6539825b60,
+                0x8b84d9f622a22f50,
+                0xf87b3d9e395da1fa,
+                0xb65f0f8279380f95,
+                0xa6ab5ee63d38
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a27bb3bbac5c611,
+                0xd40fa4f13ad28c05,
+                0xabfde2a4ca51d48a,
+                0xc689052f49c47380,
+                0x1f20ea306bfe68dd,
+                0xed026cd90af7e336,
+                0x86a29f37776e7408,
+                0x294ed78dceacc962,
+                0x350cc5a8a96807d2,
+                0x4d431c87a389477a,
+                0xb87f6749e027de76,
+                0x195ffb77e5e94
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e14b37dfe1f0bae,
+                0x9f2c9db15bbc4bab,
+                0xaf93f0890a46df46,
+                0x4a6f0bca6a87f7b3,
+                0xdf7496eca3c06fb7,
+                0xbf88f1febe5cbcee,
+                0xae3c3ad7b4b0da84,
+                0x1371e2757608f0e2,
+                0x4e11d91778cda77c,
+                0x4e6387a9e0111b81,
+                0xff3ce8979ad23dbe,
+                0xfa082c3cef98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x538baaba2df58f66,
+                0xaee36f149b6de8,
+                0xb050b309be9b1fa8,
+                0x63a47a79ae1a6011,
+                0x5ae30ffb72aa1c87,
+                0xa77b15cc3812fde0,
+                0x326b7382d8ad9b8e,
+                0xd849f6a22d6bb01a,
+                0x35eb03fb3c527380,
+                0x4df3159a7d0e5e67,
+                0x941a93025b816a2e,
+                0x10e08e4816471
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa71d63c040467bd,
+                0xb30e96bdb5033b8f,
+                0x424f39cd8b08f8dc,
+                0xa73c93197a85d3d8,
+                0x34e60df3b313d06c,
+                0x715810dbaf12cf22,
+                0xe842d3e0258f13c9,
+                0x8f717d6c212d9e6a,
+                0x4d772658e23be31c,
+                0xe338d964fb64eb87,
+                0x118f9461bd22047c,
+                0x11b8a1e80d8d9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x702b70d40b105ad1,
+                0x2e90160f986f6030,
+                0xa72e73598bbbd32b,
+                0x4cfccebe9838d300,
+                0xde7e19774b9044f9,
+                0x6008d84a6783a120,
+                0xd838ba5c18fac8c1,
+                0x8aaf51ddcc27d4f5,
+                0xa45cc1f4d7685d97,
+                0x46e54b0ed06aff4c,
+                0x5c013fd0f673af05,
+                0x7a1cfe61b96e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba851590c705e01a,
+                0x4a0a74c87c4a3f6e,
+                0x13fd0e93fba29baa,
+                0xbba08e2aaaa69d4a,
+                0x73e2fd1a202fb4e,
+                0x9b45c34558bf6bbc,
+                0xd05d14df6b42ed00,
+                0xfb94c3e4bf35ac38,
+                0x487e00b3442b1afa,
+                0xa72d98474e41af00,
+                0xaaaef711b36b2a47,
+                0x14d039733e83d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x26c0ce56a23a184f,
+                0xa0861ccb8194794f,
+                0x185ffa3eb614d776,
+                0x760937311c2634ac,
+                0x53b0cd8231e22e7c,
+                0xf48bbc4a544767f5,
+                0xbbd796def2837360,
+                0xdbaafb82d99be9fd,
+                0xa7694cbc62477b6a,
+                0x2a8e5fbec1631dd6,
+                0x38abec1a97031bef,
+                0x421bdbf92a5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4dd0476b3b45ba60,
+                0x98dd540ca9929710,
+                0x6caf45c54fc47f1a,
+                0xe26657a9ddd1981f,
+                0xa8e39cb2c46c74c2,
+                0x4a9c554283339b9b,
+                0xcdf197c6c2ea8e29,
+                0xfd871d8dc7f62911,
+                0x5f77a019a602c15d,
+                0x5aab4a91f73a3954,
+                0x3e385f8e9665a128,
+                0x1999b09f38c12
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf5bcc641ac01f7b7,
+                0xc9a3532dc9b286d,
+                0x6c1ab14dd84e3062,
+                0xa1827e8d980d8d90,
+                0x9e25d73e4c67f3ae,
+                0xb6e9f6608bab6205,
+                0x8cb62f26b258e335,
+                0x3ec4462aadae5a8b,
+                0xd7e90e74cfa20e96,
+                0x844025bcde6b5b38,
+                0xe2fe5a7bba5c9b5a,
+                0xabd7b2acd7ce
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa823df7e38bb7890,
+                0x17c16295dd40bd1c,
+                0x9c1607fac8b736a,
+                0xfc8c9f6ce601e78d,
+                0xde5b37ca3150a4c4,
+                0xff235a39c88ed03a,
+                0x46807d221374852e,
+                0xec5f19827f93ab0d,
+                0x9121eab6c7dab250,
+                0x23ee3302e017de22,
+                0xd61b202e8c1df791,
+                0x46517854d421
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3dd4a38a013b83ef,
+                0x9789a54ba2a2c7fc,
+                0x6a12216c917e7eb,
+                0xbd4ac04a13bdcd6c,
+                0x3cd6a0fef2603f4c,
+                0xea785f6c3652f1e0,
+                0x3e0058547dc304ae,
+                0x1148da739497232,
+                0xfcc89874ec3a76db,
+                0x4e6c5c3f5ff38799,
+                0x5c8f996c579f877f,
+                0x72903cbff10f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2aa65f8e6a101c4,
+                0x81adead2971524a,
+                0x29e31317ce1cce49,
+                0xeb166009224e1fa8,
+                0xfc487adeea734d18,
+                0x5764a8d61676ab4b,
+                0x7f21fe23ba3e5906,
+                0x7136c7f8ee49cd35,
+                0xbbdcb3019d27e044,
+                0x3dfc88eb26ba62f7,
+                0x6c91baf583abb6df,
+                0x13bf56fb811a0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2bf7f530be9404b7,
+                0x4e04366c1029d2db,
+                0xf3ed5c8f619b4ac9,
+                0xb65e20f815b83a83,
+                0xe84a4587f43b0012,
+                0xe14e04239b5ed72e,
+                0xadb5a6788fbfebef,
+                0x7a4e67d4057ec1eb,
+                0x8149644442f15d05,
+                0xde3546c3072ccf7e,
+                0xaaf758ce283f09d,
+                0xa81b60f5f7db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9f27798ac8bf00d,
+                0x83e11db4e5b2db76,
+                0xcf1f0caf7e3790ef,
+                0xa3dd000b268a0c5e,
+                0x9fc24617a56f73df,
+                0xd4a4a5bf4e2c805,
+                0xd4166e5755addd6b,
+                0x86cc9dcb5fccfa0b,
+                0xa51c5d47cbb93af2,
+                0xedd10a80e704dfe4,
+                0x729d7118f36c72e0,
+                0x18b1d7ec4df37
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x13c08068459be77c,
+                0x55dc7e52be51c528,
+                0x8498f9a50e5530da,
+                0xd74e807cfec762b4,
+                0xa60212f85eacb42d,
+                0xb09df77e72d14a77,
+                0x35b7716111c7513d,
+                0x5d1edc717d82261f,
+                0x797323dfcf16eae8,
+                0xbc7a4197e226ad12,
+                0xf4b56f61e2732a21,
+                0x6efd0e410b9e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xecfd79c4c169db43,
+                0xfcef6e5bf3a81a7d,
+                0x5a5aaa7e2a388951,
+                0xc5e7d98afa0a4853,
+                0x6538ee4bc4575d72,
+                0xcb11250e7780981c,
+                0x4faaba7272a358f7,
+                0x9a1a427a6ff7036e,
+                0xad2c50e13f9f4b6c,
+                0x7c24650246453b4d,
+                0x7436f149e463f51c,
+                0x5daf6d650871
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8eddbc945578be72,
+                0x86c65deb97c3d7a6,
+                0xdb7a9439bd514c3c,
+                0xbac5303ee6ad8fa5,
+                0xf213f9de9ca52a57,
+                0xc6719088b64b79af,
+                0x1ace50014b7d6f82,
+                0xb17eb13039310079,
+                0x1517050e86904bed,
+                0x9bc5e959dcb8ee76,
+                0x3d1516c146f00931,
+                0xfee829498d97
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef3eba49fe8cc4e9,
+                0x377303ca5fe75d7,
+                0x3ff6a729cc760601,
+                0x4c70cc561987ac48,
+                0x38b31e07c633f1b8,
+                0x46e2a7006898a854,
+                0x658b9464ed495fd0,
+                0x4e460295cf841ca4,
+                0x43cb6d14275be37c,
+                0x85654c12eb181f4f,
+                0x6cc6425038b9b9ce,
+                0x11e518b15f3a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd434cd715633a571,
+                0x96b825306f9a7f00,
+                0xc010d0842c41b471,
+                0xe404b3d27a0e4cac,
+                0xe96ad15b080b6441,
+                0xc2b0c8ef557756a4,
+                0x38350b612e8764dc,
+                0xfe80a9e5dd3773ff,
+                0x263ff8b3c301dfc3,
+                0x30857c72645ee13a,
+                0x5eb78c90ee5b1e7,
+                0xc7f4d80643e6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a964a948282d827,
+                0x24fddc6c46ee4363,
+                0xa6599d970c26bbe3,
+                0x34688d889b802a83,
+                0x136f7c5a74f0e305,
+                0x9301ef7b7c1b8bb1,
+                0xb5d5d0c073525057,
+                0xd0841c5860895fed,
+                0x7e16f0bb09d8fc51,
+                0xcfb6b2622ad7b7d3,
+                0x7f6f7574ce1867b9,
+                0x1bc9e74fe5864
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c02e0eca122645,
+                0xd0144cc4e55183cc,
+                0x34c4a401a7d44652,
+                0x2795a5d93ee1c2e7,
+                0xb9e3be05a40961eb,
+                0x8a67b1c7ab571809,
+                0x4e2cf3ae6837825e,
+                0x71a901bf35a108c2,
+                0x17b7c20a749f96df,
+                0xc249c820b5f3177b,
+                0x92a6fe87c89c4514,
+                0x10fe39e0193c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x677d74fc1882db38,
+                0x294add3e8aa1e910,
+                0x218fe11a64b1d7d6,
+                0x2e56da53752d6126,
+                0x6faf0108b3954b87,
+                0xe245d6f3ecd0050b,
+                0xae608f72898ac66e,
+                0x9f7fe76383575a8d,
+                0x80d465866ce53587,
+                0x40bd9ceba70663b1,
+                0x8d82a3bcefd18cf3,
+                0xa0884758f8a7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cbd35e904c25e91,
+                0x5af271a8800767a8,
+                0xd29521e72f13a0e3,
+                0xeb1c5027efddf365,
+                0xa7645149a066a72c,
+                0xaa934149306cdd51,
+                0x6a2cbff21530e2f5,
+                0xdae6d7a07294723a,
+                0xcf27140df84e5918,
+                0x15317f39ba372ae5,
+                0xfbdf93273027392b,
+                0xad83af420f75
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x412fe3faae7b48eb,
+                0x84c5e34d6093c032,
+                0x4c1bcd64381fec5e,
+                0x3e66d13cca3ab5a1,
+                0x52f759a8a7bbd2c2,
+                0x18173e14d7cce3a4,
+                0xf6fab1aeb66c4da7,
+                0x985f132aab904825,
+                0x689a73ae33d38caa,
+                0x81e8f093f1352d12,
+                0xcda90ba1465212d7,
+                0xac6e2b7eba86
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4efce4f5e8c28acc,
+                0x6ae905130c6a913a,
+                0x63c1e179e35aad80,
+                0x1a9d263c42a51219,
+                0x24339ed2a4719f88,
+                0x7478d24b267eb499,
+                0x47bbab46c7f12f93,
+                0x6e67a78e79e1cc53,
+                0x19b1d5111b451c67,
+                0xe6aaafaa80f459f8,
+                0x9b7a3982c76257ba,
+                0x142f56b193eed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf53fc0e32fee6273,
+                0xaf037b3a04f7c64c,
+                0xc5a82897d43d8a6e,
+                0xf06e570baedaa6d,
+                0x971aa8587ad12c27,
+                0x5183575bf4f13d9a,
+                0xb8f5b2688d60a6e7,
+                0xa00c6366085e8e4,
+                0xbb45fed21b6f1b09,
+                0x7861f0d89a7b2ac7,
+                0xb3c3f0e5460ff2a6,
+                0x1680c78cebd3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37627b2c9e0b07fc,
+                0x96bf589b3c7f408e,
+                0x615e8867cbe7ec83,
+                0x770008748cde3e21,
+                0x9fabfe95c8072ec1,
+                0xe8f25fb9a923408a,
+                0xe80a2ee2df0b7c00,
+                0xa8a4d91099c8c38f,
+                0x1224238e451c41d5,
+                0x24c54be221fc3997,
+                0x26bcf3a3ee336ec7,
+                0x63c59b7664ca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fda074802eaae66,
+                0xbee824a1696f8608,
+                0xe978dc6e4bc92a7,
+                0x1a963705abc8a7c5,
+                0xd78b325017640887,
+                0x7319d9afa8c6ac3e,
+                0x9828fb8f7feefe80,
+                0xe1aaa6da2af0980d,
+                0xfb398d89687161a1,
+                0xda1ea941d748a3ca,
+                0xe2e939e11360c29c,
+                0x1b7af4bb62dd4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x76043150e343efb3,
+                0xff20f3089cba8e4d,
+                0xcbc2f2914508509c,
+                0x397652c3ff766d3e,
+                0xef616e9cfa0ed15c,
+                0x93b6480fbfd5cd36,
+                0xd8a4dc320663132c,
+                0xbbdcf4a4dfa4b019,
+                0x1923cfb8b93c890c,
+                0x52e8c038e5b96fe5,
+                0x74e9406183ce1c61,
+                0x10afbc7a035c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x75058e59f5f01747,
+                0x99709b2f31579f53,
+                0xb73ddd55701c938d,
+                0xdf612607cada3374,
+                0x86cacaeafb7b3745,
+                0xf7a98c2c3adf920b,
+                0xdc4e22c1b0efbce4,
+                0x9ca064130399d3ca,
+                0x69ae1942607f3873,
+                0x6f206ce124b54cb1,
+                0xf0abe5fcd984ed1b,
+                0x60185b6b4e47
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x266db7b02b068eb2,
+                0x6a4011bc956983c5,
+                0x5f6c991aeccb9a7f,
+                0xa7ab3781d421bc8,
+                0xe83d065902e4a77a,
+                0x24d7675329d8115a,
+                0xa6b1f69c3195eb60,
+                0xc76df862c6fa0d1d,
+                0x63c4922649050ebc,
+                0x8a1207732e0cc6f0,
+                0x947aae7a1ce6c0a7,
+                0x1929d9f5f6325
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x141ce5618f31efb2,
+                0xf14904fc6632e783,
+                0xdad4727958ba61aa,
+                0xe226610c0772924d,
+                0xe60e4540ffb54cbd,
+                0x5eb948db7993b9ee,
+                0x25a8505edf9348ae,
+                0x61be6d31a96c5c70,
+                0x34ae8e7e8233b22,
+                0x1c742dea3159b370,
+                0xe008a9978406b7ae,
+                0x47323f3d5a60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x354018c0b2078e50,
+                0x24d597f5a5f62387,
+                0x9f733e20733dae7f,
+                0x86ca16318c674723,
+                0x3a612cdd2205ab50,
+                0x1bf3dc5429bbe95,
+                0xf1443b4f3fda728d,
+                0xc22fd77dde51bea4,
+                0xafe9abb1acd69df,
+                0xb0ef128373dd2f21,
+                0xeb2348a63787b2c0,
+                0x1c34b160d4913
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7d3dec6b4de48e57,
+                0x553a999eb5ee3e54,
+                0xc0d28b0c5b0294ba,
+                0xe50d03fca1091fb3,
+                0x82527efc19cf61a0,
+                0x3e4dc83cccd16ef5,
+                0x1a7e418bd44fc603,
+                0x507ceebdfc1fb484,
+                0xba030ff50d358258,
+                0x8032292f302599bb,
+                0xae45f4f231a48b06,
+                0x172412cb0f284
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6cc5c0fe0fc0e0c1,
+                0x877697ebf1279cca,
+                0x707570025815f687,
+                0xa2527d91ba6824e0,
+                0x7f3c77bfcf3f279c,
+                0x6f6173b2b330ffae,
+                0xda32458ff8518f53,
+                0x7e8442af35814190,
+                0x719848e9e30bc9d5,
+                0xe4fc7ed9798a9035,
+                0xf66b737fd4f973e2,
+                0x124c476e86a34
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1011d43fb1c7bdf1,
+                0xc903a9d4f8a427bd,
+                0x40b2636a97b85c56,
+                0xa77dc5ed15c2de70,
+                0x619a3eda1966299d,
+                0xd13a76400304f385,
+                0x7df3064f907c68ab,
+                0xa9b4caf1b204cfc7,
+                0xb8ef3239ff6803ee,
+                0xff55385842cabfdc,
+                0xa5308a88ad8e0b4d,
+                0xd8234bfebd09
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x402e29efbe905df2,
+                0x75514050c77e147,
+                0xb468febf64751c05,
+                0xdb734e14504f4eee,
+                0x6d6dd7a1e65c7d56,
+                0x2c658d447bd6c69c,
+                0xffe7d176ad19346e,
+                0xd1a5e39019a0677,
+                0xd0d905dd16adf00e,
+                0x2c305cca9d5ee9b9,
+                0xf2eca5218c63fbd2,
+                0x16fdf79b4f896
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x989192f4c58b3f88,
+                0xb0872e34bb74cf66,
+                0x9a8629762dcda302,
+                0x7b822e9bc413a65b,
+                0x2730f65935dab51a,
+                0xb95ee6f72f663933,
+                0x28896362309a06be,
+                0x38ef9adbb06eb1fd,
+                0xb7ed69ba89c0076b,
+                0x268cf668c48f902a,
+                0x6d02d0510fc2c385,
+                0x364275a2d6d0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5fba6b0ba6369ba9,
+                0x3cee8936e33a48ba,
+                0x3c06958851dcd24,
+                0xbffd0e29ade636d2,
+                0x42fa5f5cdd614e5f,
+                0xc96e0d670b7f5602,
+                0x3654a3f7e9db1e3c,
+                0x33d6a89c91fa8efc,
+                0x5bf8b8f7beb9d6ef,
+                0xd322e94b801cc9f1,
+                0x765e3a1e5c6462ea,
+                0x1792ae1eefc2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfdc76ad4dd5ee256,
+                0xe8541c5de9ce2a6a,
+                0x3d58a6dc3936a60c,
+                0xc8a01a64f42dc68c,
+                0x7a0cfdb9d57f8ed3,
+                0x8af2a079cd1c08b2,
+                0xf67c2888a056b1af,
+                0x700638a56e6f692a,
+                0xb40e695849f6cb40,
+                0xa897cc5f1be83b87,
+                0x9f728f422f215197,
+                0x531e38dff7c4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9cb88d3a39674fea,
+                0x30cb4719e5a417a8,
+                0x39f3420f7cab496a,
+                0xd0dc6c7c43972d83,
+                0x699b075cd43faf81,
+                0xd245e528ab69b7e6,
+                0x1705b40650045592,
+                0x232f2cd5d3310d99,
+                0x7e710fddd45427a7,
+                0x93e47cddc8546eeb,
+                0xeb90958d5efa4ac3,
+                0x541ccf8cda6c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2a17b1d0963963a1,
+                0x765ebf523491008b,
+                0x9e54d691010f28e5,
+                0x360c59cefaf26ccd,
+                0xbeeacdb0594cd0c8,
+                0x1388340b7ff57009,
+                0x94882c886a3a858b,
+                0x2d97aae54613431b,
+                0x11557f1ba795c561,
+                0x86744045e0df329,
+                0x4c2930d536bbcc0e,
+                0x16791a6007435
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x65a3e3936aac8843,
+                0xbf51b34d7deb891d,
+                0xde2dc30b5d60a1cb,
+                0x8785c0692ab78bbd,
+                0xd8691fef79ca6498,
+                0x6289a49648865b38,
+                0xacd6aee8b4a5f873,
+                0x5235dcd293f02ea2,
+                0xd86b75c1626b96db,
+                0x6387b6c8367ce52e,
+                0xb6cae2933ce24b24,
+                0x1548af4361fd0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c71971d8a4dd7b5,
+                0xfdb7ae106b69560a,
+                0xa5345d91c283b6c7,
+                0x7a057b091877d932,
+                0x2155f65fb0f6cd12,
+                0x9f69c48474a2b3d6,
+                0x2497921d8a2540e2,
+                0xe8c696b42247fc36,
+                0x26411e789503f575,
+                0xe344395afee18fda,
+                0xa46bafd795a4f8b8,
+                0x42064b6ef2d6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc15def464ab16d68,
+                0xf7f9dda7238b2f28,
+                0x7534bacfd3bd97df,
+                0x7be732be293218b5,
+                0x164bc161f6b8bf53,
+                0xd940cb7d8d1b72c9,
+                0xeeda32da457c631a,
+                0x181b17291a65378b,
+                0x79c75f933cc71d9,
+                0xc73bcbe960af2799,
+                0x966e26929af83f76,
+                0x960cb8ab90b4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x869f3a63ded78e53,
+                0x7c797364c953c96b,
+                0x1052d7dcde571bfd,
+                0x71765111e0f8ec9a,
+                0x863cd5a9412fec61,
+                0x7d56d849e43c344e,
+                0xf2adffc11ca024ad,
+                0x87b1f2c05866d871,
+                0x6d3c49464099187d,
+                0x517ca1bca993c88,
+                0x3711e7afe6a6eb28,
+                0x2fd840ff3cdc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x58341893f27e8533,
+                0xae3985c404df192,
+                0x3d6654695e90b764,
+                0xb1e7cf1cad0ea48b,
+                0xdb172385c289a11b,
+                0xfcadde199af5533b,
+                0x7a132131a44dea0a,
+                0xf75947359c3f0fb9,
+                0xeab100167d8617ee,
+                0x256c48b1d1522419,
+                0x9b833caedf659b20,
+                0x1833d53c6de3c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xac70c6f226b786aa,
+                0x89fddf5664daec0a,
+                0xb888be4fd2d1b7c1,
+                0xfb83a324436b615e,
+                0xc7a26eabd524fa7f,
+                0x516c1e1ba1a20eca,
+                0x796975870a729339,
+                0xd896633882a9a1e3,
+                0x39f3031db413bda2,
+                0x195405d76e2d8024,
+                0x2df2c07268234b8c,
+                0x18097c63f1046
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfdf8ba260456c23d,
+                0x89d2bf1485af36b7,
+                0xa8449ad1d7817a50,
+                0x4ef60a9af39b8aa7,
+                0xac3739755c16c13f,
+                0x5934f55b1b45b2be,
+                0x353860e46d353e83,
+                0x33b378f36a11cd5d,
+                0x2628eb4c333665c5,
+                0xfdc61b8c80c26355,
+                0x432c3884c79cd623,
+                0x73986501f660
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7135bdb0228cb2ee,
+                0xf461b9eb52ecd464,
+                0x66f168dbc2dab29,
+                0x944aad3bf205a5a6,
+                0xa63de1e7385bb353,
+                0x5aeea4fb3721a299,
+                0xe7855726b4e0823d,
+                0x6c758db490dd0759,
+                0xd3d62e7d14b16d96,
+                0x51a9c1ce086be8d9,
+                0x46ff05197b0bf70b,
+                0xdb3a5695cfa1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7c23684cef053584,
+                0xa1fa65adaeddd392,
+                0xd29358e7d871e65a,
+                0x7e29cc78da552bbf,
+                0x56d5ab6d7763af2f,
+                0x456dd98675675ea4,
+                0xf5159be737d96f01,
+                0x96a29788471412d2,
+                0x20f1d77adc1af4f8,
+                0x8d16c1217bcb77b3,
+                0xc5f99289b1801563,
+                0xe62d0a90983e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2ce9f122a0e7bbf,
+                0xdd900c7c13527614,
+                0xf96337a9e6fa55e0,
+                0x9b6512886649cbd2,
+                0x715c4692241e7a8e,
+                0xd9ea2ee95a0bdee7,
+                0x8c009d07fa5d7044,
+                0x678b8caf3d9c655b,
+                0xaf14dbc75fbdb2a7,
+                0x97f7d380e910b3cd,
+                0xdeeca5cf40f78e06,
+                0x34a3d829b00f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x832692687ee16142,
+                0x778e56a3d4c7ad8a,
+                0x16541f09016b3000,
+                0xe337d46d09ab0ea8,
+                0x2256bea240ecb981,
+                0x6ffe5baf061aa068,
+                0xa02592d21bf0acc3,
+                0x98fa05474522a6a4,
+                0x158679b7a8a7c674,
+                0x18871ff3402917d0,
+                0x30f829ef640009d7,
+                0xf045aa2e6d7a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe22cf747a3b853d9,
+                0xe53f8e5d5f27725f,
+                0x3ea4995e855d43e,
+                0x59e82ed8686b9605,
+                0x2a13e8a78a01e5c6,
+                0xaf81562f1cb48231,
+                0xaf3c828f3d5aa251,
+                0x86997fbbec78af41,
+                0xaad7ce006108b812,
+                0xd529da9057ba70f8,
+                0x1ddf200264afe703,
+                0x22fa61b44624
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66b8d8b8e8d72ab5,
+                0x460d3a76084a0a39,
+                0xcc1b0d7977bd7a81,
+                0x9fceff38fcbc3d27,
+                0xa0be7aa3455d8efb,
+                0x5838671915cdb567,
+                0xdfdc89f546b6bad9,
+                0xd934f357372d9764,
+                0xb0375d89c0a785e6,
+                0x49109123dab60884,
+                0x6775acb82a5e97ad,
+                0x179b00ebe9d43
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9226e57091c95153,
+                0xa8c7ae0a786d41bb,
+                0x6f4410768c90984e,
+                0x1956124821c952c3,
+                0xa429abadaf64ad02,
+                0xfe2bcd64bf2b9b5b,
+                0x4f1df73a37f5edfc,
+                0x75a1c4cbd9883a8d,
+                0x841aac1cf1813178,
+                0x938bc505149c040e,
+                0x55fadfbf3d072e6c,
+                0x1409b59ed305f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19b409a3f0ec7994,
+                0xa619e7fa6eeaf98f,
+                0x6bf50d5a77f5edf,
+                0x3e9808c634a2e5eb,
+                0x7138b71c3b70c0a7,
+                0x3da69e3e486d2c59,
+                0xe44362c4d115ed76,
+                0x5631648451f5b744,
+                0x8f5ee9550e049b9e,
+                0xaf25a50cf52f67da,
+                0xb8885165ca7fb1de,
+                0x10b19bde90566
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a59a254e42b8759,
+                0x6afe286fe7af79ce,
+                0x52f3ef0caf1c35c3,
+                0x739cd7dc19a585b9,
+                0x126413a02fc1b642,
+                0xabba29312988df0d,
+                0x3c6acf2d3d4bbc21,
+                0xdadfbe42e752ea,
+                0xf68d1c53da02d023,
+                0x29e843cc3b36f0c9,
+                0x1bc746141e1f2f87,
+                0x19dfd02b4badf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x748d553eeecc9477,
+                0x79f023e4a184cce0,
+                0x83a8a977d541b32b,
+                0x8c8a9432ba44bb4c,
+                0x5ad8fce65a478191,
+                0x6c36bf3bd88e7fd0,
+                0x6ce3948c71f6ec69,
+                0xffeec782ed849212,
+                0x7bea7d67cf8aaa22,
+                0x259e33a346dc0dd0,
+                0x1874cd56269903c0,
+                0x1a562ca391640
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9036935978da77f1,
+                0xa2377181ee8cdd01,
+                0x63c330e5158abf12,
+                0xa6a604931f180ef0,
+                0xdaa64b35734bc023,
+                0x954c2f44bc13580e,
+                0xb3d50013171743ab,
+                0x4abcd38512f2efd6,
+                0xf1d0ba9823e6e530,
+                0x5e9e3fe35638921b,
+                0x817340f82feca557,
+                0x14e12c2d878da
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e808b6ec0d4a850,
+                0x93a2da8ddcce0d79,
+                0xb71820b842f6fdcd,
+                0x496bd0c215e8bd6f,
+                0xe4959eb659351e70,
+                0x2fcf766e925af38f,
+                0x34b6219a6bbed00a,
+                0xe88c91e22716250c,
+                0x3c9f94e272289c6f,
+                0x5e41d53525ddeb5b,
+                0x69de96d6e50830e7,
+                0x11cbfddc13d9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x697cddb18c8089b6,
+                0xb3c12ea84085d6ef,
+                0x597560f2f4361531,
+                0x423e7afd7a559c28,
+                0x16f25c15d8886343,
+                0x7e2089130e5e3b60,
+                0x50a1ad37adc01acc,
+                0x14fa6be5f704ed4c,
+                0x6e794c863759c397,
+                0x63407bdfa9cfc96a,
+                0x93b1b38f685d278a,
+                0xd27d9f8797d2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x770e707a45752d6c,
+                0xfde7dc2dde3839f,
+                0x943593e573adf3b,
+                0xb657c21cb5b50dab,
+                0xe6ae2a4a6636ebf1,
+                0xf25d5e9432b68b0,
+                0xeffd815420204b2c,
+                0x2671e9d1f7812965,
+                0x399438ee669c434f,
+                0x7992072b85ee8552,
+                0xa377ef07eb5f194b,
+                0x1af309dd7c184
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x14f82225731f8a2e,
+                0xab03042573e7fbc,
+                0xd0c2f871db31ac91,
+                0xe52ef2669fe1a5cf,
+                0xf65217aa70240cc5,
+                0xd1ecda17fb153396,
+                0xa97e72c5ced9274c,
+                0x7781ff529b03c3a4,
+                0xbe467e13c7fbc0e7,
+                0xc3ff74466a3fb435,
+                0x3fa7e22dd1bd15b,
+                0x17dd1ad73f915
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6ebd5fa76272922,
+                0x313c122815387f58,
+                0x7b66e478fd7be864,
+                0x702e8bda7972fe38,
+                0x23607a7235bdd1fc,
+                0xd80e2f1b72362533,
+                0x5c35c7743afd5da0,
+                0xa3cdbf40ae30523b,
+                0xa577a0f1c0ea57c0,
+                0x13fb38d7b0e57c3f,
+                0xee9a8517dcb7427c,
+                0x13eb5bd622f9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x966b9578c61a0a60,
+                0xe797c70fa2db110,
+                0x7a59aab147207d2f,
+                0x5e90d0936ec5994b,
+                0x89be03a979b6432,
+                0x4102522180bdca04,
+                0xd5077afed432673e,
+                0x7316eccc422dec81,
+                0xba8d16dc78f8b7da,
+                0x2f284af065d1689a,
+                0xb94e0bc809a86f83,
+                0x5e54eff4dd98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa1fa4d56be2ede2,
+                0x6eef954cb053b6c3,
+                0x8128616e4d785884,
+                0x44c448ee620136b5,
+                0x6ae7515dac0d70be,
+                0x7c28ef4968b8a831,
+                0x47a83875e71871cc,
+                0xb09fef638f6f2d89,
+                0x447ac47dd0fc943c,
+                0xf53c3e5615c93d6e,
+                0xdac4b1148f88474a,
+                0x1110c36acdc58
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x613a3ec3ec643e81,
+                0xd4af6d245d4eaf3e,
+                0x38912616442dffb8,
+                0x27ae352dc182a89e,
+                0xff63366a89867b5,
+                0xb365d379549b50b,
+                0xaf5d9b2c2a07ba0f,
+                0x833306d1e9d3d8e7,
+                0xdceba8b4722dd454,
+                0x33164db5d98af574,
+                0xe33a7230ca506bc,
+                0x2ee2f9288536
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4a3ae9170aa6f867,
+                0x605d16913c990d85,
+                0xdc9381fdc8885a65,
+                0x3af4226b8661c79a,
+                0xfa6b608b66b6e80f,
+                0x6a48db9815cf41fd,
+                0x4ffb88fac3ff7b8b,
+                0x795ee49b86d3f2d1,
+                0xde00d43065b5bc73,
+                0x5c255ca873ed4a4a,
+                0xc804754fd4242cb6,
+                0x688bd7951af2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c94da368b328463,
+                0x38ca71069c52c1e2,
+                0x6a90c971a7a8f92f,
+                0xeada3d8eedd33b5f,
+                0x9c5c06d688c1193f,
+                0x88e92a00b390caeb,
+                0x86c2bab83b0e42f8,
+                0xe2df96895e825177,
+                0x9ed1774a30f00ace,
+                0xe14a245dc4147761,
+                0xdddfeed72e140e3d,
+                0x90e57632764c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x15395f081d415360,
+                0x1085464a9fd9de95,
+                0x3662aaafd7d81acf,
+                0xe9f545433184bbd9,
+                0x7ce2506b15c6ee0,
+                0x2435281069013016,
+                0x236d04149bd64525,
+                0x84e5b10c0abdd410,
+                0x1ac48f508192c1c0,
+                0xd6f8827c33ca1391,
+                0x19ee834b06e006ef,
+                0x8562c10fa742
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6f351dc8e113eeca,
+                0x40dc21ac2133bb84,
+                0x55d01268c77a9a23,
+                0x99043fe1c640d932,
+                0xca1a98e615c7758,
+                0x4a36bac87fc9cc8d,
+                0x180cd47b59ca8782,
+                0xd0c8ab63dbbefe80,
+                0xbcc5edab0d961b24,
+                0x78add95fb5145c7e,
+                0x52a855bcb19292f5,
+                0xbc1704ccf3fe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6cebdd3d4266e707,
+                0xea2b8c8e125eab85,
+                0xc1bff5e2da27df09,
+                0xf518a87d484323a1,
+                0xecfda98d6bd06616,
+                0xb3440359a0b473cf,
+                0x4bcc633b121c4dd3,
+                0x3684c71c5ab5e5d7,
+                0xaeb08f1fd44bc032,
+                0x297c9dab2fa0e2ab,
+                0xc8d83b26e362128f,
+                0x1458c14f3f369
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa93c88421a8754d2,
+                0x48b2f7a64186b3dc,
+                0x5105f7c1aa4c4027,
+                0x90a6d88283bab74,
+                0x1b0c4ea86123d2e7,
+                0xbb0f214b0e55d382,
+                0xa034cf9f93ba1c5,
+                0x8f34fc533397e765,
+                0x143ea009aaa21b0f,
+                0x298039f33ba4b159,
+                0x8cb8ae1f0bd4318f,
+                0x8222e1b1b868
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf423f6c5f99a3faa,
+                0x637617fba08d2943,
+                0x95947f3c1ac73d3e,
+                0x6434b5843f2d44b,
+                0x7195207aba83e900,
+                0x63c57d8a1be314d0,
+                0xdfcb61c089d897a2,
+                0x3d32e5110fac884f,
+                0x23ba235979ecf1d0,
+                0xc3d4c9422760971a,
+                0x880b319fc380b9a1,
+                0x769ca4c6be48
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x80f60c3322900061,
+                0xedbe7d9d273b78bd,
+                0xf513762bfc6c59ba,
+                0x55375275e79b4e22,
+                0x5d622f45e96670a5,
+                0xe02f490b7ca214f3,
+                0x212f6ff253ceaffb,
+                0x6f31b246ea6e040b,
+                0x8e08da3438b97bc9,
+                0x87093de2a256333d,
+                0xb67918f36854a22c,
+                0x142d911024630
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79c7a2d490188a45,
+                0x8bb3662b3befe295,
+                0xd1152adced62913a,
+                0x288fcc24f8d1c1eb,
+                0x4969c9316cebba97,
+                0xdd92cd08c1d2d531,
+                0x47899eb74af5e395,
+                0xa311a7b72cf7d7e8,
+                0x26cb4b41ab0f8aaa,
+                0x5f65247d9f091d1d,
+                0xfe5b9c9d488c8785,
+                0x1245541e41c8f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c41882c94a4ae3b,
+                0xc22d45d10a837c9a,
+                0x5b3a5319fef286ad,
+                0x74b317de13b201b0,
+                0xea39544c99b1cc02,
+                0xb580b1ba13e079f6,
+                0xd5c4c762ceb6d10a,
+                0x5fc0142fed155f59,
+                0xf3d6cf69eec13594,
+                0xe269629e5cf38bd2,
+                0x2c8bafbb1391bdc9,
+                0x14e07566cca3b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeac31a3513fca13a,
+                0x1edee1d37bc6c6d1,
+                0x8671a1de7c8011b0,
+                0x3e7d422f54b1e507,
+                0xd5fd0f133067b826,
+                0x31c95271f8801c69,
+                0x57c2665de576d69e,
+                0xb87246719e567b3f,
+                0xc9d4566996762d8f,
+                0x9ad5ab88bd2156a4,
+                0x5a439491c5876fe3,
+                0x6d6641bf0dc1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x217df381d1ab284a,
+                0x94d38c8da1e2e98b,
+                0x40552bad1365f54f,
+                0x248a4f5751818e1f,
+                0xb78f084c8f6e265b,
+                0x4525bb7de464a69f,
+                0x272ce5e6d1daa692,
+                0x49529ff9fd63069a,
+                0xb126b9c05431b519,
+                0xadd56338adc790fa,
+                0xaa4c80dc57a0c05f,
+                0x64518b93d46f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x72bf35dcccbf4e83,
+                0x442b43c85294987b,
+                0x790076e2b953de8d,
+                0xc817ade3b08700e1,
+                0xcd2991040d3f1608,
+                0x3d64eee0daf4e49c,
+                0x96bfaa6698fef8d0,
+                0xb38a7688ac06de10,
+                0xfe7b06fe1ca5394f,
+                0xdcbb3482bf45ef3b,
+                0xec6c3d358d86f544,
+                0xaebfa8f5ce6c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e65eebc05496940,
+                0x61544593e49755f2,
+                0xa0dc952082f06008,
+                0xe561c01b11f9d55e,
+                0x99e7594c35b99280,
+                0x9df4067d3f2f5662,
+                0xad6b0ce3ef58bd88,
+                0xb581416b37735159,
+                0xc5a05d382ee2d4c,
+                0xa4d99f1676431301,
+                0xad6005c690f6b983,
+                0x120b9f3630157
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ec66ce01a3948fa,
+                0x7d9d47e10e798443,
+                0x831266407362cd06,
+                0xf5d4e98c8dda4671,
+                0x4bfc8dd66933e576,
+                0x55f8ab45cfb2e462,
+                0x44c8ab0e319f4b19,
+                0xec3a19c3e954a7e3,
+                0xe80e99f7aafa222,
+                0xbd0939e5e75a9309,
+                0x729d21acd18e1a65,
+                0x72176e586a97
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1710064a4930f980,
+                0x196893774bffcb9c,
+                0xb33f3e51b29dffce,
+                0x87b643bec5e7d9af,
+                0x33ef0418991c42fd,
+                0x9de1a7fc774f36ec,
+                0x8d6391368f3e9504,
+                0x3503ef0a8cf53eac,
+                0x848b1cdfb4b538fa,
+                0xae258ca622b183b9,
+                0x60d0ea6a2088d26f,
+                0x2aa9a3ba20bc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb117c1efe1d4f45d,
+                0x146cd758397181b1,
+                0x66d1f9b622ee215b,
+                0x1f7f306fa13f8925,
+                0x80688d4c4bce50f1,
+                0x5e0dc81e4c186156,
+                0x561ff1db22675838,
+                0x8ca467164805bff2,
+                0xa2ff0c24b0af5dfe,
+                0x51e2d8f9f9e37a1f,
+                0x75a0f103514a8cb1,
+                0xa18e05bb76ff
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e056ba555294f55,
+                0x49a886e64bdba70a,
+                0xef884058c83274ca,
+                0xac2be41fd2657073,
+                0x86f450a57cb0d898,
+                0xa9ec06cff06417ff,
+                0x555fd3a7d17163ac,
+                0x43994854ff545bc4,
+                0xa9ea5a364d6ceb0,
+                0x6a484ee7534f8011,
+                0x20a163db12007dbe,
+                0x1b6cbca634148
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f744f4a8174a1db,
+                0x7015e96e383c48dc,
+                0x3dde73a83a27fd58,
+                0xcaa97321349cae9d,
+                0xce1364f7fed4a89f,
+                0x84ed2d5203ca0893,
+                0x36fe9c5861fa4f9c,
+                0x49f5b71643f5e84f,
+                0xa064a4ae4b6a3f62,
+                0xe32d63b949475af6,
+                0x38f6473fcf0b194a,
+                0x68dbb7e58f0f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x792945cb34f2084d,
+                0x2d32901f67022824,
+                0xcdb780edefca54b6,
+                0x5967925263d7990a,
+                0x8e10755c8c224a0,
+                0xd1c9b6000a954149,
+                0x2e988262c2efbc00,
+                0x6300a6c8483567d3,
+                0xc987f553c81e00bd,
+                0x407bbcca05576125,
+                0x39252567c13a2344,
+                0x106fff57837c3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x403567424bf13b40,
+                0x906a80501c4a15d4,
+                0x63f35956da0fc36b,
+                0x46cd73368b318c66,
+                0x6bcef9a4ee6c0572,
+                0x8a550c9a323fd79c,
+                0x7794acb7123ca30a,
+                0xa715f5f32ae66e6e,
+                0xb4d5f654ea2951c7,
+                0xefc10abd30867c51,
+                0xbccce8c15075b230,
+                0x16525ae9df814
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x42b96ae41119aba6,
+                0x287e80d52c00406b,
+                0x2bd06375a1e36db4,
+                0x643f39d1e18f0880,
+                0xad46945e509e62c2,
+                0x9196be5b36ca691e,
+                0x2967648fae63f20f,
+                0x96ded377d3571d6,
+                0x155afb58caaa7269,
+                0x8ec1713c1034812e,
+                0x3f3682cd1732f4a8,
+                0x5ea8937692d1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0a6fed3810ec2f1,
+                0xd0745ed3cbb86848,
+                0xdb1d9045c18e714a,
+                0x8f6fc712152162fe,
+                0x5ab89b0f11051297,
+                0x35f45d57c11ae78c,
+                0x6d3cd641f9ec404,
+                0xe96286f33b4e56a9,
+                0x1ecab096e7390b37,
+                0xce83bd9f6f32f7c,
+                0xbc633a4a49e7023b,
+                0x1ad259588ddaf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8bf08dd58213d99b,
+                0xfa4926ccfbc044e9,
+                0xea9bfe8bd2d7b877,
+                0xbddee3ae42f46fb6,
+                0x61f09352a053610e,
+                0x2991b1b14ca7dc2c,
+                0x7f54654b6775a166,
+                0x4b7eb840c3b93849,
+                0xf486959f7ac70a27,
+                0x47f09310d7617636,
+                0x4931a20606cd9b5f,
+                0x1735d12b5e5e6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9da87b9b27362e79,
+                0x9879b1b8561d53fa,
+                0xfef42bea3f8296d6,
+                0x2208f9e49aeeb87e,
+                0x7b57bdb0315f60f5,
+                0x59a5e15da2840c3e,
+                0xebda99fed818deef,
+                0xc285dbfea5fb7dc8,
+                0x9cd8d00383eb7d19,
+                0xa5a931fbc4c901bb,
+                0x14ea974c518a986f,
+                0xe59a9df2a8cd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf0424a7489b5520b,
+                0x177efeab057cede9,
+                0x2dcb5fe2815b5e35,
+                0x4492269dcb22b743,
+                0x92db77362b76fd9f,
+                0xb7f22f34056c9bf4,
+                0xf1ec850374d7a2e7,
+                0x7bcef8af9158a26e,
+                0x1b762b773ae7a4ce,
+                0x567cc2708664b6ca,
+                0x394dc61bc770dcd6,
+                0x1b0f531e58385
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd68c4a319a14525f,
+                0x9a78bc416d889d3c,
+                0x945c5fd737568817,
+                0x729f82ddfbe42927,
+                0xb41a7d5aad6b3c66,
+                0xd963bea6a87ac5a5,
+                0x150a0ab0ef5813b0,
+                0xbba2ecd74c5e3743,
+                0x159b91c03ae2ebdd,
+                0xe510f81fa22f0aae,
+                0x652ebc3f37d577d,
+                0x73237ee17150
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x354c0751849488a0,
+                0x77366608f3843ece,
+                0xdae5b21300ebb7f8,
+                0xf2677149800d45ec,
+                0xaf3defc1aa27fa55,
+                0x11d13ee1e4c47af,
+                0x3ecacff03eabf55,
+                0xdd15805a76696076,
+                0x27c24818d9bf4760,
+                0xd369d6ba7452793a,
+                0x194a7c48f42351ca,
+                0xa3825b9f2c70
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x63c01e14f7615d72,
+                0xd00cc1fced6e16b5,
+                0x3fc08ae176f1ea5d,
+                0x665c23958860030c,
+                0xfd3063584960f5b0,
+                0x80e8b3f54b4cf0e5,
+                0xf1036b630e26f8d3,
+                0x6d21ade71e0e26a7,
+                0xb31a89a4ef4f48f8,
+                0xe38270a0d614ea6b,
+                0x6f4965c580b4cb37,
+                0xf9fb645e2381
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a55438ede37781,
+                0x34fdfb3853be8cad,
+                0x1cc5a99251504035,
+                0xd196e0a1bfb7ce50,
+                0x8406e8ccda033a41,
+                0x7bd8def7911bb707,
+                0x2685fd115e3de5be,
+                0x4116e9f0b0b10f82,
+                0x9cc6fc2615bf9697,
+                0xc18cfff5caf4de0,
+                0x54ca9c44a039a68e,
+                0x43bdf91d1b25
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa00aa7b2cf1601c6,
+                0xd361cbe6da66d56b,
+                0x8712764c086a1081,
+                0x36e352558a671437,
+                0x60143ef5ce1b0546,
+                0xda75c77c69bc2dec,
+                0xb3816100dbd7a17f,
+                0xd8cfff2fa7f22bdf,
+                0xb976920c23815f2c,
+                0xc29527fb6710c0ae,
+                0x5f1e93e41d2fd56f,
+                0x10e0b8ebbe1e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x86b68093512bbaf6,
+                0x4a379aa145aaf10f,
+                0x305e7baea84e5120,
+                0x5f5dfd3949f6fad,
+                0xa88d0712369fab0f,
+                0xc5dd16c1cd4307eb,
+                0x6abb132daa9bf9bf,
+                0x7f63e1fdc3b90624,
+                0xd8ccefcbc79d458d,
+                0x8e21af77310515e9,
+                0x664970422dd587e1,
+                0x42a923b6beba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x88c72222fb2a1a86,
+                0x8fc92be3fa2ea1ea,
+                0x1c335835c0e5bedb,
+                0x3ead264db75b0d97,
+                0x780304490b4cb735,
+                0x8451383807f2f383,
+                0x6b2e4bb65d088702,
+                0x2c998b5d428fe80a,
+                0xe9dc5965658740b6,
+                0xbc99c9284a864983,
+                0xf7c95fe6873f4efb,
+                0xebc58255d22d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e20d4c05ff47efc,
+                0xa13b97fc0aeeef79,
+                0x83d08e3b1ca3bffa,
+                0x7443fcb0aa3b89cb,
+                0xda177cfa22595c7d,
+                0xe739ad9f5f7cd89,
+                0x23a61a5a748e2349,
+                0x1efcceac9afbf43,
+                0x4b7729908b6098d6,
+                0xf9fc80be010924c2,
+                0xce1407c70382c77b,
+                0x8f746c86be9d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x907142d26eb0b4d6,
+                0x65be76db70b60647,
+                0x920c196167baaae4,
+                0x9b13b604e545b4fd,
+                0x6eb9d9c852349ac0,
+                0xb00062ce92c2b64e,
+                0x2f4171c72942060e,
+                0x4f1d18288c8be2f4,
+                0x6229afcc187ef52c,
+                0xba8ed93b9f9ff4c4,
+                0x16ccf8c25de7989a,
+                0x1abe8abea97ed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeeb90feb078da0ce,
+                0x14d6056110b0fec6,
+                0xa48375333f3276f5,
+                0xe444153fae5c04fa,
+                0x6c2f82eb885108f1,
+                0xf2245dc422c759d5,
+                0x4799bcb856bbf0,
+                0x45789b796a555314,
+                0x66ab2dbdcdcd7aa3,
+                0x9ff78da013c34a24,
+                0x5b75b94ab17c87bf,
+                0x3e77100d4020
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9609d8b03f29173c,
+                0x6bc7363a38ca879,
+                0xc4161ef790164c81,
+                0x604922c8ad212d27,
+                0x8adc9908c464746f,
+                0xb0d45a929f6f6048,
+                0xcda79bcee4aa41d2,
+                0x4fe984b99bcec38f,
+                0xbd9fb299da18b67e,
+                0x2c27f45d87a14e60,
+                0xef6f67e6f1f8f1fc,
+                0x1fe741d8d2bb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5d5ee0ab09679b12,
+                0x2d5b9099e1de16e5,
+                0x4bd18c73ad83294a,
+                0x4e19f91fc0b9a3db,
+                0xb3c42d179d9774ea,
+                0x81f534dea64adf37,
+                0x367744d318b27c90,
+                0x79013d813cc5e7f9,
+                0xbcc55230eb35d8,
+                0xbdf095d3871c5e05,
+                0x3deb57b132e64766,
+                0xc760da23aea1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x20c56f179c0ff556,
+                0x5e49a6b3c55fea3c,
+                0xf453e2759620b063,
+                0xfd704a75c090230b,
+                0x873dfdc8e723aa5,
+                0x4a870ea759826e87,
+                0x473784e2e6e7d917,
+                0x3a074839c77ab20d,
+                0x3dae550e508cd740,
+                0xcf8acf1aa6b980b8,
+                0xb28ac029d02a9b73,
+                0x7620f9475f07
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb92b640c5262feba,
+                0x443eebdbe424576d,
+                0xebeef82838a36610,
+                0x62d27248f54b10e2,
+                0x1acd27a88dddff04,
+                0xe2c38f6f65792b55,
+                0x5489f58557ad5eb3,
+                0x99a54a725790e2a5,
+                0x5929be66c2f64d67,
+                0xf737ced4ce2e2a35,
+                0xc9ed6789d129bac8,
+                0xa330610a6f81
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x853944b29aac6885,
+                0xdbbb0601c2e6dc45,
+                0x6b36e3fe820ec56d,
+                0x8145137932f284de,
+                0xd8e4716f4607e200,
+                0x9516c3fef17653d7,
+                0xd63115eb5519831c,
+                0x751cfcf5a3ecd8a7,
+                0x3d3944a47b73fd06,
+                0xaf7cb28c4f0158ea,
+                0x665c4ee158a0907d,
+                0x2f3c31e702c1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf403162f68d88e3,
+                0x6058c5348749b863,
+                0x9d7fcd1be496516d,
+                0x7ceeaea4addff79c,
+                0x55d98a658aa0f09e,
+                0x5f0484b79fbadcb6,
+                0x41eada379556ab91,
+                0xaa34ca33c7de0971,
+                0x2d1bd68bc405710e,
+                0xb2a5c3c6737a0630,
+                0xe03e1b73eda0ee5b,
+                0xb5a976abb66c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x956be7728e497d84,
+                0x86ef5e332a42c3a9,
+                0xc7c8021c0210c4fc,
+                0xd3c3d7ca2f7a9a09,
+                0x158ea40ab25b56e9,
+                0x3a877d00a2697dbf,
+                0xb97f65b496e483a4,
+                0x4ded71718ca8b549,
+                0xf60c11912c7379c,
+                0xcb94d89c43c58a49,
+                0xf70e4c4cf232d816,
+                0x129c4ba85a2a8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5441793b82cd89ef,
+                0xf1fcf6bc85b4340a,
+                0xfa966c18cf3c4461,
+                0x80fb76b06382cf96,
+                0x624b2d1f08a6226,
+                0xda0c243ec66ff0d3,
+                0x6524d38f987431e3,
+                0xf5c9563261deb6d7,
+                0x65d1b1d855b097e2,
+                0xb09fffe419ee20cf,
+                0xbc4473166c660235,
+                0x11fd8e7606b1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b3f61799c01301d,
+                0x5e3222ea0858e8f8,
+                0x2b1a008e737ddfcb,
+                0x1c11f24265810385,
+                0xfb17ba8838c1331f,
+                0xbdf760bcb9e9fa39,
+                0xc3f3ed4dd7e142c,
+                0x22e4dc9d561d052f,
+                0x6afc5e3a99d5ff37,
+                0x71e04851f997d6f7,
+                0x743a2a39f91d5410,
+                0x6e0840d1f3f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c0d155d9aea9e1e,
+                0x1687c3d74c011bcf,
+                0x26442a8f032b8907,
+                0x2c7dea8da117b7e5,
+                0x79f415964d8a5f2a,
+                0x67550ea88074287c,
+                0xd8f0052e6ef23835,
+                0x34394706fc5f8a2,
+                0x59b9b1e8a1b90d89,
+                0x755624ec1e9c4d18,
+                0xf82a3a2e093cb6ac,
+                0x16712ba113a68
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfaf214dcb5d61b8e,
+                0x7158f7f42068d397,
+                0x68ee3590ae10d642,
+                0x4c6eb31a2be34ca,
+                0xa9a4ffda4b0fefa8,
+                0x6eddf4ecc646a7b7,
+                0xa459661c8880934f,
+                0x1ea83564cc939cf3,
+                0x82d730cc693ce465,
+                0xb16cd42d664227ac,
+                0x1b6e4dfbb6786551,
+                0x90e7e3f8031b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa423511f14d6b398,
+                0x281f4a0a44ee1eff,
+                0x7f7d19b6ebb607e1,
+                0x6a61fa4e5fed8ae2,
+                0xa13856e1d663951,
+                0xba3cb80069db5da3,
+                0x2888ce7f96551c0,
+                0x31116262a918714a,
+                0x46f1b20a1dc3e916,
+                0x5779e9677c06a4fc,
+                0x76b6db6500d5822e,
+                0x13f1086c55f2b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x77104ddee6b7d7a8,
+                0x6a7ed969b574c2b9,
+                0x18ddb35c8a2f821c,
+                0xef3ff919d25d3ed7,
+                0xb42abe938472c42a,
+                0x9d2785ff25b97f3c,
+                0xe105714c967e8f9f,
+                0xd13146d144aa05d9,
+                0x3ec81c39ce8d69dd,
+                0xc273f43a94c48e03,
+                0x4461f9aed4fba546,
+                0x6dae97f8ae3c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa438139ae4b6ce86,
+                0x5dbcd145041a8e93,
+                0x453185754471263,
+                0x606911c3f900e0ca,
+                0x13f2968e38adb752,
+                0xe9287f267ba36a2b,
+                0x89ce792b1b9e0aed,
+                0x6a7693af4a7d57b7,
+                0xa50d1fb2de9ef55e,
+                0xf8a0420b10282a8a,
+                0x5e155e3bb4ccc55e,
+                0x1412a7848dba7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82c4426542154def,
+                0x765035d977a03f1a,
+                0xb0439da72fe5e4d6,
+                0x3ab5aa2daea73d99,
+                0x55800f91476b7f12,
+                0x305ac884cf639e7f,
+                0xe258298ebd87a870,
+                0x8678fa1921543810,
+                0xdbd841472b0e8123,
+                0xbfce905bb3b1a944,
+                0x965d8ebd4043e8cb,
+                0x9e89a8ba46d2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x71db09b869b7ae40,
+                0x3f1600c0ab725f9b,
+                0x336241dbfc54c68,
+                0x814487538f9c673a,
+                0x77b463c993ab928c,
+                0xd7adc84bf066c74b,
+                0x1e043f186ee6f83d,
+                0x4f0f724687852e67,
+                0x8a155308f428b121,
+                0x736f71c9d8c6592b,
+                0xad10e7ebe184a976,
+                0x19d4c129e39ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x161b751afd81fb49,
+                0x507ae7bbfbb78b46,
+                0x92b0c4d4671a51ed,
+                0x6974a6e8b3063ef3,
+                0x22d933ca3cf0d4fc,
+                0xf9331120d8fc8e6f,
+                0x2761b9086d09bd82,
+                0xcd17ceb09497bdfe,
+                0xfd59148027182269,
+                0x9663941db84249f8,
+                0xaa3ba5951dc69086,
+                0x14c4099d14cc3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3717575db62c2ee8,
+                0x8fec3303cf0830f5,
+                0x65d1426fc1acf628,
+                0x5d5b2377ead8ac85,
+                0x2c719315eaa17bb8,
+                0x9df040e7d1b3e058,
+                0x7123fa9d1711a02,
+                0x4b8336ef50c2dae3,
+                0x60d0ecd93c449e3b,
+                0x1935ac781eaab67e,
+                0x20be5fa5dcf6c8e7,
+                0xb54920221b24
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd1909b7d5ee3ab3d,
+                0x897b116c49144468,
+                0x33bf95e06a846bb7,
+                0xe1e2387b821bbaf5,
+                0x41c8774f1a7802cc,
+                0x19e786c082d37d9b,
+                0xa416ebea9c46474f,
+                0x28e2434974fab4ed,
+                0xcf1dcf2680ce1c93,
+                0x38d44445e1763d6f,
+                0x9cc4ea645638a881,
+                0x1971e62371aba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66cc699a692a23c6,
+                0x72c4f53db0adee12,
+                0x6b252df70c79390c,
+                0xa48ed997a22adc36,
+                0x36d4b10dca4c3385,
+                0xe47e2513b569a842,
+                0x90b41ac6c30f36e,
+                0x481e38b1d4081564,
+                0xe79df6792de39acd,
+                0x6a20d497c09a10c,
+                0xd784287a0076921d,
+                0xb83336c814e4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf76b8b9b35a6b5be,
+                0x15f2fcdd175ab62c,
+                0x7131edb0a21188ef,
+                0x4e6a039840ecff78,
+                0xe19346850d89c885,
+                0x883332a81d695062,
+                0x9808b763e8a5ff02,
+                0x682c61fdb744a47c,
+                0x5c8caf281276bfbb,
+                0x364fe42cb4f9702,
+                0x5c706f282adac0ae,
+                0x19d634f9c6460
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x665297f78349c87b,
+                0xa1a31deeb230b608,
+                0x6d0bac04242a4fbd,
+                0x5b519008d17a4d1f,
+                0x7ac7f8aae0dc7058,
+                0x347fb282bad0293a,
+                0xfa5083216e34b642,
+                0x9f4945665a442e3f,
+                0x40166bdb64d46929,
+                0x2b2c022e0471c11e,
+                0x35ffde7b7a5cfaa3,
+                0xdefffb164721
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2394f93d3bb1b72,
+                0x267a6f478f318107,
+                0x238fce00123f55e8,
+                0xb3e2a071455ad223,
+                0x15bfa3651ccf6018,
+                0x2826ed7e1bc158da,
+                0x2507446eb3ada15d,
+                0x56e5f63463263e5a,
+                0x38a724156f032bd5,
+                0x7b3c4cb12c1e2c1c,
+                0xde4b10968425eb5e,
+                0x125012e98d275
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40af47d68bc3df3a,
+                0x24aa588ac7164632,
+                0x3d7c451b6c78c99d,
+                0x628e3f75b605d11f,
+                0xca9828b63438bb5e,
+                0x18000e3f0d7df16,
+                0xe01254bd1217047e,
+                0x72c28da65d68614c,
+                0xbe70cb322fb5de68,
+                0x449dbcdfaea938d5,
+                0x57d6bbd573dd958,
+                0x44728aad7f11
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x225de01ff32f6a7c,
+                0x65a6531a54fa96e2,
+                0xc10dfef38de2a110,
+                0x990b13d56f465f67,
+                0xcb929c93b07867f9,
+                0x4de6c5c8bc5e98cb,
+                0xc0998d2a0bb2579c,
+                0xef2e2c85f0d68580,
+                0x93634605319088a0,
+                0x277ee8aed7483022,
+                0x43d66dbdc91d5f8,
+                0x42c64e77c7b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x157c22e0a6086466,
+                0x89b7dbdc2c22ab8f,
+                0x2544fa625b453335,
+                0x91e22fa8068eae6c,
+                0x4a5dfcfed7042e6d,
+                0x9101b10defa6fd3d,
+                0x9734bfbfdcdbf917,
+                0xf0a2557a86a0665d,
+                0x1cb3528c0fc2534a,
+                0x2cb52f61712c3443,
+                0x28b956f87f3416bd,
+                0x13f8dc5d08616
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4348cf9bd408f8a1,
+                0x8f330d46fd8ea43f,
+                0x5aa43f025782455f,
+                0xf6529f742b44789f,
+                0x5a198b934dbb60d0,
+                0x71c8b7a3347beeb6,
+                0x76927a58be575518,
+                0xc9b3b3153a080c76,
+                0x26c2d15911c87182,
+                0x3dc8cc50ffc32908,
+                0x80370918fccb9cf0,
+                0x72d61504f7ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4a4162ac5e777cd6,
+                0xbe9c32fe60d8c6f0,
+                0x17af1259d4bf8324,
+                0x6aab33890d87cada,
+                0xf076fb503ba3ec8d,
+                0xb19fef6041810cce,
+                0x325b6203e3a9e49,
+                0xfc38754b6e289af0,
+                0xcbc914e4c5237450,
+                0xb72d29ee713b0abb,
+                0xe4086458f6c0b84b,
+                0xda15fdbe3173
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x953abb7e0df08851,
+                0xaa9e8aa206a28a4c,
+                0x4296f721033d2a91,
+                0xdf1172d051cfe0ee,
+                0xb0118b99bba21b18,
+                0xf8e403a628d13003,
+                0x5f614f5486aba889,
+                0xcdc05c344ba59d45,
+                0xa72007bfd6f2b754,
+                0xc663faf7bf9598d8,
+                0x2cec8002baacf983,
+                0xe1e1726d18eb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef2e3f3663ff9f8b,
+                0x889e382fb95070f3,
+                0xb48020641bb0eb5,
+                0xb4ea51003fbddf38,
+                0xf2d4837851a94bfd,
+                0x224dac1179b1f088,
+                0x532ef97350346749,
+                0xae9d3741165a7ca1,
+                0xd7ddce5a84cb879,
+                0xed29e360ec0fdf6a,
+                0xe05cf26c792ba916,
+                0xe5740536077b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc06a9ee97d93c0fe,
+                0x735af1fba2226b70,
+                0x224db5e4c4be3f5c,
+                0xfb767896e20965ee,
+                0x64b97fc42969ca0b,
+                0xcfde84f501f0a75a,
+                0xa04542cea771e1,
+                0xb37b212e280c6aa9,
+                0x4b4aa72b800c19c6,
+                0xd7112d4af0b9a9c9,
+                0xacf46bf30d54a57,
+                0xdb8d529e763
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe4351930c3c52306,
+                0xb31fe35b3d726e42,
+                0xf91b3ce6ab828a24,
+                0xbbe2df1cba88a662,
+                0x7fa91b1aefe5e60b,
+                0xa7d2fa40f445d7e6,
+                0x493219c5b5d750a0,
+                0xba29acd9c14bea3a,
+                0x4fd96390e484d28f,
+                0x8e28882e7746fe0c,
+                0x6b8194554fe961c1,
+                0x143390ee72259
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0fd0ea86060ea4b,
+                0x10eafc638fa99cc5,
+                0x3a1c547535cc1975,
+                0x3cb2f6f51ae2867d,
+                0x6956afc4c7a721ba,
+                0x480545a8d025dee,
+                0x337b709d862d330e,
+                0xb26e76ec6d1b99cd,
+                0x59746791a10825cd,
+                0x48ff38ea0b2e64d7,
+                0x8ef14d5d03edcab7,
+                0x8600d4cca9ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x84c14691a7c9c052,
+                0x28e74a752899fb7f,
+                0x29c17a5a4f6ab7e6,
+                0x70fe0475feeac9be,
+                0xea8a9aec5178c834,
+                0x5aeae1fc9ebb177e,
+                0x369a55652d8c019b,
+                0x35903fbd822bb716,
+                0x56bc9087ef89ee1f,
+                0xf7c9357b5e47e14e,
+                0xfa9f5175e6fac626,
+                0x629138b16643
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x56f1ccd9f8c22695,
+                0x3e84f5514e673d91,
+                0x9af6f82fd2bfb446,
+                0x7ad319951eda449f,
+                0xd1239b5ba799e61f,
+                0x4b33539675e0684f,
+                0xd3116d199aae064a,
+                0x78231dd883a7f548,
+                0x7f60e0b60e2a465f,
+                0xf3a9d725046be64f,
+                0xb80330609b979b2c,
+                0xa3749e9f7483
+            ])
+        ),
+    ];
+
+    // This MDS matrix supports fast matrix multiplication
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x818ab4a4fffff4f4,
+                0x69e4d53f56efd5ee,
+                0x8842d47808386487,
+                0xa9c83a32f34c2b0b,
+                0xeb3e2349a5cddb72,
+                0xfaf5ed7ac1826104,
+                0xcd9cebdd87189df0,
+                0xb926b13a2e3fc7c0,
+                0xa85e5ca46294e943,
+                0xa6cc744c88b48dcd,
+                0xc22fbe190e87e4f3,
+                0x85a8a3e7854
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8bc54a053fffc92d,
+                0x5156f4b21bd01868,
+                0xbf7e5c8e003de3b4,
+                0x8dc6747a5355e4d5,
+                0x66ecc7351b9df5af,
+                0x153f701ff45275c5,
+                0x1ad8b896d1caa563,
+                0xe4d266af514055d8,
+                0x23465d8377305580,
+                0xb75d55743975ed60,
+                0x691bd4d143add502,
+                0x32ea8df335e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2bbc684d7fff9c06,
+                0x1bf6946f63002d18,
+                0xa9e781cd57caf2d5,
+                0xddcdac3960aefbf8,
+                0x5e64df6803d984ac,
+                0xda8a3ab1abd9550f,
+                0xe881da123f6833ff,
+                0x139f880496fa413b,
+                0x614e076d3b022393,
+                0xd0be281e04a56690,
+                0xe081bb8100f8f634,
+                0xcd1596b74f6d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a7e5215bfffacdf,
+                0xcba9c9f5b64f9857,
+                0xedbc3e4ec48ea700,
+                0x6c3f84b58a3bff1e,
+                0x70ecd990f35654ae,
+                0xde26e5c91a73cd96,
+                0x5d17b0a1b4fdf3d2,
+                0xb543d7b0d63c77e0,
+                0x29c219a3c53fcd94,
+                0x5cc470f49c705cb1,
+                0xb6d0180bac1429f5,
+                0x88b9a716c9ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c4f2061bfffe08f,
+                0xd892a1ed93c59779,
+                0x86fdf047263e03b0,
+                0xd4ed92743a43273b,
+                0xac63a39ed4bd8155,
+                0x1f0261bcb4e255da,
+                0xc7386165c2dc9ac9,
+                0xbf95af18d432fede,
+                0xeb9bf5c0adc38741,
+                0x91817a712f4599c0,
+                0xb14a25a716fb2d0a,
+                0x7a915004f880
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e164085ffff7358,
+                0x92e2ec51924e10e9,
+                0x24bfea0277dfc5b4,
+                0x8897a5a9af5f5e60,
+                0xc27a0846e158fe6f,
+                0xa67d37ab7c67cbb5,
+                0xae98ccf05ce342ef,
+                0x3dc3e7547aaaf4,
+                0x43e6b4edb1fb0c24,
+                0xc574c131557ebc6d,
+                0x827e4e6f32ae0562,
+                0x1491ce672e19e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x13716e353fffe3ed,
+                0x99561d9434a7f056,
+                0xad4cc8234964f32b,
+                0xb5178e7956369e46,
+                0x8a57c281525c2d9b,
+                0xe7e0b30f52cb6e98,
+                0xb5727633132a5fc7,
+                0xa7eb1cbe2212e1aa,
+                0xdade6d9c550e5a96,
+                0xbecfb057d17fb05,
+                0x5ab7b4776abbbfc,
+                0x95e4b7bfe6c1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ba38bd7fff9fc6,
+                0xf634aae9ff9ad886,
+                0x70e03b999136a3b,
+                0xe5b5277e9f4ae61e,
+                0x529c8b024028d1e5,
+                0xf0e3d3a0fc155b3b,
+                0xb8cc0a2f5488c35c,
+                0xb15d62cf275143c,
+                0x92f7aec4746dd2f1,
+                0xb8b595755f7920b3,
+                0x621e3e80d37c41a6,
+                0xf70c71859838
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd53b0a937fff9e5e,
+                0x645d627c04e0d83c,
+                0x41f930100983d75,
+                0x82be5944a7d06e50,
+                0xd707aaa8698b14f0,
+                0x8823a473dfed8ea,
+                0x6ab038246c9c8d9a,
+                0xce4978ddd027051c,
+                0x60581003bee5712d,
+                0x1b8cc749d69bae6,
+                0xb1838d60e48b055c,
+                0x33abcf15cbe0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe16e7efc3fff7b29,
+                0x282f8992bd86db08,
+                0xd2e8fa6ae98c6b62,
+                0x68448a9ad112c0d2,
+                0x9384785c5d74b032,
+                0x47799271813e822c,
+                0xfa59d60694ecba8f,
+                0x7671f536a65b7ac3,
+                0x9f6aba8780dde745,
+                0xcb5c7c4d039cdace,
+                0xa0a6ef7c6d77b03d,
+                0xb92bc7b3704f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x121c1467fffb3ca,
+                0xbcacdc7ed6adfeba,
+                0xa83ebf969bec9abd,
+                0x4cb249736a44b178,
+                0xba1f48897ba9b146,
+                0x31c873eb1ebc2292,
+                0x76f19e30f54cf3c4,
+                0xfd83b0f12787da77,
+                0x6c0acf2603b6315c,
+                0x186e39de07766bcf,
+                0x55eb39b647190abd,
+                0xf8a2f4483856
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2791c1c8ffff9104,
+                0x98216495563c4a25,
+                0x95cd679fd606c345,
+                0xca55a50a61f77174,
+                0xbed8ec7b44f2de42,
+                0xcdbbc1c440dc5624,
+                0xb84a3670140e7e19,
+                0x1caf74b9db835084,
+                0xbcdb7dcfdb858155,
+                0xa74a858e20f6d3a3,
+                0x440b1af7645ce3f1,
+                0x11efaa2fb0d92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1b7f731e7fff882a,
+                0x9e964e74fd1e235e,
+                0x97430b5a2cff71f7,
+                0x81cf84bccda65a5f,
+                0xcbb9c90735869dd9,
+                0x7a9400c6d7350df9,
+                0x330a0811d4dab43c,
+                0x60d69b2cbb0c9921,
+                0x54f74e04a3739720,
+                0x30052843ab1b988b,
+                0x721b45c0e0cc5043,
+                0x79c27427f267
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda6757a13fff801d,
+                0x9ba5cfcc281bae87,
+                0x934792d2f73e44d3,
+                0x11a428175809394a,
+                0x3b6bde3815a5f743,
+                0x8320c71dd5a9720,
+                0xda445bf9cb7f33a7,
+                0xb2b04fae3741c21,
+                0xc658adf560fa0109,
+                0xb9df65287503e87,
+                0x8bd8dc83a8da70b1,
+                0x1518d4bea87a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2263323fff9741,
+                0x17fc6971d7050e42,
+                0x576dbaf4db292e2b,
+                0x548cdb7081da47c4,
+                0x43c7aabfd8be41a8,
+                0xdbb6ac2e1833639c,
+                0x45fd1813a88930db,
+                0xf67b76360f6d3cdd,
+                0x9e23d9ca4ac6c6ed,
+                0x574f75d950534f6b,
+                0xeba844e3a139f8a6,
+                0x190ac80b83995
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa4e977e17fffc3d6,
+                0x75c57a8da5e1b808,
+                0xf1849859956a2abf,
+                0x2c95e47751dbe9e,
+                0x69d60a0d7b9ae775,
+                0x549f525a1d89c9c8,
+                0xbf0cf27a350a6a4f,
+                0x38fabd421debd6b7,
+                0x5b3951a0985b8741,
+                0x5196575b4d3180db,
+                0x408c2a47a6958a61,
+                0x6deaa8ca1fdc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a2122e0fffffa64,
+                0xf32542a4539ce81a,
+                0x4f54440e9a14ab42,
+                0x5ba4acf0f47b0b5b,
+                0xd5ba8e94a0da505,
+                0x282b0b3c290c9d2b,
+                0x2ed54b3ae5bb076b,
+                0x2cc56f5b194b79b5,
+                0xb06128e2dc3ddabf,
+                0x3f3393e66012880,
+                0x7e1f7c0bffc6458c,
+                0x650030e0bf9e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x502e52c27fff9e3a,
+                0x55c7db3dd234a502,
+                0xd0a15488248bec2e,
+                0x5f3f44a542442f21,
+                0xfddf2dd2a0e1b53e,
+                0xf145448b1162ff15,
+                0xc9470989bbd1bb6c,
+                0xae9b6f8919859d32,
+                0xe7b4cf0798b0100,
+                0x229f8527bd34fd85,
+                0x6ca748774ca618ee,
+                0x1bd90ada0d140
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5182ba767fff828a,
+                0x57392cbd12362239,
+                0x8b894877cb12bedd,
+                0xf5f44bd4efbc7b27,
+                0xdd66479fdb0faa03,
+                0xd90d9b5fdedb04b7,
+                0xfa9abfe63529dd30,
+                0x6da525f031d45c9f,
+                0x8a78d301cd521013,
+                0x54120440a2de0156,
+                0xafb0814125075f18,
+                0x1aabf36eb4a53
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5f6cc26b3fffc005,
+                0x62dd398aff922d4d,
+                0x516278ed251be24c,
+                0xa3025228b854ef87,
+                0x47f5ea9c84181099,
+                0x65139ab57f59804d,
+                0xb7df4fe968df6979,
+                0xb9ab23954fc13d4f,
+                0xdf5931b928da783f,
+                0x320bddadddd317f4,
+                0xaca414f5f7029666,
+                0x15013e573bf76
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x21b79a5dbfffdcff,
+                0xbc713fc5e565db04,
+                0x6e7fc1a6b505f8a8,
+                0xa704dd58d8623bfe,
+                0x8461f3cc4ea55e5f,
+                0x7cfa1072f5761cca,
+                0xce251a16eeca78fd,
+                0x47b2180c16e4b66a,
+                0x7c6dfd2dd0d06d75,
+                0x28566c2aff62dc1f,
+                0x3628a9340efe722b,
+                0xd38ff20f426b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xac4aad6e3fff8cb1,
+                0x97ab65dcc00d22dd,
+                0xe663669b678bfffc,
+                0x6d1eae4ef5edaa7,
+                0xa93bedcd6a9a9f9d,
+                0x43293d69e523ebd3,
+                0x94e7dd5b57b1bf2f,
+                0x8e87d5cceba01534,
+                0x27970f3f46e887e9,
+                0x1b01351232a11433,
+                0x7e95d3e8659d7dbf,
+                0x1aadbb766634f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e69ed693fffb63d,
+                0x5003cffa1e8fd04a,
+                0xa9157fa4e9c32253,
+                0x8c4245f63d8f5332,
+                0xd5a0716a37da494f,
+                0x312e2bcdd25689cd,
+                0x322edf3727196b00,
+                0x8ff028c9e9adac47,
+                0x687a6a0afb9d5319,
+                0xb0bbd3ae48624e2e,
+                0xda923f455c96cb36,
+                0x64219c0fabec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47b092f43ffff409,
+                0x3c784d13fdef4ea2,
+                0x62cabcaa32e7fca2,
+                0x92e3ab0aaa355ba,
+                0x28cb2d47e45fc736,
+                0x317d540ad733af82,
+                0x9aa018166897502e,
+                0x633dbb6da285b6c4,
+                0xb58b7fadb33137ab,
+                0xc4ae8d887bce0ee7,
+                0xf2756e07c455167b,
+                0x7ab525cfd0cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x48ba91857fff81e6,
+                0xf85719f70eecc712,
+                0xf64a2bdf6edaa1ee,
+                0xe2787c1b3d92e98d,
+                0x8e5880b581d32ef4,
+                0x8ba2913f856a5d43,
+                0x709f247ad6e33c2a,
+                0x4f1a89a72abc2de9,
+                0xdca793383c0b6662,
+                0x782d14c5fad14d45,
+                0x598c2ae01bbb9bce,
+                0x17bd12ef92405
+            ])
+        ),
+    ];
+
+    /// Short Montgomery multiplication with respect to the short Montgomery constant R_2=2^64
+    #[inline]
+    fn dot_product(res: &mut Fr, state: &mut [Fr], mut start_idx_cst: usize) {
+        state.iter().for_each(|&x| {
+            let elem = Self::MDS_CST_SHORT[start_idx_cst].mul_short(x);
+            start_idx_cst += 1;
+            *res += &elem;
+        });
+    }
+}
@@ -0,0 +1,12459 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonInverseSBox,
+    PoseidonParameters, PoseidonShortParameters,
+};
+
+use algebra::{biginteger::BigInteger768 as BigInteger, field_new, fields::mnt4753::Fr, MulShort};
+
+pub type MNT4InversePoseidonSBoxRate8 = PoseidonInverseSBox<Fr, MNT4753PoseidonParametersRate8>;
+pub type MNT4PoseidonHashRate8 =
+    PoseidonHash<Fr, MNT4753PoseidonParametersRate8, MNT4InversePoseidonSBoxRate8>;
+pub type MNT4BatchPoseidonHashRate8 =
+    PoseidonBatchHash<Fr, MNT4753PoseidonParametersRate8, MNT4InversePoseidonSBoxRate8>;
+
+#[derive(Debug, Clone)]
+/// x^{-1}-POSEIDON-128 parameters of rate 8 for scalar field Fr of MNT4-753, with an MDS matrix
+/// supporting short Montgomery multiplication.
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_short_grain.
+pub struct MNT4753PoseidonParametersRate8;
+
+impl FieldBasedHashParameters for MNT4753PoseidonParametersRate8 {
+    type Fr = Fr;
+    const R: usize = 8; // The rate of the hash function
+}
+
+impl PoseidonShortParameters for MNT4753PoseidonParametersRate8 {
+    const MDS_CST_SHORT: &'static [Fr] = &[
+        // These constants are in Partial Montgomery representation with R = 2^64
+        field_new!(
+            Fr,
+            BigInteger([
+                0x980458e7dc0417a9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2d8c308952a82143,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x81168d62862a8cb3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ec563e9409609b5,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c116202d3c0f9fe,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x958ee0fe1238bd67,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf485db3d77023dea,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5e89f6fb5695fa65,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfeb76839081c035e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e1ca7df87330f58,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdfef039c667c3159,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40f1851024d057d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b6602a431ae7605,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ff3fbf0d982cd4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c7ff18497a4434b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb91178a670235d8,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3b5a2b52e9c6a3b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc7458504c2f93129,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x65fe8c7811ee04e8,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b9ddc3caf5d9c31,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8c4e11d373d5767,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x29a253c00ff1513,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b6ca3f96c248317,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x913b20def5f0cce4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd98c50f59927e93d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd58d77dced173c7f,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x815cdd4c62550fd1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x230a117254e0fbc9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x62b00195fc5caef,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf0b5855f59231c5d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6a5d45462883c0d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x594e49953b721356,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1c2302c5bab0abb9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbeff9e65766f958d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5871baf7598134d3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x735f7dbc452bc4e6,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x154247dc89ff5fc0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3f9f545a90585649,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35b73c186709a56e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47a44eef236f5ded,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc918bef2f045a95e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdcad25620a9f4635,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70e05ca653c6e16f,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x204858d4f43b7b64,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9282d40e834f7f40,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1184dcf99a3eae1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x14fb5d421d77cb6e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa5afffe07654297e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc474529b79f6915,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc50f9d3b76dace68,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35ca07ee6c80ec61,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x597ae8b09ace8cae,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x760154f93a6baf8a,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b6dfa4e9b5600f1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x46d5a47194ede33a,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x302ce9dc46cf8b67,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19a0f3ffd3981ee2,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3798a4f0817cb0f2,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x736786f130ac9b92,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x17cdb134bced2ebc,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef4d5419ee827ef4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb48d7e6478b69fa,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6fe0d335ebeaf725,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9bcfa19bce9bfea8,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfaae915faba7dbd,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x120c9f624e192aa7,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ab68af5d16f4757,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10688c3a27ee68db,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee2a55e8fa21d361,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a4f1754f3541795,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x887aa9ca8f0e54f1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e058ffce24b2570,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb228084dd222fe,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf2ee5581a449f107,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb60a064524814ea0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x373c53a6dbb3e724,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdd4c8b5f834ee657,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83f2c438438c36b3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa76cd5d2fdf77091,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc1702928dde52be,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x239a4ed766105da9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+    ];
+}
+
+impl PoseidonParameters for MNT4753PoseidonParametersRate8 {
+    const T: usize = 9; // Size of the internal state (in field elements)
+                        // Number of rounds including security margin. Without such, R_f = 3, R_p = 50.
+    const R_F: i32 = 4; // Half number of full rounds (R_f in the Poseidon paper)
+    const R_P: i32 = 54; // Number of partial rounds
+
+    // The zero element of the field
+    const ZERO: Fr = field_new!(
+        Fr,
+        BigInteger([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])
+    );
+
+    // State vector after permutation of zero state vector
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                10716939165922054239,
+                6717922918593955183,
+                6062448846192427436,
+                13729637432842505512,
+                5108439008626298644,
+                15681223270722740614,
+                16310884419466628001,
+                10078122039933509857,
+                1575560220050315235,
+                11957815428182234461,
+                16380032758090847608,
+                330396814136272
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                3761562730619476036,
+                17046774591358647980,
+                7444842512823034509,
+                13557157427466927613,
+                86718198772528660,
+                2879809446108292619,
+                6216416802286268468,
+                13160014996509908473,
+                9887228649675672007,
+                8890887919318977215,
+                2447777237416571677,
+                222614869870785
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                12527085457172798646,
+                17349000062712663776,
+                7197366701675070332,
+                10227024944155145017,
+                7985710401513293782,
+                7821012828066028147,
+                3588644088799545362,
+                13096612574286416257,
+                3877225465867965027,
+                6177264415609009027,
+                16796832326918313246,
+                148843101433295
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                12130644392010939015,
+                10913646712980877827,
+                8124240605506773977,
+                3760064265802291738,
+                3027009919313071577,
+                17207068188611880905,
+                5798547713509418897,
+                12888723796222549856,
+                7987405477775789839,
+                7394152747654600920,
+                5920132166793920536,
+                298567767826792
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                5633455040650252939,
+                18369425369355329425,
+                13884109796493516204,
+                16066631607084041052,
+                15302945366549605623,
+                7649534614571845284,
+                9628628469328816469,
+                7299019785471115165,
+                7156613019347239601,
+                5455205566997267697,
+                2742094434655184110,
+                416481414292367
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                3944243886281738578,
+                2958654282523395871,
+                666165718237091990,
+                3186132396857842808,
+                2558688379430591008,
+                8240250916291079392,
+                14012243376349244556,
+                16170994870947484511,
+                5509272846576330297,
+                16010536852953859124,
+                6598447389715890898,
+                341393486205817
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                1131615408014677216,
+                13906003171668324795,
+                17695836615723849122,
+                2072594763511016290,
+                9315189068701595926,
+                6094339413713785425,
+                5824080795805526271,
+                15626153169690294058,
+                10788060799811464007,
+                3418812635171760920,
+                18389735900645871597,
+                396252468161623
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                8486416952703455764,
+                2176347749239005141,
+                1467958401848735599,
+                15584633642592516374,
+                2364779626372284504,
+                12049273384796544492,
+                4502474172618117713,
+                4395806673303684966,
+                17640956641002069526,
+                4550962832962960786,
+                3391523265998364698,
+                49197502484498
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                1414640267800792628,
+                10566861109875479503,
+                14507361773846280177,
+                6322638043742682782,
+                840512976069318424,
+                1902659433809524471,
+                3320876105186167529,
+                13918073213674542808,
+                18398826620495565097,
+                7252110094784263054,
+                3274756822481213438,
+                170348936107215
+            ])
+        ),
+    ];
+
+    // Array of round constants (in Montgomery representation)
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5600da8422af82f0,
+                0x9869e24b9c8ba5ba,
+                0x6cd954db2d27797f,
+                0xa502cb80e0950e90,
+                0xc947a523a2c64fbd,
+                0x20b7ff491ad1caa3,
+                0x4834a79bbc95dc24,
+                0xb42415673960b5bc,
+                0x8db413be02cbf88a,
+                0xfb63bfeb000a066a,
+                0xc37276cc6ce1ee3d,
+                0x7a22f2b3206b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf53ad8fa6a47799c,
+                0x9503ee3403510726,
+                0xffd164af02561328,
+                0x71c5e052cc00045b,
+                0xb90f641dd71eae50,
+                0x4a7bffc9cede92bc,
+                0x432aedf7a10851e4,
+                0xa6b4ed62e9c3e214,
+                0x411ae5046fd2c989,
+                0x61bc0ab5e1037a5a,
+                0xcd87e8169b08c790,
+                0x19b2f725e70d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e168ef88a451f0d,
+                0x28a8cd9ce89c27ff,
+                0x413968adfeec3c81,
+                0xcd6de0a6910c9f42,
+                0x16e2e119aff7914f,
+                0x8933f0ff6323fab8,
+                0xe289ddb8c76f208d,
+                0x3b981a73a103a4e2,
+                0xb511ec078517ff5,
+                0x1404d5cfff75ef05,
+                0x8b0118e262a061c8,
+                0xdb2bd309246
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ff667905e1242a6,
+                0x7270e5efd6e09964,
+                0x81bb7194b276d6c7,
+                0x666348c2dbc1a789,
+                0xe4b145947bfd82ae,
+                0x60a1534b51db46b4,
+                0xaaa75832a2f09c33,
+                0x6feaa72c6faa03ed,
+                0xd73d7c370bb1b934,
+                0xb13bc775e28ba4ed,
+                0xf3cbf5875704bc3c,
+                0x199bd6c1f8304
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5039d61206350ef3,
+                0xecc55464178d03b4,
+                0xcf3017ae03cc473e,
+                0xee9f345187faf2f1,
+                0x5f597f6467e578db,
+                0x1e64d5792cee8146,
+                0xd25ce27b4c2e0fcb,
+                0xcd298e891fbfceb5,
+                0x8e99a894fc95fbaf,
+                0xa787efdc435082ba,
+                0x16f7e92955bf3b6e,
+                0x1a48a9079b017
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd46cbfe256ba4063,
+                0x976304628845deac,
+                0xb580683115319814,
+                0x436d0b60364dd949,
+                0xf73b0d1f53d4ff75,
+                0x74c518862d9d01a3,
+                0x9f523d2520789bbe,
+                0x407231b82a0ee530,
+                0x1ac7fe5d6d1e1a76,
+                0xbacb6b0f6c27de04,
+                0x390633e72fa76772,
+                0x169a0492d2042
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x819649a0b3d4bc0e,
+                0x41c89a03d30d72bb,
+                0x44f188ddf63d5260,
+                0x52a097440768953,
+                0x70698f92e9e1638,
+                0x8f5cd90293ef2f48,
+                0x827c1ece1f94a22,
+                0x2d113f70e08ccf05,
+                0x1b60006ebf11f937,
+                0x3a575a42cb853be2,
+                0xcd10e73b1cba5325,
+                0x1626957a3909a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa174f2a88f71921,
+                0xc7bd25ca39790b27,
+                0xde83ae2ddb4cb5c1,
+                0x949c08c23bb5394b,
+                0x47ff4e797eb35e09,
+                0x9c97a1de9c62b0a5,
+                0x9f14df3efe528080,
+                0x270388a013ad76de,
+                0x3cd552f8e84149a4,
+                0x2be371acd6796643,
+                0x17a09f327cfa1d30,
+                0x1e83d4f2a752
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f908db0ccdb9cbf,
+                0x139ee885c0ba0625,
+                0xfcdfe37aead92a9d,
+                0xa4db4e8999155469,
+                0xe3283e50e649c515,
+                0x58eec2541136cd89,
+                0xcd98995fd2d74de5,
+                0x2faddb0d0fed7f7e,
+                0x7388ef7d62ae2b94,
+                0x198c536d782fbe4d,
+                0x3f596179a9bdf96e,
+                0xf8ed60bcf51
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x95337c7378fa9fa8,
+                0x33a76705d9fcca64,
+                0x5cec253aea16d74,
+                0xe5b766c463d98c36,
+                0xb8afe54b7bda90cd,
+                0x40eaace0c8fff72f,
+                0x2f7723f353193de2,
+                0x10d6d28f6d1a5522,
+                0xaf632fb207f00aae,
+                0xc1136c29c1de982e,
+                0x549d3f8954dfb1cf,
+                0x17f6f06c57acd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ff889dc1589dd80,
+                0x4f14d3eba535a1d,
+                0xa491480813b3e931,
+                0xf5c6afba1c2ea74a,
+                0xb1f5b9c036390e06,
+                0xd9dbfc1732074580,
+                0xd9592b6125ba60d3,
+                0x62b5d844e65d993c,
+                0xf8fe6a56c706aa47,
+                0x6cf92c37a355b5ba,
+                0xa87e41308565ceb9,
+                0xfccdabea268
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb746cc1eb8e0a8e1,
+                0xd8a71b9f8cda4c4e,
+                0x4d6a4feb75a97f55,
+                0xf7cb1c904bc0aef7,
+                0x97649a0f26f3d8a1,
+                0x2e5ddcb493e8937a,
+                0xc7a56ce00107ac84,
+                0x6126f9a05bf52522,
+                0xb4874faf3eec5cfd,
+                0x2dc1bf37d5587dc,
+                0x1f43a54766618b98,
+                0xc6a46e80e22b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa31bc7eb12ed505,
+                0x34bc237d33c9c7b8,
+                0x3e100448e624d93c,
+                0xb0b841e991692c0f,
+                0x46525e1079a614d0,
+                0x8136f67365710719,
+                0x81f0bdb526138c78,
+                0xb603acb384880410,
+                0xd7e2415855f83f7,
+                0x9823f19f9f5532b7,
+                0x7fc57ffc2f85c473,
+                0x40100bec55ea
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45eb30c8d0688a52,
+                0x1255d501a3154c54,
+                0x7746f39cf2e7958f,
+                0x2b348b2d896403f8,
+                0xbb8c437f10807e5b,
+                0xf2ad59f99d4ca7a0,
+                0x8dcb399094d36b09,
+                0xcf568a7fef06b6f2,
+                0x695a993d81508be0,
+                0x45c939cff23d73dc,
+                0xf2a9cef6d0e01d69,
+                0x1418d0005de23
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70132c4dd7af84d6,
+                0x4e9cb7020e12730d,
+                0xb3ee8797214e5d66,
+                0x65c1bc92bafee88b,
+                0x39389459739433d8,
+                0xad4ead4ada7cf219,
+                0x6dbb39d77f94dfd0,
+                0x103512f373314545,
+                0x12fb08fd8bb40df2,
+                0xee5e65fe75c18b44,
+                0xc41ac95236ac0d70,
+                0x148f98f3efec2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc28d15117c0894f4,
+                0x2eb0eec4c301d2d2,
+                0x5c4f6bacf82468f1,
+                0xe4a38ee01a9cd0bb,
+                0x10a0485c4524ad5b,
+                0xd8b97ee53230281f,
+                0xcd4d77d1900f24bf,
+                0x652c8897f9db8246,
+                0x10d57c4a3fb857e2,
+                0x8fafebfe2f06b187,
+                0xcbe6edd5521886b5,
+                0x117163b1d5c0b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6dda1893a1670b6,
+                0x500916c6d0f7cafc,
+                0x9c4e8093be6f6c27,
+                0x4b16365bb09afddf,
+                0xdad2d57ba230872f,
+                0x181bf7048bc6413,
+                0x3eace7615421ba6d,
+                0x7df89042af9aabb4,
+                0x4e352cedd04ef165,
+                0x35316cc494e4c296,
+                0xb43fa5016ccc5544,
+                0x184aac10f6641
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xab64865a39c2138d,
+                0x5867a058011ec2e2,
+                0x7400205a50a3c115,
+                0x28b4f7ba29ecab3d,
+                0x443f04e4a3274500,
+                0x1f8a4b3b68902e4c,
+                0x7828c5e725530552,
+                0xcf6ade9d729641b7,
+                0x4319894588311660,
+                0xf7917f5e524cf8fc,
+                0x57981b2a6e1beee,
+                0x775ecd42547d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb03c19806b3ffda0,
+                0x592eed808c5cdc12,
+                0x8bed8d03b1166866,
+                0x8df4947aecbc9238,
+                0x542d1b0d851ca7af,
+                0x2a937aa2bea5d052,
+                0x31bbce122508fabe,
+                0xcdffb2095e663b4a,
+                0x35c13f027496998c,
+                0x930d45cb59f182b9,
+                0xf190394b585f3f8f,
+                0x1b03ae1dc673a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ef4232d98ea1ac4,
+                0xe0c68b9d248d1721,
+                0xb3be7bb43a11889c,
+                0xce47dd82fa97fe40,
+                0xca8949827f02ecd4,
+                0xa3c4c0a0cb8ac44,
+                0x6601b47448fcf4aa,
+                0xad5a3be18dcea96a,
+                0x552e16e6a49d1c27,
+                0x116128cdb64d4a05,
+                0x763e09adf05ace0c,
+                0xb5abe5bbca12
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc2f19ee7095fd02,
+                0x6fe4ad0c696dc74b,
+                0xfddd864c33be6684,
+                0x8f2be987828ae905,
+                0xce3418e98adb4479,
+                0x3b53a66f4a0cc85b,
+                0x8ffa347ec0a115de,
+                0x8900674ba927b82,
+                0x9c550da9fc74ae14,
+                0x47d42ed67f6c28ea,
+                0xdb7c9c8522c9144c,
+                0x1d3fdb1d55d4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6dad19a0f91d5ae0,
+                0xe84246c3f37f2a75,
+                0x384eb2c7df45145d,
+                0xcd573b7d33ae7128,
+                0xa6f54b4e39472ba0,
+                0xeee805d9371d5324,
+                0xd6d5b798b6351a14,
+                0x85101c72d7ad6653,
+                0x725b2fed4abaccc9,
+                0xc7c3ed0d2d86cec2,
+                0x66665e26c3265f4a,
+                0x13a9a458b7a10
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43a56fb8748140f7,
+                0x2008a05de3e32a29,
+                0x7274adc1c5dc3df2,
+                0x6daa0c0cb92d7d86,
+                0xec552108de3c4b2b,
+                0x779fa962de1cd8d2,
+                0xd5148b73272ac913,
+                0x4661284b9e5dbeac,
+                0x40a9bf09975d38b3,
+                0x1e57289818271b9d,
+                0xf033a51a087239ab,
+                0x14911188bf513
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfaf2d529d3b2b8b5,
+                0x1cf5f1b69a65ba28,
+                0xe7b253b7488b5d28,
+                0xd0264321717e23fd,
+                0xddc01854e17ad9fd,
+                0x746b9a47db8ea61c,
+                0x4f3dbb7fcefd4de1,
+                0xe5d4371e85b6b5d4,
+                0xdc12b823643c28fd,
+                0xd41ff841cca2f6a9,
+                0x3a06ffd7304aa404,
+                0x7ecdcecfc339
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44e7a018531f581b,
+                0x9bfef705e1022424,
+                0x11201176f32fae68,
+                0xcd76e0e342f96d24,
+                0xa6f07c464d08dc60,
+                0xbbe554edb47f61f8,
+                0x9082cd937cb1f9cb,
+                0x147b4ea122fa9e62,
+                0x4c7ca60bba166a67,
+                0xf41fd78b9fca9d5,
+                0x869213dd066c83b4,
+                0x10f3127beb678
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x143333a1594754c7,
+                0xb95f260fb41bd9f1,
+                0xf0cce53eb1e0fb8a,
+                0xa46963188a9218be,
+                0xabad49165ba8ffff,
+                0xd8d94a0596e1ea4b,
+                0x7cad11848df4c34a,
+                0xe2c330749667745f,
+                0x7bf273f4f54e0e2e,
+                0x69a21dc66f668b75,
+                0x5bd411e964789a52,
+                0x19c813d37462f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc0bfef7b4de37499,
+                0xaa89f5450a59c1d7,
+                0x3c091b7f5516706,
+                0xcb59c9bac9a7435a,
+                0x480ea8453c8cc34c,
+                0xd1d5eed281e2700c,
+                0x8cd25ff951e2c3a1,
+                0x888710f68ad3c2ae,
+                0x11f48a70069944c0,
+                0x9567004ed5db918b,
+                0xfe447a7d0a1b5dc4,
+                0x1ae0d8f626804
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x105f5e61912aeb59,
+                0xcce746a9a4038fc9,
+                0xf6d0c57b230713c1,
+                0x5a492ba451c4729c,
+                0x8146988409a9495a,
+                0x8140b25a1444ec73,
+                0x324e3c1c81d2364f,
+                0xc23df3d3e3b1f693,
+                0x5d822ac6fb531fd2,
+                0x83e7df7c77ea15ea,
+                0xf7ee4f85b17bc66e,
+                0xa167eba41469
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe45c513445025922,
+                0xb50eaeb4dd0b1aae,
+                0xfe6db23b63de8e0c,
+                0xded21e360c7a8204,
+                0x76e710c65fe3c0d0,
+                0x75799d30bb1c175,
+                0x6595f4f124d45786,
+                0xccae4401e3b9386,
+                0x4a3b7f372a8c5431,
+                0x9958341756c33e0b,
+                0x2f2a35aea6e78cc,
+                0x1c42faa8366f0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6961cc1f1bd7cc67,
+                0x38167c0d89ff5ac0,
+                0x11af99f75805550d,
+                0x3078bdaedac14054,
+                0x14aec458209a67ff,
+                0x231ade9f0261aa12,
+                0xeffaf068292fc84e,
+                0x463fd367d588a0f9,
+                0xf44b1c926834ac0f,
+                0x5b1ebc906124c8fa,
+                0x23866c11238c806f,
+                0xba41dbd535c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8c337abffe5174d9,
+                0xc4226dbd3c4a485b,
+                0x1a36443f2f5db3d4,
+                0x330bc825125fd98,
+                0x9b3a586e24cd9b61,
+                0x72705dff92d45218,
+                0x18e5565fe6f11e29,
+                0xe8621b54b7a09701,
+                0x7bf3882324201a6,
+                0x5f3fde8ec8fc6ca4,
+                0xf15cd73b0e0db6cd,
+                0x100ddd7d2f045
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6c00e0dc2e596a6a,
+                0x7456934a131a7d05,
+                0xf21e5a61a0e2f390,
+                0x399cf3e9c8a0ca0d,
+                0x6f01ddb1960f94d0,
+                0x3234987bd4037537,
+                0x7d257e4b6494b60d,
+                0x81b2a9656a0a7fee,
+                0x43e987ca1d25124b,
+                0xf25a66e8b4acdc65,
+                0xa15800564cc047f4,
+                0x470832686857
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd4c8c662c985f169,
+                0xa6380516b427cb6e,
+                0x8a56cedffd6f3fdf,
+                0xfbcbf7d3d803cada,
+                0x67738402d626c7c4,
+                0xcf8137b621b954e8,
+                0x29532934ce82baf8,
+                0xa3ed167f70590d81,
+                0x86b2ce51bbee99ea,
+                0x3a73af37d5fa73db,
+                0xe6ede65f542aa97f,
+                0x49708816c94f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7a8edd5f39d779e4,
+                0x39f5ace495898055,
+                0x74d816bc80278868,
+                0x9183aaa1cb19a08d,
+                0x15fe3b72aa2c0ad5,
+                0xf475def44847a67e,
+                0xf307f921e4b6dbc1,
+                0x5db08d2104e59c09,
+                0xad3267fd04543f92,
+                0xfa76b4fd15affec7,
+                0xfefdb3a22ef7d734,
+                0x7aacafbf74de
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x57fcc1b6a065a6b8,
+                0x4f280b9402750b69,
+                0xa16adf412dc966e0,
+                0xca241f786e922817,
+                0x63ac1274d85fcde8,
+                0x5adc23a5e0eab5b,
+                0x1d16f81cb4445bd4,
+                0x993d28a7ae557d31,
+                0x2bb4181716265c24,
+                0x3796a1abedf2b12a,
+                0xf040252c3fb8ea22,
+                0x1c43dc020b8cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b2faa5a23d68f3f,
+                0x921ab5c3075c7e7c,
+                0x9cc3684e5ddfa8f1,
+                0x6ebded11f5292658,
+                0x84ef4269e7458fab,
+                0xbf28571b8439baa3,
+                0xf59ab78af490cd45,
+                0x88c5cb4c135da662,
+                0xc9c269513ad5fe42,
+                0xf79c93a787634545,
+                0x5d487d8a3cf94ce4,
+                0xef3941b78567
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5823e0761a43c75,
+                0x704403bb8f2bf048,
+                0x30e622eedb8252ff,
+                0x9c819177ecb74d,
+                0x24351c88bd37bed3,
+                0x6ef99967d87bed93,
+                0xfd80bb72771c9caf,
+                0x2706953fe8b89271,
+                0x51c194818cdab1c0,
+                0x66e9160a289f7015,
+                0xf62c65564e43bfab,
+                0xb4b262f29f2b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd328f6c1ee3297eb,
+                0xabe073f6b8ef2025,
+                0x14c3b7316adf3258,
+                0xd13e51e6691b72c8,
+                0x4db89be2c68fc049,
+                0xf2268c5b19379db7,
+                0x6f8b9f869ea2c866,
+                0x34d9ebf191231925,
+                0x25aade65e088dad5,
+                0xa86187b7bcd15622,
+                0x710d35814b3ad493,
+                0x190d978f572e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4d65adcb89b5d37,
+                0x8e1042dff3f93624,
+                0x5382a274bd9e8eba,
+                0x64b7b3043a3eb924,
+                0xaeb4b5c73be5b0f3,
+                0x8020358e614a6dd3,
+                0xe965dfd1ee0dcfa2,
+                0x4c1a3625ca2f0488,
+                0xd68ce4ac707ba210,
+                0x89e81623510190e3,
+                0x37f8227616b19c53,
+                0x1687aabdcb038
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb38aced6d9099502,
+                0xe266f1e81d1cdc6f,
+                0x2bfa931584d94b5a,
+                0x439aa1c832b0a2d4,
+                0xdfe9a4eea23bcadd,
+                0xf1937ee788d18312,
+                0x4426b5d393a27070,
+                0xe5fc6b773b759396,
+                0x164b0659469fa110,
+                0xa660f32c742d3cb5,
+                0xf0ba9cc40e1545e5,
+                0x5a3c2905bf29
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb25a273f67023e1a,
+                0x897a8565d870f960,
+                0xead53d7b694d7d21,
+                0xe370c5464d43b650,
+                0x2e4a5780f73a8bdc,
+                0xaae938163ac657f5,
+                0x116e1f3068bc3aa,
+                0xe95ab290b72aed24,
+                0xe86a154c9fe2f105,
+                0x78d93a69c59b84a8,
+                0x91ce1a94f4ccc104,
+                0xa8cdeb35febd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44244745de72883a,
+                0xff1bbcf5da684243,
+                0x2525e6c71f84d166,
+                0xe6b5f851e57fc81d,
+                0x6b358a1ddadc7dd,
+                0xc26b7c9b02ed2f36,
+                0x514408aa931f2239,
+                0x70edd48d92776836,
+                0x46a668ff95773bd3,
+                0xc1081d9c836f5c7f,
+                0x4b4cebff40421972,
+                0x274c006c8113
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5e36cf935f054aa,
+                0x5d618d6b5bc938a6,
+                0x59b180277b86f39,
+                0x7c2803767d9f2d69,
+                0x35a8051eaaa9bc7,
+                0x5b8dde5d5fe55d00,
+                0xb64fa12098b58866,
+                0x5347b96aa3c1c9e1,
+                0xb0bcf6f667071c4e,
+                0xe78d830f81257939,
+                0xe7d4a6800933f2d2,
+                0x1b7147fcebb46
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd31f94494505a467,
+                0xd45ab117a188f7b0,
+                0x399fe01b2b617bc4,
+                0x6133d71f8af7dc27,
+                0xede21f73027bd55c,
+                0x4395d3837a8247ff,
+                0x4df57a76a8666202,
+                0xc1585adbe034f42,
+                0xb74bac931da9017f,
+                0xdb5ea5126a67e72c,
+                0xacc60597b0c6d4a6,
+                0xa032a60dcb0f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa109f63e3fdcece4,
+                0xaeaf9cc73be84992,
+                0x47699aa870141c5b,
+                0x27c1a90714744f43,
+                0x83b43044ba509673,
+                0xfad9c5db87787c06,
+                0xf0abd74cd3eb7e37,
+                0x15568b6ff26af5cc,
+                0x82e63ad69f518f1f,
+                0xb341c88c4daa5783,
+                0xd32c23cfbdafeb2,
+                0x995983adf75e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba736538547b095a,
+                0x1fcb7185e0c4b818,
+                0x35f56ec861901d0f,
+                0x4c4468bb6996463,
+                0x6307ea846141e3e1,
+                0xa99660d094376bd6,
+                0x38d018b001dace85,
+                0xb50add3cdcdfe320,
+                0xe5bc689ec56f88bf,
+                0x23fe4f74497a451a,
+                0xcec5922bed9b8b2e,
+                0x161650cff109d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf3885f10971d692,
+                0x37ab9d1761c55fd3,
+                0x372120a8003412eb,
+                0x61cc6174d3f0f5cd,
+                0x84e25d49fe2ab17d,
+                0x98236e549af17397,
+                0xc23e6a61c7383c67,
+                0xf91044cfb9cd5732,
+                0x97664911a1beb033,
+                0x5a84401cfe3ab07d,
+                0x953ae76eca843cbf,
+                0x189beed9f37e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcab2ac25451b52a4,
+                0x4a64f05b8f0a545c,
+                0x2b525af8cf66153,
+                0x6caefba1bbb3033a,
+                0xbdb5968cd85d2fbf,
+                0xb3637e3bce41b35,
+                0x959e58b172c4fe77,
+                0xa555c4ad2918d7d5,
+                0x7d87173311fe225c,
+                0xb23878825ffb0f4e,
+                0x979a10b5fdf5e172,
+                0x10458568301ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba6520e3e029058a,
+                0xc207dc6050590c74,
+                0xecb3e0a7c084a0eb,
+                0x7933d6afd7a79160,
+                0x3be981db34e67568,
+                0x50b3da1172b0f308,
+                0xbc20ba670f9b2b52,
+                0x7410579aecde27eb,
+                0x63b49198087f08f,
+                0xe8e23dff5c1d3200,
+                0xeb2036bff04dfab7,
+                0x14eaf91a6792b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x76155813694b8366,
+                0x28370a8e223e4b18,
+                0x9444e6d44b0f4787,
+                0x5081a57199421de3,
+                0x1110ab77bf0c7b98,
+                0x57b2dfb6b6ba27cd,
+                0xf78bac1b78914584,
+                0xb29844dd9f4e6b10,
+                0x60ec3b20320d8619,
+                0x3503208deff59510,
+                0x795855b3285a3ad8,
+                0xbaeaa2390b42
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x319ebc50302c82f3,
+                0x4aa14a0e14bebc05,
+                0x916c2ffe3616c159,
+                0xd50f78b479db0a64,
+                0x322287b1314e5f16,
+                0xa499c7db7032ec1e,
+                0x9a009fd4923a00c6,
+                0xf8916f9a1b7c6449,
+                0x6494422cdce818c9,
+                0xd580a8c5872b0192,
+                0x2bbc503aafb0dcc2,
+                0x108bb0a1d1ad8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5604ee0a49ebe955,
+                0x1bbfc1015300ab4f,
+                0x3742c3bec0ad8ab1,
+                0x2eb1a0ad86ddbcf4,
+                0x550f7b8fca164e3f,
+                0xf987fd67b547f8a5,
+                0x3e91a6899902c678,
+                0xfaf282c4decda6eb,
+                0xc2474347fafa3c9f,
+                0x300fe4adbe3c61b6,
+                0xc96ffc3368b88052,
+                0x1428d0dcfaad2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x127a05f318c03213,
+                0xcb58d08c07a4eb66,
+                0x9e70fb506bb145db,
+                0x13eb932a62abd626,
+                0x1f312bf91f803192,
+                0xdaeec6dd76282fd9,
+                0xd7ae659b6316c381,
+                0xe530bf0b7b855f1e,
+                0x8102338c39d91521,
+                0x972fedca77cab167,
+                0x989d17a070879e5c,
+                0x1bb8ef6fcf6bd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x96a1772f48201151,
+                0xc042c30d52fb3568,
+                0x213633f0e190dfa3,
+                0x784a4e175302627c,
+                0x6f0c69d7c81ac80c,
+                0xeea43f5ee43ef5bb,
+                0x64712f2413b6c8c1,
+                0x91ae1f08f506dc22,
+                0x70e5d48600ef98a7,
+                0x5d9e999f16667b0d,
+                0x1fe28c911474c42c,
+                0x1225926c1fa2c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9653531da7a5bbd9,
+                0xfdcecb136420e894,
+                0xb40efb79c1cac075,
+                0xa7a651ae694f503d,
+                0x5f025bb9951190c2,
+                0x91b05fcf55d33c12,
+                0x1f4a9124134bcea5,
+                0xd0e4796e52a2e012,
+                0x5f0d9a7cf9cc73cb,
+                0x4a0e7987eb960664,
+                0x466ec02f1714d841,
+                0x478955b32d6a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66e727832386b0a1,
+                0xcd6576a7f2069acf,
+                0x66da21967030053a,
+                0x12afde98c648b43f,
+                0xf62893eecfe03b10,
+                0x6aeebc541ccd43b,
+                0x3cbf8c2c310c8e5c,
+                0xb5a41ed4edcdb928,
+                0xccff455ac682662c,
+                0xbe31dc27cb147fe7,
+                0x284cf576e6a88294,
+                0x120cc331c8c07
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd73738ca0b8bdf6,
+                0x867a2d833da94d69,
+                0x1ffc76a74fb7251b,
+                0x67c81365d48c5b81,
+                0x8fddf44a1698849b,
+                0xc32458cd26d8785f,
+                0x695faeb18441a38b,
+                0x14fd157f448c1eda,
+                0x287df9e699f4f586,
+                0xdd76bcaa245f5671,
+                0x309bfef38caf1b94,
+                0xdf5e8ebc3549
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa78777c17907ff83,
+                0x5cc35bb83b417e08,
+                0xeb4b9652cb0997a1,
+                0x88be9877eb7cebf6,
+                0x34f693b09a81e726,
+                0xfbfff52d564a5127,
+                0x7afba3c95a275e31,
+                0x428467703b7fa222,
+                0x7ffe03cda0779a58,
+                0xb481b7f0a992569e,
+                0x8d5c60c29ac9d263,
+                0x1281f3dfda0e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x57858e2d2f36ef83,
+                0x8abe6fd9f475cd20,
+                0xc444cbe2a1cf2cf6,
+                0xc8250b81b7dc8514,
+                0x6980d97b8acfad0,
+                0xf9e85e3f7cbdbead,
+                0xb0b910b1aaf781bf,
+                0x9df2c5ab73410af5,
+                0x8243734715309228,
+                0x66180aade18b96af,
+                0x270ac8cbc6b7889,
+                0x19fcc51f3b881
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc73c3523f0e26a7f,
+                0x5df6dbcf5f40bb8,
+                0x10edc0d4a6c778f4,
+                0x3630444f79db2381,
+                0xccde5c40dc76b9aa,
+                0xbccfad3f4e55b5c7,
+                0xb5b972293726b4aa,
+                0x9ac592b711740b9e,
+                0xf6c2d25c1a96543,
+                0x75eccc09b1aa5913,
+                0xf8657a7bc7700b0f,
+                0x6dad23485fb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed9312ce41f55f28,
+                0xd8f97b0af6b75853,
+                0x40dd1638afef4a98,
+                0xd10e6ad8090d13a,
+                0xe5e4ce93bbef05f9,
+                0x8b2f3e5b92e26a3f,
+                0x9ca3136e7e952d8a,
+                0xb3c27aa91a80559,
+                0x69b9d3161ccbaa2e,
+                0x3979885ac9de41ce,
+                0xaed6f6a7e048f67,
+                0x1a6f362091944
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x300627888a2c02d7,
+                0xdc78336cdbbecd17,
+                0xdb29fa689bf8a7bf,
+                0xbb01d22d85c4af90,
+                0x70b509920bc5a2fa,
+                0x55f5246783f3f3e7,
+                0x133058e8af22f2ec,
+                0xa58a1965349ff3e0,
+                0xb53bf28a2d0863bb,
+                0x6bbc754360d77457,
+                0x6c4502bd3627c307,
+                0x2f3b159164ce
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdd03545662ef5042,
+                0x24cc49f2fd9d4347,
+                0x59410bb25510648e,
+                0xaf58893c3264b130,
+                0x2342ccc2184fc2d5,
+                0x2ab0eacae0bf9a4e,
+                0xe2a535b129c2daaa,
+                0xbc8a87daff1b0251,
+                0x42bd7b8d9c1342ef,
+                0xa8843293aef0f257,
+                0x14f386e5a2617d5e,
+                0x7b80515c9910
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x95ca474e952a7bf3,
+                0x4bff8838762a51c1,
+                0x361b3234e3ba3cb4,
+                0x87156c691a022e28,
+                0x3ef62cd22f427b8c,
+                0x5b07f076daab41ca,
+                0x6ec64ef165b994a3,
+                0x11ce2db72be9f2c0,
+                0x75ec6c1246a002cd,
+                0x5f4d0d1237676d03,
+                0x32d539c104240154,
+                0x18008eb2482cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x84e7b94b509ea5e,
+                0x109e31d66d7ff2fd,
+                0x2fa4aaad17d09e39,
+                0xc8d144d611021128,
+                0x44ef80b9a2fa8c9e,
+                0xe8552ede2dc96867,
+                0x6e39dce4642c6249,
+                0xf170fab17a3777ff,
+                0xa05759f391e5f1ad,
+                0xb8882b47c34ad111,
+                0x75b710de45a9824c,
+                0x12dfcaa89adac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x416ba97ac9dfd582,
+                0x13e93a1c88299581,
+                0x51cd3b9f302a3a73,
+                0xbf64852aa4e79c15,
+                0x5002ec1b0f497e92,
+                0x56f1925277760699,
+                0x2c6e91a3ff34d5ce,
+                0x6d9a27bc3c231154,
+                0x1339934e54984beb,
+                0x8c941edafe5562ef,
+                0x8a2497b4ff51cc2b,
+                0x156bbb41ee241
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b036ce0ca312e46,
+                0x8e4782d6db72f8a1,
+                0xbb9b670d3860596b,
+                0xed801242b6adc8c6,
+                0xb73177117810ccca,
+                0xe122bce5f5c7a474,
+                0xb2d1808a03309a1f,
+                0x9235822db6942eb2,
+                0xa858439e7eb05961,
+                0xaf16abb1dc65cd00,
+                0x6cf4f444abe70f02,
+                0xee131bcec4dd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfff47988805aad77,
+                0x92ab8bde24802af8,
+                0x6d880b93558e9ec8,
+                0xdf74ecce95deff7d,
+                0x5406aaa80258a391,
+                0x363cefbd04e325f1,
+                0x4fb5094fbbafc967,
+                0xc9428ca78460505d,
+                0xe531765bb48b9d15,
+                0xe3a8b29de53090ca,
+                0x1bccf4d3b0a04cc3,
+                0x1a260a9ba098c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcdc538510982e568,
+                0x673a346194135f54,
+                0xff5d63e60d479b3e,
+                0x545a93312e4a2f16,
+                0x63a50fedc46f2205,
+                0x27d567419103bc30,
+                0xe1e0a0aaa6bfcb6e,
+                0xc4a9f1f985e8407b,
+                0x1a65af817f89601e,
+                0x3008eef7ec3a07fc,
+                0xe3807f1ede269ecf,
+                0xe895e692a795
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfb57b497bdef14e,
+                0x52d965fdc6fd983,
+                0x6dd3bcb0b4883ba2,
+                0x967d667c532c91d2,
+                0x9c134293735180e3,
+                0x43f584dfc7b8fd75,
+                0x1ce0c4bc2c7b5723,
+                0x54926545d6d8622,
+                0x288c5f54bd037188,
+                0xcbac78a8c97d6069,
+                0x45652a6a84da2334,
+                0x260caec05474
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a0b4cd34770fdd6,
+                0xc4fdfabbe3a38ad1,
+                0xb7f56f852997fab0,
+                0xada3e0b4a8ee52a,
+                0x345d1e6806b78a57,
+                0xd2647e5578d13874,
+                0x65950fb72f99deb9,
+                0x97adfe7a7b2ad265,
+                0x45617687373a5775,
+                0xc469e68d8c77a97c,
+                0x96ac180c36c869a0,
+                0x138084774610b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cb205c260cda271,
+                0x46fc02ac9431f2d7,
+                0x3f168ae3d51ea200,
+                0x575a67f66437577b,
+                0x887406cf1de2c873,
+                0xb9fd7e50a6aa65c3,
+                0xe5a8f12bc85ca7c4,
+                0x8614234751056869,
+                0x6f2a83622a56494a,
+                0xe072e9afa1193df2,
+                0xc1d93067efc72050,
+                0xdc975cca1a08
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd83925f23910b44e,
+                0xbc338785d69274ba,
+                0xd266be967d6ff629,
+                0x519672f4583d7f77,
+                0x8321ad4351505c1e,
+                0x7740f2dd19d9c7aa,
+                0x62bbeea155115963,
+                0x68ca6a5aa3306153,
+                0xb0441b95aa50f038,
+                0xc9f97e8da29496d7,
+                0xabbc1e4b3c35b8d2,
+                0x1463526da8ef2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a7694ec97ded685,
+                0x41bba48188938fbf,
+                0xb4cc27f9adbca980,
+                0x51defaa3ab866c0d,
+                0x712d9a72a907eb3b,
+                0x8e22f662398bdcff,
+                0xd5c19539a90d819d,
+                0x177a0eea871619d9,
+                0x6a5d3c2ce49f2adf,
+                0xd4e556de073ec,
+                0x43c04a224f232445,
+                0x6284072204b1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7795fb4700860b56,
+                0xee03de90192f736f,
+                0x4ce5759d36bb2797,
+                0xb25f94968478e064,
+                0xacd9fc57cb5ada33,
+                0x273a4b18afa050c8,
+                0xda07a878274b07a,
+                0xd5eaf817c3efe05e,
+                0x5388b85381fe5a8e,
+                0x3095a27904a6f610,
+                0x340e82ff5dd26b7,
+                0x1633c39561a30
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce5556540b051908,
+                0xe477e037ae695289,
+                0x299adb5eaa5bbc67,
+                0xb4b9025299ed5b00,
+                0x7ca784bbaab7d0b2,
+                0x857a613471f1359f,
+                0x1147a9e00cbfcfe1,
+                0x5d6c70d9689571b0,
+                0xb8de50088ec9fb3b,
+                0xd1b4dc5a81c4e839,
+                0x986b718af1d0d190,
+                0x527c283844c0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf7b4e6605fb3fdb,
+                0xefe989c9b436e1b1,
+                0x6ce986073a28c3b4,
+                0x99923955e0d6557a,
+                0x2260ff464a70f1b9,
+                0xec23e54eb6242a52,
+                0x305436604b061298,
+                0x5b69b3e98cbe7123,
+                0xc1d37c5276d413cb,
+                0x4a84db6348023475,
+                0xa44d9485bcbd6461,
+                0x1144cf7c036af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf71ce065a20f3ff5,
+                0x6dfff99f28903cbf,
+                0xa9b0753145041f69,
+                0x51b8c817608042ac,
+                0xbe5e976b9294c712,
+                0x21adc0c7848ff74a,
+                0xfb1199ee8342a3a5,
+                0x3b05c8f966554f28,
+                0x4a771a15bc390e68,
+                0xa569f807577f84f0,
+                0xdfa06d2035880594,
+                0x138bfda25ddcb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbbe25fedb9db3150,
+                0x15809bfe6a70dd70,
+                0x95098719f87a70c1,
+                0xd61207b5295b4e99,
+                0x1592cf77209be320,
+                0x4968c3a1ea98d1c9,
+                0x18531bd6dd39ef9,
+                0x922b07f1989c9eb0,
+                0x8edc19d96447d975,
+                0xfc88d0f80e32ce8d,
+                0x6eb0cf27465e288b,
+                0x6819064b9ad8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5c38622f16d2863a,
+                0x35a4409c4d3e634d,
+                0x116be06ca20d7a3d,
+                0xa712e95664583c85,
+                0xce1a530b0d5019e8,
+                0x4daf4df0ed4b5268,
+                0x85794918bc2cbad4,
+                0xa670e697386ba85,
+                0xfffef8f647abd104,
+                0x6744f693c9395dbe,
+                0xde55a59b160f2f3b,
+                0x103d63d31848e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x24d423c0b62560bf,
+                0xe3243429eb5cd385,
+                0xf062d097d09e7219,
+                0x909b1df4aad50ac6,
+                0x7ee3809f7077f333,
+                0x6a04e7b972fa5355,
+                0x2ca5e78407e9b158,
+                0xed60af1e28d8faab,
+                0x43949b93a78fb014,
+                0x69a83a47d1060bf0,
+                0x9d2f550f7b268301,
+                0x6329c71c1c4b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbf4a2382e993a52b,
+                0xcd72a1c4a8739f46,
+                0xd3e75ee1844b6c8e,
+                0x74d7441ad7196f72,
+                0xa70b3b4615cf486a,
+                0xbb6300aa5e2dc8ee,
+                0x16976bff29895722,
+                0x2815e60462a75a86,
+                0xbb3c735d0e547aa0,
+                0xd4cf17cfbc35992c,
+                0x61eb7dbe48f7eaa3,
+                0xe462be710b74
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0cf2c4b6402d97c,
+                0xd1a9d97f3fdcb485,
+                0xc4c4ca6a787f84b0,
+                0x95f247f031eaac75,
+                0x93e996f91caac4a1,
+                0x8afb5350f1bee479,
+                0x5a717b03516e4dee,
+                0xfc9f2ddfdd8c8663,
+                0xf30e37af5f489deb,
+                0x27dba615b5cb7479,
+                0xc21f9b00737eb19e,
+                0x358713110a2b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd7ef7541d95d8937,
+                0xc9899628dacf494e,
+                0x9084af74d55ed623,
+                0x4fd56704bf8cca47,
+                0x328467b75a17698c,
+                0x14bbff57d15c0ff7,
+                0x9d428284dbfc3f90,
+                0x3bc91ef52dc66fd1,
+                0xd4be4b30364d7e9a,
+                0x973b5a7c0c388a78,
+                0xa3d3ba392a710ded,
+                0x1538e8e92f52
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6dd6d04b55b508f0,
+                0x9ee9db6ec5eb6679,
+                0x6b978ec02ea93ebd,
+                0x5dd2d75c5575ff1a,
+                0xbae74856627a87ff,
+                0xe4ec9f03f5498ced,
+                0xc898993654fb548e,
+                0x7967186ef1656a79,
+                0x236b182b0aef572e,
+                0x8152d760b90b6f3,
+                0x53029668830d55e6,
+                0x8d9eb71b2f0f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2afc3b6924f00a69,
+                0x3ccbf0de06acc8a8,
+                0x9a52a3815be299f7,
+                0xc112881df30c8da2,
+                0x6e2c32586b9987bb,
+                0xcb27eb6d2161f1b2,
+                0x811a12ad207cb051,
+                0x15562927436622b0,
+                0x49726a95bd4b3ae,
+                0xf11a76c61752c127,
+                0x64851a8db56af2c6,
+                0xfaf8d67933df
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe45a330b90ae9dfc,
+                0x7152262955c6e1c,
+                0x363dc6ce4fe4b8fb,
+                0x795249905c8fcc88,
+                0xc229637301db6223,
+                0x865198ce4253484d,
+                0x3ac819162c09bf58,
+                0xe6419e96ab7ae7fb,
+                0xb98271851e87fe71,
+                0xf7e188454245a328,
+                0xf659376ca983c532,
+                0xa21af85b2b54
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9fe1227ba34b2889,
+                0x61a4d94009257bc9,
+                0x1220b0ea6fd3a6ff,
+                0x26d1c4fe5ad84d5b,
+                0x31d1a7dfc5ebc6ac,
+                0x514cef73ef917fc3,
+                0xe754b660d09b286f,
+                0x8c5c2551977dc581,
+                0x38c950813a04cd9d,
+                0x832a65ebe2293d37,
+                0x29741407f0fc16cd,
+                0x1932fbfb045f3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x78ae94ae50a1751f,
+                0xd45e5288f1b51335,
+                0xaaec9f9efb6f1e64,
+                0x319aa1e7dd029970,
+                0x6fafaa6aa94147b8,
+                0x8a41118c8cba80d4,
+                0xc8c7ee19ac92843b,
+                0xa3695fbd690a20b2,
+                0x787a0ebd5408c924,
+                0x449e46344f183fc6,
+                0x8f0e7434cb319297,
+                0xdbcfff09ff1a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcfd3d9b783ec8933,
+                0x7ba3931d4a8b31cc,
+                0x91da7125ef5fda67,
+                0x743fd62c1153b5d0,
+                0x67b81928f574757e,
+                0x52efc352c98b4b49,
+                0xfd0af3974272f92f,
+                0xb5fe3af86b81cf98,
+                0xc20a7b0b8f5fc9cd,
+                0x9c975e60bd57b17e,
+                0x19103cfce32f42f7,
+                0xc312ca63b20e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfe26fd9def42af1c,
+                0x4c1dfa2131cb0973,
+                0x7ad59157ba891952,
+                0xffe679eeeada7bb,
+                0x31432ac8ee274831,
+                0x165f11b6c5e84e88,
+                0x9f83b37eda405efc,
+                0xbd3409b11aaa1935,
+                0x3d0b56507345faca,
+                0x8488ad90191fb701,
+                0xdc450983384768c1,
+                0x1066be72d2bbb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ca4063774000455,
+                0x75f46453761b6b74,
+                0x722f3cdb6076ff7e,
+                0x3f1927316c18533f,
+                0xb0b8aefff6bb0c75,
+                0xe9b12aeb9d2bd583,
+                0x7f57a399a2dedd35,
+                0x7471695bfe4098c4,
+                0xb26c80575d8ed5df,
+                0x42654bf7c2b13ad3,
+                0x82490964cf17a452,
+                0x19b674ab33e8b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb71161fc01905678,
+                0xc5b52a70879d66da,
+                0x7afe9b795f29e5a8,
+                0x94b8215052d5953d,
+                0x16c423695741867d,
+                0x6d0add3e025eb02e,
+                0xeed397f63b49e2b,
+                0xdedb0c9dae3a06f5,
+                0xa6a10c20c77b4591,
+                0xc192330a3d9425ad,
+                0x73dc2c74a11717eb,
+                0xbbe0ab1c956f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70fd09b5d7e872f6,
+                0xcb0271137265b705,
+                0xe31963c3c5c0afbe,
+                0xf16f74a8f8856978,
+                0x3b8b4eb135680559,
+                0x35aff1d151ad5600,
+                0x107ac4229d5716b9,
+                0xb2cc5697956d1333,
+                0xe623fc0d2a79ab96,
+                0xca8435d4f9e93890,
+                0x64b602a65b2ff4a,
+                0x17ae867c31c01
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8039457fa0496759,
+                0x6e4d6fa961d6d5d9,
+                0xd736ab8fd5ccb7bc,
+                0xbc827ec150ea20d,
+                0x1bdc54d7256585da,
+                0x5a6af4b02cb4d42e,
+                0x690ef30a49072243,
+                0x9f2d05bfe49f0781,
+                0xdc830ae2b1aaddb3,
+                0x8ecdf86f5b97bb2f,
+                0xe76f74df31a7f1b,
+                0x151fad046a106
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa9d37831c52f002,
+                0xc0d6b13decdac85a,
+                0x6cd68900f89002ec,
+                0xc8a134dbdad4eb59,
+                0x982b71a5e0d781bc,
+                0xbbc39aad88086ae5,
+                0xf4240751bd61ea0f,
+                0x1fa3a3c2c1d6268f,
+                0xf4d3d9122dc9f9b1,
+                0xbb736d38c439a50d,
+                0x8b4c22e2d74d80ef,
+                0xf33d69a7357d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc9596cfc94c5295,
+                0x154483a3bbb5b984,
+                0xcd8b3f8d2ebc8909,
+                0x66551b370f60cd36,
+                0x3a187f073024bf49,
+                0x2d1051d3eb3c64,
+                0x9066b2147c2bad3e,
+                0x5225bdb456b0896,
+                0xa2cf056967f573c6,
+                0xbf527e3c7985008b,
+                0xd5824db1ba514d77,
+                0xdb35a69aef33
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1c95e48cfbb8069c,
+                0x89c2c5a2f26362f1,
+                0x8af48ba11b8cd03,
+                0xe4fe8455188d46d,
+                0x4f60ae462f14d1ef,
+                0x7d0f8921de5e2ede,
+                0x89596cb0dc5da569,
+                0xa91edcba6e002ee5,
+                0xd425f9e36b39c444,
+                0x16418b445fce40ce,
+                0xd9ab6e42979e9de9,
+                0x6ba91684de40
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ae4d4b5ca7a7852,
+                0x52d75a7c1b5a2d81,
+                0x7c35bd4a55784207,
+                0xb937638cb10626bf,
+                0x168b8dc31fb47225,
+                0x4de313c378aa1105,
+                0x457640e04107f0d6,
+                0x95c3ee892fa7e62d,
+                0x106afdb18e2fb683,
+                0x34cee2a133b1bd95,
+                0x64a8b5cff8d99dd0,
+                0x15b691db2a595
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x14d678f07789ca39,
+                0x15ba39bb1a05363c,
+                0xd519ea22ef2b7dd4,
+                0x2bcabe5789d39b33,
+                0x5bc533ca94caf107,
+                0x611c536833755c50,
+                0x631d6c56105ff927,
+                0xf4d05b777bf16dcb,
+                0x64aa41208ae1ae62,
+                0xb34513a254feea8d,
+                0x574dcfa106655017,
+                0x4565c235a731
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7444d9cb6a904ef4,
+                0x8570db4a9915b2c0,
+                0x888c1568d1e903d1,
+                0xb714a2616cc1b252,
+                0xfcec4be4f526ae83,
+                0x911b1d405ae14945,
+                0x56f818e4c0b8df02,
+                0xc2adf20156f749b,
+                0xb3dccf5b238abe3c,
+                0xada341244460197f,
+                0x55061fce2dcb942b,
+                0xae2eca707263
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2f5979495eab7f4,
+                0x6763cc97044e8991,
+                0x8e64669c126e5a31,
+                0x88a6b85b5e2f6a15,
+                0xbeecd065b4afcbc0,
+                0x87360f4cc18cd858,
+                0x3e92893804e4bf21,
+                0xfe0fa21b98bf7f63,
+                0x1532b9d0956a4794,
+                0x83ecf1f4fbd2ec8f,
+                0xb9b59fa0f9b9f99,
+                0x16c8d42812db9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x52e8fd7679954b6e,
+                0x101abb3c579cba04,
+                0x733717c5d67186f7,
+                0x5dc971ebe428b00a,
+                0x46d1118456dd2094,
+                0xd2329317c77d0079,
+                0x639778fc33eff1b1,
+                0xdfefeb6dd3a67695,
+                0x31162e55756d85fb,
+                0x6644861bcb945989,
+                0xae6cd10ba82b89ba,
+                0xe0fb3f2d2aa5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x92156fdcb14a5b33,
+                0x19a4647c49515942,
+                0x76ff1edb9f4b8c33,
+                0x179d1570b19076f8,
+                0x83bb7ca27a7cdd05,
+                0xd4934b5306008bc6,
+                0xfa30eaaa3dfed5e2,
+                0x17137ca503a354f6,
+                0xbea3671f1e7bc311,
+                0xe3f2d9c0a8088c34,
+                0xaf2edb02af937cd2,
+                0xb850a1f51293
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x859c2c87b25f9bab,
+                0xcb1df459b13f5202,
+                0xcba115e6ff691e36,
+                0x915c2784b89d39ad,
+                0x4a19270d6be95ed9,
+                0x934a54c17202829b,
+                0xb2d0f21db42f0338,
+                0xe85c1283178ca461,
+                0x34e2e0fedb294bf0,
+                0x7b63bb9d25ae73a0,
+                0x2f979644b45dc6bb,
+                0x6049ac08c968
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa9a4452ed6ac605c,
+                0xbab3ec77625f2695,
+                0x3d7f6b9b95fd86cd,
+                0xe2343a0f49593108,
+                0x99dec57c39f24539,
+                0xf3f9544c02bfe8b,
+                0xd4c1681837de282b,
+                0x9848e21816a3a78f,
+                0x2c44bf132d838c9b,
+                0x44003d77c66e1320,
+                0xd7143eba06f7a816,
+                0x8d5c2d35eeb1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x42bbf206e43fad47,
+                0xb3f4fc09e0c7ab0d,
+                0x64b3041fd8c7e3fc,
+                0xbfa27792ea55e9f4,
+                0xc845c62951c16b5c,
+                0x54e6b979c83b6e9,
+                0x68ba7acd5bb2df0c,
+                0xe4b08ba3028a5966,
+                0x786ebdea900011bc,
+                0x521e48e5952c7e2d,
+                0x80d2d27594f6cb10,
+                0x10235f3ea1f50
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa92eb9b4b1e9ed6,
+                0x9b426efdc49a6a1f,
+                0xfffc95c6862cd08,
+                0x455947e488688daf,
+                0x31f4ab555eebd321,
+                0x70c193e184977370,
+                0xcb20e8cef06fb3c5,
+                0x4cc30b57b6526970,
+                0x9594306efad853d7,
+                0xb649dfeadb2cd622,
+                0x4974dace208bed4e,
+                0xe424745acc9e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66c5c0067ff3258b,
+                0xac5144c8da477ab1,
+                0x3bd18b73b6f871d9,
+                0x6ea373b639dcae87,
+                0x37bdf83a80432773,
+                0xd279ad49e8f45da7,
+                0x46ab847b9c12a66f,
+                0xb0f66a18c36ed846,
+                0x8e7eeb614b684c69,
+                0xd5743d1a0207f78e,
+                0x858b24ff74526fcf,
+                0x164f7cb7ec991
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc578d8699169d64f,
+                0x6d30e1b5039921,
+                0xc76ec16bc01640b8,
+                0x8a9b8cb469233222,
+                0x543d30629dfa6d23,
+                0xf756578bcf2eda,
+                0x2f152828d39678bd,
+                0xfb8bce9d20089f79,
+                0x38f39ab650e2511c,
+                0xca9b6822e0c14235,
+                0x80974e1af1b6f95a,
+                0x190a7af5e8785
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ddda2f283e9f89e,
+                0xd824710920f3f120,
+                0x24cf8b2fc45c8b84,
+                0x7033692f94e5b60d,
+                0xc7bf0f9f4b317d80,
+                0x1925c86fcc710d0c,
+                0x7182f89cb88cdb3d,
+                0x59640d0bda855694,
+                0x33b4824ff9753925,
+                0x900c47f15f4ff610,
+                0xa460123b93825365,
+                0xc6b47007ba65
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x377df4dce8d9c5cc,
+                0xd06cfe38dcf929e3,
+                0x50c3784d557c6a16,
+                0x1cf78e77905235a4,
+                0xba8858f091223d14,
+                0xf81f121f36039376,
+                0x9283f649b5e3b4f3,
+                0x89390dfa1a6b8249,
+                0x26895dd6e7c48814,
+                0x231c1bb1cf57edd5,
+                0xc63ecee8616d4570,
+                0x1357bd2597c6f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x84697d6c326e544c,
+                0x5153bc56d4416603,
+                0x4de9955b506c12e9,
+                0x26653aa47e794e4,
+                0x82d7e2cbdedad471,
+                0xc89eff5d8e68fb6e,
+                0xcdb69f532fa1c5bf,
+                0x994ffd814c2ea27c,
+                0xfdb196544da1b128,
+                0x3f3a59ffd6e3935f,
+                0x5b8de945d46cda2,
+                0x14ab793333960
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3e5f15ead8f078c,
+                0x5511b92a63d5a135,
+                0xd94f2fb35b16b2e2,
+                0x5c58b95039e55198,
+                0xd6cee9c99d4e5073,
+                0x9d0c4921855270f1,
+                0x83b7cae85500648,
+                0x36aa082f268a8575,
+                0xaa49272b353be86d,
+                0xa1db16152feee335,
+                0xec5a52fc15db513b,
+                0x1038dc00226a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a8cee28c7c9c783,
+                0x4b6e56af1f25d5c4,
+                0x53806dbec5c4b7ec,
+                0x1f957609af5eb9c0,
+                0x10f5e3c435055994,
+                0x9d23d5d8545b8e39,
+                0x186915f9042189cf,
+                0xc9d06f182207990e,
+                0x4bc5735070927b04,
+                0x667aba942bda3757,
+                0x59ddb7bd4aecdc74,
+                0xe0fb594bc1d5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe06dc1aa0c35a99e,
+                0x5cdf8a5527a1814b,
+                0xa0093bc2973c55f,
+                0x55b2ff1320881169,
+                0x3fd1e40676c37891,
+                0xe29570f5a16c57ba,
+                0xba8637a7f987add5,
+                0x3cdafdcefb7ce091,
+                0xb0d1fcbe9e70b1b8,
+                0x97e5ddb42698acc1,
+                0xbacb9ffec11b9af9,
+                0xbf62ae934009
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd784e462daad7936,
+                0xf91328e1cb37c146,
+                0x9f28c0fe7c2b8514,
+                0x1eb02c92b447928,
+                0x332ff21107bf673c,
+                0xcfa35074f94052c1,
+                0x16b3d6f6afa3d36,
+                0x9516e98fb08d5a2a,
+                0x952e195036626e37,
+                0xde6dddd716a4e419,
+                0x8691c4105a59f01f,
+                0x1a519a19e8f9a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdf9ac9f181f9956a,
+                0xcbc601edb53c473d,
+                0x7f6cc1f5da994012,
+                0xbd238d56090e8caf,
+                0xef41ba2a567f0cdb,
+                0xfa87985585aa60ad,
+                0x9c03e6cf6e20cc7e,
+                0x1db3d3e1f936bf55,
+                0x6c4ab50c70621df3,
+                0x43ab512b462e066a,
+                0x377b454f9bd11706,
+                0xe433db9c1c5d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ab0882082ffb3d7,
+                0x7989d585477b5564,
+                0x7c1f86adce9065aa,
+                0x3481d426b864e5a2,
+                0xef79d6b70fde6b5c,
+                0xc70126b9203d505e,
+                0xaa8ab963b8996319,
+                0x9ae6e5f7d90f23c0,
+                0x9da593ecbcb38577,
+                0x792e9a0a471e9b8d,
+                0xaa1b7bb9132bb4cf,
+                0xc748bc5910b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6ba6346bed21dc62,
+                0xd0e08e29cd1352e8,
+                0xfb949925cdf7404f,
+                0x2b756fbb252f22a1,
+                0x7e4d1f06ac3c91dc,
+                0x9620f4dafd383d98,
+                0xa3fb59e097142b1,
+                0x7312ef866135ff57,
+                0xe5f100aef6ce109a,
+                0x2a5fd6ce3c10e8db,
+                0x232d502c8359ba61,
+                0x149529c83da8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x190f9c61bda3368,
+                0xf50f25a1da6327bc,
+                0x83d3a473aa5244cc,
+                0xa8bf721b7a6e8c6e,
+                0x4e2b39e15a6f74d3,
+                0xf889f277bd26b5c5,
+                0xe4ca7c76b65ea6a,
+                0xd863622506995e33,
+                0x9aa1038beb358ff4,
+                0x484cad3e6585785e,
+                0xf5090e47e7bc2676,
+                0x121d990478f3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8408341b9c4fb236,
+                0x92ccce778baacec8,
+                0x90be06c399f89c80,
+                0x76a140dd5830fb4c,
+                0x5034aad242443698,
+                0x7cb6541491a18dd0,
+                0xf38b84c5c08b9a7b,
+                0x79cb0f08f07deb5b,
+                0x54ceba4788912449,
+                0xfe72a4fd777db052,
+                0x1bf6f9f6fa74fe48,
+                0x12b3845cb9cc6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x85d1eca34f5e54b3,
+                0xf286a2248efe7d9c,
+                0x3913952866cebb7f,
+                0x54519420b409c865,
+                0xb9e78bb19171770e,
+                0x91627dbf333c938a,
+                0xae16284af7455075,
+                0x3eab43f27a192860,
+                0xec617744a928ee2a,
+                0xeac9d9186920551b,
+                0x25e79a2ba3bda8da,
+                0x1853b0b7d7557
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe9bedadf85858276,
+                0x7586e1642d7bbce7,
+                0x90ca43ce145e740d,
+                0xf74cc2a7b9fded12,
+                0x233b0dffd1d78429,
+                0xfab407a56b1d6f58,
+                0xf0f8adfce047a706,
+                0xd13185c4298294d6,
+                0x5774d400edcef9cf,
+                0x7f6e0bdd60fb9271,
+                0x9038aa9c9f102a4c,
+                0x4913f96b3796
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd4950a248385f29c,
+                0x53a207fa2ba8d1e0,
+                0x20c41a430fa54904,
+                0x76e4adfcf203a1fe,
+                0x2739f5e3e41cabbd,
+                0x3be58f3452556e12,
+                0xd07c924ed637cd78,
+                0x64d82e8b517deeaa,
+                0x7e7990f538afd9b1,
+                0x4fdf1e3e499d118c,
+                0x70002b4f5c11ebc7,
+                0xeb579cb31d11
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x881560c3eacd12c0,
+                0xa2ce435f59eb09bd,
+                0xc0ed38b7e996a72f,
+                0xf0ff82c6fca1ddb,
+                0xfb191b5f14f87853,
+                0xc9c965e8dc03acb6,
+                0xb7382be328e2e226,
+                0xa1ffa3a9fe8ab9b1,
+                0x13559c57f3ce2630,
+                0xb9c7ff0adc5cb54c,
+                0xbfd1a1298144c38c,
+                0x9a5b445c2193
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa2dc52cda676a6e,
+                0x400b166160ffdbef,
+                0xe4c3593affe17b00,
+                0x31b7eea9df38ba30,
+                0x5f0238c8ba743453,
+                0xc441dcc3c6375332,
+                0xe3ad5de004d80d89,
+                0x9d0de1d8bd3e63a1,
+                0xf4cb91953b92ade6,
+                0x4a4a409798dd707e,
+                0xd3f89f2a5ae2bfba,
+                0x1ad6dad74ce77
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8afbef771798e678,
+                0x186e24531be289ce,
+                0x110082339e7fe9e4,
+                0x9249087aa7c8814c,
+                0x7474e911a550f724,
+                0xd3cd4b6c5dbe5908,
+                0xba7e86ff928617cb,
+                0x3278274bd0492840,
+                0x763da8204c666b7c,
+                0x9548acfacf7cd1a5,
+                0xf78954e143510ff8,
+                0x5e0cb563cb10
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f3bb9caf8799040,
+                0x338c2b05efc609e,
+                0xbe93d60fa5eff636,
+                0x3f628a0e675a6256,
+                0xc6cb32fc8fc5c148,
+                0x8b361b69510ddba9,
+                0x502fba84c3d1d673,
+                0xc00f767051bbcb01,
+                0xaf55c4a8ab897ead,
+                0x56bf5d07708a81df,
+                0x583db254906fd7fa,
+                0x607b64989dfe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x309e2321d030004a,
+                0x921d881f09e28290,
+                0x86f1db6ab84e569a,
+                0x3183c42a8abb638e,
+                0xb0cc21dabbee9258,
+                0xb4705781ee8f4773,
+                0x2e9e0bd2d49206f0,
+                0x5608be21d5b56c4c,
+                0x3f15a0b100a86418,
+                0x98ae882c6968898d,
+                0x900aac8ddebd8090,
+                0x18aefcf956d8c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7cafbfba7c65c64c,
+                0xb50d9ea7c8883869,
+                0xf6efa4593c2ac93c,
+                0x655323b8a60f096e,
+                0x3c3775e71cd698bd,
+                0x8f3ef83e91ec53d4,
+                0x89b9067a97a2671a,
+                0x89a954469f5687c7,
+                0x97051ebae785dfbf,
+                0x2c223c5e15af9237,
+                0xeb766a0f41d0492a,
+                0x95e7a51d6fe3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc3561755d825bad6,
+                0x4587c0e3fe43b1bc,
+                0x1b1f20497852b67,
+                0x816c8e6e91f63b88,
+                0xdcdd1c89bdf2232b,
+                0xd47c172f867a00ba,
+                0x4ca162682a01cb9b,
+                0xcaeb7bacfb5f7c2d,
+                0x67863c57bac1c022,
+                0x1cf303531cd25bfc,
+                0x4f86a0ab94da2ed5,
+                0x78fd9baea9f0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x23e8318d0d2b4e1f,
+                0x64ca5d104157ebea,
+                0x26df04f7daa65c16,
+                0x7606f7bbf51fda1,
+                0x32a71d907d0df808,
+                0x2f5d411f614a0956,
+                0x58b696c0d7ea3596,
+                0x81ef54a17ed1a8ba,
+                0xa2cb6ce96f717c2f,
+                0xe968b15c999bead6,
+                0x753268d8a77653ee,
+                0x773b820257f5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2b3358dab696fe0,
+                0xb128dc8d8c45f8da,
+                0xaed67a16c0a18a41,
+                0xa8d687c263a34bb1,
+                0xa36caf404e4056a1,
+                0xc44b3f6fedf8df21,
+                0xa8c3e64f4e6a46d6,
+                0xd95735f36ebb59e,
+                0xba83824841c05a4a,
+                0x20e5ce3047a7ccc3,
+                0xef3e770dc0b45415,
+                0x971a59b282cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x94f95275909839d2,
+                0xb0564c02d94f88b9,
+                0x853f357539549a87,
+                0x602d14b5d2d629bb,
+                0xbb3b01348c9c052c,
+                0x1104d640a087493e,
+                0xce6f92acd1d4450f,
+                0xa3a7ec418fbeacc6,
+                0x358b33efe4a7109d,
+                0xe34bd3cf83da4da5,
+                0x5fa75f4c8895dc1a,
+                0x67210de23440
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc7d643e0a5f71b30,
+                0x756f8163abfd96cb,
+                0x8f513a8bbed33e61,
+                0xbe9371c8bd56b6d1,
+                0x369c07a8b57c4280,
+                0x7f9a2dc52a255cdd,
+                0x529785a49ee57f68,
+                0xfe0c8b2ed2050f18,
+                0x9c7cb5d8dd6eab7e,
+                0x4b612b1a67915455,
+                0xe2b3e2e752820c0e,
+                0x136aedf11f2db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x78c631bf7578c05b,
+                0x9d526b9818246420,
+                0x96b643eb8345e342,
+                0x4ba5b968f6f4d4e4,
+                0x18932203aa4068f0,
+                0x41d4cc7f4bdad28b,
+                0x4b383d4419b397d8,
+                0x94fe52a7aeb8e0b0,
+                0x80451f2f544e2fd5,
+                0xa2950de8dd6ba4c2,
+                0x475e198161535fdf,
+                0x138ed0f96506e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x92e60299c5282e2e,
+                0xb633c48ab3ddc30a,
+                0x4a305063d9b41899,
+                0x912f7a043daceb70,
+                0xe5868afaad001d6,
+                0xbba963d3eef1ec00,
+                0xf879873b3a72e4b2,
+                0x4ac20d690a2a489,
+                0x31bec185df1e397,
+                0xbb43cfbeabdcd129,
+                0x7afa3fb8eb0579ae,
+                0x1487ebcb9c3e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xab0347adeff12bab,
+                0x1a4d641dd02b3826,
+                0xbb06110daf0b5c42,
+                0x42e4bf9e6b31d133,
+                0x4bcdaa64ff7c3afa,
+                0x3134734a516c1b7e,
+                0x43bc9d0cee55bbd0,
+                0x71c5884924e07131,
+                0x368c6369f34a94be,
+                0x367b18b14c82e771,
+                0x6ae8a1672e68bbe4,
+                0xa5b86a9b89f6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x92f844d1106cce7,
+                0x9c12cddbbf04c6ef,
+                0xf0c7780a198a42e1,
+                0x462cf2a019b15f6a,
+                0x32ce6179073f04fc,
+                0xf3c34b9e668ce113,
+                0x6b794bd40637208c,
+                0x4d3de5e3b182e78b,
+                0x6fad3bacd9319b2d,
+                0xdd8d6e4bf2bbacc8,
+                0xbe53eb1e6098899c,
+                0xf8b93a562afc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcbc0740cb1e7d669,
+                0x1ee9bf0928878e91,
+                0x1a1f7edd99e789fa,
+                0x14c75241e4fb2b09,
+                0x96ee496c47252934,
+                0x27363cc01591f94c,
+                0x9aeab7398062b17c,
+                0x8254ae9e55aa5d32,
+                0xfc0e9f65ff2d66e4,
+                0x2b16ec1d3619a4d9,
+                0x641a28b94a00fd8e,
+                0x16f0e34c9729
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x408d365f66d109f8,
+                0xf1bae2b88f34cd16,
+                0x2eff592cee77ccb6,
+                0x660509c7677fa404,
+                0x581e3a523157a3,
+                0x89044efa2c71f4b,
+                0xe93d0198d90ace58,
+                0x760386405bda4a5b,
+                0xad2fe1f39335987e,
+                0xeced224f2f81e8ce,
+                0x98a93c40298bfbdb,
+                0x88d3ea7bb3b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc61a0567980c8345,
+                0x5158da9731f15e3d,
+                0xc168c5ed993de144,
+                0x331a9c7840a37929,
+                0xed9d09a31ebcd038,
+                0xe0e40b666f4484e9,
+                0x156b788c4a8e1df,
+                0xdbd6ed459a5b9c23,
+                0xcd394c812b7c6216,
+                0x9fefe80cffcb8445,
+                0x4406139b6842eecd,
+                0xb0c096cb65b7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb186ba6fac5f326d,
+                0x53bdb7b4d2579a40,
+                0x1bcf4ff9276f5a56,
+                0x21fbfc2a5c27fa6e,
+                0x9a5e8b7686ea5f92,
+                0x5e405e48aba8d09,
+                0x2b28624ea7082f96,
+                0x5d15f30337202b36,
+                0x8c559e75d5391930,
+                0x129e46b9accfd34e,
+                0x768648c97e1fa02f,
+                0xb655f1cb8a74
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa8016f1bfe9cfdc8,
+                0xf17b56ab3c177069,
+                0xeaf9ef551aa46899,
+                0x43c535e328979b34,
+                0xf2ffe32870d367f2,
+                0x30119655cdd2f6ae,
+                0x53972327066abe89,
+                0x1d6b1395f13da83a,
+                0xfce70d00da01a03d,
+                0x7ca6a347beb94a21,
+                0xee3f579d74669a64,
+                0x1bf64c1003bd2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd8567f8e211a28f2,
+                0x310fc0109d9b4c8f,
+                0xc90b9d15cd6e5652,
+                0x79bc32e7749dfa51,
+                0xc371a6726a32541,
+                0xa4901cd7b4774a77,
+                0x8aca7cac59160d4f,
+                0xfaa480261295a7ba,
+                0xa19debea2a8e4270,
+                0xe309cddb56238d68,
+                0xcb9ea1be0ee44772,
+                0x1aa50b344abdc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf3730042ab70688e,
+                0xcf1da087d1ef1df,
+                0x277dc77c3638415c,
+                0xb6af8ec53d608126,
+                0x22aecef6a600d39e,
+                0xae06ceaaa3c2bdf3,
+                0x1d46c31bbb6927c3,
+                0x37fea0b3960b4736,
+                0x67108ab12ef88611,
+                0xbd2786af5e20fda2,
+                0x2e8d26707645a1f9,
+                0xb931c66673a8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e880cdbc3920e26,
+                0x7ac7bc612a4de86,
+                0x165e602aae62a15,
+                0xa9b41ac28f0814a7,
+                0xc63665fb4202ffe0,
+                0xe1cca7c3c38c71d2,
+                0x30e9e0af23b1768,
+                0x3dd4648b3523a912,
+                0x227df62bc91e50ea,
+                0xa4ef68c7e065a1c7,
+                0x4ecb1216e313feb1,
+                0x7926a163b2d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44f40558f49ef49f,
+                0x5bc17ea0e0091fc,
+                0xa63b603a31e3c270,
+                0x9360ff6d21bfb7d6,
+                0xccdcc5ef48b40224,
+                0x88770a714b7bfb4f,
+                0xa6ecfa148a12829a,
+                0x1fe84a376dc251d,
+                0xfe2d010de20e9f46,
+                0x251896971b6a5c7d,
+                0xa051706e7df6b64b,
+                0xcfd646e5de7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb50e47ad213cfa8a,
+                0x292c5f53b64c8e3,
+                0x402e49dcb606ade9,
+                0xfdc2efe160e0a951,
+                0xa1d786430b2c75f8,
+                0x4465ea8ea04c2a5,
+                0xcc3ffa046e5629b2,
+                0x145b80754c1f882c,
+                0xf781bdeb49589373,
+                0xd23df0d1fdcc3d51,
+                0xaf124e648a42aa1a,
+                0x143d9ab4473cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x965c3f48450fc821,
+                0xeadf215d08b675b4,
+                0x99f0e2acbcdf4b70,
+                0x11b59fda84129be5,
+                0x7f16f0861af170da,
+                0xbd754a9ac32db12a,
+                0xf8df15b156650a94,
+                0xbbcc3e65d41698c0,
+                0xc99a0e6aa2854049,
+                0xdad498664db41d56,
+                0x46284c2910ea9984,
+                0x17894ccae9db8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8cc0e39ac519993c,
+                0x5a8983230c8db748,
+                0x1cc91d5dbd38fb92,
+                0xa1c71acad3231d82,
+                0x8da752ad0f0d1da0,
+                0xf41e4f93755538e5,
+                0xe9aad661ccdb4e68,
+                0x72bd938e26be1abf,
+                0xd2682dd49dff2c4d,
+                0x5357794b6b40d4cf,
+                0x43fbc493f7772b1,
+                0xb3607ca79bee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb93c38b69751c531,
+                0x7046030c5a5103f,
+                0x24ebc2656139c4b3,
+                0xe9032c2cc928e432,
+                0xd3dfdb8c120725af,
+                0x9dfc989b8ddc4757,
+                0x1f87389363277a31,
+                0x5b38d243c38074d5,
+                0x726fe48173e43988,
+                0xef65586f836e0039,
+                0x9273dc6e69fcd455,
+                0x884c7666f7c3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x466c3b308546932e,
+                0x8cffb06d31eb6d0f,
+                0x2118ee7429c9a305,
+                0xc4f321bd7d4527e1,
+                0x5249ba05acbb4fb2,
+                0x8dc37d3a2bc388ae,
+                0xe9b9a275789a9f3e,
+                0x65fd8f63151606fc,
+                0x9e69abfb54cfd396,
+                0x922a0b5685800a55,
+                0xabd19da20f30304,
+                0xe7344fc7ffec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc8b2e810327eefe9,
+                0xa5a397a0ea9ca48,
+                0xeb8700ac5f218e60,
+                0xcd2cb90321ef195d,
+                0xb87b8dbc6d082e20,
+                0x7547644d1135b43a,
+                0x60ae2635588b596b,
+                0x5fce07d721adc788,
+                0x46ad956e6aa0b355,
+                0x35552ca784172f8c,
+                0x774b0c9f43f7885a,
+                0x4844fb97937b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x189c27f6a8cf7246,
+                0xbd6f39308d4e2211,
+                0x983c1ccd7021b1ca,
+                0x26459cf59b7341c2,
+                0x4b360595590185ee,
+                0xc999fcd14eec04c4,
+                0xf206693a94130d90,
+                0x7d1f217ca6e64652,
+                0x7b177a9c2b5db4cf,
+                0xcb83e67003179085,
+                0x71688e901e494313,
+                0x4ec92a8a139b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e1cdc1bb3ddf027,
+                0x67163cd62af0230f,
+                0x9948400eb7753ca2,
+                0x81a1415cd65dfd58,
+                0x766ad0bc14dc305c,
+                0x7d590477c4105b91,
+                0x9aa09d05b6dede88,
+                0xde2761e8e2bb9620,
+                0xcb270c97dd6ae4c8,
+                0xb217aab13065b92e,
+                0xff70df64a7d026a8,
+                0x17fec7ae69cd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe8dfb43cecdc9c65,
+                0x34beaa87c9e6a055,
+                0xc24856e424f0f08,
+                0x456e3177733cab44,
+                0x4774ce2ad5e9f36d,
+                0xcc9054be3b5297c7,
+                0xc25bcfaebeecdf84,
+                0x1876839191da34bb,
+                0xc7e1a5428e01c47a,
+                0xb7bbf094f7ef0c15,
+                0x2f2447b55f536995,
+                0x10391faef9d07
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1c2b808c87804c2,
+                0x1d396193956cf6c7,
+                0x64be69e2465e0874,
+                0xab9e637ca4796c5f,
+                0x1d5734c62f1d4fa2,
+                0x2eeb2a78ff6fef79,
+                0x8e8ee75f8e1783b,
+                0x39702e5a00059c2b,
+                0xb547f2d4cb991450,
+                0x7c5ad461ceb1330e,
+                0xab03f217f6ac3343,
+                0x1ebe46fb6fe4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x36051ea592270a30,
+                0xfa41172409e652c8,
+                0x84a9f2b3e85b2fdc,
+                0x2dcd6fc0550f9d0b,
+                0x9fb79bc066194c89,
+                0xe8dcc55eccf1c55,
+                0x7a49531c9f536a07,
+                0xbaa08e38f475581b,
+                0x21a3562601e5651b,
+                0x613fbf010290f899,
+                0xb2a795cea8363a76,
+                0x154f808b233d2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7d2570088b145e96,
+                0x9262e1442c713841,
+                0xef892fa89cce1950,
+                0x859e82150eaa401c,
+                0x30dcca868727053e,
+                0x225b65b2c8e46439,
+                0x8ece89dd6dadb5e6,
+                0xf79c031efc12128,
+                0x529a8acc237a8989,
+                0xe709a9b1cceedfa3,
+                0xaa2af23a2a04f4bd,
+                0xa8df05ec7321
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x89c4536f8c2d0f94,
+                0x6f1ed82f620cc613,
+                0x9fd9bd9f0efe34c4,
+                0xe2ef8d92dcb0adef,
+                0x5fbe89635bd224e8,
+                0xec0ea334b09bd84c,
+                0x58d35f61823e6689,
+                0xe9ce6c55481cbacc,
+                0x8bf86e3346e71df3,
+                0xf2b924cbcea2990f,
+                0x375ad71aa57b5e6d,
+                0x1ade4af7d079b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4fb8c12b9c1ebd68,
+                0x3be9a837813373cc,
+                0x4a37c1bb7a11edba,
+                0x7068a8040e9b1fe,
+                0x338ec453c60f95b6,
+                0xec13949fc8a4382,
+                0x28f845ccb63695c7,
+                0x54278eacacb5c0c2,
+                0xbee1d07fd29459f7,
+                0xa6164614b77e0502,
+                0x299c11b87a72c207,
+                0x19f3a0f98e8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe033640076d958b7,
+                0x77376c639b449cdb,
+                0xda29ae300d17326,
+                0x63e2a35b13b106f9,
+                0xa12fb4cfb61c0dcf,
+                0x742aa7b45dc82f94,
+                0x463bc683774f36cb,
+                0xe9c896b0747d7c51,
+                0xede481c64afd3dd1,
+                0xfc28eaab0740891f,
+                0x21a20dc552cb9fee,
+                0xb42352c2c65c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ca39f6e90852d8f,
+                0x196203d84413e907,
+                0x9eb78aa02c0db7af,
+                0xcbe36b4934d73476,
+                0xf09fef8bd7d7820d,
+                0x8697089637a9d9e4,
+                0xf2634fd22d7a385f,
+                0x745cdd9b032f481b,
+                0xbace7ed2a2ff4bc2,
+                0x9a2aad0c3f6aa597,
+                0x9d6a7c541c3a87da,
+                0x19778258dcf02
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe0619068c640dad8,
+                0xd080a3ce7762dd56,
+                0xfdce28956d0b802a,
+                0xc0e6201d71aceace,
+                0xcfc871defada4886,
+                0x2de6349511cba87b,
+                0x290c5db88aa37ce2,
+                0xe0b75396b7eecb47,
+                0x90910a004d325ba2,
+                0x7300017ab3fa1b10,
+                0xada88373ec1d4e9e,
+                0x5b694fa3d7ed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc65520e71b18b15,
+                0xf59ddfa8fb3b9efc,
+                0x11872e8d7fbabe42,
+                0xe6633b08afa5a83b,
+                0xf9e9eaf2ec598619,
+                0xe628b76ca23688fc,
+                0xaef2167a344786d3,
+                0x793e3539e392817e,
+                0x844deeac913f883f,
+                0x113d1a62d9545852,
+                0xe06ac9b8ddd7a15c,
+                0x6d196f883cb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x38e0b7dc9d39cf43,
+                0xaca720e818988461,
+                0x3d25f7ec72b72b5c,
+                0x2a0d227ce077c840,
+                0x5a7cbc7ffe2ebcdf,
+                0x9d85321fa8835c3d,
+                0x71acfd96067b4427,
+                0x7355f3aba6e618ad,
+                0xde0c194461434fe5,
+                0x2157f1fcc24ed77d,
+                0xfc02e6ef4b2d1044,
+                0x15bd48694d489
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf5b27fa554e8f194,
+                0x4e6eb9e02076bdfd,
+                0x1c6339cdcc50386,
+                0xf95687f14137779d,
+                0x7371be73ee6760c9,
+                0x83e8c1e24ab8bd1f,
+                0x3e981b188bc14836,
+                0x8de4af96a0fbedfe,
+                0x3028cb070f537814,
+                0xd5dd5118b16ebef7,
+                0x8d3894c28b00c632,
+                0x163382e9112d8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x57d008264b1c4c7b,
+                0x4db7ed008a85cc8b,
+                0xf65959810f4608f7,
+                0x30f31dd2cbcf93be,
+                0xe380eba73b4ceb02,
+                0x1b4da367bceefcf8,
+                0x366857393ba0649f,
+                0x3cf8ef124818e1cf,
+                0x9fef8ffe77782eea,
+                0xbf731ea559681189,
+                0x7f3714276e09c50e,
+                0x3c94703e38e9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x61ebcef32136cc01,
+                0x38618034f41d87b7,
+                0xe3e8df4db5cf0108,
+                0x201b7fc995dea3c8,
+                0xcdc4d0cd63504294,
+                0x95dc23f607cdf4e9,
+                0xf4d1cd1650ba3605,
+                0x432d975661b44946,
+                0x222386e57fa695f1,
+                0x5e581cd287af446f,
+                0x8e3bbfeb3603432c,
+                0x10375f624aee1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc606f82f572983eb,
+                0x6684d22911b1ff18,
+                0xfd7ee9725b54a337,
+                0x990cea35f880123f,
+                0x72518587907f4ba9,
+                0xa9fafb4f8bcbe6e8,
+                0xd9d9568e60cb2e8d,
+                0x18ea71899a9a5d1f,
+                0x90629df48ef17876,
+                0x4e8862fd65bce3f4,
+                0x515a84b837b6621d,
+                0x1429e9b572677
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa661c59b5e975bdc,
+                0x7a9685357f6f9c1,
+                0x1b132cccea78b47a,
+                0x6fe27f9f351e1db7,
+                0xbf99b79731d8bb2a,
+                0x5c63d834c4b4be23,
+                0x6c02170de51a6dfa,
+                0x376dda75ceb5de08,
+                0x56068b7ff6ba0e4c,
+                0xd5de8c6f0a9e6bc3,
+                0x16dfab6b14dc4c61,
+                0x1aee5554b7ff0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x65aa72ba68654d17,
+                0x17b4c1e8d5730e10,
+                0xb60a02f3c62cc336,
+                0xae235df80f3345bf,
+                0x2f4cebc146813a34,
+                0x826bfc4e1405fc8f,
+                0xf6ad10ecdc6f78c4,
+                0xccd4b859f7b23c06,
+                0xeecc38e8dcb7b4e4,
+                0xc59cb8778aa4ebaa,
+                0x6c5f83c8f057ee5f,
+                0xa2443bd1d658
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6589247f4e6fc2b,
+                0xa68586e338f5c526,
+                0xbe230f25d8cdec42,
+                0x46cb6b1b67e2f20c,
+                0x7a3fa00fc5053e0d,
+                0x5a96d818220d4839,
+                0xcbccbe3e3cad901d,
+                0x6bab7517528029dd,
+                0xa1f78a027c805445,
+                0xe56e9382c6ebb8ba,
+                0xce2ab178ba61ac0d,
+                0x116a39154c80a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1413312d40a4c7cf,
+                0x5d32d8c9e935c3b6,
+                0xa94f6fe012d94c70,
+                0x1df5d6b403460cc5,
+                0xb8d5acfd48be4d81,
+                0x3d898090cc6d5e6d,
+                0x9bafd42943b870eb,
+                0x756a5baff3ad4103,
+                0x4a333c32e830387d,
+                0x4ceadf58d18fe13e,
+                0x563b120dc438d93c,
+                0x79b4b638df88
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbe34e2aa77af6de,
+                0x4837b985b07d9d09,
+                0x426cf8687b04e305,
+                0xee6dfbeeeceebf9c,
+                0xa2bb14d3adf6b35d,
+                0x6178d78204fe9db0,
+                0xa4e5725acd29ffa5,
+                0xc1085c574be0c75e,
+                0xbfbde0914d0463e7,
+                0x25bb16040c718d33,
+                0x2890bf4f64a26372,
+                0x8663fa0344a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ba4f4e4681cfc87,
+                0x11f55b72597fc907,
+                0x7b8e065c01b55cfd,
+                0x760d7372cf6194cd,
+                0x9607e29ebd3220b7,
+                0x294be8527b6230d5,
+                0xebe2a3c80bc4a82d,
+                0x280f7133f0479aff,
+                0x9806829490c9a719,
+                0x7e13e6a2fd9db420,
+                0x45d9d3aa10f469e5,
+                0x150f78ed23f15
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb08f708c94321ef9,
+                0xa523047aedf0743a,
+                0xec7c51242fda1593,
+                0xab8b6c58e7c8192b,
+                0xd76942d167943cb0,
+                0x1ce8171fd0f3d1fd,
+                0x88c5fc1e19ade464,
+                0xffef4a62bad7b3be,
+                0x3faf6af6355f301,
+                0x135dfeae96605d49,
+                0xcbc0c2fbb566f4e0,
+                0xe2f27443c9ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e95bf658a5f2660,
+                0x4fc44df17e92716c,
+                0x26ffb53d72a214,
+                0x8242ad9438a500de,
+                0xb94a47dd044d41a2,
+                0x8152320f3b574ed4,
+                0xc7bce994cddb69e0,
+                0x69f4f1ae4839f1d1,
+                0x8e5fc64853dbf88f,
+                0x28678235ab5386b0,
+                0x7d0df54116c0c955,
+                0x30c4426e1dd1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x94fabb4bc1c11bd7,
+                0x405a7bac00f98bf5,
+                0x730b2bac7096cfb2,
+                0x70ccd2a1b0faa448,
+                0x7c6010931a734146,
+                0xac7510505d4eda0f,
+                0x3481035e6189d689,
+                0xe935d60715df4d48,
+                0x27ef6f9898226b0a,
+                0x40c9777a3172a66f,
+                0x8c50aeec69428b14,
+                0xaaea09311a0e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82b211fe8eff90df,
+                0x2449a0425ff502dc,
+                0xcdd10d33482cb8e,
+                0x3670f46a88827dd9,
+                0x26ba4a206a3a4b07,
+                0xfe522c45b47811f4,
+                0x6429200053d9adfb,
+                0xd04b170dc51bf841,
+                0x375feb035bead1dd,
+                0x2bc52fed38a533f7,
+                0x71526e184fe0e5da,
+                0x1b382a87b9e64
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x752f53ae5d054b02,
+                0xec84dc46b1799ef4,
+                0xce7ab7f25cd983ef,
+                0xf6ce530693f13e68,
+                0xe1cdee857946bb6d,
+                0x1c1fd9196512b6c,
+                0xf398d5d8369cd703,
+                0x448be28ebecaacb6,
+                0x5564eae5fb58c6fa,
+                0xc2302ef0e64811dc,
+                0x674cd613f080a651,
+                0xf00559afad63
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5764a9da03dd5225,
+                0x6c387d2c66b84908,
+                0x1fefc531012521a1,
+                0xb99fed1003a8ba84,
+                0xa4c1eabe69def6f4,
+                0x6bbe1d7a8d29c94a,
+                0xef6515a18526f3f2,
+                0xd08009e2aa6a1831,
+                0x698eea1ef86dd61d,
+                0xc8b84cccb6781577,
+                0xe65e9a97ccde0a7d,
+                0x169f42e9e8bfc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda8b9c2041670784,
+                0x6a866d28f132e84e,
+                0x2853e99548025ee8,
+                0xc866795fd802f404,
+                0xb053d869fec85fdd,
+                0xe01018215dcef41f,
+                0xc6dfcccc421a8c1d,
+                0x4cefdc32242625f3,
+                0x6dec6f729ce56a0d,
+                0x4e4ca42af69353b7,
+                0x414233b5e3835858,
+                0x50d9b580644c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaccf6eea5b145b0a,
+                0x5e149cc9d9b2be9c,
+                0x778427e839417fdb,
+                0x332214dd24b24150,
+                0x9e94cc8915517b95,
+                0x5ab915d99548702f,
+                0x23c2e1868eb170d7,
+                0xa48d199d998fc72b,
+                0x57e7211afbac52a9,
+                0xe90e8a2e2871b72c,
+                0x19485c2e44229d2b,
+                0xaecd9da3c447
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ece64093270fc97,
+                0x4eeea6babb2a7226,
+                0x5a14f41e7ad42cd2,
+                0xa15e804a7bba5767,
+                0x9fa459104fed808c,
+                0x6a132b5f4bfd67fc,
+                0xddffd4f70b5ec3f5,
+                0xf136f808d6ca55d5,
+                0xffa72e1ba7e318a3,
+                0xbaef41b267197600,
+                0xd09bc8cbedb0c897,
+                0x63d31e34c205
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe6165906c78765a,
+                0x5afcf228d8ffe853,
+                0x50b59cc39c6f878d,
+                0xcdccb0c49ad8fd53,
+                0x5015c035b63b712c,
+                0xc64a10dbf50adeba,
+                0xe0432cae6b0c16b8,
+                0x40f20518e514a025,
+                0xbfaae169f1e702fd,
+                0xbd03b1c6d5a3a92,
+                0x48008043ae765bb9,
+                0x19644933612a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf38221e085e89c5e,
+                0x5f45b00a3c432497,
+                0xf7a95595fb112421,
+                0x6ea7b29e2eeaa27a,
+                0x513c378a5b600c38,
+                0x3daf8f8392b87128,
+                0xfae0fab8a31b3c02,
+                0x761d4ac22599901c,
+                0x3d14886b27a5d3bf,
+                0x6b9d566d25ed988d,
+                0x2835521773cd0092,
+                0x13833ea3c3593
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9003d3d1a1d6b8e6,
+                0xf00d13782671493b,
+                0x98bc397f588d63de,
+                0x6a267a104a3c3b8c,
+                0xd4bbea5a2c5c4a16,
+                0xbef4c3e76ad052e4,
+                0xbaf4305f1662470,
+                0x93d2e57ca7766553,
+                0x3633135dfa0962d2,
+                0x3578d85d0280a2f9,
+                0xd26143948c34f7a3,
+                0x1c0673b9e2b93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc01e036893cc20d0,
+                0x98b59bd69b3842f8,
+                0xb258dc18533022aa,
+                0x49433d4e5b69e4d5,
+                0x9523f4765f05593c,
+                0x288692924edc8904,
+                0x3d75c29a3004c7a,
+                0x95bfa0f6bc3fb58f,
+                0x55c407004c51fe54,
+                0x4303a880aa915d4a,
+                0x6c7374638ecb0387,
+                0xa22504141252
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x25932915a75cf8a3,
+                0x9107629c7113e349,
+                0x19215c95166edac3,
+                0x736cf115f0e8278e,
+                0x19b2570da31d0d04,
+                0x406f763b4ef03d78,
+                0x5597e1c79738cd98,
+                0xb5535cb8dc500dd3,
+                0x19a878bfe91f7c5e,
+                0x219a04bc05a3ebd1,
+                0x4fee9fd822f5ace8,
+                0x1b768b75feba9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb7d59558c7944355,
+                0x1409c2e15c2935d7,
+                0x4b0782404b064b0f,
+                0x33aa91d639753e7e,
+                0x6ca96674789a5ae5,
+                0x31a0746e4c77b70b,
+                0x39b65970832c84b7,
+                0xb48a5ffff6ea10b,
+                0xe692b7d9ae0cfb8e,
+                0x9e062c0430792b39,
+                0x708e5b5be3fcf807,
+                0x1a83c3c57f8ad
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9663ab891f18a3be,
+                0x86c876bd72546c6d,
+                0x899233a7a3ad5601,
+                0x6bdb31e6de91f08,
+                0x6afdda31c430d1c0,
+                0x549f539d3172887a,
+                0xb1346bf6b1c3db68,
+                0x2ac19b5bacb32911,
+                0x91a70f97404945ff,
+                0x80abfe6ab3b88279,
+                0xf3c464dd11b152a4,
+                0x19caa43fba1af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x625def83e0e978d4,
+                0xb1f58de359ff7682,
+                0x446f9398a9a78e44,
+                0xaa5cacb30eb6e6f8,
+                0x29a6fbde5c53f2ee,
+                0xe96308b4c78147e1,
+                0x48ba0d52ed4a6a04,
+                0xafb7a30e8514b6b9,
+                0x1474356adf5e1658,
+                0xb4265e5cfc1ee79b,
+                0xbb92e766dad828f2,
+                0x1506195e09e56
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd39ab0b9c31c56d,
+                0x7a78fbe358e2a62c,
+                0x334eba1ff06ebbe6,
+                0x52e080ba3b182265,
+                0x7dd4999a744bbb75,
+                0xd841157911f916b3,
+                0x193bbaa72bc67e19,
+                0x6b98ce347db07eb9,
+                0x2942269d935bdd00,
+                0x46880e9133c86ce4,
+                0xd3427ddc27077931,
+                0xdf0b81528e3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e7a0cf5ed55c6a,
+                0xdebd92a95a6a96d0,
+                0x39bc20b4e10c4a41,
+                0x139b8f40b97ebe25,
+                0x6abcb2be0686c11,
+                0x4e23afb9a896a72,
+                0x33ca8a610a589afc,
+                0xf6df39a59f29a3b0,
+                0xd620b6df79e011c3,
+                0x21a9d0920788c537,
+                0x29f25960ad2b9802,
+                0x309e55c53682
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf8500383bee1d9c1,
+                0x3bbd9c4ae37b5eec,
+                0xb39072df2eb6d24d,
+                0xb854d9f4874c6e22,
+                0x824f542b70c38b94,
+                0x6ccfd2740bf3610,
+                0xc8f9fa4f9e104995,
+                0x7c24f972029e7c76,
+                0xed88f75e4ec91103,
+                0xe3dc0e3cb5f89d1c,
+                0xb7d2826f37ae63ec,
+                0xba0d95b69bfe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdcb6483e4388d1a,
+                0x1f24ec0efa35c997,
+                0xa7285e8879bfa2a7,
+                0x223ea3c30725e26,
+                0x726aaca7c9628038,
+                0x6fa6c7f858188f93,
+                0x2e7bec724a1b2a46,
+                0xc0537ce9f1780857,
+                0x75cdec6f54f4655d,
+                0xc05b34d36da8f62b,
+                0xc6535347978ade67,
+                0x1aecc1858b47b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x59b32299a5862924,
+                0x9cb49eec45fedafc,
+                0x27e61c0382989b17,
+                0xf562bb52b87111fb,
+                0x167ae02858510f41,
+                0x99084d2ac300209b,
+                0x7b79acf284a902a5,
+                0x15a054e1cde27191,
+                0x93f1d093ac10afa,
+                0xe0c7762cf4a29b1c,
+                0xd8e7130093e6a6f9,
+                0xc8752102a7cc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1ac66cfa546742d,
+                0x7bcb02c5edd8d65b,
+                0xe09d8ba03a65738e,
+                0x89dae0acb9f401b,
+                0x4e1b2e1a9aa6a9bb,
+                0x85ae7f9eae612b52,
+                0x873c03e800741b2f,
+                0x3cf33f063d1e675,
+                0x16bd3e1600e262b2,
+                0x3cb93ca234b69370,
+                0xb68a886481e35622,
+                0xe6625293c668
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x362d231d8f924ad4,
+                0x24e0881541515208,
+                0x3507becb66e1e933,
+                0xe563c9b058c111e0,
+                0x10c8deea4f07050a,
+                0x1999b4ce3c3429ca,
+                0x493ca1af98347c41,
+                0xf4e84d4551d53128,
+                0xf711e8486111b3ab,
+                0x116e2d964f5e73b4,
+                0x2e98dcfcf47d319d,
+                0x1b96a4af0bcb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4050d73bd64f2397,
+                0x5bbb20b477b0e74f,
+                0x6cd999bd7cc9edf0,
+                0x82a2e2861729d90e,
+                0xb7483ea0ac8e45e4,
+                0x939937d7d3f0ef5,
+                0xaf812b242c34229c,
+                0x637d2b893a16c95,
+                0x22ce7365ebf24fb0,
+                0xbf073450e0425b7,
+                0x30ab4f08b120d5b8,
+                0x917517e4264
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x321ce8a9f23b4c9c,
+                0x2e76574d52eae080,
+                0x65532196329a24c2,
+                0xcbbf2b357b9a5c41,
+                0x25b425908d5ae3e4,
+                0xa284d5d4066aee55,
+                0x6fec10f27a5b5591,
+                0x3c32444ef9418fdc,
+                0x268aae4befb87a63,
+                0x1d3ac7a26dc7d7aa,
+                0x5c588a3049735ff4,
+                0x167f31957f134
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xff35c18f04ccace2,
+                0xc41fdd9560cdd906,
+                0x433ed4d116e7b2fa,
+                0xdc1060c4161ecec,
+                0x83faa3c03fcfd86d,
+                0xfb67b5377b0ed531,
+                0x58cfaa765ce366d,
+                0x848936a9c96cf71b,
+                0xa9625bff992e2527,
+                0x5814ad409a60f81b,
+                0x2ff1902bb41dad22,
+                0xab5b7fdc4e78
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb92bb40c9eaa5e24,
+                0x474b3bb335fe2363,
+                0x5dffed9733b42958,
+                0x7317f51b3d3fe923,
+                0x3202f79d1271eee4,
+                0x87c7dd0a8f9bc371,
+                0x6970014b4cadac1f,
+                0xad1e8dcf54cf6551,
+                0x4cca10c77d55da19,
+                0xf232229d41426901,
+                0xc1a9b937527f75fe,
+                0xb1d14bc4c8ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdd9dd4341c9f6461,
+                0xdfbbc48d0850a745,
+                0xaa31c9e5d3fafba4,
+                0x27a086a510706132,
+                0xdd7cd0d2c30bac77,
+                0x459c8270e96f6d67,
+                0x157c8c5950253a56,
+                0x888d826f2ef5bf8a,
+                0x15de96166eb9424c,
+                0x2dcc13930e9e4b3c,
+                0x8d1067628066f7ed,
+                0xa52a8084065a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c83655ad360c575,
+                0x2f62be48460f018b,
+                0x5ff1816871859c3a,
+                0xe62c8eabec029d96,
+                0x3924f5db2a0720e6,
+                0x7269a699c652fdc6,
+                0x91c853545f468ebb,
+                0x2a36ddbcf3401f14,
+                0x9d54cda65afa9192,
+                0x76b4be46572f3fb6,
+                0xb863ac0ee3fb9d73,
+                0x18dd8706e0c5f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7d5b95d1b452cf15,
+                0xf615825b2128f317,
+                0x43383deea08cd4cd,
+                0x8dd46b8422774439,
+                0xe84e709f0c52c911,
+                0x6f4936961077474f,
+                0xd89e1b0c2a036942,
+                0xb905f7c41cbe4451,
+                0x4d13c5c613c7c563,
+                0x447620d51600b69a,
+                0x93e06ccbdadd9907,
+                0x1666c8c112fb4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1eb1877870180611,
+                0xe07f6f598f10bf36,
+                0x42639debdffcb276,
+                0x41c8575f3b3e6cbd,
+                0xd87cd2acc8977c55,
+                0x226bd898132ab11a,
+                0xd9f3c4257e20e846,
+                0x9cc2c78add4e772d,
+                0xbe494d784eebe11b,
+                0x8615a435803305a5,
+                0x71172296cffa4453,
+                0x4b80a9f11384
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbe3d1d68a94e7f1a,
+                0xddcc7781154e7d6e,
+                0x843cb289f9205909,
+                0x43b8adff19d84ba9,
+                0x5bae6b6f59920ae0,
+                0xbf5feb196a3b10e3,
+                0xac8f9bca348a6e1d,
+                0x8eda98c31828179f,
+                0x94362fdee9eb0e6,
+                0x88423bc9e046ebbc,
+                0x41b8fca23825ccdc,
+                0xbfaa6508dabb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb80bc707a13cbbf6,
+                0xa9a7a2c8ff842cfa,
+                0x8cbca8081cc6e6c2,
+                0x468375d1e82ebd45,
+                0xd28edc8dac855dc5,
+                0x7c2bc7f04086e186,
+                0xf97ba7fe66573e0f,
+                0xe0d1669a3fa7a6fa,
+                0x8eeafedf5bf5f4b3,
+                0x1a13f63bf1857c1,
+                0x7fba8cd72aa6f671,
+                0xf46133841360
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a8b02f78a64d018,
+                0xfc2d18f7cd71ce4,
+                0x1f9c1927c745a0c3,
+                0x942c8c5b70e544d3,
+                0x2e06241b6e83bcfa,
+                0x4bd69917a5ee9d0e,
+                0x3fbd8302f75e9fee,
+                0x4121953783aedeb3,
+                0x21535aeea07aa7ea,
+                0x74aecb98ed753080,
+                0x19475c55049646b2,
+                0x1f6db9b0acd9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd447cae51bff743,
+                0xf9f0d54d25454399,
+                0xd5ca55435cd02188,
+                0xdc52c142cc7c464,
+                0xf80a7a6462b98857,
+                0x4a19227c98c143ac,
+                0x1665c52db4edd882,
+                0x5a7b18f4d98dc2f5,
+                0xfe82374bd2630513,
+                0xc8336a12bcd8acc7,
+                0x7b691cde7265c128,
+                0x95211c71a5ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfbd7ce7417ada510,
+                0x98561fbe5ef6844c,
+                0x66f2e473a86c30f8,
+                0x5206a2fc19ef59c,
+                0xaa4c227787d2df07,
+                0xbb303cc0ffa8b56e,
+                0x2a33f40f10c0fe12,
+                0xbeff85810a5e8826,
+                0xeab3780fa9245f75,
+                0x3994a1f649ec43c9,
+                0x87fccb7e4071cc10,
+                0x409d2a89e01f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf7352888d88b0aa,
+                0xfa368994bbb9f644,
+                0x57eb73b00a2b6599,
+                0x6e7eff8da900e321,
+                0x9fca9356d17b5ffb,
+                0x65edc21ee45a90,
+                0x3a3ff1cfa0e5a79a,
+                0x8527135e3ba0ba3f,
+                0x2c27f4ac8e12edab,
+                0x46ecfe3cdde67c34,
+                0x12833cbb4b431714,
+                0x55df10b7571c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf7c5ec0b5849162f,
+                0xb2c80fbe0c1376d,
+                0x31fa185c2c2196a9,
+                0xe6006b5331d51184,
+                0xe39b61ef570d3d69,
+                0x20369427bd934cbf,
+                0xd19f306486b5c208,
+                0x8b2032df7d8832e2,
+                0x9b04452406e3f2b5,
+                0xb19a9814b5349dd8,
+                0x6a9c5aa42b97ab4c,
+                0x139e25d91b0e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x284060515bde167f,
+                0xea31f7ae36a701c2,
+                0x8a5f5d8512c3f3aa,
+                0x86cea195c41ccf8b,
+                0x709ac18eeadadaa4,
+                0x377d568c4bf2f431,
+                0x456d862b3c400cb3,
+                0x1217036dd1d5e244,
+                0x727d6ef35c27a770,
+                0x6873fafdff437366,
+                0xfef3167b247cfae,
+                0xfc7cbef7c2d7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe60e7dd2165dc434,
+                0x4a2beb5de1570b6d,
+                0xa63338630582c41,
+                0xf4f1d5a507faa717,
+                0x63390d0951c96d4b,
+                0xb709449475cf7e09,
+                0x10ae945707894ca1,
+                0x1bb1a1579db8155a,
+                0xf3882f7b3dbcb97f,
+                0x29131bb780811f07,
+                0x4e4359f9784effaf,
+                0x92c150669b28
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x388d91d854c69ca7,
+                0xa72ee22c804aec41,
+                0xf63a33309ff2dc61,
+                0xb4ebd410aa11a658,
+                0x38e00073ad538c1b,
+                0xe39dd5f9d427c4a9,
+                0x4bde3f07ceb4a51e,
+                0x5e4e48c5462c8572,
+                0xc784a3bee0235c5a,
+                0x83980bb52884fc76,
+                0x9cd8e9e565294ab4,
+                0x204b9d0682b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc97007c2daa23336,
+                0xdf43422a64508ba4,
+                0xf6dbf9ef0ca4956d,
+                0x27900cc2ba2a949e,
+                0xdcba4e50cf0a551c,
+                0x5b89c0f4c530987b,
+                0x988503d39c986616,
+                0x92bfcad8fc78f41f,
+                0xa7314ed825089ab1,
+                0x9acd3e2bd872d459,
+                0x85f19dfa30304d94,
+                0x42a07e9b7311
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1042acc6d373935,
+                0x3c926b89636ff2dc,
+                0x4027e31d29e96987,
+                0x786ab134bd92710e,
+                0x3e4cb8826442643e,
+                0xfc3cb5c2c8c28555,
+                0x3c884e0ef26cbdec,
+                0x8f29a5799496e8a3,
+                0x46e5e282cf14b41f,
+                0xac50d9b4f01484bd,
+                0x75d08ed04e47f175,
+                0x13616cd566a83
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7514f4f579bf2768,
+                0x33ffd79c20239f81,
+                0x1e0677a30f9ebefd,
+                0x4ceb80580653328c,
+                0x87e342bcc3e374a1,
+                0xa60d480ec2cd2aca,
+                0xfa1e785c5946defd,
+                0xab9231e3a0494452,
+                0xed8c06c86f19671f,
+                0xf946b1bdd084fc21,
+                0x168de4a4b5486b31,
+                0xaa4b44ed778d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e6f55218dc8abd8,
+                0xfe6d7f31fc126eae,
+                0xa66814d50d3fdecb,
+                0xc76a3adcedf81d11,
+                0x5307a37a8a55e0f,
+                0x9c7eb01602509c5,
+                0x219c91a5dfcf72c8,
+                0x8230e8a6db69a68,
+                0xdceff142b62ed485,
+                0x464bc726310f4286,
+                0x46a2e1bf696b4a0a,
+                0x1429b92ea2998
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd5c67be3eb99542a,
+                0x3d56a05f6c21facf,
+                0x87548c1381d70476,
+                0xf25a69f7678b2bbd,
+                0xaffbc398998ec28c,
+                0xb1f3f24b26100154,
+                0x2becff501a4bd44c,
+                0xc6479115d7a69b00,
+                0xb6b6e0f0d0b12f7,
+                0xc411dace832d6d86,
+                0x1c3c80b4cdffae03,
+                0x10f5d1928ffa7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf92e9ef5e431364,
+                0xd93818036a978148,
+                0x2b8a4415965aaa33,
+                0x52cdd1182724cdb6,
+                0x72b0bebce4bdf09a,
+                0x88bb0254bdab00df,
+                0x3baab84ca275f62f,
+                0xd506df13843873b3,
+                0xe2935188308c7551,
+                0x8b896e8e0721f1f7,
+                0x81117e7a88fbee49,
+                0x123d405f71780
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8649b25fdab393af,
+                0xf08f7c83e60d9d52,
+                0x19414f9e3c2df1b,
+                0x36e611a04755d003,
+                0xdd288c6d8e4f9956,
+                0x2fb2e03953bd69e1,
+                0xec603bc1a4226725,
+                0x460797624e06bb2f,
+                0x3e0dbd36a99ae4c2,
+                0xdc9494c1b2df9474,
+                0x2dc8af49bf5843a9,
+                0x416c163285da
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x63ce8b26de92a417,
+                0x9b1aed9be958a4c2,
+                0x5d6776315309ae8d,
+                0xe42db52aa0492116,
+                0x170acadc5e196b47,
+                0x2b8ed1eae5d8f00a,
+                0x7f3b41fd6aa36d08,
+                0x17d5bec0d47efac1,
+                0xaab1a8f2fe782b67,
+                0x67ad3e23009ef192,
+                0x7141716fc9a217c5,
+                0x57f362ad7169
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f4e6b11b48b17fa,
+                0xacdfc14dd3066dc8,
+                0x7396ebf02c354845,
+                0x60ec1e819185a9d3,
+                0x26c6a376d92124d5,
+                0xf3421afe98d879ab,
+                0x49d68d2ba0fe0752,
+                0x16fdec04d3cf3f43,
+                0x51ff66f8e118f9e,
+                0xbb6550a5254fbbbf,
+                0x4415a755b13e2391,
+                0x1ef2ba27f1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x807425497278a16,
+                0x8379476b084f72a7,
+                0xfec81f4d7c5fa397,
+                0x7ab9a6d32b90943d,
+                0xbdd8b76654bf3728,
+                0x486ad1d7272cc4f9,
+                0xfcf49621d15cc8b1,
+                0x9c9c98eada67c9a7,
+                0x3ea9e03001a09a88,
+                0xb3a365b6ca190,
+                0x3c77083b644a360d,
+                0x129332c384d20
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf5c518c8b401b42,
+                0x7a452c5f7da3c8c3,
+                0x39eb7ebe33c0c93a,
+                0x6265ddcb8ea9719c,
+                0xd8747d2be6dc32bc,
+                0x42de8269f785fea9,
+                0xb09362abb873314d,
+                0x94791ebe2c52ec15,
+                0x2abb41d2a87bf9ff,
+                0x3386ce30afa70d24,
+                0x1ba8a1388dcbfea2,
+                0xa1ece6aceb00
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa9f1492bff7ab644,
+                0x77aae38a30fde94c,
+                0xa5d8ee196cd45dae,
+                0x16b2a861d91da9ba,
+                0x2e57ef75a93ddb2e,
+                0x7ab79dbd14c87f9d,
+                0x7ef1844bad56f010,
+                0x86956916d05a76b8,
+                0x34959c1f5ae4dd32,
+                0xdfce37a0a8bb90e5,
+                0x687344b40045ff27,
+                0x1e68b8028ecf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa35fa4df81d8b5bc,
+                0x3cfa1ec9eea78556,
+                0x7132e807b9d42639,
+                0x8daeb0f6c264bba2,
+                0x1d93ae8769674358,
+                0xb1938048b9fdf9c2,
+                0x7a52b415b853d4f4,
+                0x8c9293dabb6dab58,
+                0x8f818a138ce042fe,
+                0x69f733e2a26108de,
+                0x3d78d6954f7451ff,
+                0x1542373ee0793
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeadabce5fbcc5b99,
+                0x804cbb2407000de4,
+                0xff64a02538c8eefd,
+                0xb39695a0698b5979,
+                0x79e5166df439b3d9,
+                0xeac800918ab6193c,
+                0xca4fd7f8dbb30411,
+                0x949b494f13e7c697,
+                0x91ed4f0e39af918a,
+                0xb65e7693b81d0315,
+                0xd4f9466cc01513be,
+                0x3140294eb145
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44dd1c8dcaaeef8e,
+                0x7c9021ec6a4449f9,
+                0x3dcfa0bc76b1613f,
+                0x6825863559179370,
+                0x3d795f0bb1665170,
+                0xf5a5f71c0dfbe245,
+                0x9037a1889a757847,
+                0x56e042534d8225fe,
+                0x6b9c62599e22c1b6,
+                0xf2f828e03616c744,
+                0x672ac6c0b43e8f29,
+                0xda95f6a1da8f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf83fe1ab4afa1997,
+                0xc1e569798c1cfc39,
+                0x6c611844bb2b76c1,
+                0x3c42eddb1899a0f5,
+                0xdc1683ca405dbfc5,
+                0x82e77a9442cdb5d0,
+                0x84400372307b7be8,
+                0xb8319f65fdc67ccd,
+                0x2d974baae40a45fd,
+                0xc75d675d9ce4e403,
+                0x8130a1bec7c60510,
+                0x10dd72989daa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcca70226b7eb6604,
+                0xf8cb8ac580af126,
+                0xd556e4ce19b651fd,
+                0xa39686f44156fcef,
+                0x7205de0f0b84ac7c,
+                0xd572a4bfcb841473,
+                0xd0730002570ee78f,
+                0x7d9624cba3e4514a,
+                0xa9e23ce3b1e6625b,
+                0xc77df14b3b850b56,
+                0x15040270f666b0ee,
+                0x5ebe5d166d05
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x406c6a668e27726b,
+                0xf9c7c4fbaf27acfe,
+                0x8da2585c4a00b08b,
+                0xde7d0c0561116869,
+                0x68647cc69afdc12f,
+                0xac3210857f719a63,
+                0xc6a84de595244b10,
+                0x95a5cbe7a78dd7da,
+                0x469563e9f3bc5deb,
+                0xb1a0a748ed175bb5,
+                0x85369974cda65a4c,
+                0x19872d12405c0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0012ad745cb21fd,
+                0x6635b9d180ff679e,
+                0x7a83ebb4e004cd17,
+                0x75daa72412de5355,
+                0x2209c0c68258b6d3,
+                0xc4f0f86e5a293162,
+                0x3ac5687cde7d6fd4,
+                0x3f8f378004951410,
+                0x9d670babf464486b,
+                0x58eb48100d86ce40,
+                0xe695003d649d6b2e,
+                0x7f532e414393
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9989030236e3ecc6,
+                0x91e9210136b98b8a,
+                0x4fc1654e18f3eade,
+                0xb5b24a95a389e39f,
+                0x9e97ddc1e4d7965a,
+                0x5408c5fa968a63f1,
+                0xc4d4ed314746a9ef,
+                0xdc43b87ffa63bc20,
+                0x7d54c6a925ff7bb,
+                0xd10820e35767f9c2,
+                0x38ce432553940e6d,
+                0x11d5ea2ed0378
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x176f73308198ce4f,
+                0x67a199acfe78310,
+                0xd3caa624cb53c1da,
+                0xd8aa28f3104cac69,
+                0x77d62f873d8fd94e,
+                0x641c6c1d66abe9d6,
+                0xb8ebdee4255b1fe,
+                0xd219ebbb66ad8053,
+                0x65a1fe17e3092be,
+                0x36e5e3680c02a663,
+                0xd1d1673f94f20642,
+                0x1b6b598036f5a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6baca766594ed06e,
+                0x1b155a32040db72c,
+                0x3d45737d90eec7a6,
+                0x2d5ff616522a4983,
+                0x4ba73fe04d3ef503,
+                0x3434d16aec5ef6d2,
+                0x63a23fd162fdcfce,
+                0xafdc0be64fcfe0e7,
+                0x4caa4b2fed59d139,
+                0x231fe02b4ee5f34b,
+                0xb85e39059410daec,
+                0x3244a13f67d9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae291af77baa35c2,
+                0xf8a29b1ef2a29c04,
+                0xca681f4505936366,
+                0x3573a3e789f84823,
+                0x68f7e0968bda472b,
+                0x3714594b0791b375,
+                0xe8a4b8f2aed8f187,
+                0xe2c713cafc588ab1,
+                0x816026280c6e9c45,
+                0xe4c18164693b86f4,
+                0xe9d829df0148838c,
+                0x228bc6ca716d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1c6173489fe18ff2,
+                0x83772b7f0b1cd1a2,
+                0xfd480fa943593000,
+                0xee42a99232807759,
+                0xa0d652b3593fb7e3,
+                0x38ddec333359f6a7,
+                0x212c33429b504069,
+                0x6b65570a05daaf24,
+                0x9109a4bf9795536d,
+                0xe3fcd8a5f159444c,
+                0x974d1b87fbdc622e,
+                0x57d9a2861199
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf426766840e318c,
+                0x636ca28553aced91,
+                0x2aaa665ab086e1c8,
+                0x8105c0150b3686d3,
+                0x3cb4338f2cf85538,
+                0x71edb6a60102e99c,
+                0x17af8586e3e119c6,
+                0xd1cde97761f6498c,
+                0x6cb331e078409913,
+                0xddf7bb94aae4733f,
+                0x6c39c431463a32c,
+                0x4494d04ac9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1cad45ab3485d9a,
+                0x2147ef5867d9d7ae,
+                0xe7518b58205f8473,
+                0x7aacf71ed8b72d99,
+                0x76af196994f07edf,
+                0x217b1c5250e1e49c,
+                0x5b1ac8a94e812741,
+                0xae13c002ee9cf22e,
+                0x98a5fcede98808e8,
+                0x27b169a370505598,
+                0x8e977929ec9e2044,
+                0x1a25b66500f6b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf765f50b87a21,
+                0x38f28b364d95d392,
+                0x5bc7ffbb05ac93d,
+                0xb1619f00aeba70a6,
+                0xfb716144759cfd58,
+                0xa4dc72a820bac672,
+                0xdc88b7c25eb252de,
+                0x895490e04a30bf4a,
+                0xcb07e1768d277938,
+                0x1f53451e885e49e,
+                0xabeee911b20b242e,
+                0x16f8dbeb9460f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x753bb8b88a282286,
+                0x88750daa6eba19c2,
+                0x342358c0e052eb98,
+                0x3db71d8e0aa56a8d,
+                0x3237c0c15f54078f,
+                0x9f56c02b54529aee,
+                0x5f0571f63c8c18d5,
+                0x5101a0dea60816c7,
+                0x283c6a2f72550438,
+                0xb5c2b62685974dd,
+                0x48cd3c4e437c40ab,
+                0xb210d4ad09e0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd010714ac44305d3,
+                0xe56d52257b2d63be,
+                0xd30ce944a64055fc,
+                0xc1a56391377fb173,
+                0x37ffad3168084eaa,
+                0xd2a0c307f867cc9f,
+                0xd22636aafb29a912,
+                0xa9c435274375c86e,
+                0x3c567ee3c1412973,
+                0x265e58d533f8c8f4,
+                0x6eb750bada414a8c,
+                0xf0016541d771
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8971048558e5de40,
+                0x9e84244ee5d9b987,
+                0xf24cb1ac523a5aae,
+                0x965fa2cf1bd491b3,
+                0xa8aaf11cc44b486c,
+                0x59aa3fc8aa4d10cd,
+                0xe3489689e9c2d7a5,
+                0x356d9fcc26ad6d7d,
+                0xf8098e79d8edfc35,
+                0xa579ec096e38ba16,
+                0x2f1619062c9d3b51,
+                0x1c4a3cb64f658
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x536ec28de9658d2d,
+                0xcf3775581511ade2,
+                0x7ab078b782e8a031,
+                0xbf6e16ed4b4d1a6e,
+                0x3f573556d4669a77,
+                0x515db610ceb805b0,
+                0x1893c18d5e66cd88,
+                0xeaf6def34eb6c4f0,
+                0xc56e85cfb530b83c,
+                0xe8d28bd8e0093669,
+                0x2f8ee15d1899695d,
+                0x1a712104a5cb6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40334987e1c37167,
+                0x7ef5b82ab7c986b1,
+                0x7b350798a8c6b24f,
+                0xdbe00be27a0e581a,
+                0x3e72d0c6429026cf,
+                0xb0970050a92862f5,
+                0xfc30874cf6b61afd,
+                0x79ed26c041ed73dd,
+                0x4d832b73af76ba0c,
+                0xa3733bfd71b64cd8,
+                0x75689fdd4376d8f6,
+                0x1a78043a55f24
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbef0d9587a1a66ad,
+                0x52e26842bb32d6a2,
+                0x224013eff2c0fb67,
+                0xd063e9b1614e9653,
+                0x8024a40330af74e7,
+                0xd01a70970fd4c03a,
+                0x4a921522271e831,
+                0xca5a4a8a93686092,
+                0x8eba36a1b1cd1afd,
+                0xc95b8cae5ba5c230,
+                0xd92a6417ef39324b,
+                0xdcf56d71f98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd6ac9bfc55f52ac4,
+                0xbd1853a6e11c61e1,
+                0xb7066767651eb4fd,
+                0x394670b290c50cb8,
+                0x9fa4e547b65bf540,
+                0xe8913225798adf16,
+                0x178bfbc1dfb85cef,
+                0xd84284c16651913d,
+                0x9cc99944f6a21f38,
+                0xfccc51193be4c335,
+                0x6b37b69c78c8184c,
+                0xe64a3430ccf1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x933fee05fb3aaea2,
+                0xf6175b22a84af849,
+                0xf76eac4b2a43f323,
+                0x56550f7ba20c98e8,
+                0x157e4c8e3ce88bac,
+                0xd7a9e5436ee98e9b,
+                0x724ae020e7e7245a,
+                0xf1d9059a5be94634,
+                0x262f06b744995085,
+                0x68689ac58b8fbc8f,
+                0xe897b38203ef9141,
+                0x101aea58c13fb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce79539dbc2c5b84,
+                0x3b5310eff6383bd9,
+                0x6c7101e140180daf,
+                0x261689ef1f7432a4,
+                0xaddf73fe3de05211,
+                0x1a6b3a723424f62f,
+                0x1648612c423729a,
+                0xf0027f3eb2a97c0e,
+                0x7d0f1c7b543d34ce,
+                0x47a9036428fe64af,
+                0xd3092bc3c174fea,
+                0x2f6d487befba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x75c6cb77d9e7a529,
+                0x39d5d0439f8c95b8,
+                0xf79532aeb5a5a8d9,
+                0x475a70790bb5f53f,
+                0x69446208d2c8ee6f,
+                0x22d0b396058f8828,
+                0xd1207f95a2174d3f,
+                0x36a8125f9ffade8b,
+                0x834fe8c20588dbe1,
+                0xe5963ef4c82dada4,
+                0x626fe7146bd1b176,
+                0x5553a5073feb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9687a4a843b1303d,
+                0xff97c3cea6bb7f78,
+                0x896fdcf4d47d7050,
+                0x25a26a2d9d21c114,
+                0x5bf07ed1924221a1,
+                0xcf3699d66b74baec,
+                0x80d549f2a29a5230,
+                0x47a403e0058c7f3e,
+                0xddec9d544a716683,
+                0xb6b5e37de037a0b4,
+                0x558ec9e8a91452ea,
+                0xe123011af36c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x508e5e5b26be8902,
+                0x3e8996c390317288,
+                0xfd6f9f919684e225,
+                0xcf1e7b15f819a43,
+                0x90815b8eb28260d3,
+                0xfc170debbd74689f,
+                0x57a797abae8c52d8,
+                0xa5ac4868ce10b941,
+                0x39e709418b19ec2,
+                0xb0ade6ad11db5ebf,
+                0x53c295c83b2cfd21,
+                0x1443c0893a0a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2463e7f3158a0d06,
+                0x19771eb8278d380c,
+                0x9f3806cf02a8f4ef,
+                0x862f5891868b8f8b,
+                0x9955884638e0896f,
+                0xefbb97d110c620a0,
+                0x7f334799b873f555,
+                0x8253acd3bf2a3120,
+                0xc2be55930d869f2b,
+                0xf8380bc9f6adcd5f,
+                0x908a10103bec8a0e,
+                0x1c24f755daceb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f241db4fb8c86e9,
+                0x1fa71606aeb6d7d,
+                0x4198b026ef5ad378,
+                0xba1afa0b47ee743e,
+                0xf4e47166958141c3,
+                0xa2c65d642197a838,
+                0xd0dcb04fee21791c,
+                0xa83b68d8441ebec7,
+                0xaa70aa1d4c69185c,
+                0x753ae14cf5f052c5,
+                0xda3590e533bc4cd7,
+                0x322b304001a7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd8a2ad29a9505d72,
+                0x4cc6903edd950068,
+                0x44e4276a47283de0,
+                0x1f91b0a0f57454d0,
+                0x35b11493efde98e9,
+                0x24befb98761d8440,
+                0x1a974a3bdf1e4602,
+                0x324efc82b339a6b8,
+                0xf330a1196f10d6d5,
+                0x84e99e40f27b8120,
+                0xaf280863190a1df8,
+                0x38c3841576a4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x25312430b8327f24,
+                0x52100e8352fcf47e,
+                0x26072e1417d86d0a,
+                0x22a8c7dc33a46518,
+                0x3d2c89f757f5d837,
+                0x7f8c53cd971e00de,
+                0xc1526e748588fb5b,
+                0xc3a243de10f93d8c,
+                0xa15cc4521de4ff01,
+                0x5c2a360dde157af5,
+                0xbddccde188ce28f8,
+                0x4d3c70e81eae
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x54d584f8469c024,
+                0x503890478da1672e,
+                0x2775d08c95e49758,
+                0x26b41bcf2e07f5,
+                0x7d88fb4e2a92c939,
+                0x22634c6673599c45,
+                0x428a8683630e68ca,
+                0x419289419963247f,
+                0x7f70a93695b343c0,
+                0x545084fd449415c2,
+                0x158952864704497e,
+                0x11a8ccca8b2f0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2c5622d8d1c73a6f,
+                0x1a3860b1e0c23c85,
+                0x569b9c5202cc692a,
+                0xca3ce00da2b01345,
+                0x23b3b7130a49c75b,
+                0x77ed4e3b3a7cbe07,
+                0x471a7ba83abb2d26,
+                0xc7c35afc2ea071f8,
+                0x7ec061babe836ce0,
+                0x49e7a61946604c8f,
+                0xebc55307d14888a2,
+                0xcea028c0d36d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe920cb3dc188a701,
+                0x8e6f57d7c597fa39,
+                0x4b28ef35674d639f,
+                0x27b712a43945f7a9,
+                0xc42a2968a4160bb4,
+                0x4a20f2b23f035658,
+                0x3f631dfea5440f41,
+                0xa961700ea63ec2f7,
+                0x3a09ff3409c84d95,
+                0xfe90ec43319a5279,
+                0x68d63095f089f96a,
+                0x1c79c6649f10
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1ed46e585f28c13a,
+                0xe0484f83799f2fd5,
+                0xab668319596d0468,
+                0xa335f3803caf79f4,
+                0xe9b4dff57d0f8e8e,
+                0xc8ee85216f642f4f,
+                0xa95307082198b6f6,
+                0x942a62f418a2eff4,
+                0x8d6d8d80d7d25803,
+                0xbb94534207d2083f,
+                0x1b703340774e1860,
+                0x16bf4b18e0b60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x46f7e9363b59caf1,
+                0x5f64cab5e676c4e6,
+                0x66da4e860cab8b15,
+                0xf6eb2e4116c32332,
+                0xce30e885361db8d1,
+                0x4fb58204b01232b6,
+                0x787f1070e41bfe90,
+                0x7486c9c8efe0156b,
+                0x8fbe6e585f26d609,
+                0xb9626048646b1633,
+                0x99f3d3ab173597f6,
+                0xc178e7580c65
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd313eafaeaa1793,
+                0x3838891cceeca58a,
+                0xd9dd8704ac1a0229,
+                0xf2190f2f82cc36db,
+                0x81eddbb13fc26f9,
+                0xe518535887dd7e6f,
+                0x693a8297bbc8e5a9,
+                0x68a7974d0a8fb9e4,
+                0x890c778c4456e889,
+                0x58a3c17d479b69f8,
+                0x9dc9bc189d0d481a,
+                0x216808071491
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b0447ca4534d90f,
+                0x7749391518f1ce7,
+                0xfca193dcee4e7fdd,
+                0x92d70cc3a8431f46,
+                0x5bd050f43b484be0,
+                0x2e5a6badd4e1f4cf,
+                0x74752a6acbfe49ab,
+                0x1cfdbcc61a53a46d,
+                0x552cefd81558130b,
+                0xbcfaa609a40132f7,
+                0xfb4f7fe5da3c7882,
+                0x2b92e5fcfee4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf5c242221bd26593,
+                0x74d1a92f55378cd5,
+                0x753a5957eab3e21a,
+                0xe22b505db2456c16,
+                0xd32057a4fe55efdd,
+                0x805493b39268a5e8,
+                0xffc2e167ce534fd8,
+                0x88d773b19ffc228e,
+                0x405ea206c18e0266,
+                0xe23ca541a93277f6,
+                0xb12e19ac9f5f71ce,
+                0xa605434e8d2e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x67bf50ac2eb9ef5f,
+                0x4259048775865ada,
+                0x4b9c823dddb30e2b,
+                0x933ffbe9349bceeb,
+                0x4f39285b7ee0b2e,
+                0xd60dfe8d215c3d51,
+                0x4a315e5bd4ffd607,
+                0x1bdaf5791082ed2e,
+                0x3c7c8c2f7dbb8e20,
+                0x7eab56df740ea145,
+                0x7a725f79532b9621,
+                0x14b6d5c44f5ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1e1714dca3ae683,
+                0x8a78be7067cd78a5,
+                0x4a6478e1bded5f70,
+                0xaa410ba1cbb63933,
+                0xce427fa93d901f7d,
+                0x3151ad9072b1bca2,
+                0x95b9263bb5f0cb55,
+                0x8c291370d592942c,
+                0x1fbeba675b602726,
+                0xbf2a478507e8e0a7,
+                0xf649cac9ecec0b5c,
+                0xa80b6754eb12
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd78fa61a9f681354,
+                0xb0afc1161e01379b,
+                0xe64b77d1bbc2890d,
+                0xd0b63c204ea4b557,
+                0x5a129d5358c37ec,
+                0xd935190e269de5e9,
+                0x2c2555292f1b29e0,
+                0x360c0a8b4308849f,
+                0x1866c4d7d65b2085,
+                0xeb6c6f88d2b62e9a,
+                0x10f48f5f172e36ce,
+                0x9cbada24e6a9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1126eff5fd2cf18c,
+                0x6e0b5c4e5f5c4565,
+                0xdabb97be05ff056d,
+                0xd94f1a20d6e4a16b,
+                0x5c0a124bfed80d3,
+                0xd6868cf6d134cb0b,
+                0xcdec17f439c9a3f9,
+                0xa85e855b73044ebf,
+                0x4b4d5b62b6e6d70,
+                0x1b2f5b5dc106397c,
+                0x944d612b492cfeed,
+                0x1c41c000a59f3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf22008b17567aeb9,
+                0xbcfc33b4796d846f,
+                0xe167aa6a57fa5805,
+                0x4ada47f21540fe60,
+                0xed86ab03c3e01c52,
+                0xa44cf4f5d7393e6c,
+                0xb0423106e5d30390,
+                0x1f8100cac68dd06f,
+                0xb21d48a999e0399,
+                0x5e85db664dfc947e,
+                0xb86a645a8e1a13e2,
+                0xa6c4783b6ea1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x359c7c13bf71cf87,
+                0x1c386d456a0c983a,
+                0x4cefce0b700969a8,
+                0xe612b0b310faeffe,
+                0xc76f152da934d1f6,
+                0x346835c546926732,
+                0xc3d0afc56ad1a973,
+                0xc78287709da29190,
+                0x5a2c705037ab5a3d,
+                0xd6b033ba016e2db3,
+                0xc981ab6d22b1ba7f,
+                0x1024a12f59332
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfe8b7a07dd18a292,
+                0xa4ddc3dfa5553ec3,
+                0xbb01ffc95d9322b7,
+                0xa920145edb553141,
+                0xa4a0e173047cfbb4,
+                0xbd6782579fdc050b,
+                0x61d83307c418cab,
+                0xa5562444e3d4952b,
+                0x4fe5a9c065df1159,
+                0x57cc8aa3e98483fe,
+                0x7af6d9df03e30a77,
+                0xbd5fdb5b1bde
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b0ee4a7d7f3a26e,
+                0x811bf32bc388a4dc,
+                0x6c9a8a0a90f3e877,
+                0x7436ac7369db52aa,
+                0xf3b3fdb710e7d617,
+                0xfeb785bb26372117,
+                0x5675f5b27eb53dd1,
+                0xc40803213ed8e904,
+                0xec8e22a1ade8e11a,
+                0x39138334742dd979,
+                0x5fdc77cccc03ce79,
+                0x101972e25989d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98381398aceb18a5,
+                0xf58dcdb1c1e1806,
+                0x4168e13c4e83c617,
+                0x1d42a5106cef6ae6,
+                0xdf15ae7ce2541603,
+                0x51a88997b67c57da,
+                0xf258f6a42f95502f,
+                0xe2c094db65095caa,
+                0xbfa4f4a9ec77b670,
+                0x4dfc5ad1702f395a,
+                0xabd9d75bf1e3f3d0,
+                0x427ab3c6087b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x149fef687e176fa8,
+                0xd622e407a0fe6a0c,
+                0x623b7cd083d7940b,
+                0x13e46ff7b16d91aa,
+                0x2e4ea57737c4763a,
+                0x572c8cbb71866098,
+                0x433221ce65042287,
+                0x7868770a9e9949dd,
+                0x4b887bbd0190c927,
+                0x4372127a722b3028,
+                0x74b70b68b423514f,
+                0x19895984de97d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x74061b68a5b326a6,
+                0x8e05474cd9c3155b,
+                0xcd939f32d72d75fa,
+                0x1b928c868c7b4ee1,
+                0x1b4202027de4ba5e,
+                0x88ed47a3cb23303a,
+                0xe88fd5e2013b216e,
+                0x22034a372466fc8d,
+                0xb80dcc300699b8e2,
+                0xdcaccabdd5fc0b2d,
+                0x8ef0332a579bb824,
+                0x6aaa75b4e6d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70c74152f3dc6c90,
+                0x9ccb88361d51a835,
+                0xbe374863d493e98a,
+                0xfc2962c6c8602afd,
+                0x70cb135cff519828,
+                0x7f5a883b6fdd0d58,
+                0xafb90d03ebc2b080,
+                0xe37fe72e720bed4e,
+                0x7e1e8c4a86078e9b,
+                0x49a3c0099f6c8a34,
+                0xba9939d548e01e2f,
+                0xf52241103e01
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe657447eacd0b8b9,
+                0x3d139acb2a0928fe,
+                0xfbff4077f6c7fd5f,
+                0x242d628c3f219c47,
+                0x3162734b20fe4c67,
+                0xeaa587416262d818,
+                0x3147100ee991a8b4,
+                0x6f5dcc4917e8a251,
+                0xb1e88330224f4764,
+                0x95cf2216b12d3e34,
+                0x9748a024e3c05af7,
+                0x1568e87af3d72
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x573c766a571cf6de,
+                0x207f9b1684305f9e,
+                0x248008ccbd8823a1,
+                0x37d69ac6c6b8b167,
+                0x3e0c207a1b0d32cc,
+                0x52ea81ce04eaf721,
+                0x8ae1ad7c46d9cfe8,
+                0x5ce1b57f6d722fb,
+                0xb928341bc5110df4,
+                0x2c04b8cc33704c7c,
+                0x1d33c3967fe7bc29,
+                0x6d5bd125f113
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc51edadcde2145b6,
+                0xbb0d100677f30b4d,
+                0xd81648f122d970fc,
+                0xba6dd9ea41a3fc73,
+                0x5bf113dc72a1753b,
+                0xabd765cd4bdc1013,
+                0x942b6597c44ce514,
+                0xe8f42eb40fadedb,
+                0xb5b44c6d1c203039,
+                0x4af5674b6d318bab,
+                0x121aac95d027b79d,
+                0x86643b125289
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf4a25b3847703546,
+                0xa5364e5f92c69f4c,
+                0x94a8bd577f24106d,
+                0xe163bcabbc891ef0,
+                0x1dae8725ee1ecbe5,
+                0x9d9c6abf91b34440,
+                0x83b9da8b190b2a55,
+                0x9512430140589688,
+                0x23460924afd03d8c,
+                0xc67bb172a5a8092c,
+                0xb694a038aa0137c2,
+                0x198e90ea96d48
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb69859730716ff2b,
+                0xd65b64c98d146cb1,
+                0xa8d86cc57b4fcf0b,
+                0x7a44b2deb1b07795,
+                0x7fabb85df4c3a4a,
+                0xc441254c1a2783e4,
+                0x30afe0ea2f8ccbf6,
+                0x5861d2facc6eda32,
+                0xdf2a67fe3191ca12,
+                0x5a952d367c93e169,
+                0xa02c7c463be7c870,
+                0x429d32aab36d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70ab7c0f525e6553,
+                0x5d08a41e39e1383,
+                0x7564dd273eb171eb,
+                0xc81bde1f8c122c64,
+                0xf42fdf4e4fd7ce7e,
+                0xcd9e7c29dc72f534,
+                0x13e22dc5a6b04896,
+                0xd646ad18b87096d3,
+                0xb3b186b2183a4570,
+                0x911b11ae5d818c17,
+                0xd18ed4abacd78d0f,
+                0x13bb4b2c5261a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b78dfbb1b3b97ff,
+                0xa43b47cb259c46b5,
+                0xd07a835412e796f,
+                0x60af64e1c0543d9a,
+                0x795aa0d87e0eb18b,
+                0xf0625ef89b94c065,
+                0xcfe32763ca646803,
+                0xb39e39d910465d75,
+                0x7ed702ec88b289da,
+                0x47b21434d3f67c31,
+                0xed553cdb97cd4f26,
+                0x241679effd08
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e376c413e94d0dd,
+                0xd3d4af27ffaef115,
+                0xbadbb35434bcbc5,
+                0xdd534c5e8d691c,
+                0x6cbb8a27d2a29138,
+                0x4917a556f234c19a,
+                0x58d495c0d38ff3e,
+                0x3f22f036b589ded8,
+                0xfcd652cbc519bb56,
+                0x3cd173d382c2be8d,
+                0x67318024c55d5e66,
+                0x11bf44d0e6449
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x96bcb5a5e7a11e55,
+                0x9de4ed815f765580,
+                0x35243e5d045a4ad9,
+                0xe03480e7359700e6,
+                0x1cdc8750c5f09b31,
+                0x2020e5e7c6de4587,
+                0x14766b859803bb56,
+                0xace18ccd72921ceb,
+                0x1324e4302498efe3,
+                0x185c19b90eaa58ad,
+                0x96b194bdd88b2aba,
+                0xed56f9c98b10
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x39b4e647131532a9,
+                0xb7882ec098c69021,
+                0x70c2a09089595662,
+                0x1b8cba4c6262614,
+                0xb948b8434badf7b0,
+                0xad5fbfe9dbe2af67,
+                0xbf9437b5eb5b60ab,
+                0xa40fa67e12bd3d18,
+                0x8673347c5df6c361,
+                0x3fe4dfffbc436f0b,
+                0x55b9b1b2a7265f5f,
+                0x12e72e0d20c6c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8d4d3e72af6ec9c8,
+                0x3c2d9d1144d92b73,
+                0xf7439f2bbd30825f,
+                0x22f6db0db600f239,
+                0x4587acb8fa517c6,
+                0x582eab2135bb0d71,
+                0xbeafa32b4e123da6,
+                0xc7fe8ce1b32c3f2c,
+                0xce1bad7126d09564,
+                0xf86bbd54f43c602c,
+                0x4afbe3a7519f097c,
+                0x18caf4b188c0f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x55ee30bd0dab7dcb,
+                0xba7016b60b7d5b21,
+                0xd6a373c08888137a,
+                0xb5d48ed58014cd9f,
+                0xb351242ce24938c0,
+                0x99a7cc2f49bcec25,
+                0x5117a8ac25e5d771,
+                0x3a1749e2156d13e5,
+                0xb5235c994dad6c73,
+                0x42cc54636683fe48,
+                0x7ae251f94a865771,
+                0x90bcfaf78e90
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a8adf5ba7332d02,
+                0xbcefca834088a4d0,
+                0xa14eac2463fc92f9,
+                0x73ec455b8aab3f22,
+                0xb6cb3697a6b514e,
+                0x2f37350d740281a4,
+                0xf21fac47b5b387f0,
+                0xd2018079b86a2682,
+                0x5d62a47479c37213,
+                0x3e989c694aa6eeff,
+                0xdd0867838f7840b2,
+                0x51f88b2c182a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b3110912a339031,
+                0xc472d596ac3d089d,
+                0x2c657e3e776c768a,
+                0x4e8b798be27d5f05,
+                0xa36cf1359be1d90f,
+                0xa5acd4dab24da55f,
+                0xbe5d1ef83e97b921,
+                0x362065e541ad93e4,
+                0x3de26bf63023db60,
+                0xa0469544c3c47bbb,
+                0xc9cd28a5853e780b,
+                0x532d29e8d9cd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x709b1905c1309ea1,
+                0x4f51a6adbd23f0de,
+                0xe9e61516d602fa5f,
+                0x864ad280ec99bb46,
+                0xcb33fab101af4807,
+                0x3eef520d70ed2903,
+                0x58c35e9e7829efbf,
+                0x4b97c142be326355,
+                0x6a70d971ae33c6d8,
+                0xdf4fb282c85952d6,
+                0xbbfd2fbfefa3d1d7,
+                0x170b9907cb3df
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a4328d76dcd8b4e,
+                0x8a3400b8f1ad7eb9,
+                0x4d95c9e037e84980,
+                0xb9547e432fd93ba9,
+                0x330b304dc8f10b2,
+                0xf336df11d4005441,
+                0xde0e63bc73bff346,
+                0x158be520d2a80ff2,
+                0x9852808acc2636ba,
+                0xbca821fd914fc602,
+                0x3b813249e23b37ae,
+                0x13ee0c128eedd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x58283be2cb543091,
+                0x1dd6f5aac4cdced1,
+                0x65fa4a6f1802f87a,
+                0x2b26c2648cc49768,
+                0xc69ef286e60267de,
+                0xd2e500fdc01327f0,
+                0x62a527953c3e0aea,
+                0x224391bdfaed0fba,
+                0xb4efc710d59e21ea,
+                0xe087aabe4a196142,
+                0x5c5d6f2f6a8643e8,
+                0x143706cc7319e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3fcfaf1b3a13ef25,
+                0x712a15a226cd4bd5,
+                0x11c34a27d9433ae4,
+                0x9d93a2ae661b2ba5,
+                0x8c42d15bb5305a88,
+                0x666a4f4b5475c088,
+                0xf71120652c9e015b,
+                0x5d898e382f30ca63,
+                0xf40ce9cd78804b,
+                0x1ae599e2b6bb80bb,
+                0x94dc786f456206f,
+                0x183fe440536b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc97814554567c73c,
+                0xa2c85da5bd4509cb,
+                0x545eadf110ca06b0,
+                0x63ca40d2f70db489,
+                0x606a9ff901e92798,
+                0xa43c3e8f58b01196,
+                0x1db4ef1fbb4b9e23,
+                0xe1726519d150ea29,
+                0x9e6cc4f27f0b30a4,
+                0x4fc7815e03f8b1b8,
+                0xf19c7e3f28e1ecd8,
+                0x75d9b6dd2bfe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1661228bb2fa5ad5,
+                0x6fff265fe0e75f2,
+                0x3a4afa025729d9d0,
+                0x419296aa32e53a39,
+                0xd4f089a2f8b65450,
+                0x17bd9732e3f0e5e7,
+                0x11decde9ef57dc88,
+                0x94f4f3ef1d6ec0a0,
+                0x1b133ac98bc3f90a,
+                0xa02dc0d93ba99b03,
+                0xc61ed2fdaf02d26c,
+                0xe12dbebcf428
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9af5fc449e7155d5,
+                0x309587559ef877c,
+                0x86298c9bc7d93c66,
+                0x19c05528debebae7,
+                0xfd53b1fc2709ee88,
+                0xcfa828bc1adee618,
+                0xe06582dda1699e1b,
+                0xfc72ecb59af48468,
+                0x8ce64e5fe7f7e8d4,
+                0x5417a62c3a28fc92,
+                0xd6010202ba063148,
+                0x16f24dcb45565
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe44525a98e5435f8,
+                0x690c10cbc26e6404,
+                0x57acf41ff4661342,
+                0xbc3efd5a2ee8ec2,
+                0xe9bccfe8cb27caf8,
+                0x5e13dd4c11aae94d,
+                0x564983bb7f09ed74,
+                0x5ccdfad03f236b4a,
+                0x1e246c054830e423,
+                0xb08978177b9ea225,
+                0x73d8c853766611f4,
+                0xd55ca1ec552d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb2e2d119843b46f,
+                0xdbce32e718bd3392,
+                0xef396e074468f122,
+                0x8f9db1bb2eff4966,
+                0xde14784a1ddecfc6,
+                0xbc66e545a1abe7b9,
+                0xaff40bdc96f03306,
+                0x8a9eb1e2896caf6,
+                0x51dbf857bedfbc3f,
+                0x62c5e7f6b93987c7,
+                0x867a97f50800258c,
+                0x84c7530ab02
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe152fa6e16cdfc7,
+                0x8183d857e8bbb547,
+                0x233c4029112b284f,
+                0xb3ee3dfe58434c85,
+                0x5e732e993b4b39c3,
+                0x690766cc67facc88,
+                0x61b99ded4a3b2622,
+                0x91053191bef1ccab,
+                0xd2351c18eb1ac3e1,
+                0x47ac93222de964dc,
+                0x41bef592caa96df,
+                0x3b59715368b9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2af53e45c3cdecbb,
+                0x27c4025265c45fc5,
+                0xa96aad3d70d7f3ee,
+                0x9af4303910570246,
+                0x9a809bea150d6fab,
+                0xe1511cc64031ac37,
+                0x828b4a721954e515,
+                0xb47049197caa82bd,
+                0x3a8350181a8e4bcd,
+                0xd85328d64bb26be4,
+                0x82c22abaf145f4bb,
+                0x1be2a6bde3572
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9d877b0e66da044,
+                0x5cccf1d3a1796282,
+                0xb1021b167e642c6,
+                0xc216e04b54d711f7,
+                0x937a0db8eb88bfaf,
+                0xb001aaa578bbe2f3,
+                0xfd20715788d0658f,
+                0xa892498dfd1ce12d,
+                0xdc6e5775232a3486,
+                0xf110348ba1c80ef7,
+                0xd64c68ca4e382382,
+                0x1c074545454c0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb7febd23509e0bce,
+                0xfdbef2f14645e5d3,
+                0xd048d7bbbcbeda37,
+                0x85fecd5b19985aaf,
+                0xb4a31605fa44e8f0,
+                0x35de9bee940e8d9f,
+                0x1304bb41c3a86402,
+                0x1fa52dd2f218a3a3,
+                0x3894c7a33094d5af,
+                0xd2f4f6125b2f6d32,
+                0x5d9adcc3d430c596,
+                0x130039f26ae20
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40c0eac8fe4c125f,
+                0x6d51a743240419cb,
+                0x3137d2441d16d0b2,
+                0xae5f14f1d534f9af,
+                0x702e750ff76b9b8d,
+                0x28053d24eeb12577,
+                0x646c6fa2f669f18c,
+                0x2ccc63b0901fd931,
+                0x359cb8e3fdbe0761,
+                0x625bf43d7ccd9cb2,
+                0xb51df2e2ef7a259,
+                0xb7655b173389
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98887e2f26aa25e1,
+                0xd9c8af2d0b7da034,
+                0xa87eb1e56e63b43c,
+                0xd8d4c0888143a143,
+                0x3c897a441718ab29,
+                0xbecf44f22d053acc,
+                0xc46b61249669a7f3,
+                0xd34ef5f7d46e8f17,
+                0xa384c3b2b3a967ba,
+                0xc3bc0b672c214854,
+                0x8801f2cc71e8e5e4,
+                0x1a634aabad04b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf39c2bd9483637db,
+                0x1bb83b09f6ee5c5b,
+                0xf4269844ef907832,
+                0xcd93943f1a7a3bb2,
+                0x37118c181539157a,
+                0xf5719f22bb04b291,
+                0x5c1db8ee2b5c9028,
+                0x7a38e2b09f0cb55a,
+                0xaa7359760c044802,
+                0xa409f674b953ef90,
+                0x4ea500a2b251e25c,
+                0x293877e38e5f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdf434bd29fd29d67,
+                0x7537ab6827ec70a2,
+                0x3f3447cf5c9ce5d1,
+                0x7246bcf561ff90ce,
+                0xde5322ae25fa7c35,
+                0xcec01d72b3923dca,
+                0x4d4c35246321f605,
+                0x595ae224674e16fe,
+                0x4f552aeb8eb6c2c1,
+                0x80124f78f442f3e4,
+                0xc307176c6c8b1f35,
+                0x1568eeaf21b3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa49409f5b48f0e41,
+                0xb85fdf124cd2b818,
+                0xfff01997b7a72664,
+                0x807233c75ebdf88b,
+                0x275eb87d47564fa0,
+                0x3a55d9419f23053a,
+                0x8c99be11171845e6,
+                0x69338eccaebbecb0,
+                0x2fb74007107f1d82,
+                0x3a5311ca093ce71c,
+                0x8a7c3d600d9865b4,
+                0xff251586fdc4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b7106bef6da3c9f,
+                0x6e9c030d125339ee,
+                0xfe419b0670b2a1f7,
+                0xf9a6bcba98c36653,
+                0x8622509f59c44455,
+                0x88a8c034245837a0,
+                0xb2547d7637cc022f,
+                0x74035c1d80cdc27a,
+                0x159d2dca3e6a2d2,
+                0xbd5c991a9fff224b,
+                0x7b44d5bcc0182a40,
+                0xee493e0f2d9f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x95aaacbcc5e572ad,
+                0xcb8638d4f4ea106a,
+                0xfdb96673177af156,
+                0xa00c6b2d206d57c0,
+                0xcc0ce49d57567b4,
+                0xf95ea6d7aaf62cff,
+                0xd9e6dc29a53d8a2d,
+                0x7525f44cadc7a124,
+                0xcd1c94ee632cfefb,
+                0xdb1a8008b326c8b1,
+                0xe620667a27c2fe1f,
+                0xc6eb18d28915
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcd990e3e5e3ec7fc,
+                0xba8851228bbb9b0f,
+                0x6f085874035333b5,
+                0xcf1706d1fc2e8a31,
+                0xaabd5704869cc5b5,
+                0x8c82bd8e995903dd,
+                0x1b25c0007646dfe4,
+                0xdd6d596717774ecd,
+                0x33cd545a4585f880,
+                0x3d1df0506765883d,
+                0xc5a4aefc2296a2e2,
+                0x69eb03e463ef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaf5c96d7b5d2a64b,
+                0x577e9a14f6ff3dbf,
+                0x8a3a5877aa9d6373,
+                0x987d1a8a2c1ce2a8,
+                0x6d373d41e3e1252c,
+                0x7d591ae60ad90e03,
+                0x235bc4d837fd8015,
+                0x6a5ea535aa94c904,
+                0xe314ec65a80b51c9,
+                0x6b24ab7ccc44661c,
+                0x4b2207f09391339d,
+                0x4fcc1440db18
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b4d537c52ffb9ac,
+                0x5b68ed6cbd7e8cae,
+                0x54df810a21a9f32,
+                0xd20ddf8a76471c0e,
+                0x7864ad1c3fc09a04,
+                0xcda7b90d92472880,
+                0x57be2d7cb46df21a,
+                0x677bdc32604e9f0f,
+                0xdb9e904b0c9d2869,
+                0x6cfdf1101df40725,
+                0x626e400d33c05f5d,
+                0xa73b9887e2f5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f07b07962d373f4,
+                0x6fc737a2a4874478,
+                0xe3cd4a5667301cda,
+                0x1ef99fff4338fb72,
+                0x6f9c1eba3ac3dceb,
+                0xb7e6c40ba2e7a85e,
+                0xc774c0fb21538837,
+                0x997d282ab79fac3d,
+                0xcc909b2829ec1142,
+                0x23ce460666a64b,
+                0xd094bf14036218f4,
+                0xab4ac528bdb0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdbf57bcb97897cf7,
+                0x11893bfe9cac0f34,
+                0xab2e36b6800aae2,
+                0xeec5c239d85e8458,
+                0x4daaf88164bc76d4,
+                0xfa40363af32dbb3,
+                0x5a7707ce90ef3f57,
+                0xe0b92c0e811f3dc0,
+                0x3e868842abe32f8b,
+                0xe3d4b15aa34ced90,
+                0xaa8e52fb5e2aee81,
+                0x1fee85293c9d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e8afb75854be69a,
+                0x6137b5563054f6b5,
+                0xb1d109983ddbb08f,
+                0x3170b41688da5780,
+                0x3dadc73d57f6a727,
+                0xfeb446139d1e77f5,
+                0xa184ebeca23619a2,
+                0xcccd61b3a6367548,
+                0x278c3f55265a9403,
+                0x1267133b7c9b5f15,
+                0x90ff52d30dc86e4f,
+                0x3c4a72ca8984
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x56327c5f1118d8d3,
+                0x5ad567b40a24e97f,
+                0x900284e37d6104ba,
+                0xb5adf3a70f0c0964,
+                0x3eb0512f08337c0e,
+                0xe9be765a156446e7,
+                0xb07de4a2b54287d8,
+                0xd4d47d004f4eff4e,
+                0x3a4593afd6c66507,
+                0x6bd6f657d84acb48,
+                0x6016f46df554a06a,
+                0x1378ebb65372f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xedc6de5a16fefcd9,
+                0x717846b1d45aabf4,
+                0x41c7e5295349500e,
+                0xd8df21424b22f2d,
+                0x76c9d221e2674444,
+                0xa0a391c4ab765fa7,
+                0x960c57ff8ad4fb47,
+                0x3a01f937b14c35fa,
+                0x6d6fbed9618b9dcf,
+                0x938162e3f9aa56fa,
+                0x240cc90f6ea70dc,
+                0xdb0288004431
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x378f7464ac88b10c,
+                0x486bd48db5fe0863,
+                0x4e517d396c6dca90,
+                0xe9eb07039424d0cc,
+                0x7cee9824aa1f21c2,
+                0x47f1d15e5ce5071e,
+                0x2de88b483d1d44d3,
+                0x23322845fbc6b51c,
+                0x89a78b5644a28c2,
+                0xa479fcd7c2373c8c,
+                0xc5285d5acdb7a7bc,
+                0x197078fc42954
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb8d30262e660e1c0,
+                0x63c7437f43450350,
+                0xa38006afa497a355,
+                0x4274be1775c72b74,
+                0x11d3d81c8f1a177b,
+                0x86cd0a077857eda5,
+                0x166a0effae9581f6,
+                0x1113f7527e8bb91f,
+                0x25ab8cc11ca6cc9b,
+                0x3db75ffe3483c220,
+                0x26587eb1469a6244,
+                0x630b74f95f0d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd50074fbaf4d0e98,
+                0x63e1fa19c2d0b4e0,
+                0x6fde5b115065b363,
+                0x50f29173a676c55b,
+                0x5f6003989f9f5e41,
+                0x2ca9c5ee1cd3c0c6,
+                0x6dd89d0c24532836,
+                0xef3f5ea754a1bd4c,
+                0x30130f2ee4b0d9a5,
+                0x35ffcb593d07a6c7,
+                0xb0ebe02dbb39ce51,
+                0x71069876d2d4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdfe273de63706123,
+                0xa85e0254da7dad5f,
+                0xf128b7ed32946d53,
+                0x76ba1b99d9cf4294,
+                0x8ab4a8ccf0eee5ad,
+                0xe4a15780e7c0881c,
+                0x642215f5bb160672,
+                0x57c525dca207df93,
+                0x14fbd7c5099ed97d,
+                0x8d7f4003f1b47f76,
+                0xda8094bd144e6762,
+                0xf56dfd1f904b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2067d7d73e36eb05,
+                0x1dc54ab9a8bcdd1,
+                0xf15defbf6e0d3620,
+                0x7cf61466af75c7f0,
+                0x85daf9598b885cd4,
+                0x8995389bcf35384f,
+                0x8cfb10f831e077e5,
+                0x3df757ce2edbe703,
+                0x67fe8ee997e72cb7,
+                0xca745650ae9b3ae5,
+                0xae683057e9e28900,
+                0x1224fea663568
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed91cb73afa2debc,
+                0x521dfa1e3a2306a4,
+                0x480f556b356bf114,
+                0x15b58c3953abe6ea,
+                0xd5098b13389fa875,
+                0xddda1288b510d63e,
+                0x25586fdc0894457c,
+                0x26ebfa0d5f918468,
+                0xb766bff76467ea3b,
+                0x2881af40d37a4072,
+                0x5d16c53ff083fd90,
+                0x654fa9edd33f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fbf0fda8c4d1925,
+                0x6f37f05f9b20050b,
+                0xf9bc45375de0ff36,
+                0xea3c06f989c2d2d6,
+                0xb04fdf070e86efa3,
+                0xb29cbdbe60da4779,
+                0xa59330757aa97fc5,
+                0x50007f2c32cf8956,
+                0x812de94c8d1827e7,
+                0x4c2d1f5426bb93fb,
+                0xc55b5158cb41935a,
+                0x7ecce824d508
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9bc4664559b44008,
+                0xf7aff4cd6af6c52c,
+                0x276484a5482fc7db,
+                0xba2203e02fce757b,
+                0x50aefcf945108ada,
+                0xd8a07562e0e78a0a,
+                0x7ab6d78e285f2ea9,
+                0xe7507d74f77d3d70,
+                0x9cabbf0bb0a537ed,
+                0x76c5e4ef1ffac2b5,
+                0x4466e8615f2d9896,
+                0x1b423119db26f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa94c981f7028fa4c,
+                0x63ccb78b1eee0928,
+                0x76175bb2354bdf39,
+                0x79a521ec6003cf99,
+                0x6725d10e11ef7fc6,
+                0x7708ffe201969814,
+                0xd2ea5aefcd285c19,
+                0x588d949037bea71f,
+                0x1f37dd8a4cc64179,
+                0x7ec57015f2fb9d4f,
+                0x310ac1a68a342e48,
+                0x40f89851ba89
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbed75cd872f94d9c,
+                0xc73348ca4300c54a,
+                0xad669d9527d0c7ec,
+                0x872227f40f1bfb3e,
+                0xf4e1fa4171da1665,
+                0xf32af4f4d142fdc1,
+                0xd89fdcd72289b82,
+                0x69327e81021bb12a,
+                0x51e134f5b1d9a8f1,
+                0xdca091c748e678f3,
+                0x76edee1fcc55078a,
+                0x13cabf9b7a5a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe960a9e499d04a19,
+                0xa39c8fc3cf931631,
+                0x9c2d11e2f83a1707,
+                0xf4229b2921bce8b5,
+                0x33cb79708e5f9420,
+                0x132e7861881c53f6,
+                0xcfee03c45df4ca05,
+                0xa1566b7cd8fffb48,
+                0xdd4311f1799165eb,
+                0x970f782d945706df,
+                0x8a50ff7ac2e2e3c,
+                0x1603562b07a7d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd8b84257d4b63fe6,
+                0xa6629483660b0371,
+                0xa65ace5c3a715329,
+                0x6ae79dbe5bfa37d8,
+                0xbfb12804e4769d0,
+                0x735bea85b99fe667,
+                0xa25cfe5fadc50877,
+                0x4f2c7ab6bf378537,
+                0x299d47ca67ef4f31,
+                0x5c0d7e7da72b2204,
+                0x6f77e2d15a9342e0,
+                0x16d3dd7aa0619
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8cee8c51e46db909,
+                0x9c8a1e334993601a,
+                0x34b8be464336d7e4,
+                0xe29e49103c6a4bea,
+                0x6fcb3324844fd890,
+                0xc2d2e56654b51220,
+                0x6033cd7f04322fe4,
+                0x5bc615dac5bfd3a0,
+                0x25b381bb99a02954,
+                0x24b92484e720dc10,
+                0x3a67e4b8937931f8,
+                0x5ef840e678e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x72118e67a81c2776,
+                0x52659f593f927a7f,
+                0x11c3561e0eca43d9,
+                0xdab9c627454d4567,
+                0x8a022d116cddc207,
+                0xa776155a66d3532b,
+                0xd898f3b9628365e9,
+                0xeefb54da4936400c,
+                0x4f2d8bab9f999022,
+                0xdf06a21136372fbd,
+                0xa37ba9514e66ee5f,
+                0x5d9f3b1b5755
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6303181e1dc8f199,
+                0x1c66d60d2a2fff03,
+                0x875abb3441dd81fd,
+                0x9c2792718560a346,
+                0x21c35db514df4c10,
+                0x4f66fda132169a0d,
+                0x3ea1b611270d4dbb,
+                0x1c6472dfabcd6cd4,
+                0x955bb1484740a722,
+                0x8eb9a55e2bf58522,
+                0x77307b4475d2268a,
+                0xdd15e3746c57
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9bad4b041119feeb,
+                0x4c77596857583f3,
+                0x109ef43b052ac44e,
+                0x6e6e5596595d8928,
+                0x75424c743762350d,
+                0xb169035eff13c546,
+                0x9c797a3fa310e2d1,
+                0x588a03b10d76a09e,
+                0x46c082bd56b8f80f,
+                0x8b0b0ea1fec3c436,
+                0x76b6fa93ed0e362b,
+                0xa0a354ab99bb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd52cef6e4f703a48,
+                0x26ab6709b0077f3e,
+                0x652a9b783777b36a,
+                0x41ebabc63302c38c,
+                0xb4e2c9808eec0350,
+                0xdc9a4c78b74402fb,
+                0xf67edf9f132a315f,
+                0x6e2bf260db05b05d,
+                0xf55d6a1316f5ffe6,
+                0x5dd623011ef529c8,
+                0x194ab40de9e9fbe8,
+                0x1075b11744c0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x32b124ca8aaafe6f,
+                0xbe297f36d7927ffe,
+                0x4493c53524eee745,
+                0x758d3426cdcf6c2f,
+                0xee13539f14b116d4,
+                0x162b05e43d00236d,
+                0x922532865d60944,
+                0x3cea2e94703bfd52,
+                0xc4668588eea3c7f0,
+                0x92c3592542f07f4c,
+                0xbc2aac51cbb0fb9a,
+                0x5ec5859eb1bf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc90eaecf1fd04757,
+                0xe861a07b6774a552,
+                0xb47e9b9650ffabb,
+                0xdc300416c728e0e,
+                0x98eb4aabe2bb8874,
+                0xfdd0b1690774d4,
+                0x4b40d1df9179b8,
+                0x8b54d78ebb73909c,
+                0x2e27703b7f39588c,
+                0xa747921e6699e718,
+                0xd38fea3d693b95db,
+                0xe2214e20f42e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed0fbccfbbf5ae54,
+                0x409b0fac08c2848b,
+                0x125254587bfaa3dd,
+                0xce4cff059bd37510,
+                0x1b3045c978f72e57,
+                0x7cf4b9be96bef69a,
+                0x6a99339c23b1cdfe,
+                0x629e044f16c350a9,
+                0x68125d5250ec01d7,
+                0xcb9c333eaf800389,
+                0xcfe59054a460f554,
+                0xfac714a1f4df
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xca5adfb0d18c9503,
+                0x703f6e262c17895e,
+                0x1803d47521705872,
+                0x6e07bde995e852a,
+                0x2174826dd0f633f0,
+                0x541ff062a8fb692d,
+                0xe2b5512e2a8f0561,
+                0x818e97db37968b2e,
+                0x82238d0951b66ffa,
+                0xf805ef4296e043b9,
+                0x8238f78a4a6260d4,
+                0xc42ebb74738a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5439f39092ce0aff,
+                0x197b15655c872654,
+                0x20a0683dfd32bdb9,
+                0x4d8403f96d8b987,
+                0x9b4a1e569d349f18,
+                0x695164996886da93,
+                0x2f75fadacd012a2b,
+                0x7ccc013e7badeba6,
+                0xbdfa57b62e1e25e9,
+                0xa0dd6dd4316a53d,
+                0xbf58e9fa108b0930,
+                0x10bf04ed1231d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79ce62a147ec3d06,
+                0x6b0eff5d2f50300e,
+                0xd64fe0bad0ef4dc7,
+                0x2da426c19f1c73a0,
+                0x1739da3a8dfadf82,
+                0x79626ef55bd1841,
+                0x3754db63b01b3503,
+                0x8e80ad361e5c0c0f,
+                0xe8d1422d5f3c9da1,
+                0x6618ef2150625995,
+                0xd1146c9e82223e73,
+                0x13e013a8ec18c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa14e70b0a804c14a,
+                0x7f142c9d14a6886a,
+                0x3c9c5acfd05a952,
+                0xfd84a8ebcf29c94b,
+                0x69e9670e72d4fca6,
+                0x85aa7042df86dd3,
+                0x5a62c6176eef7d7d,
+                0xf72a03dcfd748a18,
+                0x2decc4a30a0baeb9,
+                0xd774ad0e47019e3b,
+                0xc25feb9720a59e2d,
+                0x10846695f2ec9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe5079e551f2cf7,
+                0x6a6c30d4e49ad29d,
+                0xac8b84f982f4c5b4,
+                0x357340d677eceba8,
+                0xcf7911f39fc086b4,
+                0x1f85ad6b595917f1,
+                0xe7477a70ff8437cc,
+                0x1327b33108a3ceb,
+                0x50caa974de86e917,
+                0xa69e711fd0afa138,
+                0xd90c786f01958391,
+                0x140bfa47e88a1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd41c0359a590b732,
+                0xaa41be260fbc91af,
+                0x7939c4d86c622de7,
+                0x53d9e0bbc620add9,
+                0x6136f87f268ed66b,
+                0x20475565d0d8cc63,
+                0x72098b5e2a709c48,
+                0xb302d307d643c4fb,
+                0xe2d57b3d17e1d4e0,
+                0x2bd6eefd54c540b0,
+                0x5e682c833887e55,
+                0x11a056bffcaef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x31c2122e27a83db,
+                0xe6b3b95ecc09dbf8,
+                0xa926a0e68ccb299b,
+                0x3fb7570884326741,
+                0x1d8aa26c092a0ab1,
+                0x93b73dcab0e34f30,
+                0xfa0f587939d05d07,
+                0x88a35eca00790524,
+                0x26abbbcb022eba11,
+                0xa9e4403e7a2d61ad,
+                0x6220885f990db61,
+                0xa015f6effc39
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c39a228d42997ac,
+                0xf6082ce9567c3f39,
+                0x87d0982d036e05b1,
+                0x76fe4691599cfddf,
+                0xea657a69f574cc3,
+                0xa376785bf6648a59,
+                0x7dd221b7afe3aae,
+                0x6ef5b01ab0b27df5,
+                0x152c500ad9339a2c,
+                0x915559afafd5d0aa,
+                0xcbb5caef39672524,
+                0x199655f4e8725
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c81efc692237d40,
+                0xcfbe2def8dd98a43,
+                0x61acbc13561729ea,
+                0x7fa7c698db3ebb92,
+                0xa26acf6ef11517fe,
+                0xd735324a9ff31598,
+                0xee2550d7277caeed,
+                0xeceea75db7e6d34c,
+                0x8cf846edebde3de8,
+                0xdef20272473077fa,
+                0x14c67133f6531c6b,
+                0x828e6e36b12d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47371b43ba09797b,
+                0x66fdb8b61cbe1223,
+                0xbdd062dab004f10b,
+                0x8b54c9f8d970d31d,
+                0x82b5dcb720b42a32,
+                0xa18ce2de243141c9,
+                0x4688f63fa5fa4906,
+                0xadb96a0e018b5543,
+                0x65ac0175ecbf5d2e,
+                0xa71c6e95af417f4f,
+                0x67c46ee40f181a05,
+                0x17a5019f26927
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x80fdab14248f970e,
+                0x9047082a792e94a9,
+                0xa8dfc31525ff2a59,
+                0x266000777d1bc22e,
+                0x84ec6b52092b2bb5,
+                0x55bb191afbc0649d,
+                0x8d5b353d1e5ff343,
+                0x3aef08e94a720b84,
+                0x8cf0f6e98bd2ea90,
+                0xa2c8bf7a962ab30c,
+                0x24c21a1c3cf56276,
+                0xd476950fdf22
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x48de9d37b32cf383,
+                0x346a42fc757c3842,
+                0x6054f5723506f31f,
+                0x3e98d23e5713ce13,
+                0xff0c97f188710ae8,
+                0x16e084afbbf48afe,
+                0xa2b588810c7b7433,
+                0x924b3612b12018f0,
+                0x6785eed8053a7ff,
+                0x49eceb7f934b8823,
+                0x2c86b1f70340ddbc,
+                0x3fc0a64c0314
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e19209576ca0113,
+                0x6d29f326773f1de5,
+                0xef94353eb1690cef,
+                0xee0d6426dce23770,
+                0xd274e196d52806a0,
+                0xc857327bd9c0ad10,
+                0xd3f909b915c60339,
+                0xc37f4a26164a5e1,
+                0xdd917afc923ced42,
+                0xc8300bd6dd85a0fb,
+                0x5c70d082cfccac95,
+                0x192d73df47243
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf78d5dd3cf7d18b1,
+                0x7d2bed81888c9fbc,
+                0xf50d205cb1f5d6b2,
+                0xa11c33541313cddf,
+                0xb1c5661d2cbc9f5a,
+                0x2a23ce4a201eb37b,
+                0x61bd2bc143e5a886,
+                0xfe2360d878fa3ab2,
+                0xc2169bbc4c934923,
+                0xef8f1aa8a6bf63b,
+                0xbfd84b25a5d0700b,
+                0xd5bd8f8b061c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e58b9962e4ebcd0,
+                0x853087cbdf252d47,
+                0x4be3210c040521a2,
+                0xc045492d4e93086e,
+                0x7617022531190e4d,
+                0xfab74fe6285133ba,
+                0x2dd9b8883f9ba44f,
+                0xa26a8bdb5de927a8,
+                0x5b875bcd19f4a26d,
+                0x5e93a4dd069e4fda,
+                0xc3c4957129b08e5f,
+                0x1985ea0347b83
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xebed688c66d25bd5,
+                0x328762b49ee240f0,
+                0xb9ea6a88d849b175,
+                0xad0c75f5809cb703,
+                0x910f66b32f8ead10,
+                0x1f5dcac075447405,
+                0x2850f300bbacc8fc,
+                0x194fa5888a800b64,
+                0x206f613d99f0cef8,
+                0xf28a14a51ecff552,
+                0xb75838d1b16520ba,
+                0xc8d81381ea0b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd76fc4467ff7fe7d,
+                0x478480c16420c909,
+                0x6f2ec03ee7fd353a,
+                0xb579f51b0601ce8b,
+                0x8503304cdcc9a0a4,
+                0xaa7f2a10cbaa7ada,
+                0xec98ef5efb7fcdf2,
+                0xf3863460ad0beca,
+                0xef55b33a2edca881,
+                0x3de4919ae01262f7,
+                0x1dc2066b0ea2fe39,
+                0xfca75e5a3136
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x78ee3e580aab0a6e,
+                0xf8d72f1003c43cee,
+                0xd409b82be5d7f718,
+                0x32d08937a78d2fe5,
+                0x9dc177381cbbc74,
+                0x5e2beb06eeeb2822,
+                0x8b52b4f9686c3100,
+                0x58cb51d7e5e8b319,
+                0x3ee6a4b1fc7cb308,
+                0xc0690be38c87f293,
+                0x4d1bd67a9405c9a4,
+                0x19eacbe1f383c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6f1e16dec038cd7,
+                0xe4590df6eae5d5af,
+                0xeb1e1f8171a5533d,
+                0x97a87e3540c558a6,
+                0x138e218910eb4d60,
+                0x51b0a2c6e5ec1f91,
+                0x3c58559568501122,
+                0x9a5f4325f91e3cfe,
+                0xd49e08b417a0ed10,
+                0x44cdc9eb224c1769,
+                0xe2c2d5cb0c55abac,
+                0x15fe1cf35fdd5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdcaed57aedbcd06d,
+                0x181582cc4780bcf5,
+                0x615dfb8e287f93e0,
+                0x49a446015597a09d,
+                0xad1e3b5e4edd8acb,
+                0x57a9e21edecfd6d4,
+                0x8a9f5b97fd1ad9f1,
+                0x7d2dcaff1e848121,
+                0xd92714bc824b7a26,
+                0x6ddf5d033db1758b,
+                0xade43ce0e9e59bea,
+                0xbff252278f7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2996d95361aa8576,
+                0xac17fe2dde4d1ec4,
+                0x2296ceae801da057,
+                0xa91b034950911fe1,
+                0x57625c11d00a0ce2,
+                0x2af8e846fb248fd6,
+                0x50e2778c3c3729e8,
+                0xec08f876878fb7b0,
+                0x41abcd6be9cb6a3b,
+                0xae6a6ae3ec894a51,
+                0x21ce2644c3106dda,
+                0x56511deba87a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37ca156b640ff1d5,
+                0xbc3329e0b94a7369,
+                0x874c3fd5a5f2a1a3,
+                0x9f0b3d81ffe0e304,
+                0xf988c2c0c4fd468f,
+                0x17e4095a3a6ae71,
+                0xab325a73d9be99f,
+                0x108868bb705d67a4,
+                0x2ebc00c8203da6ae,
+                0xfbd6be3e818e5252,
+                0x218e20616a06d7c9,
+                0xef602277367b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3e8d4cd7417050d,
+                0xbe6cb2579668eae,
+                0x682e07859b91d08e,
+                0x3efa76c83f137965,
+                0x655ac2da94079ed5,
+                0xaaa6ea0c614294ca,
+                0xc3cacad3a5d6cd72,
+                0xc554a0938d544121,
+                0x7faf0cb0d21f7736,
+                0xf2befa1d85b5babd,
+                0xecb59057d8a67478,
+                0x227981b0bca8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x78ae767b77e57fe0,
+                0xb648024b1f5c4a4f,
+                0x27fc9a07b27c67d2,
+                0xc6d7e033c9c16dde,
+                0x4b2e5ddf73a2d8ba,
+                0xc7fa7f23aa4e6128,
+                0x7aad25baf6c5f50d,
+                0xa5afd2786f23e319,
+                0x4d3acbab2168fa2,
+                0xb9ecbe7ae7eabe51,
+                0x270ee0a9cd1e96b5,
+                0x87eb2d5f5d8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce51282bdea052de,
+                0x770fdf7634c1110d,
+                0x22966c3e2475565a,
+                0xefd98780e2d99b64,
+                0x9683d8664afe8ee,
+                0x3c5495a95fcaa65b,
+                0x285469cff65c7b9e,
+                0xcab1c49fc3566681,
+                0x313b4782a34298f8,
+                0xa64a963f0b653818,
+                0x3403635ce6ff0a22,
+                0x179dc90b6bb45
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x25968fc2143049bf,
+                0xcbdd3301f5765ad1,
+                0x7bcc8a09e2eadcdb,
+                0xbb8e546539fec85c,
+                0x4cf72801fb8cf998,
+                0x19d12046d479fc93,
+                0xaa15a5b87078862c,
+                0x5be799f318fa572,
+                0xa1836394e364a170,
+                0x127019ff8870aece,
+                0xe1772fc6f4afefe8,
+                0x19e67665e8ba8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6ddcc7c5679f199,
+                0x455820cfd5f17e2a,
+                0xe21edcf2399f4916,
+                0x658540a9101d510b,
+                0x69f41b8da04a8438,
+                0x22eb46ed4fe8062a,
+                0xe604781fe347e62a,
+                0xd4601e3711cb995,
+                0x4f0832b1de30a9d2,
+                0xb171e5db58f38938,
+                0xf2bab67c07aca192,
+                0x17a835c242f22
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3bd6b7612d11c659,
+                0x844c8afbed434101,
+                0x2c7f4b908a990a2f,
+                0x65ba87edd097a2e4,
+                0x6ec4e1476057113e,
+                0xe7705c1a4596d5d5,
+                0x7dad8a4fb9b1e79f,
+                0x76082cfb53243360,
+                0x38c58d92ad54acd5,
+                0x74b13855abde90f0,
+                0x4aa489f03e397d16,
+                0x7fd258e83f25
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa5a3885cdc13ef2,
+                0x834cd45f294a452e,
+                0x9820e7c950e1b65c,
+                0x445762f11400ba4b,
+                0x5ec7b552d482d1db,
+                0x13f311047b0c6ed4,
+                0x5850a2ffead00a9e,
+                0x235eaeea86893f44,
+                0xfeb9714fb430985c,
+                0x49b93d91994f7dac,
+                0x607cf2d192a6fbf8,
+                0xf84e3fa23213
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xad75465048ded8ed,
+                0x879f8868e5f9a74d,
+                0xe11b2b40a89a0745,
+                0xb49e08bdb3703627,
+                0x866b7b58fe498f67,
+                0x14795c06bc283458,
+                0xd40f063c0765e773,
+                0x2d100cee860c0aff,
+                0xbab7a3fed82443be,
+                0xf0898da18f60b17f,
+                0x96d2f7a26b1f77ce,
+                0x1b089e06e8da2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c11e4f3968eba4,
+                0xbda694cd85c18cf5,
+                0x3c1f7eec3d4bf6a8,
+                0xf14d024484fb40c0,
+                0x97895e37e5eeb9c5,
+                0xbaa5a7fcf175024e,
+                0xc64f3458f9590faa,
+                0x13e75fe04cd39cd4,
+                0x33a752dad3563bc2,
+                0x7587a935aa3a0c7c,
+                0x69282a627df2fd0d,
+                0x7ff2fa344bb2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b65a474360d4a61,
+                0xecb39e25ec47d5e1,
+                0x89eba21144998f5f,
+                0xdd61f82695f65a36,
+                0x6943f470e7bf6ffb,
+                0x128b936b3d2f29be,
+                0xb66d1696a34ca0ef,
+                0x95fde3c46fb5e0b2,
+                0x93547d74e2bbd9fc,
+                0x31fca1af0211dcc1,
+                0x14598d539696f1ac,
+                0x6e972f6fa681
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfca24f67718c970,
+                0x24a45407cb81aff0,
+                0xc708a6fb47de839d,
+                0x24df3b7870a5f2cf,
+                0x590c7875fc89cfcd,
+                0x4bc903f84f5ff88e,
+                0xdff997a94ba4354a,
+                0xbd4356e0632f6f4,
+                0x89d913bdc0ce89c1,
+                0xd76e72c0af139694,
+                0x7c8828a62d253d8d,
+                0x1bb510f356fe5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9f6dbaf33dcce662,
+                0x89406152ff1d706d,
+                0xb8bac8b83feef3fd,
+                0x468b0824bf7123e2,
+                0xc49e08356dd48a1,
+                0xf7dd31ac4dda5d73,
+                0xfa12a4e6ad480de5,
+                0x6517a742e6742136,
+                0xd8381e9c7839a3c6,
+                0x34b24ea484a6bf9c,
+                0x600a2304e1ab62c6,
+                0xe8fefc3c5a83
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe1c85ba53fca6d9d,
+                0xb2246ab3668cfad,
+                0x254c2ba74a405e9,
+                0x4c0cabf00a92f00,
+                0x62e44a2eec937f83,
+                0xe6cae4296b352373,
+                0x5c3e2d40a55b7c9f,
+                0x292d0e0631d52cf4,
+                0x50053cca885258da,
+                0xb3c6c20fbf39d6db,
+                0x713e547b1b409129,
+                0xfcc97e65607
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb6a0fa66f6fa3a0,
+                0xe71344e4126ef887,
+                0x71fe29acbf925d08,
+                0xb9863989d540731f,
+                0xc5c36550d329c578,
+                0x9e93decafba4e210,
+                0xe93494098e190b1b,
+                0x8aa422f01bf98201,
+                0xd8d616dddbce58e0,
+                0xf05122faa2c042a,
+                0x2403773cf96e5daf,
+                0x1a28aa2b02f83
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x77def58ba18e31f5,
+                0x6edefc7350be25fa,
+                0xd0001d1d1b5a1cd1,
+                0x4c94da2fcc1ef2db,
+                0x72a5a6781492edfa,
+                0xe1bc56706d0475e9,
+                0x4a4eb4b41922985,
+                0xc66fea63c9ff7a51,
+                0xeb2d030883c3760e,
+                0x628a46ea2a132832,
+                0xe82caff740511102,
+                0x768d2d06d179
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xddb781254c0876a7,
+                0x7fd5d152fd9e4141,
+                0xeded4069793ebce2,
+                0x48ad0a6ac6c9e7c0,
+                0xc6fcdbae31937f9d,
+                0x2ec11cebae709043,
+                0x69a45b6dd9983d7b,
+                0xcf95a26c2f2ed369,
+                0x85493a0db5ba90cb,
+                0x766a3ba7ebc0cbcd,
+                0xfadfa7733c010e,
+                0x123df1bf13a42
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb9691766d86b19b,
+                0x979f626a36e03bb9,
+                0x7a3d0c80dbdb03e2,
+                0xa905a294e16aaf4b,
+                0x462b918dee40c64,
+                0x8c2a0cca972e47c6,
+                0xdd5a15bad20ee05b,
+                0x46e6e1f62ff4e3f2,
+                0x35a626c15ca638fc,
+                0x29799b66f22f2b57,
+                0x2fd691e8855c279c,
+                0x15bf41aa3c4b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf6a1fafa3532317d,
+                0xc11a8c1093a71447,
+                0x16b60507968566d3,
+                0x36ec79d5010da13f,
+                0x299ab0612e0fb41a,
+                0xff24425a3fdc5953,
+                0x1f629e5f51e659f0,
+                0x7ec573bf86e2f3ab,
+                0x26a640476731b7c9,
+                0x1a422a81aff56fa2,
+                0x823b32352a4e5c89,
+                0x9983d657520
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7cab1567c04277a,
+                0xc82dd53df1bfb29c,
+                0xcc6539af2e893f9a,
+                0x4ead9d0f2823297,
+                0xe9b0c67c80561104,
+                0x4d3a3dfe0e308563,
+                0xf96f7bfb5defc602,
+                0xea09c8d57d27d0f6,
+                0x8c10afb954c21708,
+                0x1e47b82e74e8caa,
+                0xdb9ac21fc603dcd0,
+                0x107abc42e6336
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc18d3abe2960448,
+                0x80bdb26f29706be,
+                0x1879f0209d7d3469,
+                0xbb2ac158e932193c,
+                0xeb9a324f796db48b,
+                0x7498d7b4abf1562b,
+                0x100796032e641dce,
+                0xd2437f90ddc10799,
+                0x93a5b3a93f182cae,
+                0x5451efb68822aeeb,
+                0x532d64ae1a32e58a,
+                0x1bb37d28486bb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf18ef5292798a3b2,
+                0xe52b8b5a05d8b9bc,
+                0xc37a31e6cfc31c24,
+                0xcd234ac33cf06d7a,
+                0x84f2f3048769caab,
+                0xe4bd08af477d2a82,
+                0xcfbce10925a68ae8,
+                0xf01715ea11dae228,
+                0xdbad6b25d3da5077,
+                0x7fae7737aa43116a,
+                0x70dbaf1c15c217ed,
+                0xa0ccdb723162
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5917a010162d34cb,
+                0x4d1468dfb95e0ef9,
+                0x1c8b691a7f13b95e,
+                0x695453264d9fe121,
+                0xe13c4b5f2049db7a,
+                0xdbac924be7227062,
+                0x3b7cfddbbeba4b9e,
+                0x24bad624de9f4bed,
+                0x8f25453046e21053,
+                0x9bb32d5e84d0d207,
+                0xfff5672e17d197b5,
+                0x18f5a308131a4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x823f89b3e599a61d,
+                0x637dfe678eb8418b,
+                0xc832baee1fa8d751,
+                0x7ccbe87842f7df0d,
+                0xe72bae29f36b0fdf,
+                0x3d20a720c845c8e,
+                0x2087bc14e09981d7,
+                0x3a7904fbb0e38467,
+                0x293d63f60d1f597b,
+                0xcba61cdf1b6dd904,
+                0xe3674a4757a0f2b,
+                0xaf2737b00deb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd1f82db8c03e9795,
+                0xe1538244027a3940,
+                0xfd5c862abb7cf0b5,
+                0x70bd8caed9e07def,
+                0x5618996ecaf0ee59,
+                0xfada91f926fd1819,
+                0x8b9be4dd3e1146a2,
+                0x3cb976768a45570c,
+                0x5f64fc7d2b4b6ff0,
+                0x681c96618b4c5a79,
+                0x1e38d56a4fc44aa3,
+                0x1b807ec571658
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf60553c156fb90d,
+                0xe3962fcc7b3605ed,
+                0x52aacea65fdeee90,
+                0x4b393eec7b132e2c,
+                0x37f193586e8c5c3c,
+                0x560d4d26a7bd8a37,
+                0x8c9de806a684062d,
+                0xa27ecd445ccf18a1,
+                0xae7cd6abede49fd7,
+                0xe3fc7c1a04a28b21,
+                0xceaeec9f4b99739,
+                0xce0cb0ede76
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd7871b40492b646f,
+                0x25c7b3c43b9c5686,
+                0xdc0de7451dff94e4,
+                0x2a7d93c81cb665ba,
+                0xb467850491d7756a,
+                0x8657e89007a3a26,
+                0x4d8beac3fa2c0015,
+                0xaee3ab5ad5234909,
+                0x5b882e8f504d0eb6,
+                0xdd6097ca50409980,
+                0x2199be9df3594fe7,
+                0xcfa26470a3ca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x297d1b8face0b519,
+                0xf0d1cc961fa99b51,
+                0xe1f00760a46a64a5,
+                0xc8ad40eeae97ad16,
+                0x35de8b9da90939d1,
+                0x9ef6a7e532bdaaa5,
+                0xd68bf9088ad84ef4,
+                0x3a3010bc0377427b,
+                0x79b506bb558e6fe,
+                0xb9a5b6f82f57e61a,
+                0x9c8d5c7aa2830ce7,
+                0x10aa1d96a4b0a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe752e8f64d21f925,
+                0xa61c0343a1527162,
+                0x48d9523613b3a868,
+                0xfdc9e1ccc69198b9,
+                0x4d3ef2d4fac858a,
+                0xb04f917c665d405b,
+                0xf9b2843d3bf8e053,
+                0x207869417c4cc973,
+                0x8adbf0dd1384b280,
+                0xbd0e46c0ea3643cc,
+                0x22890eddde4be572,
+                0xc63ab86da560
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda14dba57b55ecd2,
+                0xba380b4f2df512f9,
+                0xd677d4ed4abdd984,
+                0xb7a3feeff4a5d18d,
+                0x1491e5b0a3b4bbf9,
+                0x1e72bb3f11f2b824,
+                0x48f3bb499b06c669,
+                0xf74cdf3b42106f3f,
+                0x908a0bb3f351e877,
+                0x8502071f4d59e84c,
+                0x8e82e803417771b0,
+                0x107e2c826d70f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x13e1dac558d49d0a,
+                0x2a9f49c69160967b,
+                0xe9e4ac357a5a408c,
+                0x733b1ce73aa4f2e9,
+                0x9901155b486686b7,
+                0xb7bc4231bd9f7673,
+                0xcee01fa6fba40674,
+                0xa562584d862bf69a,
+                0x3d8bfe3be5bde985,
+                0xe71fed6c951a0306,
+                0x4be2f041f723830c,
+                0x15492a8c787c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b77b670e1accf1f,
+                0xbccacebe7c8b57cf,
+                0x17cfd171e42ab28e,
+                0x8df6a61a5284095f,
+                0x20d5fca65057e02c,
+                0x77fa9639a193be2b,
+                0x12e7164c09c3e098,
+                0x4b11555d4f631ac0,
+                0xc2d2e4cbd08da729,
+                0xd5c79a60e7db44c3,
+                0x160974de151a4b55,
+                0x8c6bd1630796
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3283bcf4eaa80b42,
+                0xbd665250acb60fbf,
+                0x35f0613372345721,
+                0x5e9abbde44d8022e,
+                0x70f770059f700c92,
+                0x9c84ce28efcc813,
+                0x2dc7e904f7ed3d5f,
+                0x15c23e6f2c28665e,
+                0x9e208c21e6dd7e0c,
+                0xac5b354615994e02,
+                0x5f664ca3996cc3f,
+                0x19d959fded05c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4bbbe2e4ab7fc77,
+                0xe619c94d6e01f364,
+                0xc0c75e5d62548bda,
+                0x9ce6435184229315,
+                0x2bf8692dd0f8e045,
+                0xcbdf2f95f55296d3,
+                0x7cdba4ab4fb7e958,
+                0x694054ac392079bc,
+                0x4dc980da04f349af,
+                0xb75cf420d8d0074e,
+                0x39cd2051fc3c0ca,
+                0x7a8743c3c18d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3a30314f76fcc01,
+                0xfe3675e9a6b4c357,
+                0x1a52f07efe704943,
+                0x4c6c451f125d1efb,
+                0xc4e1f712d7da246f,
+                0x8d92b832729d5259,
+                0x87118d4c23cb1114,
+                0x3388a606a3dc0a3,
+                0x647de3f03e33bc02,
+                0xb48cb3067f2de925,
+                0xcbac2a59969e2c3f,
+                0x184aebf1f723e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9307f9493600929b,
+                0xf55de3557912f815,
+                0x8bdfb6855e0ab571,
+                0xb9364ab3e0777984,
+                0xa0c2baa83171ae9,
+                0x70217c8a545d9433,
+                0x1565b10aa760eabe,
+                0xccdea81701e6d95c,
+                0x21866cab732033b7,
+                0x7161e9fe1b32a87a,
+                0x53c9455fa5f20bc3,
+                0x755e1c5dc3c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1b508aa92240f6e9,
+                0x180f7ae7ed7ec9df,
+                0x3b745fd46e93f5b7,
+                0x31afc417ff223e35,
+                0x844d038d8146b605,
+                0x1bcb707b82532903,
+                0xd57ddf41e638b180,
+                0x75963c9ce40654e0,
+                0xe72850a01aacd44f,
+                0xc6849ce1d26eeb4f,
+                0xd1e31bf16639ea2f,
+                0xfb0260323e71
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf9fb7097635094,
+                0xdbdd16a9e15b14d4,
+                0xd8ea46ca3e7f02ef,
+                0x1c493f9462c3e5e2,
+                0xff6105714de1c4ab,
+                0x47abd52fc3e372b2,
+                0xd2b4a921f1690f25,
+                0xa9c2abf14d7f1e29,
+                0x968e56271766b437,
+                0x2ff95101c7da1aea,
+                0x5f48a4ade06ad864,
+                0xae2d99f7d453
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x397e20ff05fbe9a4,
+                0x9d33b563542204ac,
+                0x9580aadfe03ba2fe,
+                0x6100b0f48269c89,
+                0xa67bb9803cbb0445,
+                0xb79d770a2cbc9889,
+                0xfbf5362b4024e39b,
+                0xc8d0766f18d509f6,
+                0x99f4699748027354,
+                0x93a7b4b164bf1e3b,
+                0xd9f5f0b6fc5314a7,
+                0x9072410f2fc3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7d23b0aacf754dcc,
+                0x73e3c521e2d82aec,
+                0xbe08d2735bd4aece,
+                0x5ac8b856606bd69,
+                0x508c5148b25c4487,
+                0x8e21d72582ca4d77,
+                0xd9cbede7c5ba3b4a,
+                0xa927d4e5ad0179ae,
+                0x15fdd0b5184bd14,
+                0x8dbbc0e25b6cad74,
+                0x3011a8fcad34702e,
+                0x1562210d31b87
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a332b16b09e544b,
+                0x17a8863753503074,
+                0xebc72fce6a12e919,
+                0x2dd8b068b3e170ae,
+                0xc0eeed54526e0932,
+                0x3ca25c3a8c2a3727,
+                0xc6bf724e876856ec,
+                0x8a30fb55c4313530,
+                0xaf2c92da99a3f857,
+                0xef0466e3d01e9fa2,
+                0x5470fcecbf5da9b4,
+                0xa41091537582
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fad2cb486a19e72,
+                0xa223015c7cd37d1a,
+                0xd0ff0254fed994dd,
+                0x1c99cda22a5d464c,
+                0x1ecf3f869cf6c24a,
+                0x9d1d7036d58d0e49,
+                0x6ba377b655f203c0,
+                0xe72ec8ffb87f567c,
+                0xa9f36dc909f19818,
+                0xea7927e425c2b6d9,
+                0x10c90b0598aae44a,
+                0x1b898d91bf009
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda4b15359287353c,
+                0x5e8103f03cba8a17,
+                0x76e7ef40c0741e2f,
+                0x5035f164d9dcfebe,
+                0x30a7e0bb71222893,
+                0x84c29dd7956c5eb4,
+                0x864271be2bcde6f6,
+                0x2eff9e907fd73686,
+                0xbdee481d37dc14a7,
+                0xc511007010190b1d,
+                0x637dd5e6104573cc,
+                0x18558abebd7f5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e7f622741adffea,
+                0x2aefa25ced18eb62,
+                0x55a1ae05cea79a7b,
+                0x8eb750ca5fe66a6d,
+                0xa752022aa3c98b91,
+                0xb3a7883df3e24a39,
+                0xd9da056ef05ddad6,
+                0xc5715f5ab3bc3f6a,
+                0xc5bec7b62a5a5d1e,
+                0xaec35d5ed2ddf3cf,
+                0x5415bdf5a4905faf,
+                0x9825ea3d4d85
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x95052e266008759f,
+                0x511ca988649391a,
+                0x725a447c68355579,
+                0x339fdf82bdf57811,
+                0xac59937fa6099b28,
+                0x70be77ceb790cc5e,
+                0x6a74df3aca64a425,
+                0xd801546af4a2bcc8,
+                0xea0ea1844da21eea,
+                0x7255cc725e49eb09,
+                0x8699426fe395e9ce,
+                0x7c10031851ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35a6fb3ad35f3a40,
+                0xf7f1196e792cc8ed,
+                0x4095a41c3dfe08f3,
+                0x9bcddb24264dcf13,
+                0xd2c86c7550a8f0e7,
+                0xa5418bd72c0115a7,
+                0xa36d738272b063f9,
+                0xbdb0f4a3930defdc,
+                0x94ba0894ff9dbc3a,
+                0x8ed189538c17e5e,
+                0x6f2729b513e79318,
+                0x15b3d045bdc98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xca0419e5e5daafb0,
+                0x5932989f13b2c56f,
+                0xf20fbac8e99b8422,
+                0x9915e371de0ac24d,
+                0x42adbd9042047bb2,
+                0x3bde8a0eb42ef4de,
+                0xa9391af12827576,
+                0x332db7c7d783cfc6,
+                0x19c1a41de1a9e873,
+                0x610cde1b306d4f4a,
+                0x508c323b42054a81,
+                0xca1c83eea896
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x22e9d72bd5ac75ef,
+                0x94ca632957c00b2f,
+                0x1cff39b7298d28e9,
+                0xdf436ce2fa88cdb5,
+                0x6297225cc90426f6,
+                0xda8dd6909b219def,
+                0xb82de028554120d9,
+                0x1f8b6f0714423722,
+                0xaff8a59e71fced29,
+                0x36e22b23abbb27de,
+                0xd9c6e88718599631,
+                0x1b6d9dea7c29c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x313b067b4d4c93bb,
+                0xd9e10719dfea6fb0,
+                0x2fe8f3d225946dc8,
+                0xe47c4c3023b80656,
+                0xdf80805848d3dbe6,
+                0x98f474dbc7678676,
+                0x375fb8c754775a39,
+                0x65ee5d9e54fff6bc,
+                0xd13722d141920fbd,
+                0xfbb16ef7f266c660,
+                0x26b55590018a5c39,
+                0xaa121c03f76a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdb945d4d85560220,
+                0x18619cec6c358346,
+                0xe812111f4dd93182,
+                0x53e0da19419b8b68,
+                0x7e2af91f6476e4d5,
+                0x98c3f15391ee66dc,
+                0xa576e1ab05f91c41,
+                0x9bf95bfdb9600cf0,
+                0x537aea7efddcd979,
+                0x665ee3552e378cd9,
+                0x8380796407887246,
+                0x1be0f5768e0aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbee9557e4df187cf,
+                0x9a1ba874fe239bff,
+                0x7e1c66d257bafe50,
+                0x919977feadda45d1,
+                0xb51eeb410ccf0de9,
+                0xb4c60a04e1a76eda,
+                0x3c10f9b1c6b6e746,
+                0x1956f14ffbfe8207,
+                0xfcfb1a3840565c3d,
+                0xf0df48fd7b13d516,
+                0xeaa40b4e4468cfa2,
+                0xb514ac04478e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2181716ff21f7d18,
+                0x8977eaa2873c511c,
+                0x6d85a3996e570c5d,
+                0xa8ab417ee5c6388e,
+                0xe1b74e0d8ee277b8,
+                0x837947b8b2785f58,
+                0x2209bb894a45e2bf,
+                0xa4852e0bb96dda10,
+                0xf5c7f28a58882677,
+                0x96886ecbd946a48f,
+                0xb948b4e8d1e3a89b,
+                0xf5e1514fcb7c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5d482672517ed804,
+                0xb103446baf99c78e,
+                0x5add7625866c4313,
+                0xa78114f0f46c9683,
+                0x504feba5910316f1,
+                0x88b5765f98caabb3,
+                0x7113c9aa4c3ec66b,
+                0x6cd9a9e7883d4571,
+                0x881fbc90620bb851,
+                0xdbc08354b2105709,
+                0x68b56ef188b7305,
+                0x1abb6bee7974a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x26312021ddfd8e82,
+                0xa26a775d0eacdfd5,
+                0x81e7575ebbe43ef7,
+                0xf418a7a342c99e14,
+                0x3756b9e71b7c1eb5,
+                0x3b4ca70c0b7c1f35,
+                0xf4b72ac53d37ea49,
+                0x49d99ff8f8789dfe,
+                0x4235d60a940654df,
+                0x42272edd259d94,
+                0x320f4a42f5174bd7,
+                0x160089a410c36
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9fd8bfd94b431a91,
+                0xbbcd6e1ca099a912,
+                0x40da8735089cd6e2,
+                0xa6f8cb6df32e8f6c,
+                0x829ae1ef69f53ae5,
+                0x422c4f44f99a9ea5,
+                0x8e41c0b54a452a7f,
+                0xaf5d33924968d8ac,
+                0xd9441b3680732569,
+                0xdff48ba45267a329,
+                0xe93c1e3ed4634061,
+                0xac455ac33585
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xea0efb4da557f409,
+                0xedaf2e1916bc9a02,
+                0xa5baac24b49768c1,
+                0xc2320218e4ea898d,
+                0xb0bd128964f743bf,
+                0x30fc27051c54a340,
+                0x8c87b87cc44694d1,
+                0x556917700f562935,
+                0xaa4d34866347543b,
+                0xfb4739658bbfee9b,
+                0x20d7bbb75a04d2da,
+                0x6b225a9f7566
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2dbb268858721357,
+                0x9e32538c59377a7d,
+                0x71ab18e146521bbf,
+                0x9f1059769c4a9b38,
+                0x55be8f7af6e5ce15,
+                0xc3b1ed9e7223710d,
+                0x73263bad7b4b22c9,
+                0x26ddba2499ded6ed,
+                0x81a8b8482c60b061,
+                0xe8ca399e127b46f0,
+                0xaf327c9591154e92,
+                0x77c484048f5f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa53920984d697e06,
+                0x92ec5c39cb7aa741,
+                0x3a9ef70300fbb56d,
+                0x6052a599f4c17fd1,
+                0x6bdabbbd05d49d7a,
+                0x65cce99d701bf00,
+                0xabfd16665df8b357,
+                0x6750ada2b12cd21d,
+                0xf00b7490022cc5af,
+                0xf82a8b60e8b59e6f,
+                0x1c26fad8ef1e8289,
+                0xac991f635869
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc16be75dc93052d0,
+                0x7497363731558ad0,
+                0x3fd84533788edd68,
+                0x28ef5fffe8c45c58,
+                0xc89500217ec6980a,
+                0x44d85f32636a824d,
+                0x181fda88f46a3200,
+                0xfaff58c81b508ee4,
+                0x93720ef899bde5ab,
+                0x2f6edf0f4c08ed7d,
+                0xcb7979e4ba8a84db,
+                0x521a3739396b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9cd74f78321aaeb,
+                0x67051f84d58be92f,
+                0xa35e5807ac431efe,
+                0xf169bb5c52bb2686,
+                0x8d99601f46c7da91,
+                0x3c3e53cadb921632,
+                0x988ab8f1d115a95e,
+                0xf79bc4d08165a38b,
+                0xdbef2b154869f052,
+                0xf4f9ece499fc68fe,
+                0x61847bbe24faee9a,
+                0x11f3954d65a43
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfce5e1c33256bb93,
+                0x7240ea2bad93447e,
+                0x56284e82a13b6108,
+                0xabba5ff1759c1aa5,
+                0x4576e9118ad3432f,
+                0xe8479d51ba1047b3,
+                0x6b8f19b396263706,
+                0x474ebc87133c989d,
+                0xbfae4602fe615f1e,
+                0x4dfe66139cc2919d,
+                0xcadbb26b3f0c6d2d,
+                0xddbdf9de87ac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0b6386e00964573,
+                0x63161b6a816a5294,
+                0xf19c5395d864e29c,
+                0x6cc8ded0349a4f23,
+                0x5969c7143a02a940,
+                0x12858fcf3eb927c4,
+                0x63820854c5d8c2b4,
+                0x40852f9840469b74,
+                0x88568a43afacef8e,
+                0x3bb94ddf90812f04,
+                0x9d38c597cc5ff688,
+                0x1ba01a295d3f7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6b3092f2f83ae7f0,
+                0xe51ab9219de542a1,
+                0x7b4952b4ccc0d1cc,
+                0x8cf0619b1fb6c9cb,
+                0x26baf369e7cab116,
+                0x1392e4b9e59e2a87,
+                0xf443dd05424bdc5e,
+                0xb27de3533b9f673,
+                0xe3bb0b6d18412b28,
+                0x4b89907ac04c5324,
+                0x736a4105567959c4,
+                0x74ff8438711b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x11b19fc770293738,
+                0x84f956dadcc601a2,
+                0x9030ca78f7fd1f9a,
+                0x7f20e0d3027df93,
+                0x9994c3fc9e2cb8a1,
+                0x7fe18c01708ea771,
+                0x925d0ac2b78eeee6,
+                0x2d4fb18322f5df22,
+                0x6d3ca08ad2dc3730,
+                0x4a47a26af707bf31,
+                0x1ecf2d2c50c69ae0,
+                0x404a850deee4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f9e9681f6943862,
+                0x544adcb6135f3312,
+                0xd5195a2f77908ecb,
+                0xa4e2f69d3a4eeb44,
+                0xb4a60a9627d7c2f4,
+                0xc56d33dff9ac662e,
+                0x1a69b8757315266d,
+                0xa9872f2c909bcac7,
+                0x1150402afd7bfe30,
+                0x66aadfa78b358bc5,
+                0x4706e53ebba9d611,
+                0x1f53d06d1b9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x17a53e421e40cc2b,
+                0xf64d4a02df215c93,
+                0xce695abff4dbe77f,
+                0x7903988c7d7c8a09,
+                0x73f0698cf5952af8,
+                0xac3b1d9f283f9a61,
+                0x5e653515a4300a7b,
+                0x2b5f2c042230e4f6,
+                0x63104687773e33b2,
+                0xbacd61ac7b078cd2,
+                0x1bb480b99c5fba50,
+                0xcf5fe6d9946a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5c6781bc0d7e2154,
+                0xeac154a36d13e8ff,
+                0x3412b8b8ed1ad7ab,
+                0x436e256b5855c47f,
+                0x5146477acd6221c8,
+                0xaa281042cac7e30e,
+                0x7ee1c5dc4eb4c1f2,
+                0xef3bdde5c76e4e7a,
+                0x15e04f079f80980c,
+                0xe0518a839aa2344c,
+                0x68959c20ea76bc68,
+                0x137b07214b726
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x97e695624b58b84e,
+                0x7b0869983c8b46a4,
+                0xf3af07bbecfa0ee9,
+                0x7ee88e25c83751a4,
+                0xcaecf8fbe0fcd70a,
+                0x664661a6ce80a28e,
+                0x82e900459d1548f8,
+                0x432294e11923821b,
+                0xf6edb805122d93cf,
+                0x13db04d771f6e611,
+                0xb3b1c4b9bbfc0758,
+                0x1536d1b1e94d5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9fb5ce9fd48b94aa,
+                0xdda334eb61e28727,
+                0x6020758fa2ffe786,
+                0xadd95967058d318b,
+                0x7e9a0dee3b36378c,
+                0xd6191f3f47f8b41a,
+                0x292a2ca8ba780cd6,
+                0xcb362e84071627ce,
+                0xcb2f2c06e74e9051,
+                0xe6fb4cf8c9773759,
+                0xf6111672236dcdcc,
+                0x7ea41cff0e8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5f977e38fba03713,
+                0xce4b7057688fb321,
+                0xd1f92baefe09361a,
+                0xf0956304ea8b2773,
+                0x98f5ba293d2d06e3,
+                0x509ac3e289b9d1b9,
+                0xd5b7d13f5019b05d,
+                0x64efda68a17181ca,
+                0xac04133657b083c7,
+                0xee7bd961c265f60a,
+                0x7ee71b8681e8dc1a,
+                0x15cc85e16d3e9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe374e473f55b6400,
+                0xe10b31297086ba0d,
+                0x5b257c3f31640610,
+                0x78469d60556049b6,
+                0x3cf828cf00810d9d,
+                0x286b8469eaeb74c5,
+                0xe88d164022a4b846,
+                0xfe67a33b814d62f9,
+                0x699ee6955b8f27ec,
+                0xe006bbcce41965c2,
+                0x6c974f6e6f8940ac,
+                0x6b50d65ac34c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf43357ccda939489,
+                0x5a524a47d13f0138,
+                0x2e3e146eb3564c61,
+                0xfa123c54ac44db69,
+                0x14d160bd287a471a,
+                0xa34b17d2c54468ed,
+                0xcedca41d6d534094,
+                0xce059a875aa9db72,
+                0x25048d7c9a52f882,
+                0xf06cba3e9050a2df,
+                0xf2a79bbb3fa61180,
+                0x1a7ee3d812de9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x89117ff67b2dea06,
+                0x3c3d3871aae41400,
+                0x5e9ad4e46d4fefd,
+                0x38e67eb0142718c7,
+                0x256f1d4cd32d28ae,
+                0x8982291ed411723e,
+                0x5d2f12d1ba42d1a2,
+                0xf4c0039e9c2a7a47,
+                0xd468a77acb3bdc66,
+                0x85d59f4cc7f85048,
+                0x2b5cca8a4b2031a6,
+                0x18b765c53876e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc213ef84d497f0c1,
+                0x7e241280d3481f96,
+                0x797253c5e18e833e,
+                0x9d2503dd92a40536,
+                0x20e3234768d99caa,
+                0x3c41723a0d8e748b,
+                0x50aa091efa1d40b7,
+                0xb7e8b133d6e15d00,
+                0x8e4b221c4ad5e07,
+                0x8f390a85f4a71d34,
+                0x38a48bc8c220dd38,
+                0xd9f71d47d620
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3fdd92a46e8173da,
+                0xe2f66cdf1fd52a41,
+                0xa60ce04f5f60be7a,
+                0x35122222eaf9a69a,
+                0xf39a832efe65113d,
+                0x7d5a4576153d65c0,
+                0xf404c247d61abcbf,
+                0xa2282b2245bf79f0,
+                0x7fd595678baf7a1,
+                0xeaaeb51a14a479ed,
+                0x668799118ccc4de8,
+                0x590f35b7f173
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2a06c01e1dd28cab,
+                0x73f071a25834acbb,
+                0x8ae5cbf825a68f84,
+                0xd2d9b777828e9c76,
+                0x462ad7c6a41883dd,
+                0xbb6f47237527c1fe,
+                0x9410acd51ee5edad,
+                0x6b563b5fc89292d7,
+                0xf3f8c26bac372616,
+                0xfa05e9f5b85eafc6,
+                0x74f7cb8e2648b985,
+                0xa23b35f5b8aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x451c9cd0a842d5b5,
+                0xfae3cef29f13a07d,
+                0x27cb7fb649db7ccd,
+                0x8fb3d9d7025db18f,
+                0x9c2dec03595326bc,
+                0x1d1f8a563e54ce26,
+                0xbe723c6704894c6c,
+                0x2fd9984ae5fe3eb8,
+                0xf6e12612b61791ef,
+                0x59b55a4d89fc0c1d,
+                0x5d0aebaebf90f1db,
+                0x11937732b914
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x21e9482f363802c5,
+                0xe29492c1c5199d71,
+                0x3fd9c6969c26b7e6,
+                0x33c82fba0929d54f,
+                0xb9a7c2a64a772c29,
+                0xa907f7a5f1b03c0d,
+                0x8f17ed4dd8c5e200,
+                0xf77b1f236c210536,
+                0x8d19aa80514f5b,
+                0x9849c08022b62709,
+                0x6353f4f7b848a64f,
+                0x19f71f1f67eab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xabf0301d6c739625,
+                0x48104a6d7e3b4c1f,
+                0x78b1ea761ed7a4bc,
+                0x12694ae351c2cbc5,
+                0xb695b72a9907ef64,
+                0xc60aaccdac3e0ae4,
+                0xaf3a59e589e95ca3,
+                0x84844caf58e8f192,
+                0x6f55851a87c4f588,
+                0xadce9c7864cc5525,
+                0x412e33c7e71b2bfb,
+                0x12591b6ed642f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82b099a538debcc4,
+                0x32c9d8962a0e1304,
+                0x1d6b54532613ed68,
+                0x912d0ffa79375428,
+                0x1f8ec0db4e8f63de,
+                0x725be481830518b6,
+                0xa687bdcd5d76399e,
+                0xadf14d1edd1b6477,
+                0x5442cb1d84acb3bc,
+                0x1d41a1952d789980,
+                0xb213a5b49b3287ad,
+                0x11daafaaf531e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e2367298131c5f9,
+                0x266651a14f72d2eb,
+                0x515c6c6fe8b3380f,
+                0x142be9bbe2ff5419,
+                0x856037ea6caf7d74,
+                0xb5bc60e69fd03539,
+                0x44b42000918b328,
+                0xb23caad176a24c9e,
+                0xe4bb7651244c8f3c,
+                0x4ce2b331be2603ba,
+                0x2e40f680ecb31015,
+                0x19dec85064f98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x61fd0289cee2ab1c,
+                0x2603d939b2ccb0b5,
+                0x2f866343b6038912,
+                0xeac97827b8b77fed,
+                0xdc6152b25a7c0dc9,
+                0x5567d0794287abe6,
+                0xe37d92dc026a6185,
+                0x243a4e8b0bd57eaf,
+                0x5b9ad22cb7eee9be,
+                0xc80c19e9a78e05d5,
+                0xd6b4392d5d370d02,
+                0xa8179516d22
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb19ce43ea4d460aa,
+                0x2ecb4808152f21f0,
+                0x7554373b0af9228,
+                0x97dffb70ec48c98d,
+                0xf41bb1812ae11a82,
+                0xbdb00d623801f4aa,
+                0x6c80c6d0ef416b2d,
+                0x1d6d790a0b0b5ce7,
+                0xe4dd2cf0eb228637,
+                0x26df0e810ecba5b1,
+                0x1d18df9a164adfd4,
+                0x1b7155d55dce3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2111e6d4a9b0e4ab,
+                0xc2671efbcebecd53,
+                0x39780e139c7e46d3,
+                0x71accaa27e4919a4,
+                0xfaa164400ec34550,
+                0x9aab37c594d7a540,
+                0x1237a4ca1e292810,
+                0xba57d8d45d20499d,
+                0x996384e724ebde23,
+                0x9de93a7cd4be6779,
+                0x3a3b59fcbf3c45f9,
+                0x140669afe42bf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x39b0a2bd4bf8fc5,
+                0x6a0748f8e5e3649,
+                0x35b17139366498ee,
+                0xeeab65a8fa71be24,
+                0x8da22c7d0fa780cd,
+                0x3a1fb8d1ba83f66a,
+                0x82d13a9410957432,
+                0x736761ddfcc8758d,
+                0x3325c94f83ebb307,
+                0x926caea22219332,
+                0x5c7948d3036e16ae,
+                0x4a8bf64801ce
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cdf6a8f7f58d6f,
+                0x746990013977a63e,
+                0x7967718a6b39eb48,
+                0x8c37a55e824d463a,
+                0x640b4411406bfd32,
+                0xc60a5efb5cf23aa4,
+                0x42268d41a2d2dc49,
+                0xeb3f4b2651ee1216,
+                0x3203fcff76064dfc,
+                0xbd1632800af5669a,
+                0x725ce92f7d416869,
+                0xe82eddada167
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7424b68454e6ad5a,
+                0xf2d6a3d802d27e5f,
+                0x7f4e383d582787bb,
+                0x13833460ef02f978,
+                0xdc7079c1d3cbe73b,
+                0xd08808d2ac7e848a,
+                0x3e51f17d549098ce,
+                0xe717c8d83048bfc5,
+                0x69f13c7a54c0c269,
+                0x533ebc0a7a29fb17,
+                0x631e9414d7e5eb62,
+                0x1584c64f92e29
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed9b90658078e473,
+                0xccf793786527560b,
+                0x18371b13c522b3e7,
+                0x1e9a4dc6871ffc5f,
+                0x8995d5d310f133e6,
+                0x88f9eed6043eff24,
+                0x733f0defc90ff2b4,
+                0x5127dc0bda19b7e4,
+                0x5d455a9b42ef0237,
+                0x72da967489b4836c,
+                0x9418d0d6934c8d2a,
+                0x6fd14ab6e324
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x573a75b7131bf715,
+                0x8e90048429bd6479,
+                0xb9e78eae3cb5cc50,
+                0x78b662209888dcb1,
+                0x112d63560faea9,
+                0x1d04be7340c8e6d6,
+                0x9212659dafd3140e,
+                0x4e08649c7af4c563,
+                0x24b5d041bd16ca5c,
+                0xfbc04a0f1708fd70,
+                0xe8be0915944443c3,
+                0x3874a85de728
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e627e0605ec67c3,
+                0x6db8802891427fb2,
+                0xc3b00703b62182bf,
+                0xb082887d2be08ec,
+                0x64352f5e4c0cc977,
+                0x2ff873ae1adf92a5,
+                0x86e004c614333210,
+                0x8869a695c9b6227e,
+                0xdae7e88cf3c05083,
+                0x98cf4d2c2106d05e,
+                0x10d6c09a9dda40c6,
+                0xc05a3c486584
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x862d11844793d8b,
+                0xca61c477680e0b75,
+                0x94f9eac0671d2c0c,
+                0xa5ab3159e64ef64e,
+                0x45e24319e4205361,
+                0x353794deb2ba9f7d,
+                0xc4115c3ebf030a54,
+                0xd436baf7dd761d9f,
+                0xeb5e762757925327,
+                0x80f03aa1891aa26a,
+                0xac2123732dfa8d6,
+                0x1a56ea320eee6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe8f238de28db1dcb,
+                0xf547e650a3679046,
+                0x31c3be5d337f2233,
+                0x733e5e3af8bddddb,
+                0x87311982da67bec3,
+                0xfa4c7d8200b29302,
+                0x92562717dd99c152,
+                0x8755b7e459371f2c,
+                0xbeb6668dc584af44,
+                0x7a05182655c89f1c,
+                0xce5fd4ea531c870f,
+                0xaf861b486ac8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd2d9e10fbb56a694,
+                0xe8545e75fb3f6769,
+                0xc7d65639ed43cadf,
+                0x5e7aad8c943d3d7c,
+                0x680d47b847024f30,
+                0xe4b531c76b0d9e1a,
+                0xd04de34e52b9f384,
+                0x9560549cd0450db7,
+                0x8719db37658a645f,
+                0x7bea08b259c07790,
+                0x53a458af3dfcff42,
+                0xdca579668eb0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e6aeeaa5e20288c,
+                0x543044da437a14eb,
+                0x82fa10c721489335,
+                0x2a28efd2c970c04f,
+                0x10cf6d88345d1eb3,
+                0xac4a5f33de5bee00,
+                0x2dddb093602f022b,
+                0x21c6684bd1d1d7ba,
+                0xd6f3f93b112641c3,
+                0xf46c10d6485ca20c,
+                0x1dfb7422937b2b65,
+                0x14e839af135b7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8956a76c8f060d53,
+                0x5d86cbf5b229f1c2,
+                0x125f1a826166c16c,
+                0x3e296f74bf5f8aa9,
+                0xf224d0784c9a02d9,
+                0xd590a5a9f1d42e57,
+                0x1d6a57530f81a518,
+                0x599b0112b5818746,
+                0x4727535bcec5794b,
+                0x6d8584149c13a122,
+                0x14496395e8f789e6,
+                0xebde4207c0dd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f3f43691e954b74,
+                0x3a455549fafdc7c1,
+                0xc3125c208c40bcc9,
+                0xed761908fe4dd4b5,
+                0x117a193266062b23,
+                0x2a09f24049a24aa9,
+                0xf6a268fa7c9a58e1,
+                0xe2ef8665f60198f0,
+                0xcb90cf797f838b13,
+                0x57c06b25f677e661,
+                0x3d48aa899f7ee39d,
+                0x63c5ef0f211f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd9f34d4adfc1e35b,
+                0xbf81677eb9caf8ea,
+                0x6de7af7159090162,
+                0x5610e637c9cd71bf,
+                0x5d767f45f9b08a71,
+                0xdf3499badf03d9ff,
+                0xbf16fd0faa51a13c,
+                0x3a76f252bef8c882,
+                0x5f5cee8a87614cc3,
+                0x5da0fafd7e66cd88,
+                0x91a5da1e565d6450,
+                0x19196f59a5728
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x113b495070eeea03,
+                0xfa1b622e99e87fbc,
+                0x6e4b645f2e27db1,
+                0x54e3f2a09d181cf1,
+                0x77a04ae0226b168b,
+                0x39b81122f7e8a9f9,
+                0xeaca12581fb397b1,
+                0xe6f99e244bc1ad73,
+                0x6d6245bde129529f,
+                0x8a4308e38402c14e,
+                0x6423c4d4d3b5936e,
+                0x1c1fd128ca9b1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e76f109effdad79,
+                0x54b070ea891d6558,
+                0x58d39ef77700ec48,
+                0xd0b55c3b42ee912f,
+                0x318f340bffbe4a12,
+                0x422bff10c9503dbd,
+                0xca621ef160939ae,
+                0x8e67a26cf35b036f,
+                0x66aa3c02906cd68d,
+                0xe5e270f0202c0593,
+                0xc41089767f0f5391,
+                0x382daf5e2e4b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x891d02493619e6a,
+                0x34a4d956f3ebac2c,
+                0xf319597057442c07,
+                0xe22f5e631689ed29,
+                0x7fd7bb1bb2aeca74,
+                0x6ea1c7e69cd598ea,
+                0x93d5d3dedee9bf1e,
+                0x50021af0e90bbeb5,
+                0x3b4697555ab8f0b3,
+                0xd20c17f9f5fa36f,
+                0xd58c7eeef7c1d42e,
+                0x67a3c0450a48
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ac37c0fb9576b77,
+                0x40b2bbaced04951c,
+                0xb26542bf038b1446,
+                0x25155a9bcbd115f7,
+                0x10ab3344beb453dc,
+                0x37d481bdea3cd059,
+                0xda172946ae37c2e9,
+                0x7d4b831d8c838b96,
+                0xe42d8351b34fd8bb,
+                0xced52fc963d6b443,
+                0x246dbb00296963b0,
+                0x101388993378b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x57d3ba1d4f094998,
+                0x99e85cf0c1a14ec2,
+                0xca6400c93e8536a3,
+                0x216538a961d910cb,
+                0x605063c8209f7a04,
+                0xcf0d5ffd4d525863,
+                0xa1f45332afaaf8d1,
+                0xb50d3005b5e0c359,
+                0x17de993f8f839f6c,
+                0xd5baaf09a2214a3f,
+                0xfe36137db249019c,
+                0x1a63e96a72bf4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x74393833cc620e3,
+                0x461f1b281ec2b52,
+                0xd914aaa376feea98,
+                0x89a7bcae90f9997e,
+                0x4a9d43807067eda5,
+                0x9a45f372b5936b96,
+                0x9da28725a325b698,
+                0x1fceaa5205f75984,
+                0x1da4b3f1b1203458,
+                0x7734d8308a46fe8e,
+                0xecca262220a312ed,
+                0x115f736d77d92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3f21ac16ce9003ae,
+                0x606a71f30786863d,
+                0xf1bd81a495d553b8,
+                0xe451b6e10959305,
+                0xda4e8556e9332d4f,
+                0xa7d01d2bb06deadd,
+                0x3b99b6d0aeed0af8,
+                0x62a4178f8c2d648a,
+                0xf942a238e87b7e66,
+                0xe68841f339e5a477,
+                0x6f8293f8c3b2cbff,
+                0xe4404c69f5d8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc5251252bcd4e57,
+                0x7f58eb40dea557c9,
+                0xc9d693c798e1ae2c,
+                0x55a949cb903d6f9d,
+                0x6f2a052f752ea075,
+                0xb01bb1c5a31806fd,
+                0x3565a9054d97a70f,
+                0xb47a7371da4bb553,
+                0xe90d4132aa38dd59,
+                0xe4d223e378edb5,
+                0xe2ad7d8a5f0e2d65,
+                0xcdc2cc894639
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2d255d1274c63a1,
+                0xe2080990f9949846,
+                0xb3dc52f9111aaff9,
+                0x63ab4f42b86a3f6d,
+                0x48184ecd399d6b17,
+                0xb4f4440cc346066d,
+                0x5cc4abcd0108428c,
+                0x40f8d004cc891eb9,
+                0x3ad20cbe7544b36e,
+                0x3fbdaa4ddd2870fe,
+                0x65d079705a79387,
+                0x7ff6d97a03a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a92d4ea82de1543,
+                0x1b84d270e33c7b3e,
+                0xeb9aaae649b8467d,
+                0xee30e68d8c869314,
+                0xea00c3d20206b7e,
+                0xb592f449cc91abbc,
+                0x68faafa22c984eb2,
+                0xa81fd2be3c1c3f92,
+                0xaae456ab38eef1bb,
+                0x42411107cffba6b4,
+                0x624fcc931417b8c8,
+                0x1444d96140ea6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3ceb3ea86bae717c,
+                0xe7aceb1391f4fcab,
+                0x90280e1a0fba2667,
+                0xfd28bbe686570ea6,
+                0xc058ec772c4ab35c,
+                0xec53bb001541e4a1,
+                0x8a171ee668ca6135,
+                0x3915f975228d315,
+                0x826cf680f02a68c6,
+                0x8c0ec21ba6a2b70,
+                0xae735e7402b765b1,
+                0x1f65d0f2222a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee8cc3de06f74a5c,
+                0x630f23a5387c504c,
+                0x54710a2c272b676f,
+                0xfe0d6b568780fa12,
+                0x1909006e4dfd8e7c,
+                0x49a4c838d015f2fa,
+                0x66833a3f568c914c,
+                0x235085aa0cdd36bf,
+                0xfb75fda500c51e4,
+                0x1b0192eefdd0de4c,
+                0x8090b1b29653452,
+                0x436ea8867ddf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfccbb9430b36b352,
+                0x3c099dc538d8763a,
+                0xefb28c1da54ed040,
+                0x9307e8f45362a524,
+                0xc7390201e646d587,
+                0xa955a33ca253fb1d,
+                0x1c6266d4bcefb1b2,
+                0x94c242e6774e9c5d,
+                0xf5cd3addf3d4f114,
+                0xde8f481755f978f3,
+                0xaf2da060b3ee748a,
+                0x14e2550e4b45c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3cc163a3899e8b66,
+                0xa0baaa58a5690029,
+                0xd70ff56672c89e7e,
+                0xfb25f5d542914156,
+                0xb7f99abb5496500a,
+                0x282382139f54713d,
+                0x2dadb5f854785ab0,
+                0x3102ca73b11a1214,
+                0x7d998dd644f01cb7,
+                0xeb89decd359b3e9f,
+                0xca24adb9f143f68b,
+                0x16ce61f182c2f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x153d4dc789a19617,
+                0xd194a24bddcf8937,
+                0x54b757d9aa3008f3,
+                0x3c4d1b2de8ab2015,
+                0xb84831fece7eb2ee,
+                0x2f9fc755604f04e4,
+                0xfc9a9c3be400ed34,
+                0x7e00b4aeb539404e,
+                0x8b52cb54abed18ff,
+                0xa89b9db621baa8d8,
+                0xd45bdfc2203ee78e,
+                0x7fb415d773e0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x125895750500166c,
+                0x292699b852292e79,
+                0xc5f8cf6a85732469,
+                0x4915515371149d68,
+                0x6bb09019c4087274,
+                0x135b15f67952caa3,
+                0xba4413f0db53fe80,
+                0xd7cfde57740fdcc4,
+                0x15fc8895fac03e59,
+                0xa6efb16db5f353b2,
+                0xaae4b754d84a030a,
+                0x13477489cbce6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x609cff3e5b323767,
+                0x2af04f9cc92953ec,
+                0xe2ac3367abea1eba,
+                0xa23079d0237ba721,
+                0x161fba794560f96b,
+                0x4b170696cd190d35,
+                0xc75b1f76f1362caf,
+                0xdaa0425d1857444f,
+                0xe31525332af13a8a,
+                0x502b856d2c099c1e,
+                0xc4cbce1be4bbc1fe,
+                0x174065ecb39e9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae33e6595aebd45a,
+                0x54ac0936b2acc4a3,
+                0x3b25bf71c65cfd75,
+                0x3867ebac590c17bb,
+                0x967858406349c35,
+                0x589edbab3200ae38,
+                0x485f4b196d901e75,
+                0xd99bb48a932a900a,
+                0x2e3a965e3901a399,
+                0xcaf7f3a90c2c0dad,
+                0xf8b9539fcccb6867,
+                0xebf87b3e392d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf252eb080f059f15,
+                0xab86fb247e370588,
+                0xe881c2222229efaf,
+                0x59b48c187caef512,
+                0x7c9387fccda6acff,
+                0x84ec495f4a797afc,
+                0xd6baae6148eadc1c,
+                0x1eacaf79213be319,
+                0x4db745a371be2b06,
+                0xc4db60158fa4af22,
+                0xdd4d695f1df2aeb4,
+                0xac640b907a54
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf1efd533a7bb1aa3,
+                0x666ba3e96055b54b,
+                0xf86e3cb4d9e1303e,
+                0xd054d3b2cfa7cac7,
+                0x600eb9494757c6ea,
+                0x760f9cf146efa5dd,
+                0x931fd3e21615503c,
+                0x1c4641d61e55067,
+                0x47850a00618d4af9,
+                0xdf2d3f418398e3f0,
+                0x10ccc94fec10e197,
+                0x16dfaf2b93d1b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd8914e64d04295b2,
+                0x2d618072cea3ebf1,
+                0x68a5092f66ca7b7c,
+                0xa218f40e18efe54d,
+                0xf3e716ba15d1638,
+                0x8c1e8eefa95fd492,
+                0xdc8b99e617786f26,
+                0x89bf1ce0a7908e55,
+                0x456f3c96b21081d,
+                0xbe19b05aa2c421d2,
+                0xcaf47dbf508e81bc,
+                0xee00e54336ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47686b049794ce6c,
+                0x8335d3e92de58d4d,
+                0x99a81f0b0e2c647b,
+                0x4a4dcc10de669f83,
+                0xbf6a9f68fe15c392,
+                0xc82b9a28821133a9,
+                0xa13175da30f5df1a,
+                0x47bedb351c3761b8,
+                0xa36503d697406e69,
+                0x72d80e29ba01ce63,
+                0x7938fc58b2f035ae,
+                0x4fb7ca0f7a44
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe7f65902f94250c0,
+                0xb585e51ad7ada39d,
+                0x47bc6d7e334bb3fe,
+                0xb411145f1932ebcb,
+                0xa4d865b3a691abc9,
+                0xa36368bc5ec4cf83,
+                0xa966741302b854a0,
+                0x947e010d70cb782d,
+                0xb5c7113bf2aaeb17,
+                0x70fcf67d7e07ad31,
+                0x2cb0451e9d75ed26,
+                0x5857c77b1bfc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x681c5acd7e1da37,
+                0xb9335c5cc26f2887,
+                0xd9ace07d8a393ad1,
+                0x6e845024d118063,
+                0x4a2792596f4fa4aa,
+                0x4d7749d8cdaf666,
+                0xec9f99997b158f42,
+                0x562ecbdba66618e8,
+                0xb0a9737f58b9038a,
+                0x56cb27d5d8eea4bc,
+                0x946dc813d6722067,
+                0x3269864495c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa34ea92517722274,
+                0xe56c5c6818b740e1,
+                0x2e57cef2e305012,
+                0xa636c868f5489f54,
+                0x4c8256abb714579a,
+                0xa01a21bf6d79335f,
+                0xdf8dc6fc9483a454,
+                0xba8ec7490d14aba7,
+                0x138430ef87f78565,
+                0xd6243ba471dea2d2,
+                0x822b9273aa2a1e49,
+                0x1407a4bf3f276
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2f3eabe72ae14b70,
+                0xc349026a76ae8580,
+                0x298f008fe56f8c5b,
+                0x2d912c3f02ed374,
+                0xd9ef029818a36608,
+                0x86b14f3a68bb9c02,
+                0x8d8de8eb91e64e00,
+                0xb580292213ab2a7d,
+                0xf043830d7eb0e41c,
+                0x1d8688e2a9616001,
+                0x1acf0dda074add4c,
+                0x186b2b4622d27
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe0a1ffa051154da2,
+                0xb70cc4a5712f6fb2,
+                0xe3f1a20c24a6e1d1,
+                0x5037fd6c26da8be9,
+                0x5b973c9015622348,
+                0x951aae057d1d900e,
+                0x63a4ccf6294562d3,
+                0x2c5a5878cb32687b,
+                0x5501a72a6dcd6ebc,
+                0xebbcfd6cb8e5df9f,
+                0x910032a3021df792,
+                0xd07825f10c90
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b318c899453087,
+                0x242f975c8fae3748,
+                0xcb3b8ca2c4831ecf,
+                0xd5bd3010036a03f1,
+                0xf8dcf3df693bf432,
+                0x56896f1ad21b499e,
+                0x2d1ddd3add20baaf,
+                0x8105ae31a34e1cbc,
+                0x20b136c1a86dc808,
+                0x62841ffa870fa9e,
+                0xf1e3667d2d283bbf,
+                0xf5d3a859ad2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1bab917133a4ad40,
+                0xc24ca78f4ac45e65,
+                0xd15210a06d2ea12,
+                0xfb7b7b55c54392b8,
+                0x66a75dfb1aa97619,
+                0x309c806b58627905,
+                0xaabbac471350491d,
+                0x3f36e9903ceba6cb,
+                0xe612dfa29e4e850e,
+                0x3f39d4bcae0afa07,
+                0x419063a9f7003384,
+                0xae247386891e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10df184af042f3c8,
+                0xdf7dd52ddfdf140,
+                0xc017082d93b6ddfb,
+                0x963daee757977a0d,
+                0x1dfb63d5bac8eb8b,
+                0xe1bcef0ae7c4e5ac,
+                0x98f39d06305d5cf1,
+                0x7635c012928ba10e,
+                0xd41880ba90d564a2,
+                0xb783104ea1b660ef,
+                0x3ffafed3cc2303e3,
+                0x9afdba65ed22
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x781529fc4189c8b6,
+                0xd9e697ca96e619f8,
+                0x713374e942d89979,
+                0x79a2e73b450fbd08,
+                0x88ae09a1a96bdf71,
+                0xb2871fbd58816ab9,
+                0x43992b3e688eaff8,
+                0x64b4bfbb872253b4,
+                0xf6f12a37b1a2d9e7,
+                0xb48ab68f92471b76,
+                0x9f0918d22847ba84,
+                0x61e627a94131
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb63c1a86b41694c,
+                0xc77c29234553420d,
+                0xfeb71ad5e466a995,
+                0x841543e6cf653857,
+                0x54a68e2b32faaf8a,
+                0xfdca23e5a84508ee,
+                0xa64d6e860e58fb2e,
+                0xc28362efccf5dccc,
+                0x1465770585bd3aa9,
+                0x8281422c4456466a,
+                0x402d7542dbac8a6a,
+                0x20c58ba10ddb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66cba4f0340dfdd7,
+                0x6966cc8f87af62ff,
+                0x25dd970dd80cebd2,
+                0x737cb75cc73ff0f1,
+                0x62a374fd4fc1ef09,
+                0xfc4489a5acb459ce,
+                0xe3262db637d4532b,
+                0x69a7626b97501234,
+                0xa038c14c12944a0f,
+                0x972f88e0c5b7fc8d,
+                0x835d828055d1bd13,
+                0x5815dad94463
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6658ea6a4522fb02,
+                0x1a0ff2fc295a1ff6,
+                0x6c769d5f95cff602,
+                0xc762bea18d649eed,
+                0xa0a769cc836f6bcb,
+                0xe102ea62e33cf83c,
+                0xb5a68a5d11d0cb42,
+                0x62d4ce7c497c95f,
+                0x9ac19f17436fbc16,
+                0x6d8562605638231d,
+                0xbf189376eaa757b3,
+                0xa1b3db33953c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbdb4ed52b1cda282,
+                0x4e8cadb13ba5b702,
+                0x3bef9ed499dd03b2,
+                0x6dcbbab694e130b8,
+                0x6b9d060ff8382ce9,
+                0x7f2398a0b3024119,
+                0xdcc8d9dbc14656cd,
+                0xfacc96db099e55a7,
+                0xc271cdd46f352ba7,
+                0xaba58397b6b18468,
+                0x1b354524c9948917,
+                0x71a00a834978
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x89a23daab6414190,
+                0xada75949c46bc25d,
+                0xd03f24bb24c1988a,
+                0x1fd2aecfdda42216,
+                0x3119b9901de1d8d5,
+                0x610d0cdbfcb1915b,
+                0xaaa4141fc210bff1,
+                0x5bffd9d024205934,
+                0x89c0a93523f76e33,
+                0xff7a603ed2243bcd,
+                0x72c2d467692475a,
+                0x1670f5865b0c3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x34ec9d2bfc41a030,
+                0xbbb8184cff774955,
+                0x87912767ff68c402,
+                0x2b378cf62dadf992,
+                0x2165d2ccad73c611,
+                0x6f16dece43e213fa,
+                0x728c8668da1be52d,
+                0xdd7d0cb05fbcc4f7,
+                0x555ec7e79b5b6bf4,
+                0xf9caab0d152a1352,
+                0x9ed09d522e8c37eb,
+                0xac2355d7311d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb388203ad31cf2fb,
+                0x418882153e62f2cb,
+                0xe1e2f4ab2be4197d,
+                0xb61a77a00ff32b8f,
+                0xe91cdf2133e02ffc,
+                0x347e67e45ad560fb,
+                0x6d2ba6a475586771,
+                0xbedddd8fa2752312,
+                0xc51c00a4a2f3783e,
+                0x246882f8729cca55,
+                0x6e8bc31a5c4f2c14,
+                0x1b579a40b40d2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9bccb30571951b83,
+                0xef6af6f3b6d84992,
+                0xcc9f6d7a651ee751,
+                0xe06b6972daea21fc,
+                0xdc460a65225c62f,
+                0x86aa6b0ab0139af5,
+                0xefc924ee5e8fbae9,
+                0xfccfb8db73a5cc0d,
+                0xca20353161a1b4a9,
+                0xecaaf40887abcab3,
+                0xe725478b0d8b6d46,
+                0xba85c950f183
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7225738c610f73e6,
+                0x17966df78edd9ba0,
+                0xd59ece5a66c43bf7,
+                0x9350cad1c98a40af,
+                0x900d489c5c2c6aa7,
+                0x7548a86d0dbf48fa,
+                0xe0ab47a67eabbbb1,
+                0xad6c6551a142b27f,
+                0xe1d3f8ac1de83018,
+                0x44699ebb1c2b31ee,
+                0x87903a6b109dabc4,
+                0x1a4319b2310b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfdc8dbfd7d869588,
+                0x2efc118ac94d7fc5,
+                0xfb343917c21185fe,
+                0xbe0b4afed273ba46,
+                0x339fe9862772148,
+                0xda5a6edba17706d8,
+                0x10d2964943c6b08d,
+                0xb98afc8f5d6713e0,
+                0x64ad67df261adcdd,
+                0x27de3eb97f230e3,
+                0x177f8c3303e9f326,
+                0x5710fcc7c78e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66ddd44743895a9e,
+                0xe0afddd6269c35be,
+                0xf94e7ba31cee81a8,
+                0x653f8cb90ec8bf9d,
+                0xf02c91706159ae1c,
+                0x49967a0f5f5cfc7b,
+                0x9b1a4e38469be51d,
+                0xcbe656e90a5a23c6,
+                0x5f502f52ae0a2aa7,
+                0xd91c5690985c2ef,
+                0xb58e07b0fb065075,
+                0x15baa3fd2e740
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1e1e1d011b2fcc,
+                0x47023c58af9ca274,
+                0x7f30e61914fc2877,
+                0x6b6cb5125a5ce023,
+                0xb7de409f9dda3c81,
+                0x50afdb9e72870e57,
+                0xe7b6d9c39f602426,
+                0xdb920ff36cf93141,
+                0x59103fdeae3dfcb3,
+                0x717cc6238124cd1e,
+                0x6fa461968ecc8b59,
+                0x12bc0261377d6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe02547256aa31e51,
+                0xf82239dfadd1690a,
+                0x2bdceb534b2d74d9,
+                0x8382b78b302311e8,
+                0x8dda102f84425193,
+                0xfd01427cf40af54e,
+                0xe94a2a07457ce478,
+                0x6d445530f92864ca,
+                0xe63d3d4dd8befa2d,
+                0x63e68aca2b6ee663,
+                0x802025156b26fb89,
+                0x83ce18f720c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfe574c47c5f2e9a6,
+                0xd7c525356926801f,
+                0xbcbd70610dd2db96,
+                0x92880836c5ee334e,
+                0x5f504e39821e1de4,
+                0x7dc9033831f4c1b4,
+                0x1d96963a5e02ecdb,
+                0xf76b63e998b846d5,
+                0xe673d2a51dbcff68,
+                0x99c26a0d2759d66a,
+                0x902519b49094310,
+                0xf94ca59e07e8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43b74df0851ca168,
+                0x5ccf3fc30303e2a2,
+                0x97a72cb554a4a04e,
+                0xf533e601bc360a69,
+                0xcd72da32d58f833a,
+                0x9306b32fc939e5a9,
+                0x6b797803f6844645,
+                0xed6ad441b71aab61,
+                0xa2f7a91ed3428bbf,
+                0xb8faffc3adaea5b8,
+                0x1da26aaf8b61c2d4,
+                0x8e29843455cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x108ab2719eee2e44,
+                0x30ed8cc49a492ea5,
+                0x1e32d09f4c8bedd4,
+                0x3803a017f5a0e242,
+                0x93be1984ce15ecd4,
+                0x65761813f4d04310,
+                0x19648cce25a75b0f,
+                0x57052fff340e271a,
+                0x278a30f240e47948,
+                0x985552c32a610d66,
+                0xee8ab89a1166857f,
+                0x9c85fcea0a93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b119e9104e3aa8d,
+                0xb1bf715e136a5b30,
+                0x3c4737c5acb5bdee,
+                0xd625545ed030a505,
+                0x8ff5e3edec48ff8d,
+                0xa80383add56b3a41,
+                0x91af054430bf90b2,
+                0xa0393d91af5011a3,
+                0xdda365c2b2a34806,
+                0x7b7cc52dcf43d9b3,
+                0xc9739f52a76791de,
+                0xa05f96ba9e2a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf7a239f7175175b0,
+                0xffc6ebe13bc5b359,
+                0xf48ec2c71ad8720b,
+                0x8c7042608b3db7d9,
+                0xa8a8eb7d72922887,
+                0xc646fa17a6b14127,
+                0x348c70d7a1af4e79,
+                0x83b8c838f4ca88fb,
+                0xb38577ed5e839c4a,
+                0x9ca0e73310214273,
+                0xe8f6754cdbc5543e,
+                0x1984333f4da4d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa8c6cce9381e5507,
+                0x250892c2458f3e0a,
+                0x856be18369c96b7a,
+                0x3f563cd42563fa8d,
+                0x3122ab028026493e,
+                0x5e1897b17ea4982,
+                0x930d395c0c2a45fd,
+                0xb5be8e3f2762cf0e,
+                0xa16847370b08ad2c,
+                0x9c7635587b0bd75b,
+                0x13b5d6131647b720,
+                0xbcbbbfc35d69
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7777080c2514ea31,
+                0x2e965ca1a4204411,
+                0x504ba12a79ecbf97,
+                0x648f10b668667bd1,
+                0xe85195988c149ace,
+                0xd4e8977c2a222b36,
+                0xcce9add533e3f7a0,
+                0xde38b650defb30b6,
+                0xb2fdaefd6b3c08d4,
+                0x7607f7741701a79a,
+                0x8651e8c603560223,
+                0x544d1f6c392b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda7ebfcf74a915ff,
+                0x81204695917fab48,
+                0x6ecb97d89b32550f,
+                0x67ac560f116eab18,
+                0xc7d70dea8006f963,
+                0x526093703521b6be,
+                0x85125f6c1f45d8b5,
+                0x8371c677a705dbf5,
+                0x3eade42838c49eb3,
+                0xa9ba9cf1284c9960,
+                0x4706f6160e258631,
+                0x1817551b1c9fb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8eb9a64349abb97b,
+                0x5ba8fcf6f7c21c31,
+                0x62bd23613449f37c,
+                0x3799eb4fea0747e9,
+                0xd4ff6beb97502fa6,
+                0xfd0af5fc94c15f5e,
+                0x8f58f13fadc48b27,
+                0xe21d48691b7ea1ba,
+                0x1d66054db014d1da,
+                0x3ae2aa4a551a710f,
+                0x21bd57e31e70af03,
+                0x15b3976e75374
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7c8e6db8e4b7aab7,
+                0xb83a4c3e2585dead,
+                0xf6f91159588ae14c,
+                0x9df19a132cf237f5,
+                0x43fd7257cc9eceb8,
+                0x616b2d73dda2de68,
+                0x954c870c671766d,
+                0x18a37bf89fbf2ec1,
+                0xdefccc6fa0ea5f7c,
+                0x76aab4628512962d,
+                0x25a88e1b64bf0bbf,
+                0x19fe471b9bc95
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcbe0c47d4b94b64e,
+                0xfec422261bb0661,
+                0x52a8f47930675b1c,
+                0x4baaaaf3671bf212,
+                0xf0017a3382cd7755,
+                0x8626a1ff354c24bf,
+                0x657c9c77c78d19a7,
+                0x9a7fdf583cdc708b,
+                0x3f2ebcbc00945722,
+                0xd8a5a402eded44d,
+                0x759bd6ef92d71959,
+                0x1027303bd5e94
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbe0890d704ee2035,
+                0x666b5bdb650e04e1,
+                0xcafc52c53ae64827,
+                0x3a9ee853b7c10e18,
+                0xffc45c2906254d18,
+                0xa6781947015eec14,
+                0x2b0b20b66f239dee,
+                0x281101a63dc31a8,
+                0xf698fcd184d9cbbb,
+                0xcd2fb80dbdbb009c,
+                0x381a110c62638c39,
+                0x124c6ca6c79fe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfe59a85459701a50,
+                0x2acd5be674566e82,
+                0x461f003c4d24fb8b,
+                0x6af2ed8ecca1ed87,
+                0x69831b89ab791c80,
+                0xf848df399cfe3131,
+                0x3b899841d7c8b65e,
+                0xd705254b10cdfde1,
+                0xd2a09a2f85ec40fb,
+                0xe6514fb5568fbbf2,
+                0x25ae4deb4d82b402,
+                0x84b9dbccb46a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x617166a19826bcdb,
+                0x24606bd34988d0a1,
+                0xcd836eb0278205be,
+                0xbc63cb8ba674d0f6,
+                0x45e3cd5761349282,
+                0x2ae2254d7a1e26d6,
+                0xab338eb2200c00ac,
+                0x8d26c12f63755a4,
+                0x15d15b1ee134fe83,
+                0xaa26335638d5fbd2,
+                0x6502c6c077e0afd7,
+                0xc981242b542
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeee9b6c0d4536e32,
+                0xe85f1b6e44fcedd0,
+                0xec29b0202f45a4b,
+                0x14d09dc7f2751326,
+                0x35ca7351bf1ec2e3,
+                0x1206c8531805585e,
+                0x1c0b83c54de71c59,
+                0x73ddae0003a52382,
+                0xfa584e23f7eaa702,
+                0xbf640108e227f2f1,
+                0xf4cc774d86098490,
+                0x2943aecec1d6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa935a2d4920b8877,
+                0xc0c81900a9ed37ed,
+                0xe81edfa155ac365e,
+                0xb6d7bb1ac0025cd2,
+                0x39716943b39c9668,
+                0x9dffe59511a04adc,
+                0xefc1e65b5e614515,
+                0xe328988d9b5bff22,
+                0xa29a48ae157ea36e,
+                0xec603a1179beb9a8,
+                0xfc6657a85ef4771c,
+                0xa8e6544d39f7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc28cc7a31c1b00a0,
+                0xcc4b2b635a75d9bc,
+                0xac2516c71edffb3d,
+                0xbb24cf7f87c27b61,
+                0x5fadf21b11fa5d3f,
+                0xd6a0706903841485,
+                0x8b58f435e35cd5a0,
+                0xcf5b864eebe0cd60,
+                0xf099cd35c2ade2c0,
+                0xe254e060f5d223e2,
+                0x8615bde597cd01d8,
+                0x5b04b13e97be
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2cc34671d1e6e118,
+                0xadc53ae40f984ded,
+                0xbd5ffc7c7f14a0c0,
+                0xcd5a243155a73585,
+                0x2896d0500894d600,
+                0x7e1ddc732007c5d0,
+                0xa234993bc22a406a,
+                0xa029dd8cc7591418,
+                0xae7c38d4baac6875,
+                0x28ef04cbd65023e7,
+                0xb0421cb10c63e889,
+                0x691989e7e256
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e57dbfcd02825fb,
+                0xcb744b0d87cc86f3,
+                0xf19ea6fe18941004,
+                0x7a4a3f3851b9dac6,
+                0x6274b5c3ac14c035,
+                0x71d7cf2fac4c7a7a,
+                0xef20f9bad539337e,
+                0x33e8fa728b85260a,
+                0xc6309bc63051a7f8,
+                0xa62c965c9bccf2da,
+                0xf70a2ec8a5ffe385,
+                0x420e01834b3f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc17bea5021026200,
+                0x9bd84f2482ba03bc,
+                0x1338839ad27bafc3,
+                0xf1b858c0cb8c988a,
+                0xd20db64985ce7bdc,
+                0x21dfbc65e740059,
+                0x44e281efb94f3584,
+                0x45ab01c23d6d8410,
+                0xc01a7787db0d60aa,
+                0x941e62817cc962c1,
+                0x99b8b724e44b194b,
+                0x186ab56e6da72
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a7cd699540205ee,
+                0x23e60c7da6c47910,
+                0x31be49bf1b89ae1d,
+                0xd62f1c937edbf504,
+                0xbdfa664b7defa76e,
+                0x64a832ad62670a04,
+                0xc076a16d40c08e07,
+                0xbf4d7a531ff9306e,
+                0xa1ae9c857981b54,
+                0x1f5f57dadff162e0,
+                0x90e2e0275f837618,
+                0x77fab10b3cf2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa979c7b63c29ae2e,
+                0x65994f7affdbae58,
+                0x2e93fd36b310d91b,
+                0x646de40dac4ae69c,
+                0x6a21b01be706671,
+                0xbd029649f9012981,
+                0x6736788a02d2d6bf,
+                0xa274cd1bf160d13,
+                0x4c426fc1a579f6b6,
+                0x50518ff465a5949d,
+                0x4ac4becb51ea777e,
+                0x19a4f9e19a226
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2abe303eca90107,
+                0x18254de86759c40c,
+                0xfae0fc763bdae58c,
+                0x3cfc124daca6d762,
+                0xa62e6befee3f59ad,
+                0xa2c8ee1d2b4f9206,
+                0xec73e685ff58a2ec,
+                0xe1b28dedd7014f8e,
+                0x108b3d3ead5ca37b,
+                0xf8701bc6df18be36,
+                0x72c904f632cc3155,
+                0xa6b4dfd0b6c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x829e40c50f884a08,
+                0x9f96fd3d98dffad0,
+                0xbf6c72a3537460ed,
+                0x1b771aefb1db6146,
+                0xfa2ad449ffbddf47,
+                0xbeeeba64d28f816c,
+                0xffb6354a344e3dee,
+                0x67490c0964797adc,
+                0x769cac6827aa8c05,
+                0xe585347a33601835,
+                0x23bea7cbf79f500d,
+                0x197bfde065ed9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf51f9fa0df936d3b,
+                0x36830fc894266519,
+                0xe54ab4109fb543c4,
+                0x687ab9d6d2681cd6,
+                0xac3a39b7fb427b4e,
+                0x50a2834b294b01bd,
+                0x7e4cae59d5314af4,
+                0x2c2d520fc65651f2,
+                0xcec8e7ba08b7e980,
+                0x62cb9b0cb9ce517b,
+                0x5d475726aafe1bfa,
+                0x13d77c07104f7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf97c0689cd18d81,
+                0x931e300c40a9b649,
+                0x2cc6366b3e3f798,
+                0xe55ca4fe197337b2,
+                0x3f65a17291f5fde7,
+                0xf600a15a7446eb3e,
+                0xbdffd38a1f48da16,
+                0xfaca7373cad1de97,
+                0x7d17fdb2750e07ac,
+                0x1f27be887b8206bf,
+                0xf90da1390ba0d351,
+                0x6f39970ec5d2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ae6d3280735e550,
+                0x747e8957bc03ddef,
+                0x250788b0737f909e,
+                0xb252d8841f041d25,
+                0x9a9f5216a965aa7d,
+                0x18806569be7ee405,
+                0xe82de69466414ceb,
+                0x81525b65143c57fe,
+                0xda465f7d38e4da85,
+                0xd09bdedaf1966b5c,
+                0xcfd4d8695dd833f3,
+                0x26158690c519
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6f2c29f4a22d5ed,
+                0xad3255b4fd7d7e8a,
+                0x75717f2d964c0b4c,
+                0x403391bd71fcede8,
+                0x791c24b81eb2bd5c,
+                0xbdf7a0836256d5fd,
+                0xd5489ed12c1e708b,
+                0x67a8b9b93c72b725,
+                0x7c553b9954d69a47,
+                0x94be861bcd2b9aac,
+                0x3f5771d882dedf26,
+                0xac836beba4f2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x52e9ec2c3b506d0b,
+                0xa92711ab81e25790,
+                0xbdd351877770b694,
+                0x67ba6ac702c57337,
+                0xd678630f7e4fc2ee,
+                0x6916dd926b73545d,
+                0x4b0631d3f2021f2b,
+                0x6da431bc8f8284,
+                0xa5741b9edcf4782,
+                0x3c816e85a2c7475,
+                0x27a08299eb1d7e24,
+                0x1af50fbc05b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x15b1c58c9487eb01,
+                0x931e685c69c1fd55,
+                0x8beee9d589f8756a,
+                0x41ee3416cb72e3ed,
+                0xed75d3c2bb395267,
+                0xfcc884fcb066ead9,
+                0x594e316aaffc953,
+                0x7803509461b2554e,
+                0x64be15dab4968b97,
+                0x59ec771125be0d59,
+                0x7ee9bfaf6433c914,
+                0xd1edb50a1a74
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf22c4e2dd95cc770,
+                0x3740e73c8f3d2350,
+                0xd2f77cb5cc0c07f1,
+                0x9b3b9b148c608379,
+                0xd9af03dc1d0a70b1,
+                0xb54ab0a768d86980,
+                0x495b661d0b818208,
+                0x4dab8f2a579b6ee3,
+                0xb2311f57cbfcb6b3,
+                0xa4ca00d2530ed989,
+                0xc80ba029b50abf5b,
+                0xd9e5e30404e2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3f52d4aadc6edb7,
+                0xe7824a49c9a4c9e5,
+                0xd505fb2e2d2ad1e7,
+                0xb8f2709b69c577a4,
+                0x65537f17a824bce1,
+                0xc75bc7843334b06e,
+                0x6eb85d987c8a8d5b,
+                0x93bef602238be032,
+                0x33354a3baabd6b4e,
+                0x8fa41459e6c2cc15,
+                0x5ab670afc4b5578,
+                0x82fa2bf8728a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x97908dbdaeedb331,
+                0x7eeac94d7e8c4b3c,
+                0x42db6304342420ea,
+                0x4b9a891d08bffa49,
+                0x53f5645f2a6ac756,
+                0xbb9c50449b03a2c7,
+                0xfcc2217e00dce461,
+                0x5433de78a115a49e,
+                0x4f3bd7048750c772,
+                0x8e959cb4ad002df1,
+                0xaf14d988f5617bca,
+                0x5b4fdb124906
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc09a348e4a0cb1b9,
+                0xc47e795ddaa92920,
+                0x27d72bd5e20c6d5,
+                0x6dbeaca330f93c1c,
+                0x372d500d5e18bf8b,
+                0x2da8d1e65bde3816,
+                0x8765090333ba596f,
+                0xc9ef60d48465e8f6,
+                0x84e00efcd518b97b,
+                0xf695e7dd0a78ed9b,
+                0x351479b3ecd78e1e,
+                0xdcf39e473e9c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e45f24fc27aba51,
+                0xe659f5a1e94e468c,
+                0xc08768884a7d164c,
+                0x8c433e36e4cdafd5,
+                0x34f57119a2afab22,
+                0x44f9fcbe997808ac,
+                0x815f1a53cb58eb04,
+                0x888436a9ffc56e80,
+                0x4d85a57e70f63e3e,
+                0x80fa7c1a41f3962b,
+                0xc4fcaa36afd4b62e,
+                0x14def39b56e88
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c6fc401b280faed,
+                0x9185cbb035a3a6b9,
+                0xe80ec521856e1c3c,
+                0x408ace63ca8f8e4a,
+                0x804966516886d16d,
+                0x849e2dd9fdea2838,
+                0xb67880aecda8714,
+                0xf194f4b3cb2a25d3,
+                0x358ca2183f88f9ec,
+                0xd8951b4c0eba1929,
+                0xdef20f0131bb8aea,
+                0x2dca42d74c82
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6d8fed2b7fe6536,
+                0xb2fe880dcf41cc71,
+                0x6c302e6b52bfbe4c,
+                0xf62d9836f32bcb83,
+                0xa6c588065b035376,
+                0xff998d7c49ecd5bd,
+                0xc6a1ab323e2431b9,
+                0x85a0bf1920930125,
+                0x410fa484d5728659,
+                0x9015158fd32f5fe1,
+                0x41836c1b7b47563a,
+                0xa2caf7cff780
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x397c3c4c36b4535,
+                0xcf49ca4c1daa8dfc,
+                0x7226fcc0df438e6f,
+                0xdecef93b35ff5e0,
+                0x31b3fed139e54c0a,
+                0xb568a07f1544d411,
+                0xc111fa10b399f744,
+                0x11a24825f56b493a,
+                0xc7c1c0e1256436f0,
+                0x4d0d12727fe624b0,
+                0xf27e94f1a889ce6e,
+                0xb3396197ac88
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8b3466ec682ca3d6,
+                0x14def20e84392eec,
+                0xad3395ff7026cb14,
+                0x42b029c1da74d811,
+                0x546b87bfee66999,
+                0x24ebdeb602eb4382,
+                0xf202d4197eb3ff86,
+                0x9fd63022a380a770,
+                0x1f7e0fa9c1da31ea,
+                0xb6d69505d95ae785,
+                0x1d718e10efaccdb8,
+                0x542cd3e8a888
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x33ccd24a40c811bc,
+                0x624867487b179e4,
+                0xc80d22b5edd444a,
+                0xfe5f02774731f355,
+                0x1c0d264641fb7cb5,
+                0xa31d0e1c3cc44ae2,
+                0x2a90dd3b1e41c772,
+                0xe0f6ef940e6402c,
+                0xf9c9651fa62a7aea,
+                0x480c7a984813f8b6,
+                0x641d29f102fb928,
+                0x6ee84c6042b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3060ff857a7fb13,
+                0xa658db91b5e96306,
+                0xde95ccd598254c77,
+                0xd0ae1a952fefd556,
+                0x165c97ab57a7f498,
+                0x9487f6c56d4bf3b7,
+                0x9379648dd8e5d264,
+                0xf288fd0c6f83121d,
+                0x1b3f0eb6b1c1c069,
+                0xa7135f9102901070,
+                0x88f7f4e8e00c0476,
+                0x1a7e120a8c4ef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4433e6af4872adde,
+                0x473d3ab293eac658,
+                0xf3c984c5e92de3a1,
+                0x4bc259ac4d837335,
+                0xb5310ab1d9f619e0,
+                0x95049043f4fbace2,
+                0xd72f1f34090e5c02,
+                0xfecb05b5b2c4aef1,
+                0xfb71fd5e6a77032d,
+                0xb6432856eac9854c,
+                0x67ec5ff8f1e6fa22,
+                0xec95e0ed593a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x782495d5bc4392d7,
+                0x7476026e259a964d,
+                0xcb26e05b7e1f8b5b,
+                0x809b7765f1e58d8b,
+                0xa4bd1b5f66db90cd,
+                0xd9dddb4f317bcc9e,
+                0xbe6c1de8a0d0c870,
+                0xd55dca86747d0760,
+                0xc73e35a2fff1c714,
+                0x86af1f1dc9594baa,
+                0xdee1343c9f910bb6,
+                0x1b0b8bbf1e8a9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x948a5793b6de843,
+                0xbec49cad758e93f0,
+                0x2ecf283c95fd17d0,
+                0x1aed751372e72c4,
+                0x8355bcfa649eab6f,
+                0x15e89518f634903d,
+                0x44a7d7937ab25c73,
+                0x9e3ed30c0ddc40f2,
+                0xeeca7d2c5ab1a041,
+                0xa7b2da18c9152998,
+                0xd5ebe2bd3f06f717,
+                0x10c7f21865565
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83ac5e8d43c2961e,
+                0xc78f6702c82bd96e,
+                0x91f8145e3205cf4c,
+                0xe7085db11d2ff5c5,
+                0x1804de722c4e2eb1,
+                0xe499a929404c4e4e,
+                0x3737bcb91b0fa0f4,
+                0x6b2b22aa84b03f35,
+                0x13bc689586c8e2db,
+                0xc0379c9f2803fb15,
+                0x34fa72780af7ac69,
+                0x2a1d3633ade9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x772bcb4566fcb1cc,
+                0xd26faec93fc43ec9,
+                0x82e4529ab76c9929,
+                0xad56c4600f84b5a9,
+                0xdaf4c2978f6c711e,
+                0x5072139deac6fe77,
+                0xac739e8843d4ce68,
+                0x1e58930d85f63293,
+                0x9e5ba40e8c5aeb66,
+                0xfa918eb4a1fe987d,
+                0xd8b9f052d96f586f,
+                0x17820bbc660f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4d0952d84d60d545,
+                0x8b9003df5cd100c8,
+                0xf47deac753013bb4,
+                0xbfcf107883d4f992,
+                0x85098e09627fab56,
+                0x26d8c28a1cffe765,
+                0x5547e753c5264b13,
+                0x7695dfeeb0c759c0,
+                0xe50bd05358dba7b7,
+                0x2d86f303f7009ca3,
+                0xddc7133150592dc5,
+                0x9033de6d0331
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4cbe1f67a52559d5,
+                0x31b47321ca63580e,
+                0x53f215ecbe4bb133,
+                0x3151ecd254fbccf2,
+                0xff2c85522e8a0dca,
+                0x8ad99966de073f4c,
+                0x518553010c5d80a,
+                0x2fda1b726f787e5f,
+                0x240bd4ab1a3d153f,
+                0xcaecf08fd48b14bb,
+                0xc9b20226a1cd80bf,
+                0x13f4b79f59c50
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2ea05ededffb7a8c,
+                0xd4cb616c6ab332dc,
+                0x5d7550a2506d509f,
+                0xd3569b024891cad9,
+                0x2fcfa3e54bfcc4ad,
+                0x721048370ba9dd81,
+                0x37b4dc1ece69e881,
+                0x7ccf9cb304712c30,
+                0x468de502d181f459,
+                0xfaf62607f96d69e4,
+                0x6ee46ba316c3bcf9,
+                0x138c2f28215b9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37a9ce041fce6b43,
+                0x2b0189e6323ff86b,
+                0xaef69b47d4ccdd1b,
+                0x56376b3d9a57382d,
+                0x7833a0d1b2dab4f3,
+                0x54d13d8380aa8599,
+                0x966dd435055c49c2,
+                0xe6bfdf009054c3b0,
+                0x641eda6527db3404,
+                0x6b397affc4d1a7dd,
+                0xec22a3913551b10a,
+                0x1572918681c23
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2c7dcc9b9711d2f,
+                0xbb7a91110793b773,
+                0x9f5e7be700aa5496,
+                0x56f96367dbedfde2,
+                0x2d30f8839f1312d4,
+                0x2ab57a92c2d296cd,
+                0xc1c3128397dcfc1c,
+                0xabd2435495c36e13,
+                0xbdbbab204c868aed,
+                0x67e70c88acb7047f,
+                0x61f0f2acbcb22ce5,
+                0x101dc34ee307e
+            ])
+        ),
+    ];
+
+    // This MDS matrix supports fast matrix multiplication
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x837107fd3fffaa0d,
+                0xaba06eba1992325,
+                0x3a58596515979e45,
+                0x3291f55532149a39,
+                0xfbeb83b4f3d88e56,
+                0xa88afac40d9375be,
+                0x8d3dc2d8a26f9911,
+                0x2bafaac6c05e7e84,
+                0x7130a2f80ff5928,
+                0x7ed7b0526132313c,
+                0x35559585f06c1604,
+                0xc3a8de9a511b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7fcabe0fffffe640,
+                0x5899ee2d886277eb,
+                0xd5b350a9b73087d7,
+                0xb8a85c359659eb65,
+                0xc85f98dcea67fecd,
+                0x99bae5d8fb30a26b,
+                0x368b1c051997e34d,
+                0x3aa06fd9d01b01c7,
+                0x50e953b9fa6df2,
+                0xa507aaca4c0ddfb9,
+                0x86007c78afcfc6be,
+                0x14173cf6f69e3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43375748ffffb704,
+                0x6edad0e744e4245c,
+                0x9b0f58f9e30738f1,
+                0x95d30d920abe955,
+                0xbeeaea96309efdda,
+                0xe369cf819009617b,
+                0xc3c2846394cfe695,
+                0xc62b1541bec65559,
+                0x97083ee65a69484,
+                0xb3c0732575140fb3,
+                0x65704a6d0ee4ad40,
+                0x11d7c628cbf5d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43fbe95abfffb853,
+                0x53032614b8df72d9,
+                0xa4e61e505479d7f1,
+                0x4574a287f52fa70a,
+                0x1579027802ffff77,
+                0x5f3668dfbd5e53a3,
+                0xc71bd2dbad9edf2,
+                0x3b305ddd295d31a6,
+                0xfc1a6253805ad30c,
+                0x735d754d461b4751,
+                0x82aae823a9afd394,
+                0x7358c006ceb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf9117b7fffd4fe,
+                0x6907ee31cbf221ec,
+                0x7bae5df27348e69c,
+                0xed59323dd9f3daa3,
+                0xe72187ef1c528259,
+                0x67ac6e075fcf9937,
+                0xab2d283275b293cc,
+                0x51eb2fa126d75c0e,
+                0x5e6e48cfa574fcc2,
+                0xa3a251fe2653c13c,
+                0x1185019a09ae36bd,
+                0x8eced1ac9426
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x51d25a9e3fffab71,
+                0x620eeafd5dce3a2f,
+                0xe238c32cb2116cae,
+                0xae08ddb6572a2773,
+                0x7bd139be25f1b270,
+                0xc73b237aaa4351a2,
+                0x7415017d04eff05d,
+                0x4885237e4028d76e,
+                0xbed3050a8428591f,
+                0x55ee1c11b502d753,
+                0xa566061a23d21b98,
+                0xef5674e79e93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdf04da0dbfff75bf,
+                0x76b2b2b81ea126bb,
+                0x146c953dd1b23232,
+                0x11a7f0a20b7b4060,
+                0xeaedb235affbcbe8,
+                0x48ce9da4cbce3fa9,
+                0x340853c2fe6c1ec7,
+                0x32c58df92f155a21,
+                0xcfb7642192c4087c,
+                0xbe0f433d4bae5fab,
+                0x4586925b158a21b7,
+                0x105ac70d42e2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf608c11d3fffca8d,
+                0x6e29746799804632,
+                0xc50bf64a0b653c0,
+                0x21bd7702a606877d,
+                0xeb2352eda93280ef,
+                0x6a3e28336f9bab3b,
+                0x9a6b63d4aedf1e38,
+                0xe1b0facf2e86f88b,
+                0x6026b478c7f9f3ab,
+                0xae8d63f21f07d3c2,
+                0x98a204d9bb88a3df,
+                0x18a857b105e63
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6902e076ffff6ffc,
+                0x6f60a2d910ae2cac,
+                0xabf8159ed2b98568,
+                0x1c2d9c90fc9e5c9e,
+                0x625d7e8cb5fc9e97,
+                0x42779eaa6f32062e,
+                0xf40101d64f4b6d2c,
+                0x27e3c88dd7dca343,
+                0x12140f04e3dfe9b5,
+                0xbafc6f3dbecbbb03,
+                0x4e621914b0c311cc,
+                0xdb7dc3875a06
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1650050dbfffc1bf,
+                0x24258b5bfbf0db2a,
+                0x1ef077f1ebb31d8a,
+                0x8fb7083f88e43022,
+                0xeb11ae6b87540b16,
+                0x742ab91f6a285657,
+                0x4af8efa9ffeeefbf,
+                0x85bccf08f59b63cb,
+                0x68e1705ea7062edb,
+                0xd6fb1e6bf3e8d7ca,
+                0x8850f1466a99b455,
+                0x1b2846973251a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x12f032a0ffff8164,
+                0xac95c411f0d2a86,
+                0xe7024a4770ae2719,
+                0x7ebbce1487c321d8,
+                0x9a9ba1789efdc72a,
+                0xf09bc489c7e23c6d,
+                0x546a6df6e6b17e15,
+                0x4042846709038c01,
+                0xc343448f16ef7bf8,
+                0xb6e3df77bd9f6bb,
+                0x27fef922cc8f2997,
+                0x1906a648c5102
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5be267373ffffdb5,
+                0xb5c0f81f294f4ae9,
+                0x8db5855b8a37280e,
+                0xcb6efdf564a68807,
+                0xb9567e45b0fd6083,
+                0xe188ae7c9a681906,
+                0x4d7080fb046a3967,
+                0xad38761396ff4c50,
+                0xd04ccc13ffd2b03c,
+                0x26b20abe0d879ab7,
+                0xbf7fe63df262ab,
+                0xe5e682c5725d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa3688a4bfffba3b,
+                0x3526fa0a84369b0e,
+                0x9956d286d20d05f,
+                0x98682bdc39477144,
+                0x6f32ce66a30d7c9,
+                0x7dc6169da849a393,
+                0x431ffc095041f28e,
+                0x76d94e209c220793,
+                0x8aae7dca81cf6e53,
+                0x2725e67bd002a3ce,
+                0x448daabb0a07e6bb,
+                0x2bda90e4fb8a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x46cf07283ffffa59,
+                0x923eaea6a7af66ac,
+                0x14edb0f7e510e7c2,
+                0x5f04f4dcb1e58646,
+                0x5939f48b85a100ab,
+                0x7d83157b8d32537f,
+                0x92d8b5e0f6d263a4,
+                0xd4de7aba1a61449f,
+                0x9e7a262b31b78dcd,
+                0x1c39b8ca76d4994d,
+                0xcca34a8bbc076f15,
+                0x3937505f061
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f84a468ffffa784,
+                0xb595eb7501dc4eb8,
+                0xc4b473295db98fae,
+                0xf9c288a9d99eee54,
+                0x7826f218aecead98,
+                0x53d5576022447b2c,
+                0x9a07571e92d1da48,
+                0xe97549ac00644289,
+                0xe6d569db340b9d88,
+                0x1717159a41ed3076,
+                0x4db62cf65370a89d,
+                0x81dc11276d7b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3654802bfff95f3,
+                0x8274ad5b72aafa14,
+                0x245a775aa8b6e6c2,
+                0xd2556337e1efc4b4,
+                0x2c255d722f7e6641,
+                0x12566ef8b3381b10,
+                0x971f042323da211d,
+                0x8973e6c08d774e1e,
+                0xa31e45e9caa60d9,
+                0x4fac0b01dadbc7ba,
+                0xde90374ff551a000,
+                0xa9b515f304b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc784b40d3fff884d,
+                0x5e8b3c9c20291c48,
+                0xf3fdc816ca0b6ba7,
+                0xeb6e9fe5fe995ee8,
+                0x65f67b48d50ed754,
+                0xdf649a5a3b773e33,
+                0x3aa211d2e44a8ecb,
+                0x7886eb5b890d137d,
+                0x481c281e7c362eda,
+                0x5724d8402fc0a5ff,
+                0xa6d4fa8789ce6f03,
+                0xecf417d963cc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc4009adf3fff8f55,
+                0x37bfa6c205cb1db6,
+                0x2a5fbb1c4734b88,
+                0xda40a707d3fdb5ef,
+                0xcfdedd0a0623881f,
+                0x694c991af1e7c9c5,
+                0xc12d2c096be79b9a,
+                0xa884bde734935f1e,
+                0x453a41e207e017aa,
+                0xea14c543fa0da301,
+                0x19da702734849c78,
+                0x60b41c28c2b4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3792ad817fffc656,
+                0x7443434b8f37fa7,
+                0xda48eef716452504,
+                0xb2b905caf4305ab7,
+                0xb7507c744e7a7045,
+                0x6385b84efdb1cde5,
+                0x49e9128d9875748d,
+                0x33499c07b03db8b8,
+                0x26ffc13013f8a6d5,
+                0x4190a04034695248,
+                0x96f4819cdd97bcad,
+                0x1c0b820d004fb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9765c7c8bfffde4b,
+                0xb4b7c9ae2a7d7a91,
+                0xf40c01c8697790e2,
+                0xb57c62a50f1a49c4,
+                0x1e2cabf1a5a2ed58,
+                0xe1819556c9be1220,
+                0x4960e45430c39980,
+                0xa4be3535c798ca0e,
+                0x52f020a6a5bd22a3,
+                0xc006a861bdbb03f6,
+                0x22fb9681dd212f75,
+                0x303e8b52c90c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2dffb078bffffb0b,
+                0x3de920af85c9f231,
+                0x68dde5dbb34dcef7,
+                0x9cc0690d44704ce3,
+                0x192ccf901ead92b8,
+                0x8cdb29d6db8a4171,
+                0x8644553328128f4a,
+                0x63493715da467114,
+                0x7a5a2398b34c0dc9,
+                0x7c4eeaa20bcec59,
+                0x4ab82d5d5ba71df,
+                0x76bd4eb76ed2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3d0963b1fffffe88,
+                0x841f26210b325ab9,
+                0x90d97383777f59c4,
+                0xff099a62bef1dde4,
+                0x95ae7663974fdf9e,
+                0xf0d8a419bc4ee3fb,
+                0x14d1e05b02645061,
+                0x4358105253a31806,
+                0xe1e1d1a880fa170c,
+                0xc969c1f984f59b5c,
+                0x4d3c4cb122e1e8d9,
+                0x12229116f1c03
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7527bad1bfffcc4f,
+                0xa4768ef112e8c68e,
+                0x8ffacc4b5f504bb8,
+                0xdd71f8cb1b5f9d3e,
+                0x859d6b5df577ad21,
+                0xcb827e39d37ce20,
+                0xfc0e2a6250c6616c,
+                0xedb06f7219688b73,
+                0xdf6e3ae9aae46ddf,
+                0x13495f33119b1302,
+                0xf5636241a6362f3d,
+                0x1bdfac9b8d13
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6130fdebfffade3,
+                0xa6c54561960ed170,
+                0xc4c017ec0e79cac,
+                0x33b8e0c2f5db8e89,
+                0x5866980ab9012443,
+                0x14347f3495840f42,
+                0x95811da995616ffc,
+                0xd2f3e23119adbbb0,
+                0x5c8ab6f60f77a268,
+                0x36421e919a5f09c5,
+                0x19ea7b864a6d0e64,
+                0x5bd2c9831fca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x69e14b3fffff8500,
+                0xd671eb7d88fba2b9,
+                0x10aa8dbad5ea4d38,
+                0x632434ba60d2ccd0,
+                0xb5aad03d12a3b4b1,
+                0xefb867bceb8268c4,
+                0x834b6f794b073b45,
+                0x180ac93aaddcf718,
+                0xa31028d30b00bb29,
+                0x144c6401f678f37d,
+                0x64bf3739072d889b,
+                0x1191014f3b67c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6cbbb6147fff8742,
+                0x5d0b918ed3014b01,
+                0xf61578c114b010d8,
+                0xed5719d22c934fc,
+                0xc60a32c1ef7bfc41,
+                0xc8607bd145f35948,
+                0xcd80a2d79a6a4d52,
+                0x22e6d0d1e93550cd,
+                0x7e4c2bfa39d76ee1,
+                0x75d40770b0e4296d,
+                0xd2c8a6186341ead4,
+                0x876a4e41b844
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5a0cc3eeffffb6dc,
+                0x25c2e54cd3b307e2,
+                0xc8313900af9894d,
+                0xfe5e3660e8babf94,
+                0xea13436fc371054c,
+                0x27b69224206e139,
+                0xbb14c2625e9935f2,
+                0x8686275565a13739,
+                0x3cb41cf56decc28b,
+                0xf4c0ce9726a0929c,
+                0xe009c4f7bb748a1b,
+                0xae6f1fc89fd0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf524144e3fffec31,
+                0x80c78031654dec53,
+                0xd549201c0838f1d1,
+                0x1461838418bf58c5,
+                0x9f4e3325485c09f4,
+                0xaf8a742757e955cc,
+                0x1a270d0cc711133e,
+                0xb5197a14f43e6816,
+                0xc3001b41ec277164,
+                0xb6f3af3a4cc22108,
+                0x635adc060157cbe0,
+                0x13fd196a72dca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9fdc3a8fffffc84,
+                0x86a93a58a38d1c7,
+                0xaec7f4147aceccca,
+                0x5796c820ba17a9a3,
+                0xc26a23c0b07ed950,
+                0x4e6ee1e5e7950710,
+                0xb4399b1c7093686,
+                0x27eedfe96f44469c,
+                0xf7303ae99ee9cf30,
+                0xf654c400f75700e9,
+                0xc791c647a1bb56b2,
+                0x15d9294629f7a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd71d31e7bfff77e7,
+                0xfccc71d246e2e7,
+                0x29fc5325aa6f1074,
+                0xdc99d78375e1b3cf,
+                0x975a81ae5f7631e8,
+                0x2755594cd23f669,
+                0xdeffc906ea920ad0,
+                0x6ddcc7b6ca159374,
+                0xc45bdf3ba2ec085,
+                0x703d8882b981b780,
+                0x100d5dae2e95a04c,
+                0x5ceeda4e620
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xefaf741fbfffa1c7,
+                0x8607722051b0b287,
+                0x64d4fdf3d8c2f070,
+                0x5f880d5e06596a70,
+                0x691d7de8ab966590,
+                0x7c08815a77c23b54,
+                0xf4e6e24babd2f65f,
+                0xe85db241cf11cfd,
+                0x61818a9710b69174,
+                0x63ddcd7cda68ab5e,
+                0xb761148187fa6ac4,
+                0x3f7858dc5053
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb91b4c247fffcd82,
+                0xef0e2598d141b44f,
+                0x1c6f610aecb9562b,
+                0xc50a218c2f20f0ec,
+                0xfa615b0b483880c2,
+                0x4aaaccbe0be334c,
+                0x71dd5b5ed0dd5389,
+                0x82f577e812656c43,
+                0x75fa9e06e4fcffd2,
+                0xb399d490deeb0cab,
+                0x4ed63c262032d690,
+                0xbb2422ea3dc9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe1450135fffff018,
+                0x557ce92f637879eb,
+                0xea3865b5e28ec145,
+                0x6d67ffcb5ab33207,
+                0x2971bb51c8b82983,
+                0xf466174d2dce329f,
+                0x594dda51710d5ba1,
+                0xe436fd03c03d6ba9,
+                0x62ae4098b0b51a48,
+                0xa5f129cf9a79a855,
+                0x5a3b545838686cc9,
+                0x11280d431fc50
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x64eef1a6bfff9403,
+                0x2b4c10ad2a49ff60,
+                0x98f1aa0980d319a,
+                0xb0620e31f90e2554,
+                0x434cde627f445c39,
+                0x6063dff4857f7e46,
+                0x91e79e7a839a5f93,
+                0xddd2d4dd5ab0bc4,
+                0x85de811c367701fa,
+                0xdc16df507476ebd2,
+                0x9b98f3a11de31f6b,
+                0x1517e1470aef1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1c0589dbfffcdff,
+                0x5378e5db72fb2d4c,
+                0xf9e5b9f5afe41b0d,
+                0x8766f043ddf29368,
+                0xb38345635d68297b,
+                0x2393acb5b486041a,
+                0x8cfc59a29a483b89,
+                0x69d8df6aa8f96a67,
+                0xb5c75fd0eb21affd,
+                0x8878b6cd9413f392,
+                0x2fb69d34c4f14463,
+                0x1414a81a31b9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1618279b3fffbec5,
+                0x1a1ddcb77609497e,
+                0xdd004d9e64ae652a,
+                0x4b0a7e66f8cba17a,
+                0xa138b1691aa84c31,
+                0x5da067bb0f457e3e,
+                0x72713fdfb729e45a,
+                0xbc83b43286984c4f,
+                0x7975f9f16b0be875,
+                0x3a0eb93b6a372f3e,
+                0x816fe94b5b817d40,
+                0xd27d80903fde
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x69481294bffff3fb,
+                0xefafedd1231e1e44,
+                0xa419a45ec0e3325b,
+                0xdeee9639ca4220b6,
+                0xb7e6192da48fb037,
+                0x6f9049cfe24c6904,
+                0x3130142f959dc58e,
+                0xf35d9b5ae9b8b906,
+                0x877cc209c2e36160,
+                0xb508472379f26fec,
+                0x10918c1eb3edd6fb,
+                0x25dac4f0bea9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb83e611ffffdc08,
+                0xdf7d8a85d0d697f9,
+                0x37dd9505ebb175ab,
+                0x4feb2c4c188aa6f3,
+                0xcee17ed89fa97f92,
+                0x566d2519a6f37800,
+                0x655a8c1c40058fd6,
+                0x91e47478a39f82cb,
+                0x18fc34860a4e9678,
+                0xa6856da2a7c01e19,
+                0xa4cf977f9229ff8e,
+                0x19c23595c108c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6f53d56c7fffe1a2,
+                0x4f1715b35df73b3f,
+                0x3b024d7ed39c1b3a,
+                0x80868c6ebadd264e,
+                0x43ae40c703598e1f,
+                0xc969618bed33879f,
+                0x2e2f72e1794992f,
+                0x552392d1b9122dfb,
+                0xab2b39b054e10ad3,
+                0x329f05c38a9f95bd,
+                0x66afb2db49e1ea7,
+                0x1c1fc782ba746
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x78a9bdfdbfffd77f,
+                0x492740efeea523da,
+                0x3b36364c33243878,
+                0x5728d3378d9fb161,
+                0x7387c4ea18533161,
+                0x28ff3025085146f0,
+                0xcfda6d1f7a7895a8,
+                0x5437c78ca1ca2b9c,
+                0x88eca1588da9f4c9,
+                0x4b963233691b4296,
+                0x780fe9122f9336b7,
+                0x12ff33dd7e6ce
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf44e018d3fff8e4d,
+                0x2221c6f9e786fb5e,
+                0x89083190cc18f77f,
+                0x2b47652195f93bf1,
+                0xecb5f43f9bf41fb0,
+                0x698d8f0c5570e1ac,
+                0x217f2b9b061840fa,
+                0x6addcecf4e9e6518,
+                0x979200aa0b48e10a,
+                0x30b0ba658de03637,
+                0x7635cc20daa08153,
+                0xae434a1b0950
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5fabece4bfff833b,
+                0xb44305aee0af736e,
+                0x8b5fb9f5a4f4e7a,
+                0x79cfc68f4dae0720,
+                0x5e6d58104afb15da,
+                0x35f9539264d9490e,
+                0x54353a5ec50f1139,
+                0xf412292609189b87,
+                0x7a0e775e24eb79b,
+                0x87a3a2cff136a475,
+                0xd79629684fd79434,
+                0xbacde1b62142
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x219ecca77fffc02e,
+                0xfa95be3c80648417,
+                0xb6b240133c29e987,
+                0x67e1461724df53ed,
+                0x5bb95c571a672f5d,
+                0xf86e5d3d95b5aa2a,
+                0x5a5e36c4407111ba,
+                0x14dcaa7918748fd,
+                0xacd81ab8d2c22ac,
+                0xa905198c87d644f9,
+                0x422d2a8e39558738,
+                0x2cddd321d877
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37c65eefffffedc0,
+                0xd161b22c197cec7,
+                0x9000548239c176a5,
+                0xc87752c01391bfb0,
+                0xb0cef0116306993f,
+                0xc66e17b79ba8aec3,
+                0xd71f7c3f43d90207,
+                0x298d0c2a8710a7c8,
+                0x63a438022cd1ccae,
+                0x74f6857901b553ff,
+                0x8939827854d65da2,
+                0x138153dc143fd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2fae265e7fffad2a,
+                0xd4b6a3b74a8badbc,
+                0xd903407539a85054,
+                0xe0dd9a56f3202d69,
+                0xe00132f9000c86b6,
+                0x23e5e5bbccb87e11,
+                0x6d5d7c63faa47f06,
+                0xc9915cbfd62105c,
+                0xe9a35ab695bc3748,
+                0x42e3c57f6f88e73b,
+                0x70f05247a8866bda,
+                0xdbcb67d221d1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1b994bd5bfff9bdf,
+                0x217f41ec01704aee,
+                0xf21ebe1fbebd9ac8,
+                0x8caeab375d570cdb,
+                0xc87902d5bfccb256,
+                0xac08307b26307e68,
+                0x79a53ceaaa8c7af9,
+                0xdbf8533e267610a9,
+                0xf349896dafe02a0c,
+                0xc9b81ae011c19966,
+                0x6b3dc62e9c6ba0bd,
+                0x86766521fa60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b7a1cd0fffff424,
+                0x87687282a3f0750e,
+                0x9696b84d7f13997,
+                0xa3cd8a2836cc851d,
+                0xcba98ae83adecefb,
+                0x2eb0c57f8a892e1,
+                0xd3aefb0a6d2f6dd0,
+                0x3b00426d2b7ec4b3,
+                0xb2f111fc27350bcd,
+                0x2c01830223f59cf0,
+                0xa61aa1b6f640c7ce,
+                0x1befda4ea9112
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6bd00f87fffa252,
+                0x373a91a5ce3b5be2,
+                0xfc6f2a0df27f98,
+                0x4c24808c958c41f1,
+                0x93247c5b00962547,
+                0x5cde8b7f4071529f,
+                0x7975e476483768ff,
+                0x654962b96c5218e0,
+                0xcf5d0a05072917e9,
+                0x4862f61e916d3140,
+                0x7a2557793d201817,
+                0x429f2b58b308
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xac4dce713fff715d,
+                0xdad3bba59dff94c6,
+                0xcf8dfa31b2324d0b,
+                0x6a04988f83e839ea,
+                0x257fd4d96cb2500d,
+                0x49e2b2e6b2d4e540,
+                0xd64d1edcdbadd9f,
+                0x2cc015d39dc43385,
+                0xadac4ef3a1416053,
+                0x6a26150bffed5152,
+                0x8e0ad9401780ae57,
+                0x10db34a6b1b44
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd5535af3fff9095,
+                0x807f03958f540186,
+                0x7708270084e0c8aa,
+                0x32387ac9938703fb,
+                0x769c163d6f934c88,
+                0x70bfcc1561fbcbd4,
+                0x69b3c131d9d20b9,
+                0xa5ac2d49fdbc501f,
+                0xab1d79a9c5aea774,
+                0xe211e9b66da98bb7,
+                0x450e9bd1d005b59e,
+                0x70a3c77f7547
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1c01b9b3bfffe197,
+                0xee3081b5b209b9d1,
+                0x9bba681e9857b9,
+                0x83e6d45a7847a139,
+                0x8f8c8c693eece9c5,
+                0x1ec16bcb51593df3,
+                0x66e661d428abf569,
+                0xfd3c9e30ba27f8e5,
+                0x994436f8aa5abde1,
+                0x4ae5854f9b73068a,
+                0x54eec9ad70df4830,
+                0x1194e8854817d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8760b00c3fffcd69,
+                0x415f32584a830283,
+                0x2357b5a8c5b0c865,
+                0x9e2ac5010c2a36d3,
+                0xd55a929343fbc56a,
+                0x9815acd04ffca323,
+                0x6c70c21e0efb2522,
+                0xbb2e63345aae396f,
+                0x3604ddab4a28dc96,
+                0xbc3a0db7ede2de7d,
+                0xbb7628bccc0cc099,
+                0x122e50ef19176
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x15fc38e1ffffbd48,
+                0x151a0565331580a8,
+                0x3c083874a12c08fa,
+                0xa8b4397ab0bf5a27,
+                0xfc4c32e7e4526cbe,
+                0xd25b3f08e1d41231,
+                0x862d67fa92c75ea7,
+                0xd7e726c74f16c091,
+                0x81c03ebacd0ec542,
+                0x6b9886a3255e5af8,
+                0x7dff654dd3f561b5,
+                0xcec8a2625d1d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b2f51a57fffde66,
+                0xffa7ef1cd07ea0fd,
+                0x9aaab0a30e80cdd7,
+                0x501bb21c9b437927,
+                0xc10b0991fc21f51e,
+                0xb2ef4da3eb32f57f,
+                0x826fc748355bb722,
+                0x7c80bc355091d7fd,
+                0x5055b2f519c0f6c5,
+                0x27599ddb65e291ff,
+                0xd6a0ca310f0ce0c8,
+                0xf3745c5db98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa9131364ffffd7f4,
+                0x388d387a1354ca58,
+                0x62908154fe4c409f,
+                0x4a85d63d97a77eb7,
+                0x354b5aa0e479a864,
+                0xb4854ec8994bcae4,
+                0x1c704496390bc0ba,
+                0xfb2d65dff356bd54,
+                0xd2fa1b572f0fe15b,
+                0x60a859ed41c6aa12,
+                0xd7dbc9095d3b371c,
+                0x17408836a07e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7faecf56bfffe4c3,
+                0x539616db456eaf15,
+                0x34bb3b7ff3ae2ba7,
+                0x165217494e4da412,
+                0x23731a5bb4121f5b,
+                0xe75bd26cdbf365f,
+                0x4a47441ff5355d9b,
+                0x5603e26e98997609,
+                0x89b2e1d1bfd4abf,
+                0xd691783207350b73,
+                0x828ff87b2843ab33,
+                0x1fd4f02906a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9eda9406bffff183,
+                0xd335fce28d162924,
+                0x71715ba3380af4d1,
+                0xfdfeba67eff959c4,
+                0x61c9fb681ab9591c,
+                0xf40cc52144f1b1c1,
+                0x74dd1ae93d0a783d,
+                0x38fc85c49c6e4372,
+                0xb1759a45ac050565,
+                0x84dab8c16f381dea,
+                0x3b3db5e0f402121d,
+                0x108bd8c3573ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfe5e0e263fffe091,
+                0x75d3d41bb3080c18,
+                0x3484f3bfa43eb2df,
+                0x48ad8560a3759c85,
+                0x2a3b38c726ffcdc3,
+                0x83db1a0e4595a911,
+                0xfadaab1905928a04,
+                0xcf912164a574d9f9,
+                0xa90bc7b386f33827,
+                0x174a911e664f99b,
+                0xd18f45ecf4c0c866,
+                0x537df91b9c97
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd8f2d52fffffbec0,
+                0x90fadf4427e325ee,
+                0xab2ec4f129acaf35,
+                0xa9aa9f17f1cd7c42,
+                0x669dbc844d028d1f,
+                0xe1829aef25852e36,
+                0x715b879f90630e45,
+                0x948f1674fb73a88b,
+                0x9fde6d124c14ae36,
+                0xa22ec4a9a068bf9b,
+                0x30c3189cb11378db,
+                0x3d388334235
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e8d2d58bffff28b,
+                0xe8d3dcaaab5a4b7c,
+                0xeb0f25c4306548da,
+                0xacf7fc4e2dfdd3c1,
+                0x44f2e43284e8c18c,
+                0xf3cbcf2fe16899da,
+                0x148b1b57a2d9d2dd,
+                0x76a374dc82633d79,
+                0x5f1ddb7da89c3c05,
+                0x3e3ec39fdb658ab4,
+                0xdee259e74de62d43,
+                0xb25174e4b51d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc90fee32bfff78b3,
+                0xa8f6cad246c15a88,
+                0x4dc7b527deb4dd06,
+                0xfb14a1b55ffc6f2a,
+                0xbb3fefbf25e0a584,
+                0x30cec61474971e1f,
+                0x71a926737f0f5c7a,
+                0xcc0c51ec2a52e04b,
+                0x86d364b6432f3c2f,
+                0xb221c8bb001e8a7,
+                0xeb9839848b5186bb,
+                0xa275d3f8864f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc080adb53fff71ed,
+                0x1529d89e68b061b0,
+                0x9d86f41522639228,
+                0xf800eb0d1a1936a3,
+                0x8a21c8308f57ced5,
+                0xa6d689d765444c93,
+                0x93098c5890e62653,
+                0xab783b267849d32b,
+                0xf51f5b40b6ab2107,
+                0xe68b323f80c046d7,
+                0xa17bece67714600e,
+                0x14fe97ce565c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed922cdbfffc0bf,
+                0x838c744c5ab68b51,
+                0x5b6ebbb2eb5b863b,
+                0xafbd920aefa98b4b,
+                0xff471a42662dd45c,
+                0x7ce9057107ebb18,
+                0x79d41608a4f7520d,
+                0x8803a92054add631,
+                0xb0f876f20f2dbbd3,
+                0xdd63ce106438ea6b,
+                0x65c0ce5787cc069d,
+                0x117ad3b66fbd7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x166f14c9ffffa7e8,
+                0x6c51b8771cd715e9,
+                0xa91320b1f9dbc6c9,
+                0x953ffad66579d6b8,
+                0xc4213f8bfc19af9,
+                0x629574e654abbd1,
+                0xafb9bc219a5a93e2,
+                0x8919c7adf410dd9,
+                0xe6ac6b499f5c2a78,
+                0xf49630fe060de92f,
+                0x9b367a9ba4090078,
+                0x10f5d1573bf82
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x291ae5ec7fff93a2,
+                0x60720ef03e32e71f,
+                0xa97af24cb8ec0145,
+                0x428488680afeecde,
+                0x6bf51c3ee9b4e17d,
+                0xc554f4809b863a73,
+                0x49a8a80460228cd2,
+                0x6ba05f0b0b1091d,
+                0xa22f3a9a10edfe64,
+                0x268489ddc30542e1,
+                0x7e80566499f33099,
+                0x10d17541f4caa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9fd7038afffff5cc,
+                0xc16600b486f9094d,
+                0xbd384b4d30824c31,
+                0x7ec2b5ef5495a620,
+                0x231104c59c6199f,
+                0x8663afe3cd297c02,
+                0x9614037dabd98aff,
+                0x773ae9367608373a,
+                0x938aef1802b38a52,
+                0xe1642019c9f0fe15,
+                0x5f595b929de56f86,
+                0xe95950a0ac8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x36b016b47fffd5c2,
+                0x9bfd23d9c362c70e,
+                0xe95db212af8bf674,
+                0x3cd430be1f44c0d7,
+                0x13a8a15e99b3c440,
+                0x2a2fd40c4757414,
+                0x6f4d5ed1ff582889,
+                0x702cf0a7420d3c78,
+                0xe33ca7c6c9b6b4d4,
+                0x7eba2b84405672f8,
+                0x6bfb5c58ef53afbe,
+                0x5f7cb3b8fe8f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8bc013003ffff6b9,
+                0x8c13bb0dff3c0539,
+                0x9037669383d36345,
+                0x931ca85e0670f0bb,
+                0x427b9b766d767a49,
+                0xb4b501a5482b80bb,
+                0xfcb320f80d10c7fd,
+                0xdd1f514ed3042351,
+                0x43cd9e018b46ecd0,
+                0x3375357e8df6dcd6,
+                0x4f58cbe9c5ddd95a,
+                0x1a95b556e8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaadf8e05ffff7958,
+                0x567976af59abefff,
+                0xb9ca537c79ed518c,
+                0xc8706ae546bf3b68,
+                0x4939813da83e46ca,
+                0x30a62c5d96616f2f,
+                0x9575e6b87eb0f51e,
+                0xf294a75b1a0bfc8f,
+                0x935c8d79410dbe53,
+                0x9f00a356b39e4ca5,
+                0x51df2008838017b2,
+                0x178003f3039bc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7d4a616d3fffb1cd,
+                0x675c79a4c31d0df7,
+                0xa585cc4d583e72e6,
+                0xba5f496cd59b17b9,
+                0x9f4d7ff3b4c0b676,
+                0x705536d4c475e8e6,
+                0xa21b597b236e9f38,
+                0x1a0a90919f8f87ec,
+                0x1860973919ccd4a6,
+                0x4d2c5f17fac5a0da,
+                0x4132a4414e261856,
+                0x81f72759d544
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x260471a17fffb2d6,
+                0xcb9af283f1026a9f,
+                0xf5e7182a8f991e86,
+                0x233884c94838cc5b,
+                0xc16233524811451d,
+                0x22809d0c29467a9a,
+                0xdb9a7ec32a98f176,
+                0x5faf38cf6e256f80,
+                0x24c0c16a82fbe3b9,
+                0xbe8a0146c282bd91,
+                0xf4f9d86a96ed0129,
+                0xc591f0010849
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1bb10e8affffc1cc,
+                0x224d5187c720d138,
+                0x6de0e811eb79039,
+                0x16b34034ac2a81,
+                0x1d0af7f19e02fbde,
+                0x83ab673196b5f33a,
+                0x1a97ceb7318d82c1,
+                0xed9f35f5c5c773fc,
+                0x383845092abc2cb2,
+                0x2ea7cd809a34c6d8,
+                0x5a12430c8c1e2628,
+                0x198b214ff3010
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb6c85eea3ffff721,
+                0x7d51ec6c58bbb5a9,
+                0xcfa41b0d1bf6f8bd,
+                0x161a006364b0c3b2,
+                0xd245e7a722ee0085,
+                0x30ba7236ac9867cc,
+                0x79aa19619a05600e,
+                0x1c3288b55464a4d8,
+                0xbe844355a8f6db8c,
+                0xf0daae23c0565544,
+                0xdd635a1ad20167ec,
+                0x18dfa04d8cf9f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb4c120357fff76a6,
+                0x699cd687391cc4c8,
+                0xded6a61aab013bba,
+                0xcac20a4b81bf2b1d,
+                0xb1b17de6cce54749,
+                0x4895c67194b64abf,
+                0xffc0942397818e13,
+                0x68b79f2e184bb4e6,
+                0x47aa9d328fc85848,
+                0xc046ccbfea561edc,
+                0xd4b6a1e0a431b978,
+                0x1197213c3e164
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf3c041ecffff9914,
+                0x86f3ae835a226dea,
+                0x1e13655bc8c8f72f,
+                0x6820ee1275604278,
+                0xbea3706e036f77d,
+                0x5762ca9393c3c9d0,
+                0xde835115017ae588,
+                0x8a54365d6cfe962c,
+                0x67a1d8cb63c6a0c5,
+                0xf39e7d7057713d6f,
+                0x5ab5349d68f72c8c,
+                0x1a96aa715c277
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xece9aafd7fffe0c6,
+                0xbd1385e1ef691ea0,
+                0xaafecfb8af50d532,
+                0xc40c2ad9872ec0a5,
+                0x310c2973aadcb717,
+                0x744a2e7fc025c635,
+                0xd3274c276d67cdaa,
+                0x7718763dcec6084a,
+                0x451f0357026307f8,
+                0x1820fcb4db2465c0,
+                0x28b71d2869b55d5d,
+                0x1bf3bc2b56a52
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x130c215a3fff82e1,
+                0xfcd33936200f35c,
+                0x87fa5f7134308349,
+                0x21121300cfcf692c,
+                0x3f881ff9d553a69d,
+                0x7be0ed3bf553a87a,
+                0x40ae45dc0b1403c8,
+                0x24df11d2408517bf,
+                0xbaf8ffc5b4ec9f2b,
+                0xd9e4708fc0b2cb01,
+                0x2b6f7d20541b4521,
+                0xf31f54ccd365
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x492515657fffb566,
+                0x47233d9bfe27d73b,
+                0x4ae38a8c00799240,
+                0x7127c3b010df12c2,
+                0xfd994efbad033159,
+                0x582cc58d8f09186b,
+                0x388ec70a3b3758f,
+                0xd9d9a9f38a862a5b,
+                0xdc05c090c8168a7d,
+                0x2e1dbf3162b58dd3,
+                0x4d8b31eebc1c0e51,
+                0x11bd46c9555c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc6b7123bfffa157,
+                0x1fc478097b272f98,
+                0x6f4c3b98489c9e3e,
+                0xd8ae9a7034fc252,
+                0xe1f4dd16ad15ad9f,
+                0x6ca02f82d0880768,
+                0xa966c3151406a161,
+                0x8f84da8e56892f0a,
+                0xf10b9d778e47df20,
+                0xe6ab9a54cb8bb384,
+                0xa842053904c06ec3,
+                0x12a430415bd60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9017584dffff7178,
+                0x25c3e1144400bb32,
+                0x762ca90c573b8a01,
+                0x4a3e8071011694d,
+                0xc85e3279c33157d3,
+                0x1b506b33d449c89f,
+                0x4673b4e1d252fb41,
+                0x4829cd326bd4174,
+                0xab11e14215453475,
+                0xd1790a85a814df5b,
+                0x41b00cef496c5fa9,
+                0x8c02ef24b2ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70bfffd5bfffebdf,
+                0x515676ce63a93ec0,
+                0xaa991cfda1ce35a,
+                0x8aa39ba73faa8ff9,
+                0x77c74fb01bbdcc6c,
+                0x88d4ef172b310212,
+                0xd5293f5717edc2bb,
+                0x25d42bf070b250ba,
+                0x6c177b5d23841c8c,
+                0xc901377cf8bbc700,
+                0x3848b0d57cb1ea3b,
+                0x75bc0ed3c233
+            ])
+        ),
+    ];
+
+    /// Short Montgomery multiplication with respect to the short Montgomery constant R_2=2^64
+    #[inline]
+    fn dot_product(res: &mut Fr, state: &mut [Fr], mut start_idx_cst: usize) {
+        state.iter().for_each(|&x| {
+            let elem = Self::MDS_CST_SHORT[start_idx_cst].mul_short(x);
+            start_idx_cst += 1;
+            *res += &elem;
+        });
+    }
+}
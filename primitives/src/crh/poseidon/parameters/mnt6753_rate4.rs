@@ -0,0 +1,6781 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonInverseSBox,
+    PoseidonParameters, PoseidonShortParameters,
+};
+
+use algebra::{biginteger::BigInteger768 as BigInteger, field_new, fields::mnt6753::Fr, MulShort};
+
+pub type MNT6InversePoseidonSBoxRate4 = PoseidonInverseSBox<Fr, MNT6753PoseidonParametersRate4>;
+pub type MNT6PoseidonHashRate4 =
+    PoseidonHash<Fr, MNT6753PoseidonParametersRate4, MNT6InversePoseidonSBoxRate4>;
+pub type MNT6BatchPoseidonHashRate4 =
+    PoseidonBatchHash<Fr, MNT6753PoseidonParametersRate4, MNT6InversePoseidonSBoxRate4>;
+
+#[derive(Debug, Clone)]
+/// x^{-1}-POSEIDON-128 parameters of rate 4 for scalar field Fr of MNT6-753, with an MDS matrix
+/// supporting short Montgomery multiplication.
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_short_grain.
+pub struct MNT6753PoseidonParametersRate4;
+
+impl FieldBasedHashParameters for MNT6753PoseidonParametersRate4 {
+    type Fr = Fr;
+    const R: usize = 4; // The rate of the hash function
+}
+
+impl PoseidonShortParameters for MNT6753PoseidonParametersRate4 {
+    const MDS_CST_SHORT: &'static [Fr] = &[
+        // These constants are in Partial Montgomery representation with R = 2^64
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1389c599fb8c64d3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x60f700232e3266d6,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0d39a3dd9dd6155,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9307270c1c93985b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x379c5590338b94fd,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf8c6cc1711fce1a4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x31a7dd96271651c9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaa31dd09ce4c6a23,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xacadd059424055ca,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeaf32b1bbb0ee6f6,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x86cb4ff82372a4ba,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc44bf9a48fb3634d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3f3169bc5fbc72b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2311de38452d9c5,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb943e30c20df70df,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a69291c88d24a71,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ec2ca7d4248f55,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xacef061c896eed90,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdde72258e24dff12,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x712a0399b29a2f2a,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3de9b09130705bb1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcbf26a7cb5c48ed3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8275ca152cc3e286,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1529d9e06cd7b8f4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdf0902600c6173b2,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+    ];
+}
+
+impl PoseidonParameters for MNT6753PoseidonParametersRate4 {
+    const T: usize = 5; // Size of the internal state (in field elements)
+                        // Number of rounds including security margin. Without such, R_f = 3, R_p = 55.
+    const R_F: i32 = 4; // Half number of full rounds (R_f in the Poseidon paper)
+    const R_P: i32 = 60; // Number of partial rounds
+
+    // The zero element of the field
+    const ZERO: Fr = field_new!(
+        Fr,
+        BigInteger([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])
+    );
+
+    // State vector after permutation of zero state vector
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                17834909506601725679,
+                10648317514969166845,
+                2962986061941448995,
+                6462805870073455818,
+                14037694733889850460,
+                8641137163276732781,
+                4789377655842956307,
+                15405952206829104290,
+                12705675300259979969,
+                5314672096224116555,
+                2209132150175623387,
+                353649529116855
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                15994469063210129567,
+                14690456977997197000,
+                6312432462515491092,
+                1774293904074414351,
+                9381304985313226326,
+                14803207925876539548,
+                13784224775764466159,
+                249548126702401800,
+                15207698937308259802,
+                6859761751225779507,
+                16925737885662914434,
+                131148661184998
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                377756090821422553,
+                11616951308465025946,
+                14262736672074193446,
+                2876472729716431192,
+                9194360935364442938,
+                6271057843310913635,
+                7164968581376909064,
+                378655566132977630,
+                9460454313171438502,
+                2540059023692275771,
+                6225936723747837106,
+                371726201967647
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                10937803455719670163,
+                10458334743910280046,
+                5366115915241964404,
+                7195998748057785345,
+                16758611712937498421,
+                10598032004089874950,
+                3002271129173998531,
+                10175369274497614460,
+                3752038860854969562,
+                1820522461148486109,
+                8974919418953473482,
+                341249726345380
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                2729988035240129827,
+                3242934360243388630,
+                13646040037511098510,
+                11917653594210976867,
+                15416930704868910758,
+                11050769645061691969,
+                12679281705869575023,
+                6433462116388570573,
+                15901199039046794260,
+                896145603136964768,
+                15593622559307817782,
+                32976771132214
+            ])
+        ),
+    ];
+
+    // Array of round constants (in Montgomery representation)
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0xffe0cca76b66f7ae,
+                0xcf5c64cbdcee4c70,
+                0x1be9248315acace4,
+                0x752f18e60987bf01,
+                0x34276f391ab83dc9,
+                0x105c461a4194290f,
+                0x9944398a1a95b5d8,
+                0x2fcfa5b0619d2819,
+                0x4d9bbcc14629422e,
+                0xc4e8d97ddf3b2ba6,
+                0x5ba065771504fa56,
+                0x19a079c4a920a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8956255e10d327b8,
+                0x156cfefcb5695b9f,
+                0x37896653ac0cb84e,
+                0x14c16bb71a42a8a6,
+                0xae4497769c94f5dc,
+                0x97d74a976c685cf6,
+                0x64b3dbc27bab10a8,
+                0xdd9b102eb89223d1,
+                0xb31b404e82e21b40,
+                0xcf6c4173e2b2dc67,
+                0x3eadb064425b45a6,
+                0x4a498f1b3b6e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x12e8dbcda042ce46,
+                0x587dadd35259939e,
+                0xb4ac53e815c573dc,
+                0xe16f38514240010b,
+                0xc1a7015e4beafc5b,
+                0x699df159f4273827,
+                0x2c79ba8c0bf3c791,
+                0x589d44cf35b2e592,
+                0x2a456184f7a2e695,
+                0x29a7745e652f278b,
+                0x88ad01e91fa4f57c,
+                0x18dfd5ef390b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x58493f0795111593,
+                0x1185f11c96b89f79,
+                0xa70ecf490f89e537,
+                0x5cda80c1c88d1d5a,
+                0x10f87e1c6d75c9a5,
+                0x5f67b7073918cba6,
+                0x5aefb0d0cad25787,
+                0xb7cc338c18f2544f,
+                0x4ec766ee7bafb2c3,
+                0x31b932c36267d2a2,
+                0xf8bf9f5e380fa0d5,
+                0x8a8e9162bea3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x71b025723a21314,
+                0xa5cce20c3c03398a,
+                0x5ad9e66039022e38,
+                0x7b5e81eae737886b,
+                0xa1d78b41cec8cd33,
+                0x69a6e866b5f9674,
+                0x9f58c383012bd509,
+                0x2ac3c64f7c5b605e,
+                0xc1143e1022527e48,
+                0x7ba6ef79a223f3f6,
+                0x68d95161a42fda5e,
+                0x11f76445bcb9f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x959121723dbea888,
+                0xd63b397fdb4a61f2,
+                0xf7bf9b8c9b85cfe4,
+                0x124d11cf460db8db,
+                0x3c8a9688ae31ff12,
+                0xfc2c2b98489d7d05,
+                0xe238024e936d1bc5,
+                0xbfa673bba2793cff,
+                0x817566fd452826e5,
+                0xcd129a1a1489ceb3,
+                0x69a6b7929c866bd6,
+                0x121762d418c73
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x569949fc065d0ba6,
+                0x6d3f8756108c2e1e,
+                0xb4aac021ff1e99a9,
+                0x3d1606ca106c7dc2,
+                0xbf0469219dbb22b8,
+                0xba44cee210bb839e,
+                0xa35299cdda66f8a3,
+                0xc384087ce76440e7,
+                0xb61423f2a7c731c4,
+                0xa453598e4741cd79,
+                0x14bd901bc10f7800,
+                0xa54bde40fe03
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x394d1bed65f89616,
+                0x6dd9c58b93b523a8,
+                0xa547526b80c023e4,
+                0x8da732da0b095770,
+                0xca478aa45ddc6128,
+                0x788bc754590211ff,
+                0x48e47f0b196d8330,
+                0xbeceeb13fa94922a,
+                0xff6230bb25cd5b6f,
+                0x9e9a4c7b28c8dbeb,
+                0x6dfc1b1598df7142,
+                0x87e48faf3ee5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x15dce98f5e9f7586,
+                0x8450c5be09aae4ae,
+                0x6c1f35601ee693d9,
+                0xbd6dd53b657be41b,
+                0xfbe0ebe207b0c560,
+                0xb84ca0a5fe762158,
+                0x1277ee0ba7f52f01,
+                0xa8eb5d350dfc780d,
+                0x4ea7078de4df5a83,
+                0x77581d8ec665745a,
+                0x7131c4d1745cb44,
+                0xe8f7fb882290
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x304733decaf7c07e,
+                0xda01df0f941f2c37,
+                0xe8c8f71e77fdb591,
+                0x250cb3e6f4c2e427,
+                0x96996a25d7b3320a,
+                0x4a2d787a61cad06d,
+                0x3431b70d65670b4e,
+                0xa25a6b07a460c0d6,
+                0xdf09e3e882fece3,
+                0xa47fb4461c9afa6f,
+                0xbe8e21f03b0d73c0,
+                0xe2f006ecf3c9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd00dbfcbdd3d2ddf,
+                0xc68ca82445b69a28,
+                0x77786b42d269b1a9,
+                0xceea8129204cc478,
+                0x720c69fc4588e9e,
+                0x5f724189f602c0f3,
+                0x7dabf4568d24ee9d,
+                0x7020ca59b123de86,
+                0x2307fcb48f8b0bc0,
+                0x915fe3bd97650bd1,
+                0x3fbf7dde972c3414,
+                0xab2d3d443cc9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc3709675d5f55afa,
+                0x21058fd2658ab6f9,
+                0x5b11540e4a82669,
+                0x9db7ab146c3a7773,
+                0xbc57ae085ed092cc,
+                0x25122dfd0e009161,
+                0x8a6d75a9b20024d4,
+                0x544cee0b9d13e8b7,
+                0xdb5b978450efc5f5,
+                0x6fdd39bc54983e27,
+                0x1d17b9fd7855bbc,
+                0x17a525110a0e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5898187958e104c2,
+                0x35d6d112e58e6407,
+                0x6fc69d1ea14d0edc,
+                0xe18b2c5d19505af0,
+                0xcb7e736e332e51d0,
+                0x629a334fef5bfd99,
+                0x254bb7225034ef38,
+                0xf2b1182f902c55d2,
+                0xdaa15fd60851d1fc,
+                0x304892d51d8b094e,
+                0x8f63a0e2ee200b8,
+                0x267a28d61ae2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa627a8f6ec578fb1,
+                0x61f7e8ba117cc348,
+                0x127c22645f70a112,
+                0xc5f3284c69c2499e,
+                0x4728ee138f14337d,
+                0x374762fef51cc6e6,
+                0xa00bcce21c1f6b61,
+                0x56bc5dd3f28cdb06,
+                0x5cd205f538a2a70c,
+                0xd27a12f2549c6a8f,
+                0x6a770c5d37dab7b9,
+                0x96ffb8663044
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x58d8660f97f7cec9,
+                0x2e4ceac335bdc5f,
+                0xec93120504676107,
+                0xc30fe77735727a9c,
+                0x80d7a5381ac7d89f,
+                0xddd9f11ca1c20e21,
+                0x716acd2b8f35b1c6,
+                0xfc30b29d5be99964,
+                0x105b0f650b85f8a4,
+                0x966f75a5b9989b0d,
+                0xf597865a25db6146,
+                0xa2258e166075
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1d63df50ac9cfac,
+                0xca2de04b99d1374,
+                0xd02c46c085ea9dea,
+                0x8240a76173510b2e,
+                0x7ef4ffa17a4b0491,
+                0x9a3e8b5e88754acc,
+                0x5dd4210d61462593,
+                0x62560a9dec7224c1,
+                0xae4e3fe7d0bb5253,
+                0xd6e825fb4469669c,
+                0xfc64343c5925102,
+                0xc9ef22fdbe7b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc0af695ec9c3854c,
+                0x581dba2499071728,
+                0xd048dc7306700d1d,
+                0x794575bee0ef86c5,
+                0x5b270f555fa37727,
+                0x8fd3bc768658f30d,
+                0xa8e2fec8abca7ff6,
+                0x70ce91f8085cf43c,
+                0x1e6b0671f81a1bd5,
+                0xc763dd2121a5e455,
+                0xf73cecd679df60f3,
+                0xbc0f141db3b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1a651530c2d1f52,
+                0xe607de281ac2729,
+                0x9737f39ef97de23c,
+                0x5be3c7b0ec9b99c1,
+                0xbc866fe0d8855bad,
+                0x8273c3e31c1e9a59,
+                0x9f34dd48bffb6e2b,
+                0x86b8fa8778abdbb1,
+                0xe5392334e3823be5,
+                0xff123126768bb4d5,
+                0xa346b77c105342,
+                0x30c59c5386b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcb1335b267a065f4,
+                0x72361d16db09a5e4,
+                0x61a133aab831d954,
+                0x454e24ffa03f89f6,
+                0x2369477a86380b6,
+                0xc24d74af096a8f43,
+                0xdaf504ccac6c3461,
+                0xf5d4d09980cc25dc,
+                0x520a54c078f7c506,
+                0x60325ae15aea612d,
+                0xa912371d9f2eb8c2,
+                0xb725a02c79fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc1bee709c6d68dda,
+                0x49e1e27e11664d2e,
+                0x41b853a6c33274c2,
+                0x1ae9566543fb715e,
+                0x882da98b994b1810,
+                0x99fad5bf72a78e43,
+                0x9f66407fe5eb1514,
+                0x7bf6add799c74937,
+                0x85c23409c4b1c462,
+                0xc56cbddcb54b5cb1,
+                0x69f923ca24c27fe8,
+                0x14954bfa35f96
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x78c2af9bab86b730,
+                0xeee64038131817eb,
+                0x99819981bd4e4596,
+                0xb8451daca8ef5a9,
+                0xe20daae2a2abd3c4,
+                0xa04dac00c0940486,
+                0xca62136eda388e74,
+                0xde141f06d5f8f5e7,
+                0x5db4be5f1f77d2e0,
+                0xd2be031fc24e5d9d,
+                0x8eb29b833a5cbcd9,
+                0xbfbeb6c9eba2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8d88aab8c60e4a41,
+                0x49e479ea2120a001,
+                0xdea01666e90907d9,
+                0xaeba4eeb9ba58d7b,
+                0xa7ee9b44baf4c721,
+                0xffd40a3e8b303191,
+                0x5f9c4adf3bdac332,
+                0x127a3a8fbfe304ec,
+                0xa5c5683f27cc3972,
+                0x11d50b6f33aee0b8,
+                0x3f1644dbc5edf84a,
+                0x10f11750d0dfb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc5f62b7b56045b57,
+                0x9ff6174185da902,
+                0xe843b1af195a69f3,
+                0x4d76e66907b7e2af,
+                0x868ac0342fb456ee,
+                0xe2d38e69fd1e70ec,
+                0xdbdaa777f518135c,
+                0x546a45c8d0341bb9,
+                0x561fc648d021ecbe,
+                0x65c6f3c54e9d6509,
+                0x1228edf9125894f8,
+                0x14cf4d4714cbb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3fee68ae6937796,
+                0xcf076b0f5f53225,
+                0xa83a191e2328c746,
+                0x705f94362a341dae,
+                0xcda693266d643761,
+                0xac5dd49736142cc1,
+                0x6fc864df71b84847,
+                0x707ef9f094968ca9,
+                0x803b906a0b86e2fa,
+                0x35a53fb5d3ff3f4b,
+                0x99fc2abebfb61680,
+                0x5fd9e2904df4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1accbff4f6e7e163,
+                0xa016b3504650336c,
+                0x104f6447a51b7359,
+                0xa40b8c7aeaf532c8,
+                0x4e3598e92c68ec5,
+                0x2742099585fe0afb,
+                0x4718558468bd614c,
+                0x40587f0cf220139,
+                0xb9ba342dd8f308d7,
+                0x31df715cf224079e,
+                0x286dc6e81a2d4c5e,
+                0xbcd766f45b14
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2f18a80fce3f93ef,
+                0xe00d4335b36a9df1,
+                0x6cc4fb4b815044cd,
+                0xaa30b9f3d7ec223a,
+                0xcec1e132907fb5a7,
+                0xe7d11c51b8ab599c,
+                0x879718067984b5d,
+                0x17973867e464d5ed,
+                0xfd1f84e0e68927ce,
+                0xfc921297625396eb,
+                0x692d8c0269f6dc82,
+                0x18e965520084d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19d17760defa38c3,
+                0xbd3beabd7ae10fbd,
+                0xbb75d97d6b810bbe,
+                0xdbf2679df9c4aa44,
+                0x51f81116e0e88090,
+                0x9f89b851a4d31841,
+                0x6fcf1385497339ea,
+                0x814a7d9cfcc3efad,
+                0x9139777026de6af7,
+                0x6ab879338c7862b,
+                0x7d298dc49a3161f4,
+                0x3484388089c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f06ee8069691db5,
+                0xaa6c184533326ac,
+                0x8cbd38cdd7fcbe97,
+                0x9d1a53854a7bfd8c,
+                0xe86899ed6c01cc82,
+                0xa8c08bbc84ef2423,
+                0xe7974a1ddd289d5f,
+                0x4764cad953bd9038,
+                0xd590ea3a70faf322,
+                0x8f14f27812bb8c04,
+                0x6e60aed57966251d,
+                0x109f58eaaefd7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x915cca41a18bd1c2,
+                0xeb96e39820f4a409,
+                0x69f573af795a12b8,
+                0x6cca542dd6a11e18,
+                0x9346452ebe02335f,
+                0xc23f33103ac30d86,
+                0xbf9412e81ab0732c,
+                0x526066935696438c,
+                0xb9d4a7f25b2229cd,
+                0xa2c91c2cb2b1500e,
+                0x8a2fdbba634e7890,
+                0x2f0f820c3bdf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45c7cb4d63e691a7,
+                0x6733ef599c95669e,
+                0x3a7a212736963638,
+                0x49d696d4a0568017,
+                0x1f05a2239d28845a,
+                0xd436649807c62ee8,
+                0x8978a5ddda973203,
+                0x96ec0395c560f1b1,
+                0x366475bcdfda6c9d,
+                0x44e84138e854b455,
+                0x1c23644ebcab10b9,
+                0x181560ebc8627
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5c34dcf1849eb38a,
+                0x23dd8ee0dbd3f869,
+                0xa7c192d7f2da199d,
+                0x708b143c3cc7d80a,
+                0xde2181c4425500a4,
+                0x1fe3f82e7e484bd4,
+                0xf8d85205d0fc8748,
+                0x484610b731c927a,
+                0xfa9ca4b57fcb40a2,
+                0x73d233decac6bf57,
+                0x82ec0699a410eef0,
+                0x15fd1c55318db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xec69cb0ab6c2cc1d,
+                0x473686a5ce4e475c,
+                0x5a5b323968fd2338,
+                0x3905420e8fcbb164,
+                0xa96406a61cf59d94,
+                0xa32476482fc78c4a,
+                0xb5e45403e38d6027,
+                0xd56425114e4d38a,
+                0x8fd7e8e57045da95,
+                0x9eb8350b48dcbace,
+                0x922012c9c1193471,
+                0x330b61ec301c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1225ba059ffd262f,
+                0xe822529f768d12e0,
+                0xe549985afba679c3,
+                0xec781db1e84db9dd,
+                0x8152937d3efd348d,
+                0x46132513dcc81dca,
+                0x6c16e5fd6911f420,
+                0x365d217e4a5b7e0f,
+                0xe2a9290618bb7f9a,
+                0x85f258b91c922b5f,
+                0x94e81583c7342798,
+                0x5121b96ae714
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x602213fdac7e4b02,
+                0xb6095f5010d69e02,
+                0x4faf7eaa829bc2f1,
+                0x42b58d6c81948ecf,
+                0xb026c3b6d89afd10,
+                0x536c2e09f2ffdb28,
+                0x306432a8f0a6242a,
+                0xf9b3c6732c60b969,
+                0xeb9844f17f07fd55,
+                0x3b19899ea61a6aec,
+                0x486ee837d6dbdf5f,
+                0x3da1d5ba5b4b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xec5050122c335056,
+                0xe4b40d6f5cb65833,
+                0xa3172dede5a1109d,
+                0xc6601b1ee8457574,
+                0x350188898e03245a,
+                0xb3f322a9df15f93f,
+                0xee618271aebe01ba,
+                0xae1dcf6f8e0ef779,
+                0xcf21ba2c5553a280,
+                0x728259a10e1bb499,
+                0xae5bd172d3038461,
+                0x12bc49c321793
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x668cbaf7c972fed4,
+                0x37179ab57c5a6910,
+                0xd604d65ec66df56a,
+                0x346acff9d8078cd,
+                0x27925058f6fb938f,
+                0xcdc3664196f8bdec,
+                0xc38aecae65e79737,
+                0x6c9827e9377d1f31,
+                0xb792ad6d86209413,
+                0x879a320f7f9b2cf3,
+                0x804fb63e82b79d6f,
+                0x1781f7e38c56d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2cb0b28b4e87edbf,
+                0x3ac575541a7dcdc7,
+                0xd784ef213d75ec74,
+                0xf1d9e75892cb7219,
+                0x2780da8785a56235,
+                0x5a4afd690f84db4a,
+                0xd3fa6d71928ff98f,
+                0xce3182a373c0e036,
+                0x3190a67ef81a2ffc,
+                0x7fc1053da51f1d6,
+                0x987cafad74bf3ae2,
+                0xe5845f65dddb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5439018d39887600,
+                0x5374358d6d86bb40,
+                0xd55763d4102dc571,
+                0x1b35977eab478b62,
+                0xac24980a54506786,
+                0xa88c36b30d905ff4,
+                0x7393697edaad757f,
+                0x654e68f71ec7da0,
+                0xe94fbc982feab1eb,
+                0x8e5a6be33a4724e2,
+                0x840c35fc2b4ac559,
+                0x8ac8147993c6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x28350f87351581d8,
+                0xd650a3c888d4faa3,
+                0xd8687d637c591bc9,
+                0x707cadc783a2f4b5,
+                0x61ab85f7f2c5e6c3,
+                0xdbe6af0ff3aad671,
+                0xd9c9a6fe95f6a815,
+                0xd379cf91dba3d914,
+                0x5bc5a9bfe1d03c4,
+                0x54caa18e1f50eb15,
+                0x64ce12b10b778d10,
+                0x30e4167a2b4b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdec6a63105993cf8,
+                0xf0f60159a5053a2f,
+                0xbdadd87010132eff,
+                0x33609cc3e3ebca3d,
+                0x42c2521142cf6323,
+                0x1d2f6016df6bfd5d,
+                0x6da8f90cad2236f4,
+                0xe4565cc18532c885,
+                0xbaef38eeb62a4ab5,
+                0x8c0fbce1045a543e,
+                0xf2d6c723913873c7,
+                0xf86d0b0689e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4764072df376172d,
+                0xd85df50be0514029,
+                0x66cbc76529db3e79,
+                0xece1f1bcb887d935,
+                0x31f62bd3ad111b53,
+                0xdf2b69e17aadfde3,
+                0x93e66d524cefff34,
+                0x1fb0b31a68c514d7,
+                0xbbce17fb9d50512,
+                0x6d6c635079e170b3,
+                0x806872cd08cc7740,
+                0x312dcbcf32bc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf32291bbc4ea1a30,
+                0xfdcd2b8bca8dde76,
+                0x1d5159bea8b8a89e,
+                0x569f1b9559162082,
+                0x352278f515f3e39a,
+                0x942f800a0bef38ba,
+                0x6620bbbf092e234b,
+                0x7159fa77a7cd287c,
+                0xe1e90f75940950bc,
+                0x5f931143ad87ccb6,
+                0x2e73ba760ae3fd48,
+                0x14b2f03bbe2db
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x29da74f65d821d3,
+                0xc4ecd31e60375dfa,
+                0x51c85bc11868674e,
+                0x995491276d07e663,
+                0x622544b69c859a7,
+                0x25877d7f376252b2,
+                0xe28dd8ce7b891b5f,
+                0x31c4df47dd440776,
+                0xcb099c06801a69af,
+                0x646b396f5a1179ae,
+                0x634abd179fc1b2c1,
+                0x19a2a050adf92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2874f62fdd17a06,
+                0xc9fbded25bab125a,
+                0x35fd44ceca735233,
+                0xe60251ff44635e7,
+                0x40035fa775f86964,
+                0xb5b6598f30834741,
+                0x9abd545b12fe98f5,
+                0x23b2041d2f9cffe,
+                0x1e94aa8e8d36025e,
+                0x6318c44883a15be0,
+                0xe10bccb963a6f06b,
+                0x13f99b3f589e4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x92f4a3c3d13169e3,
+                0x1d8a7a958c944494,
+                0x17811359a78ba387,
+                0x1d7ded7f327dba21,
+                0x4886d74d403a31ff,
+                0x2a61190759d5d00c,
+                0x8c79b10045f90904,
+                0x42c11d59525e2550,
+                0xcd6a7a479045efe7,
+                0xbb25e637bd1e2dc8,
+                0xdb4d96fc7be4debc,
+                0x164e49b5aa765
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa9676de78f6d5ee,
+                0x15e48e0ca8447409,
+                0xde9ea97bb18904f8,
+                0x317832db8ea25a04,
+                0x192eed57e6175fb,
+                0x3da074088157bc33,
+                0x47239da6e80e2a73,
+                0xb4189ad38fcd17c4,
+                0xb524c686dc75d3d1,
+                0x871cb7d5edf0b02e,
+                0xa1209a8b0d4ee119,
+                0x6fb9ae73b40a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x339af6fc4d27ebc7,
+                0xd26be831a879439c,
+                0xf31d9c047d6788d,
+                0x52372ae58c0f02d9,
+                0x9a5ed5589c4e58d5,
+                0x847443ee6ff8c89b,
+                0x3651327fefedaabb,
+                0x7c84af1b7170fdd7,
+                0x2ac57807acf05138,
+                0xb809eca5d54a48f7,
+                0xa963cff8cbc333e2,
+                0xa06b43484228
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x177267bd9bd90005,
+                0xe04837e173817f93,
+                0x7bd7955fc6dcd4a5,
+                0x78606ac04a2fdb1e,
+                0x1dea8e1edf3810c7,
+                0x548341bfeed8222a,
+                0xaa8fbfe1b598b223,
+                0xaabd5ee0eb3ebd7a,
+                0xa9c931d4141735eb,
+                0xf17e78e18323aef,
+                0x226df173e50a7fc7,
+                0x895ef3f1043a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbf2d44d994de2bfb,
+                0x3c6fe5032470a7c,
+                0x7b16dbed1c549f35,
+                0x2eaa892700bb4556,
+                0xe4f492fac2fde932,
+                0xab33c79b4fb3b98a,
+                0x143f638db6d63ea8,
+                0xc06d1cc3b6ee8736,
+                0xff6090c729c87f1a,
+                0x45bb294b8286ed99,
+                0xc60a6e4bb609da0d,
+                0x143f17a7b1cfe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10e0f990ab091c68,
+                0xbdcb4caed9d6ad73,
+                0x6638fe1da8d3136c,
+                0x7178cd27d69bd78f,
+                0x4e9004bf9ff4022c,
+                0xb09de828b15dd2a8,
+                0x12acda3529e0668a,
+                0xa98cee9977de53,
+                0x7f8cf52f354a34b5,
+                0xd3d1982a18c1dcae,
+                0xc9ba77142ecdf1f4,
+                0xcd98a9728dd3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x815f79539f5889ae,
+                0xd2003c085b6615cc,
+                0xf888c84d3f1c042a,
+                0xdd2d2299a3bfb341,
+                0x942df0f49822158f,
+                0x7adb317ccd00c787,
+                0xd0e8309e2367c703,
+                0xf2db13600866b71c,
+                0x8be1f1fabeef3467,
+                0x6ac2fca833978d1e,
+                0xb0e0e70209ca7db5,
+                0xfea83c2242c6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa97f6858d7fd4e35,
+                0x6d4ff5e5fb2575a6,
+                0x9e0d5de8f4d5c177,
+                0xd1bc2a85622e0e40,
+                0x64e9ee2b1e3ca76d,
+                0xc47109c5e814acc4,
+                0x643209c853310374,
+                0x823bf7bb9657dbf3,
+                0x4ad2e8713b5e6a26,
+                0x46d98fbf22a35305,
+                0xd3b644575fbe3e8e,
+                0xbc4ad2cf8558
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f761bff570a48d1,
+                0x9747ef86990fbaa0,
+                0x7393ed0e170b9e5e,
+                0x9187c603d8fbd892,
+                0xbd2cf88d4bc81969,
+                0x54535b8749697430,
+                0x3fe9e46d7583ee23,
+                0xa36c31250fee2650,
+                0x1d4fbf2206c7fd4e,
+                0xe7f19ee486de3da8,
+                0x8b7c50b7ea195d5,
+                0x1714e14a05f5b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7587ce953dcafd60,
+                0xe3ea5a69da3d0ef9,
+                0xe77789b500efa61f,
+                0xe2cfb7ab7e30ff5a,
+                0xfe7cbff87f387c13,
+                0xc174a9c1f8c62b8a,
+                0xd5c23dec86a63597,
+                0x49e47f7414d56458,
+                0xa1b112ab893df314,
+                0x6a9c64ec5cfd3fb,
+                0x51fb7ed89fb753a2,
+                0x12716b0fffebe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6aff3152bb7b472c,
+                0xcd663e9de838a56d,
+                0x461bd49ea80619d5,
+                0x9404e3f397be0226,
+                0xf0eb074b5583752d,
+                0x302aa55f1f2b0a7,
+                0x61b151bb19937d1d,
+                0x8228532db953a70,
+                0x4576815930eb3ee0,
+                0xe5e3e32cfe76ed74,
+                0xdec3a0f5de769d0d,
+                0xe5ad89b1b0b0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e0dcebb0048a0d2,
+                0xd62219c95fd02406,
+                0x37e772b0786a4193,
+                0xf74c172800df4a3a,
+                0x74e297eb0b8531c8,
+                0x876819cf9dc66eac,
+                0x2c81ce0d2db0e460,
+                0xb30860276ef329c2,
+                0x91c9fd5142f4307e,
+                0xbddd8c29e956f9b,
+                0x31fbfdaada289bb4,
+                0x97dde83c7ccc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x523039ae07640524,
+                0xe00304ea025af6ae,
+                0x3276ae174cc54f55,
+                0xed8d3ab59927cb72,
+                0x604ebc89f1cd3d3c,
+                0x59b5ae17cfedc1a3,
+                0xb2fe8c59dece7567,
+                0xa9d4a72f9aadee55,
+                0x5ebbd85ed2fa6db4,
+                0xfafc2bcac8c9a67a,
+                0x35b80914ee7f39b0,
+                0x1c1d453d9461d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3f53682d26e10641,
+                0xdd95ca08885a1b42,
+                0xeb124adee1c5cff4,
+                0x67d31ad532a12dc9,
+                0x33626b4987915c23,
+                0xe13696d598afa8db,
+                0x944f1bf814a4eff2,
+                0xdf0ec2526961ffff,
+                0xbb08a349c3973b79,
+                0x9100424039c0fe2c,
+                0x46ba26c4f19ea571,
+                0x8ecc17d3abcb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2473418b7176b0ca,
+                0x15d3e74c43450026,
+                0xfba683e9a921df77,
+                0xb8847cb5470af729,
+                0x6bd4ed74a008101a,
+                0xd4e034453b61d846,
+                0xdefe9ead521f3017,
+                0xdcb27f2b54ba3b53,
+                0xa96291bfcdd18ad4,
+                0xee0170f89f631e35,
+                0x18fe5b9fb8fd0bd9,
+                0x6a38f217460b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbd019cb9d7c6887f,
+                0xce87dd26126b609b,
+                0xb42d46220b871d7a,
+                0x9a912963559ec94,
+                0x2debcee5f770c272,
+                0x7a70f5c1fc47af21,
+                0x994c706591f50e68,
+                0x9bad0861bc81d78,
+                0x691706b418e83fc5,
+                0xe10c1fc71a3dd276,
+                0xd76b0f5a830b4cb2,
+                0x18e5b8d7fcddf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc87a62128470e3be,
+                0xf6f0c596c4c5bf7f,
+                0x103168e2d7ff0e82,
+                0x905ce45d25bf7e23,
+                0xa4365d113ec3aadf,
+                0xc7015b6edbda0497,
+                0x6740d36a4bf9fd72,
+                0xcb8c5740984773f0,
+                0x99bd284ba57559a4,
+                0x76d829499b8f713c,
+                0xba73967cdfaba8da,
+                0xe9428470601e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6d2c6eba99e1dfbb,
+                0x9679f9b0044704ee,
+                0xb6ea3bc2f6ad18b0,
+                0x486b228a4f36377f,
+                0xd9e28663a82ddc3e,
+                0x5d8e085c516c753c,
+                0x6b7ea4f82730014c,
+                0x36a61ba9ad22eca0,
+                0x2ec948479616ac38,
+                0xc38a98c3fe70934c,
+                0x6c014efbf70d5b23,
+                0x2a1a45067f2d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x798c1ba6254e0321,
+                0xf9534d38a8cfc11d,
+                0xab8592dbc2b30fe9,
+                0x7f11b842825b0a1b,
+                0x7e6eb6db0cb0ace4,
+                0xc26e74f8013de85e,
+                0x313fa70b6504352,
+                0x7f4aac2c39763972,
+                0xe0a725f555015262,
+                0xe7e05843ef344f5e,
+                0x1c31c2178d081f8,
+                0x19f2a73d3481b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae36a0db392831fc,
+                0x33e49c1e38aed5ac,
+                0x7dd5132aeade2160,
+                0xa8e14447e02970c8,
+                0x4b584fa1ee012a20,
+                0xb8da7a5a5bf3a44f,
+                0xb8f25291df09ca30,
+                0x6b99ff4295e5f58d,
+                0x7a7881ec7a6cd493,
+                0x74e78ec30221c0a5,
+                0x9a3274f7b0ece50f,
+                0x110cb401da7ac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe150fdedf780756b,
+                0x30bb9fb9c447d8cd,
+                0xba9121cc23fe59be,
+                0xd9e9de23eaa55cbb,
+                0xebb55af3d220fedc,
+                0x37c9f89f5602c072,
+                0xf6c22945fd73f20,
+                0xa0f4a35d6b1514a0,
+                0x9b080a22bab928c2,
+                0x528547f75d12fb11,
+                0x8f063a0f5dbbdeb1,
+                0x10ff6937bfaec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcdc82f98b7b4489d,
+                0xc48b47f2b96b8b4,
+                0x441f0fe14f7205b5,
+                0x3b80b96baa604361,
+                0x484eb87c65d26656,
+                0xbbd77099a5b8b671,
+                0x12b8385475c3a6a9,
+                0xd3f05296a914e7f3,
+                0xb1c40048a65174e1,
+                0x7773014604482b47,
+                0x916aad5ef9997e43,
+                0x183a8a59d5b0c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x186416e390746ab5,
+                0x75edb6071f9da30c,
+                0x76ae1ab5a4933cb3,
+                0x8c230016d0426b85,
+                0xebedbf7bc78a4afc,
+                0x281c4c6e44721438,
+                0xdee26b24a4660f48,
+                0x937679ac31c4930c,
+                0x983d14b0708d2dea,
+                0xbf91d6478e759983,
+                0xe625a7a6a9af40a3,
+                0x18acfb0fea8c4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a9515afc77b5f8,
+                0x7eda7a655f888b42,
+                0xce8889f272de10ab,
+                0x75109489dcfba29c,
+                0x7e461147b06aba3,
+                0x357d414e7b063fa,
+                0x4dbfe39f4a2b01bc,
+                0xc77298bcf75a531b,
+                0x3dbb320e45d66e57,
+                0x7577ca64066b7e76,
+                0x5f272159f38080e,
+                0x7fec5ff8260d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd65dfd342b2c28dd,
+                0x501e8ba903e2c839,
+                0x264858a70b07a985,
+                0xb2eee6e53c433830,
+                0x5cea8480b41c609c,
+                0x11d01771207b6446,
+                0xa25e50ba083181e9,
+                0x7682a1c7a683e39c,
+                0xd9b9efa87b84b7a5,
+                0xd4f4ed02006c42aa,
+                0x3befe76900d741e7,
+                0xc0b9a0c5f152
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x73354221bb7a5430,
+                0x39bbca8a2c8a6c14,
+                0x5715db33c5aae87c,
+                0xd26a29b944241d50,
+                0xf5b6529314744e63,
+                0x6c071e5af1ba2149,
+                0x1da11e256ae46a35,
+                0x9790f0347a9439b4,
+                0x4ea84ac8f4fd6399,
+                0xb090f1fa35b41a8a,
+                0xd053e70901a3425e,
+                0x14df4d9c2a5c9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd4095a316ca13e63,
+                0xbc793c6f842261c2,
+                0xabe989d2b86a0db0,
+                0x9e6698d1ae85bb85,
+                0x8620972202bfada,
+                0x32f060953a733c67,
+                0x3880a6bbfe1ad37f,
+                0x7c5b9697ce05422c,
+                0x49f718e8cddf3bcd,
+                0x43965bcbe92edcf2,
+                0x22d60ca3fd04ecd8,
+                0x1042e42a75e0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x215955c0d51001ac,
+                0xe44de93154ed2565,
+                0x5ecaf5cdca7c8803,
+                0x6714c7bfcc50566f,
+                0xa74feef4fd3cbc47,
+                0x478e66cd09ff70e8,
+                0x28a9f56619a58160,
+                0xabf488925c341f5b,
+                0x9a44efdd9ce9e328,
+                0x8a7e60cb6a37e80d,
+                0x6c50e7f052870e04,
+                0x194b0ed76a3e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83ce9df88854c736,
+                0xb07e5ea6bc0e4bbc,
+                0x63ee047f7fa74840,
+                0xe95bf0d6e0cde3e6,
+                0x782f3893f2ff8b50,
+                0x4fca41008f0eedc9,
+                0xaf556bbc39b39297,
+                0x6473d9db0a5c8683,
+                0x3f31752082de4638,
+                0xeefa78b6d7d7168,
+                0xe5467bbea261c69f,
+                0x9edf43760697
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc062ff96a6ffa09,
+                0x19f930d7a3868f6f,
+                0x98b9e495edbbb414,
+                0x5eb5f7f6795b7b1d,
+                0xa9c426479b7881c3,
+                0xb19b4e9b8d33eb58,
+                0x89381b0ce7d50a22,
+                0xf9cac53dbf9711c0,
+                0x69647802ef2cc838,
+                0x356460f94f1fbc01,
+                0xf53c0a1bd47231,
+                0x12a9e1bfe88f1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa269df3f194ec3eb,
+                0xf979ca8cc9c44048,
+                0x49b7a54db7f85cf8,
+                0x933227f1a83e10c,
+                0x39bd30c4a2bfb0b2,
+                0xbb86438eda05d737,
+                0xe3e96119f3645b82,
+                0xd8a41468ad18c9c0,
+                0x805f4a7e208678e0,
+                0xc1a06fce76c87d05,
+                0x55ece719c2c5d4ea,
+                0x86be917fac78
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed85d445138d02ba,
+                0xf89b5b44a844cb08,
+                0xaed6fb8ef1779739,
+                0xd87af1e0d5f81d5d,
+                0x1a4761658c05efbe,
+                0xaab8eb3bf1b2ecf1,
+                0xffc84da5b2fa3826,
+                0xcb6cc3e2f8fff4ab,
+                0x853dec57d1130181,
+                0xae495d2e78094864,
+                0xdf9c9c8bc5e408ca,
+                0xe1ee04c10184
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf9e667d2afd3e3ae,
+                0x844486a5dfc98b2d,
+                0x8410c47371f5f2d0,
+                0x7b108a07cd262728,
+                0xf912f1c10a0c7d84,
+                0x29dafe75c1cc1efd,
+                0x2251c7e2cd8442d6,
+                0xfc880bd85a764082,
+                0xdee7b1556a693139,
+                0x100bb3c634639355,
+                0x7cacf1ac58f785e,
+                0x7558834fbec2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f72b18d23f38266,
+                0x63cdf8b0e1c58540,
+                0xaa7a133ffa41da3f,
+                0xcd62c2d0fd097f7f,
+                0x8404344701d7c810,
+                0x92f4092af725cb02,
+                0xec34e52255d75e7a,
+                0xc57292b1dec4a3bc,
+                0x3eaa0d14bf14fb9d,
+                0x6cb7d7ebf4331f18,
+                0x80e696b1c5aeaae3,
+                0xa3240e026020
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e240956ac554caf,
+                0xec61ee865655f4ed,
+                0x71ad24096a2a63d8,
+                0x497841ebca33a730,
+                0xd7dd60e8f2ef33f,
+                0x1514bb0e74280822,
+                0x7ab990e39df01788,
+                0xefaa55d971e361e4,
+                0x810130015047d027,
+                0xdfb30898340522d0,
+                0xb76f3fecbb40ffe0,
+                0xcd491511d507
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4e108b02e51f4fb7,
+                0xcb09390d3202fe41,
+                0x5d2ef631a1e73edd,
+                0x4564c81cd73a,
+                0x825cf36de393aa91,
+                0x94751593ca4608aa,
+                0xc47bc184c973c565,
+                0xa986cf0f543968b9,
+                0x40da39ad8f4a479f,
+                0xddf397f198ecef4a,
+                0x1ef1fba89259efc1,
+                0x15970274ef036
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9912e6c8672a2377,
+                0x831c39d72a2f4f39,
+                0xbb4fd4e9556165b7,
+                0x3b2f899f326357ae,
+                0xc464b88100100bae,
+                0xa52b4c925172a7b4,
+                0xc192d1d00d15da1a,
+                0x8d04d0b047a672e6,
+                0x71ee336a29458455,
+                0x5e76ee9a95d2bd15,
+                0x1fe781cdc18f508a,
+                0x12be568606bbb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x778614f63ba95a8,
+                0x51226e9cc16d1a7b,
+                0xef83d0112a2355e8,
+                0x3ae3c8a8d28e5667,
+                0xb51cd601814a7ac,
+                0xb19e7408e0d7aa81,
+                0x265b14d4d888c668,
+                0xdc545564306ccc09,
+                0x1ac8e48b67b86e78,
+                0x25ea37be869818d0,
+                0x17717a64cba55644,
+                0x143fd5b4ff412
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x915731f5a5dae5d,
+                0x7042c114d3eb4d9a,
+                0xbe3382ff8bf2c5a1,
+                0x6efc602a6785db95,
+                0xd18c8e535eb4329c,
+                0x73188020abfdc389,
+                0xb399545dd563c42f,
+                0x7de5bab9232042f6,
+                0xd3f42c8d0f233cbe,
+                0xb9ef9c37e871596c,
+                0x54c72b404166b02f,
+                0x13ff29e05464f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x453537bfee38b610,
+                0xa9f9dd66f42d553c,
+                0x1ed64267c45f4d2e,
+                0xae2ed872f10ff62d,
+                0x8a7e00de0baa65bd,
+                0x86b577040e42a0d8,
+                0xf2bf6e7c0be130ad,
+                0x3e68e433a2ba2b75,
+                0xcf795b32206e150e,
+                0xfb5d1aa2b0025e25,
+                0x1acc7cee4f89894,
+                0x176988f14ef4e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd254544f146586fc,
+                0xe613bf5159eab4c8,
+                0x639b7ccb348c2341,
+                0x7aa6d87f5d8eda9,
+                0xfa486d6b7052c521,
+                0x305d093674833db5,
+                0x2dd7b7db8bd01220,
+                0x23d45f283c3c7887,
+                0x947d2fc823f3723f,
+                0xa681f5eae818f2cd,
+                0x4cfc669fa088f2a4,
+                0xe4e66f74975
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe93e202b44a9c37d,
+                0x2b918dd9e3a60b78,
+                0xe0c50dddb2d2d450,
+                0x94c1d37afd680681,
+                0x998e3a753c03e5b8,
+                0xc62fcd5fd43af055,
+                0xbbf518a986d56053,
+                0x610caa5b48ca63a2,
+                0xa008324527d01afd,
+                0xe84cc36c651091b7,
+                0x82313e770fade4dc,
+                0x257eedff97d2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x616881d79d8a497f,
+                0x86007bf060bf22c1,
+                0xb079fd296a209c5e,
+                0x3e554f7f14fe40a4,
+                0xe9969926cc4eeee6,
+                0x48ed3090328df26e,
+                0xe0781968be16ed51,
+                0xdcec9d226777a8fd,
+                0x27fd7b302097520b,
+                0xc60def2c65901ee9,
+                0x69376f3f57e072d8,
+                0x157d4c229f2c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44b21250630f3f1,
+                0xec752e76f5263289,
+                0xd6c710175be645cf,
+                0x85fbde4bc9207ecd,
+                0x741c6a455543d3bf,
+                0x9f9f9bb9d90960b2,
+                0x3420d01544737db4,
+                0x6306624f4f1c6a8b,
+                0xf505af92d569606a,
+                0xea5ed0190f9ca6cb,
+                0x58f45b5a4ef4936,
+                0x552c272054c7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd080efb089b27a1d,
+                0x20d3ab606145c51e,
+                0xb3610a0be4c974c1,
+                0xab5775ea088957,
+                0x7fa53c859c6e6352,
+                0x5abf5508015484d,
+                0x76a3bc18a7767a0,
+                0xbd6e3aa4b5e54b4a,
+                0xa59d35769675d798,
+                0xdba3b6f83e17c363,
+                0x4a3dd4f43308a24c,
+                0x7845afc5045b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x57649e31e6f8e1ac,
+                0xaec1102d50e75b97,
+                0xb4877f3dd2accd9c,
+                0x74e2a65417f6bc6b,
+                0x857b6b0eca35d71e,
+                0xd042d4d0940dd4f8,
+                0x23d1dd691bb9b6f5,
+                0xdb04927978e4e0cb,
+                0xefc94129544fc00e,
+                0xa627737c4ab05b50,
+                0xe7f22ff89546c0e7,
+                0x439ebab8121f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf96a3b9dde107735,
+                0xd3bb5e98aa551db7,
+                0xf94b3502e0416e75,
+                0x69a70ab153c2e9a6,
+                0xd2996340556de105,
+                0xf3d643a1a35c546c,
+                0xa4bd3d2e9d9c24d8,
+                0x6b3083fa972f0a5d,
+                0xb2bdf446c722aa8b,
+                0x4d9089fce20e864c,
+                0xf9d6c2a02450d9f1,
+                0xc660d23f4e15
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe17c1a7136e5a492,
+                0xd4e4b2bc4a774e1a,
+                0x23790f3d013002db,
+                0x29d48c5f2306a8e7,
+                0x73ef6a53317e936e,
+                0x4871f60820553429,
+                0x57781f78ae10e298,
+                0xc368fb3492815cac,
+                0x8d3cfa852db8637f,
+                0x30ea55538326951,
+                0x93a1c5b6d1e4366f,
+                0x1585729499498
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x302412eb0981c33e,
+                0xca7147b8eef95c8d,
+                0x3de98ec3db62794b,
+                0x3a9903fec3eaed09,
+                0x3a0bd507da2d1991,
+                0xa93133b1c64b08e6,
+                0x75df82e6aeae09cb,
+                0x42cf8c72d7c1681c,
+                0x9847bd477fdd763f,
+                0xad2697d78c38b5da,
+                0x775c4b57415227c0,
+                0x6bd61dedf61
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeec534bfeafb6a2b,
+                0x43a23249140777de,
+                0x7adff2b880b53bd9,
+                0xdcbb4df6f5305e13,
+                0x368a8ee0b6b34f81,
+                0x3f220ee915e8efda,
+                0x98b2da44c64cf389,
+                0x34b08250d56eecb5,
+                0x108e6807d0310fd9,
+                0xe6958b049124ed79,
+                0x3522e7bbaa0c0e24,
+                0x13e50c0519a50
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe6c69f65761fcc8d,
+                0x8ef37cddde059d1c,
+                0xe93627dc9f2d185c,
+                0xba3fcb38290f1ae9,
+                0x26e3eed05c247359,
+                0xa32152c9203f1696,
+                0xcc47030de505966b,
+                0x8fc273d35de29b5c,
+                0xb58a7e9321dd0d45,
+                0x3ecb8febc1878c4c,
+                0x37e8595c6eb10b83,
+                0x3db5accf6902
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x50c872baf5278679,
+                0x344295e1d9ca2e4d,
+                0x51f57d142591ef5f,
+                0xd707bdef067f095b,
+                0xfe9499eac9a7b1ef,
+                0xd7aed01748f3d469,
+                0x9b4cf2d9cb135a52,
+                0x1364d1193529e19e,
+                0xbb256a5ba90684c8,
+                0x6b32e93219fba5ad,
+                0xd57cc194d8c1a6f1,
+                0x17cd40bea067b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x441e300951015028,
+                0x48c0108414cc7599,
+                0x49f08cecffa3fe9f,
+                0xa42df2ad85b3b002,
+                0x91038c3f6a918936,
+                0x2d81a8387423a54b,
+                0xec07b6c1cd7d0608,
+                0xc08bac63494e2268,
+                0xa01afdee3ba6d2b6,
+                0xdc9301b8cf1b2d18,
+                0xaa1cd229d7a6ceab,
+                0xf28982832120
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3a0d46129e5c002b,
+                0x5bea7d354625ec20,
+                0xd79275515016361,
+                0x50c7069c459af037,
+                0x50f51cc3ae289ba4,
+                0x78e1036c638699d7,
+                0x838618a354220216,
+                0x80c9126e73b803e5,
+                0xa5f4460ac6b9e5d9,
+                0xc0ada7911023261f,
+                0x376a765ca9c30da9,
+                0xa18b2b9465ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd19cc9841b939031,
+                0x94014d80a0e9b794,
+                0xa7fe6d3006c3e97d,
+                0x9f8ac6ba8e5a6d46,
+                0xd82d98ca5c44a9c8,
+                0x751f59bfa1eb8f20,
+                0x703d3a065762c91f,
+                0x963b7e00d6169ff6,
+                0x4a325a46c178111e,
+                0x99279e84898dbb95,
+                0x6563bcaec15d795,
+                0x352a7bbaec3c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2f0841a190597de8,
+                0xeeb054cc81b3c694,
+                0x8696b3cdf62c1ccc,
+                0x77d09c97fa987ac3,
+                0x9f0a1aa887d79c72,
+                0x30a67ae603964693,
+                0x973b2620ae288479,
+                0xaaa72c385bd11d63,
+                0x3ee6454bfae9b74e,
+                0x6669295adda04e37,
+                0x9b68e922832c1d0d,
+                0xdebf11b78492
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x67333352533614e3,
+                0xad4b4c131688817b,
+                0xb4a6e79eef5d00d0,
+                0x7a19c639b22773d6,
+                0x19996ab2f29e24bf,
+                0x45b8ed2201b84f6e,
+                0x5d325203aa58bf8a,
+                0x3951fad37a6d49df,
+                0xcb0b738c67949146,
+                0x1de090670dd5ef07,
+                0x3aac9a685fcd184f,
+                0x5cd4abde2c47
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc8eb0cf5c2038101,
+                0xcec091c666381963,
+                0xbd64023ba6b8d6e0,
+                0xbfbb4f918823fc8f,
+                0x1b8cc0bca3975a03,
+                0x114ce4cf7bfba438,
+                0x1b2a5f388182a417,
+                0x449cab6300e155a7,
+                0x5fd43a4d8b41a4e3,
+                0x51765cc0c72663e9,
+                0xad48e929908c080b,
+                0x10084b75c4196
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb78bab6e3a069d6f,
+                0xbb44d8dc77dbb22c,
+                0xfcbba79ac7bf0a4a,
+                0x3ce16f14923efab,
+                0xb0a62e256d363ed3,
+                0x5ab5900b57476d9a,
+                0x1eaa100e1d428a4e,
+                0xa27b15b64d6facd8,
+                0x8daedb6e670e8e58,
+                0xf2c6ad4890a44a44,
+                0xf8aa6d5b8e235c43,
+                0x5091c9839f42
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba27ab838b0b32f0,
+                0x86cac2c146367a76,
+                0xa8ec764426cc0146,
+                0x89fd9c219887dfd9,
+                0x3c85bb45469c4079,
+                0x2a1421966b66d601,
+                0x3d41661dbbbffacb,
+                0xad8eda8e69c0b197,
+                0x2b71fce33ced16cb,
+                0x7ab0e478a2edcfb0,
+                0x259401d2b9ada8dc,
+                0x66670d011db8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x49f9da66f75a7c28,
+                0xf2281db6586ddab1,
+                0x37e562dc0614fa1,
+                0x9ea7738c60cc1e66,
+                0x6d5ac198b3c7ac5e,
+                0xc918b4d5aec96039,
+                0xce9958af1db35763,
+                0xc574ec529b4bcfd5,
+                0xc5e280efb72894cc,
+                0x60a2434a22e96fb5,
+                0x437ddc889b06bff5,
+                0x65752c49563d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8d30bc23fbecc0a8,
+                0x405dc849aed98de,
+                0xb17bb1b6f393042b,
+                0x8409e97e5f3c540f,
+                0xfb6e4d91d9a7eb25,
+                0x9e2bf31550d96c79,
+                0xa2dbbac65fda8aed,
+                0x7dd9747e84c43828,
+                0xb272d7efc5fd4d8a,
+                0xa9c43f192742a9fa,
+                0x2420dc77ec387d96,
+                0x13f7071af6272
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6296e5a94b0f292,
+                0xd3ea7e8c84fa2e0f,
+                0x89d264bff46adae1,
+                0xd599d7d8b1ada895,
+                0x31542aa7d799f59c,
+                0x45f33620b76a216e,
+                0xedc7327694bd1e36,
+                0xdeee3c390f5ae142,
+                0xfe13da8a47ac05bf,
+                0x43339345ae080330,
+                0x5531f6fac1560498,
+                0x18ee9b20ec85e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6aa5d8a6a2616d84,
+                0x89b9cad5cea47c8d,
+                0x31df7b283969af07,
+                0xc44f6f232fdea5e4,
+                0xca3d408aff4dd1f0,
+                0xbc816bfd6996d3da,
+                0xd724ecdc297b4cf2,
+                0xe5a528a402576163,
+                0xb438842540100b21,
+                0x754f48887681a804,
+                0x283a923bfda04c91,
+                0x11f6701d0d06d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x75f92a87190536f0,
+                0x1868c5cafd447195,
+                0xf901e4f9a5f9900c,
+                0xa832bc246569761e,
+                0x3e6c933c353634b7,
+                0xe507caba819da678,
+                0x951e34f6c9dc3290,
+                0x527a555e8e9423cc,
+                0x73b1351e2a907dde,
+                0xdd49847cd0beb253,
+                0xa1661803c4dbb62a,
+                0x1109a1e9b8beb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe77869d31da4e51b,
+                0x271571a8fa21365d,
+                0xab1d3d317339ffd2,
+                0x2bedc6281d794ede,
+                0xfd23ae8f1cf26b4a,
+                0x68b3ca2b5517a714,
+                0xa098d298d68b11f8,
+                0x76228389c75c5cf,
+                0xaea9cce3572dabf9,
+                0x33f78f45c8656705,
+                0x67a4b0d122000704,
+                0xc3b300f4b867
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb67e135b6c16b85,
+                0x350dfe3c3b18758b,
+                0x8642fee987549248,
+                0x48004b69c3376963,
+                0xaa285185123cfdb9,
+                0x41fda50a9632ea0d,
+                0xe89b6eeebab0af9e,
+                0x82f9425b4b5cae55,
+                0xc057d6542a8fa7ec,
+                0xb04af0aed3f50a0b,
+                0x8d76c08ef482cea2,
+                0x1bdbd4370c00f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7c7018d4c70c9d45,
+                0x59458d010fad8bbc,
+                0x510b7b552c9624f5,
+                0x24470280860677fd,
+                0xaf2ce951bca1a35c,
+                0x8e6343d73a1546f2,
+                0x8aaada279dd9aa86,
+                0x423f2e22d43b7427,
+                0xa152d890d8bbb0f3,
+                0x2be31e479c571ea6,
+                0x2bd853e095638d1,
+                0x24f7019c6c2d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ce1cf8354a76011,
+                0x9a4329ebf2d1cd14,
+                0xd32b33ba496fccf,
+                0x42678b94df4e98,
+                0xce861e09ceff77b2,
+                0x72cf77a97e6978e6,
+                0x78c4f8a8b1f003ee,
+                0x4013deb2f1179785,
+                0xa7114e37ccd0bbb1,
+                0xfe7d3063f985c236,
+                0x329da58b1ff76946,
+                0xf836c19f848e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5471901f8da07e00,
+                0x90e46434431d8db7,
+                0xbc4ba6771ac103aa,
+                0x535191574db4a379,
+                0xfcc2384ad435dd9c,
+                0x82178c8342e51c92,
+                0xc4a263a070e0d668,
+                0xbe0e2659d92278a4,
+                0xdf838bab2b351005,
+                0x14a4b1458f3de71d,
+                0xbc2e56193551ea62,
+                0x56c990f8e806
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ff3598fd40b4eba,
+                0x987db05b64673cee,
+                0x1b52f15f95f09f66,
+                0xf85cff0c42754b81,
+                0x85fc300c4752f643,
+                0x7591ad871fb8567d,
+                0xc8ee65170d400f05,
+                0x6273eaa646b730cc,
+                0x196972c978c5616b,
+                0xd4fc0ea7f062c6e6,
+                0xadacf665170873c5,
+                0x3c490790ad78
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda7b330f2250826,
+                0x71d7b354b066774e,
+                0x90808f3674edfc25,
+                0x4132eff78d2843c1,
+                0x432bfee180478c9a,
+                0x9a8929182db5bd3c,
+                0xf4673e763cbb7e96,
+                0xc208685196a4e7df,
+                0x7d2825a6f113879a,
+                0x19a12d03218643b1,
+                0x7d0609ca6dcbb4e4,
+                0x3b7f11da5192
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2830e5a68b2f64e2,
+                0xd7b3ef72e0039470,
+                0xde8c3ecb257d47eb,
+                0x225c19c646a7608b,
+                0xb69ff45e290ed4e8,
+                0x277ddba2f1f307c1,
+                0xf8187f19a8451acf,
+                0xfc3dd0022707d366,
+                0xb9b91462b9b65011,
+                0x7a9a51152e86648,
+                0x670252ef5bbdfdbf,
+                0x12dd4c81c819a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98519a7794d5e9fe,
+                0xfe6fc24a97a2aa2,
+                0x4e5c373dab076a86,
+                0xd471d44c0e8498a4,
+                0x5ded930b418fa346,
+                0x4b09122794010d93,
+                0x568fe8c3fb828739,
+                0xcf66a8622ca96da2,
+                0xb254bbb6c98e9b46,
+                0x66d4bf634b50e910,
+                0x21f004def86d849f,
+                0x692e046511f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd654fa9c676f97df,
+                0x3282b07690005aff,
+                0xde9c66cf7be79de,
+                0x643bf358ffd1c323,
+                0x1523230f2d39f623,
+                0x241081dae55d29ed,
+                0x772420783e03267,
+                0x3b9333722ca6a0f2,
+                0x413cb39ad9f0ffa8,
+                0x698f58bcbbbae83f,
+                0x6a57f4408962fd72,
+                0x16e6e01b4624
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdaf0bd0e7c0fa8e3,
+                0xccf25d14b3a067be,
+                0x98eff129bbf275ba,
+                0x4a4acd76db8f4f32,
+                0x25d3cc1e19c5a89f,
+                0xacc6b630b53d4ab4,
+                0xce00fe7cc8864717,
+                0xd5532ae8c85111b3,
+                0x650c005c7fe1925e,
+                0x3e7dd4958efaea5b,
+                0xe878b22378e6b809,
+                0xf9958a6dea57
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3fa4ab3afa5b626b,
+                0x40e35b05d04ba010,
+                0xb8e86c1743d87e96,
+                0x5d6c506f0c16032c,
+                0xbc6e3a79dda9c78,
+                0x5ac1c08ee1dcea4c,
+                0x992880fea08a1427,
+                0x11ce7d2a880bbfee,
+                0x495ed2d94ac83ade,
+                0xaf43a214f747c0b2,
+                0xab96461e90cb20b6,
+                0x434657b530a9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd1ed0532a404e56,
+                0x22a717c1020f313e,
+                0xf5496dda29496e1a,
+                0xdd7391282e71a6f,
+                0x56f76f8898ac2455,
+                0x2c632d687edd3782,
+                0x188adc4979a2e758,
+                0x8665260391434993,
+                0x223200f88c20e5d4,
+                0x4115f4f4c1b50f84,
+                0x4de1b6895fe23ad7,
+                0x1c4a87176ab4a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fc47a5397140295,
+                0xb98d76be1318c86f,
+                0xfb2b7a45d242d092,
+                0x9525b563055dafd6,
+                0xe5226e03c5b9f81c,
+                0xc933ec64cd59004,
+                0xf87b7411374cb5f8,
+                0x1d1dbc1f8d71b2a5,
+                0xe14a536f9dcf55a0,
+                0x182654fe5d965c1e,
+                0xdbeac2c7a4bbda0e,
+                0x1bd57122e7836
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c5071cc26715452,
+                0x82facace8f8d234e,
+                0x2b849aef3984ced2,
+                0xdf3358b923e41b24,
+                0x8bb896782b00d895,
+                0xac288685374c54c3,
+                0x517d721688bd53d3,
+                0x55656b5edfc5821a,
+                0xb690e2977348a4b1,
+                0xaab59fdcd2c8da6f,
+                0xa8e99231cf34c15c,
+                0x1be1ee26931ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5379935110e29de0,
+                0xd3d37055ec7feadd,
+                0xdd3e791bbf13bb96,
+                0x72c2035523693b80,
+                0x42c6ccbd3d1b0860,
+                0x616a5706b0ee1503,
+                0xebe4a17cb368c5bb,
+                0x9c1b55e75e7d44d7,
+                0x8ab736029585b869,
+                0x810bfcc3bcdf942f,
+                0xc7737dc019884577,
+                0xf9c354c3faec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8abe40583383eeb1,
+                0x5765ee2ad3f7c08,
+                0xc87203ceba621bbd,
+                0xdb7b6ca5313fa297,
+                0x8c2f627ac1e78e26,
+                0x40d51e2086461042,
+                0x3c31381c69c5db3,
+                0x9f3b71a818356ec4,
+                0xf10c99f8978d2f0d,
+                0x2adf424654ecbb3f,
+                0x198c367d4c9e915d,
+                0x15e5d921e4f3e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2f589c9e634d2a,
+                0xb11757a60c5c0563,
+                0xaffcd2b0486c1155,
+                0x99ecadcdbacefdea,
+                0x624206effeea9317,
+                0xb37f62f75725ef40,
+                0xbb6a2fe3ce201c76,
+                0x3cd8d545a463868b,
+                0x240f3a6853d09aa5,
+                0xace80b660babab65,
+                0x78ae443c58ecc763,
+                0xe91eda1a1936
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x88ff3f4c02bec609,
+                0x36daa13f83822688,
+                0x6345785436fdb27b,
+                0x5e8089c32819d487,
+                0xbeaee51fe977842,
+                0xda14958478fa9e99,
+                0x5c941dc028fd6853,
+                0x9c680deb0cfa7128,
+                0x23898e9ce34e04fe,
+                0x2b3c386e0e10c849,
+                0xc2d789c74e8c4c50,
+                0x43696b8d92f7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5362a193af446057,
+                0xe510e321ce7c0cb,
+                0xf6c699b47f66a7f3,
+                0x628094cc9b28c456,
+                0xcb93b1bb306314f,
+                0x3336d1eb2f2b97c8,
+                0x80a4da7caa5c5f45,
+                0x11d97f66ac904302,
+                0x168a7fb215d0d99e,
+                0x6a713097c1cad147,
+                0xe3aaf10c5561e0cd,
+                0x6894c65e3c92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc3cec79f8f21747,
+                0xb646364176594b68,
+                0xaa8a33ae98c4e614,
+                0xc9f2e402c8b223eb,
+                0xbb2bed97d1cc504b,
+                0xbf99868fd65019cc,
+                0x3abfec75eacfb77f,
+                0x34b72aad6727f760,
+                0x825f4c040a31e77,
+                0x8ee0becd9d4c8c6b,
+                0x4ddcda048758b439,
+                0x1c250cab6dc5a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9952f577f0ee4ca2,
+                0xb5c96c5982e0386,
+                0x39674276451d51,
+                0x720814bd46453ece,
+                0xffdbfe0eb54a9c4a,
+                0x4f584173e0f1e35a,
+                0x9150dc554641d729,
+                0x870f05721bacf0c2,
+                0x3e974731596db5f9,
+                0xe89b55698681226c,
+                0x1aae6f1a939c230f,
+                0x113119a367e07
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe26e7bd58431c9ef,
+                0x5ee5db47fb23dcb5,
+                0xcb08ad9943d24307,
+                0xfaa6d089afd96cfc,
+                0xc748f608663198d3,
+                0xdc6975f3b301a714,
+                0xa57f029a1fa6ff43,
+                0xbd1edd146e7e3850,
+                0xc3297cd9bd5f750f,
+                0xfec05618fd7a76d6,
+                0x224062d7768af53c,
+                0xcd88c43b9ca5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2391899484863fbd,
+                0x146f0bec12732fa2,
+                0x80f21dffb8689898,
+                0x5669b996836908a,
+                0x4fb6871495f60f30,
+                0xdd47d7b2b8d910c,
+                0x966743eab4fc6a2,
+                0x78b10b2bbf527b93,
+                0x90edc818eefc509c,
+                0x7438cbfbdf913934,
+                0x8e5a37cc9240a08c,
+                0x154e4deca9b39
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeaa2db63feb88713,
+                0x70cc4db53cc68f56,
+                0xb0b7365fce9f1689,
+                0x550a1ea09741d581,
+                0x125fa3f1abdf1425,
+                0xb7758195504b440c,
+                0xb878769cff6b2388,
+                0x2be3165d765ea22a,
+                0xd6e3d4e6fe73e815,
+                0xc7d9ed098c3411d4,
+                0x9a9d22718454fbfa,
+                0x14772187cc12
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaeba2237e15c62c8,
+                0xaad91d6bbbc8c972,
+                0x511a7100681a4db5,
+                0xd18ba0d8d2b28abc,
+                0x5cbd92a5a4fe8a23,
+                0x8e7d97901c4fcc6c,
+                0x41deb7bb87c87a94,
+                0x5e1db4088686bf7b,
+                0xad55bbf7df84b89b,
+                0xf682d706efe393ce,
+                0x5d96a1edee682e62,
+                0xe696f997ff94
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb584bee2895bbd6a,
+                0x565a14c8dfe85e94,
+                0x8bade648fd74b9d7,
+                0x6b022ef45be75c35,
+                0x31c06c40fbd137e5,
+                0x2f2ac11e96e7288,
+                0x8a7d9106647d8dc0,
+                0xf4f428ddf0d01c36,
+                0xa25f7e4e87c2b2a5,
+                0x6f42d50fef733806,
+                0xaa982db4546b5b15,
+                0x1af390991a76f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x52d3a108fbf7754f,
+                0x267807e7539661b2,
+                0xc79ecea39802eb85,
+                0x215e6bb4943a9ed5,
+                0xc30c35ef4be73e40,
+                0x7a60e79767a74000,
+                0x5d8c4d66f8fb52e,
+                0xcf296393f2ed2a2c,
+                0xf9f16c870e412155,
+                0xaa43d45c293243f5,
+                0xc2847ca0294aa66c,
+                0x122715ba02144
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b08db4f3e655337,
+                0x55a0072ee4db56a0,
+                0xa7284910b7d7051e,
+                0xa54782a66852a651,
+                0x79a0711bab3fbc2b,
+                0x5340f7f72a85ee3a,
+                0x45371372418ac925,
+                0x1b8f01212631f25e,
+                0x42c641b4610f3222,
+                0xf3fc92f83ce54c01,
+                0xea5a3f15c6c7d469,
+                0x18624ef397a7a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc99d3fe40e2272ae,
+                0xb6b0372e4925e567,
+                0x96fbe7d433339336,
+                0x9bbaf18acbb93a0,
+                0x82c415a6b997f997,
+                0x504284435c5a7814,
+                0x62150171e5d629c5,
+                0x2a152685a69f0e9b,
+                0xb5eac420db53654c,
+                0x5f5d1c06544ae27e,
+                0x385bec98f74eefdc,
+                0x42722cd7db75
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a6abeed5aec2abd,
+                0x480c09e14afe9946,
+                0x1261fe75218b9232,
+                0x8966ce222315c7d8,
+                0x4955c9737173f28,
+                0x2c7c14c3b56b134b,
+                0x11c0b0830e2f5943,
+                0x2e68e07e652e315,
+                0x94914e86a33fba5e,
+                0xc1c7be1d7f5baf87,
+                0xaed8b4d5036b5cb1,
+                0x8450172d69b1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6061798c6b0cda46,
+                0x27a04961612bc343,
+                0x887602c5c2d5d958,
+                0x5fca41e5353346fd,
+                0xba3880d34815abad,
+                0xf98eb3a01c8f0a3d,
+                0x2130e1186413f6d1,
+                0x9035f3b52eca6e,
+                0x2c63cb4e3e3c2c46,
+                0x3b22775ad14075eb,
+                0xe168392d6255c2e6,
+                0x1050d7b3d5616
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9303f3b650f6968,
+                0x9eb2c8b793e68e3b,
+                0xccce1dac40633b05,
+                0x2b27c02c82b3ffb8,
+                0xd01b688b5ae1d71c,
+                0x7b15d8acde7ff30c,
+                0x1caa15f21c5123db,
+                0x62a594c5255d9c8a,
+                0xc8b2c53df668a049,
+                0xe09b019473290f25,
+                0x8e7612dd045045d3,
+                0x1a3bd592b9c94
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdb893d21ddd533bc,
+                0x780ab9be4200704f,
+                0xa02bdc850a66d736,
+                0xa37968145546e171,
+                0x95bbd50a275695d4,
+                0x276ee15211d768b9,
+                0x34a114f00a4ff87b,
+                0x4fbae2b4073ebddf,
+                0x524024606b19d411,
+                0xedfd0d5fb1a08da8,
+                0x3345e450b1a9bf95,
+                0x1488c6ff3226d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2edde59330e7a017,
+                0x1c396db10e8c1763,
+                0x283854329dff0e24,
+                0x2f2b78d904ccd5fc,
+                0xbe69fdeb51ed8087,
+                0x73461cd504ed2342,
+                0xffcca51d4e484e17,
+                0x2f62ad24df72e15,
+                0x57ce1aa74d31feef,
+                0xdb0f49b1cdcc80ad,
+                0x103fd8bb967beb94,
+                0x83581481884d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ee6757aa6a61531,
+                0x4925441bc65cc1ce,
+                0x684262e12defa351,
+                0xf528399daf160cf8,
+                0x8d55ba814b2b5a10,
+                0xef82a1affeadefb,
+                0xcf26249b57336e80,
+                0xf3e70005cc0caccf,
+                0x54d5dfff8cf88943,
+                0x6231b0c2666efb9b,
+                0x2ed838480e5e8b63,
+                0xf58f1fbb80dc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbba0461251fdac49,
+                0x8862f4ab3a2d13ba,
+                0xcf220320ea51a4b9,
+                0xfd530ba2f00535af,
+                0xc6389db675cf7443,
+                0x6abb2a6d45663950,
+                0xa29962d1282ff0c,
+                0x81911a9a5e5594ba,
+                0xa3d35da3e64070ae,
+                0xce78818e3f78fbae,
+                0x10c04c3b28490de0,
+                0x13331674613ac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc73b6e03bc97e8df,
+                0xb4e25f6b62d67d93,
+                0xf3daeb186d99359c,
+                0x21df7209e1af2ab6,
+                0x59b863e93c3029c9,
+                0x25553b2f2f5d637e,
+                0x1af27fa1f745c7b3,
+                0x48f5701130d5cd8c,
+                0x56c44e8ef1dd1767,
+                0xf52908e37466a958,
+                0x10572ad9bb0e0a1f,
+                0x6c2b3d3b186b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x72ac811bee584c6a,
+                0x2bee7a8f82e73a1e,
+                0x680c4215c2728126,
+                0x7192f19c8442bf29,
+                0x29d81fbab0bbe5f0,
+                0xeed77ebeff6cb1f5,
+                0xbc7a0e8fede9c3e6,
+                0x8015481c07261385,
+                0x61de35eaeedd41ec,
+                0x264799c91a3c794f,
+                0x748e46c4005200e5,
+                0x512012f2bab7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xafcbcde185e35417,
+                0xac6c9ed5bc459d78,
+                0xdc48749284817ad5,
+                0xa5bed54aa1ff006e,
+                0xfed086960cb3d45e,
+                0x564b63d1c535e27b,
+                0xd11bc0491a6944a7,
+                0x16fa53ce4059281,
+                0xf466638d7fd3a9b1,
+                0x1bbde9d98c0d46e0,
+                0xbe96335104f9ff5f,
+                0x116fe308b5ad6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb94a00b10cfc1a5e,
+                0xeb0359aacb0a66c3,
+                0x3cdb439217783a3a,
+                0xde743bb1c747722c,
+                0x9971af642d22b6e8,
+                0xb7738ce5620eb2c0,
+                0x5c7a3417342ace6b,
+                0x7557fa6a94e410aa,
+                0x54e8cf6822d6ca8b,
+                0x8b085cf52744629b,
+                0xdebf4ec0aced78cb,
+                0xfb3042830aef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x527d46c651c9bb4b,
+                0x2a84b0228158ba2f,
+                0x9362d275d63efbea,
+                0xa43d75bc4fcb586b,
+                0x19c53232d018628d,
+                0xa00a7b992685c0fd,
+                0x2acde5368fc1c225,
+                0x7109758fee3fe8f0,
+                0x34f02ef11b4af6a3,
+                0xc74d569d8f75c807,
+                0x4e00745fd8487da9,
+                0x875f871518ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6d6977db4623c26,
+                0x35903f3183f2161,
+                0x78c08b726fa49a3a,
+                0x59df7f09427e9700,
+                0xc1007d8bb139040a,
+                0x265681e3c6baa4df,
+                0x4c3fee3779dca754,
+                0x503e77013757e953,
+                0xa2865522c27b9a9a,
+                0x48f97825894b7389,
+                0x814f2ed50314b7e5,
+                0xb7dfe68a6795
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc94aaa43b479ddf1,
+                0x1d06db5d68a6f4cc,
+                0xbeb462c966e7f2c6,
+                0x98c1b2c8b32e3750,
+                0x9b5396fed34dd6e5,
+                0x7e4a1fa24eb63246,
+                0xae38503ee47c4289,
+                0xf7c767672354b127,
+                0x66b7882aecc433b2,
+                0x1f764748de0ceb04,
+                0x40945db0944897ac,
+                0x19b95ae783668
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeffe166d4dc6705f,
+                0x3dc776b5c0bf0351,
+                0x4864bd30f5fdeca2,
+                0x49b548bbabb83350,
+                0x640c4b9f0240ab4,
+                0xb08f100217f22b79,
+                0xe89918f8cbc67463,
+                0x3ab2cedd73393923,
+                0xef05d62443cced9e,
+                0xbe235eb27fd8ebd3,
+                0xe8398e5a807e8bfa,
+                0x41ca3be52315
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb5b00dfe9a79765,
+                0x9a48381c8a000e9d,
+                0xcbcf3e219d924299,
+                0x8605dd339d4eed0d,
+                0x6fd071f5b05da25e,
+                0x27a329f5d626dd7d,
+                0x61f4341d0589d6a8,
+                0x28662aef64e71669,
+                0xfb917a0f005350d4,
+                0x756c1bb351bb20b1,
+                0x76c811726e33b221,
+                0x8bafea137690
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x15ac589759f3f5be,
+                0xc617ef855cef912d,
+                0x74aed5998f4ba0b5,
+                0x7559489de47bdaf4,
+                0x7c280930139f0d79,
+                0x87699ce19528c853,
+                0x7864469648e5941c,
+                0x2e849c4faf4cd84d,
+                0xcc0ece5d80c67413,
+                0x2ff8c1704574911d,
+                0x9515c4dcdf5c06e8,
+                0xed97f8c87910
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa0871ec660fdc96d,
+                0x9334d4e2528b874f,
+                0xc2dc1ff59ac7819,
+                0x8f4215e7e0cafc98,
+                0xa65a9feae92c535f,
+                0xf7dd181ae62e1720,
+                0xee5ea976895e96ab,
+                0xd1710d41dcbcba51,
+                0x32c0586c0a1b0104,
+                0x59e8dd73a490425f,
+                0x36bc3e7f5f4dc31d,
+                0x5f271331a1b4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a52097552cdcffd,
+                0xecdbf71e14270aa8,
+                0x88496cde34f82b32,
+                0x1e01c88a04045776,
+                0xb0bc5a2cc1af4896,
+                0xcb56853fa8690dfb,
+                0xb76d751796c35a1c,
+                0x828f0c56d028634e,
+                0x359643306cf3eca1,
+                0xeb7d56f3278642c8,
+                0xb69de0033ac374d7,
+                0x1076aaa54a5aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe6019ce3eeb233c2,
+                0xccb83f66b84b59d5,
+                0x6c41980e26ce89a,
+                0x5870f63f9af4710e,
+                0xd334e21d761d2fd,
+                0x2c6bcf4531008e7c,
+                0x595a50d8246cf478,
+                0x94b229657b38cfb,
+                0x6846a2f70a237d62,
+                0xa4cec71f4b8855d0,
+                0x77734959e21a2045,
+                0x117552106d6c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae1e3b4499d3df56,
+                0xc537a004b74aaf7e,
+                0x7e13bdeee30c34fd,
+                0x974674f30799e819,
+                0xbdb805de93217de,
+                0x5598deda4b5aaaaa,
+                0xd839d70d952ab875,
+                0x530db51b146b0ad1,
+                0x3a44fce504f96d58,
+                0x8ec51b35de446231,
+                0xc4bb97671ff3d7f3,
+                0x890eba5f762f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6d1a26da047152,
+                0x6974cf58cbd550c5,
+                0x914fb22542e3273e,
+                0x4c1f0b8a2902101a,
+                0x32d9699eb8b650fb,
+                0x74fa148859fc03f5,
+                0x849ac8a1ab57822c,
+                0x83abe74de21677b2,
+                0x37f2c4b7a5d3ecaa,
+                0x700875c33f06b33b,
+                0xd2197d300d6448d3,
+                0x590b17ea79bd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc5945f43257dab84,
+                0x24a6144da6a627c9,
+                0xcc685a6c860ac2ae,
+                0x2b982b342615c11e,
+                0x2a6bf1f0bb2dbcd1,
+                0xf042cd6599e213ea,
+                0x136b369b8f494b1,
+                0x51ab5f171aaa50ac,
+                0x84f129614ad767d5,
+                0xab7cf0e46c4445d1,
+                0x26c86ccb24853443,
+                0x1a3a445ee73
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x790653707015a2e9,
+                0x3985fadb8b770e35,
+                0xe64303e6302ca060,
+                0xecf26cbd46e5a72b,
+                0xc139fd996ead7c04,
+                0xb4fdc984fa7e333e,
+                0x104334e9f66c0280,
+                0xd73f69a15ffbb9da,
+                0xfd9c3ff456955c5d,
+                0x7bb6d8425b7d6560,
+                0xe8699fd8c9509398,
+                0x127b98e5fd0b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70ede5b11a37b345,
+                0x47ecae52f97e2531,
+                0x5a11a5e606d3bfa7,
+                0xb726911feefaeab,
+                0xecafb7747f01670a,
+                0xa4035abc7e4f5a51,
+                0xe134d3f4fb5f8c28,
+                0x6a0bc2e4e4a2c187,
+                0xa3ac85cfae7c67ce,
+                0x1248567bab73e89e,
+                0x5fc5a8b0f1b602ee,
+                0x1390a5491ba10
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ff7ec0e5c732b28,
+                0x80c6de389d2c8695,
+                0x45522159f22391c9,
+                0xbc3dd69d37cf5428,
+                0x8d10946bf4590afb,
+                0x6f165b26d3a94c25,
+                0xc75b3050287b144c,
+                0x303ddca98d90beb2,
+                0x7b278e018c3b6644,
+                0xb743871b7f714e20,
+                0x4c5c395d894d198d,
+                0xd3e7d2535114
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e8894fa9777dc9b,
+                0x4fac10346e141e09,
+                0x4b590e87cfccdec2,
+                0xf98ddb73b6ab8a7a,
+                0x797d90becb3fd082,
+                0x2236ddbce39e71d,
+                0xfac4b24729c176d0,
+                0x1d04de097e976ee8,
+                0xd330c64e12f8eed1,
+                0xe0d8b385b4656724,
+                0x5e2a2097dc5cf532,
+                0xdd6f7fa0391e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9dfc642cca50c12,
+                0x784a534d0bef1990,
+                0x5d4fd0cb0061bfdd,
+                0xe421c3989a830fa7,
+                0xb0034253dfb4c07e,
+                0x2817a949b5f5c0a5,
+                0x56997c90a9420c5,
+                0x762956d7ee9714d5,
+                0x6f6656f83d9a181d,
+                0x2b58d501e7cbb91,
+                0x7dbfac5713db5ca0,
+                0x12692419bf74e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xea22853c0fb94624,
+                0x11bc376b0a5bec7e,
+                0xa552e0e15327526,
+                0x5e762b491be0488c,
+                0x2e6481bfd4b122d4,
+                0x4d7d19421722b1c1,
+                0x59ad4917eb087b8e,
+                0x3ccf7b203b65ac9d,
+                0x10c28df0cf869af4,
+                0x2c8797f8d7159a96,
+                0x6ae6bf506e562da6,
+                0x13128364711da
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b7a6142f7cd36dd,
+                0x6e69678354d90d56,
+                0x2f66649fd2e6c348,
+                0x14de505ec8369856,
+                0xd66a7652fe7d949e,
+                0x931add800213a17d,
+                0x178a0d43ac96223c,
+                0x9d274922cfcea232,
+                0x791f4a4479b926fd,
+                0x65b8d6024147d81,
+                0x2a3426539ede0e8b,
+                0x379502af7bdb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4df74129b9315fa5,
+                0x7d3a50ab0ab9ace9,
+                0x66265ada875bbf18,
+                0x69b97a3d313ae26d,
+                0x39971fc9a0a8c7f3,
+                0x563f5ca278b1a781,
+                0x829f8999f2aea7b6,
+                0x5c95241313ad6c3f,
+                0x258bcd34882a611d,
+                0xfdc6a99991d9bead,
+                0xc706cf1f574b0f18,
+                0xb8589d70619b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb4d4a0146e3383c1,
+                0x14e39438db5a5748,
+                0x27970759d68d3705,
+                0x4538c78dd2d1ae92,
+                0xaba379e03cc04173,
+                0x1a0e1cb67031fe10,
+                0x350e0fc1258aedb9,
+                0xfd387b38db5d4d6b,
+                0xb34da192b74805f7,
+                0x18a054cbe1f283ad,
+                0x48d24fc5897ab1ae,
+                0xc5c0089d5958
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd4242dc1ba0a0d53,
+                0xcf9ef489ef13c59e,
+                0xcb44332c8f61723e,
+                0xaf54ea4c12c84ea0,
+                0x8efc8deb2eeba8aa,
+                0x3916960cb299ce23,
+                0xfb11f9b2ca3be4fb,
+                0xc348afdd58d2a11b,
+                0x922f52a4a519c675,
+                0x785dc2d6345305a6,
+                0xa46cbb0e87cad9a5,
+                0x13b57762777bc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6190a07f88a3b4e3,
+                0x2d52ddf9f5b48206,
+                0xa6b7eca8e6d2bbf8,
+                0xcc78153fd9a52d7,
+                0x3c6d3725cc6ca897,
+                0xaef7050de08f4e27,
+                0x4113f218b40db2de,
+                0xbba56174cfa6267a,
+                0xef26b691f9f9a6e3,
+                0x2a0e811a03264831,
+                0xc5559d797c2f57f1,
+                0x76f73a10912a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x376d0b5d947f4fcf,
+                0x4590fe624143b147,
+                0xf2657861c4ce1eed,
+                0x3835f64014d0f58,
+                0xca1cc814a3dd4bed,
+                0x4f3ae767450fa5a6,
+                0x8cb34431666f74aa,
+                0x769c8c13ca7baae4,
+                0xd21523b08a1279f5,
+                0x64b4fc559e12adc8,
+                0x5688e92985057f91,
+                0x137ad04d4fc73
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf8548e413647a8e2,
+                0x9906f10796e73ca7,
+                0x9f6ed9f5e11a6de0,
+                0xb41fede766974fda,
+                0xdcb4b3fe8febd03c,
+                0x7c6d8383ba0e6100,
+                0x7a565d84007830a1,
+                0x5b2184193cb7b65,
+                0x282bd82b1fb93d6b,
+                0x5c2ce1faa6ba527f,
+                0xfbe375965385669e,
+                0x63ad2ddb5428
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1ef25d3cbfd791e2,
+                0xa9469709b7b55643,
+                0x9f611669930f331e,
+                0xb4fb51eaf85fba0d,
+                0x444c8d6289e5aa52,
+                0x6e0520006bd05e73,
+                0x53f895d913e36d9f,
+                0xf97a8e437d0a842d,
+                0xbaae2d2df59da3e1,
+                0xae78df20f8c690b4,
+                0xdf3e42bdf94096aa,
+                0x53617e14f228
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2183f313fc550de0,
+                0xfe6727ab69ceb3d1,
+                0x68c915527320785c,
+                0x5611a01a7e3d8ce2,
+                0xc12f9f1661791c1b,
+                0x9ff8b8a80a81938c,
+                0x2c618129935d7ceb,
+                0x13f541cd2768fd86,
+                0x1cbd8293c52e1cfc,
+                0xe65bbe16f293f058,
+                0xceb1e138960fa90b,
+                0x1a959040ead65
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8af595901ffabd14,
+                0x834243f0a9db7f57,
+                0x8abfc5d991414127,
+                0xabcdbad738924871,
+                0xb70061ba5f4377fb,
+                0x1f84910f998d6ca3,
+                0xfc08c09e6e0dab70,
+                0xb61c72ea51168983,
+                0xb5857dd6f73832f4,
+                0x52372630909a257a,
+                0x6a7bb41acc7da668,
+                0x1b4685b49840
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe2d58d4f41573afd,
+                0x665404cfe7052341,
+                0xe7a5a2dc0479231c,
+                0x533b39d03e86c8c2,
+                0x4d990547aebbc61a,
+                0xa60af69c5bd8cc66,
+                0x1dde3d5b66adcb40,
+                0x5db33ecd98b04ede,
+                0x65df3ed542046925,
+                0x6777b7998ac35b45,
+                0x3cc3be583c0aff39,
+                0x11fd8649a5d9a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf06c8f31d6c05cb4,
+                0x595b32bf7f001ac5,
+                0x419136ef630fc1ed,
+                0x6af38cc01506528a,
+                0x57c2ffa4f9b2ff14,
+                0x15565df3ea9b9fd1,
+                0x6b4f3e81052f963d,
+                0xd526035d19d26b3b,
+                0xc04c07efd7e23c8f,
+                0x64f3ef52765905ad,
+                0x90a8368a31bff4be,
+                0x1ac7468014fcb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e74d82f4714cbf6,
+                0x12a5afb7dcea93c6,
+                0xc0656e687447d8f3,
+                0x955f9f394caf937a,
+                0xa9396deb6dfbcc5f,
+                0x70f6b52a84f59aef,
+                0xa82efcc6f26ad413,
+                0xedf2ec7dd22c0449,
+                0x4802c1ce35f1f992,
+                0xcfcfb2242949e521,
+                0xed011ff7118a3d2a,
+                0xe2c0dfe0b1c1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8f07404ddcb1da35,
+                0x62975de6a8ce4979,
+                0x16b43e22a00215cb,
+                0x9cb2eafc945fdd71,
+                0x538a8a30b69303dc,
+                0xd7ceab0f9f46b14,
+                0xe6bef0bbc9b9e7b7,
+                0x6062f6a9a12c447f,
+                0x92fb30a5a8ff2d29,
+                0xcaebea2213589a7e,
+                0x55b985b9a4ae525d,
+                0x46bb429b3bd2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf9df5b04bb9efe05,
+                0x7fa14dd55e2e0ce5,
+                0x7b1f9ba470f3c648,
+                0x44e19d641fbc4f12,
+                0xa08f9914dab5dec0,
+                0x57a1d433b089d0b9,
+                0x4c3d152bee6f68a5,
+                0x8d9838d196c53542,
+                0x710448f96943086a,
+                0x4259206e2aff50e7,
+                0x294a31ee6cc1adb3,
+                0x116e01f24e80f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79aaa20964747bf0,
+                0x46bc362918307583,
+                0xdd03cd22d4b1d213,
+                0x9648a747ab0b6533,
+                0x1c2bfe039f3973dd,
+                0x5445633d022319b8,
+                0xe091674e2ae5657d,
+                0xe457a8c958af502a,
+                0x24d111f788fb9717,
+                0x25c6777db92b5403,
+                0x61f3dec7a680ffe6,
+                0x98f707bf220d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e44c2e77f6ff5bf,
+                0x4e6743cfee560522,
+                0x76f3c09b353637ec,
+                0x541e90d5d4cb92ee,
+                0x6cbeac1b8a513189,
+                0x757c35a2707b7989,
+                0x82bc65679f4abcb3,
+                0x9638601dfd9e02ed,
+                0x625f5acffc975319,
+                0x86aa7ec3350571d5,
+                0xe77fcfb07a933f72,
+                0x17a5898b90961
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd22a5b34ae149562,
+                0x50af008f758cf1fa,
+                0xaef660ae636ea76e,
+                0x21b3c6cc9176cf4c,
+                0x1c7497bd0890322c,
+                0x97bd60aa656c5f23,
+                0xdc4dbcae01919172,
+                0x1c2715198103750d,
+                0xa6d6243d51b1333a,
+                0x26535a38989f59a2,
+                0xa26c821f1318bc33,
+                0x12efd8569671f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2fb3656eb0980beb,
+                0xc18c2da04124b8d9,
+                0xb80413d93e8d84b6,
+                0x9f38c07b952093b6,
+                0x1c5e2de39bc26dfc,
+                0xa0c7cd70744bfc72,
+                0x4fb253763ee943e6,
+                0xd7e0aad0026287e1,
+                0x9ccbd3d37fc5f6c3,
+                0xb6fda923f6bf214,
+                0x8c4647500b2c71be,
+                0xf055287b9d09
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b724b3932f1611d,
+                0x6d3b80a015fb3cc2,
+                0x9d584d468709de7e,
+                0x2d428b79c8e26ba9,
+                0x40afceee8c6d4ce9,
+                0xd42a92bce34e7706,
+                0xf8d33c4f9c8d0542,
+                0x2fb5ac22042513b4,
+                0x31cf9c3e688fab79,
+                0x4dc90a3c8a4b0ca8,
+                0x6b44b1cdb4527218,
+                0x12f7bfd051ae4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f2b7673ba6323e3,
+                0x2024bb85b8b25ea6,
+                0x58d3a5a4710a77ee,
+                0x819b790becdb4fdf,
+                0xfda5531b68671279,
+                0xde2a4c137329510f,
+                0x90f576e959de4003,
+                0xe1528b459af17606,
+                0x5952d6e3e6d6c917,
+                0x4823670bda105979,
+                0x8ec580598ff64eb6,
+                0x10ab5a8615f4b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ba903fb0b47f7b7,
+                0x619f6620f3115023,
+                0x198fe0aa5d34bfe,
+                0x5225a2de6d0cd16f,
+                0x4f1a548e171ec04c,
+                0xfa583b096acf19a4,
+                0x98069a9c350d5eb1,
+                0xc8e29bf6c8155194,
+                0xc4bc889a8751468b,
+                0x17da6fa9a16d7c7f,
+                0x616fef7cee01ae56,
+                0x12573a9768482
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xab31c9c309c3bc68,
+                0x827e6c70203f3418,
+                0x4f87a8092b1e3cbe,
+                0x343632a597c537f7,
+                0x79ed3f62f2a8cdd8,
+                0x69380bfb047e2217,
+                0x6aa72d82c1efffb4,
+                0x8b5377003b569d49,
+                0x321ee7d2867d3c7c,
+                0x65af3531b1477f44,
+                0x5cfce8d0a84f8804,
+                0x117cfcc2deb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3c6f8e952b0e105,
+                0xabae5e576fb06058,
+                0x1a4d6f596dc2afdf,
+                0xf1d54d3076f818ea,
+                0x26667183559dc59c,
+                0x4848021b053be301,
+                0x94ef2fa1c6b8478d,
+                0x640dbf03394030bd,
+                0xcd1b55e6be1274c8,
+                0x58918dc74ec95e14,
+                0x7ab79723157dec7a,
+                0x1b45021fe41eb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x75115e26b073f667,
+                0xbb2dc4d6d695a655,
+                0x59537f5475dfbd88,
+                0x5fa93caac90df3f1,
+                0x21efd190bdfb42dc,
+                0x9f3862390872265f,
+                0xdc86b8e98c44dbde,
+                0x16de9fb63152f43e,
+                0xc8e9a6bee1f5c8ca,
+                0x710127b49c2e89cf,
+                0xb7ef792895369f12,
+                0x11cd7dc18c436
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9775fe7aa8b4c7fa,
+                0x94dec5523d045e71,
+                0x55dfcde6a0035745,
+                0x5045f882c0ee1cce,
+                0x957f7129fd86d064,
+                0x45f4d2efeba475b3,
+                0xc95a1ad75797b072,
+                0x412ca61db105dbd6,
+                0x94aa78b3e0a3e16a,
+                0x9fa9b3c5e1eb0e67,
+                0xf3f6b831aaaf3a34,
+                0x1c4234d554ef3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb7d6657363a0e4c,
+                0xfe754160331c8184,
+                0x7160156adef38b78,
+                0xfb3c4cf93ae9dfc1,
+                0xda68f28724693c28,
+                0xad0b034f7bd9ded8,
+                0x676c05e2a0c51351,
+                0xd971c1fe23dbcd6c,
+                0xe6c7e107e1a45e30,
+                0xeec6db7619baddfd,
+                0x7192d1f66e8b4e94,
+                0xf3003657a470
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79d16e99fcf14502,
+                0x4d594e2ba5f3ff95,
+                0xd913e068b25cd741,
+                0xa4d39b5957b8e283,
+                0xe226f7b77a311a08,
+                0x307c277f7e3d428c,
+                0xc4e7882ddc4b8c1f,
+                0x1305b77a1cde09fc,
+                0xca37de96c2f3abdb,
+                0x46f488d1b20ad217,
+                0x6752348c5e6c2258,
+                0x12b5622ce8a6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee5dc29266cbe145,
+                0x78d49c86b2dda5b8,
+                0x4b701582ccf53112,
+                0x88753956406928f1,
+                0xb948495bb20942b5,
+                0xfd98694bf405437e,
+                0x169f1e54ab61bbca,
+                0x7ab92f0196314dee,
+                0xb163d37de7f9a618,
+                0x423c8031563e5c44,
+                0x4a6ae67123ff3b41,
+                0x13eecb7405673
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb13f4692260301a4,
+                0xcf6067ad4835743b,
+                0x6d3d081b51191087,
+                0x5255a8a0f45caf40,
+                0xd7fc2775995233e1,
+                0x7e8a5122f901f8fb,
+                0xd90a10a9cbe8f4e5,
+                0x8a65d637d8017077,
+                0xd0a638ed000fc217,
+                0xb5cbb07fbb6aeaaa,
+                0xc0289704f7ec42aa,
+                0xad8c5326e56f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40473c5ea2857650,
+                0xf17c5c7a7a3318ab,
+                0x3049bf7e9d292c37,
+                0xf3a3bd4621d2434b,
+                0xb905ad0a189d7e08,
+                0xb2d818bf6a7a84a7,
+                0x563fcff0bc4a6617,
+                0x6cbd46d61bc7a8fd,
+                0x3fb799173e42cbae,
+                0x349d09efca7fef3c,
+                0x7de16e2ef06d2ab0,
+                0x1b9c412280385
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a6c8648edaad976,
+                0x56dd43c799f2e485,
+                0xbbc4043b293855b7,
+                0x3e0cac0320125c40,
+                0xd8f00b06afbeb6ca,
+                0x644829a84264690c,
+                0xcd4247e340b521ee,
+                0xe25cec8b3fb0e653,
+                0x97826b365e565794,
+                0x788effda0e9f4d22,
+                0x20e0af581dd15503,
+                0x1192f3d20847b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc7cc56c0a04ba2a5,
+                0x75fea86bd4046cac,
+                0x5acabfe26e03f213,
+                0xed580740ca9f6fb6,
+                0x2f81a18d6cd5d6d,
+                0xcac2f1761be434f5,
+                0x43b5b788250e5293,
+                0x16a6a552fe52abf2,
+                0x9487aba9319ef1d1,
+                0xb7a9af9d7a621624,
+                0xe6800493fb758442,
+                0x10392d3a1fbaf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7dbc579f9f461c89,
+                0x3e5914bc3995a33c,
+                0x12bce26ca64c5f30,
+                0x3d91de5ea05468d3,
+                0xecbd7296fbb45dfc,
+                0xfb2bc99e511db6d0,
+                0x419251184fd59db1,
+                0x55d7941adb48ea5f,
+                0x740407330a2d5492,
+                0xbe12e07e28a1f3ac,
+                0x6e85a7380a86a7dd,
+                0x7e6504112334
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5bf258f3e86ec992,
+                0xc329680cd5c5a692,
+                0xbdbc42e0eb8badef,
+                0x9d1851d43e96779,
+                0xa2b68c8e64542072,
+                0x466903b6b41fac86,
+                0x987d58097b9bb2fd,
+                0xd3c5127f026b3b7e,
+                0x92d42647f71485b7,
+                0xceacc228bba922e6,
+                0x646a14db8aa2826b,
+                0xdc7028fc558f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3cd9a8f5b38e33a0,
+                0xfc25d1171f168a41,
+                0x4fe080325a15e47a,
+                0xed27af79e655777b,
+                0x203fa7ff5edf151c,
+                0x8743b3735411ce93,
+                0x47796c05f458bc72,
+                0xeea90a7dbb5f9f5e,
+                0x98eb364cbe06bf9b,
+                0x53e378c6977081aa,
+                0x7fdfa46403714813,
+                0x942799e0273c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x175c768fdb4041f5,
+                0x3937bcd97bf0822a,
+                0x4652954f42ea7fa4,
+                0xfc39eb10272a3c5,
+                0x20911cbf266e0dfe,
+                0x7f24b745a57ec81d,
+                0x9513b45705168b23,
+                0x2db05f7f63ed6001,
+                0xb494fe8010a15c19,
+                0x963f1b1faa33e964,
+                0x1b5c8e7a70b7401e,
+                0x1796d3f8906c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x210b989df9c409dd,
+                0x4aca25d4742df62a,
+                0xd99c55fb24bd4355,
+                0xee05e9e204e532d6,
+                0xeb8b513dda3e24ea,
+                0xbe3276ec4d54410a,
+                0xfa77a6faa16d3db1,
+                0x150208299ab4ffce,
+                0xcb2fcd2adfc8d6bc,
+                0x297e06cb1fa71355,
+                0x47edb62587d51878,
+                0x17273780a9509
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x52af6d0652bbd39a,
+                0xe4d3f17f19d7caca,
+                0xa309f46baa70feb6,
+                0x8f605a3787a84ca,
+                0x630d659f42fb6234,
+                0x4fa0183bfd9b93e9,
+                0x33fe99b5bbfe6109,
+                0xab2e348c8770eaf9,
+                0xa3087cd36a5b9f2,
+                0x31d698c8455042de,
+                0x981925be9f44ce52,
+                0x27cdaa2b00b5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2010f2bd04bbf960,
+                0x25d73a21560ec12d,
+                0x504ac2cd1c8fa18d,
+                0x2e580b580b568801,
+                0x4b500d0ca2f0a9d5,
+                0x7feea043ee915cb7,
+                0xaa882e59d7b10a71,
+                0x8cfeca7cc125f93c,
+                0x28e807d54e272571,
+                0xe9fb06fd51b38dc9,
+                0x8eb514ffb3e109fd,
+                0xc171e4f0e9dd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x56a76123059cac47,
+                0x373b5110d7bddafc,
+                0x865ef13161cbddd,
+                0xa93a32b673648629,
+                0x4e8f6c96b3c56a7f,
+                0x42ee9d8ae726ad35,
+                0xadd370dbce2b5221,
+                0xbc1d72941d938d6f,
+                0xcc2aa613fcd4049,
+                0x19fd1ad8bc438fe4,
+                0x12693504e7b67f9c,
+                0x7028defe9f68
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x863d361b073af721,
+                0xf6fa8410cacb14d5,
+                0x6ac7bdab58fb81cc,
+                0x902624ffbc691552,
+                0x502825d580c19bbc,
+                0xee4d6cf75495630,
+                0x5abf405ad864aa47,
+                0xeca907ef5f50f46e,
+                0x61ce57dc63d9d4da,
+                0xb82fb88e032fbb56,
+                0x7775c7ccef7002e6,
+                0x17da6382df750
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8f8501ef6e1953ed,
+                0x4e0c42e11ae9c31e,
+                0xd3e6621fc64d6ff6,
+                0xb15601f2354756aa,
+                0xcfb06f931c90f787,
+                0x88c031ad2389c509,
+                0x61a5f4c57019b9bd,
+                0x353d31b007c9d5eb,
+                0x3dea7077b84c6d4,
+                0x2b9f44ea84f2515a,
+                0xc0f18ee4cfb0cf5,
+                0x1786949c16bbd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35dd5cb13a7dc7a6,
+                0x64c73d6bbbd164b0,
+                0xcff98372d8d80025,
+                0x2a39c969793ff18d,
+                0x4f2daefb8e610357,
+                0x60d8c3d504f906d6,
+                0x7a885d42e815dc1d,
+                0xce91f5ca779c2e34,
+                0x5cbce6ffa67133b1,
+                0x991fc2f4c4441e4c,
+                0x8cf77de7ce9791fd,
+                0x16d52ad50d361
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9e14172df8eb60f6,
+                0x9c5229fff3e2b32a,
+                0x817ea48b42fceec3,
+                0x7896f08b5546c999,
+                0x1053b17630d2f162,
+                0x620bdb7815f206de,
+                0x3c4d6e6c96d5c776,
+                0xdde5797ecfd92a3e,
+                0x4529d4834d30ba86,
+                0x2b99319732684111,
+                0x8ae38d443d2e22bd,
+                0x20810a2e9c60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd4a30802c6eb4ec9,
+                0x745710ab9a53fe3f,
+                0x859e35edfff64050,
+                0xc966816c5e839fd9,
+                0x5bcd1f8459acd88e,
+                0xb95d84f15c3987a5,
+                0x805cfa012282032,
+                0xc84b42e572404a9b,
+                0x7ff5e7456718c5b0,
+                0x97479e4de9a493f8,
+                0x27de0edb92add82d,
+                0x12baa93b90fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e1ac9ca6b6353c8,
+                0x55dfd9b3dd21063b,
+                0xaa400f50fc34b111,
+                0x30c22fc0419211e8,
+                0x886a299adf44d21f,
+                0x6aac0551e6b827fa,
+                0x4f6419019f5b403b,
+                0xc36a9db77c1c303b,
+                0x8924fe0a2959910f,
+                0x96353af32876509d,
+                0xcc745e7b891ea30f,
+                0x186ef4df65e2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7667c32eb622ce51,
+                0x522503d64b27b540,
+                0x13443d952f9a3cc2,
+                0x41e62c1ff2ef33e7,
+                0xd9eeb93879af43da,
+                0xd3850e9e5ebf1722,
+                0x971c63cb9213a757,
+                0x3285140c3967824c,
+                0x66ae38a058e7b0a7,
+                0xa3195d0e3b7f10f4,
+                0x5be8c748fd479df3,
+                0xdd7d89c63787
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x51e1a133537fbd0d,
+                0x9f9a11f6e714f1c9,
+                0xa19b014ed6ab7849,
+                0xf5ab0b7b099dd3cb,
+                0x5128f0eb2ceb748c,
+                0xe317f9e2d5437ef6,
+                0x9d84b021a76e1728,
+                0xfd3b35d6c1354232,
+                0xf4b33c15671b5e9c,
+                0x5987a703db5d24da,
+                0xe88b82b42f640463,
+                0x9fc84f4cc629
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf4ba52eb9ef6f78f,
+                0x275572e9d08bff1e,
+                0x9fec2c02cbc01ba,
+                0xc0c272d1e7edc9a9,
+                0xb9d8ffc0448bf005,
+                0x3a5cdbbb4ea308fd,
+                0x6963f51c12fb68fd,
+                0x3e10e68e2dc0f481,
+                0x8daacde3b1533e21,
+                0xd083550d94d3064c,
+                0x555e55992563c8ef,
+                0xb33fd016dfcf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3f534aa30f1ad79d,
+                0xe8190d72512b3295,
+                0x3092801a69518204,
+                0xb78bd81dc771ea73,
+                0xb1efe9d5020b9039,
+                0xabd32f31e125781c,
+                0xe09a3591b4430ca1,
+                0xfa8e0765d74f4a92,
+                0xe0beaf317a1d6cb9,
+                0xdf4f82f91e51f7fb,
+                0x777cc0b34b6463b,
+                0x5b1390727054
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82542dc6d58bc80e,
+                0xdfb6cefaf3429bb5,
+                0xe4d867a3e1f638e6,
+                0xaa89734122ada417,
+                0x2153869f3f1b1ebb,
+                0x66244abbb2a9645f,
+                0x60536de3e12c6653,
+                0x43660105af60a072,
+                0x4322594f1a3a6a7f,
+                0xbafa35eb162dd4ce,
+                0xd77df0da7a9a7a58,
+                0xc1a5b29834bd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x12f510ba3302c248,
+                0x7c2baa607ee5fb07,
+                0xe9c7a389d28c2910,
+                0xf4b7235c1195e607,
+                0x2af4d0cbf838ebe4,
+                0xc7608ba7d2a4231a,
+                0xf36b8192cee93005,
+                0x3adf44f2491dddc9,
+                0x6577de8d1788c1b9,
+                0x117aa667b5863275,
+                0x1398bb52235fbb9e,
+                0xc5bd3698a046
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x569ffdef3a78c532,
+                0xb87dea68c8e67f7c,
+                0x79b5fb031355cda7,
+                0x9938c90056f16b58,
+                0x44e64e9cbd274a09,
+                0x1a7fe5bfdca473e0,
+                0x7cf9237dda53fcd3,
+                0xb7f5602253587d4c,
+                0xb50055c3470c93af,
+                0x72ff38cc1b954efc,
+                0x3196e2914fd44e49,
+                0x12305246c9fb4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x638610c5a1ea46c9,
+                0x43d1d0a54bae479f,
+                0x861fe667c59b7e76,
+                0xafb07703b73fe4a3,
+                0x404a55d9ba5c0662,
+                0xd73982d6af743d48,
+                0xdc2db0aab155605b,
+                0x1a56e5483f31afc0,
+                0x6f89e08d7761c932,
+                0xddf09c47de411f09,
+                0xc5d904f19ba76824,
+                0x2223b8ddd417
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x119e6d0f68f1ffe0,
+                0xc095d03199886923,
+                0x5b191ee4373bd35e,
+                0xb3fa294bd298693b,
+                0xa27567147d71be2c,
+                0xee3cfd6d728365f6,
+                0x5f0c3a2cd252c566,
+                0x8b893618f791eac,
+                0xd071119ea68ae954,
+                0xbb924e47db1af4ed,
+                0x4839a15bcf919407,
+                0xc46fd927161b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x453b53e14aa5083,
+                0x55a6b4fe1cf4af0e,
+                0xaec4595bd38ab495,
+                0xf91dd2bff2bff667,
+                0x661fd4a6c1cc991b,
+                0x7dafcdbea90b54c3,
+                0xd648de7a0f394f6,
+                0x8f2b8750826c6e8e,
+                0xa24b55bc779e1eb4,
+                0xdf194f2f84adf685,
+                0xf4d4d4cd3f90b147,
+                0x69b8a9b2962d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5e5d177129d95c73,
+                0x27c51ebb23fd152d,
+                0xc2bd3b5d3430c653,
+                0xe89af2a48f34a339,
+                0xb0ef8fa478470aed,
+                0xefcb60c4616933c5,
+                0x45ce1c00c6f3e7fa,
+                0xfc27090d2a20a6b3,
+                0x7569474cc045be8c,
+                0xd4533aea094462e4,
+                0xda1d922e87004b62,
+                0x3b6e85711e3a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xea60718b1558a33f,
+                0x4736b9aadea0006f,
+                0x5130b7d228c46059,
+                0xd753ebbfbbd947ad,
+                0x5bf76b4e4de7ba2e,
+                0xeb9a98f684c8f643,
+                0xec462a557ba823fc,
+                0x707453c76593720e,
+                0x372a5cdcbed2662c,
+                0x41cd8aa5e92b2f24,
+                0xf982a812af9e9668,
+                0x10bdccfbc3063
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x373adf4fbb4c559d,
+                0x4aa81f1e8a5bb1e1,
+                0x51731da2bfa4bbb6,
+                0x461ceb9f50e92707,
+                0xbfb3f68e158174f0,
+                0x19995eb908932aa,
+                0x9f754c72723b6db5,
+                0x38d40e00d845f62a,
+                0xfbce8814afcec737,
+                0x1cd80086128131ab,
+                0xc6fc258c3489d9ef,
+                0x2d7aff51cd98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbd4094efaf6fff6f,
+                0x98cf18263e9137c8,
+                0xd62d92a8a4e6a64c,
+                0x8d5631af237919df,
+                0x27ea6e7ebf31e0c6,
+                0x1c719a3313fe8ace,
+                0x3b4c7912012f7b18,
+                0xff9868c8a35bf0bc,
+                0x95ff8d5bf357f8e7,
+                0xecdca5afa5c447c7,
+                0xdc020d80fb9d6b67,
+                0xbe649c42f667
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x46ea52ae1498cefa,
+                0x71aca78b71ce8c6,
+                0x8e0da11adec9119d,
+                0x563d060e68c14d02,
+                0x4374321018a0e6fb,
+                0x1becb22a01b6d16e,
+                0x8cbf89938e23c24c,
+                0x1537dc0c63187444,
+                0x2ea0834cad80490f,
+                0xdc703128fff4064a,
+                0x1b2a63b93b001ee5,
+                0xda0245d8a189
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd7f0f834455fcc76,
+                0xf549ce71d46d708a,
+                0xc92d527531023d7d,
+                0x709acd8d13d1e1bc,
+                0x2674873e2ae4d5ef,
+                0xe35147d363c8b7de,
+                0x5fd2bd2ea3066413,
+                0x8be69de5ac98bc46,
+                0xd70483965eb42e04,
+                0x13625ca872a0b86,
+                0x750c6b085c9e3678,
+                0x16b0de9c2ccbd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xac37dfc278014904,
+                0x29f7406b153f7886,
+                0x2af7aae8500d92b3,
+                0xd8ce7c28e84bdee8,
+                0x6224b8696bfd082c,
+                0xfaa5a8094080dbe5,
+                0x31972255ba7393d5,
+                0xba1459722f6eb2d2,
+                0x4ef41e05884140,
+                0x503fe32897ccff20,
+                0x81bc97f5e9a173cb,
+                0x117b1acb566a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x67d0ba0ec78f47f9,
+                0x536bc72b71d05e03,
+                0x3e57aef786989864,
+                0xfc36c5cd4311c615,
+                0x612da3b03e573b69,
+                0xb77de3eb19bf9487,
+                0x81f0fef01e04daa5,
+                0x382c7b09770ed34a,
+                0x6780275f934ca9e,
+                0x5f3de27ae8d5e18,
+                0xa17cf60f345bcba2,
+                0x489792851efe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1cd3123d5768e8ed,
+                0x6998166a7d6f73cd,
+                0xc280f2d9ee4c766c,
+                0x320ca3d94602819d,
+                0x1c2a219c3a253044,
+                0x9cb90ff9ff967c12,
+                0x2f20b264d8d4fccb,
+                0x234a90345a90f689,
+                0xe416655f10254cac,
+                0x4a97aaee5360eadf,
+                0xe7a7c6768fd5b3b0,
+                0xbd680dc1000e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x88a1e1b920cd3552,
+                0xe033bbc24302ae89,
+                0xda92d8933ca899aa,
+                0xf423f71143ecbf94,
+                0xe96affbe985bcabb,
+                0x53aa4f71b9032c56,
+                0x564c3ab5339d26b5,
+                0x39a285ae049bc847,
+                0xe536fd27ce082f47,
+                0xc46ec708ec9c5eee,
+                0x654d2b3cdc7f837a,
+                0x17b0490a6bd6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ae5581b61d4d79c,
+                0xddf00f2ca541046,
+                0x305f65ffb421714c,
+                0x8c121d7fb52dd4e6,
+                0x75b685e4fa9ffe86,
+                0x438883a3b4ea1bb8,
+                0x9a2dc176e8f48a8e,
+                0x39442578d66f8088,
+                0x769e01bf6295ad7f,
+                0xe0995da0dd35a20d,
+                0xaf2dcb8b9540a551,
+                0x184ec084e7ce7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbf82ac2ef9f9156f,
+                0x9201624cac016065,
+                0xdbbe6b5cea4d07f9,
+                0x43a193e438cb181b,
+                0x1abb0b5e60838bd9,
+                0xaebb42965b654426,
+                0x532b212b6520f1bd,
+                0x154704d473a34e65,
+                0x316256d7deb71459,
+                0x545a25f6ee203894,
+                0x147bd4922e295df1,
+                0x12304c34cfe63
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7284b282d75ed1f3,
+                0xd45638f57e69895e,
+                0x7ad72ee2bb3386e3,
+                0x6ef53e977c33448,
+                0xc126a8fb6352a975,
+                0x79706d888d47e913,
+                0xf3b20a09e3e30344,
+                0xa115bbc5c0ce8811,
+                0xc001dbe960ca69be,
+                0x77ab9ba4ecd13687,
+                0xad4ec8ccf5afea8c,
+                0x146d35e1a3312
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44723ed8e1d83a12,
+                0x4fd71924358b5fe0,
+                0x574189c99fbd6720,
+                0xdb532caa2e7ca349,
+                0x194877171c3fb0aa,
+                0x984a1f3135dd76d,
+                0xe3db39ad15c28b1,
+                0xbf3642a77bee804e,
+                0x5f60afe65a63e7bc,
+                0x15acc22bed47cfab,
+                0x4d96db9c05c22cee,
+                0x8f8d2188c040
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4979914b71e9ad8b,
+                0x2d79498f2921bb81,
+                0xff51ef595a75a5f6,
+                0x44bc0096ae18c175,
+                0x3c4e781035d4885f,
+                0xcc390c9b705d7156,
+                0x8feca110a23283b9,
+                0xa2c0ddc4227b6ef4,
+                0xa9e72abc800970ca,
+                0x26db9e52692acef7,
+                0xda49e0ec4da6d141,
+                0xc81e0454287b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x407c21059169e2c4,
+                0xb345306e661cccea,
+                0x35a06cda8900d29b,
+                0xcb73fcb7fc42f275,
+                0x6b13fc273e0b8e77,
+                0xa723fd553d452373,
+                0x90f1449f43cf564a,
+                0x182934b8bb259a07,
+                0x4ff0cfecc64bb243,
+                0xc9facfc741d0f3bc,
+                0xd120d0aed9f2d2bf,
+                0x117a1aac27cc8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1d4547b6a0e963e8,
+                0xfe4bca44d737cea,
+                0x8262a879f153d8fb,
+                0xf6e015a2e1ee94e4,
+                0x6746ec300d0e761,
+                0x1a9ff9553f42b379,
+                0x89f6befb7296a319,
+                0x6825ff89fba45a68,
+                0x98e7f5543b824d67,
+                0x54c1e0fc092cc411,
+                0xf43ac359ce48fe51,
+                0x9b5916bbc70d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4783717416675618,
+                0xf9b62bc50b7a1f45,
+                0xea0e6ed5417e7c67,
+                0xe339ebcafeafabf,
+                0x9cd7f9314816acb3,
+                0x6f31377dd349846f,
+                0x58c1a1cc0ecca36b,
+                0x1a1e235dad9f8491,
+                0xf5975c3d6f02629,
+                0x8bc2bd895724269e,
+                0x3e8c053befa43ceb,
+                0xb404b6c240d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x76d30b53ef1dcb92,
+                0x43b3015fb42645c7,
+                0x66443f10859577a2,
+                0xd9740143481e9a65,
+                0xf5d87931afa4c20c,
+                0x2137db93d996612d,
+                0x3011e36d22a038ad,
+                0x19af5a8f507e6616,
+                0x36849df7bf8f2254,
+                0x17a97b86c79a70e1,
+                0xbc63984bfd3d8ac1,
+                0xc4732273a629
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x29193ee6b3677f49,
+                0x8b57712f31a2d806,
+                0xc0f5604434431ee,
+                0x3c825e8213b88379,
+                0x103d2a9efd25c5af,
+                0xd2278755ccaebe66,
+                0x6c8804c351b108e5,
+                0xe49b1e03862d3c3c,
+                0x805aa01031a841b6,
+                0x1f7dd9ed2d0dd0c2,
+                0x7df00ec11f842c25,
+                0xd1d1b5a17beb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f167495b45fd704,
+                0x7b82d0161e4288f4,
+                0x1922750dcb310779,
+                0x365831ad2afe2358,
+                0xa3e254518164865b,
+                0xb11ead4195bb238d,
+                0x5a1bf2c4456de7f8,
+                0x13252f7a95691b5d,
+                0xb772bd1f118e7bba,
+                0x25d7fae56f5140be,
+                0xec57fb3ce4ce49e4,
+                0xeab75c957151
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcc7c302e717e7c94,
+                0x21bdc5fe8417c311,
+                0xef2c40308041acaa,
+                0x17d6f22100d95f62,
+                0xb4c48bc11075c8fa,
+                0x6ba0d4533dd68e0c,
+                0xd873d03f06653b6b,
+                0xafc0d70bc52d4bca,
+                0xa6ae95085f5ff3a1,
+                0x13e13556a5a3c4f5,
+                0x53c2f619461bf8b0,
+                0xbadb65affb64
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a3f99590adbceb8,
+                0x6c9e21c78430a889,
+                0xd98c55e7da6c29ee,
+                0x98e950e9cbd1a717,
+                0x5b08aa42b74af9a8,
+                0x1baab2e55d52dd74,
+                0x158b642dcd5f0c6d,
+                0x5ac2025234c48b62,
+                0x7915b4204494634a,
+                0x56fb55a482302e,
+                0x4a131442f1c260d2,
+                0x18beaf811b10f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc677fb2c075457f2,
+                0xd1d51c95992628eb,
+                0xa25eb82ad66fe9ce,
+                0xcb2fd2c1dbf6ffdb,
+                0x36a7de130022fb7e,
+                0xcfc6db8be737bf9b,
+                0xd89e0d257fdbacbb,
+                0xb59e4265f0d1cae3,
+                0xab71817d4a0bc6f8,
+                0x8b55d03025c26dbc,
+                0x14c0b48bf50d23c6,
+                0x17ac88afe8022
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc27bfd2d70c275e7,
+                0xb98dc37c1d86fdb1,
+                0x17148bd362041816,
+                0xb8b91862a1fbbc6a,
+                0x5fafa5ce69e0177e,
+                0xae648cca8cb264b6,
+                0x2bbcac1fb392ba20,
+                0x321e5259c7e6b15b,
+                0x420d634c8f861d61,
+                0xb2a739fbea47b768,
+                0x7473b6956753dc60,
+                0x1c06abf63cf1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xedaf6b1c106fda1e,
+                0x60021674d5d22c04,
+                0x2dc557807022666a,
+                0xf8f31c2839f460f4,
+                0x1c250d3a95054932,
+                0xc75be3bbf3069814,
+                0xcdc42200937d1ec3,
+                0xff306f7adcc567cf,
+                0x1cc411633e1283a7,
+                0xeef40517b72dc59d,
+                0xdebe654e1cedd573,
+                0x50af550f9674
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3876de168ea63865,
+                0x8635cebe33e80359,
+                0xb9dd944558261836,
+                0xe8efbf715708a0db,
+                0xbf80b3b2040ce279,
+                0xf095ad235e3b424c,
+                0xf28c7af05bad6a91,
+                0x16e8b8518db52eaf,
+                0x54ee192b0e05f10e,
+                0x1f2ab860006c42e2,
+                0x4494888a56a582c9,
+                0x9323690ee3ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf226f9087e65bae6,
+                0xb426f77bd70d37c9,
+                0xe5f45a66edac52a7,
+                0x8685454922f8ae3,
+                0x21f43477e00c0395,
+                0xcf4e5f698faf7737,
+                0x88a2bc2d9a241d8b,
+                0x7afa416b67526ce0,
+                0xae619e7119141ba0,
+                0x372d1269257b2b8a,
+                0x9dcf51a618ebd10a,
+                0x1168150d057a4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x27eabee89dd85de7,
+                0x24d6f1a39405bc14,
+                0x7b91029e61f027bb,
+                0x711a15603007f37e,
+                0x8dd88caec09919ba,
+                0xb6a7f32c70d31aec,
+                0x6df9771364a02b46,
+                0x88d417809c5e1222,
+                0x8ce12132663fc569,
+                0x6aa21b7b45d9aa3c,
+                0x7f8283b559a87159,
+                0xc33ae1f7dadf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x76a5c2616a1ffb5b,
+                0x2473d405353c5130,
+                0x9df88062e78e57e9,
+                0x579c299f453889,
+                0x91c9af56e633f9f3,
+                0x840f359f4e16b29c,
+                0xf21c8692a66d4bea,
+                0xf7c7ead292891459,
+                0x59eb283e40b6256e,
+                0x883f2d18257d43f5,
+                0x79926660232756d7,
+                0x19ac16f194259
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe7abad5da2299286,
+                0x6d323ebf8953fb44,
+                0x1a26374370bdeeb1,
+                0x1ce7567eca5ffd24,
+                0x849ab6d9f6c23ea6,
+                0x74c97d8fe6d4d8a8,
+                0xdbd74d8f677052bc,
+                0x1e3b1815de9b7c0d,
+                0x5b61fd5cd37343f5,
+                0x29f32ccce2872b8d,
+                0xff6d3cdd97a21f21,
+                0xdd194b0e88fe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xec5a226800edf217,
+                0x9d6d7ec689f010fe,
+                0xbfa4dc324a525a5f,
+                0x862f377d395e5721,
+                0x4bbcb8e4caafeec9,
+                0x2e0a2bf2e0a627b1,
+                0x9ca1f7f376bc7239,
+                0x523c16c460000253,
+                0x515df2723af4353a,
+                0xcb03ac814bb01938,
+                0x22a8210e823764e8,
+                0x84bc8facecd2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb87ae6a0090adc60,
+                0xfe7cd1f3dd758982,
+                0xe69add05417211cc,
+                0xa121f2b02893f07c,
+                0x64f22af2eaa2047c,
+                0x31e889395aaf5d2a,
+                0x7b69c7d53782626c,
+                0x578d72536540f508,
+                0x13306e1ae9b14de2,
+                0xbd4f58b5b234943a,
+                0xb3177c2fed656bbc,
+                0x111d26b447314
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa514b99f7a344182,
+                0x1d762bd5abfe27ea,
+                0x4583fd971647b500,
+                0x87eaad2a48b9f56d,
+                0x4ebed460ca261dac,
+                0x3937ce790be34d08,
+                0x99914475b39bd427,
+                0x334015fc7ee5b7d,
+                0x2270c781e7f2fbdf,
+                0xee19afefdc4c60d8,
+                0xeaccf8ed71509af8,
+                0xe49c64a84f6c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb7b8f143e94dc3b2,
+                0x1a97cb2224c4a45a,
+                0xa497beeb8c805f1b,
+                0xd3e5d0905b42c7f4,
+                0xb41abe71c8fba692,
+                0xd2f99f8faa54d051,
+                0x763963e842ca9a5b,
+                0x6947c893df76b3f6,
+                0x1d0ef4f9b27fb429,
+                0x30e2a4c3ac9fb806,
+                0x40e1a0a6209b979b,
+                0x1505ae3e45969
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xea9b570a7555f29f,
+                0x5dd373a7c9f9de37,
+                0x4e49b5edf98d3509,
+                0xde1a69ce39289e5e,
+                0x5a0db68783680d20,
+                0xad9acbbfaf12d8c9,
+                0x69cbe35a8d0400f0,
+                0x7faf715264c53626,
+                0x1d80c1d859cc75de,
+                0x1f46fdfb5e968a50,
+                0xf3bc17895340ffe1,
+                0x15872de653e0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x184e3cc6e9ceb2ed,
+                0x2859a76e30fb9433,
+                0x6bf48883b471ea6b,
+                0x77323c8a70914624,
+                0x4146bcc6713ec30c,
+                0x7ad0e32ecff934f0,
+                0x284c1bae2ff2f786,
+                0x3332a24e4d63a2d,
+                0xe4697b4a0b7d8642,
+                0x94ff007d5fcbdc4,
+                0x2631a9e260d1e9b7,
+                0x1691d325cb2cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe8e54f5b9bca6402,
+                0xf3e9c95cacbcb0f6,
+                0x1b7103920476d499,
+                0xd22cfaf97c0f3730,
+                0xa9b15322279a7f03,
+                0x65580ca9f3da5437,
+                0x3033fe364f6eef27,
+                0xe92ebba5fe34282a,
+                0x8136043289eb0cd7,
+                0xbfbebee61e795600,
+                0x5e4859998bf2b048,
+                0x1ee815d058a1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4193c1d169c540c0,
+                0x6c03a55fac7aaaf0,
+                0x3fb23154fe2a44e8,
+                0xa143cf23bee0cfb3,
+                0x2928cc72ea9f5ead,
+                0x1580c90e76c48ec4,
+                0xab518165ff403679,
+                0xb15e401aefdcb669,
+                0x3e65629542615910,
+                0xb3dbd297f1480f31,
+                0x34b2921eec1dc95d,
+                0x13cc059a7b9df
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1577ba57ebdba5b,
+                0x865b1432540dd70c,
+                0xd1faa2f0d2c16bd8,
+                0x9647c428c91943b1,
+                0xc5e4314653047424,
+                0xcda0ac0de47ada31,
+                0x2cfc7068021c5ed1,
+                0xf544a98e5a213f98,
+                0xc5dec8b73a9243cc,
+                0x800bd36bed7c0c3d,
+                0x9a7c2b1b5ca51ee7,
+                0x18d6686c5c8c3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe616bd42dbfc0d2f,
+                0x1d538e70ad2b123b,
+                0x2ab87f9d21372921,
+                0x499c616131a09bd6,
+                0xb30abd9f69860db2,
+                0xd4d77ae9df7d73a3,
+                0xe5f01a21dba3df33,
+                0x210ef31d85ca2bfb,
+                0x95e562b0090040f2,
+                0xbf5271d54b5c0f,
+                0xda2a87061ac99e9e,
+                0xe24b095a2cbb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x847bfe594b3ddaad,
+                0xb6465f33f3a90d4e,
+                0x1305651db9c3c16,
+                0xa1822a422e1371b3,
+                0x227790e1bb229cc3,
+                0x2c58fd655098a0d,
+                0x7c13085f6047bbba,
+                0xd0a3eb7982747c24,
+                0xf5f3643767b42e05,
+                0x75b97534fd2ef758,
+                0xb95a991c2ca043d1,
+                0xc00b7c8f6f76
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8734e51c97eb505c,
+                0xa1ecbfd8ee36984a,
+                0xa55fa4929c7d7fba,
+                0x8c9a0f5c308dec6a,
+                0xa5a40de2365cc308,
+                0x3f69f923ebb94de1,
+                0xd26646cefc0a5d5e,
+                0xc2d7d3960bd95dc7,
+                0x48f1a37b969d7730,
+                0xfdea93bbe1c6bf82,
+                0x51c50dfc787a4de1,
+                0x1117afe8e922f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x64ec607a32ab53d5,
+                0x606f23fbb476c50e,
+                0xaf2ea419e81e1ec7,
+                0x43e82c4473f37cfe,
+                0x6ca8df56a98b5b1d,
+                0xf6b8f25990b8a931,
+                0xb15d91322b792c33,
+                0x1a20fff761b6e735,
+                0x65eb58d99f2e8384,
+                0xac3d66d920abc473,
+                0x2c7b219a5f7ae512,
+                0x82a2d7afe878
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43930c1fc0a7a45d,
+                0x446df690a5060e53,
+                0xaaa21defc2a3c2f1,
+                0xab8c37390fa45aa9,
+                0x2c93963c0623de95,
+                0xbfa9a8ca5b30a882,
+                0x560cb2d57d691151,
+                0xec566cf6f5e49994,
+                0x214f21c5c4d97945,
+                0x137475ae55787908,
+                0x22659db5f6863378,
+                0xbb262f45c9f9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa245c764b1bf41c2,
+                0x9229ad937c803fce,
+                0xac49394ca290444a,
+                0x9931c1d500587151,
+                0x30a59f6f6c3fc62,
+                0xcb13ed6f09b723bc,
+                0xea88e4eca62071c2,
+                0x9c18533a6e2c6fc2,
+                0xd7e48b77e41e2e29,
+                0xf78da1936be55908,
+                0xa096c5beda6e3d10,
+                0xab36183e4088
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1c53620d7c5e03,
+                0xdbf0559391fdb63a,
+                0x3dad29d6a33285ff,
+                0x32172dde6a16784d,
+                0x212650481da8e880,
+                0x9130b52ec5043dad,
+                0xa05db5d36554f33b,
+                0xe83e4a6254577944,
+                0x1c5b75082d3be31c,
+                0x6b44c95707412c57,
+                0xe5c927459c1f2f14,
+                0x148c92fb658c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa16953c020631bb4,
+                0x535623dbf5a6ede4,
+                0x5d0c8fb39b99a4f9,
+                0x82687fab85669c29,
+                0x39d536f30977a0c1,
+                0x9dce920008ca7bad,
+                0xb1090a625416cb11,
+                0xb4b0369f112efefa,
+                0xb778358a84951221,
+                0x5ffcd33811203e4e,
+                0xa4c0dc904f717e89,
+                0x7ec570f93571
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x94dca8316a327c21,
+                0xada2a9ae51d79cbd,
+                0x7e20bfe0516e58f6,
+                0xd48734e0ca4fa641,
+                0x52b0b8ba5a5cb768,
+                0xdc362d1940da6e5a,
+                0x4f3203291175b05,
+                0x1be9286503eb3e5a,
+                0xbfd9aafdfd0b873b,
+                0xdef6bd5d5f391c9e,
+                0xdd7fd9d9fe627e2d,
+                0x13b78ee2fb7f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fece6df9ebfb86d,
+                0xd9de34e857fb6988,
+                0x6cf449822a31ec10,
+                0x94ba627669615fdb,
+                0xce790c52b3fb8e7c,
+                0x9ae78e8a446aa84c,
+                0x25205a065a715af6,
+                0x8c1a35e7e4064c5b,
+                0x5bc9ed23646ba8d,
+                0x8af17532411f1d28,
+                0xca18b00a5d6849e1,
+                0xce2a3a2c9924
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44c2a79b6ef687dc,
+                0xf2338c1bc68721ab,
+                0x6d39c3b32ca1129c,
+                0xd44bf283fc632bd7,
+                0x195d88092f5044e8,
+                0xc31146d153bc35c3,
+                0x4edde5032e3e1ad6,
+                0xc4eadf19c610a6c,
+                0x704caee59068111e,
+                0x8af031fec985320,
+                0x873b8cf6eacff126,
+                0x18cac3e38dce9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xebe8c045a35b999a,
+                0x6bc7087e6cf1624c,
+                0xbc11a9f074cc45c5,
+                0x474c8122406cc39a,
+                0x74d1acc2ff864ec6,
+                0x4e62aa62cff223ad,
+                0xa7f8f3bda4d3dc86,
+                0x4005de778d5e3edc,
+                0x8e44fea3dc108c72,
+                0x66e5149f0332477f,
+                0x76e41733d840bc25,
+                0xb9527316a2a5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee9dc982014fd6e6,
+                0x49c44f7cecf9d7f6,
+                0x66fdc3759cd71a43,
+                0x3959e20c0e44d18d,
+                0x3bd73bdeb6ab4346,
+                0xb97626da1aaf7042,
+                0xa66273dd6861f570,
+                0xa5dd8eb8a5f93acf,
+                0x815d42d853c87c72,
+                0x7b4f82e48f1446be,
+                0x14282ca947442596,
+                0x59b0bcfd9246
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed42c3a294bb614a,
+                0x8599cd2d0139317d,
+                0x647231e7c3fc0f28,
+                0xe10feea8809e7469,
+                0x44596ca44fc54a4a,
+                0xac8aeac11b627b1,
+                0xaea9df84d74a5bcf,
+                0x9c902fa8f0c449eb,
+                0xa30753bfabb43445,
+                0xfcfe657275f41728,
+                0xe89b31caae98cd33,
+                0x148ad9492afd3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc98c3e66b9e98004,
+                0xc7ea0309472b3c3a,
+                0x9bc99ef8f29ab2e0,
+                0xc90400c3f077b0d0,
+                0x80fde7ac02cd9b6c,
+                0x2f4a5e97f4afa64b,
+                0x3aeec6c78a093109,
+                0x326877fe7c5b9dfd,
+                0x9555efd37ccf4ae3,
+                0x388f3d2c789442c5,
+                0xb312f96ba8fc33d,
+                0x19d60ec3108ae
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ffa4a5e6b9206f0,
+                0x3a7d7020d0b55c88,
+                0x8ce9f75c4394a597,
+                0x326f667c93d5e3dd,
+                0x9377e244d9c07a49,
+                0x3ff702602c157473,
+                0x420c052b0e525a82,
+                0x58d6387d6d2b8729,
+                0x39ab9265227241c4,
+                0xb1286e53ad194168,
+                0x4ed028cca02c53d4,
+                0x92044a3b572
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa2cc0432a7e58e2,
+                0x57ceac8ba1308d27,
+                0xe7c59694032cb9f8,
+                0xde3dafe1f13af096,
+                0xd43d4a02bac0dafd,
+                0xe1dedc8947558aad,
+                0x89fda9085bead3dc,
+                0x7e83babbdc1c784e,
+                0x76d3327b3864cf32,
+                0x641b453271e50dee,
+                0xf540e1e69ba37907,
+                0xf4a3f26c387
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb38582a58862ea94,
+                0x9a5793ee549b14f6,
+                0x7b76ad1c578b797e,
+                0x2b26312dfd8d821,
+                0x5b563a730a17c7fb,
+                0x91d50e7acf77e6a7,
+                0xcd475ba092c4162b,
+                0xa775b1c86f802733,
+                0x578ee1fa29c1018f,
+                0x9250615498bbbc43,
+                0x21afc70c98a8f7b,
+                0x2bd3c9db6042
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2d4cb76610e6759a,
+                0x901264eab27c9322,
+                0x6a11cc8f5b43e668,
+                0x24003db2648c13b6,
+                0x3f4aaabffbdc663f,
+                0xc2635fb9f9905f05,
+                0xdaa9e7de6055d564,
+                0x7ec3b6074344bf90,
+                0xb5ab10b3e7b58153,
+                0x928c2a2ca29769e0,
+                0x7322317204f062a9,
+                0x4fd1e189a63c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79940c642b9d0b4c,
+                0x1740a063172c13a6,
+                0x71097ee153cf0bb3,
+                0x495e0eb108d32303,
+                0xde9465df58c22c11,
+                0x19c2036f8b73c2e1,
+                0x682efb5781c7877c,
+                0x3f8bee57951088c0,
+                0x6f26d832d9f3981d,
+                0x3934b6c898305151,
+                0x9a49199efba138eb,
+                0x153b7513f9182
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x18245549c6d15a7,
+                0xf630f1a22026a4eb,
+                0xea3fc0bdae280ca,
+                0x44974cda8aa8a1e5,
+                0xe4803ecb61c534f8,
+                0x76d08e0b43d5c5c8,
+                0x4493ef6a56666d80,
+                0xf1d2953f5d7abd44,
+                0x6579e9a507ec8699,
+                0x6a9e827421d2abf3,
+                0xd4772092e011655,
+                0x1650b3c231b19
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc8fd13c3cd692a1e,
+                0xe3607f0ea112bd47,
+                0xa05ad6b0785e02f0,
+                0xc87d10156197d640,
+                0x185017968db78f1d,
+                0xa803a43b60f54837,
+                0x4265876164093125,
+                0xc9ae914447450612,
+                0xc4a2bb51a028d41f,
+                0x4d0d5007fccac6ca,
+                0xe55665383e6641f3,
+                0x7c22b541def2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98998149cf4fa64d,
+                0x548677a6fe76c342,
+                0x6bf2afb34aec7eea,
+                0x8ce5ce21d781ac22,
+                0x99df49c8f402bb81,
+                0xa14efb1a3ee00d09,
+                0x9022a69fe2992dad,
+                0x6e242efefe813445,
+                0x685c2a2da6753de0,
+                0x1cdbf6445e474c78,
+                0xadd99f1bc9d5c66e,
+                0xa3ceb91c4894
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce3475f33b57796f,
+                0x808868069dbd57a7,
+                0x44b1e032571a5940,
+                0x34aa849aba29eddb,
+                0x1018abfb841502df,
+                0x25a9894e94fb4c9f,
+                0x16fa9036ee0be9c9,
+                0x4bc47f59f50c850e,
+                0xf461ce243ab119c4,
+                0x99c9b4398284a2b6,
+                0x2a2d528bee4ef415,
+                0x1b3a987f465b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x74d043ad6eeea328,
+                0x2ec78c004712fc39,
+                0xc402fe2674d5696f,
+                0xdbbd0e2471c90caa,
+                0x137c68961f9ac457,
+                0x35e9a939ae4fae4b,
+                0x344a9276ce1fe331,
+                0xfd5db4696f632698,
+                0xf0d11ba1e367486e,
+                0xc7c219bd257a906a,
+                0x86337ac57c7488ec,
+                0x1051d0f26c9b1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd9c118fa7a0c71a,
+                0x903d02a531c3d085,
+                0xa2876d3b773b0ad3,
+                0x80ae4103d74bb10a,
+                0xae7a453242af50c9,
+                0x804c9bc84e95392f,
+                0xb98a2f5d1d291239,
+                0x654df43d4ea9e0d5,
+                0xdebba5e20da2ef73,
+                0xefd2f11ee66233d,
+                0x27756a3c9d497498,
+                0x11808c094ed4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8b032208c81db95b,
+                0xc22621e4a0fd5ba3,
+                0x31d091e4b72067e1,
+                0x3478e20722961603,
+                0x499335115128dff,
+                0x56afc651c69d7e34,
+                0xd9e4499b3a87a003,
+                0x34764314c14595f,
+                0x7f0b37c12f18bac9,
+                0x64a654c1d40ae0b3,
+                0x386119e3e751e53e,
+                0x125f1c0f9d023
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2ce7c1c1e625a992,
+                0x353edc508551692f,
+                0xf50802de6552916a,
+                0x27fc9f4a417ddfa9,
+                0x8e25de3f2ca03166,
+                0xa976573d736d237f,
+                0xc3a2be5f0011afd,
+                0x6105f65b990fca9b,
+                0x72808e419f7a65a5,
+                0x4ee9cbf80e967a0b,
+                0xd49fad9722836ba1,
+                0x5d5f97b61481
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x105028bfa5811087,
+                0xdaa94235eceeb416,
+                0x3b0545de8b7500ee,
+                0xa4aa953b692336d6,
+                0x39fc30d5e3e7120f,
+                0xe6a935d8f99f796f,
+                0x3bb004040c041417,
+                0xf71ed9522994c59a,
+                0x5a5d4ec65b998b1b,
+                0x6acbd8614638015c,
+                0x4156e281a18b6757,
+                0x646b47e917fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1d0ba85bfd4b0622,
+                0x4efc5a0809f152f9,
+                0x405b73fcb026ffe4,
+                0x4ac994183c3e75f5,
+                0x318eb189583fbabe,
+                0x10adb2717a61acf0,
+                0x899e4c8be6523625,
+                0x34a0a311099406aa,
+                0x35e4124769188781,
+                0xd0330017fbe740b6,
+                0xc89b286c707040b5,
+                0x7ef1c41f2313
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed66e6f3fae855ab,
+                0x6b40d11026c22520,
+                0x1b96aed663ca9f9e,
+                0x92d6b8f213ad1133,
+                0x96ccd5a096d3afb,
+                0xe7d718c18f63413c,
+                0xf1d346c191c5fda0,
+                0x986526485569e2b,
+                0x981768cf4a9f3038,
+                0xc10641bfe255f550,
+                0x55abb66357c720fe,
+                0xaddece427ca0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x59059266ae49480f,
+                0x895c4fbd5d4b71ea,
+                0xd5784848395953f7,
+                0xf79d84e69e2b5571,
+                0xc82fc0c5ba8c9132,
+                0x57f1b5d94f8b6c20,
+                0x5c422ae96d289ce3,
+                0x3eecef2a1bf3373c,
+                0x9ee7c9e93801b279,
+                0x1fb0a68c69244a6c,
+                0x5c9884aa4e176d3c,
+                0xedf32063e2c0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6fd61fa06915d70,
+                0x5825db9638550f60,
+                0xb0ac2fcdbc30ad4d,
+                0xfe72ea29446e6e28,
+                0x781b7e08102a2eb6,
+                0x52334e52cddcace6,
+                0x587710650450855a,
+                0x9f3f9fed6937d144,
+                0x9915f752b707e17b,
+                0x25bfc24669832e4f,
+                0xe6bdfd468e29a184,
+                0xc5890fd41086
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1abe7e142f21c54c,
+                0xcddf01c3daf265d4,
+                0x9a8c97afe37c4b26,
+                0xdc2b2bf01f243359,
+                0xb92ee8d339189a33,
+                0x8d392d0a1f52d41,
+                0xa168fa99e27b5fba,
+                0xfc84f23e2974e7ae,
+                0xa792f6e0062322f1,
+                0x46baf7a5ef10f7,
+                0x6bf92161aefdb95d,
+                0x9ca62faa8d3b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x964d0b002754dfdd,
+                0xaf8340c82273815b,
+                0xb3232103978030e4,
+                0x6b0f7c5766d62460,
+                0xf60913297305a6c7,
+                0x899e074f6164c966,
+                0xde4a09382715c5ab,
+                0x292680c9fa867e4e,
+                0xec7ac4434284a835,
+                0x5ac011a76e59ff30,
+                0xea12dc501e4d9ee9,
+                0x125e843a07e68
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c993810c0687fc8,
+                0xe1f505e00db933ec,
+                0x734857c025014225,
+                0xde5edca2a44841db,
+                0x7ab27813d0a3f349,
+                0x5e505a1745b13a61,
+                0x918e8a4db58bc895,
+                0xbf0485ce3a8186d8,
+                0x43634a87a200500,
+                0x68d1777accbc4f47,
+                0x3dddb961a08431b2,
+                0x15b53460fd99d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee900dac75d9cbbd,
+                0x43f78df72ce17812,
+                0x2cbb541c88a8b645,
+                0xbf8f8e82802e054,
+                0xdcc0fc99fff2c77c,
+                0x344a3ebca90cb12f,
+                0x9edc80084d371996,
+                0x56c3159d24fc676f,
+                0xabadf418ecc8adf7,
+                0xa287b11e2d1a7ecb,
+                0x39504f3e02b1c3bb,
+                0x1a96d9cdf96bb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe93dde5897ab2b0e,
+                0x6544133a321829d1,
+                0xcd5ab3ba35283941,
+                0xf3ae3631f7f0220e,
+                0xe323fef2f04d4e48,
+                0xef3599cc1cd5c55,
+                0x8a5095caf07e3549,
+                0xf17d14e00a735ed3,
+                0xee73c93e221056e4,
+                0x41493b148d9a483a,
+                0xcd04de5fffde391e,
+                0xe2f6c8aa8300
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x651cb0eafb90e69,
+                0x438ac99f74909f80,
+                0xcd8477df6d673b82,
+                0xbf0694619bee6ff7,
+                0xd8641652c6fa3b4,
+                0xdce0bbfc92fd37fb,
+                0x818aaddc501c93ef,
+                0x3f410ade29a96a04,
+                0x5970e98f86636e62,
+                0xe8013c3f58cbce06,
+                0x7764928bb24ed116,
+                0xfd4ea7ba8762
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83eaf2edfd7ea99,
+                0x7b67943874839eb2,
+                0xb2431e3bd754151,
+                0xe2a8cb0b6a52f3d2,
+                0x443646840055887f,
+                0x4933a5f538dba25f,
+                0x2dc7771a4fb6b14d,
+                0xd1f2fdb844d96a48,
+                0x807896a476037622,
+                0xd7cb3b1e50e9a1f0,
+                0x47ddf9f43ec199d8,
+                0x8d3b09d76dce
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe37b987b3c183675,
+                0x3ab7c912e362e4f6,
+                0xb824a538c18ea11b,
+                0xaed412931561e00,
+                0x822ff71fbd12d44d,
+                0xa824772e0902c7af,
+                0x1255002c445ec267,
+                0x1a8491ad49c85f50,
+                0xbf201508dc787f16,
+                0x3fa02ba2d25b9f47,
+                0xb40b07d77ebf5d56,
+                0xd05d343382ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf910b7ef6cfc77e8,
+                0xa474eac138e4673a,
+                0xff2b7a0585f41dd3,
+                0x2f0c0fee4f9657d0,
+                0xe3c41b3ec7d5e2f9,
+                0x80483ca9385ceb8f,
+                0x5f8567ef434615e6,
+                0x50ba95002386f14f,
+                0xb79d2e35bd107551,
+                0x857775c89c58554c,
+                0xcb320217b568798a,
+                0xeffb7c92c471
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x317b252dcbf3ad9b,
+                0x6b4c15e700e76fdb,
+                0xdf84acfa6b206f48,
+                0xe532c6991c33cb28,
+                0xc739009072fe21bf,
+                0x9b32f925d9597c77,
+                0x84f8ec57d8f7708c,
+                0x38b1fa93bc3945d7,
+                0xbdd3f29077842907,
+                0x6acbf860928976fa,
+                0x825af8b2b6950958,
+                0x18d3d5c12b26b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1c2af1a886bbb181,
+                0xa3bf3f1380e5d3bc,
+                0x8a33ba1fc0dfb93e,
+                0x6edb244aa0a8a9f0,
+                0xb4d60c0b37639e6c,
+                0xcfe58ad4c996c84d,
+                0x516e69fabb7c34ee,
+                0xfaeabe9716e5b164,
+                0xc19f0cf890df52d2,
+                0x622a6317fd9215c,
+                0x63fd67bdac4128c7,
+                0x19a3e45e1d999
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x798050757ead2d39,
+                0xaa46b164e233f36e,
+                0xcf31867f358263f,
+                0x68677a6a98b9caa4,
+                0xc6ef6da1d317b831,
+                0xa75c4670f9a7f7f,
+                0x6f7c4735733d30bb,
+                0xe30171dd35bab55c,
+                0x4f9848e39418c1bc,
+                0xcac39dd6d3b15fb0,
+                0xf0c8ed90315d625e,
+                0x18f80fc16b805
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd893e3bc0eac4ee9,
+                0x59cbdbf849ba90c0,
+                0x847d3b737d70c666,
+                0xfd2c3c4f4c557a2e,
+                0xc020264e35025a7b,
+                0xf86807e643d94326,
+                0xd018a31f718a15b7,
+                0xccca26cafb8d70eb,
+                0x25f31e4a3c4bb336,
+                0xdb037043b07a4fbb,
+                0x9706accca18c5c4b,
+                0x1070abd8b8951
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8866f1956c4743b5,
+                0xb40b6fafb11a005f,
+                0x7c12fc775f1b38d3,
+                0x87a888d5e8cb29b5,
+                0xeb0e4af458b02377,
+                0xcd33bfa949fd0c3,
+                0xeaf433092821bb8b,
+                0x4af0afe27cd268ee,
+                0x9058e02ad2845e6b,
+                0xb0906857aa2299bf,
+                0x9e0bbe04d3bed7d,
+                0xd79c870e2857
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd20dca143bc320c1,
+                0x204c15987f577f83,
+                0x9b6dce2c3e1a756,
+                0x21841c6b2ef1f1af,
+                0xf4161db363355121,
+                0xc2d6e302525cdc9f,
+                0xc120dbb0ad4f2b8,
+                0x799c77b534fb4c1b,
+                0x8e70fbfbc4c75e83,
+                0xa6e60f5690702765,
+                0xc31cc01428a1c120,
+                0xb94d8e8c3a05
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4d4c92b4f1e36b6d,
+                0xbf015397c8212d7,
+                0xa3f8cc76aea86fc1,
+                0x1acd8ddb47b0dd35,
+                0x12739987233a8b2f,
+                0x7e37e1fd0e895033,
+                0x6e8999885dbebe7a,
+                0x8972ce15c2d822df,
+                0xb78be9fa528ab482,
+                0x79a373a5af018a7c,
+                0x2c6d1278d99b0271,
+                0xaf2fce957ded
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3ba2d8a80ba82d2,
+                0xf32de0686472246f,
+                0x4ec2ae4d56c52308,
+                0xb5c1171cea0c5ac,
+                0x3bb4f2a74e19d822,
+                0xb2e4d5e200045614,
+                0xcbff6cd05ff13817,
+                0xdb63f18e1d688eaf,
+                0x211239cf74d4c3e7,
+                0x8f0c5f33f9d6822e,
+                0xb64aba4a36ac2025,
+                0xee47f4f67a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa98284f19ad2fdbb,
+                0xc492dea6a15b6d9a,
+                0x9c69e2b233981f99,
+                0x10637318f17f5be5,
+                0x440ff62e7cb443c2,
+                0x2f8d0906e6faa3e9,
+                0x652faa4a1d4947c5,
+                0x9d43377e98825391,
+                0xb034bbb0a754da42,
+                0xbd37c59ac0fdf3c,
+                0x76d29210e86180d0,
+                0x1af0f97ae69e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3a2e27abd2b4e2ee,
+                0x41e3bab57b8a733e,
+                0x5c90a79fe8323541,
+                0xc67888a1c49ba4a9,
+                0x8b6cc6c03fc648de,
+                0xfe3fd3fe2a397abc,
+                0xa0b81f9210185570,
+                0xc0cbd9d59bb6dab6,
+                0x8c84cbbd4c230440,
+                0x49e1a6802691e674,
+                0xa668af08561b0a6c,
+                0x135ac4797533b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x549124a40538fcfe,
+                0x7b8b15f376c3d6b5,
+                0xfe4b19712eb0ce1,
+                0x6e56032b8dcceb38,
+                0x8e9bd2bb11ba3a8c,
+                0xc9873296d04cec2d,
+                0xf85fa3ebfeb54e59,
+                0xd961ba86a28b54c6,
+                0xf52951f7b6e938fa,
+                0xa720accec6d78707,
+                0x8fd2df888673da2e,
+                0x1316bf3a8eeb0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6e924a6b971b6e3,
+                0x8176e34aa97fe36d,
+                0x6e74f95d089e12a,
+                0x1d0a6ab51c400dd4,
+                0x601e1ed99fcf866a,
+                0x7f1fdf72edc092ec,
+                0xaa2cf406d623e525,
+                0x1f55b85d08dde2c5,
+                0xa1c9ae1c0f16cd69,
+                0xea12333fb7150bac,
+                0xd4a7d8b83e8760b3,
+                0x12fb113326be2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd08d3d6c5dcb4d6d,
+                0x2a24e82908dc758f,
+                0x8616959751b03f2f,
+                0x639dfe973a07c706,
+                0x1a5c0f4c02635b67,
+                0x469290d230039fa1,
+                0x858a159740029463,
+                0xd90b569fff97bd06,
+                0x6edf315968e5c96,
+                0x555a3b5c8dc9f5d9,
+                0xb0345bee0cf7eb20,
+                0xad1f70274db1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9979424c159b4d6a,
+                0x2ec10fcb206ad8f6,
+                0xd426689ebf46badf,
+                0xc96a8b6cefe1dab4,
+                0x999d4d6b587818e8,
+                0x978478a5fb58c5c4,
+                0x4f867ad9cb1ab01,
+                0x13e74e29e2a0c782,
+                0x61697858c64c2f02,
+                0x41fd61d5c625d0a0,
+                0xec483cc93d379d7e,
+                0x8aced76414b0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa46ea4dc9d435b3,
+                0x742662618885ebae,
+                0xff8b4a739d8fc276,
+                0xedea4bf3e34e509d,
+                0x154a25388cbc14bd,
+                0x41a5add05005e891,
+                0x680f7a57b87332bd,
+                0x50e4326e9e7c71df,
+                0x7f2d525dac073dd9,
+                0xe89a2e796c7dadd8,
+                0x6b03ce7385154c2f,
+                0x4d26fb88db94
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9116cf4dcf16f2ac,
+                0xa74089d4f8662f33,
+                0xed369fe1c3466386,
+                0x80b4f5cb769363bb,
+                0x66f35cecb2856c32,
+                0xf7ed6cd9c749661c,
+                0xde8251b76e8371d3,
+                0x1d9f4145e45def77,
+                0xde816dc93bf9f613,
+                0x1cde4351754f922c,
+                0x650f68a550605bb4,
+                0x16233d9e82446
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa882c380c6785995,
+                0x2475b80ab6205bae,
+                0x3f1aa1ad76332c,
+                0x33fd3188f6acc2b6,
+                0x5f96f76cca94629c,
+                0x5d4a5f06661828d4,
+                0xa42422294b537848,
+                0x3614b38a642a9706,
+                0x3dd82b4c0bbf6b53,
+                0x9d964beded8e9443,
+                0xc46e1c459d49c5fd,
+                0x18691dcb46b71
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5315ca5eace5fa6,
+                0x935f8642a63bc2f7,
+                0xf811ab63e00d215f,
+                0xbef84d1611a3723,
+                0x99053a4e7fe18e6b,
+                0xa2f00fb8850dc274,
+                0x4d25d238d844bbb4,
+                0x3cdd5c0cef5c1fd5,
+                0xbdf52850bdcaf4d4,
+                0xdbcf1869f26bb909,
+                0x4910efdaa5c8c524,
+                0x137ed213cfffe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaf8164373d25be94,
+                0x87fdbcb5a39d4bb6,
+                0x98e0c3a4fa1166c7,
+                0xf72a4347354bab5a,
+                0x7cd60cf82bc502a6,
+                0xb6dc88412f5ab9d5,
+                0xd3f4a84fa1daea71,
+                0x69453b38fd0d9b9b,
+                0x421f119d302b649c,
+                0xf0847a3ff4ca3c0b,
+                0xf9f523da16253aea,
+                0x2e6306965219
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6366fb4a9e4499b5,
+                0x61a39e3c416b26d2,
+                0x559be9e9fc08e0d1,
+                0xa82e3659f8821085,
+                0x9995913f98a1ec99,
+                0x346999f84b6b057d,
+                0x9bc8031258ea0c86,
+                0x10ad8f914a7980d1,
+                0xe9e0fda07c248d46,
+                0xf157e04c596bda9d,
+                0x18e936cb1524c6d8,
+                0x14f46123d99ca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbbbf1d8531bb11a8,
+                0x6d1199a87546b116,
+                0x9a59d875b69032c1,
+                0xf216e67b2f7aeddb,
+                0xf3af3f3c055464a9,
+                0xbfb64ddf487faf9c,
+                0x8aabf9c67815686b,
+                0xaf4187155c34582c,
+                0xae2079e302d01143,
+                0x66d27044e70bb6d7,
+                0x87f8f13a4cadeff2,
+                0x1529a78e8d004
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x90d702135c0512a7,
+                0xc447e84d9bf753dd,
+                0x32151a3b8f727031,
+                0x9f93b48132417c6b,
+                0x2c3419033d305f63,
+                0x97ec0bf8c86aee83,
+                0xb1aa06a0d11c1cf8,
+                0xbf4e3ab6d00df396,
+                0xf902f3817765260a,
+                0xee6736298cd0d7df,
+                0x824784dfd6bfe1da,
+                0xc32f80eb17f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba6f54da0d29ac41,
+                0x4886dcb5178e1d78,
+                0xbe37fba3f49cd415,
+                0x2d75af6f468d1357,
+                0xe9fc020b33b1d69c,
+                0xbaa4608402b11453,
+                0xf62e9499cf56bb94,
+                0xa26e3cd4a021e0c0,
+                0xaf61da081cfe4610,
+                0xe45fbb8d94155f91,
+                0xa25dcc70db21f10e,
+                0x1a41d7dc5a2b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x461446a4d047d9b2,
+                0xd2172a4e105a4008,
+                0x5ab7ed96323e7099,
+                0xb91534e1de222977,
+                0x59165854d8282baa,
+                0x9567ed43ae1db1b2,
+                0xcd349e501104f730,
+                0xf6dea0f396f8da48,
+                0xf64ce0486834f68f,
+                0x5c816eab59368d90,
+                0x5d5610acb38dcf82,
+                0xe0719880f85a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1721a1e9a4965bc,
+                0x24944dc7ae1b6481,
+                0x7c9d62576b96f0fe,
+                0x1f66a9ed99d3beec,
+                0x61c08c0c7830bd2d,
+                0x2c032f7977588aa5,
+                0xbb13fe65c672d413,
+                0x215131cd21f6a858,
+                0x67da58277888ad6e,
+                0x848189b16ebdc5bc,
+                0x3bbfc56773ef839f,
+                0x1001c64699503
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaf2eb1a994517582,
+                0xfc3906d85b5231a,
+                0xab164d61f0d0265d,
+                0x25dbd3375f76701f,
+                0xa676718df8db845,
+                0x330236298d91579c,
+                0x548ee1456cf6dc79,
+                0x796056db0e3f854c,
+                0xb84a100c2ab8dae9,
+                0x9db5c2471bce7995,
+                0x7d9ff0bf2e95f92d,
+                0x6e18b73d2d87
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8960e383906435ed,
+                0xb1161af73c078e18,
+                0xc8f1d60e0af2273,
+                0x5d3cf0d1b45b8c2d,
+                0xdc3831c80e2d6222,
+                0x4bbb533d426be7a8,
+                0x168b7fa36b8f89a1,
+                0x7c00cb2211ab8631,
+                0x1776814382fad9ab,
+                0x4a5f5338f5b93f74,
+                0x26ec17d70f57461d,
+                0x13e187454b0f7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcc4bae776eb7c9ac,
+                0x96fbd4b0b81182ba,
+                0x69fad2bb5fc46252,
+                0x6c78d5b29a9f8236,
+                0x177e3780e359aec1,
+                0x16df625024d39407,
+                0xddc5845a7e7aeecb,
+                0x70f922adee3fba1b,
+                0x433a7bc4b59e0ec3,
+                0x87b14ad869142f9f,
+                0xd21b29cd3be9a52f,
+                0x1a19ea9b3a813
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd8501a2e041a0840,
+                0xdde44c6477d14222,
+                0x4b63fa22799afca7,
+                0x54b054363e21949a,
+                0xd7f6635312dc6ece,
+                0x3b168231c4b5649e,
+                0xf1dab93a63d6b9b3,
+                0xbecb7edceb666ec0,
+                0x35ab051c32d9fa98,
+                0x7fa6d8b5010c538d,
+                0x5df885569961ab49,
+                0x356a9492fab4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x15613062c71b693e,
+                0xa7d3da70f813f910,
+                0xe61fa03fbb6e46f3,
+                0xf8f9a341d4e4132a,
+                0x17d857fddb13d98e,
+                0x28ac53489c4d09a0,
+                0x9952ced8699e2240,
+                0x1d291c9b8038333b,
+                0x755868979fcd0037,
+                0x7a0d167c3dd7e15a,
+                0xd2b6a8b5faa7d728,
+                0x1abd519d1c983
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf3a3d7842568237,
+                0xce81fdb611913395,
+                0x8b3fde9ce299adec,
+                0x2bd2e9bbeb7befcd,
+                0xa026d7a3a82de5fa,
+                0x99aa5123f1591aa6,
+                0xa52ba7399e5a111c,
+                0x45a9be7cb0635c9a,
+                0xfde563f78e7e25c6,
+                0x3a3921b7bdb3893a,
+                0x7dc5728e35d79dd6,
+                0x19bf21ffa3902
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd15bd0d00dc2edc5,
+                0xee3d3651a7441ff,
+                0xc9dda011be046762,
+                0xf7269e143194ee4f,
+                0x672fe5eda8a61ec0,
+                0x1607022bac2b0dcb,
+                0x3480b1235bf99f6f,
+                0x91943afd5b48711d,
+                0x50ed8a1185486003,
+                0xe6fb09dbe36c0ee6,
+                0x19af70ed5d3bd067,
+                0x18e3b18461082
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x537e536b3f5bed2b,
+                0x1286e155654d82ed,
+                0x4d9a5547e4c5682,
+                0x38f52959d54c524d,
+                0x98872c82ba725df8,
+                0xb80c42da8760f231,
+                0xa1e61717ea3136d3,
+                0x26d4cf67e2d565ac,
+                0xca082e860dc768db,
+                0xf2dd5abd3db2ca91,
+                0x420515960946c584,
+                0xd3f45aec1ed2
+            ])
+        ),
+    ];
+
+    // This MDS matrix supports fast matrix multiplication
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cf0c6063c11f4f4,
+                0x9200843865e15377,
+                0x7cea5906512cdeed,
+                0x34f05cf78900f804,
+                0xb9b53c7de1a9a8df,
+                0xabef0a059ea1f5b2,
+                0xcd9cebdd87189dff,
+                0xb926b13a2e3fc7c0,
+                0xa85e5ca46294e943,
+                0xa6cc744c88b48dcd,
+                0xc22fbe190e87e4f3,
+                0x85a8a3e7854
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x99ecd43c171c492d,
+                0x358a2ace6412dbb2,
+                0x43627bb81053ea71,
+                0xd5efdc9741e98f83,
+                0x1eb6c775cd8f117e,
+                0xfe7921e565041a2e,
+                0x1ad8b896d1caa5ab,
+                0xe4d266af514055d8,
+                0x23465d8377305580,
+                0xb75d55743975ed60,
+                0x691bd4d143add502,
+                0x32ea8df335e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd05f90fff0369c06,
+                0xe0bfe066b24c28cb,
+                0x2ec4023501c6cabb,
+                0xfa159b341d9dc4f9,
+                0x6f058e7626a6ec8e,
+                0xd024f6384a5e85cc,
+                0xe881da123f683484,
+                0x139f880496fa413b,
+                0x614e076d3b022393,
+                0xd0be281e04a56690,
+                0xe081bb8100f8f634,
+                0xcd1596b74f6d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xff021a92ac512cdf,
+                0xa6617cdc8aca0ecb,
+                0xe210c2d746a0226,
+                0x1bdbe480a9d1ca8a,
+                0x64a27cb4b85070e5,
+                0x6bdf992d011523fe,
+                0x5d17b0a1b4fdf441,
+                0xb543d7b0d63c77e0,
+                0x29c219a3c53fcd94,
+                0x5cc470f49c705cb1,
+                0xb6d0180bac1429f5,
+                0x88b9a716c9ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc62c048a80c9608f,
+                0x7a57d44b6d48ec33,
+                0x96f2fc1ceed3151b,
+                0xf11b479adf48bb62,
+                0xeeb3e63223dc7476,
+                0xef6b6d2e3ab76c17,
+                0xc7386165c2dc9af2,
+                0xbf95af18d432fede,
+                0xeb9bf5c0adc38741,
+                0x91817a712f4599c0,
+                0xb14a25a716fb2d0a,
+                0x7a915004f880
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfaa0fa5473fb7358,
+                0x96a3b989a94947b2,
+                0x45b714cf2a875d70,
+                0xdc59da03beae8db0,
+                0xfaa86000e82e51e9,
+                0xb5bb0d8bfd2a0fd6,
+                0xae98ccf05ce343aa,
+                0x3dc3e7547aaaf4,
+                0x43e6b4edb1fb0c24,
+                0xc574c131557ebc6d,
+                0x827e4e6f32ae0562,
+                0x1491ce672e19e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x305c4a88f6fc63ed,
+                0xe619c0fe7daa42d4,
+                0xbb1a5aa40f4ad639,
+                0x52d941b7b441bce9,
+                0xb163da74c6ff7666,
+                0x3de8cfb1679a5529,
+                0xb5727633132a5fed,
+                0xa7eb1cbe2212e1aa,
+                0xdade6d9c550e5a96,
+                0xbecfb057d17fb05,
+                0x5ab7b4776abbbfc,
+                0x95e4b7bfe6c1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6dd6120852969fc6,
+                0x6ebc148eef4e3e6e,
+                0x6d01ac3961551211,
+                0x4e9606b04a5b2d6e,
+                0xa778bc309fc04016,
+                0xe9c8f4442473953e,
+                0xb8cc0a2f5488c3dc,
+                0xb15d62cf275143c,
+                0x92f7aec4746dd2f1,
+                0xb8b595755f7920b3,
+                0x621e3e80d37c41a6,
+                0xf70c71859838
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x72c9a1a52db29e5e,
+                0x597d80ffd86d7651,
+                0x75aa8c77bd7fb751,
+                0x8ee5de61b9942642,
+                0xd24d8b0ab256c0c3,
+                0xe02b74ffb2abaf73,
+                0x6ab038246c9c8e1b,
+                0xce4978ddd027051c,
+                0x60581003bee5712d,
+                0x1b8cc749d69bae6,
+                0xb1838d60e48b055c,
+                0x33abcf15cbe0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x213d95aebaa1fb29,
+                0xb763cfda55400665,
+                0x403f9cc9e1b3ed53,
+                0x8a1586da9256269e,
+                0xa8520ceb2fcbe8d1,
+                0xf19346464aedf5a2,
+                0xfa59d60694ecbb3f,
+                0x7671f536a65b7ac3,
+                0x9f6aba8780dde745,
+                0xcb5c7c4d039cdace,
+                0xa0a6ef7c6d77b03d,
+                0xb92bc7b3704f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2fe4fc584610b3ca,
+                0x47c7d158e822f84f,
+                0x9c6ace171f5c7b9d,
+                0x803779712c157c6,
+                0xb4c5e6aa52eaa11f,
+                0x8c3ddd09cd43fe16,
+                0x76f19e30f54cf429,
+                0xfd83b0f12787da77,
+                0x6c0acf2603b6315c,
+                0x186e39de07766bcf,
+                0x55eb39b647190abd,
+                0xf8a2f4483856
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdef43db397f99104,
+                0x56e5afd4d8d02c7c,
+                0x8efeffab5e9d2512,
+                0x4291f94a47160b69,
+                0x5951536df99383a2,
+                0x67030ae35df0b3a8,
+                0xb84a3670140e7ead,
+                0x1caf74b9db835084,
+                0xbcdb7dcfdb858155,
+                0xa74a858e20f6d3a3,
+                0x440b1af7645ce3f1,
+                0x11efaa2fb0d92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd4e04165ab80882a,
+                0x98495073bc022fd1,
+                0x8c9d721772654ed3,
+                0x8ed82ec7d714d450,
+                0x241285e438884456,
+                0xd96c3808754efd99,
+                0x330a0811d4dab4db,
+                0x60d69b2cbb0c9921,
+                0x54f74e04a3739720,
+                0x30052843ab1b988b,
+                0x721b45c0e0cc5043,
+                0x79c27427f267
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x846c3dfadeb4001d,
+                0x1ea976e08c3b4fc5,
+                0x3e310f7c753fda7b,
+                0x6bd47b3940a164db,
+                0x52e1a61e49591836,
+                0x1bf9bf6d69e9c6d6,
+                0xda445bf9cb7f3451,
+                0xb2b04fae3741c21,
+                0xc658adf560fa0109,
+                0xb9df65287503e87,
+                0x8bd8dc83a8da70b1,
+                0x1518d4bea87a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9b33e7c797e1741,
+                0x435cb476b7e71bad,
+                0x76cd945dd83dd4ce,
+                0x410245b22e5010ea,
+                0x8f3976b3616de985,
+                0x2921fbe79dcbbc8d,
+                0x45fd1813a8893167,
+                0xf67b76360f6d3cdd,
+                0x9e23d9ca4ac6c6ed,
+                0x574f75d950534f6b,
+                0xeba844e3a139f8a6,
+                0x190ac80b83995
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa4e78d07e27ec3d6,
+                0xc86ce800d399c1b0,
+                0xc7d2ad16a2ed5121,
+                0x9913a9efc56795b3,
+                0x4654e72512b81249,
+                0x57d425bea6be1cfc,
+                0xbf0cf27a350a6a9f,
+                0x38fabd421debd6b7,
+                0x5b3951a0985b8741,
+                0x5196575b4d3180db,
+                0x408c2a47a6958a61,
+                0x6deaa8ca1fdc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e0fcdebfde9fa64,
+                0x397b030c57f12609,
+                0xb0c3c2bfdbbb2cc3,
+                0xa2aa926b96ad35e1,
+                0x8b8f71ff77a7fb96,
+                0x9de9a0ca01407f4b,
+                0x2ed54b3ae5bb0772,
+                0x2cc56f5b194b79b5,
+                0xb06128e2dc3ddabf,
+                0x3f3393e66012880,
+                0x7e1f7c0bffc6458c,
+                0x650030e0bf9e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x267b966810689e3a,
+                0x575da5718956fbe8,
+                0x76883c7df9ea6171,
+                0xe2210d8d11803f24,
+                0xf02f9fba1a9900d4,
+                0xf8cee845a74ae545,
+                0xc9470989bbd1bbee,
+                0xae9b6f8919859d32,
+                0xe7b4cf0798b0100,
+                0x229f8527bd34fd85,
+                0x6ca748774ca618ee,
+                0x1bd90ada0d140
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe8ae7fd917f0828a,
+                0x434f0237607f0f5d,
+                0xaf40f310e30fe3d3,
+                0x90178d8d93f8b7a0,
+                0xcf65c14c82e2470b,
+                0xb2f63af6ae2f666d,
+                0xfa9abfe63529ddd7,
+                0x6da525f031d45c9f,
+                0x8a78d301cd521013,
+                0x54120440a2de0156,
+                0xafb0814125075f18,
+                0x1aabf36eb4a53
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfda6a3f15d84005,
+                0x1cfe1a639be98078,
+                0xed8beb86fc5884cd,
+                0x568ba2f2bb128e53,
+                0xd4e1d16819135d03,
+                0x7b99c8ccb910ae10,
+                0xb7df4fe968df69ce,
+                0xb9ab23954fc13d4f,
+                0xdf5931b928da783f,
+                0x320bddadddd317f4,
+                0xaca414f5f7029666,
+                0x15013e573bf76
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3c83d28f0215cff,
+                0x99db6f8b99ba2472,
+                0x2386765940beaf0,
+                0x2da147e4e7fb31c0,
+                0x3913478dca6acb83,
+                0xa42d56fc5236a38,
+                0xce251a16eeca792c,
+                0x47b2180c16e4b66a,
+                0x7c6dfd2dd0d06d75,
+                0x28566c2aff62dc1f,
+                0x3628a9340efe722b,
+                0xd38ff20f426b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf494641c53560cb1,
+                0x1592107cd8dcf856,
+                0x299fe46b206d146a,
+                0x1ede3bf16b2088f,
+                0x70394f7fbf88b5b7,
+                0x9ca5238a80106aa5,
+                0x94e7dd5b57b1bfc8,
+                0x8e87d5cceba01534,
+                0x27970f3f46e887e9,
+                0x1b01351232a11433,
+                0x7e95d3e8659d7dbf,
+                0x1aadbb766634f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcb89956b5984363d,
+                0xc21d569cc99521a3,
+                0x62ab14552dc5689a,
+                0x9e6757642cc6a666,
+                0x81a453bb5068c546,
+                0x49c9c55cbe19bf60,
+                0x322edf3727196b62,
+                0x8ff028c9e9adac47,
+                0x687a6a0afb9d5319,
+                0xb0bbd3ae48624e2e,
+                0xda923f455c96cb36,
+                0x64219c0fabec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e65191ad9527409,
+                0xa0941cc936f2262b,
+                0x1f0340bd3f1e0acc,
+                0x699642a7ce0d451d,
+                0xc3e3b54c3c04cb17,
+                0x1afd8fd56294cdea,
+                0x9aa018166897503e,
+                0x633dbb6da285b6c4,
+                0xb58b7fadb33137ab,
+                0xc4ae8d887bce0ee7,
+                0xf2756e07c455167b,
+                0x7ab525cfd0cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x542e8589cb6681e6,
+                0x7284fd92a2dfc545,
+                0xec166a49bd679581,
+                0xb5ec47fdb2ba7e50,
+                0xe5c1031641656ae8,
+                0x3fa5f2c396cbcd63,
+                0x709f247ad6e33cd2,
+                0x4f1a89a72abc2de9,
+                0xdca793383c0b6662,
+                0x782d14c5fad14d45,
+                0x598c2ae01bbb9bce,
+                0x17bd12ef92405
+            ])
+        ),
+    ];
+
+    /// Short Montgomery multiplication with respect to the short Montgomery constant R_2=2^64
+    #[inline]
+    fn dot_product(res: &mut Fr, state: &mut [Fr], mut start_idx_cst: usize) {
+        state.iter().for_each(|&x| {
+            let elem = Self::MDS_CST_SHORT[start_idx_cst].mul_short(x);
+            start_idx_cst += 1;
+            *res += &elem;
+        });
+    }
+}
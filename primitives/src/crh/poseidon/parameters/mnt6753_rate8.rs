@@ -0,0 +1,12459 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonInverseSBox,
+    PoseidonParameters, PoseidonShortParameters,
+};
+
+use algebra::{biginteger::BigInteger768 as BigInteger, field_new, fields::mnt6753::Fr, MulShort};
+
+pub type MNT6InversePoseidonSBoxRate8 = PoseidonInverseSBox<Fr, MNT6753PoseidonParametersRate8>;
+pub type MNT6PoseidonHashRate8 =
+    PoseidonHash<Fr, MNT6753PoseidonParametersRate8, MNT6InversePoseidonSBoxRate8>;
+pub type MNT6BatchPoseidonHashRate8 =
+    PoseidonBatchHash<Fr, MNT6753PoseidonParametersRate8, MNT6InversePoseidonSBoxRate8>;
+
+#[derive(Debug, Clone)]
+/// x^{-1}-POSEIDON-128 parameters of rate 8 for scalar field Fr of MNT6-753, with an MDS matrix
+/// supporting short Montgomery multiplication.
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_short_grain.
+pub struct MNT6753PoseidonParametersRate8;
+
+impl FieldBasedHashParameters for MNT6753PoseidonParametersRate8 {
+    type Fr = Fr;
+    const R: usize = 8; // The rate of the hash function
+}
+
+impl PoseidonShortParameters for MNT6753PoseidonParametersRate8 {
+    const MDS_CST_SHORT: &'static [Fr] = &[
+        // These constants are in Partial Montgomery representation with R = 2^64
+        field_new!(
+            Fr,
+            BigInteger([
+                0x980458e7dc0417a9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2d8c308952a82143,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x81168d62862a8cb3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ec563e9409609b5,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c116202d3c0f9fe,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x958ee0fe1238bd67,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf485db3d77023dea,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5e89f6fb5695fa65,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfeb76839081c035e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e1ca7df87330f58,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdfef039c667c3159,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40f1851024d057d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b6602a431ae7605,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ff3fbf0d982cd4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c7ff18497a4434b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb91178a670235d8,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3b5a2b52e9c6a3b,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc7458504c2f93129,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x65fe8c7811ee04e8,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b9ddc3caf5d9c31,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8c4e11d373d5767,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x29a253c00ff1513,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b6ca3f96c248317,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x913b20def5f0cce4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd98c50f59927e93d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd58d77dced173c7f,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x815cdd4c62550fd1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x230a117254e0fbc9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x62b00195fc5caef,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf0b5855f59231c5d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa6a5d45462883c0d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x594e49953b721356,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1c2302c5bab0abb9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbeff9e65766f958d,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5871baf7598134d3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x735f7dbc452bc4e6,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x154247dc89ff5fc0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3f9f545a90585649,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35b73c186709a56e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47a44eef236f5ded,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc918bef2f045a95e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdcad25620a9f4635,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x70e05ca653c6e16f,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x204858d4f43b7b64,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9282d40e834f7f40,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1184dcf99a3eae1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x14fb5d421d77cb6e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa5afffe07654297e,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc474529b79f6915,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc50f9d3b76dace68,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35ca07ee6c80ec61,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x597ae8b09ace8cae,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x760154f93a6baf8a,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b6dfa4e9b5600f1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x46d5a47194ede33a,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x302ce9dc46cf8b67,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19a0f3ffd3981ee2,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3798a4f0817cb0f2,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x736786f130ac9b92,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x17cdb134bced2ebc,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef4d5419ee827ef4,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb48d7e6478b69fa,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6fe0d335ebeaf725,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9bcfa19bce9bfea8,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfaae915faba7dbd,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x120c9f624e192aa7,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ab68af5d16f4757,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10688c3a27ee68db,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee2a55e8fa21d361,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a4f1754f3541795,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x887aa9ca8f0e54f1,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e058ffce24b2570,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb228084dd222fe,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf2ee5581a449f107,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb60a064524814ea0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x373c53a6dbb3e724,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdd4c8b5f834ee657,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83f2c438438c36b3,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa76cd5d2fdf77091,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc1702928dde52be,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x239a4ed766105da9,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0,
+                0x0
+            ])
+        ),
+    ];
+}
+
+impl PoseidonParameters for MNT6753PoseidonParametersRate8 {
+    const T: usize = 9; // Size of the internal state (in field elements)
+                        // Number of rounds including security margin. Without such, R_f = 3, R_p = 50.
+    const R_F: i32 = 4; // Half number of full rounds (R_f in the Poseidon paper)
+    const R_P: i32 = 54; // Number of partial rounds
+
+    // The zero element of the field
+    const ZERO: Fr = field_new!(
+        Fr,
+        BigInteger([0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0])
+    );
+
+    // State vector after permutation of zero state vector
+    const AFTER_ZERO_PERM: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                14087046266063294830,
+                10694173608371110639,
+                1991575991284943848,
+                6398032354435189608,
+                5336959467790867741,
+                4246899668661933121,
+                15232132013716693436,
+                15453620188254277905,
+                15646328073504592136,
+                5372644338561136235,
+                206877895845518418,
+                353792330244463
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                212446915703629470,
+                2617668535796219271,
+                8325425874249829652,
+                3294502386489590636,
+                14767606484095198385,
+                12576475663087941437,
+                896597270338063206,
+                3683954656859336771,
+                11583712629863461117,
+                4107078474372548233,
+                3573326692814368467,
+                394345802604382
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                12059190589006851452,
+                14337959184995566523,
+                1266319322394406522,
+                11178679127211167377,
+                14000030582445539475,
+                17793246816462149295,
+                6589811771142283903,
+                9856453712465315943,
+                5340676869509641441,
+                508044722865752399,
+                10286762607976869267,
+                488266250378939
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                15495662403882181377,
+                17386215102353801978,
+                15739159907278865186,
+                8572987688513964334,
+                17813239502088292030,
+                7713630461811311741,
+                17065916695321864750,
+                5505838641945425586,
+                9418199056302965717,
+                10567584927326379627,
+                12384034656114417562,
+                152644345312193
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                16585338193996922855,
+                8056134196973872584,
+                11727570582763552345,
+                9553711719012439741,
+                1558207897618974247,
+                16265303852510318868,
+                1538323151374426120,
+                11266036896827416479,
+                844597593440224173,
+                11136101551161011979,
+                9541131733145150241,
+                372274889585631
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                17912546271577976213,
+                250109511701153811,
+                6660509218908768428,
+                573529450885132362,
+                11570613521748463646,
+                13330467076717703925,
+                2809221064542188088,
+                15493467885018128847,
+                16766543632681835175,
+                192571351143976283,
+                10163057197072772584,
+                54679804370447
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                8717448042233759439,
+                7496922467368321000,
+                13013128731952748728,
+                16975695948705849639,
+                7048691942297122973,
+                11549574441096356717,
+                12583987665265078977,
+                5705788166176486822,
+                5413349457952246303,
+                5201345753081132291,
+                17614589316838297322,
+                493803814912331
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                6001564396658953988,
+                1238575255064270026,
+                7973958707271212068,
+                8831049827225631075,
+                13197020453832802231,
+                11446206868982330924,
+                11963774354348064242,
+                4987366544868093632,
+                4051030834924510054,
+                6847138324789451997,
+                8958671971324216959,
+                155991817629945
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                4072401191857321493,
+                16993790081806492583,
+                3640497747187341250,
+                7807534027959759300,
+                9066928222368912863,
+                5349902159270956481,
+                18314037850352121673,
+                135853484995467092,
+                5811663506026528385,
+                17805830624086893231,
+                6838188537895485658,
+                184013644678365
+            ])
+        ),
+    ];
+
+    // Array of round constants (in Montgomery representation)
+    const ROUND_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x729c7c5d9eac95ca,
+                0x350a91f06f145e11,
+                0xcbbde7d8bf8ec05b,
+                0xc52eb8b77d93e0be,
+                0x57c83ac937d164b,
+                0xdceda600c4051ae3,
+                0xc932e5ba7e9182ed,
+                0xf941e28277a37a8b,
+                0x5d6a5f49e9be373c,
+                0x868db808f1dddd98,
+                0xa9bcfa93d3c60dce,
+                0x10865cc9551cd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x99550bbf39b7a299,
+                0x5772bc0f0f474074,
+                0x979448c00f2504b6,
+                0xe4a61e013fc0fa62,
+                0xf2c9be65722777a4,
+                0xcd890e2dcded5a98,
+                0xa0fa780ec1c47fc9,
+                0x412aeb04bdd1dc47,
+                0xb42467149c071bad,
+                0x60a046f715ea2e30,
+                0x242efca85e500cad,
+                0x2157310e3e29
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x97f9f3c2d9be5409,
+                0x2d6174796782ad5e,
+                0x44777576424bf859,
+                0xad35905cbf05504c,
+                0x42d67924e63ddb7c,
+                0x1e776686ad8bc5bf,
+                0xd259f3630ab3d966,
+                0xea7cfd2ad814e985,
+                0x8952f6f6ef6f567c,
+                0x60f50da6cc306209,
+                0x420b7fa06a6ea78c,
+                0x1bebafed7f271
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcb1c8b03e227e45b,
+                0xa8d827881deba81e,
+                0xcdd10c8386b56f08,
+                0xe55a15abc69ac26e,
+                0xdce05c076e4b083b,
+                0x910a659fedbb712c,
+                0x44bf0541be184ef4,
+                0xe92764e379059f31,
+                0x93f59408746a6ab1,
+                0xd5c91d672eae8fad,
+                0x77414d1100226014,
+                0x15a2ec7b47ae7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3b9702dad8b073a,
+                0x22a77b9d73ba4bcc,
+                0x31ef68c529278034,
+                0xe066fbcbe75934d0,
+                0x50f3ca41c6fcd69b,
+                0xb2c29d4a9c4a3b6e,
+                0x59e4f57ee5ffc397,
+                0xf6bb657cbb124ff8,
+                0xc3b99614560c88ba,
+                0xf1210e96cc297dd1,
+                0xf7514026f5520650,
+                0xb4938314d0e8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6cfbb3b8a6996443,
+                0x7cb657601365145e,
+                0x4bb4d7bbb2c97187,
+                0xa64f50a44797fa1d,
+                0x92390f1ccb5034c1,
+                0x273061e1d41a4582,
+                0x85d682bff6484770,
+                0x7b141434d4efedbc,
+                0x6a3d98b604636515,
+                0x20a71accb4cd89a7,
+                0xf0dce077848d5615,
+                0x8726b49bbb6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5ec3dd8a782f5334,
+                0x2f507d1cfafae30a,
+                0x9add49f5954824ec,
+                0xbc298021b753ee75,
+                0x911db3443e31650,
+                0xcf324896503ef1a4,
+                0xb814669c9f4a5fb8,
+                0xc5342b19dd65c7bb,
+                0x9a9b5c0b589e6da1,
+                0xc095707dd57d577b,
+                0x5211c344d9ddd0e4,
+                0x1b67f72df56c4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5505742d045f4ab6,
+                0x89b17e44f7ea5a29,
+                0x4278466c1f86214f,
+                0xccf9d017687b6363,
+                0x4de72746624a6ad1,
+                0x22690ac23cf63f81,
+                0x4289942e3304f6c9,
+                0x62edcb8ea6f9fb4f,
+                0x3e6c8e37d1c2f118,
+                0xa1f64210f994865e,
+                0x292f1a82624cec1,
+                0x1ac8180bd98fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbdb2854411f166d8,
+                0x2da34983606f6c10,
+                0x3c0dcb8e6b4183ae,
+                0xc82581f86e5d8349,
+                0xd574c5ce651b6d80,
+                0x156b536fbf668b88,
+                0x30588e988330d796,
+                0xadb32a8e895e5b83,
+                0xb1671354b046fb02,
+                0x4630bfeb71f22056,
+                0xc72193f81b930c78,
+                0x3160c13292ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x71aefda0304b9f31,
+                0xee36266d75923f8a,
+                0xde63d0b742b56521,
+                0x533d68c341270389,
+                0x65b807b01faa5f2f,
+                0xa3cd71bf35ae0c80,
+                0x3a3f8737d488ffaa,
+                0xbcb7eeba2594293c,
+                0xfa38dbb9deb71c09,
+                0xfd760e0b81d25f8e,
+                0x17c71cb2e962c2d8,
+                0x172f877e2eec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x519e478576318c9c,
+                0xa67ede7cda3a3f52,
+                0x2e33915788982ec4,
+                0xf00ac64310fbab37,
+                0xbebbdc5cf30baced,
+                0x53bf0e41ea6ffa9,
+                0xe0d0c857a6cd371d,
+                0x4b1811705227d106,
+                0x81551de2dff996e9,
+                0xea5b0a18d33b9cf2,
+                0x8ed8b0a117b6f244,
+                0xe8956398b44c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x52f9f61d4b97aff6,
+                0x1bd46029a1100122,
+                0x52ca1212855f1acf,
+                0x487c576a5fe2b11f,
+                0xf480e8be53921803,
+                0xe7f045e51ba1f6b4,
+                0x29344d43843d89cc,
+                0x54ad01d73b5041c5,
+                0x4da527f415ec8a08,
+                0xa33986e524b440d5,
+                0x8ef3d1e2ab016031,
+                0x174c9314dab61
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e611aff5764c468,
+                0xe506d46a42a17464,
+                0xa13183d22ac59d24,
+                0xcce45b9a6e3e6677,
+                0xdb172d581c96f62f,
+                0x95c414b036d1d0da,
+                0xf4b81975d167cebf,
+                0xb0f698adf1cb546c,
+                0xe25fd9d5322ccacc,
+                0xeebb4868c4174ac9,
+                0x86fc06d5d9d0a73d,
+                0xb23ff05042e2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x88c502ee81dcef78,
+                0xc3c517c3e11aa39c,
+                0xf242cc0409c8fc6b,
+                0x5528be75a7472d8,
+                0xd6144a8260d6826e,
+                0xf3db32ab1dac9820,
+                0x50f01c2c11f3868f,
+                0xf5b9a7999e69483,
+                0x4112cc2351af6acf,
+                0x35958c0783c023ce,
+                0xccfbccdda39bfac2,
+                0x146e6038f2a34
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x362f9469dc9f589a,
+                0xb6a48e8595ef5955,
+                0xb2491c97faa25f29,
+                0xe519d7e27a2e1837,
+                0xb98cf54253572616,
+                0xb4e27e8eaf207e08,
+                0xed3eb408301351a3,
+                0x2d9c2854914ba95b,
+                0xb86101d125e46808,
+                0x7cae1407d1ad6d9b,
+                0x757e4bc8d0da1a40,
+                0x5595f8e4b495
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x30bed6fd2b5cfbf2,
+                0x8fca1f8b2e82964e,
+                0x7873ec2c18cd2dcb,
+                0xa6dbd2e09041ea7d,
+                0x7572ff593bff5ce4,
+                0xdff4be86938da86a,
+                0x2d14ec65802ded18,
+                0xdbfcff0d902b013d,
+                0x7985c60733a456cc,
+                0xdedc405810d5e184,
+                0x512710d36932a496,
+                0x123e63cbcbfa8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf7bd8cff279e4d6,
+                0xb436724ce914d60a,
+                0x10e35d72134ce32d,
+                0x102e9a266fdeba66,
+                0x988884fad24d67f7,
+                0xba18c800b27d49f6,
+                0x686ecbdbf31436a7,
+                0x1c9668965df6802b,
+                0xad2af53610a07575,
+                0xe0ae172a267d62e8,
+                0xc76f4b52be1bcfab,
+                0x206a49c7a0ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x60b7bd7aae9f052c,
+                0x885ee628f5b61719,
+                0x5fe06487b8e04f11,
+                0x8961e10fb6bd95bb,
+                0x4b3ca31de3493a1a,
+                0x1a2ac806e5af6b4c,
+                0xf41512d973f12332,
+                0x63f49a3d2c967234,
+                0x7c7bfd3455c9285e,
+                0xacb7c9a6c03cc03a,
+                0x9131bc5da5c139ce,
+                0x95aac23a6691
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b772989da78f93b,
+                0x5dd889447a63e46,
+                0x701eaeda29da4821,
+                0xbfca323ae1e022d3,
+                0xc0157e356e42510e,
+                0xbc7263e8d9cf3a73,
+                0xbc712cc2954b635a,
+                0x24986edc8fba7349,
+                0xa0bf2d75a4aab7cf,
+                0xa08715ca5ba311e,
+                0xc8e4e456cf0d38d4,
+                0x45c68aea7ba1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x290e65e1deb4e341,
+                0x14cc1d9af2b0562e,
+                0x70d6ecba12a014a3,
+                0xc3c8247dbf95ffa1,
+                0xc21abd400a23d452,
+                0x640f683c4336d6ba,
+                0xcafdb4b5273c96c0,
+                0x86e34c10da3f362b,
+                0x77243798793eb6d9,
+                0x59fedb94d5269abb,
+                0x141badba38414ae3,
+                0x1504c468aba8a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc7afda50923cc92,
+                0x8af1827cc2e12968,
+                0x430307f79bf97e4a,
+                0x31bd7c470176defe,
+                0xdb02fe2ed7c86a0c,
+                0x4332550c92fb5f45,
+                0x32f266ffae37b2da,
+                0xd1429079874659de,
+                0xbde7702f73506e00,
+                0xe9e10f52be885728,
+                0x2ab7a5f05df80d76,
+                0xd60735a3c57d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6ad9df1c2be42e57,
+                0x54267fde0e781a29,
+                0x124286e6da4e5a5a,
+                0x5fbcdb65d7440a5c,
+                0x99556df64e1624a,
+                0xd85fa65e9521240c,
+                0xdddc20eb6ab33481,
+                0x1faaa5fa966c30f6,
+                0x97a88573177ca931,
+                0x5d423a9d1a801bf3,
+                0x8b6ec4aa756454cc,
+                0x16cd47b12ac93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10032cbe7fe181d8,
+                0x9534d1853a6b82e5,
+                0xd9e9fa5402f484e4,
+                0xbe0a1b05a695cfa0,
+                0xb9f7665c3c947b0b,
+                0x3eb607d57228c9da,
+                0x87ed37da29c6c694,
+                0x25151f5084f0fc59,
+                0xde55339b55605793,
+                0xa8bd6b3e58d10488,
+                0x8caf4e3854c84680,
+                0x85615360e0a5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x147eb45082789f0f,
+                0x95c9cdca861e5dfd,
+                0xd56c0dae4c24f118,
+                0x4215d57992eae5ff,
+                0xd01eb57554074286,
+                0x3ec4986d04884b84,
+                0xebbd8ac7b06d41c5,
+                0xc1eb0bc6dc6c6574,
+                0x6ec7cb217baf4822,
+                0xde5d6d27d20afe44,
+                0xe0f1abfffe5c5075,
+                0x1815dbe3df74e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6e9dc6a069a0a276,
+                0x865c0bb0c79de3b4,
+                0xaf701d827a353bd6,
+                0x5db6a2d3c47255cb,
+                0xc410408d1ec07f26,
+                0x64023b8e5ff2a28,
+                0xd9984c8751f69f11,
+                0xb74c50c4294f2d8,
+                0x97ee2a2ced082da8,
+                0xf30449ab2b0f1b66,
+                0x2736a7557bc092e2,
+                0x1a0f8490cfd18
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x12914797a5328568,
+                0xd55646288014eccb,
+                0x4efe60175ccb4861,
+                0x90ce0756f9c0911e,
+                0xfe975f1cab7dbcf9,
+                0xceaa8fb7c2656da1,
+                0x86f3df031d1344f5,
+                0x52d849717508991a,
+                0xac471e7f7f65291a,
+                0x6fd36215b01594a9,
+                0xb249eae4f1fa0029,
+                0x118ae778907b0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45120b803d84351d,
+                0xc6e3977794d54d20,
+                0x1cfbde0a843263a9,
+                0x89b8667bb35d397,
+                0xc7aa4a08bffd7331,
+                0x44fec8a60cbbfa4a,
+                0x3f2e2079cb8ccdee,
+                0xdd3857e992d39f4b,
+                0x480d7c3b612488d4,
+                0xf79ed3d4a2afe811,
+                0xf5d7b53b17bf90e6,
+                0xf1b31b093dcf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8f8112c0489e21b4,
+                0x2e5f3045b4dc18bb,
+                0x848fb1e6f6c3da32,
+                0x1544cd2dfad670c4,
+                0xf7807960012f7d73,
+                0x8af7735d2d04ae33,
+                0x4761a3fdfe791ccc,
+                0x227e888b8292605e,
+                0xc38a5ca15dc00e1e,
+                0x23abdf8fbb93b46e,
+                0x47f59d1b11d1f352,
+                0x877cb61b6f4a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae531b66ea7dd122,
+                0xcd172a7edc732cd,
+                0xe716f638fc967a5,
+                0x413d335952052d01,
+                0x20e5e83669731a56,
+                0x3c1b12f9f754176f,
+                0x13cb02467336efaa,
+                0x8f7c7c7a0eb52c50,
+                0x89f6c4cc067f1d3a,
+                0x2e9ddb39540f8939,
+                0x7e173fc78eb9fc37,
+                0x4f35b0131d2f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa93e9cec74b74801,
+                0xae907f6862ed766f,
+                0x6a4e4e980c1f72e1,
+                0x49230c6afa9bb5cc,
+                0x7cef80f951c7dfdf,
+                0xbe5c088998732be8,
+                0x2bf1e209965defa1,
+                0x60ba03049193d6ad,
+                0x9a8a65505a7e259a,
+                0xcce06fa08909227,
+                0x669dc00e53d09fc5,
+                0x6ef2292b2889
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdebb71ce9ddb9e56,
+                0x5f73c9322a72f8dc,
+                0x9284188c7cfb073b,
+                0xd3781364760b4641,
+                0x712f2cc31ae7bb4,
+                0x1a679d7a3851e5e6,
+                0x2ae67b31c6395e05,
+                0xc1e4287a24d08b7f,
+                0x6f75c32b3523e3ce,
+                0xfa7bbb7febdfc020,
+                0x998e08e04fa11658,
+                0x43efb715ee7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82ece06331c6dcda,
+                0xcb046ab56a57e14a,
+                0x1ad796a3dea6af06,
+                0xc9fd77f2de309281,
+                0x62726cceb82f627f,
+                0x3307d8fd12acc521,
+                0xddea9193f6e1d533,
+                0xf6ea76adf868cc71,
+                0xde83ef45b87f9cc3,
+                0x7b946e457e9c08c5,
+                0x7caf2a6fc8ded77f,
+                0xbdb138dcb333
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8c2fe15d1d8250fb,
+                0xd9e231e36f97d28a,
+                0x99c4c8d4e6913edf,
+                0x168166b580f98e18,
+                0x5422b6b7666a6497,
+                0x5d62f936e203faeb,
+                0xa18d3fc3f67c23d8,
+                0x743647d122df363c,
+                0x718edc8ee96e8e45,
+                0x49cc97297d8d5d56,
+                0x98d5ff31b238cad2,
+                0x51068263fe23
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x364c0693ad161c4c,
+                0xec452faa19344ae3,
+                0x2a0569be861507c9,
+                0x4ae763b924341e27,
+                0x4395025dac857eb6,
+                0x36f1f01de57bbc44,
+                0x79d96cd30b3d6770,
+                0xb1d36b42a3b82ef4,
+                0x93f22c4a9f122ece,
+                0x4c9faa68aa1e93c0,
+                0x227cc77a5dc9e657,
+                0x30a20487f275
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9711fe6fcf333cc9,
+                0x4e33345b15a5811d,
+                0x2b04c5000f7ca47c,
+                0xca784074ae31e82,
+                0xda56ad2e75fffd2b,
+                0xfe76d7848a3607aa,
+                0x93e4c31b89b96516,
+                0xe7d7b3987735ab74,
+                0xc31bd83b47f5cd9e,
+                0x65b33ee115ef8532,
+                0xfc8e8170b5b386db,
+                0x164e7afad5a74
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5f9998e370e29472,
+                0x85de6612b3bf972b,
+                0x6ab1b40e0f5637df,
+                0xcfb7ca92fa3bd297,
+                0x494da9c748334b7e,
+                0xe5d47bc3719ec3e0,
+                0xee101ad4c47a5caf,
+                0x5e5406a61af5268a,
+                0xe67f17d1764622b0,
+                0xc72c9f618dbe64e8,
+                0x969e59d7f228c4df,
+                0x439306dd7f99
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x264ded40ed4156d5,
+                0x5be73f2b2c5a6c49,
+                0x15bcf6a2133d185b,
+                0x62977e5f928c74dc,
+                0x937a60d2faafa1c4,
+                0xed4c4e7646d8a19c,
+                0x2e66381efa12fa33,
+                0x14cd87e4bc3cb806,
+                0x57d29d935aaa05e7,
+                0xd1b8eb5a57f52df3,
+                0x74991bc6b3cac79a,
+                0x14231d95e869e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x89c65f5f4b67ead8,
+                0x85b40463033f2dab,
+                0xe4580886536417e6,
+                0x4844cc9c4fcacb98,
+                0xc405dad8ce9887a7,
+                0xd36e33c8d7ae8832,
+                0x704717e9be672dfe,
+                0xa9fdd435a99866df,
+                0xe13d8a582c17f8f3,
+                0x68e11a28a8345f3a,
+                0x5d0c6c5669009ab5,
+                0x59b06bacfb52
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e6e3c89872f1ad,
+                0xfe8bda788d88453,
+                0x7dbcbf0893e338c6,
+                0xd5fb62d543c74263,
+                0xda2de4957645b613,
+                0x988078d9665a4271,
+                0x4235367689072cba,
+                0xf0ad494a85595ded,
+                0x4dee34985bececb3,
+                0xc0e7c4ac7e933371,
+                0xbfa29b2c74562193,
+                0x1c34afa6edfb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x74a5ad94fef41159,
+                0x8674fa1d7e02ddb0,
+                0x8f1201babcf00ad2,
+                0xc34ecf790f07cb00,
+                0x1b3d8b2343b288be,
+                0xcdd8173afa4518eb,
+                0xd6ab2821578181cb,
+                0xf86d87b7ac1d8674,
+                0x7c264803f845b346,
+                0x48044e9b482318c6,
+                0x5e942849c5576578,
+                0x19ef874508f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9ebd650300d43635,
+                0xe4287a4777edd47d,
+                0xc680bc72ca240897,
+                0x45941265617baeb3,
+                0x1475b73d9d355c1c,
+                0x70a16312d8a58b09,
+                0x1f80aca5ffafacad,
+                0xd4ac4a4bc3480342,
+                0x2f4578923596db91,
+                0xc8ddead07a826116,
+                0x5e857de4101b15e1,
+                0x69f1e030baed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x14480f89d9574cb1,
+                0xb868b3dd4200e8e,
+                0x26e69927b36206b9,
+                0xd6822806bcd065d6,
+                0xa4fc42b3f5d95b2c,
+                0xa7d7a584d5e68772,
+                0xc2b1a684f5a15b56,
+                0x21c680b7085da9e4,
+                0x442c46e8afdf1039,
+                0x37bb905dcc89d53b,
+                0xe1f35240495d9e8b,
+                0x208c0f8aa19b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf5a629c4d1b52e77,
+                0x5d57c62c26784320,
+                0x864657137f4082fd,
+                0xcbf770382ed22269,
+                0xac9190346b139d52,
+                0xda51fcf86301446c,
+                0x1200a0c5352a8a63,
+                0x2be19a11f7c2b2d,
+                0x7af4a168a551a1cc,
+                0xdf83f137823f568b,
+                0x6801c198386a5704,
+                0x3df4d47124ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x25ad117008f43c09,
+                0x382361b4df6ee426,
+                0x1cbb0a467ea6df5f,
+                0xc1d529cf288f3cf8,
+                0xd4762c6329426b2,
+                0x288736aa1d32a161,
+                0x9131d519e4462909,
+                0x2dfc937f7ad33aaf,
+                0x21e8c96e5c405dfe,
+                0x7c267f3603f32505,
+                0xd08b686b04d5fade,
+                0xbd7ad9493e4e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1017952e637cf55b,
+                0xdfd95a32ade0679a,
+                0xcdaf6efae230dd31,
+                0xf57ef2e146140bd0,
+                0x614e9369bc4ccb09,
+                0x7ed452a656296868,
+                0x693adb6aa94fd574,
+                0xd0feae8a33e3d908,
+                0x7929aa6984682e62,
+                0x9204bf906595c1d8,
+                0xa2c7c485a90f7981,
+                0x1412b614f4407
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9fbfca2922cff7a4,
+                0x8e16ba1af8dd4b4e,
+                0x699d731be3fb90ac,
+                0x1f28d3264248440d,
+                0xdc93b7a182c5b8b2,
+                0xe573ebc3ce88d09f,
+                0x7a801bf7eea1f98c,
+                0x3f73014776f11a9a,
+                0x21f529cbbe5627b1,
+                0xa2580211d5747cc0,
+                0xcf4c19aa5981a9ce,
+                0x157a4231287e0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x653aeccc8e65fe1f,
+                0x6646872675583cde,
+                0xd4220d722554239b,
+                0x4eb6706b3374765b,
+                0x5ec3f82dcb33fb77,
+                0xbf03b256a7753f50,
+                0x2564575d9d1fd2a3,
+                0xdd9b6d33e7378ca2,
+                0xf7a97b39ad6f8a66,
+                0xdb5909a4480f74fc,
+                0x2b322951235ea759,
+                0x953c77e5ed35
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa23e2d9c9f4accc,
+                0x5c2a239e28f4518b,
+                0xe93b9286578305ab,
+                0xa0ced18834d3fefd,
+                0xd39564a1119cf461,
+                0xe55fdcbb3fef2c0f,
+                0xf30681ffaf233e12,
+                0x1c680314c49965b7,
+                0xbddf406b824b461f,
+                0x16205703dd686563,
+                0x6d08d6560eade59e,
+                0xd9522c983a38
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa4d0724c4a3418f,
+                0x804f775ecab4e81e,
+                0x75c717691ea6a469,
+                0x29ef368b8da60c77,
+                0x53e3c8092dc0f632,
+                0xab84a2cf450ce684,
+                0x1f41caf0ede61daf,
+                0x43ec689de736e08b,
+                0xe0d4126e5fefabcd,
+                0x8c810e118d892ea0,
+                0x41bbca01d4b81fd,
+                0x104d5eb87eeb2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x23eb66d7856c0e8c,
+                0xdc3308518ea19f1a,
+                0x8c4c3a16b47585d,
+                0xe8e1152bbe7de7f4,
+                0xcaea8ce08443a7c1,
+                0x7735819c53e84754,
+                0xb29f88f9d03bd405,
+                0x45721c91e50fb695,
+                0x24241af2e28b3fb1,
+                0xa553ab88f9f98b4c,
+                0x9cd3624413495b4c,
+                0x168b8f4929c3d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ef28e6b6781f6ef,
+                0x70c4cc935dbcaca1,
+                0x42af476ecf1d72d9,
+                0x93ffd88464d8df40,
+                0x8ddbd214cecc140e,
+                0xfe8301664f876e47,
+                0x858b2522ec4212d8,
+                0x744c92e311eb201b,
+                0x7aa918423b41bc01,
+                0x270b6dc45f61393,
+                0x3a8abeabebc38112,
+                0xddecaae19320
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x226fd99afbb7cdd5,
+                0x92d2effdfbcd8cb7,
+                0xa65a06ccad50364c,
+                0xe38224a76a5bacec,
+                0x894eacb4c4557fb4,
+                0x2b1b08c91693e9e6,
+                0xe6767bed9b673638,
+                0xf10bb6922988011b,
+                0x325fc765c65c58ab,
+                0x5e5c695428c50ea5,
+                0x281e92e9ccae4af4,
+                0x12a6298e5035d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x529b25038122fb28,
+                0x43cb1692c4c25a15,
+                0x9c089c592f6d220,
+                0x249ef5cc66344210,
+                0x6e6ced98e3897abe,
+                0x9b6c707b93f3ce71,
+                0x6bb55245f1fdbcda,
+                0xbbd1e259b976bba0,
+                0xb5ae59e390378e26,
+                0x5636b91f4e161d0f,
+                0xbfc00c06c8d88cab,
+                0xd232b98163aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe4e52f8d0e869887,
+                0x956048c327960d14,
+                0xda8a2faf138e5920,
+                0x859fdc40ea442e38,
+                0xafdc2e67c84d24a,
+                0x24abef93d22af758,
+                0xe67ebc4870bf3a1c,
+                0xf9d0d1cbb728bb90,
+                0x888b45fa7f9b6847,
+                0xe96573975e741092,
+                0x910b9f30a8b5a6f4,
+                0x19e2c1c56ff5b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1b72c14a15e2e607,
+                0xe1b5134843e4f5f7,
+                0x65df74190046f00d,
+                0xd641579c1a02475b,
+                0x56673d82c22bd27b,
+                0x1d798351056c7dec,
+                0x6ddf192ead983ad8,
+                0x86fb007a8e60127c,
+                0xf73ebf2a88ee533a,
+                0x8412ce535b3f9865,
+                0x46d62c2becc57543,
+                0x65a216d0e4b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83cfa582a4a772e0,
+                0xbb933b2362f8e1b0,
+                0x7aba12440ab9f5c0,
+                0x6654c072cf9b409a,
+                0x8d5c75d0b9b30181,
+                0x3dd386a66d1aaf30,
+                0x2e23123f762979b4,
+                0x7ba7f7d968151fa1,
+                0x5201350028d647d9,
+                0x36477cc36fb73b6d,
+                0xfa63b490e268e4f3,
+                0x15037d8987677
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcec602e75432aff3,
+                0x39cf0305fdb808f,
+                0x71564807dfee120e,
+                0x43aa2e1578de347,
+                0x53144b3b9dfb2331,
+                0x70758b57331303ff,
+                0xc0889222afad96c0,
+                0xa3f9ac434e9e5fc2,
+                0x3817bdb32400e7ef,
+                0xc3a5559ea1514829,
+                0xe1247d5ba8d5545b,
+                0x1012278f5a5e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9d251efed089e45c,
+                0x303b7643febdeccc,
+                0x9296657bab95b6a5,
+                0x2f152930fd12b35e,
+                0xed0aed86899e0d72,
+                0xd8fa294ef266f58d,
+                0xff4870328a687043,
+                0xe2a0733325c5b5ca,
+                0xc71031795847c4d,
+                0x342244a1ce3cd624,
+                0xf7c420747895fee4,
+                0x4f24d5f29d31
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3461fa22ceffaef0,
+                0xb3e24986302428c5,
+                0x786da080c350ef43,
+                0x867d0378c4a2cfc5,
+                0x81b271a0d7b565e9,
+                0x870f8fc611c40752,
+                0xb86bdf137722878,
+                0x2280b6eee3f248ff,
+                0x20b8f0ada3086958,
+                0xe78bdd83703b37e0,
+                0x1c254d4a2bb78a64,
+                0x772abf7764e4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x60b18e2e0407a86,
+                0x212a58de750e530b,
+                0x400d6cb71172e61b,
+                0x3d6d6fa8de19cf7c,
+                0x803d0e694941fedd,
+                0x9ef7195f2ebfecb6,
+                0xfbc378cdd5c467c,
+                0xf7e6c8194ea9f133,
+                0xf2b00c2442366486,
+                0x1c78ead2dd0de56b,
+                0xcf79dce65a47ba77,
+                0x1c4a81fa4784b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4436f58fdcbf3b62,
+                0xea7e877a5f90d09f,
+                0xaa276db13f30f029,
+                0xb8329343dc3f6595,
+                0xfc73036ac644b7d0,
+                0xfe1a3c65009362da,
+                0x39e3dd9e8dcf99ee,
+                0x6346f7d7df0d277,
+                0xb7ecaf168edce00c,
+                0x4cfa2e3e4de9b213,
+                0x2f05675a8ebc4773,
+                0xcf435c767d68
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x190263de43ad077c,
+                0x7970f76a6baa6150,
+                0x6cf6de744fc61507,
+                0xc774f0b5ab1f10a5,
+                0x7b28c520dc1d059e,
+                0xfd6f36f8763268f2,
+                0x1d5a3f31b34bb136,
+                0x383479214612099f,
+                0x96ca0a864b43b81d,
+                0xc53e255a570a584a,
+                0x8ec21f082e340d50,
+                0x15a37e84cc2c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe72e507c656618b4,
+                0x2a76dfacfec490ed,
+                0x3e32e78cae503f27,
+                0xb40156e33151e868,
+                0x38543191dbca86bf,
+                0x5f73ebaf4b140cd0,
+                0xa2f982c440abc52a,
+                0x525b3d0e94c500f4,
+                0x85cd9447cc007fbd,
+                0x91a2c8c81037bfe7,
+                0x82d6f7f754baf258,
+                0xaa9cf25715a0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2b901e2ffca0472,
+                0x21f5dc015c6d80b,
+                0xdd8f748c734adec6,
+                0x512e04aa0bb32931,
+                0x10e62ea190751aae,
+                0x71210e72386c6e77,
+                0x15701562fe560288,
+                0x25820b7c19d9fb3a,
+                0x19d769bb88970222,
+                0x6f83fefa1acc7c2d,
+                0x74aa190c11fd0b92,
+                0x155370bb25225
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x98a39530b957d613,
+                0xe2ac4afca4b470ea,
+                0x3994f88804ef42d1,
+                0xa0515a34ea84c7a7,
+                0xda72f72025fec357,
+                0x96b53f3627bda93a,
+                0x3a54fbb4feb856fe,
+                0x7e1402e8894a1a00,
+                0xf843615bae32f6ad,
+                0x9ffdb6ed00b709e7,
+                0x9d20e3d45cf0bcab,
+                0x12ab073800ae4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45124a429aa7b6ce,
+                0x1be73ea1a54af130,
+                0x341fcee600e5ff59,
+                0x1dd3f5a295ed2dd1,
+                0xe2b44dd25667db0a,
+                0x62cc1c3e7db9e240,
+                0x6d480b03b51d6c47,
+                0xedbc801ca812a0d4,
+                0x996501bed6eb67e4,
+                0xa63a070ea967d3b0,
+                0x1f42961a3e9fab49,
+                0x1b445e9601fec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb006c1bf3297e7e,
+                0x8979d7d722fcd13b,
+                0x44e79821b4040667,
+                0xdea7fa9f8650dc9e,
+                0xe1fe3973018ac747,
+                0xe5172abe896604ff,
+                0xc06e536cb064e781,
+                0x8403b542ddfe528f,
+                0xecfd2a52004ef440,
+                0xa73733573c572fdb,
+                0x9ce482c51a1417e1,
+                0x57d738ddf93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc54815b955ffe9ea,
+                0x5b7bf7253c0dcb94,
+                0x4e6542497cb562c0,
+                0x5b28b80868e5554a,
+                0x1fd13f47ab9c85b0,
+                0xe376e8eb76fe1f22,
+                0x556603e76f9439d8,
+                0xe1701e02671deb5f,
+                0x7b347f5fb766747b,
+                0x9fc0d838a1f06daa,
+                0xdf3bebe56f36151d,
+                0x4f61e6646a29
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x993da2841265c047,
+                0x9a3932af8cb0627a,
+                0xf4c0f114047466ce,
+                0x5200146fdb8dcf65,
+                0xf10565e00b012dbd,
+                0x7d879f1c287d192e,
+                0x2205267e25ae6f7,
+                0x461774369d226692,
+                0xc74d5987d48f0490,
+                0x87862e03c5481f6e,
+                0x7f7d7e8c7fdaf0b9,
+                0xe564f6de8568
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb009415115de5c0b,
+                0xdffac3981d3168dd,
+                0xef9967958258337f,
+                0xe832695af641f672,
+                0xbd97cd255ddef17b,
+                0xda0dc95b154272c,
+                0xaaa82e55ae9f86cf,
+                0xf9a8c304b2cc72e3,
+                0x7f7abd919f9af131,
+                0x63bc553e58ed72be,
+                0xa53b64ed5d7c13f4,
+                0x83b03cc2c738
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x256bd13bec1bad42,
+                0xcd5aedd65429605,
+                0x9c3bb2e4f254c410,
+                0x915b0f6193f32a17,
+                0x2536d963a3e768cf,
+                0xafef335996de56e,
+                0x26c143d4074fe6e5,
+                0x5395c5f3ae9bd667,
+                0x76bd601019cf41cf,
+                0xc4c6ca3fcebfdde7,
+                0xd97e03f61d8ac955,
+                0x1540a0d76c3c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe249d7774cff1172,
+                0x5d5e00b2e96084a5,
+                0x4d5efc4017f63692,
+                0x9c9bc2218057947c,
+                0xe139056740c92677,
+                0x933b0f6387332eaf,
+                0x901c66d7235ea06b,
+                0x39a5e7f4f2c82584,
+                0xa6777a158109f8bf,
+                0xbccf31a8cd5f1bf6,
+                0x54c7ed2450eba115,
+                0xbefa8027b633
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3fe465d3468732d9,
+                0xf75a95b90f596893,
+                0xfe7f40e838d3828e,
+                0xcffb6aad93281f53,
+                0x94ee2bf23634eedd,
+                0x6304efa3940c6079,
+                0x132f9732c76c6a8e,
+                0x3292feb768276224,
+                0xdd794bfb49a427a1,
+                0xc9dfb23794345a9e,
+                0x29caf2a7d26ad28,
+                0xd9877affd580
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1654c5823bce1921,
+                0x4d0e87c252771d4c,
+                0xe3687a96954e5a0e,
+                0x8b53604327d5078d,
+                0xd709e3729232887b,
+                0xa0b7c22e21b39c19,
+                0x65f2997338376617,
+                0x26bff8d55958b6bc,
+                0x971e63c566bc06d,
+                0xee722990311996d0,
+                0x7039f8c510ca1dd0,
+                0x878325094f77
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x54e8f9fed84d4314,
+                0xf5a4dab2d54607c8,
+                0xfcee555bb041542e,
+                0x28369a171783829b,
+                0xbba13f5d20662be5,
+                0xc7a5e25b2bd85d0c,
+                0x3a51af2d9a8aec24,
+                0xd3cc02bd17d0ca6d,
+                0xc813264b6228893a,
+                0x5228e0fdb13d0e3d,
+                0x24f54247514ff65,
+                0xd3d9bc97dfd0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed7ceff0f0239317,
+                0x9ec5db21bff385b7,
+                0xbd74b396e614633a,
+                0x73606a4ba50efd47,
+                0x50e5042036a2c27e,
+                0x1d60141c58e9b33f,
+                0xd3917cbd121ca7af,
+                0x8d04c96a59ba4ae8,
+                0xf4cbff2a1436a62c,
+                0x4f22ffb1bbecf6e0,
+                0xd8119aacaf7e5d2,
+                0x110349918cffe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b464e822a57fcd1,
+                0xde7037a2a90c69f2,
+                0xf070c0a7cac6f2f4,
+                0x52dc725c9d2b4afd,
+                0x2629768e70c26269,
+                0x4bf6c4b19b36085c,
+                0xcd6ee13008b7819a,
+                0xe9082ae5be5e556c,
+                0x426a0e64e75378b8,
+                0xee8e2147ae2e2434,
+                0x9176d4bb4649df73,
+                0xb71f9573344d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef566b9ee49cb40,
+                0xeff14558d32b9d96,
+                0xa4b25bf3e970745f,
+                0xae729eeb49a9fa80,
+                0x5e518b2ddf733304,
+                0xab4422e5575f89eb,
+                0xac78800735f06926,
+                0xaa5c80e939d3f6eb,
+                0x4e7aca62101da6c8,
+                0x413d6c9934d1d530,
+                0x8c291310cc29a48e,
+                0x183c266094788
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b8cb6b22759f422,
+                0x9724b6fc8489290a,
+                0x4b20bfbbe79028e4,
+                0x54f3ee7cb7219206,
+                0x6f3c44d11c3cf9e9,
+                0xdfdd0941a1eec521,
+                0x1291dd807eeeb7be,
+                0xa9daa0d2422d9a4b,
+                0x33a46ded6dde3098,
+                0x91b5927956892e4b,
+                0xc17d574ba1596a48,
+                0x19ef4ff8dea70
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf4038e15a2d79c50,
+                0xaf0e3ef826aec2df,
+                0x9d43355f6edfb71a,
+                0xbd925ecf9e638c3b,
+                0x10a0f74334462e4f,
+                0xd088e710d28cd8e,
+                0xf18a80ebf90a2646,
+                0x977a4cc0179e41e,
+                0x4af20aed693493b1,
+                0x23a67528299ca803,
+                0xdd8d28e95709f680,
+                0xd28832d128e4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7a539adcdf820118,
+                0xd717f06ab31f821b,
+                0x80e5a32388de3244,
+                0xe7395d0ab41bd064,
+                0x81568e367bf09c53,
+                0xb2b0b0a5181866b6,
+                0xe47414a42785e0a0,
+                0xceb7ac7159f906b8,
+                0xe91a5214d43c2d32,
+                0x27b3181b5ad17647,
+                0xddc9a6314226709,
+                0x77cead36ea2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf6416332e12d34df,
+                0x6db2b104098d90cd,
+                0x969e66939b6ec4f9,
+                0x708344d225c197b7,
+                0x9a2560a55b2273cd,
+                0x58041f4b775ba2ee,
+                0xd60c4a9985b5f59f,
+                0xd95e4d27a8089734,
+                0x494f30bc8a18fd40,
+                0xd448eb5272a4ed80,
+                0x5a5e233e74027847,
+                0xc33addf9b708
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb83c09f93b4f345b,
+                0xf01bc0e6e41cc830,
+                0x7b9217dac18ea336,
+                0x3f5e7e828bcc9d20,
+                0xe62bb51e1bca839f,
+                0x99ccebec1866409d,
+                0x63e71e6111eb3bcd,
+                0xaec1e167c0f3036c,
+                0x29e80a56ba310eec,
+                0x99fe0670d5851bf1,
+                0x47dae99bc3e475ac,
+                0x1987a2cb816f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5c0d7b45a1dbea5,
+                0x7992edc77857afb5,
+                0x2bd2c2e7a9bd3dfd,
+                0x9c2a079017c86d03,
+                0x695310d3e0d4f8fc,
+                0x292b86412ad12e96,
+                0x1469b223d0da3778,
+                0xa4c8ad643b36222c,
+                0x38f1b13827618ecd,
+                0xe8798b34aed42f3,
+                0xc8510aac1371f887,
+                0x168f404045088
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x171e54b65dfc3e62,
+                0xe62f9002f8b93b3f,
+                0x8c30a4cd905b2c19,
+                0xd4324ccba81634bd,
+                0x5ae33c6a98c50d7e,
+                0xc93e0924e04e9fb7,
+                0xa9d197d5d6fd74c1,
+                0xd16f36315904f7c6,
+                0x3ba8745ca2ed7854,
+                0x8c1d8fa5b155eb8c,
+                0x1663393ea0a41ab4,
+                0xc788fe6c8fa2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x42d3df52f5d6bebd,
+                0xb8c0c9dcfdaf29a8,
+                0x124ef499c41be29b,
+                0x18259f74dd3cc16c,
+                0xf86c3890888c8874,
+                0x4b690aad0e1f2cc9,
+                0x7a8fd0ab282b49fd,
+                0x4c8233abed7148f6,
+                0x52dd4ca39583313,
+                0x36fd99dab63988e4,
+                0xfb65fb81b275620e,
+                0x6ba8e4ebf750
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x881f59a56e506ebf,
+                0x9b542b556624b2b,
+                0x3a9ffe80e503900f,
+                0x3be58ba51ebabefc,
+                0x88dd88cee64b5f9b,
+                0x8d63facffa69a4e5,
+                0x2321153af0c6776f,
+                0x6a399f63829f9c2b,
+                0x60e7042992332aed,
+                0x215fb5a775a76506,
+                0x1ee06d38813ded1d,
+                0x1587c3e70e6d9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19c7063ab0033cf2,
+                0x19f91016162a6108,
+                0xa5bb7c48519505b7,
+                0xad5a3842c8a88b2d,
+                0xbada2f2903c5bf14,
+                0xa2b69a11dec0bf0a,
+                0xa083084cc7b741b9,
+                0x7d7bf6d7a417d05d,
+                0x40de7cbc1085f17f,
+                0x2b1127a2f351be40,
+                0xc65c5f7ff6119749,
+                0xbf22ae5ede8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4269643897309e95,
+                0x49d8946ecd74e147,
+                0x9a1d14b31ec0a09e,
+                0x802a47bd64b774d1,
+                0xfe5a225098e867ea,
+                0x941f86ef51a0be76,
+                0x98cb1fc00bbb59e9,
+                0xf61db07b641f3e4c,
+                0xd6d008d4c5d49b25,
+                0xaae08017a36cb2cf,
+                0x301781ba9b2890bf,
+                0xa0968cc5997c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19344ee75f553223,
+                0xfc9a10152e9bcaa8,
+                0x3a2c48c730d7d45d,
+                0x95bd2da4db231200,
+                0x193bbfd2e0bbac19,
+                0x13d67c9a1d97b3dd,
+                0x981477409ce9e35a,
+                0x53ca998a5d69e655,
+                0xae56dc2246faa8f1,
+                0xda6dfc8987e20a69,
+                0x903e94e0b5449bc0,
+                0x3cc61df52601
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x472cc71f38091950,
+                0x7a861189240cad58,
+                0x7427e04962fbdd18,
+                0xc69e1d81ea93ad,
+                0x368a545660c061d9,
+                0xcd03d641a5db4584,
+                0x633fe3384851deb4,
+                0x29892e0685aa6344,
+                0xa58ed3ff2e04a64e,
+                0x597847fc8fe3418,
+                0xa372777b76449779,
+                0x123b444505f6e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10dac8abc4317951,
+                0xd5d2b18d1fd11764,
+                0xd5595957ddb08850,
+                0x2b5b0f04a6a5f8ee,
+                0xf93729adac30cda2,
+                0x43116c56c7e9e101,
+                0x9f83a5de3e948c84,
+                0xda67be5661a096b8,
+                0x63db943e08a52690,
+                0x7d7cf8fff14dc83b,
+                0x25cc93c550f2bd4e,
+                0x1bda3153dc72c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x709a22b6571e46,
+                0xc5d97949eefa7710,
+                0xbcf3ea8a4825658,
+                0xbf970d4a60338dd,
+                0xa48003905caf20c9,
+                0xe0a43b8c6a84c8a7,
+                0xf624f5bd7eef6caa,
+                0x42553aa61a0ea66e,
+                0xfc33c904cf0348b5,
+                0x5a6966f40a31786,
+                0x96a778da9b5a99be,
+                0x8b5593735169
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37530b4dca845c1b,
+                0xbb03afc20a665dc1,
+                0x6c8a8b7651d17d73,
+                0xcd9185a5504741da,
+                0xb9db098c0213352,
+                0x3a26d8f7e76684e9,
+                0x59f0871f4f2a4408,
+                0x66107f6c698c05f2,
+                0xa3a4b4020af0a2fe,
+                0xebdbf57c4c830ad8,
+                0xa8d7e59bf6af297b,
+                0x10f218aaa2a4c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1b967681b0b22efb,
+                0xcfd6037505c3f2a2,
+                0xdeb6dd9be4ecec8a,
+                0x83c3451a14534673,
+                0x4d752ad0d5cc7596,
+                0xd262d0256090c533,
+                0xa212db8c1045a41c,
+                0x3e1065d07576d45d,
+                0x4eba92a5a08a672,
+                0x3d7ab7384f00c327,
+                0x8f59175cc0d4a129,
+                0xa60b96bcb399
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fb4decb50e8de92,
+                0x7d590268754376ec,
+                0x1a97e940e082f440,
+                0x35384498c294a44a,
+                0xb91aa0d43b6897c9,
+                0x9d523c1db7d1f90e,
+                0x320594b301c26108,
+                0xf00a364528af8b76,
+                0x9164958724d981eb,
+                0xa4ddab9d0fce11de,
+                0x9fa79c4e31add799,
+                0xfb9b87c90f78
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7298edc53b238d42,
+                0x4414f43617f661fc,
+                0x9d8376cfa2db3935,
+                0x89d20a66e36956c1,
+                0xa979f2b28f895525,
+                0x66aeda6c40ce785a,
+                0x47d465d033157627,
+                0x9e237e83cdcf7572,
+                0xa9755d8fdfd285de,
+                0x8301010dfbb9d621,
+                0x7e1c0e59ecad434c,
+                0xd400df6482c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x83195fa4419c8c7c,
+                0xb89533be4204ed1,
+                0xf056385a3c987f36,
+                0xb7ee64641b3a893,
+                0x1d358a8e7b685f82,
+                0x3c140acb2ca844ef,
+                0xb717586d98922293,
+                0x7a870d0b2f8e74f2,
+                0xa3fe3614f4c17a8f,
+                0xe2347d96c741b4b4,
+                0x33fa1597fcb9ba0f,
+                0x141e43d769996
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45a67f9b0f135a5c,
+                0x27351331a8c13c2c,
+                0x24c47b877522588a,
+                0xc5871d5cabbd8727,
+                0x598fac4655fde266,
+                0x3dc7049eb261bf1a,
+                0xfc8714eafdd09b2a,
+                0x3e6dc0bd9efa59d0,
+                0x28894a2c797c103a,
+                0xb7376c67e90bb88b,
+                0xf88477a04d1d9e6b,
+                0x2267f389c839
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdac003d6263adf,
+                0xb08e132c72bfb261,
+                0x9f97c79b2ebf9d82,
+                0xfc4952db60c96ec6,
+                0xafe073a299f48819,
+                0xec1d4c7603867a2a,
+                0x4bb04fe6e4a9313e,
+                0xe536c0bc9bbd774f,
+                0xdca4a30787d651ec,
+                0x458f8f02f5ac653e,
+                0xd457e39556322bd9,
+                0xb547cbd16421
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x31edbf15f61f3220,
+                0x7d66ca4f89282b48,
+                0xe839b2c97f79db1d,
+                0x3806a7e41dd7ddb7,
+                0x21be3169c62f9096,
+                0x3baf6ca5b4e577ca,
+                0xe11a149e0753e92,
+                0x7200c5b129f31702,
+                0x9a11485d8b7046ff,
+                0xfa7c1c8e0a7aae36,
+                0x278db48d891c6c78,
+                0x48ad8985e3e3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x80aaa1e349fbb4df,
+                0x1a12ea8dc8f1d640,
+                0x5bf3f83bffb426c9,
+                0x23e9a4afa2cc0501,
+                0x7e13a50be7e5c3a,
+                0x8f2213bf478e571,
+                0x92e6149669e5c52c,
+                0xb07569caac70ccf0,
+                0x6723134dc7ead79e,
+                0xbde766d1c5bda987,
+                0xc5a80170467f78b9,
+                0xb1179901d2a9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x375a1d990c14a201,
+                0x101120d5a674882c,
+                0xa64c7fc8338a529e,
+                0x731a21536f9754f9,
+                0x490aea7b7efd3aa5,
+                0x1687e0bd1e0d1856,
+                0xea99585bbf9996a9,
+                0x6757f94319de4c9f,
+                0x32af6ee8bb4ee9b0,
+                0xcd34f4cb071b7ad,
+                0x537d515cb66746bb,
+                0x180b6c57870a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x975066f7261472e8,
+                0xff77b28cf883d258,
+                0x5892ba1534c85c5a,
+                0x3a5843acf5ddf539,
+                0x4f69d9c6e80d9d7a,
+                0x600dc44b870d6f54,
+                0xc1029f7f2fd4f204,
+                0x39a16d3a0a4cc795,
+                0xb0f744fd36b5e50,
+                0xa98de1d3c4003fbf,
+                0xda18bbdfae8347e7,
+                0x9f12f0d94aa8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c9351161e1c6464,
+                0xc3f6a3af8a82a50,
+                0xf1d1b7e37d6f8990,
+                0x7fd1226ee590acde,
+                0x5c18f72f3a25d265,
+                0x5789166750f534d8,
+                0x4c662676922033bb,
+                0x99f9dad8c8f11419,
+                0x77d8754dadeb05ec,
+                0x8ccf475d8a492b18,
+                0xa57ad03dc8399bb3,
+                0x901ad5c9abb5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x84a6d4c183e12749,
+                0x538c5281e17560a2,
+                0x29646321ee830207,
+                0x7bafbba6ca40e195,
+                0x11af82b1521cc7cc,
+                0x3e94fa89947917b,
+                0x54939dd5ca13012f,
+                0x22e627aa9f2dbd26,
+                0x6d93672ac161039b,
+                0xbabeaa91be2ad23f,
+                0xb231038d4ca4c044,
+                0x102e46e4d334b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f0881059f5334c0,
+                0x8e26a63bb553fb0a,
+                0xb6d55986382dffe,
+                0xd63c49049c71507f,
+                0xe14f42e5d87ea0e3,
+                0x50bf70190716f533,
+                0x5bdcb4bb24d0bcdc,
+                0xb5da0961028ade50,
+                0x6756a23a3492c037,
+                0xadecd1f6fe367a19,
+                0x4b52491d98a41dfe,
+                0x14a67703ca4ac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x881efd9adc9fc9d4,
+                0x260c84729d7d1964,
+                0x73cf932fae0bcb9e,
+                0x61213a0042b36a73,
+                0x95a4030b045522ee,
+                0x23e81f85f671dc03,
+                0xebd310384cbd1749,
+                0xfba7d3538597d7de,
+                0x569d99a32c853a52,
+                0x60b1a6fcd27f0119,
+                0x5be696ab9081a513,
+                0xe48c0c7d1cac
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x69a38b0538d327d,
+                0x3c35e586902baaec,
+                0xdf0968d6c6b35eec,
+                0x89224d01be02a1d6,
+                0x264517eda23f1175,
+                0xd2eec5212e625923,
+                0xdc28696e2c421853,
+                0x3b3cb98b659184c0,
+                0xbaf076ad711a1abf,
+                0x856c1b41c364851,
+                0xdd3c4a5b925b64f,
+                0x670ec0db0afb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc081d06ab5db6ff3,
+                0x33961bf26e41ed7b,
+                0xca0e3f9c4dad8f01,
+                0x4cc6faf643d75e4b,
+                0xc56ab55c8f949186,
+                0x8aaf467073ee34ab,
+                0xaa0cf7b962bf4690,
+                0x53cf3ae3af9c6bf3,
+                0x354bf443302a89a7,
+                0xa1d5c591a416e93e,
+                0xd703114209263863,
+                0x22b7f1d82f0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf6cdddf7de27e9a8,
+                0x41dcb52f3800d34c,
+                0x1b7550acb8303743,
+                0x38a0ba1ef0d2985d,
+                0x5ff9f42f7678c880,
+                0x10a10b20f79286d0,
+                0xf7ca234068f60079,
+                0xb18bf785597addaa,
+                0x97d9162c1b370e05,
+                0x3f229849cbd15393,
+                0xa3aab468a12db780,
+                0x14dce40fa7950
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd06726efd6352471,
+                0xfa9ed4c8e20e275,
+                0x91080abdc6259898,
+                0x1510187e9851142e,
+                0x66e46d4dc6713fac,
+                0x472244890be3dff2,
+                0x462790326b63e162,
+                0x4d145616800cb0d0,
+                0xea879cba1d61ca02,
+                0x57e80284851a0268,
+                0x255c73cd5d78c237,
+                0xf06e2875961
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x44b250c2351b8c60,
+                0x4e2c0217333f59e2,
+                0x995ad1cda1418369,
+                0xfe76436dc3379f9e,
+                0xd18873ce895390d5,
+                0x4cbd52e949a45797,
+                0xe22a3923063904d4,
+                0xa1ec410d0aafaca3,
+                0x3e7e165f35222ca1,
+                0xe282a1aa3558015a,
+                0xdfb28a4d903fd0b5,
+                0x171b831b2a712
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b63719a626b18f6,
+                0x25f8dd91dfcd5021,
+                0x745ad7c9295b468a,
+                0x6057a4cc1082bc9e,
+                0xf30922044a5e745e,
+                0x49aae180c1ce2116,
+                0x8774c13550ed414e,
+                0x8916fa2bc5948514,
+                0xc51828aa4cb0a905,
+                0x11f4d32ab87986a0,
+                0x87f77a9e26b30808,
+                0x7f4711029cab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfb7fe913dc3186,
+                0x225984dbc3deea1,
+                0xa8cea07df5c9ffe7,
+                0x90d68d838f35ba45,
+                0x13135b978dccddf3,
+                0xf5f96abff64d692a,
+                0x6057eaa33c9b865b,
+                0xcb683adffb1aa0a0,
+                0x2d08fa73e3ab77b,
+                0x125317006f255ae5,
+                0x46647e076220fdd7,
+                0x14b8ee32f1c02
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc99abb9aaa9619fd,
+                0xc1525c0e0ceaec3d,
+                0x762553224b818c42,
+                0x2a4324020578a84c,
+                0xa75f52d5658efae4,
+                0xccbbede77572e86c,
+                0xe26f6641669da191,
+                0x93e9a443c771664e,
+                0xe7ed4540880effd9,
+                0xfad6dcc92ae3f9e1,
+                0x39567cb4d4254a6d,
+                0x9c306527088f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x661854bd50072d1,
+                0x8a201b08acba5c9e,
+                0xc4cf98cfc81bec45,
+                0x50ae94aaa3a623ca,
+                0xf91c790209db241d,
+                0xce8362bbbd0dcad2,
+                0xf055da3f4def6e5e,
+                0x36e2a095a53a173b,
+                0xf8ddcf8b22c30a41,
+                0x367556f20cd6a904,
+                0x100b064fc57cfe2,
+                0x16c941e1b1aa3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x41b2efd09d1dec33,
+                0xe69758246f4a8fad,
+                0xdca93b68c982bb5b,
+                0x896f3ef85b4a6040,
+                0x60edb6f231f54275,
+                0xcd63395b621826f7,
+                0x632e64012f350f0b,
+                0x70c345f283c1f1ea,
+                0xba787ccfd08a311,
+                0xe5233a2063bf807b,
+                0x7362118664df592,
+                0xe173dedf2a72
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x501bc169e25b79c,
+                0xe7b6eabf3404815b,
+                0xcdebdcaf81fbbbcb,
+                0x73806a90b01ecf9,
+                0x94d20f811f7df6f2,
+                0x422659de9c4a1ad9,
+                0xbe5e91e9bc1441c5,
+                0x344e6f73d604030d,
+                0xcf87fd91e9d67159,
+                0x9b0637cf36710e17,
+                0x55c199ae6c71ef0d,
+                0x1926061b30369
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x516ec074392552f9,
+                0x122f3c430ae3ec66,
+                0x98b80f0876a84017,
+                0x17bf68a28b6dfc6e,
+                0xd5fb94e4e859123c,
+                0x27a3a3649011f72a,
+                0xb98d17dd66ad5b7b,
+                0xb5452560aceb018d,
+                0x19b1e496ec4d98d7,
+                0x2549b33e494d7329,
+                0xc5041666b85f34c8,
+                0x13197adc53736
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3686a9d645ffec35,
+                0x4d68c86c81a456ec,
+                0xaf64b95a46ff5000,
+                0x768357cf335f4f73,
+                0xc1bc0fd46f6359dd,
+                0xc85532ee3aca5648,
+                0x4593f9e66b5400d8,
+                0x333795bf1ed817c4,
+                0x142b95aaf75b4c9,
+                0x57791c28b956c9c4,
+                0x4bc2ffeece5d09ef,
+                0x37f93d85fc44
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6170f99685f6a508,
+                0xf8cea0e861b3993,
+                0xa4cc7f3dfb6b2fe9,
+                0x56cb989026bdb911,
+                0xfcc0674841dd6941,
+                0xa461f5668e49962d,
+                0x5d4fbbf1d67cc68d,
+                0xd0285f9074639ed7,
+                0x911984cb1ce9dfbf,
+                0xa83a924974225911,
+                0xdcc998598ec6c1c4,
+                0x24a0071308ed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x863bb45e6c7696e5,
+                0xf803802ef386c013,
+                0x770a7082c814b32f,
+                0x965940f0d80bb663,
+                0xbf702126613c0ad6,
+                0x9c8dec478b6aae93,
+                0xdfae924e9d09e10d,
+                0x15d985928d5b1c7,
+                0x2c2d7d827254f192,
+                0x5dd27dcc208f6b16,
+                0x2982ff398236a9aa,
+                0x128ff803e164f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xad7bdbd4a229b76a,
+                0x42f5913f3441f048,
+                0x8de7cfcc9f1fbe52,
+                0xc0a9d40e9c20f956,
+                0x79f0136f7746f694,
+                0xa565dcdf725cf9ea,
+                0x198101bfb34c6702,
+                0x7cc07b0cc29928f6,
+                0x64fba4f0064d5128,
+                0x4a8faa92d8e2b693,
+                0x3adebe0685440693,
+                0x191a87b14806
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcc485bea3a448a1a,
+                0x64d1fbacf7a7d2e2,
+                0x64d0a96c009685a8,
+                0x389b6e4ba80fbcf4,
+                0xb977d9c801e44eb8,
+                0x39e15f6486ec9ba,
+                0x479d9b872bbcb7aa,
+                0x352824f5c4f2d185,
+                0x7315eb73f6c72af5,
+                0x97d7852a08e28e5c,
+                0x1ffe4e3b36e5742b,
+                0x158e79d59f9d8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x838c1b8f22eed330,
+                0x71bc8ad204c0dde4,
+                0x596843f21663a30a,
+                0x66dfb7b1e6590e6a,
+                0x11a9dfbeeb37f2d5,
+                0x60c3cd5f01bc30d3,
+                0x96c149fa0c46c57b,
+                0xe9bfc7e0046191ef,
+                0x43de6b08eba90da9,
+                0xdd5343b9bebbf175,
+                0xea69d2126dd34f1d,
+                0x95ccbfe3e766
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc2148cdb98e6e1a3,
+                0xb31e2f90675ef756,
+                0xd3b1f3d6e9fa4aaf,
+                0xe6907ce3dfaac826,
+                0x85ba1017d1172323,
+                0xd5ae5a4f0235767,
+                0xcce763dd8e700ebc,
+                0xf9dc7709aafc4b79,
+                0x5b842d567673da66,
+                0xae1454f09de97aa5,
+                0x488eed357860934,
+                0x177eaa5b93d19
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf35590d6e61567bb,
+                0x1d40344530db931c,
+                0xb7ee5b7227abe4be,
+                0x364850dfdaf864fc,
+                0x1b97e841d42c3ebb,
+                0xbc7682e50730b619,
+                0xd61b4ca121d9f38c,
+                0xb189d1eb52007495,
+                0x75916a069e247d74,
+                0x2bf9dc618d176dda,
+                0xb870262e0ca5cd49,
+                0x907b42abd525
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfcd874e9427656a,
+                0xd4de7650ec8ba0dc,
+                0xcacd394010f319a6,
+                0x21d87609034aee2e,
+                0x83f0d8a31afd84b2,
+                0x33e2676179905a34,
+                0xcb8ea87b455906ae,
+                0xd130084b856c0107,
+                0x744cab3c97e78aec,
+                0xa3b41f53653960be,
+                0xf61e06fec608d88e,
+                0xe9857cbf8c9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x148d6e19cce27b4,
+                0xe72ea0fd44053151,
+                0x3f0c5008bffcd640,
+                0xd3db35a3374c8872,
+                0xe02cb51608413b43,
+                0xedc1ac536c6e1d83,
+                0x93a95eaa6d2e3743,
+                0x95c02c28d7876c51,
+                0x8016719aa451c77d,
+                0x5009c7fcf21cb35f,
+                0xeda38906fd350f01,
+                0x1517afbd08c71
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe549dcc58c5e4779,
+                0x558e5f504b1b8434,
+                0x2551ba6563d92966,
+                0xdf64d2ae78efac32,
+                0xaf243ea3f28518cb,
+                0x796abe7269f9361b,
+                0x9c534d0fefdea026,
+                0x6ed696c5801c9409,
+                0xd615a62424e718e1,
+                0xeb53df56f49823e6,
+                0x77f1dc7debefe553,
+                0x1567b30be0387
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35bd804fc295830f,
+                0x17f723d3c306a90b,
+                0x539b91e8b4d0ecd,
+                0x8ce7725fcde6b8e4,
+                0x819834643042a10a,
+                0xf23c9553a25771d0,
+                0x13c7584ec0e42a2f,
+                0x692e7a72a1e2297f,
+                0xcb7fa5697c6c12d7,
+                0xbffa319b60810420,
+                0xb38fdfe6c5b14f13,
+                0x36c9e67e5a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd94b277cd97c0132,
+                0xaadda9f9181d4364,
+                0xb9608fdd9c2ef7fa,
+                0x63eb1c8223d32aaa,
+                0xd88f6e629909457e,
+                0x36e99f0f71ab1367,
+                0x4a20683e7ebf8874,
+                0x78f3249618aa61b6,
+                0xdf8e6b63b53c3f19,
+                0xb4ce370b392f14c3,
+                0x4122422342c6b3c5,
+                0x15cfbdfc42ee1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x13d82c86c6b3eac9,
+                0xe3f9eb9168120b53,
+                0x7e6673e13a4d1723,
+                0x746ab76c81dcf595,
+                0x878d03880656f014,
+                0x975a655249ada8d,
+                0x5f8a09085cd6252c,
+                0x279f1d954e4ce09f,
+                0xb4f8214b5e93ada9,
+                0xb2c80df1716c39c8,
+                0x8c122ce28677793a,
+                0x6c22ee89cc5c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa07c249ba74b0b97,
+                0xb88f7116f779b31c,
+                0xe1cd4fddb9aaadc0,
+                0x102390b4c65e5657,
+                0xd6327934076736ec,
+                0x61dedc1c9afe6bb8,
+                0xd25b8e3ebd9464ec,
+                0xd43591f6e0f7758e,
+                0x97492f91ab93c94a,
+                0x2f4b114bcfaf44e1,
+                0xac04904dc3568d75,
+                0x110e9cfd00e1a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2ac955db6e28fa53,
+                0xcd3a17a070205bc,
+                0xa75cb5964aebc1cf,
+                0xd047707b3df6964f,
+                0x4b27098658e5f979,
+                0x1aa0f6afaaca371d,
+                0xe3ac7d6b1086ed45,
+                0xdee38750dfb74e6a,
+                0xe95d6c3dd9b451c4,
+                0xd19227f8a51f3b45,
+                0x5c66dae521b67407,
+                0x557f1df15707
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x990ccfb1825495d,
+                0x638b88f66db4e112,
+                0xd4cb9ad24c44a1e,
+                0x18c68bdb7869ef3f,
+                0xf5e7f6b343199290,
+                0xe3a1a613d50a15a6,
+                0xe0d63d6b2a96a118,
+                0x5a6ffa1292e9d10,
+                0xfa6679d2674a20fc,
+                0xd59819b5b1fe97e8,
+                0xf8d530c7557fe29,
+                0xad73133a4d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f190c500e498b3,
+                0x87d469e87b928c62,
+                0xeef6fec57b4603bd,
+                0x8a335a17b127d68d,
+                0x663461587fff55c2,
+                0xcf3855c5adac1b11,
+                0x6740e0e7e7be5c35,
+                0x135cc85f00e5358f,
+                0xd774b4bc67c3df28,
+                0x1c3fca0dc704e743,
+                0x71909481cb87ea52,
+                0x15f1f150ff909
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa9bdb6a72ede0a9d,
+                0xa5862f2798f42e23,
+                0x569224f78f475ae7,
+                0xade0c7a829b7b18a,
+                0x6441df1945b6d809,
+                0xda87186aa24571d1,
+                0x9c9a6e38e1af7633,
+                0x4d229defcbdad1b3,
+                0xc1de77ddd7ecc827,
+                0xedf6f22ae630594d,
+                0x4bcd31c102b6753,
+                0x14f57a8125a4e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9f4f4edd72800b3d,
+                0xb3bc10153b033fb2,
+                0xe1df1328a7b99924,
+                0x91970694be6e0332,
+                0x5286703d128bb40d,
+                0x608e9b8adc11c830,
+                0x7d9edd82d84a91d0,
+                0x6ecd70c8a6e56886,
+                0xb3456b6dbaa55031,
+                0xf0b534cc8e9e37a8,
+                0x4f822e18d59d90ba,
+                0xd469be9a180f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x34c9e0c2c322155b,
+                0xb60968ea0a13a2ef,
+                0xfd2a11663a22562a,
+                0xe481dab4485d12d8,
+                0x387aa74e1609d64c,
+                0x8f6098bea6cf5c6e,
+                0x95d123cd5b6fe1fd,
+                0x597f24202230279e,
+                0xa4f7367ececcc50d,
+                0x287cf486c0ae69fc,
+                0xfd1ac786f7e9b3cc,
+                0x9fd386b6737c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3bf4832a41e30dda,
+                0xd041902064784db9,
+                0xc2e38852a8ad45a4,
+                0x94be6013b14d0926,
+                0xb155b0ae459d1d6f,
+                0x120d6f7e2f57c7db,
+                0x1f0ffa04422dc790,
+                0x6ad1f3865a8c5fbf,
+                0x40de60cc53015601,
+                0x61c3b94458172ddf,
+                0xe88e4bfc4ae359b0,
+                0x8907792d68f2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xba8ba212ecf95b8b,
+                0xb8e9d8042d2e5b30,
+                0xbebe1f09b1a6338e,
+                0xb82db8db563d463c,
+                0xada4717d1c13458d,
+                0x844ba9e73ba1e4ce,
+                0x6c2df0d4a873e93a,
+                0x327b2fa63b2db071,
+                0x84da3cf054a15369,
+                0x11ee0c6ba361f793,
+                0xb9c3130e17b80d98,
+                0x194c2c8a6c04d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x616c44e762a5d9b,
+                0x8a52ad7240395e15,
+                0xd5ed830301b32a90,
+                0x4fb9379a79d5cb33,
+                0x7f94f5ba88f25b26,
+                0x73be0d1054ad0e43,
+                0x2ee7e6b00c8cadbc,
+                0x9634cf95dad2651b,
+                0xcc85dce973139be9,
+                0x4309f07316379109,
+                0x25810266cb97844e,
+                0xe35e6e96eef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5210504520430188,
+                0x9398d0fc5a902322,
+                0x9c3df771adf9306d,
+                0x5de18527a6c60099,
+                0xab57987bb399429b,
+                0xb8918704fc897327,
+                0x69f3aa49900cf71c,
+                0x52e22511eda07be7,
+                0x582fd974cf4431dd,
+                0x421ad48c7842fca1,
+                0xd0b26aee84df33bc,
+                0x1381b29a8cf60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cb097448f524e4a,
+                0xb6a63fcda283d627,
+                0xb15f4f350f594663,
+                0x1534b354fc6f8911,
+                0x8f37c64088cc9dac,
+                0x6cac3fd0ca752b30,
+                0x4b4d08f56402d274,
+                0xbe465147f67ddc1b,
+                0xd26a05b86e50f26f,
+                0x762fa5c531adabf6,
+                0xb2a1af71230427aa,
+                0x793100d30840
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x74a17c3b5f2fe6dc,
+                0xee11158f7700ce28,
+                0x8addb35eb0c1790a,
+                0x917ae1cb0d043759,
+                0xf75231b46376c28f,
+                0xf1755e281dd0256c,
+                0x81c07e853685a7bf,
+                0x633ded511d17c9dd,
+                0x22e04a6675a1bf67,
+                0xde97a08b6d0c41e6,
+                0x6a34ebac95c2494b,
+                0x1bb1759e0eb58
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda7d41ff8cb6a539,
+                0xf731b8b60302a9ad,
+                0xda3a6bc9d0334222,
+                0x1f339e510a7247e6,
+                0xb368ad6ad1171a6,
+                0xe8e0d82f635f8eff,
+                0x111d4d4af74f7f24,
+                0xa604bed5ad96f528,
+                0xdffd87f084bd794e,
+                0x2684b3c38c272034,
+                0xfe812c6ecdcd0c7c,
+                0xdc6bf45db9bb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x32dc9343f697ba2,
+                0x68b4f0d80d55d448,
+                0x1ceb34f8ea70a84e,
+                0xbe1f66421dca2f0c,
+                0xd8dc3604c3e46fd0,
+                0x46d0c863392e4bc0,
+                0x6a826df302847e50,
+                0x354236723fd6f051,
+                0xddb959894bcedc53,
+                0x8463c83843e83a51,
+                0x366d3922cd3a38a9,
+                0x281f1b4b4b68
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47d166dd29f10356,
+                0x661068747f8ca64f,
+                0x16f6379d3c67b22a,
+                0x496ca1746bef34e5,
+                0x451b911ed1ff2af,
+                0xa37cd9b0d8e025ca,
+                0xa67f31ea04d3a9c,
+                0x9bd0f918c79d9381,
+                0x1879f712d6f5f71b,
+                0x891b540565251f34,
+                0xa60fd05689594aca,
+                0xfa054f249b8b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6ba02635bb1d2b,
+                0xc821404ef2e6533b,
+                0x2329f401479bf60d,
+                0x1e4b833c0a8a8c04,
+                0x40f24167c53c02d8,
+                0xf9d8c0f8bb419a2,
+                0xe7bf2c7a92a9dddb,
+                0x8e7d7474af2963c5,
+                0x1be1031f6875ffad,
+                0x122ac727bfe15a10,
+                0x19fbef1701b4eed8,
+                0x1a5be62e1ef14
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x18fb278282d646e1,
+                0x59086e34016486d1,
+                0xd0c01699014acc52,
+                0x7f99e5b444a476f7,
+                0x5655267a4804d3d6,
+                0xd34332a8fdeb0e38,
+                0x6dc51d326e4f0008,
+                0x458a3ed8191bbcce,
+                0x47d858a6d76ad187,
+                0xaadfaea94618f711,
+                0x4830d6dd4b170fb6,
+                0xdce44f1a9af9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x94818ea746979a76,
+                0xc8a8f489a16e0ab4,
+                0xb07f440a151d34d8,
+                0xb96d91be79f020af,
+                0x29e858a267913058,
+                0xd2c936b6069f9989,
+                0xad4961aa6d30f047,
+                0x636869452355c2b,
+                0x7a38fd3720a6b260,
+                0xa15b8887dc3014b4,
+                0x803ad432abfcc253,
+                0x9babf9e77900
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xae8d92e4b3f2f4d1,
+                0xe80b378b7a961be1,
+                0x90f35b3dde89c6ba,
+                0x665bc523ed484352,
+                0xe8cee241115a0814,
+                0xdffaebbabb5a6e64,
+                0x4173ece6d2317f3,
+                0x25f4f38e3b52bc94,
+                0x953f854fa1712fd7,
+                0x201e705c9e7cc22c,
+                0x8a03edfb62478c45,
+                0x1417b30226fde
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9941d641acac223e,
+                0x678ffea4a4333573,
+                0x19b7ac0aec2aa2aa,
+                0xbcfd0f923d214ee0,
+                0x9d6901294eaad9b,
+                0x8c53f4cf0f83633e,
+                0x864511da600dfdab,
+                0xd0ae0bb9f62e406e,
+                0x3c200d8563ee9e4f,
+                0x376f6fb9da8d1d89,
+                0xaca28560f8fb8d77,
+                0x5d0df763598c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2d8f874e800d5031,
+                0xce31e306ccb2cf0c,
+                0x52a5518fbfe49705,
+                0xf010a8de7bff6e3e,
+                0xfd83090e0e87db40,
+                0xe72fb5a756375216,
+                0x826bdc8cfc2d1e87,
+                0x67dcd8c77cf2b6db,
+                0x5297863346e599b6,
+                0xe057a21342b8bfc8,
+                0xbdbab30ee1ea9ce8,
+                0x3722a2ece66b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e2571c210975893,
+                0x52eb13100a1230b9,
+                0x8d882f1dddaec3dc,
+                0x164695a473c1d65b,
+                0x7a6359ab9b242aba,
+                0x2c043d5e2eff5e86,
+                0xb6b19d4c5a71a1ac,
+                0xdb59a8147bd85b35,
+                0x5d062570b84eafef,
+                0xff7fe15074b39313,
+                0xdcb10485322c2721,
+                0x1bca059c11fd7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa21aed1b9bab9406,
+                0xeab9d139dd94c9e1,
+                0x8fb7d25735aacce1,
+                0x190d369d3560daa6,
+                0x29130d0875dcddd3,
+                0x1ee6a43687761de2,
+                0x72d450164e791ee4,
+                0xed9f29ac92c70adf,
+                0x71a973a5c51b2653,
+                0x2d56d7af4fa8e441,
+                0xeee71b9b4207aa20,
+                0x124e4a5accef5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6bfc7b72b3777343,
+                0xaa44056a1ab3bf16,
+                0x8aec5187c5937a21,
+                0x59d23b681434dd3a,
+                0x5d0175a744749f12,
+                0xed08db97c81e301a,
+                0x84578078362e58b0,
+                0xa51fef87d40349f,
+                0x1cb57a6fc143e505,
+                0x2e51e684225cd625,
+                0x69c495c12a95ddff,
+                0x18e40dc02516b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5344416a32b627bf,
+                0x4ef65c945140d371,
+                0xb412f10db05c5aa0,
+                0xa04f8bfd6aa8a703,
+                0xee23e47b95e8074c,
+                0xdf22a24a5ded0eb0,
+                0xc10407c6e1e524f7,
+                0xc859e7c92d7515f4,
+                0xfe4bde0f29b3c20c,
+                0xcd45b37ffa442963,
+                0x24539287c233ad9e,
+                0xd47697d58de
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe022f20cba1ea086,
+                0xa599d398d305d873,
+                0xe27cd4cc48052d8f,
+                0xba5e72be537f02a8,
+                0xf9f78f61abb899aa,
+                0x9beb43ffbd3f826d,
+                0xf07a58dc55c2a22c,
+                0xb53e1bbac754864,
+                0x14acdc97d28f9eb8,
+                0x6c4922c5a5690e84,
+                0xb4a4eba54bf59a32,
+                0x384f833c133
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a269b40a54bc0f5,
+                0x24934ee882a199bc,
+                0x2689b907266f2ae3,
+                0x91329f42df0f605a,
+                0xc053c7b11b8342e,
+                0xf78ee25425c017ce,
+                0x3d0c969c76d26de6,
+                0xfa45dc6304fc54a0,
+                0x77a33269be9ed0f2,
+                0x53902b08719d1220,
+                0x3cb3280fe47abe90,
+                0x12ff99a96d3a4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x73f82cd963853bb9,
+                0xc837478c6c614b92,
+                0x128c6cf9d224f583,
+                0x93a735d535390907,
+                0x94aee9e0b0383f98,
+                0x6ffd8f874ad44681,
+                0x589781dafaebaf5d,
+                0xb02e64d61f452cdc,
+                0x1c56568b99dd1456,
+                0x749cd8748068e245,
+                0x76334dcb08586d8e,
+                0xf0852763d888
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6468feedc3f861f7,
+                0xe660745fe28a5b3a,
+                0x41fde3a2b21c3ec3,
+                0x4663ba43df00fbdf,
+                0x86b0940d8866d033,
+                0xebc20518afd71ea8,
+                0x423e9fc5c4705b4f,
+                0xb8e80b8df571742a,
+                0x6c2a3d9b43544cd8,
+                0x628cf36f9e3b47ef,
+                0xdb09c56af641d6ae,
+                0x13882d5f5c5f2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf318f7705ef7fe09,
+                0x8ee7ebd0c5c46a66,
+                0x2eedaeb2b8c21bc6,
+                0x7c16555faf09bce0,
+                0x19f7d32d0a5fd560,
+                0xf10b83aa9a4c373f,
+                0xad96b47f89e3ab29,
+                0x6e5681540f0a6d70,
+                0xf89456c7b51f829e,
+                0x73844850ca561a4e,
+                0x1f48644bfd2867eb,
+                0x614ca76a5820
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb7e1f1b31af0c840,
+                0x8c80f52bac0a39d5,
+                0x787522690d228b47,
+                0xffb6706ca2f90bc2,
+                0x754854280e6b63ea,
+                0x3e9ddaac5e9328d0,
+                0xaccb3eada417f1b6,
+                0xb51b36063518cb35,
+                0x1102439c0bb520e5,
+                0xe2470b0679c1bafc,
+                0x85f97c0807ba46a2,
+                0x13004f1092893
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6f7c2f45fb73bea2,
+                0xf8ce107e55c35eef,
+                0xfa428e651552a111,
+                0x2882c371a4ad9f5a,
+                0xe6a026875a906751,
+                0xe17bc7f3c2f2e2b4,
+                0xc51e14817bc6ce0c,
+                0x1a6ee18e11be81fb,
+                0x6181fcbbf6c78544,
+                0xaaae6a348bfcd7a1,
+                0xc404a1f2f05add8c,
+                0x810a48b41ca0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x250bc05b94c273c8,
+                0xc2db12b5bbcc3f0c,
+                0x7a8d0d8ab571267c,
+                0x2ffe5f0c95e4e2ae,
+                0x8e31a500803308a,
+                0x4ace969f5235d2ad,
+                0x6aa07ace8ff604aa,
+                0xdf9c08a49d965ea2,
+                0xa91c983194659528,
+                0xf4951ecb4ff8a7d1,
+                0x1166c166178d22d4,
+                0x13c1f404e1d92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8828adcc63f5b83e,
+                0x9bee0802dee75127,
+                0x7b50ab27067c087d,
+                0x2aa7fd66ddb45d72,
+                0x8e0a5fe1b71ad200,
+                0xab5e55595752d665,
+                0x495d377af7228f63,
+                0x68ed4a5493bace9d,
+                0xd39ffd4347a22fdc,
+                0x8541b97b168e844f,
+                0x17f3cf0c798fe3b0,
+                0x355a18fdb612
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x48e5d0a4a2bd9163,
+                0xf4e48a52cfcf3b26,
+                0xe1c25020196e0c26,
+                0xe95cb2b6eeb70dd0,
+                0x543ac5f35f8d7920,
+                0xf41b152e140945e4,
+                0x715839fa79b21ceb,
+                0x4f9921fc9db410b9,
+                0x2b29df9a8eaac165,
+                0xf04886c3cda12659,
+                0x2eeb8eab47278405,
+                0x10f9a65f59c7b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x172c561badb80799,
+                0x4a4f65b84b5d00fc,
+                0xc3db98868bbfd516,
+                0x4cdba1d110c34525,
+                0xaeffc8c3bed817be,
+                0xa5c3ae8ac13e75a,
+                0x577e918bad824f15,
+                0xea24553fd6082588,
+                0x926b09970d5e0ba4,
+                0xabea005fc535d72e,
+                0xe4b45c15cc00c3c6,
+                0xe1646a5552f1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1df359ae999dced0,
+                0x48242f29502d337c,
+                0xea4cfe766abc04ed,
+                0x27e4e1de97e19c65,
+                0xf5953e02c06818ee,
+                0xc264b44c5ff7fd42,
+                0xabe08120b1c2611e,
+                0x975a6989db38d026,
+                0xdd36203d38a1bee6,
+                0xa1748c9b9a9419b2,
+                0xf3bc61642c5dc549,
+                0x724b41c4dc4e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10b3b3bc4589be3f,
+                0xe73aa034ad518bc4,
+                0x8dc48050aad97a84,
+                0xc8959ac30a040f69,
+                0x72ea53d9c13c3a8a,
+                0x84ac1c59ed4c5732,
+                0x63300d7ce9fd78d0,
+                0x5739b74c05e42a4c,
+                0x1d017cf36ba4b7cf,
+                0x923b18dbca658e00,
+                0xf9d9d869b5c330ad,
+                0x1a1f858fccb93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc2041b7d1382ffc4,
+                0xf661f32bb8b0cdfe,
+                0x7315de545de71938,
+                0xd3bfaa652d1a380,
+                0xa5ee2fd2f92cb59d,
+                0x3225b5a864819309,
+                0x590ef546253b3636,
+                0x1e5333eec8e160ab,
+                0xbb6ab616ab6481d8,
+                0x999bbb319ee14df6,
+                0x549249b655559c12,
+                0x18a1a000be8eb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfde1aa99de99dd22,
+                0xd09a4e13234b7ab3,
+                0x1380a51173c3780d,
+                0x8a9d4de349d06cfa,
+                0xbbe9528640f29fb5,
+                0x8b677b2fa3bbec6d,
+                0x8eb7021b0f0fcbd7,
+                0x6f69547405952059,
+                0xb6544cc0339fedf,
+                0x61949a6406ae0762,
+                0xcbb4441a0ec6a3af,
+                0x676558c4bcf5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4a93490e63408e8a,
+                0x9c7429c192039bf0,
+                0x2876a91667e8c5f4,
+                0xe4f29124dd50cc1a,
+                0xe57398650e95d93e,
+                0x2f4a3bf5d4d5816,
+                0xd9d75efa6808da23,
+                0xb61ec8909201de2a,
+                0x5546910fb7c9241d,
+                0x8d6e68474aa5fc55,
+                0x58d2a57a892434d3,
+                0xcbb2cbb47c7c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x18e674df031f45bd,
+                0x230c0e7e5be08eee,
+                0x8b41864fd3d4cf6c,
+                0x2edfb031e32d824b,
+                0x87ce086cfcdf5374,
+                0xf09ed59f4250ad8f,
+                0xa15709c4f4043906,
+                0x295dff54a398b6be,
+                0x53f43dfb01be64e8,
+                0x5be93e9c67dc3e9,
+                0x5bee8c40053ac5b,
+                0x47066f39f460
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef8c3cf61b6148e1,
+                0x3eb92c7e93be76dd,
+                0xc74b0dbbbe895943,
+                0x5e64b4029c14eade,
+                0x83bb28e402240f4b,
+                0xfd0e440e5247848f,
+                0x984b5915e7267cc3,
+                0xc43397e3f5fbd23c,
+                0xd275957754459eb1,
+                0x8acf65ddf6f36b8c,
+                0x5a3cc2b5b8afbe5b,
+                0xafb70a9e2629
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x10047de1fbacffd2,
+                0xc7e3436539f3228d,
+                0xc38e126a19c46298,
+                0x97a06803ea127e1d,
+                0x854f0c6f2a6e64de,
+                0x476231ce6853319d,
+                0x4fc84e9584e9ac09,
+                0x28587953d4678ba5,
+                0x610387b3b0bbd683,
+                0xb0c0a8385ccc1ffc,
+                0x48627c7f86a6f91c,
+                0x52e8c3736575
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb0eee2f4865091b9,
+                0x367320bdc465b1a5,
+                0x5b69feee35b9245a,
+                0xf6d0a9c4ecfdd3c1,
+                0xf29f895862a3ed1b,
+                0xbc971104670643f9,
+                0x47a89b7fafa3dfca,
+                0x85b6f28182b5306a,
+                0x944a550da499137c,
+                0xb938c96593951dee,
+                0x59c11cad22516c6,
+                0x63007556af8c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x27838d4567974342,
+                0xfb9bb2e178e68623,
+                0x91af19b35516799d,
+                0xfd506d8221674dc6,
+                0xe9f9abd489b2f25e,
+                0x1c36f9fbc3bb3b2f,
+                0x1f615394a97bbb4a,
+                0xa652ea9d55b89350,
+                0x8537842abf64488a,
+                0x205d0fa399cb7230,
+                0x35f11901bdd9ef6e,
+                0xb115bcc05529
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x31b01fdf943a30dd,
+                0x7e06ea4935bc600,
+                0x392d16dd972f7b1b,
+                0xbed11ecce985ae0b,
+                0xeb51de517a7a9100,
+                0x572633fba68ecdf1,
+                0xf7ec232b4ec40cb1,
+                0x407b320ae0cd332f,
+                0x4b4a1f29c55b6683,
+                0x10c4bbe8df15eb0a,
+                0x49d94c9cb39e5b75,
+                0x175ebb8479c3c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x65bd16bdbfc49c01,
+                0x7978012ba32cafb9,
+                0x9990ede1fc243744,
+                0x142bc4b1802565c2,
+                0xcc57f3f51fbbb6b,
+                0x6ff4582bbeb336e3,
+                0xd24e3ee8557d6367,
+                0x87e189ef78de09d0,
+                0x1e155dfcbf26f210,
+                0x158381a8968452dd,
+                0xba86dcac5867894f,
+                0x187005d93a8cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9370547047996f21,
+                0xd060df17530057aa,
+                0x5ef5fdc2c1b966f5,
+                0x9a3b784eba2ba130,
+                0xf6605bb182a97aef,
+                0x1b2aac0eb7e21237,
+                0xb009bd7679c7192f,
+                0x4258732400619fd4,
+                0xb1aa75fbec90177f,
+                0x8f57a32c584ad3ad,
+                0xea49be8de28738fc,
+                0x12aec121355f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc6377b5db8d59e4,
+                0x8e91986eb8e1f5fa,
+                0xc7d71818a559835e,
+                0x88f8e816eae82fbb,
+                0x4ea8235a8177ba24,
+                0xfa2e093534d4bd3a,
+                0x2ec08b787930e6cf,
+                0xdfac976b064684d4,
+                0x87e451f0dc88b01b,
+                0xfea0899e8d6eaa1,
+                0x12718071d8516264,
+                0xfa06834215d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5d9908fbfd5dc6cc,
+                0xb059ce28745dfefa,
+                0x48a86fdc4012c20,
+                0x9bbd683b7d1d09a6,
+                0x2410dc257d9e1075,
+                0xf766ed1dfb7d7dd,
+                0x285c6d9cc73a8bff,
+                0x547bacc61f662e34,
+                0xbf01a383358e65fe,
+                0xf4d158ff228a6e3c,
+                0x3b0d368b54142363,
+                0x810f1056fe5a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a74b27e1b98103c,
+                0xc25834c7b0d810ed,
+                0x64536c23e91d2f7d,
+                0x4b56ced201681721,
+                0xb6ad85ab0a1d09ea,
+                0xd9e1275356988eef,
+                0x330e64b8ddf34242,
+                0xe312cdb9e474f281,
+                0x8cd0ca37f6ba2a7f,
+                0x14963522fabf314b,
+                0x26a56ce5dbf7132f,
+                0x233d14e83462
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ed1cd338b799ff1,
+                0xa429fc2fbc1e365a,
+                0x995a118316f736f2,
+                0xcdf16c1ee2e27f,
+                0x59a368a332ee64f9,
+                0xa2d5387d579e45c4,
+                0x7f994d90c23c857f,
+                0xf8a32ed0b5be0cc5,
+                0x4826211931c37739,
+                0x42bdefdf97a9cfba,
+                0xa4d1af78ad0dcfd4,
+                0x2983ecc1585a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4fa555382937c0c8,
+                0x4478634f2ca7a902,
+                0x7047df149b515f49,
+                0x2e3d455bd7762539,
+                0xce5939ffcdbff7bd,
+                0x750d04a54f4ca62f,
+                0x70878e4b5d1c4431,
+                0x5fe5cf11887c9aff,
+                0xe774c94a6c7e7559,
+                0xe806b726bae6cd54,
+                0x685c20bcd9bf9ce0,
+                0x4b7c6b283b21
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x367282e819b3984a,
+                0x67a9859239995245,
+                0xb1f723ced7d347d2,
+                0xd260729adc6c206,
+                0x709646ef15631979,
+                0x9c8380fb2b2e1986,
+                0x4bc20ee6a606fbc5,
+                0xa4dd8daf034a441c,
+                0xd73172750f79541,
+                0x9a14258512393706,
+                0xe2c28cade3eb6f80,
+                0x1c0a57e1aaa95
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x68f6e74c58c20352,
+                0xc1fb929486a26e4b,
+                0xa1ae63315e1c6bf9,
+                0x7479e379385392be,
+                0x6ae2a098725f3f67,
+                0xf8e8f6b45484d9cd,
+                0xf996a46f8a8180e6,
+                0xb65be833a3d918ec,
+                0x2c506f8c15783ab,
+                0x516cd58a54166f44,
+                0xe13c39cc38b2e3ee,
+                0x17ca78eff550
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a9f3ade225f6c8d,
+                0x52343e4a83d9a91,
+                0x7aa01fbab69c604,
+                0x868ef77ad2dc172b,
+                0x7ab486d9435bcede,
+                0x6ba8c28e3d9c53f8,
+                0x7a8f4bdfd7822fb1,
+                0xbac4fb2db6e259b1,
+                0xfbd73b87e5ab4866,
+                0x679298681103f3fb,
+                0x4e3823d7f1435cf,
+                0x9ea19be58ac4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb568863dd2b3eee,
+                0x8de48af239e7d68b,
+                0x5ec9f4d8e51f78dc,
+                0x56995e95dc084587,
+                0x1dc68fe39966cb56,
+                0xd5f8b3eb7cbe46a,
+                0x9ac0a2cfa677cf09,
+                0xd585ddadfdaa759b,
+                0x6438c82d47b8cf12,
+                0x590a21b3a019d174,
+                0x55b7def1bed735de,
+                0x109462c713e47
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x120389062b53b94b,
+                0xa45f04e6e3839581,
+                0xd31ca40f1aba7eae,
+                0xfd862296f0eb49d4,
+                0xaadef4de30f97356,
+                0x6ab4cc987ba8ec9,
+                0x939c179b15c1af6a,
+                0x6518e83dcb000419,
+                0xd2eaa82f6d49f81e,
+                0x20876dbc858fe8d1,
+                0x13e305331bddc34,
+                0x19e07302cf50f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbce4024b1c4ce4b9,
+                0x975dae3f4ea53567,
+                0xb6ae3720a031eb75,
+                0x3ac9b471c5fe4c4b,
+                0x4fc826f88d1e8234,
+                0x1a59fb5db0d991bc,
+                0x3e394a58e36a88f1,
+                0x821ab823ed7f07df,
+                0xd95139578f358d8b,
+                0xc2449567f600f2fc,
+                0x729b797f13538cc9,
+                0x18454d85934e4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc807c028f00e5170,
+                0xc0b2b8cff16efb17,
+                0x484e02d4f429b9c8,
+                0x4dfaa9321723ce53,
+                0x1d4c180761507df7,
+                0x832e1ae4ff83ea76,
+                0x6d452cca24bdb011,
+                0x8539a3a8b8a62aac,
+                0xf39e43f78695e628,
+                0x2a48224cddb66991,
+                0xdc01aa8b9b9a5012,
+                0x1d0bb37ba803
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x65a74bc060412bf5,
+                0x6673b9d120a44ba7,
+                0x978d8294090f21f4,
+                0x117d4c50bbe12bcd,
+                0xb96c4b7ca2ce897,
+                0xcd3c51129347788d,
+                0x789f9deb1e80f338,
+                0xa82e39afc3bf614b,
+                0xb3d831240a772815,
+                0x84a5090ed83e6349,
+                0xd659e9ca88fe1f2f,
+                0x1ae114a652697
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x27f3e1a1a340f791,
+                0xfbe63377b685bddb,
+                0xb887514122e3372f,
+                0xb764fe448cfe406a,
+                0x4c9ce01594fbe22f,
+                0xb21b7b384d373d1d,
+                0xdfdb4f7bef6ddda0,
+                0x263ae4f7df9034f9,
+                0x73cf9ed7f6fe9ff7,
+                0xfd8198647a151e17,
+                0x722ca0e09c026680,
+                0x1a0b5cc15351c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdd7598f3843bcbc8,
+                0x860effaa6a7c41d,
+                0xdeae53d845ea92d4,
+                0x7f2a67c94052cc79,
+                0xc4d6f11c3e38c90a,
+                0xea1fd54a12652374,
+                0x2c3203d99c55748c,
+                0x199138e54f924fe7,
+                0x28f8654416a5bebe,
+                0x4e05d4b5ec3d77f2,
+                0xae0fcc8174c6fdc3,
+                0x1885f35824848
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x916937f90eee3392,
+                0x69a4141cbbb761eb,
+                0x4d42ec2e2917ac64,
+                0xa6733c30a71d111,
+                0x6d60e64ea14834df,
+                0x5c2f1019a6cc3b43,
+                0xda67cc7ac19ce81c,
+                0x4bab9e424928b543,
+                0xaf2a4bbd7431cb3e,
+                0xa553ac52d9777ad6,
+                0xc83d1d6f3964d3cd,
+                0x1335f33832adb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xca904e745e3024bc,
+                0x301469715cfba908,
+                0xfe5e9a50d9f7f9b8,
+                0xf3c319b85b66d7d6,
+                0x3112ffe527a80eb9,
+                0xb2619aeed581b709,
+                0x6290b6d5b9810a69,
+                0xd77f0d7ba594e344,
+                0xa8c2f0e73bd3735f,
+                0x3d73587511ec9c48,
+                0x1783b2bfb5caaa0c,
+                0xa019f4f7a40e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1ac100f6408c26fc,
+                0x47058289a5048d6c,
+                0x5a5dc39d50ce9e69,
+                0x85b4a1def3cca96a,
+                0x966ec93435b80734,
+                0xf71dd6223545c3e5,
+                0xdf81749844eac4e0,
+                0xbe5f6b201abea6a0,
+                0x3b04f8ad11825043,
+                0x1ab88aa330f71981,
+                0xec0f3d7874da464b,
+                0xf54e90f4aba2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x12e449f08eebf93d,
+                0x4017d1d52c85634e,
+                0x5feafadacf1fb2,
+                0x49f0571af2e03cc5,
+                0xff29c201429db4bb,
+                0xfb9316741053d35e,
+                0xe073a33d1d511f99,
+                0x631701275a7f070d,
+                0xf586ae5b95f28f1c,
+                0x673ed970e6f3eced,
+                0xb3896d4095c39b58,
+                0x10f3e1e271f56
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7396a0e015f19996,
+                0x12ed7bf49b8492e2,
+                0xa2b37792810251a4,
+                0x75c800c75765bb8d,
+                0x1cd8ecf434a53fee,
+                0x196f909b7a2876f4,
+                0x255bf7546791508f,
+                0x774a3d4c3ad890d0,
+                0xfed11a96a1e10765,
+                0x5d60b1f2ad4dedea,
+                0xbd8732136224ff6a,
+                0xddac97849d3f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf75c44b144fcfaa9,
+                0xebff80371c1f397a,
+                0x22f82c4416032269,
+                0x39c5c07ddfad3dec,
+                0x421b900adaf1606b,
+                0x6c7919c5e9ce2f4d,
+                0x5d87b242790ac28,
+                0xb8dfa18421f03849,
+                0x945e945933fe60b3,
+                0x374735cee24efa12,
+                0x570e504432c793b8,
+                0x12c005e35953c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x40cd964118d378b,
+                0x36c16bfaa8ba1bc4,
+                0x434e7ead39926cdd,
+                0xb1b9d4f092998c38,
+                0xb5f839cfb258682a,
+                0x78bf8a414c4488c5,
+                0x54afdf3870a07e4,
+                0x232d1cedb66fee3c,
+                0xd36932e1b922be6c,
+                0xd4cf45dd334d15da,
+                0xb5e3beacc9309c25,
+                0x12ec620893ae0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2a6096dc04177a9,
+                0x8966092c381ec4d6,
+                0x918aab80e301be0d,
+                0x8cc5b6e75634d7ef,
+                0x73268461dfa316fc,
+                0xdecef9dc7bb2a8c,
+                0x2a86f21878cad20c,
+                0x3c21d547bc82c734,
+                0x5f80cb99bdf3b7ef,
+                0xf0719e4287a094a2,
+                0x40295e5603e991ee,
+                0x72b3d368c08e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcd4c15cd024f231,
+                0x4e9fee17a5a6e60e,
+                0xba7621937f727ea0,
+                0x4f93640a0dc0a8ad,
+                0x6e687e69d40948dd,
+                0xb34f230fdf14f9f4,
+                0x5dd4310cc297fdfe,
+                0xfb1b57769a3453d6,
+                0xe97bb96d9a9f9a3a,
+                0x53a047a74ebad813,
+                0x1df5fa95699b05bc,
+                0x6d406262ec5c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf8b8825c4467ce88,
+                0xb70a6d7db223e4b,
+                0xdbf302f4ad2daef1,
+                0x2bdc3ef082afbc8e,
+                0xca4a03ef9d260b82,
+                0xf4ffbf1416734395,
+                0x57405ae8cc67e8ba,
+                0x4f10c697daaee736,
+                0xfa1f9c86c5f9c059,
+                0x94920491739dca34,
+                0x1c640f447502bc1a,
+                0x19932814dd9b1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeb1a33a96656495a,
+                0xe1879dd2b65aac32,
+                0xe623020c74061d66,
+                0xf087af5abdcd26b6,
+                0x96ce3340626f5795,
+                0xd4f9ff37fbaf7b91,
+                0x41c7cdaa963e7b23,
+                0xb0b2b6378a8795ea,
+                0xedc7f52be8c39cb7,
+                0x88f6569f5e17d049,
+                0xecf7c968d1cf3b04,
+                0x151611fc19ec8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4fe19452f1458897,
+                0x6ab5caebc8323bed,
+                0x63f04ae32aeca444,
+                0x8f252384dca5e12b,
+                0x5d77fce06de61540,
+                0x86a2c8602a52a10a,
+                0x210c71be8432fd08,
+                0xc648f75546066057,
+                0xcd5b9d3bd93089b1,
+                0xcf90985382c1091e,
+                0x1c7fac9aa19771dd,
+                0x112539806de0b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdbbff3609298b349,
+                0xc239c841b4b4eea9,
+                0x13067118c230166c,
+                0x9dc48f9d504ae12,
+                0xd2b4c50f321563b5,
+                0x4c092ed162384214,
+                0xdd1db654e82a9db,
+                0xdf8bd9f3552fcfc7,
+                0x5334ceb5160e5a0e,
+                0x573197f271f11ef9,
+                0x137f071b809fb3b6,
+                0x7f489f345e27
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x85c08c4aec090adb,
+                0x6463f914089b24bf,
+                0xe2b3275b50749a8b,
+                0x6dae9db3176a853c,
+                0x541b756de5268f53,
+                0xcdf894ebe413f14a,
+                0x79aa59a3d38e325c,
+                0x12deb3106e0b11f1,
+                0x8c02d3671d0b741f,
+                0xe17371e44209572a,
+                0xe75893149cdb0b88,
+                0x6b99862c4573
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7cf6848d08203170,
+                0x795465731fb3c2dd,
+                0x5719e97f37881c00,
+                0x1bd77cb333a497ca,
+                0x2bade7cd0101d631,
+                0x99b0a96699abdc97,
+                0xb2c3366b8d1957c8,
+                0xb68788bb946f758d,
+                0x18a483715de1700e,
+                0xdf7d587da2abce53,
+                0x9ec15f46854eb544,
+                0x2aad997dfd47
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc7402c4e06d1a440,
+                0x66bbb590376c7145,
+                0xaf395840d20f4fde,
+                0x3ce94f0f91f977e3,
+                0x5b2fef9b40c50cbe,
+                0xd0c07e43751cdd50,
+                0x68da1bcf8376a3db,
+                0x543e8c0ef98518b1,
+                0xcf42a98f176af083,
+                0x54d1ffbb4890a8dc,
+                0x606c4e578c1fba39,
+                0x87e30a1d6ee8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb36899f82c7be897,
+                0xb462ad50e65dc235,
+                0x56f2d7452a4e18a1,
+                0xfb07a2599d7a1751,
+                0xc74b688ec0a3e7f8,
+                0x6d7f3f7f63f4c06f,
+                0xc9993bf44eece301,
+                0x6d97788e9bbde731,
+                0xf28125e507b9db7e,
+                0x74bfce6381f44c73,
+                0xdf680a649f7d3ae7,
+                0x129743fd71602
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79ab2a91618bfbd1,
+                0xfea84e2d1769a93e,
+                0xed37bda889fb372e,
+                0xd1fcfd8e5f69566,
+                0x3d16d9216f666f6b,
+                0x2ede644ba5cf3743,
+                0xed9e4145debc1e91,
+                0xa91346a37e5ecc4c,
+                0x526ed0563b09b8cb,
+                0xb593295c2bcfe399,
+                0xa550989fe18656b6,
+                0x8db7974ed034
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd3689b5223b81704,
+                0x690cc3ffc777d3f,
+                0xd32ba07e7c4d2b13,
+                0x1251b1d619eac7bf,
+                0xcf52420c7df4372f,
+                0x95a6aad00ad9d89c,
+                0x91b6d57c234dd39,
+                0x986cd5282f79c0b2,
+                0x3d7424d9e25c1e0a,
+                0x14224f2c5a08fd05,
+                0xe3ea65364f87a49b,
+                0x8ff5715d150
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb61a6e435cdb657e,
+                0x63d4a8b86ba5dbc9,
+                0x39cf647378885b8d,
+                0x7b386579af00cb79,
+                0xcbde4eb1641d144,
+                0x98fae15fb220068,
+                0xed65bd80602296c4,
+                0x16e33c77dba52692,
+                0x65a6af7dbabaf94b,
+                0xcdb06be7bdb95632,
+                0x5a1bfb47e077864f,
+                0x19529e9d5eeb2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbaa7ae220e8cbf7c,
+                0xa6727a316793472b,
+                0x18c24b2d3640049b,
+                0xe965b2664b10e294,
+                0xfa0df1fc4ff38fec,
+                0xc35292cfc1b44a7e,
+                0xa7221c5e38a9fed8,
+                0x5425bb128cc32226,
+                0x83baa97d8dd01fb6,
+                0x8fa825a0bb69e534,
+                0x3e2d905dfcc8a9f9,
+                0xa26c711cb1f2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3bb9aa78f268d4b,
+                0x172df81d647a1b69,
+                0xbf5e4fafc0df8b2c,
+                0xca49b8cbb166b218,
+                0xed568842c8144cab,
+                0x4f13b62eb8ff8e7c,
+                0x304eb4de45b2f40a,
+                0xb084e8921065610,
+                0xedd9a857a5fb6aca,
+                0x8f75cbb068bb9128,
+                0xcff71ee3f0f786d1,
+                0x4ed5187230cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43a234bd335528e9,
+                0xccdce745782dcf8,
+                0x80969beba1d9f876,
+                0x4388f3f72749cba5,
+                0xbdacef94b6717e1f,
+                0xe4a8cabd2509668f,
+                0xec05011523af6465,
+                0xd2269c108e84fa,
+                0x711a70b2b3aadfb0,
+                0xf2df57e6e748c737,
+                0x29bfef96f84a62ca,
+                0xce59f2aca70e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x763130e919aaf197,
+                0xc90ac69050944d5b,
+                0xc3946b4209f7d95a,
+                0x2a698e46c0eaf3b5,
+                0xefb12bdab91fd08b,
+                0x13805e9fb13baade,
+                0xa39527b676dafb20,
+                0x9a36e65205d1bae8,
+                0x29466d7449796c22,
+                0xb84832b29b4c4168,
+                0x994b4c4b050cf281,
+                0xdd43b0b742f1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x68063af1ed1f4f,
+                0x87aa8e0075c72e8b,
+                0xf7ea2cd0eb77517b,
+                0xeb956870379400e4,
+                0x75f3e68cad3ad3c2,
+                0x9b15ed2197d81fe7,
+                0xe3dd39fd3be92d61,
+                0xc3b88275067f7f4a,
+                0x5057c39163208e8f,
+                0x57d4a705c5cc5091,
+                0x8f92d472963cce6d,
+                0xd129f991f56d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x84f911baf8826994,
+                0x8d1767131f201e06,
+                0xc47ee4095746c53,
+                0x21a3095bd1d09c12,
+                0x3257dbc22f521974,
+                0xe08b999e1b7ee63f,
+                0xead3c9ce54b1a62a,
+                0xdacef1af1743fda6,
+                0xcb858ab505ed8621,
+                0x32cb8b28e0ec1119,
+                0x34711b1e3cc4220f,
+                0x16d086a626538
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x11556afe74e8073a,
+                0xc3924499d574f977,
+                0x9d9b706d7f1fa7a4,
+                0xb33d894479f3f78b,
+                0xfc3e76599c30b956,
+                0x58486b00e90d0df3,
+                0xad5bccb8acde5b02,
+                0xd21478b29a1ee6da,
+                0xd71053ecf4bfbca6,
+                0x2577b3b4d778a34,
+                0x1e97e806855d4659,
+                0xbf572f9bb587
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b3daff4baf21abd,
+                0xf08e0f99711b639d,
+                0xad4a46aa75aa17ef,
+                0x71a5625c57e6184b,
+                0x8cfc61516a9c44b9,
+                0xb8c377f1eda2f269,
+                0xbcdc895ee9e0fce2,
+                0xf8972cf4e363377f,
+                0x6fce2fd4c2b17c6d,
+                0xa8c1f87a3e856702,
+                0xc3c4744a363f600f,
+                0x305e34478bad
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b33da1c902aee3d,
+                0x63dd85cc0ed09b62,
+                0xea11b96cec901945,
+                0xa9db21fb7961c6de,
+                0xad04187c6e4c78ba,
+                0x3448894438dee8f0,
+                0x80403e367055b8e3,
+                0xf7d6f33a4834d848,
+                0x2d58a9280ebaa072,
+                0xec693f0df5fd5115,
+                0x847ea17476876a6e,
+                0x9dda7c06135f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe1d04b0108e5f7c3,
+                0x43026d37fa11cf62,
+                0x769feaa1d427f147,
+                0xb1038651ce319e46,
+                0xaabc1b32c58e8243,
+                0x6d2c1e10fe16f876,
+                0x7413f5f8e7ea1ce4,
+                0x46615248bb690ae6,
+                0x107427e87eb9e603,
+                0x9e41373379ed238e,
+                0xa1b741e20c956573,
+                0x46709655adbe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x64f6ec724a7c5811,
+                0xce2092eef3635028,
+                0xbe09f4c9699c75e3,
+                0x6cae15979a9b2786,
+                0x2fe5c26efcf68747,
+                0x6ba434e4359f5349,
+                0xfa249c9de5131b82,
+                0x6d69ae1ecd14de66,
+                0x385676dffa4f96f9,
+                0x8511d66772006d50,
+                0xaf179fdeb2e6bc2b,
+                0x11fd28d406fd9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x69b83f5919c45dc,
+                0x3e950efe72bcdeb3,
+                0xb212b0f6c14aa2c4,
+                0x64276e9f7feb222c,
+                0x516b82e3e3d08c9,
+                0x1f00b7a67d0763d7,
+                0x7053d0809a70bc82,
+                0x6f845c033d600b40,
+                0xcd66e769b0d6183e,
+                0xb4a02f49a32b1e72,
+                0x4ae5f4e89279f104,
+                0x156a27bc788e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf4530f6b7b11e847,
+                0x680b9fcadb81090d,
+                0xc1a26bc046fbeb12,
+                0xb1af28337e8cb40d,
+                0x23cbad5758d1b490,
+                0x9d01acf1815f929e,
+                0xc6a56c3e207e4b18,
+                0xbbe51e1e70d01035,
+                0xe7b85ac98ca6f85c,
+                0x9f31931cb21128b0,
+                0x554da962173410c6,
+                0x17b43ae19feee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6bf12b9951eae06,
+                0x7846eb164fdb9ac0,
+                0xe1b724d7626299d5,
+                0x4191b444cb0da33a,
+                0x5258f8bd95e30daa,
+                0xb11c44a571dc11c8,
+                0x5b57fbe616bcb115,
+                0xd6f2a4cd6e0e7da,
+                0x7a1fdf7a25d31187,
+                0x51bdaad7950632f1,
+                0x6d035b2dc7d12e88,
+                0x4cd11bad921e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x43c661a7f7e251f4,
+                0xa1503239837983c6,
+                0xfe233299528e1656,
+                0x91251f609cfaed3d,
+                0xfd13f1e7c380a713,
+                0xe25fb944619a6270,
+                0x4b64ca272e7a111,
+                0xe0fea9a07ad40a19,
+                0xc4a408967f0c9354,
+                0x5beb1ebda71df6e7,
+                0x99ee7fa8dca49b10,
+                0x1a8c0d68350cd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x457818e3617aedd5,
+                0xcc933a91cf532399,
+                0xfe2946dfef6fdf58,
+                0x792b63b5c7e74503,
+                0x6ca38933ccbbefb2,
+                0x87107ac95c8561a0,
+                0x7f46d307959ced49,
+                0x49f8e3c5bbc96580,
+                0x4b55847e07cd10e7,
+                0x96d070c410f2df8c,
+                0xb3223349bf554a7a,
+                0x181f88fe8da05
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35902dfa7f1ed01f,
+                0xafdbf320842d9fc,
+                0xbcb922b993d3e2ef,
+                0x26da1822c07dcfbe,
+                0x2dca7c1af6939e2e,
+                0x8536ef6c0eaa9161,
+                0x762ce474c524e0ed,
+                0xdd7ad3e0be4c452f,
+                0x4e3f9db6dae4bf83,
+                0xef41494b75fa82b0,
+                0x1cc02de334dafca0,
+                0x198d44076b19d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd57154891b252f6,
+                0x5f5e370072572147,
+                0x524d6f5711d9f3c1,
+                0xe0f853ddebc138b8,
+                0x4a28d33298d35832,
+                0xd29b3c42946d46f1,
+                0xe139409f1a247d10,
+                0xed1a8c3cb22426d0,
+                0x9c6fca8524865eb,
+                0x8a04e2c2ea1a9fd1,
+                0xbbe520e548621448,
+                0xb37c27fc380d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa518c714f65c9c8b,
+                0xbfb9e2f6e2e134c8,
+                0x2d0f35f07977a99e,
+                0x6589a5a97c3317ef,
+                0x320af75e32b3b5dc,
+                0x66131a5f73223249,
+                0x179cdff977cbcc46,
+                0xcaf6849cd676def0,
+                0xe27fb9d3b9fc2697,
+                0x91e2d471572081a9,
+                0x27cb22ae8198cb85,
+                0xb0a3b2abef27
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcd4e32c0a7e4b82e,
+                0x49285e8b5790669,
+                0xff83d9ca39f36388,
+                0x6d9812baf0749cdc,
+                0x85f2d44dabf84368,
+                0x82a6ec8982a8eb54,
+                0xf1f25a1b6a62f2a7,
+                0x878d55fe9c670a22,
+                0x8f9892a3c0a3ffa2,
+                0x38a2e4dba3a83ee5,
+                0xfc441b56fa82d2d6,
+                0xcc6a8f98148a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8c766e92b2675f92,
+                0x73a4bb05b67062da,
+                0x35dce339d57dfd65,
+                0x3866824c344e536b,
+                0x9946cece9e7cb83e,
+                0x32d3d77eaedb16ae,
+                0xe4669b74e2c6ec20,
+                0x22adb00a696bdea2,
+                0x6ce7cc00e787b983,
+                0x24861eb8e43f5ee9,
+                0x8fe9bf2d02d1fca7,
+                0xb641e4835aa2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeb99143d18343ee4,
+                0x33e778e8193197e9,
+                0x66b07699f0d10e0c,
+                0xae36641cb80057fb,
+                0x65ef07c59f6f8782,
+                0x8d29d437305fb2ae,
+                0xbbf1306de15153aa,
+                0x832503ee4ab25e71,
+                0xadf3b1c44c877bcc,
+                0x76ccc056b77cde1c,
+                0xb6ad465deceaf1ec,
+                0xf96bec5e5750
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82cba0ac6c5832c,
+                0xb83eaeca891a1bc8,
+                0xc5f4e369e984122f,
+                0x1a0757ba6c04804,
+                0x3b4f350c73587fb6,
+                0xd8ba6691b03c4c5e,
+                0x69f83ac30475e43f,
+                0xd4bd6e1d83e598fc,
+                0x181afcaa310faea2,
+                0x5eff2f5914789ad5,
+                0xb41a4ab7764c4bca,
+                0xde3fd5d80a85
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5bf751d978065c9,
+                0xf37b8424919499a1,
+                0x5ad64d55690a57c2,
+                0x2a3ec56c246986aa,
+                0x21b33b67f8ef44e,
+                0xd0fc8f2b36b4c09a,
+                0x3d8ea074f0629b84,
+                0xc52ba7cd3b045f04,
+                0x288c4a38065cc606,
+                0xbffa7f7372faa0f,
+                0xa84d1e64c617e2d7,
+                0xc06e6fff70ec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x880e2ef379ccc5b3,
+                0x7fe6f747e869e8d2,
+                0x84f80352ff35f1dd,
+                0x3b80a3e2dd0373c9,
+                0xbdc6ef8e53d45493,
+                0x2b5f3481d217662e,
+                0xc207e27caf82b41f,
+                0x7597fe51c1a33fa5,
+                0xd78a627d1af6c483,
+                0xa093195467dadee7,
+                0xd4263f222f4b4d9b,
+                0xea681da00416
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcc696e2f74321b9e,
+                0xec5158f07c4cc65e,
+                0xe9a0af57eeed2405,
+                0x46df42a9e52cec77,
+                0x3756105d802afc9e,
+                0x62980d68763ebbe4,
+                0x9cb7ef51ea128ceb,
+                0x79ee5e4911fd6ae2,
+                0xbd9b07a48693381c,
+                0x34bad3e9f4f735d6,
+                0x56b164ebdf735936,
+                0x139369bdb80ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa2a115b8e874d40d,
+                0x35efbcce09df2743,
+                0x84698b777883b097,
+                0xb42625b8b653bcd8,
+                0x3289c22263ddd689,
+                0x6bdb00f9fd2db49f,
+                0x3f08e19437e541aa,
+                0x5e95ef0962edbc59,
+                0xbc4fba1385a923aa,
+                0xb46403857d46d7de,
+                0xe160a0bf18b42a65,
+                0x4110e8b0b7b7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x19b88f9bbf3757dc,
+                0xdaf0b16fe2ed71f8,
+                0x283034caa97791b1,
+                0xdf8bddd2586d1c21,
+                0xae0cf807becff2d3,
+                0x148bc473e5f29e9f,
+                0x9c7679c24166e75e,
+                0xd3e395a173792c59,
+                0x357ef57a04d27e03,
+                0xc2179b5dc72248db,
+                0x24a2e0bb809398b0,
+                0x1ac65d5ca72f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ba97753f3ea709,
+                0x2c8b29bebcf0f0d3,
+                0x452f8ecef44d6df6,
+                0x9fbe18546484b3e9,
+                0x11c45d42097c6a45,
+                0xd078409a33d3037,
+                0x3f7ea4240bf6f750,
+                0x95b4a72c99542e5c,
+                0x9e0a823c6953b925,
+                0xae3c75bd9ffa4650,
+                0x63017a99f46b4448,
+                0x85626d492406
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc893b7345f2b8327,
+                0xae8085b7141a99ef,
+                0x8c702e3292b2996b,
+                0x39cb6db3465febbe,
+                0x2b981156318b5e93,
+                0x810673d82005f406,
+                0xcb20d79d08934812,
+                0x3cc30870d217cc08,
+                0xcd18b106e3b8b352,
+                0x9ef09b0d7eb2e949,
+                0xfeed62096e238505,
+                0xc75df6de6963
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce7396fa4bcd2821,
+                0xa5cf895d6823237,
+                0xfbec9f130d5ebd64,
+                0xeba3798d57f85ec4,
+                0x7d008629a5323666,
+                0xb3d54e31757485ad,
+                0xcc4413a2150d688e,
+                0x8ff00dd012417b2a,
+                0x26d7c2b0385489ab,
+                0x73b77552a3f271,
+                0xab5b3cd3262787b5,
+                0x310bc370a43f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x55edfd3ef810ea06,
+                0x3e0947e1ac3aa1f9,
+                0xbd5758589fd7d0b4,
+                0x52612ad8be8c15c5,
+                0xf89c0c309eae0d62,
+                0x7602bd4a234d9412,
+                0xebfda78c4822794e,
+                0x25d15228e6410e0e,
+                0xdc38bd16e21e8ead,
+                0xd53958a9cf24f2fc,
+                0xee848c2aecb62c0a,
+                0x222afc14c79f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb721b5e07631f03d,
+                0xb05ece6499da7811,
+                0xa5ced598cb4c2cd7,
+                0x9acead3ecfe4635b,
+                0x90321a50b9b8bbed,
+                0xb7e325594b1261ea,
+                0xcc530340363f1e20,
+                0xfb382f99ec7ccf6a,
+                0xcf8ba153963027be,
+                0x94d342ab3ddf95f1,
+                0x740515fa7389a9f4,
+                0xd421d1ab56e5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x479121dcfb6654fa,
+                0xc5f035646fcafc93,
+                0xeaad6561dc02ae9a,
+                0xb3fa469574a189a3,
+                0x8b2a8584cbc35070,
+                0xdb3bf315a475dcb3,
+                0x7a69fcc309034f0,
+                0xd01d9c01ade5e555,
+                0xbc06b62b75140574,
+                0x36f58e6de3dfaf7b,
+                0x644e48996eb8735d,
+                0x1300da4289e7e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd2d1d2fb8707bc6f,
+                0x73760c5746a56f43,
+                0x4c45dca3e8fd3e4c,
+                0x31bac67ca2aff1ac,
+                0x359096aeebedaa41,
+                0x604c22fc258ad844,
+                0xce6409a4c68ae731,
+                0xcc859005363eda70,
+                0xdc59bc600e6f1237,
+                0xd2a5da23f495dad5,
+                0xf2aa909fbf8201d8,
+                0xfcb7906e35ca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe9e422ac5f5d511e,
+                0x91f5bfd8c9539ae7,
+                0x9b32124dde317f3e,
+                0x7b8085fe409ec51c,
+                0x68ed6fb112e7f967,
+                0x20e67eab30fd78de,
+                0x6b909f962e26bc62,
+                0xf803c3b0e0628072,
+                0x79acc7c1e8051139,
+                0x14e4a422a3fead71,
+                0x8c085d00a3a774ec,
+                0xf6d1c0a9584b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4f333bbc892dd56c,
+                0x48449a9d37ecd5d3,
+                0x6ce17c63a4b0b42,
+                0x6fcaf15424b90f05,
+                0xebc7c5da7a40d145,
+                0x8fd9f1fc729019f9,
+                0x42b27afcd3bd4451,
+                0x61dcfe01ab65da1a,
+                0xb68f524585ba4f29,
+                0xf28ab899b16ae74a,
+                0x49315b3fd1f05cca,
+                0x61d6fe466a3e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x39ac72f273640663,
+                0x2d61397304249fd0,
+                0xb184181982c47626,
+                0xc1e91b081c63fcdb,
+                0x1c5017354263c487,
+                0x67f98f933c0e124a,
+                0x6c43554e83941dfd,
+                0x78c82f6f84129429,
+                0x4f644786bf6d5f7f,
+                0xabd734f256596951,
+                0x84e77cc00aa04b24,
+                0x1319a8f070083
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5d76c6f800e87630,
+                0x7706775b6746ff64,
+                0xefecececae8f77b2,
+                0xae6dd6360f50e335,
+                0x6267387ecfbb3f3d,
+                0x94837f6047f7041e,
+                0x3879108bc60963b1,
+                0xde238733aba17f35,
+                0x609b926c565b0f84,
+                0xa85b0e648f89e95c,
+                0x700764122cc961b4,
+                0x74e5eda34753
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf4a22509c5a8472e,
+                0x5e76e3ee6e7a2452,
+                0x7cc7e047cd344d3a,
+                0x7c4a7921070e1109,
+                0x8e6798af1e69e373,
+                0x304f012e4f5a6062,
+                0x72df851c82c219a,
+                0xaf0d8e86d6658d8f,
+                0xfc94b218aed15956,
+                0xfaaa721a06aead1,
+                0xc4e3200b95d7a360,
+                0x9badae8ff09
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa29848aac99abc67,
+                0x39abc9943fd2940c,
+                0xc55c6f9c9b060f76,
+                0x516373d92ede03c2,
+                0x6e23ff865e5c06fb,
+                0xbef7005d75c1093b,
+                0x82e9f28a79870002,
+                0xfde5fb855eb83fd1,
+                0xf7c7d8f40652b7f4,
+                0x3fcd65de49132594,
+                0x3e839f34c46ad75,
+                0x1aeb4316b49b2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb7b36f788b85329d,
+                0x391029baab2a39e0,
+                0x974e79806622235a,
+                0xf54445cc0604dc36,
+                0x493dcdd37d3aec46,
+                0xd4d9876e4977034c,
+                0xe78087e269261fb4,
+                0x7fed4fada0581a62,
+                0xa09be089f556d51e,
+                0x149ff1e000cb2eb,
+                0xb651a396fccf369f,
+                0x3694f5eb12c7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x17541fcec81b4827,
+                0xca2011dba04a892a,
+                0xe7b985fdf4174d8e,
+                0x7153da325c53b41a,
+                0xa0026ecd304332ef,
+                0xea1b3f1c55617f91,
+                0xec172d4ed6a2f7c0,
+                0xc7152a896ad6bd76,
+                0x62bebd1480ef1b91,
+                0x5195fdc833c04812,
+                0xdb50314c811e1223,
+                0x3ad83f10bc13
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x583d026e69febeaa,
+                0x3ec79ba71f6fdcd2,
+                0x18f06be90fcf26c3,
+                0xa9daabe9af4897eb,
+                0xd27583c9db6c8574,
+                0xac9c3aedade9e409,
+                0x927121b6a2822433,
+                0xfd72e1a66505934e,
+                0xc18f71967e23b3e2,
+                0xdf41d194f15353bd,
+                0x1a068a64c78a70ad,
+                0xf64cdc16ff75
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd1e7241a6f61d37b,
+                0xa5d8729e2805af83,
+                0x8626197861d6ae,
+                0xeafc8433fb69fec5,
+                0xb34435afd612d3f7,
+                0xe9273cedcd3ba3ed,
+                0x64149e55a936409a,
+                0x590cde870e5f2f24,
+                0xe9ec25e9e83615dc,
+                0xe00b579e4ddf87ef,
+                0x77dde9b10032d270,
+                0x136e5e91ae63
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xddecf080abe7991d,
+                0x7976bdae1555f8c6,
+                0x46db7f9bd1393e3c,
+                0xbdc1445f35d271b4,
+                0x4f1d5ce012b05d9f,
+                0x5265a611826e757f,
+                0xd27379049e33d4b7,
+                0x79319b7aef1de069,
+                0xc23e48154e4ee5cc,
+                0xefdbb19cee3421bd,
+                0x29969036e596dc55,
+                0x3b9682131396
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc96681e5482dc27a,
+                0xd54ba1148f066e3,
+                0x1755bd737774a966,
+                0xb9b12d9bb6838bda,
+                0x88879cfe854b964e,
+                0xb55e1304bbf4e92a,
+                0x3dea76e33543acfa,
+                0xe3481fd639b197d9,
+                0x83e6806cff1266b2,
+                0x74f6cab32a57e86e,
+                0x9b4a57789e84735f,
+                0x54c0e5582979
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x480f2bd9099068b9,
+                0x55a16f89138abca8,
+                0x29e9f969aa65b54d,
+                0x6c50dfbc5979fbac,
+                0xdb419926005f554c,
+                0x97c2d7ecbddb71ac,
+                0xb5e2485cc7759db1,
+                0x635a6e840879cdbf,
+                0xe8bb89a41d19ebdf,
+                0xb080644022ae468b,
+                0xb5fda360e38dd084,
+                0x76ff1a2da106
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b736d9a822d97b9,
+                0x13796470fa70acf3,
+                0x88db8f4a316295ea,
+                0xd88e9e4b7f42fa2c,
+                0x2a1867815cfa18ac,
+                0xe262d2fe4757c2ff,
+                0xe67fc0f456d450c9,
+                0x5be6067558f6928d,
+                0x7243e2f00d20fb08,
+                0x4e065700d513d592,
+                0xc7e649bfc4b49b02,
+                0x1a065ddf2e6aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc399a9109911bef3,
+                0xa09b1a4ca17db544,
+                0xacc27b6889d44993,
+                0xadac3b4f4d41bcc5,
+                0xc33b6e6d5853c64,
+                0xe0f00454eb8387b7,
+                0x4e8322c362aaf2fe,
+                0x4facc8256565d855,
+                0x5b57ccb09084b4ea,
+                0xd83270b13510122f,
+                0x13c1af915c6cf837,
+                0xf3ca41c2cd57
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66f9f6cdd2a0684f,
+                0x978a10d1f1196a71,
+                0xf38aba08e46dfc40,
+                0x4529954746bf9059,
+                0xcfab4bfc29061fbc,
+                0xd0cd14d8cfef048b,
+                0x3b37bc8736ba947d,
+                0xe66f664165cc9da1,
+                0x4852fae93caca07e,
+                0xffca86ee155092b8,
+                0x436461f72af8712f,
+                0xfc992d3c2510
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2941cdd3f7860675,
+                0x1567f888ec73fcf,
+                0xa995111822ab75ec,
+                0x7b0eb48e5e45edb2,
+                0xd07ed48877e6a4de,
+                0x2372c8e176cb5bcc,
+                0xc802aeaf87bcfea9,
+                0xc635625f95bfde56,
+                0xa361375bef473420,
+                0x4a7101e041d458c0,
+                0x9d74dd010d5df9b1,
+                0x141aba898270c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x604c1d9df51b5fc,
+                0xe3cd9ad707bbe992,
+                0xfe1a7d9924ca5c70,
+                0xc1858a3ceef1e010,
+                0xd118be4d61b53b11,
+                0x961899347c8a1ac8,
+                0x726c302ec66dbbf5,
+                0x6771b9645dbfb4c2,
+                0xf2e5076271e3dca2,
+                0x4597289d9024f40d,
+                0x8cc140113d7f48af,
+                0x4248fd7443a9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x47c698a8a0831f53,
+                0x42da8d0ad9029ee0,
+                0x538f2b022acd1ece,
+                0xdc065510d188263d,
+                0x3b7974ab797725a9,
+                0xaac2e3e20559d66a,
+                0x15da0c2b5c619af3,
+                0x6ae47cc195f3b618,
+                0xb7839891dc10d836,
+                0xf2d10c268f6680ec,
+                0x9425d2f5b79aa6f1,
+                0x702dbca0c95e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb6359dbc7dc05218,
+                0xe131daaf76c94ba9,
+                0xea487f206a6baa1f,
+                0xd98b393000c1df79,
+                0x7c8c0be0f8876be8,
+                0x7aa9a98629963a79,
+                0x149da3b097f7c669,
+                0x80c4177b0e037b2f,
+                0xabdb230458a43d6d,
+                0xaf7e58a8572abc91,
+                0xa4baccde4aa19263,
+                0x9dd753e11a79
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x53931f0c1955ff89,
+                0x93bce18ea3ca49d9,
+                0x4b0e7c1244fae068,
+                0x556d158ed15784db,
+                0x12a05e181626b792,
+                0x839889715b2f5420,
+                0x379c8e35fd008ef7,
+                0x84bf7f7fc6667ab,
+                0xfc6070e8fd194b77,
+                0xd24014323c2657b1,
+                0xf8f260022a6104ee,
+                0xd181dbda70fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x328eae32cc7c21e1,
+                0x5aaed2c132b0d61c,
+                0x988732f4ea15f392,
+                0xc2b08958b59eedb1,
+                0xece7f6fdfa432ccf,
+                0x4e291cbbc73d6411,
+                0xf3ba2caf252ed5f8,
+                0xefc8ce3c989b501f,
+                0xa7111a5ef787b3fa,
+                0xb00527d1b431a5d3,
+                0x3bb02088ccf811c,
+                0xfbfde84fdf42
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5fb7722b98880812,
+                0x8c7be1a395e90df2,
+                0xacc565999d0efb5c,
+                0xa886396cf840f80a,
+                0xead092b54c8959a2,
+                0xfb19e4f6764957fa,
+                0x789913fbb3c30c76,
+                0x5036defef771f129,
+                0x87c464b8b5ed3a6a,
+                0x40db57aa063ba674,
+                0xe5750a6e61d0a0c7,
+                0x7ff662637045
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b8fe764c74bd66f,
+                0x87faa4d0b6fe904c,
+                0x634a10494bcf0416,
+                0x5d19d06f6ef58eee,
+                0x9cfa9e3c78b19f92,
+                0xeb711f3d65e80090,
+                0x203fce3212ff2159,
+                0xb9543b2b20e868df,
+                0x18c8da5285400ae2,
+                0x72e434c420e57d4b,
+                0xb8e687fc0ea40104,
+                0x3a454ae17bc0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe748f0f240762a57,
+                0x6bb6c5e567ea6a9c,
+                0xbccb7c93537a409a,
+                0xf93e064063555d51,
+                0x192a081943489ba9,
+                0xdd4a90ccd2236c19,
+                0xef99cf2b70814d8b,
+                0x747a8efc1ad36354,
+                0xcc4eae32d601e869,
+                0x865f7709cb02ab30,
+                0x90c3a48e5f0fd094,
+                0x1af763548d151
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb683c5c6e48fcf19,
+                0x699d6802a6453857,
+                0x34dd08f46e3a0160,
+                0x397576cd5c723b58,
+                0x4ecd93fbc1bbe7f8,
+                0x46e110f69b05d4fe,
+                0x1f4cb633ba21057b,
+                0xb4861151ab09d3c4,
+                0xbd0adae3948e804e,
+                0x173054574ef31372,
+                0x38888b1ed8e41414,
+                0xbae50ff1c33b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7c672ceeffbbda7a,
+                0x4f3b9caf41db689b,
+                0xbd1a931307b7eac7,
+                0x57e068a456c04355,
+                0xe0a0c7e8486449e1,
+                0x5cdd8bfe75dd9617,
+                0x5928db474c12b0a,
+                0x20b6e8f4f3551e49,
+                0x15fb581aac29cd87,
+                0x7e68045e917e69c0,
+                0x6ae036fe0321eaa4,
+                0x16d1cf22ed0fa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd7c95e99c84ad691,
+                0x3d5ddd2d58038ebe,
+                0x724fb16a8f9fbcba,
+                0x214445a639e57239,
+                0x6678b2fe89c9935,
+                0xd772f18f69367e49,
+                0xc8972824fa8310f,
+                0x9157b9125d92563b,
+                0xb22774085c92ae3a,
+                0x395d45bff7a54f9d,
+                0x2ff4274792b934f8,
+                0x4d82f7a9f4a4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb71c1effd97d0a4c,
+                0xc28865aeec85895a,
+                0x8e188f85499b4643,
+                0x965e978c8ca3c818,
+                0xfddeb86fff507939,
+                0xf92565379cd5a8ef,
+                0x41fc99572f61a72f,
+                0x16912b3ef2b06b97,
+                0x9adb939ae134d082,
+                0x98eee519d8ead369,
+                0x368a0eca834e54c3,
+                0x7cb1df5c617c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e39ccc5a7ade3be,
+                0xcd6a9eb90972d15,
+                0xc68c3fa1b0c1374d,
+                0x598e170ce92a2137,
+                0x53b9a388b40ee6bf,
+                0x2c21237b3d09f801,
+                0x9f78b5d046b24fb3,
+                0x8bc42c17e8f2f382,
+                0x5a334dae31f877cb,
+                0x3230dc9b73266f90,
+                0xbb882a969df437f0,
+                0x1414d0e80740c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbd824c62d597ed3d,
+                0xd4efcc7b26ba2a9b,
+                0xa2c189ace36fb10a,
+                0x35eb49b936fc38c7,
+                0x681071a944d5104b,
+                0xc1a7348f96efa4df,
+                0x33aa652e4c55ae42,
+                0xfd85fcc4bbaa659c,
+                0xdffdd7821aeb49d8,
+                0xfdd470a3d73d66a5,
+                0x94d98e4b878a5a41,
+                0xc4c642480d73
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x291c819d27f20d1a,
+                0x2c431aeb5179587,
+                0x77b742c883cbd7f7,
+                0x189d8e267028f110,
+                0xb35a7048ff21167a,
+                0x2325e6c9e518a599,
+                0xae2fca55cc77b69d,
+                0xec9e9a14acfa9802,
+                0x89eb470015c326e6,
+                0x4c1e839bb70b9ac,
+                0x46754c14a60353ec,
+                0x1187b2c6b209b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f66f1aafe795ff9,
+                0xdb86cec9c460c129,
+                0x880a03eb06670e1d,
+                0x65dca1ad170d3b08,
+                0xf4de6498d5398f78,
+                0x6cc94905910e2f81,
+                0xc5e4a8d652caf902,
+                0xffa92d1281473ac3,
+                0x4aaa71da6e3b8cde,
+                0x4c051d79b68c03eb,
+                0xbd1844b210a58214,
+                0x1ea7cce3d567
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a30130bb5bbe268,
+                0xf4a34fac35205642,
+                0x8bfd5db5ffb92ace,
+                0x37dc45f095ad0a17,
+                0x2c662ac8b5dbb665,
+                0x8008c59b4665728b,
+                0x9de6d42f77b06a50,
+                0x17d0ba04ba560e8f,
+                0x7c7d8ff4df777e3b,
+                0xcec0af17e29207,
+                0xb0406bc210a0d1ce,
+                0x1694dacc0da77
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4419810676360e8b,
+                0xd53846ecbf094996,
+                0x57e7958bf125e02,
+                0x8a42405d584c4f14,
+                0x37770bdeea512e7f,
+                0xdd9471b989a76e72,
+                0x8aeb5ba26f718a7c,
+                0xd2f6699bc8475db7,
+                0xaee39155d9e92249,
+                0xe48e39bba7f47d50,
+                0x723111990a45b7ae,
+                0x1c2cb734f9f7a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66f294241d937ce9,
+                0xe7a083cb358df29f,
+                0x5fcdfaa45edc947a,
+                0x37f344fa7c68ce28,
+                0x66ef9518ef5a13c9,
+                0x4af08f07c4fa0418,
+                0x944dde0e635abdd4,
+                0xa14ea9c3a196e10b,
+                0x4d8a78ed13d0173c,
+                0x2affda886887a899,
+                0x36d7de06e1885c8a,
+                0xee697600afad
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb1cb883207bb331b,
+                0xb705bbb6d128e008,
+                0xff103503d7280b8b,
+                0xb4c22489d05e7e7c,
+                0x16c231265113e46e,
+                0x92beebec67db4a1,
+                0xf9e7d9c89f80d6d,
+                0x7632304f890626f9,
+                0xbe27b3262745eb8c,
+                0xf46a5eddc1a19527,
+                0xbb1e19946d0a39f0,
+                0xd23af159c86a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xec430e998fc7e12a,
+                0x97269f6c6bf2436c,
+                0xaf41e28d4a1ef67d,
+                0xf087516229e0467d,
+                0xc45fe554c5581bba,
+                0xf8cef6be73f8cf59,
+                0xeeca9f79d24aca4d,
+                0xfacbb94c2c686f23,
+                0x746e7e45fb6a8d68,
+                0x2696633ceb2506d4,
+                0xd820abd40b31dcc0,
+                0x71fff026eeec
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x989ed2ea54107378,
+                0x5ede45d5c308ad78,
+                0x3e29bca5bcba7812,
+                0x65710b98819b1e8a,
+                0xa9c867b0e87f398a,
+                0xf1961bf695aef2da,
+                0x9fbbee6953140fa8,
+                0xc5abcf619a7d7725,
+                0x3aa7ac75e75927ce,
+                0x5afe40deeaf68a12,
+                0x7ce3b5c6190d09d3,
+                0x6ea61739529
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd14316b3ab7401eb,
+                0xa49986666092c223,
+                0xd6fc3da6898c9ffa,
+                0x15b110c93d4308e5,
+                0xedfd4a249e846a2,
+                0x1d2ae34eedf84957,
+                0x65e804eddea5d2a6,
+                0xabc641b0a657938f,
+                0x5b09c56c4366a721,
+                0x696b804566ed72bd,
+                0xecf2ff944656bc8f,
+                0x1b82674dd2e3d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5c008e7fe1d371ed,
+                0x303289e26c2543c0,
+                0x46359d3761fbbc0d,
+                0xd31e746627281747,
+                0x1583eb9cc1a5ff32,
+                0x449ebcf4d8b36583,
+                0xe9c48fc04b8a9142,
+                0x2d9cac8ef58af387,
+                0xd46487a27d0c406b,
+                0x4be986e82e540fbb,
+                0x526dbfd937f80aca,
+                0x358bdce3ac23
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6797071e443691a8,
+                0x682ab5cec0f45dae,
+                0x574efc224bca85cf,
+                0x44ee45f50f0534fa,
+                0x2839ed3b1872aa89,
+                0xe5252c23c6dfe106,
+                0x59566b7087497548,
+                0x5cc42987bc0541d5,
+                0x860383a146099d66,
+                0x4f1c9d2c8899bc79,
+                0x84c13e138613fbbf,
+                0x66fbf522182c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9d3792cacfac6214,
+                0x2dd5000e17c5d94,
+                0xd5a66be3bf15298d,
+                0x95c6cb2dec86eeec,
+                0xdd2f190354556d59,
+                0xea2531cb2e2bb28e,
+                0x320d2e43358757ec,
+                0xeb2f814b4dc7d7e7,
+                0x3ee6299cebe61313,
+                0xd708a7b54f2ef221,
+                0x2717bec026f8cc59,
+                0x154be2030d020
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b40a4a7b2caa58f,
+                0x3c35552ef642c49e,
+                0xbac5715b73ba8291,
+                0xd5ac7f0d0283192d,
+                0xbe025bcca03787c1,
+                0x69fe77fa9dbe3b6f,
+                0x9e729e2c57806eef,
+                0xe18deaaf067e2d1d,
+                0x7f5f03038005722c,
+                0xe2a3ad4341a17910,
+                0x8e6018834cf6dfee,
+                0x2e9be0048db9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeee7c65eef793dc,
+                0xa9fe0b935506607,
+                0x23848f387be7c863,
+                0x3249bdd59846d169,
+                0x32120f6ca40414eb,
+                0xf10a8fe8f4d7a69d,
+                0x8ea6c451f2137f01,
+                0x8d17f09a694c039a,
+                0x54bc490bb1b89bda,
+                0x9988d66be54b8716,
+                0x4ff47d0cfc731c9a,
+                0x3dc0c926e477
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbd0cb2dfbd7abd3e,
+                0xe1597d65db6cfa78,
+                0xdac30ab5c1c469c5,
+                0x1a84d27e0cdb0d56,
+                0xb2b51e91c4a5879c,
+                0x4353c30c4fb0c3b1,
+                0x3223edbefca5afba,
+                0xad8e56fcc34a11de,
+                0xda317c6b8339e13a,
+                0x57b9232578c090ac,
+                0xd5ef8dd2ad1aa684,
+                0x4978cd04adb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa058699dd3e382b8,
+                0x53b0764b8660233a,
+                0x90c6fd5fb11bcd8e,
+                0x2c7f7e1cd2628916,
+                0x62c79dbd0ccf195d,
+                0x2241e27952fb0a0d,
+                0x8e076991f8d73d7c,
+                0x174117e5c5140e81,
+                0xcd6220e3793f9c5b,
+                0x46981991cf863886,
+                0x2b5b6a741b606e24,
+                0xb99d38a1512a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7aa460e6a35db355,
+                0x3731345d7fc2e58f,
+                0x128a0eb52616a9f0,
+                0x16b638c5d893ef72,
+                0xea53fa03f9d91013,
+                0xe560e512a1fcd438,
+                0x4adecb85351ad95,
+                0x687395603ccdbd4c,
+                0xb139b1f3658c0d6a,
+                0xcf2e12dacce6f970,
+                0x353b61bb9a74c9d0,
+                0xd3297ebbfeb7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7b1f36ab759b3ff8,
+                0xe587934fb8375868,
+                0x32727e5d0f6ff807,
+                0x9e7d27e48d1223e3,
+                0x70173e2b6971287b,
+                0x5f7ade2e6207861d,
+                0x873f03b21baea1f5,
+                0x613927f871848590,
+                0x3d6225b064f13829,
+                0x8524014f4d2b1273,
+                0x794bf128a6c0f859,
+                0x11e05eb41d9fe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x461c3c8d2f40183f,
+                0xeb21e4b174c5ed,
+                0x12ef0d751cabb913,
+                0x475e551c0bc7411f,
+                0xd0278fce67687e5f,
+                0x2dbf934e0fe27fc4,
+                0xd307c60477b457b7,
+                0x6d6a10e0bc6fd20d,
+                0x3ceadd5974df5bfc,
+                0xb94e58ec2b29a295,
+                0x1dc247eb818fac62,
+                0x18cff7d66bd75
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x545bb281b4572778,
+                0xf65d5065c1ad5c63,
+                0xac8a247dee7c9af0,
+                0x5d16f2c8e261f495,
+                0xcc88387557c584a6,
+                0xbc79751d76dc4cc8,
+                0x8d68e932c09342e9,
+                0x173e220258e837ce,
+                0xf7169c0dabf6bca1,
+                0xe984c18d6a1af4b6,
+                0x4acaed7e74de9add,
+                0xaefe6cd556f9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4956e78be61d3010,
+                0xca75a8c1e837fc89,
+                0x2b5f314881c934ac,
+                0x27cff30172bddb29,
+                0x38064bbc6122124f,
+                0xd697e5f2771ce846,
+                0xf3fbe919f212655f,
+                0x18fb684f22d6cb57,
+                0x2324a60c78b10f77,
+                0xab5f742c8b5affe9,
+                0x686f5328c2853298,
+                0x618982f7d6a5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1f1b8b2a45fc50d1,
+                0x4c382b1bde40557,
+                0xa912c3948f8249c0,
+                0xe37aff3875ceb27b,
+                0x96f8a019c6c46fcd,
+                0x9ebc104aeb95f01b,
+                0x9455246726423967,
+                0xc250b713fa06806c,
+                0x3a58c1287f17e5d5,
+                0x373dcfe4c558c16b,
+                0x4c895dbe8ca03b77,
+                0xe8f48c031555
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x23d04c50c93fee80,
+                0xe6600ae1ba6a7041,
+                0x70ad406f8f9ef92c,
+                0xac01c0e054f41cc1,
+                0x3657123e0481a18f,
+                0xcfe65bdad673fd9e,
+                0xe2c7ac9344933025,
+                0x28d8d3422827bdc5,
+                0x5786a77bc7dbe94f,
+                0x11eefcb47cd30d87,
+                0xf7cbb740bfab78a1,
+                0x12d6641d73bef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2b2a043250ae73c1,
+                0x4c83886fa910f18e,
+                0x1a667453d94c3ff4,
+                0x6935bcb9eb45908d,
+                0xfd34e6125d770ef9,
+                0x2b24fc0babcf80c1,
+                0xe78ec693c114462c,
+                0xcc1fc7030b0cd437,
+                0xb1e77054b2477e58,
+                0xe965d6433fe8a93,
+                0xc1de0e498ece472f,
+                0x1acb5a469ced7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x959b96242c83b00d,
+                0x34a47d9f5d976123,
+                0x125e69f69ff10a7b,
+                0x3d5eb5d11d89ba20,
+                0xa0a669721901ee11,
+                0xcfc90f49d96665ca,
+                0x423158729c1a4739,
+                0x1d9fa86f945b38b6,
+                0x42f295b0cc72e407,
+                0xb54027445e437b14,
+                0x5fe994729ca21fae,
+                0x4287ee9f7af1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1e46f029e97a839a,
+                0x60b9b632830607e8,
+                0x8fd36dd8f4914f82,
+                0x248c99dce7fc9c5b,
+                0x6833b35451c1f24c,
+                0x5a5a2284144bccb2,
+                0x2d19764d1b27b1ff,
+                0x30dfe268c2983fb6,
+                0xb5c3d7d7f278991c,
+                0x9cd19dd4405d13d7,
+                0x1bbd87c9091d632c,
+                0x10934809602f6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc45fab48d6438d4a,
+                0xcad596e194b752c4,
+                0x642568f592dc5d3c,
+                0x89f3cc54e8c16879,
+                0x4b7a39fe17557ebe,
+                0xd737a7aeade2f08,
+                0x6be89272aa8cfc3a,
+                0xd7c51114a9b6856c,
+                0x3fe94615514e0504,
+                0x5ad8faae1f7f68c9,
+                0x869be9ea8e20dfdf,
+                0x6165dd286c75
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfd75e0cfc9006ca7,
+                0x8c720e6d2ce5ceab,
+                0xd34592fea720771c,
+                0xf84d662b711929b9,
+                0x9d1352782bac10ed,
+                0x17a3f3f85971ef65,
+                0x5f506cd36a008b8,
+                0x7b1424a1437e7f2c,
+                0x373b4d529dddd67a,
+                0xf82804d46a23feaf,
+                0x915b3ca9828dfe27,
+                0xb0aae7588eb5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x964893352492aeb9,
+                0x5d2597ae829f6a5d,
+                0x13003f5553b441b6,
+                0x67352ca93cdb2d40,
+                0xf4e2051bd0ab81e2,
+                0x411eb8f799850cd2,
+                0xaff442aae82e4483,
+                0xd78a031a31c67715,
+                0xbcda4e43560ac0da,
+                0x5fcb844e617fb2c4,
+                0x93d8690d9ac68661,
+                0x1c611cc73f1f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6d577b34f4907c6d,
+                0xdebe4f383d83fc54,
+                0xbbd7adc9280466af,
+                0x3af575bbe9f4e3c3,
+                0xe8c4e658fce9b11f,
+                0x9d44be9edd37b85b,
+                0x137ed58b4b3b3645,
+                0xad588b917452d8bb,
+                0xe1057c4df0d6dd76,
+                0x3701e422283f2366,
+                0x275e8901fe71b9c6,
+                0xd445ac2f90e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xac79a32f497a0155,
+                0x46332b50a818112c,
+                0x387c85111b45b26c,
+                0x4481c569b3ac10f,
+                0xfc417c07838011db,
+                0x678f6069da0df39e,
+                0x23f775088196a56b,
+                0x7804fc7759640893,
+                0x4b5c5b8135262d38,
+                0x30285cee4c086100,
+                0xd06bf2abdeb222d0,
+                0x92177a785310
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa34506a85796f5a4,
+                0x5e106cc3eea4ed87,
+                0x950e96844f9286ad,
+                0xb5d1f2d2053bdbab,
+                0x1ac479cd00990b91,
+                0x737958d82c8a175a,
+                0x96d7b52b43eed335,
+                0x95b725c4559b3bdd,
+                0x9b55cde4406dd52,
+                0xbb3a955228efa51b,
+                0x5bbbc688dbf9f257,
+                0x13985a95a4454
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x76e0d3dc63a23fc6,
+                0xd5d775601191d085,
+                0xfe184d3ffbb1840,
+                0x446c949d760e09cd,
+                0x6bf6fd4ab4c3e617,
+                0x19f23381a57f836b,
+                0xd8268e155abb7db8,
+                0x4de684510cd0bd8c,
+                0x9b526b16e4e1b79,
+                0xe6b1c645893e62cc,
+                0xf783117e0467a009,
+                0x94590e861b07
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd6472b3ae24d3f62,
+                0xcd4837ba4060174b,
+                0xe50ec9d2eeb6aca9,
+                0x48b29fa6c7fd269,
+                0x5f9697cf0a751ce1,
+                0x50dac0e4f9a48e4a,
+                0xe2b65900ae9ef081,
+                0x3e23c5a7538a517a,
+                0x4205a5e38d8ba97e,
+                0x56ceec7019086301,
+                0x681cdc40af83e0a8,
+                0x1acab6a1bc822
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8a500b5995fca0a8,
+                0xf5dd88df1b40dda,
+                0x38b08ea50f7957f,
+                0x16d3122cef3e520d,
+                0x7c815653a41021fe,
+                0x3eadb51ffc0e5217,
+                0x781d2e6803e5de76,
+                0xaeac1be88a0767fd,
+                0x4b162e3136a38c8f,
+                0x8da91e47a4424b1a,
+                0xf0486b03025b700a,
+                0x1c50287e19ed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x981bf9e8b2990b1d,
+                0xc545d198c9d2b019,
+                0xdede72915272d3e7,
+                0x9025e027ab05adfe,
+                0x832c9bd85f716436,
+                0x301ea38a8c408f08,
+                0x7b06b37680669672,
+                0xee9e20541129c2bf,
+                0x190f4e6d4d3da9b9,
+                0x6632a5033552078b,
+                0xba2a07aad4bee098,
+                0x6e5652d634a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe911a6000d19b27d,
+                0x647f59a08dba2090,
+                0xce52d7b7a881a6a8,
+                0xa47db2180375e71e,
+                0x12c31253b97d810b,
+                0x22a982a76ae474bd,
+                0x8fab25e0cc8797d6,
+                0x96150b09d897b821,
+                0x94a0b9d9dcd15650,
+                0x9f3a9cec0aaa006c,
+                0xde64d45e8bc3c385,
+                0xd435ede3edd8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcbbf8c173a9df219,
+                0x672078f94134bf42,
+                0xbcf9f295e8dfa243,
+                0xd1fb36aae08ff3ef,
+                0x150cf1429c84de0b,
+                0x4ef21fa502848cf3,
+                0xffdefbff02b3f057,
+                0x8ff7fea260738b99,
+                0x600c0d8821b29d0c,
+                0x47a2d6c3f34ae7d1,
+                0x8c96a71c84f87890,
+                0x13ebbb5a06114
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4bb118d5370dbaf9,
+                0x83d5c923c6becf56,
+                0x9e3e1f0244a39181,
+                0xe6aff8cda06c138f,
+                0xb86cfe5be997e46b,
+                0xed28d8615fc71cff,
+                0x9383a3b3ef1486c2,
+                0xab5bbd33c9626ea5,
+                0x9ac739d94abf67be,
+                0xe1d0388de02f6a7c,
+                0x372dc79fbc66cf35,
+                0x6516793118ef
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe6bfe79ab2fc67cc,
+                0xb9dc8aaa34554cb4,
+                0x963d35635cdc0864,
+                0x13cd974954c9cb00,
+                0x25be93eedc9ba884,
+                0xc35fa77ddeeeea9b,
+                0x2a7e166a7755a8dc,
+                0xbd38b92294d85983,
+                0xd75f7507cd751a99,
+                0xe20d83ea831ad4ea,
+                0x325700aee536591d,
+                0x9b346ccfbd7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x71b794855c118413,
+                0x75a80348648b07f9,
+                0xe5a93eb906266456,
+                0x7fa7e073a0e030cd,
+                0x3db204d7741291c4,
+                0x9c38ef468b5eb30b,
+                0x74aded8acf3984f7,
+                0x73e1a0d5faac2d09,
+                0xf8e3c5b8d018e5a1,
+                0x9345160b8b13b2d0,
+                0xad7ebe337dc43396,
+                0xcbb6b14a373b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6fa39b15a3ad92b0,
+                0x709d97e7427263,
+                0x8c18a43fad8d3e5e,
+                0x973daa6223ed5a52,
+                0x823e208c28bf6091,
+                0xa5e59abff7e2f285,
+                0xd54c0b95681309bb,
+                0xb767f597cf2aed2,
+                0xbd6205b853bb7774,
+                0xbab5410367653df6,
+                0x3d09a47c1be9f7d1,
+                0xdb83fd390beb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9cc1f41e1b73fde2,
+                0xb4db106fa292ea10,
+                0xcfbfd5d5ea1211aa,
+                0xfbb5865fba28222f,
+                0x882760c2effa3c29,
+                0xf84f4b65293fc77a,
+                0xb81622bdfb7709a0,
+                0x4201d2998b87ed6,
+                0xc659904e0144ec61,
+                0x16b4cf1ea1e11f03,
+                0x8be731f90f258ce0,
+                0x80e77c3d624a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x62f0316e635d7e3,
+                0x97e668e292c2e60,
+                0xc6ea86c9cad20b24,
+                0xbc98d95f512927d1,
+                0x796e8538a508090d,
+                0xbdb7e16a4c5f7fd4,
+                0xf6142e148693d2b4,
+                0x83bf4a4f2dee56c9,
+                0x4e6d80ef9005d328,
+                0x8069d466d298201,
+                0x5daade8c448d15be,
+                0x13780b39d85b6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xebb8e5a3a904ce6c,
+                0x238a06b7ef0585f4,
+                0xc0442b49cf02d416,
+                0x10d9053e287f223,
+                0x3b6ea0512ad262f5,
+                0x99ac8c19ad8195f5,
+                0x13a8f8b13d4d4245,
+                0x56be2c4d30cdbab0,
+                0x2d54b21cd0974e4d,
+                0x4376a0a6a2768b6f,
+                0xd3e52018401ff192,
+                0xefcac665e2f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x878dd89879fb542e,
+                0x1f0cfdc27894178e,
+                0xeee1e003efef44c7,
+                0x8693213f37e269a9,
+                0x8846f7eb68ec64a4,
+                0xfba35d0db915c10d,
+                0xc0e2698291bbb707,
+                0xae7743548b93a4bd,
+                0x19e4d42a3d2e4504,
+                0x6f952ab44a16561b,
+                0x190219469861f563,
+                0x1096da8ff4575
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x48dd2d4515d11363,
+                0x7073d5059cb3006f,
+                0x4bdb856e7170c988,
+                0x5fe4234ab609b843,
+                0xe222c50f86994a54,
+                0x184db074222965f8,
+                0xab056e906e046f56,
+                0xc6253d3f05eced70,
+                0x2ca8d4fdfe1d946d,
+                0x8d69525b37ea5392,
+                0x608469206e1093ea,
+                0x35eabeb1396
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc8c88c92649691b,
+                0xc1fb56b48fb28b51,
+                0xc49a9485a413e9e5,
+                0xa30b4ac04c9d1e5c,
+                0x4588a06b17339bed,
+                0xff211d0fbd293d6f,
+                0x3a75c3e9bf939144,
+                0xc1ef0a24186dafb0,
+                0x72a4793247f72090,
+                0x7e98e8aa61d29bc1,
+                0xf13fc8640795c140,
+                0x25f579c4945d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd281fa81c9b43f3b,
+                0x8a70b5c3dc08a122,
+                0xd0c02ee8e274d60b,
+                0x9f9a33aa838ff422,
+                0x4def536909a1f100,
+                0x9d01f098908d5e71,
+                0xd4067eaecf2ff97e,
+                0x553b30b3f2b01bd,
+                0x8de26bdae54cad28,
+                0xb220ec75dc6b4c0a,
+                0x7c44143073c8e06c,
+                0x125f920ff7285
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9046681f211a1c68,
+                0x73875aa32ab2c894,
+                0xa29d219f260eab0a,
+                0x8382d302442e7b1e,
+                0x67fe028553d5be9e,
+                0x16b2f0506d77b6a8,
+                0x7ed5022bab3f8767,
+                0x7544d81932b5b43a,
+                0x1fa887ff615909a8,
+                0x3289c0d37e4f6fd,
+                0x21e677dd51d15830,
+                0x17e5a5e0d6e14
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd701cfd447d4e49e,
+                0x6a722982fd1f9c5c,
+                0x12e5e70f03f5378,
+                0xafcd826af8cf7b10,
+                0x3ad4c017dc1c80f5,
+                0x626e7450a2947bc0,
+                0xc311ac2653599907,
+                0x5de4fde80865b74b,
+                0x5d213343b203402,
+                0x2f15f640b1459699,
+                0xe6e3431b65def8cc,
+                0x13ca21129b973
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x90ef2ae320abe350,
+                0x72d1a5ffd3f098a3,
+                0xa356cde4eae086af,
+                0x48d77d6397951734,
+                0xc370ba6cd53a33b7,
+                0x8dae68c8d698f8e2,
+                0x28d6df8a74753ebc,
+                0x3c08eb658a89a217,
+                0xabc3e213d2707414,
+                0xa79c2c7a1e4befd9,
+                0x5f3ba78ef41b0117,
+                0x138fff2c59d91
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6778cb1116bc7ab,
+                0xb8d9c4af22cbc63c,
+                0x4a09fc71f90c7e9b,
+                0x292119d9f8d58cb9,
+                0xf31eec84b5d1c1eb,
+                0x6c5fc749e3ab06f2,
+                0x76c6e5240d3ecfdb,
+                0x28207b22149e7ef0,
+                0xe94e5ae4e548e6f9,
+                0x2b0ee0fdf2460687,
+                0x76293698b3cf0432,
+                0x19091b6dd152e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf015019c75bf6ab4,
+                0xdd3f4e9e67e28923,
+                0xf621329b6d35ccf8,
+                0x1b40d9c1c423ce9e,
+                0xde251b31ce9e37e0,
+                0x5a9d8f233415a353,
+                0x2875159e1fbec722,
+                0xb0910d4e8fbc19d9,
+                0xef70be0c34791d1e,
+                0x1480e6c892e5a0df,
+                0x45e488b2770f21e,
+                0x1411fa29fcd6d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x611635802f589765,
+                0x76bc5d486fe2dbf9,
+                0x5a1bb07405818d84,
+                0x84d6acfcf41456ae,
+                0x6c0a2f5d9d32bbdb,
+                0x7605a352b8effe25,
+                0x6ac340424368f92,
+                0xd1aa72ce443d3b04,
+                0xe7eeffc0a3b006d2,
+                0xf2bee969c399348c,
+                0xb2f8225c567963bf,
+                0x16353bf410c59
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc3a834ee765d34bc,
+                0xc48a1bf40df4eabc,
+                0x758397a7443d2653,
+                0x7032f437715d8cb5,
+                0x1c5c9dd4a102f385,
+                0x7c080ebe55d0e052,
+                0xb26cc6cc96c541e3,
+                0x1f516321a4cfd795,
+                0xd836488f93775e72,
+                0x945601183400a53b,
+                0xa11c15b60f3fe369,
+                0x159b6f46ace48
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe33ebdc26eb2ef0d,
+                0x49d46956b67dbcb0,
+                0x223743fa46db2d5d,
+                0x2e4672a5ecc3bc4a,
+                0x72938467a4fe8782,
+                0xfe5be10a4a13cd1c,
+                0xcc5d9270b207a05f,
+                0x59af82503a17380d,
+                0x5547bfd804af725c,
+                0x203ca46a193bcdae,
+                0x796602acca91f42,
+                0x9fb340a1557b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa1bd212fc6b285a6,
+                0x4b05555b9c7b2215,
+                0x1f60d28f6be76cc0,
+                0x8443e1c1e2f68191,
+                0x66e1667ad2e5880c,
+                0x532cf81f59035fd,
+                0x5176340c6b6fd4ed,
+                0x7c66a0915e19b1e,
+                0x44e512d44d262656,
+                0xb4fb03f29fb02ac5,
+                0xc6e216776fcbda57,
+                0xf993210cc270
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66d4f8d40432baf4,
+                0xe1ab08b7c6d60450,
+                0xa93ec1676ad08e8d,
+                0xd5b103787683aefb,
+                0x6c76c75ebad67de0,
+                0x6237098e3da0467b,
+                0xc061c5f31ba30fe2,
+                0x1d6574a49640e294,
+                0x393578b0eb13222,
+                0x5996d3647291c3e3,
+                0x4ab8d6d93f3a84a9,
+                0xca21dc64118f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xeb3ab57b3bb862e3,
+                0xba39cf8f219b37a3,
+                0x3fbb7f0282d89508,
+                0x7ae29d0fc4ec7992,
+                0xeb59563c8991bf58,
+                0xf7a45cb2cafb0122,
+                0x8081032990a22a5,
+                0x41b4318161828d1a,
+                0x56e7b5e914f54d84,
+                0xe9026c61982903bb,
+                0xd5c355b5c93c7aa3,
+                0x12be6c6272e0a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa8b353711669f45c,
+                0xa1cf1fd9aa980321,
+                0x6b5ecdbbf29c0f55,
+                0x79a90066cf7b2ca3,
+                0x59860a9424a04bcb,
+                0x7fe5f6a1ba3aa89,
+                0xd848d80efe36f405,
+                0x7039e6f410b3aeaa,
+                0xd934080712d2d0ac,
+                0xda4ec5f116d3f73e,
+                0x2f3b26dde7d70ca2,
+                0x2434cc25f42d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb628183d263655e1,
+                0x7173096023c10ce4,
+                0xa23d6a909548a6db,
+                0xec67161ecb58d285,
+                0x2ce5a023f5569205,
+                0x32327f053699b797,
+                0xd411c1db2b225436,
+                0x6e52f96a4b364309,
+                0x3bd315a2fd9dde60,
+                0xd87468eb35ae8fe3,
+                0xa1944a2bc4a89a78,
+                0xee6823a97bff
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x50dd41d7dd42f0b7,
+                0x5f109ead67a0e67c,
+                0x24454d530c9e93a9,
+                0xd44e6d80398604c7,
+                0xbe746b0d30da5862,
+                0xea3316b8d90c8dc7,
+                0x5aecbd20e534d765,
+                0x48abb796faf3190a,
+                0xfdcdafba3a784cd3,
+                0xbaee4aa63d24e3da,
+                0xdd0cc89e02d4a7ce,
+                0x22e622bd706a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x757e816f102410e3,
+                0x4aeb84dac809bfc7,
+                0xbc0dbcf0605c2af,
+                0xb6d49c56b94301c6,
+                0xf1018039946e267,
+                0x9f8273a5b2664cc0,
+                0xe1215b97660e6dca,
+                0x677383a9713db8d7,
+                0x6fd596f2286432f0,
+                0x8313526fdd80756b,
+                0x1d88f8d03961d0e0,
+                0x18c2e6f6f9380
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9590c944abdc0cbc,
+                0x1a35b0e09eab92c8,
+                0x1728024ec534e94c,
+                0x2409fc0119e3af0a,
+                0x1f9c3c9c9eda4993,
+                0x9eeae2783d856f8f,
+                0x47f60436fe369788,
+                0x36e51083e7330e9e,
+                0x844ab910fead93f0,
+                0x7d2f82aa0dbfe13d,
+                0x8d299b365bc32740,
+                0x18ffb091fd591
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc5c89ed1f03cb34,
+                0xe0ed3265eb443b54,
+                0xa0b93e8d74f5c604,
+                0x28a8c1af7054c124,
+                0xbd5ad0e9d7eb534a,
+                0x87949cdd3af838c1,
+                0x9627ec82b0eea738,
+                0xbbb227d5d4e90ed7,
+                0xca28acddec4f618d,
+                0x5b4f00febede73c9,
+                0x5de4857d7e7a18ba,
+                0xaf1dd1b8e2af
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x25894fba3f8c37be,
+                0x4ee566ecc65eca4c,
+                0x415b87bdd290e121,
+                0x5a262040f6f71884,
+                0xa7e85f1a3e1a3,
+                0xb4bb81faea8e577c,
+                0x82ae40a9a3bc017a,
+                0x9d7614aa879edb69,
+                0xdb819302dbe80ba6,
+                0xa6688c017643280a,
+                0x85b463031f603e42,
+                0x1224d59ea55e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xecd55c49c7fc0dd,
+                0x268d92cf1e8839ea,
+                0xad841881be5a385a,
+                0x696332dc3a1cac2d,
+                0x93981e8864ad498c,
+                0x824b8be22e8e7c6,
+                0x6cf5183207d3f283,
+                0x9f981f3460b26b20,
+                0xa8312a46e1b555ed,
+                0x414f101d942b3247,
+                0xe514ced5c1f127e9,
+                0x1844c11bf8ce3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa71ca27be7f0cce,
+                0x3967b94ef253effd,
+                0x3790cc5d4269217,
+                0x4048ebd3f4925e55,
+                0x9f1cd761937a5697,
+                0x4889b70506b238a2,
+                0x54a7848d7bed71b6,
+                0x13c6309f3fc13639,
+                0x6aa0c97fc7fc0aa4,
+                0x6b278da5e270f8cc,
+                0xcdec9b18c13d0799,
+                0x1893841a825c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb8c530f5e3ca0bdf,
+                0xb1fbeaba2037fdc4,
+                0x18ee130be823f5b7,
+                0x6ff51a3c4e1b1279,
+                0x20963e60a16b770e,
+                0x8df7e3e1dbdaf4ec,
+                0x9a93b15239491d31,
+                0xa5302b0b8b2fb5ca,
+                0xc9f12b90835fa549,
+                0x3c149299f8a854f3,
+                0x3ac303b102df39f5,
+                0x1ab6cfd81b5e4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xde9dac4e44f5ee46,
+                0xe402c4961fbfc6af,
+                0x5d7ffcc997f8e0bf,
+                0x1f48553ba78fb4fd,
+                0x2b867b4a6449090a,
+                0x10fff5a252ed4217,
+                0x32ac22c1a4eb6951,
+                0x25c0057e359c825,
+                0xfca25e493db8a596,
+                0xa8e1afdb192630f2,
+                0x1cbd1560911fc7a7,
+                0xd7ab8d9fd717
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5acd603a5393a2bb,
+                0xead0ffe409d75c8b,
+                0x8176ae87804a5e21,
+                0xb25f9d5348c92926,
+                0x1df42fdd05e46ed3,
+                0x2dbe3b759f4b7880,
+                0x997000b6c13089da,
+                0x6fdbe04e8c0a7686,
+                0x436782d0fd973f19,
+                0xd49b3fa297931f0a,
+                0x10826ba3b1f41c61,
+                0x19b4c6b785b92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x89d958450a5a613c,
+                0x56017ae399a3061b,
+                0xba9c80f375956e17,
+                0x6389fda6696d34db,
+                0x370ad1ffcb57682d,
+                0xe6d50f72d5cb58f8,
+                0x3e4679ad750b1a3a,
+                0xc743fd49960449ea,
+                0xbd100df4bfe9c8ff,
+                0x3aaee4157ba1a63f,
+                0xbf0a615dadea350a,
+                0xeb576908ece5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x742520fe7340616a,
+                0xac0e84d753b61795,
+                0xfae1a9e0571131d2,
+                0xf048505afd321d97,
+                0xc91bdff3475a682,
+                0x4f3381f1fd69a9c6,
+                0xc15d77e238ab2c41,
+                0xe0593789d755d58,
+                0x2939d27f2e754d4,
+                0x1ead89f1c354a9e5,
+                0xfc04ab61829b8cd9,
+                0x1501bac4cdc03
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3b224e81aa5ccffa,
+                0xe8d4b178d21a92a5,
+                0x23c03090fac508ea,
+                0x8c68d15e02dd6a29,
+                0x4845e1d4c4aa7d64,
+                0xa0e2f4cc4d176778,
+                0x23a9c3c6cacbb9b8,
+                0x66ef456033e69e34,
+                0x30b5ca75582d64f9,
+                0x66714a89a173383a,
+                0x5143439ddb03af37,
+                0x409c08bcf4cc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc43fd09feb46b128,
+                0x4afff32fc2cf5e04,
+                0xbda9e96e770529aa,
+                0xc50e22e2e789c2e1,
+                0xa809d7cdb3bf30dd,
+                0xcdf4729ec1fa9fdc,
+                0x1547bd889cae6eff,
+                0x8a07e5e63bbede8,
+                0x3cb8794a29e6ea7b,
+                0x873d6e275dca1486,
+                0xe5a66954ddd0c785,
+                0x59d8fcdfc820
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4bcf0efa2e145035,
+                0x15495b09b2cd8139,
+                0x8d38c415695ecc6,
+                0xdb73ffe6fd68ba2d,
+                0xa691556187752083,
+                0xf1e334d23a10a44c,
+                0xd9538c1fbab8b871,
+                0xc695cf18e2867f1a,
+                0x88b8e2854c8b2d8a,
+                0xe9cf6819207072a9,
+                0x999da4c9afc65b1e,
+                0x5118f284817d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37bc4f9f63669a07,
+                0xecb38b606b63553f,
+                0x64d5122f1695fe5c,
+                0xd50694790f6d98c2,
+                0x49cf6c68ab30f3c0,
+                0xe5dda428e5c4c48,
+                0x22e4c3437f6661c7,
+                0x3855906371aa9722,
+                0x4a4d555f59b8c5b9,
+                0x212f195db528fc38,
+                0xc6a4a94b21f6f1b8,
+                0x185ed5ba1fd3a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf1f365dcdb337c5f,
+                0x72ef0a14d96f8561,
+                0x24ffd1ff22018ad,
+                0x622a252ba902c386,
+                0x58bbb5f4c3ebfbe6,
+                0x5feebeff2101f346,
+                0x58b2d113aaa7d99,
+                0xd43431a763195082,
+                0x39f486c234b15ae7,
+                0x3105d19ded2c8630,
+                0x2f0d6ecc85bff7ff,
+                0xca921e918f03
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc4cff43328417034,
+                0x777ffa9968b72dc4,
+                0x73294ff331a8b063,
+                0xeaccc65be53716df,
+                0x8a25726a55d13a9a,
+                0x7caaefd517fbfe45,
+                0x5653fbc60b12631e,
+                0xdc0d1db7bab2b7e7,
+                0x1abb9b1bdfb9b045,
+                0x97055ed911feaf69,
+                0x71435002d60d6c37,
+                0xcc2963ccc82c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x58f620ff292a67dc,
+                0xbb9e165cef7d38bc,
+                0xdf5dc3efbcce77b5,
+                0xdd39c824ee254f0e,
+                0xa4486002b8aeb870,
+                0xe0b2a94bcae817ba,
+                0xf89150ba870d0c37,
+                0xf6caa3b997ae3cd0,
+                0xa840fb3b479a6c06,
+                0xda4b3a8301b122be,
+                0x7b39f98fde05527b,
+                0x6afa3d1308c1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xaaf6efe0519de199,
+                0xcbcd6b827e3a857b,
+                0xbb73aecd37365742,
+                0x8033c44f8bf51a24,
+                0x23b3812eace825a6,
+                0x6d02ba73043b357f,
+                0x89307206156d3b1d,
+                0xa0cf45b2939cbd56,
+                0x1d4251525dd5e263,
+                0x6de9cc91d1381c7a,
+                0xdc65dafd2c38d3b5,
+                0x9276d3cd8ce2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4580a104aa448585,
+                0x4ed0c63c23893364,
+                0x2719cacafbcee81d,
+                0x6700f87d8d5c27a4,
+                0x3b4e28b20bb8cca7,
+                0xc10b5fd89bc7bed0,
+                0x592dd988e59ca408,
+                0x5a3424f7c567c542,
+                0x3a5657163d53f218,
+                0xe51d217fc6520c40,
+                0xe79f9ffbe0499e23,
+                0x129c2786befe7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa43f3c6ece68f1a5,
+                0x7ca5c710573218d2,
+                0x77fc8935240a4f22,
+                0xbb27e8f06c6e8e0,
+                0xf8f1f59fbb10c9f8,
+                0xa822bb3a113226d,
+                0x6a3d4bf88f759ce7,
+                0xdb846d64477c59c4,
+                0x290d83b6eddc44c,
+                0xfa9fca0e8594f760,
+                0xac2d30f60b629217,
+                0x2900ebd0e95c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3eace06cfcbb56c2,
+                0x7566efeccee769c0,
+                0x90a154d8f1c9e3f,
+                0x9b06b18316b7926e,
+                0x75ff4c1f32725be4,
+                0xaed6d464dc4d23ba,
+                0xa64b11e81c5fa057,
+                0x6c87e1b4d9dc2975,
+                0xf2b2b839dcd29ace,
+                0xcf1e3841541c7d2a,
+                0x4a05a28b0a99f3fb,
+                0xabec8cb84c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xffb3bb085e8b83d9,
+                0x22dcf97f4e8730ef,
+                0x60d4c36953896c71,
+                0xb9280157ce5b57ed,
+                0x8255dedcc621ebde,
+                0x9daaf8200ed4fc33,
+                0x959906488644680c,
+                0x5bcb4cf2e1d8496f,
+                0x9403e08bb4f9e17,
+                0x10ce8440d89fc8e,
+                0xc33515c25e8af6cf,
+                0x42fb3b7d456d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3120721f12552d56,
+                0x5b64046438ab6277,
+                0x38d025e92916a873,
+                0x149240e6eed16218,
+                0x5b566afaf9cace0f,
+                0x9789b6a2fa109534,
+                0xec299f06371aed51,
+                0xbca6927b48450270,
+                0xf94637660c4ef9be,
+                0xe43d1dadf6888c30,
+                0xc25c1bc0e107fe27,
+                0xc6760fb4ae69
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe95fc31ac71b18c9,
+                0xf4d177c85b34925f,
+                0x4b314d3e0413db74,
+                0xa33217df4d24ce74,
+                0x8b89ca23732e082f,
+                0x4da8fd2729c1697e,
+                0xe5f42be6ec35b38,
+                0x8e7fefc776cf052e,
+                0xc4210eaff5f5148a,
+                0x8403f72ad8244337,
+                0x69d7a0405d8dc165,
+                0x177215c7eb3a3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x35b7de54d1da9945,
+                0xf13a91c39f81b9d9,
+                0xdbe43bd6c58781f4,
+                0x663f3862386a5fce,
+                0x4c12d27c4d13724d,
+                0xaae16fb7836a5d18,
+                0x4acba2d3759362a8,
+                0x406482c2b029a29a,
+                0x24b738c35cad93be,
+                0xafc901cbb88f1fb2,
+                0x860603532236bd36,
+                0x13619acb1e54b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x79fa89e15f5f1f70,
+                0xfa91234bee12854c,
+                0x59a582781afb43f5,
+                0xb706a14d6759ce34,
+                0x443d556daee4b003,
+                0xf22a816b01a93b17,
+                0x5c6f481c11d39675,
+                0xd5d2d1341fd0d157,
+                0x55b701f0ee3b8a90,
+                0xe390b94f1e78a915,
+                0x512b9f16bece19f1,
+                0x1bb767ee8629d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa0ebe097c13053e,
+                0xa43164ab74a4ca2f,
+                0x33d24e39df684296,
+                0xd07d52b957e333b3,
+                0xc4b13d2c091edf30,
+                0x558abb7803be972c,
+                0xbbfda7b483be2ff7,
+                0x273d1feca9683357,
+                0x5e88751acc158abc,
+                0x74f6ed4d11cfe9f4,
+                0xd4a58c286eed0e2d,
+                0xd88c9f7a3d3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5038e5992860ba9,
+                0x456504d6000d2726,
+                0xe170929e1e657653,
+                0xf90200b033cb6f4c,
+                0xb5eb252be5078a98,
+                0x6ba83739df8b87b6,
+                0x7b249c3ff444b7b7,
+                0x8236638b4563787b,
+                0x27c9ebc6024ac207,
+                0xabbb3d6797842cfb,
+                0xe12687248cc08909,
+                0x1c01cc8859cc6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x189ce91d1dc7637,
+                0x9485c1d1258e0b76,
+                0x16defe1d10a266ae,
+                0xeb68918efd0a3a73,
+                0x2948f8e60b3b5a48,
+                0xe5ab1fd72f895c62,
+                0xfe3acdccb73907b3,
+                0x52c4f41102501148,
+                0xcc3ef82bf0b28a8b,
+                0x2622109e6c87ebd8,
+                0x44f4b73d74112af3,
+                0xbe66b17139c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3db4c4167aad9a4,
+                0xc99ab3f17f8ac2d1,
+                0x92f88fdbb62349da,
+                0xf974dfea1a500cf2,
+                0x42863492c86c3cd5,
+                0xa174fcd8d0b6c6d4,
+                0x7746ae7978a93d62,
+                0x7ab24259e35bbb10,
+                0xf607fbf6021a72c5,
+                0x303e4f58027e4c38,
+                0x26e04855647a9401,
+                0x8e3442c9efda
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b7d9e099ecb0790,
+                0xfb6f508e81057049,
+                0x8afd5c048e026b5b,
+                0xb90a6bd97137015f,
+                0xf808dd8b984b9f3c,
+                0xc429212c8fc8646d,
+                0xfcc758068080b982,
+                0x5e614f80075d15c3,
+                0x2c9dbafab3fb23fd,
+                0xbf56989916022134,
+                0xb3ab3ec208d39b6f,
+                0x14e0865c06cf0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x867e65d47db9095b,
+                0x8c037290d119d93a,
+                0x47f6a671f5834dbf,
+                0xa8a4c5c935a33dd9,
+                0xfe5cc36c2dbbd0aa,
+                0xf6817bd75682d3d2,
+                0x6d48dec19eaf15c5,
+                0x2cd8fe79abd14375,
+                0x3b3f82c02b6f833a,
+                0x32dd2283958f131c,
+                0x445d978705126a11,
+                0x12d086315795b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3591780e849b351d,
+                0x603f86bd16812201,
+                0x70d09da0ef990223,
+                0x94365b622c236a31,
+                0x38e361510bbd0d81,
+                0x6f79d76fabb8d5ff,
+                0x614c297036f66157,
+                0x9825377c31b5b2ae,
+                0xb14f06e5938e85f0,
+                0xbe3c5007849f4e28,
+                0x8bc61716885f8699,
+                0xac02009b04ff
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb7d860d8b22ac5f5,
+                0xe178fd1dd590e5b1,
+                0x24438a7ba6c474df,
+                0xd10a92039080416a,
+                0x24fd517e359d148,
+                0xb91450cb6742a79,
+                0x2b2203e23a3eadca,
+                0x5017b7572a67125b,
+                0x2673249efccfb31f,
+                0xd43be70d3ff7877d,
+                0xc6375da417952af5,
+                0x12d76224d4c61
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb727863064563470,
+                0xae49e1d392ce7cb9,
+                0xc62cda0076ccfc07,
+                0x12502024a559d7a3,
+                0xe00a955720f37654,
+                0x740b8c6f300aed51,
+                0x3a801591ac3b2ac5,
+                0x229b4eb301cc40f9,
+                0xa0cd4606d19299ee,
+                0xfba6713cb708bdfd,
+                0x927882d938cffd86,
+                0x12176d817ea46
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x192c8c4ea384b021,
+                0xe147a1b9f773601e,
+                0x9415b84e9eacac92,
+                0x8bb6a46123a396c3,
+                0xd7eef0ab09c5471b,
+                0xcfec636abeda66a5,
+                0xe235729e9297da72,
+                0xc830ea2e41474d41,
+                0x3fa7b2fb185710e2,
+                0xc4f443d3e79b47d9,
+                0x4833355043475e80,
+                0x9189b4890ce1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x23421b573e94ec0a,
+                0x825d134005b6502e,
+                0x610a234f1e182420,
+                0x23cf476fe052340d,
+                0x132869eb9995d1eb,
+                0x38bd756465c74f44,
+                0xb219fe7299bcded5,
+                0x5d15be9516df2f32,
+                0xf213c42b89ebeffb,
+                0x5592dd08e1ab2c97,
+                0xd44ee3cd1926874a,
+                0x15a8e847981c4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x12da93045d418db2,
+                0x903de70fd8efd94e,
+                0x99e9ad59a10b689a,
+                0xa651b310d891257b,
+                0x8d697f5d9dcced42,
+                0xbf85da18124efedd,
+                0x169182dfc28a10e1,
+                0xa89692551bf2cbf,
+                0xf3c249cb33fb3e29,
+                0x9c444fb938ff9f47,
+                0x4807e950b684001d,
+                0x1b708c1d3ca7b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x826681d0de7ae14,
+                0x45d13241c2b982cb,
+                0xc331cd26b9928b62,
+                0x694a3ffbecdf9caa,
+                0xb99fb9b67222c190,
+                0xd61af94e04eb20c3,
+                0xd5881f89bf43f4a1,
+                0x4602e97e4c87aaaf,
+                0xd33e21bc5e23b2dc,
+                0x185ffcf29d195630,
+                0xbfa68626d2c72d9c,
+                0xeb5c8a000486
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x167cdfc06b957fe8,
+                0xb3e1a0831cf34a24,
+                0x561f3bbb9a74d36c,
+                0x4d74562043e26ea6,
+                0x574153b283b6b028,
+                0x796dd1f7e7962767,
+                0x2bad990a55968298,
+                0x8e79ef4502127c89,
+                0xe42d13fdd0d5493a,
+                0x1eb247d38f021081,
+                0x2cf5cda4a1f1f8d8,
+                0xd3ca4b002882
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd760f8bb703260ad,
+                0xc0b9b510e26fb471,
+                0xb883c4de2d2eb792,
+                0xe60531be7ce5bb4a,
+                0x9f25e2520f533f30,
+                0x6d53e37794c107d5,
+                0x62991bfd85081692,
+                0x87b31b6d76058fe5,
+                0x87c26038db223245,
+                0x3f389dfe000d02dc,
+                0x9124ed2c57aeaa7d,
+                0x191c2d5e09741
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xddd81fdf20cba731,
+                0x67fa6b4b115cf8e8,
+                0x3543c1b9284635ee,
+                0x3285300f5e4c20ac,
+                0x2870cb3e735e9354,
+                0x3b14badf15ed4268,
+                0xb0552b11fc3eaaa9,
+                0xc4c25f6e824686dd,
+                0x773d23415ae5bfb9,
+                0xed4442b2d1435bb5,
+                0xf2428638f29e3c56,
+                0x1b26ea0989920
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cd1eb0163de3f23,
+                0x96955003b42d099e,
+                0x5ada34160fac91a6,
+                0x55492aba0f6f0ad6,
+                0xc2af473e3d68bd9b,
+                0x4b92cc30c9baed54,
+                0x9ee1484f9945fd36,
+                0x8eb587e4c0ebb231,
+                0x33618c4c71c17a18,
+                0x9aba1cb9bd7d8b9c,
+                0x362a2d3aa03a8d83,
+                0x17c9b440b6c1c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x66595e90e7a50b3d,
+                0xc5a89901d70560df,
+                0xd2bc24b9ac3fc2aa,
+                0xc7d114dd20b45c46,
+                0x2ce14f8d37cac0e3,
+                0xc74a57eb58cdd375,
+                0x3125f32a9ab04cf3,
+                0xc7233c6d419db560,
+                0x3940ce6cb67bbd89,
+                0xcc185d768089788c,
+                0x5280eb8869c57cfb,
+                0xdb7cf0dfc592
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x72d0154ee2babadf,
+                0x9ef0a821d727b09,
+                0x95b768db57fe3333,
+                0xb9faf54ef9024f1,
+                0x912ead3c1a5d8929,
+                0x6b24501147ca6f50,
+                0x51756bc44f3e3b23,
+                0x2fe8a392874dedc4,
+                0xfc158d26fcfb823c,
+                0x74621cd06b9e1d18,
+                0xe4b393ad158dd0d3,
+                0x4231fafefad0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f4045d064764ac2,
+                0xabc36fb92150320f,
+                0x635c4763faccf1d,
+                0x5bdb0cc53abe3ca3,
+                0xb87049033d502fe6,
+                0xbd5ce3806fb8377b,
+                0x7992ebc6bd5cf641,
+                0xdfd709d9bede43db,
+                0xaaa8665f437b19e8,
+                0x9f280016badaf05c,
+                0xb01653957800d62a,
+                0x10743b23f9390
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4949b7ff5e7786d3,
+                0x9a2b6a32255f5c7e,
+                0x6c2c49cf1d04671c,
+                0xcd84d190bb16cb93,
+                0xa0da2fda86d83f53,
+                0x48be0a9c2bb985b4,
+                0x33768c775601ffa0,
+                0xf99c515ce25a64b3,
+                0x281baea96947f614,
+                0xe986f4538faa8943,
+                0x41615eae2096cee1,
+                0x11c7d6f85c5f8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1d5841d1b6d116e2,
+                0x4d1f845bd34bdbca,
+                0x496c9fe82bed5f64,
+                0xae7eb93bfe257fd2,
+                0xb2f9ef03370b69fa,
+                0xa587c5380d3bcef6,
+                0x965cb52099de1c78,
+                0xf2af1afd871c9574,
+                0x50c689051efebea2,
+                0x17a3bd61c988ad76,
+                0xdf69e21d8aa53356,
+                0xf4a291bbb9e8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7a2edc30118a5481,
+                0x8f8b25ce9c6e7f47,
+                0xc4868c1794f2a29b,
+                0xf0d55a4c0f8c457b,
+                0xd04fb9c5a282158a,
+                0xf957d89b215c2f0,
+                0xdfe94e3430554390,
+                0x64687251f819dca5,
+                0x708df51747412cd,
+                0x83f98165288162ac,
+                0xc313cbd07ee6228b,
+                0xc1fa16551e7d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa419501ef7c15fbf,
+                0x93fc7ba4163d537d,
+                0xa06c2d6053b142a3,
+                0xc0ab714b37d8cbfd,
+                0x782592bad704cceb,
+                0x83a3efeacf0b8d58,
+                0xa184bf5108e1bb2a,
+                0x45e2bc3008523ab8,
+                0xd5714339e3fcf862,
+                0x29988f97df57fdd3,
+                0x843726f1c66c4578,
+                0x81c2080b4b0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xddd35cbee3e30675,
+                0x3a4c6e6463071f57,
+                0xd4885aee8ab3baf8,
+                0xdc55c1dce6d4574b,
+                0xdc037f70f8d35257,
+                0x59537a1541f9d07a,
+                0x50ca421f9946b8a0,
+                0xfb51f8b6a97bbd78,
+                0x85d0b59a179bd446,
+                0x4ac037358185989f,
+                0x79270a196c91375a,
+                0x74380f500315
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x24a725be1a19eb95,
+                0x11c8ddf7651c1fa5,
+                0x44e33c82dbbedfef,
+                0xe782227b09f7418e,
+                0xe72bfe6d458d2151,
+                0x8476272560cea529,
+                0xd7d930a0b8499579,
+                0x3c47f7e737e202f8,
+                0x76554270bc439b63,
+                0xe5206b5617d1c228,
+                0xec47e6a675375ff9,
+                0x147af6c36559e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x448d68d2fb0db3e8,
+                0xc9bbd0ea98b5b03b,
+                0xcfd0b63abee9461,
+                0xe276898fb7ec1dd6,
+                0xc5bf442a6de70693,
+                0xaeb394b878198a38,
+                0xd7efa24bb3437ab6,
+                0x78755f849ac7d08a,
+                0x4bf765a470d3d6dc,
+                0xa3d52d7538327118,
+                0x7f168ba3e609b5ff,
+                0x13c238f5eff52
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x62d2a7507112a04,
+                0x78971b380bc8043,
+                0xfc6edde40044daf5,
+                0x68fc6e1c4cb999e5,
+                0x53c88fb0912e8e8,
+                0x4ee663efd72d7f0d,
+                0x336ded20904c9be3,
+                0xcfe1eac92b518c3f,
+                0xaefdc81194c7593d,
+                0x67d7d5918041f101,
+                0x2f4f469c7486bd30,
+                0xab4e4cd2437c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x960e41bb7d74dded,
+                0x7f76f9039574ffcb,
+                0xfbd816d5beb42561,
+                0x5dec7c96de7a053c,
+                0xee2a46701e35ee38,
+                0x79ac2b2e4c94c2fe,
+                0x604b9a2cbd8be28d,
+                0xc6c3bc8438866adc,
+                0x763f8bbc67822279,
+                0x93691867a9536113,
+                0xd32402119368e3a9,
+                0xac46cb90b31a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9c0f3bae925d60f4,
+                0x28da30455e0ab0d1,
+                0x2cb57b54b4393003,
+                0x68d551e25b10b386,
+                0x48eda6c5d663c854,
+                0x689f33b943a52ef1,
+                0x931fe3a8a69d4546,
+                0x8fbc24913ac2960f,
+                0xf460964e4b66c1f,
+                0x133475917f34290b,
+                0x300b0db208bdcf95,
+                0x212cf1673ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x21757af521e30124,
+                0xce3d8f3f67ee2539,
+                0x99ff3ca6ef0d0db2,
+                0xc6a820bffe1f6c59,
+                0xde7bc1fbb0c8d434,
+                0x43d79e54bf2e3fbb,
+                0xa7089f59c7287227,
+                0xaaaf0950be18d89e,
+                0xceacd99c86532e57,
+                0x2a797b774eafa252,
+                0x50c444900ab03b83,
+                0x98e21cb5eed
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7378a4555cb7355d,
+                0x827c85e963622006,
+                0x8a0ff1e9ce7ea0e6,
+                0xa6d1a86a8df46fb5,
+                0x9810b79980d0da24,
+                0xa810dabc68d0422,
+                0x8b5f121c73f7c349,
+                0xe55f3567a43c54d6,
+                0xb61a5f9a524d3cd6,
+                0x1709e8f0c948276e,
+                0x192e100868f34540,
+                0xf22f8527fecc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdfb357c18d4beb35,
+                0xffbee53b15390a95,
+                0x68be7b6aab6a92af,
+                0x6eb083b473072b25,
+                0xc02edf70bebc8d40,
+                0x7edd22c86a0fc111,
+                0x8aef390d79431bf3,
+                0x5ca41c42c9735ac1,
+                0xb436c430fbf603aa,
+                0x9ef9c1fdfbd33c42,
+                0x5b5064f3d817abd1,
+                0xddbeaa5fb0a0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3cb094a60b9dc84,
+                0xfb29127800821232,
+                0x85336bcf41703311,
+                0x7d1f59610b2ed3a9,
+                0x1b5de06c3367a91d,
+                0x9624e6c5138c6355,
+                0x9927efbd3327dcaf,
+                0x59815265845c685c,
+                0x3075a23588dc1250,
+                0xb4620a75465cea9d,
+                0x89b5e378bac40c5a,
+                0xd6b85668a482
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3773131c11cba6d0,
+                0xe3ab23ff66b3ce45,
+                0xde9e72280bb134a6,
+                0x2e1bcfbbacb5f280,
+                0x778b1e4829a93eca,
+                0x2cb0d343b01d6866,
+                0x251a8fd044eae7d3,
+                0x25203ccbaec40697,
+                0x784d7831887d8f78,
+                0xa50b133847c7c8e4,
+                0x729108e00ee478d6,
+                0x1237725614f19
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd26f7b429cab50d0,
+                0x5636184000864dfd,
+                0x6e0f74eab84755aa,
+                0x27ca34f2f6f1c33f,
+                0x9bd8a9f8dbba585b,
+                0xb1dcecc630000db6,
+                0x5b3384b689fa981b,
+                0x77a67f81c473556,
+                0x4eb2b42e2bffc6dc,
+                0x87b6baa2d9f1cee4,
+                0xc77398ee398b698d,
+                0x128f5822ca403
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x935db1db8226bec3,
+                0x94a6f7e3c673c0a,
+                0x27378b8c4e1a3b90,
+                0xfd4104ff0eb06fd0,
+                0xe82bed636ec5c06e,
+                0x93c4bff02968fca4,
+                0x8cb77d38583203b5,
+                0x8c0b37aa7ce94a28,
+                0xee470fc32a00d9fc,
+                0x99955132b97b7a2,
+                0x4a5cd28c98a08e9b,
+                0x1a04b31b4db56
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x87449b9f330edd7a,
+                0xc1eaf7f3bea2bb1,
+                0x193b3dd51d62d108,
+                0x772586a128a7ab9f,
+                0x91487c1aa492ab62,
+                0x7cb0cda09577ba97,
+                0x18f33a39f054a9ac,
+                0x9e065c8fc313a6f9,
+                0x31fb19e93a8d6172,
+                0x72913a9b7acf0c92,
+                0x41826839eeb10266,
+                0x3bf6d16ecc93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x655c68826b9d0c48,
+                0x76cafe084a032fc1,
+                0x7c5dccc556e1b483,
+                0x7551105906bfe35f,
+                0x6e71b7c0dff70826,
+                0xde14689f5cc0ea6c,
+                0xfc69daf1f03e9c9e,
+                0x94701df4f4e1b03c,
+                0x150b4f129b821643,
+                0x68187deb797de11c,
+                0x23ee21e2a25a0698,
+                0x728cf8599867
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbebe376d45e7036b,
+                0x50113a3753d8c123,
+                0x31ddbd59702208ec,
+                0x3162721736a14139,
+                0xbed8443ac8c55c0,
+                0x78b532a473b535d3,
+                0x56591ae2ab9a6a3b,
+                0x3d7549f6bad8d00e,
+                0xb54f7f18c359fa72,
+                0x5139a3273d4f7777,
+                0xc8f9b35142e561e8,
+                0x22f67c70b996
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x887f67db5867eeb6,
+                0xbbc1a509749e3801,
+                0x4134a9f3439c20d5,
+                0xf9b58cf9117649b3,
+                0xec00c19f715f0e2c,
+                0xa6c9f8db8e0b9844,
+                0x7e45068058a6c468,
+                0xbd1f5bf858b8121,
+                0xb9978e60431583b7,
+                0xa0235bdbc9ce164f,
+                0x479478c8b8f94e65,
+                0xf15ea19d01bd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe0f65f2d8035ad6,
+                0xb60ced01a3f4d1d7,
+                0x181568e11592963b,
+                0x55172e4c5a405f53,
+                0x6362ef762fe338b3,
+                0xe6d889d41d4e3c6,
+                0xb47682a511f4c573,
+                0x77466e7a7f2030fc,
+                0x680bc5c5000f7001,
+                0x7ba42c6a59cd0e25,
+                0x4b8c8e1bd4faae7,
+                0x829b8b920b28
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa62e2ecaf888c7c6,
+                0x29247c437c2c93b6,
+                0x4e08339a71630118,
+                0xafd59e91126aa7cd,
+                0x7ba6c6f36de99007,
+                0xa99a63e8d88dd678,
+                0xa1e2e3c6c125abc9,
+                0xdbd0ec8bcd7896b2,
+                0xd12f96c0f2e86b0b,
+                0x2be0db30c4bed7a3,
+                0x6dd94937d5d10146,
+                0x15dbccd862e4d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc6213d396ededb60,
+                0xc1da3a847b204a4,
+                0xc58f8b0846d8f6a1,
+                0xa5ba9dfd62fe3892,
+                0x7c2d85a6184258dc,
+                0x2971ddac1f2c4758,
+                0x412d689391877004,
+                0xc18debe47c073,
+                0x9fce05d17cd4d90,
+                0x74cfe45efa18f7f,
+                0x72377f56d3468061,
+                0x12fee76f40996
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0f87dd748c6c68d,
+                0xea6770e14daee737,
+                0x2a5c108213a71e97,
+                0x629e981467ad870b,
+                0x57a2e804fb43b532,
+                0x1d4d75babe5b7233,
+                0xa6f3c1249d507c33,
+                0xd6fb6d46a35077f4,
+                0x19eef5e60778f520,
+                0x9a222765567a02bc,
+                0x5c01b7a225dc1632,
+                0xcdc519e5100d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x71d1408ac1fb287,
+                0x55c215f54cd54801,
+                0x3752bd92b88fd623,
+                0x41c71ea8221543c0,
+                0x82111179ca89bbec,
+                0xdb5a0122cb80a284,
+                0xaa9d2a2adfcf2eae,
+                0x7a778d69d111111e,
+                0xa574756b1b867470,
+                0x3be4675461302598,
+                0xf20e39aa2bff2594,
+                0x9a5f9560d6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x620a2a9d35aac48e,
+                0x1b899127e0972e59,
+                0xd5ba56c7d4c40c75,
+                0x7215d4f6e9a7b73b,
+                0x2f5dfc2765387b37,
+                0x5918f905dbca8da0,
+                0xd8d66758c1635ddc,
+                0xf048a1095c1fdb4a,
+                0xf1ce9f9ca5b03624,
+                0x9025cb8e92f7eb03,
+                0xf6e6f4dbf296d07b,
+                0xcb045eb34520
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x25ce6df05b8cec53,
+                0x4f5475335745de1c,
+                0x6fe010c19d8b1030,
+                0x1c6dc8620222e21,
+                0xf66e3ce93f2c4e04,
+                0x5a88cc92c2bc8e2,
+                0x87522214f00c3be,
+                0xdeb1660357da6d8e,
+                0xa7dc209fcdd603c8,
+                0x462ca1500d9d6192,
+                0xd2fa243775c520f6,
+                0x13fb0b8fe5ef7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbf8c6527c21369e1,
+                0x8f023b567183d8cb,
+                0x1757b3e9b8a29209,
+                0xe5b2f24c8f7ae67d,
+                0x90be7074848b6ac8,
+                0x455e691262608ce6,
+                0xa1f22bfeec8f14f6,
+                0x5480b7c6ee3a8d9f,
+                0x820f7a37325411b8,
+                0x76bd74a49609e7c1,
+                0xe5d2ca4c03fb99fd,
+                0x1702cbea65eca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5bfbff17c0406d20,
+                0x2a7161e11c977c9b,
+                0x539a408facb14269,
+                0x52a06e6253a7a44,
+                0xe8c9e99587e49318,
+                0xd0602e5ad13e0ca2,
+                0x2b3d6a996a3a99b2,
+                0x437f9334fce8519c,
+                0xd0c0a41ac505f55e,
+                0x70a23a5e4f94b28b,
+                0xa3e8a8b095a080cf,
+                0x7a76b444ddca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5a420c5d167aa051,
+                0x89bebfab565b9427,
+                0x8caf0b895015b17,
+                0x3e574bc40c92e5b3,
+                0x531ddc88f65a153a,
+                0xf017c117300f6611,
+                0xeb62a3acc2b2c07f,
+                0xf35a34945f491301,
+                0xed7493a30f0ba027,
+                0xbe338a4e55daead1,
+                0xaec05b89d1ba8fff,
+                0x157a435c1ce14
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc3512a4087a805,
+                0x851c704eeca8fdd8,
+                0x3052cefec5178d63,
+                0xc7adf4d735d8a4e,
+                0xfc531b4fc9cad5ff,
+                0xd639b8e00927a951,
+                0xb46784a256ceb47a,
+                0x12615b9044d0e44b,
+                0x6cfe852bdfbbc154,
+                0xffbd2ad70aef99d3,
+                0xb37c86ea8f11a5fa,
+                0x869308c2d2ea
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcfcc511429e074ec,
+                0x5a703736504b2e53,
+                0x78324fc5ff598609,
+                0x629ca007d9948880,
+                0x69e2a69b990d6e47,
+                0x5f4fb00a6c14a68b,
+                0x16001950004a07b2,
+                0xf5b9e1c37f79da43,
+                0x9abb9f4ebf27156f,
+                0x80b80347ed70d77a,
+                0x8e7f8941c62a445e,
+                0xdef166bb7804
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1478645c9c6fd4a6,
+                0x149df5b8e7300d85,
+                0xc26bc645a7d929e8,
+                0xef4052785f9c4982,
+                0xf830d3f38c4b71b6,
+                0x9bccc48ec0d65c17,
+                0x4c5a19ab7a3a61dd,
+                0x4a34d11ae7f1cb5,
+                0x3cf9618171bdd3f1,
+                0x74bd8ad6f10db614,
+                0xc930f303484cfda9,
+                0x1548bc20b77fc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x838962879bde562e,
+                0xe73cb02a91310cb5,
+                0x617c94c89430be83,
+                0xf04e7e5811b0ce86,
+                0x1295ae3e39edcefd,
+                0x2f77342859f3fcd2,
+                0x791d99d231c78324,
+                0xb25657d4483b05d6,
+                0x8ac55bf8436cadd9,
+                0x1722c04ea7a67772,
+                0x6413b16a04011b5a,
+                0x13c5ba1257a0e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf217bfc8c9ac4bde,
+                0xa4323842fc857533,
+                0x1b62c1843e36ec61,
+                0xf5df9f44dd6835c6,
+                0xe3b1561f1ecbe0ec,
+                0x79022ae6a669aafc,
+                0x738bb9f213fe0dda,
+                0xff59b83bbd852c34,
+                0x45134746be699ace,
+                0x42a7cca80605ff69,
+                0xd700c8fa38cb8f30,
+                0x1b06738477424
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb42c6a93689fbab0,
+                0x4c78baf705d74e8b,
+                0xfd62af5b2090d4b5,
+                0x17c9a69bf33ff27e,
+                0xf986e13c199d22cf,
+                0x44412f4956bac691,
+                0x39bb68ea4b638411,
+                0xecaf910b4ca41ee9,
+                0x46c7359fe36ac36a,
+                0x36095dd584feb896,
+                0x189d28e645fb8ee3,
+                0x167c3288d4245
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x90bb1bef8beae769,
+                0xbfdc06385a4e9064,
+                0x860815270b823cab,
+                0x74caaf55692643fd,
+                0xd9db136b7e3f35ee,
+                0x81b4dd4bd84f5c79,
+                0xb7fbd5fb48c2aeb5,
+                0xf209957f7c908b08,
+                0xca34d2f9d0222b2b,
+                0xf6ca26a4901f7e9c,
+                0xecd66b88831c3740,
+                0x44dea08a2548
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ab54d6eab392284,
+                0xcccdfc5657466876,
+                0x1f1bc1e9320aee93,
+                0x7fa514e38f62f5a2,
+                0x1ba66f7605f19c3d,
+                0x410d88c8fd5245b1,
+                0x6d086763b536a941,
+                0xb51c02a5b97c9e79,
+                0x1f2859677a05c81f,
+                0xd78fc58194d7fe71,
+                0xe15d468f7a1e5717,
+                0xed3c584b4ec9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe555632e79224a86,
+                0xb16cf4d15dd5331d,
+                0x84b3a22a1d45453e,
+                0xaa2d4ce9e8b8f6e0,
+                0x1d4d0c08274e10e6,
+                0x140975b3c61162b4,
+                0x893bad03ce1b4757,
+                0xa7d95c59fdd34848,
+                0xb6916ed5692a0d63,
+                0x4eb024c870533029,
+                0xd424e783f54392ef,
+                0x160bc332c4195
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x309042dbe12d0dd,
+                0x20f565e9e09d5a7f,
+                0x6836a92627229eb8,
+                0x19fc08e7a069bbca,
+                0x70e2bd83d489aa77,
+                0x1b3e804351ec1f50,
+                0xab31ac9a726525b5,
+                0x456fc0ee75499298,
+                0x3a37e52c80594a28,
+                0x1c840ada7de47cc5,
+                0xa69c95be7fe1379b,
+                0x7814c334bdb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6bff7ebe9a43293f,
+                0x252ee3a5b737e897,
+                0x378f2146079ec459,
+                0xa12571f2dfb35c7,
+                0xf300fdc63983596e,
+                0x7dc6072d33e2c153,
+                0x4c3e84745267af16,
+                0x7e5e56fcbfbe641,
+                0x2dc6ea5f90a517a7,
+                0xf1ec5c99493ea1d1,
+                0xe53d1c94c93aea3e,
+                0x1c48bdbb5f871
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6df3e9445a3a3056,
+                0x118591369bec09b2,
+                0xf59126d3df6b79dc,
+                0xe413b64120535edf,
+                0x329f5e6bd0ada1ae,
+                0x59a03665888f09cf,
+                0x7cc43f1019768723,
+                0x64c476c9a2a38069,
+                0x904d8661141a7a53,
+                0x4b2c93bf6e45fa6,
+                0x52a5f4ad5757ae02,
+                0x5aaece0e412e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcf23bc0e03d9771a,
+                0xc053de099348ab98,
+                0xebdd0bd7d9d80e03,
+                0x42b06dc30876ecfd,
+                0x104a5043bb25e4bd,
+                0xb2480930eeff0907,
+                0x8ea849a93dbd8020,
+                0xaa56aa901674ddaf,
+                0x1d51e4ab1d5db0d1,
+                0x7b7a738da72fec3f,
+                0x3714aa25c5cb9d46,
+                0x1c09e1cfd6b1f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5704dfdeb2c7308,
+                0x2c3406c3221e3cef,
+                0xf41d0ec4aa5a95b7,
+                0x1fa98ffbc4e31cb9,
+                0x54979e4324f4973c,
+                0xdc43c18ee25da739,
+                0x125d62ae0dd5f51c,
+                0x659a87ba84948f30,
+                0x5dcd58dfe48cb612,
+                0xa18301ca0d129eee,
+                0x1c8553b00ec4c8ff,
+                0x69878cfd3b34
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb392d960dca134d3,
+                0x91bc7c94a462d2b4,
+                0xfe385fba795c914b,
+                0xe6fb5b562f6e77bf,
+                0xe5c7eb28a97ca165,
+                0xf684c47d7df6d6a,
+                0x379917efa98e0732,
+                0xecf9be1059b06a78,
+                0x5892694b990be634,
+                0xe98af05d824f9692,
+                0x19301f070e0def26,
+                0xa9f2850ec366
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xec6d69c7dff26925,
+                0x7d541b0cdb598641,
+                0xd37e503800ea6e27,
+                0xf6805fa231af62aa,
+                0xd4b193e4fa5ff494,
+                0xa656247b8880b17f,
+                0x9aa5bf1219bc6313,
+                0x65cc6a049aea59b,
+                0xd8bd40679ef81826,
+                0x260139992cab577,
+                0x50a7798c31b01fe6,
+                0x7305f92cd846
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd7f5f7f6d7cc60f9,
+                0x1d7b861d5193b7ee,
+                0x48ea423e11d1b0cb,
+                0x620a466f80eb328b,
+                0xaf19d55ac10fb726,
+                0x92dbacb692d76cf5,
+                0x9cbdb2ef878b683e,
+                0xe575210145ef731,
+                0x7b279dc1a321fb05,
+                0xfd9dcbcd32c0757a,
+                0x81389616259153fd,
+                0x16990bde8e35d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a965236095150df,
+                0x5e5a7d8e68d58d,
+                0xc61b70c573a897ae,
+                0xdfb5a66385dd25a1,
+                0x2d9cacb160c6d5f8,
+                0xcfc64dfd2eeab0a2,
+                0x872d799ab955f30c,
+                0xd59e41fa820df83d,
+                0x93b04348622406ab,
+                0x40a75b9c8855b15,
+                0x507382d7caa8635f,
+                0x829c67d96837
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbb530f30abd48694,
+                0xf40bff1232c3e7f6,
+                0x630bd9d4a616525e,
+                0x4a1e75ecda76a6ae,
+                0xe35df101e281b6c1,
+                0xdc2ebc11f6e682c0,
+                0x3ce6b115c2b7531c,
+                0x69264cbe8dacc0be,
+                0x19b2198b3dbf2eeb,
+                0x41cf072b5825ab45,
+                0xc9d683878d1fee3f,
+                0x1740782590954
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x63a29608a07f4484,
+                0xaf182f631af5ac1e,
+                0x9fda6fb86cea2e02,
+                0x1f5d991ea6f60a5,
+                0xfeafbc3526fa2801,
+                0x457e5eb599fcf7f7,
+                0x35e25968660e558d,
+                0xbc86d3905df277f,
+                0xecf546b2564171f,
+                0x7cb74072dcecdce4,
+                0xe617b3e9e1095045,
+                0xf4a615803d98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfb862202f51f646a,
+                0xc66fb3603ad5f18d,
+                0x7a0282db8f0d81f6,
+                0x4395a651920410c,
+                0x8698535f14291801,
+                0x6d91cff2b46dd31f,
+                0x33bf8fb1a23a9a53,
+                0xa9a8887adb3092b4,
+                0x235953c4552d6517,
+                0xd579e2522569b4a,
+                0x350f63404dbb4141,
+                0x13cdc5fdc58d7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9f4630583b0b10c3,
+                0xe5ed0617e77e90fd,
+                0x652721936a6d7379,
+                0x28aba30eb94d0752,
+                0xbd5ca02faa1db231,
+                0xf1bfc38478b52d68,
+                0xe3b1cf4ab51f010f,
+                0x77e211e29a0187e5,
+                0x531a4edc881a10f5,
+                0x442ed4494ba47c20,
+                0x9dbb8bb51aaf64af,
+                0x19457220b0820
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x27fe530e331d0f59,
+                0x6671c0217a83fb75,
+                0x83265e15cb603393,
+                0xf93e8c45cacfe835,
+                0x80b265402ec266a5,
+                0xb71408e9591546c7,
+                0xa81a35348d3be50,
+                0x325ea864654b608b,
+                0x3df8c37522c9406c,
+                0x88dcae429cf66f5c,
+                0x3e23df4560817270,
+                0xe376612d96c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd39d8c471acb546a,
+                0x20611d3184b19493,
+                0x36c760abea0b28bb,
+                0xd13deb3131818af1,
+                0x6efbd65839ca734f,
+                0xba2df532a181a3da,
+                0x7b1457bb3a8f287d,
+                0x2ab42fd5dbf766cb,
+                0x9fe66f3f2b59b057,
+                0xdfdaf92e64388b81,
+                0x9fe6b5e8534da288,
+                0xf69b842c0fae
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf25b83ee33b9eebb,
+                0xad4253d15927520,
+                0xc622ac6866b553e7,
+                0x98b388a365995af2,
+                0xa5a7f65fa8e552fb,
+                0x690a68815cdc80f5,
+                0xda455f74965935f6,
+                0xa71bd03a67cdfdb7,
+                0x1ce28b66c9e853bf,
+                0x799baee8586aacb6,
+                0x894c9957775a5845,
+                0x11711633fd2aa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6a01fb7b3d42097c,
+                0x63a10cf5ec747183,
+                0xdf46f76e40306ab7,
+                0xa835acad71b446bf,
+                0x95397110d11bb582,
+                0x8858c1a886681d8c,
+                0x2c35bc35b9964353,
+                0xeae0afe2f0042cb1,
+                0xdf84309c72104ca2,
+                0xddb8b3bdc2873365,
+                0xa94033cecf593df8,
+                0x193ba60a040bb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x13681dc5f49658de,
+                0x84fc03bad7fcb70,
+                0xf9e680c695708ab3,
+                0x21571a6631cd0cdb,
+                0xff6503aa3796a8a9,
+                0x2b1146ee76b3c41f,
+                0x10ee8cc118aa0301,
+                0x458b2617e7e891ae,
+                0xdba80612999bb726,
+                0x5a7539f39175c603,
+                0xb05eef23580d3e7e,
+                0xed46eef3903c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5376c96ff2242ede,
+                0x74a128185f5ad92a,
+                0x54797854cb10cf74,
+                0xa47febaca6234e37,
+                0xe442879eb4118863,
+                0x462f11571d15fbf7,
+                0xcf1270aa2f8d09f8,
+                0x8ab4dfe3c291f302,
+                0x2640c7a355e00182,
+                0xde801df012685d15,
+                0xbd746c29e3d6d274,
+                0x1a869c9681b5a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8802ff575b05cd74,
+                0xe95299b43e61f27b,
+                0xcafd5630d921214b,
+                0x4d311718eb8ada84,
+                0x894692033d0d4f37,
+                0xc0da5cd3a80a8e9a,
+                0x40add6f939964a0,
+                0x3074909f8c84e39e,
+                0x896f4835f69d5194,
+                0xd502d354fa7f6ebf,
+                0x2f83310e239d55fc,
+                0x14e34ba4c6f78
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x266fb4edece5e2d4,
+                0x88f20c97ff643888,
+                0xbeae47b5efee7946,
+                0x3049a7373c7c18c2,
+                0x6f902346e3ce4c84,
+                0x771f982929ae60b5,
+                0xf1979f269642fd52,
+                0x1f156123c285f074,
+                0x60508a4b109a218,
+                0xede79bda950a1e1f,
+                0x617af4b7ebcb705b,
+                0x338c58aa4918
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbe03bb5ea57687da,
+                0x988144040b59b93e,
+                0x2a9c51658e7af099,
+                0x545f7f8668cfcebe,
+                0x6575d2229ae91e0f,
+                0x3d8cb256ff4f4e26,
+                0x9e06846f7a1dffcb,
+                0x25afc8c66ff99670,
+                0x3645c94ad2edbae8,
+                0x8ffc789f8f6eab9c,
+                0x3f5617f148909312,
+                0x59e9eb114a2c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda1f6819a724dae6,
+                0x436c246fed49e225,
+                0x310c207dc547e6c6,
+                0xd14fc7e553a57dc,
+                0xa8911886a1669f68,
+                0xbd90f775fc4b699f,
+                0xe35b0f5b44b26b82,
+                0xd3b99f636bddf9ab,
+                0xf6a493a8ca3c997,
+                0x23d4b8c52030b4f0,
+                0x4e5dcea3c9547256,
+                0x9126a286968d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2cad3ee418defdb8,
+                0x6fbd7c676dff098d,
+                0x1b8cf84f42d60e74,
+                0xa3957afb4a5c4fa3,
+                0xf7fe89ade109da7,
+                0x40bce4cc09ed0bda,
+                0x5ec8b3ce8a690fc6,
+                0x4feb29f25fef30b6,
+                0xd90f5169c0309725,
+                0xeeeefcc12df499e6,
+                0xf95e3ab924923ad5,
+                0xc7126c94fa07
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x59d819c7012a58bd,
+                0x8587c714f684cd7f,
+                0x11b7245d63687be5,
+                0xf74558fe7e3ecb5c,
+                0x6c663a68cda4005b,
+                0xf35eb9bcb64de4f4,
+                0x33703ba7c771745e,
+                0x4bcb1ef8e4f8a2ac,
+                0x18a58b6361daf724,
+                0xbb9e65d4035ba614,
+                0xbf361fcb94bb427c,
+                0xec4601077990
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b83fb205fc47e40,
+                0x2cd38352a6ddb23c,
+                0x7f90fff543f3ae5a,
+                0xbdcc4f893b023af9,
+                0xf15d555a93dd4012,
+                0xd26779656fbac648,
+                0x527568816ec9dbf3,
+                0x3b4139b6cb99575b,
+                0x6328162128c676f4,
+                0x5f559631ef11c9d0,
+                0x7529708b1690699e,
+                0x1a5fdde12b607
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed33255ef3c1ffb2,
+                0x9b56aa6f054a97dc,
+                0xb45aac51fa9f477a,
+                0xb431b88271a21f39,
+                0x7400a5ac5128e34b,
+                0x6434933d4cd85395,
+                0xef32e39e6ba096ab,
+                0xbf16ccb520d5536a,
+                0x881680dfca3e73ab,
+                0xec8062bca2b65790,
+                0x2739dfb9400641d,
+                0x57bfed43eb6a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2fd45ebfb3739ec2,
+                0x42fb1cc22b818ba,
+                0xa8b171f90886caa6,
+                0xd994a6b79fb5b00e,
+                0x3c5a089d2c88fb3c,
+                0x73efd88c2f992a29,
+                0xafac0940a879d21c,
+                0xa907658d74031efa,
+                0xe5ad48afe468c277,
+                0xf40cb076d6fecf21,
+                0xd4aa1d311b72b8f2,
+                0x1b08d4a7c9272
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe904122930639c5,
+                0x7d1c901a348c3692,
+                0x188e04ad5bd6e7ce,
+                0x64a9d1819718d67c,
+                0x4b01c83c5acfabed,
+                0xee37b382b2599cb5,
+                0x46c71e23400e09d3,
+                0x24fef3495d42cf72,
+                0x5796e2bd0aa3e5a1,
+                0x98d77b57c4483917,
+                0x360723cef64cb9fb,
+                0x5ec48ae2e3cb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xef0069c665c1855a,
+                0x4ed216b7241c0a0,
+                0x5bdb4aaa61e3a159,
+                0xb624888b1353e99f,
+                0x2bdaeb197540fbce,
+                0xcbf9474ccddbd151,
+                0xc010ad1d78ca8ecf,
+                0xc1b762ddbf6cdfa7,
+                0xeed3009950e9cfde,
+                0x5ff136eca4a411b1,
+                0xc8ba326b86a56311,
+                0x11664a27c6af1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3b1e0acf54b43b6,
+                0x104eab9b07d01632,
+                0xe68cf37558aabcf4,
+                0x73c660a5dce93b39,
+                0xb5a78c50f4fa9c6,
+                0xa850c299b29f6254,
+                0x2b84d744738743ea,
+                0x74c333434c164117,
+                0x3a0154bedb58618c,
+                0x54801924e8376fef,
+                0x3611e8b2e78f96b7,
+                0xa3be85ec8503
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf3122fb66dc3b9a1,
+                0x22f53323b793243d,
+                0xd2bb75a4e1b1857f,
+                0x59c55111388fe07f,
+                0xc5dd65ad327c3ad2,
+                0x547c0d16c0396671,
+                0x9a3f2ea205e8efd,
+                0xbd30c62349c0023b,
+                0x7c47dc4afe9efcb1,
+                0xbc25a3cb0a55928a,
+                0x20ed6348d22f01dd,
+                0x128a34b3290fa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9f082da49a4492b6,
+                0x65cf6bc04d8d3f1e,
+                0xc68e640ad3bf16cd,
+                0xa2fc431ab4f5541e,
+                0x9179eb811651dacf,
+                0xc09b99ef17f5f2ce,
+                0x3bc4f44b81e97b46,
+                0xd411b842a374a198,
+                0x1f1066b8f775268c,
+                0x3712c975fd825f0c,
+                0x39235d3680f0e41e,
+                0x136db44ac4781
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfa097dbcd6a3013,
+                0xf5c94fc12c6be000,
+                0x87e02613bafe5ee5,
+                0x82e92f69768c5e5d,
+                0x9218fba4d477bb20,
+                0x4c4c8bbb914d1dc2,
+                0xaa50c26fd30461d3,
+                0x9d380acf412f9326,
+                0xee3ccfd55a95c8d6,
+                0x7e0b86736a8245ee,
+                0x219b139c8f8852dc,
+                0x13d280fbd212a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x823991a081413fac,
+                0x94cbb5fa51bd8fc3,
+                0x91fa429718168e42,
+                0xd233a7ea39bfdc8f,
+                0x7bcc0b4f40f02dcc,
+                0xa3f5814fc9518009,
+                0xce55258c0948f06c,
+                0xfc5de2704ea8357a,
+                0x2ee505689866fab6,
+                0x1ab5680260b38479,
+                0x953356f2027280b,
+                0x2bc946e00a81
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc5e6a0c12fc2107,
+                0xcbcaeb93d4168cca,
+                0x852c300492056432,
+                0x435563bec077b7f,
+                0x63ebf6dc393476f5,
+                0x13a12d50622690bc,
+                0x26dd44700ffffb8c,
+                0xa82f08357d5980ef,
+                0x5d134ad3f3056265,
+                0x5062a3e74f64d17f,
+                0xd54ab02ee5aa0983,
+                0x1479397013167
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x911ac5c3612e4d3e,
+                0x30d6a9e7a07b17a2,
+                0xe4f961e06ef03d84,
+                0xb2d06b82051998f1,
+                0xda8c30954f81b007,
+                0xebd743902ab8b0a4,
+                0xa7cbc0880048d576,
+                0xea853cf321b5c850,
+                0x399812e47ecfbec1,
+                0x57f06a9df78beef8,
+                0xc133e93085d565cc,
+                0xf2fbc038de38
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x24d26f2a884ac529,
+                0x971aca523252d27e,
+                0x65d1c61e26dc4341,
+                0x86910cd613d1bd05,
+                0x91baafd5425086ab,
+                0xbc79e28d50b01255,
+                0xddfda2f2e32b6188,
+                0x36cd81210ab5ff84,
+                0x9e3fdf5cb99c2d65,
+                0xf1e42e083eb36098,
+                0x19f522cbac28f49e,
+                0x180fc95d0e15
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7be1e2ffd249c1fe,
+                0x16ccf8ea08d1a872,
+                0x18c1658b9b49c1b7,
+                0xac0349122a85a28c,
+                0xfbbe217a74328ca9,
+                0x4a8063823c4536f3,
+                0x3131d5218083ac9c,
+                0x9130b693195dd7ba,
+                0x6825f5cfdc0ae827,
+                0x1d879c02f3faabf0,
+                0xfc23ca925037f661,
+                0xee64f682e5ee
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x583efd65e6e52607,
+                0xd170fb9866ce8cea,
+                0x899c327df584c397,
+                0xb2fe13caa16fe4c1,
+                0x6b953982c90e745c,
+                0x74c0fc7133412320,
+                0xb06edf8dfcdaac99,
+                0xf645273cb94f5356,
+                0x8c24230e7ee53246,
+                0xe8c255b8d2ce824d,
+                0x11f42b804669fd3a,
+                0xd1ef26bd5b8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd12bacac30e9d6c,
+                0x1fa4fe7760f6ec2e,
+                0xd14c8e3753910ef0,
+                0x17dcc339193665ff,
+                0x30c8226c359604f8,
+                0xdc5041087af82e4c,
+                0xa077423dfd74d4b,
+                0x3707582aa374f9d7,
+                0xafdc424909949fa5,
+                0x23692a8cd709b5fc,
+                0xd6524b495e90881f,
+                0x16f2213f1b715
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x750c2a59c1c81c51,
+                0x9f6f0995567f3556,
+                0xaa21d164ffc49e07,
+                0xb825bcf2ec63d669,
+                0x8d1fca9eda6ea87f,
+                0x5af86cf317e2de43,
+                0x7235ccc2498b69ab,
+                0x381d5e47b0bfd4e3,
+                0x1bebad5208be8e77,
+                0xdfe06edb6a8414d3,
+                0x230253e0d16849c3,
+                0x620fced2020d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x68c4ec100fd09d46,
+                0x7a8bcf675205184c,
+                0x3312df85b71649aa,
+                0x93e4d8d485fb913a,
+                0xcb2b430e1d317c87,
+                0x5b89da3d0a5a41a9,
+                0x7cfa9d12598d4bab,
+                0xa4e4e6d575fe313f,
+                0x2553f4825301d6fc,
+                0x7aeab3d33627284f,
+                0x3fa3e68e7f79bed8,
+                0x1aa4babf5da83
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfc2527bbd659ffd8,
+                0x2eced2a56044b414,
+                0x747984a2b84988dc,
+                0x1ceecff1fe2e34df,
+                0x2b5b5451242e06e8,
+                0xcf165e7f9a8e70c3,
+                0x3083fe89e04542f4,
+                0x164dbf48bede89d2,
+                0xeca7ad0b8aff76aa,
+                0x83e0f97bc5cc55d4,
+                0xfc3961584e2ce2c,
+                0x1ae26a6971733
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x67b44a58e9cc91b7,
+                0x721cc568ae4c97b6,
+                0x30fbe4a089caa063,
+                0xcb5346a467600431,
+                0x2cd14f28a5b97652,
+                0xad1268b1cee31f71,
+                0xdcf774c34829c6af,
+                0x1410bccdb91d87bd,
+                0xc56db61c487f7c6,
+                0x932e11695a71b9c4,
+                0x9d664cfb6b199272,
+                0x1490694e48b09
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xccedf5db6a26330e,
+                0xfa9410eae4df7d60,
+                0x9fa0c1060ec9d392,
+                0x6a1d6f4d2b0d7a1e,
+                0x36432d2fe1c49549,
+                0xf112eea2301040b8,
+                0xcb524c6101f0d23c,
+                0xd8dc4cc659b68288,
+                0xfcf316ef0e6be946,
+                0xa950b5e04863e6b0,
+                0x73f8c4b8dd239042,
+                0xc15b099b558
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37254935d25f32db,
+                0xae9a27806e2ca61b,
+                0x7b5bbc3ec02dc6cf,
+                0x10b42ac83e263c18,
+                0x15c805b8725eb2b8,
+                0xb4f45b0b95540202,
+                0x2a8ebfde0399674,
+                0xe8ab328773ade46f,
+                0x34c2fb82f2029252,
+                0x216a315468bdf12b,
+                0xd808e23d8acd28de,
+                0x1a0357f8725a8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb54479cfeded7993,
+                0x467b5dcb0149fe27,
+                0x2e46ef85f0ed0f39,
+                0x4910fd48f162ea42,
+                0xd490dfa2db97ce34,
+                0x29daa24a18dd7542,
+                0xe4e0c6baee98e06a,
+                0x5eeac5a562f41f7c,
+                0x4c99d90c5baa1d69,
+                0x82d89281becb9b59,
+                0x7b52638bbb82ba64,
+                0x1b3520365024
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6d2b0fc502db5314,
+                0xb5505fefa9b3e1d7,
+                0x12efe72ecd2eda30,
+                0xdeb1f3e00dcda72d,
+                0x3a20ca8b817dbd5a,
+                0x1510cf33ed50718f,
+                0x3a3bed2407639bee,
+                0xecd0d33f0c9da819,
+                0xfb4bffde8fe22eec,
+                0xfb702e1611285bd0,
+                0x6f4dcd9765b59c2a,
+                0x9088a7553cb6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1aad3c2f94593f60,
+                0x828a6d27068a7a94,
+                0xf4085adbdcca6df8,
+                0x2ca6ff37ae863669,
+                0xdd2d4cea88d08508,
+                0x211bd2203365bc13,
+                0x129b23785bab9773,
+                0x3b6e60d6446829b7,
+                0x6a9fbab82a9133d4,
+                0x9485703fbcf4e0d9,
+                0xbe00e7f3a0501aaa,
+                0x18e21206b67eb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd67f1d2b0f00a950,
+                0xdb5ed75d630eb9d,
+                0xe4f961d15104c370,
+                0x7edcb5553dcb48e3,
+                0x7a4a19c022b185a7,
+                0xab6753080d42202f,
+                0x866472a9c5ece5e7,
+                0xc8a16781d222ac82,
+                0x5735c451ef0f2fbd,
+                0x7d305b77e84c93ef,
+                0x49ff5ba5982e187f,
+                0x893bddc52f4d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd099b2a3475f73f5,
+                0xf9c0fed5f7affb11,
+                0x86686b6bc06ce200,
+                0xa498668c36f0c680,
+                0x76471d78a89b3312,
+                0xfa0af169fb22a6c4,
+                0x30baccba2ff1d84,
+                0x3c99d133640e6886,
+                0x35f3fe790fd2a222,
+                0x84d13b8e5b683596,
+                0x62f5b64cc90273a8,
+                0x17224069fc39c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xac38f2f6a94a8d9b,
+                0xed26ff4b1a80e57c,
+                0x81a39e83275a1248,
+                0xc3e5be96044e275f,
+                0x81cbc4e60d521f2f,
+                0xf58e847baf3a759a,
+                0x7b521c5b6c49ff61,
+                0x853212f98e8d8004,
+                0xb9c55cd085bdf7fe,
+                0x6755221cc807a25e,
+                0x6adf702be7876036,
+                0x69a5820c7a08
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x343dafec5a8ecc04,
+                0x3cb417fb0099804a,
+                0x1c7f75082a2da414,
+                0x788f3d909f297ab,
+                0x781c2f2837851ae,
+                0xde64b5248f1c8675,
+                0x1f88d7edc781da9d,
+                0x6ac24ee257e9283e,
+                0xe6404e28404d6db2,
+                0x6dcc3d55e1c80ae3,
+                0xd695027965056ef,
+                0x857263a2239
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2a64115f955863cc,
+                0x70de7cda8a574131,
+                0xfe332d07f3ca517e,
+                0x5f9fe93dd010849c,
+                0xd1233aadd6b09354,
+                0xb25198ecf3c8c045,
+                0xc0b477b83b641f6e,
+                0x6448798d9a73d894,
+                0x3aa5d844e9df8748,
+                0x105eaf30460435bb,
+                0xea459426875d2fa6,
+                0x1759ce26ede04
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfea0286895c50e28,
+                0xc4143eeecb4d64c6,
+                0xda1d70fe4233d5a5,
+                0x41a54ac6417e1860,
+                0x3dcd8f83a88ddb8b,
+                0x15eec85f6a9f832,
+                0xc2000af7c9606550,
+                0x3d7ee6ead129dcce,
+                0x194779ca0c1c0d60,
+                0xc1933d8f9bafd768,
+                0x80d15dc1069626d5,
+                0x8d1576331bf4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ca1dffa2ecd8613,
+                0xc522692e0c36c44,
+                0xe7234a11dc790f0a,
+                0x90a26ac823026a0f,
+                0xc3133f4a545535aa,
+                0x22e9bbf7e527808b,
+                0xceefdbcdc7360af4,
+                0x8a26be5b28511f6f,
+                0x3ac1447167972db3,
+                0x6803c0b1195a3ec6,
+                0xbf2fac7ebb123d61,
+                0xb07486231f78
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3f9629f3636041c,
+                0x9bc6fd1155c6273e,
+                0xbc8255c6dcc78034,
+                0x781d392bdc281af1,
+                0x4d354be6fbcc33a7,
+                0x63f59a6aa107c526,
+                0x1daf1972f21eaaf0,
+                0x74915be1569f15c,
+                0xd1e459db4990a2ff,
+                0x34be41d282254297,
+                0x170997ca94e989a,
+                0x72a5d236f831
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa8ecace9b1e4d629,
+                0xd172238518d1f6f0,
+                0xb16daf553275009,
+                0x9913d8c6f3083bf7,
+                0x941fd0a74b7df769,
+                0xd7ecaf365c6b1126,
+                0xf213e04e6444994,
+                0xa11ba7c40ea5e543,
+                0xd70fef5ee43b67c1,
+                0xc10770bb9b240fc,
+                0x1da48eb69219f090,
+                0x7898d819968b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9b656aac590cc0f8,
+                0x56f00f18b10d492b,
+                0xa677c4f57f9c8c06,
+                0x2ac70961f41c1992,
+                0xb390cb8058997c9d,
+                0x671b7ff7ed968b24,
+                0xb3622d0ab22ba2fb,
+                0x6f8e85a6f6a069c8,
+                0x879aa81de2479402,
+                0x827969391f4036de,
+                0x59d5807289658e23,
+                0x10f0c545e106e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9f23769a35b49ddb,
+                0xde9450a959f45d33,
+                0xb78370b9bd355e78,
+                0x490a6ed0e51d473b,
+                0x461a25a9239ee17e,
+                0x707211507c319d1a,
+                0xdc588d7821aaa427,
+                0x194674597714a599,
+                0x17a3b9eafd62e1d9,
+                0x5d76b2f504f9a963,
+                0x1eb034b08273e48d,
+                0x12345c0e55a79
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe5af35f407bee3e2,
+                0x7431fd1ba4ac3951,
+                0x4a14c899beef2602,
+                0x4d2a9b655e4afb15,
+                0x3a098148c5267833,
+                0xbcba5a5a9f0189c4,
+                0x1db73f21d24af7fa,
+                0xb4d9a3b4b4f2e92c,
+                0x57953b3754401344,
+                0xd64dbd05505f592c,
+                0xb9502d9b58ebf722,
+                0x1a9521a68940b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5158ed9a3b72cb27,
+                0xcaadd7863771ba5f,
+                0x56bc29249ce305ac,
+                0xa7ad4035e24cf504,
+                0x7ae80211a658868c,
+                0x52527fdc727a816e,
+                0x9fb46ba4c28d376,
+                0xfebd9556a1136400,
+                0xf2dc4ef9adbaa254,
+                0x1e7bfb60c0a5516a,
+                0xa7d93c2bfff1013,
+                0x13a3d9667d370
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0ca274e1a175f94,
+                0x5534c51ff6c2917,
+                0x6897045eb22510b3,
+                0x941f7aae9fbe2a12,
+                0x2516096dcd7a484b,
+                0xd6c359b6d407b136,
+                0xccefa7ebf3d39bc,
+                0xdc447a897716c234,
+                0x8dd2481085741201,
+                0x39773b88b307cd29,
+                0xf247a769f3a06dd8,
+                0xfb86aaa3bea1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbfea3abc7713b6a9,
+                0xa60bf59b15937765,
+                0xc467c7d75a1e8b29,
+                0xb36fb453df17543,
+                0xef81ea6f90a72b81,
+                0x3eed0e716a0f0cc3,
+                0x12ba7f7e9f0b16ae,
+                0x2be351be3c9b5571,
+                0xd395250c815ba889,
+                0x443290647bc4028a,
+                0xa40a2165c08583d,
+                0xbe89b7fdd39
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd9a763cb9bee190b,
+                0xadb2784399157117,
+                0xaccb0cf1044527df,
+                0x5f87403a6e3b20ec,
+                0x6605c50d5f2673f2,
+                0x6d49a7c69e2fdd8b,
+                0xfd2a9ada9b810f09,
+                0x2d2ec28c793db102,
+                0x80090037e1ae2197,
+                0xb5f868a094e7f2f,
+                0xba86367e4c0ff57d,
+                0x2607a591a513
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ce8088462116c9e,
+                0xd631657553a37448,
+                0xd07e81a9c60521ed,
+                0x9a214d22e9ae46d5,
+                0xbbd5507bc3418c63,
+                0x3b005e50bc6ae2c0,
+                0x787925304f7812cf,
+                0x4b8d3151d1a89a70,
+                0x63db9d2b23c26bf0,
+                0xf2f860de2a82e8a9,
+                0xe638f240bb42a0e8,
+                0x82a636b35aa8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2f6447d7d5e1421,
+                0xfdf12b284120df2,
+                0xa9fc9a30cfd9c25c,
+                0xdca01b9e10d898a,
+                0xcd04a368c45caa3e,
+                0xf1d1b565535cc82,
+                0x733022986ba912cc,
+                0x92fc8b84092c6025,
+                0x7cfe5b177a09bab1,
+                0x7c67c9898c9d97f3,
+                0xe8b0c30bbe2c9fa7,
+                0xee9b662f5f4d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0fa2c3abedf3162,
+                0xbe450b8554454fbb,
+                0xa9c9f91092fcea99,
+                0xdebd9ebf3c563b1f,
+                0x5851fb8abedea027,
+                0x5c4b40867189c27a,
+                0xb61fb908e6d4f36b,
+                0x703248b4e1412437,
+                0x3ac82baa7110d10b,
+                0xb66964b07e22b4c1,
+                0x718bdbdba53b0d3d,
+                0xa8fcfe09082f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa547f08fa59745b6,
+                0xcd49562e519e936f,
+                0x1397b9a26ea8b21c,
+                0x9a893dfaf7f85e61,
+                0x7333878b383a66cb,
+                0x55ef1a3d9b7a15c7,
+                0x5584f711f4296bb0,
+                0x6d1ca00f288b505c,
+                0x2448419890730701,
+                0xa6bc7157c158a4b1,
+                0x7ac9245d630ced90,
+                0x179c0f81cbb1a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5af4a81824a96da5,
+                0xbc53638efe71cc36,
+                0x7951da564be806a5,
+                0x66e8ecfe277a00cd,
+                0xa9a9c777f3ebf86f,
+                0x7bcae91c124d7d1a,
+                0xb319efc25bd87673,
+                0xc3cb3b6e734f3809,
+                0x193062eaf50f232d,
+                0x721463860b3e8b77,
+                0x547a1c04fd3800ce,
+                0x10bb2cca46d50
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2f127a851d834b8f,
+                0x82e9dcce77f732d2,
+                0xf9c01ddf05ac525a,
+                0xb289d5e9c8fee924,
+                0x1aa07b7da051ab82,
+                0xbeb99a684414819d,
+                0xe1b4b4210916226e,
+                0xd342000484d64e7d,
+                0xb1d99f1290710d1c,
+                0x28cf8a494deb2892,
+                0x5af9312888e1fd2c,
+                0x72f57e6b0e02
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x87a4f50a02996981,
+                0x33b9a9d618bed53f,
+                0x24be0f217a11ba2,
+                0xebdedb08c6ead7d0,
+                0x42d19db103326489,
+                0xae654af2830b6412,
+                0xd99a6c27030f8965,
+                0x76c95789390fc638,
+                0x43b5b61441be8659,
+                0x85124148c018614c,
+                0x5e8d5219e41ae387,
+                0x16bf90275e222
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2d2a981876ea2ba,
+                0xf80c6c7ce99d5971,
+                0xbc1fb17c3ec85a82,
+                0xfc607a8ef4bcd1f5,
+                0x3b8a2f1a8777cf30,
+                0x6ef584ae52b5d9b9,
+                0x1b31af4224a17e3c,
+                0xb2b73c8f1d31a3ac,
+                0x656da862648ab044,
+                0x5723f6e3949b5cad,
+                0x5aac1e3a36f99137,
+                0x1564be9468f15
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa0cc1c398dd0a2cf,
+                0x5aefaba9aa60729d,
+                0x47e2a37c357ab89c,
+                0x5e8b93ca95bab1bf,
+                0x9afa1c0f14a56580,
+                0xecef746483ce3245,
+                0xd20f640edd5ed5e7,
+                0xd312cdfd8e007839,
+                0xe4c40f6aa320e0f2,
+                0xa4a2ec596f0a113f,
+                0x45d4d726c76f93e8,
+                0xfaa08ed98bd6
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb690353a0d0d627,
+                0x32d1524cdb4d6714,
+                0xbc2d5570a21dfc91,
+                0xc904d8dd11b75858,
+                0x2dac355d8d47b230,
+                0x47c786c60a2db197,
+                0xace7dac7db80adc1,
+                0xd02d5659996e5991,
+                0x251db2a46a865920,
+                0x3edd425ade24d7b0,
+                0x5d1d4427c22a4e46,
+                0x1a09ea8ff9271
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xfe7e6903d1a2599f,
+                0x617cb9f640493c12,
+                0xdf77e6fe5f4b7218,
+                0x88a56ac509555fe3,
+                0x4d7ee6dad91f0fc4,
+                0xe0a86885933a34f8,
+                0x5dd125eeec7ea63f,
+                0xb30d8e382a53ad94,
+                0x680b91b8af8d7333,
+                0x869f3975ede80e93,
+                0xebe857a5ee6ada19,
+                0xc95f99f8d33a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x121fc2170e6578ed,
+                0xc50616868ba08d59,
+                0xf15fb052b927567e,
+                0xd40521ffb2adddea,
+                0xf5669cfe659e1632,
+                0x5e8dc705e5054817,
+                0x7028d8ecabb2234e,
+                0x3a586d16c1f27b40,
+                0x193c1c3ed69ca74e,
+                0x9f097c9582f1e2b4,
+                0xd2e408ea00dbe3ba,
+                0x5c878c28ab93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x584a365b8703870b,
+                0x8ec816913a864a29,
+                0x451f9fe6ff5bbf08,
+                0x94269111427f14b1,
+                0xff887f455401d0bb,
+                0x1fc76ff529b31a8e,
+                0x62707521ebd6f6eb,
+                0x5f7c14ae96d6d560,
+                0x3794580558c530ba,
+                0x10d2d47f8a32ca49,
+                0xbb4736aa12a690fc,
+                0x1bc2981205ea8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc39693c1a83ea70f,
+                0x503d79af2b5c1a71,
+                0x1d92a1ec16429e6b,
+                0x7077ce422b5585dc,
+                0xa8e7126078fdd4ca,
+                0x21637bdac3a6c77b,
+                0xf9d8c0ff883b8f06,
+                0xd493a280e734f190,
+                0x2230f0b39a4b2e7,
+                0xa32db4be364ec2c5,
+                0x9bd87334f7137ec7,
+                0x1003bc48cb900
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd0fa529af9f25696,
+                0x97d8157b5a86e439,
+                0xbd059a4040f4195,
+                0x882fa19d4013ce59,
+                0x563a25e1a476f445,
+                0xf1de60f252abc0c5,
+                0x9251fd0f66565c51,
+                0xb3e91759d4425952,
+                0x3c3c3a595fe73b98,
+                0xf6dbc1f2bace0383,
+                0x9c95b80417526c6c,
+                0x124f85c9dd8cf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc851b65d0af67e5b,
+                0x5632d02285f327c6,
+                0x19d154740d25df06,
+                0xf550b73bbde86256,
+                0xed6d3e9656ecd5b6,
+                0x99a59507e37edb6e,
+                0x714233151e4ac2aa,
+                0xc04058f56e3c5dbc,
+                0x20950eb1f8632823,
+                0x44a27d46f0ff9645,
+                0x5784ff4e546815ec,
+                0xfa738dc9672c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x430f01768d9560aa,
+                0x8a44a82e8c843d57,
+                0xa502e755b026ec0d,
+                0x705365edf8d9e624,
+                0xbbc8e0b5f9449ea7,
+                0xd6ea637f109db76e,
+                0xcce852223fa09e7b,
+                0x7ef5c94ccda4c83f,
+                0x672f260444dfbea4,
+                0x4f9a2493650444f2,
+                0xe474cf055a5ff239,
+                0x1831cab900571
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9da7bd772be67f3a,
+                0xd21b8f16092315c6,
+                0xd8686e93d70359c6,
+                0x63e5dccdf0fc63d0,
+                0xd3aec0f682cb2820,
+                0x72639b281c82df16,
+                0x2fb843a1e165e4af,
+                0x12d52a327c95fe53,
+                0xe564757eaa9400e,
+                0xa03218af308e723c,
+                0x5c4887de81a4c62d,
+                0x12a0232ddf26b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4c63f6dc288ef9e1,
+                0xc783aa5b68e911d9,
+                0x67a353a6f43bbcd2,
+                0x70425036638f714b,
+                0x2cc1d900f7c7c31f,
+                0x2a38ba73f377dc15,
+                0xb425e7abf0f5aec3,
+                0x1148ad0204403523,
+                0x599a03a1e2c8dcfc,
+                0xa077b40a0144d1d2,
+                0xec9ccb8404ff13b0,
+                0xbf2ee0c8ac72
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc627e5d624452954,
+                0x3b406f41051261ec,
+                0x9f588143470fd3d7,
+                0xeabf18cbd71f26c2,
+                0x38e735014b62165a,
+                0x5c248c32d9b8ce05,
+                0x53b8a7fb39da857f,
+                0xc72c7bcf79cb9aa3,
+                0xcffa8561b1bc7a40,
+                0x196bba483e12a935,
+                0xadc27324812decf6,
+                0x9a2fa8c6673e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x277fa687ec25cb2f,
+                0x9fc9664bd2606cbe,
+                0xda74a94c49cb1cfd,
+                0xad96c2ecccbe41ea,
+                0x7b041d2de88a1d8d,
+                0x351824d3038539e4,
+                0x2b8e32854ca2c4c4,
+                0xb9d8d01ced2c8ecb,
+                0x11145b7817715d42,
+                0x630a65defc0a524c,
+                0x2e2b2e7a8b66efff,
+                0x1516c53e8d564
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x82074f2bee76f54d,
+                0xbf1fd264d82157e7,
+                0x6e518d6fd25e8c24,
+                0xa72d74f6d36b0e87,
+                0xe520c15075971699,
+                0x98c120cdf7c0e32f,
+                0xba32b21e1440b832,
+                0x609b6e784c3fbaaf,
+                0x5c38671ccd5d7781,
+                0x9abb6d17b39b94c5,
+                0x9fb3a237af5a0525,
+                0x836843e95e2f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc3bb6845de9b573e,
+                0xdd0f77f3d33ca46c,
+                0x622f64ccff5a861a,
+                0x7b6d327c42256ca5,
+                0xb282ea55e2d6931f,
+                0x1ea58c3d8e6e3893,
+                0x66bfd7836dbe9f29,
+                0xa258517440ec9582,
+                0xe1878068e8263a7b,
+                0x38390b3bac047729,
+                0x9be1e59859d57257,
+                0xefd942b66200
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2e4ee7d3c6adbf65,
+                0x4a9e6c3e6eedeb4,
+                0xc35d8e84dea9d035,
+                0x25093ca7c6099c3e,
+                0xc4c67b3f589eeefe,
+                0x592290aeef20d060,
+                0x3448842a62ad0f3e,
+                0xfc43795c61cab221,
+                0xa90c84328ef99143,
+                0x10aec84eecb0e902,
+                0x8b1709841318dda0,
+                0x779292e730e1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7e515a9f5cc6a4ae,
+                0x60e2ca67a254ac99,
+                0x7345ad912377535,
+                0xb4cd18ab7c2f05d5,
+                0xb2635a5685b54dfb,
+                0x78253e87c5eecf07,
+                0x363dca90999194fb,
+                0x4eab4f70a880deed,
+                0xf9c555182d92e63e,
+                0x146e05fecf1ac4c4,
+                0x7664fddace30e336,
+                0x8d54d1b1f7e9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ad8ca0fe57b9c72,
+                0xec440ec792e55400,
+                0x93bebe650d3405ec,
+                0x94b2a89e0329370a,
+                0x7788de9a366caa33,
+                0xaded9bc99fdbf362,
+                0x913f3a317820259a,
+                0xf1e9623b54e62bce,
+                0xfe7c909de0948f44,
+                0x21b4f17597873a32,
+                0x70573e4e83438c5b,
+                0x91f75e80be20
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b05c433390f4216,
+                0x728b55540afbd3c,
+                0xaa65205fd8ed2cc6,
+                0xf8a2f7956e1a612,
+                0xe7ada1d3dde4641b,
+                0x70270ca45ea850d7,
+                0xc93f36d4c2999e40,
+                0xf50d9f504e892f6e,
+                0xbd26fa54d805347f,
+                0x4192012bc81bc9fa,
+                0x67ab7531bcc3931b,
+                0x42d9d3039461
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe4a8a5d470a38dd6,
+                0x59f98e9e220a9c5d,
+                0x4a5465f825c0df49,
+                0xcf3b3954af66c8dd,
+                0xc39b9f720ee2695a,
+                0xfc6d4f5ba393792d,
+                0x264437698ed08006,
+                0x52196a8eb73b6dc9,
+                0x3c5a1a1675a63da6,
+                0x7bf42640f654708a,
+                0x8727153d4076ee63,
+                0x169b912005064
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa78cc783a756698e,
+                0x9eadb0df5d23deb6,
+                0xdc2a51066c512a85,
+                0xb9d4b95159cf5e42,
+                0x104091ea4e00d709,
+                0x38dab95260c4f97f,
+                0x71d25672fa91b532,
+                0xc806d082827dcb31,
+                0x6b7572da18b40ec9,
+                0xdd503462c2e4218f,
+                0xefb27b0f49303000,
+                0x381ce16915b7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6253b58dcaaf2300,
+                0x1546b078782b3c35,
+                0x1dc36c24a322eab9,
+                0x82fb7ac5190b3c38,
+                0x47589471ecaddc37,
+                0x66393a37a91df6a0,
+                0xf04de17efa1a5d92,
+                0xe4a89b8291d051a5,
+                0x6d62c23c48190c0e,
+                0xb11232784cfc970d,
+                0x8c6dc6f5d4dc8df3,
+                0x1167376d38610
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xebf261440335a466,
+                0xb9f7c105f77f85ba,
+                0x513f578da51fad4e,
+                0x126cd9426b099ea3,
+                0x164df1efe071552a,
+                0x71fa0fe65c1621a9,
+                0xc623955ee1006efa,
+                0x953167edc8d02a6c,
+                0x1fb0e2e50575ee6c,
+                0xbfb4d16093f3c30c,
+                0xda59ed27ef553cbb,
+                0xa1bc02503e89
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcfa77367a88b1a55,
+                0xbea3cb31f70b019c,
+                0x8af75b983c5a6ade,
+                0x569d24d56291e41,
+                0xf3599af231ccd725,
+                0x211d4ff2e8c0550f,
+                0x21bcdf788cb898f,
+                0x57c609a590ed8be2,
+                0x134e2739b339ec08,
+                0x7573a38b2cc1e03e,
+                0x2b909a51eff52801,
+                0xc598d8cc491f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x726355c3caeb06db,
+                0x9214b69a85133634,
+                0xa1fe12be829ce048,
+                0x61f20506b5208d93,
+                0x90cf01c966fc5599,
+                0xab75cdb5a129425a,
+                0x9015834602a926d5,
+                0xf2abca2cf326142e,
+                0x16254c423633986c,
+                0xa337532bddc5b0c2,
+                0xc48a9a2cc15cf7ad,
+                0x82ac0144470f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x855e48e652b7738c,
+                0xbed9f6c4e89d1b99,
+                0x76151732a20d02fe,
+                0xb8184a7dcbff6a5,
+                0xa0b34b5eb67f86e8,
+                0x2a7f01d1d1e93e3b,
+                0x9d244777b727dbd8,
+                0x79ee2a467e14f629,
+                0x9646d8bd62d839f3,
+                0x72f5aff15ab14037,
+                0xa563a008d1764c1e,
+                0x1bd890b989c45
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8db5864ec8f54131,
+                0x1d1c43120900fffa,
+                0x8fdf9dbd5e80b0ad,
+                0x166586da8554b985,
+                0x8df37b627303d64c,
+                0x1d872a70b4319180,
+                0x87833e3092fd2fef,
+                0x6b3c65fdb4eeb68f,
+                0x524f27eaff5abe8a,
+                0xda7370dc57be5c53,
+                0x3b28ea205aef6d03,
+                0x151bcb349abcd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa05eac3767a0dce9,
+                0x67cc323b5dfb47b1,
+                0x856e655970aaad5a,
+                0xe8408328d8967851,
+                0xb26ea7805f556e80,
+                0x9a5a6794b21ff856,
+                0x5bb7feea3c229103,
+                0x8969eae2f2156f5a,
+                0x53359c79fbc517b1,
+                0x228c19d103e9f758,
+                0x8627d9160a189ae3,
+                0x120d9f469ea92
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8ea9b74a46db6487,
+                0xcdf2e6b5b6610f6d,
+                0xe8590b8f74e85088,
+                0x14deb753609bf385,
+                0x645f94e6be4d56a6,
+                0xfa396df374c48fa7,
+                0xaa9b6b9bfc9ff657,
+                0xfe1be3d0e6939495,
+                0xeecab528a20842a3,
+                0xbf1dacf71f87937e,
+                0xaaeaa4478745beba,
+                0xf46076ab6360
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa493646b421c98d1,
+                0x3d7d42e1388c54c3,
+                0xc8e3dca09c09500a,
+                0xd6701775dd0f9601,
+                0xb66d5cfb610821ec,
+                0x4dd447a30e4102ac,
+                0x66cc92b83b2ce09e,
+                0xbfabfdc02dcf373,
+                0x48a2761f0f12ffb8,
+                0x37f9ba5a183696d,
+                0x18707b1bc1b3dac4,
+                0x3974cedada1e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdb675980a662a30e,
+                0x9fc48d4d93727ef6,
+                0xa9c4f5030c161626,
+                0x4fb5e330eb470518,
+                0xf1bbc9955b9079a5,
+                0xa80a2956ffd80016,
+                0xed600a0be3401482,
+                0x60e5891244e4bff,
+                0x3ed24a6ff315b5c5,
+                0x2d9c67ba5b73da32,
+                0x9f47f40059d9608d,
+                0x30d463f258bf
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb30009943c7e6b95,
+                0xbf2bb2cd20e1a001,
+                0x9bb1cd76275733bc,
+                0xd94990f01b07b95f,
+                0x4179c5ef43e3d116,
+                0xa536d6b1715028c4,
+                0xa8dca9552eb492e9,
+                0x137ab574ec133946,
+                0xcc13ef4bbbb29d7f,
+                0x5c2b7eae22ef0acd,
+                0x5fbdd97825083f15,
+                0x38cbb8414124
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2feba1b19ed96d8f,
+                0x302705e34c568f55,
+                0xfc272de987f4b8a9,
+                0x6b4c3b2dc356af5a,
+                0xbe5db98f1e03bb60,
+                0xa80b9d8b0c088089,
+                0xd7317b643fa34352,
+                0x2a6e355e611c2df1,
+                0xfd8c47fd1fbf932c,
+                0xb4b14361ade9aaef,
+                0x26463ab503026880,
+                0x10f9377671b40
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x830b645be022a477,
+                0x6e9938eed1e73f08,
+                0x600ad07ea6ea0335,
+                0xb1a8973ab1bed019,
+                0x5f63ea00297d1469,
+                0x383ff2b44cf48253,
+                0x57a19e81ec4df54,
+                0xbb3585f80ee1a51e,
+                0xdd91553f79c09519,
+                0xabf95a52b48a57c,
+                0xfee5eafbcadd1f07,
+                0x196f3633e30c9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x371eb29b836316e7,
+                0x17cc192e06dccd8e,
+                0x6a7cc280a176c9d3,
+                0xc00321da59b9e9f5,
+                0x1f9e8da829babd2d,
+                0x2c155ea0477f695e,
+                0x5caaec2930bd7d08,
+                0xd73be0516139cc1,
+                0x8937c96f84969c61,
+                0xadb022191822c119,
+                0x1638df979486c651,
+                0x1be1883539d61
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x37eec3d58c9fd4cf,
+                0x93dcd3f7c26f4dd8,
+                0x4edeaa59134e7493,
+                0xca7ef1797fa2b209,
+                0x3783d94587369a62,
+                0xe239f2fa276a8f50,
+                0x2e64475318a7b4cb,
+                0x6661247e47f98e3a,
+                0x7bf6d0394f372ba5,
+                0x8cd1ac4f6143619e,
+                0x297bded176276bd8,
+                0x5a82c2e014e8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x94937f67b5b712cf,
+                0x3347263f82f16650,
+                0xf3b36ed95c072c76,
+                0x8b809be56ba8075f,
+                0xee44c10400d9ec4f,
+                0xfa070946aae60c4b,
+                0x5034205a33c197d4,
+                0x25464e2ebc7e8eb8,
+                0xce426d897879e8d1,
+                0x522aa8240d9af488,
+                0x9956b0f721bb107,
+                0xfbc653098f21
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x347f11fae864d6a4,
+                0x9a637b1371b1229a,
+                0x30292e6f22aec338,
+                0xa3bfb0aea7fda1e1,
+                0xbfe049deb4d9d406,
+                0x4931cd91f38b1ddf,
+                0x39a72e7b80991aaa,
+                0x91cf30bf7b82021f,
+                0x68248b182cffbd60,
+                0xbc9545eec4e3b502,
+                0x2f23d73aa792c89e,
+                0x9a4f45b9ed8f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa82f078b3fe4c0e6,
+                0x8fef897902043164,
+                0x5344377f2d6e3fe2,
+                0xecd416aeb46ab84f,
+                0x54c07ada998bb396,
+                0x4738637364c88d8a,
+                0x5f0241d443245381,
+                0xf950e957bb873200,
+                0x119445b29f4d2792,
+                0xa20fdccffe07b3cb,
+                0x3125683671182894,
+                0x6916534dd40
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa51263d48379ac5,
+                0xe94768dac63f16d6,
+                0xd3249bdfb9a1b316,
+                0xf104c0c594916499,
+                0x4de60d16c0fca7b,
+                0xf45083f39f5a9c75,
+                0x64f510149db2578a,
+                0xfc3333861a08998b,
+                0x669cbc0e42cffa8a,
+                0x78cc5512e8a894bb,
+                0x1d2acce4381b025d,
+                0x12632dcb1746d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8d66cecce395c3b3,
+                0x6fd506525b39653f,
+                0xa6b2c25b3037aaec,
+                0x5e5eb2d8643de659,
+                0x691ced3ea67a2e29,
+                0x8d9058129e53902f,
+                0x7f88039fc664a44e,
+                0x37fcd0f0ab5ff968,
+                0xbdf5a5166b520c6a,
+                0x3c0c3f91c0a77e77,
+                0x3a14a9519492fc59,
+                0x7ae4db2c0080
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x682c464bfef8a5d4,
+                0x4b78c77fcd2f894,
+                0x730296a968b115ef,
+                0xf3adfbb86c1468d5,
+                0x6e322202d51b70cc,
+                0x771533190603febc,
+                0xdd905999c9473f60,
+                0x827300faa69df3fd,
+                0x362351cec8f0b872,
+                0x93dca1a865906680,
+                0xe95de7d707acc434,
+                0x10da0478c0a1d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x348e3943582582cd,
+                0x51e43d3e24c6c84b,
+                0x3bacc25d42a8aa6,
+                0xb574c89d21477c34,
+                0xde07757694a210c3,
+                0x9339e2856ef193ac,
+                0x411a4760488e2105,
+                0x1bff5964095458f9,
+                0x277498c5e8cd1161,
+                0xb093dc755288711b,
+                0xdf8768e0f74e0fba,
+                0xd2f3339c76c8
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6f6e5136b9e41340,
+                0xf55a8f25ee570cd3,
+                0x4f1caec1b8d55c5d,
+                0x146b0b6407fb281a,
+                0x251d8484a29d79b7,
+                0xc596fadef35521ea,
+                0xe7ef2fbdeec051bb,
+                0x76751943af8dea9d,
+                0x43f1883b4da30561,
+                0xdc3a93106b2f476a,
+                0xf1b9ae11db2891be,
+                0x1108ec335043d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7f3b46d932adaf76,
+                0x619419b68c31ffb7,
+                0xe8b2534982476bb5,
+                0x889d0ddba5adf0b0,
+                0x102e2dfc5e331291,
+                0x28ad3b239cda00cc,
+                0xa5c3eeb9062295a6,
+                0x1361676920267c1f,
+                0xa7c3819c7cc9009f,
+                0x15945a10be8ee530,
+                0x6da3889aaa9a58d8,
+                0xfb0920e1e5a1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa5ab85039076ca43,
+                0x7f02ec7bda8b0a08,
+                0xce6abf7586da022b,
+                0x2b1071b136681fd2,
+                0xa3781c64ccc69403,
+                0xb0a276eb30f5b031,
+                0x97aeae8c401a849a,
+                0x348afc67ee2b332,
+                0xfa62e301280eba41,
+                0x42803feef18568c6,
+                0x4c94b0f310020f9,
+                0x2b29dfa76107
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xdedc57d183fec6d4,
+                0x86f7062874a3d6c3,
+                0x3e07f7c6eba80f9c,
+                0x62bd03a31f2b027d,
+                0x2f5d23dbe4b34ef9,
+                0x51779a4c8d6441ff,
+                0xfe3523e83daacd96,
+                0x820165c1d4adee9d,
+                0xf520e7563104062,
+                0x1f906789f1d52c46,
+                0x1f7d54ec0de9656d,
+                0xf080aeaa7abe
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2f79d5c8dcdec8,
+                0x3df260a61ed8a4f8,
+                0x4f77e7a5b72781c6,
+                0x7df2d31d80529a03,
+                0x2ec92d5fd49536f1,
+                0xc07ac5bc1d31562a,
+                0xd8995f3d110e4dc8,
+                0x73a54798355bb1f6,
+                0xddcb1e3587f9820d,
+                0x93acd552b0cb468a,
+                0xc711ad62bc60edec,
+                0xa8b4180baf70
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x797bb0582252b355,
+                0x24b47ccab219c7be,
+                0xe995a6e0fe773a06,
+                0x1563ad17352f9c5a,
+                0x5b8a5d71a36affa7,
+                0xad5c64cdbf88e84a,
+                0x13e3420b1cc46174,
+                0xad8abdeddb28bae,
+                0xe54d2ea0c112082,
+                0x88ea9acfe9d8b8a7,
+                0x7c4ac8a5953db678,
+                0x9d1f7d45ff5e
+            ])
+        ),
+    ];
+
+    // This MDS matrix supports fast matrix multiplication
+    const MDS_CST: &'static [Fr] = &[
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4bc8721019cc2a0d,
+                0xb4a9ad1a034e5b53,
+                0x5862abc6f73700c4,
+                0xcbc5fa109b5f12e9,
+                0x4a2dff1d46d675ce,
+                0xf674cbb639554d2d,
+                0x8d3dc2d8a26f9983,
+                0x2bafaac6c05e7e84,
+                0x7130a2f80ff5928,
+                0x7ed7b0526132313c,
+                0x35559585f06c1604,
+                0xc3a8de9a511b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xf9f467d77e9fe640,
+                0xecc4bbfcc37a383c,
+                0x105892aec52f051a,
+                0xdde38455fffc76fd,
+                0x7c6092cc5a0dd380,
+                0xd877c926156f62bc,
+                0x368b1c051997e36f,
+                0x3aa06fd9d01b01c7,
+                0x50e953b9fa6df2,
+                0xa507aaca4c0ddfb9,
+                0x86007c78afcfc6be,
+                0x14173cf6f69e3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4dc737199ef9b704,
+                0xb169e27da96dd486,
+                0xc16fdca47c3ee6db,
+                0xbcfca0df3fa6722f,
+                0x9557ef9c5e4fe31f,
+                0xf35eb2d2eec61cfa,
+                0xc3c2846394cfe6f6,
+                0xc62b1541bec65559,
+                0x97083ee65a69484,
+                0xb3c0732575140fb3,
+                0x65704a6d0ee4ad40,
+                0x11d7c628cbf5d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcb9e6cb36a33853,
+                0x8c4b3a065eb7e413,
+                0x7d614e02ec8d15,
+                0x4bcec6613f43354e,
+                0x710acb9170920ef0,
+                0xb1a69779bc35765e,
+                0xc71bd2dbad9ee51,
+                0x3b305ddd295d31a6,
+                0xfc1a6253805ad30c,
+                0x735d754d461b4751,
+                0x82aae823a9afd394,
+                0x7358c006ceb3
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x218e827d7c2d4fe,
+                0xcb9b89d2845017df,
+                0x5e2cca1dd8183de2,
+                0xeedf2f47dee3b9ff,
+                0x8970b8d63258a07,
+                0x99ef366812de76da,
+                0xab2d283275b29405,
+                0x51eb2fa126d75c0e,
+                0x5e6e48cfa574fcc2,
+                0xa3a251fe2653c13c,
+                0x1185019a09ae36bd,
+                0x8eced1ac9426
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xcc9352faad362b71,
+                0x3b72ef6066badcf2,
+                0xc19989c22ffbc3c1,
+                0xea0aeeb9525cc783,
+                0x3f1cf9ac06f1fc3f,
+                0x3bb2ad1ee2bd1c81,
+                0x7415017d04eff0ce,
+                0x4885237e4028d76e,
+                0xbed3050a8428591f,
+                0x55ee1c11b502d753,
+                0xa566061a23d21b98,
+                0xef5674e79e93
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2780e4fdd300f5bf,
+                0x65994af3705e9d8f,
+                0x619695b9f7be8323,
+                0x8e7c1edf4b57e49b,
+                0x26d2294c5dc28b6c,
+                0x26281c4b94df8dbc,
+                0x340853c2fe6c1f7f,
+                0x32c58df92f155a21,
+                0xcfb7642192c4087c,
+                0xbe0f433d4bae5fab,
+                0x4586925b158a21b7,
+                0x105ac70d42e2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa07625ae190c4a8d,
+                0x2dde1bcc140b16d4,
+                0x1e7917ecde0815aa,
+                0x52c99d9a1f1df18c,
+                0x33698b6d605c9f0e,
+                0x7f576572460bd30b,
+                0x9a6b63d4aedf1e7f,
+                0xe1b0facf2e86f88b,
+                0x6026b478c7f9f3ab,
+                0xae8d63f21f07d3c2,
+                0x98a204d9bb88a3df,
+                0x18a857b105e63
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4d917c1246856ffc,
+                0xf21c757af2a21451,
+                0xe89fd72b3f97e833,
+                0x4b5179b9d69a3571,
+                0xa8168709dbb24c6b,
+                0xc96d79f6b4773200,
+                0xf40101d64f4b6deb,
+                0x27e3c88dd7dca343,
+                0x12140f04e3dfe9b5,
+                0xbafc6f3dbecbbb03,
+                0x4e621914b0c311cc,
+                0xdb7dc3875a06
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x526d7c9e10141bf,
+                0x1aa1b0451199eda2,
+                0xc6784fac330206b6,
+                0x83516e093c78b227,
+                0x9edf61a9273b4a67,
+                0x3edf6c2ab68a6060,
+                0x4af8efa9ffeef012,
+                0x85bccf08f59b63cb,
+                0x68e1705ea7062edb,
+                0xd6fb1e6bf3e8d7ca,
+                0x8850f1466a99b455,
+                0x1b2846973251a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4edbcebb53698164,
+                0xdca02bd7da9cd257,
+                0x60fe109925cc512d,
+                0xb7d0396f8cab82d6,
+                0x6e1c14e500ff5243,
+                0x5182685ca698f34c,
+                0x546a6df6e6b17ebe,
+                0x4042846709038c01,
+                0xc343448f16ef7bf8,
+                0xb6e3df77bd9f6bb,
+                0x27fef922cc8f2997,
+                0x1906a648c5102
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2aeb01a29b507db5,
+                0x163fa79321189792,
+                0xc7024b82203d00a6,
+                0xb7cc24ad67d7eb0f,
+                0xf57bb4d6c78d94a8,
+                0xee304242e2eb1f5a,
+                0x4d7080fb046a396a,
+                0xad38761396ff4c50,
+                0xd04ccc13ffd2b03c,
+                0x26b20abe0d879ab7,
+                0xbf7fe63df262ab,
+                0xe5e682c5725d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4ff846408ac73a3b,
+                0x7033f6abe4217bdf,
+                0xbbdcb9dd08d4e047,
+                0x38e5636b09348eab,
+                0x4dbccf9d07baaa77,
+                0x4737d2373a002b05,
+                0x431ffc095041f2eb,
+                0x76d94e209c220793,
+                0x8aae7dca81cf6e53,
+                0x2725e67bd002a3ce,
+                0x448daabb0a07e6bb,
+                0x2bda90e4fb8a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7ddb83606dda7a59,
+                0x71b863846a716430,
+                0x69eb0dde354d8b69,
+                0x26c3c4637fc392d1,
+                0xa2e9dbdfbb118f2a,
+                0x1e2aead9a384184,
+                0x92d8b5e0f6d263ac,
+                0xd4de7aba1a61449f,
+                0x9e7a262b31b78dcd,
+                0x1c39b8ca76d4994d,
+                0xcca34a8bbc076f15,
+                0x3937505f061
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8fbb43e5e639a784,
+                0xaacee8c646dcae4c,
+                0x470fbc59bf2d113,
+                0x3d947e3353867e29,
+                0xac0465ccd02b78ce,
+                0xdaff2a7a6f1055,
+                0x9a07571e92d1dabe,
+                0xe97549ac00644289,
+                0xe6d569db340b9d88,
+                0x1717159a41ed3076,
+                0x4db62cf65370a89d,
+                0x81dc11276d7b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6950f2a9063315f3,
+                0x4c18b1402f7a1dc0,
+                0x9b482025ec545485,
+                0x1a8aed19a525a270,
+                0x6b95a8641a7f3bed,
+                0x1bf1feb7c27468c7,
+                0x971f042323da21aa,
+                0x8973e6c08d774e1e,
+                0xa31e45e9caa60d9,
+                0x4fac0b01dadbc7ba,
+                0xde90374ff551a000,
+                0xa9b515f304b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc29de8c4a46c084d,
+                0xb6cbd7afde3b989b,
+                0x2837b19f9b367ec2,
+                0xd34232b308b666ca,
+                0x4e21638f04f2860c,
+                0xfb0dd4b8e97c229,
+                0x3aa211d2e44a8f6b,
+                0x7886eb5b890d137d,
+                0x481c281e7c362eda,
+                0x5724d8402fc0a5ff,
+                0xa6d4fa8789ce6f03,
+                0xecf417d963cc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa9dc1ab45ce00f55,
+                0x2104b97b509f812c,
+                0xfceb5067ce614483,
+                0x91b2fc3bdc998aa5,
+                0x77f9594ca80202aa,
+                0x3fc459a1c77f3f1f,
+                0xc12d2c096be79c30,
+                0xa884bde734935f1e,
+                0x453a41e207e017aa,
+                0xea14c543fa0da301,
+                0x19da702734849c78,
+                0x60b41c28c2b4
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xde13862cebec656,
+                0xd1bfb570fc45d01d,
+                0x9bfbc9198d4c2b5a,
+                0x7c13f197e33b30ac,
+                0x6bf705a163739f4e,
+                0x1396cf1be2cc2748,
+                0x49e9128d987574da,
+                0x33499c07b03db8b8,
+                0x26ffc13013f8a6d5,
+                0x4190a04034695248,
+                0x96f4819cdd97bcad,
+                0x1c0b820d004fb
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8709c1401aaf5e4b,
+                0xc9e4ca1dc86cff21,
+                0x16adbaba4a6d1f57,
+                0x836306aa8440f6f1,
+                0xec98fa917ab27caa,
+                0xb54303b1bc4ab2c2,
+                0x4960e45430c399ad,
+                0xa4be3535c798ca0e,
+                0x52f020a6a5bd22a3,
+                0xc006a861bdbb03f6,
+                0x22fb9681dd212f75,
+                0x303e8b52c90c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3e40f3d5b78f7b0b,
+                0xb51d04a79c27a500,
+                0x9e867cdbd1afece7,
+                0xb5e63eb7db426d1e,
+                0x9d6159271b1f5262,
+                0x24819f61eeec292e,
+                0x8644553328128f51,
+                0x63493715da467114,
+                0x7a5a2398b34c0dc9,
+                0x7c4eeaa20bcec59,
+                0x4ab82d5d5ba71df,
+                0x76bd4eb76ed2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1bed51ba9533fe88,
+                0xb0ec274e1b4e1ded,
+                0xd027d924afe84631,
+                0xbaa3091a0813ae8d,
+                0xa9e3f47d5d5e9d26,
+                0xe4e4094c6cb7c059,
+                0x14d1e05b02645063,
+                0x4358105253a31806,
+                0xe1e1d1a880fa170c,
+                0xc969c1f984f59b5c,
+                0x4d3c4cb122e1e8d9,
+                0x12229116f1c03
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xda45b22a07294c4f,
+                0x486c543ef0a410f8,
+                0x13a5ff9eead2c71a,
+                0xc265cffc5416ba83,
+                0xbddf88dcc9207e35,
+                0xcb5c4487d445b248,
+                0xfc0e2a6250c661b0,
+                0xedb06f7219688b73,
+                0xdf6e3ae9aae46ddf,
+                0x13495f33119b1302,
+                0xf5636241a6362f3d,
+                0x1bdfac9b8d13
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x9a7882f9c4b2de3,
+                0xd22b20521b4ff4a8,
+                0x2451bf8ea3a08acd,
+                0x1814019cf5efc17f,
+                0x53ec5917391ca22f,
+                0x4826adfae17ad82d,
+                0x95811da995617069,
+                0xd2f3e23119adbbb0,
+                0x5c8ab6f60f77a268,
+                0x36421e919a5f09c5,
+                0x19ea7b864a6d0e64,
+                0x5bd2c9831fca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa0044486987f8500,
+                0x687c8471c8886d90,
+                0xa0196aa3c1c542a3,
+                0x5f8bd4171155042d,
+                0xc471574ee25ae5dc,
+                0x83c9d9ae754d3890,
+                0x834b6f794b073be9,
+                0x180ac93aaddcf718,
+                0xa31028d30b00bb29,
+                0x144c6401f678f37d,
+                0x64bf3739072d889b,
+                0x1191014f3b67c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2205c014b5dc8742,
+                0x51b4e5fb13aa3764,
+                0x594e8b2330a2ec85,
+                0xfc8e7b347f781f56,
+                0x566e27a3f4dde137,
+                0x5bc2743d0f89e6aa,
+                0xcd80a2d79a6a4df3,
+                0x22e6d0d1e93550cd,
+                0x7e4c2bfa39d76ee1,
+                0x75d40770b0e4296d,
+                0xd2c8a6186341ead4,
+                0x876a4e41b844
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x87379c63f035b6dc,
+                0x20d4b5a6a6e3139f,
+                0x342d4ae44d98f938,
+                0x6ecc9c64bdc93880,
+                0xd2fdcdb499442a4f,
+                0x47a2883d1b053c55,
+                0xbb14c2625e993653,
+                0x8686275565a13739,
+                0x3cb41cf56decc28b,
+                0xf4c0ce9726a0929c,
+                0xe009c4f7bb748a1b,
+                0xae6f1fc89fd0
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd1d5c2ad94166c31,
+                0x4686b83950efec1d,
+                0x6bc245a74c947153,
+                0xe15efb6ebd455bb0,
+                0xdd0a8d9651ec0a4,
+                0x77e02da7628c0cf,
+                0x1a270d0cc7111359,
+                0xb5197a14f43e6816,
+                0xc3001b41ec277164,
+                0xb6f3af3a4cc22108,
+                0x635adc060157cbe0,
+                0x13fd196a72dca
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x80e405f946b9fc84,
+                0xe7ce31abadb698b2,
+                0x31e6b428bc8bcca6,
+                0x639e028749a11335,
+                0xab8c724f89143374,
+                0xf0b57d8c744c6e72,
+                0xb4399b1c709368a,
+                0x27eedfe96f44469c,
+                0xf7303ae99ee9cf30,
+                0xf654c400f75700e9,
+                0xc791c647a1bb56b2,
+                0x15d9294629f7a
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed83bfc3ec4f7e7,
+                0x30d71c242d0c9d33,
+                0xfefa0aafaeb0528e,
+                0x9245f646b1de6711,
+                0x74796cac9cab50d,
+                0x1b334c598aba9d1,
+                0xdeffc906ea920b85,
+                0x6ddcc7b6ca159374,
+                0xc45bdf3ba2ec085,
+                0x703d8882b981b780,
+                0x100d5dae2e95a04c,
+                0x5ceeda4e620
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xed2e322f33f521c7,
+                0x8d020d3ad64e8eca,
+                0xe0aa9fe0846519c9,
+                0x6c8aa686a576cad9,
+                0x7da3156d12102f1f,
+                0xcaafc99f4696d64c,
+                0xf4e6e24babd2f6dc,
+                0xe85db241cf11cfd,
+                0x61818a9710b69174,
+                0x63ddcd7cda68ab5e,
+                0xb761148187fa6ac4,
+                0x3f7858dc5053
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x416d758fa47ccd82,
+                0x3e1872cabdc03317,
+                0x6fd1b7db840c46fb,
+                0xe7498bf55f323ba6,
+                0x77ece6af9e40dbda,
+                0x2b06d82c27146e6c,
+                0x71dd5b5ed0dd53cc,
+                0x82f577e812656c43,
+                0x75fa9e06e4fcffd2,
+                0xb399d490deeb0cab,
+                0x4ed63c262032d690,
+                0xbb2422ea3dc9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd74b7a8d80dbf018,
+                0x817ac4e6639fb107,
+                0xd3ba70fa4004805b,
+                0x1bb49e7bf56da930,
+                0x508eead5d7f04ec4,
+                0x1bc6440531e6ab48,
+                0x594dda51710d5bb7,
+                0xe436fd03c03d6ba9,
+                0x62ae4098b0b51a48,
+                0xa5f129cf9a79a855,
+                0x5a3b545838686cc9,
+                0x11280d431fc50
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x318f74428f1b1403,
+                0x4b4552094327f8f9,
+                0xc3a8440a104ad1cc,
+                0xea9de8c427a1a3ef,
+                0xce6b372a28baaea2,
+                0xfda18842b3e98732,
+                0x91e79e7a839a6022,
+                0xddd2d4dd5ab0bc4,
+                0x85de811c367701fa,
+                0xdc16df507476ebd2,
+                0x9b98f3a11de31f6b,
+                0x1517e1470aef1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6dee390766a14dff,
+                0x61ea9eeaa5b1cde8,
+                0x941bf5415d2cd9a,
+                0xdb9f99f435058feb,
+                0x574690a3e6057d65,
+                0xa3b2dd405ccf2c6f,
+                0x8cfc59a29a483bcb,
+                0x69d8df6aa8f96a67,
+                0xb5c75fd0eb21affd,
+                0x8878b6cd9413f392,
+                0x2fb69d34c4f14463,
+                0x1414a81a31b9b
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8b5d94919fb83ec5,
+                0x9854b3ab87937942,
+                0x83775d85872917b0,
+                0xe50b7f1eac28c0d7,
+                0xc210c229f0b59680,
+                0x1db8741e1b09a8ea,
+                0x72713fdfb729e4b1,
+                0xbc83b43286984c4f,
+                0x7975f9f16b0be875,
+                0x3a0eb93b6a372f3e,
+                0x816fe94b5b817d40,
+                0xd27d80903fde
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x627fa2f4dc2773fb,
+                0x2df9804ac2db2f74,
+                0xad925a86c863f7a0,
+                0xb49f0de353cca41f,
+                0xb310f63b1d73e401,
+                0x6baee75425447f4a,
+                0x3130142f959dc59e,
+                0xf35d9b5ae9b8b906,
+                0x877cc209c2e36160,
+                0xb508472379f26fec,
+                0x10918c1eb3edd6fb,
+                0x25dac4f0bea9
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xa677e1d3d8f3dc08,
+                0xfd0f1442506e23ae,
+                0xf9f089c940cb4f84,
+                0x7e0592a39fab8723,
+                0xa28d1c969cdc0316,
+                0x2c3281ac2ff5ff0b,
+                0x655a8c1c40059006,
+                0x91e47478a39f82cb,
+                0x18fc34860a4e9678,
+                0xa6856da2a7c01e19,
+                0xa4cf977f9229ff8e,
+                0x19c23595c108c
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5b474c2b924ce1a2,
+                0xfc5968919ec2da66,
+                0x821ca6262f87d6df,
+                0xaa566651c19a7f9,
+                0xa6df8f4b8e8d0b0f,
+                0x2c1911f44a857484,
+                0x2e2f72e17949958,
+                0x552392d1b9122dfb,
+                0xab2b39b054e10ad3,
+                0x329f05c38a9f95bd,
+                0x66afb2db49e1ea7,
+                0x1c1fc782ba746
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xc9a2f760e861577f,
+                0xb88bab94d9d937ea,
+                0x95ddf6925d3b3e0c,
+                0x3a3a43d97329a99c,
+                0xe64837af7f349545,
+                0x6c9c73c410486c3,
+                0xcfda6d1f7a7895de,
+                0x5437c78ca1ca2b9c,
+                0x88eca1588da9f4c9,
+                0x4b963233691b4296,
+                0x780fe9122f9336b7,
+                0x12ff33dd7e6ce
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x24f51d9edb6c0e4d,
+                0x66c5c4bd0ca554d3,
+                0x589a5b40341a2418,
+                0xf40fab79b6b2411f,
+                0xa8734652f9e7d87e,
+                0x9f1da6f81eb97479,
+                0x217f2b9b06184191,
+                0x6addcecf4e9e6518,
+                0x979200aa0b48e10a,
+                0x30b0ba658de03637,
+                0x7635cc20daa08153,
+                0xae434a1b0950
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4a838c703d47033b,
+                0xa3164f0865583e8e,
+                0x8054519ce4ae1f60,
+                0xb6df3af879053d49,
+                0x11ce30ee179c8c0b,
+                0x247d443410cb487a,
+                0x54353a5ec50f11df,
+                0xf412292609189b87,
+                0x7a0e775e24eb79b,
+                0x87a3a2cff136a475,
+                0xd79629684fd79434,
+                0xbacde1b62142
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe4fa68d2e8fac02e,
+                0x91309b8ccb5152fe,
+                0xde568db8c5c29861,
+                0xd167456b3621ca7,
+                0x4f4b00f5a972940c,
+                0xd86ddad97ee99453,
+                0x5a5e36c44071120f,
+                0x14dcaa7918748fd,
+                0xacd81ab8d2c22ac,
+                0xa905198c87d644f9,
+                0x422d2a8e39558738,
+                0x2cddd321d877
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x34e169e8435fedc0,
+                0x8bd244d3d7e6382,
+                0x8cd3e6b7844b93c6,
+                0x86e45b4e597747e7,
+                0xed46ee414c407a8f,
+                0xbbfc53dc99981a0,
+                0xd71f7c3f43d90220,
+                0x298d0c2a8710a7c8,
+                0x63a438022cd1ccae,
+                0x74f6857901b553ff,
+                0x8939827854d65da2,
+                0x138153dc143fd
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb34f5ca75400ad2a,
+                0x557930efaf8e387c,
+                0x36fddd75cbe11fb9,
+                0xae75ece65d5096b3,
+                0x510b7c4749c66b6b,
+                0x4de06905ee1ec933,
+                0x6d5d7c63faa47f74,
+                0xc9915cbfd62105c,
+                0xe9a35ab695bc3748,
+                0x42e3c57f6f88e73b,
+                0x70f05247a8866bda,
+                0xdbcb67d221d1
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x68605a2865d11bdf,
+                0x33c807e1dc9e7984,
+                0x50980e66de50309,
+                0x144063a31232dfee,
+                0x64ba7a342ceeb1f7,
+                0xd580b31968b5aac5,
+                0x79a53ceaaa8c7b7e,
+                0xdbf8533e267610a9,
+                0xf349896dafe02a0c,
+                0xc9b81ae011c19966,
+                0x6b3dc62e9c6ba0bd,
+                0x86766521fa60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x879fa188af49f424,
+                0xa22c0173f24301fa,
+                0x9e5cfcb891ce0b34,
+                0x6b29df474c1c3273,
+                0x2d7a25c8add31b0a,
+                0xc882f960eb1d658d,
+                0xd3aefb0a6d2f6ddf,
+                0x3b00426d2b7ec4b3,
+                0xb2f111fc27350bcd,
+                0x2c01830223f59cf0,
+                0xa61aa1b6f640c7ce,
+                0x1befda4ea9112
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x45946bccf344a252,
+                0x236ed5d932572053,
+                0xeb8b8d8f8ce5b395,
+                0x15d7e949f58ac01b,
+                0x6dcf7e727865b7c0,
+                0xf2aa7734b9a1e2ef,
+                0x7975e4764837697b,
+                0x654962b96c5218e0,
+                0xcf5d0a05072917e9,
+                0x4862f61e916d3140,
+                0x7a2557793d201817,
+                0x429f2b58b308
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2ab31626cd3f15d,
+                0xce0dbacacf776112,
+                0x273e83d1a810395d,
+                0x308607a62a40ec1f,
+                0x74d858a739d35833,
+                0xfb63a4fa8796f07b,
+                0xd64d1edcdbade5c,
+                0x2cc015d39dc43385,
+                0xadac4ef3a1416053,
+                0x6a26150bffed5152,
+                0x8e0ad9401780ae57,
+                0x10db34a6b1b44
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xde58f061d3001095,
+                0xa5ae203364f58862,
+                0x66ffde694390b19f,
+                0x333200feb835822,
+                0x8aca688ad05fc92d,
+                0x9da5ae5065864445,
+                0x69b3c131d9d214d,
+                0xa5ac2d49fdbc501f,
+                0xab1d79a9c5aea774,
+                0xe211e9b66da98bb7,
+                0x450e9bd1d005b59e,
+                0x70a3c77f7547
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b1301a0023d6197,
+                0x3496c909b143188d,
+                0x3b43f144891a3586,
+                0x8ebe985d053004e9,
+                0xbe39f92bd1f69ea2,
+                0x90121fd7e37d36bd,
+                0x66e661d428abf591,
+                0xfd3c9e30ba27f8e5,
+                0x994436f8aa5abde1,
+                0x4ae5854f9b73068a,
+                0x54eec9ad70df4830,
+                0x1194e8854817d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x553b4de17424d69,
+                0x3bb36c45c297a87,
+                0xb7881cc366e49275,
+                0xb66b8928ce07f798,
+                0x7e74917ec3198858,
+                0xdfb13d8e82bbe205,
+                0x6c70c21e0efb2565,
+                0xbb2e63345aae396f,
+                0x3604ddab4a28dc96,
+                0xbc3a0db7ede2de7d,
+                0xbb7628bccc0cc099,
+                0x122e50ef19176
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xce78f2f57f13bd48,
+                0xd72e355ec2903f12,
+                0x61f6e472313ca02d,
+                0x95e887a96400c82f,
+                0xd3a9726a5572bc8d,
+                0x8d24f817cd494d2f,
+                0x862d67fa92c75f00,
+                0xd7e726c74f16c091,
+                0x81c03ebacd0ec542,
+                0x6b9886a3255e5af8,
+                0x7dff654dd3f561b5,
+                0xcec8a2625d1d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x804449adf0a6de66,
+                0xcb7caec883bddaf0,
+                0x960776b59d1d1fbf,
+                0x84f6a34a024fe447,
+                0x562f6b0dec80cc9d,
+                0x62c86d3d44d34a65,
+                0x826fc748355bb74f,
+                0x7c80bc355091d7fd,
+                0x5055b2f519c0f6c5,
+                0x27599ddb65e291ff,
+                0xd6a0ca310f0ce0c8,
+                0xf3745c5db98
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1a09be78791d7f4,
+                0xbf7335235ae245c1,
+                0x130d7a7e18e09524,
+                0xebb9ea8c956a58be,
+                0x52974756c5d450c,
+                0xf6b69098e5ff17d9,
+                0x1c704496390bc0ef,
+                0xfb2d65dff356bd54,
+                0xd2fa1b572f0fe15b,
+                0x60a859ed41c6aa12,
+                0xd7dbc9095d3b371c,
+                0x17408836a07e7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x3d0fc63b5dfb64c3,
+                0x2b9e3daffe76fe0d,
+                0xeed8199b6f428d98,
+                0x8ec08ce0b7d47e54,
+                0x8df9430cbecaf98d,
+                0x47e44d1fc7af0701,
+                0x4a47441ff5355dbf,
+                0x5603e26e98997609,
+                0x89b2e1d1bfd4abf,
+                0xd691783207350b73,
+                0x828ff87b2843ab33,
+                0x1fd4f02906a2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xee09168b12db7183,
+                0x4191556c0117ae02,
+                0xf5a9c210b4264cee,
+                0x14752d86e98bae49,
+                0x4de744ed275448c0,
+                0x38ab79ae79f6a1ec,
+                0x74dd1ae93d0a7851,
+                0x38fc85c49c6e4372,
+                0xb1759a45ac050565,
+                0x84dab8c16f381dea,
+                0x3b3db5e0f402121d,
+                0x108bd8c3573ab
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x834ccc46c9866091,
+                0x41927cefc7030f72,
+                0x5e031d00245b60fd,
+                0xc1d0e16dcc2d64ab,
+                0x5ed2017dba8370e7,
+                0x519b3c1c1ee77753,
+                0xfadaab1905928a2e,
+                0xcf912164a574d9f9,
+                0xa90bc7b386f33827,
+                0x174a911e664f99b,
+                0xd18f45ecf4c0c866,
+                0x537df91b9c97
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb28ba13ae9dfbec0,
+                0x26420e10a7422125,
+                0x91cf0b4d815459fc,
+                0xdb457e8f5bed19a1,
+                0x29c57decf8141f66,
+                0xa840eecb60918cd6,
+                0x715b879f90630e9c,
+                0x948f1674fb73a88b,
+                0x9fde6d124c14ae36,
+                0xa22ec4a9a068bf9b,
+                0x30c3189cb11378db,
+                0x3d388334235
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x72f013a0944f728b,
+                0xfbd04a2a4511da5c,
+                0x99fab7384e6d6d58,
+                0xb2187e490f72f7d0,
+                0x1d6d57e6d56e72eb,
+                0xd9522c5822bc6c92,
+                0x148b1b57a2d9d2ef,
+                0x76a374dc82633d79,
+                0x5f1ddb7da89c3c05,
+                0x3e3ec39fdb658ab4,
+                0xdee259e74de62d43,
+                0xb25174e4b51d
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x69e7d1013a12f8b3,
+                0x92364d9fed36a832,
+                0x4f67253702fe8e83,
+                0xbaa1405114f9da11,
+                0xb34676e8d0534a00,
+                0x20bfa78e83d0237f,
+                0x71a926737f0f5d2e,
+                0xcc0c51ec2a52e04b,
+                0x86d364b6432f3c2f,
+                0xb221c8bb001e8a7,
+                0xeb9839848b5186bb,
+                0xa275d3f8864f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe3e35e56e1fbf1ed,
+                0xd68d29040bd14ab7,
+                0x23c7c3b8b66590dd,
+                0xe3994af8ca908897,
+                0xfd5005e998ca57ed,
+                0x98d5d7e2b51a1932,
+                0x93098c5890e62710,
+                0xab783b267849d32b,
+                0xf51f5b40b6ab2107,
+                0xe68b323f80c046d7,
+                0xa17bece67714600e,
+                0x14fe97ce565c2
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x74c2f9a5828140bf,
+                0x7d4d5e1834884e43,
+                0x3e67de116edc15d2,
+                0x28848492ca240dc4,
+                0x8fd1bbddd3bd11ff,
+                0x26f7f5389e84c2a8,
+                0x79d41608a4f75261,
+                0x8803a92054add631,
+                0xb0f876f20f2dbbd3,
+                0xdd63ce106438ea6b,
+                0x65c0ce5787cc069d,
+                0x117ad3b66fbd7
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x802246ac1b23a7e8,
+                0x9443d8dfcd37908d,
+                0xe59768261091a32c,
+                0x10c8965982f8e5f,
+                0x91e5ba903cc8c6d7,
+                0x2e3169a10bd141f1,
+                0xafb9bc219a5a9457,
+                0x8919c7adf410dd9,
+                0xe6ac6b499f5c2a78,
+                0xf49630fe060de92f,
+                0x9b367a9ba4090078,
+                0x10f5d1573bf82
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cd99d16c74c93a2,
+                0xca85ee643644b96,
+                0xd1a08fe6bf87186,
+                0x6036444e464f91a6,
+                0x10b70b581e17db43,
+                0xe392d430f8cf6670,
+                0x49a8a80460228d62,
+                0x6ba05f0b0b1091d,
+                0xa22f3a9a10edfe64,
+                0x268489ddc30542e1,
+                0x7e80566499f33099,
+                0x10d17541f4caa
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x26c50974ebcdf5cc,
+                0x9ebfa98e406831ef,
+                0x77b838e0e6ace22d,
+                0x418d41f3797862b8,
+                0x668c061701c2877,
+                0x181a5661e2e6b2c6,
+                0x9614037dabd98b0d,
+                0x773ae9367608373a,
+                0x938aef1802b38a52,
+                0xe1642019c9f0fe15,
+                0x5f595b929de56f86,
+                0xe95950a0ac8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x68a55e3bb01cd5c2,
+                0x101018bcdd9195e3,
+                0x9259611856454a51,
+                0xd46412068e6c87db,
+                0xf41d7e7d0f122d37,
+                0x303c6d796d42c39a,
+                0x6f4d5ed1ff5828c1,
+                0x702cf0a7420d3c78,
+                0xe33ca7c6c9b6b4d4,
+                0x7eba2b84405672f8,
+                0x6bfb5c58ef53afbe,
+                0x5f7cb3b8fe8f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xb2717e1c974a76b9,
+                0x5766b9a1c9118279,
+                0x9caf720d2e440230,
+                0xdd9cf6162150bee8,
+                0x6c5862deac6802af,
+                0xb3b9f800323e5c9,
+                0xfcb320f80d10c80a,
+                0xdd1f514ed3042351,
+                0x43cd9e018b46ecd0,
+                0x3375357e8df6dcd6,
+                0x4f58cbe9c5ddd95a,
+                0x1a95b556e8e
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x5cf82f2eaafb7958,
+                0x469da696d7b303ea,
+                0x7619be6fc36b02c6,
+                0xfd2752ca6caa6805,
+                0x54fa42c4dd23a45b,
+                0x4527d7388d4bc227,
+                0x9575e6b87eb0f5d1,
+                0xf294a75b1a0bfc8f,
+                0x935c8d79410dbe53,
+                0x9f00a356b39e4ca5,
+                0x51df2008838017b2,
+                0x178003f3039bc
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x2ace6ba9f62c31cd,
+                0xb7f729f5b496ee70,
+                0xb6e21c36675d0ba8,
+                0x8b7a0b66916f0cec,
+                0xbed7c4050de8aaf7,
+                0x6fb6a58a7380d37f,
+                0xa21b597b236e9fa0,
+                0x1a0a90919f8f87ec,
+                0x1860973919ccd4a6,
+                0x4d2c5f17fac5a0da,
+                0x4132a4414e261856,
+                0x81f72759d544
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xe45cc9d9bfeb2d6,
+                0x55d37306256e2c69,
+                0xbebb21cf9c685204,
+                0x917829122dc8ab17,
+                0x466ce4a48756535b,
+                0xc1753fab0e5ea3c3,
+                0xdb9a7ec32a98f1dc,
+                0x5faf38cf6e256f80,
+                0x24c0c16a82fbe3b9,
+                0xbe8a0146c282bd91,
+                0xf4f9d86a96ed0129,
+                0xc591f0010849
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xd27bea11b9cdc1cc,
+                0xa99ef87158d3d2b9,
+                0xd46125710ef7f3f1,
+                0xcfedd5e44046fe80,
+                0xf7a313147a78b344,
+                0x3d162d3501c2a962,
+                0x1a97ceb7318d8314,
+                0xed9f35f5c5c773fc,
+                0x383845092abc2cb2,
+                0x2ea7cd809a34c6d8,
+                0x5a12430c8c1e2628,
+                0x198b214ff3010
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x1d1a105b5dae7721,
+                0xcf50fb0402e0ab38,
+                0xbf620340df36c0c,
+                0x428034ee3fc321b1,
+                0x3275ee394653499a,
+                0xfcf1a7d25ce62ddc,
+                0x79aa19619a056019,
+                0x1c3288b55464a4d8,
+                0xbe844355a8f6db8c,
+                0xf0daae23c0565544,
+                0xdd635a1ad20167ec,
+                0x18dfa04d8cf9f
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x7bcb0270a44676a6,
+                0xc8906119ebd9845d,
+                0x315d6c3c9ebcbf80,
+                0xb86b05892c8244f1,
+                0x3c6779e6d6197052,
+                0xf2b9f8a0088c9f0d,
+                0xffc0942397818ec9,
+                0x68b79f2e184bb4e6,
+                0x47aa9d328fc85848,
+                0xc046ccbfea561edc,
+                0xd4b6a1e0a431b978,
+                0x1197213c3e164
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x4b196cb8d1e19914,
+                0x7b5d865e8f2e4167,
+                0x803939b2c0f0b3d,
+                0x9ea6139540e078cc,
+                0x52af5c254abd2a6b,
+                0x37bd39e33f9dfb44,
+                0xde835115017ae611,
+                0x8a54365d6cfe962c,
+                0x67a1d8cb63c6a0c5,
+                0xf39e7d7057713d6f,
+                0x5ab5349d68f72c8c,
+                0x1a96aa715c277
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x173179445116e0c6,
+                0x6124f1f310c7b571,
+                0xf92e84852ef73bdc,
+                0x5c9d4dc351d8eab3,
+                0x79efd4d0d2cc9295,
+                0xfb8e27bc3de0a0fa,
+                0xd3274c276d67cdd3,
+                0x7718763dcec6084a,
+                0x451f0357026307f8,
+                0x1820fcb4db2465c0,
+                0x28b71d2869b55d5d,
+                0x1bf3bc2b56a52
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xbc07057740e02e1,
+                0x9dc6aa249fa00c87,
+                0x827e89ac7bb8c8b0,
+                0x6f330e4d4d37b7e,
+                0x5c8364a49c422c37,
+                0xe215e462f4594f07,
+                0x40ae45dc0b14046e,
+                0x24df11d2408517bf,
+                0xbaf8ffc5b4ec9f2b,
+                0xd9e4708fc0b2cb01,
+                0x2b6f7d20541b4521,
+                0xf31f54ccd365
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x6045b5dace26b566,
+                0x98fb85991beb54cb,
+                0xcb6544ec33098b4b,
+                0xfa253f51b2c19056,
+                0xecffdd7d8d49c3e6,
+                0x8eb660776bed07ea,
+                0x388ec70a3b375f2,
+                0xd9d9a9f38a862a5b,
+                0xdc05c090c8168a7d,
+                0x2e1dbf3162b58dd3,
+                0x4d8b31eebc1c0e51,
+                0x11bd46c9555c5
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8e0280ff4a9d2157,
+                0xf82d29473596d911,
+                0x55236e2cce948065,
+                0xc4d10062d371c2ee,
+                0xf70d1ce41d88f671,
+                0x503a85955c14614b,
+                0xa966c3151406a1df,
+                0x8f84da8e56892f0a,
+                0xf10b9d778e47df20,
+                0xe6ab9a54cb8bb384,
+                0xa842053904c06ec3,
+                0x12a430415bd60
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0xabe5b9d042cb7178,
+                0xcfa59f758ac83ce1,
+                0xa6983fccfac039c5,
+                0x3219a445a84fd975,
+                0xde6ec923aba1a826,
+                0xa8e90e86101f881d,
+                0x4673b4e1d252fbfe,
+                0x4829cd326bd4174,
+                0xab11e14215453475,
+                0xd1790a85a814df5b,
+                0x41b00cef496c5fa9,
+                0x8c02ef24b2ba
+            ])
+        ),
+        field_new!(
+            Fr,
+            BigInteger([
+                0x8795c585edd16bdf,
+                0x5e21b5e6f2204711,
+                0x8a2d0143b9c04a2a,
+                0x744958a6cca63f08,
+                0x18fe49be44605293,
+                0x4dd5dec0ea76f44a,
+                0xd5293f5717edc2d6,
+                0x25d42bf070b250ba,
+                0x6c177b5d23841c8c,
+                0xc901377cf8bbc700,
+                0x3848b0d57cb1ea3b,
+                0x75bc0ed3c233
+            ])
+        ),
+    ];
+
+    /// Short Montgomery multiplication with respect to the short Montgomery constant R_2=2^64
+    #[inline]
+    fn dot_product(res: &mut Fr, state: &mut [Fr], mut start_idx_cst: usize) {
+        state.iter().for_each(|&x| {
+            let elem = Self::MDS_CST_SHORT[start_idx_cst].mul_short(x);
+            start_idx_cst += 1;
+            *res += &elem;
+        });
+    }
+}
@@ -3,11 +3,31 @@ pub mod mnt4753;
 #[cfg(feature = "mnt4_753")]
 pub use self::mnt4753::*;
 
+#[cfg(feature = "mnt4_753")]
+pub mod mnt4753_rate4;
+#[cfg(feature = "mnt4_753")]
+pub use self::mnt4753_rate4::*;
+
+#[cfg(feature = "mnt4_753")]
+pub mod mnt4753_rate8;
+#[cfg(feature = "mnt4_753")]
+pub use self::mnt4753_rate8::*;
+
 #[cfg(feature = "mnt6_753")]
 pub mod mnt6753;
 #[cfg(feature = "mnt6_753")]
 pub use self::mnt6753::*;
 
+#[cfg(feature = "mnt6_753")]
+pub mod mnt6753_rate4;
+#[cfg(feature = "mnt6_753")]
+pub use self::mnt6753_rate4::*;
+
+#[cfg(feature = "mnt6_753")]
+pub mod mnt6753_rate8;
+#[cfg(feature = "mnt6_753")]
+pub use self::mnt6753_rate8::*;
+
 #[cfg(feature = "bn_382")]
 pub mod bn382;
 #[cfg(feature = "bn_382")]
@@ -23,7 +43,27 @@ pub mod tweedle_dee;
 #[cfg(feature = "tweedle")]
 pub use self::tweedle_dee::*;
 
+#[cfg(feature = "tweedle")]
+pub mod tweedle_dee_rate4;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle_dee_rate4::*;
+
+#[cfg(feature = "tweedle")]
+pub mod tweedle_dee_rate8;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle_dee_rate8::*;
+
 #[cfg(feature = "tweedle")]
 pub mod tweedle_dum;
 #[cfg(feature = "tweedle")]
 pub use self::tweedle_dum::*;
+
+#[cfg(feature = "tweedle")]
+pub mod tweedle_dum_rate4;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle_dum_rate4::*;
+
+#[cfg(feature = "tweedle")]
+pub mod tweedle_dum_rate8;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle_dum_rate8::*;
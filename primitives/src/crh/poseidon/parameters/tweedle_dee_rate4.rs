@@ -0,0 +1,3186 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonParameters,
+    PoseidonQuinticSBox,
+};
+use algebra::fields::tweedle::Fr as TweedleFr;
+
+use algebra::biginteger::BigInteger256 as BigInteger;
+use algebra::field_new;
+
+#[derive(Clone)]
+/// x^5-POSEIDON-128 parameters of rate 4 for scalar field of the Tweedle Dee curve (= Fr).
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_grain.
+pub struct TweedleFrPoseidonParametersRate4;
+
+impl FieldBasedHashParameters for TweedleFrPoseidonParametersRate4 {
+    type Fr = TweedleFr;
+    const R: usize = 4; // The rate of the hash function
+}
+
+impl PoseidonParameters for TweedleFrPoseidonParametersRate4 {
+    const T: usize = 5; // Size of the internal state (in field elements)
+    const R_F: i32 = 4; // Half number of full rounds (the R_f in the paper)
+    const R_P: i32 = 56; // Number of partial rounds.
+
+    // The zero element of the field
+    const ZERO: TweedleFr = field_new!(TweedleFr, BigInteger([0x0, 0x0, 0x0, 0x0]));
+
+    // State vector after permutation of zero state vector (Montgomery rep.)
+    const AFTER_ZERO_PERM: &'static [TweedleFr] = &[
+        TweedleFr::new(BigInteger([
+            0xa55fdc8b93b514ec,
+            0x7cd72b6d94d6f10d,
+            0x230b37dff97cd701,
+            0x3799493e568c06a0,
+        ])),
+        TweedleFr::new(BigInteger([
+            0xf78a7cc68ca23366,
+            0x77dd1667df4ae64,
+            0x6097bffaab3f37de,
+            0x1968607c039475c,
+        ])),
+        TweedleFr::new(BigInteger([
+            0x16ec2cd6f086a1d8,
+            0x2f03f6e4a5f4a167,
+            0x25a821e77a904a3a,
+            0x1bcafea585ca2f63,
+        ])),
+        TweedleFr::new(BigInteger([
+            0xd7394868e35a5a69,
+            0x761e950e8070ded0,
+            0xb24f9f6d190d91c8,
+            0x36e11c68ff4bed21,
+        ])),
+        TweedleFr::new(BigInteger([
+            0xf53a67f1ba2aa669,
+            0x7f1c92c5e0b1249f,
+            0xfbfaa5a85498f607,
+            0x3da343e48e8b52bf,
+        ])),
+    ];
+
+    // Array of round constants
+    const ROUND_CST: &'static [TweedleFr] = &[
+        // Constants converted to Montgomery representation.
+        // For rounds 4 + 56 + 4 = 64
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4f10677390435fc0,
+                0xa6157b0b38a77748,
+                0xc1a32fe3b3ac07b1,
+                0x2b7b9611f3416e64
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7aa437926a84c313,
+                0x6572ff5871932708,
+                0xeb42e58b0f5850e7,
+                0xa0af4b549026525
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf5a0fedaa976b32d,
+                0x92717c0fb3f2a7aa,
+                0x1d865025d8597108,
+                0x19c717764deedecc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdcf36ad95d44343,
+                0xbb0644590789f3cc,
+                0xb79640e6c0c2287e,
+                0x3b15881c4cae16f9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xea37d8615b3709c9,
+                0xbe37c17bebaba780,
+                0x46b07072bf898bec,
+                0x340b4820ba4f5351
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x16e939f6af20b8af,
+                0x22cc45c07633b021,
+                0x663d07f3496c9306,
+                0x384cea60f674d5f0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3998d0fbd12265bb,
+                0x16026421c54ac62d,
+                0xa1190347d77d3dd0,
+                0xcb7d8539c87afee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe4b1439239a8d89a,
+                0xbf0ffbd8f797da3f,
+                0x24675f04dc2c3f67,
+                0x11999ce617349d92
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc30ece429f7a174b,
+                0xd03dc2a9affe83fa,
+                0xf8b1b49164e5dd3c,
+                0xe0bfc44d6716fe9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3d19905dc86e187c,
+                0x4bbf9a141b90bea5,
+                0x3aafc323e863d54c,
+                0x3b12307fbbbe14a7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbdb560a60b7a8117,
+                0x81f160c284f5d9a5,
+                0xdea2aeb599b6dd87,
+                0x14ba70c73631bf67
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfe33efdd38fae257,
+                0x43ae63d40d2d5c6,
+                0x6aba9904ae76594,
+                0x36da1a7c09a485a9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c955c63c7bdd37e,
+                0xc13b9f334b80a0b,
+                0xc1ec95dc5ccda881,
+                0x379caf8d76edcc5d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x90c59fef53b2b11e,
+                0xe8d7c4391ae352b9,
+                0x41cc263c3d83cf5,
+                0x3ad60f92c2d9c3dd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc5824af2021df154,
+                0x35a6ba10a6c85ab5,
+                0x4fc110961e27cbb9,
+                0x27c3e54513ce78b0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe647854035f89406,
+                0x9cd24e5b5164c9cd,
+                0x9125eae822c9fd01,
+                0x1b8091c73e6efae1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6e5a2d1678aa3a7f,
+                0xe391b4359f492670,
+                0x81a714e45ed795a3,
+                0x12ddd264195b2401
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x327937250cea521d,
+                0x1e70ccdd49163e61,
+                0x912ac7f81bc72444,
+                0xa9d31dc58bb801b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdd525e1c0cf5f8b6,
+                0x1b302ca6e96facf6,
+                0xaf7c4c804513ab94,
+                0x3aca85b9a912f313
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6d6a16b5ea5d4abb,
+                0x132565a3476b092f,
+                0xfbc32a7b4b0bc881,
+                0xf49ce18de7262f0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc6f6084fdbf06c5a,
+                0x646e6a79781b0795,
+                0x8c58ec197700ec92,
+                0x3cf6b25c9f28c4e4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2bd1a10ccc8cd905,
+                0xeb37187363b37f15,
+                0xf43bcd0d83677c64,
+                0xf1ba5b18a947b0a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x16c9bcf07676913f,
+                0x3a1a351674137a4f,
+                0x99a71e8475f01177,
+                0x2042d000db5b0afb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe355a482060f11e3,
+                0x3119f546cd653197,
+                0xae7427c1d2a813d4,
+                0xac0de3c5c2b037b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x590c44dd2e5ddbc8,
+                0x3fd8a9035f5bec66,
+                0x80ccfc5fa0a1cb10,
+                0x1fec5cda1b4275fe
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc3cdb3087d6a4945,
+                0x29384d2a1f40b63e,
+                0xa2018b25969112f6,
+                0x14d5d7304ad54656
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa59d8af4c5f75a7d,
+                0xe83e842d73ddd571,
+                0xcecf8e32a4e42a07,
+                0x6deea4c0603b805
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3d52c06d9f54000d,
+                0xb7151511aa2575e8,
+                0x41161eb5340a03c2,
+                0xa1e157aab5e293e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeb57af0a00497616,
+                0x1ee33057d9e09b32,
+                0xab61b05362cb8c66,
+                0x3af8a4f209f625cf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x167e53a2b127d203,
+                0x46f9525a13b7e853,
+                0xa06e69a29c3126bc,
+                0x246b9e7e3fe4fb6f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf5f203952c0f15f3,
+                0x7c6be77aed77e53e,
+                0x30af6cd057259bf1,
+                0x2a76044ba3686425
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2ee101904dbdfbc9,
+                0x48c3e9175fb66d5a,
+                0x9d77059f16e789d,
+                0x2f939bba98135551
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x281b91417157ae93,
+                0x4498e06dd52c1902,
+                0x122ad389395ee78,
+                0x2d128f8dc3656210
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaa95b0c972eae725,
+                0x2113cde6df12236a,
+                0xdd7fdb75bb00a51,
+                0x20368d1712aeb640
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8eb75831a93f3c86,
+                0xc76c4f5152d67c77,
+                0x34430808ff564ff0,
+                0x3f8dc7becb186424
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8a303941d7e35bf5,
+                0x8e4bc8b568a29d40,
+                0x6876790d322f5b52,
+                0x19f65ab80db89bbb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x898ac98223a49f44,
+                0x4beee3abffc2d878,
+                0xa07de9b213158fa9,
+                0x30bfc70f21313066
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x44891376c8017f5c,
+                0x1b5331d12b94c601,
+                0xc74f61b064b112ce,
+                0x35dfe945cc75844c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5cf9e1b2aeda11cb,
+                0x9342b4d57c9d728,
+                0x316f62ce5422656f,
+                0x2c87eb27ff4ea46
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x59d5667466253088,
+                0x15b2b643e5b83e8c,
+                0x7ae8f60691779a98,
+                0x1d38a78d7054e1ed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x66c862ac15c5fba,
+                0x47b5fce1cfad86a8,
+                0xbb4a67766f1f22e8,
+                0x3d7ab6338e1f27a4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcf7ebec1efb3743d,
+                0x949ae295a4e4c096,
+                0x8d0ce96edc879c29,
+                0x3eb5abfdd7585b50
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2418848ffbc76913,
+                0xc188f987fc94c2cd,
+                0x76c96ce655ec2458,
+                0x1c51c16a5a520195
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6d58e6e056b9a2fc,
+                0xb29a48468959f2fc,
+                0xe47c7c98d2f0c240,
+                0x36ec29286c4f4a3f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeea24ca6d44c221b,
+                0x318bfec6dc45acf3,
+                0xc16b550b42ab47c2,
+                0xa980f94a9402832
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5fcb19f0c9f220ec,
+                0xd6cac9fe2831041d,
+                0xee5ddc1538356b33,
+                0x399e83184f3d1183
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x237ad2126c047f26,
+                0x74f4aa5b00e75f46,
+                0x5b36f221d1903049,
+                0x3d84db49b96d1dca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbcc0f0a6c9e45d6e,
+                0x76c002a25e2c5443,
+                0x2d484ffe2c5b3ba9,
+                0x1005a0c3810f0d4e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3823aaa2ff996a3c,
+                0xa414c10c25955987,
+                0x34247e605dc42226,
+                0x2a5bd0bc2bf15047
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2857df4fb3273794,
+                0xb1974e4e9f1d1bd4,
+                0xa68f8445b3adf7fd,
+                0x282aa0243c63f6d2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xad174d84d5bd1b22,
+                0x27a35e101c5bd939,
+                0xe16555993043b07c,
+                0x1fc335486750e03f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xed045796ce0998c4,
+                0xec07c45c162ccce1,
+                0x5f6f6723f798e1e5,
+                0x2ab6340fbdf29d4d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x60a33d9bdd1540b9,
+                0x751bfc3fa5b7e00c,
+                0xe9f71b828e510da8,
+                0x252c87b4bc1b1f8a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa78fbb3202db827c,
+                0xb08e02075816023f,
+                0x7a860376d618c6fe,
+                0x1d64a98b75582faf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf1c6eec595637701,
+                0x10de761a22a55b9c,
+                0x19945f90b0cf9d43,
+                0x2921070d9447e8c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1e235e80199c5e38,
+                0xca86d132101da887,
+                0xd6ebc0b349a585fa,
+                0x2d9c3f949e97b4e5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x52c62c13841217d1,
+                0xec8f053c292ad731,
+                0x1898ac3de370bb4e,
+                0x1c1f31fc11beefe9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2c1f13c47b79d018,
+                0x6845de82e45e6cae,
+                0xe55b9e33bffb4ab7,
+                0x34006281b9c4e6a5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x89f89908bee7b5c4,
+                0x80bff0bcccb80b76,
+                0x1286db7c0d5e9f14,
+                0x2d845f403362abf9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa5ce080537c58163,
+                0x226ed7a078894e12,
+                0x139e91b20f133395,
+                0x9a7a16fb3ee7f10
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaba64be8f9bab5d9,
+                0xb0290035bac25b96,
+                0x3c346d50637d2237,
+                0x24641a609bb891fa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5dbe2e34b9f3a77d,
+                0x4ed179be398e4092,
+                0x88a26a835903c5c2,
+                0x3df8c197976f93b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x95728b371820bd95,
+                0x69ff6b4b45dd43e0,
+                0xc7cc6a829ed824ff,
+                0x313c0edc374a4f41
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbbef8cd8aea5ab54,
+                0x1eb8bb2be2dbc169,
+                0xd24fe6253dba5b51,
+                0xfb78e22bea01860
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x90dd9a82b7f3a783,
+                0xddbe0ddf0c7ea16e,
+                0xd81acf152ed1c7e3,
+                0x2125e1ae4f37347f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6b9adb517d5504f,
+                0x2cd52bcc550f289c,
+                0xb0437e33893e1fc2,
+                0x6a83ee71cabe2ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x83e57d3fc31ec0c3,
+                0xd2d8778470f876dc,
+                0x75b94f26472893dc,
+                0x27dfc8a2eb508309
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa46cde04d4d478d6,
+                0xef518f7f859f3223,
+                0x7b122c38cc678dc5,
+                0x29b8dc28a50471ad
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb074232d1025a338,
+                0x2ec140601bbb828a,
+                0x53cbca4722c21510,
+                0x1ed556e9401c7874
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd3be9ce9f7fa7d25,
+                0xd02ec74fbdf468a2,
+                0x10f6b04e4705cd7d,
+                0x2972c6c7941ed7d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5ee0c3a586b5a4b,
+                0xde0060117793effc,
+                0x797f1842f525b147,
+                0xb0b92a6383bea6a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xff75a5fd0b1b1c50,
+                0xc07ac31d7902ece9,
+                0x988429ee52d0a75b,
+                0x1d29151190123659
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1390ac84ab91d2d9,
+                0xbc9f3f2cfcb4d95,
+                0xa895d772cb0cd773,
+                0x23c84d9c2670c6d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe2d5fc38fede211a,
+                0x6206f8e223f9a1b,
+                0x1a2ec3142d91f3c2,
+                0x321d0cc3a8f38b75
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9bfb9c54d64f8e30,
+                0x2f41e4010702c7c8,
+                0x71577e5e45d710f9,
+                0x1888f0725c077b65
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf3794e89a7c0a702,
+                0x8e3d29bb2df95dc5,
+                0x479d05247aaaabf7,
+                0x2d2252a0af59a0f1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4daa1490f0001e2a,
+                0xd8449306218a4046,
+                0xa4ce2a0f02e93de9,
+                0x30ae8bc7022f223
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x637ba0d459a9abf9,
+                0x905e9ceb06adfcc6,
+                0xde0a0d61a93877a7,
+                0x1c19973759c9ba10
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe376e725783ccb94,
+                0x5870fa90081a054f,
+                0x7e497b10737dc53b,
+                0xba62e49a961cebd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x519e405430bffd1a,
+                0x9ddbb3f2a430b009,
+                0xabdc9fca6986cb4,
+                0x28920e090e5c1755
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x576560bc9524a73a,
+                0x1fc148a3b94f582d,
+                0x354f2b508f83834c,
+                0x29a641b7b7c55c1f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x17b648774fc61291,
+                0x3a42ce9065dd63a4,
+                0x8e0be1197e24d8cd,
+                0x1c64184eef7e14fb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc29aeb6fe529a4dd,
+                0xe85a34a4036f58a5,
+                0x2d6daffc8b46c94,
+                0x321e009ad99cf02
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcbd6ab8868f8a8ed,
+                0x823db1c9eda920e9,
+                0xc75ba10fb89e13c8,
+                0x39547bce13b8a2d6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe2ae8f0bbd281829,
+                0xb296778ddf754250,
+                0x5cd23c2c615c0328,
+                0x3398e0e1f0a82784
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd6625274b1b007ed,
+                0xd8de3b428d207d04,
+                0x65b3582c0ad46d3,
+                0x2f26ad38bfaba06c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdc805f8ec80b559a,
+                0x386bb602f8d685fa,
+                0xf7330fb9c331dd45,
+                0x145f6a2d54b568ca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9c53f72d88753eb,
+                0xb94556009d4e121f,
+                0x2ea056f50329017d,
+                0x28cf411348bcf8c0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6df187e3397f135a,
+                0x35d839b9f639ba3d,
+                0x5e0d76b37374cbfc,
+                0x6c40c59e0a387f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x751b08a97a6e673f,
+                0x6e58d1a5e66f7a00,
+                0xa1502501a794889d,
+                0xf881a6036ae19a2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x68a18bda0ddf31f3,
+                0x4adf8fe24e0a0bac,
+                0x41054fe06bf9e99d,
+                0x2a8c6f59de2334d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf5d6d55f5b4338af,
+                0xab7851b74895e5,
+                0x229afe59897231ed,
+                0xbae2a1b99b86f5e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf6e9079600242fec,
+                0x90d20bc66affddd6,
+                0xad0eae6d2a9e12fb,
+                0x1a9c9796d7c50160
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd95b3e6e0424f423,
+                0xcbc6ef7bb96d6c3e,
+                0xd91b17b892809c2a,
+                0xe8e36af547aac86
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x74d16c8dfca2c9c5,
+                0x15e771a0dfe2e988,
+                0xc7a522344bf5a7c5,
+                0x324c17c65d288ce0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6eb2ad4340b27f69,
+                0xfeb1725036c82ecf,
+                0x7b11a2e3f90f2e13,
+                0x29a03d6693221239
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdf81875a7616dcd9,
+                0xc070a0872b9541f7,
+                0x5fd9fca147893f4b,
+                0x12b5762ceee55259
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8f7337f4f008f146,
+                0x49ba29a58698ebc1,
+                0x3e1cdd0013505727,
+                0x387ae637963ffbb8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd1def8ba267955e1,
+                0xb7256a142eb1082b,
+                0x8a44ada380d69ca1,
+                0x3f11b908be68dd0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa79d10dfb7302b6d,
+                0xf5c97dac362a16c0,
+                0x4a4bb8a4a9925c92,
+                0x2c47da17509e7999
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x625f65e816968ff1,
+                0x98690df5701d87f4,
+                0x4e7f4e0515595a62,
+                0x20d34c15aa63cb28
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x51d1457d8933ff66,
+                0xc90529725c0c67bf,
+                0x776e0253035d85c0,
+                0x3cfdb45db6932c42
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x50f8761ece1578da,
+                0x3be9b52121290b4e,
+                0x1ee0277057651948,
+                0x2726380add2ef2e8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x98ad8ccb5a68426c,
+                0xeb52c5136fc0c5b1,
+                0x512e177228329d38,
+                0x28f8dc0379f1c51c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4f032c8c981e82ce,
+                0x18a35b34e7898c91,
+                0x4232b2ae103642de,
+                0x270a96189e91505b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb44440ac273326d,
+                0x464e48058fe4e7a5,
+                0xc3e9695a6f88f28a,
+                0x19df575e62661
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9138e6d3fe9507f4,
+                0xac39142dbbc35e8f,
+                0x289748ce8c25eb8a,
+                0x2043d88440650413
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x447c21db66127a9a,
+                0xab9a64f7a34fb2e,
+                0x682572d23f25dd7f,
+                0x3d7c213fa25df6cd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcd92c0184fab1082,
+                0xd3b5154bcfb0ce14,
+                0x134b22d9f8a57a59,
+                0x2d87fa8945dc4b69
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe065eab0016e7aec,
+                0xb22a4a25e25a94d8,
+                0x338727b3f8fa026a,
+                0x1acc9635f76da07a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7a435ff838a352ae,
+                0xac51ce2158d1170f,
+                0x8c1e15f0533ff1bf,
+                0x9cc839fb0e71d41
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x84e50c79fb3d97f7,
+                0xe488a95f5b09a916,
+                0x8cd503cb7fd4df36,
+                0x3f05bc7ba3facf88
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x63a6df9f11481289,
+                0x50f82d8401e5818b,
+                0x9676132f15caed5,
+                0x3c877076a2adf63
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9a3dc893ddd882c2,
+                0x78885729a3b07617,
+                0x2ad52a159a783895,
+                0x107a4ce9eb82103e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79a61434323da7fd,
+                0xe7bf9819e9833254,
+                0x1280bf92ad2db296,
+                0x28837ac03b0882ed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2caf34495477552c,
+                0xde5193ad12db5e4b,
+                0x1ea1badf2b2cb2e,
+                0x2bfd1437a6f21ccc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4c9448a28571f963,
+                0x20a6487a0ef51a81,
+                0x3c317097696a9930,
+                0x27d39c41c41336b2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4c6b4d68d51320a3,
+                0x95ea2af57c04d120,
+                0x862da3cd4f6e2d78,
+                0x3110f1d7a7cff2fd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x84ff1d744b6cf7c8,
+                0xf6cae068b7316153,
+                0xf751833bcf5937d9,
+                0x397eced9e606258e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc68a8b9ae3d8c69e,
+                0x1839767bf8af9144,
+                0x10dc114f7b264a1c,
+                0x2c5b5e77f1390e34
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x649d18ba9aa9015d,
+                0x6834cb882f16791,
+                0x831198c0547ba82b,
+                0x19289fb56dabcd30
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xadb2cc8aaf823718,
+                0x1c908036826d3e9c,
+                0xac062a0152157352,
+                0x2333736c0e93d920
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6ae7b4b4b89270d3,
+                0xd78366b6d4b1215b,
+                0x472c345be16326ea,
+                0x1606e96bdc1b80e1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5377159218bd31f4,
+                0xd61cc64db5ecd40b,
+                0xb9991ca57f916df,
+                0xa44347eb0c8f6f2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa5d7749179a5c134,
+                0x5c7210ad2dddc84,
+                0x7e8876ea05c15495,
+                0x36d9a67cb0123319
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8cc9ff8d04252840,
+                0x77270d022da69c90,
+                0xb508f612358293b2,
+                0x3f4333c4768e0505
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xade11eaf4e7fd73d,
+                0xe0d35b62544b34ef,
+                0xe27689c8d2b8435a,
+                0x1e9d21fd59125250
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x56714941f02ffbb,
+                0xae44272d10e00af6,
+                0xc3952d4964daa9d6,
+                0x1028e537d923830e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf24835db22de8ae0,
+                0x9773c8bd5f93a4e8,
+                0xadf67d1a25a32aaa,
+                0x390b847da3bad2ca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1d175dba4e47ae95,
+                0xb492ebad8b40d5c0,
+                0x960713ef9ac1a85a,
+                0x39f884010a61760c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7ceb3c6581c13e55,
+                0x44798412ae562b21,
+                0x15b3bd808da7786f,
+                0x1b2e1202cb97accd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xabc4cf86a792f0ba,
+                0xe1b84c21df5e8b05,
+                0x5771a5bcbb93d14,
+                0xf5f8b3ca424459e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8f72ea64a0f02862,
+                0x80d41e248010f6ca,
+                0x9013a8413689d4f2,
+                0x3b58cecc29c1a9e9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5fde3c0a7d489b7c,
+                0xf3c747387cfb584,
+                0x33dc17e62c20e0f2,
+                0x29470ee91afb3e38
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xba20b8a614d45037,
+                0x99a056c7b46efe1f,
+                0xf92bf2c66b536d18,
+                0x25b19caedecc5790
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x70fe8f593b2ebad9,
+                0xa5097b5dd1b39c8,
+                0xb32c90e0c56e3f0,
+                0x521212744bd051f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaefc6ad5e9233d8d,
+                0x788357fac4a8cc13,
+                0x7a4438805ed9b9bd,
+                0x8b559cd71c756d1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcc36bda7b9bed20c,
+                0x5d398590fd879b13,
+                0xc4b67aceade82d45,
+                0x1fbdcaf10cd0ea7b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9779418abe3e97f6,
+                0xde9b78c5ad534119,
+                0x10a2ffea58a8cc2a,
+                0x2fed1c8db837f168
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbb625cd637de8272,
+                0x917b063a94ec9bb0,
+                0x6a44e65771fd8805,
+                0x799d854b7b7af52
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1580eca1dc498596,
+                0x89e9e750fb350c2a,
+                0xca0367781858fef5,
+                0x255694603303823e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf79c6e70e530b004,
+                0x6c3fe50987d74f6,
+                0x5a743412f0dce75f,
+                0x29226a037f5e3d62
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x49bbfa9ebd533f67,
+                0x90663b32d74f8892,
+                0xd014b09aa90169f7,
+                0x2b7db02b96a6b160
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4dec7a276fbb08b5,
+                0x4d43d3b608bd995c,
+                0x69839e89bc4b67fe,
+                0x1c284e247f343cbb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x28a2405b6d78e032,
+                0xc0aa89c5e292bdda,
+                0x6dc7a42f3ea72578,
+                0x2ccaf0c1cd2a63c5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9dbcd78eda9215b0,
+                0xc0b38225bd076532,
+                0xce6de0e85836e348,
+                0x5ff5aa861cbb511
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79b707a0c04cc8fa,
+                0x8e3dfaabc501b10e,
+                0x74d3d43e717f6b2c,
+                0x3c814faa16d303c1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6c30578a5cd91d1a,
+                0x9cc82fe6221c6ffd,
+                0x6fc15215f114ce69,
+                0x58f67d81a9e9fc5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb5500f0ad52a6dd5,
+                0x4110b8d704ba72e5,
+                0x1b9d58eb5fd49c82,
+                0x3ffee199be1cb686
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcdd39754fbf09acd,
+                0xb3d597cbc4011262,
+                0xd45d8e8f6bc6a18e,
+                0x1e8a13ba38068c27
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x88dd7bb5580ded03,
+                0x3e566f3cc12e9955,
+                0x8d5a49bcd52bce05,
+                0x3e59e324992615c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2cfe15aea1f4a2c1,
+                0xca8be2e6537d107c,
+                0xe59f121d20b96cac,
+                0x2c5ba66f8304e08e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfcaa614bb36ba558,
+                0xc85f70a33ff7d0cd,
+                0xa6f66e5b166963f5,
+                0x2cbb9304ebb994d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3a73e4db1a3b9787,
+                0x3443f2097d11cb6c,
+                0x7c9ab6ab3691a92a,
+                0x581b5e50bd32b76
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd647ce67e9d8be7b,
+                0x21e4dc516f93e55d,
+                0x8c7c2aea87e2a1f6,
+                0x74e878d57d6208e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd3c512e8984893d1,
+                0xb17bd679b509fd96,
+                0xcd7b2e1c87d9ed0,
+                0xbe201a70958a067
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf2f37c27f5d61a73,
+                0x785c8be52d9502ee,
+                0xdd4f149a9c85b13e,
+                0x1130d1a4fb4e88df
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x28d40ba64e2d08b3,
+                0xe2b8f397400ee5de,
+                0xaaba0512347645d5,
+                0x686697b626c811b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfec6352f8094a7e4,
+                0xa07ec17eacd8d76a,
+                0x47f89a26fa688783,
+                0x1c2eeaa9156f1e79
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8d8bb1cb5685a151,
+                0xd5cc9381024e488,
+                0xa18b207474d90c90,
+                0x38d3d6aaf2415d5b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3b45eb7626e1423a,
+                0xfabfe081b8e897af,
+                0xbc69aa13f6c759aa,
+                0x4881f869947d762
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x32f48afe60cc8f27,
+                0x24a0cc801e84d164,
+                0xfb20eea280d819cc,
+                0x13de8b6bab18822b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9f846f5bbe78cc72,
+                0x7272e94f7bb52eed,
+                0xd9297fe7bbd371b1,
+                0xa9be588a9416fb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3a20b6664090ef82,
+                0xa3e55cd33f85c232,
+                0x48a46dbd68922afb,
+                0x21490ed5ca6a23db
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x81cf49a5a81b74b7,
+                0x160cab493fd22d92,
+                0xa3ca6b164fc7d8e1,
+                0x274ae6a4e0030bbe
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe28fae287e58ff8d,
+                0x734bcd351fc53c91,
+                0x18c00f46fd2f6d9d,
+                0xfe4f290e944844a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x13c915eb97468619,
+                0x136648bb0d96885,
+                0x439a8d2f47fcd073,
+                0x1bab6df11d007ecb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa19596aca0653861,
+                0x3357acfafea5dd40,
+                0x4465f4d441b0eb86,
+                0x33bd608569fdb150
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3200d429f350eb97,
+                0x851d6213a0d49e99,
+                0x8d70d219a45c2013,
+                0x414a612647adeba
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2539b93179f10b0e,
+                0x78729628928e7389,
+                0x222454e8a2403a7c,
+                0x362ff1cb721fb2be
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdaf3a8068df6770a,
+                0x5066aa5be1aad8f8,
+                0x6f738258eb060bda,
+                0x10c736f8090cde2f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x416094917b47842c,
+                0x92e082d5226d3e15,
+                0x3343320d18e9ed2b,
+                0x2321750db1e50a0e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe0ecd600e13824a1,
+                0xba2fadfd49424cc8,
+                0xe0d1edd7f4fdd923,
+                0x326784752e1065b7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x55f3527b59f42c5e,
+                0x582fdd16d8b37c0a,
+                0x48833614f04028b2,
+                0x26ffbabdb3825291
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1d80c018932382a3,
+                0x1fe36a464b2eef53,
+                0x97cc3d66c3ba61dc,
+                0x2ed6be726ba4c617
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfc547b29612364ff,
+                0x3a885470bcce7994,
+                0xa0254d6905cc86a8,
+                0x30dcee31354eff94
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9c740c0aa87e498f,
+                0xb1609dafda81a3ff,
+                0xb020070c7ea105eb,
+                0x3d97e33204ae4f78
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x136f81333f59b677,
+                0xd7c6e131bd29c4aa,
+                0x71c905e47ba9c455,
+                0x3ff508293838a0eb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x845cb12f3785df32,
+                0x5dd845c4c0be689e,
+                0x8601af8a67c08338,
+                0x139cff9fc5d71e8a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67ec9ff2796d8ff3,
+                0xd874a616cbe83ceb,
+                0x4411517362be6b8b,
+                0x105c33013a208d81
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x36b1c9c9e04efd5b,
+                0x62fa5b961026045,
+                0xbc582e630a598239,
+                0x32b215db356dadeb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa71221a9df1a6872,
+                0xce00ea432d7abb64,
+                0x1899b3ecac3832aa,
+                0x39fc4ae39f84c176
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcf5c2a2e0236d287,
+                0x3f586e7dbf02c790,
+                0xed2ba516cbe1f2e0,
+                0x34f8ed30cf37be7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x94da315d1235b8ab,
+                0x3da86b20ede7069c,
+                0xa171fa1223b35d36,
+                0x17f02993e93e5388
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1bef69dd1912fffc,
+                0x3641983742b44a16,
+                0x7e388e654a848ea3,
+                0xdff741e6325bb5e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb14ba12429380874,
+                0x253acba85818957f,
+                0x5a0e9df15f20a4cc,
+                0x246dd50c6b71ebd7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1c7b2b362f0b8913,
+                0x84db03db12266e31,
+                0x66435157cfe619fe,
+                0x259541a28ddb0c8d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1dde377661397421,
+                0x1fcfb2ea3a62d724,
+                0x2b5956f19848165a,
+                0x51d34b7e42a571
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfd59cf8614988129,
+                0xfd8bcba04a70f467,
+                0xc84b10ad6445c8cd,
+                0x163838f815e2fd04
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe9acd926276df9bd,
+                0xf9a8194ab97c7a0a,
+                0x7735bd26379fa4b4,
+                0x1d9929d6bbadffca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd103a2efd0a1d627,
+                0x85b6d6ebacf91c73,
+                0xdc6d823a04f0aa77,
+                0x28541483089b56f0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8b4af70dabe89906,
+                0x1a865f31d81a42b8,
+                0x898d731d42b82ca6,
+                0x12c7a26a86cb18aa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x22d22fd6b69f3754,
+                0xb0f2e153c46f2f1e,
+                0xfe5d6e3e62c3f831,
+                0x3cab1990cfcf94d1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4f17d2eca507fd04,
+                0x94fef5be25dacdc0,
+                0x8c70591e35216d10,
+                0x6a54e766026de3b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa91deefe98706efe,
+                0xe101573d0efcb648,
+                0x94f104dd707980b3,
+                0xb1409551a9b8946
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3ec09bd66c683987,
+                0x6a2d4b8fb88178f1,
+                0x823c1413b0b25725,
+                0x3fc5a892ce81b1d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4e9f6d4639b136b0,
+                0x3551e82feda04c01,
+                0x57942ec0a323c6c,
+                0x2140559a62b91498
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbfdb9905ac4bf0cf,
+                0x8c8e7e890063730b,
+                0x706e006b2dd9891d,
+                0x151d291ce7ac5332
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeb4c0e9f5658e9f0,
+                0x75eb7a5e0d6b8893,
+                0x9751eefdc6760e15,
+                0x2319e9127a2412a6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x76c3a643161ab012,
+                0xc143925061cc41a2,
+                0x561d1f80837539f3,
+                0x3596872eaf741aac
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc5b267c0df8a13d9,
+                0x14a865ed74abf3f1,
+                0x7a5b599a9f3996e0,
+                0xa89d69ee84f01d5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9abbd4c9345bdce,
+                0xf66b8d97364a1690,
+                0x20b127a37b5c8275,
+                0x84094784bb09418
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc39d4b1b0137139,
+                0xad42ad26fb3cbe1a,
+                0xae98be62e20c7c57,
+                0x27e646fd6e6787eb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb91baecc93a2959a,
+                0x4fbdfad89f124b79,
+                0x54fdee7c5b002bc7,
+                0xb0410ff8c676421
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe545916eaac822fa,
+                0xf21ceeb82749c3a,
+                0x6ad8c99ae31684b9,
+                0x3d88067314eac64a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5a187a3edc312fdf,
+                0xc06a1ccbc87f27b6,
+                0x15abbcdda716bfbd,
+                0x33857025da7d67
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x42ca89c7d769c43,
+                0x725cc3ce9c94bfdf,
+                0x3c407692fe3fcb1b,
+                0x3c6551af9cd6ceee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x103df6ef000f2258,
+                0xdb59ff5ba54d6735,
+                0x815319338824d5c3,
+                0x116ee0d1b1ac522d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x86ada195cba7f05d,
+                0xa1b45ff24e1d5499,
+                0xd2a4da0f12391938,
+                0x79fbac421b25af9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x909c0d945a782ccc,
+                0x487c4bb157a6be1e,
+                0xc086545ff1fa12,
+                0x2e669aed411a5da4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4b41b48dd8da633f,
+                0xe6b861cc01eabe25,
+                0xc86849f0ab742f29,
+                0x2a68830cbefc8ce4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5971b9688789916d,
+                0xffe6163501490cb4,
+                0x38f70c733a518aa3,
+                0x3ce653449c4ce3c3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x413de1021a9e61f5,
+                0x5946e3bcad0906ba,
+                0x29ad8ac2fa468f2e,
+                0x2d1645897ab094ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9e909dafd6ebc224,
+                0xadd7b9998f962c99,
+                0x9a4f9b130c8f088,
+                0x1410417f0f49bd10
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb0c873259d06133a,
+                0xd7490473ab33602a,
+                0x3ab17cbaa34d4522,
+                0x232d43ab79e5ab3c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe8096a99b89266cc,
+                0x14d477e1cbe1992,
+                0xf6fcaf26aa351678,
+                0x33dfc428d9064fe5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x27b6cf57e7ced4f4,
+                0x524f8c0f0440b130,
+                0x7839094f747b0bfe,
+                0x1a609d5fc5144fa9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x185e5f6c1e9f09e,
+                0x14d485dbc9af72e4,
+                0x803913e41b8af279,
+                0xe7ef3a025d89474
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79cd19684df569ec,
+                0x3a1a9648adb46ab4,
+                0xc1e68feabdbd3b36,
+                0xb5c7d12612537c2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe45b037bb24dad39,
+                0xad970402f80b7e1c,
+                0x299ce4964030e794,
+                0x3ea3f93f6ad2f5bb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf96f225c43bff2d6,
+                0x7ec9409bb9408ff9,
+                0xa9866706575acc2a,
+                0xdcdf2c8ab41b185
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe7e61e264027e437,
+                0x954ef1459359f3f5,
+                0xf988f74ca118f901,
+                0x30959ca3be91c099
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x66e37360dc47f806,
+                0x5537cd8eb1662cfc,
+                0x8c040bd5a2d2cd28,
+                0x2614c56f4e5070b2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe290a052612bbb99,
+                0xe901327738cb234e,
+                0xbdf32af3e82734f3,
+                0x32d38038043ac5c7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4495febed1f32634,
+                0x3b52110843e4730a,
+                0x40a72a467a8f16c8,
+                0x1792aa70a8a71ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x60fa16688a60a51d,
+                0x4a59a90433733437,
+                0x82357d4df027fe90,
+                0x1d0892044c036d87
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x87019fe21cec6e4,
+                0x2d0aa8a2d3d936f7,
+                0x30cc9535bc3be214,
+                0x204e12bfbefa9e59
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb7c8b66dcc947714,
+                0xa88944b730f7926d,
+                0x818f55d6ff74cda3,
+                0x3b605228124a3c15
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd6ed4407c613f2c1,
+                0xbf14602e34d04560,
+                0x42fdebdeacacb2e0,
+                0x3ba0a32b6b2edbbd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xce869aa120c0afe9,
+                0xaf8d4ddbfb5594bb,
+                0xdd66449997d03ce7,
+                0x3c8f176babedef72
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc7d9ec239a08bdad,
+                0x350b899fecb8e4e7,
+                0xd3f7230b0102bb16,
+                0x2586f00a4a84b57f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x29cc700d40e87520,
+                0xd8c6b4936c7e5fd1,
+                0xae9134cb8f80c0c,
+                0x2e52626942f4930d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6077129c7bf5269,
+                0x4f2c3c7b547036a3,
+                0xab85ab4ff3223329,
+                0xc63fda297b9e883
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xda3dcd9709ea6ed,
+                0xe9e0512ea8d2738e,
+                0x512a182019721045,
+                0x35cb48c0707e8adb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2485bcdb10243b86,
+                0x525afc867af8f00c,
+                0x684e5405f3a9364b,
+                0x3138468ebd88ca6e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1a4fdc65c1128c1,
+                0x5462101a390ab464,
+                0x6490e27c5ab7c4e7,
+                0x1d5c0859cab67aa7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcd894dd315fcfb02,
+                0x3c0eb65cd070b2be,
+                0x41466b836ee636f4,
+                0x152957bb6ad144ac
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x58c6e21c81fc0a44,
+                0xfa3fecfc52f1c04d,
+                0x17d43a74d58278c1,
+                0x98bc03c57524a1c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x46839a47760bc862,
+                0xe36b495768044bb1,
+                0x73749ed3e3fa173f,
+                0x79cb89ca1726d4f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7478f1eebcab5458,
+                0x9c6dc8c317e02673,
+                0x7cbf34af58971451,
+                0x4e2ec6f201383a1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeebc61ced8014cbe,
+                0x4d5e740ecbecf3cb,
+                0xc169d92f134dc79c,
+                0x2e068f8d2ad2c3fb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xce7ec497339948a7,
+                0xde0946870744ee37,
+                0x7d338606b75a81b0,
+                0x1934c4307889ce0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x76caa08eeb306579,
+                0x26ff135826d84d9c,
+                0xca92ef69916de226,
+                0x3fa958ad567c2e7e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5eb594de4ead1abe,
+                0x81bd143824a30241,
+                0x4533791a8a5a6b52,
+                0x4e79c31c0348cf7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x62a69843da667805,
+                0xea8bcbb87f033041,
+                0x4648c2c878b5e21e,
+                0x3298986d92bc3a87
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67ba9cca3b9b581e,
+                0xa6fb9596d7a6b33,
+                0x29e50ec72bee6fe5,
+                0x23dc38ebc5737338
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2b4fc1954cdba8b4,
+                0x572a8d4a7576a66c,
+                0x442fd5bd34793505,
+                0x26b437f574596ecd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd0f92ae5769bb13a,
+                0xd4f6d836b53a47ca,
+                0x777e0d34e23319ab,
+                0x326576f2fecda4d6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3bfe73449de7e235,
+                0x79d19a59e8018fa6,
+                0x46951725f596e721,
+                0x3bcd2ca28dbc496a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xece3fbe10d5d520c,
+                0xa92259d33b497f38,
+                0x73b7c1a80c28ceba,
+                0x1899096d8ecd1209
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x30bed4795c239c1b,
+                0x2158dd1aa5cfd099,
+                0x7e7d0b08e98f5ab0,
+                0x1aa581586be9884d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x645a9cacb2b8e385,
+                0x38727a7a5aabe4c4,
+                0x6092eea2eeee368f,
+                0x2ac7ba14a4c60fbf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5b60f6df9d7ca3cb,
+                0x399e0706e84e9ac,
+                0x515528116332f6fa,
+                0x77583642d85fbf3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1b1a8153d5c4cb08,
+                0x959ccd39f9be4a2e,
+                0xa05e07f945ccba5e,
+                0x2882baa3d1e967a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6a4add4e733e8688,
+                0x30029b9dcb1a0c8d,
+                0xc852cd9a7afa1403,
+                0x205a050c9390ddd4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa562e38c41d4b011,
+                0x76cfe44c32121a57,
+                0xe3bb7f5c7cdafc2e,
+                0x1ed39c2af2726494
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x86e77087ab57d95f,
+                0xad9d38d9459e1b18,
+                0x66d76dd243d9f094,
+                0x3f270b7821601051
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf4c57fa2ea32e255,
+                0xc8e911497511d188,
+                0x88a0e07822c05b1b,
+                0x3a70f354f686cd9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd444aef4377f94cd,
+                0x5dbdd775f4c47f6c,
+                0xf1196959b309edf0,
+                0x3ca3e1f0562aaca4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x548f354c873bc972,
+                0x96df245f667c0b10,
+                0x56a3c1f80ee5d5c1,
+                0x150b38f1da580c44
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x13efd67da02ae564,
+                0xbf3c22a02fa91e51,
+                0x340fd959e0caee03,
+                0x2c146acd65b3bdca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab2cb167bc9f4b78,
+                0xf9143e5a86650708,
+                0x526a048352a2cf44,
+                0x1d5d5740e028ffc6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe40f0dfc685b45f7,
+                0x585212857b413b2e,
+                0x4f1f65e736421a07,
+                0x1771ee69a199768
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd53bba1b1f27326f,
+                0xc8c1c628e13682f3,
+                0x9a367c4d59feda4f,
+                0x39c5bc6d06dc8287
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3cc6758f381a5cc2,
+                0x849c8e4ae4ec359a,
+                0xbab408928b2a35ae,
+                0x333bae8da8ec6ff6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb7eda8af27ea1f97,
+                0x2eb4fa77835ff976,
+                0x23187f65b7fc89dc,
+                0x365d765828816672
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3ab4a99eeb6b370d,
+                0x502008a311d1c04e,
+                0xc90c5b93dbd1ea56,
+                0x3bdfeb6d0313d215
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1c6ce1c36def67df,
+                0x269f1fca5018490c,
+                0x6a374753b42e082f,
+                0x4902d7a6822bfdb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xea800544ffd13e57,
+                0xfcb62b7f76bc1d99,
+                0x30d78eaf1ec23c0f,
+                0x21db6e4bc8cc2ed4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x695931d91635bc69,
+                0x8079759c098273ff,
+                0xb2ad3fd35da52cea,
+                0x2f094a5520d09f37
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1c0f6d00c86bf80f,
+                0x29dd1331e949d479,
+                0xf9e27714c3e3b0f2,
+                0x963e11f55b67905
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x81fa79bf1c0b4df9,
+                0x65be3f44e77648f1,
+                0xe68bad1bf93da801,
+                0x1198295b1bed4a7d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1f635643e67effe3,
+                0xf5876b28e2a44d6,
+                0xb3b1e87966fe19e1,
+                0x88c4534f6289a8c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4570867857d85643,
+                0x97290475c31063f3,
+                0xb64197953e970edf,
+                0x3d4851a2e3b3f775
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5cf281fa400a69cc,
+                0x480759b2701f8fd0,
+                0xdda0210ebbfb22b1,
+                0xa1e488719f1389c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x39a8cf03910aa8,
+                0x833f3d370f11f57f,
+                0x91051c79d3eecfe,
+                0x2e2ae3d34cdd7896
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4aa565ef5f8c81b0,
+                0xad494674103c1cd,
+                0x99b4b010444fba70,
+                0x12362d4c16b454e8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe01efac86898db13,
+                0x3249f7b00dc280e2,
+                0x499621c98ba3819a,
+                0x187e39d70d2034e7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab1bea62f558a93a,
+                0xd1a594256077fc1e,
+                0xea0bc7796ab11f98,
+                0x200257822da7401f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf960aea36a961941,
+                0x947e7a095333bb24,
+                0xd5bade8354ffe46c,
+                0x1940df20791359ac
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x210c82f59cbbf384,
+                0xd391abe5b059bbaf,
+                0xe41245257449662e,
+                0x361bbaf86937d15d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x72caadbf44fe489f,
+                0x54d4647b6209b17,
+                0xfc8a100fb45ec988,
+                0x1a3ab3aa0b679433
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x87be8bc04fa6f554,
+                0xa4ff1bf5d35e759e,
+                0x22eda0a02792890,
+                0x8c513aa6f65cb6a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x57a37351fd805e98,
+                0xdab49ed5705e8bb3,
+                0x779f775a1221aa32,
+                0x2374d39c4164c81f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa87b11d2932c447c,
+                0x19f985c43b216065,
+                0xf66d234d0468c545,
+                0x1c7627355bceb7e2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6ef7632cdfb4aee8,
+                0x7a52f8ae2063f746,
+                0xd4797496fa96a31a,
+                0x74f53455e621304
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf1fedc1efaac3a49,
+                0x591e3fdb4136bbe5,
+                0x9054b42f6712de93,
+                0x97026c06d9882b3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4d65f9eefa453d29,
+                0x2635a6ce958f22bb,
+                0x12e18c2f926a57c5,
+                0x1e3e27590a97cb48
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd7bd59afc29552bd,
+                0x203393a96fb1735d,
+                0xc00112ce85ad59e4,
+                0x1fadaa29b90c4378
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x907566a83ccf51e,
+                0x1e651958a839b02d,
+                0xd9deae15c03c367,
+                0x2aa748692067f83
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x31900bb5135884f3,
+                0x39335de5dfd2d76f,
+                0xc6e8652c220ea1fe,
+                0xfbe44754552126
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x406ab5ba554faaaf,
+                0x74debbea289cb97a,
+                0x2e7d88d1d84d8aed,
+                0x219c715bc9a09aa1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x10277f728a5dedb8,
+                0x5e357c4a4518d563,
+                0x321055ac4f89b27,
+                0xc0ec8f83319a8ea
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6f8429af182bea51,
+                0xfafe4db5a34b4a6e,
+                0x102a1ec77d6b3710,
+                0x29999da3c7d9784
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc18b2e4a6955c654,
+                0x717845c3ef4dd3,
+                0x946d41cfee9462b8,
+                0xa34cd9d18dabd44
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x83deab0fed340fd6,
+                0x46070f00a4a5ec39,
+                0xb4fc71069e26f34e,
+                0x1fea32f3d3329536
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x66c714d7675e0636,
+                0x4987f95d8a032d7c,
+                0xbe85adb9d64a02b,
+                0x24f8ee05c352b704
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb1e7cf62488fd10a,
+                0x795b9c86fa1766e6,
+                0xf8ca0ac4308aeb87,
+                0x38bb0932de6ae985
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xac3c0284dfc26eaa,
+                0x977af20981290f78,
+                0x128066889f75c0d,
+                0x96ae8731da3c3c0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd307046b3fc8befb,
+                0xf59e2ce0eb3056ac,
+                0x79c2cda866e3d22a,
+                0x36e13337ac5d789a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x75ba4f10d94e345d,
+                0xf0621390701548df,
+                0xb162a6e15450277d,
+                0x571d5417f0b1569
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x88ac0254ff20628c,
+                0x8db4ee0f2c366542,
+                0xc12bd2d4fd8aba43,
+                0xfa85bfc2eb8e4cc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4bf3e2d7d5864ff5,
+                0x629d528a9b4485de,
+                0xb469ec75d51747a3,
+                0x3c4fe14057fdc334
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe14238cf8d04886e,
+                0x2651aa29a97423a2,
+                0xf912c96893a8c213,
+                0x7fb4444f7249836
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xefb123aeac0cb28e,
+                0x77f301a036878715,
+                0x446b225b08e7bcde,
+                0x37ab111f005f177a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf7b4187ea34fac7e,
+                0x42620fb519405e39,
+                0x718d31662020854e,
+                0x37fdb7d897d37b3f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe46e345c9a0be17a,
+                0xa6ba33edb26ade1,
+                0x2b00e97a9601c8de,
+                0x3ecb6af2b569bf5b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x20a4cfcb206f224,
+                0x596ca629c578d061,
+                0x24b22704056df0aa,
+                0x4065fef314a2aee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab325abb219cdb7,
+                0xc3239fd99ce7796d,
+                0xcae0eb442b7b774e,
+                0x1e07a06c4e2aebb7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1771b87cf512e413,
+                0xbcb2e93bd54c902d,
+                0x211f41266dc03135,
+                0x2b96fdf14e4573ca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x21de89be18baa60b,
+                0x3b938fedac4eebf6,
+                0x99b84ad01f55ccbf,
+                0x1c051e1149e54163
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9c0edd207b6b1290,
+                0xb649d0bae085e0c1,
+                0xc0fec6ccc6a6dbea,
+                0x1a81745c500782ee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfc15c4ec172fb3b,
+                0x1f84271b25b1f511,
+                0x1b24011b04e18518,
+                0x286db36454256d1c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf340e1a25f583cae,
+                0xc4d44c25ece09c18,
+                0x627a006dbc77277e,
+                0xa8c312f574c0ad8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc2e6080b04b0bdd3,
+                0x10575866ccc54268,
+                0x6eef725a62a9aa8e,
+                0x22ede9f3533131da
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb7b5876db3e18fac,
+                0xb2975184b38d0173,
+                0xd835822416a7414d,
+                0x28bd1f547030928b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd0a64fec7066022f,
+                0x746ed9106cd490b5,
+                0x9d6eff14ec83ecb6,
+                0x2a698eda33c1d180
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbcf12b1238ff4195,
+                0x1de5a214fe92820a,
+                0xfb90c480411ef301,
+                0x37eb8923d66711ba
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf55563f72dfb6a36,
+                0x16f7a09d1c20c32c,
+                0xcc90b92ea5623521,
+                0x303f3d95ab74de79
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x90dc3f097b73ce15,
+                0xfdd4090e96492a7b,
+                0x550c058842dcd4f7,
+                0x30f0211ab138c899
+            ])
+        ),
+    ];
+
+    // The MDS matrix constants
+    const MDS_CST: &'static [TweedleFr] = &[
+        // Constants in Montgomery representation
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x93c151b63f6ac046,
+                0xe0a58decdb087738,
+                0xfb5287f58fdbf4aa,
+                0x1a5cebfcb378477e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x773bed1d60c86851,
+                0xb214563394857ab,
+                0x2a4a84d950511c77,
+                0xb745c6bf64e072c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c00fd95c0835d5a,
+                0x50d057d4a53c01,
+                0x7501f266bd7961ed,
+                0x2897322be06cc3b2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x34a4f9d24e73aea4,
+                0x2d5578f150bd0605,
+                0x315d88b51549a2b3,
+                0x39dd999d61166a59
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x14e5eee571f43a3,
+                0x8cb510fbb41f388f,
+                0x98561cd80f4ed575,
+                0x2828a40d6b8cfd0c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb24659e74b461049,
+                0xf92705141d3a62be,
+                0x91f5fa466e63b50d,
+                0x1c9ea34dfce940b8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd959c96b64794fe1,
+                0xc14c5c827e686125,
+                0xe8eda1d6c91bc9e1,
+                0x2001b332e5f177df
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2eef1209e10bb050,
+                0xf8b9d3851a9e2704,
+                0xd8e397bc9cf88273,
+                0x38a1d9efa1e76e19
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x32f1af6a166b1def,
+                0xed1d6b1450458716,
+                0x434bb8628d75a211,
+                0x3bbc663fac8817e0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67246ff8e3de0e06,
+                0xd6da0fcfe87f16bb,
+                0x652b28c9549a28bc,
+                0x17ddfc5342723198
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1d126cd26e4a6251,
+                0xf8a730167b7234f0,
+                0x185ef9e936587fa5,
+                0x3f86a8a3f981dd22
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4eb6fa9fd1b82bd6,
+                0x378f0c82bd8b8e16,
+                0xf0abdba579b1f84,
+                0x27d244e449f3efa7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9a6cee7fcd9f50e7,
+                0xed9480bd4ca7ca6,
+                0xfa7290a1488a1d4f,
+                0x2bd3e89a3ed049f2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4afabd58d16d4992,
+                0x60e2cdb2312124f9,
+                0x6359ec6683e72eb2,
+                0x2e21f0d56950030f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x11299cafab91b1b7,
+                0x282aee1cdb10b989,
+                0xf86ef6e529756ea0,
+                0x3f7b6d33c8154879
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6c74bb8fb63a88e1,
+                0x63752764ceb3c570,
+                0x6a4bf2f46121a26b,
+                0x2c3515c8df489361
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x547fc8d3703934d2,
+                0x8eb50219969a2126,
+                0x7a52983631a7b200,
+                0xb22188e0a5f43e4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe6b89131d6e76392,
+                0x6f6c2a5f5df0bc9,
+                0xeaf3ff2e34a01921,
+                0x1d1dad9922b33c12
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9efeaa25d8b2f384,
+                0xa8def2f39f472d64,
+                0x7d4d1e783a5a7ae7,
+                0x4ef762ceff302ac
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9da94ca093a95951,
+                0xa852214fc073e2d8,
+                0x578ae4988caa026,
+                0x3f51f68f103fa04e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd6f1a431e32e3183,
+                0x1c9e4bec42ee8ae6,
+                0xb61d882e3f4145e3,
+                0x20ea40fa45275c07
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd2dae192b4819090,
+                0x9a2916b4089354e3,
+                0x85424cd3aa1fca27,
+                0x178733a69c33d44c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbad74192dc470c9d,
+                0xb7dec27c9a80785b,
+                0xfd98a0cba8274d11,
+                0x31cdb68f4c78f3df
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3121cbee40623058,
+                0x4482aefc299c1b15,
+                0x178a7fcc8f59fe03,
+                0xf4fb593a29b5f88
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc2a47199bf75ad9b,
+                0x851ffbd1b23e0f01,
+                0xa8c6948ac04c90be,
+                0x370bcbad5ab34f09
+            ])
+        ),
+    ];
+}
+
+pub type TweedleFrQuinticSboxRate4 =
+    PoseidonQuinticSBox<TweedleFr, TweedleFrPoseidonParametersRate4>;
+pub type TweedleFrPoseidonHashRate4 =
+    PoseidonHash<TweedleFr, TweedleFrPoseidonParametersRate4, TweedleFrQuinticSboxRate4>;
+pub type TweedleFrBatchPoseidonHashRate4 =
+    PoseidonBatchHash<TweedleFr, TweedleFrPoseidonParametersRate4, TweedleFrQuinticSboxRate4>;
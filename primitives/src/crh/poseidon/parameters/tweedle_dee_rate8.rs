@@ -0,0 +1,6099 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonParameters,
+    PoseidonQuinticSBox,
+};
+use algebra::fields::tweedle::Fr as TweedleFr;
+
+use algebra::biginteger::BigInteger256 as BigInteger;
+use algebra::field_new;
+
+#[derive(Clone)]
+/// x^5-POSEIDON-128 parameters of rate 8 for scalar field of the Tweedle Dee curve (= Fr).
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_grain.
+pub struct TweedleFrPoseidonParametersRate8;
+
+impl FieldBasedHashParameters for TweedleFrPoseidonParametersRate8 {
+    type Fr = TweedleFr;
+    const R: usize = 8; // The rate of the hash function
+}
+
+impl PoseidonParameters for TweedleFrPoseidonParametersRate8 {
+    const T: usize = 9; // Size of the internal state (in field elements)
+    const R_F: i32 = 4; // Half number of full rounds (the R_f in the paper)
+    const R_P: i32 = 57; // Number of partial rounds.
+
+    // The zero element of the field
+    const ZERO: TweedleFr = field_new!(TweedleFr, BigInteger([0x0, 0x0, 0x0, 0x0]));
+
+    // State vector after permutation of zero state vector (Montgomery rep.)
+    const AFTER_ZERO_PERM: &'static [TweedleFr] = &[
+        TweedleFr::new(BigInteger([
+            0x84270443e1945e5e,
+            0x71957c80d9c01f6f,
+            0x67ac1137b397000a,
+            0x11729b390548202d,
+        ])),
+        TweedleFr::new(BigInteger([
+            0x9af7a0b67c512e6a,
+            0xf19310043fe22ef8,
+            0xe7336e3b5a9e383d,
+            0x2524696f6597c9c6,
+        ])),
+        TweedleFr::new(BigInteger([
+            0xde741bac6c9a89db,
+            0x578fb297365e577d,
+            0xcae2ac0ca43fbc4b,
+            0x383d4deb10498153,
+        ])),
+        TweedleFr::new(BigInteger([
+            0xd52ac13fa7517840,
+            0x6c6f67d11e143859,
+            0x8ff5e14a2667ab9b,
+            0x1b42c95014cde329,
+        ])),
+        TweedleFr::new(BigInteger([
+            0x9e0c88ca3e8a7aa,
+            0x771ac6fcd3b05fc4,
+            0xf5a326c687c899ac,
+            0x16e7b0f284d0a218,
+        ])),
+        TweedleFr::new(BigInteger([
+            0x4bbadaa446f7679e,
+            0x9f2d3254b9a19d34,
+            0x8c8dfca0267b900b,
+            0x9275a39e6c70458,
+        ])),
+        TweedleFr::new(BigInteger([
+            0xb42e99916d534d87,
+            0xd1770da217901cbd,
+            0xcaea6e0eb9598de2,
+            0xfd5c4f1a7a03a38,
+        ])),
+        TweedleFr::new(BigInteger([
+            0x58b73e657e7ad6c0,
+            0x8c0b5fd3bd95d9f6,
+            0x1b8f6baac104ed32,
+            0x25fb031cde302822,
+        ])),
+        TweedleFr::new(BigInteger([
+            0x52a762ad323ab81a,
+            0xbbd1272a9cf95844,
+            0x99dd2dab88050839,
+            0x91f35f0bdd8faa1,
+        ])),
+    ];
+
+    // Array of round constants
+    const ROUND_CST: &'static [TweedleFr] = &[
+        // Constants converted to Montgomery representation.
+        // For rounds 4 + 57 + 4 = 65
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdf0bf35937486820,
+                0xb96c1dd9503905ea,
+                0xa174452e80b75c07,
+                0x4b75393a9fd499b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xea3e4dded98ba4da,
+                0xb668b62f0224f2a2,
+                0xf69ca5dbf2b21c7a,
+                0x315cc56973d72e6d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x76c36fd82c059ba8,
+                0xd70d42296ca844f3,
+                0x5e6042422b52f3cc,
+                0x36c1ce5beb391478
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x266377239a23eb06,
+                0xff21a74c70b9fc55,
+                0xbcb1cca0eb77408c,
+                0xfa62cb2c061c32f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x17b4d00c17adf6ff,
+                0x886def9d162cd275,
+                0x55daeeea7da1073e,
+                0xc3c372a13bc198a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x692f5ebf84006fe2,
+                0xb0bc26cb365a1c0e,
+                0x64456575d3002bf,
+                0x2c5a275d776228ac
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa53f29d6b04e89b8,
+                0xcb39a6a367e5f872,
+                0xd83c46ab243a32ab,
+                0xb78d62a810e76ba
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x51ed270b7896ee58,
+                0x57be353a0ebcb793,
+                0x3ddc59c96681f875,
+                0x1a32f07bc9cc5fad
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9b3f26423bb5eee1,
+                0xc10a94c53786d427,
+                0xb664182227a6c1c0,
+                0x243a2622ce329424
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x85352b79317adcd5,
+                0x4b1eddca02f8abed,
+                0xf9eddad50ad7d85d,
+                0x291e1d8aa165e402
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf2a2e43b50096049,
+                0xf5a51ffd19971e8,
+                0xf6cb404ef15d4bd9,
+                0x3e6f285118b1a2d2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x714695038c82058f,
+                0xb675384a5da63788,
+                0x5e9fa333945d094f,
+                0x84e90926a4db25f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c1ba958d981f046,
+                0x5d1565f6faddc49d,
+                0x81dfa071af843e86,
+                0x6244837189fb145
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd85f395492411dab,
+                0xe3b0a34d035d35,
+                0x6e81070463c8581d,
+                0x248d905c43246545
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5552db46e175418b,
+                0xe0ad913075c49195,
+                0x8c9c93211a631908,
+                0x1fe2000e32a8044e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x84095560cd23a049,
+                0x5c5feafdef1ce27a,
+                0xdc68fbca77161888,
+                0x11ba5228b6790f11
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x810ce535090423e3,
+                0x2d751c0b579ab3a9,
+                0xd34dc60fb91d5a5a,
+                0x269f42f06ec2532a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd10a92591db3cf1a,
+                0x4b348ff67d914287,
+                0x27b9377b1cc7d5b6,
+                0x294d971a2f76188e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe1172d21297e2a94,
+                0x731c11da7ac6f7d0,
+                0x899455b61358efb0,
+                0x2e383e7b7151b9ff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x80208674cba8e67d,
+                0x891b8237a87a4316,
+                0x8ae10051442c62fd,
+                0x3b3d06c899a6284a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x97cf8f1735b5efd1,
+                0xbffd4eb7d886465b,
+                0x8394e83f06515f05,
+                0x233694c0794d1e19
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3fa0aa5c8cdccdd6,
+                0x5267e729f230c1d3,
+                0xf38b55125cf2ac95,
+                0x3abe57a09fbdea68
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x97c7629b790772a7,
+                0x56e232cacfb67480,
+                0x7ea2c5f0e5f336eb,
+                0x1e4aff488bc1deb9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf2fc74001d6340d1,
+                0xf8de5075de20e2cc,
+                0xc5eb4143374fd430,
+                0xcf07ec114767d02
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x242ab582a763f164,
+                0xe35124010b6c1030,
+                0xbe8f143237ecc0f1,
+                0x394740cdb66d74c0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe4a5138048e0b43f,
+                0xe2dc4299e7cc17c6,
+                0x1598c0e264e527f4,
+                0x1616ef84ae3f7bed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xac3b1c1a235554f1,
+                0x4fbc5258f9f64c87,
+                0x423564161a8f1fa4,
+                0x21277365567234fe
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe45fb6f30292e23c,
+                0xdc967174568c3bcb,
+                0xde6d315b1ca0f010,
+                0x279db2a7288bf915
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd15053229c0d40b8,
+                0x8aa83d9884654c92,
+                0x9e9b84879c5065d7,
+                0xb905a938e081695
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4096e39f13fd62dd,
+                0xc6644af8c76e9c83,
+                0xef9cc3438df01c88,
+                0x28d94107c56dca22
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfdde8c881e8c31c8,
+                0x320e787a876222d8,
+                0x300e002cc27571fa,
+                0x3fe4cd72d9ab1d3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x95b8f96c956c3f5c,
+                0xe7fbd8cd0ad96f99,
+                0x738a4040b1bb6b60,
+                0xcd147f037d07213
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4665a4d97cd3b9e4,
+                0x880b9ba41fd23bc4,
+                0x5cb0ab0c085a0fa0,
+                0x3dec97229d9686da
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xca6e83d13a94dcf,
+                0x4c925d941ce3f661,
+                0xfdd958de9e082231,
+                0x37d0a202a8b03d90
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc80d3bfecf0d61c4,
+                0x90dab10950e37827,
+                0x106cba6654e60660,
+                0xcd94a2e7252c0fb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xef7936de135de66f,
+                0xa5a1fba9e28506b4,
+                0x3a2be487d97ca519,
+                0x39b81feeca0b58f1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf4935fa763ce2c9a,
+                0xe93645da80440b3f,
+                0x57c6b3333e9e8e7b,
+                0x3f2bc4a62ddd9da6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4b05697eec28b79c,
+                0x957cd4a6d83bd0f2,
+                0xf3c8d3866dd358f1,
+                0x19a852cb28534a1d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbd841f5000aaa866,
+                0x1d8f954d91275bc3,
+                0x483440d7d908d5a6,
+                0x3ea906aed520fa5d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x46045cac86ab4d2d,
+                0x923535f532c96886,
+                0xd0f5fd0103098693,
+                0x30a4d69f2198a09f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x74824eecc4d07e63,
+                0x42c0a73177487db5,
+                0x557c1f9f9b997147,
+                0x1522ba735419fb27
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9f8e12fe46b5e612,
+                0xa962cb1242e8a315,
+                0x98e80769dadecaa3,
+                0x33381ef6aca617ae
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x77a08b1bbec43281,
+                0x6d20e34321fa32f5,
+                0xddba8a63e451c579,
+                0x1f8f1b14cdfb9936
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xad12fd135f262daf,
+                0x20560ee2d83e9b8c,
+                0x591abe2ae0afea92,
+                0x15fe1a5ad39e73da
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf5d75fe4252dc7f8,
+                0x8b3707ee826e1379,
+                0x94cc39c9e610fea5,
+                0x2877828e0f27aedc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb97cd0f315600dc4,
+                0x71d0804d6f049fbe,
+                0x2a2099af350f0ae2,
+                0xf19671be50f4b19
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3038055d2e27f7a0,
+                0x9b2af39e90998417,
+                0xb90e1584b400f34c,
+                0x255bfcecec743266
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa751f416d4fdf48e,
+                0x267a7e6ed89f6707,
+                0x160b6ec67246646a,
+                0x2271f13bc01f4e7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe119fc2c081e810c,
+                0xd25acf0b2f219c95,
+                0x76abd628e104a2b,
+                0xd895a5b7315b5b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9aaaa096edb16c6c,
+                0x1db8630021d41c9b,
+                0x6fba465b8ee7d200,
+                0x6d228cbd119a552
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8f5dba8437a823f2,
+                0xe8c6b42f97e37e86,
+                0x75d281336adc0474,
+                0x1ff9b7f0926bcc6d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5c7fd504beea830a,
+                0xb4433da7c59e9c89,
+                0x14671422e5e9a0a9,
+                0x23bee504b52463e9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd04ec6a7a16ccdad,
+                0x6a2242a98b705fdd,
+                0xa13ea24c4af44cfd,
+                0x2019b764afef339f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4680da353b44e83,
+                0x2e18fa2ff6776453,
+                0x10f9bce5cda4388,
+                0xd6de35ca49f041
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5c6222fd4c11b04,
+                0x49726e97c16edca8,
+                0xb0a924f3f409c1ff,
+                0x1d6656b8969ebec7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6d1410935e90b90d,
+                0x7dd7de882e50ad0a,
+                0x2fb851cab01131dc,
+                0x1c53f2b7d2b8990b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x27c4eefd4e37d1f9,
+                0xed7199c3bcd77c2b,
+                0xe6f50a7ead50f33e,
+                0x1c0fd4805ae0ead5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7a280fd66e6026dc,
+                0xa292eb2de22f213e,
+                0x61b2d068e1b090ed,
+                0xa167810591ad61b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4586a0bc5f0ed061,
+                0x4beedae6ff57b798,
+                0x4032e55038f89a0f,
+                0x167b1b24992c1ec5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb1d534c5d025fe03,
+                0x27c58594502644f0,
+                0xc0128586b52a3755,
+                0x380815706a409e28
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x486a5a53486fb1be,
+                0xfc2aa7b9610a5332,
+                0x1bcf33cbb6eba94b,
+                0x266bffe1b6a7f62c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x301c39086781ef9b,
+                0x4c7036064c5a002c,
+                0x901fbe3c2facae5,
+                0x1368cc800efae36c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa6cfa8a23f7be4fa,
+                0xcfc14b435d21fa98,
+                0xe6f4ed9a145fd6d2,
+                0x209e2f964be2a25d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa2a77595ae4872d0,
+                0x5878e7c0ad55b3c8,
+                0x10be7ce624d98a06,
+                0x38e850e0719d4f3d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x60a15f1df3ce57e5,
+                0xf8309427477dd3b4,
+                0x4051e097c0bc594b,
+                0x1c73510607047709
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc54114f08c41f776,
+                0x2af8ab0bbcb6a919,
+                0x5f10cccc05c870f,
+                0x37e6d298145ddc67
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa0861eef1b4831a2,
+                0xe791b677c4c4c837,
+                0xdbed45edf0f970a5,
+                0x2f306a0762c2ea4b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8b3f3e88af2f2edc,
+                0x8763f8a97f73013c,
+                0x8957bd6071d5897a,
+                0x2780b2e508056547
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa1bafb86e6a7463f,
+                0xe31da9fa81f77250,
+                0xb16f509ed951a47b,
+                0x1d95de4b995893ad
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeac58fc38a1ce468,
+                0xc73abf94cdf201a4,
+                0xff659e85cb946498,
+                0x1da5fc4deeb74859
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd262230bf41c62ea,
+                0x86b69dbde3e623b4,
+                0x93c37a3b1ea0377c,
+                0xaecf7960250e8e5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xecf2d27b3c67dec8,
+                0xb9dc72bc03df5709,
+                0xcbf7994264bcf0e4,
+                0x1f4da90e9f4932a5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc84ce4a7e770f4c,
+                0x32a753e822779679,
+                0x136501939a9091b6,
+                0x1cf7cbbe1250c006
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x162757e2617d20f5,
+                0x48b9eb51a6475ffd,
+                0x73d274b73a14dd54,
+                0xec287d603c2a0f4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x304330242debebf8,
+                0xe94bdad1a1bf786e,
+                0xccd6bd98f4322e99,
+                0x2f7a6adc7dbea46a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa9f6ebfccc031147,
+                0x3ecc36cc4a0321b6,
+                0x7a5b7fa7f9cce27a,
+                0x3a46ed00a9cc802a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa44a2d08cf091c87,
+                0x1602d52ea76949d9,
+                0x379661c773292eda,
+                0x2a9072f652e13e8b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x75fbc094fa398f1b,
+                0x9525788aea10c44b,
+                0xcd52bc7e8d0a4270,
+                0x1d47bc73df9479c8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5803ad8d7e920473,
+                0x2def3576df7f2249,
+                0x4f0755fdbf67452d,
+                0xc5e8916d1a39a4b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf048e3c07e9c78b,
+                0x2c9417a114a9fed8,
+                0x7a1a730fbec35c33,
+                0x3cb476e684438c53
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa061683824b0d63e,
+                0x123f299797865f14,
+                0xc5301061d89b8648,
+                0x398654114510d616
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x21a06db04dd2267b,
+                0xeb967d05f871561b,
+                0x31b1184c7fa3bea6,
+                0x34065c16632bcfa6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9537bc17f1d7f953,
+                0x5e02448fe42fe5df,
+                0xb61ac863da2636c5,
+                0x2f930a0782d99876
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x398a2f113b241dc4,
+                0x24bdb481cfaf2340,
+                0xffa6c8029c00f743,
+                0x27559f3ae7cd394b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x53f7506b8f450fee,
+                0xa66f19a4359506a2,
+                0x361970534c57227e,
+                0x13f1cc6beb1fc4c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf3b6c0cd494cea9a,
+                0x834e6a2d5538a5bd,
+                0x5c8100d1b55c5564,
+                0x3d32888ac4a7550e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4d34a288d9e5c6c,
+                0x3b4b2117946fd354,
+                0xf4cbd9de99a5ebbb,
+                0x3a796fcf1aa5a533
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab702f614f113bc3,
+                0x25cad3ed94ce2a8b,
+                0x4985d79f4b2cd7d8,
+                0x159de1ba558910ab
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x598e7d9d1e225418,
+                0x87a42b6896e57153,
+                0xa0fd3b93a8f0ac57,
+                0x10ca4dcae34e3bf2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x13a9fdd15bb42fc6,
+                0xaad84daa3d9f6491,
+                0xcccd1af411985013,
+                0x7eac2d50df65adc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x845abfbbf963356e,
+                0xb99bac923545b3b2,
+                0xd044be11903ca2d6,
+                0x20300a5df201424
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xca29c25b11c32abd,
+                0xddd8f2822ca07620,
+                0xb5abcf97210d4a5a,
+                0x2059d7d816a2d619
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xce4d096a807a9a04,
+                0x59a2d15ab0de30b5,
+                0x80af1efb6a2d9caa,
+                0x3842a99947ef73f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x800d884abbba7468,
+                0x58c4dded1d31562d,
+                0x7fb327bbf70afb7c,
+                0x174e675c1d47d787
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb6e90b4a3b196486,
+                0x5fb4ab19c13885cc,
+                0xf8d25930a9f2e801,
+                0x37ed8a06f0002ea9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2eb7a49efe756ade,
+                0xc6c2d4ae08a607c2,
+                0xdeab1fc3a8cdd77,
+                0x2771bef77810f9f6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1817901efc3c289a,
+                0x17720fe9d52f3db1,
+                0x30010b5accb8b8b4,
+                0x37659082be4c4782
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaa18fd1ff56069bc,
+                0x32bb4ae983da049d,
+                0xfbc61423112187dc,
+                0x183cff2f0521d8ff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb9131b96a6370ac4,
+                0x84570a73d219bbd4,
+                0x598f3b2ba7491b9b,
+                0x7f94c2d043d9321
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf27f25b89b316f6f,
+                0xf9345b5b9377595e,
+                0xeb659a551d9cdb83,
+                0x2810e47f35bf053c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x34e4b9f2088ee219,
+                0x54e3100915cb5835,
+                0xbbeb831c6b773605,
+                0x3e0ccfa8c59d0e32
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5902f4da2bf8431b,
+                0x763c728f698f19f6,
+                0x1796188c51c2542e,
+                0x74ce8a4e982dbf7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2b5b91275d548679,
+                0xa5f76ae0fcdab6ea,
+                0x8c569a2760565079,
+                0x352e7c8937d13fd6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7bdbeb3283befa99,
+                0xaaf7f2347eef0ff8,
+                0x60c15c1a208da4fa,
+                0x13320de5d00b6dca
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6c18d1f0d9d58e57,
+                0x3f33429f3ef0320f,
+                0xe8e74d9fcf2babd5,
+                0x5d4a478d63d157a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd2603c05f46ffd04,
+                0xb97062346bd9efea,
+                0x253f3ada2684509f,
+                0x22a9008985d26e10
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x587896bc3b7e80fc,
+                0xed826e0a97418344,
+                0xf736491164f39b13,
+                0x31f1222b3f8b877e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfd2227d5af8131fe,
+                0xd1ff72a3a0216ab6,
+                0xb9820c7900537aed,
+                0x23266a3f993c21fc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd62025e03b2233e5,
+                0xd5fdc3e4cf0304f5,
+                0xe30ff9de39c7fd66,
+                0x1e88adc26d7dad75
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6756fa0d82b4b04b,
+                0xf1364868be0e24e1,
+                0x434e399d4ab7d7a5,
+                0x273d748cb20d529d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x597a303854b5a27b,
+                0x1def88eaee30019e,
+                0x80ae3443358e169a,
+                0x23343c9474dcf03e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfb5f22bcf0294cb6,
+                0x9a7ae9cbb3da0bb9,
+                0x35dde740df7ae379,
+                0x1278a6bd2a7f1f4a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdec99d408c33a429,
+                0xa775d4d119d336c7,
+                0x909c7c3a1d831c0e,
+                0x2fdd31b7ae73f40b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x133244b0f423809f,
+                0x632302910320e15b,
+                0x7c686c26a625e7c4,
+                0x3c36fdd70a219426
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe747c1529c8da911,
+                0xb582a1f40f604dd,
+                0x6d68a2a384ae63d7,
+                0x1e09dc69595f1b35
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x134c035446dbc99,
+                0xc091142dde4de3d8,
+                0x6e375e06c1201029,
+                0x2e3c0f7bcbb2752c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79798bbce8d471dd,
+                0x5a4a23afc43e47db,
+                0x2cdfd85f53cec546,
+                0x1b6fea51973ac780
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf48546b892daf26f,
+                0x702a2d5577af1155,
+                0x5966d7e7c4c68a1,
+                0x38e2f2abdf220005
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd54411f9877bb420,
+                0xa26f51f1d8175595,
+                0x7903c4805d31f291,
+                0x6c7107bf5c9b00d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa2fa69e61d59446a,
+                0xc224d633f7d24341,
+                0x6667fb6b9d120475,
+                0x1943ae05bb4e5efd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfa29cb925382d7c0,
+                0xd1b2394829f83b50,
+                0x53ab6dd766b728b6,
+                0x36f4f047d4379e08
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x20fffb927508beee,
+                0x47ef30de09f209b1,
+                0x6e68f2ad6b0abe25,
+                0x3f3ea00741cc8f2c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x31fe50bafd8210a,
+                0xf5ffeae7d858649,
+                0x3d9f2bffb3a9cc8c,
+                0x1bd0c65d2401b2d7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x49a13aaa4621bbec,
+                0xbe62d18f2dad1ea6,
+                0x26663b32a96fd62a,
+                0x1d03595009b63034
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79babf2959003ef7,
+                0x9e0cb9b06794f948,
+                0x890353f9ca9f5fc,
+                0x3bd1d1f617960b79
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1b186db88233c79,
+                0x4d6cc15d3e2404e6,
+                0x6970b75ef4e00f63,
+                0x24c01d9b15f08c00
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2fd5c33db3ebdbc6,
+                0x590d4e8f7361ff57,
+                0xc8c7195df060d914,
+                0x3ab941d1c320af79
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc07046eca09e2a67,
+                0x484d955ad6d6d469,
+                0x89be04aa56a25689,
+                0x2632b4ee0f522384
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6a3b12b0f0c27c5c,
+                0x8e8315cc2dcdfd84,
+                0xbeba89630dde4f8a,
+                0x130cb05dd0807fe1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd35947b4e9a5106a,
+                0x2f49217b7e05aa74,
+                0x6cff2b687fad1015,
+                0x2fe13a06892feff0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa1967e451b6979f5,
+                0x1905aed33111e47e,
+                0x3adb4256b7a01121,
+                0x1c9c66026af1a808
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x633146da6ae6c9af,
+                0xa2212ed8667b9b1c,
+                0x8f5f5dbb151868fb,
+                0x1ab00f66de040be
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9dfd5b4d441d43a5,
+                0xc61357aebc87a8d4,
+                0xcd731929482b84d9,
+                0x330fab34dde7a616
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb27136851f70d828,
+                0x6d60829bbf936cc3,
+                0xce2f7215d48e4b44,
+                0x39bda4797611028f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x29634a74dade2747,
+                0xcdcdbe3b79b87888,
+                0x33f70a21a7656341,
+                0x26a4fb355c7a5cc6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x841aceaa977da1be,
+                0x22e21dac2f785a2e,
+                0x50c791bc91e11659,
+                0x38429d16c4e207e4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xce66abc10e3ab61f,
+                0x74e202205a2c3db1,
+                0x38875d638193e186,
+                0xf0a6d8733ea5113
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa5cc4d8fb62f203f,
+                0x4b73c0f75032dd4a,
+                0x80e4a16fb7751808,
+                0x1cf6afa2598b61b1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb5a5be10b864676d,
+                0xacb940704f3b3030,
+                0x67cfc407b780d1bc,
+                0x1b8223b4777aa2af
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x88389e828dd04d0e,
+                0xba7c9187d7b6fd77,
+                0xe0d47a5a4452490d,
+                0xad93137fed7a0e6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbcf471f5dd4acc6a,
+                0x771faea6a30747fd,
+                0xfe7bb639c431bf2b,
+                0x66db6fc9d598d5d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5315aa7734a6cea8,
+                0x2cae057649323b5,
+                0xad8f74b5a3f5172f,
+                0x3f77e3ffefb94a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8a3e86e9a8e38f9a,
+                0xa458e266d70ccd55,
+                0x433cf225143d4095,
+                0x3697a033f1dfdb52
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7dd9bb01fd02a187,
+                0x602629374ac76017,
+                0x143a62d9c88d8c31,
+                0x24129e8d3a68c2e8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfa81cda42262050f,
+                0x3f05c1040d7f22c8,
+                0x21daa63dd7c8c445,
+                0x211a17bd96cff34f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf7f3aa69be982501,
+                0xf702be074da4ca95,
+                0xd447f530efd3c2ce,
+                0x1647a9bbf00ce08c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab1cfc6f567ce277,
+                0x38962b26283a7bf0,
+                0x530f25aff2b855f9,
+                0x1bc33ce978f9b329
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc1e1e20dcaded34,
+                0x6a27e01578e6fda2,
+                0xab26e4e3b1568f14,
+                0x37f55c7920ac3f1a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa4188174b6778a7,
+                0x7b561ee699b92fca,
+                0xcb649b21f72d8a7b,
+                0x3652395f838d3dd1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe37dd9bef1683b40,
+                0xf95d3607cdebff00,
+                0xe72909083749e42a,
+                0x3d9b75f879b812e1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3a48d1645e65e318,
+                0xce7e213f8fed245b,
+                0xae21cd3dd4080529,
+                0x1ecc3988d0a77443
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdb42f8cc04bccd1a,
+                0x613e2014dd552a22,
+                0xe6d338b7868fd905,
+                0x1af9d2c64aa70e72
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x72bc11764b590c10,
+                0xf857063ce50575d7,
+                0xcf64a6601a0dfd9f,
+                0xe50bfba4306bb59
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2fa22f254b4af0dc,
+                0x4cb7b1a44f995fe7,
+                0x8525b5acaa412c56,
+                0x3f5e3f25edbce104
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x995b4fa93298a8c9,
+                0xd3a74f8e58c8481a,
+                0x2752d9c56bd904a8,
+                0x11e0034df4e5bb8e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc5424218b2eff2b2,
+                0xd8e164b9362f00d4,
+                0x4519b2cdbf2ebb0c,
+                0x27eeed19891187d3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1e7698d34902d802,
+                0x8c2e79769dfdb1ea,
+                0x822144a2468719e7,
+                0x232098419925942d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4dfc91f7ce18a73b,
+                0xdf805d9c3d09b573,
+                0xc67572b5501af5a,
+                0x1b3a1bec19633057
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x94a966cf44679c65,
+                0xbca46365f047d58b,
+                0xd920ebd351e9004b,
+                0x221835151390e8bd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd88ff201a83a46df,
+                0x2cedecbefcdebe3e,
+                0x867204413ae2ff85,
+                0x198c3615272ff442
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xda9e242b91755e3,
+                0x744b7ba37c3236b6,
+                0xbdfa24840b33b77d,
+                0xf493707f935908e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x35ee06739739c845,
+                0x7a59202c1265afbe,
+                0x5d97b8f2f72a780,
+                0x2c74ad034198cc3f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1a7f06f7f657c1da,
+                0xc1c475b0229ac121,
+                0xec83591fc52bd746,
+                0x20b160c0839879b8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x36c38c69f39fe2fd,
+                0xfcb53599e4d505b4,
+                0x5ce2f4eafc678ca7,
+                0x6a2d8ace63b5e8b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x86023f4ffa33daa8,
+                0x3b8b7733d8a7f26e,
+                0xaad71835caa39513,
+                0x792f9960250586f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe3a279f7a47d2d31,
+                0xa5a71a766a4b40fd,
+                0x1aed04ee8dc722f5,
+                0x1d4d1217b0e65398
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa74439deac25145f,
+                0x76515db459082aba,
+                0x5d02a96849f5ae5c,
+                0x3f8135ce7672317e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe2ebcdf507dd3b79,
+                0x5dbcf11166136231,
+                0x6afdaf8d56588140,
+                0x360beb89c53542d1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xadd450d709c9d6eb,
+                0xee645198de86a98b,
+                0x3f840ed575e958fd,
+                0x1095d712accec27d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x879a908d85683f5f,
+                0x4980e800697342e3,
+                0xe7c9ca62020e22a5,
+                0x96d170f97d109aa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd1f84b47d9720c5e,
+                0x6321e74915fa1bb6,
+                0x273596763c889f4a,
+                0x2d5cabb2490986f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbcb5ff56813fde72,
+                0x5ca6eb93f4529866,
+                0x5a8ca85975460257,
+                0x2e88b2216ec3791a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x749510343132e85c,
+                0xfcceb28483af9944,
+                0x8f8e982f84d5a783,
+                0x26facffb91b217c2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x89886f71d6db69cf,
+                0xd42b220068dfe6fe,
+                0x391fb9d89afd2d95,
+                0x7aefa3bad7fdafc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1a88bc18a5e7d773,
+                0x17e1a5b98650d930,
+                0x26f2e40494aaad5e,
+                0x29454d40b455c469
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfb87acf9621d3061,
+                0x8a752ec988efb3d2,
+                0x70a0503bc3ca99e9,
+                0x1de7738a03651c95
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x16b9c830151bb444,
+                0x4be9355237651e85,
+                0x6eec090637bd0739,
+                0x333fe397d17dcebe
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x259ff90c7b25266e,
+                0xb68d2b25da6999dc,
+                0xc9df033aa8c8bf2e,
+                0x7e9466e9b9de80c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xea3773140ae925d,
+                0x7fdcc5e58f4c9b05,
+                0x55fdbca3bdf8bad3,
+                0x37f02d4de45c679f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd1a3fa5b69384cba,
+                0x7baa15d78ab73f59,
+                0xf72040215d5df68e,
+                0x2771e86289231e05
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x80b1c3a1f28b1bb5,
+                0xdb2be9be55343894,
+                0x45b1bea1922f2ad6,
+                0x1e6aefa6e46d55ff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x137bcfe1bb41623c,
+                0x75bde52ca8719556,
+                0x83c819d4f56186ac,
+                0x34ec755f248425ce
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2623aa16cab04f8e,
+                0x65ae9186d87094f6,
+                0x6ac6f025495064a3,
+                0x286566e41a23fb71
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5bae59228594484a,
+                0x7838cc24df6f4183,
+                0xac5ef8c2e14a5531,
+                0x247dfadab64a0c31
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8704c725adef8fe1,
+                0x45d7bb8df7ff5fa6,
+                0xc5cbb2b4af84ced9,
+                0x15d9cd178729aab2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc11d4a25b18d08ae,
+                0xa887d06419545286,
+                0x3a67c780f36cad89,
+                0x14b35e80905b8e52
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe8e1a3986581a9fc,
+                0xbb814a0bdf884437,
+                0xd530de12fb3a2010,
+                0x30db4901c9e1202a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4d540927e282ac98,
+                0x9757076b1b588c5,
+                0xd809bbd06b0c36a6,
+                0x3892eb032fef0159
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc34904fc407afb8,
+                0x4bc79ab40f22ccc2,
+                0x4ee99e31ae6e38f5,
+                0x3531e74cea24bfbe
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4a9d2e63a9858909,
+                0x12f2d1da145444ab,
+                0x5ed6cbc3987221e0,
+                0x131a88c12ddb6c3b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x34d9ccfa9054b01,
+                0x3099d252642dfa44,
+                0xa1bcebcf16e0bdcc,
+                0x2358058db28f99bb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe83045f60db4f5a1,
+                0x663dd5315e6d4e58,
+                0x553d69b94f5af2c6,
+                0x3fc3926b4a76f759
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfd0eeb91682e95b5,
+                0x9c01a04539d73f22,
+                0x804322827d7d2a70,
+                0x1dc5a098fc67a9b0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x70ac60d278eec378,
+                0x3ee6d2468830c66f,
+                0xf40c8a94de13e3f7,
+                0x1707355ae291ff7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x407d8e1972a0f391,
+                0xdfbe9e3daa28a796,
+                0x8479f0a0374c7edb,
+                0x3c87516c4f8408c8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6d47bacf9e0b16d4,
+                0x6ccb5f5a212b34d4,
+                0x98f49137636cbd4b,
+                0x3bd49a31ceb0b402
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7824e01db7371d2b,
+                0x8fbaad4f2b6c870f,
+                0xa4dd98ebb7741b6a,
+                0x394342cfeece1fa6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdbc77ef6aefc9db9,
+                0x69a41b8b7ce5773d,
+                0x2c0480355dde03fc,
+                0x1bda458788a234e0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf906c18f79a15bb1,
+                0xa6f0d5147d8ec440,
+                0x84723b7ab5753465,
+                0x1f14bd3fea301aee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4d724aa9ea3e0348,
+                0x2b78a608f11b6339,
+                0x40bb1d9fb7bfa6d1,
+                0xba8f2c2a49bbbcd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe9b69414c47f149a,
+                0x6bd1218829c98509,
+                0xf5077a5e77953907,
+                0x23c70509c7216aff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc6dc4b36b239c043,
+                0x3115682c329f57e8,
+                0xd9f6d3d25c3f5ed9,
+                0x2776bd4c60c2225c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa9f3b197804cf5a7,
+                0x81478daf0efd3ce2,
+                0x751d434e57ebac18,
+                0x3ecb2f76a5580bb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c5459847c0ccd91,
+                0x1d77506f25b7d63e,
+                0xe0964442be8356c5,
+                0x17bc247efc4b839f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaa28f9722f26d7ae,
+                0x8f31f93fd3aa9ab0,
+                0x3ef5af3b8395eae2,
+                0x3c75bef54426fc52
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6a14108bf47892b,
+                0xcf57cc42bb9f8e8d,
+                0xa04e467d93f63268,
+                0x3dbb0e9a4f7a3ff9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9409b74851ad2922,
+                0xc8da6fa0df61cc03,
+                0x83c12ca6dc8b4dfe,
+                0x30cb8c0f3aa787fc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xda84762bd592013,
+                0xba088a1fd11d858a,
+                0xb765d4edbe68a016,
+                0x618039ddb47561
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2ee1aff06c47197d,
+                0x63a622e689375deb,
+                0x1ad59626522044b3,
+                0x29fa0e01ea2e01d0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x593727a4386b11b8,
+                0xb9f0e7d4c673dd6,
+                0x69e38c14757411a9,
+                0x1ee73678fc3972e3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8f1e49efb56b9bd8,
+                0x46ea793e0e7eed0,
+                0x6d5fdc2d34aa5262,
+                0x1f1b681429c4043a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaf40ab2b7b4053fe,
+                0x841e535fa4d9ff2d,
+                0x286171f7546412ac,
+                0x1b75d464f7b9be5b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5d62072588c74e3f,
+                0x48766e9e5c6db273,
+                0xd740d8122f2ae77a,
+                0x802f7fef9ab1de0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7cca11a499f7999,
+                0xcc1b52dd19409008,
+                0x895a285944efdefc,
+                0xd0128ac8a4e7bce
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x21d63ef406a90975,
+                0x460516d70dfb02af,
+                0x30afa61826ad9bd8,
+                0x1848f78d284aca4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x688b6c885aa05907,
+                0x784c1be31ad5c5bc,
+                0xc2ce3d4c966578c,
+                0x4bc5172cfd70cc3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x98dd695e0d6f320e,
+                0xb040a6f1c6c7f3b0,
+                0x545b55f18d4fc368,
+                0x2b50c86460e213d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcb4531f3ec381dae,
+                0x6104bd63eed0723d,
+                0xad43dc74fd43e247,
+                0xd4a45f4d751a163
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x21cbec830401c952,
+                0xc6222750d8aae4fa,
+                0xf336c1f7d221d127,
+                0xfb69cef81107c0b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4d969ace729a72da,
+                0xefeea7bb8e8e9fe3,
+                0x5d685929c94c41c7,
+                0x23861359adf5b587
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6532b720b2827fd,
+                0x7dd34ca360803787,
+                0x12aac7605a817885,
+                0x25277b82d02ecba7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6470ee42a735caa4,
+                0xf2fea636fef853b1,
+                0xc65ab93e99c57455,
+                0x1ba3052857abaddd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5b7d0d6f01d2f78b,
+                0x2853b3d14a649a76,
+                0xa482ddb268fb8165,
+                0x3601bfb4936318bf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x484528184525f2ee,
+                0xd20b6a4dd9b9187a,
+                0x87fb602cd8557a2c,
+                0x16b68efc57b0e446
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x353bf6bde2364ace,
+                0x44307773407a65a1,
+                0x6f10a6282dd57ccb,
+                0x4546cae8eb88834
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7cd271ffc45469c1,
+                0xd3c66ac00b17c174,
+                0x5498034ad7430bc8,
+                0x3c4690d60de34b19
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4cce645d23a128f6,
+                0x532bc3c44dd22939,
+                0x692b48d829f99f75,
+                0x2394c9d8706e3f9d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6043daa9e5d4b025,
+                0xde9204c52215b520,
+                0xeb6117c1f01f368e,
+                0x2ba0c19d0e70dc94
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfabb34d7f5abed40,
+                0xb7686b297f977cc8,
+                0x3a83dd4444794f04,
+                0x8f50113b1571b1a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x902f3d02f802116,
+                0xefec6b3177393226,
+                0x179db9f5b010e893,
+                0x6b45eb881e82850
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x173c580ed90bca93,
+                0x92f43c862c6553a,
+                0x2127b6f6cdcb7550,
+                0x10d0c46cb1030320
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd228fe73c4f80c61,
+                0x3ee5d76f976d5104,
+                0xecdb1aa0159286e,
+                0x103f35b8f205d268
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xda69a09baabdb50,
+                0xc7f10df61bfeaccc,
+                0x9ea76d616f2708f6,
+                0x28c72494bcaed2e0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1091a1b22d7d4422,
+                0x95b0669a826c1af1,
+                0x35c5406b3f2f3d62,
+                0x382994335cd3bf9b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xed798311299301cc,
+                0xa71f924398b820e7,
+                0xf5e46e0512fbb967,
+                0xa9679b9880c6c99
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4bdcdc3a1b0025fc,
+                0xa04f1378dc7583b7,
+                0x27261531c0dc7b84,
+                0x38a78e422686129a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x68c13baf64e40ef1,
+                0x87f5506bd0defe2f,
+                0x8a2c037174ed63a9,
+                0x6fc5bf0162f6a56
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x49da9331f31f30b0,
+                0x632db2c334400a60,
+                0x7d04c05d64174e46,
+                0x255f4c1f5ba46843
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9f0f7e297605652e,
+                0xc992408f1108d04c,
+                0x25f6c9def8e74ded,
+                0x1367ced601fa80d5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf168acf0fba6b859,
+                0xc8e21f460b72b12e,
+                0xebdcf8596a8372e9,
+                0x331ffd598c0e182
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x98e3002064daf341,
+                0xb738aa35d27cfc6d,
+                0xf4e2d574ca427452,
+                0x1777358ba181af6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x27d71eb1923e913b,
+                0x33ae20155ea4369a,
+                0xd35356bf6b88c7a,
+                0x32bb4f9ffd4466de
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbdbb496f548f0af7,
+                0x41cee3605e1ecbcf,
+                0xaad09272cb5de374,
+                0x12185147c2b9b637
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7779074947625303,
+                0xc271e29af4415a03,
+                0xb9691b06f9f06a62,
+                0x147f88ed66f54a18
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2fab44c0044879f6,
+                0xa322cf1ddf74969b,
+                0x84d0e18273a91ed2,
+                0x1789b95717942de
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaf8e22c1966b6e57,
+                0x41ed13a0840ceb98,
+                0x46f6ea5aed7bbda3,
+                0xb658bc2afa8e485
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x20e275526f5cf4e6,
+                0xd11773022bd5b967,
+                0x8946d369ff7b8559,
+                0x39f8af0faaeb6a2e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5c0a67f10efe8812,
+                0xac2ab83ddfb555a0,
+                0xcdfe813642e1228a,
+                0x250b41b7fe53e1ff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x649e91af6ea5188d,
+                0xc30393a2cfd23c62,
+                0x5f77d57b4f6f1b,
+                0xc0cbfd1ff52b5b2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x83473d4cc61c23b3,
+                0x42349968d92be6ad,
+                0x27fe425cf17840e5,
+                0x37fe3999c0903543
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdabc9928a1f1d511,
+                0x1c03881feeef56d4,
+                0x355c7bab55f09150,
+                0x96fad31d47722fc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x60e0b64b02370fea,
+                0x9eeba716c6b5e410,
+                0x71d4f0e28d3feb6e,
+                0x18a2ddf557489331
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfe992f07ed9964f3,
+                0x3f0f5b3715b92bff,
+                0xa1d24ed2cd4dd1b8,
+                0x313c346bd2584d86
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x31c2bd3b4f87831f,
+                0xa9361e5c39138f67,
+                0xf502df80a84ee97d,
+                0x34ab099b5469cbf0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xff2b7dd4e0d678cc,
+                0x1083e88593be6ace,
+                0x1d386468064a3ec8,
+                0x391ad27f5f48219a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8d31e643dec9e1bd,
+                0xf7259de1b9951736,
+                0xa896fc4e3df77193,
+                0x3e4f57d7d621517b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x48a3543f2b3105ea,
+                0xf87af5ba026e2abe,
+                0x45f834a6c51e8f12,
+                0x27ded61296c29038
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcb999ef92e6615d1,
+                0x1f9d6220e0799539,
+                0xfb7e0bd91ae36977,
+                0x25c712dd09059efa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x904965e8d0eacf2f,
+                0x522b12dd6e41780c,
+                0xede08f466850c936,
+                0x1db54b24869074b9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xffc9ffe682c82f98,
+                0xc9624e816b121325,
+                0xcbc8491f528650ee,
+                0x3c4f6c4414143496
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x92e46ee80d2e2fa4,
+                0x6c32e75adc1c9f86,
+                0xc7f94035ea0c4f78,
+                0xba419f9cf47a5c3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x56315c9cf0935617,
+                0x62f26c87463206da,
+                0x61badc3b5a5306fc,
+                0x16435ab0052ecfb6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbd2450c2538dfd25,
+                0x8ca40d9f67d52e0b,
+                0xa47bdc5bc4d6d65,
+                0xd3d3c1901a85a17
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9ac4741482a4ae37,
+                0x6aa2d070a95f935c,
+                0x995acd8dda2560ec,
+                0xe77139b0ecd0d5a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xee4e3f646ccd66a9,
+                0x9d57091f7a8689a2,
+                0xd1e634073be6be4e,
+                0x3c47ccf5f7aefb15
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4fe95d0b98269c3d,
+                0x4b604d69e9286e35,
+                0xc3a39ad0fdc2c457,
+                0xd264be5a722b66
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1e877cb8d92f0e00,
+                0xfeb0b42179c735f7,
+                0x7065701a6673a7cf,
+                0x3f15e47a61311412
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5cba7a2b944d472e,
+                0x273cdc828bf63c3d,
+                0x9425537c90da125,
+                0x27cc2ddaf17e8982
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x59936f7c2c2060ab,
+                0xd85694c5daa5b48f,
+                0xb6f79948aee82d64,
+                0x26208b482aa76d7d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x43690e2f0137d298,
+                0x25335dfa89436511,
+                0xb63ab2b49a3ed06b,
+                0x3cf1f5261a2cda56
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x366f527015971f82,
+                0x3f2b8dec5b0d840c,
+                0xf131cdbcc6e06247,
+                0x13822beac42c0185
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x358464272dbaa697,
+                0x708a973da3862976,
+                0x8dec1df73e9ebf95,
+                0x62f37ad04ff971c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x94b159598794ef41,
+                0x9adb24fd1b3fd7f,
+                0x81ce83a8b655c9a8,
+                0x1ef1313bcf8a9abd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbbc7fe73fd14548c,
+                0x754bb178873fa6cb,
+                0x357e15e20d7a2c9f,
+                0xce64838f6ad7d0e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf97b88dbf5d2b62,
+                0x167f4068b09ee249,
+                0x2f57dddd137b574f,
+                0x205337834fbb6fae
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2cb9e84efaad1ac4,
+                0xeddf8427df882aac,
+                0xb4936d287a2ea45e,
+                0x15d3da39a4e8e7d0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdc7af6bf57449af2,
+                0x8f8e8a84a5402cd7,
+                0x281d98b5a57f5ef7,
+                0x5f97e58471f3ddb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdb25bdeb5cfbb1a,
+                0x9cca65740d14adee,
+                0x569fde9e9131f8e,
+                0x361ac348dc056a87
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c3f7e01b8cf1f75,
+                0x9b9955ee78008cac,
+                0x368c8eda426e36c4,
+                0x1d99f42168345ec1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4a2779adc3029dba,
+                0x8d69c92856debe73,
+                0x62c8a8075ed991de,
+                0x18cac2c641890a4b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbc26f93056ab6c3,
+                0x7b41e99070731e0a,
+                0xa9c921bce14e168e,
+                0x3a41a0f101cf14d0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf2963ac5d5684483,
+                0xbc315ac2b4e0d0af,
+                0x2c56995771134ac2,
+                0x412e1f666c1204f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcd959729ec112686,
+                0x84e7a210806ed7c4,
+                0x788a8126117ee084,
+                0x3265f01cbb2759d3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf025fc5c44f5a6e7,
+                0x2bd50f31c41e1f4e,
+                0x4037342c227768e9,
+                0x2842e1e8ccc72dc4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbfe5e993300240cf,
+                0xef6e0562ae4bfbf8,
+                0x2d0ee3193de68e3e,
+                0x23b8e3fb838c3598
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf1b8b8bdcb31464,
+                0x5ff6551d8467d050,
+                0xb91ac13bf204cf4d,
+                0x29d6f736cfd50702
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6fb9dda628f46d7,
+                0x472537f059d1de60,
+                0xbb09f2c9717eeb9d,
+                0x28b035d101b3cb8e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbef77a80fe534522,
+                0x1116f4ab139f822d,
+                0xb3a765e6bf00c98d,
+                0x3e381bd4d639258b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc1c3b728d782dafd,
+                0x90d1f52a5c1ac1cb,
+                0x38646d9dd436af59,
+                0x221389ae2c964b8f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb48729e7582d476d,
+                0x9a611a0194099227,
+                0x222a60ad26142ced,
+                0x2bfad1859e85783a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9868c4638ab2f06a,
+                0xa9da18d2464ac254,
+                0x2b4ba51ae347572d,
+                0x33c89315e233545e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x78d5a28c8e7526de,
+                0x7207681ac207b6a2,
+                0xc0da44017d967519,
+                0x3930c918f240559d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x177c430ca9cdd1e8,
+                0x5a47ebb93b1f9e9f,
+                0x98c24b4ad6edfc46,
+                0x342256db0b1853f7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd520ab4be6fe6a31,
+                0x27bc48dd8cebecd8,
+                0xfdefadec32a86d63,
+                0x2af9fb032051bfd6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x23aaa1f86a0ebc64,
+                0x4ef03bc5556490e0,
+                0xd3ecbdfa86a2265c,
+                0x22e6e6ca4db0ed3f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7cd0119eaa8550c5,
+                0x68e6f67847952da4,
+                0xc28964b84614019b,
+                0x1ff3f15a2434b3f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa879111ee85bb4f3,
+                0xd4b7a205f98de0fb,
+                0xf168abbf0a17b7b1,
+                0x117f7cec8f62844f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xceb717f3d529bd7b,
+                0xc6cdc12b9e2b440,
+                0x30d4fbad460a153e,
+                0x39c459be2523b63d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7dfa92aca13def92,
+                0xae0374e54d0b23bb,
+                0xaf4bd77e1720fa83,
+                0x28293aa457b42631
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6925882f67f2ee8f,
+                0x779df5370d62b815,
+                0x377917b30fb846c,
+                0x1325ff7042d57389
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7b82c51e7f83b4a4,
+                0x54e5ac67a035ae12,
+                0xec5e2bd113d57f19,
+                0x10e6940d565fab56
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79a72c1242d2c162,
+                0xa247dfde5148b7b0,
+                0x5472fd9ceda48efc,
+                0x3e12a2a3987cfa7f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1526af6ce14a6812,
+                0x67cc12cca5c56198,
+                0xa893e5af4ea5e5f5,
+                0x168061584ad4033a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x143de5963000a579,
+                0x1660516aaaca365a,
+                0x8fd652161d28c369,
+                0x3f2b04928b5e828b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x56a53f287ce107f8,
+                0x1b66a7c174a7a264,
+                0x65cfdc06d0d3462b,
+                0x28d31adfbc566eed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe5a95fd806c4a777,
+                0xcd1d27972503ffd0,
+                0x83ba8dc090c9bdf4,
+                0xfad715ab4da9335
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd3e1eca5d4c6f2ac,
+                0xcf7d0d8fe7ea9843,
+                0x21509a2bf09e9585,
+                0x1ce80556b0ff2f85
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1f0c490217190dc4,
+                0xd1f511d97e8e0e62,
+                0x4147850c9f01413b,
+                0xc650408b6c7153
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xce8f8c3095730279,
+                0x273e8286a179b1e7,
+                0x529f527d7fca2d14,
+                0xa96ba6f683ab681
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x715d3759f5539de0,
+                0x778cb7f495a961d2,
+                0x3a3feadd35a91874,
+                0x134905d84a9c1b90
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67ea0aa3d3999ba3,
+                0xc7f207659f541828,
+                0xe142ee220fa5da16,
+                0x18ca76c0db13df15
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x19dd9f6c1de95401,
+                0xcf9531151cbc417a,
+                0xf6732368da742c38,
+                0x2421622b2392818
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x16bcbb48c6678938,
+                0xc03e905439de61ae,
+                0x52b3ea93b090c24e,
+                0x266e3999273f567
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2a7fc56e149b0c60,
+                0x4b15e2d7903f3f3b,
+                0x41fd7cccd702cc9a,
+                0x1f7bfc49b49eaaec
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd0c62c1ba38d0732,
+                0x164cfa9f366215b0,
+                0xb68eae4e0dbef4de,
+                0x3e5f1a36a097562
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x75dd60992cb92c03,
+                0x4fb653b8d76ec799,
+                0xa1f8834098816456,
+                0xc7065d706aaccb2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x773953bd36404b1e,
+                0x49a1b34f4e0f8bbb,
+                0xb5ac2dd5e2417028,
+                0x158dc23b98258cb9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xce3bb4dd22b097e9,
+                0x93d2d02351d4bb4e,
+                0x5bf883468434c5b6,
+                0x78974b2b913fea9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf8a3a77774e993e3,
+                0xe1dfc497e16da960,
+                0x2864fb0af71a6220,
+                0x3d7ae1ec0c0efc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3325c3f159fa3d31,
+                0x26b27879a9a69371,
+                0xfcbdd6d5230f58fc,
+                0x923f84035eb0d1f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf05c6779e8c5d29d,
+                0x6d975c32ecbd7cf6,
+                0x41b6af352931a9e0,
+                0x186ad379956df758
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdfd2794f9baef44d,
+                0x7c1aa7ea31e55709,
+                0xfacfefe1518002cb,
+                0x35db1ef9947cb49c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcdbe1a85497cfe58,
+                0x44f89984a8ca0513,
+                0x9b6619c4314ad681,
+                0x6ffec037791be4a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8ce7a3cc0dbfd588,
+                0x82154808b7acb9c7,
+                0x35fce13b5a4e5500,
+                0x282553199450d901
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3a562810ba194b1e,
+                0xb3000ceae36f62de,
+                0x28f25d65d96b7ec5,
+                0x10290d96b33afca5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb3c0511dc03922a7,
+                0x35d4302c6c5bc967,
+                0x9daf1310582007cd,
+                0x202c5915e9dd5ccf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1d9a635865e54d59,
+                0xb4180ca30d4e10d2,
+                0x7ac2233b5a910305,
+                0xdef9561196319d7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdbcafe87df45f5bd,
+                0xcea45327c992565e,
+                0x23d53eff840144c0,
+                0x9224aa41140cd69
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa9497ffb1889d82,
+                0x5dabf97c7a9fd0ac,
+                0xe55d1c64b0031971,
+                0x29e083f08e963653
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x696de6c92fb4f683,
+                0xd3aaa748d86f19cb,
+                0x70943575902031e0,
+                0x3003c9014dd973e2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x75c40c06e892ccbe,
+                0x61aa62269377ec95,
+                0x93ea255897e39074,
+                0x3d4de413b2aa87cd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb7a2b94bf00e2e81,
+                0x21e8d8715490aa76,
+                0x51bd92ddd23e30a7,
+                0x9707581de808e93
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7f13e90ced11d06e,
+                0x295ec9d423d01afd,
+                0x7ea0f31df1cd4954,
+                0x3b0c0ff52e3b25e8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8d2a2ec12dbd511,
+                0x3cdbd905d696b39b,
+                0xac151621976a8a09,
+                0xc9778ffbba09ada
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x19a792f42a3fecf,
+                0xcd9fbb693790ddaf,
+                0xcaf359265fda0add,
+                0x33092c20169576e1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9fad28c9b8d5a1b3,
+                0x1b19aeac8aefd570,
+                0x30cc8b6afc7d47f7,
+                0x1f39a159586b0329
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa74daf479fb05cbc,
+                0xe50eb16c9fc9b249,
+                0x169d30f69a581d63,
+                0x1f51f93e64811842
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd8a668a3e6631dc7,
+                0x51f91ba06352b65f,
+                0xbee471bcea5f4cde,
+                0x3851f40f75807244
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x86ced421807afe8d,
+                0xe62e22900f591c79,
+                0x8a45a567edfeaaaa,
+                0x322c043fb9dd27c4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1e5e8657893f7fd,
+                0x3ad9752555d50289,
+                0x159c72819de096a4,
+                0x2308aa9ce40f6a6b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xefa29bd7a5592f3c,
+                0xf434411d53b03334,
+                0x495694467b257ae9,
+                0x1b3759b6cb545c4b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x839138c5a1ad03d7,
+                0xafb31664395bc94f,
+                0x7d7acad358aaa514,
+                0x3c45f973ddd1525a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd30894df40e73fc,
+                0x6d82a5cbfd0645fa,
+                0x655bd583ed91b458,
+                0x34dde3be3ef6e4ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x394bee9b5aa88b44,
+                0x4a118105eb14e1c9,
+                0xeb8649563843c06c,
+                0x15a0e02525d591b4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6c93759d82e2a615,
+                0x72c23761982f6075,
+                0x3b407ee6213f9846,
+                0x228086199d2259c7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdf6a8069922f7812,
+                0x1e557bd0c9f1f5c1,
+                0xca9835ab1a52fe5c,
+                0x2f7a2740286f9f1e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67fa049de21f777e,
+                0x2e9f4331ad3b5834,
+                0x79efeed389ea1fbd,
+                0x3db8e081a365799b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7613b9c8d5ae1836,
+                0xd3ab588ef4bab389,
+                0x58bd6aa7fcaedb9b,
+                0x214ea38ee4c1e97d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd1288fa91c4a26df,
+                0x4ceb7530491414bd,
+                0x17832f475f48bcf7,
+                0xbe2ff52ab0b97b0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa2131313b9aa10e2,
+                0x2a1659ef97159adc,
+                0x912adb4480c300be,
+                0x5882bf923bd993e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x81d2f91f1da28903,
+                0x87ee5f2968bc8992,
+                0xea4f70a6f85a8161,
+                0x134780cd0ff19c9f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5b474c0cda5828f7,
+                0x366ae50a89e65211,
+                0x2cace8021d3f1cdb,
+                0x2c0a68c0d8c1a8dd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd28b67a3dedb9b19,
+                0xdb36c0b4470bcd03,
+                0x7754e3b1576a2e86,
+                0x3d5128dbc455153
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x42bbce5e960e4088,
+                0x2163ce0829c0aeea,
+                0x1254b5df6f2fef7d,
+                0x11ccbc4d24c22d4e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc33b1e07066cd2da,
+                0xc967e4a8751dc2d7,
+                0x92d8b9c831d5d297,
+                0x285531e81fe62067
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbfb1a44f141911de,
+                0x8c86b24a619846cd,
+                0x5d815a78ba65098,
+                0x3f16248ce213d90f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7c78cc15cd724f16,
+                0x4bcd078b75341a4f,
+                0xdc6a17940d79ae9a,
+                0x3f1dc79b2d616c3f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x35730e6e4a83981a,
+                0x20051654290ac43d,
+                0xefdb5fa4b3134700,
+                0x2210b9998e5b8935
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x10b03365464915d6,
+                0x8e1fe3a33a71589,
+                0xf8b7be543cd7f208,
+                0x2d656bdba63e49ea
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab827dc854394428,
+                0x7f2557766e827437,
+                0xa12320fff42c95e4,
+                0x22c4de7f26517f69
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd5443cb99e1074c,
+                0xf617a526c547a3fc,
+                0xb5ba51fc56a761b2,
+                0x1d85f794dc157986
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb73b5b158c759627,
+                0x80ac17587ef74c8f,
+                0x4a6e8895a34e0ecf,
+                0xb39b93aeeae6bff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8fda2e701592fdad,
+                0xf0e09ced2a6c2a30,
+                0xeb0833b98ae82f86,
+                0x1479822ba956f302
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd6dfeb5d237eab6a,
+                0x5c88e9ac04fcd8fe,
+                0xce0e202c317be6f4,
+                0xe3d3d2b05291bb3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdd490bcd1bb3dae0,
+                0x7e103a065f1d38f8,
+                0xec857ee70b754b34,
+                0xba817d00eb9b813
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x48f9d7970b69bdc9,
+                0x86e09fe1b197e656,
+                0xd3bbedcfda834b28,
+                0x35def423fe34e286
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3ce33d74473777d2,
+                0x24c2ccdc35a1e4ee,
+                0x2a9b80bf6bffbfc,
+                0x96b0b588401f287
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc0abe28cd4cef7f,
+                0xfdf074c736a6ed9b,
+                0x476765ae799cfc65,
+                0x70d07156ca7fc00
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfbc49fb3cc3f1547,
+                0x3b7d5d6afea73591,
+                0xff46b42a92e7cb3b,
+                0x209a2bb3f8ed3ab2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf1d9b040201c1e4c,
+                0xbc2d5abd37cc23c8,
+                0x6d9e828a9bf01162,
+                0xed45cf4bc89e28f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe01597b5ea3ce3fd,
+                0x48491bba1181f541,
+                0xe276e880e439ddf1,
+                0x3e6c4ba4c22ef853
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe9a5afba81338f98,
+                0xedf5ab5ca9912f54,
+                0x6406083689ae5622,
+                0x9584a5919f51d7d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x359b7502c98eb57,
+                0x25fa4ea5a742bf8c,
+                0xd1b9c352e2619d5c,
+                0x28b6fe48f054bd12
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x64e40e80619e23b2,
+                0x16105e99fd030e60,
+                0xf40d139fed3ae0b2,
+                0x18ac4177b351fad1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3325ba7a175c9205,
+                0x6c5ffe148022b7cc,
+                0xc3e190b29bdcb0e5,
+                0x3419b69e93bcda5b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9ea4cdb8b223e106,
+                0x11c2d8eba5bd3f10,
+                0x3aa5d4b32f61edac,
+                0xa282a979151ad70
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x56894707fc642673,
+                0x86b9a1e2bbdf82ad,
+                0x6136e5727e23c0ae,
+                0x383abca114a6b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8c005a6a44621350,
+                0xb32b60e2fa2bf2d7,
+                0x85bc51dbf9429505,
+                0x3569948a6876f0fd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd32f807de49a616b,
+                0xa18f28eb7057983d,
+                0xde26f7cbe9eff2f4,
+                0x1627c10abac46bb0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd216492f23d5e158,
+                0xb74439cd7892e679,
+                0x1d3bc19f0a37536c,
+                0x21dd0b41ac36c92d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa7b9898fc4161601,
+                0x7e2203cb46613a88,
+                0x667e4201a5451818,
+                0x292fa60712e75c1f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x769589d6dc2af57a,
+                0x2613e11c092feb1a,
+                0x96dbe65787f5ace8,
+                0x346b20e32c52fb00
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd31d6afb8b22f113,
+                0x6fa40a7a12efa51a,
+                0x126110e91f6279c4,
+                0x19e79222f5ec1029
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbd149df867aaaf67,
+                0x5cecfb23fd9c55fa,
+                0x65277da34b60ab62,
+                0x6f4323e76d67379
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc8b2c06e8668589a,
+                0xbbaa40bd02bf7d85,
+                0x3edccd6900a2744d,
+                0x5dd2c505b59ff8b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x819b17efe9a4450b,
+                0xce9faa4c5930ded3,
+                0x68a47d1cb35f4120,
+                0x2e8202c05f570b39
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5ad583f004cbdd10,
+                0x913e7f77d86f67ac,
+                0x61b447986e42b3a1,
+                0x2c2feb87fdbc08be
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7616fa7597edd14c,
+                0x1ffa891d45563d90,
+                0x984a7cd448b8e47b,
+                0x37289a1701a4adb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa2ae33bfb2bc9ff2,
+                0x609228d655108644,
+                0x9dfa3a50fa2d841e,
+                0x1b8bf0f7a183da69
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x36c6884cb75332c1,
+                0xd867153ec118fafa,
+                0xa902f97572f0350a,
+                0x3e155bf8e354e0d8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb233c6f21d9fc01,
+                0xad834db4e48706e4,
+                0xa7f8e8a9bdb253ad,
+                0x36f00550005757a3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdddeddeff175854f,
+                0x248a0b280e0b74fd,
+                0x75985dd79a90bdba,
+                0x318bde2e46d772c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x556139cf5b894919,
+                0xb72a3f1c19d49a6c,
+                0x4121b2dcbb6641a7,
+                0x169b89fcf1db4064
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x961d325ffc9c8740,
+                0x5ac4de098f76e56a,
+                0xc335215d864a349f,
+                0x86d492f80388bb9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xca824210582427b4,
+                0x3d18b93b32410829,
+                0x4c8b57b18a0d5bc3,
+                0x265c86c62717e47c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3450726372711bfb,
+                0xeb778d23dbca861d,
+                0xf5b315cecb955954,
+                0x1f304a8c91840ae3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5d9ec8b6cf26c3e6,
+                0xcca9d085a4fe89,
+                0xbba9d92d16b76e2f,
+                0x3391351303842811
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5bc5c8d531e9eaba,
+                0x77cea534af9ff882,
+                0xc29d9bc20b77bfc9,
+                0x3e62794331e73224
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x91aaa16043774414,
+                0x74970fe727608078,
+                0x5edf45ceeaa1114,
+                0x1cc0a1ad2126f9c7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf3fa54bd59b1cc71,
+                0xd567f75be6f65998,
+                0xbf9ce033cb3b4c14,
+                0x3bfd9387c722c7b0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5473f1e2dc9df42b,
+                0x54c17cf6dbb59646,
+                0xfc55d904c023a49d,
+                0x37d9265c3b3418f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xabb14c242d65cf86,
+                0x709b4c5bb1efdbbe,
+                0xe5cc351af1e6508a,
+                0x16992d8ef1520896
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6ff6215dc5ca10df,
+                0xbffebffe04ef25c0,
+                0x3aa7c2e131eb83b3,
+                0x104b0189781a2c5c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x31ee8895890244f0,
+                0x14b20710802a20bf,
+                0x3cdb717389dec360,
+                0x2638c73dd784b4a5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x66812539f0036d03,
+                0xc6e06f2c69134f17,
+                0x3a8d155c860a298,
+                0x3e99b0dbafeeba1e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8d646496c3704ef5,
+                0x91e77b4b5dea4402,
+                0x54bee638798aa847,
+                0x1d838ae365cb870e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd3b25b47bad082ed,
+                0xae570d558b227073,
+                0x938490ecd64a7bc0,
+                0x3e2a1537f9e35812
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9a2f81ae1c8c13c8,
+                0x4039c0adf39d850e,
+                0xe7c78289d5cff45c,
+                0x4582d9c2601ccbc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd8a80651e9de1885,
+                0x77bd703cf51098e8,
+                0x99996083c6eb8ad4,
+                0x86252a49ad74d3c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x85478c2a8c23ef49,
+                0xad9efb18840c189c,
+                0x5be425b6cd87f6b2,
+                0x13b04c28ca4e1978
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd2bac89daf260592,
+                0x7e2118b321b1cb10,
+                0xbfce82614f56099b,
+                0x3ef3001d1410041c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe75ea54e3e94169d,
+                0x1ef6ea02fc263994,
+                0x35e7af58abb1faa5,
+                0x2cd02f8439941ee6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd8bc96b12100c86,
+                0x2ca2cce49e05eaa7,
+                0xdfa256e9742f8160,
+                0x143d18c99caf2314
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4a089abd1c467740,
+                0x77a12dc4ead25242,
+                0x302122df63a60f95,
+                0x3846db3d24fe126f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1af0b4ac64559872,
+                0x858007ca18d4171e,
+                0x6d1761240092542b,
+                0x33907e6eb09fdc08
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4fdf3a8851a5df96,
+                0xf6eb0f63b8919146,
+                0x7212378d92c37c8b,
+                0x781ce7284f99d01
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x85ce06c4361cac2b,
+                0x636cce283f394738,
+                0xc48b1eedeaca9307,
+                0x2321b122388b0dfa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6e7bd002153ba555,
+                0xad0ce38ab8484d26,
+                0xe47e89f55bb7186,
+                0x26c49c11fec30704
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x90e9cb9f8084d68,
+                0x1d4cba08c3608b3,
+                0xebc63b0500d92568,
+                0x3ff0e83fca75e318
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7fd6b50986114768,
+                0xbe245ccee0f3570,
+                0x2c13377d37f9969d,
+                0xaff3b25b682a00f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4c1c20de5e943ad8,
+                0xcb04c6a9307231b2,
+                0xfa44cf028ee4c18e,
+                0x613d368cc8c31e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8d337746df99e1b4,
+                0x60bb68697a8db91b,
+                0xe6aaa15e85b1bcc2,
+                0x210b022e85b531ed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1c48f0e587445742,
+                0xfc361be192c75d8d,
+                0x55ca8768dcd3716b,
+                0x398184216be22ee7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa45194f05b7a16b6,
+                0x1e23f30e24655db0,
+                0x6d2073e611e7419b,
+                0x2dacac41722c9888
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe320de267a565c9b,
+                0xa0dd7a48594a152c,
+                0xe56105ed674480c3,
+                0x3b1bf824c618faee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc51b2703aec1a51d,
+                0x573041627a659f14,
+                0x7f78193d4af9998a,
+                0x2362fd16e1b1c68b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x903a854dc5af5a3,
+                0x647fb43135467720,
+                0x3e3af7fbe6a6e966,
+                0x765a698b35d5d0d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5a2959403b73c4d9,
+                0x7422384ef48a5606,
+                0xf0382c24e105489d,
+                0x1d7c0f949f0dbd89
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdd26cf4a217b290b,
+                0x15ae6782c548c469,
+                0xfd8f74470b7b4688,
+                0xb60db32a008cf81
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x98b64292f558e186,
+                0x391630971c7e9ee5,
+                0x59b2621befeeb111,
+                0x9ff2aa02b299380
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7b11ba152bb53487,
+                0x55ceec36902c0d2,
+                0x6f90bb968b58062b,
+                0x301059f8caa948c1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9caa6fcdfe55500f,
+                0x4f16a4f86122b71a,
+                0x4b47a5e88bc28605,
+                0x1f72cbce55c526bf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x74d53491088c1235,
+                0x489008f0e7c8b8c0,
+                0x1600004baf6e073c,
+                0x39816e923b437055
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe321c28b6f3f5eb,
+                0xfd2597bf569d240d,
+                0x7fb1923a31751ae,
+                0x203e0787a27f539a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1c6e53107170471a,
+                0x99974ab26375cac5,
+                0x50651f109a28d9c7,
+                0x30ca5b338e0b2345
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x713b344420abf3ab,
+                0xd34da110ef870f47,
+                0x1dc5a43087cdd1d0,
+                0x22dedc2bb7e6bfb7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xacb5fcbae61ddab7,
+                0x241268ed8e2aa76,
+                0x4fea2af734c02ebf,
+                0x3636d46231fbc9e3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8598d6234fa215b9,
+                0xa35b4152259f4017,
+                0xf4d9472b7ad7fd6a,
+                0x3972bc6df04a15eb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa99e2b19ba7ee888,
+                0x773d05280e8376,
+                0x4a617dc41ff2e122,
+                0x40a09d6ef02c29d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2349fbc3d3cebf07,
+                0xf061b50385fa137,
+                0xd047b9e11cc1956,
+                0x14a0a6edb099705d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x45e4e80650e8e4aa,
+                0x80262ecf8fa78ece,
+                0xe16aab24bc46996b,
+                0x30085f58a94b0df0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd20b2eaa0415a6ed,
+                0x80ac78b3d852aa68,
+                0x8e7c43855d597546,
+                0x32a8670401215b49
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x13602157dd754554,
+                0x1f655a7025b896b0,
+                0x853615b2e69b5939,
+                0x2ed17a0652e0d078
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaa545c99d21e03c,
+                0x27f2834384659f6c,
+                0xcfff2c6829549337,
+                0x3cd4229d3fb4c107
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf29b09bbdc5dd1f8,
+                0x26d22f130e443b51,
+                0x19b4a8e718da389d,
+                0x13747f1825b14896
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc454b73e13ccc54c,
+                0xd6a5061733c3a429,
+                0xd476965656aa15a6,
+                0x1d1a9ed4a81fc19e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4899caa4f7ca9626,
+                0xd57b9be625c147b,
+                0x472e80cf7290e919,
+                0x1c327a6c01b27d01
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc25a571fd74d9c95,
+                0xc7f8b667c852e696,
+                0x6bf14d797c0d8f5f,
+                0x1e64c349150c64bd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa4fda227de15b648,
+                0xe5b76bf9457dd80b,
+                0xe02fef95b0dae504,
+                0x19f1ede862ade2fe
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc0fda9b23dfafb9a,
+                0x13983b3bb1c0d9b3,
+                0x29238a94302948ce,
+                0xd936f16cc4ae1b3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8561697327399835,
+                0xaa2c78b1c7b198,
+                0x4a231cab00b20775,
+                0x336e21576158d771
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6542e36e17b91147,
+                0xf08036c1520ebb65,
+                0x3b8ec41011c41df0,
+                0x327abd3aaff6fe3b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd97c97b871a07062,
+                0x29f9af9accb94c1e,
+                0xe724acef6097b9ea,
+                0x10f362ce1017f39d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x221698be90887a25,
+                0xfb58cc8f72660d12,
+                0xda7b9c00d830b56d,
+                0x318d5d09d0774b5f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1a571512a060b725,
+                0x4129dd9185613018,
+                0xa45af9e495b8aaac,
+                0x1777c781a53d596
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7a721f31b19278d6,
+                0xae02b452cc1f804e,
+                0x1fada3a6d437c21,
+                0x55d3c8ffda2de70
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc0702b2f5581b525,
+                0xbb180ef6566fd1cc,
+                0x4fc01a9f33090a9d,
+                0x3cfb6ca4e3fd7125
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x373cff94b5c247aa,
+                0xe46e1773bec1b495,
+                0xdacd035806ff568,
+                0x7f4e1352db5ae6e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1980f0e43566aa63,
+                0xc0fa08a7c55f4160,
+                0xfef397c75dcee4b0,
+                0x19b3cda7686bb605
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb5c8e69db8965416,
+                0x59c9ead91b008968,
+                0xc711469e5fd55886,
+                0x23ee3ab199f4d58
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7acfa58bb7c4a157,
+                0xe9638f5acc4fe9a9,
+                0x9fe4e78537f016e2,
+                0xcb58c0368b8665c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5005678f508196a7,
+                0xc1a933f5b03d9aa4,
+                0xc969f1632f359e7b,
+                0x19ce9ff3814a9a36
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xceb7d672b7325b49,
+                0xf5f139814f41be5,
+                0x658afd944fa94063,
+                0xbd447b06465ac0f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x71896b5ee38dc452,
+                0xf1d04472d19a939b,
+                0xb805d9acb61c240e,
+                0x115e52edc183e84c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7e2ea0db42aeb995,
+                0xfb82f600021db616,
+                0x905e58936039765a,
+                0x55d05250cb48827
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdb52a2af31a49a5b,
+                0x7cc673bb77473919,
+                0xe6d6dd4c4e3f20d,
+                0x2c8e6dad44802224
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb926021c37afbfd9,
+                0x834bb2df64867e62,
+                0x52decf740d9cbe0e,
+                0xa05468bf532015a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8079b62b66cae54a,
+                0x1dfb8e872a30b18f,
+                0x597cb3071faf0900,
+                0x974fb60cbd1dc6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x325d20a81b0644ab,
+                0xe0450192cd9c2fb6,
+                0x3fd46b159949fc59,
+                0x39e4f7bd286b9d07
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2fc0ff860fbb61ef,
+                0xa8f913595330fadb,
+                0xc7d45d18881f8bfc,
+                0x126f622d030294dd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9fe85ed149b78b84,
+                0xd0e45ccde7b41a2f,
+                0x415bf2c2854c0600,
+                0x627d747f22998d6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2cb9c050cc8b78ea,
+                0xe9f14fe56404ba0d,
+                0x9c0775571b356bd7,
+                0x1bf495f8411a8550
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6a93ed173cb6299,
+                0xb144645f3d3ccc28,
+                0xdf788773a40de182,
+                0xb7f721ba9fb0a75
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe82dda141bc777b3,
+                0x5e3b7f2f7c2d81,
+                0x63c01ce9a35423ac,
+                0x172694f4db863c75
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7261db41a2ed73c2,
+                0x7f83ef677a821f1d,
+                0x3978b093267f7706,
+                0x61afb856850b64f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x28608a3658dad392,
+                0x2da2ff3498f1e54c,
+                0x2acefd7b0dc2bf06,
+                0x392c1524c045da09
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4bb216333cfb9297,
+                0xda2f37d8d9e7bd91,
+                0xf2a9ff058acf014f,
+                0x145a9d6d8d031fe7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x261432edb4ae117,
+                0xc140aadef0f1a5d6,
+                0xcf65c4fa30f0e374,
+                0x2b045581d641fb8e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8df799d975692f93,
+                0xc8b590add54f9925,
+                0xc32eeea91b6b4a84,
+                0x58839dda930578c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb076326f4f37bf76,
+                0x4e4ea726e21ed04a,
+                0xd77e32550d723ae4,
+                0x2a133ff2335e5a9b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x845fe423da053717,
+                0x407cb2bdc275a1dc,
+                0xe8f9bb14b9a52337,
+                0x264c6240de1d51c5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6b08899cd04d712b,
+                0x51fd86215a1188e7,
+                0x4a1e1e91a013434f,
+                0x113a30ced8daeb98
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x10ba8fdd4c546f9,
+                0x48ddd9b766578d2b,
+                0x55a4e017c20374c9,
+                0x3f696ae3541981d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5291134e46b0103a,
+                0xb586b14077d2fe5a,
+                0xcdbd682ad9f37320,
+                0x1e23db0e7e7a6db3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c203ded82f2bc76,
+                0x82dbb1f5b32aae09,
+                0x1a08914d21b25da9,
+                0x11e2e593e522bdc3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc27f4825efcafdce,
+                0x2192fcda3ac5c267,
+                0x502e1d81965d06d5,
+                0x29432ae79ca953d2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x62766c622f279937,
+                0x762ba70d5aaca359,
+                0x7c9d975b72796a5,
+                0x1e8d5cfac8d139c0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6bc2d64daca7db5e,
+                0x4660ea83f8c4e6eb,
+                0xccc4b77d2f02fcda,
+                0x5cca0f93e1df561
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe42d9e55a3e7cc84,
+                0x9f24c0a50550beaa,
+                0x7bb455e84b9026ad,
+                0x23410759fd6084e1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbf647e6adee687fb,
+                0x659fab2258f9377c,
+                0x4a6908b689f66789,
+                0x2a00d82766253ad6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc8cf0028de867334,
+                0xd8e29bd2260981f7,
+                0x4c63d0442731c7e1,
+                0x1b58a0ab6774118a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6b60c37eea620f8f,
+                0xaa4f1ce8b13c1be,
+                0x967e2b5b2f9162d9,
+                0x2962ca5990806812
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6299b8692edb1e39,
+                0x1ee5eeb126ea1705,
+                0xb92fe8ae8c35d251,
+                0x1e389218f1c24a31
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x224e8bebc756b977,
+                0x6bb0933e5678a82e,
+                0x4972902d9e191fdb,
+                0x398f2d5ee2c40f5d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1e2bf65425cd6edb,
+                0x80e97468507f1757,
+                0xea5ea3d4af0e6641,
+                0x37fea03be2c33277
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x19d2d36eb8a45e77,
+                0x24dc2e97cdec9183,
+                0x8df5a739d159d1e2,
+                0x1c922ae47056a33f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7913187210dd6824,
+                0x42b2c4d92b109157,
+                0x5e8139eff39c03fb,
+                0x26da207efd16881f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa4f92ca30ae899b2,
+                0xe86e19af4479df83,
+                0xf70007aca6dd91ea,
+                0x20bb27bf280f0b3d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfd637d5b50accaf1,
+                0x58f95ec36799063b,
+                0x1f43739e17b07ccd,
+                0x3e8e8005351251d0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4c46208384720d81,
+                0x9e99b51f79c11b78,
+                0xed68095b82f5a95d,
+                0x369f0985dd831065
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x32a96679d2e6fe81,
+                0xb1033a44f2891f08,
+                0xd7883ed9174fdf98,
+                0x1d2509c3cb0d34f7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9fe8b6d6576576d5,
+                0x7211a6de639b0a0c,
+                0xb8741150d2b90c72,
+                0x8de804c8505a6b4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x26fb537c1a1985,
+                0x1f2985e52ea7caed,
+                0x1a0815d8cd47d352,
+                0x2ae6e699448a528b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf8eb89954c0171b,
+                0x6896990080e8d560,
+                0xb16ea811aa52b947,
+                0xc83b68447924dce
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x828671a4d67f5ccb,
+                0x23cd7bb70428b8fa,
+                0x1c771749f74d9bee,
+                0x11faee85557e0de1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7c6fbe457a42abe6,
+                0x3748caab4dcfc0af,
+                0x73442bcc7c7d0514,
+                0x205e79ddc4a92e09
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9f542392c31c2b4b,
+                0x26e545ea4dbbe6bd,
+                0xc068601bdc642883,
+                0x284a5a301f19600f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa81b0894a7b00644,
+                0x75a567c97c583498,
+                0x822648fc1a76905a,
+                0x18af07573ae19905
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x545a4ca111ed2362,
+                0xea88f1fbfbe7d3bd,
+                0xbae944c1f358f26d,
+                0x2dc9d3086d3d943
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x71ca4c3949c558ec,
+                0xb93f060c73242419,
+                0x690b05e6b44cb7b4,
+                0x31901aac5fa76c4f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf949f98f1444b10,
+                0x561a9435ae4fd817,
+                0xd0cb3ae71235b6b2,
+                0x367dc5e83129fae7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2da080beb1e1add5,
+                0x9bfc8f107556d6d1,
+                0xf7e9bb2169be18e0,
+                0x39ed54e7f8fccaf5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbea6efc93ed234e2,
+                0x39b6bc9737ed07c8,
+                0x4d0b32c261ee1fd2,
+                0x330000dbc8676a5e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe65e37711c0232b4,
+                0x8371e05ab2e97f70,
+                0x441b480544702bd0,
+                0x375f92c7a596b70d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe26c9ee45184384,
+                0x30cafec091cbd322,
+                0xf5dab74da274b699,
+                0xf02eeedd5ec120c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6568b92d9bbdc230,
+                0xfb3884ec2f29d67,
+                0xb6b54e6e5780a6bc,
+                0x9c2440fa30a30e9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdc2db470e8c6c850,
+                0x78302482206236fb,
+                0xf5945a3120e2f04b,
+                0x1f1cde0f3c3a4300
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc3821f199a8d4233,
+                0x63c86c9be8aebb4b,
+                0x24100e5a9648ce3,
+                0x1361e6c8eec23aa5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1a77ce4d7424875a,
+                0x147e0dd2cfa81905,
+                0xa38b2a0a79fbffb4,
+                0x15d7d5b9608ef8dc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa39f3c66b8d214fe,
+                0xc5bdf836176a96b9,
+                0x457ed2c45dc39204,
+                0xda4a6461ad798f0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa7fa78a8db405fc1,
+                0xc16e81c03bb90fc1,
+                0x2abeb2777b45fc3a,
+                0x11a6d5289baf9326
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe4b97f828f910bce,
+                0xd0744fd492c207a7,
+                0x1c089c060b0a0465,
+                0x2d1986b6cc1dc61
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6bb60600088a5df3,
+                0x7a384730c9d179c9,
+                0x3fe28fc503053b85,
+                0x2b3f884ee31eca3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9859d12e1a2aea92,
+                0x193f3891101d65cd,
+                0x7cf6017de313eac9,
+                0x9b06eeab83514b6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab5c5d3a0822e42,
+                0xe85f2875e3d116c1,
+                0x231af7b81e3a2dcd,
+                0x2c569795ef7f75d5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7c50e955e316a02a,
+                0xae99ed80283f4b53,
+                0x605829cd7525f675,
+                0x423090ff05ea22c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb64e58ce12f6bf,
+                0xd3c7acf6e2a19e41,
+                0xb15aae416d4f411e,
+                0xee6bbd60cebda0f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4404a0334e0aef60,
+                0xa642ae3671aa5e42,
+                0xa569977abb58ac9b,
+                0x20f08f681f2501a9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdf5b95d1a98a099c,
+                0x3437c1732edcb1ed,
+                0x175b940ba3faf7a7,
+                0x2f202180fd4358ba
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa9c34e56fb386e4a,
+                0xb2807c2f114495c8,
+                0x8555e78c1133cb2c,
+                0x2f24017f2bd23554
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa15fb426994abd60,
+                0x85adfd280fd56694,
+                0xb7ba159146bec9ef,
+                0x2d9cf899253f1ba7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x803effb68c31c586,
+                0xd85bf9a33549bb6c,
+                0x59e939ba4de2245d,
+                0x1f1264214b425364
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3d38867e8de05aa0,
+                0x787ade4f17bbada2,
+                0x9191bf3f78f54a62,
+                0x1bfbdf0e2981ebea
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9271f54f6f58692b,
+                0x4f4f60b608482099,
+                0x1c04e328217a95c5,
+                0xefb8a0c14cc21ed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x97dff24236849157,
+                0xeb09778d65c167a6,
+                0xe1b1723d060fd762,
+                0x3329fb91e2a89b93
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1d97d06f9279a995,
+                0xabd3321f39dc39,
+                0xb208fffc6438d4e0,
+                0x591856bfe1afed7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7a2ac395aaf976de,
+                0x47e918d9c14a7875,
+                0xceeb3fedb2133943,
+                0x23e3b09b408edaea
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5bec65af5f6d2d3f,
+                0x741cbbe78095252f,
+                0x4b010af0de627b05,
+                0x1ec5059410871541
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9f89c50f72fe607e,
+                0x893086e569f09a2f,
+                0x2481f16ae1e448c4,
+                0x2acbbd94a4339bf6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x90072ee0f8b086da,
+                0x4947a504c5f82032,
+                0xb40eb30825304a2b,
+                0x3ff53286aefe48c6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x25b35a422abb269e,
+                0xa09fb42c05b74e38,
+                0x622e7c867051c02c,
+                0x357e269abb9d6e91
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x84358ad595c3b715,
+                0x1c1d3a4441e51bdf,
+                0xd490dd4d79f1f7f4,
+                0x3a47cb152b705285
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6cb231d9cf546c9d,
+                0x1390c8e660bded7b,
+                0xf9ee6e62dab16100,
+                0x1bcea4f5709143d1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa79ca004d26fb053,
+                0xb77c225c6565ae55,
+                0x38aa25328cb2d721,
+                0x2265e6d97cf3591e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1bc79baeb318e438,
+                0x4930aea472a3593d,
+                0x4a7b06f44e82bdd9,
+                0x2bd4181f18be63ec
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67c87dc4d8e514ac,
+                0x894b4c1d995cf623,
+                0x2930f096cb7a239c,
+                0x30af55dd80348b1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x33fdd009b30a65ca,
+                0xdd7b597026b194ea,
+                0x413db5a71e2eef4f,
+                0x4408353f9712838
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf9ad153b6acc54cb,
+                0x5c6e0d04f357ff84,
+                0xba75600f41f3f689,
+                0x353bd1c87a6aabd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb214815ea609e315,
+                0x9d1015533e8fed12,
+                0x271f718c02688868,
+                0x3123fae4af2bde7f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x97533e85bcd31389,
+                0xf1c31e1711607817,
+                0x6997a2e7e398e30d,
+                0x340f60ff9a26e1a7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xff79a48ad586e48b,
+                0x304e365b4da9ed0a,
+                0x4bd533dc557d144f,
+                0x15b7cc0a14370f6d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa4f6a91e99b66d46,
+                0x2da04df906639606,
+                0xdd5d6d670740987e,
+                0x3845549e1e311887
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x75d0545acd809009,
+                0x3363915354c318b6,
+                0xb275a0a0e6d78255,
+                0xee99626a6a2916
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc7c64e1cc46955e0,
+                0xc42fad411dde59ca,
+                0xeae91296a24c9ea7,
+                0x1a88143defc18010
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8535c6e06a73e6ab,
+                0x6289b1aa8724c86d,
+                0xcded0dbf0ae902f8,
+                0x9acb361b2d884c8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe617ef6e61407a71,
+                0xb33ca5b89e8254be,
+                0x45ec06e021a82096,
+                0x1e1c98e15bdd3712
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7f2a56e335b56286,
+                0x84230d1ad30f855f,
+                0x14a050b104f2e362,
+                0x12003388840b7e9d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7c891806efd46317,
+                0x2f52801a9ace762,
+                0x5629c0207d493fb4,
+                0xbb6d12575fe1e3c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9f2eb875e0de3ae6,
+                0xd5bc6df11048282c,
+                0x336099d11a225f51,
+                0x3b0a161ebd9532dd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe14d49d880a61c74,
+                0x6c864a8c6824abf1,
+                0xdcee5d0b4b4094d5,
+                0x1d984a0db7d4f7d5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x40af77778238a46e,
+                0x2bd1b274f5d3b19e,
+                0xc5b42d7c846b2375,
+                0x24e81bb4f1fc9a37
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x70381867ad2122cc,
+                0xa2b777aacc10e74f,
+                0x2a03a49f80ab0cd2,
+                0x1be0f74b3fe8068f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7efcf47231279bfe,
+                0x49401caee6830e8f,
+                0xa961c5f59a9133f,
+                0x27eccf31e8d9963d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd2852f00b749e03d,
+                0x8fd171ed5be4aac4,
+                0xad393ef7a67123cd,
+                0x387f3c555d8c31ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xba4399fbe3e875b9,
+                0x7d99741dd26c1230,
+                0xd7dd1e95a57df0cd,
+                0x2e26fbebde686e3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9bdbdc7e012ae4fe,
+                0x751d4a2ed230813e,
+                0x3fd7ddbfcb168413,
+                0xe9523a309bf93b4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7dc529a61b9a6432,
+                0x750e4c3d9ea31829,
+                0x48702ffab3bda0b2,
+                0x22b6734a40b71b54
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe6e9e472c023c338,
+                0xdfc33deb835213cf,
+                0xe5d50345a045cda5,
+                0x7805a034773aa7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2e37bfee1fb8819d,
+                0xefb598108ae3bc4a,
+                0x5339b4c7c3a7a91,
+                0x1732a61687a38de3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x947b0c9009ed4826,
+                0x29117ad0707425a,
+                0x76502647f8cab0d7,
+                0x19b0551435c66332
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1284d9be4da80d9a,
+                0xca53a86a20ca6e57,
+                0xd87d5be9ae9fd5ff,
+                0x20617e08eebd3df3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf0668abb932b08f6,
+                0xa62ea2b48ffce8a0,
+                0x8052a1b44a36ff77,
+                0x14f856846d1d404e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2036aeac4d213a1a,
+                0x10157e6ad95fdbbc,
+                0xfb74260fd553afb5,
+                0x103ef88d9a5ee763
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4087089343236fe,
+                0x91d0f67ab247834,
+                0x252f7c359a9d48d9,
+                0x1d18a284028bc928
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd1cad1f3fb91923a,
+                0x6eecdcf4b0fd4ea,
+                0xa98196f7434d8994,
+                0x10623cd1c0fbf34a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa80691bf61c887c1,
+                0xa2e64256aa50b10c,
+                0x26aad96254cb1e1c,
+                0x1e93919d8f0762d7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x679a23454597f481,
+                0x5fd1a942d8b4c6c,
+                0xaeccc5f2c26a14e5,
+                0x2c23bb195e49cd17
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa940a59f2cf77d5a,
+                0x37d0290604654a2c,
+                0x2d54831585c1d3d0,
+                0x3b1092b814193b54
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8f66236c70d1ccc5,
+                0xa943502c254baad6,
+                0x64c7375974a02dc8,
+                0xb7c5920c206a879
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1e94232da1e96aec,
+                0xc7a8f45ce5cbe860,
+                0x81fb8378f1b05d74,
+                0xb1cc7d60d005b02
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x36f288181d9c640e,
+                0xa546d60de58c855,
+                0x570377d82ac79c8b,
+                0x324951e36439646d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8750b20f05ef457d,
+                0xebe7bbc467d65b44,
+                0x61f6453c3a30f623,
+                0x223e528fd931260e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x18d6b451207a3e48,
+                0xf9d4e986a6d60102,
+                0x25882335758ddf6d,
+                0x2f4aa4b92dfc2cec
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x58cc77f4a364acb7,
+                0xdd5c471e4497898e,
+                0x8e8a2319b38774b8,
+                0x1641bc14afd1734f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x411b9f87a4a5c1aa,
+                0xc0f310e6f2784bdd,
+                0xa24b99e8f32de8ca,
+                0xf2892871695709e
+            ])
+        ),
+    ];
+
+    // The MDS matrix constants
+    const MDS_CST: &'static [TweedleFr] = &[
+        // Constants in Montgomery representation
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x419550123c45d5fe,
+                0x19eb803449ed66b0,
+                0xecd2712aebd0198c,
+                0x3d520771ce4706c9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x43877f16f6bf5607,
+                0x6208e5a376d1a1d7,
+                0x694989c32603041b,
+                0x102ab649c1a8626
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x86079277758db321,
+                0x11a2245fbbfa7b03,
+                0x89963b896bf7b731,
+                0x1cdb858724ac2f7f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc310de85465f5925,
+                0xfbd1d08c914a8a62,
+                0xf1bbfea099d881d1,
+                0x1c5a37f00f2418e6
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9193282da9b725ae,
+                0xa4fa03bdaafac4c0,
+                0xf23359b0701aebed,
+                0xf76d51b5ff96dab
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x673025613d6d4b5e,
+                0x67fabb6143efc6fa,
+                0x39294a7d7f32f37d,
+                0x32ce26bbf4a12b57
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xea5be28241e9c059,
+                0x321cb12d0c2ad060,
+                0x46f45e9a2d71997b,
+                0x3f5dff7927a88a2d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb36f3b701cbad19d,
+                0xeb90fa755cfec87c,
+                0xa5dd5368640bd111,
+                0x1e2ccc62acf2ee7c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x47cd74c67da8c5a2,
+                0x92de29bae544c51b,
+                0x76e4cbc2d960ebc8,
+                0x307ce074cb05c6f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x37357b69202be7b6,
+                0xfa223634883e7a96,
+                0xd056e3d7a144e6fd,
+                0x168e2ff78dace9f4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7d85f520bf65ed1a,
+                0x35322b210a7afdf0,
+                0x114890550d687598,
+                0x119b2c85020b5874
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa7b9f1133e1353c3,
+                0xc13dbc611b17e77b,
+                0x6539c7e02ea57cee,
+                0x283e1970039f7eab
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8a111ab5519f20c1,
+                0xc5fe71ca539c3a6d,
+                0x2a84d4c9b6b725de,
+                0x110fad0c8f1ff998
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x31775ccf550df281,
+                0x9ec5263ab918e474,
+                0xf036a85340667e2b,
+                0x5584ef95d619e2b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc3f04757f3201ee3,
+                0x78c3a84774e1a541,
+                0x1f793e61e0d26ce1,
+                0x174af5c3e4a5a38d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x13c2849b0aca06be,
+                0x4052d5c13d8a8982,
+                0x718e36df7213ffeb,
+                0x9f1f5bb0e0ec5df
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7bf8a5c65938b689,
+                0x6f063ba99f6e3e96,
+                0x11d59c1d3d6b009e,
+                0xd647ba7579bf4a9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf6eb0588ae0a027c,
+                0x863f4eb25a4fbd8b,
+                0x682671fa42a5b2aa,
+                0x3f64f7329b24667b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd262b9b2658d0cd1,
+                0xbd652a401cae950d,
+                0xca7b62319b396e86,
+                0x8f8cc8cf2278d1e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc4c9f1a785a2af39,
+                0x44d0998676322e34,
+                0x33187c9bc8980e7c,
+                0xb298c4d9ca7b160
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa465ba491b35c69e,
+                0xdd9fc5616d31caa3,
+                0x925371f8043442e6,
+                0xcb7afd821783c32
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x35eb65158bd466c0,
+                0xc43f6e971758320c,
+                0xa5fc22075c0cfa3f,
+                0x6d61dab6159dd27
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x12eb7d68cc7b195e,
+                0x6e1fdb2f8181636a,
+                0xcc97c99397436aab,
+                0xdf8f9e4b6eba637
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeea19fb7c46e28e6,
+                0x94a492bfeee68b38,
+                0x6c0ddf87477974b5,
+                0x22c656660c89675d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x402865e7b2379bc7,
+                0x456fa2afd07637e4,
+                0x5ee6a48848da51ec,
+                0x35da3e335262ca89
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5447cf7755791e2b,
+                0xd1c7c5b3a3672f67,
+                0x42357b07cc6f0e54,
+                0x1b9b1ba1e8f1d538
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf61948e3982a6d10,
+                0x9bb3f4968d458ed9,
+                0xe7869586fde984a6,
+                0x21ed5fef463f533b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1c4cbd3cdb60d66f,
+                0x44a56cfda1f9667f,
+                0x2ad8a39d5328b425,
+                0x22b4a2d55360b264
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xfd974b29c03fe828,
+                0xf2243d17a5141306,
+                0x232be35069109a7a,
+                0xb33c6a987dec6a7
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x10e02f892897a9db,
+                0xf5edb85791212e2e,
+                0x7cc62c5b79e6d46b,
+                0xe49643cd51c0512
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7c1a4da7c6ec8561,
+                0xe09568cf1cc6d97f,
+                0x6523b07e0931e0ed,
+                0x22a1690ce65f7942
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3a7ff1c88ce005f4,
+                0x7754e394a4b230c3,
+                0xa2948c93d738c3f3,
+                0x279d1f3e5228b597
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe5e2f6799be416f7,
+                0x3dde488368f0db4d,
+                0xe5e2592b75fc72f9,
+                0x1e7f4b3bfbd33dcb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe70dde58ecef5495,
+                0xe5de196534e0347a,
+                0x9b65758312ad2483,
+                0x20b762266fc5b945
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc920606adefbd69a,
+                0xedb62a403bfa40b0,
+                0xdf93778a6f7cc23d,
+                0x6872d2af4aca40a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3336a9fceabf5f81,
+                0xdc0ff14e50f9d3ab,
+                0x9964c64b74efc4b1,
+                0x1c70f02bdaa8c193
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc5335ca47230315a,
+                0x7515fae8d572304e,
+                0x273be41d65bc343f,
+                0x1e2d32b7a5595e47
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7cfcd1f4d97efd45,
+                0x65a062846b43e6f4,
+                0x76ebe28b486a39e0,
+                0x3fdfdfa1a8d0ae8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x14403bc2fa2be09f,
+                0xf06ccbd086a03878,
+                0xdc1cbd951b23c877,
+                0x1e1dc952475d7819
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x95239c3654d54b8a,
+                0x4b98ff9cc27469d3,
+                0x89a42bb04e5dfbeb,
+                0x2ebfb84de92d47cc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5eea725fb1da88dc,
+                0x371da527233ce0fa,
+                0x4a62c1acd74d4824,
+                0x2ede5cf5713ab8cf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdec7f038c1e5ea92,
+                0xb18255aaf45140fd,
+                0x349692a63592d5e5,
+                0xbed0747b1b96512
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7975096f501b9ebf,
+                0xd54b2b8ef06104ce,
+                0x8b63785d5dc57c80,
+                0x3c8ba7414f696d94
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3dd6602223600ce6,
+                0x8976a087bad1df80,
+                0x60dd7b29efdcbaf0,
+                0x1a35c11297aaf47f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4f0119b0980fb038,
+                0x95da5a2b284d00fa,
+                0xf98ad2f791d53d71,
+                0x390e83177ad5bbd5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd6c1ad939699ed4c,
+                0xa0970087bcd7f739,
+                0x99a156594a62fd8c,
+                0x9d5029f9f073aa4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xda3872f4443c70e8,
+                0x6d091e5bfc7f6be5,
+                0x5891ccd2cc258f07,
+                0x3e0cbe74880dfaf3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x74f9a8c194a80f12,
+                0x7a420409b07a6edf,
+                0x367bcf28d04332ec,
+                0x1e2f4d27cc3f1cee
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd8d2ed50693e396d,
+                0xcc600b24e459efc8,
+                0xe4f50990ee37cce7,
+                0x99ebffd4bd1f10
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3343b69a75e875b1,
+                0xe7fae7eba20af873,
+                0xf6d4565f25889248,
+                0x5ab3f65e76d1baf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xba751477c1ff5f88,
+                0x99b71e0c0df609cb,
+                0x741e46c0ae7ab8ea,
+                0x716243a0d873f95
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa2fd8c4a27bec938,
+                0x79d21fade04085be,
+                0x9c740375028f0ca9,
+                0x29991ed0abccd4c4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x90e213a0e109b4b4,
+                0x43c1a3c612c73582,
+                0x3fadcae4597d0082,
+                0x17f7a4dcd9a2b0b1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x67d3f1855002d7a0,
+                0xfaffa8f7244e8d9a,
+                0x15137270b3afa41c,
+                0xab038e6d41a3efc
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd9e979a404e03bf1,
+                0x5b189dddbda0a21d,
+                0x397cba79f4d9904d,
+                0x1b9e3ae431182ebb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2dc53d3019defdec,
+                0xd8441c2f57ec69af,
+                0x59232b8fcdc4ac2c,
+                0x2950fb8227ff10fa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa1935dbc266a268e,
+                0x8564252b5b27485e,
+                0x210d1e876da46827,
+                0x2152238fde237658
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb5b10c6f9a4eef90,
+                0xa4d139a6a6209ee1,
+                0x7c06520f915661d5,
+                0xf6a16687d4a945b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7b607d54d002a261,
+                0x8f6b54f7ff180e4e,
+                0xba89e187bfce4ee,
+                0x2ac62a0124904877
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8008d4a06669ec2d,
+                0xe7117fa9726e4e36,
+                0x65995d0f11291e9d,
+                0x1678840e0d2fecd8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcee092daa8f4aa80,
+                0xe2d02439a29d2e84,
+                0x34ea1b58ff7469ee,
+                0x5f4dd4a623291f3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x73e7317f64d98c22,
+                0xb4944e24713591cb,
+                0xe08532448766679e,
+                0xdacbd374436d1cf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4395324a99fd8c92,
+                0x440783e43a11165a,
+                0xf5ad52c3df74ff5e,
+                0xaa75016cb40c745
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaff48ed27b8235a7,
+                0x37ee53c0afe8bcda,
+                0xbd75afb4568f987d,
+                0x2fba818ff205b224
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x182a79e69429962a,
+                0x97f3f649f42f8cde,
+                0xcf72a37f80f00844,
+                0x23b9be497a47b3f3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xad0eb6e7b78e6a32,
+                0x14ffd75cde077391,
+                0xd3826b53644a532e,
+                0x1f08aeb879ab87f1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb3ba3a09505d7ba7,
+                0xc9e3746bf4031633,
+                0xd863a72f7c18211,
+                0x30b6df26db1f5193
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc3ec7cfadd6e11af,
+                0xd413fd214ebde635,
+                0xbdbff2b9b5edfa6c,
+                0xe922051974e81a9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x824e5d9c823d3e32,
+                0x768550bf7107f00a,
+                0x7b05fd1a0da63b1a,
+                0x31c7d467faeee72d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x65cfd7057fd752d8,
+                0x4d27f48f0efc494,
+                0xa768c985b2abfa,
+                0x34ccd1f472842ba3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb5ff9141e48c4bcf,
+                0xf0f78ebe75c0feed,
+                0x95de17e8323d98e8,
+                0x3d14906dcca35069
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x289440671cbd5c7f,
+                0xc46cc1bd21bfb6c7,
+                0x8e896e41c47d7c81,
+                0x14acb97cd0142bb3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb599c32231a1792c,
+                0xa535f0e1f5f08a52,
+                0xde7aa7cd5e4134da,
+                0x1729a7495dc8915f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe1c315c95e2b0e93,
+                0xb0855f519c9b84a6,
+                0x818b38bfac1878b3,
+                0x14154455973c1873
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x930005c123b90dbe,
+                0x98646959c5c86403,
+                0xfea1fc2e8d889ca3,
+                0x34c5f625b30b62f8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7fed058bd4d5e0d1,
+                0xb08ae52e12479231,
+                0xd38a905ce7ee84e7,
+                0x378495d55d93144c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc66f22f1ca4ecbfb,
+                0xf40ebf22fb9b8e25,
+                0x6e43e9dc9c5f9d8d,
+                0x54c7e9a4a0b0c02
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9900065b8002f25f,
+                0xda31446ba2ee2a70,
+                0x51a20a6b6f5329a8,
+                0x375db5596126236f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3de9fe2ec1d214d3,
+                0xcd1ebc1b28347e2b,
+                0xbabad3b1b61ec87c,
+                0x153b89363b70dbb8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x99c002a87e451e6a,
+                0xafdd3e47a7c9a81e,
+                0xf6ad97ef3502e31b,
+                0x2be7210fc57b1d97
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc00e0f08a19cbe50,
+                0xbd23b8098a36b52b,
+                0xe6c48d65c2767aaa,
+                0x15dccd218b3880f1
+            ])
+        ),
+    ];
+}
+
+pub type TweedleFrQuinticSboxRate8 =
+    PoseidonQuinticSBox<TweedleFr, TweedleFrPoseidonParametersRate8>;
+pub type TweedleFrPoseidonHashRate8 =
+    PoseidonHash<TweedleFr, TweedleFrPoseidonParametersRate8, TweedleFrQuinticSboxRate8>;
+pub type TweedleFrBatchPoseidonHashRate8 =
+    PoseidonBatchHash<TweedleFr, TweedleFrPoseidonParametersRate8, TweedleFrQuinticSboxRate8>;
@@ -0,0 +1,3186 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonParameters,
+    PoseidonQuinticSBox,
+};
+use algebra::fields::tweedle::Fq as TweedleFq;
+
+use algebra::biginteger::BigInteger256 as BigInteger;
+use algebra::field_new;
+
+#[derive(Clone)]
+/// x^5-POSEIDON-128 parameters of rate 4 for scalar field of the Tweedle Dum (= the Tweedle Dee Fq).
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_grain.
+pub struct TweedleFqPoseidonParametersRate4;
+
+impl FieldBasedHashParameters for TweedleFqPoseidonParametersRate4 {
+    type Fr = TweedleFq;
+    const R: usize = 4; // The rate of the hash function
+}
+
+impl PoseidonParameters for TweedleFqPoseidonParametersRate4 {
+    const T: usize = 5; // Size of the internal state (in field elements)
+    const R_F: i32 = 4; // Half number of full rounds (the R_f in the paper)
+    const R_P: i32 = 56; // Number of partial rounds.
+
+    // The zero element of the field
+    const ZERO: TweedleFq = field_new!(TweedleFq, BigInteger([0x0, 0x0, 0x0, 0x0]));
+
+    // State vector after permutation of zero state vector (Montgomery rep.)
+    const AFTER_ZERO_PERM: &'static [TweedleFq] = &[
+        TweedleFq::new(BigInteger([
+            0x31eb99bc873672d7,
+            0x978f0923e6fc0c02,
+            0x37ee1034647ea0ee,
+            0x38dd3520614423da,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x184dc5740d681a6f,
+            0x8463aa3a86ac9c75,
+            0xe0b1624d7c727c7,
+            0x6fb7898abf535ad,
+        ])),
+        TweedleFq::new(BigInteger([
+            0xbb90c132d6db33a3,
+            0xcce8c41206e2a262,
+            0x5aed49a26829934a,
+            0x3417a1ae349be0a0,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x696a44d13257bd33,
+            0xa147582f9e528fa5,
+            0xbe621e07ec53d244,
+            0x91fd79bb91a319f,
+        ])),
+        TweedleFq::new(BigInteger([
+            0xb2177c88d4a9336f,
+            0x52690cb33ed69194,
+            0xa611a2429b639363,
+            0x2df33ed3272be8b9,
+        ])),
+    ];
+
+    // Array of round constants
+    const ROUND_CST: &'static [TweedleFq] = &[
+        // Constants converted to Montgomery representation.
+        // For rounds 4 + 56 + 4 = 64
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd789c76e2486f320,
+                0x88683c614b3112e5,
+                0x86c9c355f40658da,
+                0x5417b2c1b91442a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaff94ef08e0a89e6,
+                0x502c2b40e9f3e24b,
+                0x1929618226e3a8d0,
+                0x30a7d1cb97381532
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x594dffe326f469f8,
+                0xf79962548f2bde3b,
+                0xc3f35b64ba6fa6ed,
+                0x259b77f2c85a1073
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb831cc1cdac226a6,
+                0x44ba80c9c3a0063f,
+                0xe8564fceb44f179c,
+                0x3e3e247f666d5fcc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x984967fe12c81487,
+                0x7ce31a6a0d1cd9fc,
+                0x3dd1743389cb6a8f,
+                0x1fab30352f0fde61
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6af577f338bed2b9,
+                0xf14e514ff4d55246,
+                0x2ff1f5bb5544ad39,
+                0x5d12f3726b3eac2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe19bda55675fe399,
+                0xc92f0d325726800,
+                0x80c433b5a8990011,
+                0x3077bfb8b0446bf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7d283cd0c29366a7,
+                0x69d4bcc91f07f64d,
+                0x67b30d409b3cf394,
+                0x33dfd9fb6735b6ec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2113c409f81a22,
+                0xd73edd8072c4b695,
+                0x38ea099d2da2a5dc,
+                0xc8e3fe2e580ac37
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xffd1348cddb29e13,
+                0xa29aa5271972e58d,
+                0xdada3189d4a548fe,
+                0x18e812df3cf2711f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2d616e76f1bcc654,
+                0xf5768fd79be7ba73,
+                0x7359bee963752fb9,
+                0x18330a6820a92f12
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3a571bb282e93101,
+                0xf32108570850db15,
+                0xbdcf5ab180ebd6d8,
+                0x333864e9e48065e6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x35f268e7cba1c2ab,
+                0x51cff2620d1c3640,
+                0xfffcc80695f33ad9,
+                0x302697c9a56e39fe
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd546db1ce7586bf1,
+                0xf05f54d317dc73d,
+                0x85d8f709513be0a4,
+                0x2525185484f84a3d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa33ab01c8f994ce1,
+                0xdd409a1816c5ce2c,
+                0x7df9a381225cc81e,
+                0x2051e2842bd65839
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x520442f3dbaed560,
+                0x3361ae8630f08d51,
+                0x99d4f9860f3bd779,
+                0x25d0d6caf0e5a18c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5b3b5ec58c5728ab,
+                0x8dc10488fd5e076b,
+                0xe5798f46bb75c4d5,
+                0x2433c495e5f2e9f1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd84ddc2b6823cd7b,
+                0xe01726c3371a4bcc,
+                0x2534505b278516c8,
+                0x2fe300141ae708af
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x27d5719f045239d2,
+                0x62315e7ba01fb419,
+                0x9effeceef7cd0942,
+                0x133d7faf7a4ce8e3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa5356a7b00e0f21d,
+                0x9dbaa524eed4c158,
+                0xb3ebbd1ccc55b0d3,
+                0x38ca6d16dc5ef01b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x34876ddc3f47bea9,
+                0xa2f0a62a4d1a48,
+                0xc86c54c24d590ab0,
+                0x2f2b1b6a4167a488
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x228012edcc50a8be,
+                0x80d10adb0299207f,
+                0x3d67a253a5b8c89c,
+                0x3f41453b204aa105
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x45f989117fd68b1e,
+                0xcce9c600961283d3,
+                0xd88d59de28e5553f,
+                0x3b5e8f21e44f257b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x28091d2b69c87cc7,
+                0x3080c02ebd5aa92f,
+                0x896f2d0d0678d25,
+                0x27625d3f914e203d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x667bcb3ffda84a0d,
+                0x8ad5cdb63904af3e,
+                0x1f8cb8894589887c,
+                0xa41fe19a7205a50
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfe303140e1e82b82,
+                0xa7008511daa0d8b8,
+                0xc1bd2d9669b7ce0d,
+                0x8f75af87a50eab
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcc85957a1613186c,
+                0xab28389531063302,
+                0x755e980533981ed6,
+                0x23354b330b415e61
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1fefd8dc71896226,
+                0x29fc62319c823187,
+                0xb4ec356a0aef9175,
+                0x34b56d6ee098b69
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe3a29615e54973ae,
+                0x41f30e641a765da7,
+                0xb6af50668ca397f7,
+                0x31f7acec57d2f37c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x64430cdd9f9a2d0f,
+                0xbf5f35b98db8114c,
+                0xbe7f828ffbf429a5,
+                0x24050174307002f9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5967bf9bb3398b0b,
+                0x35e4790ef39c8338,
+                0xd28067fee59dca86,
+                0x201a235903c8cbaa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf237b082b1a91af2,
+                0x5e5e905754982d01,
+                0x9ed128c39ebe4800,
+                0xfd0e0fbb4e2fdfc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x87a64256317fbbe9,
+                0xa182dbc082b540a9,
+                0x8bf7904c3f9fa02b,
+                0x2e02d96f400777bb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe275835deabeffac,
+                0x64ca0887d1559ca8,
+                0x407abc96250d6504,
+                0x29b24ca40068be40
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1597b10ec90e8073,
+                0xd75c09229af449f1,
+                0xcc0aa515e0134f47,
+                0x12a1123aae2cfe19
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x25bd5e38bf9b41d1,
+                0x1e6c74fa05af9f4,
+                0xc4c552d3453be5b6,
+                0x10feea4017d15b64
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd6defa7236dc659b,
+                0xbf492bf98b6c7b3f,
+                0x534034b3cb1ea59b,
+                0x22c928a7f96e0129
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x20000747e5f6281a,
+                0x6b42b88bb2c6ad8d,
+                0x198db1e6e827e48,
+                0x2d63a791e66b484b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3527ce7406b72bd3,
+                0x6c11d4ee9babc99b,
+                0x9a1ef9ffd1bf5925,
+                0x5497d974c972c6e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xae79f88a7313e445,
+                0x3bf5fb4c04d93428,
+                0x40c57508f63d3a15,
+                0x6b4dec0a0baa0bc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf8fdabb6020bcb17,
+                0xae6e612c71d73844,
+                0xa8fd7b5ae1f69fbd,
+                0x28da91f3dd9a3a0c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x447a5017b21bb831,
+                0x872c54e8e98dc77e,
+                0x70fb8b561b782e3,
+                0x230261340d3c4685
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfc02ea946ee6dad1,
+                0x7b902d9c7484d28c,
+                0xd06b51bf21355c66,
+                0x17827021ad845b1b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x53d950667f65cee3,
+                0xb184f5dcc9554322,
+                0xed21e04441f58062,
+                0x373eb767da9788d5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x22283c2de427f6d4,
+                0x5431832190114e6f,
+                0x9f7c0ca069a7923b,
+                0x1337ab7c1c3c9137
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeee9cde68b523ead,
+                0x5f8ca197aa994df1,
+                0x96631eb6772f04d,
+                0x33d34e06f43b7909
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc0a219079bc96d79,
+                0x33f291cce5d07009,
+                0x8ad964c58b740243,
+                0x25a7fbb69cbb287d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4d0e809aeb9c8a41,
+                0x430ffdb99e021e2e,
+                0x505ac1fc4d22bc3b,
+                0x13ad77818b28d805
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xca50ec496e0c649,
+                0x5d9c2d69fdc8f744,
+                0x5624551b91ce5af1,
+                0x14c456a32bb5132a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xac8f8e23233ce32a,
+                0x45b5b7d4880ac0b3,
+                0x40c55e402605cf10,
+                0x3203b1c52605dcea
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x29db71b768f28684,
+                0x53f23ca3b88d56bb,
+                0x3499d239ca627a7d,
+                0x26d17b6113b0e392
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1451ca62095e670c,
+                0xec174f7f1fcbfaf3,
+                0x42256f3c9063d12a,
+                0x11736094cc1928b5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2f86b6bd253c3237,
+                0x722324d5cbadd4d9,
+                0xf302ff5aa607de26,
+                0x37fb16731665390c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf8bfcdaf3445ab55,
+                0xe22f571442c6bfb2,
+                0x718abb5815cb4d1d,
+                0x582ef78d7bed068
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8973720372f282ee,
+                0xda462115ecf8d39f,
+                0x5568c7c6928d895a,
+                0x14144256c075efe5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8739c4f5d0e7505f,
+                0x892c8849a2e52763,
+                0xb7bb8fbf5f80f95d,
+                0x3617c8ff002f30bc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfa0498ae53e50ebd,
+                0x8ab70b3528f002c0,
+                0x43d1ad6b2254028,
+                0x21cfed06ad8ce0b0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8a12f67001e39c39,
+                0xf6972bc01654bd40,
+                0xa034700a477ff63f,
+                0x9c7cfce4bb1bb4d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa4c63f2f1e74fcdf,
+                0x6d75597e37b0a658,
+                0x24dd1490896f1992,
+                0x2a2be11b3cafc2f6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd00d9e5de24c169c,
+                0xca506909548a580a,
+                0x5b7910f067d552f5,
+                0x381218f23b10623a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1e724a806b2d0705,
+                0x64db76517e1f36ad,
+                0x5ec5fccea35a16ff,
+                0x301b4e07bc1fed30
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7f31986fa85b85f,
+                0x5de2f9f01f79907d,
+                0xcbcc628bf3d37805,
+                0x192bdb19def95cac
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2d39ca6572b4ff8c,
+                0xa36af1cad2cabdbb,
+                0x33bb5d0ef7a01ed7,
+                0xbbebc10707984c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7d87a9721f56f2,
+                0x2eec65f50ef7c9e6,
+                0x4c88b5f6c0ed6dad,
+                0x39d985371fe885a6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4aa7658433e02412,
+                0x5e576db74056e8bd,
+                0x890d850673970b08,
+                0xd03eef96b7d6dd1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5f649a5e5fef2195,
+                0xbf0a21807ad6dc,
+                0xc90db46e7014d0dd,
+                0x143435b547f5c9ea
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5847c30c9ad6777b,
+                0x349ee95298e05db4,
+                0x5002c7ca7a416695,
+                0x2228cd4d65decfe5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x887317f92ff79d9e,
+                0xf5bea1b9c4c8d865,
+                0xa5d4a60f698fa996,
+                0xa6df55e9170ba3d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb89c84bca2fcf8d3,
+                0xe0b1aa43422933a5,
+                0xba22c13688408c7,
+                0x13783ea236b65963
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x67e4d1ddb5ea4d12,
+                0xc1611f1fffbb882d,
+                0xf47d4e28140e70ed,
+                0x150a62c02e193e4d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9a14e6e04580eced,
+                0xeb40451b602c3bef,
+                0xd727ae29d1e3a6f7,
+                0x30c03e7778a8277d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6340e3f23e6bd291,
+                0x931a0592b94d7aca,
+                0x579df9d5163adb9e,
+                0xff8136634f5565
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x774bf9138c940dcc,
+                0x6d2ba0e61c9a0ca5,
+                0x9b7572d369f21e2d,
+                0x302881168e4ecd91
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x730c96cf1c7c9f9b,
+                0x2b000bef95c28265,
+                0x3f20f8ffde621f48,
+                0x296302479cdc67ee
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfba8fc8d6c0cc234,
+                0xf38d591943712530,
+                0x8b1cd382a0afa6c2,
+                0x25ff71006c51e42
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf2018dd4880064c8,
+                0xb879234433b30973,
+                0xa1f41bbf01fc2be,
+                0x18a6622f1e258f3a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x38c07017140f0ddf,
+                0x76725ea78ea4ba21,
+                0x97c04d86906c0728,
+                0x30ba823fc09a61fc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xad83e4020891de68,
+                0x56ed44f676d0039f,
+                0xc0b67f384004a95,
+                0x9c291b1fb186344
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc0e41a7b5fbfefd5,
+                0xbafab04a0d1c8040,
+                0x8ab4951e21fa04e3,
+                0x2b30f801b1c90d40
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe3af2928ae40bb9f,
+                0x2c33ff2067e370e9,
+                0x526e4282856b349d,
+                0xae1aca3610be546
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd1ac3f53bbdc945e,
+                0x8ba495214a291e92,
+                0x44c3751283707d96,
+                0x39e243ba235246d4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x58873cc93fe9e097,
+                0xe86653de4ae9435e,
+                0x906ae9949f5f2fbc,
+                0x170fe2400ea116d9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x847b28a74fafd2b6,
+                0xa91a519b093149c6,
+                0x7497c0a43ddf83dd,
+                0x5ca0d3bfb54635b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xec4f2ff0fd2af4a8,
+                0x3bb9321d9fa0a32a,
+                0xc7083f0861bd344,
+                0x2c504c470e497e86
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x82b8a9d7c5200244,
+                0xfc86ce79bd1cea73,
+                0x2dd3a02348cbfae1,
+                0x131d0c2e50a76139
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf6ef2b152ee0bec6,
+                0x9c6ce9551c9acd33,
+                0x2beaafaeca4ce5c7,
+                0x11c1594736646555
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4c8b5c9260b4021c,
+                0xde8fc5d5a756b2d2,
+                0xba5fad14cd98e81f,
+                0x1c530855e08ee852
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x176cfffbb4f7b743,
+                0x5992a56f80189fe1,
+                0x35b1b1d92f0c356a,
+                0x1dd964f4400f6303
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x97b4deac7a528f51,
+                0x88a48b45d7b0101f,
+                0x34b5430f5612485,
+                0x44bf89cf12852
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x31e5219097fbcfcc,
+                0x1f15be06d3c4c93c,
+                0x39448a14d631bd68,
+                0x144cb68157f91bc0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbeb02c0560f9d2ed,
+                0x16c4b649e8597f8e,
+                0x871c2d0aeea9fe85,
+                0x3b4f2ca6d71eb56
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x440910530cab5830,
+                0x268be77646a16da6,
+                0x4794ef2b2bdc50e7,
+                0x25bd2441a2f147c7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7f4823fb50e53ab0,
+                0xde48f92521462736,
+                0xb6c0a3ec3b8d9079,
+                0x20534e8a3432e82d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2ff4d12e833ebf9c,
+                0xd4c2d46c59eb3a07,
+                0xdf44d403ae8f8736,
+                0x16bacf2750f4ef00
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcd484c0b21155f7b,
+                0x7cdd4dbe71ad6a34,
+                0x1a8d3e66b3d3da67,
+                0x3f0597c503de656b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbd4d5b3342b2f567,
+                0x5a4458a4874754,
+                0xdf125c1b67a934e,
+                0xee668a5aa7d272
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa7334497de1cfa0,
+                0x6d5e43c8b33116e6,
+                0xd66d5648b988f8ae,
+                0x133aa19d17a0da61
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x749f89761358fe9f,
+                0x399514c6cb1a6459,
+                0x38b4f7cde2feb400,
+                0x374145b1fefe871c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbc8f52d8d82aadf1,
+                0x3ad5df8a853ae19c,
+                0xc38195650446be7b,
+                0x343b48378578757e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4846b5509a9c7b7c,
+                0xf2935a354560332b,
+                0xdeca5c8494066226,
+                0x3f30c83fd1dd8777
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeb160d1ed1d2370f,
+                0x288358da7c13db9d,
+                0x83601c60475adfad,
+                0x1d68967b448e13c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbc87ed7b87374eb,
+                0x4efca89df6ea8028,
+                0x48c59fc5e1be98e5,
+                0x317cb41328e39a1b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x31e2dfc40c1d3fe1,
+                0xe08f96a9c358ad3e,
+                0x4aeac816711cfa0d,
+                0x2ab2038095ccd35e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x29933a43c1520f95,
+                0xf376a08559a82825,
+                0xe932ad09820abc0b,
+                0x5c5e8cf621c7509
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9e9d4b21af40b484,
+                0x1cd319acc190f1cb,
+                0xe8fa9a1274ee5f40,
+                0x15d01f01d6c1e8fd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3f6f4d09a2ec5f5c,
+                0xc21c5b58bbc8ff27,
+                0xa1992935f664b88c,
+                0x19fdbf578b6944fa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5a023b686dad4abc,
+                0xe9bec14d90a90772,
+                0xd430c66494e9e98c,
+                0x2d92fada0178ca2d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd7df1c8a9b329eee,
+                0xa83b7d7c5abad7d4,
+                0xd73c13f07be3542,
+                0xb97430e6e23f98e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaff2eaf418b647b9,
+                0x6869d33391c3bfaf,
+                0x2a17b585bf7e216c,
+                0x37cbc41dc7b459ab
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3d515eba014d14e4,
+                0x53f84bfae1b80b2a,
+                0x71aa7e12b25d1591,
+                0x42efd0e2f66cf10
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4a59c953dc367b17,
+                0x7428a6f99026729c,
+                0x53de96ba915196c9,
+                0x147eefae67354050
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf3c0d550ebfdd748,
+                0xc3f7155e6b15ac09,
+                0xa9c02c674bdc5bea,
+                0x37b10164f720b526
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x726b1f7b69cfc9c6,
+                0x22c066dde1df462,
+                0x85460a006547b7fb,
+                0x3f1c5f0f12d802fe
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x98d8f0d3b6672a0c,
+                0x806ec304ad29665,
+                0x8c5eac1425c323bb,
+                0x2832b21ac12d3943
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3b513ae494a74f86,
+                0xa5e06877ff9a4000,
+                0x9d74b1f1be55b6ca,
+                0x2645549870047b53
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x36f9a23fbbee9a1,
+                0x38914ee014e9acd7,
+                0x42df2f684d780589,
+                0x2d60f046e6d996f2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcc669137c8b270c,
+                0x1b25504caefc4375,
+                0x927f8370d81b8c15,
+                0x1322b4e6a691c957
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xca24cfb3c385529,
+                0xc3a244e407b5efaa,
+                0xfaa60f7d3384c77e,
+                0x9b0ac57d4b21462
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8119f92a30d30479,
+                0xe1960f900585080f,
+                0xfab90512804c4e4,
+                0x32e24a460ea53344
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd82ac5b321d2262b,
+                0x3575cc5099c8454b,
+                0xa98b79d9cabe8610,
+                0x1abe2ec8ec5a3083
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfc1d31ff39047963,
+                0xeaa37e7c31b5be27,
+                0x2b992f0fa3aa53b2,
+                0xdd736e8864499c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5da81e5990bafaa8,
+                0x6b6eadd2caddc5e0,
+                0xfa7c78135498aeb1,
+                0x59e17ce1cdbacb7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x65290181edf70aae,
+                0xe01f417ef9f80c15,
+                0x4ab56af68b2b98e,
+                0x3ff10a5c9e8899b9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xba20cfe2978346a1,
+                0x807bfe6d076e707e,
+                0xb9ccad2fcf49be4f,
+                0x678cd3b47d446ce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xddd02aa5a8867e4c,
+                0x45acb1352b9d6349,
+                0x25008105d2fcf1b0,
+                0x1f71c0c084e1a0e2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb87902e7d0a96784,
+                0x531772fc35885249,
+                0xdbab835616ef4649,
+                0x1387a700ab5be4ba
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x228f45c91e97183c,
+                0x2f4afa34856a0ec0,
+                0xf967f3d8e76ac09c,
+                0x366c1a11346284b7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x74b9622f1033945d,
+                0xef630af497261f69,
+                0x877f0a05ab23e588,
+                0x112dc1f1d3cc0c09
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x59811156f580ff16,
+                0xd066e0b854cb80c1,
+                0xee18792c896864d5,
+                0x138052fca357228f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6f76d685113f2fb,
+                0x5f98f04b94bf53d7,
+                0xbb090b3fbb82266a,
+                0x1fd5ab2e508b939e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd74954cf120000ce,
+                0xc485be7bd9c0cde2,
+                0xd53dd06b396d4356,
+                0x27e059c8c267236c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfc6d9cb8d387e445,
+                0xc7888d01c0179f8d,
+                0x67665898b6b5cbfd,
+                0x450e33c08bce45
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf7ca6d7c31441914,
+                0xa0bd79bb8b00a858,
+                0x4cf0db4e6d72c709,
+                0x24a7d9419f756abb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7ae3596d9d0897ca,
+                0x1f4385bb6088a8bb,
+                0x3e7f1547c8a8f5a5,
+                0x209e88108a344a1b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x49b08d85807e72a9,
+                0x33df600291c1846c,
+                0xc3dffbb6cbca0093,
+                0x10031a09a4536f62
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3aa8e69d3eb71a9b,
+                0xe3ef55ec29da7f8,
+                0xd1935781408f2c7b,
+                0x77725dfc493753
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6867fe9abe22f055,
+                0x43d7c6f8786a4799,
+                0x372e11b73f0034fc,
+                0x356134f57e5089d9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa25619cbfcb4ea61,
+                0x5b3f637237ac9eaa,
+                0xdc141fea90d23cec,
+                0x236c7e7f74dd5748
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c5fc92a59075a5c,
+                0xa01a8f0718745cf5,
+                0x54eec31359223784,
+                0x1dc028903856e92a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaceb3b55caa1526c,
+                0xa6342781a6d846f,
+                0xd0323d6cdd8c2f6c,
+                0x94774b2f0b1ed13
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbfa5640a44d16286,
+                0x39968e7def84649b,
+                0x4d2145695271efa2,
+                0x17904f025b967003
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeab1a4449d70876c,
+                0x50428cecb5b7912e,
+                0x6b1f2cc71b872676,
+                0x231d7d8801227224
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x889a4f9dd7ac34a1,
+                0xc15f1511bfae9fd4,
+                0xb919081467fdab28,
+                0xe58e2f045d4a516
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9bc316972b717454,
+                0x9286e113e654c2d2,
+                0x635d26df5a72750d,
+                0x12960f930fb5de6c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x277307af4751fac7,
+                0xab49c22c2b0a5a7a,
+                0xd09426ecea6180ba,
+                0x1bab582d0c7dd67c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x540811501188e224,
+                0x5e77b2c16a0346a6,
+                0xeefbca84839f44ee,
+                0x8e3063f7e0bf1e1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8cf7d2414062ee2f,
+                0xbb07473a58485b36,
+                0xfd31cd0fbd1fc2c4,
+                0x2421bc79e7e151b1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xef4e0b3ba7dbdaac,
+                0x7846db96c28263f7,
+                0x59a3ed11b2773c16,
+                0x168cff7ae2b9a0e4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x860bebbd82e79e91,
+                0xd836652cd15c0303,
+                0x2c0924355745b9b7,
+                0xc160c6540a9a2f4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb8e69d6eda3323c5,
+                0x8a1b729f0a5153c4,
+                0x6b8ad9aafcfdc738,
+                0x51222404a7f635d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6ceefd550218d953,
+                0x98faade385a7b097,
+                0x2f84c946d77cacd6,
+                0xad762f2ebaf9312
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2bfb1e3e5f52a597,
+                0x8257bbc526aaa6e0,
+                0x3d88e22b4f1813d0,
+                0x4031c811e7641fa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6ba54f479df9757d,
+                0xf5eaecbf9eda63a1,
+                0xe1387b62a44fa5c6,
+                0x3074649834769423
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1d4820790766d2ed,
+                0x36aa2ad64ce7e536,
+                0x4d93a97f28bd2822,
+                0x34685ce65c23e97b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8e852ae7cd35e914,
+                0x93243a3e69a31f60,
+                0x26871fc3ac197f2b,
+                0x152333e4b7a5c6f9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc1a898480899555a,
+                0xf1ba490b7c668aea,
+                0x802fbd586fe5edac,
+                0x3b00a9ed6874fc47
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8b81dc94c2dab5a9,
+                0xea91df79bfb5e686,
+                0x7220c272d7abf7eb,
+                0x3f118fbfc89542e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4c518a6cb024734b,
+                0xaa5110303ad5f412,
+                0x8893fed1490e5986,
+                0x26047cf65a180da6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xce01d8e2a7b9cd3b,
+                0x307735f585014d47,
+                0x6f0543e4bf69f235,
+                0x7cc7b08121bb0d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x63303761da7307c1,
+                0x2f682149bcf6e272,
+                0x694e850dbc41f73d,
+                0x40c83d9d84bc7fc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x34733dcc3024b474,
+                0xbaebec13719654e7,
+                0x95276442b25645dc,
+                0x1c130d2eb8769256
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf5a184e2da458f41,
+                0xa8a861c171cce529,
+                0xfc4430c8354a1945,
+                0xdee562fbaa19768
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6fd609a6d3388a6f,
+                0x5306d5fd1820619e,
+                0x81cc8dc14ca71299,
+                0xccc62b6a4a2960f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6bd36462adcf6297,
+                0xf3699392213b23a7,
+                0x1dc436617368097,
+                0x2c4713615ee00815
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9b2b2168b53a4ee3,
+                0x29233067cfdbfb0d,
+                0xed3580cb400d49f7,
+                0x2d4cb7f9cef41a7a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf3c263aeb44efcd,
+                0x85289e27d8b14ab5,
+                0xc6ae4033a9242843,
+                0x31fde8d6ef416bbc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf144185cb8fc5819,
+                0x1b298c683588f93e,
+                0x6856b1fd4346689d,
+                0x1d6041c15cf4ac09
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe73d73ddb111ea6a,
+                0x803e8ff20bb61cdb,
+                0xb4f42c67ca019f5d,
+                0x1bea66e7da416cf3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x14424569f45542ce,
+                0x1cc8ddc268bbb797,
+                0xdb103542ed6f1897,
+                0x18ccd1af4e02dcf8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfb4b03998a3c98ae,
+                0xd45d0dfb8a7f4a6f,
+                0xbed433df61f02b4c,
+                0x18533ca06f15760b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5d2d77da8b61a02c,
+                0x953e4d5bc2df7b5c,
+                0xebc2375bae80e5c3,
+                0x2073da2cf974749e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5bc173b03a512f98,
+                0x642c3d84e3cfc010,
+                0x1f51b1f83247f057,
+                0x3023af6de5dc761e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x548f98289de46d9,
+                0x26d0dd24460fbe79,
+                0x3e6c2bfe4b0894e9,
+                0x12b856594617a2be
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9e7811926c3da3f5,
+                0xc024c1ae4333317e,
+                0xf78f4a2acadcec08,
+                0x1fed2af072bd896a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9b33632b497b3f15,
+                0xd3caec6c9bb49443,
+                0x155da02030513a2c,
+                0x8b8a3a0975a7efb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcda27f7a7813fb94,
+                0x74e30c204cad3af5,
+                0x16dbe24fa50273bd,
+                0x155ba24a7e629042
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x519f93e8e8985684,
+                0xa41cffc1a5e8c06a,
+                0x2b3e28d6ed1a9d79,
+                0x3c949c6c6754d18f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe3234651bd26adaf,
+                0x45b9985dd30184cd,
+                0xf249cf140d7edc63,
+                0x14744b35b64e69c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x49f77803527cf65e,
+                0x1d96dc12b2de5448,
+                0x7ce5cf9e9e51d7ed,
+                0x3079c63071dba1b4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x69486fe5075c532d,
+                0x2cea2f17196e54c7,
+                0x47a6946d137c9dc0,
+                0x1b935a1bc76124a5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf26d5ff28de03a24,
+                0xae934b80265313b0,
+                0x7c103c7d696b80a9,
+                0x3e518d82957f4ea7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8df4e0990fda8578,
+                0x8b2441db2046eb91,
+                0x304ce4aa46a1cbe,
+                0x12367d5b4a128eb5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7d0ba7cda20e2477,
+                0x25389c478bc7c5e9,
+                0xec0b188f066115e2,
+                0x1441ae1d60bb6580
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x623b3a5db6ae0ef5,
+                0xf21f700b89a1d93f,
+                0xd4ab16cf8fd07538,
+                0x33868cf1bb215a22
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa5c0615fad62455b,
+                0xc48933158498a1e3,
+                0xf01afa7345d3fb64,
+                0x1fb707a697c14ee0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5a069078bfc9d8d,
+                0x72eabb5c1d78c9de,
+                0x6041822638678e1a,
+                0x3d528c0689ecf9d5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd612dc288095103b,
+                0xeb2fb42d7354c322,
+                0x9151946077ae172a,
+                0x142eb1d48e019ff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8697b567a88d479d,
+                0x10dd641a7f4442bd,
+                0x57d80853ecac1483,
+                0x22143679d8518207
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x41fa9bbba8f43ab5,
+                0xef7040a9fdcea0a5,
+                0xc5500d424cad97dc,
+                0x3ae3454d065eda1a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa22ce77463ec9599,
+                0xa3887e3b197e61ba,
+                0x28cfd31aebb96048,
+                0x1fa3986ff429d26d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3cf6b5430a38dfbd,
+                0xddcbbb92f6f731d4,
+                0x54d6d40248f3d1c,
+                0x1a7377f82d8704b9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7bd0d76a991e2e95,
+                0x5c1aa646f258eade,
+                0xeb9f1944c0f70176,
+                0x165266b46f4534d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x775c4a2ed65c60cc,
+                0x88428c83d4fea62b,
+                0xe7494c373a4202e7,
+                0x27a238e6a072b852
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1fae4a6fd8c37451,
+                0x84005e041bb207f7,
+                0xfcaf71e8349498e2,
+                0x2fcf169ed2bf1b7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x26afb8fac7fb5659,
+                0x506e49b2fd2e18a4,
+                0xcb18229a3c4837a4,
+                0x109a9dddc89679a9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe011b88353936760,
+                0xe6433fb022a49666,
+                0xe041e0cf518e3173,
+                0x2e2332bdefced152
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8306f54fd87cff45,
+                0x138082160c30129d,
+                0xc49cdbd2d7e7b237,
+                0x5d64ed74ab10351
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x606ad5e4981a9e07,
+                0x86cf6659e1358e3c,
+                0x529a20d76a20fe3a,
+                0x3cbc9722f9d1281c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9c22ba7431a09b77,
+                0x9d4a83c307fd61fd,
+                0xf60f289f731b5e0c,
+                0x27acb953f0781d81
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xec49e7dde69ad29,
+                0x6be4a20fa07ab5ba,
+                0x2c41de80038d978d,
+                0x3be616dd64311f42
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x83945bba9d3e0073,
+                0x9f4b64364c063cef,
+                0x65867354dac03903,
+                0x10b92225e3db5fb9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2bc68a07721e02bf,
+                0x242a989cd47d9a32,
+                0x424dd331fd658cca,
+                0x31168fb5c81a48be
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x418525bb4d641ad8,
+                0x4ef1d36dad05f15,
+                0x99218dda6aaf9cb6,
+                0x1f0294ad1524a017
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe6cc13167610336c,
+                0xb60e02a8b421f038,
+                0x634560901ea6f5be,
+                0x3c0ee3214499792a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5c1f6029ccc70479,
+                0xf35108c78badd287,
+                0x634f619cf9a24aac,
+                0x11a631d211a1d4ac
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5fb7d9beb8821832,
+                0xad835e155f66d7a5,
+                0xcc0686f47406811a,
+                0x39ee10cc7c0f0507
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1e37beb0564fda38,
+                0xdeaa9e7bf588af6d,
+                0xbd8105b3a7534032,
+                0x335110bfefecd517
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x75043a46b3525c2e,
+                0xdc6adffc8b1f5bce,
+                0x6bece0b9fe58c717,
+                0x2f9013031876900c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa04244291f73cf3c,
+                0x8b70f95793b363d2,
+                0x76b9bc1592063ba9,
+                0x20729d104be96021
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc45e5cb69d0b456b,
+                0xe8590a227b6d4b46,
+                0x6b62f76d1991a11,
+                0x323f91e3cc224d11
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf0c5c406b056ed55,
+                0xa54782a6a84091a4,
+                0x58851e697af63190,
+                0x3356a113eda23e32
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d16dad180d40f3c,
+                0x98a5f7dfc18e3dde,
+                0x7481e8dff32cc9b1,
+                0x3c28577da9d828f1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x978e27d312fb9fec,
+                0x70f9cfe2014d142d,
+                0x8f79c8af71720073,
+                0x247063969173bcbd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb1d00f6f03c7c9ba,
+                0xbcd3867a03c089ca,
+                0x8b29ecc945655e2b,
+                0x1fb835f4ebc3391d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa6505922aeff4306,
+                0xd881e6987b1e983e,
+                0x3d94e8a55b16ef1e,
+                0x1e09f0ff97937e46
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9bb84e8a3f09379b,
+                0x44a86fac963a63ce,
+                0x66b9ba8850c45bfe,
+                0x1420e3d2cfa61c40
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3f7f9da8f04bbd63,
+                0xd440937b75fd1e85,
+                0x2e142b91b8314122,
+                0x100ab26b81c7df4e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x14c2bca4a85f6d97,
+                0xd4ff3c40d9700ecf,
+                0xd9fa9d8a41b462a3,
+                0x2132f5ec3cb98f5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2c8aef354002fcb3,
+                0x68c121522aaccf6e,
+                0x49b54205a1c8d3e,
+                0x164e20d379bb35e8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8f4b85fd66b52293,
+                0xe2ed7b5385122846,
+                0x6f05d43f51f88d98,
+                0x1a0bde17c578919f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5160b27d2b1e1014,
+                0x91504c7b81682140,
+                0xfda0bf38498a9e61,
+                0x2db7dc7b2132dd9d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x63a9a77067fde8dd,
+                0xaca03f0059511923,
+                0x59de9d00658cf968,
+                0x3d86787b7ea6ae4c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf7db3f6f0fa607be,
+                0xdb61b78812568a09,
+                0xfffa9c4cc0b4d547,
+                0x1f8e3f4686fb2f26
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x750cc0951656f9bb,
+                0xce5a98fb06249d2a,
+                0xf918737ed3c8f376,
+                0x3b24c1bc6ce38ecb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5dd36a78039a7fb1,
+                0x24cfcb544092d21d,
+                0xc46481fbc3bbe00e,
+                0x4ec6fc787faa8b3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2e96a805e4ba7b71,
+                0x79dc7942e1311b90,
+                0x477d00eb28685d3d,
+                0x3e993dc3d3521a06
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe730a2692845fca5,
+                0x7db545461d22f5aa,
+                0x48137c76af4afba3,
+                0x20eb71a8b088c0ff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf8eb957f5d12a2dc,
+                0x152bc296ee3ef6a6,
+                0xe7215750be4bfe8c,
+                0x3b9e42b09fbd2178
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe8c395c36acbc87e,
+                0x89a77f91b004e296,
+                0x756ff218ac3d6d00,
+                0x11cf622b86536cf7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5f6baa7bbeeaed12,
+                0xa4ac7e6af0c063a0,
+                0xffb6de850707c02d,
+                0x1425ed92f10421b8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5d399a27a93d1ced,
+                0xea67e7eef280ac38,
+                0x59210187b18d2cdb,
+                0x13f9af01edcb157c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6591d75981ba4ff0,
+                0xa647c7335b2b00ea,
+                0xcab20a0bba797b6d,
+                0x7feac5722279276
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc803bf4afa328fcb,
+                0x703349b7d3ba4ffe,
+                0x18e3a12f855071ba,
+                0x2adb1d1f531065fc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdb109890d7115c31,
+                0xfed297978e4443f,
+                0x3c94efa3c1bc4cde,
+                0x3e9611ecf28db51c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf561b48c289d020,
+                0x49f1e3336fa72acf,
+                0xc37d0eb3f274e8fe,
+                0x302456f8faf4c8c0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdd21df2971c4d6d8,
+                0xee0b0ed812c73030,
+                0x620feb6766a9d6d,
+                0x2aa67c323f2d56fa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x52458c9c5d3bc3f3,
+                0x9245fb227ff53c6,
+                0x3b73adfe8d6bbb39,
+                0x29b96320a36d73c5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8fd13731a2e1eea2,
+                0x1cba37409dd3c3e0,
+                0xedb86ef84e69e790,
+                0x2af0a2d4ecc7b0b6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa085bdfe691d0308,
+                0x4cbbb1d1086d4a2b,
+                0x3c87766b747bd87b,
+                0x14a3387850f8d1dc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x41674199a6179c11,
+                0xf101a691b2243192,
+                0x2d31f2179a55d64a,
+                0x14a45701b57c961c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x15f832aaf2f97e97,
+                0x592d73e11073c295,
+                0x1ff0928a52115646,
+                0x2029de90ebb635ed
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4b8bd380225c29b7,
+                0x21bf7a7269dfd999,
+                0x667b3bc6241738a4,
+                0x34a512d71c64f3bc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x42e40dd3d9468b1b,
+                0x4a728f4accede2e9,
+                0xe35cf353f77cc56d,
+                0x25b2d43966621e86
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6971517f11aa11bb,
+                0x1850b7024c44637d,
+                0x75527ecc430b7013,
+                0x32ebc9bce08e247b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf7b7d623c4f6688c,
+                0x6881fb3d9b634006,
+                0xd7aad980f4f52ea0,
+                0x201de78b150a7278
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4983da631599eb5a,
+                0xdd5325d1b6ee2450,
+                0x977a38d3f33d292d,
+                0xfaee6d51b397690
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4cfddb3382ba1f5d,
+                0x50fb26f2e2303d23,
+                0x21a5e488effbe5f7,
+                0x3cc145ab1d41c650
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x12e0fb03f3f69913,
+                0x30f8a7ff252e8219,
+                0xc2f2f04e8a9d7827,
+                0xd662514c41f649
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf098d41d38388132,
+                0x3ec3c12a998aebe8,
+                0x8ac5b21119af7b3e,
+                0x1df8ad93fde0916f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc065b3906dd49d37,
+                0x470cc995f2343a2f,
+                0xf783b9e9079468be,
+                0x3283f9c04ba86b15
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf82a5be9013e010e,
+                0xa176776dc5cb58d8,
+                0xaa6fcd7692b6bc76,
+                0x3ca12c450b568c4b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1d3f47deb28dc74a,
+                0x61d409e8ddf12049,
+                0x3039d01d7e1d52de,
+                0x3177346c239b5595
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x34ef8459e12654c5,
+                0x34ee24a66d3e86b,
+                0xf8aeff0fa8870ea6,
+                0x183d3d08468855d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4c33e132434df34b,
+                0xbc27e3da27ae9931,
+                0xc21397d8b0e9b759,
+                0x884cb7e8805595f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa6df597027dda22a,
+                0xa2442b96274c45aa,
+                0x5c55c07c4bf08b74,
+                0x6b67abd4bffe9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x64bb252385698afd,
+                0x984e00f76810585f,
+                0xd6fb79ce125aa4b1,
+                0xf215a67d86f5a9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x845e9268598e9bca,
+                0xf8f80230e48c44be,
+                0xe3f797e7c3ac605b,
+                0x2bb47ca83b70a1cc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7e124f0d3b20382d,
+                0x7418ddc58b7e8124,
+                0x6649aeab81049b99,
+                0x1aa4d9607e9c94d8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8f08da3530df19a8,
+                0x9aff901314a50b7a,
+                0xc9eca3d296b1eca5,
+                0x2b8d7ff69c925776
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x61910d77a76cf359,
+                0xd9a45db2f2b61a6c,
+                0x57032ee780d2f11d,
+                0xe3222ea9f372c7e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf375d9e1d20b6b18,
+                0x902619db927e750a,
+                0x85c29696be89d1bd,
+                0xa5efb95d05c5e57
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5f6a5c872cafff3a,
+                0x5c85e53c6291e1cf,
+                0x28a46168b05b8196,
+                0x1969e9043cde7df4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf4d9f6d1fb2ec9a2,
+                0x9c87d7ee070179ab,
+                0x57ff6935a6128958,
+                0x1283dc490124e235
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x26854ea2c6f6319,
+                0x20f16b09eb8e8683,
+                0x26c135d74f118bb4,
+                0x43dfe5fb1fcc3c1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd2bbdeeb9964e5e2,
+                0xd0c2d0fc409e7c59,
+                0x9322037c96e26aa2,
+                0xe8c4f73c7fc8d5e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6b065e4d0f4e7748,
+                0x8b3e2155b9b3f8d2,
+                0x9c868f9a19384ea7,
+                0x38690a9d8729d3c5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6e481885a8cec698,
+                0x320d96447afbfd14,
+                0xa2feac6bbe715d77,
+                0x1864e0425bb31d3d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc272dabdbc806574,
+                0x586c901d2aede5b3,
+                0xc804a2256fdb8e35,
+                0x2d00f744b727d6ec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc83abdc12aa918af,
+                0x17b18327b5204514,
+                0x850434b578961442,
+                0x1ebad6b2aa689ce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x14171e2557dcc6ef,
+                0xec30eab1f4a80a2d,
+                0xe3e0688bf5a587d,
+                0x3d124422e6f24fce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7fee46fe299060de,
+                0x2a86ec63f22f4218,
+                0x8171cf3e0fafe8d,
+                0xebdbb913281356a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7268793fa14121e9,
+                0x3458ac2c04345acb,
+                0xabbf8fb8684eaa10,
+                0x2f7f4e700c1d678f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x962ee0984c9a9407,
+                0x239cef07d82ab558,
+                0x1b4f0e112fd91d6d,
+                0x1f85c6f1a822f40b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa7156250896ea26e,
+                0x1ca9c6780683db3e,
+                0x14c0dd7c56cdecd2,
+                0x797340570d09fc1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x13d1b5c74be01018,
+                0xb63d702a14f32820,
+                0x40e3dc3a7e2ca41e,
+                0x2510ea528dce2c00
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf56baaf5aa8bc4ad,
+                0x21a8b79dbcf70e43,
+                0xed90d2e70c425b2,
+                0x1eb3d8164a390361
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfef3fea039ab8069,
+                0xc4969c976bebf5a8,
+                0xe7dd00b6ff73e535,
+                0x14d8934b6574ce2a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5d087d7f9c6cb347,
+                0x7a99f13d434fe9d5,
+                0x16b0289e460830f0,
+                0x2fb1751fc93d70d9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2fae828bee36ea1f,
+                0x24a346bd45af3097,
+                0x2f428d868edd1f2e,
+                0x12cb59b77285d56
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8716f06f2065f139,
+                0x56882e50947bbe0a,
+                0xf14a552a00f4886d,
+                0x54a6a55306ff6a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1676dbb4f377b288,
+                0x510f14473e0549ba,
+                0x1438430244beded7,
+                0x386038732119e9b5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x214beb37ef9ea456,
+                0xddf57176a04c9c6f,
+                0x78bae5b7f52ead0a,
+                0x216a3189c6dc792e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2b9919945eaa41c1,
+                0x63fe75486f1f9a20,
+                0xaf9e58f620ab291c,
+                0x2bea0a97773144d6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4102c05adbc06f34,
+                0xde0fa12bf03804b6,
+                0x2e0d0a38bb19500c,
+                0x2a16fa4b9426e490
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x495699d77641573d,
+                0x7199cabe7d381b5d,
+                0xae02ef0ff8160770,
+                0x242411b8b69ca8ea
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x80480b8b2c3199b1,
+                0x16ae11f7d3a9b400,
+                0x25f4aae81e6cda7b,
+                0x2822514db8fbf44a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4a23447924e50e0,
+                0x274312266f00fd40,
+                0xbade9a9bf6b2aa81,
+                0x162bbf960f957bc2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2c452c4053c3aa75,
+                0x2cb8d3f0f7a45362,
+                0xaa43c50893d94c3e,
+                0x34c0ae28434b20d4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc3f6328af73a21d1,
+                0x50a8cc9f50fcaa50,
+                0xfd66f8d9ff9858f5,
+                0x1cfdc709d4d5f20d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1f78036a64a89103,
+                0xe11ceb5704103cec,
+                0xdd293cb4871bd660,
+                0x7b1cc7b3a12951e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x836884ebdb105c73,
+                0x156834e7c33fd3e2,
+                0x4b51b484845db25e,
+                0xfb2fe8727be739f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9154ce2e89784f1a,
+                0xf1519c88787e7b8f,
+                0xe7a3e1f98acbb15b,
+                0xfb57304c1318d37
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x22f0a547525a8020,
+                0x92e04a188c736ee5,
+                0xba5e199de8d1068e,
+                0x1c565297ba018d0b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc5c5b8dcc577d6b1,
+                0xdc51968b8196e4c2,
+                0xa291eede595916a5,
+                0x357ccd1991013014
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4fda41d104d1bac,
+                0x90d4c3d2e82fefaf,
+                0xae768c56f5808022,
+                0x19b373c4d73e47c1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9f9fe899b64f14e0,
+                0x8c7b9a954b79adc1,
+                0x9c2cc2e49f34efdb,
+                0x1518cf8d07918129
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xccb1f48412506d23,
+                0x17193198f4afeb57,
+                0x5e5448a91c4593dd,
+                0x1380d1835273f448
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8f5e1ee4627b3561,
+                0x4e4cfdfcfb90c9da,
+                0xa7b15e23fb18746c,
+                0x9a0f46d073ccd53
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x41ad94a71b007d16,
+                0x80026431ca255aff,
+                0xf4e6bd75596a1199,
+                0x2943f1409d3c674c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x56608ecafc11e65a,
+                0x5d97453f609e2b62,
+                0x585e9050efb8d85a,
+                0x1f6145e981afbf70
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5e5fba47958e1742,
+                0x6e96b1dc1cfadfe3,
+                0x67671bf4c1fa6f78,
+                0x12bfb165ded91947
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf5f0edf1d978f522,
+                0xb8d07a0dee9911dc,
+                0x716998fe0f995225,
+                0x14e2cffda3aadf2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9f6219fd1b5357ec,
+                0x43d131264b8563a6,
+                0x8897284d63566e6e,
+                0x37dd739a7e183f40
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5e9773a1d48c2f4b,
+                0x42a7a866f352ba56,
+                0x85654cbd0a56cfa,
+                0x29be21f958fb7e14
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8f31b2685143f6f8,
+                0x4af55592d1bb561e,
+                0xa20b7175f8cf953d,
+                0x2fdd3478b5573feb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb2157fa3d75c3a0,
+                0x90da5e5d4a806d04,
+                0x1604fb1a0470a8da,
+                0x1109c85bb662dd41
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4fb3f6f380d8b375,
+                0xc9cb5efd59a0455c,
+                0xe8b81747d28e3c92,
+                0x1f5bf170fbaf3c52
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8239715c2e4928fa,
+                0x2438f43b092eaa7a,
+                0x1149e39c1004de14,
+                0x61d92e4e4c2b6b5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd6bfa7f93f8ef6d,
+                0xd422c5df87e6d832,
+                0x132d02c2ef2ec6f0,
+                0x381ca14b7243a965
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xebe38f69f516ee6c,
+                0x4e678ef9dfc9927f,
+                0x1ba4a244d85011df,
+                0x255bc86b904b7b2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf8244b1628765293,
+                0x1241dfab3526af10,
+                0xb1235fafd6e12cd6,
+                0x3f008e925ba0be7c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x45061a42693c56c9,
+                0xba2325deb8cfbab3,
+                0x8e021844bc9e3cef,
+                0x9c7a0dcbd7ae9ec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbce9bf7d21fd60e6,
+                0xc58c292642456fb,
+                0x94c61cc1209f7e79,
+                0xfa6d57eb7222436
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf3dc47377a6b8d0f,
+                0xc42d9ed055615fd3,
+                0x831d5123996b66e3,
+                0x2e87a77a6d51f056
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x794ef70970ec6888,
+                0x3d7f6f72b9ec4b5e,
+                0xe34d4554375616dc,
+                0x3f1877b6402826af
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x177a6e7fbb05978d,
+                0xdd869c556d3e6910,
+                0xa0f91f8e8e3043e3,
+                0x399fb50754cc3e4c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc42991497b9e48e,
+                0xecdbd2c20a2f82e1,
+                0x237dc0d8a7db7b53,
+                0x14ba3f0d3fa9c277
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf88279bf55bc155c,
+                0x486b88fc6c21999e,
+                0xee452c7b1d9e2a9a,
+                0x2376d552c03c275
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2adbed41a27f65ee,
+                0x4e0b34f4de2f147,
+                0x32723caea2b6e002,
+                0x2ed16758f4635d61
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5ecfce4109fa8a99,
+                0x43ff7737cd678e5a,
+                0x8b633222a3212119,
+                0xbcd3350e96cbd8e
+            ])
+        ),
+    ];
+
+    // The MDS matrix constants
+    const MDS_CST: &'static [TweedleFq] = &[
+        // Constants in Montgomery representation
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdc7c48eb64f30fff,
+                0x2ddc6a4ac77ab03a,
+                0x1e3122cf1428d66,
+                0x1e73485ace874b94
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x25b5d27a9a79b8b9,
+                0x3c789b68dd45a063,
+                0xfa5b787c3025697,
+                0x14fe99ce46a3f6e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x217fadbac835fe78,
+                0xefbc1de66928ea22,
+                0x3c7f1e2f79924361,
+                0x3320d59ca5792661
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4fa3b004229cd50,
+                0x859ee83e2fa52efa,
+                0xc339add371812851,
+                0x2851e9287d33d405
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc04b6fc726c5b9fc,
+                0x6af070ee80737a9a,
+                0xf57c377e8e41610a,
+                0xceb8b615f640e0e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4baa9bc4a9968891,
+                0x3f15f81d669414d2,
+                0x3680d0635052a5b8,
+                0x31c2ee8ca31300a4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5d85f570dcd49200,
+                0xa41fc699b18f31b2,
+                0x705a89a0b892289b,
+                0xd1af770d0f81147
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe01efdf23bc96abb,
+                0xa6af6a6d2e40eb2d,
+                0x4a79783894a0d1b2,
+                0x232b80ca9ade70fe
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x52e619834dd1e2c8,
+                0xd65cf873257152c5,
+                0xb70198408fcd4c1e,
+                0x2e070e18b4f11343
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2aaa05d08eafa9b8,
+                0x97dff2d77f0947f8,
+                0x6b3ae913a41bd0ff,
+                0x505e05b38cb0790
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x95de171e4884030d,
+                0x39863498086c3b95,
+                0x1bffcd254e6537,
+                0x15e7fe4c78f0e73d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x84ecdb58b2a97037,
+                0x733f320128bb8c57,
+                0x9378efaf7e2ba1f8,
+                0x247260de5f11e0aa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa3f3ecbd3704784e,
+                0xeeb0bf022bcd227b,
+                0x6362988a3c3c7d3f,
+                0x1eac6f02d5a4287b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x42a43d88dbdf1a32,
+                0x5101359f0a0a8b6d,
+                0xc192d4532a526801,
+                0x37fda5d6430e2250
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8ba8a217f4b14b96,
+                0x1781b596839d36ee,
+                0x1dfb09b7803bf008,
+                0x146ecf20ac3fb4bf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9f27ea8d0e5b915b,
+                0xae6b05f25f9eb1cf,
+                0x9f914dd7427adb6a,
+                0x2e3ee1cef6c5c58
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x851385721a3a37ae,
+                0x2499ba48da29ecea,
+                0x3c8b592ac34cd239,
+                0x2cadbfad0657b869
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa6a7ade0bab44b6d,
+                0x8c3a5fd5955e4a81,
+                0x2ec24a888d94d0ec,
+                0x1dede7ab78ce5375
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7349af0d9883dfe4,
+                0x5005288f5d1829ab,
+                0xaa21101899a3b1d3,
+                0x38e23815988cafc1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3ca7be6abb1eafa9,
+                0x85f8462fbeb988ec,
+                0x2b6a1f59a0214e93,
+                0x37d8c81088dc57f1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x53f43f3962334b36,
+                0x4db7b8cfa795c83f,
+                0xcbc57fd0fe181c8,
+                0x2201dfdf66e7fed1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4dc907cd699174e7,
+                0xf734e03e2a7e3aab,
+                0xcebcbb9fe313f978,
+                0x3fcb09c92b7d7eb3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c13efd50a0c0863,
+                0x90ab75a81974e221,
+                0x7e9f58110ffffc57,
+                0x1caa4ba698ad0935
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x84a2d2e8da9cf9f,
+                0xd9b964089df7f0e3,
+                0xa34118e1222c7e42,
+                0x3e4546279c33d5a5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x40b8e468e8147100,
+                0xf30c6bf11bd9fb07,
+                0x48bc55978b2c29b6,
+                0x3b44b4e98611a82a
+            ])
+        ),
+    ];
+}
+
+pub type TweedleFqQuinticSboxRate4 =
+    PoseidonQuinticSBox<TweedleFq, TweedleFqPoseidonParametersRate4>;
+pub type TweedleFqPoseidonHashRate4 =
+    PoseidonHash<TweedleFq, TweedleFqPoseidonParametersRate4, TweedleFqQuinticSboxRate4>;
+pub type TweedleFqBatchPoseidonHashRate4 =
+    PoseidonBatchHash<TweedleFq, TweedleFqPoseidonParametersRate4, TweedleFqQuinticSboxRate4>;
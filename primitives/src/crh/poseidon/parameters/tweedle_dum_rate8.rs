@@ -0,0 +1,6099 @@
+use crate::crh::{
+    batched_crh::PoseidonBatchHash, FieldBasedHashParameters, PoseidonHash, PoseidonParameters,
+    PoseidonQuinticSBox,
+};
+use algebra::fields::tweedle::Fq as TweedleFq;
+
+use algebra::biginteger::BigInteger256 as BigInteger;
+use algebra::field_new;
+
+#[derive(Clone)]
+/// x^5-POSEIDON-128 parameters of rate 8 for scalar field of the Tweedle Dum (= the Tweedle Dee Fq).
+///
+/// The number of rounds are computed by ./scripts/calc_round_numbers.py, round constants and matrix
+/// are generated using the script ./scripts/generate_parameters_grain.
+pub struct TweedleFqPoseidonParametersRate8;
+
+impl FieldBasedHashParameters for TweedleFqPoseidonParametersRate8 {
+    type Fr = TweedleFq;
+    const R: usize = 8; // The rate of the hash function
+}
+
+impl PoseidonParameters for TweedleFqPoseidonParametersRate8 {
+    const T: usize = 9; // Size of the internal state (in field elements)
+    const R_F: i32 = 4; // Half number of full rounds (the R_f in the paper)
+    const R_P: i32 = 57; // Number of partial rounds.
+
+    // The zero element of the field
+    const ZERO: TweedleFq = field_new!(TweedleFq, BigInteger([0x0, 0x0, 0x0, 0x0]));
+
+    // State vector after permutation of zero state vector (Montgomery rep.)
+    const AFTER_ZERO_PERM: &'static [TweedleFq] = &[
+        TweedleFq::new(BigInteger([
+            0x34c7d85d355fb743,
+            0x23f3a8c97c48aacc,
+            0x8771027b612db3c1,
+            0x1bfa46331d5e9116,
+        ])),
+        TweedleFq::new(BigInteger([
+            0xad3e7d9132361cd0,
+            0x4c53dc5a9d3f3420,
+            0x566430c097f28a7c,
+            0x2478792e3dfe6c63,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x570316ab19c8912,
+            0x601ec0978823cc3d,
+            0x4a48399b1cb5afc1,
+            0x23ffeadec4fe1ac,
+        ])),
+        TweedleFq::new(BigInteger([
+            0xccedd2a2c9c5c5e0,
+            0x2487a9f67aa74311,
+            0xf120255e42ecd80f,
+            0x7f9a7e8d3fc1fc5,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x2eaec6e1575ffa21,
+            0xfcb732fa7cd2ee1e,
+            0x892732256277caa6,
+            0x2e1f5344cb7023dc,
+        ])),
+        TweedleFq::new(BigInteger([
+            0xc1cf3e3d3fc537c4,
+            0x8af0da25dc244a6c,
+            0x4d501d362ab93961,
+            0x3ab152da1699bcfb,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x3682f23aec6d0dff,
+            0xae7302b9df96225f,
+            0xd85bf0c06d625481,
+            0xae0ea82b7e4b4f9,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x88154cdf49918c12,
+            0x3aabac3bcc466378,
+            0x35ebcc7fe863c24f,
+            0x1bf11be074ddc0ab,
+        ])),
+        TweedleFq::new(BigInteger([
+            0x83fec2a59d298f7,
+            0x3e0f7db7bab8f81b,
+            0xd95a1ee007f76a9b,
+            0x15dc3e9e9c2e0777,
+        ])),
+    ];
+
+    // Array of round constants
+    const ROUND_CST: &'static [TweedleFq] = &[
+        // Constants converted to Montgomery representation.
+        // For rounds 4 + 57 + 4 = 65
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa43612a32325738c,
+                0x985f664bbafa2a53,
+                0xe4f9ae819771a17f,
+                0x20a9d9b8c70390ed
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9452844be493aa7b,
+                0xd5c68b94f6a78bcb,
+                0x89b3d8ed9d1963d3,
+                0x1c79b74b7358f47c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3db91821b13de07,
+                0xfedad04fcaa0bda0,
+                0x1d9f87739e85f0ed,
+                0x508b0ef8a1ff06
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeb1b691a85dbb398,
+                0xd35da43dca551908,
+                0x785eddd02bdc01d5,
+                0x30cbf1b7d63c4c0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x60a33ff8b8b08048,
+                0xefd7963d548dd9b2,
+                0x85f5dff335c6da77,
+                0xe7adec00ea8d64f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8d50de2676e47db,
+                0xf714e193613bf246,
+                0x79871a48910e4ce2,
+                0x3872be21fc13b8b3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1e47c62543aa0e0a,
+                0xa212a4f4a787f9e4,
+                0x1f507de0196ed671,
+                0x1134e15ba49270d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x632000ac23b6f892,
+                0x6a707ecc35299eb0,
+                0x25265d335e9029,
+                0xdd8bbc3b62eb33e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7283d4cedee2caa1,
+                0x3827041c92b41876,
+                0xaabbf357c01353d6,
+                0x13e9ea3cf6ac32ce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6ca8b6c76f2e3108,
+                0x7d2488aa5479e2d4,
+                0xc467e3b16e2350fd,
+                0x291db166d2e8a95b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc8fbe0a35bedb727,
+                0xb4c2197a6ba896a0,
+                0xaf235143b280fa6,
+                0x3a232e23e9b4f976
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x88920dca20fa24c9,
+                0xe5a19edafe6cfed9,
+                0x90d0029312b011e6,
+                0xc953616e70161f0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6f2f1383c1f92045,
+                0x310c49096eadf551,
+                0xcfe9094ddaa616b0,
+                0x2d8bb1f4b7c2310c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa649d8c8a05f7e95,
+                0x760aaf8b1ded6237,
+                0xf488597ecdd00a73,
+                0x1f372414361b5c72
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xff702f9052e0fe54,
+                0xfb665c1a2a6a0367,
+                0xa5882326a54621d4,
+                0x27d2aea0e02e6521
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf110805b38ecc832,
+                0x3025647cbbca8b2d,
+                0xd58bae218c200a76,
+                0x1b59eb10f1c8d8a2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3c2b5fbbbeed5032,
+                0xa06d42d4094e65ba,
+                0x1bba5e40e9150a36,
+                0x8f6c240dbc64370
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf4b3850acf9d28f9,
+                0xde6b7e770e352b2f,
+                0xcb646e5f9aa9393e,
+                0x2afc08cd24cd5d65
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb10c716a37bfb4c2,
+                0x3082bdd13db8fa26,
+                0xfbd8a77b23dd94ad,
+                0x24b5fc1ccc62eb79
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1195898b9bb287ea,
+                0xe573baf59e38a61a,
+                0x1d2b92d539cc6dca,
+                0x2aea94c2fd4386bf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x192c10757fdda162,
+                0x8a8a4016cd875f3a,
+                0x6af03aeae3e9501,
+                0xa998e4605fe6a78
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x70e7202e74f37b67,
+                0x23499f9c4e5b383b,
+                0xa5e2f86297a16f35,
+                0x2a98f01208adda9e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x52d0e353f849a7cd,
+                0x4e17fa13287d5517,
+                0x3b9b79f0016105b9,
+                0x192370c08363f2bb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c6a99e71508a69e,
+                0x1c98631a3e31b4be,
+                0x77744ea446c94ffe,
+                0x53e045e8d85a128
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbd09f9e86cc78cb2,
+                0xf7aa8210631dbc23,
+                0x2394f92d3bddcd5d,
+                0x326823231be0b238
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3ea86fdd640a1ac9,
+                0x5ed24371fb4fa459,
+                0xfd8f94960a036091,
+                0x386482517cceb329
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf584555515865a00,
+                0x90f5c9acb05f5987,
+                0xf1b7732cdd208b96,
+                0x123cb89749b5ee2a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc4198dfa21b41dcb,
+                0x41c0610751cbab2c,
+                0x491c22dfcf766802,
+                0x2e548af9256e09a2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x96e87efb4cf7725f,
+                0xf56a78804de35e9e,
+                0xc952ea164735b9aa,
+                0x286dc7579c262e4e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xabd561d29648db4f,
+                0x4c052e19cba8c0c8,
+                0x1af59a384fae6c2a,
+                0x1f8d22ee5e817836
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x20090fafa2207987,
+                0x6817e2ab4848af03,
+                0xc1d9bcf55145eda8,
+                0x3471bb1d4816c5f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9ce9bc70429453b2,
+                0x124fbfa64fdb7494,
+                0x9951d7fd91fa2862,
+                0x22c832591fcfe91d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3271e6973aa685a4,
+                0x363fa12825e7b9c2,
+                0x82b98753191e1696,
+                0xac1dd20cdcf71a5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3ff4b2f8b62504ae,
+                0xdc3ccb7ca5f99b40,
+                0x1374a3da526a1c7f,
+                0x932032dbcaeb24b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9cb1bcca91b27d58,
+                0x191577ddbfaa9d49,
+                0x72d9fd29b66eeaad,
+                0x1e2f203a544ea128
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc2ee412bc8877575,
+                0x326ada2b49ec75d0,
+                0xe13c97c6dae30438,
+                0x26e29e140c50980e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe5195466c75fc41c,
+                0x1ad1107d2ffb82a,
+                0xdde6de22b2d138fe,
+                0x3e79e8359b9e87f0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2e0f22e1ab7714a1,
+                0x6b774379bf780cea,
+                0x37ffbe19541dda0d,
+                0xc6e121705f5fbd7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1a60806e866ed9db,
+                0xe9f7e4dbda722bb9,
+                0x65172a0a6f94241e,
+                0x1bf23dc7874600ad
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa513bdf9af3eda6a,
+                0xca1ef14550e764da,
+                0x647c9182239362d8,
+                0x227c1ad1327421ff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x40ce2a082b44ff71,
+                0x5812d2d699054460,
+                0x41d5fb500e9ad6d4,
+                0x144f0247b914014c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2b0db550b6d47773,
+                0x24d28bcbd9cbd111,
+                0x8be30ffdaf9095ef,
+                0x26e4ef6954c8575b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x27126ea74ca0b1ae,
+                0x21421a4e6cf47d24,
+                0x4f59edb3ed098470,
+                0x28e5c60af3c37678
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcca04cce2dc6dfa4,
+                0x4026788796898553,
+                0x44e291298ad2a16b,
+                0x1ce78daf452905f2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x353d13d54c1c489,
+                0x5924f18692b2fb34,
+                0xd4444f7432e4d518,
+                0xfbb5cd6e3144e33
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7af7f372a1d6a6b6,
+                0x30a0a455c54734be,
+                0x2809962cc4f3323f,
+                0x3f14fea82617aa78
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x833595b08ab88857,
+                0x65ab59dfc3bdca51,
+                0x36117172076ea696,
+                0x3ad7d3fe74bff0e7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcc2918a249c9bdc8,
+                0x38fc35e37dbbf45d,
+                0xde6df12b35b164ac,
+                0x4ff0e18da5cd16f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdc3cebcea0c2c7e5,
+                0x6783d339304668ee,
+                0x93b42670297ef9e9,
+                0x316a9c258c3f003
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x81c9ffa2ff1c4b4b,
+                0xb8da3bcd4cd34a9f,
+                0x8a21567b4420ea74,
+                0x74caf62253cb422
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf83c04e5cb7f2089,
+                0x33adb5683e8ed344,
+                0xf880bbe3a1024cdf,
+                0x1eb0fe72cd6eb2e5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe2d24ee18075482b,
+                0x35cf3b4058f67d12,
+                0x11de9fbd24a22783,
+                0x273131291521e7cc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb1d8d2185e45c7d2,
+                0xe4d8482f8bed61aa,
+                0x2d91b573530ac828,
+                0x34ffaf0c7a60ddb0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9344dfb11290949b,
+                0xaeedad0df9148e98,
+                0x38cd495d153e70d5,
+                0x36003ba0d8226515
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa4adcbc6c2c0f00e,
+                0x6b29c0721a97159c,
+                0x8c94021665ea31b5,
+                0x3ba9f5dd786a7f16
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x33c7633847023853,
+                0x51a1cdee86956628,
+                0x94990879c2f91096,
+                0x30bbf6a2a4f48b44
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x364e547bf4a61597,
+                0x8f565dc4ffd45bce,
+                0xea93939820fdbcbc,
+                0x1a8a78b33103b14d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x152187a70a3b4f05,
+                0xf4bd03f72bdfc667,
+                0x850d1158d0c7001c,
+                0x3bab3179930d0043
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6aafb30b93ac0555,
+                0x6aef47d33eef63a4,
+                0xc7957f4ca3461073,
+                0xa5a80ed8700b58c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x30084392365a1055,
+                0xc0dac28ebdb6e8b5,
+                0x327834658a2aa2fe,
+                0x223b17e0b548477a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x64fca4bf4ae58259,
+                0x18d00de444b90b14,
+                0xc15b2f67efed42f1,
+                0x2e6f0a9883253658
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xccccac830656d45d,
+                0xe784aa398a31d3aa,
+                0x1f22910ce1389015,
+                0xcf619b8e310d668
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd0f627aa7a471248,
+                0x6dfaecb4a4979e62,
+                0x7efc3452f8bd568,
+                0x9fc099f3fe37005
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcce6aa65aebc42ae,
+                0xdc8512c258b678d4,
+                0xb5584343b69f5fc5,
+                0xc6e19836a3a9bdf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6d7932efb78678a2,
+                0xf743f63a5c2663aa,
+                0xa16f84d76578da3b,
+                0x15c5ba39ca583d7f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2fbb8c36898ceaf,
+                0xb090bf35c4838fc7,
+                0xde58b6f1c5f29a58,
+                0x3416fb7abcdba35
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf11e5e374d671b9f,
+                0x53d7815b7f9c0282,
+                0x4954b3fa3a482ac0,
+                0x1c4d1b90e952036
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x54fd2e8ab11da0d7,
+                0x26aef7803aa340a0,
+                0xe16843a6c2dd3285,
+                0x1ead9aac7e1307f2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xad805e7d57336539,
+                0xcb7afdbbcf38beb9,
+                0xb9489a6e0adba35e,
+                0x2c02ccffcc372832
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x18474ed83ee7ae06,
+                0x11931bb52b5b7814,
+                0x67ff943569149b93,
+                0x2be2d3d7d69fbbb7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7570fd0a935b7b57,
+                0x3a8b895415af4b27,
+                0x55003a05f5b7e267,
+                0x730231b19a1d357
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8dd44e999e9b9712,
+                0x9b4f6a448d41a6d3,
+                0x362f34bb71b0258f,
+                0x1dabb959d95c0330
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xedc7e0c32f258645,
+                0xb26e8cff1ab155b8,
+                0x8fb66151ad6e4639,
+                0x31ffb6ef7e52d615
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x46d7085507ac6aa7,
+                0x58854b2522922e81,
+                0x2f744e7027c4bf8e,
+                0x84d202b331f8454
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7964b1d808fbea58,
+                0x43bae5d0c51fe162,
+                0x56adeca85337202,
+                0x32bfe2abf8a27b3a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9a5025be04a6be9e,
+                0xf64d9332a60fe35b,
+                0x250644e7a34b8f8d,
+                0x1a7b6c63c1a5714c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb9e7be4a207fa1fd,
+                0x8fad5c3fb5f0de77,
+                0x81f239a4b8bffae9,
+                0x1cd3d65f2efffc1f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf08c103f73220b7,
+                0x530e768096af7037,
+                0x40d6b67419b94922,
+                0xa7dff8c4e611e50
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x87020f0a216bcc3,
+                0xb40f686aa7e7b4e5,
+                0x603345d7793b89c8,
+                0x1e020d84b5da2b4f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9004dd893ec8e73f,
+                0x5805e1fb6a069757,
+                0x821ecfc8e0287a5,
+                0x28fdfed468d6a133
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5929bae52b92772e,
+                0xaac2ef5048b96e15,
+                0x5ad6f194c80f2bd4,
+                0x8dda01f1cb3c9c6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x344f2eb36edbd4f1,
+                0xbf883a7682f3a0e4,
+                0xf86fa2f5a9473136,
+                0x29bfa91e13b3f4b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7fd301192a67f785,
+                0x23d0b5f542f61256,
+                0x2caac66fdf9b1c0c,
+                0x265c91305478ef68
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x56338915a210541f,
+                0xc5e78069dc333ab1,
+                0xdf8e65cc7fc02fa3,
+                0x17ed46536aea87e6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x568a465cbc7736a7,
+                0x55e40bb291ed2bcd,
+                0x7ae66ad8f96d93c,
+                0x375dc5ced90b4d7b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x28c3124d52669426,
+                0xbac703f3e7918801,
+                0x65dfc01f1670c56c,
+                0xb2c3ae96905852b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe9cefae4063f8d24,
+                0xa9fa9906f4bbe6e6,
+                0xf0473168d9553daa,
+                0x1ad1b443b8d8335e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5a87790ca540459f,
+                0x73d4894a69ae0dc4,
+                0xd6b8aac361904813,
+                0x1fbd3cc9f5a5930a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf95d9912f72ee6af,
+                0xf9fe3a6f4b98b3e7,
+                0x16cceaffe801cd6d,
+                0x14e3998ba6b6e56c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x26f64b55da3678c9,
+                0xaef5e4ef467c7142,
+                0xf4d2227d70f7880f,
+                0x1a3f4bc476fb4d2b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc710af866e03f5da,
+                0x9a0eab71f034f43,
+                0x1839661abba3f23e,
+                0x393d7f138d9e928
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd7ea23b1391c655c,
+                0x20a72f3747468a7,
+                0x1df640d0d233dc37,
+                0x83bd80747945a70
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaa88af4bea7e3fa,
+                0x1b139b104fc5b612,
+                0xdcbdfb9bee3f7fab,
+                0x86e63778df4ad7d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf3331775fb801e25,
+                0x1c29065f654730a4,
+                0xea786375929c929d,
+                0x864aa0a11ac7080
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb96ce7f912137826,
+                0x95ed4001f192d48a,
+                0xfed2dadc571b4a01,
+                0x11f0fcd3e4da6fc1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x70b59b105fcae33b,
+                0x550065b6b8c5c9d1,
+                0xa4ac281cc619faeb,
+                0x2ea575b8ed2ca7c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x40f0ac83fb6cf133,
+                0xb82e97591388429a,
+                0xf003e0f00274e3ee,
+                0x73975a6fbcde78d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x327afcce745e78a0,
+                0x84a6fb3f4423c7ba,
+                0x8867888385b61e21,
+                0x396478b20869f5d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1353616cc602c9b1,
+                0x51852c323e221188,
+                0x6c6d0ce8b84a9ad2,
+                0x24628f603e217a34
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb117d32f20cd862c,
+                0xbb217b26817485f,
+                0x5fd61b832041619a,
+                0x111daccddf4a4344
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x53dd9c6db8e0c48f,
+                0x72d68e2a126c0ea6,
+                0xa821c20b43ae7937,
+                0x1d9bc2d588f3836b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc9bbbfe557ad32d,
+                0xca5bc927b95384f7,
+                0xc471160193ee96ee,
+                0xdba757b3c501990
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x83bcdc09b586bea0,
+                0xf0b194fb9d7b3174,
+                0x34ebf7f3a6789883,
+                0x29194839f52f1ca
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfc798955d470df77,
+                0x24c9b504585d3e21,
+                0x84a3bd2ca4a08d74,
+                0x2b9f274dc6d59e32
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x50f2f879f780e63d,
+                0x3bfa33bcfb414dad,
+                0xb493fc52a56c0252,
+                0x2fb971d3a837a3c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6f9899044191cdb2,
+                0xc789b3e1dfccf035,
+                0xaceb6c4100a67b5c,
+                0x2c30cf534516c311
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1a14ba492de7c540,
+                0x5d1d31f77aaef6d4,
+                0x1bcf3a9fa6ca7fed,
+                0x35ee8014977c3368
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf9c7c25d49f59f89,
+                0xe3bd2e6c61eba61b,
+                0x7e520007dfb122f7,
+                0xf60248766dad6d0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x195da59542f7b104,
+                0x70ede1b6ad2f8386,
+                0xbc8db6befb45b9a9,
+                0x155208ecd5fe1b20
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x614802f3b2bd3602,
+                0x5e1104a269f005b4,
+                0x21607eedc07dbef8,
+                0x266f5df50eabd6bf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x429b8a88c8058fc7,
+                0x9efa96a5098f2bf3,
+                0x4496dedd2f260d0b,
+                0xceb9b0a4f6248c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5a2dbecff379e16b,
+                0x33496c8fbbf04498,
+                0xea99fe830f44141b,
+                0xdde2e83a4f56568
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf069c9cb40c9e6ba,
+                0x1efcd5604a0480a3,
+                0x261fba843acf7ca7,
+                0x2dacb4490cd73075
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x677369141c8cf571,
+                0x3a4a10459d5d61c,
+                0xc1fc272b9f4c77dd,
+                0x2d2d976775800bd0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x980dc8135f5eb462,
+                0xb555c930cabb79e,
+                0xef268160a8ca48,
+                0x3627b99c0a5c511a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xebbfef7dba0f61e,
+                0xaeca2c52b2f7810a,
+                0x1ed63eebd64e1337,
+                0x3d2e388eb26f2f26
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x472b9634c17f6135,
+                0x10eedc47fad6c46b,
+                0x14fb3388530c0a9,
+                0x29efd55ca7619960
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa097bcb72b79ddc7,
+                0x60f9de7ef0bb5b2c,
+                0x63b1fe605f3a6986,
+                0x66b45cfda15c726
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2ddb54354bd8bc3a,
+                0xe0a6c009dcd894be,
+                0x8d0b5a4eb42e4a84,
+                0xe7183ea28081d09
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x739096384bb7ee76,
+                0xcef7671b9e88de0b,
+                0x806a1a6f3b3b1e0f,
+                0xa0574b06e039d88
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1ba72754f2d1f89,
+                0xc70c2f85ab9636f0,
+                0x4b99d6b99748bb6f,
+                0x1fb72574a5f8bde0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5149a6de5a819bf,
+                0x71125ec3b46716b,
+                0xb60d321bc5e50c22,
+                0x138b614012fd76cb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x77dea890a6dda2c2,
+                0xbdfbe18774f77760,
+                0xf3bece7f6ab1937,
+                0x2b25aa450e1b0798
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x77f4d90af5072da,
+                0x87c5385cc932d65b,
+                0xf9f01120fbe7f3ab,
+                0x3f3f1d683b3c1331
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x260a1ad5287da01d,
+                0x8c77d0edf15d3a7c,
+                0xadde1d07e6febf18,
+                0x18c52ec3cf7b681e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x32ccbbc799d24043,
+                0x8e244a1dbe992dff,
+                0x44fe1918792eec68,
+                0x383360adfb9cb619
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6d6bea2fb3834b95,
+                0x3fe630f817c77d31,
+                0xb4a6f07f3a6d5147,
+                0x3682bc2271f9f697
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8fc8cea28dc9076e,
+                0xc04ff9f6efba8fc,
+                0x4efae66d530697cc,
+                0x22c3e9e3956e5856
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf1d346c719a90f11,
+                0xfadda7effd6c5b70,
+                0x1d8d6a16e84071ca,
+                0x92ce1e46089b4f3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa6a75ffaa3e73e25,
+                0xc2f1cd9db93bda3c,
+                0xd8a87e9b632e1e14,
+                0x3495b66d7fc8668b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc9478be5c4d8bf1d,
+                0x491b1bfe428e977f,
+                0xc40a5a5f14273288,
+                0x2b218a0b80d3ef7b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2dcfca673428e0e4,
+                0x9c774f7e625c470b,
+                0x2d5d3a2bbb34bc5c,
+                0x3d635e294ac707d8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x33f69e5f2f7ad08d,
+                0x5dc45af07c65b657,
+                0xf3cc4d10998d4221,
+                0x31d3a1fdda7a75e9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x263c3c39c711e970,
+                0x1d82d9f079b2a5b1,
+                0x6e9c954e3160db78,
+                0x2f2458509fd9c756
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x687f9075c01651f9,
+                0xf32f4d6c02f5c5e9,
+                0x8a64feb5fb75d9ff,
+                0x18bd6b30861653c8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xce3add734f12f5d9,
+                0x4aa18f898de11c5e,
+                0x77c7eb8cf6b6e13d,
+                0x3ca6db1adcc163b1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x88a190ff2310f849,
+                0x96cd13a2f6b3c546,
+                0x13ee8e3f635de8ef,
+                0x249c5baa7ad0dba
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe656de692233c920,
+                0xd6f568051fc59e79,
+                0x383cad3331a2d885,
+                0x204c5c5bb5a04bbb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc169fae9e4766662,
+                0xcda05391e51a421a,
+                0xc6fec25b67f5f965,
+                0x38a5726fcba53523
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbd3402f2ace7666c,
+                0xb9eff2e5f235b70a,
+                0xac18339206ef9036,
+                0x181b0585852df49a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd1676c551f363da3,
+                0xcc6d0ed14e0701c3,
+                0xf5a1f502058ec57b,
+                0x1bbc4fac31b05b2c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x323f37f17da16a79,
+                0x64eacb94570d9e14,
+                0xcc28237a08d45287,
+                0x3c9e7de564a331c5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2689d398f0dfaac5,
+                0xed2adecf5a0c0463,
+                0xb1b5687acfbec29c,
+                0x23421e24e8fe62aa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6bfe1e13007c34e2,
+                0xa9727f18db42f816,
+                0x29f1bfa01ff70cf0,
+                0x285dc9901cfa9651
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbeefb4209563219c,
+                0x171511a761a80be7,
+                0x3fad94f42f1348fe,
+                0x3dbbed675ea54973
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa5d0ef95b0e67678,
+                0x31ea53aa92750bd6,
+                0x49f66af2fc28d85e,
+                0x228168c30ee33068
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xffd03a500b6559f8,
+                0xba37eaeb92a76f1e,
+                0xa04cc0a01cc395f0,
+                0x2cc09c164d7bdc32
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5b4257ed69d85594,
+                0xda28d4ce1d33d0c,
+                0xd6d684063fc323a1,
+                0x1f4b2f90802cf1a4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9698e720119868a9,
+                0x8b74bf2146b71d6f,
+                0x43a190ee5755c956,
+                0x3076ad6daa194a42
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x72c6699a84962a66,
+                0xf5dabcc9817638da,
+                0xe80f4522f1779010,
+                0x28c5e5a6444a0408
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x83729e4c19a05610,
+                0xa7e497ab84ab4cfa,
+                0xd64f5f9653f44853,
+                0x175f23db226fc523
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x115178814f67e7e5,
+                0x738e7a72e1ba311a,
+                0x63d3c499db4bc955,
+                0x345252f8285ee51
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4dfdb8be77cdd343,
+                0xda64030c0a28a1b5,
+                0xff9b51e47c2514c0,
+                0x3b3711f32131a637
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb5135d0be80c081a,
+                0xcd7cb8e3af134b26,
+                0x87ed3cf35cf9c56c,
+                0x3b3259e655aa7f2a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x85d3e56292cae645,
+                0x9b068ee5a507527d,
+                0x9108e6b9b6a1978,
+                0xa74cad0961af2eb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc2395c9a0cbf33ed,
+                0x65cf37ccf92083de,
+                0x848472fe2f6ec5b1,
+                0x12816f75ddf0bf56
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd80702db4d8dad8a,
+                0x4d5410d45fd5a4a1,
+                0xba3398077d4b724d,
+                0x27b4c481c54221c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe68f5462755c8c82,
+                0x2327237646b389ee,
+                0xd4b5f1f46fb610d7,
+                0x17ee23b05bb8d5c4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4ef828fe4b9d8a20,
+                0x510f49a96c0d64f7,
+                0x3241a5194aa3434a,
+                0xd83748907c703a2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa58f7e84ca2dab25,
+                0x1dff666c72a40805,
+                0x5f2824c060176e53,
+                0x351ae1b64063496b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc4dda1958143ea4b,
+                0x5a64233ffa83a5fd,
+                0x6d50bc5bedc81c3a,
+                0x325b33cea8a7059b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x60896a0da7992855,
+                0x3fc2d6a346a037ed,
+                0xdc84731ed65de817,
+                0x359d520f5a62d65
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x60194462187760c4,
+                0xa1caba22a5d3f873,
+                0xe89ceab290dabaa0,
+                0x33b75a86274aca31
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x20d43aa272626d29,
+                0x7a7e796ffff23ded,
+                0x29d1a72c78e3da34,
+                0x355d6509f7f147ce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x44926dc64c1a841,
+                0x83c1878a8f647a5a,
+                0x22c89361b6eea772,
+                0x97213150be8da01
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x211f59e446c33d06,
+                0xe3faf812d4cf98dd,
+                0x48b4e15373fe4936,
+                0x2e2be89395c3a531
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4327eccf69bb86f6,
+                0x1f7d86e7d19c844d,
+                0x2da70740df260c6f,
+                0x352459e56c340341
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2827d56da9ecc1fe,
+                0xcb525bf7c2001c7f,
+                0x20f165f2d215ae82,
+                0x1a7291aebc319373
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf24c4749e9cb2189,
+                0x6ceac8852237170e,
+                0x71fd609a67f480fc,
+                0xc1d616500972e39
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x389422d303b47019,
+                0x83b9a2e7ee17322e,
+                0xddd51cf3c2d60bfb,
+                0x3f241f96a54cdc62
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3b948fa903673520,
+                0xd9d52277cd1953af,
+                0x74085bb49d145dfd,
+                0x39595a2eeee8b328
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x46c93a68f21b1cd9,
+                0xfb7437b1415929d0,
+                0x2e421bc8dae9702b,
+                0x1b8b718848cd2e6e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x96e9bbb3fe683df9,
+                0xe9a2916e5a034116,
+                0xbb41bfaa12e03ba1,
+                0xef9892326a44778
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd39bfab5b835f3c,
+                0xc2fd612f3622a7f1,
+                0x9c3b0d83be426fdc,
+                0x11da3496a7f67458
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd487a47f8fe2050f,
+                0xceb77107759ce51a,
+                0x8c36f8e5317a7a7a,
+                0x317ec7d8be3c30df
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7725a0ef8f1a7e1e,
+                0x43843587679932b8,
+                0x62027a3d6421862a,
+                0x15d96c2ddf0b3e3e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3ce4b00be06da235,
+                0x5feb6c0add99a12d,
+                0x43d4482748bb0d17,
+                0xe70cf40a1b8c200
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa30ba2076027a68b,
+                0x5ddb96e8153d681a,
+                0x16a8ca9415f9bb76,
+                0x3b3cd3b77469f3f0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdc4c0cb26573bf1c,
+                0xbc46fdb5f2156ef8,
+                0xe9ad4d994b4c8cb7,
+                0x2943e1573e2f5ecd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x79125e1255afd261,
+                0xe277f08db8bfa98c,
+                0x99327559c67db574,
+                0x2a62349c91d1bf1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x817acdf439908a27,
+                0x4c26ca2e42c7d868,
+                0x4fcabd1134d31e6b,
+                0x1471f9625e567f36
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd780501be55b276c,
+                0xccd3022b889f222b,
+                0xf1b5e2abf3bae851,
+                0x36f4262e20a27220
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdb050c511a9d5683,
+                0x3c95b3be4b470079,
+                0x81ec636cb0b058c2,
+                0x1ef64539b6003b03
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe7d9c146c18f8617,
+                0x87fc8a36458d3481,
+                0x9589febcc5c78de9,
+                0x2ebed0dc340b60ef
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x83e8a84dd7867cd7,
+                0x7c92a5db45da1e42,
+                0x1378c4f486792ac,
+                0x2d512488e65002ac
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d2c4f26c03e243a,
+                0x7ee4c220b82116fc,
+                0x9764d8ede2e4b35d,
+                0x15b5acfc7ed54e28
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf83d17c8a4448db,
+                0x5bf43835c23e2d35,
+                0x194664c52296e34e,
+                0xe9a6316297a5d28
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x93ca35d68f4cb748,
+                0xabd7cd12ff5cabb7,
+                0x2f3c9c8023e8e870,
+                0x3a5b41b4950a5b2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xecc309ce2ed53fed,
+                0xd8b7aeceb719b1ea,
+                0x6cc9d6ddf834cbd1,
+                0x290dd4311e083e72
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x10571ded4919dad3,
+                0x6cb56403bb4115cf,
+                0xc23ec05d61bae4cb,
+                0x2be48db58581917f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x11bb3304e7941a1b,
+                0x8f8e66d1c099e97d,
+                0xdb7fde28c2efe695,
+                0x7c17344d201039b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdae52b2a50bdccd6,
+                0x5de1163ed343465a,
+                0x7fca6ee08cc57d2c,
+                0x2f32cf955df52750
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x53de8f2e458bb4bb,
+                0x45454688abaf6eae,
+                0xdcaac3d029563004,
+                0x3387cc14167b1b6b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x43343434067b9088,
+                0x229532926179b7cb,
+                0x7a67ea3d4daaee8e,
+                0x253fec552644da3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xba94b3814f0ff1d1,
+                0x8ca5c60b0013375c,
+                0x5cb53e21f55ae40b,
+                0xe205466f41b3a1a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3a16c0414207141c,
+                0xbd23b7f4cfe0098,
+                0x8995b2b5319db03c,
+                0x695634b9a8292c5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4eee2fdb8f8b5b7,
+                0xeab1be2e9cbaaec,
+                0xad712a7d00d8261c,
+                0x3a54105291458737
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4e3442db7d2a8f34,
+                0xfa94e39c79a366cb,
+                0xfb458133d74fdf89,
+                0x14fba0936911489d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1c0d275bae8668c3,
+                0x30b7f97885e89c23,
+                0x372ace3d120222b2,
+                0x69f5ff9fe21cf19
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb4319aceafec4163,
+                0x8692a74a1fe69f39,
+                0x1c0c4759af13d035,
+                0x1d975df46e3bfbad
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5030ffa5106b8eaa,
+                0x46b9bf7f49c6f9de,
+                0x785b29caa203f1f0,
+                0x14326bd4396346f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8e45bef019917833,
+                0x3ff4d81f4ee63dd6,
+                0x13c69a814707bdd0,
+                0x11dc39a64ca0f51f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa8aa3131c8dd88ff,
+                0xfbdfb12120a2a6bf,
+                0xeedd0fc92b6e39e,
+                0x6fc6338c29435c6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3bf73743e78be975,
+                0x96320d5d87a09f00,
+                0x2b081461038bc00b,
+                0x3ec33de590c8fb94
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x42dae000b70d3cb3,
+                0xea1d66c4c1b0b5bb,
+                0x54b6c9713e2a0932,
+                0x3950564ed7ffe5b1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x27a0e372d82434ea,
+                0x5c769af7ce3baa84,
+                0x49c48252d453a3cd,
+                0x1971a464e35e858a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb919ed806e12881f,
+                0x33d239989ba94670,
+                0xf957cf76896831a0,
+                0x2bc1126ab4f17d66
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x56f66a6e3efb4f8b,
+                0x505bec3cd18e5d26,
+                0x4c9ae23638ed4499,
+                0x2d50e8f4cea44122
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa7494522b921e348,
+                0x45d246a43b92ed6b,
+                0xc46ccf1639644b3b,
+                0x211511911bf7966c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd7c4d13bef2a1529,
+                0x865aba8173ffadfb,
+                0x5efeb05018a6c432,
+                0x1fe85826a3d616b5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x41372a3f83977fee,
+                0x300f8341dea8faf3,
+                0x74339b9bade5d2fa,
+                0xd3983d7acdc6911
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb24f5cc7b6c3dab8,
+                0x14fee68382bd39f0,
+                0xdbf274b733206064,
+                0x38894508de892c56
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x91c996f8e68ba882,
+                0x2560619938627888,
+                0x5ccd84af13f27d35,
+                0x31788095921b1030
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5525daa7513d1e3,
+                0x4fde42e7abcd3509,
+                0x9a79c4df215752db,
+                0x3a5ffcb2552c5de
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb0c0b3c1dd6ad805,
+                0x5479bf1ee9df4eb1,
+                0xe8dbe89fd1e36ac8,
+                0x1ee9c31ba4bb454d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x78a28466f8abcdb2,
+                0x219b8e2abed4eb15,
+                0x505d25d98804f1b,
+                0xa949347bf21e2c4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfdafd53872ef34a8,
+                0x6b8f411e06016f64,
+                0xa3656240d9a08f21,
+                0x2e088dd15a2a4f78
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb13a52203f20e32e,
+                0xb6e1ebed445cd9b4,
+                0x9b9a2f2440795c6f,
+                0x135f3a817c09ed51
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8b4c49da66017188,
+                0x44e65b6dc1c9fcc6,
+                0x47a054d1551f7209,
+                0x92b44723f42d3c4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd33c4cc4765f27c8,
+                0x114f0a1df2d228ea,
+                0x54ae1e1d439a74f5,
+                0xb25e1f0ef070193
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9010ccb247dba307,
+                0xf79f037fd76c5dc4,
+                0x45dac45a088e7ac0,
+                0x332db84872f1553d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe175c3cd6be44770,
+                0xd8664eb517fd471d,
+                0x13b59b5a3fed81ce,
+                0x1f757af855095529
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc8945c50a05f70c9,
+                0x8c8362cd83459f0a,
+                0xd12cfe0a959a9ff8,
+                0x28052b4d2ce022a5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x534d6fdd62f2eed2,
+                0xfe59d6a01e035226,
+                0xb0cb9db1378deca4,
+                0x14b0d3fb466a28a0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x58843d32228ababd,
+                0xe25c37a7bb9be040,
+                0x69fbfbb3c13bb590,
+                0xe89646b681c5bb9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5dfbff7d949217f2,
+                0xdeda7dd3081ac895,
+                0x16409c00c0b87181,
+                0x1713f76c669ee8d8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xde88defd4faea6c2,
+                0x4112667e3c9a15ad,
+                0x7bd13768699dd2a1,
+                0x2d15ca4990492a0d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x15499fe16780567c,
+                0x8f9eea4443324818,
+                0x42f703bd15f24104,
+                0x2c0815c0741e6000
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb9c5c5b2e9520415,
+                0xbd89aa9c7a96e5e5,
+                0x1f22bb2baa2bd080,
+                0x2f45fdbaed61958e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x490ab0deb2f2fcc1,
+                0x1c99e241acbb7897,
+                0xe800eef27f79f691,
+                0x35e0aff176412aef
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd2111c07e33c7bbe,
+                0x7a4e9de1cbd72047,
+                0x9909f5152faeb77c,
+                0x287bfbfcdcfa82c6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d50ea79890edca5,
+                0x79ac5fb0f55ab24c,
+                0xb5b94d3113d29ef3,
+                0x830172cb519745e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd285cf08a4003a3b,
+                0xdecc74bfed2afc1c,
+                0x9ff045b5a285d55f,
+                0x399664e00224633d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9c73496351e346ff,
+                0xf4d475b1301a3cf5,
+                0xb01de0dde3006318,
+                0x2c04346d60e6efe7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x911dc0614532a087,
+                0x6a7bbf779ee379a8,
+                0x72d3191aa1e34101,
+                0x36af6979317e7649
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3abadd87937279f,
+                0xe62ec305afa5f8b8,
+                0x74116b163b959d3b,
+                0x152a08fc9e37b446
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb4967f7d4e986fad,
+                0xdefe29c4d24e5d6f,
+                0xeda536d0a67db3db,
+                0xcc68d9194ccc00
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5acf19ccfe0e55d2,
+                0x3f35dc44e586d9a4,
+                0xbbc97bfceb8ebe1,
+                0x20c6d2d3ef3cbcf0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcbf82efc94aa87c5,
+                0x478973b43019874f,
+                0x50d37213387fdb42,
+                0x1aa2989149f2abc4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd3688257eda0f7a0,
+                0xa377f8e005d632c7,
+                0xf1d639852c4e681d,
+                0xd49457aafd27b70
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x474ebc61a7585e38,
+                0x9b596e4bbfd6b54,
+                0x26530e037b816831,
+                0x260647b32054a0b2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb26f4b6c3586240a,
+                0x590434b634ccd5ea,
+                0x18a87c640317cb8e,
+                0x376c30b2d9a8e44a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x860626e254dafd7e,
+                0xe746efd5d29a0549,
+                0x31a27b76142c9941,
+                0x1cd4d2709998a4c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6ee2e511ef5fdced,
+                0x2bc414baf2741f07,
+                0x3ee85f334539a252,
+                0x29d94fcdd857727c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x356cb7fc7ac95239,
+                0x9c88d0cdf56ff149,
+                0x71c97b964495704c,
+                0x13b709486c02af6d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2db606885e0a8859,
+                0x6e3288698ea346da,
+                0x6775f829952a45ef,
+                0x3118c1ee7e525cdd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf204c71da42a4c21,
+                0x68176ca6d236b21e,
+                0x27cd6b14509cd24b,
+                0x8d77208582b1ec0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x24a01fa16672b3,
+                0x298f2811d9250be5,
+                0xbf4a3a825e6d3892,
+                0x108f408beecbd059
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x39f806c459715d78,
+                0xc44ecd0df22337d4,
+                0xe052a8508816f261,
+                0xe01473e2ea8d5c9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7e9139d7ed3b581,
+                0x9c46c53f5a0b8ed5,
+                0x9ea54ef5bed3bf01,
+                0x30148f27f1cc58d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7ed209a257e5ca73,
+                0xe58921e25e566ff4,
+                0x1ce962a76d72cc10,
+                0x331e95dbae4a22ec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x59e5dff68951ce61,
+                0x2b5ba2735fbf27b,
+                0x4cc740bca932ec99,
+                0xa89be78fa229bf5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcc54ad77aac129cb,
+                0x3a9eeaddcd416347,
+                0xec7bebb5ce6fcd16,
+                0x195bc182b3b556c0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8efa475f2cb4e6b,
+                0x6e293175c18b19b6,
+                0x1e3d167f39eaef6a,
+                0x3249c49ff8c70586
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6ef443f3df86d0c1,
+                0xb8ec101bbe2c34eb,
+                0x1bd2bf248ac938bc,
+                0x2698ed26d2636db7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe5818d724d45d449,
+                0x91f1b139af7bf410,
+                0x521eab75360e0522,
+                0xe74a1b24503b9ee
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x65c19b272e48360b,
+                0x1711bf62c0ee3006,
+                0x9d9c80aa6917d3fa,
+                0x142571804e083b87
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xac26ac028e4598cd,
+                0xba9adc649fb25a6e,
+                0x53458511f2f3b63c,
+                0x3d699e5eeafe6e84
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc35363da624ea8c3,
+                0x53f91210086e6ff0,
+                0xb5118e248823ab43,
+                0x2c7ceccc3f5aa60
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa0522ce3c85ce699,
+                0xcd9668ea6d65f6cd,
+                0x68087ce3961cf26b,
+                0x39e1572743836d7b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2dba41f119b94f28,
+                0x48a4ed7de123f5ef,
+                0x61353f5c404a9d5f,
+                0x1d465903f1c2df8d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2e9897175299b926,
+                0xb20b11d4c834ca28,
+                0x7bbf747d3524c928,
+                0x2e6540342cdc56d1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x825d8ecf59c4ad04,
+                0x954febbb52fbeec2,
+                0xe2dd4d3ae7b1767f,
+                0xb710fae6a5e28a1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9a2572b84285bf3d,
+                0x3c5e5ff39d165802,
+                0x9175e6edf507d1ef,
+                0x1bfa39677e76bc18
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5be4d296241569a9,
+                0xd222f3f392b34e67,
+                0xe0bdfa5590bf896,
+                0x35766543cd4f086a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1076672b5fac71e5,
+                0x1854069a98b641fe,
+                0x98288eee22d0e3fd,
+                0x2128fa638321b0da
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe7b058daaafa3ae3,
+                0xa1c908231ac13b5e,
+                0x3b6629808248d464,
+                0x3421947d3558730e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d28a80e8b0137a4,
+                0xec33d0b77c3d0e1b,
+                0x3d210a4489664d2e,
+                0x39336bae43ceb6ed
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x67fa79b5f3e3b0f2,
+                0x7045503801a31e61,
+                0x3a2985a2a0c0a29,
+                0x1131dffa6762cd56
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb4c8eaddeec58ffc,
+                0x16fa1a32d3d50a11,
+                0x28d6c34726066e9e,
+                0x31965fbf4ac8e4c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x96e2c9b9c8da46c1,
+                0xf932c18625b8a46d,
+                0x399f9e7d3113be28,
+                0x2ba598b5c909b9e9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d4de015027d08e7,
+                0x270260f77ab65063,
+                0x96b32a43298a2b3d,
+                0x15b17344e1a6de6c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd31f25afa1b22a5c,
+                0x85c9dafd818fff5c,
+                0x72fb2ed95a4ebf93,
+                0x18f1f54eeff3751a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5bd37cbe8dcf2e54,
+                0xa2ce66c679a239c3,
+                0x2077e650c141b0ec,
+                0x1362d07e441e89a9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2515074f645d1759,
+                0x5ffd44ad1f7621f3,
+                0xd4b5d5473218c33b,
+                0x3d84c28b8c2127fd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfef97f9ef906204f,
+                0x973f6da60a26a137,
+                0xe94dd251fbac6d88,
+                0x39f12b1f31c599d0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x90bbcbc9ab259033,
+                0x3af7a1de1b799c5f,
+                0x2ccbaf35bd71767b,
+                0x14dea0f185ae40ec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1c45dc4dc8930dcd,
+                0x30d076d57a3a89e9,
+                0x4ecceb17eccf1ffe,
+                0x3ff3e953359ea17a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe516e78f2df91d83,
+                0x1a0b0829912fe9f9,
+                0xb2d30e30a85b5812,
+                0x174a8b81e0d20a10
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf321ceccf622bf2e,
+                0x5b2a8633deb57bf6,
+                0xde21a97cd4bb376b,
+                0x1c3a088ce0b339e4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5fd75a455fe686cc,
+                0x6799a0ea79a2ae86,
+                0x14d5b86af662aa4f,
+                0x848162cce499d36
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4a5c02c83f575923,
+                0x1d4a142c8bb9376,
+                0xd0dbcb2052e4db8d,
+                0x671ea3f42363646
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xedf6723f068fd461,
+                0x879d82db1bf92c03,
+                0x3e0b86c17bcd1a93,
+                0x1e48773759b3e73b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xba2f8babdd3cfc90,
+                0x11531adca0357392,
+                0x1dc4e3785b488093,
+                0x1e688a54183c272d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3582971b34fb1096,
+                0x63a80873742f36b4,
+                0x2988b34dc8f73ca7,
+                0x1dfd0c40157909e3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb907358c25bc5f2,
+                0x5e24eb0dde18dc13,
+                0x272c8721f6662144,
+                0x682f7b40395c04b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcf7afe8b9ccace58,
+                0x4bdbd4cbf4015a49,
+                0x4ae35d20155e57d5,
+                0x1fd583c00bd47f51
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x33c0c019769e6a03,
+                0xf3565aebfb2d04ed,
+                0xa8af686e48e01c06,
+                0x16ecd630c312c616
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7933a42907d3cf30,
+                0x976b6ba0f49daa86,
+                0x5bafbcfe8d4ea18e,
+                0x25e52a74dae53c2f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5904f6745eee2acd,
+                0x56032be3f3992b4e,
+                0x3986408afe5307c9,
+                0xa1d52b0b0fdf237
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe420b92f14a83d07,
+                0xb3c0d46a64b65de8,
+                0x93c8eb9e453375c3,
+                0xa035c42cff04c48
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x64606dd22fd75f0f,
+                0x2a90f8d42466a55b,
+                0x27b1a9781a6085e8,
+                0xd9876dad6c4fce4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x56c5fc6ba50da4be,
+                0xa302888dc3c3859d,
+                0x8a1694e3eac0504b,
+                0x8ca596b31e6db20
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2b456e399cdbfb21,
+                0x5acbb311bf168f59,
+                0xf86106a704cf4fcd,
+                0x1644a38f7259e17c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeb875ad454d88cc0,
+                0xef6dd56ac17f2b07,
+                0x5416cfb16f316b07,
+                0x30eba4d03b44f3ef
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x25c5b6df0e2a6890,
+                0x9d14123ac4368e3d,
+                0x5460336183496eaf,
+                0x22604df96679209
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x83e60791a8c307e,
+                0xa990c20a63461083,
+                0x145d1eadaae73b92,
+                0x11b132e4d21bae71
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8d441a89ec0e4286,
+                0x87d8e055fdc6a804,
+                0x954905a6cf10d80,
+                0x1bdfc86bead5d5d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xce19482b1f4923fc,
+                0x332e84123e75cd73,
+                0xae9f1eab7f0eaad4,
+                0x282416e9f05d98a6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xab5d080e68ecc11f,
+                0xc84e2a4d19ebeff,
+                0xa80022ee8c57d39b,
+                0x3c4c36904662d8e3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x314e68f215a51de2,
+                0xf15e9e4b7946ad9,
+                0x54b1ab9e885fe67b,
+                0x1b0bb9f888beb513
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x327e3596498a14c7,
+                0x2a50a6122885c03e,
+                0x87e70f47601355c6,
+                0x156d12d36b223495
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc170086c4529ef8d,
+                0xf7897f78dd42e641,
+                0xaa78e0ddf4665c8b,
+                0x331351483b3232e9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa6aacc36ba2cc74e,
+                0x5f2904f0c6063ea4,
+                0x17ec4547112afc40,
+                0x2bc59cde17c8f078
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfdc05bf2cecfb608,
+                0xd3a9018bb7b5021f,
+                0x23da2a9a69219998,
+                0x1f7228ed27c03ada
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x85cb7847e6077b27,
+                0x3f4b003e415304ab,
+                0x394cc5570267b9db,
+                0x11f0597ee5e9137d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3e59ab91b8bb2a38,
+                0x6e76bad738755c1d,
+                0xa885c4bc65fddcf4,
+                0x292ffdb6e4b0b3d1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd33b2ca100120eb3,
+                0x9dc0e1e65eacb78f,
+                0xe956abe5b5d2384e,
+                0x31318fd5c290d688
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xde896284cd383d31,
+                0x2060a73a5f17a922,
+                0x59495224242bbb1c,
+                0xbea848cc6ae4b7f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5556bb4fd7844c4b,
+                0xfe55d3356b00602a,
+                0xa2518ea4384f911b,
+                0x87817d4b2f9def3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7ee30a3043614f86,
+                0x76288a54abb96e1b,
+                0x8a6317c91366104d,
+                0x71d3d3d3b3a4045
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x53915913345257ed,
+                0x61eeee0a0cf41ca0,
+                0x5eb875d24c4311a2,
+                0x113012b422714f0e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x63437a2821f73504,
+                0x1d5a590c1a34219,
+                0x87199256077eb806,
+                0x219aeb0fb74fe898
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1b01abb407803345,
+                0x57b78b6221fd2,
+                0x2ef03cb8b5269378,
+                0x36fef46f4b5939a3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4a67d16b8c19a027,
+                0x260d6c1836e16b34,
+                0x6418cbdd6e54e0e0,
+                0x33fd7467c77200d6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc0dbe09091ecb30e,
+                0x29c771fd49bd23ae,
+                0xd107c90ea2d99b7a,
+                0x2bf3a7025c628de2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe931ff690800457a,
+                0x16abddb97f29ef66,
+                0x8c5dbceb6fc7a128,
+                0x1d13458f7b271a2d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x433ef22fc11c958f,
+                0x15c6c11121db3139,
+                0x708b52e7c59d7493,
+                0x30e691d06e2bc335
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2c6c2cbc745ef61c,
+                0x347f6fc9f418dbac,
+                0x24f360f7ddb43c83,
+                0x1eb56a7a031f917d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xadd018907328d5f1,
+                0x91325125d033221c,
+                0xd0aa0375ce40212c,
+                0xe535e90da877884
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8226f8311d578109,
+                0xc29e45a8aaf338a1,
+                0xd7735ad70aafeb98,
+                0x20450c89753d4ba4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa184fa2a4ab19831,
+                0x67fc62d7c1691183,
+                0x7985d6f71a736085,
+                0x1b08132a3c7e7a2e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa399b1eb29128642,
+                0xcdb51c0ee462a290,
+                0x67fefb6c6ca20539,
+                0x4a6e81ef1a3b7cd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd7a4d92f706a519c,
+                0x7d27fbf699f887d8,
+                0x4def39fbdcc6b086,
+                0x3ababa016d3e47d7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf31ff9320b97cd2c,
+                0x71987396f290e7ef,
+                0xe138a449154b2300,
+                0x3e0531aca6a2e212
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2765c75f293b6c3,
+                0x7f1c6d1d59215ac4,
+                0x297df45351266e64,
+                0x2e18988506690e8c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x36e38783ee5c3531,
+                0x1b2540a3e160a8bc,
+                0xbb352cf91edf0fd3,
+                0x2dda25e5630eb9e7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8317f8d57e6d89d4,
+                0x98a84af317a69186,
+                0xf6806022ae8664db,
+                0x561627b435f4cc1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x62d2e74df8d256de,
+                0x297179ef0b544135,
+                0x2a9e5563b620454a,
+                0x341c1ce64391c4d4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xae27517e26ed1a24,
+                0x53bb621590034a66,
+                0xa1faf3679f94b893,
+                0xa9366884b382e5b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3d2ab7da6dcfb39f,
+                0xfab6f40daee8784e,
+                0xd8926029842291f4,
+                0x2694330b2a10872f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4fc367650ad4104e,
+                0x6b21c6eff7c752d0,
+                0xc08ebee21fdba175,
+                0x35e7f543578edda5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2438ec208e8e68d7,
+                0x80de8fbd7f5f8da3,
+                0xe4ee28ce9ebf768e,
+                0x379857e6772fb348
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5becf9bda43e306c,
+                0xacc0176e8c270ec,
+                0xbb0bfc6c13c3b374,
+                0x3a760ef90c025a1b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5de63ba1abd13136,
+                0x4d16f90c3cbdfba8,
+                0x5e693edcda85e1f4,
+                0x37f97dff1cdf80fa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf03ea7c4ca229797,
+                0x533a328cf2aa7419,
+                0x3fa39d0265d2e5bf,
+                0x1584ed3aedd5a5eb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1d9ab8fd101a7704,
+                0x53ae9329eb6d3cc5,
+                0x18878b7c873ffce7,
+                0x25d2315a568b530f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x435640ba8150eb19,
+                0x202183104047a7c0,
+                0x67a4f3af369454f3,
+                0x3529757a245f5f2f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x36f93c487330d354,
+                0xb0b7abd96c59bb9b,
+                0x16472103a1179430,
+                0x294713c143609d43
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcaa2e4d122cb8dd,
+                0xda1463bb3e66affa,
+                0x72100b10648e6fa0,
+                0xaf27574b594e886
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe1e4a60b0a9c3db6,
+                0x18a63835e02b40e2,
+                0x22b8f234ad4a2f95,
+                0xfa243a322dd11aa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x582be7c772b70a01,
+                0xbd89427de22d28d8,
+                0x318651dba0dc4cd2,
+                0x22f5ddcc653c6e7a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x33b8d1030f7c355c,
+                0xe92e01dbf7748,
+                0x2862a33a82eb4ad6,
+                0x163ce8e7933b4ea9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x576c1b11aea3aea5,
+                0x22bb154c0a5eda09,
+                0xd883072b3871db93,
+                0x80462eb5e8b18b4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6f691c6aaa7c8790,
+                0x88460631627b43cc,
+                0x160e53934e7ed5a3,
+                0x236d596bea6ec2db
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x930c9e140134f3c,
+                0x402b570a0a4e0373,
+                0x5eca6b89744295c0,
+                0x271537ca7691b77b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xce1a9164841b993,
+                0x9b6e24885149845f,
+                0xc856cbee75f16fb4,
+                0xaa0853b4340fc1a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe34e3111c7283001,
+                0x7b95d737b424d708,
+                0xba95e2290927dbaf,
+                0x3fedfdb32175a5fb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9f70b164557cc6e0,
+                0x468a8d3eb7924f00,
+                0x74c91bc3cb98cfcf,
+                0x11f6fa4682124588
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c2feaed0b7f8cb2,
+                0xb91110c2daae3731,
+                0xd4564cfec9201d0d,
+                0x52de0c5b219d0cb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1a90a68d6da9be6b,
+                0xfa8ae01a7953aff9,
+                0xba5fbc3767cc493b,
+                0x3ee44e57046ae723
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa9bc1a2e1585e610,
+                0x70f156dd3a17b0bc,
+                0xf9bc03719e2899d4,
+                0x3c5647b5f0fffae6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xedb62603e55d8962,
+                0x280eed0346da94b9,
+                0x1caacd750d206eec,
+                0x1eac7d432af275ba
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2f257bced74248aa,
+                0xe3d6bd8473fdfa81,
+                0x4960d6a719234a99,
+                0x1cbf4494f6927345
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x328400c45baeac7c,
+                0x7a6895cf38332889,
+                0x1b22a751069c2a85,
+                0x34233c9e5aa408f8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xae6be7690d57d038,
+                0xf75566c7b21d66fd,
+                0x402fb9e9a47a09dc,
+                0x3ab881bfaec6d08a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6fc9bd6f9e4d9f6d,
+                0xf783dd7f6ca09f16,
+                0x2ba284ed7fc1704c,
+                0x33401e0a086e14f2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf81e6640c2dea563,
+                0xcb65b1929579aaa3,
+                0xc4928920e3fefecc,
+                0xad11239ce1ad408
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x36a3280313962db6,
+                0xb61b827c513649ce,
+                0xa06c3f0c6a735f28,
+                0x15dc4457891ec4f8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xee84547c7284f88d,
+                0x7b3b7af6b667f489,
+                0xc0cb9442b8bd51c7,
+                0x28c675b2145631df
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3495cef6946ccf03,
+                0xbabebb78bf4161de,
+                0xe5bccdfd445f2e11,
+                0x1b882f1cd7cfdb72
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdef32e95cf7ab646,
+                0x2b45ebb4547940eb,
+                0x23161688f1fb033c,
+                0xeb734aa5526878
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6711d7dde5a2e0ae,
+                0x2b958bc7c68a649,
+                0xbd675280a5bd28b,
+                0x2d21525b267f63ca
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x69031a29182d8637,
+                0x13c9ab8895ea722,
+                0xb8e619c66cf4dc2a,
+                0x4e9c3fab84ef7db
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7f698135f27b812c,
+                0xb6558840649f95bc,
+                0x913968629133bbd1,
+                0x2928bad77828c19b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x48e50d3df50e8c75,
+                0x644b5a22415d5190,
+                0xc3da8dab63301f19,
+                0x6b9bde32b2c889d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xce4aaae59b2ff545,
+                0x5782fc45d88a0507,
+                0x4d6e7f37f21cae2b,
+                0xff70f5479d94a5e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3ea3acb9fda33449,
+                0x8967a16e0432ea16,
+                0xfc73d5f491cc1760,
+                0x206b34d17653a985
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x39dac0a23cf00013,
+                0x4232641e73475ed6,
+                0x92a5be213b137be9,
+                0x2d1f11d918b9c8a1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x832e03287e1eab58,
+                0x2965f3fffc3edd3f,
+                0xd61a2d26b9cdcf28,
+                0x1ab4300cf31d1a0f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa51bdaad8210b460,
+                0xa49f2e91e048b51d,
+                0x8ea697de4c507914,
+                0xf578c02ed7b7ba6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa02400804535b3d0,
+                0x550a318cb427c281,
+                0x12c09885fbc9d46b,
+                0x12540f7b25a5dfac
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xad95f0306bdec3a0,
+                0x74853264823b84aa,
+                0x335c31313813d6ba,
+                0x329de82ba7be56de
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd130d57f0ff72d10,
+                0x95f7a02dfa37c700,
+                0x2a49b088ced005e3,
+                0x1705784fbb5e94f3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4f8f3a696aa0040a,
+                0x2dff8d39fed120b2,
+                0xa47cb4bdb176868e,
+                0x3ac6e840a555d66
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa51f91e7db33d242,
+                0x482ec36447018890,
+                0x2398494438e7189f,
+                0x445c0f7471b42b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6db670688818408b,
+                0xa3d62ffff6d40548,
+                0x4bf1962b497208fd,
+                0x381e28ba34beadd5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x94dbcb6ffb697690,
+                0xfcf31b3e617cacbb,
+                0xcc815f8b6269a831,
+                0x354960177e5bec4a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd9f40a90e0d93573,
+                0x720bea27fe937426,
+                0x2f54cb35e07877ea,
+                0x269707b9cd05fe38
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe06d936860ede54e,
+                0xdb3c0c40c635b2bc,
+                0xeae67cb4057a5b62,
+                0x3cef2856c662d6a9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7e3f9e036324a553,
+                0xc90ad6b8421cd830,
+                0xf1a9d7640e7e9af2,
+                0x2c6b3a7f3d402729
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x338ca4fa850f56f4,
+                0x7337043acd0a57a3,
+                0xa28f8782c1201b9b,
+                0x17bf543ebb2c52b6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4d6f1eca06dc967e,
+                0xa5a5ae624a6e507,
+                0xccbe103eac6b96f4,
+                0x350a93384d148ed8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d040afc87066262,
+                0x50918ea252ef0498,
+                0x5b4469b8d696496f,
+                0x3bcd0ef6b5883e3e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2049afc07e0e4f0,
+                0xd781924f402f2975,
+                0xde604dec900f0229,
+                0x29a323ff76235547
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2670e9d5035cf97,
+                0x844385c1c94d16b2,
+                0xb6b0f2178bc10075,
+                0xf7bc1269a1108d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb5f2c8557374c21e,
+                0x49d4763c56223242,
+                0xc42287feb732b535,
+                0xcc26bd27b1fa814
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x437fda74a8e0fdf5,
+                0x566931464d2b7d36,
+                0x4e9d82995754c954,
+                0x2c41618e4ae4268
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa43a530c33bd52cf,
+                0x5c45849a10e3a8d4,
+                0xa23920a3cb6884ca,
+                0x228e4e8a3f167771
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdc7bbceec54f7bca,
+                0x6615bbf8cc55dae1,
+                0xc58fa95febc6f3a1,
+                0x38e07f96dc0af259
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbee99aa2639f6f08,
+                0x9a56a1da6b0a7160,
+                0x2ce59961aaddb903,
+                0x17fda00bb805f8f0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfea18dd76c1b61b4,
+                0x7fe781487e1df7d9,
+                0xc0af5bde94252644,
+                0x6369b3c07828a5c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x346921b696a44b9b,
+                0x6cbfbeba6e22345f,
+                0xb2867f8cbbb709df,
+                0x2ef8f44c33e51ec3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa28c0d1af121b1f7,
+                0x62659744ca4963ba,
+                0x24e74aaed54a328e,
+                0x2376454033ca74f0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x35ce381832e3b8a4,
+                0xe971a6d3cb907839,
+                0xba7e72a8d7348538,
+                0x1729adf64617b47e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xab1e28a3fc9e234b,
+                0x4398ef0fad357409,
+                0x2f77d3f6acd8ede2,
+                0xc860ffb1b36b1ca
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb191a351c0456375,
+                0x63ecbc2167938cf,
+                0xd619b50c5ed20e22,
+                0x2827cb9f4fae5560
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x981484477f6a3858,
+                0x25061a3bb5eaf93f,
+                0x2f7fda46d50d7bf8,
+                0xd90604f27869462
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6e6ef4ad8cd047e2,
+                0xddec49f05083aa36,
+                0x5e95cb2f32fa2317,
+                0x1ff98e699d5ea454
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcaa11c0757a6c865,
+                0x59457047a7d3499b,
+                0xfc7bb0159af4dd7d,
+                0xb264d8338b6ea97
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x20b3cc4ae9619144,
+                0xb9899b51ab6476c4,
+                0xc5c1bffd26773a8a,
+                0x28a1cbce09051645
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8e67ce8475ec9ffe,
+                0x88c1b98787629efa,
+                0x7bebbe87edf6e952,
+                0xd6efb20f9544ada
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x96a658e472f31f6b,
+                0x5ef7027c1d9ac9f3,
+                0x5d680011381a91,
+                0x17b7b77fd0a6ba1f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6c7674f7425d82eb,
+                0xa685baafd3008b58,
+                0xf9e9d9a74c140ff1,
+                0xa8c4e503a4224af
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x489b15f58d5f69cd,
+                0xbc28fe83d2a62442,
+                0x22aa30faf596b9a7,
+                0x1d99962930760faa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcc34d0e0f0df2cac,
+                0x6a540ae4e870dec,
+                0x77a9114cbb0ad57a,
+                0x3be0083359647bca
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x61cb594042a4e3c0,
+                0x8fb8b45fbedcb16e,
+                0x9e0aa957026c38b3,
+                0x1d0db2217acef11a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdd66e953f76ac68d,
+                0xf60b21419c04468a,
+                0xf1e096bda9acdbec,
+                0x2ecfda569d9a471a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd1ca35c0c292ff78,
+                0xeff8dfffe304013a,
+                0xbfc7fded8ccb4e7e,
+                0x57eda222f89f33
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe32735f992856e42,
+                0xdb017f13f484898f,
+                0xeb5135c5cd687070,
+                0x72c7787d87bee47
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x859ea39aecaeea77,
+                0x5b4f5517a69245b7,
+                0xf719ec3f2f483e38,
+                0x265ef25b7ecabdba
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x172e4ace471e203e,
+                0x93a938fd1a18d96b,
+                0xec88ef91c2584f4b,
+                0x1ed1d9e491ae4833
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfa8a6c28d7dedd27,
+                0x8ec9db3a5da881db,
+                0x170db46f9c5408ae,
+                0x118c70beb95d41c9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4f4db55be0d91402,
+                0xcf0a272df32e440c,
+                0x69533c08810712f0,
+                0x528eef5a6d5669a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf58deeffd82b9bb2,
+                0xc0dcb2271915a3b4,
+                0xff5527bdaa156833,
+                0x21020b9f40915f0b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcac8b73f59d2315,
+                0x34c5f5ea5ac3f434,
+                0x5b3cddfb1fcfd4cb,
+                0x20cafb254874cf1a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7656b0b6135ea881,
+                0xc74f15bbe93c662a,
+                0xf7e19a90996efc5e,
+                0xba4290ba5731bfa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x62060f5db22761cf,
+                0x1bb3e937dab4b340,
+                0xf230f2eac86d72d9,
+                0x23058dfe1c447b0d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf7f44876966dc6c,
+                0x6f99b0d99b36dc6b,
+                0xe6121e3ddc723adf,
+                0x2d65cbf0cf2f6ff5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x887970cf0f9b0365,
+                0xf47b811b5dff60f6,
+                0x307939582b3c254c,
+                0x22b43c66508858bc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdf2c7e6dd1431ee1,
+                0x76f06fae8362db4b,
+                0x1e0f9fa90a90292f,
+                0x1a25fd88958251ca
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6de646c264718be7,
+                0x31d409f3827e4d29,
+                0xb07c1ec30bbd465d,
+                0x7688ca12036068
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x49dce9d949a1558,
+                0x12cf478aae8eb2d,
+                0xc899d332dcf50124,
+                0x39bd894f3419e2cf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1b78033f5ed638b8,
+                0xfa5ec664f49f6ec1,
+                0xf4cf5d2cda0751e8,
+                0x28261f6ddb749fad
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x831b6a6bda25ff2b,
+                0x5a1eca318a731d19,
+                0x70471e5d8d4a84b0,
+                0x20e6726b257091d3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc7f79a11b5b2729a,
+                0xf75ded307f90b711,
+                0xb5103d681dfbfe18,
+                0x19f4e60f1c7dfd90
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x528fb69f4ce912e5,
+                0xfc891a0201be6329,
+                0xbd1f34f8e538328e,
+                0x38ac38956f9178a8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc295e4a1f59a3e,
+                0xdf5da3b7a7c8da6e,
+                0x95cbbc819a053bcc,
+                0x2e8d66859571834f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc92207980a7f8713,
+                0x8781784e58a815cd,
+                0x702f6383fbff19f8,
+                0x2e7ad3229faa85a6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x853e2882fa9e80e2,
+                0x73ebd996382980b5,
+                0xa5a3c5422cba02c8,
+                0x293eacc2d2c3b63d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x220874dc09701bcb,
+                0x6c6e5bc2fb5a1917,
+                0xf06a35f8cd2752ee,
+                0xf12ecf83bb0ea42
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe8af03925c863446,
+                0xc46a051158e75830,
+                0x854bb753a5bac868,
+                0x2c83c4ed4ea4adbb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6b1b2cfdc2157b14,
+                0xab8e7723e9537b63,
+                0x7cb4e6c2e333b27a,
+                0x23250778497c334f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d113b474b828648,
+                0x3807bcea77ed56d9,
+                0xd3713d6e63a1140e,
+                0x16345cce8ec1a525
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5a89d430a98f77ed,
+                0x6d1bd06713e13c6a,
+                0x7a071a92dedf9cb3,
+                0xd62914e59c7e425
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe01eff62f13f0c3a,
+                0xf47230442ab3989d,
+                0x1d0df8de5092dc9c,
+                0x362cc798864de2c2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa927cb8939f876e1,
+                0x94c227fc73e54162,
+                0x89dcd50b112ac781,
+                0x3b8b6f2d2da42520
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5c953788a7a1d95b,
+                0xf447be95c310044d,
+                0xf53621341abea99c,
+                0x1ff0efae69ffffd8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc3057f9c8ee39f39,
+                0xa4daa2d7eb95d731,
+                0xb607dadd375cf1e8,
+                0x22a4342e62623608
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8ccaabd6e74dd994,
+                0xdf9da656929f9afa,
+                0x243ab8ac8b866970,
+                0x342fabe49f714f28
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2f7df501be5c4f9f,
+                0xc6b32cf8c19c0494,
+                0xcf3647de518eed08,
+                0x8c67f2f491ea432
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x46d57d36630825da,
+                0x37e3d750314ad3aa,
+                0xa61d307a0342aa03,
+                0x1435e8e543a4c362
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6ae07c8fe7afa4c7,
+                0x1457babfd74869c6,
+                0x3575de59a6c4cae4,
+                0xfa4c9238edc2319
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5ada302c10f92104,
+                0xb8fd24b4edde9e1f,
+                0xfabe01ba7863f663,
+                0x32d45023a26287ab
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf91a9eb65236888e,
+                0xd92daf0cf0a15484,
+                0xcc3f1d869a294c64,
+                0xd0065d0f0c76e49
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3dc122f9735690ed,
+                0x2250b3de5e71743c,
+                0x4f4e116cdac5e811,
+                0x2812dcb386b3a1ec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3514d03cbaca037f,
+                0xf01e3f4c167ef9ec,
+                0x2a46363edb5fe5b1,
+                0x2445a218907bde85
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe263610f078647bc,
+                0x13882f4edf516518,
+                0xeb025683177e19ff,
+                0x2158d1d04aa46433
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf118b3b8e33a743c,
+                0x8dec041decaf2ca6,
+                0xcaa5315658b5a5de,
+                0x3a187e4640a9a5a2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5942817aacc8eadd,
+                0x1a0dbd335b3b942a,
+                0x690624e788e99346,
+                0xdf3c17fa7845524
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9304e52218c89c5c,
+                0x6145375b9639dd3b,
+                0x2aa661bf5db6c387,
+                0x3c18dfde241079c6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa346ddbb7dade425,
+                0x7843c3585a8c883c,
+                0xb00626fa2b3f7fe9,
+                0x2ab894b3b6d3dcaf
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x112110adf41a4544,
+                0x4f8dc597d96ce186,
+                0xe693b2fffb98253a,
+                0x33ea1485e5f89e1c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe3be9b99868dee7d,
+                0xaaebddafb5a83b44,
+                0xfe7363bac986b787,
+                0x991eb52bb72eef7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9f86f57ba14bb164,
+                0x65656ebad73f28bc,
+                0x19632daaab54fed2,
+                0x341b346e1a092882
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5c685b6d57247371,
+                0xa2c5b1d9e803902a,
+                0x908e53755cf27b5d,
+                0x1ab3a0322016cd2e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8ccfdfe84b241e46,
+                0xb31e06609e954c3c,
+                0xaaa57a3be6fef020,
+                0x3f053ab21ac787e9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7ecba765a1f21eba,
+                0xbb683b91c61c61e6,
+                0x4a20742e9a20a7df,
+                0x356c318e10be7e9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x22d658bd71a8a77c,
+                0xdb139af5075f8514,
+                0x9c78e8c051abbd6c,
+                0x337b02ac6d92eb9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3d489fcf0fe593c,
+                0xff5a674954304476,
+                0xc0b371bb5ccb909c,
+                0x3e847c20546b3a87
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x48f921e6390a2b16,
+                0x1cf38a6aa1852868,
+                0xcbae88aaae0afdef,
+                0x13eef879b26e53a0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7b6d7d03180c2fd0,
+                0xc1bcced9126252bd,
+                0x1def48cedd378359,
+                0x1beafa4f096aaa47
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf22356761c4b0069,
+                0x27d5e33d2c2ea779,
+                0xacaf5cd41f5bb601,
+                0xdd167f4fc52b13d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8af983141d2d1a30,
+                0xa224f858ef4761a4,
+                0x42c552a060c1d6ab,
+                0x3af45d3e33e080f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2ef47d1060bda80b,
+                0xdc1a421e41dabafc,
+                0x34609f7f7ba4a4d2,
+                0x22001f5972fb06c7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaa04e946bfb89344,
+                0xefce8e4b1b1a0a5a,
+                0x69a68c6adfe1ee69,
+                0xe59ba76d76ce783
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2324b9d956edc63d,
+                0x19fdce54ef04b0c3,
+                0x4ea06eb9c8f8f51,
+                0x3e80dabe94f5dc3f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x45afd4e113c3e372,
+                0x85e8d1d27ad29169,
+                0x3f919a371da7dc00,
+                0x3a5566c92e24a3eb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcaad4786d018a80,
+                0xf98bd8b00d730a0e,
+                0x4e3ebd78270ceedf,
+                0x28a9b534da9f9434
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf4c981362e770ae,
+                0xe02c43e28dceb70c,
+                0x8b7fd7b897053c1b,
+                0xd5df3392470823e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe701176bd55d20ea,
+                0x22be3a3cbaeb63d4,
+                0xdeb523c1331d95bb,
+                0x28fc1406b563a53
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x91431c3ad06f7a14,
+                0x46a7419099bf9148,
+                0xdd53c3707f5e0c0b,
+                0x136564434f9b69c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeae3d9cc2a19abeb,
+                0x96a9967374f42092,
+                0x72b9bb45aedc72d,
+                0x3c04402f71aa43d7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xef1bd8611789508c,
+                0x20ac704af3e62e45,
+                0xee9630e5e5721042,
+                0x2ab02c541a8ecc34
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb0d1489878b0a41f,
+                0xa4ecb5d76f953b1c,
+                0x20b027047b98a9b0,
+                0x52a08ab9700e1dd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd527043aee97b1d6,
+                0xc812325aa4b07ed6,
+                0xc7d471a2ecaeb7fa,
+                0x3beccc25a7d0ad4c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9c7e915eb74ffad8,
+                0x580153e053dcfd62,
+                0x846d9affac8bef37,
+                0x3400282182d08c76
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbe00762e8780c75e,
+                0x464dc4b7305302d6,
+                0x4e60b1fcd2f43a11,
+                0x3a3f18e601e5157e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbd66dd091c6bbd9f,
+                0x95e66ef5a22a0af6,
+                0x9b0094185c1b3a57,
+                0x3e905eb6c1a5c5dc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7b3d6555dbee7036,
+                0x55a94d4ecd935e8f,
+                0x7ab19547866850a8,
+                0x35724004977bc0d5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc6a96dc9c7275edc,
+                0x8dbc0a2d89a6f27c,
+                0xcfadff8f8c26ac41,
+                0x183eeca831feeab9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2383082f3541b9f,
+                0x63b953b31a011ef7,
+                0x9b155d48c4d5b3a9,
+                0x22a0294699da878a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb18f92edbef6acf4,
+                0x6cc8067196113df0,
+                0xbc330b660a8054c7,
+                0x2e7d6bd0a722248e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa1ecdfea12126698,
+                0x198920d2894d0547,
+                0x8f456da4792bd3f4,
+                0x3918350a02ed5380
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7251ebc79bbce4b3,
+                0xb9d0261114586031,
+                0x9a0be21e0ab5468,
+                0x17a0c303c8fedb14
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x78af91927db5e8cb,
+                0xe1279ac2fbabcf7e,
+                0x7cce0fce52d1b837,
+                0xae831ab3e61dce9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5ffc5eb6cbe4d771,
+                0x593c72c64e95261f,
+                0x4ba11487b71732be,
+                0x2f24e2ff0ae9a86
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2ad3d6d41f5a031d,
+                0x6b7eb10a865629aa,
+                0x6c72944b705dccb3,
+                0x36448bb4f063d95a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x80bd913a578997d6,
+                0xdad1801a0c1413b6,
+                0x3e48fa8ba0f1ec3b,
+                0x23a9fe923bbb7d42
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd287423d34d57044,
+                0xbdac8700dd0f3056,
+                0xc25b19d6e5f26730,
+                0x2f7c0360aa3ca9b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1dd4d1af0f0e0483,
+                0xb61ef1fda1ffa315,
+                0xb9def7c50d7a957c,
+                0x30324c0c97f890c8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2ed297e155a4141f,
+                0x36302832dfdb9a23,
+                0x535326b9e09b56a1,
+                0x2f24b6af625c7c91
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb5d0c4070676ee29,
+                0xa3b0bbf8a20cc7f8,
+                0x91c920384c8d3245,
+                0x3c9d221d7693b48e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd260c5255e15d7f8,
+                0x2561cb7bf1399fb5,
+                0xac089ebe1642e13a,
+                0x374ab8294346160f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd26fbb972cf04317,
+                0xe86a14c4aa262aad,
+                0x91e8f1a2e1551c4e,
+                0x2812a540384ea19a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x85146bb5530ee137,
+                0x6a3366e4383e2834,
+                0xbeb1ce7189406539,
+                0x25b8ab8362334842
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x48f7bdc64f20c9f3,
+                0xa7d6e959d64b094f,
+                0x340cb220bea7449,
+                0x300341258a866d69
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x83ca9d3042d83652,
+                0xbe3e6ebffbf08b23,
+                0xb691bd87a3c219e9,
+                0xcf27f774fcec51f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xae3f91aa78e47874,
+                0x672733351fb61d0b,
+                0xd56b1b0c5a04db74,
+                0x1a4162c3f88bae6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf683c72860f30f51,
+                0xb36ba7bc9761796,
+                0x9bf4b8dd6b426cc6,
+                0x204f41de13ff0783
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd904e208c6e4ee53,
+                0x9df3a253f2547793,
+                0x940d17084ee8446f,
+                0x214b69e2d7c8c816
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x27a25449c7662f3b,
+                0x63ba08c962b6fb71,
+                0xa4ce81084eb1202e,
+                0x23b8d6971bf7f38
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x575fa5a21e640696,
+                0x2e1403d37a06bd8a,
+                0x6c16596ad31c2740,
+                0x156f197a1fcf8e5f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6945dcbbb90200a9,
+                0xe87ec5317fb6b449,
+                0x6b056a3cfc7628e0,
+                0x1aa521683b30459c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5e54df6b6e94719f,
+                0x8f4b9083f19decfa,
+                0xfeb1eedf4902ca92,
+                0xb7a0e2d3209a638
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5cec9713abe8a806,
+                0x7a1911a65736ef33,
+                0xf6905c458130fb10,
+                0x393ab047fe864558
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x625887172a8394bd,
+                0x91945bca7110c751,
+                0xf3ed5792a57f2ecf,
+                0x156163451dbbf989
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe5d369dd59913955,
+                0xca7c7e951115d4d8,
+                0x87aa539be58a906a,
+                0x14e5bb3b8047339
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfc977669364a335c,
+                0x5904dfeba06df185,
+                0x386b17cffe08075,
+                0x162d242a30166978
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x22a79b81b19b23e3,
+                0x2b08f1dfcd1dcf79,
+                0xe516397b3231586,
+                0x2e1e3463c95d049a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3963bc2669c4a056,
+                0xc0e294f89484b711,
+                0xe80fd02ac48277d4,
+                0x304c09d07d5319fd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9d2715576f3caa1b,
+                0x2a2dd605d0181949,
+                0x8fe3ab53064b09a4,
+                0x315bbd1abb8a58a0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x39d4640f3edee8c9,
+                0xaa13c4714d4fde00,
+                0x5f9d2e41f37172f9,
+                0x14289f6b99d5b4b6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf8e80409ac849a27,
+                0x7d109ddd7aa6360b,
+                0x4f02fa572a1a233b,
+                0x3bc33369a1137720
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9396e1997a0db620,
+                0x5ad9befc4145d6ea,
+                0xa56d4507585d68f2,
+                0x1a8895382d200fbd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4f345e0d1d6b4581,
+                0xa3476db5e4af2174,
+                0x50772a5d05a1e126,
+                0x4c7cd2df3f78a48
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x10ddf2b4701fad35,
+                0x52f1faba6a6ac77b,
+                0xc631a6c294082b7,
+                0xf5f400c41645c4c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x32bf575f20451b4,
+                0x752b282b64b94f4,
+                0xb25793093492cd55,
+                0xc5659b38c71988f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb83d0eec5bbe17e8,
+                0x68a408cb7ff78511,
+                0x4ade42e5d00788f0,
+                0x37e20e5a90c24e32
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb35185ad48034b5e,
+                0xb2a987e0444f2307,
+                0xbfdcf0df396f91b4,
+                0x6c0174bd697be6d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x466dc5c9efb5214c,
+                0x85e662ed14fba789,
+                0xe931bc3cdf819945,
+                0x157b9dcee0c5fcce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf835a704073b196f,
+                0x2a4730f07df5164e,
+                0xa667099c4bfc8d2d,
+                0xa734397c442114c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xac4662a2fe0bef3,
+                0x16313ce3a10b1dd2,
+                0x3fa793ceccf02c8b,
+                0x19f2ea5b47823edb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe09fdf4e2f5ed779,
+                0xfa851fbc5c1d91d,
+                0x459b1467e8669c7c,
+                0x278e2f94e2fded1c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf4ac4c8e4fa54469,
+                0x10facf886694dc3,
+                0xd6c1fdb2afcdf8fc,
+                0x590a67c7290abee
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3da8963f66ec6736,
+                0xe14b0d04d23ba2cb,
+                0xf82d629dfbd25b39,
+                0x33ed1db9536ec081
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4beb52778722f38e,
+                0x1734e2db89da1783,
+                0x19f78da187f10cb4,
+                0x1bf3847987905710
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaea84610a16e7e9a,
+                0xc0c04b63f2b3fd7a,
+                0xf825bb6da3e8464a,
+                0xbb55c61038442b9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1231e25427c2bb00,
+                0xe947d678e54c6c31,
+                0x3b4cc6a3c062453a,
+                0x2d7fcee81ce03792
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe5d2851601c9eb60,
+                0x47278819d7a85460,
+                0x3e4bdb6c42b32af,
+                0x2caf914a18b598fa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5432a34d04b871cc,
+                0x5c324873c38c7676,
+                0xab0c223061164a85,
+                0x16cd2c72debed78e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbac4863b8d1c3012,
+                0xec92f32c48f3cf5,
+                0x3a861ee20a228144,
+                0x32a74ae2345a2fb7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5b6714976d8cb5af,
+                0xc073fa573d7ec3e2,
+                0x9f444093d9b00ce1,
+                0x141958a20aab77c5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x15c908f6da5e7d05,
+                0xf7fd566f962eada1,
+                0x6c61ea773372a9c7,
+                0x3f2ad195703f395d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7b059bf3cbef5ba2,
+                0xd6d78cf353d29570,
+                0x24038841c56c7968,
+                0x2f15ed96b46b0add
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbfe3142b02b0431b,
+                0xf730a277ae46fd55,
+                0x8a2bd929f37c7826,
+                0x10f8c337781dc9c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xea2ddc5e7cc9be37,
+                0xc8b9322efc0572fa,
+                0x594701dd2d6100fb,
+                0xd435e77e56f5403
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4c18d66296c4e7b,
+                0xdf67ca832b3ed7aa,
+                0x8cfa4d7cc057cf81,
+                0x2b0a260c4ad9b82c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x145308a5dd0e59cf,
+                0x130f39752acdf191,
+                0xee321dc360ea1971,
+                0x34e531e4039dc4c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfa1e1734ab598dd9,
+                0x6f71d0801352558a,
+                0x42ebbc9f239b2db0,
+                0x1bcc33e2dbdbddfe
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x34dbb74a01ae24a4,
+                0x4f09dc676a4af07c,
+                0xd4b6ded8613d30fe,
+                0x90c3d27768d3387
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x10bf23ef1b8c46c3,
+                0xb681f0fa74968f99,
+                0xf28f4b9f101cf3c5,
+                0x1b0985bb77149ce1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb400150a10cf53dd,
+                0x357c2f838c9c53ee,
+                0x3d47cc88e87dfb03,
+                0x1067a38325af4546
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x315d7834ea966ed,
+                0x5167890aeebf1d3b,
+                0x700fc1def2be1c21,
+                0x353fbd16aff8a089
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb6eddb5674216636,
+                0x7626e52fa386920f,
+                0x8d1b98d1a9c79b10,
+                0x160ef99eb0e3e4d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x91ac1f64cb2a43c4,
+                0x5b1654737ac30aad,
+                0xaa9be6cb74ef14e8,
+                0x3876da588783166e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x94f4f7f068f2d6d,
+                0x3e6d7db8b31752ac,
+                0xee3a39f00085ef19,
+                0x254614b5e3680d7d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x156b6991b4f3d41e,
+                0x7687421a914d7bdd,
+                0xd02d11407dd90531,
+                0x1952b64bdb93093e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdbfe1b715cfe5a21,
+                0x457b9645f0dbcb87,
+                0xad6272523192d8ec,
+                0x35bd00428e0ab925
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe12213e3a9b68350,
+                0xca6a957191e30ae9,
+                0xdf542499cd407057,
+                0x3ea0cc6aefc9e2d4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcbf5a5c8a67f98da,
+                0x73e9ca7db2e5ba0f,
+                0xcdd7bc16a84e86eb,
+                0x1a763e77e4502bc7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x42eb2d6d1a266250,
+                0xe0674f9153e7b097,
+                0x65d2f83bbdb377ca,
+                0xe0a3afa3514302c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb6abe99bde45c5c,
+                0x73cf191a21bc7987,
+                0x4143ed6ea8e60952,
+                0x1dacb79928dcef52
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3c7ea8e718b4e1c1,
+                0xcd6a6530ffa351a0,
+                0x828a19d5bbc09218,
+                0xbf580a744ff2d13
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd45a33e256bdfd3f,
+                0x140eafd599a41ebc,
+                0x147b7d9efb06c058,
+                0x174274e8f0371a51
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x33afa71ff47536a0,
+                0x8e7f489cfdf18b58,
+                0xc9eac7786aec95fa,
+                0x359b93c1144192c3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc9360d091c9f728d,
+                0x27438d970f9c13a3,
+                0x6285ed1358ad047c,
+                0x2fc01e24d3fa074d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf31ab0a6381e1bd1,
+                0x115c671db13b02fd,
+                0xd8cee23659de66a0,
+                0x32ca6bb91cb2365b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf4da36d4c102e374,
+                0xdde9b1000ad498d1,
+                0x854c5b7b734331ce,
+                0x325b8d3f23a0745d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x193d95ce3ddeee7a,
+                0x62a7e1451e6ff2f5,
+                0xc713a6e21ed97b11,
+                0x17660de4f228ff3c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x40e63492f757227b,
+                0xc94394cd076d6ea9,
+                0xc33c1a78903c5d05,
+                0x262437b974c843fb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x16d5086c74528587,
+                0x3757398908a1c5f5,
+                0x9a26736504465f2c,
+                0x29c5aa66237363d0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2e0d53a8c1038d91,
+                0xeefa00c06add4e8f,
+                0xcac57d63de94def0,
+                0x2987aa900364ee03
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xfddd62d552ba6a77,
+                0xe7a17365a61acd68,
+                0x14550f28a28abf40,
+                0xca6080ddd4b40b6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x515ab335eba5bde5,
+                0x1d25112db6bbde00,
+                0x83f344878f6c7abb,
+                0xd32d93c63440ee0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa2779381cec67c11,
+                0x2049b548fce6e4fe,
+                0x1299ede226d47ea0,
+                0x27d43002018537c9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8ddc0658ed472bfd,
+                0x7f7417831665edfa,
+                0xcae92eeac4552f45,
+                0xc1572d819016fd2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xae73a463e4e34cc3,
+                0x7414d8b012c7de04,
+                0xbcf89b752c568aa9,
+                0x17e3fe8cb469965e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x559ad3d92ae4339d,
+                0x469561d1c45d3b3a,
+                0xc4ff49a5ce98408d,
+                0x1c4067b38114f920
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5061871499fd048b,
+                0xa6addcd3523f9174,
+                0x3b5fd830a8ebe486,
+                0x23a1177c4b5d3559
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1cdc56da55ee573,
+                0xa59c1bfd16050972,
+                0x641587c23f3856c2,
+                0x2c6205ca2f32b14d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3b770fd837ce9748,
+                0x36524e0706ae1207,
+                0xdad2710e5dbe82fb,
+                0x37575fb530763195
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2f4424e587dcb31f,
+                0xaf7aa1771801be28,
+                0x626dce567ef00e86,
+                0x4dbefa24024447a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x78f920ea2d530889,
+                0x992ff28c9a794807,
+                0xadd940d7ff42038e,
+                0x114e54c1ad71037
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6cac9b08c6698115,
+                0xf1884465d230d1e7,
+                0x2a0d3d659fb6e6fc,
+                0xe207c7ebf4818b2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x972e08382a88b412,
+                0x1f62804e85d2806e,
+                0x4628c77111549f07,
+                0x32a36c8305e824e9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x42c4db3f0e76b7af,
+                0x95b2d53b66cb9c20,
+                0x501c1b2c479feaf2,
+                0x11e1bb6d6c483512
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7b452324a7dc5495,
+                0xb24f4ed24c211557,
+                0x2b5dd372e87b2b81,
+                0x22ab18972d147850
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbe8fbcd4c3f34bac,
+                0x55fdb55bed0bb37e,
+                0xc9117dae8b20fc36,
+                0x25626001de0c2592
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2a8761f7b4889759,
+                0x5485fac42d9b4d32,
+                0xfc59d20255289124,
+                0x1b8d66d16c78d975
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb31e345521cd86a,
+                0x14e087425942983,
+                0x241eabdb23b3fe97,
+                0x28857ec94da19dfd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8dbbe941f9ffc37,
+                0xdcc4f340c1f9be14,
+                0x7b57cba9a546a716,
+                0x370d3092328761ce
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x4eed6dc5497ef2c5,
+                0x4f02ca2506da6721,
+                0xcba9538b3a3bb79e,
+                0x337b37b7ad1ca037
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf9b2fd5cf9d2084,
+                0x8aabbf2222a9a7dd,
+                0xf4b6fda17214d8f7,
+                0xc3b31134f47b64e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5200138d6807bdb4,
+                0x847077938249973d,
+                0x660e1a71e2e94791,
+                0x214aa86cf860d14c
+            ])
+        ),
+    ];
+
+    // The MDS matrix constants
+    const MDS_CST: &'static [TweedleFq] = &[
+        // Constants in Montgomery representation
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x740bdceae58123b5,
+                0x9f4d81acc7f5ecce,
+                0xfbf47264914e81b,
+                0x1a902e97410d7ad7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa81ee1dd2cefb947,
+                0x682ac74202221f2e,
+                0x8836375d549523b7,
+                0x399a5367e92fce3b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6fc00203c89fcfb5,
+                0x5f4e8b139508f7ca,
+                0xec93984d74ffae2f,
+                0x2f5e7feb6a7a6f9c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6867c1b4ec00f744,
+                0xb1b4331608c174d4,
+                0xc2e7fe55de644bf8,
+                0x360b19672acb23d3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb345d0963ff98752,
+                0x7b85663cfa86deb7,
+                0xdf36f58d38995358,
+                0x17e343fac626edf9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x81d7b34692d6c084,
+                0xb27fe5fe0401ff28,
+                0xfb8885ae5b6759d9,
+                0x2054a12937ed61d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa18a9fd88a7e4446,
+                0xeca8b61ef7be611b,
+                0xca84a63f064e096a,
+                0x306877494a18473c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4576ed24a5f7674,
+                0x5c23edafa15fd485,
+                0x77e168cac9a6f825,
+                0x13be7b4e74ee5e87
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x756fd905b21785,
+                0xa5f1ebc786d5e518,
+                0xb8c905788bea857a,
+                0x2df79c0792fc89b1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbeb7b8626ec4c334,
+                0x5e842f2a700d6d1b,
+                0xf041e95fc13faa44,
+                0x33abaa4655a775e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8b12dccd32a211a,
+                0x9e0f34946bc6c8e8,
+                0x3762e64dd405adc2,
+                0x2acf8a19e1cc98d4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8210cb9aa976b95,
+                0x7a972ed8a4931453,
+                0x521f5e7b8a3c94d6,
+                0x1cf28e196a7e23b3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3d90e791bf92249e,
+                0x987f06187900cd18,
+                0x48ff822cbac4c983,
+                0x3930755dc3c8d7fb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7aeabf71c396f5fe,
+                0x28dfa8b1d5a89643,
+                0xc4a6ccc1af41e81e,
+                0x3714d24e06935094
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x84b2150ea34025a8,
+                0xb25a2323382104d,
+                0x50d802389858e681,
+                0x19f15dab612e3284
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcc9fde5147381d10,
+                0x992ab2ccefed0dd4,
+                0x8f2d9eba9e3f5673,
+                0xc9f2ee7bd2a48a8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xee9672c1ceb579f4,
+                0x3c35fafc7d7f0702,
+                0x889e4da5a3cc2ec0,
+                0x2572775d75c2b0a9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x752494a785144f51,
+                0xfb575344b04f288b,
+                0x23a78f0b30387426,
+                0x14e5e16dfb9c93c1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2a27f9a1a62848e1,
+                0x82d1683d66136ae9,
+                0xc2a93a4506ead830,
+                0x3ab5c92df02b23df
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9aebc40347994c69,
+                0x5ac0cfc6cea2fbd6,
+                0xf0b3f9f615d062f0,
+                0x1cf4682c60efa719
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdf189e480277259,
+                0x9078d188144c57ff,
+                0x4148c1fb37ee6914,
+                0xe6469c2a3ad3a80
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcf46233817abdd2,
+                0xbae0b6052dca22a8,
+                0xd4e5264909dc0ac5,
+                0x1c1a5eb1f5aee6d7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x846c57644ae603c2,
+                0x4fe35091e54890f3,
+                0x8c2fb7d29ff0cdfd,
+                0x29ebff5b2abbf5f5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb93e0fbdc7811721,
+                0x794bde5a6a0c454f,
+                0x20fd1f748bdcd493,
+                0x270edda53c19c904
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c377febe48237be,
+                0x76d7f86b24c51acd,
+                0x326497204ba2a6eb,
+                0x10df6fa736e77c36
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x76adfe27a340f692,
+                0xe20c8179865ad76,
+                0xda275f4df1c9e1d,
+                0x3932086636233597
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeb333d478bd4e891,
+                0xd24440a375de9bc3,
+                0x90c87d272089e025,
+                0x16c32be939edd060
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb8b2664696365414,
+                0x243562121bdb6a4d,
+                0x40433d1d9a5689a,
+                0xc3063d8e510f58
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdbc2299b8e88980e,
+                0xa8c994fdce47f45b,
+                0xb37befae426f3ac1,
+                0x3aadb31935221ad6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x75438a591dfa3ee2,
+                0x48e7432a4f31d988,
+                0xae943b9c0c67dc5f,
+                0x24fb315a457f7ab0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdeb624eeb3f23ff4,
+                0xad325c52018f0173,
+                0x4c92b258e31145c3,
+                0x537b19965084760
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5eb6879fd118203f,
+                0x409343cd3ec20e31,
+                0x7dcff1d0a51a02cf,
+                0xaf6fc2205759a30
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x77d2bb3fb99e2407,
+                0x25516d1eb67ce563,
+                0xc36a76688f92b15f,
+                0x31b866e92fa96c03
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3f29349800207bef,
+                0x93c3227325cd8d01,
+                0x6a9a6822eed74a66,
+                0x2fcf749f0e33e8f4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcfe2dc7d2f30ef18,
+                0xad89fe79f5d8f425,
+                0xa1bf06bc7592ec76,
+                0x17e879bb6b10cf41
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xadc0fdee95d90b06,
+                0x18fe0d670a292ad,
+                0x236f7de2de1d1cda,
+                0x1c6419613175ba25
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdcccec12b38dee7d,
+                0xd5ef3b2ac8808da3,
+                0x6899b89ce533ffe6,
+                0x3db29277d41e6a09
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1af17cf46550593e,
+                0xd25a00dbef881699,
+                0x4e65338c8629f748,
+                0x2c14536e1f9f142b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc80674260682661d,
+                0xfd1ba1409640958f,
+                0x90de70f38e2dc9b0,
+                0xc4e3852a871666
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7b4ca6fd4c1cb96a,
+                0xaa491dedea7852cf,
+                0x24a5ee9758b128c7,
+                0x39ef1879b8451d83
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x797200e15f13c9fb,
+                0xbed17f74caa67618,
+                0x5d7621ffac5b363,
+                0xa7e9e36f7b6e984
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcb178451df7652a0,
+                0x423d049ed6ff16db,
+                0xd6372dd395809240,
+                0x1da35d8c5731f50f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x62da4e5797b2fa7e,
+                0x955cc0434ea0e3c7,
+                0x38ec43cfeadb91d5,
+                0xd7a92a05ac84f6a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x62934c2f3c9f68ad,
+                0xa581b2ac37ef6a00,
+                0x62a4945bdd9a8c2b,
+                0x112e88d4e50e944e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x22c1d1f310dd750,
+                0xb1ad6a4bf2e32b1b,
+                0x3bcbaff149155249,
+                0x27607b440cbff6fc
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c752f7d2fad5dda,
+                0xd1f3e1a900f7e398,
+                0x3b668ffe36c19b1c,
+                0x13d994484d2c8e18
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x86fa015f0040ddb1,
+                0x6214ec0ddf1b0ca1,
+                0x1772e37303444e78,
+                0xb73ff017d85c1aa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x55a1973fd6b6a555,
+                0xdbf14463af8c0647,
+                0xb6fa48f35db157ce,
+                0xb4f09f1eea8099
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xecc3572e8919529f,
+                0x192f10fdd1fe99d4,
+                0x45eb5194ff3d9565,
+                0x2d2cda59c5572b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf114cc229747427d,
+                0xfc003b97256141ea,
+                0x2b8ea48bfc6bfb17,
+                0x3f4bb930b29f14f8
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x78c8058ca2bef125,
+                0xf95c8d8bc053b1c8,
+                0x9583dcd808be3b3d,
+                0x100bac0e776624ee
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf7e65e9ca4a179fd,
+                0x2dd82e62a3a69513,
+                0x350264ff7c6aedec,
+                0x1086008dda3bc9a9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x82e4c8c9f7e59f69,
+                0xe2697c814f13b7c5,
+                0x2e9e11fdc914d268,
+                0x32e451280de4105f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb3234a532fcd62a3,
+                0x85c2d5b1e355966e,
+                0x903a4a53cf8cc7f6,
+                0x15d33762e257b94e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x468175289c892574,
+                0xe7fb85c89bc3e323,
+                0x2609fe2ef9b3874,
+                0x96764e2a4a8a327
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5eda42f3a4016b94,
+                0x15e0d0e56914f47d,
+                0x9280f6e5fc9aa360,
+                0x243b3ecf4c1cb52
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x89bd7c37274fe34f,
+                0x362bb21c6122f158,
+                0x168549132afabf62,
+                0x28a8a971a8ce92ff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2d64fdef957dbf40,
+                0xab87e159b322e10d,
+                0x12c80081925638f8,
+                0x2c00fa710b103bea
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5bc059199fa51aac,
+                0x54811d418aa88da5,
+                0x8d726d1fa9d34ee2,
+                0x1196365c98154093
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe20a5685f3779111,
+                0xc027c9252aea9e5c,
+                0xbc663683b2892dc7,
+                0x34f85b00d0af5f43
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf71511705c4e316e,
+                0xe0fa44dfc4f20a6b,
+                0xc40cf58a47cef0f0,
+                0xa90b7717f6869c4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd26165cd5bc16da,
+                0xf507da793d6f7265,
+                0x3c03ce50180dabad,
+                0xb6cd26694cd4160
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc921409f1a150db6,
+                0x74f7d7756f77466a,
+                0x220f4c314eb0c289,
+                0x35d7d298bf9c3072
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa1cf4034b762c608,
+                0xb601b199722ad174,
+                0x8ebd1d1d660a70bc,
+                0xf3276d1c8713e55
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3147c80ae5dcf190,
+                0x95ef60d5afced665,
+                0x2d9d5531432f2568,
+                0x39ac945becb3619a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x79ca953d43c3c38a,
+                0x3ff5cdf097b7676c,
+                0x8bd2a09adb62c1dd,
+                0x6d24c00a56b2ac7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xade36b486d6c0869,
+                0xa488f9ce818bb9c3,
+                0xa4e8e052a012bd69,
+                0x395422c74680af0e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe08cd6dca261a536,
+                0x29484d50089f1cf5,
+                0x2a3f02ae696925f9,
+                0x2d1f3562ac3defb9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x373a99b1024ac324,
+                0x5fa366df2965ad76,
+                0xc926b13a460b9be7,
+                0x9d542dd126a0c1f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcbb3ae69e7d26c43,
+                0x6a66cf22df6982e9,
+                0x7c9575d6197c39d4,
+                0x403badc5b7160b9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x459900f3bc4ea625,
+                0x1d8952055d79b564,
+                0x253b5d564060ced,
+                0x1836e8f21538f96c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xabe5cc21902432c1,
+                0xb9c88a8dd515f6b8,
+                0x9d4badde5e4bfa10,
+                0x21769e9162315ee
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x79f2e8f173b320fb,
+                0xb18b3d2a93b79ab8,
+                0x85f26671cbb453f1,
+                0x1123689a43e8f904
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf87829baacae939e,
+                0x78e96747df662bea,
+                0x466d43b32d13020f,
+                0x20aba9e23da4ff48
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xbb79c2e58329b23d,
+                0x542297e235eeda34,
+                0x18cd0212723db26b,
+                0x1c1a2bda4edabdcd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb62fbbe71251750,
+                0x564c4b6cad960f36,
+                0xf138468ccb22200e,
+                0x3e11c008f09299b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x509e3dbba94a46a9,
+                0x61546b6bc2a1e66c,
+                0xb98156eeafd6bf01,
+                0x38c096e3c142e691
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb9e072882940ddf,
+                0x9bee9940ac6f24d4,
+                0xf2dd5a2925bb3017,
+                0x19f1fd0dc03d08f9
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x14cbe93ea6e8cd9e,
+                0x98343a329029abe1,
+                0xc07d84510cfb7d7b,
+                0x3a1cea582265fdcd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xce6c2effefb41222,
+                0x884da3fd4bca1324,
+                0xc5485dcb3c1ec8ac,
+                0x78b01a448ab645e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5127d4e2ab1726d,
+                0x12bb215ab70aae0d,
+                0x47a88176ff95327f,
+                0x32058187e3a8c74c
+            ])
+        ),
+    ];
+}
+
+pub type TweedleFqQuinticSboxRate8 =
+    PoseidonQuinticSBox<TweedleFq, TweedleFqPoseidonParametersRate8>;
+pub type TweedleFqPoseidonHashRate8 =
+    PoseidonHash<TweedleFq, TweedleFqPoseidonParametersRate8, TweedleFqQuinticSboxRate8>;
+pub type TweedleFqBatchPoseidonHashRate8 =
+    PoseidonBatchHash<TweedleFq, TweedleFqPoseidonParametersRate8, TweedleFqQuinticSboxRate8>;
@@ -42,7 +42,7 @@ impl<F: PrimeField, P: PoseidonParameters<Fr = F>> SBox for PoseidonInverseSBox<
             let mut w_bar = accum_prod.inverse().unwrap();
 
             // Extract the individual inversions
-            let mut idx: i64 = w.len() as i64 - P::R as i64;
+            let mut idx: i64 = w.len() as i64 - 2;
             for d in state.iter_mut().rev() {
                 let tmp = d.clone();
                 *d = w_bar * &w[idx as usize];
@@ -93,7 +93,7 @@ impl<F: PrimeField, P: PoseidonParameters<Fr = F>> BatchSBox for PoseidonInverse
             let mut w_bar = accum_prod.inverse().unwrap();
 
             // Extract the individual inversions
-            let mut idx: i64 = w.len() as i64 - P::R as i64;
+            let mut idx: i64 = w.len() as i64 - 2;
             for i in (0..vec_state.len()).rev() {
                 for j in (0..P::T).rev() {
                     let vec_1 = vec_state[i][j].clone();
@@ -132,7 +132,7 @@ impl<F: PrimeField, P: PoseidonParameters<Fr = F>> BatchSBox for PoseidonInverse
             let mut w_bar = accum_prod.inverse().unwrap();
 
             // Extract the individual inversions
-            let mut idx: i64 = w.len() as i64 - P::R as i64;
+            let mut idx: i64 = w.len() as i64 - 2;
             for i in (0..vec_state.len()).rev() {
                 let vec_1 = vec_state[i][0].clone();
                 vec_state[i][0] = w_bar * &w[idx as usize];
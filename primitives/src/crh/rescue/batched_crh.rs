@@ -0,0 +1,134 @@
+use crate::crh::{
+    rescue::{RescueHash, RescueParameters},
+    BatchFieldBasedHash,
+};
+use crate::{BatchSBox, CryptoError, Error};
+use algebra::PrimeField;
+use rayon::prelude::*;
+use std::marker::PhantomData;
+
+pub struct RescueBatchHash<
+    F: PrimeField,
+    P: RescueParameters<Fr = F>,
+    SB: BatchSBox<Field = F, Parameters = P>,
+    SBInv: BatchSBox<Field = F, Parameters = P>,
+> {
+    _field: PhantomData<F>,
+    _parameters: PhantomData<P>,
+    _sbox: PhantomData<SB>,
+    _inverse_sbox: PhantomData<SBInv>,
+}
+
+impl<F, P, SB, SBInv> RescueBatchHash<F, P, SB, SBInv>
+where
+    F: PrimeField,
+    P: RescueParameters<Fr = F>,
+    SB: BatchSBox<Field = F, Parameters = P>,
+    SBInv: BatchSBox<Field = F, Parameters = P>,
+{
+    fn check_input(input_array: &[F]) -> Result<(), Error> {
+        if input_array.len() % P::R != 0 {
+            Err(Box::new(CryptoError::Other(
+                "The length of the input data array is not a multiple of the rate.".to_owned(),
+            )))?
+        }
+
+        if input_array.is_empty() {
+            Err(Box::new(CryptoError::Other(
+                "Input data array does not contain any data.".to_owned(),
+            )))?
+        }
+        Ok(())
+    }
+
+    fn apply_permutation(input_array: &[F]) -> Vec<Vec<F>> {
+        // Initialize each state vector with the permutation of the zero state vector
+        // and add the input data in chunks of rate size
+        let mut state = input_array
+            .chunks(P::R)
+            .map(|chunk| {
+                let mut state = P::AFTER_ZERO_PERM.to_vec();
+                state
+                    .iter_mut()
+                    .zip(chunk.iter())
+                    .for_each(|(s, input)| *s += input);
+                state
+            })
+            .collect::<Vec<_>>();
+
+        // Calculate the chunk size to split the state vector
+        let cpus = rayon::current_num_threads();
+        let chunk_size = (state.len() as f64 / cpus as f64).ceil() as usize;
+
+        // apply permutation to different chunks in parallel
+        state.par_chunks_mut(chunk_size).for_each(|p1| {
+            Self::rescue_perm_gen(p1);
+        });
+
+        state
+    }
+
+    pub fn rescue_perm_gen(vec_state: &mut [Vec<P::Fr>]) {
+        // index that goes over the round constants
+        let mut round_cst_idx: usize = 0;
+
+        for _i in 0..P::R_N {
+            // First half: S-Box, matrix mix and round constants
+            SB::apply_full_batch(vec_state);
+            Self::matrix_mix_and_add_round_constants(vec_state, &mut round_cst_idx);
+
+            // Second half: inverse S-Box, matrix mix and round constants
+            SBInv::apply_full_batch(vec_state);
+            Self::matrix_mix_and_add_round_constants(vec_state, &mut round_cst_idx);
+        }
+    }
+
+    fn matrix_mix_and_add_round_constants(vec_state: &mut [Vec<P::Fr>], round_cst_idx: &mut usize) {
+        for state in vec_state.iter_mut() {
+            P::matrix_mix(state);
+            P::add_round_constants(state, &mut round_cst_idx.clone());
+        }
+        *round_cst_idx += P::T;
+    }
+}
+
+impl<F, P, SB, SBInv> BatchFieldBasedHash for RescueBatchHash<F, P, SB, SBInv>
+where
+    F: PrimeField,
+    P: RescueParameters<Fr = F>,
+    SB: BatchSBox<Field = F, Parameters = P>,
+    SBInv: BatchSBox<Field = F, Parameters = P>,
+{
+    type Data = F;
+    type BaseHash = RescueHash<F, P, SB, SBInv>;
+
+    fn batch_evaluate(input_array: &[F]) -> Result<Vec<F>, Error> {
+        Self::check_input(input_array)?;
+
+        // The output of each hash is the first element of the corresponding state vector
+        Ok(Self::apply_permutation(input_array)
+            .into_iter()
+            .map(|state| state[0])
+            .collect())
+    }
+
+    fn batch_evaluate_in_place(input_array: &mut [F], output_array: &mut [F]) -> Result<(), Error> {
+        Self::check_input(input_array)?;
+
+        if output_array.len() != input_array.len() / P::R {
+            Err(Box::new(CryptoError::Other(format!(
+                "Output array size must be equal to input_array_size/rate. Output array size: {}, Input array size: {}, Rate: {}",
+                output_array.len(),
+                input_array.len(),
+                P::R
+            ))))?
+        }
+
+        let state = Self::apply_permutation(input_array);
+        output_array
+            .iter_mut()
+            .zip(state)
+            .for_each(|(output, state)| *output = state[0]);
+        Ok(())
+    }
+}
@@ -0,0 +1,420 @@
+//! Rescue-Prime hash function, as described in https://eprint.iacr.org/2020/1143.pdf.
+//! The sponge construction (padding, personalization and output) is the same used by
+//! `PoseidonHash`, only the permutation changes.
+use algebra::{Field, PrimeField};
+
+use std::marker::PhantomData;
+
+use crate::{
+    crh::{FieldBasedHash, FieldBasedHashParameters, SBox},
+    CryptoError, Error,
+};
+
+pub mod batched_crh;
+pub use self::batched_crh::*;
+
+pub mod parameters;
+pub use self::parameters::*;
+
+pub mod sbox;
+pub use self::sbox::*;
+
+pub trait RescueParameters: 'static + FieldBasedHashParameters + Clone {
+    const T: usize; // Size of the state
+    const R_N: i32; // Number of rounds
+    const ZERO: Self::Fr; // The zero element in the field
+    const ALPHA_INV: &'static [u64]; // Exponent of the inverse S-Box, i.e. alpha^-1 mod p - 1
+    const AFTER_ZERO_PERM: &'static [Self::Fr]; // State vector after a zero permutation
+    const ROUND_CST: &'static [Self::Fr]; // Array of round constants, 2 * T per round
+    const MDS_CST: &'static [Self::Fr]; // The MDS matrix
+
+    /// Add round constants to `state` starting from `start_idx_cst`, modifying `state` in place.
+    #[inline]
+    fn add_round_constants(
+        state: &mut [<Self as FieldBasedHashParameters>::Fr],
+        start_idx_cst: &mut usize,
+    ) {
+        for d in state.iter_mut() {
+            let rc = Self::ROUND_CST[*start_idx_cst];
+            *d += &rc;
+            *start_idx_cst += 1;
+        }
+    }
+
+    /// Perform matrix mix on `state`, modifying `state` in place.
+    #[inline]
+    fn matrix_mix(state: &mut Vec<<Self as FieldBasedHashParameters>::Fr>) {
+        let mut new_state = vec![<Self as FieldBasedHashParameters>::Fr::zero(); Self::T];
+
+        for (i, row) in Self::MDS_CST.chunks(Self::T).enumerate() {
+            for (x, m) in state.iter().zip(row.iter()) {
+                new_state[i] += &(*x * m);
+            }
+        }
+        *state = new_state;
+    }
+}
+
+/// The Rescue-Prime hash, whose rounds consist of a step with the S-Box `SB`
+/// followed by a step with its inverse `SBInv`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct RescueHash<
+    F: PrimeField,
+    P: RescueParameters<Fr = F>,
+    SB: SBox<Field = F, Parameters = P>,
+    SBInv: SBox<Field = F, Parameters = P>,
+> {
+    state: Vec<F>,
+    pending: Vec<F>,
+    input_size: Option<usize>,
+    updates_ctr: usize,
+    mod_rate: bool,
+    _parameters: PhantomData<P>,
+    _sbox: PhantomData<SB>,
+    _inverse_sbox: PhantomData<SBInv>,
+}
+
+impl<F, P, SB, SBInv> RescueHash<F, P, SB, SBInv>
+where
+    F: PrimeField,
+    P: RescueParameters<Fr = F>,
+    SB: SBox<Field = F, Parameters = P>,
+    SBInv: SBox<Field = F, Parameters = P>,
+{
+    fn _init(constant_size: Option<usize>, mod_rate: bool, personalization: Option<&[F]>) -> Self {
+        let mut instance = Self {
+            state: P::AFTER_ZERO_PERM.to_vec(),
+            pending: Vec::with_capacity(P::R),
+            input_size: constant_size,
+            updates_ctr: 0,
+            mod_rate,
+            _parameters: PhantomData,
+            _sbox: PhantomData,
+            _inverse_sbox: PhantomData,
+        };
+
+        // Personalization is applied as in PoseidonHash: it is absorbed, padded as a variable
+        // length input, and the resulting state is used as initial state.
+        if let Some(personalization) = personalization {
+            let mut personalization_instance = Self::init_variable_length(false, None);
+
+            for &p in personalization.iter() {
+                personalization_instance.update(p);
+            }
+
+            personalization_instance.update(F::one());
+            while !personalization_instance.pending.is_empty() {
+                personalization_instance.update(F::zero());
+            }
+
+            instance.state = personalization_instance.state;
+        }
+        instance
+    }
+
+    #[inline]
+    fn apply_permutation_if_needed(&mut self) {
+        if self.pending.len() == P::R {
+            for (input, state) in self.pending.iter().zip(self.state.iter_mut()) {
+                *state += input;
+            }
+            Self::rescue_perm(&mut self.state);
+            self.pending.clear();
+        }
+    }
+
+    #[inline]
+    fn get_hash(mut state: Vec<F>, inputs: Vec<F>) -> F {
+        for (input, s) in inputs.iter().zip(state.iter_mut()) {
+            *s += input;
+        }
+        Self::rescue_perm(&mut state);
+        state[0]
+    }
+
+    #[inline]
+    fn pad_and_finalize(&self) -> F {
+        // Constant input length instance: pad with 0s up to rate, if needed
+        if self.input_size.is_some() {
+            if self.pending.is_empty() {
+                self.state[0]
+            } else {
+                Self::get_hash(self.state.clone(), self.pending.clone())
+            }
+        }
+        // Variable input length instance: pad with a single 1 and then 0s up to rate,
+        // unless the input is declared to be modulus rate
+        else if self.mod_rate {
+            self.state[0]
+        } else {
+            let mut pending = self.pending.clone();
+            pending.push(F::one());
+            Self::get_hash(self.state.clone(), pending)
+        }
+    }
+
+    pub(crate) fn rescue_perm(state: &mut Vec<F>) {
+        // index that goes over the round constants
+        let round_cst_idx = &mut 0;
+
+        for _i in 0..P::R_N {
+            // First half: S-Box, matrix mix and round constants
+            SB::apply_full(state);
+            P::matrix_mix(state);
+            P::add_round_constants(state, round_cst_idx);
+
+            // Second half: inverse S-Box, matrix mix and round constants
+            SBInv::apply_full(state);
+            P::matrix_mix(state);
+            P::add_round_constants(state, round_cst_idx);
+        }
+    }
+}
+
+impl<F, P, SB, SBInv> FieldBasedHash for RescueHash<F, P, SB, SBInv>
+where
+    F: PrimeField,
+    P: RescueParameters<Fr = F>,
+    SB: SBox<Field = F, Parameters = P>,
+    SBInv: SBox<Field = F, Parameters = P>,
+{
+    type Data = F;
+    type Parameters = P;
+
+    fn init_constant_length(input_size: usize, personalization: Option<&[Self::Data]>) -> Self {
+        Self::_init(
+            Some(input_size),
+            input_size % P::R == 0, // Not taken into account, can be any
+            personalization,
+        )
+    }
+
+    fn init_variable_length(mod_rate: bool, personalization: Option<&[Self::Data]>) -> Self {
+        Self::_init(None, mod_rate, personalization)
+    }
+
+    fn update(&mut self, input: Self::Data) -> &mut Self {
+        self.pending.push(input);
+        self.updates_ctr += 1;
+        self.apply_permutation_if_needed();
+        self
+    }
+
+    fn finalize(&self) -> Result<Self::Data, Error> {
+        let error_condition =
+            // Constant input length instance, but the size of the input is different from the declared one
+            (self.input_size.is_some() && self.updates_ctr != self.input_size.unwrap())
+            ||
+            // Variable modulus rate input length instance, but the size of the input is not modulus rate
+            (self.input_size.is_none() && self.mod_rate && self.updates_ctr % P::R != 0);
+
+        if error_condition {
+            Err(Box::new(CryptoError::HashingError(
+                "attempt to finalize with an input of invalid size".to_owned(),
+            )))
+        } else {
+            Ok(self.pad_and_finalize())
+        }
+    }
+
+    fn reset(&mut self, personalization: Option<&[Self::Data]>) -> &mut Self {
+        let new_instance = Self::_init(self.input_size, self.mod_rate, personalization);
+        *self = new_instance;
+        self
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::*;
+    use crate::crh::{
+        test::{constant_length_field_based_hash_test, variable_length_field_based_hash_test},
+        BatchFieldBasedHash,
+    };
+    use algebra::{
+        fields::tweedle::{Fq as TweedleFq, Fr as TweedleFr},
+        FpParameters, SquareRootField,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use sha3::{
+        digest::{ExtendableOutput, Update, XofReader},
+        Shake256,
+    };
+    use std::str::FromStr;
+
+    fn generate_inputs<F: PrimeField>(num: usize) -> Vec<F> {
+        (1..=num).map(|i| F::from(i as u32)).collect()
+    }
+
+    // Derive the parameters as in the reference implementation of the Rescue-Prime
+    // specification, for m = 3, capacity = 1 and security level 128, from the decimal
+    // modulus `p` of `F`.
+    fn check_parameters<F: PrimeField + SquareRootField, P: RescueParameters<Fr = F>>(p: &str) {
+        let (t, n) = (P::T, P::R_N as usize);
+        assert_eq!(F::from_str(p).ok(), Some(F::zero()));
+
+        // Round constants: (bit size of p / 8) + 2 bytes little endian integers, reduced
+        // modulo p, read from the SHAKE256 output on the seed
+        let bytes_per_int = F::Params::MODULUS_BITS as usize / 8 + 2;
+        let mut shake = Shake256::default();
+        shake.update(format!("Rescue-XLIX({},{},{},{})", p, t, 1, 128).as_bytes());
+        let mut bytes = vec![0u8; bytes_per_int * 2 * t * n];
+        shake.finalize_xof().read(&mut bytes);
+        let round_cst = bytes
+            .chunks(bytes_per_int)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .rev()
+                    .fold(F::zero(), |acc, &b| acc * F::from(256u32) + F::from(b))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(round_cst.as_slice(), P::ROUND_CST);
+
+        // MDS matrix: the transpose of the right half of the echelon form of the t x 2t
+        // Vandermonde matrix V[i][j] = g^(i * j), g the smallest primitive element.
+        // 2 and 3 are squares, hence g = 5.
+        assert!(F::from(2u8).legendre().is_qr() && F::from(3u8).legendre().is_qr());
+        assert!(F::from(5u8).legendre().is_qnr());
+        let g = F::from(5u8);
+        let mut v = (0..t)
+            .map(|i| {
+                (0..2 * t)
+                    .map(|j| g.pow([(i * j) as u64]))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        for c in 0..t {
+            let inv = v[c][c].inverse().unwrap();
+            v[c].iter_mut().for_each(|x| *x *= &inv);
+            for r in (0..t).filter(|&r| r != c) {
+                let f = v[r][c];
+                let pivot_row = v[c].clone();
+                v[r].iter_mut()
+                    .zip(pivot_row.iter())
+                    .for_each(|(x, y)| *x -= &(f * y));
+            }
+        }
+        let mds = (0..t)
+            .flat_map(|i| (0..t).map(|j| v[j][t + i]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(mds.as_slice(), P::MDS_CST);
+
+        // alpha * alpha^-1 = 1 mod p - 1, i.e. the S-Boxes are one the inverse of the other
+        let x = F::from(12345u64);
+        assert_eq!(x.pow([5u64]).pow(P::ALPHA_INV), x);
+    }
+
+    // Check the permutation of (0, 1, 2) against the one of the reference implementation
+    fn check_permutation<F: PrimeField, H>(perm: H, expected: [&str; 3])
+    where
+        H: Fn(&mut Vec<F>),
+    {
+        let mut state = vec![F::zero(), F::one(), F::from(2u8)];
+        perm(&mut state);
+        let expected = expected
+            .iter()
+            .map(|x| F::from_str(x).ok().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(state, expected);
+    }
+
+    fn test_routine<F: PrimeField, H: FieldBasedHash<Data = F>>(num_samples: usize) {
+        let rate = <H::Parameters as FieldBasedHashParameters>::R;
+        for i in 0..num_samples {
+            let ins = generate_inputs::<F>(i + 1);
+
+            {
+                let mut digest = H::init_constant_length(i + 1, None);
+                constant_length_field_based_hash_test::<H>(&mut digest, ins.clone());
+            }
+
+            {
+                let mod_rate = (i + 1) % rate == 0;
+                let mut digest = H::init_variable_length(mod_rate, None);
+                variable_length_field_based_hash_test::<H>(&mut digest, ins.clone(), mod_rate);
+
+                if mod_rate {
+                    let mut digest = H::init_variable_length(!mod_rate, None);
+                    variable_length_field_based_hash_test::<H>(&mut digest, ins, !mod_rate);
+                }
+            }
+        }
+    }
+
+    fn batch_test<F: PrimeField, H: FieldBasedHash<Data = F>, BH: BatchFieldBasedHash<Data = F>>() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let rate = <H::Parameters as FieldBasedHashParameters>::R;
+        let num_hashes = 10;
+
+        let input = (0..num_hashes * rate)
+            .map(|_| F::rand(rng))
+            .collect::<Vec<_>>();
+        let expected = input
+            .chunks(rate)
+            .map(|chunk| {
+                let mut digest = H::init_constant_length(rate, None);
+                chunk.iter().for_each(|&x| {
+                    digest.update(x);
+                });
+                digest.finalize().unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(BH::batch_evaluate(&input).unwrap(), expected);
+
+        let mut output = vec![F::zero(); num_hashes];
+        BH::batch_evaluate_in_place(input.clone().as_mut_slice(), output.as_mut_slice()).unwrap();
+        assert_eq!(output, expected);
+
+        // Wrong input sizes
+        assert!(BH::batch_evaluate(&input[..rate + 1]).is_err());
+        assert!(BH::batch_evaluate(&[]).is_err());
+    }
+
+    #[test]
+    fn test_rescue_hash_tweedle_fr() {
+        check_parameters::<TweedleFr, TweedleFrRescueParameters>(
+            "28948022309329048855892746252171976963322203655955319056773317069363642105857",
+        );
+        check_permutation::<TweedleFr, _>(
+            TweedleFrRescueHash::rescue_perm,
+            [
+                "5933002959154326420890643677771988261176323162760339681191292706678426802392",
+                "696397350919987932627229952142845605408787277861792535315666208602712096921",
+                "20796206725295459979927699097598560780970567655130146090425169404053939910845",
+            ],
+        );
+
+        let mut state = vec![TweedleFr::zero(); TweedleFrRescueParameters::T];
+        TweedleFrRescueHash::rescue_perm(&mut state);
+        assert_eq!(state.as_slice(), TweedleFrRescueParameters::AFTER_ZERO_PERM);
+
+        test_routine::<TweedleFr, TweedleFrRescueHash>(3);
+        batch_test::<TweedleFr, TweedleFrRescueHash, TweedleFrBatchRescueHash>();
+    }
+
+    #[test]
+    fn test_rescue_hash_tweedle_fq() {
+        check_parameters::<TweedleFq, TweedleFqRescueParameters>(
+            "28948022309329048855892746252171976963322203655954433126947083963168578338817",
+        );
+        check_permutation::<TweedleFq, _>(
+            TweedleFqRescueHash::rescue_perm,
+            [
+                "9264390553745388031346372616953695053950381881587731516638064691291901129282",
+                "26068042182539925390471780097772187643790429256626394551924987710066620364152",
+                "18290514594494454348840185963232825778241848325266836114434447359845498104544",
+            ],
+        );
+
+        let mut state = vec![TweedleFq::zero(); TweedleFqRescueParameters::T];
+        TweedleFqRescueHash::rescue_perm(&mut state);
+        assert_eq!(state.as_slice(), TweedleFqRescueParameters::AFTER_ZERO_PERM);
+
+        test_routine::<TweedleFq, TweedleFqRescueHash>(3);
+        batch_test::<TweedleFq, TweedleFqRescueHash, TweedleFqBatchRescueHash>();
+    }
+}
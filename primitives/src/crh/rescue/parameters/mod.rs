@@ -0,0 +1,9 @@
+#[cfg(feature = "tweedle")]
+pub mod tweedle_dee;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle_dee::*;
+
+#[cfg(feature = "tweedle")]
+pub mod tweedle_dum;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle_dum::*;
@@ -0,0 +1,921 @@
+use crate::crh::{
+    rescue::{
+        RescueBatchHash, RescueHash, RescueInverseQuinticSBox, RescueParameters, RescueQuinticSBox,
+    },
+    FieldBasedHashParameters,
+};
+use algebra::fields::tweedle::Fr as TweedleFr;
+
+use algebra::biginteger::BigInteger256 as BigInteger;
+use algebra::field_new;
+
+#[derive(Clone)]
+/// x^5-RESCUE-PRIME-128 parameters for scalar field of the Tweedle Dee curve (= Fr).
+///
+/// Generated as in the reference implementation of the Rescue-Prime specification, with
+/// m = 3, capacity = 1 and security level 128: the number of rounds is 1.5 times the rounds
+/// needed to resist Groebner basis attacks, the round constants are read from the SHAKE256
+/// output on the seed "Rescue-XLIX(p,3,1,128)", and the MDS matrix comes from the Vandermonde
+/// matrix of the smallest primitive element, which is 5.
+pub struct TweedleFrRescueParameters;
+
+impl FieldBasedHashParameters for TweedleFrRescueParameters {
+    type Fr = TweedleFr;
+    const R: usize = 2; // The rate of the hash function
+}
+
+impl RescueParameters for TweedleFrRescueParameters {
+    const T: usize = 3; // Size of the internal state (in field elements)
+    const R_N: i32 = 14; // Number of rounds
+
+    // The zero element of the field
+    const ZERO: TweedleFr = field_new!(TweedleFr, BigInteger([0x0, 0x0, 0x0, 0x0]));
+
+    // The inverse of 5 modulo p - 1
+    const ALPHA_INV: &'static [u64] = &[
+        0x810050b4cccccccd,
+        0x360880ec56991494,
+        0x3333333333333333,
+        0x3333333333333333,
+    ];
+
+    // State vector after permutation of zero state vector (Montgomery rep.)
+    const AFTER_ZERO_PERM: &'static [TweedleFr] = &[
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9d36786993a2c803,
+                0x6920f1d1c07ba23c,
+                0xd62c30d8bc8ef379,
+                0xcfe88de764ca9ae
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4f027f396a5c5fbf,
+                0x115c6d8208cb3a8e,
+                0xfb14476592cc2e2f,
+                0x2be35c0009f25f9b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x38af84ab4df770,
+                0x3731621642967271,
+                0x5aa87d5e7efd026b,
+                0x66cf087197f0056
+            ])
+        ),
+    ];
+
+    // Array of round constants
+    const ROUND_CST: &'static [TweedleFr] = &[
+        // Constants converted to Montgomery representation.
+        // For 14 rounds, 2 * 3 constants per round
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x25ceb4b3c2d2a84a,
+                0x95dfe0cd26e278af,
+                0x904cdaf0f46ec25b,
+                0x2b47ff4a5665187e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9a74ededfc9eaba4,
+                0xf7fb845b45a7e6fc,
+                0x3a6b6710cf7a4e81,
+                0x2712bc46205b60ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9436d006d1d68ac1,
+                0x4366c4950b889d2,
+                0x2557e9318fa76b83,
+                0x37237ca39d3a3e8e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf5015973b480fead,
+                0x617241a86397c5d9,
+                0xe970c0e831847b0d,
+                0x3413e140ab6ad5e2
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x19b766acd006031c,
+                0xbbe427259e55dde3,
+                0xb1a7347b6e7d6e93,
+                0x3c3478122c8096dd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x2c15d44cb8f36c1b,
+                0x33e41fd2f79c6597,
+                0x7f3705394538cb7d,
+                0xb0557a7d874fa6e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x43ea097f3377f957,
+                0xceb4a941eccb1ca9,
+                0xdf7b89dc96fbb15d,
+                0xc3af1f13412596b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9a7c1fdacf241b3f,
+                0x147ef1decaaf91be,
+                0x9523e0e8a00e1b9,
+                0x1ff32f9542ddb3cf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xcb5a7df2a991a9d0,
+                0xbfe066b3a50db48d,
+                0x432bc89af6236d4f,
+                0x1e8d055bf01ed8e3
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6fce680def9ff00f,
+                0xbf49822e1c55dcfd,
+                0xb3c92b33f6386187,
+                0x1c3fa10683c84d81
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9503041d5b776b48,
+                0x216c8049a79e76b6,
+                0xbb4dfdfc3447962a,
+                0x416e499631fda8d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xbada3de41bd8d64,
+                0xa337d467c14257dd,
+                0xc2498c26f3dedb55,
+                0x55d0b4713daea2a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x716624c3f7ee09cb,
+                0x3c054dfd065d4301,
+                0x3005031a4eac81d5,
+                0x34ecfbd9dcdef2bd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xeade424a7ab887e8,
+                0x99ee2cb312e3d406,
+                0xbf3c6a23852a7f68,
+                0x178dc713e5d4fb59
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x83bf8c2e61eed76c,
+                0xceb80fec6e29482b,
+                0xc9107ce67756fe95,
+                0x3c20e613ac8caf31
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x169301477bbc067b,
+                0x3bb39d2b2db430c3,
+                0xfd47fe9451746e11,
+                0x2e11e8534df300f8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x48791ec03a106ce3,
+                0x35672a513c7f13aa,
+                0xbaebfc827a16415b,
+                0x33ce0ec041595bf4
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x68d995a0c934231b,
+                0xff353c12af8fda3,
+                0x8b28313bcd8cce97,
+                0x2b7e2c87316c9624
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x310924aab57cd1bf,
+                0x988a64c48013d5b3,
+                0x1b9fea37bc0f4e8,
+                0x1635d80f7b387b72
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3ce67335b696993c,
+                0x296683b602cbafeb,
+                0xad7c8128e2f9bb05,
+                0x5a6fdc33f55e285
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x58ef2bf0e8e1b707,
+                0xe8dca4045e5bc4ac,
+                0x21db58e4e787bed4,
+                0x3ce6581feb744023
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x618793cc691b7731,
+                0x4e7e466b1baf4f9c,
+                0xd4ec7132f1f660f7,
+                0x2375fe427e576962
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x28b090306ca88411,
+                0x781b4f670dcfbf95,
+                0xcf4422478d57563b,
+                0x3a61b09d19fa59e0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xafc3b5b81d345cd7,
+                0x189ba46a7bf0f36e,
+                0xac0bef4d6871ebca,
+                0x71fcaa03c776e39
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7957317b3e7a9478,
+                0x51dcdab51f2cbae1,
+                0x1733fbb072f09bb4,
+                0x3433315942ab1075
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9294de20de5bbaca,
+                0xb92a11d23375a09d,
+                0x9dafb9c2232c76f7,
+                0x1d41d4ddc4e35684
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xdfc66ae4a5f91213,
+                0xa462c23752026290,
+                0xaba10b9ae870166f,
+                0x1217e0db0ac0db5f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5ad3c21ee0c6b664,
+                0x8956f9c81865328d,
+                0xae8cb11a67ad824d,
+                0x2cfea6cc694d7833
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3c0986d73db35964,
+                0x5e8a8cfb363face4,
+                0x823bfc522215b361,
+                0x33a732102a2b879f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xead52ae5573de40c,
+                0xf704c8bb1a749ada,
+                0x88232699488dfeb6,
+                0x2c84d25acc81f2b1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4cbf9740b61a6e28,
+                0x2eaabce87a1235ef,
+                0xcfbef65202fc3ef2,
+                0x38c9abcf7862135d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7ee67ad0d4776299,
+                0x8182cb493380945d,
+                0xf93743e26f3113b5,
+                0x1bb3a5535c371df
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4e256d3e8486755a,
+                0x9f3a9b4915bbeb62,
+                0x3a0c7f24fe856663,
+                0x31bf5643e7a1c6d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd9443f75dc38c3c5,
+                0xd9fd9683a66dc073,
+                0xded52bb0cf79c89a,
+                0x20c3da3b9b462a85
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa8137e3340bbd32d,
+                0x4618a22dc3249fe7,
+                0xce3417adcd9c6df2,
+                0x25d19254ed3568bf
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa8e41cecf7ce6883,
+                0x162f053be9558853,
+                0xa4e9813e0352e7d2,
+                0xf043f58bb49614b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc33a83c62f58c28f,
+                0xa9b488f12cf9dfef,
+                0x4c9f09c39417e2fc,
+                0x3c07954e12630db
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6ec72d7aa76ccfb9,
+                0x38c74441b05dacb,
+                0x3347ef3ea21aa467,
+                0x4d9e173eb2833ed
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6310fe578d833348,
+                0x8d3bdc4bd4692d7b,
+                0x927edad4a6ba0734,
+                0x2e8c1d7781a69cb8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x70ab986a8037bde6,
+                0xa91825b95cda957d,
+                0x2320df830377649f,
+                0x3ca12751cb5df6a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1816d5cc6d379384,
+                0xc346df7acca78009,
+                0x78bbf3c63bc47d4d,
+                0x2794cfb9eac0d4ef
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7f0f4b448687976d,
+                0xae04703358610c65,
+                0x6a3f74828e836e5e,
+                0x144080ef95e01c4a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xffed24968153be80,
+                0x550dddd19c310d40,
+                0xc1433b97c1a702bb,
+                0x21f64fd982cea7e1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3605dea938c0bc04,
+                0x41967cddebca07a9,
+                0x8c151588d445962a,
+                0x367333da9a52a8ea
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5b6953abdb912778,
+                0x98302509a753af8b,
+                0xe74b04358221c0f,
+                0x392f88b2f69e8f5f
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd0825b294a8ad08f,
+                0x262ae964899a5441,
+                0x7efac35bdf906e0e,
+                0x31b7bce3f47d0b50
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x556874b319e17db3,
+                0x4a2ed95f516c6086,
+                0x10bd9856de0fbfe1,
+                0xda461294ff53b48
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9b332c346a74b0a6,
+                0x25afe1a845588d98,
+                0x3c33ad0fa939b25b,
+                0x24161444ef1f3ac5
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf8738c65b0e29f6f,
+                0x8fff91fe008981ef,
+                0xfd403723dbfa2b4,
+                0x11ebb6509db756a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x18368739f7ae36de,
+                0xe083365905c24cb,
+                0xaedb0f4902c576dc,
+                0x1d7e7c221107aae1
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xba008b78584fd4f7,
+                0x6ff1d95f14a0a554,
+                0xd50c0a1fd2aeaac2,
+                0x294e08ca6f64708c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x72dd10236ed5bd1e,
+                0xb47f3ce623b26c62,
+                0x62b005ec794b9f4c,
+                0x6249d8abbd1bcae
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xab09e035096338b6,
+                0xf6fbbe7fa8670354,
+                0x78a2c3c0522c212e,
+                0x10fa1a4586b083d9
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5cac129c30c2330b,
+                0x656421e6a59b70e4,
+                0xe713b715304d132,
+                0x2fb8290493fe454c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x9ef4942d908217fd,
+                0xcd5aa217fbe8f8e2,
+                0xbc297826229d8c3f,
+                0x136116262f2f89ad
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf3616f45e0aa0b36,
+                0x378cf5580fdd28cd,
+                0x97a9e9c7e1d60fd0,
+                0x1241ee030827756b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79db790388809950,
+                0x4920dc39601c717c,
+                0x1be59b5658c003d4,
+                0x3af2d66a7a68ab45
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x137e2af0a2fbc488,
+                0x763c29397a623211,
+                0xf8eac3119f58aeb6,
+                0x922fa80250f9b1b
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf4ee7a2a8171410b,
+                0x674e99b0a6f75df4,
+                0xcf77f4a72fc30c17,
+                0x898489a0a14220
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6c114bfe253c02bc,
+                0xa5d8bcb075379ec4,
+                0xb51128b674c7d229,
+                0x36b722857f5613e0
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xa46e0cddb084cc0a,
+                0x2d1d3cd0b8b3228d,
+                0x29020750e56e86a7,
+                0x169539315ae2e3cb
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x88bb5c0e1c9f146a,
+                0xad6f7071e073b15c,
+                0xab1b8052f42493a8,
+                0x388fee88d6cf9bc8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x1a7c196cc8d06049,
+                0xfde57dbce25f526,
+                0x8f71d95532b2e408,
+                0x26cbdc76ada7a059
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x79f6cc9d04ac371a,
+                0x230436a6bb8d53cc,
+                0xc9ad25844ad04b2,
+                0x3671b16106deac56
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x4afcf8df66aa5706,
+                0x51e269c308852522,
+                0x6853966a62ce1505,
+                0x12a3de503e970d0e
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x3d05f1e95011f5e6,
+                0xde17e96b9736e5cb,
+                0xd7ffaf37f90a3059,
+                0x246753401d8d1dfd
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5792fc073d4c8046,
+                0x25e23792bf5aa83,
+                0x3b95b09232a84f9,
+                0x16977e0d8c824a7c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6de2e78d1568f75,
+                0xcab812a8ef2c78a9,
+                0x8c64bad2eae73d0b,
+                0x25b4dbbb6c352d7d
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe04738eaeed883fd,
+                0xd9c0c26fab55ac67,
+                0xe0a2bfdc5e1d98f,
+                0x1afad60325f354a8
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x41a133d99a92341f,
+                0x8988544a3b0932c8,
+                0xdc5cc4d998cd2e07,
+                0x38851ac4debe0a98
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6ec852b9cc457805,
+                0x49096279b8ce4a45,
+                0xf56c48afafc64c35,
+                0x35c19dd38175b3aa
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5ceac3f8166ad14d,
+                0xb03c06f53d60b9a7,
+                0x2323429d9d6f8cb1,
+                0xb5e036aa9452c93
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xe8f77ecc1ec8ec1b,
+                0xfdb0c97fff8a92ed,
+                0xe8e4db5d36b2e56a,
+                0x13f6e6da13a08347
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd61b0d3c5445f9a3,
+                0x405c5ee70a28f176,
+                0xcf0dab427ab858b4,
+                0x37f8f2385cce1e60
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x37ccd10e1f1c743e,
+                0x111b248984958a15,
+                0xe33eb6e7f65d32a6,
+                0x1b0e3c4292b7ed34
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x112d8292934816d,
+                0x1a384bbd0b36fb9b,
+                0x26b8e77b5cf66759,
+                0x3fae4f50ebbcae9c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb32b6640af8a69a1,
+                0x6e9fcccb808a4381,
+                0xb5624ae680952c34,
+                0x1a68b8630586b139
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7b5c537e719a2506,
+                0xfa4973ee639516fd,
+                0x5a380ff770f8a8c8,
+                0xcf71cabf18a835a
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xd2730cc64136b839,
+                0xb384de6df28d69,
+                0x2781f3e7c18fcb9c,
+                0x240a082cbe20fd89
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x7271a9eb8f203be8,
+                0x16d29dc3023d7104,
+                0xac7c6cd6687fe902,
+                0xdac5013532e2d62
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xc22c077f2f35f68a,
+                0x40e10f140176fcc6,
+                0xbff2bd54f2a1671d,
+                0x1f55981df0773b4c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xb067d325068564db,
+                0x7f8e683eaf412f89,
+                0x14bc6501a890c90f,
+                0xb941057f9b8b52c
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5e292053853d3978,
+                0x5b7129118a495966,
+                0xf9c42e3eaf21365d,
+                0x249149c7c6f43c41
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xf599df3d29a89f7d,
+                0xbca7647830030f96,
+                0xb0128cd3303f8c96,
+                0x23aedffce3f629c4
+            ])
+        ),
+    ];
+
+    // The MDS matrix constants
+    const MDS_CST: &'static [TweedleFr] = &[
+        // Constants in Montgomery representation
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xaf7b5b79fffffe0d,
+                0x18c7e02800841b2a,
+                0xfffffffffffffff9,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([0x87f453580000026c, 0x93be4b7a296d4d92, 0x8, 0x0])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x860f8769ffffff85,
+                0x4c64920efd8fe3cf,
+                0xfffffffffffffffe,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x5a644149ffffc375,
+                0x95f542396092c66a,
+                0xffffffffffffff29,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([0x84d10e4000004920, 0xf94862cb9881262f, 0x102, 0x0])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0xde49e6b1fffff369,
+                0x69ad18ac2e6d5ff2,
+                0xffffffffffffffd3,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x6ee4c771fff9d9c9,
+                0xe25efcfa3cb863ac,
+                0xffffffffffffea38,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([0xc46243780007637c, 0xbb8c43b06275a808, 0x1a2a, 0x0])
+        ),
+        field_new!(
+            TweedleFr,
+            BigInteger([
+                0x8a382b51fffec2b9,
+                0x5aff7d06885340d7,
+                0xfffffffffffffb9c,
+                0x3fffffffffffffff
+            ])
+        ),
+    ];
+}
+
+pub type TweedleFrRescueQuinticSBox = RescueQuinticSBox<TweedleFr, TweedleFrRescueParameters>;
+pub type TweedleFrRescueInverseQuinticSBox =
+    RescueInverseQuinticSBox<TweedleFr, TweedleFrRescueParameters>;
+pub type TweedleFrRescueHash = RescueHash<
+    TweedleFr,
+    TweedleFrRescueParameters,
+    TweedleFrRescueQuinticSBox,
+    TweedleFrRescueInverseQuinticSBox,
+>;
+pub type TweedleFrBatchRescueHash = RescueBatchHash<
+    TweedleFr,
+    TweedleFrRescueParameters,
+    TweedleFrRescueQuinticSBox,
+    TweedleFrRescueInverseQuinticSBox,
+>;
@@ -0,0 +1,921 @@
+use crate::crh::{
+    rescue::{
+        RescueBatchHash, RescueHash, RescueInverseQuinticSBox, RescueParameters, RescueQuinticSBox,
+    },
+    FieldBasedHashParameters,
+};
+use algebra::fields::tweedle::Fq as TweedleFq;
+
+use algebra::biginteger::BigInteger256 as BigInteger;
+use algebra::field_new;
+
+#[derive(Clone)]
+/// x^5-RESCUE-PRIME-128 parameters for scalar field of the Tweedle Dum curve (= Fq).
+///
+/// Generated as in the reference implementation of the Rescue-Prime specification, with
+/// m = 3, capacity = 1 and security level 128: the number of rounds is 1.5 times the rounds
+/// needed to resist Groebner basis attacks, the round constants are read from the SHAKE256
+/// output on the seed "Rescue-XLIX(p,3,1,128)", and the MDS matrix comes from the Vandermonde
+/// matrix of the smallest primitive element, which is 5.
+pub struct TweedleFqRescueParameters;
+
+impl FieldBasedHashParameters for TweedleFqRescueParameters {
+    type Fr = TweedleFq;
+    const R: usize = 2; // The rate of the hash function
+}
+
+impl RescueParameters for TweedleFqRescueParameters {
+    const T: usize = 3; // Size of the internal state (in field elements)
+    const R_N: i32 = 14; // Number of rounds
+
+    // The zero element of the field
+    const ZERO: TweedleFq = field_new!(TweedleFq, BigInteger([0x0, 0x0, 0x0, 0x0]));
+
+    // The inverse of 5 modulo p - 1
+    const ALPHA_INV: &'static [u64] = &[
+        0xd023bfdccccccccd,
+        0x360880ec544ed23a,
+        0x3333333333333333,
+        0x3333333333333333,
+    ];
+
+    // State vector after permutation of zero state vector (Montgomery rep.)
+    const AFTER_ZERO_PERM: &'static [TweedleFq] = &[
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x590b5724d86e9447,
+                0xb0cbcfd003c15298,
+                0xf9ffbec7b8af90ea,
+                0x33c37357cca45342
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x95fc6d721f2871a,
+                0x2fe6673f0f6e7e78,
+                0x8607322e892bbed3,
+                0xcff0e2079216d36
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5f317d9b25f13dff,
+                0x10729d9d6d88af99,
+                0x6e337e0fe0cc7ed5,
+                0x2ccce6ec3fbe7b12
+            ])
+        ),
+    ];
+
+    // Array of round constants
+    const ROUND_CST: &'static [TweedleFq] = &[
+        // Constants converted to Montgomery representation.
+        // For 14 rounds, 2 * 3 constants per round
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x86561d8f6863fe2c,
+                0x2d5a4a1fa5e17eac,
+                0x41e3a41e47f6f117,
+                0x2b4fcf759ce77e77
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9162d40972611fa4,
+                0x84dc3ad4d954b6d1,
+                0x2c48abf5e731225b,
+                0x3c367854f1f5dfd6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc44c36eb79ab8823,
+                0xeb64ed2605ee57ac,
+                0x596e34016224b108,
+                0x2afeded29f2bc6c6
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x15d3fbe133d6a8ce,
+                0x498fa0dadaf0578d,
+                0xc31c5decfe279fbf,
+                0x2c1ca6b008663b56
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf0c49824855ca260,
+                0x2853c12d37aa1ca5,
+                0xa9c7b4fc0f7039db,
+                0x1f2a7e2d60707231
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf0be87d35f286650,
+                0xf6e8b0dc12c6b811,
+                0xa4cb4bd52d86cefe,
+                0x38644dac01aac73e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2aaa75a09277cf4f,
+                0xedf0845e0339c1e,
+                0x37adc226e5709eca,
+                0x3914cf29a0a6c6c5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x27d6efb2dfd28f52,
+                0x2218daec5e849d6b,
+                0xa5f8e9f704d477eb,
+                0x3c151281dbfecf2d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x322f746ce16eb7f3,
+                0xeb0a1fef94210382,
+                0x348ac0fd10f1e6ce,
+                0x161848794638865f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x72a87a0ed73818ec,
+                0x4013c8cc2c9ae78e,
+                0xc98d52f451633ab0,
+                0x1d409b3d82fa7d59
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x86e149774499e006,
+                0x8c88355c18c1f13d,
+                0xde6080eff2ec72b7,
+                0x110bf661a52470d1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xea2aa8522c3abb96,
+                0x9070c0074193fe70,
+                0x3c27a6d7bf8131e0,
+                0x2f5bed4d171cfc3a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb0fbef7fec577217,
+                0x140f9de30e294e4f,
+                0xe3d9b94b45419740,
+                0x617ee9e702e04b5
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa74af6142ba4249e,
+                0x4fb31a5cf19e847a,
+                0x1a15c8195bfb2942,
+                0x1585809f2aa416fb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x657c7ff4ac32ec02,
+                0x69725463cd0139c,
+                0xee8550635dde99bf,
+                0x34e1b0f6e785e354
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa0633eca114d9c11,
+                0x9102a8bfefc2d751,
+                0xa4dd6797dee1b192,
+                0x6ada1b82adfe471
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd3f766b0bd136fb,
+                0x322ab46cfebd8ca2,
+                0xb9474bbcd73029d4,
+                0x5cbb1f814725fec
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x809edf480957dad4,
+                0x49d5fb9e87fef745,
+                0x3d5a58ae640bea5b,
+                0x25f9b99dbcb6432a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x21fd7d915c93e669,
+                0x99228cabef4cd86f,
+                0x5ce585000f9241f8,
+                0xb5f90615d3dbc47
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2b25984dc1cbd473,
+                0xdd4eb21d1681875f,
+                0xd59210c841b1ad52,
+                0x3548c836bb054716
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcad817fdea9b4657,
+                0x621a7d0794281b5c,
+                0xaeaf67bde011594a,
+                0x3ecbfa195fc286d3
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x630c85b5e994d630,
+                0x97b51620bf1ede00,
+                0x7532f29818b1091d,
+                0x24db6de6f450cb2f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9ff4dd87570afff9,
+                0x9594edff7edc61d1,
+                0x7775f46e538d50a9,
+                0x2a991a3419217fa4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1fdf07705e5bc0dc,
+                0xf840228af0e3fc98,
+                0x93ab5f5ef1eec701,
+                0x6671a8879f89328
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x9590a17dd09a58f0,
+                0x227479d058f7f29d,
+                0xbc58b0f3e91122b,
+                0x630f15c1f5ba7e4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2b8f611563b98fbf,
+                0x4608ae201eff6a9e,
+                0xaa0ff8b37772bf0c,
+                0x33c6f7317edd615f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd36561e536c6e394,
+                0xd145d162ad31648b,
+                0x4b93043ad3d3bc96,
+                0x3d87829dbd94e4da
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x97ef7cfba1eb0914,
+                0x32b5c21fafe9b894,
+                0x6373b26f57c630e0,
+                0x3368298cbbf290f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd02ac8995fe9b940,
+                0x30862edac52d8c9c,
+                0x4cc80b62d1969c61,
+                0x319c76a60b62d467
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x2e9a5c2dd4ec6450,
+                0x235a2871b85810a4,
+                0x6480d8d8160ad90c,
+                0x1c485181ea639c0e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x825598fdd568ef2d,
+                0x279c2abb4298f06,
+                0xfac7f8c857984bb8,
+                0x7ee77195efafcc0
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xccc571d42ab8d8b7,
+                0x7a3caa42a0da6888,
+                0x3ba190659d4eee42,
+                0x2fbe7d3c8c64a03e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3fbefc1ca21c21b1,
+                0xc533eec9e8e92fde,
+                0x3101f891ca6bb6e5,
+                0xe0ca1ce08df6a18
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd75e52e5a26a1d51,
+                0xd4aa2011869024a4,
+                0xcc016516649dc15c,
+                0x263a27751251cc22
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3143b0281588865f,
+                0xe8dcd566b975e04d,
+                0xd209f40159b1ccc4,
+                0x396e8292eefbd693
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd0775926edbd836b,
+                0x2e90fe7b1c0375ef,
+                0xa780bf367b7e39aa,
+                0xb674be898257f69
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3db1df955e51842f,
+                0xc1b435e4b64e2e7d,
+                0xfde4a2e8d6334036,
+                0x2bde5e3d02f7bc8f
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8430417d45808345,
+                0x4e59d6b67f5a0a41,
+                0x8bc531e70f0e9586,
+                0xb2bc4a19bb1a361
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1e683d87396bb40d,
+                0x8dc3d6fedddba3b8,
+                0xb5c5c73dd89a8f50,
+                0x1d54e8021cd88c7e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x82dcc90d2f6bb6cd,
+                0x2738d75ed44a4b41,
+                0x80b169f93e79847c,
+                0x3b15712123ce206d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x25531c95a4e233f2,
+                0x90e5c12ed0931d2a,
+                0x8d558f04126615ec,
+                0x111abfe862763ffd
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7416008f6cdcfef,
+                0xf4954efdd8c513c4,
+                0x4a3935592cd10802,
+                0x142faec7f54be1d2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xcd36879a4d93e3be,
+                0x1a82d8af02166704,
+                0xb8cb503269feeb9,
+                0x400c6799c8ecf1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7cc454c30b2c5345,
+                0xe5f6fa97da45d744,
+                0x623cffd208cc552,
+                0x1d283ae493f2e1c4
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd6a9dc9005ec8fd7,
+                0x73b22f21cbaea1fe,
+                0x99b95d48a76ad3e7,
+                0x3b32832a2f8c802e
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf4c6e43922a491c,
+                0x345e1a46cfada2df,
+                0xd7a506625b9de065,
+                0x321baaa2818e6373
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xaf7a2679919c4e32,
+                0xaa7263c9e9475d83,
+                0x36dd86754afd67a7,
+                0x244da681c2b71b64
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd7d15a6773ffc3dd,
+                0x8d909492c82e319a,
+                0xd84b6f7c9b66bb1a,
+                0x302bcab8c56083d7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd4eef8726ace6925,
+                0x2c00b19dc9aab72b,
+                0xbda468b051ce590e,
+                0x39f4ca3915dfd7b1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe6a63619a4a3bb0,
+                0x83edc0648a7fae41,
+                0x5aeb7831d17d0838,
+                0x19955f276b7d6bd2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc2774bf6376aa0e,
+                0xf46d335824b9ccc2,
+                0x5392f0ac550ad07c,
+                0x2df6de923097720
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc3fb11f162b772cf,
+                0x1aa6bc6c0ca89518,
+                0xafff163d5079bc29,
+                0x35ea832c25c54dbb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5bd15dcab4b1213a,
+                0xc24acc2ccf910cf,
+                0x2450a03b1c187951,
+                0x23430066a51df303
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x61b7e55114544a6,
+                0xf947f7518ce51fcd,
+                0xbba755ae4acf3928,
+                0x29b8d2e283e8fb05
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x15d53a894fbe91bb,
+                0x29222688d9804395,
+                0xe2107b66ad97f405,
+                0x3901c2745faab71a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x8a58d1c2d835f108,
+                0xba9a7bd30e680ea0,
+                0x1777d0a9e60c0aa3,
+                0x2682b4a74ce423b7
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5eaa915c9adbf80a,
+                0x15269fb326ab1e5f,
+                0xc4487f37c9ae7bf9,
+                0x3eea62a71453ac7a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa554c2b203f9f3ab,
+                0xce78fb3b8f5cedb4,
+                0x5d2b6833232a444c,
+                0x58e9d7ee1af7aee
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa2308910bccf18bf,
+                0x6d44691d60198d7d,
+                0x3d26d3fec12fb9e1,
+                0x1b43cf584506ee1
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe51c5b19fbe51d5c,
+                0x12b0ed3d4b990476,
+                0xb3303b770386d993,
+                0x278a04c7dd8897e2
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x35c91d0fb9cfa6c,
+                0x376006f0f376daa5,
+                0x9e9dc1fd0b682731,
+                0x34b17dd9b1b2f63a
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xdc8bacd90e2820ea,
+                0xc5f6e4716d361752,
+                0x6ea696c5e1bcd037,
+                0x1a7135728c8e0a15
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1f07b88434466ac,
+                0xb5c72046718997c4,
+                0xf79b093645eb84a1,
+                0x1f0de1216200d784
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7e12de63825b86c7,
+                0x998153a84b0ea79,
+                0x5e0d552fd3f9509c,
+                0x11f0ed4224fdae6d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8145fe23dbc3696,
+                0x7acac45af2581427,
+                0xd63dbb1ec9ed2a34,
+                0x377e83849377ed5d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xb717ab0e6b9263e4,
+                0x2cca09894d808231,
+                0x6deac93abe91bdbc,
+                0x2ca9598c37785382
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd8c43e5ba7e3e8e9,
+                0x40ee67f9cd013765,
+                0x9ec0c4bef4c3c5a1,
+                0x3fa6b1383f7a7d8b
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x6c62487d21be93b4,
+                0x51924da9595ffd41,
+                0x950455ff34c7de2e,
+                0x337502e8153b8edb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x35218f4fcddffea3,
+                0x5ec432203f69d01a,
+                0x59d365629b2f6b9c,
+                0x2593e420b2c6afdb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x36873065a253c520,
+                0x6808d2013e780025,
+                0x3cac208a955367be,
+                0x3cf8385416b4cb64
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x707ab2125e0fe29c,
+                0x300d5fcbdea70f36,
+                0x9769b5932eaf52d7,
+                0x1c5ce70b924b468d
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x105d578bab82ae11,
+                0x5496ff67b78279a9,
+                0x1eb8e3e22566f2c8,
+                0x1519fab427e56993
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xeca2ef6dc42f0a92,
+                0x8115ad13b5aa26cd,
+                0xeb8c086ff85107cd,
+                0x35b70f3ca24fba23
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x3b489dad2edc547d,
+                0x7d658cab07e2a4d6,
+                0x9fd06f55d3145fce,
+                0x940e021e932aa91
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xab3b0efdac149fcf,
+                0x83c15e82dbf94a53,
+                0x14ca85bd4309a680,
+                0x1dfe682b0f865a24
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1b66a7e2f2e079cc,
+                0xa79556a39c357639,
+                0x171a59da7bba8921,
+                0xe6a231c1703b708
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7c6f09736a291dff,
+                0x349f1625f4de3247,
+                0x86d09b35cfb49b09,
+                0x126a05980991ada
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7b51e7a653527788,
+                0x5f4ac9cc6b18fb54,
+                0x2a1894fb2de57365,
+                0x15105d78a2554736
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7648b001f5b687a5,
+                0x2b90852a8ebf0347,
+                0xf4f0ff793b4c086e,
+                0x343c8eb01141c79c
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xe08f0c028381b1c4,
+                0x96fcc6ac2196eaaf,
+                0x118bee4415d085b1,
+                0x2bcb3686f56bac82
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x129047bf1ec386ef,
+                0x923c4f18f7bcccb0,
+                0xa91fdb03b62962a4,
+                0x1e821ae577462efa
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xa9b254d85b4b491,
+                0xd6f4f607a851f6f6,
+                0x8c491083fd6c293d,
+                0x313fb0dceed781eb
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x26b87fece41341a7,
+                0xa85b1d5433c5b5f0,
+                0x1fc9efec51798304,
+                0x23953712a9c5beab
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x93e63facc995c339,
+                0xb5534d6db5bfeace,
+                0x651fd95e61e1fde2,
+                0x17ba445614b24aa3
+            ])
+        ),
+    ];
+
+    // The MDS matrix constants
+    const MDS_CST: &'static [TweedleFq] = &[
+        // Constants in Montgomery representation
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x5ce545c3fffffe0d,
+                0x18c7e02d94f34533,
+                0xfffffffffffffff9,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([0x1c39d5700000026c, 0x93be4b733a9e700c, 0x8, 0x0])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x7e878523ffffff85,
+                0x4c6492105da93d2d,
+                0xfffffffffffffffe,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xc286d7e3ffffc375,
+                0x95f542e6afe9959a,
+                0xffffffffffffff29,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([0x43b96e8000004920, 0xf94861fa44c04fe1, 0x102, 0x0])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xf16659f3fffff369,
+                0x69ad18d038910cf0,
+                0xffffffffffffffd3,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0xd954c173fff9d9c9,
+                0xe25f0e94931a0809,
+                0xffffffffffffea38,
+                0x3fffffffffffffff
+            ])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([0x1c7026b00007637c, 0xbb8c2e89d55bd4f2, 0x1a2a, 0x0])
+        ),
+        field_new!(
+            TweedleFq,
+            BigInteger([
+                0x1e1b833fffec2b9,
+                0x5aff8092c4c51571,
+                0xfffffffffffffb9c,
+                0x3fffffffffffffff
+            ])
+        ),
+    ];
+}
+
+pub type TweedleFqRescueQuinticSBox = RescueQuinticSBox<TweedleFq, TweedleFqRescueParameters>;
+pub type TweedleFqRescueInverseQuinticSBox =
+    RescueInverseQuinticSBox<TweedleFq, TweedleFqRescueParameters>;
+pub type TweedleFqRescueHash = RescueHash<
+    TweedleFq,
+    TweedleFqRescueParameters,
+    TweedleFqRescueQuinticSBox,
+    TweedleFqRescueInverseQuinticSBox,
+>;
+pub type TweedleFqBatchRescueHash = RescueBatchHash<
+    TweedleFq,
+    TweedleFqRescueParameters,
+    TweedleFqRescueQuinticSBox,
+    TweedleFqRescueInverseQuinticSBox,
+>;
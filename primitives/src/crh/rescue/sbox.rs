@@ -0,0 +1,66 @@
+use crate::{crh::rescue::RescueParameters, BatchSBox, SBox};
+use algebra::PrimeField;
+use std::marker::PhantomData;
+
+/// S-Box: S(x) = x^5
+#[derive(Debug)]
+pub struct RescueQuinticSBox<F: PrimeField, P: RescueParameters<Fr = F>> {
+    _field: PhantomData<F>,
+    _parameters: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: RescueParameters<Fr = F>> RescueQuinticSBox<F, P> {
+    #[inline]
+    fn exp_by_5(elem: &mut F) {
+        let w1 = *elem * &(*elem);
+        let w2 = w1 * &w1;
+        *elem = w2 * &(*elem);
+    }
+}
+
+impl<F: PrimeField, P: RescueParameters<Fr = F>> SBox for RescueQuinticSBox<F, P> {
+    type Field = F;
+    type Parameters = P;
+
+    #[inline]
+    fn apply_full(state: &mut Vec<F>) {
+        // Apply the S-BOX to each of the elements of the state vector
+        state.iter_mut().for_each(Self::exp_by_5);
+    }
+
+    #[inline]
+    fn apply_partial(state: &mut Vec<F>) {
+        // Apply S-BOX only to the first element of the state vector
+        Self::exp_by_5(&mut state[0]);
+    }
+}
+
+impl<F: PrimeField, P: RescueParameters<Fr = F>> BatchSBox for RescueQuinticSBox<F, P> {}
+
+/// Inverse S-Box: S(x) = x^(1/5), i.e. x^P::ALPHA_INV
+#[derive(Debug)]
+pub struct RescueInverseQuinticSBox<F: PrimeField, P: RescueParameters<Fr = F>> {
+    _field: PhantomData<F>,
+    _parameters: PhantomData<P>,
+}
+
+impl<F: PrimeField, P: RescueParameters<Fr = F>> SBox for RescueInverseQuinticSBox<F, P> {
+    type Field = F;
+    type Parameters = P;
+
+    #[inline]
+    fn apply_full(state: &mut Vec<F>) {
+        // Apply the S-BOX to each of the elements of the state vector
+        state
+            .iter_mut()
+            .for_each(|elem| *elem = elem.pow(P::ALPHA_INV));
+    }
+
+    #[inline]
+    fn apply_partial(state: &mut Vec<F>) {
+        // Apply S-BOX only to the first element of the state vector
+        state[0] = state[0].pow(P::ALPHA_INV);
+    }
+}
+
+impl<F: PrimeField, P: RescueParameters<Fr = F>> BatchSBox for RescueInverseQuinticSBox<F, P> {}
@@ -0,0 +1,62 @@
+//! A fixed-length CRH from the [SHA-512](https://tools.ietf.org/html/rfc6234) hash function.
+use crate::{crh::FixedLengthCRH, CryptoError, Error};
+use rand::Rng;
+use sha2::{Digest, Sha512};
+
+/// Byte-oriented SHA-512 CRH. The input is hashed as it is, i.e. without any zero padding,
+/// and the output is the 64 bytes long (big endian) digest.
+pub struct Sha512CRH;
+
+impl FixedLengthCRH for Sha512CRH {
+    /// Enough to hash the concatenation of two digests, e.g. in a Merkle tree.
+    const INPUT_SIZE_BITS: usize = 1024;
+    type Output = Vec<u8>;
+    type Parameters = ();
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(())
+    }
+
+    fn evaluate(_parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let eval_time = start_timer!(|| "Sha512CRH::Eval");
+
+        if input.len() * 8 > Self::INPUT_SIZE_BITS {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "Sha512CRH input".to_owned(),
+                input.len(),
+            )));
+        }
+        let result = Sha512::digest(input).to_vec();
+
+        end_timer!(eval_time);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Sha512CRH;
+    use crate::crh::FixedLengthCRH;
+
+    #[test]
+    fn test_sha512_crh_test_vectors() {
+        let test_inputs = [
+            "",
+            "abc",
+            "abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        ];
+        let test_outputs = [
+            "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+            "8e959b75dae313da8cf4f72814fc143f8f7779c6eb9f7fa17299aeadb6889018501d289e4900f7e4331b99dec4b5433ac7d329eeb6dd26545e96e55b874be909",
+        ];
+
+        for (input, output) in test_inputs.iter().zip(test_outputs.iter()) {
+            let digest = Sha512CRH::evaluate(&(), input.as_bytes()).unwrap();
+            assert_eq!(hex::encode(digest), *output);
+        }
+
+        // Inputs longer than `INPUT_SIZE_BITS` are rejected
+        assert!(Sha512CRH::evaluate(&(), &[0u8; 129]).is_err());
+    }
+}
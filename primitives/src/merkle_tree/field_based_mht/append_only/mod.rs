@@ -1,7 +1,8 @@
 use crate::{
-    check_precomputed_parameters, BatchFieldBasedHash, BatchFieldBasedMerkleTreeParameters, Error,
-    FieldBasedHash, FieldBasedHashParameters, FieldBasedMHTPath, FieldBasedMerkleTree,
-    FieldBasedMerkleTreePath, MerkleTreeError,
+    check_precomputed_parameters, get_multi_path_node_positions, BatchFieldBasedHash,
+    BatchFieldBasedMerkleTreeParameters, Error, FieldBasedHash, FieldBasedHashParameters,
+    FieldBasedMHTMultiPath, FieldBasedMHTPath, FieldBasedMerkleTree, FieldBasedMerkleTreePath,
+    MerkleTreeError,
 };
 use algebra::{serialize::*, Field};
 use std::marker::PhantomData;
@@ -15,7 +16,6 @@ use std::marker::PhantomData;
 /// 2) Leaves and nodes are hashed without using any kind of domain separation:
 ///    while this is ok for use cases where the Merkle Trees have always the
 ///    same height, it's not for all the others.
-/// TODO: Test with arity > 2
 #[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
 pub struct FieldBasedAppendOnlyMHT<T: BatchFieldBasedMerkleTreeParameters> {
     root: T::Data,
@@ -44,12 +44,11 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedAppendOnlyMHT<T> {
     /// `primitives/src/benches/poseidon_mht.rs` to properly tune the `processing_step`
     /// parameter according to your use case.
     pub fn init(height: usize, processing_step: usize) -> Result<Self, Error> {
-
         if !check_precomputed_parameters::<T>(height) {
             Err(Box::new(MerkleTreeError::Other(
                 format!(
                     "Unsupported height. Max supported height is: {}",
-                    T::ZERO_NODE_CST.unwrap().nodes.len()
+                    T::ZERO_NODE_CST.unwrap().nodes.len() - 1
                 )
                 .to_owned(),
             )))?
@@ -188,6 +187,38 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedAppendOnlyMHT<T> {
         &self.array_nodes[(self.initial_pos[0] as usize)..(self.new_elem_pos[0] as usize)]
     }
 
+    /// Given the indices of a set of leaves, in any order, returns the FieldBasedMHTMultiPath
+    /// of these leaves up until the root of the Merkle Tree. Returns None if the tree has not
+    /// been finalized, or if no index or an invalid one is given.
+    pub fn get_multi_path(&self, leaf_indices: &[usize]) -> Option<FieldBasedMHTMultiPath<T>> {
+        let num_leaves = T::MERKLE_ARITY.pow(self.height as u32);
+        if !self.finalized || leaf_indices.is_empty() {
+            return None;
+        }
+        if let Some(leaf_index) = leaf_indices.iter().find(|&&idx| idx >= num_leaves) {
+            eprintln!(
+                "Invalid leaf index {} for num leaves {}",
+                leaf_index, num_leaves
+            );
+            return None;
+        }
+
+        let mut sorted_indices = leaf_indices.to_vec();
+        sorted_indices.sort_unstable();
+        sorted_indices.dedup();
+
+        let nodes = get_multi_path_node_positions(T::MERKLE_ARITY, self.height, &sorted_indices)
+            .into_iter()
+            .map(|(level, position)| self.array_nodes[self.initial_pos[level] as usize + position])
+            .collect();
+
+        Some(FieldBasedMHTMultiPath::new(
+            sorted_indices,
+            nodes,
+            self.height,
+        ))
+    }
+
     fn batch_hash(
         input: &mut [T::Data],
         output: &mut [T::Data],
@@ -308,7 +339,7 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
         Ok(self)
     }
 
-    fn reset(&mut self) -> &mut Self {
+    fn reset(&mut self) -> Result<&mut Self, Error> {
         // Reset indices
         for i in 0..self.new_elem_pos.len() {
             self.new_elem_pos[i] = self.initial_pos[i];
@@ -323,7 +354,7 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
         // Reset finalized value
         self.finalized = false;
 
-        self
+        Ok(self)
     }
 
     fn root(&self) -> Option<T::Data> {
@@ -392,8 +423,9 @@ mod test {
 
     use crate::{
         merkle_tree::field_based_mht::{
-            BatchFieldBasedMerkleTreeParameters, FieldBasedAppendOnlyMHT, FieldBasedMerkleTree,
-            FieldBasedMerkleTreeParameters, FieldBasedMerkleTreePath, NaiveMerkleTree,
+            check_precomputed_parameters, BatchFieldBasedMerkleTreeParameters,
+            FieldBasedAppendOnlyMHT, FieldBasedMerkleTree, FieldBasedMerkleTreeParameters,
+            FieldBasedMerkleTreePath, FieldBasedSparseMHT, InMemorySMTStorage, NaiveMerkleTree,
         },
         FieldBasedMHTPath, FieldBasedMerkleTreePrecomputedZeroConstants,
     };
@@ -464,7 +496,7 @@ mod test {
         });
 
         // Reset the tree
-        tree.finalize_in_place().unwrap().reset();
+        tree.finalize_in_place().unwrap().reset().unwrap();
 
         // Add the same leaves as we did initially
         leaves[..num_leaves / 2].iter().for_each(|leaf| {
@@ -495,23 +527,32 @@ mod test {
         // HEIGHT == 1
         {
             // Generate empty tree and attempt to finalize
-            let tree = FieldBasedAppendOnlyMHT::<T>::init(1, 2).unwrap();
+            let tree = FieldBasedAppendOnlyMHT::<T>::init(1, T::MERKLE_ARITY).unwrap();
             tree.finalize().unwrap();
 
             // Generate tree with only 1 leaf and attempt to finalize
-            let mut tree = FieldBasedAppendOnlyMHT::<T>::init(1, 2).unwrap();
+            let mut tree = FieldBasedAppendOnlyMHT::<T>::init(1, T::MERKLE_ARITY).unwrap();
             assert!(tree.append(T::Data::rand(rng)).is_ok());
             tree.finalize().unwrap();
 
-            // Generate tree with exactly 2 leaves and attempt to finalize.
+            // Generate tree with exactly MERKLE_ARITY leaves and attempt to finalize.
             // Assert error if trying to add another leaf
-            let mut tree = FieldBasedAppendOnlyMHT::<T>::init(1, 2).unwrap();
-            assert!(tree.append(T::Data::rand(rng)).is_ok());
-            assert!(tree.append(T::Data::rand(rng)).is_ok());
+            let mut tree = FieldBasedAppendOnlyMHT::<T>::init(1, T::MERKLE_ARITY).unwrap();
+            for _ in 0..T::MERKLE_ARITY {
+                assert!(tree.append(T::Data::rand(rng)).is_ok());
+            }
             assert!(tree.append(T::Data::rand(rng)).is_err());
             tree.finalize().unwrap();
         }
 
+        // HEIGHT >= number of precomputed empty nodes: the empty root is not available
+        {
+            let max_height = T::ZERO_NODE_CST.unwrap().nodes.len() - 1;
+            assert!(check_precomputed_parameters::<T>(max_height));
+            assert!(!check_precomputed_parameters::<T>(max_height + 1));
+            assert!(FieldBasedAppendOnlyMHT::<T>::init(max_height + 1, 1).is_err());
+        }
+
         // HEIGHT == 0
         {
             // Generate empty tree and attempt to finalize
@@ -520,7 +561,7 @@ mod test {
             assert_eq!(tree.root().unwrap(), T::Data::zero());
 
             // Check that reset() works properly also in this case
-            tree.reset();
+            tree.reset().unwrap();
             tree.finalize_in_place().unwrap();
             assert_eq!(tree.root().unwrap(), T::Data::zero());
 
@@ -533,7 +574,7 @@ mod test {
             assert_eq!(fe, tree.root().unwrap());
 
             // Check that reset() works properly also in this case
-            tree.reset();
+            tree.reset().unwrap();
             let fe = T::Data::rand(rng);
             assert!(tree.append(fe).is_ok());
             tree.finalize_in_place().unwrap();
@@ -678,6 +719,67 @@ mod test {
         }
     }
 
+    /// Compare roots and paths of a FieldBasedAppendOnlyMHT with the ones of a FieldBasedSparseMHT
+    /// holding the same leaves. Used for arities for which a NaiveMerkleTree is not available.
+    fn merkle_tree_compare_with_smt_test<T: BatchFieldBasedMerkleTreeParameters, R: RngCore>(
+        height: usize,
+        num_leaves: usize,
+        mut rng: &mut R,
+    ) {
+        let mut tree = FieldBasedAppendOnlyMHT::<T>::init(height, num_leaves).unwrap();
+        let mut smt =
+            FieldBasedSparseMHT::<T, InMemorySMTStorage<T::Data>, FieldBasedMHTPath<T>>::init(
+                height as u8,
+            );
+
+        // Generate random leaves, filling only part of the tree
+        let num_non_empty_leaves = num_leaves / 2 + 1;
+        let leaves = (0..num_non_empty_leaves)
+            .map(|_| T::Data::rand(&mut rng))
+            .collect::<Vec<_>>();
+        leaves.iter().for_each(|&leaf| {
+            tree.append(leaf).unwrap();
+            smt.append(leaf).unwrap();
+        });
+
+        tree.finalize_in_place().unwrap();
+        smt.finalize_in_place().unwrap();
+        let root = tree.root().unwrap();
+        assert_eq!(root, smt.root().unwrap());
+
+        for i in 0..num_leaves {
+            let leaf = leaves
+                .get(i)
+                .cloned()
+                .unwrap_or_else(<T::Data as Field>::zero);
+
+            // Create and verify a FieldBasedMHTPath
+            let path = tree.get_merkle_path(i).unwrap();
+            assert!(path.is_valid());
+            assert!(path.verify(tree.height(), &leaf, &root).unwrap());
+            assert_eq!(i, path.leaf_index());
+
+            // Assert it's equal to the one of the FieldBasedSparseMHT
+            assert_eq!(path, smt.get_merkle_path(i as u32).unwrap());
+
+            if i == 0 {
+                assert!(path.is_leftmost());
+            } else if i == num_leaves - 1 {
+                assert!(path.is_rightmost());
+            } else {
+                assert!(!path.is_leftmost());
+                assert!(!path.is_rightmost());
+            }
+            assert_eq!(path.are_right_leaves_empty(), i >= num_non_empty_leaves - 1);
+
+            // Serialization/deserialization test
+            let path_serialized = to_bytes!(path).unwrap();
+            let path_deserialized =
+                FieldBasedMHTPath::<T>::read(path_serialized.as_slice()).unwrap();
+            assert_eq!(path, path_deserialized);
+        }
+    }
+
     fn merkle_tree_path_are_right_leaves_empty_test<
         T: BatchFieldBasedMerkleTreeParameters,
         R: RngCore,
@@ -748,4 +850,78 @@ mod test {
             height, num_leaves, rng,
         );
     }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr_arity_4() {
+        use crate::{
+            crh::{TweedleFrBatchPoseidonHashRate4, TweedleFrPoseidonHashRate4},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        };
+        use algebra::fields::tweedle::Fr;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHashRate4;
+            const MERKLE_ARITY: usize = 4;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHashRate4;
+        }
+
+        let height = 3;
+        let num_leaves = 4usize.pow(height as u32);
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+        merkle_tree_reset_test::<TweedleFrFieldBasedMerkleTreeParams, _>(height, num_leaves, rng);
+        merkle_tree_test_edge_cases::<TweedleFrFieldBasedMerkleTreeParams>();
+        merkle_tree_compare_with_smt_test::<TweedleFrFieldBasedMerkleTreeParams, _>(
+            height, num_leaves, rng,
+        );
+        merkle_tree_path_are_right_leaves_empty_test::<TweedleFrFieldBasedMerkleTreeParams, _>(
+            height, num_leaves, rng,
+        );
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr_arity_8() {
+        use crate::{
+            crh::{TweedleFrBatchPoseidonHashRate8, TweedleFrPoseidonHashRate8},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8,
+        };
+        use algebra::fields::tweedle::Fr;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHashRate8;
+            const MERKLE_ARITY: usize = 8;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHashRate8;
+        }
+
+        let height = 2;
+        let num_leaves = 8usize.pow(height as u32);
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+        merkle_tree_reset_test::<TweedleFrFieldBasedMerkleTreeParams, _>(height, num_leaves, rng);
+        merkle_tree_test_edge_cases::<TweedleFrFieldBasedMerkleTreeParams>();
+        merkle_tree_compare_with_smt_test::<TweedleFrFieldBasedMerkleTreeParams, _>(
+            height, num_leaves, rng,
+        );
+        merkle_tree_path_are_right_leaves_empty_test::<TweedleFrFieldBasedMerkleTreeParams, _>(
+            height, num_leaves, rng,
+        );
+    }
 }
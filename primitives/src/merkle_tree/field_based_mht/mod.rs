@@ -1,6 +1,9 @@
 pub mod path;
 pub use self::path::*;
 
+pub mod multi_path;
+pub use self::multi_path::*;
+
 pub mod naive;
 pub use self::naive::*;
 
@@ -20,7 +23,7 @@ use algebra::{Field, FromBytes, ToBytes};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::{clone::Clone, fmt::Debug, hash::Hash};
+use std::{clone::Clone, convert::TryFrom, fmt::Debug, hash::Hash};
 
 /// Definition of parameters needed to implement and optimize a Merkle Tree whose nodes and leaves
 /// are Field elements. The trait is generic with respect to the arity of the Merkle Tree.
@@ -51,12 +54,17 @@ pub trait BatchFieldBasedMerkleTreeParameters: FieldBasedMerkleTreeParameters {
     >;
 }
 
+/// Check that the precomputed empty nodes of `T` are available up to the root of a tree of
+/// height `tree_height`, and that the positions of all the nodes of such a tree fit a `u32`.
 pub(crate) fn check_precomputed_parameters<T: FieldBasedMerkleTreeParameters>(
     tree_height: usize,
 ) -> bool {
     match T::ZERO_NODE_CST {
         Some(supported_params) => {
-            tree_height <= supported_params.nodes.len()
+            tree_height < supported_params.nodes.len()
+                && T::MERKLE_ARITY >= 2
+                && num_nodes(T::MERKLE_ARITY, tree_height)
+                    .map_or(false, |num_nodes| num_nodes <= u32::MAX as u64 + 1)
                 && T::MERKLE_ARITY == supported_params.merkle_arity
                 && T::MERKLE_ARITY
                     == <<T::H as FieldBasedHash>::Parameters as FieldBasedHashParameters>::R
@@ -65,6 +73,14 @@ pub(crate) fn check_precomputed_parameters<T: FieldBasedMerkleTreeParameters>(
     }
 }
 
+/// Return the total number of nodes (leaves included) of a tree of specified `arity` and
+/// `height`, i.e. (arity^(height + 1) - 1)/(arity - 1), or None if it overflows a `u64`.
+fn num_nodes(arity: usize, height: usize) -> Option<u64> {
+    let arity = arity as u64;
+    let power = arity.checked_pow(u32::try_from(height + 1).ok()?)?;
+    Some((power - 1) / (arity - 1))
+}
+
 /// Definition of a Merkle Tree whose leaves and nodes are Field Elements. The trait is
 /// designed to be efficient in memory by providing a digest-like interface that allows
 /// to update the tree one leaf at a time, without the necessity to keep all the leaves
@@ -97,7 +113,8 @@ pub trait FieldBasedMerkleTree: Clone {
     fn finalize_in_place(&mut self) -> Result<&mut Self, Error>;
 
     /// Resets the internal state of the tree, bringing it back to the initial one.
-    fn reset(&mut self) -> &mut Self;
+    /// Might fail for trees whose state is persisted, e.g. due to I/O errors.
+    fn reset(&mut self) -> Result<&mut Self, Error>;
 
     /// Return the root of the Merkle Tree. Return None if the tree has not been
     /// finalized before calling this function.
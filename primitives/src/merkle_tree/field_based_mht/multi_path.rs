@@ -0,0 +1,566 @@
+use crate::{crh::*, field_based_mht::*, MerkleTreeError};
+use algebra::{serialize::*, SemanticallyValid};
+use std::{
+    clone::Clone,
+    collections::HashMap,
+    io::{Error as IoError, ErrorKind, Read, Result as IoResult, Write},
+};
+
+/// A Merkle multi-path (or batched Merkle Path) for a set of leaves of a FieldBasedMerkleTree
+/// with arbitrary arity. Instead of a Merkle Path for each leaf, it holds only the minimal set
+/// of nodes needed to reconstruct the root from all the leaves at the same time: the nodes
+/// shared between the single paths, or computable from the opened leaves, are omitted.
+/// The nodes are stored level by level, starting from the leaves, and from left to right.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Default(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+#[derive(Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize)]
+pub struct FieldBasedMHTMultiPath<T: FieldBasedMerkleTreeParameters> {
+    /// Indices of the opened leaves, sorted and without duplicates
+    leaf_indices: Vec<usize>,
+    nodes: Vec<<T::H as FieldBasedHash>::Data>,
+    height: usize,
+}
+
+/// Return, level by level and from left to right, the (level, position) of the nodes needed
+/// to reconstruct the root of a Merkle Tree of arity `arity` and height `height` from the
+/// leaves at `leaf_indices`, which must be sorted and without duplicates.
+pub fn get_multi_path_node_positions(
+    arity: usize,
+    height: usize,
+    leaf_indices: &[usize],
+) -> Vec<(usize, usize)> {
+    let mut positions = Vec::new();
+    let mut known = leaf_indices.to_vec();
+    for level in 0..height {
+        let mut next_known = Vec::new();
+        let mut i = 0;
+        while i < known.len() {
+            let parent = known[i] / arity;
+            for child in parent * arity..(parent + 1) * arity {
+                if i < known.len() && known[i] == child {
+                    i += 1;
+                } else {
+                    positions.push((level, child));
+                }
+            }
+            next_known.push(parent);
+        }
+        known = next_known;
+    }
+    positions
+}
+
+impl<T: FieldBasedMerkleTreeParameters> FieldBasedMHTMultiPath<T> {
+    /// Return a new multi-path for the leaves at `leaf_indices`, in a Merkle Tree of height
+    /// `height`, given the `nodes` needed to reconstruct the root, ordered level by level,
+    /// starting from the leaves, and from left to right.
+    pub fn new(
+        leaf_indices: Vec<usize>,
+        nodes: Vec<<T::H as FieldBasedHash>::Data>,
+        height: usize,
+    ) -> Self {
+        Self {
+            leaf_indices,
+            nodes,
+            height,
+        }
+    }
+
+    /// Build a multi-path out of the Merkle Paths of the leaves in a Merkle Tree
+    /// of height `height`. The paths may be passed in any order, duplicates are ignored.
+    /// Fails if two paths disagree on the value of a shared sibling, as they can't belong
+    /// to the same tree.
+    pub fn from_paths(paths: &[FieldBasedMHTPath<T>], height: usize) -> Result<Self, Error> {
+        if paths.is_empty() {
+            Err(MerkleTreeError::Other(
+                "At least one Merkle Path is required".to_owned(),
+            ))?
+        }
+
+        // Collect all the siblings by (level, position) and the indices of the leaves
+        let mut siblings = HashMap::new();
+        let mut leaf_indices = Vec::with_capacity(paths.len());
+        for path in paths.iter() {
+            if !path.is_valid() {
+                Err(MerkleTreeError::Other("Invalid Merkle Path".to_owned()))?
+            }
+            let path_len = path.get_length();
+            if path_len != height {
+                Err(MerkleTreeError::IncorrectPathLength(path_len, height))?
+            }
+
+            let mut node_idx = path.leaf_index();
+            leaf_indices.push(node_idx);
+            for (level, (nodes, position)) in path.get_raw_path().iter().enumerate() {
+                let first_child = node_idx - position;
+                let sibling_positions =
+                    (first_child..first_child + T::MERKLE_ARITY).filter(|&child| child != node_idx);
+                for (sibling_position, &sibling) in sibling_positions.zip(nodes.iter()) {
+                    match siblings.insert((level, sibling_position), sibling) {
+                        Some(other_sibling) if other_sibling != sibling => {
+                            Err(MerkleTreeError::Other(format!(
+                                "Merkle Paths disagree on the node at height {} and position {}",
+                                level, sibling_position
+                            )))?
+                        }
+                        _ => {}
+                    }
+                }
+                node_idx /= T::MERKLE_ARITY;
+            }
+        }
+        leaf_indices.sort_unstable();
+        leaf_indices.dedup();
+
+        let nodes = get_multi_path_node_positions(T::MERKLE_ARITY, height, &leaf_indices)
+            .into_iter()
+            .map(|level_position| siblings[&level_position])
+            .collect();
+
+        Ok(Self::new(leaf_indices, nodes, height))
+    }
+
+    /// Return the indices of the leaves opened by `self`, sorted and without duplicates
+    pub fn leaf_indices(&self) -> &[usize] {
+        self.leaf_indices.as_slice()
+    }
+
+    /// Return the nodes needed to reconstruct the root, ordered level by level,
+    /// starting from the leaves, and from left to right.
+    pub fn get_nodes(&self) -> &[<T::H as FieldBasedHash>::Data] {
+        self.nodes.as_slice()
+    }
+
+    /// Return the height of the Merkle Tree `self` refers to
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the number of hashes needed to reconstruct the root from `self`.
+    pub fn num_hashes(&self) -> usize {
+        let mut num_hashes = 0;
+        let mut known = self.leaf_indices.clone();
+        for _ in 0..self.height {
+            known = known.iter().map(|idx| idx / T::MERKLE_ARITY).collect();
+            known.dedup();
+            num_hashes += known.len();
+        }
+        num_hashes
+    }
+
+    /// Compute the root of the Merkle Tree from `self` and the `leaves`, given in the
+    /// same order of `self.leaf_indices()`.
+    pub fn compute_root(
+        &self,
+        leaves: &[<T::H as FieldBasedHash>::Data],
+    ) -> Result<<T::H as FieldBasedHash>::Data, Error> {
+        // Rate may also be smaller than the arity actually, but this assertion
+        // is reasonable and simplify the design.
+        assert_eq!(
+            <<T::H as FieldBasedHash>::Parameters as FieldBasedHashParameters>::R,
+            T::MERKLE_ARITY
+        );
+
+        if leaves.len() != self.leaf_indices.len() || leaves.is_empty() {
+            Err(MerkleTreeError::Other(format!(
+                "Expected {} leaves, found {}",
+                self.leaf_indices.len(),
+                leaves.len()
+            )))?
+        }
+
+        if self.leaf_indices.windows(2).any(|w| w[0] >= w[1]) {
+            Err(MerkleTreeError::Other(
+                "Leaf indices must be sorted and without duplicates".to_owned(),
+            ))?
+        }
+
+        let mut known = self
+            .leaf_indices
+            .iter()
+            .cloned()
+            .zip(leaves.iter().cloned())
+            .collect::<Vec<_>>();
+        let mut nodes = self.nodes.iter();
+        let mut digest = <T::H as FieldBasedHash>::init_constant_length(T::MERKLE_ARITY, None);
+
+        for _ in 0..self.height {
+            let mut next_known = Vec::new();
+            let mut i = 0;
+            while i < known.len() {
+                let parent = known[i].0 / T::MERKLE_ARITY;
+                for child in parent * T::MERKLE_ARITY..(parent + 1) * T::MERKLE_ARITY {
+                    if i < known.len() && known[i].0 == child {
+                        digest.update(known[i].1);
+                        i += 1;
+                    } else {
+                        let node = nodes.next().ok_or_else(|| {
+                            MerkleTreeError::Other("Not enough nodes in the multi-path".to_owned())
+                        })?;
+                        digest.update(*node);
+                    }
+                }
+
+                // Compute the parent node
+                next_known.push((parent, digest.finalize()?));
+                digest.reset(None);
+            }
+            known = next_known;
+        }
+
+        if nodes.next().is_some() || known.len() != 1 || known[0].0 != 0 {
+            Err(MerkleTreeError::Other(
+                "Multi-path inconsistent with the specified leaf indices".to_owned(),
+            ))?
+        }
+
+        Ok(known[0].1)
+    }
+
+    /// Verify `self` for the `leaves`, given in the same order of `self.leaf_indices()`,
+    /// against the `expected_root` of a Merkle Tree of height `height`.
+    pub fn verify(
+        &self,
+        height: usize,
+        leaves: &[<T::H as FieldBasedHash>::Data],
+        expected_root: &<T::H as FieldBasedHash>::Data,
+    ) -> Result<bool, Error> {
+        if self.height != height {
+            Err(MerkleTreeError::IncorrectPathLength(self.height, height))?
+        }
+        let actual_root = self.compute_root(leaves)?;
+        Ok(&actual_root == expected_root)
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> SemanticallyValid for FieldBasedMHTMultiPath<T> {
+    fn is_valid(&self) -> bool {
+        let num_leaves = match T::MERKLE_ARITY.checked_pow(self.height as u32) {
+            Some(num_leaves) => num_leaves,
+            None => return false,
+        };
+
+        // Indices must be sorted, without duplicates and in range
+        if self.leaf_indices.is_empty()
+            || self.leaf_indices.windows(2).any(|w| w[0] >= w[1])
+            || self.leaf_indices[self.leaf_indices.len() - 1] >= num_leaves
+        {
+            return false;
+        }
+
+        self.nodes.len()
+            == get_multi_path_node_positions(T::MERKLE_ARITY, self.height, &self.leaf_indices).len()
+            && self.nodes.is_valid()
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> ToBytes for FieldBasedMHTMultiPath<T> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // As for the Merkle Paths, the height is written in a single byte
+        if self.height > u8::MAX as usize {
+            return Err(IoError::new(
+                ErrorKind::InvalidInput,
+                format!("Multi-path height {} exceeds {}", self.height, u8::MAX),
+            ));
+        }
+        (self.height as u8).write(&mut writer)?;
+        (self.leaf_indices.len() as u32).write(&mut writer)?;
+        for &leaf_index in self.leaf_indices.iter() {
+            (leaf_index as u64).write(&mut writer)?;
+        }
+        (self.nodes.len() as u32).write(&mut writer)?;
+        self.nodes.write(&mut writer)
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> FromBytes for FieldBasedMHTMultiPath<T> {
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let height = u8::read(&mut reader)? as usize;
+
+        // The counts are untrusted: before allocating, check them against the maximum number
+        // of leaves of the tree, and the maximum number of nodes needed for them, i.e. all
+        // the siblings of each leaf at each level.
+        let num_leaves = u32::read(&mut reader)? as usize;
+        if num_leaves == 0 {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                "Multi-path must open at least one leaf",
+            ));
+        }
+        let max_leaves = T::MERKLE_ARITY
+            .checked_pow(height as u32)
+            .unwrap_or(usize::MAX);
+        if num_leaves > max_leaves {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Multi-path opens {} leaves, but the tree has at most {}",
+                    num_leaves, max_leaves
+                ),
+            ));
+        }
+        let mut leaf_indices = Vec::with_capacity(num_leaves);
+        for _ in 0..num_leaves {
+            leaf_indices.push(u64::read(&mut reader)? as usize);
+        }
+
+        let num_nodes = u32::read(&mut reader)? as usize;
+        let max_nodes = num_leaves
+            .saturating_mul(T::MERKLE_ARITY - 1)
+            .saturating_mul(height);
+        if num_nodes > max_nodes {
+            return Err(IoError::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "Multi-path has {} nodes, but at most {} are needed",
+                    num_nodes, max_nodes
+                ),
+            ));
+        }
+        let mut nodes = Vec::with_capacity(num_nodes);
+        for _ in 0..num_nodes {
+            nodes.push(<T::H as FieldBasedHash>::Data::read(&mut reader)?);
+        }
+        Ok(Self::new(leaf_indices, nodes, height))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        fields::Field, to_bytes, CanonicalDeserialize, CanonicalSerialize, FromBytes,
+        SemanticallyValid, ToBytes, UniformRand,
+    };
+    use rand::{seq::SliceRandom, Rng, RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    use crate::{
+        merkle_tree::field_based_mht::{
+            BatchFieldBasedMerkleTreeParameters, FieldBasedAppendOnlyMHT, FieldBasedMHTMultiPath,
+            FieldBasedMerkleTree, FieldBasedMerkleTreeParameters, FieldBasedMerkleTreePath,
+        },
+        FieldBasedMHTPath, FieldBasedMerkleTreePrecomputedZeroConstants,
+    };
+
+    fn merkle_tree_multi_path_test<T: BatchFieldBasedMerkleTreeParameters, R: RngCore>(
+        height: usize,
+        rng: &mut R,
+    ) {
+        let num_leaves = T::MERKLE_ARITY.pow(height as u32);
+        let mut tree = FieldBasedAppendOnlyMHT::<T>::init(height, num_leaves).unwrap();
+
+        // Generate random leaves, half of which empty
+        let mut leaves = Vec::with_capacity(num_leaves);
+        for _ in 0..num_leaves / 2 {
+            let leaf = T::Data::rand(rng);
+            tree.append(leaf).unwrap();
+            leaves.push(leaf);
+        }
+        leaves.resize(num_leaves, <T::Data as Field>::zero());
+
+        // Multi path can't be created if the tree is not finalized
+        assert!(tree.get_multi_path(&[0]).is_none());
+        tree.finalize_in_place().unwrap();
+        let root = tree.root().unwrap();
+
+        // Invalid indices
+        assert!(tree.get_multi_path(&[]).is_none());
+        assert!(tree.get_multi_path(&[0, num_leaves]).is_none());
+
+        let all_indices = (0..num_leaves).collect::<Vec<_>>();
+        for &num_opened_leaves in [1, 2, 3, num_leaves / 4, num_leaves - 1, num_leaves]
+            .iter()
+            .filter(|&&n| n > 0 && n <= num_leaves)
+        {
+            let indices = all_indices
+                .choose_multiple(rng, num_opened_leaves)
+                .cloned()
+                .collect::<Vec<_>>();
+            let mut sorted_indices = indices.clone();
+            sorted_indices.sort_unstable();
+            let opened_leaves = sorted_indices
+                .iter()
+                .map(|&idx| leaves[idx])
+                .collect::<Vec<_>>();
+
+            let multi_path = tree.get_multi_path(&indices).unwrap();
+            assert!(multi_path.is_valid());
+            assert_eq!(multi_path.leaf_indices(), sorted_indices.as_slice());
+            assert_eq!(multi_path.height(), height);
+            assert!(multi_path.verify(height, &opened_leaves, &root).unwrap());
+
+            // Duplicated indices are ignored
+            let mut duplicated_indices = indices.clone();
+            duplicated_indices.push(indices[0]);
+            assert_eq!(
+                tree.get_multi_path(&duplicated_indices).unwrap(),
+                multi_path
+            );
+
+            // Must be the same as the one built from the single paths
+            let paths = indices
+                .iter()
+                .map(|&idx| tree.get_merkle_path(idx).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                FieldBasedMHTMultiPath::from_paths(&paths, height).unwrap(),
+                multi_path
+            );
+
+            // Paths disagreeing on a sibling must be rejected
+            if height > 0 {
+                let mut raw_path = paths[0].get_raw_path().clone();
+                raw_path[0].0[0] += &T::Data::one();
+                let mut wrong_paths = paths.clone();
+                wrong_paths.push(FieldBasedMHTPath::<T>::new(raw_path));
+                assert!(FieldBasedMHTMultiPath::from_paths(&wrong_paths, height).is_err());
+            }
+
+            // Must be smaller and cheaper to verify than the single paths
+            assert!(
+                multi_path.get_nodes().len() <= num_opened_leaves * height * (T::MERKLE_ARITY - 1)
+            );
+            assert!(multi_path.num_hashes() <= num_opened_leaves * height);
+            if num_opened_leaves > 1 && height > 0 {
+                assert!(multi_path.num_hashes() < num_opened_leaves * height);
+            }
+
+            // Wrong root
+            assert!(!multi_path
+                .verify(height, &opened_leaves, &T::Data::rand(rng))
+                .unwrap());
+
+            // Wrong leaf
+            let mut wrong_leaves = opened_leaves.clone();
+            let wrong_leaf_idx = rng.gen_range(0..wrong_leaves.len());
+            wrong_leaves[wrong_leaf_idx] += &T::Data::one();
+            assert!(!multi_path.verify(height, &wrong_leaves, &root).unwrap());
+
+            // Wrong height
+            assert!(multi_path
+                .verify(height + 1, &opened_leaves, &root)
+                .is_err());
+
+            // Wrong number of leaves
+            assert!(multi_path
+                .verify(height, &opened_leaves[1..], &root)
+                .is_err());
+
+            // Wrong number of nodes
+            let mut nodes = multi_path.get_nodes().to_vec();
+            nodes.push(T::Data::rand(rng));
+            let wrong_multi_path =
+                FieldBasedMHTMultiPath::<T>::new(sorted_indices.clone(), nodes, height);
+            assert!(!wrong_multi_path.is_valid());
+            assert!(wrong_multi_path
+                .verify(height, &opened_leaves, &root)
+                .is_err());
+
+            // Unsorted indices
+            if num_opened_leaves > 1 {
+                let mut unsorted_indices = sorted_indices.clone();
+                unsorted_indices.swap(0, 1);
+                let wrong_multi_path = FieldBasedMHTMultiPath::<T>::new(
+                    unsorted_indices,
+                    multi_path.get_nodes().to_vec(),
+                    height,
+                );
+                assert!(!wrong_multi_path.is_valid());
+                assert!(wrong_multi_path
+                    .verify(height, &opened_leaves, &root)
+                    .is_err());
+            }
+
+            // Serialization/deserialization test
+            let multi_path_serialized = to_bytes!(multi_path).unwrap();
+            let multi_path_deserialized =
+                FieldBasedMHTMultiPath::<T>::read(multi_path_serialized.as_slice()).unwrap();
+            assert_eq!(multi_path, multi_path_deserialized);
+
+            let mut multi_path_serialized = Vec::new();
+            CanonicalSerialize::serialize(&multi_path, &mut multi_path_serialized).unwrap();
+            let multi_path_deserialized: FieldBasedMHTMultiPath<T> =
+                CanonicalDeserialize::deserialize(multi_path_serialized.as_slice()).unwrap();
+            assert_eq!(multi_path, multi_path_deserialized);
+
+            // Untrusted counts must be rejected before allocating
+            let mut wrong_serialized = to_bytes!(multi_path).unwrap();
+            wrong_serialized[1..5].copy_from_slice(&u32::MAX.to_le_bytes());
+            assert!(FieldBasedMHTMultiPath::<T>::read(wrong_serialized.as_slice()).is_err());
+
+            let mut wrong_serialized = to_bytes!(multi_path).unwrap();
+            let num_nodes_offset = 5 + 8 * num_opened_leaves;
+            wrong_serialized[num_nodes_offset..num_nodes_offset + 4]
+                .copy_from_slice(&u32::MAX.to_le_bytes());
+            assert!(FieldBasedMHTMultiPath::<T>::read(wrong_serialized.as_slice()).is_err());
+        }
+
+        // Heights not fitting into a byte can't be serialized
+        let multi_path = FieldBasedMHTMultiPath::<T>::new(vec![0], vec![], u8::MAX as usize + 1);
+        assert!(to_bytes!(multi_path).is_err());
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr() {
+        use crate::{
+            crh::{TweedleFrBatchPoseidonHash, TweedleFrPoseidonHash},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS,
+        };
+        use algebra::fields::tweedle::Fr;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHash;
+            const MERKLE_ARITY: usize = 2;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHash;
+        }
+
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        for height in 1..=6 {
+            merkle_tree_multi_path_test::<TweedleFrFieldBasedMerkleTreeParams, _>(height, rng);
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr_arity_4() {
+        use crate::{
+            crh::{TweedleFrBatchPoseidonHashRate4, TweedleFrPoseidonHashRate4},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        };
+        use algebra::fields::tweedle::Fr;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHashRate4;
+            const MERKLE_ARITY: usize = 4;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHashRate4;
+        }
+
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        for height in 1..=3 {
+            merkle_tree_multi_path_test::<TweedleFrFieldBasedMerkleTreeParams, _>(height, rng);
+        }
+    }
+}
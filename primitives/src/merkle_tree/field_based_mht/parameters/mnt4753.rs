@@ -1,7 +1,10 @@
 use algebra::{biginteger::BigInteger768, field_new, fields::mnt4753::Fr as MNT4753Fr};
 
 use crate::{
-    crh::poseidon::parameters::mnt4753::MNT4PoseidonHash,
+    crh::poseidon::parameters::{
+        mnt4753::MNT4PoseidonHash, mnt4753_rate4::MNT4PoseidonHashRate4,
+        mnt4753_rate8::MNT4PoseidonHashRate8,
+    },
     FieldBasedMerkleTreePrecomputedZeroConstants,
 };
 
@@ -564,12 +567,466 @@ pub const MNT4753_MHT_POSEIDON_PARAMETERS: FieldBasedMerkleTreePrecomputedZeroCo
     merkle_arity: 2,
 };
 
+pub const MNT4753_MHT_POSEIDON_PARAMETERS_ARITY_4: FieldBasedMerkleTreePrecomputedZeroConstants<
+    'static,
+    MNT4PoseidonHashRate4,
+> = FieldBasedMerkleTreePrecomputedZeroConstants {
+    nodes: &[
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                11079924189488669119,
+                5534797216488597889,
+                3245547851323668389,
+                10946214953968227336,
+                14452097653622422251,
+                15029932355293234612,
+                15889613229916072780,
+                9253813042175674695,
+                5986138928690516305,
+                14981116838781160480,
+                8915118665710138566,
+                215398982336473
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                16585367758868898739,
+                14011999956197634026,
+                7895451278368439730,
+                14982574906844315193,
+                4183357798285630260,
+                17590153443667858611,
+                1008796212795106266,
+                1113588563370041733,
+                12181239068611907377,
+                17097249098406122009,
+                13136979316739470003,
+                250202739160555
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                12851855598170568054,
+                4653231714083591285,
+                14955542714800589885,
+                12848978751097277610,
+                4162309682523358240,
+                13369219086416254222,
+                9750785159479329236,
+                15586451028716771461,
+                13400058284924881561,
+                16372660047776238158,
+                6397382426274550802,
+                349416241295282
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                7955396272951460864,
+                16465646595488747203,
+                6054545625693367377,
+                10290123171672149238,
+                13417692840551197130,
+                14920459545458455143,
+                6852228183913509913,
+                3254331945594608509,
+                8952702386323075256,
+                1110887103808840394,
+                13664025819232407591,
+                372035753239013
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                999412431884586440,
+                3114698269600291772,
+                13512510055382925227,
+                2068479917110489766,
+                12399189143367291397,
+                18091216723178444287,
+                6339974022173733780,
+                776015057619168181,
+                16160009165991357202,
+                1479846689046177733,
+                9094410295529683800,
+                433004331196251
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                17668733017763570912,
+                546099427357406351,
+                13692099976788042333,
+                102548423959532358,
+                3502168447743674863,
+                4274772025618688995,
+                7992961376544173739,
+                3527241498756122411,
+                8825379628616239035,
+                1587910980969154266,
+                6222909446052830948,
+                299843530588857
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                5518019253653454421,
+                17510731282126346322,
+                15138579448605492313,
+                13085306948668969115,
+                13877958767547811967,
+                13006536248581499003,
+                7158010458426882776,
+                7970288646958144602,
+                5841569626850035470,
+                16173623221164064576,
+                8567765135555607843,
+                439244446168074
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                746094968844065524,
+                3727067158819783548,
+                14454561884092377650,
+                8554598757972179947,
+                8751515197027475226,
+                17115085244865922547,
+                5486828628863110575,
+                7057666152255211958,
+                9685595832092900163,
+                11418439269154810011,
+                13936993215659279797,
+                414996304456991
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                10826840894170310201,
+                643075871446856905,
+                10415711870384935979,
+                11692588995800101264,
+                827683129936461698,
+                10012387977299715298,
+                8503900935078551568,
+                8776090514803421161,
+                18426020233023625317,
+                10339963690204483684,
+                13607625033034735967,
+                121521369694131
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                11173752322798581649,
+                16752473894293227085,
+                9348167887405835531,
+                2071722828290963971,
+                15144820638983184940,
+                5428396078373707534,
+                14777483788350810339,
+                13978477706765055288,
+                2973891678033157848,
+                12948483877696545763,
+                6756766547279088697,
+                16887529120384
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                10309226950925534492,
+                11420169875563565270,
+                11656855053187990346,
+                16519053496510006068,
+                1288428067648156320,
+                13625348545743422121,
+                8161825770969919722,
+                4615830331561479269,
+                2491910665980093492,
+                15425870811446929954,
+                6293263483630095219,
+                23412271741010
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                3180696071873659686,
+                8126108211920146163,
+                12932555116256279166,
+                15683109067985642357,
+                6186005995550475264,
+                6985815645554339875,
+                10868670803477381956,
+                3723012748348489986,
+                8301608016238051293,
+                18039883379882367174,
+                15257110115291530155,
+                437163007054665
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                5765756911842329274,
+                13079019944717358906,
+                11708240995747832458,
+                3563532679617216133,
+                4915413154394192092,
+                11131633245048798468,
+                12614083523909683341,
+                5522692045731169229,
+                8873648864172885746,
+                10280864988279882407,
+                12095899964319201682,
+                411766049275589
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                9774686153173874929,
+                15768186445710797797,
+                11659265236535754338,
+                14841828882245384607,
+                1609705960423154972,
+                16572249717856134034,
+                5462551534999389982,
+                13846425846557922850,
+                4510660544394999958,
+                13403306381421206290,
+                979466112231541465,
+                288863095664129
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                13733553892252470700,
+                13083872052651045570,
+                4583123516074910593,
+                4037814723424455970,
+                3694463648028017572,
+                11782147586476589517,
+                5850183952349277323,
+                14360053079254683199,
+                16946244397229802134,
+                7287804318510539999,
+                2208648898649082660,
+                443378830515025
+            ])
+        ),
+    ],
+    merkle_arity: 4,
+};
+
+pub const MNT4753_MHT_POSEIDON_PARAMETERS_ARITY_8: FieldBasedMerkleTreePrecomputedZeroConstants<
+    'static,
+    MNT4PoseidonHashRate8,
+> = FieldBasedMerkleTreePrecomputedZeroConstants {
+    nodes: &[
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                17647840187488685910,
+                4425373948043239700,
+                355690529666425407,
+                12194781006823029557,
+                9757709055380090596,
+                10819730313240353771,
+                1061093052465121709,
+                5626265700945533470,
+                8805696252641118314,
+                275143221027860001,
+                17802881768267407982,
+                406454976411370
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                1300257626533322150,
+                17618322895873082946,
+                18061964998319478726,
+                17006968240632507254,
+                3818749741763820673,
+                7804899310167655541,
+                6071015437252686712,
+                13941538078841030700,
+                16061126893481835993,
+                17630017898664187377,
+                14893769503535091855,
+                197824747023286
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                5489570683058109565,
+                15248817810928933032,
+                4140422807182189655,
+                1406182689020353765,
+                13330282152250609672,
+                6053614679686361906,
+                2193097724381374706,
+                6902845061222858968,
+                9974106645519157002,
+                1344663372900148155,
+                11919946824958239325,
+                488699953810837
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                17365558794565525186,
+                7624642543523554159,
+                6256811157463973444,
+                5500553727225791353,
+                4460603199168007739,
+                8943389510494926933,
+                5505343712533714081,
+                11461242868629672114,
+                17264768621516066515,
+                7944632863586881112,
+                10801284617165798245,
+                395448430799778
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                2080612720969104386,
+                17283870176932230626,
+                18369200241113731274,
+                18236208944265270498,
+                2088619851627080758,
+                8677765081944467889,
+                11549991045399242757,
+                18233863443080153693,
+                6899445473952744163,
+                11078047735476812470,
+                6640507334804784675,
+                479290877938899
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                15728294018610962442,
+                3750331821612031556,
+                5988263292175256576,
+                9723921517507320062,
+                7395941136614441445,
+                15646801751194824505,
+                6551631131482838767,
+                881297098660174590,
+                16718190001774750910,
+                5928342343957782127,
+                12722236942542783279,
+                30656897256059
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                5277208853550890328,
+                2969831645276814766,
+                7645035420856610368,
+                11337503518284204105,
+                1745640184589295824,
+                5724297552298596783,
+                1763536208848990130,
+                11917863038247194753,
+                3167681578675603142,
+                14817895232987745593,
+                4464898422702712643,
+                337096563522513
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                12835299946370153834,
+                10826403624598913039,
+                11500676208624902141,
+                9866506058208775547,
+                2376307749528625405,
+                13361152156946989568,
+                2911341610985042052,
+                10878526029508675849,
+                12159710591947701210,
+                13308667435436536098,
+                206185078537862411,
+                173259803716902
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                831413228569631038,
+                11214819954355004465,
+                10142325365775260346,
+                17050718792417230610,
+                3947042431434913626,
+                12187510639435656042,
+                9373004264589011699,
+                18246728070225484466,
+                7783970492519564090,
+                16882242714690371463,
+                17247685892396653423,
+                320421316155881
+            ])
+        ),
+        field_new!(
+            MNT4753Fr,
+            BigInteger768([
+                402216883016883357,
+                16857220117687165924,
+                16300025251775971151,
+                16002488123949238899,
+                16503580430671419729,
+                8780020286951690139,
+                12587480707624177629,
+                10689191268963412248,
+                3546698504068599328,
+                18290291619803464980,
+                8370612228372516600,
+                230381833961260
+            ])
+        ),
+    ],
+    merkle_arity: 8,
+};
+
 #[cfg(test)]
 mod test {
 
-    use super::{MNT4753_MHT_POSEIDON_PARAMETERS, MNT4753_PHANTOM_MERKLE_ROOT};
+    use super::{
+        MNT4753_MHT_POSEIDON_PARAMETERS, MNT4753_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        MNT4753_MHT_POSEIDON_PARAMETERS_ARITY_8, MNT4753_PHANTOM_MERKLE_ROOT,
+    };
     use crate::{
-        crh::MNT4PoseidonHash,
+        crh::{MNT4PoseidonHash, MNT4PoseidonHashRate4, MNT4PoseidonHashRate8},
         merkle_tree::field_based_mht::parameters::{
             generate_mht_empty_nodes, generate_phantom_merkle_root_from_magic_string,
         },
@@ -602,4 +1059,44 @@ mod test {
         };
         assert_eq!(params, MNT4753_MHT_POSEIDON_PARAMETERS)
     }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_4_mnt4753_mht_empty_nodes() {
+        let merkle_arity = 4;
+        let max_height = 16;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fr, MNT4PoseidonHashRate4>(
+            merkle_arity,
+            max_height,
+            Fr::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<MNT4PoseidonHashRate4> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, MNT4753_MHT_POSEIDON_PARAMETERS_ARITY_4)
+    }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_8_mnt4753_mht_empty_nodes() {
+        let merkle_arity = 8;
+        let max_height = 11;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fr, MNT4PoseidonHashRate8>(
+            merkle_arity,
+            max_height,
+            Fr::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<MNT4PoseidonHashRate8> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, MNT4753_MHT_POSEIDON_PARAMETERS_ARITY_8)
+    }
 }
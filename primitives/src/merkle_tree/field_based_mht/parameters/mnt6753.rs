@@ -1,7 +1,10 @@
 use algebra::{biginteger::BigInteger768, field_new, fields::mnt6753::Fr as MNT6753Fr};
 
 use crate::{
-    crh::poseidon::parameters::mnt6753::MNT6PoseidonHash,
+    crh::poseidon::parameters::{
+        mnt6753::MNT6PoseidonHash, mnt6753_rate4::MNT6PoseidonHashRate4,
+        mnt6753_rate8::MNT6PoseidonHashRate8,
+    },
     FieldBasedMerkleTreePrecomputedZeroConstants,
 };
 
@@ -564,12 +567,466 @@ pub const MNT6753_MHT_POSEIDON_PARAMETERS: FieldBasedMerkleTreePrecomputedZeroCo
     merkle_arity: 2,
 };
 
+pub const MNT6753_MHT_POSEIDON_PARAMETERS_ARITY_4: FieldBasedMerkleTreePrecomputedZeroConstants<
+    'static,
+    MNT6PoseidonHashRate4,
+> = FieldBasedMerkleTreePrecomputedZeroConstants {
+    nodes: &[
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                14398475285125644166,
+                6005165928450354645,
+                13667803730730602850,
+                8799615476606724126,
+                16689532001420998960,
+                8526983625793096080,
+                999147445658339539,
+                10290504893326363082,
+                16124600580500206039,
+                9438973923602244065,
+                18238148489002262789,
+                278769195377229
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                1744187114552682225,
+                14108270076289279584,
+                4887111166915613777,
+                15425905923162821501,
+                9289504968923660416,
+                2753398565671903185,
+                13951522857128865752,
+                10738865785526735759,
+                15177434742510047379,
+                12990020268230529266,
+                1954101113292335563,
+                212621469947033
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                9936852021123605966,
+                1005630560633219206,
+                12552555594871298187,
+                12788537739415853274,
+                3494440393940515507,
+                274332805898142536,
+                2466471620925376693,
+                15128734208099565002,
+                8441231754615374922,
+                9350763376173529634,
+                16689092027372074596,
+                443465454064565
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                1671379110782715334,
+                15461329034544015332,
+                6192857734967070539,
+                16634689432134818471,
+                10478731122630619974,
+                13039059456592216904,
+                3367918549636990423,
+                7289451163550478928,
+                10487170992843049116,
+                7406784936624315589,
+                15919491920801315379,
+                452691193581430
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                17001923873616936195,
+                9943412417939585750,
+                1071615611294855100,
+                16438285742903251965,
+                235669017446796374,
+                13046828649852179234,
+                6757897599285207482,
+                11127360121008325927,
+                5039819865508913194,
+                16123293669135212860,
+                9274188838552151913,
+                52847928251963
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                12682958333284829337,
+                1903049598745286750,
+                3942422498178294386,
+                718142088130612122,
+                13098199141130892489,
+                13454132576616278570,
+                7137255725306089494,
+                1804876976126093924,
+                12257217836085490871,
+                15963373476927533531,
+                11627471064675884749,
+                430859891419882
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                12767956733907702147,
+                1301730787191121323,
+                11468993243490279147,
+                1992091231864668752,
+                13623050278125996641,
+                494827137280388683,
+                2358501042092569766,
+                2389726642958309782,
+                1963532714728386695,
+                5575748261123771802,
+                15388023001020335827,
+                284706214803612
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                12796147080675447004,
+                13061876939610751073,
+                13742409057115678260,
+                8667072721225986081,
+                12824441702223160973,
+                13083943353317740655,
+                4817813991439617244,
+                2982582463175547324,
+                2202143712101677790,
+                8806865762241161323,
+                16578544650917916418,
+                472345469586603
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                5785933194370281367,
+                10679502955974074238,
+                15855247759279299450,
+                8854469354447091222,
+                12197131465544270045,
+                6356933873530329404,
+                5983673420717365101,
+                13555043511281406028,
+                16844818116891316107,
+                2558958336756512255,
+                3655058829438122989,
+                52083559340597
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                4108451418690811876,
+                14429990460760923,
+                1872589441369968655,
+                7393460719673008335,
+                11511858583080539859,
+                9749238489583019411,
+                18058120142413002218,
+                7456474207583584400,
+                1848251848784271443,
+                15591506263440668248,
+                4836263928982662846,
+                311354185787817
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                11485891174555928464,
+                10822702068314245303,
+                12134586892823518544,
+                15181459901164769677,
+                1719362134335679619,
+                11153586802236952475,
+                2600968438767092939,
+                1507101739635396951,
+                17651638879849329143,
+                1987311325724891515,
+                2073585729877586712,
+                40341285410298
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                6059822562055360202,
+                15113339779022708245,
+                7886038298916122674,
+                9659211110702029464,
+                12917661021714451675,
+                11610563367769086346,
+                1886892874095723285,
+                6580621141763702662,
+                14165503458701035068,
+                12409065787960513376,
+                9683186472327812420,
+                481163618585572
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                6910276228389588133,
+                4709408595413704154,
+                13193971837037088016,
+                17108075497298987787,
+                6507561272758439665,
+                18031295612534229493,
+                13919734882357967960,
+                4698430467217448545,
+                13381676443168779216,
+                1676726122373419540,
+                8445862757962695499,
+                51336221952992
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                3856498696836738562,
+                17783971021577457690,
+                8231836474085779855,
+                9691331094664638585,
+                7407478399943144251,
+                15819652286196296577,
+                15696187253496231002,
+                16075002046913092819,
+                16892917325609970014,
+                2527351371631404456,
+                11796147891991013247,
+                201266403798604
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                11454871245554309361,
+                81033917344021784,
+                5299034091593002596,
+                14570819637430478936,
+                11669597628378301420,
+                2354406173672972294,
+                8042628676500284806,
+                17996729204445989022,
+                15611397836130391312,
+                15674578553483556977,
+                15379405086135027100,
+                423878030622616
+            ])
+        ),
+    ],
+    merkle_arity: 4,
+};
+
+pub const MNT6753_MHT_POSEIDON_PARAMETERS_ARITY_8: FieldBasedMerkleTreePrecomputedZeroConstants<
+    'static,
+    MNT6PoseidonHashRate8,
+> = FieldBasedMerkleTreePrecomputedZeroConstants {
+    nodes: &[
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                9390759779637358027,
+                955694952009849823,
+                8322885653569020160,
+                2009294907018842369,
+                1102041287763114173,
+                372787030027648784,
+                17240831097052216712,
+                18091595591100412911,
+                11547516584946030713,
+                1046619136353976108,
+                9159940885984727056,
+                295719118545687
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                7495265468178200820,
+                4022885902323442886,
+                191436010423601088,
+                5989227285126047196,
+                14168915059601137435,
+                68290882335910490,
+                17257557564014222121,
+                10722534646308388762,
+                10707295238883704304,
+                2812131301780123191,
+                6107634900038024386,
+                284056494992506
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                6647575824779456728,
+                1768183189698663752,
+                10025778590384193649,
+                16456717634506448134,
+                12280962400872667118,
+                18124600771300929282,
+                10508322490663427723,
+                9893892287820728384,
+                3664446483028513149,
+                993071021474709781,
+                8046282563804365048,
+                113756450389784
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                7996128561045295120,
+                5674754234714023212,
+                4726213881782501185,
+                2552172802099997004,
+                6527879123541096989,
+                8777692544945381290,
+                5682443429142422734,
+                9463916128361153636,
+                13246935743407193305,
+                11018600704539438683,
+                1877848079295434977,
+                181323026565017
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                11370514761531028283,
+                17023868775301986962,
+                311976413682589084,
+                10131799230450195382,
+                1082097557947383410,
+                13425767568213660071,
+                3001158249025888861,
+                4150998747426122206,
+                1229709324998486184,
+                947699279912124320,
+                15037359690163014504,
+                89426963374489
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                2867694067139995169,
+                17415269864328965081,
+                11655417240380789554,
+                4030204041337382991,
+                15898756729787109540,
+                4049542200670202477,
+                17234427035602854810,
+                11681270282563540504,
+                5036426667927295086,
+                9886035336331637469,
+                15278180735285017562,
+                493924875137120
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                11011775927776904388,
+                11663287414680959819,
+                15127363188048455700,
+                9598053575926844380,
+                17670025469179754135,
+                7863763478351336427,
+                18416591206851803295,
+                13229296872562849462,
+                6678666535110554198,
+                3338440194839692093,
+                240637147450588104,
+                105730247939213
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                17567819863013986383,
+                8669825843344278716,
+                17193321382749802300,
+                3087405061881545929,
+                15709198830857841784,
+                4928628357093911615,
+                6919071922436758876,
+                1099248690440244814,
+                1924549299401397099,
+                11256888242242541279,
+                5991102493187983132,
+                44933739393415
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                13459264349510940990,
+                13867234583778493762,
+                513484421163947222,
+                4159008212054594143,
+                9090941227436674483,
+                13920338205050368920,
+                14423922242342237101,
+                11930132658813753282,
+                7248552796736061860,
+                4610421484273665329,
+                9347297087905917517,
+                432414189800086
+            ])
+        ),
+        field_new!(
+            MNT6753Fr,
+            BigInteger768([
+                11915602596239318989,
+                12254792336983258080,
+                10474204153746938771,
+                5144651325951801897,
+                10708860818672559947,
+                5587099938318691323,
+                11594480830386059187,
+                10843189495491515991,
+                8277026911038917914,
+                5114959767847278822,
+                11404978798624931341,
+                139726708310758
+            ])
+        ),
+    ],
+    merkle_arity: 8,
+};
+
 #[cfg(test)]
 mod test {
 
-    use super::{MNT6753_MHT_POSEIDON_PARAMETERS, MNT6753_PHANTOM_MERKLE_ROOT};
+    use super::{
+        MNT6753_MHT_POSEIDON_PARAMETERS, MNT6753_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        MNT6753_MHT_POSEIDON_PARAMETERS_ARITY_8, MNT6753_PHANTOM_MERKLE_ROOT,
+    };
     use crate::{
-        crh::MNT6PoseidonHash,
+        crh::{MNT6PoseidonHash, MNT6PoseidonHashRate4, MNT6PoseidonHashRate8},
         merkle_tree::field_based_mht::parameters::{
             generate_mht_empty_nodes, generate_phantom_merkle_root_from_magic_string,
         },
@@ -603,4 +1060,44 @@ mod test {
 
         assert_eq!(params, MNT6753_MHT_POSEIDON_PARAMETERS)
     }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_4_mnt6753_mht_empty_nodes() {
+        let merkle_arity = 4;
+        let max_height = 16;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fr, MNT6PoseidonHashRate4>(
+            merkle_arity,
+            max_height,
+            Fr::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<MNT6PoseidonHashRate4> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, MNT6753_MHT_POSEIDON_PARAMETERS_ARITY_4)
+    }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_8_mnt6753_mht_empty_nodes() {
+        let merkle_arity = 8;
+        let max_height = 11;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fr, MNT6PoseidonHashRate8>(
+            merkle_arity,
+            max_height,
+            Fr::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<MNT6PoseidonHashRate8> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, MNT6753_MHT_POSEIDON_PARAMETERS_ARITY_8)
+    }
 }
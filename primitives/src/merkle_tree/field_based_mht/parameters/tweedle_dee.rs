@@ -1,6 +1,11 @@
 use algebra::{biginteger::BigInteger256, field_new, fields::tweedle::Fr as TweedleFr};
 
-use crate::{crh::poseidon::TweedleFrPoseidonHash, FieldBasedMerkleTreePrecomputedZeroConstants};
+use crate::{
+    crh::poseidon::{
+        TweedleFrPoseidonHash, TweedleFrPoseidonHashRate4, TweedleFrPoseidonHashRate8,
+    },
+    FieldBasedMerkleTreePrecomputedZeroConstants,
+};
 
 // PoseidonHash("This represents an empty Merkle Root for a TweedleDeePoseidonHash based Merkle Tree.")
 pub const TWEEDLE_DEE_PHANTOM_MERKLE_ROOT: TweedleFr = field_new!(
@@ -302,12 +307,258 @@ pub const TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS: FieldBasedMerkleTreePrecomputedZe
     merkle_arity: 2,
 };
 
+pub const TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4:
+    FieldBasedMerkleTreePrecomputedZeroConstants<'static, TweedleFrPoseidonHashRate4> =
+    FieldBasedMerkleTreePrecomputedZeroConstants {
+        nodes: &[
+            field_new!(TweedleFr, BigInteger256([0, 0, 0, 0])),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    10342794021525212292,
+                    14305289849745357656,
+                    4356991565218234020,
+                    2005960132823634448
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    59466719769873716,
+                    11027468045809152493,
+                    17557557023135369824,
+                    502008567163212450
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    10808374672561364867,
+                    501150740579225449,
+                    3556016308231468341,
+                    1440474258897651234
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    8476693439943048043,
+                    5702594749620902857,
+                    12693262681167625255,
+                    3507895727424290451
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    17631185253622148763,
+                    3858609493270395935,
+                    2381635605408126953,
+                    2408873283661025251
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    14328447679641538598,
+                    9705310323198588968,
+                    9363237215894073007,
+                    37917595985303791
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    2614256749715466205,
+                    10676129084425421657,
+                    69926395392733418,
+                    3450892161953780005
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    2994324780216325071,
+                    12901362931261376793,
+                    9996642625453171355,
+                    518408645461850845
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    11882981805684048089,
+                    8042598445195716106,
+                    12928671261413540765,
+                    2385038550507026031
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    16320516423704712179,
+                    5392283477258976607,
+                    9053903123286008133,
+                    1551548517166561341
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    5992235418143110457,
+                    12160434947325100158,
+                    13645708713011782225,
+                    576834117767115466
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    15121791454050627109,
+                    9966550381187951953,
+                    5801640068231457317,
+                    1113499732522378515
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    15511799961056980132,
+                    7402522454553130603,
+                    761515095554409359,
+                    4146186813455318836
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    10285287253216095111,
+                    666639999118095034,
+                    107532744296471177,
+                    2381856011337412985
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    11702687608873702507,
+                    1405738614179958568,
+                    1742126358131879660,
+                    4536217382508663495
+                ])
+            ),
+        ],
+        merkle_arity: 4,
+    };
+
+pub const TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8:
+    FieldBasedMerkleTreePrecomputedZeroConstants<'static, TweedleFrPoseidonHashRate8> =
+    FieldBasedMerkleTreePrecomputedZeroConstants {
+        nodes: &[
+            field_new!(TweedleFr, BigInteger256([0, 0, 0, 0])),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    15674503483330244649,
+                    8308150841481784979,
+                    13961820885988832566,
+                    1070452988432111885
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    16879240268619180513,
+                    17235345449183067040,
+                    6138663967468352929,
+                    2965127317080677666
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    15711878068615519801,
+                    6092114196684266892,
+                    7805399808365610997,
+                    934334888704394177
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    8614670119623988456,
+                    431697106473114085,
+                    1027821715563727560,
+                    1877573284921547445
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    11792657632349938342,
+                    15059945528749264384,
+                    6608741593317492831,
+                    4584780613559198486
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    8521373775427512473,
+                    13679843202302821980,
+                    4294453008693412542,
+                    265959559234270854
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    1030573325961115975,
+                    18152690075175001221,
+                    2487747393293016831,
+                    2046180805931536683
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    6057398300811555446,
+                    16721676019394517125,
+                    7420915334540906354,
+                    1852367571065553488
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    11624134246768186570,
+                    15102335957453379783,
+                    13211669054685819876,
+                    4230727098218990758
+                ])
+            ),
+            field_new!(
+                TweedleFr,
+                BigInteger256([
+                    3285582908042213501,
+                    6922179670177434138,
+                    14370465274179289232,
+                    1026123106806074757
+                ])
+            ),
+        ],
+        merkle_arity: 8,
+    };
+
 #[cfg(test)]
 mod test {
 
-    use super::{TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS, TWEEDLE_DEE_PHANTOM_MERKLE_ROOT};
+    use super::{
+        TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS, TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8, TWEEDLE_DEE_PHANTOM_MERKLE_ROOT,
+    };
     use crate::{
-        crh::TweedleFrPoseidonHash,
+        crh::{TweedleFrPoseidonHash, TweedleFrPoseidonHashRate4, TweedleFrPoseidonHashRate8},
         merkle_tree::field_based_mht::parameters::{
             generate_mht_empty_nodes, generate_phantom_merkle_root_from_magic_string,
         },
@@ -346,4 +597,44 @@ mod test {
         };
         assert_eq!(params, TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS)
     }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_4_tweedle_fr_mht_empty_nodes() {
+        let merkle_arity = 4;
+        let max_height = 16;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fr, TweedleFrPoseidonHashRate4>(
+            merkle_arity,
+            max_height,
+            Fr::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<TweedleFrPoseidonHashRate4> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4)
+    }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_8_tweedle_fr_mht_empty_nodes() {
+        let merkle_arity = 8;
+        let max_height = 11;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fr, TweedleFrPoseidonHashRate8>(
+            merkle_arity,
+            max_height,
+            Fr::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<TweedleFrPoseidonHashRate8> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8)
+    }
 }
@@ -1,6 +1,11 @@
 use algebra::{biginteger::BigInteger256, field_new, fields::tweedle::Fq as TweedleFq};
 
-use crate::{crh::poseidon::TweedleFqPoseidonHash, FieldBasedMerkleTreePrecomputedZeroConstants};
+use crate::{
+    crh::poseidon::{
+        TweedleFqPoseidonHash, TweedleFqPoseidonHashRate4, TweedleFqPoseidonHashRate8,
+    },
+    FieldBasedMerkleTreePrecomputedZeroConstants,
+};
 
 // PoseidonHash("This represents an empty Merkle Root for a TweedleDumPoseidonHash based Merkle Tree.")
 pub const TWEEDLE_DUM_PHANTOM_MERKLE_ROOT: TweedleFq = field_new!(
@@ -302,12 +307,258 @@ pub const TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS: FieldBasedMerkleTreePrecomputedZe
     merkle_arity: 2,
 };
 
+pub const TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS_ARITY_4:
+    FieldBasedMerkleTreePrecomputedZeroConstants<'static, TweedleFqPoseidonHashRate4> =
+    FieldBasedMerkleTreePrecomputedZeroConstants {
+        nodes: &[
+            field_new!(TweedleFq, BigInteger256([0, 0, 0, 0])),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    14479700840307632126,
+                    8028384946632385175,
+                    7856251928535779378,
+                    2058159480938466481
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    13118861878358492268,
+                    9957858205127503210,
+                    9206703735486662715,
+                    4043495538661818804
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    14636938132774142577,
+                    18237717163038724374,
+                    4663844711608815616,
+                    2312265981305116981
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    11165640884077355058,
+                    13827246850738646218,
+                    15300639289841925483,
+                    3828880421491384457
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    14691983321724220820,
+                    8098458488360841531,
+                    17777614159202665857,
+                    4526867020389871614
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    11034878609967561720,
+                    1469163605282755458,
+                    5105265445432578838,
+                    1158483213589523545
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    6907651548420727818,
+                    7642574737912744314,
+                    5178167419125068097,
+                    924909719434242638
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    14464412565411637468,
+                    17760505334809657154,
+                    17596733635386266226,
+                    2180571917205204678
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    8148242268686552590,
+                    12324378483850627661,
+                    37603711703255058,
+                    3158589753098159932
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    16610244589411397783,
+                    17851776782132321020,
+                    8579622560689397686,
+                    1183503527130972011
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    12451044414384392253,
+                    4874003605883388883,
+                    18224973342709439152,
+                    2817164058059729676
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    11919709958389788340,
+                    4452463132595535475,
+                    11082251621518950950,
+                    74054380461993472
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    4675405263130217524,
+                    10238809389316581200,
+                    2588327068146088021,
+                    2149084084729298999
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    8099800383446835717,
+                    11947416365894925512,
+                    10778111669147883315,
+                    126065271370060692
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    6152860691062196246,
+                    8999497286371717947,
+                    13219352796872062196,
+                    1493907053977933697
+                ])
+            ),
+        ],
+        merkle_arity: 4,
+    };
+
+pub const TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS_ARITY_8:
+    FieldBasedMerkleTreePrecomputedZeroConstants<'static, TweedleFqPoseidonHashRate8> =
+    FieldBasedMerkleTreePrecomputedZeroConstants {
+        nodes: &[
+            field_new!(TweedleFq, BigInteger256([0, 0, 0, 0])),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    4011656190079589422,
+                    14255315334961426652,
+                    12407755220129612354,
+                    503852501636894961
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    1827136194416196143,
+                    11153035946806083024,
+                    7833800897977270822,
+                    922877699228025705
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    10186589388940441513,
+                    5978894431913926979,
+                    6740112045618371074,
+                    2034544071498550630
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    2161093537589479111,
+                    14273302168207770378,
+                    13907539504683737756,
+                    4331989445836131245
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    10294919971722843680,
+                    4691424087339838246,
+                    1444299601484358098,
+                    4218659750231608793
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    14797474062247867108,
+                    8895258334028715694,
+                    14541339225747798764,
+                    704169161600756108
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    7630624732502223983,
+                    17225098389912898889,
+                    2113276564975102594,
+                    3369794207336348406
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    1606439287912539240,
+                    712982549978155759,
+                    17071477402639493670,
+                    3718209048165051416
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    9930898648866556148,
+                    10372121136331071062,
+                    9599141486271829414,
+                    367603750817482779
+                ])
+            ),
+            field_new!(
+                TweedleFq,
+                BigInteger256([
+                    1688319863188494809,
+                    9947970344316389094,
+                    9421203693199255265,
+                    3025493782216663083
+                ])
+            ),
+        ],
+        merkle_arity: 8,
+    };
+
 #[cfg(test)]
 mod test {
 
-    use super::{TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS, TWEEDLE_DUM_PHANTOM_MERKLE_ROOT};
+    use super::{
+        TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS, TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS_ARITY_8, TWEEDLE_DUM_PHANTOM_MERKLE_ROOT,
+    };
     use crate::{
-        crh::TweedleFqPoseidonHash,
+        crh::{TweedleFqPoseidonHash, TweedleFqPoseidonHashRate4, TweedleFqPoseidonHashRate8},
         merkle_tree::field_based_mht::parameters::{
             generate_mht_empty_nodes, generate_phantom_merkle_root_from_magic_string,
         },
@@ -346,4 +597,44 @@ mod test {
         };
         assert_eq!(params, TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS)
     }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_4_tweedle_fq_mht_empty_nodes() {
+        let merkle_arity = 4;
+        let max_height = 16;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fq, TweedleFqPoseidonHashRate4>(
+            merkle_arity,
+            max_height,
+            Fq::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<TweedleFqPoseidonHashRate4> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS_ARITY_4)
+    }
+
+    #[ignore]
+    #[test]
+    fn test_generate_arity_8_tweedle_fq_mht_empty_nodes() {
+        let merkle_arity = 8;
+        let max_height = 11;
+
+        let empty_nodes = generate_mht_empty_nodes::<Fq, TweedleFqPoseidonHashRate8>(
+            merkle_arity,
+            max_height,
+            Fq::zero(),
+        );
+        assert_eq!(empty_nodes.len(), max_height);
+
+        let params = FieldBasedMerkleTreePrecomputedZeroConstants::<TweedleFqPoseidonHashRate8> {
+            nodes: empty_nodes.as_slice(),
+            merkle_arity,
+        };
+        assert_eq!(params, TWEEDLE_DUM_MHT_POSEIDON_PARAMETERS_ARITY_8)
+    }
 }
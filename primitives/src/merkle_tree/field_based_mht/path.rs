@@ -1,4 +1,4 @@
-use crate::{crh::*, field_based_mht::*};
+use crate::{crh::*, field_based_mht::*, MerkleTreeError};
 use algebra::{serialize::*, SemanticallyValid};
 use std::{
     clone::Clone,
@@ -8,7 +8,6 @@ use std::{
 
 /// An implementation of the FieldBasedMerkleTreePath trait, for a given FieldBasedHash and
 /// FieldBasedMerkleTree with arbitrary arity.
-/// TODO: Test for arity > 2
 #[derive(Derivative)]
 #[derivative(
     Clone(bound = ""),
@@ -16,7 +15,7 @@ use std::{
     Default(bound = ""),
     Eq(bound = "")
 )]
-#[derive(Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct FieldBasedMHTPath<T: FieldBasedMerkleTreeParameters> {
     path: Vec<(Vec<<T::H as FieldBasedHash>::Data>, usize)>,
 }
@@ -191,7 +190,7 @@ impl<T: FieldBasedMerkleTreeParameters> FromBytes for FieldBasedMHTPath<T> {
     Default(bound = ""),
     Eq(bound = "")
 )]
-#[derive(Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct FieldBasedBinaryMHTPath<T: FieldBasedMerkleTreeParameters> {
     path: Vec<(<T::H as FieldBasedHash>::Data, bool)>,
 }
@@ -339,6 +338,280 @@ impl<T: FieldBasedMerkleTreeParameters> FromBytes for FieldBasedBinaryMHTPath<T>
     }
 }
 
+// Compact serialization utilities for Merkle Paths: the length of the path (1 byte) and the
+// index of the leaf (8 bytes) are written once, followed by the siblings only, level by level
+// starting from the leaf. The position of each node among its siblings is recovered from the
+// leaf index, thus paths whose leaf index doesn't fit into 64 bits can't be serialized.
+
+/// Pack the `positions` of the nodes of a path, starting from the leaf level, into the index
+/// of the leaf in a Merkle Tree of arity `arity`.
+fn positions_to_leaf_index(
+    arity: usize,
+    positions: impl DoubleEndedIterator<Item = usize>,
+) -> Result<u64, SerializationError> {
+    positions.rev().try_fold(0u64, |leaf_index, position| {
+        if position >= arity {
+            return Err(SerializationError::InvalidData);
+        }
+        leaf_index
+            .checked_mul(arity as u64)
+            .and_then(|leaf_index| leaf_index.checked_add(position as u64))
+            .ok_or(SerializationError::InvalidData)
+    })
+}
+
+/// Unpack `leaf_index` into the positions of the nodes of a path of length `length`,
+/// starting from the leaf level, in a Merkle Tree of arity `arity`.
+fn leaf_index_to_positions(
+    arity: usize,
+    length: usize,
+    mut leaf_index: u64,
+) -> Result<Vec<usize>, SerializationError> {
+    let mut positions = Vec::with_capacity(length);
+    for _ in 0..length {
+        positions.push((leaf_index % arity as u64) as usize);
+        leaf_index /= arity as u64;
+    }
+
+    // The leaf index must be smaller than arity^length
+    if leaf_index != 0 {
+        return Err(SerializationError::InvalidData);
+    }
+    Ok(positions)
+}
+
+fn write_path_header<W: Write>(
+    length: usize,
+    leaf_index: u64,
+    mut writer: W,
+) -> Result<(), SerializationError> {
+    if length > u8::MAX as usize {
+        return Err(SerializationError::InvalidData);
+    }
+    CanonicalSerialize::serialize(&(length as u8), &mut writer)?;
+    CanonicalSerialize::serialize(&leaf_index, &mut writer)
+}
+
+fn read_path_header<R: Read>(
+    arity: usize,
+    mut reader: R,
+) -> Result<Vec<usize>, SerializationError> {
+    let length: u8 = CanonicalDeserialize::deserialize(&mut reader)?;
+    let leaf_index: u64 = CanonicalDeserialize::deserialize(&mut reader)?;
+    leaf_index_to_positions(arity, length as usize, leaf_index)
+}
+
+impl<T: FieldBasedMerkleTreeParameters> FieldBasedMHTPath<T> {
+    fn serialize_internal<W: Write, F>(
+        &self,
+        mut writer: W,
+        serialize_node: F,
+    ) -> Result<(), SerializationError>
+    where
+        F: Fn(&<T::H as FieldBasedHash>::Data, &mut W) -> Result<(), SerializationError>,
+    {
+        let leaf_index =
+            positions_to_leaf_index(T::MERKLE_ARITY, self.path.iter().map(|(_, pos)| *pos))?;
+        write_path_header(self.path.len(), leaf_index, &mut writer)?;
+        for (siblings, _) in self.path.iter() {
+            if siblings.len() != T::MERKLE_ARITY - 1 {
+                return Err(SerializationError::InvalidData);
+            }
+            for sibling in siblings.iter() {
+                serialize_node(sibling, &mut writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn deserialize_internal<R: Read, F>(
+        mut reader: R,
+        deserialize_node: F,
+    ) -> Result<Self, SerializationError>
+    where
+        F: Fn(&mut R) -> Result<<T::H as FieldBasedHash>::Data, SerializationError>,
+    {
+        let positions = read_path_header(T::MERKLE_ARITY, &mut reader)?;
+        let mut path = Vec::with_capacity(positions.len());
+        for position in positions {
+            let mut siblings = Vec::with_capacity(T::MERKLE_ARITY - 1);
+            for _ in 0..T::MERKLE_ARITY - 1 {
+                siblings.push(deserialize_node(&mut reader)?);
+            }
+            path.push((siblings, position));
+        }
+        Ok(Self { path })
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> CanonicalSerialize for FieldBasedMHTPath<T> {
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_internal(writer, |node, writer| {
+            CanonicalSerialize::serialize(node, writer)
+        })
+    }
+
+    fn serialized_size(&self) -> usize {
+        1 + 8
+            + self
+                .path
+                .iter()
+                .flat_map(|(siblings, _)| siblings.iter())
+                .map(|sibling| sibling.serialized_size())
+                .sum::<usize>()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_internal(writer, |node, writer| {
+            CanonicalSerialize::serialize_uncompressed(node, writer)
+        })
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        1 + 8
+            + self
+                .path
+                .iter()
+                .flat_map(|(siblings, _)| siblings.iter())
+                .map(|sibling| sibling.uncompressed_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> CanonicalDeserialize for FieldBasedMHTPath<T> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let path =
+            Self::deserialize_internal(reader, |reader| CanonicalDeserialize::deserialize(reader))?;
+        if !path.is_valid() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(path)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_internal(reader, |reader| {
+            CanonicalDeserialize::deserialize_unchecked(reader)
+        })
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let path = Self::deserialize_internal(reader, |reader| {
+            CanonicalDeserialize::deserialize_uncompressed(reader)
+        })?;
+        if !path.is_valid() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(path)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_internal(reader, |reader| {
+            CanonicalDeserialize::deserialize_uncompressed_unchecked(reader)
+        })
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> FieldBasedBinaryMHTPath<T> {
+    fn serialize_internal<W: Write, F>(
+        &self,
+        mut writer: W,
+        serialize_node: F,
+    ) -> Result<(), SerializationError>
+    where
+        F: Fn(&<T::H as FieldBasedHash>::Data, &mut W) -> Result<(), SerializationError>,
+    {
+        let leaf_index = positions_to_leaf_index(
+            2,
+            self.path.iter().map(|(_, direction)| *direction as usize),
+        )?;
+        write_path_header(self.path.len(), leaf_index, &mut writer)?;
+        for (node, _) in self.path.iter() {
+            serialize_node(node, &mut writer)?;
+        }
+        Ok(())
+    }
+
+    fn deserialize_internal<R: Read, F>(
+        mut reader: R,
+        deserialize_node: F,
+    ) -> Result<Self, SerializationError>
+    where
+        F: Fn(&mut R) -> Result<<T::H as FieldBasedHash>::Data, SerializationError>,
+    {
+        let positions = read_path_header(2, &mut reader)?;
+        let mut path = Vec::with_capacity(positions.len());
+        for position in positions {
+            path.push((deserialize_node(&mut reader)?, position == 1));
+        }
+        Ok(Self { path })
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> CanonicalSerialize for FieldBasedBinaryMHTPath<T> {
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_internal(writer, |node, writer| {
+            CanonicalSerialize::serialize(node, writer)
+        })
+    }
+
+    fn serialized_size(&self) -> usize {
+        1 + 8
+            + self
+                .path
+                .iter()
+                .map(|(node, _)| node.serialized_size())
+                .sum::<usize>()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_internal(writer, |node, writer| {
+            CanonicalSerialize::serialize_uncompressed(node, writer)
+        })
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        1 + 8
+            + self
+                .path
+                .iter()
+                .map(|(node, _)| node.uncompressed_size())
+                .sum::<usize>()
+    }
+}
+
+impl<T: FieldBasedMerkleTreeParameters> CanonicalDeserialize for FieldBasedBinaryMHTPath<T> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let path =
+            Self::deserialize_internal(reader, |reader| CanonicalDeserialize::deserialize(reader))?;
+        if !path.is_valid() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(path)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_internal(reader, |reader| {
+            CanonicalDeserialize::deserialize_unchecked(reader)
+        })
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let path = Self::deserialize_internal(reader, |reader| {
+            CanonicalDeserialize::deserialize_uncompressed(reader)
+        })?;
+        if !path.is_valid() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(path)
+    }
+
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_internal(reader, |reader| {
+            CanonicalDeserialize::deserialize_uncompressed_unchecked(reader)
+        })
+    }
+}
+
 /// Conversion utilities for FieldBasedMHTPath and FieldBasedBinaryMHTPath
 impl<T: FieldBasedMerkleTreeParameters> From<FieldBasedBinaryMHTPath<T>> for FieldBasedMHTPath<T> {
     fn from(other: FieldBasedBinaryMHTPath<T>) -> Self {
@@ -375,6 +648,256 @@ impl<T: FieldBasedMerkleTreeParameters> PartialEq<FieldBasedMHTPath<T>>
     for FieldBasedBinaryMHTPath<T>
 {
     fn eq(&self, other: &FieldBasedMHTPath<T>) -> bool {
-        self == other
+        &FieldBasedMHTPath::<T>::from(self.clone()) == other
+    }
+}
+
+/// A proof that the leaf at a given position of a Merkle Tree is empty.
+/// It consists of the Merkle Path of the leaf, which is verified against the pre-computed
+/// empty leaf `ZERO_NODE_CST.nodes[0]` instead of a leaf value supplied by the prover:
+/// thus, it can only be created and verified for Merkle Trees supporting the pre-computed
+/// empty nodes (e.g. FieldBasedSparseMHT).
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Default(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct FieldBasedMHTNonMembershipProof<P: FieldBasedMerkleTreePath> {
+    path: P,
+}
+
+impl<P: FieldBasedMerkleTreePath> FieldBasedMHTNonMembershipProof<P> {
+    /// Return a new non-membership proof given the Merkle Path of the empty leaf.
+    pub fn new(path: P) -> Self {
+        Self { path }
+    }
+
+    /// Return the underlying Merkle Path
+    pub fn get_path(&self) -> &P {
+        &self.path
+    }
+
+    /// Returns the index of the empty leaf, in the corresponding Merkle Tree.
+    pub fn leaf_index(&self) -> usize {
+        self.path.leaf_index()
+    }
+
+    /// Verify that the leaf at position `self.leaf_index()`, in the Merkle Tree
+    /// with height `height` and root `expected_root`, is empty.
+    pub fn verify(
+        &self,
+        height: usize,
+        expected_root: &<P::H as FieldBasedHash>::Data,
+    ) -> Result<bool, Error> {
+        if !check_precomputed_parameters::<P::Parameters>(height) {
+            Err(MerkleTreeError::Other(
+                "Unable to verify non-membership: empty leaf not available for the specified Merkle Tree".to_owned(),
+            ))?
+        }
+        let empty_leaf = <P::Parameters as FieldBasedMerkleTreeParameters>::ZERO_NODE_CST
+            .unwrap()
+            .nodes[0];
+        self.path.verify(height, &empty_leaf, expected_root)
+    }
+}
+
+impl<P: FieldBasedMerkleTreePath + SemanticallyValid> SemanticallyValid
+    for FieldBasedMHTNonMembershipProof<P>
+{
+    fn is_valid(&self) -> bool {
+        self.path.is_valid()
+    }
+}
+
+impl<P: FieldBasedMerkleTreePath> ToBytes for FieldBasedMHTNonMembershipProof<P> {
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.path.write(writer)
+    }
+}
+
+impl<P: FieldBasedMerkleTreePath> FromBytes for FieldBasedMHTNonMembershipProof<P> {
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        Ok(Self {
+            path: P::read(reader)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{serialize::test_canonical_serialize_deserialize, UniformRand};
+    use rand::{Rng, RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    const NUM_SAMPLES: usize = 10;
+
+    fn random_path<T: FieldBasedMerkleTreeParameters, R: RngCore>(
+        height: usize,
+        rng: &mut R,
+    ) -> FieldBasedMHTPath<T> {
+        let path = (0..height)
+            .map(|_| {
+                let siblings = (0..T::MERKLE_ARITY - 1)
+                    .map(|_| T::Data::rand(rng))
+                    .collect::<Vec<_>>();
+                (siblings, rng.gen_range(0..T::MERKLE_ARITY))
+            })
+            .collect::<Vec<_>>();
+        FieldBasedMHTPath::<T>::new(path)
+    }
+
+    /// Check that `path` round-trips, that its serialization is smaller than the one of the
+    /// raw path, and that deserializing a corrupted serialization never returns `path` back.
+    fn check_serialization<P, R>(path: &P, rng: &mut R) -> Vec<u8>
+    where
+        P: FieldBasedMerkleTreePath
+            + SemanticallyValid
+            + CanonicalSerialize
+            + CanonicalDeserialize
+            + PartialEq
+            + std::fmt::Debug,
+        P::Path: CanonicalSerialize,
+        R: RngCore,
+    {
+        test_canonical_serialize_deserialize(true, path);
+
+        let mut serialized = Vec::with_capacity(path.serialized_size());
+        CanonicalSerialize::serialize(path, &mut serialized).unwrap();
+        if path.get_length() > 1 {
+            assert!(serialized.len() < path.get_raw_path().serialized_size());
+        }
+
+        for _ in 0..NUM_SAMPLES {
+            let mut corrupted = serialized.clone();
+            let idx = rng.gen_range(0..corrupted.len());
+            corrupted[idx] ^= 1 << rng.gen_range(0..8);
+            if let Ok(deserialized) = <P as CanonicalDeserialize>::deserialize(corrupted.as_slice())
+            {
+                assert!(deserialized.is_valid());
+                assert_ne!(&deserialized, path);
+            }
+        }
+
+        serialized
+    }
+
+    fn path_serialization_test<T: FieldBasedMerkleTreeParameters, R: RngCore>(rng: &mut R) {
+        // Max height such that all the leaf indices fit into 64 bits
+        let max_height = 64 / T::MERKLE_ARITY.trailing_zeros() as usize;
+
+        for height in 0..=max_height {
+            for _ in 0..NUM_SAMPLES {
+                let path = random_path::<T, _>(height, rng);
+                let serialized = check_serialization(&path, rng);
+
+                // Only the siblings are serialized, after the length and the leaf index
+                assert_eq!(serialized[0] as usize, height);
+                assert_eq!(
+                    <u64 as CanonicalDeserialize>::deserialize(&serialized[1..9]).unwrap() as usize,
+                    path.leaf_index()
+                );
+
+                // A binary path is serialized in the same way of the corresponding generic one
+                if T::MERKLE_ARITY == 2 {
+                    let binary_path = FieldBasedBinaryMHTPath::<T>::try_from(path).unwrap();
+                    assert_eq!(check_serialization(&binary_path, rng), serialized);
+                }
+            }
+        }
+
+        // Leaf index out of range for the length of the path
+        let height = max_height / 2;
+        let mut serialized = Vec::new();
+        CanonicalSerialize::serialize(&(height as u8), &mut serialized).unwrap();
+        CanonicalSerialize::serialize(
+            &(T::MERKLE_ARITY as u64).pow(height as u32),
+            &mut serialized,
+        )
+        .unwrap();
+        for _ in 0..height * (T::MERKLE_ARITY - 1) {
+            CanonicalSerialize::serialize(&T::Data::rand(rng), &mut serialized).unwrap();
+        }
+        assert!(
+            <FieldBasedMHTPath<T> as CanonicalDeserialize>::deserialize(serialized.as_slice())
+                .is_err()
+        );
+        assert!(FieldBasedMHTPath::<T>::deserialize_unchecked(serialized.as_slice()).is_err());
+
+        // Leaf index not fitting into 64 bits
+        let mut path = random_path::<T, _>(max_height + 1, rng);
+        path.path.last_mut().unwrap().1 = T::MERKLE_ARITY - 1;
+        assert!(CanonicalSerialize::serialize(&path, &mut Vec::new()).is_err());
+
+        // Invalid position
+        let mut path = random_path::<T, _>(max_height / 2, rng);
+        path.path[0].1 = T::MERKLE_ARITY;
+        assert!(!path.is_valid());
+        assert!(CanonicalSerialize::serialize(&path, &mut Vec::new()).is_err());
+
+        // Invalid number of siblings
+        let mut path = random_path::<T, _>(max_height / 2, rng);
+        path.path[0].0.push(T::Data::rand(rng));
+        assert!(!path.is_valid());
+        assert!(CanonicalSerialize::serialize(&path, &mut Vec::new()).is_err());
+
+        // Random bytes must never make deserialization panic
+        for _ in 0..NUM_SAMPLES {
+            let len = rng.gen_range(0..1 + 9 + height * 32);
+            let bytes = (0..len).map(|_| rng.gen()).collect::<Vec<u8>>();
+            if let Ok(path) =
+                <FieldBasedMHTPath<T> as CanonicalDeserialize>::deserialize(bytes.as_slice())
+            {
+                assert!(path.is_valid());
+            }
+            if let Ok(path) =
+                <FieldBasedBinaryMHTPath<T> as CanonicalDeserialize>::deserialize(bytes.as_slice())
+            {
+                assert!(path.is_valid());
+            }
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr() {
+        use crate::{
+            crh::{TweedleFrPoseidonHash, TweedleFrPoseidonHashRate4},
+            merkle_tree::{
+                TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS, TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4,
+            },
+        };
+        use algebra::fields::tweedle::Fr;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHash;
+            const MERKLE_ARITY: usize = 2;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS);
+        }
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParamsArity4;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParamsArity4 {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHashRate4;
+            const MERKLE_ARITY: usize = 4;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4);
+        }
+
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        path_serialization_test::<TweedleFrFieldBasedMerkleTreeParams, _>(rng);
+        path_serialization_test::<TweedleFrFieldBasedMerkleTreeParamsArity4, _>(rng);
     }
 }
@@ -1,61 +1,112 @@
+use algebra::Field;
+
 use crate::{
     crh::{BatchFieldBasedHash, FieldBasedHash, FieldBasedHashParameters},
     merkle_tree::{
         field_based_mht::{
-            check_precomputed_parameters, BatchFieldBasedMerkleTreeParameters,
-            FieldBasedBinaryMHTPath, FieldBasedMerkleTreePath, OperationLeaf,
+            check_precomputed_parameters, smt::storage::*, BatchFieldBasedMerkleTreeParameters,
+            FieldBasedBinaryMHTPath, FieldBasedMHTNonMembershipProof, FieldBasedMHTPath,
+            FieldBasedMerkleTreeParameters, FieldBasedMerkleTreePath, OperationLeaf,
         },
         MerkleTreeError,
     },
     ActionLeaf, Error, FieldBasedMerkleTree, FieldBasedSparseMerkleTree,
 };
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    marker::PhantomData,
+};
 
-/// An in-memory, sparse, Merkle Tree with lazy leaves evaluation.
+/// A sparse Merkle Tree with lazy leaves evaluation.
 /// "Lazy" means that leaves are inserted/removed in batch, and the nodes
 /// and root computation is triggered only by explicit calls to finalize()
 /// and finalize_in_place().
+/// The non-empty leaves and nodes are kept in a `SMTStorage`, by default
+/// in memory: all the changes resulting from the pending leaves updates are
+/// committed atomically to the storage at each root computation.
+/// The Merkle Paths are of type `P`: by default `FieldBasedBinaryMHTPath`, which supports
+/// only trees of arity 2; trees of greater arity must use `FieldBasedMHTPath` instead.
 #[derive(Derivative)]
-#[derivative(Clone(bound = ""), Debug(bound = ""))]
-pub struct FieldBasedSparseMHT<T: BatchFieldBasedMerkleTreeParameters> {
+#[derivative(Clone(bound = ""), Debug(bound = "S: std::fmt::Debug"))]
+pub struct FieldBasedSparseMHT<
+    T: BatchFieldBasedMerkleTreeParameters,
+    S: SMTStorage<T::Data> = InMemorySMTStorage<<T as FieldBasedMerkleTreeParameters>::Data>,
+    P: SparseMHTPath<T> = FieldBasedBinaryMHTPath<T>,
+> {
     /// the height of this tree
     pub(crate) height: u8,
     /// number of leaves
     pub(crate) width: u32,
-    /// stores the non-empty leaves of the tree, along with a bool
-    /// indicating if they have been updated since the previous
-    /// root computation or not.
+    /// stores the leaves updated since the previous root computation.
+    /// A removed leaf is stored with the empty leaf value.
     /// We don't save the empty leaves, that's why we use a Map,
     /// but the leaves are still identified uniquely by their
     /// index (otherwise we would've need to store an additional
     /// byte to specify its height).
-    pub(crate) leaves: HashMap<u32, (T::Data, bool)>,
-    /// stores the non-empty nodes of the tree.
-    /// We don't save the empty nodes, that's why we use a Map,
-    /// but the nodes are still identified uniquely by their
-    /// index (otherwise we would've need to store an additional
-    /// byte to specify its height).
-    pub(crate) nodes: HashMap<u32, T::Data>,
+    pub(crate) pending_leaves: HashMap<u32, T::Data>,
+    /// stores the non-empty leaves and nodes of the tree, as of the
+    /// last root computation.
+    pub(crate) storage: S,
     /// stores the root of the tree as well as a boolean indicating
     /// if the tree has been modified since the last root computation,
     /// thus the root must be recomputed, or not, so it can be immediately
     /// returned.
     pub(crate) root: (T::Data, bool),
+    _path: PhantomData<P>,
+}
+
+/// A Merkle Path type which can be returned by a `FieldBasedSparseMHT`, i.e. which can
+/// be obtained from a `FieldBasedMHTPath` of a tree of arity `T::MERKLE_ARITY`.
+pub trait SparseMHTPath<T: FieldBasedMerkleTreeParameters>:
+    FieldBasedMerkleTreePath<H = T::H, Parameters = T> + TryFrom<FieldBasedMHTPath<T>>
+{
+}
+
+impl<T, P> SparseMHTPath<T> for P
+where
+    T: FieldBasedMerkleTreeParameters,
+    P: FieldBasedMerkleTreePath<H = T::H, Parameters = T> + TryFrom<FieldBasedMHTPath<T>>,
+{
 }
 
-impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
-    /// Creates a new tree of specified `height`.
+impl<T: BatchFieldBasedMerkleTreeParameters, P: SparseMHTPath<T>>
+    FieldBasedSparseMHT<T, InMemorySMTStorage<T::Data>, P>
+{
+    /// Creates a new, in-memory, tree of specified `height`.
     pub fn init(height: u8) -> Self {
+        Self::init_with_storage(height, InMemorySMTStorage::new())
+            .expect("Should be able to initialize tree with empty storage")
+    }
+}
+
+impl<T: BatchFieldBasedMerkleTreeParameters, S: SMTStorage<T::Data>, P: SparseMHTPath<T>>
+    FieldBasedSparseMHT<T, S, P>
+{
+    /// Creates a new tree of specified `height`, whose leaves and nodes are kept in
+    /// `storage`. If `storage` is not empty, the tree is restored to the state of the
+    /// last batch committed to it.
+    pub fn init_with_storage(height: u8, storage: S) -> Result<Self, Error> {
         assert!(check_precomputed_parameters::<T>(height as usize));
 
         let rate = <<T::H as FieldBasedHash>::Parameters as FieldBasedHashParameters>::R;
 
-        assert_eq!(T::MERKLE_ARITY, 2); // For now we support only arity 2
-                                        // Rate may also be smaller than the arity actually, but this assertion
-                                        // is reasonable and simplify the design.
+        assert!(T::MERKLE_ARITY >= 2);
+        // Rate may also be smaller than the arity actually, but this assertion
+        // is reasonable and simplify the design.
         assert_eq!(rate, T::MERKLE_ARITY);
 
+        // The paths of the tree must be representable as P (e.g. FieldBasedBinaryMHTPath
+        // only supports arity 2)
+        let sample_path =
+            FieldBasedMHTPath::<T>::new(vec![(vec![T::Data::zero(); T::MERKLE_ARITY - 1], 0)]);
+        assert!(
+            P::try_from(sample_path).is_ok(),
+            "Merkle Path type not supporting arity {}",
+            T::MERKLE_ARITY
+        );
+
         // If height is 0 it must not be possible to add any leaf, so we'll set width to 0.
         let width: u32 = if height != 0 {
             T::MERKLE_ARITY.pow(height as u32) as u32
@@ -63,13 +114,61 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
             0
         };
 
-        Self {
+        // Restore root from storage, if any
+        let empty_root = T::ZERO_NODE_CST.unwrap().nodes[height as usize];
+        let root = match storage.get_root()? {
+            Some(root) => {
+                // Sanity checks: the root stored must be consistent with the height of the tree.
+                // Nodes are indexed after the leaves, thus a storage of a tree of different height
+                // has no node at the index of our root: no need to scan the leaves for this.
+                let root_idx = Self::root_idx(height);
+                let expected_root = storage.get_node(root_idx)?.unwrap_or(empty_root);
+
+                if root != expected_root || storage.num_leaves()? as u64 > width as u64 {
+                    return Err(MerkleTreeError::Other(format!(
+                        "Storage is not consistent with a tree of height {}",
+                        height
+                    )))?;
+                }
+                root
+            }
+            None => {
+                if storage.num_leaves()? != 0 || storage.num_nodes()? != 0 {
+                    return Err(MerkleTreeError::Other(
+                        "Storage contains leaves or nodes but no root".to_owned(),
+                    ))?;
+                }
+                empty_root
+            }
+        };
+
+        Ok(Self {
             height,
             width,
-            leaves: HashMap::new(),
-            nodes: HashMap::new(),
-            root: (T::ZERO_NODE_CST.unwrap().nodes[height as usize], false),
-        }
+            pending_leaves: HashMap::new(),
+            storage,
+            root: (root, false),
+            _path: PhantomData,
+        })
+    }
+
+    /// Return the index of the root in a tree of specified `height`.
+    /// Nodes are indexed level by level, starting from the leaves, thus the index of the root
+    /// is the total number of nodes of the tree minus one, i.e. (ARITY^(height + 1) - 1)/(ARITY - 1) - 1.
+    fn root_idx(height: u8) -> u32 {
+        let arity = T::MERKLE_ARITY as u64;
+        ((arity.pow(height as u32 + 1) - 1) / (arity - 1) - 1) as u32
+    }
+
+    /// Return a reference to the underlying storage.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Consume the tree, returning the underlying storage.
+    /// Pending changes, if any, are discarded.
+    pub fn into_storage(self) -> S {
+        self.storage
     }
 
     /// Return true if there are uncommited changes in the tree (leaves added/removed but root not yet updated),
@@ -78,6 +177,63 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
         self.root.1
     }
 
+    /// Return the current value of the leaf at 'idx', taking into account the pending changes,
+    /// or None if the leaf is empty.
+    fn get_leaf(&self, idx: u32) -> Result<Option<T::Data>, Error> {
+        match self.pending_leaves.get(&idx) {
+            Some(data) => {
+                // Leaf waiting to be removed
+                if data == &T::ZERO_NODE_CST.unwrap().nodes[0] {
+                    Ok(None)
+                } else {
+                    Ok(Some(*data))
+                }
+            }
+            None => self.storage.get_leaf(idx),
+        }
+    }
+
+    /// Return (in order) the indices of the non-empty leaves, taking into account the pending
+    /// changes. Reads all the leaves of the tree: only used in tests.
+    #[cfg(test)]
+    fn get_non_empty_leaves_indices(&self) -> Result<Vec<u32>, Error> {
+        let mut indices = Vec::new();
+        for idx in 0..self.width {
+            if self.get_leaf(idx)?.is_some() {
+                indices.push(idx);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Return the greatest index of a leaf of the storage, below the node at `height` and `idx`,
+    /// which is non-empty and not overridden by the pending changes, if any.
+    /// Only the subtrees of the non-empty nodes are visited, from the right to the left,
+    /// thus the cost doesn't depend on the number of leaves, but on the number of pending
+    /// changes at the end of the tree.
+    fn get_last_stored_leaf_idx(&self, height: u8, idx: u32) -> Result<Option<u32>, Error> {
+        if height == 0 {
+            return if self.pending_leaves.contains_key(&idx)
+                || self.storage.get_leaf(idx)?.is_none()
+            {
+                Ok(None)
+            } else {
+                Ok(Some(idx))
+            };
+        }
+        if self.storage.get_node(idx)?.is_none() {
+            return Ok(None);
+        }
+
+        let first_child_idx = (idx - self.width) * T::MERKLE_ARITY as u32;
+        for child_idx in (first_child_idx..first_child_idx + T::MERKLE_ARITY as u32).rev() {
+            if let Some(leaf_idx) = self.get_last_stored_leaf_idx(height - 1, child_idx)? {
+                return Ok(Some(leaf_idx));
+            }
+        }
+        Ok(None)
+    }
+
     /// Return true if leaf at 'idx' is empty, false otherwise.
     /// The tree doesn't need to be finalized before calling this function.
     pub fn is_leaf_empty(&self, idx: u32) -> Result<bool, Error> {
@@ -93,27 +249,45 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
             ))?;
         }
 
-        let leaf = self.leaves.get(&idx);
-        Ok(
-            leaf.is_none() || leaf.unwrap().0 == T::ZERO_NODE_CST.unwrap().nodes[0], // Leaf waiting to be removed
-        )
+        Ok(self.get_leaf(idx)?.is_none())
+    }
+
+    /// Return a proof that the leaf at 'idx' is empty.
+    /// The tree must be finalized before calling this function.
+    pub fn get_non_membership_proof(
+        &self,
+        idx: u32,
+    ) -> Result<FieldBasedMHTNonMembershipProof<P>, Error> {
+        if !self.is_leaf_empty(idx)? {
+            return Err(MerkleTreeError::Other(format!(
+                "Unable to get non-membership proof: leaf at index {} is not empty",
+                idx
+            )))?;
+        }
+
+        let path = self.get_merkle_path(idx).ok_or_else(|| {
+            MerkleTreeError::Other(
+                "Unable to get non-membership proof: tree must be finalized first".to_owned(),
+            )
+        })?;
+        Ok(FieldBasedMHTNonMembershipProof::new(path))
     }
 
-    /// Return the last non empty leaf position of the tree.
+    /// Return the last non empty leaf position of the tree, or None if the tree is empty.
     /// The tree doesn't need to be finalized before calling this function.
-    fn get_last_non_empty_position(&self) -> u32 {
-        assert!(!self.is_tree_empty());
-        let mut last_idx = 0;
-        self.leaves
+    fn get_last_non_empty_position(&self) -> Result<Option<u32>, Error> {
+        let last_pending_idx = self
+            .pending_leaves
             .iter()
-            .filter(|(_, (data, _))| data != &T::ZERO_NODE_CST.unwrap().nodes[0])
-            .for_each(|(idx, (_, _))| {
-                if idx > &last_idx {
-                    last_idx = *idx;
-                }
-            });
-
-        last_idx
+            .filter(|(_, data)| *data != &T::ZERO_NODE_CST.unwrap().nodes[0])
+            .map(|(idx, _)| *idx)
+            .max();
+        let last_stored_idx = if self.height == 0 {
+            None
+        } else {
+            self.get_last_stored_leaf_idx(self.height, Self::root_idx(self.height))?
+        };
+        Ok(last_pending_idx.max(last_stored_idx))
     }
 
     fn batch_hash(input: &[T::Data]) -> Vec<T::Data> {
@@ -124,79 +298,84 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
     /// Return true if the tree is empty, false otherwise.
     /// Emptiness of the tree is checked by checking no leaf is present.
     /// The tree doesn't need to be finalized before calling this function
-    pub fn is_tree_empty(&self) -> bool {
-        debug_assert!(self.nodes.is_empty());
-        self.leaves.is_empty()
-            || self
-                .leaves
-                .iter()
-                .all(|(_, (data, _))| data == &T::ZERO_NODE_CST.unwrap().nodes[0])
+    pub fn is_tree_empty(&self) -> Result<bool, Error> {
+        let mut removed_leaves = 0;
+        for (&idx, data) in self.pending_leaves.iter() {
+            if data != &T::ZERO_NODE_CST.unwrap().nodes[0] {
+                return Ok(false);
+            }
+            if self.storage.get_leaf(idx)?.is_some() {
+                removed_leaves += 1;
+            }
+        }
+        Ok(self.storage.num_leaves()? == removed_leaves)
     }
 
     /// Get the node at the corresponding height and idx, and return its value and 'true' if it exists;
-    /// otherwise return empty node and 'false'
-    fn get_node_at_height_and_idx(&self, height: usize, idx: u32) -> (T::Data, bool) {
-        if height == 0 {
-            self.leaves.get(&idx).map_or_else(
-                || (T::ZERO_NODE_CST.unwrap().nodes[height], false),
-                |&(data, _)| {
-                    debug_assert!(data != T::ZERO_NODE_CST.unwrap().nodes[0]);
-                    (data, true)
-                },
-            )
+    /// otherwise return empty node and 'false'.
+    /// Nodes in `updated_nodes` take precedence over the ones in the storage.
+    fn get_node_at_height_and_idx_with_updates(
+        &self,
+        height: usize,
+        idx: u32,
+        updated_nodes: &HashMap<u32, Option<T::Data>>,
+    ) -> Result<(T::Data, bool), Error> {
+        let node = if height == 0 {
+            self.get_leaf(idx)?
         } else {
-            self.nodes.get(&idx).map_or_else(
-                || (T::ZERO_NODE_CST.unwrap().nodes[height], false),
-                |&data| {
-                    debug_assert!(data != T::ZERO_NODE_CST.unwrap().nodes[0]);
-                    (data, true)
-                },
-            )
-        }
+            match updated_nodes.get(&idx) {
+                Some(node) => *node,
+                None => self.storage.get_node(idx)?,
+            }
+        };
+
+        Ok(node.map_or_else(
+            || (T::ZERO_NODE_CST.unwrap().nodes[height], false),
+            |data| {
+                debug_assert!(data != T::ZERO_NODE_CST.unwrap().nodes[0]);
+                (data, true)
+            },
+        ))
+    }
+
+    /// Get the node at the corresponding height and idx, and return its value and 'true' if it exists;
+    /// otherwise return empty node and 'false'
+    fn get_node_at_height_and_idx(
+        &self,
+        height: usize,
+        idx: u32,
+    ) -> Result<(T::Data, bool), Error> {
+        self.get_node_at_height_and_idx_with_updates(height, idx, &HashMap::new())
     }
 
-    /// Update the nodes and the root of the tree according to the changed leaves.
+    /// Update the nodes and the root of the tree according to the changed leaves,
+    /// and atomically commit all the changes to the storage.
     fn process_leaves(&mut self) -> Result<T::Data, Error> {
         // Collect nodes to (re)compute for each level of the tree
         let mut nodes_to_recompute_by_level: Vec<HashSet<u32>> =
             Vec::with_capacity(self.height as usize);
 
-        // Collect leaves in the first level
-        let mut modified_leaves_pos = HashSet::<u32>::new();
-
-        // Collect leaves to be removed from leaves Map
-        let mut leaves_to_be_removed = Vec::new();
-
-        self.leaves
-            .iter_mut()
-            .filter(|(_, (_, updated))| *updated)
-            .for_each(|(idx, (data, updated))| {
-                // Collect leaves whose value has changed since previous 'process_leaves' call, and set
-                // their state to unchanged.
-                modified_leaves_pos.insert(*idx);
-                *updated = false;
-
-                // We interpret removal as a leaf with updated state but value set to be empty node. For
-                // such leaves, we need to actually remove them before proceeding in updating the tree.
-                if data == &T::ZERO_NODE_CST.unwrap().nodes[0] {
-                    leaves_to_be_removed.push(*idx);
+        // Collect leaves whose value has changed since previous 'process_leaves' call.
+        // We interpret removal as a leaf with updated state but value set to be empty node.
+        let modified_leaves_pos = self
+            .pending_leaves
+            .keys()
+            .copied()
+            .collect::<HashSet<u32>>();
+        let updated_leaves = self
+            .pending_leaves
+            .iter()
+            .map(|(&idx, &data)| {
+                if data == T::ZERO_NODE_CST.unwrap().nodes[0] {
+                    (idx, None)
+                } else {
+                    (idx, Some(data))
                 }
-            });
+            })
+            .collect::<Vec<_>>();
 
         nodes_to_recompute_by_level.push(modified_leaves_pos);
 
-        // Remove all leaves marked for removal
-        leaves_to_be_removed.into_iter().for_each(|idx| {
-            self.leaves.remove(&idx);
-        });
-
-        // We have taken all the leaves to be inserted and removed the ones supposed to be removed.
-        // There shouldn't be any leaf with !updated stated and empty value
-        debug_assert!(self
-            .leaves
-            .iter()
-            .all(|(_, (data, updated))| !*updated && data != &T::ZERO_NODE_CST.unwrap().nodes[0]));
-
         // Find all the nodes that must be recomputed following the
         // additional/removal of leaves
         for height in 0..self.height as usize {
@@ -214,63 +393,88 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
             nodes_to_recompute_by_level.push(visited_nodes);
         }
 
+        // Keep track of the new values of the affected nodes (None if the node
+        // has become empty due to some leaf removal operation)
+        let mut updated_nodes = HashMap::<u32, Option<T::Data>>::new();
+
         // Compute hashes of the affected nodes (ignoring leaf nodes)
         for height in 1..=self.height as usize {
             let mut input_vec = Vec::new(); // Leaves to be hashed
             let mut empty_node = Vec::new(); // Keep track of which node is empty
 
             // Collect leaves to be hashed in parallel
-            nodes_to_recompute_by_level[height]
-                .iter()
-                .for_each(|&parent_idx| {
-                    // Compute children coords and get corresponding values
-                    let left_child_idx = (parent_idx - self.width) * T::MERKLE_ARITY as u32;
-                    let right_child_idx = left_child_idx + 1;
-
-                    let (left_hash, is_left_full) =
-                        self.get_node_at_height_and_idx(height - 1, left_child_idx);
-                    let (right_hash, is_right_full) =
-                        self.get_node_at_height_and_idx(height - 1, right_child_idx);
-
-                    let is_node_full = is_left_full || is_right_full;
-
-                    // Must compute hash iff node will be non-empty, otherwise
-                    // we have already its value precomputed
-                    if is_node_full {
-                        input_vec.push(left_hash);
-                        input_vec.push(right_hash);
-                    } else {
-                        // If the node was present in self.nodes we must remove it,
-                        // as it has become empty due to some leaf removal operation
-                        self.nodes.remove(&parent_idx);
-                    }
-
-                    empty_node.push(!is_node_full);
-                });
+            for &parent_idx in nodes_to_recompute_by_level[height].iter() {
+                // Compute children coords and get corresponding values
+                let first_child_idx = (parent_idx - self.width) * T::MERKLE_ARITY as u32;
+                let children = (first_child_idx..first_child_idx + T::MERKLE_ARITY as u32)
+                    .map(|child_idx| {
+                        self.get_node_at_height_and_idx_with_updates(
+                            height - 1,
+                            child_idx,
+                            &updated_nodes,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let is_node_full = children.iter().any(|&(_, is_child_full)| is_child_full);
+
+                // Must compute hash iff node will be non-empty, otherwise
+                // we have already its value precomputed
+                if is_node_full {
+                    input_vec.extend(children.into_iter().map(|(child_hash, _)| child_hash));
+                }
+
+                empty_node.push(!is_node_full);
+            }
 
             // Process the input_vec of the nodes that will be non-empty
             // (i.e. the ones who have at least one non-empty children)
             // using batch Poseidon hash
-            if !input_vec.is_empty() {
-                let output_vec = Self::batch_hash(input_vec.as_slice());
-
-                // Update the nodes map with the new values
-                let mut output_vec_index = 0;
-                for (&idx, is_empty) in nodes_to_recompute_by_level[height].iter().zip(empty_node) {
-                    if !is_empty {
-                        self.nodes.insert(idx, output_vec[output_vec_index]);
-                        output_vec_index += 1;
-                    }
+            let output_vec = if !input_vec.is_empty() {
+                Self::batch_hash(input_vec.as_slice())
+            } else {
+                vec![]
+            };
+
+            // Update the nodes accordingly
+            let mut output_vec_index = 0;
+            for (&idx, is_empty) in nodes_to_recompute_by_level[height].iter().zip(empty_node) {
+                if !is_empty {
+                    updated_nodes.insert(idx, Some(output_vec[output_vec_index]));
+                    output_vec_index += 1;
+                } else {
+                    // If the node was present in the storage we must remove it
+                    updated_nodes.insert(idx, None);
                 }
             }
         }
 
-        // Update root
-        let root_idx: u32 = (1 << (self.height + 1)) - 2;
-        let new_root = self.nodes.get(&root_idx).map_or_else(
-            || T::ZERO_NODE_CST.unwrap().nodes[self.height as usize], // If not in nodes, then the root is empty
-            |&data| data,
-        );
+        // Compute new root
+        let new_root = if self.height == 0 {
+            T::ZERO_NODE_CST.unwrap().nodes[0]
+        } else {
+            let root_idx = Self::root_idx(self.height);
+            let root = match updated_nodes.get(&root_idx) {
+                Some(root) => *root,
+                None => self.storage.get_node(root_idx)?,
+            };
+            // If not in nodes, then the root is empty
+            root.unwrap_or(T::ZERO_NODE_CST.unwrap().nodes[self.height as usize])
+        };
+
+        // We have taken all the leaves to be inserted and removed the ones supposed to be removed.
+        // There shouldn't be any empty leaf written to the storage
+        debug_assert!(updated_leaves
+            .iter()
+            .all(|(_, leaf)| leaf != &Some(T::ZERO_NODE_CST.unwrap().nodes[0])));
+
+        // Commit changes to the storage
+        self.storage.commit(SMTStorageBatch {
+            leaves: updated_leaves,
+            nodes: updated_nodes.into_iter().collect(),
+            root: new_root,
+        })?;
+        self.pending_leaves.clear();
 
         self.root.0 = new_root;
         self.root.1 = false;
@@ -278,10 +482,12 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMHT<T> {
     }
 }
 
-impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBasedSparseMHT<T> {
+impl<T: BatchFieldBasedMerkleTreeParameters, S: SMTStorage<T::Data>, P: SparseMHTPath<T>>
+    FieldBasedMerkleTree for FieldBasedSparseMHT<T, S, P>
+{
     type Position = u32;
     type Parameters = T;
-    type MerklePath = FieldBasedBinaryMHTPath<T>;
+    type MerklePath = P;
 
     fn append(&mut self, leaf: T::Data) -> Result<&mut Self, Error> {
         // Cannot append in a tree of height 0
@@ -291,17 +497,16 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
 
         // It doesn't really make sense to define an append operation in a SparseMerkleTree,
         // but let's interpret it as adding a single leaf in the last empty position.
-        let last_pos = if !self.is_tree_empty() {
-            let pos = self.get_last_non_empty_position();
+        let last_pos = match self.get_last_non_empty_position()? {
+            Some(pos) => {
+                // If the last non empty position is the final one of the tree, we forbid "appending"
+                if pos == self.width - 1 {
+                    Err(MerkleTreeError::TooManyLeaves(self.height as usize))?
+                }
 
-            // If the last non empty position is the final one of the tree, we forbid "appending"
-            if pos == self.width - 1 {
-                Err(MerkleTreeError::TooManyLeaves(self.height as usize))?
+                pos + 1
             }
-
-            pos + 1
-        } else {
-            0
+            None => 0,
         };
 
         // Insert leaves inside tree
@@ -310,6 +515,9 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
         self.insert_leaves(leaves_to_insert)
     }
 
+    /// The changes are committed to a copy of the storage: for storages whose
+    /// copies share the same data, as `FileSMTStorage`, this means that they are
+    /// committed to the storage of `self` too.
     fn finalize(&self) -> Result<Self, Error> {
         let mut copy = self.clone();
         if self.pending_changes() {
@@ -326,11 +534,12 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
         Ok(self)
     }
 
-    fn reset(&mut self) -> &mut Self {
-        self.leaves = HashMap::new();
-        self.nodes = HashMap::new();
+    /// If clearing the storage fails, the tree is left unchanged.
+    fn reset(&mut self) -> Result<&mut Self, Error> {
+        self.storage.clear()?;
+        self.pending_leaves = HashMap::new();
         self.root = (T::ZERO_NODE_CST.unwrap().nodes[self.height as usize], false);
-        self
+        Ok(self)
     }
 
     fn root(&self) -> Option<T::Data> {
@@ -362,25 +571,38 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
         let mut node_idx = idx;
         let mut height = 0usize;
         while height != self.height as usize {
-            // Estabilish if sibling is a left or right child
-            let (sibling_idx, direction) = if node_idx % T::MERKLE_ARITY as u32 == 0 {
-                (node_idx + 1, false)
-            } else {
-                (node_idx - 1, true)
+            // Estabilish the position of the node among its siblings
+            let position = node_idx % T::MERKLE_ARITY as u32;
+            let first_sibling_idx = node_idx - position;
+
+            // Get their hashes
+            let siblings = (first_sibling_idx..first_sibling_idx + T::MERKLE_ARITY as u32)
+                .filter(|&sibling_idx| sibling_idx != node_idx)
+                .map(|sibling_idx| {
+                    self.get_node_at_height_and_idx(height, sibling_idx)
+                        .map(|(sibling, _)| sibling)
+                })
+                .collect::<Result<Vec<_>, _>>();
+            let siblings = match siblings {
+                Ok(siblings) => siblings,
+                Err(e) => {
+                    eprintln!("Unable to read the nodes of the path: {}", e);
+                    return None;
+                }
             };
 
-            // Get its hash
-            let (sibling, _) = self.get_node_at_height_and_idx(height, sibling_idx);
-
             // Push info to path
-            path.push((sibling, direction));
+            path.push((siblings, position as usize));
 
             height += 1; // go up one level
             node_idx = self.width + (node_idx / T::MERKLE_ARITY as u32); // compute the index of the parent
         }
-        debug_assert!(self.get_node_at_height_and_idx(height, node_idx).0 == self.root.0);
+        debug_assert!(self
+            .get_node_at_height_and_idx(height, node_idx)
+            .map_or(true, |(root, _)| root == self.root.0));
 
-        Some(FieldBasedBinaryMHTPath::<T>::new(path))
+        // Can't fail: the conversion of the paths of the tree is checked at initialization
+        P::try_from(FieldBasedMHTPath::<T>::new(path)).ok()
     }
 
     fn height(&self) -> usize {
@@ -388,7 +610,9 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedMerkleTree for FieldBased
     }
 }
 
-impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMerkleTree for FieldBasedSparseMHT<T> {
+impl<T: BatchFieldBasedMerkleTreeParameters, S: SMTStorage<T::Data>, P: SparseMHTPath<T>>
+    FieldBasedSparseMerkleTree for FieldBasedSparseMHT<T, S, P>
+{
     /// Perform insertion/removals of the leaves as specified by 'leaves_set'.
     /// This function will return Error in the following situations:
     /// - Invalid leaf idx (leaf.coord.position > self.width);
@@ -422,11 +646,7 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMerkleTree for Fiel
                 }
 
                 // Forbid attempt to remove a non-existing leaf (or ones waiting for removal)
-                let leaf_to_remove = self.leaves.get(&idx);
-                if matches!(leaf.action, ActionLeaf::Remove)
-                    && (leaf_to_remove.is_none()
-                        || leaf_to_remove.unwrap().0 == T::ZERO_NODE_CST.unwrap().nodes[0])
-                {
+                if matches!(leaf.action, ActionLeaf::Remove) && self.get_leaf(idx)?.is_none() {
                     return Err(MerkleTreeError::IncorrectLeafIndex(
                         idx as usize,
                         format!("Leaf with index {} doesn't exist", idx),
@@ -440,8 +660,8 @@ impl<T: BatchFieldBasedMerkleTreeParameters> FieldBasedSparseMerkleTree for Fiel
                     leaf.hash.unwrap()
                 };
 
-                // Update leaves Map accordingly
-                self.leaves.insert(idx, (val, true));
+                // Update pending leaves Map accordingly
+                self.pending_leaves.insert(idx, val);
             }
 
             // Set root to be recomputed
@@ -461,9 +681,10 @@ mod test {
 
     use crate::{
         merkle_tree::field_based_mht::{
-            smt::FieldBasedSparseMHT, ActionLeaf, BatchFieldBasedMerkleTreeParameters,
-            FieldBasedAppendOnlyMHT, FieldBasedBinaryMHTPath, FieldBasedMerkleTree,
-            FieldBasedMerkleTreeParameters, FieldBasedMerkleTreePath,
+            smt::{FieldBasedSparseMHT, FileSMTStorage, InMemorySMTStorage, SMTStorage},
+            ActionLeaf, BatchFieldBasedMerkleTreeParameters, FieldBasedAppendOnlyMHT,
+            FieldBasedBinaryMHTPath, FieldBasedMHTNonMembershipProof, FieldBasedMHTPath,
+            FieldBasedMerkleTree, FieldBasedMerkleTreeParameters, FieldBasedMerkleTreePath,
             FieldBasedMerkleTreePrecomputedZeroConstants,
         },
         FieldBasedSparseMerkleTree, NaiveMerkleTree, OperationLeaf,
@@ -474,24 +695,33 @@ mod test {
     const TEST_HEIGHT: u8 = 10;
     const NUM_SAMPLES: usize = 10;
 
+    /// A FieldBasedSparseMHT returning paths supporting any arity
+    type FieldBasedSparseMHTAnyArity<
+        T,
+        S = InMemorySMTStorage<<T as FieldBasedMerkleTreeParameters>::Data>,
+    > = FieldBasedSparseMHT<T, S, FieldBasedMHTPath<T>>;
+
     /// Return (in order) the non empty leaves of the tree.
     /// The tree doesn't need to be finalized before calling this function.
     /// Used mainly for testing purposes.
-    fn get_non_empty_leaves<T: BatchFieldBasedMerkleTreeParameters>(
-        smt: &FieldBasedSparseMHT<T>,
+    fn get_non_empty_leaves<T: BatchFieldBasedMerkleTreeParameters, S: SMTStorage<T::Data>>(
+        smt: &FieldBasedSparseMHTAnyArity<T, S>,
     ) -> BTreeMap<u32, T::Data> {
         let mut non_empty_leaves = BTreeMap::new();
-        smt.leaves
-            .iter()
-            .filter(|(_, (data, _))| data != &T::ZERO_NODE_CST.unwrap().nodes[0])
-            .for_each(|(&idx, (data, _))| {
-                non_empty_leaves.insert(idx, *data);
+        smt.get_non_empty_leaves_indices()
+            .unwrap()
+            .into_iter()
+            .for_each(|idx| {
+                non_empty_leaves.insert(idx, smt.get_leaf(idx).unwrap().unwrap());
             });
         non_empty_leaves
     }
 
-    fn compute_append_only_tree_root<T: BatchFieldBasedMerkleTreeParameters>(
-        smt: &FieldBasedSparseMHT<T>,
+    fn compute_append_only_tree_root<
+        T: BatchFieldBasedMerkleTreeParameters,
+        S: SMTStorage<T::Data>,
+    >(
+        smt: &FieldBasedSparseMHTAnyArity<T, S>,
     ) -> T::Data {
         let mut optimized =
             FieldBasedAppendOnlyMHT::<T>::init(smt.height as usize, smt.width as usize).unwrap();
@@ -508,8 +738,11 @@ mod test {
         optimized.finalize().unwrap().root().unwrap()
     }
 
-    fn compare_append_only_and_smt_roots<T: BatchFieldBasedMerkleTreeParameters>(
-        smt: &mut FieldBasedSparseMHT<T>,
+    fn compare_append_only_and_smt_roots<
+        T: BatchFieldBasedMerkleTreeParameters,
+        S: SMTStorage<T::Data>,
+    >(
+        smt: &mut FieldBasedSparseMHTAnyArity<T, S>,
     ) {
         // Insert into optimized and get root
         let optimized_root = compute_append_only_tree_root(smt);
@@ -523,7 +756,9 @@ mod test {
         assert_eq!(smt.root().unwrap(), optimized_root, "Roots are not equal");
     }
 
-    fn assert_tree_empty<T: BatchFieldBasedMerkleTreeParameters>(smt: &FieldBasedSparseMHT<T>) {
+    fn assert_tree_empty<T: BatchFieldBasedMerkleTreeParameters>(
+        smt: &FieldBasedSparseMHTAnyArity<T>,
+    ) {
         // Tree must be finalized before calling this function
         assert!(!smt.pending_changes());
 
@@ -535,11 +770,11 @@ mod test {
         );
 
         // ...and tree must be empty
-        assert!(smt.is_tree_empty());
+        assert!(smt.is_tree_empty().unwrap());
 
         // Additional sanity checks
-        assert!(smt.leaves.is_empty());
-        assert!(smt.nodes.is_empty());
+        assert!(smt.storage.leaves.is_empty());
+        assert!(smt.storage.nodes.is_empty());
     }
 
     /// Test correct behavior of the SMT (compared with respect to a FieldBasedAppendOnlyMHT) by processing batches
@@ -554,7 +789,7 @@ mod test {
         finalize_in_the_end: bool,
     ) {
         // Initialize trees
-        let mut smt = FieldBasedSparseMHT::<T>::init(height);
+        let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(height);
         let num_leaves = smt.width;
 
         // Initialize leaves
@@ -586,8 +821,11 @@ mod test {
         }
 
         // Leaves and Nodes map must be full
-        assert_eq!(smt.leaves.len() as u32, num_leaves);
-        assert_eq!(smt.nodes.len() as u32, num_leaves - 1);
+        assert_eq!(smt.storage.leaves.len() as u32, num_leaves);
+        assert_eq!(
+            smt.storage.nodes.len() as u32,
+            (num_leaves - 1) / (T::MERKLE_ARITY as u32 - 1)
+        );
 
         // Test removals
         // Remove all leaves and update smt
@@ -618,7 +856,7 @@ mod test {
         finalize_in_the_end: bool,
     ) {
         // Initialize trees: fill half of the SMT
-        let mut smt = FieldBasedSparseMHT::<T>::init(height);
+        let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(height);
         let num_leaves = smt.width;
         let mut leaves = (0..num_leaves / 2)
             .map(|idx| OperationLeaf::new(idx, ActionLeaf::Insert, Some(T::Data::rand(rng))))
@@ -655,14 +893,17 @@ mod test {
             compare_append_only_and_smt_roots(&mut smt)
         }
 
-        // Nodes map must be half full
-        assert_eq!(smt.leaves.len() as u32, num_leaves / 2);
-        assert_eq!(smt.nodes.len() as u32, num_leaves / 2);
+        // Nodes map must be half full (except for the root)
+        assert_eq!(smt.storage.leaves.len() as u32, num_leaves / 2);
+        assert_eq!(
+            smt.storage.nodes.len() as u32,
+            (num_leaves - T::MERKLE_ARITY as u32) / (2 * (T::MERKLE_ARITY as u32 - 1)) + 1
+        );
     }
 
     fn test_error_cases<T: BatchFieldBasedMerkleTreeParameters>(height: u8) {
         // Initialize tree
-        let mut smt = FieldBasedSparseMHT::<T>::init(height);
+        let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(height);
 
         let mut dummy_leaf = OperationLeaf::new(0, ActionLeaf::Remove, Some(T::Data::one()));
 
@@ -709,8 +950,8 @@ mod test {
         smt.finalize_in_place().unwrap();
         let smt_root = smt.root().unwrap();
         assert_eq!(smt_root, compute_append_only_tree_root(&smt));
-        assert_eq!(smt.leaves.len(), 1);
-        assert_eq!(smt.nodes.len() as u8, height);
+        assert_eq!(smt.storage.leaves.len(), 1);
+        assert_eq!(smt.storage.nodes.len() as u8, height);
 
         // Replace previously added leaf with a new value and check correct replacement
         dummy_leaf.hash = Some(T::Data::from(2u8));
@@ -718,8 +959,8 @@ mod test {
         let new_smt_root = smt.finalize_in_place().unwrap().root().unwrap();
         assert_ne!(new_smt_root, smt_root);
         assert_eq!(new_smt_root, compute_append_only_tree_root(&smt));
-        assert_eq!(smt.leaves.len(), 1);
-        assert_eq!(smt.nodes.len() as u8, height);
+        assert_eq!(smt.storage.leaves.len(), 1);
+        assert_eq!(smt.storage.nodes.len() as u8, height);
 
         // Perform removal then insertion of the same leaf at the same time and check correct result
         let mut dummy_leaf_removal = dummy_leaf.clone();
@@ -732,8 +973,8 @@ mod test {
             new_smt_root,
             smt.finalize_in_place().unwrap().root().unwrap()
         );
-        assert_eq!(smt.leaves.len(), 1);
-        assert_eq!(smt.nodes.len() as u8, height);
+        assert_eq!(smt.storage.leaves.len(), 1);
+        assert_eq!(smt.storage.nodes.len() as u8, height);
 
         // Perform insertion then removal of the same leaf at the same time and check correct result
         let new_dummy_leaf = OperationLeaf::new(0, ActionLeaf::Insert, Some(T::Data::one()));
@@ -747,8 +988,8 @@ mod test {
             new_smt_root,
             smt.finalize_in_place().unwrap().root().unwrap()
         );
-        assert_eq!(smt.leaves.len(), 1);
-        assert_eq!(smt.nodes.len() as u8, height);
+        assert_eq!(smt.storage.leaves.len(), 1);
+        assert_eq!(smt.storage.nodes.len() as u8, height);
 
         // Remove non existing leaf with non empty tree
         dummy_leaf.position -= 1;
@@ -776,19 +1017,19 @@ mod test {
         assert_tree_empty(&smt);
     }
 
-    fn test_edge_cases<T: BatchFieldBasedMerkleTreeParameters>() {
+    fn test_edge_cases<T: BatchFieldBasedMerkleTreeParameters>(height: u8) {
         let dummy_leaf = OperationLeaf::new(0, ActionLeaf::Insert, Some(T::Data::one()));
 
         // HEIGHT > 1
         {
             // Generate empty tree and get the root
-            let mut smt = FieldBasedSparseMHT::<T>::init(TEST_HEIGHT);
+            let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(height);
             let root = smt.root().unwrap();
-            assert_eq!(root, T::ZERO_NODE_CST.unwrap().nodes[TEST_HEIGHT as usize]);
+            assert_eq!(root, T::ZERO_NODE_CST.unwrap().nodes[height as usize]);
 
             // Get merkle path on an empty tree should work
             for i in 0..smt.width {
-                let leaf = smt.get_node_at_height_and_idx(0, i).0;
+                let leaf = smt.get_node_at_height_and_idx(0, i).unwrap().0;
                 let path = smt.get_merkle_path(i).unwrap();
                 assert!(path.verify(smt.height as usize, &leaf, &root).unwrap());
             }
@@ -798,22 +1039,19 @@ mod test {
             smt.finalize_in_place().unwrap();
             let new_root = smt.root().unwrap();
             assert_ne!(new_root, root);
-            assert_ne!(
-                new_root,
-                T::ZERO_NODE_CST.unwrap().nodes[TEST_HEIGHT as usize]
-            );
+            assert_ne!(new_root, T::ZERO_NODE_CST.unwrap().nodes[height as usize]);
         }
 
         // HEIGHT == 1
         {
             // Generate empty tree and get the root
-            let mut smt = FieldBasedSparseMHT::<T>::init(1);
+            let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(1);
             let mut root = smt.root().unwrap();
             assert_eq!(root, T::ZERO_NODE_CST.unwrap().nodes[1]);
 
             // Get merkle path on an empty tree should work
             for i in 0..smt.width {
-                let leaf = smt.get_node_at_height_and_idx(0, i).0;
+                let leaf = smt.get_node_at_height_and_idx(0, i).unwrap().0;
                 let path = smt.get_merkle_path(i).unwrap();
                 assert!(path.verify(smt.height as usize, &leaf, &root).unwrap());
             }
@@ -826,14 +1064,18 @@ mod test {
             assert_ne!(new_root, T::ZERO_NODE_CST.unwrap().nodes[1]);
             root = new_root;
 
-            // Generate tree with exactly 2 leaves and attempt to get the root.
+            // Generate tree with exactly MERKLE_ARITY leaves and attempt to get the root.
             // Assert error if trying to add another leaf
             assert!(smt
-                .update_leaves(vec![OperationLeaf::new(
-                    1,
-                    ActionLeaf::Insert,
-                    Some(T::Data::one())
-                )])
+                .update_leaves(
+                    (1..T::MERKLE_ARITY as u32)
+                        .map(|idx| OperationLeaf::new(
+                            idx,
+                            ActionLeaf::Insert,
+                            Some(T::Data::one())
+                        ))
+                        .collect()
+                )
                 .is_ok());
             smt.finalize_in_place().unwrap();
             let new_root = smt.root().unwrap();
@@ -842,7 +1084,7 @@ mod test {
 
             assert!(smt
                 .update_leaves(vec![OperationLeaf::new(
-                    2,
+                    T::MERKLE_ARITY as u32,
                     ActionLeaf::Insert,
                     Some(T::Data::one())
                 )])
@@ -852,13 +1094,13 @@ mod test {
         // HEIGHT == 0
         {
             // Generate empty tree and get the root
-            let mut smt = FieldBasedSparseMHT::<T>::init(0);
+            let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(0);
             let root = smt.root().unwrap();
             assert_eq!(root, T::ZERO_NODE_CST.unwrap().nodes[0]);
 
             // Get merkle path on an empty tree should work
             for i in 0..smt.width {
-                let leaf = smt.get_node_at_height_and_idx(0, i).0;
+                let leaf = smt.get_node_at_height_and_idx(0, i).unwrap().0;
                 let path = smt.get_merkle_path(i).unwrap();
                 assert!(path.verify(smt.height as usize, &leaf, &root).unwrap());
             }
@@ -876,9 +1118,7 @@ mod test {
         height: u8,
         rng: &mut R,
     ) {
-        use std::convert::TryInto;
-
-        let mut smt = FieldBasedSparseMHT::<T>::init(height);
+        let mut smt = FieldBasedSparseMHTAnyArity::<T>::init(height);
         let num_leaves = smt.width;
         let mut optimized =
             FieldBasedAppendOnlyMHT::<T>::init(smt.height as usize, num_leaves as usize).unwrap();
@@ -902,7 +1142,7 @@ mod test {
         // Check paths also for empty leaves
         for i in 0..smt.width {
             // Get leaf at that idx if existing
-            let leaf = smt.get_node_at_height_and_idx(0, i).0;
+            let leaf = smt.get_node_at_height_and_idx(0, i).unwrap().0;
 
             // Create and verify a FieldBasedMHTPath
             let path = smt.get_merkle_path(i).unwrap();
@@ -915,7 +1155,6 @@ mod test {
                 .unwrap());
 
             // Assert the two paths are equal
-            let optimized_path: FieldBasedBinaryMHTPath<T> = optimized_path.try_into().unwrap();
             assert_eq!(optimized_path, path);
 
             // Check leaf index is the correct one
@@ -936,7 +1175,259 @@ mod test {
 
             // Serialization/deserialization test
             algebra::serialize::test_canonical_serialize_deserialize(true, &path);
+
+            // Non-membership proof can be created and verified only for empty leaves
+            if smt.is_leaf_empty(i).unwrap() {
+                let proof = smt.get_non_membership_proof(i).unwrap();
+                assert_eq!(proof.get_path(), &path);
+                assert_eq!(i as usize, proof.leaf_index());
+                assert!(proof.verify(smt.height as usize, &root).unwrap());
+                let empty_root = T::ZERO_NODE_CST.unwrap().nodes[smt.height as usize];
+                assert!(!proof.verify(smt.height as usize, &empty_root).unwrap());
+                assert!(proof.verify(smt.height as usize - 1, &root).is_err());
+            } else {
+                assert!(smt.get_non_membership_proof(i).is_err());
+                let proof = FieldBasedMHTNonMembershipProof::new(path);
+                assert!(!proof.verify(smt.height as usize, &root).unwrap());
+            }
         }
+
+        // Non-membership proof can't be created for an index out of range
+        assert!(smt.get_non_membership_proof(smt.width).is_err());
+
+        // Non-membership proof can't be created if there are pending changes
+        smt.update_leaves(vec![OperationLeaf::new(
+            num_leaves - 1,
+            ActionLeaf::Insert,
+            Some(T::Data::one()),
+        )])
+        .unwrap();
+        assert!(smt.is_leaf_empty(num_leaves - 2).unwrap());
+        assert!(smt.get_non_membership_proof(num_leaves - 2).is_err());
+    }
+
+    /// Test that a FieldBasedSparseMHT backed by a FileSMTStorage survives restarts,
+    /// i.e. that re-opening the storage gives back the tree as of the last finalization.
+    fn test_file_storage<T: BatchFieldBasedMerkleTreeParameters, R: RngCore>(
+        height: u8,
+        rng: &mut R,
+    ) {
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("smt_file_storage_{}", rng.next_u64()));
+        let _ = std::fs::remove_dir_all(&path);
+
+        let num_leaves = T::MERKLE_ARITY.pow(height as u32) as u32;
+        let (root, leaves) = {
+            let mut smt = FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+                height,
+                FileSMTStorage::open(&path).unwrap(),
+            )
+            .unwrap();
+            assert_eq!(
+                smt.root().unwrap(),
+                T::ZERO_NODE_CST.unwrap().nodes[height as usize]
+            );
+
+            // Fill half of the tree, in batches
+            let mut leaves = (0..num_leaves)
+                .map(|idx| OperationLeaf::new(idx, ActionLeaf::Insert, Some(T::Data::rand(rng))))
+                .collect::<Vec<_>>();
+            leaves.shuffle(rng);
+            leaves.truncate((num_leaves / 2) as usize);
+            leaves
+                .chunks(rng.gen_range(1..num_leaves / 2) as usize)
+                .for_each(|chunk| {
+                    smt.update_leaves(chunk.to_vec()).unwrap();
+                    compare_append_only_and_smt_roots(&mut smt);
+                });
+
+            // Remove some of them
+            let removals = leaves[..(num_leaves / 8) as usize]
+                .iter()
+                .map(|leaf| OperationLeaf::new(leaf.position, ActionLeaf::Remove, None))
+                .collect::<Vec<_>>();
+            smt.update_leaves(removals).unwrap();
+            compare_append_only_and_smt_roots(&mut smt);
+            let persisted_leaves = get_non_empty_leaves(&smt);
+            assert_eq!(
+                persisted_leaves.len(),
+                (num_leaves / 2 - num_leaves / 8) as usize
+            );
+
+            // The emptiness of the tree and its last non-empty position, found without
+            // scanning the leaves, must take into account the pending changes
+            let check_last_position =
+                |smt: &FieldBasedSparseMHTAnyArity<T, FileSMTStorage<T::Data>>| {
+                    let indices = smt.get_non_empty_leaves_indices().unwrap();
+                    assert_eq!(smt.is_tree_empty().unwrap(), indices.is_empty());
+                    assert_eq!(
+                        smt.get_last_non_empty_position().unwrap(),
+                        indices.last().copied()
+                    );
+                };
+            check_last_position(&smt);
+            for (&idx, _) in persisted_leaves.iter().rev() {
+                smt.update_leaves(vec![OperationLeaf::new(idx, ActionLeaf::Remove, None)])
+                    .unwrap();
+                check_last_position(&smt);
+            }
+            assert!(smt.is_tree_empty().unwrap());
+            for (&idx, &leaf) in persisted_leaves.iter() {
+                smt.update_leaves(vec![OperationLeaf::new(
+                    idx,
+                    ActionLeaf::Insert,
+                    Some(leaf),
+                )])
+                .unwrap();
+                check_last_position(&smt);
+            }
+            assert_eq!(get_non_empty_leaves(&smt), persisted_leaves);
+
+            // Pending changes are not persisted
+            let committed_root = smt.storage().get_root().unwrap().unwrap();
+            smt.update_leaves(vec![OperationLeaf::new(
+                leaves[0].position,
+                ActionLeaf::Insert,
+                Some(T::Data::rand(rng)),
+            )])
+            .unwrap();
+            assert_eq!(smt.storage().get_root().unwrap().unwrap(), committed_root);
+
+            // Until the tree is finalized: copies of the tree share the same storage,
+            // thus this holds also when finalizing a copy.
+            let copy = smt.finalize().unwrap();
+            assert_ne!(copy.root().unwrap(), committed_root);
+            assert_eq!(
+                copy.root().unwrap(),
+                smt.storage().get_root().unwrap().unwrap()
+            );
+            assert_eq!(copy.storage().path(), smt.storage().path());
+
+            (copy.root().unwrap(), get_non_empty_leaves(&copy))
+        };
+
+        // Re-open the storage: we must get back the same tree
+        let check_tree = |smt: &FieldBasedSparseMHTAnyArity<T, FileSMTStorage<T::Data>>| {
+            assert_eq!(smt.root().unwrap(), root);
+            assert_eq!(smt.root().unwrap(), compute_append_only_tree_root(smt));
+            for idx in 0..num_leaves {
+                let leaf = smt.get_node_at_height_and_idx(0, idx).unwrap().0;
+                let path = smt.get_merkle_path(idx).unwrap();
+                assert!(path.verify(height as usize, &leaf, &root).unwrap());
+            }
+        };
+        {
+            let smt = FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+                height,
+                FileSMTStorage::open(&path).unwrap(),
+            )
+            .unwrap();
+            check_tree(&smt);
+            assert_eq!(get_non_empty_leaves(&smt), leaves);
+        }
+
+        // Simulate a crash in the middle of a commit, by writing a truncated journal record
+        {
+            let mut file = std::fs::OpenOptions::new()
+                .write(true)
+                .open(path.join("journal"))
+                .unwrap();
+            file.write_all(&100u64.to_be_bytes()).unwrap();
+            file.write_all(&[1u8; 10]).unwrap();
+        }
+        {
+            // Use a small cache, so that leaves and nodes are mostly read from disk
+            let mut smt = FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+                height,
+                FileSMTStorage::open_with_cache_capacity(&path, 16).unwrap(),
+            )
+            .unwrap();
+            check_tree(&smt);
+            assert_eq!(
+                std::fs::metadata(path.join("journal")).unwrap().len(),
+                0,
+                "Incomplete record must be discarded"
+            );
+
+            // The tree must still be updatable
+            let empty_idx = (0..num_leaves)
+                .find(|&idx| smt.is_leaf_empty(idx).unwrap())
+                .unwrap();
+            smt.update_leaves(vec![OperationLeaf::new(
+                empty_idx,
+                ActionLeaf::Insert,
+                Some(T::Data::rand(rng)),
+            )])
+            .unwrap();
+            smt.finalize_in_place().unwrap();
+            assert_ne!(smt.root().unwrap(), root);
+            smt.update_leaves(vec![OperationLeaf::new(
+                empty_idx,
+                ActionLeaf::Remove,
+                None,
+            )])
+            .unwrap();
+            smt.finalize_in_place().unwrap();
+            check_tree(&smt);
+        }
+
+        // Storage of a tree of different height must be rejected
+        assert!(FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+            height + 1,
+            FileSMTStorage::open(&path).unwrap()
+        )
+        .is_err());
+
+        // Reset must clear the storage
+        {
+            let mut smt = FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+                height,
+                FileSMTStorage::open(&path).unwrap(),
+            )
+            .unwrap();
+            check_tree(&smt);
+            smt.reset().unwrap();
+        }
+        {
+            let smt = FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+                height,
+                FileSMTStorage::open(&path).unwrap(),
+            )
+            .unwrap();
+            assert!(smt.is_tree_empty().unwrap());
+            assert_eq!(
+                smt.root().unwrap(),
+                T::ZERO_NODE_CST.unwrap().nodes[height as usize]
+            );
+        }
+
+        // Errors of the storage, e.g. due to a corrupted leaf, are returned instead of panicking
+        {
+            use algebra::serialize::CanonicalSerialize;
+
+            let slot_size = 1 + T::Data::zero().serialized_size();
+            std::fs::write(path.join("leaves"), vec![2u8; slot_size]).unwrap();
+            let mut smt = FieldBasedSparseMHTAnyArity::<T, _>::init_with_storage(
+                height,
+                FileSMTStorage::open(&path).unwrap(),
+            )
+            .unwrap();
+            assert!(smt.is_leaf_empty(0).is_err());
+            assert!(smt.get_merkle_path(1).is_none());
+            assert!(smt
+                .update_leaves(vec![OperationLeaf::new(0, ActionLeaf::Remove, None)])
+                .is_err());
+            smt.update_leaves(vec![OperationLeaf::new(
+                0,
+                ActionLeaf::Insert,
+                Some(T::Data::one()),
+            )])
+            .unwrap();
+            assert!(smt.finalize_in_place().is_err());
+        }
+
+        std::fs::remove_dir_all(&path).unwrap();
     }
 
     // Tests below stress the functionality inherited from FieldBasedMerkleTree trait
@@ -947,7 +1438,7 @@ mod test {
         mut rng: &mut R,
     ) {
         // Init smt
-        let mut tree = FieldBasedSparseMHT::<T>::init(height as u8);
+        let mut tree = FieldBasedSparseMHTAnyArity::<T>::init(height as u8);
 
         // Init naive merkle tree used as comparison
         let mut naive_mt = NaiveMerkleTree::<T>::new(height);
@@ -984,7 +1475,7 @@ mod test {
         mut rng: &mut R,
     ) {
         // Init in memory optimized tree
-        let mut tree = FieldBasedSparseMHT::<T>::init(height as u8);
+        let mut tree = FieldBasedSparseMHTAnyArity::<T>::init(height as u8);
 
         // Create leaves at random
         let leaves = (0..num_leaves)
@@ -1006,7 +1497,7 @@ mod test {
         });
 
         // Reset the tree
-        tree.finalize_in_place().unwrap().reset();
+        tree.finalize_in_place().unwrap().reset().unwrap();
 
         // Add the same leaves as we did initially
         leaves[..num_leaves / 2].iter().for_each(|leaf| {
@@ -1019,6 +1510,73 @@ mod test {
         assert_eq!(expected_root, tree.finalize().unwrap().root().unwrap());
     }
 
+    /// Test that a binary FieldBasedSparseMHT returns, by default, FieldBasedBinaryMHTPaths
+    /// equivalent to the FieldBasedMHTPaths of a FieldBasedSparseMHTAnyArity.
+    fn test_binary_merkle_path<T: BatchFieldBasedMerkleTreeParameters, R: RngCore>(
+        height: u8,
+        rng: &mut R,
+    ) {
+        use std::convert::TryInto;
+
+        assert_eq!(T::MERKLE_ARITY, 2);
+        let mut smt = FieldBasedSparseMHT::<T>::init(height);
+        let mut smt_any_arity = FieldBasedSparseMHTAnyArity::<T>::init(height);
+
+        let mut leaves = HashMap::new();
+        while leaves.len() < (smt.width / 2) as usize {
+            leaves.insert(rng.gen_range(0..smt.width), T::Data::rand(rng));
+        }
+        smt.insert_leaves(leaves.clone()).unwrap();
+        smt_any_arity.insert_leaves(leaves).unwrap();
+        let root = smt.finalize_in_place().unwrap().root().unwrap();
+        assert_eq!(
+            root,
+            smt_any_arity.finalize_in_place().unwrap().root().unwrap()
+        );
+
+        for i in 0..smt.width {
+            let leaf = smt.get_node_at_height_and_idx(0, i).unwrap().0;
+            let path: FieldBasedBinaryMHTPath<T> = smt.get_merkle_path(i).unwrap();
+            assert!(path.verify(height as usize, &leaf, &root).unwrap());
+
+            // The two paths are equivalent, and losslessly converted into each other
+            let path_any_arity = smt_any_arity.get_merkle_path(i).unwrap();
+            assert_eq!(path, path_any_arity);
+            assert_eq!(FieldBasedMHTPath::from(path.clone()), path_any_arity);
+            let converted_path: FieldBasedBinaryMHTPath<T> = path_any_arity.try_into().unwrap();
+            assert_eq!(converted_path, path);
+
+            if smt.is_leaf_empty(i).unwrap() {
+                let proof: FieldBasedMHTNonMembershipProof<FieldBasedBinaryMHTPath<T>> =
+                    smt.get_non_membership_proof(i).unwrap();
+                assert_eq!(proof.get_path(), &path);
+                assert!(proof.verify(height as usize, &root).unwrap());
+            }
+        }
+    }
+
+    /// Run all the FieldBasedSparseMerkleTree related tests on a tree of the specified `height`.
+    fn test_sparse_merkle_tree<T: BatchFieldBasedMerkleTreeParameters, R: RngCore>(
+        height: u8,
+        rng: &mut R,
+    ) {
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        for _ in 0..NUM_SAMPLES {
+            test_batch_all_additions_removals::<T, _>(height, rng, true, true);
+            test_batch_all_additions_removals::<T, _>(height, rng, true, false);
+            test_batch_all_additions_removals::<T, _>(height, rng, false, false);
+            test_batch_all_additions_removals::<T, _>(height, rng, false, true);
+            test_batch_mixed_additions_removals::<T, _>(height, rng, false);
+            test_batch_mixed_additions_removals::<T, _>(height, rng, true);
+        }
+        test_merkle_path::<T, _>(height, rng);
+        test_error_cases::<T>(height);
+        test_edge_cases::<T>(height);
+        test_file_storage::<T, _>(height, &mut XorShiftRng::seed_from_u64(1234567890u64));
+    }
+
     #[cfg(feature = "tweedle")]
     #[test]
     fn test_tweedle_fr() {
@@ -1047,47 +1605,8 @@ mod test {
         let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
 
         // FieldBasedSparseMerkleTree related tests
-        {
-            for _ in 0..NUM_SAMPLES {
-                test_batch_all_additions_removals::<TweedleFrFieldBasedMerkleTreeParams, _>(
-                    TEST_HEIGHT,
-                    rng,
-                    true,
-                    true,
-                );
-                test_batch_all_additions_removals::<TweedleFrFieldBasedMerkleTreeParams, _>(
-                    TEST_HEIGHT,
-                    rng,
-                    true,
-                    false,
-                );
-                test_batch_all_additions_removals::<TweedleFrFieldBasedMerkleTreeParams, _>(
-                    TEST_HEIGHT,
-                    rng,
-                    false,
-                    false,
-                );
-                test_batch_all_additions_removals::<TweedleFrFieldBasedMerkleTreeParams, _>(
-                    TEST_HEIGHT,
-                    rng,
-                    false,
-                    true,
-                );
-                test_batch_mixed_additions_removals::<TweedleFrFieldBasedMerkleTreeParams, _>(
-                    TEST_HEIGHT,
-                    rng,
-                    false,
-                );
-                test_batch_mixed_additions_removals::<TweedleFrFieldBasedMerkleTreeParams, _>(
-                    TEST_HEIGHT,
-                    rng,
-                    true,
-                );
-            }
-            test_merkle_path::<TweedleFrFieldBasedMerkleTreeParams, _>(TEST_HEIGHT, rng);
-            test_error_cases::<TweedleFrFieldBasedMerkleTreeParams>(TEST_HEIGHT);
-            test_edge_cases::<TweedleFrFieldBasedMerkleTreeParams>();
-        }
+        test_sparse_merkle_tree::<TweedleFrFieldBasedMerkleTreeParams, _>(TEST_HEIGHT, rng);
+        test_binary_merkle_path::<TweedleFrFieldBasedMerkleTreeParams, _>(TEST_HEIGHT, rng);
 
         // FieldBasedMerkleTree related tests
         {
@@ -1114,4 +1633,76 @@ mod test {
             );
         }
     }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr_arity_4() {
+        use crate::{
+            crh::{TweedleFrBatchPoseidonHashRate4, TweedleFrPoseidonHashRate4},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4,
+        };
+        use algebra::fields::tweedle::Fr;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHashRate4;
+            const MERKLE_ARITY: usize = 4;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHashRate4;
+        }
+
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let height = 5;
+
+        test_sparse_merkle_tree::<TweedleFrFieldBasedMerkleTreeParams, _>(height, rng);
+        merkle_tree_reset_test::<TweedleFrFieldBasedMerkleTreeParams, _>(
+            height as usize,
+            4usize.pow(height as u32),
+            rng,
+        );
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn test_tweedle_fr_arity_8() {
+        use crate::{
+            crh::{TweedleFrBatchPoseidonHashRate8, TweedleFrPoseidonHashRate8},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8,
+        };
+        use algebra::fields::tweedle::Fr;
+        use rand::SeedableRng;
+        use rand_xorshift::XorShiftRng;
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = Fr;
+            type H = TweedleFrPoseidonHashRate8;
+            const MERKLE_ARITY: usize = 8;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHashRate8;
+        }
+
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let height = 3;
+
+        test_sparse_merkle_tree::<TweedleFrFieldBasedMerkleTreeParams, _>(height, rng);
+        merkle_tree_reset_test::<TweedleFrFieldBasedMerkleTreeParams, _>(
+            height as usize,
+            8usize.pow(height as u32),
+            rng,
+        );
+    }
 }
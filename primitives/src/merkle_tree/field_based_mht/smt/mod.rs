@@ -1,2 +1,5 @@
 pub mod big_lazy_merkle_tree;
 pub use self::big_lazy_merkle_tree::*;
+
+pub mod storage;
+pub use self::storage::*;
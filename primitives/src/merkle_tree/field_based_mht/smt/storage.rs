@@ -0,0 +1,752 @@
+use crate::{merkle_tree::MerkleTreeError, Error};
+use algebra::{serialize::*, Field};
+use fs2::FileExt;
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::{File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// A set of changes to be applied atomically to a `SMTStorage`.
+/// A `None` value means that the corresponding leaf/node must be removed,
+/// as it has become empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SMTStorageBatch<D: Field> {
+    pub leaves: Vec<(u32, Option<D>)>,
+    pub nodes: Vec<(u32, Option<D>)>,
+    pub root: D,
+}
+
+/// Storage backend for the leaves and the nodes of a `FieldBasedSparseMHT`.
+/// Only the non-empty leaves and nodes are stored: they are identified uniquely
+/// by their index, in the same way as done by the tree.
+/// Implementations are required to apply each `SMTStorageBatch` atomically, i.e.
+/// either all the changes in the batch are applied, or none of them.
+/// All the operations may fail, e.g. due to I/O errors of a disk-backed storage:
+/// errors are returned to the caller, never turned into panics.
+pub trait SMTStorage<D: Field>: Clone {
+    /// Return the leaf at index `idx`, if it's not empty.
+    fn get_leaf(&self, idx: u32) -> Result<Option<D>, Error>;
+
+    /// Return the node at index `idx`, if it's not empty.
+    fn get_node(&self, idx: u32) -> Result<Option<D>, Error>;
+
+    /// Return the root of the tree as of the last committed batch,
+    /// or None if no batch has been committed yet.
+    fn get_root(&self) -> Result<Option<D>, Error>;
+
+    /// Return the number of non-empty leaves.
+    fn num_leaves(&self) -> Result<usize, Error>;
+
+    /// Return the number of non-empty nodes.
+    fn num_nodes(&self) -> Result<usize, Error>;
+
+    /// Atomically apply all the changes in `batch`.
+    fn commit(&mut self, batch: SMTStorageBatch<D>) -> Result<(), Error>;
+
+    /// Remove all the leaves and nodes, as well as the root.
+    fn clear(&mut self) -> Result<(), Error>;
+}
+
+/// In-memory storage: leaves and nodes are kept in `HashMap`s.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), Default(bound = ""))]
+pub struct InMemorySMTStorage<D: Field> {
+    pub(crate) leaves: HashMap<u32, D>,
+    pub(crate) nodes: HashMap<u32, D>,
+    pub(crate) root: Option<D>,
+}
+
+impl<D: Field> InMemorySMTStorage<D> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn apply(&mut self, batch: SMTStorageBatch<D>) {
+        for (idx, leaf) in batch.leaves.into_iter() {
+            match leaf {
+                Some(leaf) => self.leaves.insert(idx, leaf),
+                None => self.leaves.remove(&idx),
+            };
+        }
+        for (idx, node) in batch.nodes.into_iter() {
+            match node {
+                Some(node) => self.nodes.insert(idx, node),
+                None => self.nodes.remove(&idx),
+            };
+        }
+        self.root = Some(batch.root);
+    }
+}
+
+impl<D: Field> SMTStorage<D> for InMemorySMTStorage<D> {
+    fn get_leaf(&self, idx: u32) -> Result<Option<D>, Error> {
+        Ok(self.leaves.get(&idx).copied())
+    }
+
+    fn get_node(&self, idx: u32) -> Result<Option<D>, Error> {
+        Ok(self.nodes.get(&idx).copied())
+    }
+
+    fn get_root(&self) -> Result<Option<D>, Error> {
+        Ok(self.root)
+    }
+
+    fn num_leaves(&self) -> Result<usize, Error> {
+        Ok(self.leaves.len())
+    }
+
+    fn num_nodes(&self) -> Result<usize, Error> {
+        Ok(self.nodes.len())
+    }
+
+    fn commit(&mut self, batch: SMTStorageBatch<D>) -> Result<(), Error> {
+        self.apply(batch);
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Error> {
+        *self = Self::default();
+        Ok(())
+    }
+}
+
+/// File-backed storage, allowing a `FieldBasedSparseMHT` to survive restarts.
+/// The storage is a directory holding:
+/// - `leaves` and `nodes`: each leaf/node is kept in a fixed-size slot (a presence byte
+///   followed by the serialized value) at the position given by its index. The slots of
+///   the empty leaves/nodes are never written, thus these are sparse files;
+/// - `state`: the root and the number of non-empty leaves and nodes;
+/// - `journal`: the batch being committed, if any;
+/// - `lock`: exclusively locked while the storage is open, so that a directory is never used
+///   by two storages at the same time (even from different processes).
+///
+/// A batch is first written and synced to the journal, then applied to the other files and
+/// finally removed from the journal. When re-opening the storage after a crash, a batch
+/// entirely written to the journal is applied again, while a truncated one, or one not
+/// matching its checksum, is discarded: this way the storage is always brought back to the
+/// last committed state, and the journal never holds more than a single batch.
+/// Leaves and nodes are read lazily from disk, and at most `cache_capacity` of them are
+/// kept in memory. The root and the number of non-empty leaves and nodes are instead always
+/// kept in memory.
+/// Clones of a `FileSMTStorage` share the same files: changes committed through any of
+/// them are persisted, and are visible to all of them.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""))]
+pub struct FileSMTStorage<D: Field> {
+    inner: Arc<Mutex<FileSMTStorageInner<D>>>,
+}
+
+/// Default maximum number of leaves and nodes cached in memory by a `FileSMTStorage`.
+pub const DEFAULT_SMT_STORAGE_CACHE_CAPACITY: usize = 1 << 16;
+
+const LEAVES_FILE: &str = "leaves";
+const NODES_FILE: &str = "nodes";
+const STATE_FILE: &str = "state";
+const JOURNAL_FILE: &str = "journal";
+const LOCK_FILE: &str = "lock";
+
+/// Size of the header of a journal record: the length of the record (8 bytes, big endian)
+/// followed by its CRC-32 checksum (4 bytes, big endian).
+const JOURNAL_HEADER_SIZE: usize = 12;
+
+/// The root of the tree and the number of non-empty leaves and nodes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+struct FileSMTStorageState<D: Field> {
+    root: Option<D>,
+    num_leaves: u64,
+    num_nodes: u64,
+}
+
+/// A record of the journal: the batch to be applied, after clearing the storage if
+/// `clear` is set, and the resulting state. Applying a record is idempotent.
+#[derive(Clone, Debug, CanonicalSerialize, CanonicalDeserialize)]
+struct JournalRecord<D: Field> {
+    clear: bool,
+    batch: SMTStorageBatch<D>,
+    state: FileSMTStorageState<D>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Slot {
+    Leaf(u32),
+    Node(u32),
+}
+
+/// A bounded cache of leaves and nodes (including the empty ones). When full, the
+/// entry inserted first is evicted.
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""))]
+struct SlotCache<D: Field> {
+    capacity: usize,
+    entries: HashMap<Slot, Option<D>>,
+    order: VecDeque<Slot>,
+}
+
+impl<D: Field> SlotCache<D> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&self, slot: Slot) -> Option<Option<D>> {
+        self.entries.get(&slot).copied()
+    }
+
+    fn insert(&mut self, slot: Slot, value: Option<D>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.insert(slot, value).is_none() {
+            self.order.push_back(slot);
+            if self.order.len() > self.capacity {
+                let evicted = self.order.pop_front().unwrap();
+                self.entries.remove(&evicted);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(Debug(bound = ""))]
+struct FileSMTStorageInner<D: Field> {
+    path: PathBuf,
+    leaves: File,
+    nodes: File,
+    state_file: File,
+    journal: File,
+    /// Exclusively locked until dropped
+    lock_file: File,
+    state: FileSMTStorageState<D>,
+    cache: SlotCache<D>,
+    /// Size of a slot of the `leaves` and `nodes` files
+    slot_size: u64,
+    /// Set if a batch has been journaled but not (entirely) applied, due to an I/O error:
+    /// the storage must then be re-opened to complete it.
+    needs_recovery: bool,
+}
+
+impl<D: Field> FileSMTStorageInner<D> {
+    fn file(&mut self, slot: Slot) -> (&mut File, u64) {
+        match slot {
+            Slot::Leaf(idx) => (&mut self.leaves, idx as u64 * self.slot_size),
+            Slot::Node(idx) => (&mut self.nodes, idx as u64 * self.slot_size),
+        }
+    }
+
+    /// Read the value in `slot`, from the cache or from disk.
+    fn read(&mut self, slot: Slot) -> Result<Option<D>, Error> {
+        if let Some(value) = self.cache.get(slot) {
+            return Ok(value);
+        }
+
+        let slot_size = self.slot_size as usize;
+        let (file, offset) = self.file(slot);
+        let value = if offset + slot_size as u64 > file.metadata()?.len() {
+            None
+        } else {
+            let mut bytes = vec![0u8; slot_size];
+            file.seek(SeekFrom::Start(offset))?;
+            file.read_exact(&mut bytes)?;
+            match bytes[0] {
+                0 => None,
+                1 => Some(CanonicalDeserialize::deserialize(&bytes[1..])?),
+                _ => Err(MerkleTreeError::Other(format!(
+                    "Corrupted slot {:?} in SMT storage",
+                    slot
+                )))?,
+            }
+        };
+        self.cache.insert(slot, value);
+        Ok(value)
+    }
+
+    /// Write `value` to `slot`, on disk only. Empty slots past the end of the file are
+    /// not written, to keep the file sparse.
+    fn write(&mut self, slot: Slot, value: Option<D>) -> Result<(), Error> {
+        let slot_size = self.slot_size as usize;
+        let (file, offset) = self.file(slot);
+        let mut bytes = Vec::with_capacity(slot_size);
+        match value {
+            Some(value) => {
+                bytes.push(1u8);
+                CanonicalSerialize::serialize(&value, &mut bytes)?;
+            }
+            None => {
+                if offset >= file.metadata()?.len() {
+                    return Ok(());
+                }
+                bytes.resize(slot_size, 0u8);
+            }
+        }
+        file.seek(SeekFrom::Start(offset))?;
+        file.write_all(bytes.as_slice())?;
+        Ok(())
+    }
+
+    /// Apply `record` to the `leaves`, `nodes` and `state` files, and sync them.
+    fn apply(&mut self, record: &JournalRecord<D>) -> Result<(), Error> {
+        if record.clear {
+            self.leaves.set_len(0)?;
+            self.nodes.set_len(0)?;
+        }
+        for &(idx, leaf) in record.batch.leaves.iter() {
+            self.write(Slot::Leaf(idx), leaf)?;
+        }
+        for &(idx, node) in record.batch.nodes.iter() {
+            self.write(Slot::Node(idx), node)?;
+        }
+
+        let mut state = Vec::with_capacity(record.state.serialized_size());
+        CanonicalSerialize::serialize(&record.state, &mut state)?;
+        self.state_file.set_len(0)?;
+        self.state_file.seek(SeekFrom::Start(0))?;
+        self.state_file.write_all(state.as_slice())?;
+
+        for file in [&self.leaves, &self.nodes, &self.state_file].iter() {
+            file.sync_all()?;
+        }
+        Ok(())
+    }
+
+    /// Write `record` to the journal, apply it and remove it from the journal.
+    fn commit(&mut self, record: JournalRecord<D>) -> Result<(), Error> {
+        if self.needs_recovery {
+            Err(MerkleTreeError::Other(
+                "SMT storage must be re-opened to recover from a failed commit".to_owned(),
+            ))?
+        }
+
+        let mut bytes = vec![0u8; JOURNAL_HEADER_SIZE];
+        CanonicalSerialize::serialize(&record, &mut bytes)?;
+        let record_len = (bytes.len() - JOURNAL_HEADER_SIZE) as u64;
+        let checksum = journal_checksum(&bytes[JOURNAL_HEADER_SIZE..]);
+        bytes[..8].copy_from_slice(&record_len.to_be_bytes());
+        bytes[8..JOURNAL_HEADER_SIZE].copy_from_slice(&checksum.to_be_bytes());
+        let journaled = self
+            .journal
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| self.journal.write_all(bytes.as_slice()))
+            .and_then(|_| self.journal.sync_all());
+        if let Err(e) = journaled {
+            // The batch is not committed: try to restore the journal
+            let _ = self.journal.set_len(0);
+            return Err(Box::new(e));
+        }
+
+        // From now on, the batch is committed
+        self.cache.clear();
+        let applied = self.apply(&record).and_then(|_| {
+            self.journal.set_len(0)?;
+            self.journal.sync_all()?;
+            Ok(())
+        });
+        if applied.is_err() {
+            self.needs_recovery = true;
+        }
+        self.state = record.state;
+        applied
+    }
+}
+
+impl<D: Field> FileSMTStorage<D> {
+    /// Open the storage in the directory at `path`, creating it if it doesn't exist, and
+    /// restore the last committed state from it. At most `DEFAULT_SMT_STORAGE_CACHE_CAPACITY`
+    /// leaves and nodes are cached in memory.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Self::open_with_cache_capacity(path, DEFAULT_SMT_STORAGE_CACHE_CAPACITY)
+    }
+
+    /// Open the storage in the directory at `path`, creating it if it doesn't exist, and
+    /// restore the last committed state from it. At most `cache_capacity` leaves and nodes
+    /// are cached in memory.
+    /// Fails if the directory is already used by another storage, until all the clones of
+    /// the latter are dropped.
+    pub fn open_with_cache_capacity<P: AsRef<Path>>(
+        path: P,
+        cache_capacity: usize,
+    ) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        std::fs::create_dir_all(&path)?;
+
+        let open_file = |name: &str| -> Result<(File, bool), Error> {
+            let file_path = path.join(name);
+            let created = !file_path.exists();
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(file_path)?;
+            Ok((file, created))
+        };
+        let (lock_file, created_lock) = open_file(LOCK_FILE)?;
+        lock_file.try_lock_exclusive().map_err(|e| {
+            MerkleTreeError::Other(format!(
+                "Unable to lock SMT storage at {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        let (leaves, created_leaves) = open_file(LEAVES_FILE)?;
+        let (nodes, created_nodes) = open_file(NODES_FILE)?;
+        let (mut state_file, created_state) = open_file(STATE_FILE)?;
+        let (mut journal, created_journal) = open_file(JOURNAL_FILE)?;
+
+        // Make the creation of the files durable
+        if created_lock || created_leaves || created_nodes || created_state || created_journal {
+            sync_dir(&path)?;
+        }
+
+        let mut state_bytes = Vec::new();
+        state_file.read_to_end(&mut state_bytes)?;
+        let state = if state_bytes.is_empty() {
+            FileSMTStorageState::default()
+        } else {
+            FileSMTStorageState::deserialize(state_bytes.as_slice())?
+        };
+
+        let mut journal_bytes = Vec::new();
+        journal.read_to_end(&mut journal_bytes)?;
+
+        let mut inner = FileSMTStorageInner {
+            path,
+            leaves,
+            nodes,
+            state_file,
+            journal,
+            lock_file,
+            state,
+            cache: SlotCache::new(cache_capacity),
+            slot_size: 1 + D::zero().serialized_size() as u64,
+            needs_recovery: false,
+        };
+
+        // Complete the batch in the journal, if entirely and correctly written, or discard it
+        if !journal_bytes.is_empty() {
+            if let Some(record_bytes) = read_journal_record(journal_bytes.as_slice()) {
+                let record = JournalRecord::<D>::deserialize(record_bytes).map_err(|e| {
+                    MerkleTreeError::Other(format!("Unable to read SMT storage journal: {}", e))
+                })?;
+                inner.apply(&record)?;
+                inner.state = record.state;
+            }
+            inner.journal.set_len(0)?;
+            inner.journal.sync_all()?;
+        }
+
+        Ok(Self {
+            inner: Arc::new(Mutex::new(inner)),
+        })
+    }
+
+    /// Return the path of the directory of the storage
+    pub fn path(&self) -> PathBuf {
+        self.lock().path.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FileSMTStorageInner<D>> {
+        self.inner
+            .lock()
+            .expect("SMT storage lock should not be poisoned")
+    }
+}
+
+/// CRC-32 checksum of a serialized journal record.
+fn journal_checksum(record_bytes: &[u8]) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(record_bytes);
+    hasher.finalize()
+}
+
+/// Return the serialized record in `journal_bytes`, if entirely written and matching its checksum.
+fn read_journal_record(journal_bytes: &[u8]) -> Option<&[u8]> {
+    if journal_bytes.len() < JOURNAL_HEADER_SIZE {
+        return None;
+    }
+    let mut record_len = [0u8; 8];
+    record_len.copy_from_slice(&journal_bytes[..8]);
+    let record_len = u64::from_be_bytes(record_len);
+    let mut checksum = [0u8; 4];
+    checksum.copy_from_slice(&journal_bytes[8..JOURNAL_HEADER_SIZE]);
+    let checksum = u32::from_be_bytes(checksum);
+
+    if ((journal_bytes.len() - JOURNAL_HEADER_SIZE) as u64) < record_len {
+        return None;
+    }
+    let record_bytes =
+        &journal_bytes[JOURNAL_HEADER_SIZE..JOURNAL_HEADER_SIZE + record_len as usize];
+    if journal_checksum(record_bytes) != checksum {
+        return None;
+    }
+    Some(record_bytes)
+}
+
+/// Sync the directory at `path`, so that the creation of the files in it is durable.
+#[cfg(unix)]
+fn sync_dir(path: &Path) -> Result<(), Error> {
+    File::open(path)?.sync_all()?;
+    Ok(())
+}
+
+/// Directories can't be opened (thus synced) as files on non-unix platforms.
+#[cfg(not(unix))]
+fn sync_dir(_path: &Path) -> Result<(), Error> {
+    Ok(())
+}
+
+impl<D: Field> SMTStorage<D> for FileSMTStorage<D> {
+    fn get_leaf(&self, idx: u32) -> Result<Option<D>, Error> {
+        self.lock().read(Slot::Leaf(idx))
+    }
+
+    fn get_node(&self, idx: u32) -> Result<Option<D>, Error> {
+        self.lock().read(Slot::Node(idx))
+    }
+
+    fn get_root(&self) -> Result<Option<D>, Error> {
+        Ok(self.lock().state.root)
+    }
+
+    fn num_leaves(&self) -> Result<usize, Error> {
+        Ok(self.lock().state.num_leaves as usize)
+    }
+
+    fn num_nodes(&self) -> Result<usize, Error> {
+        Ok(self.lock().state.num_nodes as usize)
+    }
+
+    fn commit(&mut self, batch: SMTStorageBatch<D>) -> Result<(), Error> {
+        let mut inner = self.lock();
+
+        // Update the number of non-empty leaves and nodes, taking into account that
+        // the same index may appear more times in the batch.
+        let mut state = FileSMTStorageState {
+            root: Some(batch.root),
+            ..inner.state.clone()
+        };
+        let mut updated = HashMap::new();
+        let updates = batch
+            .leaves
+            .iter()
+            .map(|&(idx, leaf)| (Slot::Leaf(idx), leaf.is_some()))
+            .chain(
+                batch
+                    .nodes
+                    .iter()
+                    .map(|&(idx, node)| (Slot::Node(idx), node.is_some())),
+            );
+        for (slot, is_some) in updates {
+            let was_some = match updated.get(&slot) {
+                Some(&was_some) => was_some,
+                None => inner.read(slot)?.is_some(),
+            };
+            updated.insert(slot, is_some);
+            let count = match slot {
+                Slot::Leaf(_) => &mut state.num_leaves,
+                Slot::Node(_) => &mut state.num_nodes,
+            };
+            match (was_some, is_some) {
+                (false, true) => *count += 1,
+                (true, false) => *count -= 1,
+                _ => {}
+            }
+        }
+
+        inner.commit(JournalRecord {
+            clear: false,
+            batch,
+            state,
+        })
+    }
+
+    fn clear(&mut self) -> Result<(), Error> {
+        self.lock().commit(JournalRecord {
+            clear: true,
+            batch: SMTStorageBatch::default(),
+            state: FileSMTStorageState::default(),
+        })
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::*;
+    use rand::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_file_smt_storage() {
+        use algebra::{fields::tweedle::Fr, UniformRand};
+
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let path = std::env::temp_dir().join(format!("file_smt_storage_{}", rng.next_u64()));
+        let _ = std::fs::remove_dir_all(&path);
+
+        let leaves = (0..8).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let batch = SMTStorageBatch {
+            leaves: leaves
+                .iter()
+                .enumerate()
+                .map(|(idx, &leaf)| (2 * idx as u32, Some(leaf)))
+                .collect(),
+            nodes: vec![(100, Some(Fr::rand(rng))), (1000, Some(Fr::rand(rng)))],
+            root: Fr::rand(rng),
+        };
+        let check_storage = |storage: &FileSMTStorage<Fr>, batch: &SMTStorageBatch<Fr>| {
+            let leaves = batch
+                .leaves
+                .iter()
+                .filter_map(|&(idx, leaf)| leaf.map(|leaf| (idx, leaf)))
+                .collect::<HashMap<_, _>>();
+            let nodes = batch
+                .nodes
+                .iter()
+                .filter_map(|&(idx, node)| node.map(|node| (idx, node)))
+                .collect::<HashMap<_, _>>();
+            assert_eq!(storage.get_root().unwrap(), Some(batch.root));
+            assert_eq!(storage.num_leaves().unwrap(), leaves.len());
+            assert_eq!(storage.num_nodes().unwrap(), nodes.len());
+            for idx in 0..1024 {
+                assert_eq!(storage.get_leaf(idx).unwrap(), leaves.get(&idx).copied());
+                assert_eq!(storage.get_node(idx).unwrap(), nodes.get(&idx).copied());
+            }
+        };
+
+        // Commit a batch: it must be persisted. Only a bounded number of leaves and nodes
+        // is kept in memory.
+        {
+            let mut storage = FileSMTStorage::<Fr>::open_with_cache_capacity(&path, 4).unwrap();
+            assert_eq!(storage.get_root().unwrap(), None);
+            storage.commit(batch.clone()).unwrap();
+            check_storage(&storage, &batch);
+            assert!(storage.lock().cache.entries.len() <= 4);
+        }
+        let storage = FileSMTStorage::<Fr>::open(&path).unwrap();
+        check_storage(&storage, &batch);
+
+        // Clones share the same files
+        let mut new_batch = SMTStorageBatch {
+            leaves: vec![
+                (0, None),
+                (2, Some(Fr::rand(rng))),
+                (1, Some(Fr::rand(rng))),
+            ],
+            nodes: vec![(100, None), (1000, Some(Fr::rand(rng)))],
+            root: Fr::rand(rng),
+        };
+        let mut storage_clone = storage.clone();
+        storage_clone.commit(new_batch.clone()).unwrap();
+        new_batch
+            .leaves
+            .extend(batch.leaves.iter().skip(2).cloned());
+        check_storage(&storage, &new_batch);
+        drop((storage, storage_clone));
+
+        // Simulate a crash after writing the journal record of a commit, but before
+        // applying it: the commit must be completed when re-opening the storage.
+        let last_batch = SMTStorageBatch {
+            leaves: vec![(2, None), (3, Some(Fr::rand(rng)))],
+            nodes: vec![],
+            root: Fr::rand(rng),
+        };
+        new_batch
+            .leaves
+            .retain(|&(idx, leaf)| idx != 2 && leaf.is_some());
+        new_batch.leaves.extend(last_batch.leaves.iter().cloned());
+        new_batch.nodes.retain(|&(_, node)| node.is_some());
+        new_batch.root = last_batch.root;
+        let record = JournalRecord {
+            clear: false,
+            batch: last_batch,
+            state: FileSMTStorageState {
+                root: Some(new_batch.root),
+                num_leaves: 8,
+                num_nodes: 1,
+            },
+        };
+        let mut record_bytes = vec![];
+        CanonicalSerialize::serialize(&record, &mut record_bytes).unwrap();
+        let mut journal_bytes = (record_bytes.len() as u64).to_be_bytes().to_vec();
+        journal_bytes.extend_from_slice(&journal_checksum(&record_bytes).to_be_bytes());
+        journal_bytes.extend_from_slice(&record_bytes);
+        std::fs::write(path.join(JOURNAL_FILE), &journal_bytes).unwrap();
+
+        let storage = FileSMTStorage::<Fr>::open(&path).unwrap();
+        check_storage(&storage, &new_batch);
+        assert_eq!(std::fs::metadata(path.join(JOURNAL_FILE)).unwrap().len(), 0);
+
+        // The directory can't be used by two storages at the same time
+        assert!(FileSMTStorage::<Fr>::open(&path).is_err());
+        let storage_clone = storage.clone();
+        drop(storage);
+        assert!(FileSMTStorage::<Fr>::open(&path).is_err());
+        drop(storage_clone);
+
+        // A truncated journal record is instead discarded
+        std::fs::write(
+            path.join(JOURNAL_FILE),
+            &journal_bytes[..journal_bytes.len() - 1],
+        )
+        .unwrap();
+        let storage_after_crash = FileSMTStorage::<Fr>::open(&path).unwrap();
+        check_storage(&storage_after_crash, &new_batch);
+        assert_eq!(std::fs::metadata(path.join(JOURNAL_FILE)).unwrap().len(), 0);
+        drop(storage_after_crash);
+
+        // As well as a record not matching its checksum, e.g. written only in part
+        let record = JournalRecord {
+            clear: false,
+            batch: SMTStorageBatch {
+                leaves: vec![(5, Some(Fr::rand(rng)))],
+                nodes: vec![],
+                root: Fr::rand(rng),
+            },
+            state: FileSMTStorageState {
+                root: Some(Fr::rand(rng)),
+                num_leaves: 9,
+                num_nodes: 1,
+            },
+        };
+        let mut record_bytes = vec![];
+        CanonicalSerialize::serialize(&record, &mut record_bytes).unwrap();
+        let mut journal_bytes = (record_bytes.len() as u64).to_be_bytes().to_vec();
+        journal_bytes.extend_from_slice(&(journal_checksum(&record_bytes) ^ 1).to_be_bytes());
+        journal_bytes.extend_from_slice(&record_bytes);
+        std::fs::write(path.join(JOURNAL_FILE), &journal_bytes).unwrap();
+        let mut storage = FileSMTStorage::<Fr>::open(&path).unwrap();
+        check_storage(&storage, &new_batch);
+        assert_eq!(std::fs::metadata(path.join(JOURNAL_FILE)).unwrap().len(), 0);
+
+        // Clear must be persisted
+        storage.clear().unwrap();
+        drop(storage);
+        let storage = FileSMTStorage::<Fr>::open(&path).unwrap();
+        assert_eq!(storage.get_root().unwrap(), None);
+        assert_eq!(storage.num_leaves().unwrap(), 0);
+        assert_eq!(storage.num_nodes().unwrap(), 0);
+        assert_eq!(storage.get_leaf(0).unwrap(), None);
+        assert_eq!(storage.get_node(1000).unwrap(), None);
+        drop(storage);
+
+        // I/O errors and corrupted slots are returned to the caller
+        let slot_size = 1 + Fr::zero().serialized_size();
+        std::fs::write(path.join(LEAVES_FILE), vec![2u8; slot_size]).unwrap();
+        let storage = FileSMTStorage::<Fr>::open(&path).unwrap();
+        assert!(storage.get_leaf(0).is_err());
+        assert_eq!(storage.get_leaf(1).unwrap(), None);
+
+        std::fs::remove_dir_all(&path).unwrap();
+    }
+}
@@ -0,0 +1,410 @@
+//! The [BLS](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature) signature
+//! scheme over a pairing friendly curve, with public keys in G1 and signatures in G2 (the
+//! "minimal public key size" variant), so that the aggregation of the public keys of a
+//! committee is cheap in a circuit. Rogue key attacks against aggregated signatures on the
+//! same message are prevented by proofs of possession of the secret keys.
+use crate::{CryptoError, Error, SignatureScheme};
+use algebra::{
+    hash_to_curve::{HashToCurve, HashToField, MapToCurve, MapToCurveBasedHasher},
+    to_bytes, AffineCurve, Field, PairingEngine, ProjectiveCurve, ToBytes, UniformRand,
+};
+use rand::Rng;
+use sha2::Sha256;
+use std::marker::PhantomData;
+
+#[cfg(feature = "bls12_381")]
+use algebra::curves::bls12_381::{g1::Bls12_381G1Parameters, g2::Bls12_381G2Parameters, Bls12_381};
+#[cfg(any(feature = "bls12_377", feature = "bls12_381"))]
+use algebra::hash_to_curve::SWUMap;
+#[cfg(feature = "bls12_377")]
+use algebra::{
+    curves::bls12_377::{g1::Bls12_377G1Parameters, g2::Bls12_377G2Parameters, Bls12_377},
+    hash_to_curve::SvdWMap,
+};
+
+/// Hash `message` to the prime order subgroup of `G`, by the `hash_to_curve` of the
+/// [hash to curve draft](https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-hash-to-curve),
+/// with `expand_message_xmd` on SHA-256 and the map to curve `M`.
+pub fn hash_to_group<G, M>(dst: &[u8], message: &[u8]) -> Result<G, Error>
+where
+    G: AffineCurve,
+    G::BaseField: HashToField,
+    M: MapToCurve<G>,
+{
+    MapToCurveBasedHasher::<G, M, Sha256>::new(dst)?.hash(message)
+}
+
+/// The hashes to G1 and G2 of the BLS ciphersuites over a pairing engine, together with
+/// their domain separation tags.
+pub trait BlsHashToCurve: PairingEngine {
+    /// Domain separation tag for hashing the messages to be signed.
+    const SIG_DST: &'static [u8];
+
+    /// Domain separation tag for hashing the public keys in proofs of possession.
+    const POP_DST: &'static [u8];
+
+    /// Hash `message` to the prime order subgroup of G1.
+    fn hash_to_g1(dst: &[u8], message: &[u8]) -> Result<Self::G1Affine, Error>;
+
+    /// Hash `message` to the prime order subgroup of G2.
+    fn hash_to_g2(dst: &[u8], message: &[u8]) -> Result<Self::G2Affine, Error>;
+}
+
+/// The `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` ciphersuite of the draft.
+#[cfg(feature = "bls12_381")]
+impl BlsHashToCurve for Bls12_381 {
+    const SIG_DST: &'static [u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+    fn hash_to_g1(dst: &[u8], message: &[u8]) -> Result<Self::G1Affine, Error> {
+        hash_to_group::<_, SWUMap<Bls12_381G1Parameters>>(dst, message)
+    }
+
+    fn hash_to_g2(dst: &[u8], message: &[u8]) -> Result<Self::G2Affine, Error> {
+        hash_to_group::<_, SWUMap<Bls12_381G2Parameters>>(dst, message)
+    }
+}
+
+/// A ciphersuite for BLS12-377 named after the ones of the draft, which defines none
+/// for this curve. Messages are hashed to G2 by the Shallue-van de Woestijne map.
+#[cfg(feature = "bls12_377")]
+impl BlsHashToCurve for Bls12_377 {
+    const SIG_DST: &'static [u8] = b"BLS_SIG_BLS12377G2_XMD:SHA-256_SVDW_RO_POP_";
+    const POP_DST: &'static [u8] = b"BLS_POP_BLS12377G2_XMD:SHA-256_SVDW_RO_POP_";
+
+    fn hash_to_g1(dst: &[u8], message: &[u8]) -> Result<Self::G1Affine, Error> {
+        hash_to_group::<_, SWUMap<Bls12_377G1Parameters>>(dst, message)
+    }
+
+    fn hash_to_g2(dst: &[u8], message: &[u8]) -> Result<Self::G2Affine, Error> {
+        hash_to_group::<_, SvdWMap<Bls12_377G2Parameters>>(dst, message)
+    }
+}
+
+pub struct Bls<E: BlsHashToCurve> {
+    _engine: PhantomData<E>,
+}
+
+impl<E: BlsHashToCurve> Bls<E> {
+    /// Return true iff `point` is a non-trivial element of the prime order subgroup.
+    fn is_valid_point<G: AffineCurve>(point: &G) -> bool {
+        !AffineCurve::is_zero(point) && point.group_membership_test()
+    }
+
+    /// Return true iff e(g1, signature) = prod_i e(pks[i], message_hashes[i]).
+    fn check_pairing_equation(
+        pks: &[E::G1Affine],
+        message_hashes: &[E::G2Affine],
+        signature: &E::G2Affine,
+    ) -> Result<bool, Error> {
+        let mut pairs = vec![(
+            E::G1Prepared::from(-E::G1Affine::prime_subgroup_generator()),
+            E::G2Prepared::from(*signature),
+        )];
+        pairs.extend(
+            pks.iter()
+                .zip(message_hashes.iter())
+                .map(|(pk, h)| (E::G1Prepared::from(*pk), E::G2Prepared::from(*h))),
+        );
+        Ok(E::product_of_pairings(pairs.iter())?.is_one())
+    }
+
+    /// Aggregate the public keys `pks` into a single public key, which verifies the
+    /// aggregation of the signatures of `pks` on the same message.
+    pub fn aggregate_public_keys(pks: &[E::G1Affine]) -> Result<E::G1Affine, Error> {
+        if pks.is_empty() {
+            return Err(Box::new(CryptoError::Other(
+                "No public key to aggregate".to_owned(),
+            )));
+        }
+        Ok(pks
+            .iter()
+            .fold(
+                <E::G1Projective as ProjectiveCurve>::zero(),
+                |mut acc, pk| {
+                    acc.add_assign_mixed(pk);
+                    acc
+                },
+            )
+            .into_affine())
+    }
+
+    /// Aggregate the signatures `sigs` into a single signature.
+    pub fn aggregate_signatures(sigs: &[E::G2Affine]) -> Result<E::G2Affine, Error> {
+        if sigs.is_empty() {
+            return Err(Box::new(CryptoError::Other(
+                "No signature to aggregate".to_owned(),
+            )));
+        }
+        Ok(sigs
+            .iter()
+            .fold(
+                <E::G2Projective as ProjectiveCurve>::zero(),
+                |mut acc, sig| {
+                    acc.add_assign_mixed(sig);
+                    acc
+                },
+            )
+            .into_affine())
+    }
+
+    /// Verify the aggregated `signature` of `pks` on the same `message`. The public keys
+    /// must come with a valid proof of possession.
+    pub fn fast_aggregate_verify(
+        pks: &[E::G1Affine],
+        message: &[u8],
+        signature: &E::G2Affine,
+    ) -> Result<bool, Error> {
+        if pks.iter().any(|pk| !Self::is_valid_point(pk)) {
+            return Ok(false);
+        }
+        let aggregated_pk = Self::aggregate_public_keys(pks)?;
+        Self::verify(&(), &aggregated_pk, message, signature)
+    }
+
+    /// Verify the aggregated `signature` of `pks[i]` on `messages[i]`, for every i. The public
+    /// keys must come with a valid proof of possession.
+    pub fn aggregate_verify(
+        pks: &[E::G1Affine],
+        messages: &[&[u8]],
+        signature: &E::G2Affine,
+    ) -> Result<bool, Error> {
+        if pks.is_empty() || pks.len() != messages.len() {
+            return Err(Box::new(CryptoError::Other(
+                "Aggregate verification requires the same, non zero, number of public keys and messages".to_owned(),
+            )));
+        }
+
+        let verify_time = start_timer!(|| "Bls::AggregateVerify");
+
+        if pks.iter().any(|pk| !Self::is_valid_point(pk)) || !Self::is_valid_point(signature) {
+            return Ok(false);
+        }
+        let message_hashes = messages
+            .iter()
+            .map(|message| E::hash_to_g2(E::SIG_DST, message))
+            .collect::<Result<Vec<_>, _>>()?;
+        let result = Self::check_pairing_equation(pks, &message_hashes, signature)?;
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    /// Prove the possession of `sk`, by signing the corresponding public key under a
+    /// dedicated domain separation tag.
+    pub fn pop_prove(sk: &E::Fr) -> Result<E::G2Affine, Error> {
+        let pk = E::G1Affine::prime_subgroup_generator()
+            .mul(*sk)
+            .into_affine();
+        let h = E::hash_to_g2(E::POP_DST, &to_bytes!(pk)?)?;
+        Ok(h.mul(*sk).into_affine())
+    }
+
+    /// Verify the proof of possession `pop` of the secret key of `pk`.
+    pub fn pop_verify(pk: &E::G1Affine, pop: &E::G2Affine) -> Result<bool, Error> {
+        if !Self::is_valid_point(pk) || !Self::is_valid_point(pop) {
+            return Ok(false);
+        }
+        let h = E::hash_to_g2(E::POP_DST, &to_bytes!(pk)?)?;
+        Self::check_pairing_equation(&[*pk], &[h], pop)
+    }
+}
+
+impl<E: BlsHashToCurve> SignatureScheme for Bls<E> {
+    type Parameters = ();
+    type PublicKey = E::G1Affine;
+    type SecretKey = E::Fr;
+    type Signature = E::G2Affine;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(())
+    }
+
+    fn keygen<R: Rng>(
+        _parameters: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), Error> {
+        let keygen_time = start_timer!(|| "Bls::KeyGen");
+
+        let secret_key = loop {
+            let secret_key = E::Fr::rand(rng);
+            if !secret_key.is_zero() {
+                break secret_key;
+            }
+        };
+        let public_key = E::G1Affine::prime_subgroup_generator()
+            .mul(secret_key)
+            .into_affine();
+
+        end_timer!(keygen_time);
+        Ok((public_key, secret_key))
+    }
+
+    fn sign<R: Rng>(
+        _parameters: &Self::Parameters,
+        sk: &Self::SecretKey,
+        message: &[u8],
+        _rng: &mut R,
+    ) -> Result<Self::Signature, Error> {
+        let sign_time = start_timer!(|| "Bls::Sign");
+
+        let h = E::hash_to_g2(E::SIG_DST, message)?;
+        let signature = h.mul(*sk).into_affine();
+
+        end_timer!(sign_time);
+        Ok(signature)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<bool, Error> {
+        let verify_time = start_timer!(|| "Bls::Verify");
+
+        if !Self::is_valid_point(pk) || !Self::is_valid_point(signature) {
+            return Ok(false);
+        }
+        let h = E::hash_to_g2(E::SIG_DST, message)?;
+        let result = Self::check_pairing_equation(&[*pk], &[h], signature)?;
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    fn randomize_public_key(
+        _parameters: &Self::Parameters,
+        _public_key: &Self::PublicKey,
+        _randomness: &[u8],
+    ) -> Result<Self::PublicKey, Error> {
+        Err(Box::new(CryptoError::Other(
+            "BLS public keys cannot be randomized".to_owned(),
+        )))
+    }
+
+    fn randomize_signature(
+        _parameters: &Self::Parameters,
+        _signature: &Self::Signature,
+        _randomness: &[u8],
+    ) -> Result<Self::Signature, Error> {
+        Err(Box::new(CryptoError::Other(
+            "BLS signatures cannot be randomized".to_owned(),
+        )))
+    }
+}
+
+#[cfg(all(test, feature = "bls12_377", feature = "bls12_381"))]
+mod test {
+    use super::{Bls, BlsHashToCurve};
+    use crate::SignatureScheme;
+    use algebra::{
+        curves::{bls12_377::Bls12_377, bls12_381::Bls12_381},
+        AffineCurve, ProjectiveCurve,
+    };
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    fn hash_to_curve_test<E: BlsHashToCurve>() {
+        let h1 = E::hash_to_g1(E::SIG_DST, b"Hi, I am a message").unwrap();
+        let h2 = E::hash_to_g2(E::SIG_DST, b"Hi, I am a message").unwrap();
+        assert!(h1.group_membership_test() && !h1.is_zero());
+        assert!(h2.group_membership_test() && !h2.is_zero());
+
+        // Deterministic, and domain separated
+        assert_eq!(
+            h2,
+            E::hash_to_g2(E::SIG_DST, b"Hi, I am a message").unwrap()
+        );
+        assert_ne!(
+            h2,
+            E::hash_to_g2(E::POP_DST, b"Hi, I am a message").unwrap()
+        );
+        assert_ne!(
+            h2,
+            E::hash_to_g2(E::SIG_DST, b"Hi, I am another message").unwrap()
+        );
+    }
+
+    fn bls_test<E: BlsHashToCurve, R: Rng>(rng: &mut R) {
+        let message = "Hi, I am a BLS signature!".as_bytes();
+
+        let (pk, sk) = Bls::<E>::keygen(&(), rng).unwrap();
+        let sig = Bls::<E>::sign(&(), &sk, message, rng).unwrap();
+        assert!(Bls::<E>::verify(&(), &pk, message, &sig).unwrap());
+
+        // Wrong message, public key or signature
+        assert!(!Bls::<E>::verify(&(), &pk, "Bad message".as_bytes(), &sig).unwrap());
+        let (wrong_pk, _) = Bls::<E>::keygen(&(), rng).unwrap();
+        assert!(!Bls::<E>::verify(&(), &wrong_pk, message, &sig).unwrap());
+        let wrong_sig = sig.into_projective().double().into_affine();
+        assert!(!Bls::<E>::verify(&(), &pk, message, &wrong_sig).unwrap());
+        assert!(
+            !Bls::<E>::verify(&(), &E::G1Affine::zero(), message, &E::G2Affine::zero()).unwrap()
+        );
+
+        // Proofs of possession
+        let pop = Bls::<E>::pop_prove(&sk).unwrap();
+        assert!(Bls::<E>::pop_verify(&pk, &pop).unwrap());
+        assert!(!Bls::<E>::pop_verify(&wrong_pk, &pop).unwrap());
+        assert!(!Bls::<E>::pop_verify(&pk, &sig).unwrap());
+    }
+
+    fn bls_aggregation_test<E: BlsHashToCurve, R: Rng>(rng: &mut R) {
+        let message = "Hi, I am a BLS multi-signature!".as_bytes();
+        let messages = [
+            "Hi, I am a BLS signature!".as_bytes(),
+            "Hi, I am another BLS signature!".as_bytes(),
+            "Hi, I am a BLS multi-signature!".as_bytes(),
+        ];
+
+        let keys = (0..3)
+            .map(|_| Bls::<E>::keygen(&(), rng).unwrap())
+            .collect::<Vec<_>>();
+        let pks = keys.iter().map(|(pk, _)| *pk).collect::<Vec<_>>();
+
+        // Same message
+        let sigs = keys
+            .iter()
+            .map(|(_, sk)| Bls::<E>::sign(&(), sk, message, rng).unwrap())
+            .collect::<Vec<_>>();
+        let aggregated_sig = Bls::<E>::aggregate_signatures(&sigs).unwrap();
+        assert!(Bls::<E>::fast_aggregate_verify(&pks, message, &aggregated_sig).unwrap());
+        let aggregated_pk = Bls::<E>::aggregate_public_keys(&pks).unwrap();
+        assert!(Bls::<E>::verify(&(), &aggregated_pk, message, &aggregated_sig).unwrap());
+        assert!(!Bls::<E>::fast_aggregate_verify(&pks[..2], message, &aggregated_sig).unwrap());
+
+        // Different messages
+        let sigs = keys
+            .iter()
+            .zip(messages.iter())
+            .map(|((_, sk), message)| Bls::<E>::sign(&(), sk, message, rng).unwrap())
+            .collect::<Vec<_>>();
+        let aggregated_sig = Bls::<E>::aggregate_signatures(&sigs).unwrap();
+        assert!(Bls::<E>::aggregate_verify(&pks, &messages, &aggregated_sig).unwrap());
+        let mut wrong_messages = messages;
+        wrong_messages.swap(0, 1);
+        assert!(!Bls::<E>::aggregate_verify(&pks, &wrong_messages, &aggregated_sig).unwrap());
+        assert!(Bls::<E>::aggregate_verify(&pks[..2], &messages, &aggregated_sig).is_err());
+
+        // Nothing to aggregate
+        assert!(Bls::<E>::aggregate_public_keys(&[]).is_err());
+        assert!(Bls::<E>::aggregate_signatures(&[]).is_err());
+    }
+
+    #[test]
+    fn bls12_377_bls_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        hash_to_curve_test::<Bls12_377>();
+        bls_test::<Bls12_377, _>(rng);
+        bls_aggregation_test::<Bls12_377, _>(rng);
+    }
+
+    #[test]
+    fn bls12_381_bls_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        hash_to_curve_test::<Bls12_381>();
+        bls_test::<Bls12_381, _>(rng);
+        bls_aggregation_test::<Bls12_381, _>(rng);
+    }
+}
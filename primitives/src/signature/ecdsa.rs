@@ -0,0 +1,241 @@
+//! The [ECDSA](https://www.secg.org/sec1-v2.pdf) signature scheme over a short Weierstrass
+//! curve, using SHA-256 as message digest, e.g. the Bitcoin-style ECDSA over secp256k1.
+use crate::{CryptoError, Error, SignatureScheme};
+use algebra::{
+    curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupProjective},
+    fields::{Field, PrimeField},
+    BitIterator, Group, ProjectiveCurve, UniformRand,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::marker::PhantomData;
+
+pub struct Ecdsa<P: SWModelParameters> {
+    _params: PhantomData<P>,
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: SWModelParameters"),
+    Clone(bound = "P: SWModelParameters"),
+    Default(bound = "P: SWModelParameters"),
+    Debug(bound = "P: SWModelParameters"),
+    PartialEq(bound = "P: SWModelParameters"),
+    Eq(bound = "P: SWModelParameters")
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(bound(serialize = "P: SWModelParameters"))]
+#[serde(bound(deserialize = "P: SWModelParameters"))]
+pub struct EcdsaSig<P: SWModelParameters> {
+    pub r: P::ScalarField,
+    pub s: P::ScalarField,
+}
+
+/// Reduce a big endian sequence of bits modulo the order of the scalar field.
+fn bits_to_scalar<F: PrimeField>(bits: impl Iterator<Item = bool>) -> F {
+    bits.fold(F::zero(), |acc, bit| {
+        let acc = acc.double();
+        if bit {
+            acc + &F::one()
+        } else {
+            acc
+        }
+    })
+}
+
+impl<P: SWModelParameters> Ecdsa<P>
+where
+    P::BaseField: PrimeField,
+{
+    /// The message digest `e`, i.e. the SHA-256 hash of `message` truncated to the bit length
+    /// of the scalar field modulus and reduced modulo the latter.
+    pub fn digest_to_scalar(message: &[u8]) -> P::ScalarField {
+        let digest = Sha256::digest(message);
+        let bits = digest
+            .iter()
+            .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+            .take(P::ScalarField::size_in_bits());
+        bits_to_scalar(bits)
+    }
+
+    /// The x-coordinate of `point` reduced modulo the scalar field modulus.
+    pub fn x_to_scalar(point: &GroupProjective<P>) -> P::ScalarField {
+        let x = point.into_affine().x;
+        bits_to_scalar(BitIterator::new(x.into_repr()))
+    }
+}
+
+impl<P: SWModelParameters> SignatureScheme for Ecdsa<P>
+where
+    P::BaseField: PrimeField,
+{
+    type Parameters = ();
+    type PublicKey = GroupProjective<P>;
+    type SecretKey = P::ScalarField;
+    type Signature = EcdsaSig<P>;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(())
+    }
+
+    fn keygen<R: Rng>(
+        _parameters: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), Error> {
+        let keygen_time = start_timer!(|| "Ecdsa::KeyGen");
+
+        let secret_key = loop {
+            let secret_key = P::ScalarField::rand(rng);
+            if !secret_key.is_zero() {
+                break secret_key;
+            }
+        };
+        let public_key = GroupProjective::<P>::prime_subgroup_generator().mul(&secret_key);
+
+        end_timer!(keygen_time);
+        Ok((public_key, secret_key))
+    }
+
+    /// Sign `message` with a random nonce. The signature is normalized to the "low s" form,
+    /// as required by the Bitcoin standardness rules.
+    fn sign<R: Rng>(
+        _parameters: &Self::Parameters,
+        sk: &Self::SecretKey,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self::Signature, Error> {
+        let sign_time = start_timer!(|| "Ecdsa::Sign");
+
+        let e = Self::digest_to_scalar(message);
+        let (r, s) = loop {
+            // Sample the nonce k and compute r := x(k * G) mod n
+            let k = P::ScalarField::rand(rng);
+            if k.is_zero() {
+                continue;
+            }
+            let r = Self::x_to_scalar(&GroupProjective::<P>::prime_subgroup_generator().mul(&k));
+            if r.is_zero() {
+                continue;
+            }
+
+            // s := k^-1 * (e + r * sk) mod n
+            let s = k.inverse().unwrap() * &(e + &(r * sk));
+            if !s.is_zero() {
+                break (r, s);
+            }
+        };
+
+        let s = if s.into_repr() > P::ScalarField::modulus_minus_one_div_two() {
+            -s
+        } else {
+            s
+        };
+
+        end_timer!(sign_time);
+        Ok(EcdsaSig { r, s })
+    }
+
+    /// Verify `signature` on `message`. Both the "low s" and the "high s" forms are accepted.
+    fn verify(
+        _parameters: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<bool, Error> {
+        let verify_time = start_timer!(|| "Ecdsa::Verify");
+
+        let EcdsaSig { r, s } = signature;
+        let pk_affine = pk.into_affine();
+        if r.is_zero()
+            || s.is_zero()
+            || ProjectiveCurve::is_zero(pk)
+            || !pk_affine.is_on_curve()
+            || !pk_affine.is_in_correct_subgroup_assuming_on_curve()
+        {
+            return Ok(false);
+        }
+
+        // R := (e * s^-1) * G + (r * s^-1) * pk
+        let e = Self::digest_to_scalar(message);
+        let w = s.inverse().unwrap();
+        let claimed_r =
+            GroupProjective::<P>::prime_subgroup_generator().mul(&(e * &w)) + &pk.mul(&(*r * &w));
+
+        let result = !ProjectiveCurve::is_zero(&claimed_r) && Self::x_to_scalar(&claimed_r) == *r;
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    fn randomize_public_key(
+        _parameters: &Self::Parameters,
+        _public_key: &Self::PublicKey,
+        _randomness: &[u8],
+    ) -> Result<Self::PublicKey, Error> {
+        Err(Box::new(CryptoError::Other(
+            "ECDSA public keys cannot be randomized".to_owned(),
+        )))
+    }
+
+    fn randomize_signature(
+        _parameters: &Self::Parameters,
+        _signature: &Self::Signature,
+        _randomness: &[u8],
+    ) -> Result<Self::Signature, Error> {
+        Err(Box::new(CryptoError::Other(
+            "ECDSA signatures cannot be randomized".to_owned(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Ecdsa, EcdsaSig};
+    use crate::SignatureScheme;
+    use algebra::{
+        curves::secp256k1::{Projective, Secp256k1Parameters},
+        fields::{secp256k1::Fr, Field, PrimeField},
+        ProjectiveCurve,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    type EcdsaSecp256k1 = Ecdsa<Secp256k1Parameters>;
+
+    #[test]
+    fn ecdsa_secp256k1_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an ECDSA signature!".as_bytes();
+
+        for _ in 0..10 {
+            let (pk, sk) = EcdsaSecp256k1::keygen(&(), rng).unwrap();
+            let sig = EcdsaSecp256k1::sign(&(), &sk, message, rng).unwrap();
+
+            // Signatures are in "low s" form, but "high s" ones are valid too
+            assert!(sig.s.into_repr() <= Fr::modulus_minus_one_div_two());
+            assert!(EcdsaSecp256k1::verify(&(), &pk, message, &sig).unwrap());
+            let high_s_sig = EcdsaSig {
+                r: sig.r,
+                s: -sig.s,
+            };
+            assert!(EcdsaSecp256k1::verify(&(), &pk, message, &high_s_sig).unwrap());
+
+            // Wrong message, public key or signature
+            assert!(!EcdsaSecp256k1::verify(&(), &pk, "Bad message".as_bytes(), &sig).unwrap());
+            let wrong_pk = pk.double();
+            assert!(!EcdsaSecp256k1::verify(&(), &wrong_pk, message, &sig).unwrap());
+            let wrong_sig = EcdsaSig {
+                r: sig.r,
+                s: sig.s.double(),
+            };
+            assert!(!EcdsaSecp256k1::verify(&(), &pk, message, &wrong_sig).unwrap());
+            let zero_sig = EcdsaSig {
+                r: sig.r,
+                s: Fr::zero(),
+            };
+            assert!(!EcdsaSecp256k1::verify(&(), &pk, message, &zero_sig).unwrap());
+            assert!(!EcdsaSecp256k1::verify(&(), &Projective::zero(), message, &sig).unwrap());
+        }
+    }
+}
@@ -0,0 +1,448 @@
+//! The [Ed25519](https://datatracker.ietf.org/doc/html/rfc8032#section-5.1) signature scheme,
+//! i.e. PureEdDSA over edwards25519 with SHA-512 as hash function.
+//! Verification is cofactored, i.e. it checks `[8][S]B = [8]R + [8][k]A`, as recommended by
+//! RFC 8032 for consistency with batch verification. Public keys are required to be
+//! non-trivial elements of the prime order subgroup (as the ones derived by `keygen`).
+use crate::{CryptoError, Error, SignatureScheme};
+use algebra::{
+    curves::ed25519::{Ed25519Parameters, TEEd25519Affine, TEEd25519Projective},
+    fields::{
+        ed25519::{fq::Fq, fr::Fr},
+        Field, FpParameters, PrimeField, SquareRootField,
+    },
+    AffineCurve, BigInteger, FromBytes, Group, ProjectiveCurve, TEModelParameters, ToBytes,
+    UniformRand,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+
+pub struct Ed25519;
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = ""),
+    Clone(bound = ""),
+    Default(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+#[derive(Serialize, Deserialize)]
+pub struct Ed25519Sig {
+    pub r: TEEd25519Affine,
+    pub s: Fr,
+}
+
+impl Ed25519 {
+    /// Expand the 32 bytes secret key `seed` into the secret scalar `s` and the `prefix` used
+    /// for the deterministic derivation of the signing nonces.
+    pub fn expand_secret_key(seed: &[u8; 32]) -> (Fr, [u8; 32]) {
+        let h = Sha512::digest(seed);
+
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&h[..32]);
+        s_bytes[0] &= 248;
+        s_bytes[31] &= 127;
+        s_bytes[31] |= 64;
+
+        let mut prefix = [0u8; 32];
+        prefix.copy_from_slice(&h[32..]);
+
+        (Fr::from_le_bytes_mod_order(&s_bytes), prefix)
+    }
+
+    /// Derive the public key `A = [s]B` from the 32 bytes secret key `seed`.
+    pub fn public_key_from_secret_key(seed: &[u8; 32]) -> TEEd25519Affine {
+        let (s, _) = Self::expand_secret_key(seed);
+        TEEd25519Projective::prime_subgroup_generator()
+            .mul(&s)
+            .into_affine()
+    }
+
+    /// Encode `point` as the little endian encoding of its y-coordinate, with the most
+    /// significant bit set to the least significant bit of its x-coordinate.
+    pub fn encode_point(point: &TEEd25519Affine) -> [u8; 32] {
+        let mut encoding = [0u8; 32];
+        point
+            .y
+            .into_repr()
+            .write(&mut encoding[..])
+            .expect("32 bytes are enough to encode an element of Fq");
+        if point.x.into_repr().is_odd() {
+            encoding[31] |= 0x80;
+        }
+        encoding
+    }
+
+    /// Decode a point from its encoding, returning None if `encoding` is not a canonical
+    /// encoding of a point of the curve. The point is not guaranteed to be in the prime
+    /// order subgroup.
+    pub fn decode_point(encoding: &[u8; 32]) -> Option<TEEd25519Affine> {
+        let x_is_odd = encoding[31] >> 7 == 1;
+        let mut y_bytes = *encoding;
+        y_bytes[31] &= 0x7f;
+
+        let y_repr = <Fq as PrimeField>::BigInt::read(&y_bytes[..]).ok()?;
+        if y_repr >= <Fq as PrimeField>::Params::MODULUS {
+            return None;
+        }
+        let y = Fq::from_repr(y_repr);
+
+        // x^2 = (y^2 - 1)/(d * y^2 - a), with a = -1
+        let y2 = y.square();
+        let x2 = (y2 - &Fq::one()) * &(Ed25519Parameters::COEFF_D * &y2 + &Fq::one()).inverse()?;
+        let x = x2.sqrt()?;
+        if x.is_zero() && x_is_odd {
+            return None;
+        }
+        let x = if x.into_repr().is_odd() == x_is_odd {
+            x
+        } else {
+            -x
+        };
+
+        Some(TEEd25519Affine::new(x, y))
+    }
+
+    /// Return true iff `pk` is a non-trivial element of the prime order subgroup: public keys
+    /// with a small order component are rejected by the verification.
+    pub fn is_valid_public_key(pk: &TEEd25519Affine) -> bool {
+        pk.is_on_curve()
+            && !AffineCurve::is_zero(pk)
+            && pk.is_in_correct_subgroup_assuming_on_curve()
+    }
+
+    /// Serialize `signature` into its 64 bytes encoding `(R, S)`.
+    pub fn signature_to_bytes(signature: &Ed25519Sig) -> Vec<u8> {
+        let mut bytes = Self::encode_point(&signature.r).to_vec();
+        signature
+            .s
+            .into_repr()
+            .write(&mut bytes)
+            .expect("writing to a Vec never fails");
+        bytes
+    }
+
+    /// Deserialize a signature from its 64 bytes encoding `(R, S)`, returning None if R is
+    /// not a valid point encoding or if S is not reduced modulo the group order.
+    pub fn signature_from_bytes(bytes: &[u8]) -> Option<Ed25519Sig> {
+        if bytes.len() != 64 {
+            return None;
+        }
+        let mut r_encoding = [0u8; 32];
+        r_encoding.copy_from_slice(&bytes[..32]);
+        let r = Self::decode_point(&r_encoding)?;
+
+        let s_repr = <Fr as PrimeField>::BigInt::read(&bytes[32..]).ok()?;
+        if s_repr >= <Fr as PrimeField>::Params::MODULUS {
+            return None;
+        }
+
+        Some(Ed25519Sig {
+            r,
+            s: Fr::from_repr(s_repr),
+        })
+    }
+
+    /// The challenge `k = SHA-512(R || A || message) mod L`.
+    pub fn challenge(r: &TEEd25519Affine, pk: &TEEd25519Affine, message: &[u8]) -> Fr {
+        let h = Sha512::new()
+            .chain(Self::encode_point(r))
+            .chain(Self::encode_point(pk))
+            .chain(message)
+            .finalize();
+        Fr::from_le_bytes_mod_order(&h)
+    }
+
+    /// Verify a batch of signatures at once, by checking a random linear combination of
+    /// the single verification equations. Returns `true` iff all signatures are valid.
+    pub fn batch_verify<R: Rng>(
+        pks: &[TEEd25519Affine],
+        messages: &[&[u8]],
+        signatures: &[Ed25519Sig],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        if pks.len() != messages.len() || pks.len() != signatures.len() {
+            return Err(Box::new(CryptoError::Other(
+                "Batch verification requires the same number of public keys, messages and signatures".to_owned(),
+            )));
+        }
+
+        let batch_verify_time = start_timer!(|| "Ed25519::BatchVerify");
+
+        if pks.iter().any(|pk| !Self::is_valid_public_key(pk))
+            || signatures.iter().any(|sig| !sig.r.is_on_curve())
+        {
+            return Ok(false);
+        }
+
+        // [8] ( -[sum z_i * S_i]B + sum [z_i]R_i + sum [z_i * k_i]A_i ) = 0
+        let mut s_combination = Fr::zero();
+        let mut check = <TEEd25519Projective as ProjectiveCurve>::zero();
+        for ((pk, message), sig) in pks.iter().zip(messages.iter()).zip(signatures.iter()) {
+            let z = Fr::rand(rng);
+            let k = Self::challenge(&sig.r, pk, message);
+            s_combination += &(z * &sig.s);
+            check += &(sig.r.into_projective().mul(&z) + &pk.into_projective().mul(&(z * &k)));
+        }
+        check -= &TEEd25519Projective::prime_subgroup_generator().mul(&s_combination);
+        let result = ProjectiveCurve::is_zero(&check.into_affine().scale_by_cofactor());
+
+        end_timer!(batch_verify_time);
+        Ok(result)
+    }
+}
+
+impl SignatureScheme for Ed25519 {
+    type Parameters = ();
+    type PublicKey = TEEd25519Affine;
+    type SecretKey = [u8; 32];
+    type Signature = Ed25519Sig;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(())
+    }
+
+    fn keygen<R: Rng>(
+        _parameters: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), Error> {
+        let keygen_time = start_timer!(|| "Ed25519::KeyGen");
+
+        let secret_key: [u8; 32] = rng.gen();
+        let public_key = Self::public_key_from_secret_key(&secret_key);
+
+        end_timer!(keygen_time);
+        Ok((public_key, secret_key))
+    }
+
+    /// Sign `message` deterministically, as specified by RFC 8032 (the rng is not used).
+    fn sign<R: Rng>(
+        _parameters: &Self::Parameters,
+        sk: &Self::SecretKey,
+        message: &[u8],
+        _rng: &mut R,
+    ) -> Result<Self::Signature, Error> {
+        let sign_time = start_timer!(|| "Ed25519::Sign");
+
+        let (s, prefix) = Self::expand_secret_key(sk);
+        let generator = TEEd25519Projective::prime_subgroup_generator();
+        let pk = generator.mul(&s).into_affine();
+
+        // r := SHA-512(prefix || message) mod L, R := [r]B
+        let r = Fr::from_le_bytes_mod_order(&Sha512::new().chain(prefix).chain(message).finalize());
+        let r_point = generator.mul(&r).into_affine();
+
+        // S := r + k * s mod L
+        let k = Self::challenge(&r_point, &pk, message);
+        let signature = Ed25519Sig {
+            r: r_point,
+            s: r + &(k * &s),
+        };
+
+        end_timer!(sign_time);
+        Ok(signature)
+    }
+
+    fn verify(
+        _parameters: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &[u8],
+        signature: &Self::Signature,
+    ) -> Result<bool, Error> {
+        let verify_time = start_timer!(|| "Ed25519::Verify");
+
+        if !Self::is_valid_public_key(pk) || !signature.r.is_on_curve() {
+            return Ok(false);
+        }
+
+        // [8]([S]B - R - [k]A) = 0
+        let k = Self::challenge(&signature.r, pk, message);
+        let check = TEEd25519Projective::prime_subgroup_generator().mul(&signature.s)
+            - &signature.r.into_projective()
+            - &pk.into_projective().mul(&k);
+        let result = ProjectiveCurve::is_zero(&check.into_affine().scale_by_cofactor());
+
+        end_timer!(verify_time);
+        Ok(result)
+    }
+
+    fn randomize_public_key(
+        _parameters: &Self::Parameters,
+        _public_key: &Self::PublicKey,
+        _randomness: &[u8],
+    ) -> Result<Self::PublicKey, Error> {
+        Err(Box::new(CryptoError::Other(
+            "Ed25519 public keys cannot be randomized".to_owned(),
+        )))
+    }
+
+    fn randomize_signature(
+        _parameters: &Self::Parameters,
+        _signature: &Self::Signature,
+        _randomness: &[u8],
+    ) -> Result<Self::Signature, Error> {
+        Err(Box::new(CryptoError::Other(
+            "Ed25519 signatures cannot be randomized".to_owned(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Ed25519, Ed25519Sig};
+    use crate::SignatureScheme;
+    use algebra::{
+        curves::ed25519::{TEEd25519Affine, TEEd25519Projective},
+        fields::ed25519::fr::Fr,
+        AffineCurve, Field, FpParameters, PrimeField, ProjectiveCurve, UniformRand,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    // From https://datatracker.ietf.org/doc/html/rfc8032#section-7.1
+    const RFC8032_TEST_VECTORS: [(&str, &str, &str, &str); 3] = [
+        (
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        ),
+        (
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ),
+        (
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        ),
+    ];
+
+    #[test]
+    fn ed25519_test_vectors() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+
+        for (sk, pk, message, sig) in RFC8032_TEST_VECTORS.iter() {
+            let mut seed = [0u8; 32];
+            seed.copy_from_slice(&hex::decode(sk).unwrap());
+            let message = hex::decode(message).unwrap();
+
+            let public_key = Ed25519::public_key_from_secret_key(&seed);
+            assert_eq!(hex::encode(Ed25519::encode_point(&public_key)), *pk);
+
+            let signature = Ed25519::sign(&(), &seed, &message, rng).unwrap();
+            assert_eq!(hex::encode(Ed25519::signature_to_bytes(&signature)), *sig);
+
+            let mut pk_encoding = [0u8; 32];
+            pk_encoding.copy_from_slice(&hex::decode(pk).unwrap());
+            let decoded_pk = Ed25519::decode_point(&pk_encoding).unwrap();
+            let decoded_sig = Ed25519::signature_from_bytes(&hex::decode(sig).unwrap()).unwrap();
+            assert_eq!(decoded_pk, public_key);
+            assert_eq!(decoded_sig, signature);
+            assert!(Ed25519::verify(&(), &decoded_pk, &message, &decoded_sig).unwrap());
+        }
+    }
+
+    #[test]
+    fn ed25519_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an Ed25519 signature!".as_bytes();
+
+        for _ in 0..10 {
+            let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+            let sig = Ed25519::sign(&(), &sk, message, rng).unwrap();
+            assert!(Ed25519::verify(&(), &pk, message, &sig).unwrap());
+
+            // Wrong message, public key or signature
+            assert!(!Ed25519::verify(&(), &pk, "Bad message".as_bytes(), &sig).unwrap());
+            let wrong_pk = pk.into_projective().double().into_affine();
+            assert!(!Ed25519::verify(&(), &wrong_pk, message, &sig).unwrap());
+            let wrong_sig = Ed25519Sig {
+                r: sig.r,
+                s: sig.s.double(),
+            };
+            assert!(!Ed25519::verify(&(), &pk, message, &wrong_sig).unwrap());
+            let invalid_pk = TEEd25519Affine::new(pk.x.double(), pk.y);
+            assert!(!Ed25519::verify(&(), &invalid_pk, message, &sig).unwrap());
+
+            // Encoding round trip
+            let encoded_sig = Ed25519::signature_to_bytes(&sig);
+            assert_eq!(Ed25519::signature_from_bytes(&encoded_sig).unwrap(), sig);
+            assert_eq!(
+                Ed25519::decode_point(&Ed25519::encode_point(&pk)).unwrap(),
+                pk
+            );
+        }
+
+        // Public keys with a small order component, or trivial, are rejected, even if
+        // the cofactored verification equation holds for them
+        let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+        let torsion = loop {
+            let point = TEEd25519Projective::rand(rng).into_affine();
+            let torsion = point.mul(<Fr as PrimeField>::Params::MODULUS);
+            if !torsion.is_zero() {
+                break torsion;
+            }
+        };
+        for wrong_pk in [
+            (pk.into_projective() + &torsion).into_affine(),
+            TEEd25519Affine::zero(),
+        ]
+        .iter()
+        {
+            // S := r + k * s, with k computed on the wrong public key
+            let (s, _) = Ed25519::expand_secret_key(&sk);
+            let r = Fr::rand(rng);
+            let r_point = TEEd25519Affine::prime_subgroup_generator()
+                .mul(r)
+                .into_affine();
+            let k = Ed25519::challenge(&r_point, wrong_pk, message);
+            let sig = Ed25519Sig {
+                r: r_point,
+                s: r + &(k * &s),
+            };
+            assert!(!Ed25519::is_valid_public_key(wrong_pk));
+            assert!(!Ed25519::verify(&(), wrong_pk, message, &sig).unwrap());
+            assert!(!Ed25519::batch_verify(&[*wrong_pk], &[message], &[sig], rng).unwrap());
+        }
+
+        // Non canonical S is rejected
+        let (_, sk) = Ed25519::keygen(&(), rng).unwrap();
+        let sig = Ed25519::sign(&(), &sk, message, rng).unwrap();
+        let mut encoded_sig = Ed25519::signature_to_bytes(&sig);
+        encoded_sig[63] |= 0xf0;
+        assert!(Ed25519::signature_from_bytes(&encoded_sig).is_none());
+    }
+
+    #[test]
+    fn ed25519_batch_verification_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let messages = [
+            "Hi, I am an Ed25519 signature!".as_bytes(),
+            "Hi, I am another Ed25519 signature!".as_bytes(),
+            "".as_bytes(),
+            "Hi, I am a batch of Ed25519 signatures!".as_bytes(),
+        ];
+
+        let mut pks = vec![];
+        let mut sigs = vec![];
+        for message in messages.iter() {
+            let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+            sigs.push(Ed25519::sign(&(), &sk, message, rng).unwrap());
+            pks.push(pk);
+        }
+        assert!(Ed25519::batch_verify(&pks, &messages, &sigs, rng).unwrap());
+
+        // A single invalid signature makes the whole batch fail
+        sigs.swap(0, 1);
+        assert!(!Ed25519::batch_verify(&pks, &messages, &sigs, rng).unwrap());
+
+        // Inputs of different length are an error
+        assert!(Ed25519::batch_verify(&pks[..3], &messages, &sigs, rng).is_err());
+    }
+}
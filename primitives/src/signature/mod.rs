@@ -8,6 +8,10 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::hash::Hash;
 
+pub mod bls;
+pub mod ecdsa;
+#[cfg(feature = "ed25519")]
+pub mod ed25519;
 pub mod schnorr;
 
 pub trait SignatureScheme {
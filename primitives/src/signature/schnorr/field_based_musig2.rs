@@ -0,0 +1,556 @@
+//! MuSig2 multi-signatures (Nick, Ruffing, Seurin, https://eprint.iacr.org/2020/1261) for the
+//! `FieldBasedSchnorrSignatureScheme`: `n` signers aggregate their public keys into a single
+//! `FieldBasedSchnorrPk` and jointly produce, in two rounds, a `FieldBasedSchnorrSignature`
+//! which is valid under the aggregated key, and hence verifiable by the native
+//! `FieldBasedSchnorrSignatureScheme::verify()` and by its verification gadget.
+//!
+//! Signing goes as follows:
+//! - every signer runs `nonce_gen()` and broadcasts its `MuSig2PublicNonce` (this round does
+//!   not depend on the message, and can be run in advance);
+//! - once all public nonces are known, anyone aggregates them by `nonce_aggregation()` and
+//!   the session is fixed by `session()`, which binds the aggregated key, the aggregated
+//!   nonce and the message;
+//! - every signer runs `partial_sign()`, consuming its `MuSig2SecretNonce`, and broadcasts
+//!   its partial signature, which can be checked by `partial_verify()`;
+//! - anyone aggregates the partial signatures into the final signature by
+//!   `partial_sig_aggregation()`.
+//!
+//! A `MuSig2SecretNonce` must never be used for more than one partial signature: doing so
+//! leaks the secret key.
+use crate::{
+    compute_truncation_size,
+    crh::FieldBasedHash,
+    signature::schnorr::field_based_schnorr::{
+        FieldBasedSchnorrPk, FieldBasedSchnorrSignature, FieldBasedSchnorrSignatureScheme,
+    },
+    signature::FieldBasedSignatureScheme,
+    CryptoError, Error,
+};
+use algebra::{
+    convert, leading_zeros, serialize::*, Field, Group, PrimeField, ProjectiveCurve, ToBits,
+    ToConstraintField, UniformRand,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// Personalization of the hash of the list of public keys.
+const KEY_AGG_LIST_TAG: &[u8] = b"MuSig2-KeyAgg-List";
+
+/// Personalization of the hash computing the key aggregation coefficients.
+const KEY_AGG_COEFF_TAG: &[u8] = b"MuSig2-KeyAgg-Coefficient";
+
+/// Personalization of the hash computing the nonce coefficient.
+const NONCE_COEFF_TAG: &[u8] = b"MuSig2-Nonce-Coefficient";
+
+/// The public keys of the signers, with their aggregation coefficients and the resulting
+/// aggregated public key.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "G: ProjectiveCurve"),
+    Debug(bound = "G: ProjectiveCurve"),
+    PartialEq(bound = "G: ProjectiveCurve"),
+    Eq(bound = "G: ProjectiveCurve")
+)]
+pub struct MuSig2KeyAggContext<G: ProjectiveCurve> {
+    pks: Vec<FieldBasedSchnorrPk<G>>,
+    coefficients: Vec<G::ScalarField>,
+    aggregated_pk: FieldBasedSchnorrPk<G>,
+}
+
+impl<G: ProjectiveCurve> MuSig2KeyAggContext<G> {
+    /// The public keys of the signers, in the order given to `key_aggregation()`.
+    pub fn public_keys(&self) -> &[FieldBasedSchnorrPk<G>] {
+        &self.pks
+    }
+
+    /// The aggregated public key, under which the final signature is valid.
+    pub fn aggregated_pk(&self) -> &FieldBasedSchnorrPk<G> {
+        &self.aggregated_pk
+    }
+
+    /// The sum of the aggregation coefficients of `pk`, or `None` if `pk` is not a
+    /// signer (the same key may appear more than once).
+    fn coefficient(&self, pk: &FieldBasedSchnorrPk<G>) -> Option<G::ScalarField> {
+        self.pks
+            .iter()
+            .zip(self.coefficients.iter())
+            .filter(|(other, _)| *other == pk)
+            .map(|(_, a)| *a)
+            .fold(None, |acc, a| {
+                Some(acc.unwrap_or_else(G::ScalarField::zero) + &a)
+            })
+    }
+}
+
+/// The secret nonces of a signer for a signing session. Deliberately neither `Copy` nor
+/// `Clone`, as it is consumed by `partial_sign()` and must not be reused.
+pub struct MuSig2SecretNonce<G: ProjectiveCurve> {
+    r_1: G::ScalarField,
+    r_2: G::ScalarField,
+}
+
+/// The public nonces of a signer for a signing session, `R_1 = r_1 * G` and
+/// `R_2 = r_2 * G`, also used to represent the aggregated nonce.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "G: ProjectiveCurve"),
+    Clone(bound = "G: ProjectiveCurve"),
+    Default(bound = "G: ProjectiveCurve"),
+    Debug(bound = "G: ProjectiveCurve"),
+    PartialEq(bound = "G: ProjectiveCurve"),
+    Eq(bound = "G: ProjectiveCurve")
+)]
+#[derive(Serialize, Deserialize)]
+#[serde(bound(serialize = "G: ProjectiveCurve"))]
+#[serde(bound(deserialize = "G: ProjectiveCurve"))]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct MuSig2PublicNonce<G: ProjectiveCurve> {
+    pub r_1: G,
+    pub r_2: G,
+}
+
+/// The values shared by all the signers of a session: the nonce coefficient `b`, the
+/// challenge `e`, and the signature nonce `R = R_1 + b * R_2`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "F: PrimeField, G: ProjectiveCurve"),
+    Debug(bound = "F: PrimeField, G: ProjectiveCurve")
+)]
+pub struct MuSig2Session<F: PrimeField, G: ProjectiveCurve> {
+    pub b: G::ScalarField,
+    pub e: F,
+    pub r: G,
+    e_conv: G::ScalarField,
+}
+
+/// MuSig2 multi-signatures producing `FieldBasedSchnorrSignature`s, with `H` used both for the
+/// Schnorr challenge and for the key aggregation and nonce coefficients.
+pub struct FieldBasedSchnorrMuSig2<F: PrimeField, G: ProjectiveCurve, H: FieldBasedHash> {
+    _field: PhantomData<F>,
+    _group: PhantomData<G>,
+    _hash: PhantomData<H>,
+}
+
+impl<F, G, H> FieldBasedSchnorrMuSig2<F, G, H>
+where
+    F: PrimeField,
+    G: ProjectiveCurve + ToConstraintField<F>,
+    H: FieldBasedHash<Data = F>,
+{
+    /// Hash `inputs` with variable length, personalized by `tag`.
+    fn hash(tag: &[u8], inputs: &[F]) -> Result<F, Error> {
+        let personalization = [F::from_be_bytes_mod_order(tag)];
+        let mut digest = H::init_variable_length(false, Some(&personalization));
+        inputs.iter().for_each(|input| {
+            digest.update(*input);
+        });
+        digest.finalize()
+    }
+
+    /// Convert `f` to a scalar, dropping the leading bits as needed to fit it.
+    fn truncate_to_scalar(f: F) -> Result<G::ScalarField, Error> {
+        let to_skip = compute_truncation_size(
+            F::size_in_bits() as i32,
+            G::ScalarField::size_in_bits() as i32,
+        );
+        convert::<G::ScalarField>(f.write_bits()[to_skip..].to_vec())
+    }
+
+    /// Aggregate the public keys `pks` of the signers, which are required to be valid. The
+    /// coefficient of each key is derived from the hash of the whole list, preventing
+    /// rogue key attacks, except for the second distinct key in the list whose coefficient
+    /// is one (an optimization of MuSig2).
+    pub fn key_aggregation(
+        pks: &[FieldBasedSchnorrPk<G>],
+    ) -> Result<MuSig2KeyAggContext<G>, Error> {
+        if pks.is_empty() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "public keys".to_owned(),
+                0,
+            )));
+        }
+        let mut list = Vec::new();
+        for (i, pk) in pks.iter().enumerate() {
+            if !FieldBasedSchnorrSignatureScheme::<F, G, H>::keyverify(pk) {
+                return Err(Box::new(CryptoError::InvalidElement(format!(
+                    "public key {}",
+                    i
+                ))));
+            }
+            list.extend(pk.0.to_field_elements()?);
+        }
+        let list_hash = Self::hash(KEY_AGG_LIST_TAG, &list)?;
+
+        let second_pk = pks.iter().find(|pk| **pk != pks[0]);
+        let mut coefficients = Vec::with_capacity(pks.len());
+        let mut aggregated_pk = G::zero();
+        for pk in pks.iter() {
+            let a = if Some(pk) == second_pk {
+                G::ScalarField::one()
+            } else {
+                let mut inputs = vec![list_hash];
+                inputs.extend(pk.0.to_field_elements()?);
+                Self::truncate_to_scalar(Self::hash(KEY_AGG_COEFF_TAG, &inputs)?)?
+            };
+            aggregated_pk += &pk.0.mul(&a);
+            coefficients.push(a);
+        }
+
+        // The aggregated key is the sum of keys with pseudorandom coefficients, hence it
+        // is zero only with negligible probability, but we keep the keyverify() invariant.
+        let aggregated_pk = FieldBasedSchnorrPk(aggregated_pk);
+        if !FieldBasedSchnorrSignatureScheme::<F, G, H>::keyverify(&aggregated_pk) {
+            return Err(Box::new(CryptoError::InvalidElement(
+                "aggregated public key".to_owned(),
+            )));
+        }
+
+        Ok(MuSig2KeyAggContext {
+            pks: pks.to_vec(),
+            coefficients,
+            aggregated_pk,
+        })
+    }
+
+    /// First round: sample the secret nonces of a signer, and the public ones to broadcast.
+    pub fn nonce_gen<R: Rng>(rng: &mut R) -> (MuSig2SecretNonce<G>, MuSig2PublicNonce<G>) {
+        let sample_non_zero = |rng: &mut R| loop {
+            let r = G::ScalarField::rand(rng);
+            if !r.is_zero() {
+                break r;
+            }
+        };
+        let r_1 = sample_non_zero(rng);
+        let r_2 = sample_non_zero(rng);
+        let generator = G::prime_subgroup_generator();
+        (
+            MuSig2SecretNonce { r_1, r_2 },
+            MuSig2PublicNonce {
+                r_1: generator.mul(&r_1),
+                r_2: generator.mul(&r_2),
+            },
+        )
+    }
+
+    /// Aggregate the public nonces of all the signers.
+    pub fn nonce_aggregation(
+        public_nonces: &[MuSig2PublicNonce<G>],
+    ) -> Result<MuSig2PublicNonce<G>, Error> {
+        if public_nonces.is_empty() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "public nonces".to_owned(),
+                0,
+            )));
+        }
+        Ok(public_nonces
+            .iter()
+            .fold(MuSig2PublicNonce::default(), |acc, nonce| {
+                MuSig2PublicNonce {
+                    r_1: acc.r_1 + &nonce.r_1,
+                    r_2: acc.r_2 + &nonce.r_2,
+                }
+            }))
+    }
+
+    /// Compute the session values for signing `message` under `key_agg_ctx` with the
+    /// aggregated nonce `aggregated_nonce`. The challenge `e` must be short enough to be
+    /// converted into a scalar: as the signers cannot resample their nonces without
+    /// another round, the nonce coefficient `b` is derived from a counter as well, which is
+    /// incremented until the resulting `e` has the proper bit length.
+    pub fn session(
+        key_agg_ctx: &MuSig2KeyAggContext<G>,
+        aggregated_nonce: &MuSig2PublicNonce<G>,
+        message: F,
+    ) -> Result<MuSig2Session<F, G>, Error> {
+        let required_leading_zeros_e = compute_truncation_size(
+            F::size_in_bits() as i32,
+            G::ScalarField::size_in_bits() as i32,
+        );
+        let apk_coords = key_agg_ctx.aggregated_pk.0.to_field_elements()?;
+
+        let mut b_inputs = vec![apk_coords[0]];
+        b_inputs.extend(aggregated_nonce.r_1.to_field_elements()?);
+        b_inputs.extend(aggregated_nonce.r_2.to_field_elements()?);
+        b_inputs.push(message);
+
+        let mut counter = 0u64;
+        loop {
+            let mut inputs = b_inputs.clone();
+            inputs.push(F::from(counter));
+            let b = Self::truncate_to_scalar(Self::hash(NONCE_COEFF_TAG, &inputs)?)?;
+
+            // R = R_1 + b * R_2
+            let r = aggregated_nonce.r_1 + &aggregated_nonce.r_2.mul(&b);
+            let r_coords = r.to_field_elements()?;
+
+            // e = H(m || R || apk.x), as in FieldBasedSchnorrSignatureScheme
+            let e = {
+                let mut digest = H::init_constant_length(4, None);
+                digest.update(message);
+                r_coords.into_iter().for_each(|coord| {
+                    digest.update(coord);
+                });
+                digest.update(apk_coords[0]);
+                digest.finalize()
+            }?;
+
+            let e_bits = e.write_bits();
+            if (leading_zeros(e_bits.as_slice()) as usize) >= required_leading_zeros_e {
+                let e_conv = convert::<G::ScalarField>(e_bits)?;
+                return Ok(MuSig2Session { b, e, r, e_conv });
+            }
+            counter += 1;
+        }
+    }
+
+    /// Second round: compute the partial signature `s_i = r_1 + b * r_2 + e * a_i * sk` of
+    /// the signer owning `sk`, consuming its secret nonce.
+    pub fn partial_sign(
+        key_agg_ctx: &MuSig2KeyAggContext<G>,
+        session: &MuSig2Session<F, G>,
+        sk: &G::ScalarField,
+        secret_nonce: MuSig2SecretNonce<G>,
+    ) -> Result<G::ScalarField, Error> {
+        let pk = FieldBasedSchnorrSignatureScheme::<F, G, H>::get_public_key(sk);
+        let a = key_agg_ctx.coefficient(&pk).ok_or_else(|| {
+            Box::new(CryptoError::Other(
+                "the secret key does not belong to any signer".to_owned(),
+            ))
+        })?;
+        Ok(secret_nonce.r_1 + &(session.b * &secret_nonce.r_2) + &(session.e_conv * &a * sk))
+    }
+
+    /// Check the partial signature `partial_sig` of the signer `pk` with public nonce
+    /// `public_nonce`, i.e. that `s_i * G = R_1 + b * R_2 + e * a_i * pk`.
+    pub fn partial_verify(
+        key_agg_ctx: &MuSig2KeyAggContext<G>,
+        session: &MuSig2Session<F, G>,
+        pk: &FieldBasedSchnorrPk<G>,
+        public_nonce: &MuSig2PublicNonce<G>,
+        partial_sig: &G::ScalarField,
+    ) -> Result<bool, Error> {
+        let a = match key_agg_ctx.coefficient(pk) {
+            Some(a) => a,
+            None => return Ok(false),
+        };
+        let lhs = G::prime_subgroup_generator().mul(partial_sig);
+        let rhs = public_nonce.r_1
+            + &public_nonce.r_2.mul(&session.b)
+            + &pk.0.mul(&(session.e_conv * &a));
+        Ok(lhs == rhs)
+    }
+
+    /// Aggregate the partial signatures of all the signers into a signature valid under
+    /// the aggregated public key. Fails if the aggregated `s` is too long to be converted
+    /// into an element of `F`, in which case signing must be restarted with fresh nonces.
+    pub fn partial_sig_aggregation(
+        session: &MuSig2Session<F, G>,
+        partial_sigs: &[G::ScalarField],
+    ) -> Result<FieldBasedSchnorrSignature<F, G>, Error> {
+        if partial_sigs.is_empty() {
+            return Err(Box::new(CryptoError::IncorrectInputLength(
+                "partial signatures".to_owned(),
+                0,
+            )));
+        }
+        let required_leading_zeros_s = compute_truncation_size(
+            G::ScalarField::size_in_bits() as i32,
+            F::size_in_bits() as i32,
+        );
+        let s = partial_sigs
+            .iter()
+            .fold(G::ScalarField::zero(), |acc, s_i| acc + s_i);
+        let s_bits = s.write_bits();
+        if (leading_zeros(s_bits.as_slice()) as usize) < required_leading_zeros_s {
+            return Err(Box::new(CryptoError::Other(
+                "aggregated signature too long, signing must be restarted with fresh nonces"
+                    .to_owned(),
+            )));
+        }
+        Ok(FieldBasedSchnorrSignature::new(
+            session.e,
+            convert::<F>(s_bits)?,
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::*;
+    use crate::crh::TweedleFqPoseidonHash;
+    use algebra::{
+        curves::tweedle::dee::Projective as DeeJacobian, fields::tweedle::Fq, SemanticallyValid,
+        UniformRand,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    type SchnorrTweedle = FieldBasedSchnorrSignatureScheme<Fq, DeeJacobian, TweedleFqPoseidonHash>;
+    type MuSig2Tweedle = FieldBasedSchnorrMuSig2<Fq, DeeJacobian, TweedleFqPoseidonHash>;
+
+    /// Run a full signing session of `message` among the signers with secret keys `sks`,
+    /// returning the aggregated public key and the signature.
+    fn musig2_sign<R: Rng>(
+        rng: &mut R,
+        sks: &[<DeeJacobian as ProjectiveCurve>::ScalarField],
+        message: Fq,
+    ) -> Result<
+        (
+            FieldBasedSchnorrPk<DeeJacobian>,
+            FieldBasedSchnorrSignature<Fq, DeeJacobian>,
+        ),
+        Error,
+    > {
+        let pks = sks
+            .iter()
+            .map(SchnorrTweedle::get_public_key)
+            .collect::<Vec<_>>();
+        let key_agg_ctx = MuSig2Tweedle::key_aggregation(&pks)?;
+
+        let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) = (0..sks.len())
+            .map(|_| MuSig2Tweedle::nonce_gen(rng))
+            .unzip();
+        let aggregated_nonce = MuSig2Tweedle::nonce_aggregation(&public_nonces)?;
+        let session = MuSig2Tweedle::session(&key_agg_ctx, &aggregated_nonce, message)?;
+
+        let mut partial_sigs = vec![];
+        for (((sk, pk), secret_nonce), public_nonce) in sks
+            .iter()
+            .zip(pks.iter())
+            .zip(secret_nonces)
+            .zip(public_nonces.iter())
+        {
+            let partial_sig =
+                MuSig2Tweedle::partial_sign(&key_agg_ctx, &session, sk, secret_nonce)?;
+            assert!(MuSig2Tweedle::partial_verify(
+                &key_agg_ctx,
+                &session,
+                pk,
+                public_nonce,
+                &partial_sig
+            )?);
+            partial_sigs.push(partial_sig);
+        }
+        let sig = MuSig2Tweedle::partial_sig_aggregation(&session, &partial_sigs)?;
+        Ok((*key_agg_ctx.aggregated_pk(), sig))
+    }
+
+    #[test]
+    fn musig2_sign_and_verify() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+        for &num_signers in [1usize, 2, 5].iter() {
+            let sks = (0..num_signers)
+                .map(|_| SchnorrTweedle::keygen(rng).1)
+                .collect::<Vec<_>>();
+            let message = Fq::rand(rng);
+
+            // Retry with fresh nonces if the aggregated signature is too long
+            let (apk, sig) = loop {
+                if let Ok(result) = musig2_sign(rng, &sks, message) {
+                    break result;
+                }
+            };
+            assert!(SchnorrTweedle::keyverify(&apk));
+            assert!(sig.is_valid());
+            assert!(SchnorrTweedle::verify(&apk, message, &sig).unwrap());
+            assert!(!SchnorrTweedle::verify(&apk, Fq::rand(rng), &sig).unwrap());
+
+            // The aggregated key depends on all the keys, not only on their sum
+            if num_signers > 1 {
+                let sum = FieldBasedSchnorrPk(
+                    sks.iter()
+                        .fold(<DeeJacobian as ProjectiveCurve>::zero(), |acc, sk| {
+                            acc + &SchnorrTweedle::get_public_key(sk).0
+                        }),
+                );
+                assert_ne!(apk, sum);
+                assert!(!SchnorrTweedle::verify(&sum, message, &sig).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn musig2_key_aggregation() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let pks = (0..3)
+            .map(|_| SchnorrTweedle::keygen(rng).0)
+            .collect::<Vec<_>>();
+
+        // The aggregated key depends on the order of the keys
+        let apk = *MuSig2Tweedle::key_aggregation(&pks)
+            .unwrap()
+            .aggregated_pk();
+        let reversed = pks.iter().rev().cloned().collect::<Vec<_>>();
+        assert_ne!(
+            apk,
+            *MuSig2Tweedle::key_aggregation(&reversed)
+                .unwrap()
+                .aggregated_pk()
+        );
+
+        // Empty lists and invalid keys are rejected
+        assert!(MuSig2Tweedle::key_aggregation(&[]).is_err());
+        let mut invalid = pks.clone();
+        invalid[1] = FieldBasedSchnorrPk(<DeeJacobian as ProjectiveCurve>::zero());
+        assert!(MuSig2Tweedle::key_aggregation(&invalid).is_err());
+    }
+
+    #[test]
+    fn musig2_wrong_partial_signatures() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let (pks, sks): (Vec<_>, Vec<_>) = (0..3).map(|_| SchnorrTweedle::keygen(rng)).unzip();
+        let key_agg_ctx = MuSig2Tweedle::key_aggregation(&pks).unwrap();
+        let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+            (0..3).map(|_| MuSig2Tweedle::nonce_gen(rng)).unzip();
+        let aggregated_nonce = MuSig2Tweedle::nonce_aggregation(&public_nonces).unwrap();
+        let session =
+            MuSig2Tweedle::session(&key_agg_ctx, &aggregated_nonce, Fq::rand(rng)).unwrap();
+
+        let mut secret_nonces = secret_nonces.into_iter();
+        let partial_sig = MuSig2Tweedle::partial_sign(
+            &key_agg_ctx,
+            &session,
+            &sks[0],
+            secret_nonces.next().unwrap(),
+        )
+        .unwrap();
+
+        // A partial signature does not verify for another signer, or another nonce
+        assert!(!MuSig2Tweedle::partial_verify(
+            &key_agg_ctx,
+            &session,
+            &pks[1],
+            &public_nonces[0],
+            &partial_sig
+        )
+        .unwrap());
+        assert!(!MuSig2Tweedle::partial_verify(
+            &key_agg_ctx,
+            &session,
+            &pks[0],
+            &public_nonces[1],
+            &partial_sig
+        )
+        .unwrap());
+
+        // Non signers can neither sign nor be verified
+        let (other_pk, other_sk) = SchnorrTweedle::keygen(rng);
+        assert!(MuSig2Tweedle::partial_sign(
+            &key_agg_ctx,
+            &session,
+            &other_sk,
+            secret_nonces.next().unwrap()
+        )
+        .is_err());
+        assert!(!MuSig2Tweedle::partial_verify(
+            &key_agg_ctx,
+            &session,
+            &other_pk,
+            &public_nonces[0],
+            &partial_sig
+        )
+        .unwrap());
+    }
+}
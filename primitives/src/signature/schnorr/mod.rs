@@ -14,6 +14,7 @@ use std::{
     marker::PhantomData,
 };
 
+pub mod field_based_musig2;
 pub mod field_based_schnorr;
 
 pub struct SchnorrSignature<G: Group, D: Digest> {
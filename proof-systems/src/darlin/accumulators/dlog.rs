@@ -4,18 +4,28 @@
 //! reduction steps) is the polynomial commitment of the succinct 'reduction polynomial'
 //!     h(X) = (1 + xi_d * X^1)*(1 + xi_{d-1} * X^2) * ... (1 + xi_{1}*X^{2^d}),
 //! where the xi_1,...,xi_d are the challenges of the dlog reduction.
-use crate::darlin::accumulators::{AccumulationProof, ItemAccumulator};
+//! The random oracle of the accumulation argument is pluggable: the digest based one of
+//! `InnerProductArgPC` is the default, the algebraic `AlgebraicRandomOracle` makes the
+//! accumulation proofs verifiable in-circuit.
+use crate::darlin::{
+    accumulators::{AccumulationProof, ItemAccumulator},
+    algebraic_ipa_pc::AlgebraicInnerProductArgPC,
+    fiat_shamir::FieldBasedFiatShamirRng,
+};
 use algebra::polynomial::DensePolynomial as Polynomial;
 use algebra::{
-    serialize::*, to_bytes, AffineCurve, Field, ProjectiveCurve, SemanticallyValid, ToBytes,
-    UniformRand,
+    serialize::*, to_bytes, AffineCurve, EndoMulCurve, Field, PrimeField, ProjectiveCurve,
+    SemanticallyValid, ToBytes, ToConstraintField, UniformRand, VariableBaseMSM,
 };
 use digest::Digest;
 use poly_commit::{
-    ipa_pc::{Commitment, CommitterKey, InnerProductArgPC, SuccinctCheckPolynomial, VerifierKey},
+    ipa_pc::{
+        Commitment, CommitterKey, InnerProductArgPC, Proof, SuccinctCheckPolynomial, VerifierKey,
+    },
     rng::{FiatShamirRng, FiatShamirRngSeed},
     Error, LabeledCommitment, PolynomialCommitment,
 };
+use primitives::crh::FieldBasedSponge;
 use rand::RngCore;
 use rayon::prelude::*;
 use std::marker::PhantomData;
@@ -30,6 +40,18 @@ pub struct DLogItem<G: AffineCurve> {
     pub(crate) xi_s: SuccinctCheckPolynomial<G::ScalarField>,
 }
 
+impl<G: AffineCurve> DLogItem<G> {
+    /// The final committer key of the dlog reduction.
+    pub fn g_final(&self) -> &Commitment<G> {
+        &self.g_final
+    }
+
+    /// The challenges of the dlog reduction.
+    pub fn xi_s(&self) -> &SuccinctCheckPolynomial<G::ScalarField> {
+        &self.xi_s
+    }
+}
+
 impl<G: AffineCurve> CanonicalSerialize for DLogItem<G> {
     fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
         // GFinal will always be 1 segment and without any shift
@@ -146,49 +168,123 @@ impl<G: AffineCurve> ToBytes for DLogItem<G> {
     }
 }
 
-pub struct DLogItemAccumulator<G: AffineCurve, D: Digest> {
-    _digest: PhantomData<D>,
-    _group: PhantomData<G>,
+/// Batch verification of dLog items: combine reduction polynomials and their corresponding G_fins
+/// and perform a single MSM. As deciding the items does not involve the random oracle, it is
+/// shared by the accumulators of dlog items.
+pub(crate) fn check_dlog_items<G: AffineCurve, R: RngCore>(
+    vk: &VerifierKey<G>,
+    accumulators: &[DLogItem<G>],
+    rng: &mut R,
+) -> Result<bool, Error> {
+    let check_time = start_timer!(|| "Check accumulators");
+
+    let final_comm_keys = accumulators
+        .iter()
+        .flat_map(|acc| acc.g_final.comm.clone())
+        .collect::<Vec<_>>();
+    let xi_s_vec = accumulators
+        .iter()
+        .map(|acc| acc.xi_s.clone())
+        .collect::<Vec<_>>();
+
+    let batching_time = start_timer!(|| "Combine check polynomials and final comm keys");
+
+    // Sample the batching challenge (using a cryptographically secure rng)
+    let random_scalar = G::ScalarField::rand(rng);
+    let mut batching_chal = G::ScalarField::one();
+
+    // Collect the powers of the batching challenge in a vector
+    let mut batching_chal_pows = vec![G::ScalarField::zero(); xi_s_vec.len()];
+    for i in 0..batching_chal_pows.len() {
+        batching_chal_pows[i] = batching_chal;
+        batching_chal *= &random_scalar;
+    }
+
+    // Compute the linear combination of the reduction polys,
+    //  h_bar(X) = sum_k lambda^k * h(xi's[k],X).
+    let combined_check_poly = batching_chal_pows
+        .par_iter()
+        .zip(xi_s_vec)
+        .map(|(&chal, xi_s)| Polynomial::from_coefficients_vec(xi_s.compute_scaled_coeffs(-chal)))
+        .reduce(Polynomial::zero, |acc, scaled_poly| &acc + &scaled_poly);
+    end_timer!(batching_time);
+
+    // The dlog "hard part", checking that G_bar = sum_k lambda^k * G_f[k] == Comm(h_bar(X))
+    // The equation to check would be:
+    // lambda_1 * gfin_1 + ... + lambda_n * gfin_n - combined_h_1 * g_vk_1 - ... - combined_h_m * g_vk_m = 0
+    // Where combined_h_i = lambda_1 * h_1_i + ... + lambda_n * h_n_i
+    // We do final verification and the batching of the GFin in a single MSM
+    let hard_time = start_timer!(|| "Batch verify hard parts");
+    let final_val = VariableBaseMSM::multi_scalar_mul(
+        // The vk might be oversized, but the VariableBaseMSM function, will "trim"
+        // the bases in order to be as big as the scalars vector, so no need to explicitly
+        // trim the vk here.
+        &[final_comm_keys.as_slice(), vk.comm_key.as_slice()].concat(),
+        &batching_chal_pows
+            .iter()
+            .chain(combined_check_poly.coeffs.iter())
+            .map(|scalar| scalar.into_repr())
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
+    end_timer!(hard_time);
+
+    if !ProjectiveCurve::is_zero(&final_val) {
+        end_timer!(check_time);
+        return Ok(false);
+    }
+    end_timer!(check_time);
+    Ok(true)
 }
 
-impl<G: AffineCurve, D: Digest> DLogItemAccumulator<G, D> {
-    /// The personalization string for this protocol. Used to personalize the
-    /// Fiat-Shamir rng.
-    pub const PROTOCOL_NAME: &'static [u8] = b"DL-ACC-2021";
+/// The random oracle of the dlog accumulation argument, i.e. the Fiat-Shamir transcript which
+/// binds the committer key and the items to the challenge z, continued by the dlog opening
+/// proof of their succinct check polynomials at z.
+pub trait DLogAccumulatorRandomOracle<G: AffineCurve> {
+    /// The personalization string for the protocol. Used to personalize the Fiat-Shamir rng.
+    const PROTOCOL_NAME: &'static [u8];
 
-    pub fn get_instance() -> Self {
-        Self {
-            _group: PhantomData,
-            _digest: PhantomData,
-        }
-    }
+    /// Open the succinct check polynomials of `items` at the challenge z.
+    fn open_check_polys(ck: &CommitterKey<G>, items: Vec<DLogItem<G>>) -> Result<Proof<G>, Error>;
 
-    /// This implementation handles the succinct verification of an aggregation proof
-    /// for dlog "items".
-    /// Recall that in the special situation of dlog items, the accumulated item
-    /// is part of the proof itself. However, as we use size-optimized proofs, the
-    /// xi_s are recomputed from the proof and returned by the verifier (if successful).
-    pub fn succinct_verify_accumulated_items(
+    /// Succinctly verify the opening `proof` of the succinct check polynomials of `items`
+    /// at the challenge z. Returns the new succinct check polynomial, if successful.
+    fn succinct_check(
         vk: &VerifierKey<G>,
-        previous_accumulators: Vec<DLogItem<G>>,
-        proof: &AccumulationProof<G>,
-    ) -> Result<Option<DLogItem<G>>, Error> {
-        let succinct_time = start_timer!(|| "Succinct verify accumulate");
+        items: Vec<DLogItem<G>>,
+        proof: &Proof<G>,
+    ) -> Result<Option<SuccinctCheckPolynomial<G::ScalarField>>, Error>;
+}
 
-        let poly_time = start_timer!(|| "Compute Bullet Polys evaluations");
+/// The digest based random oracle of `InnerProductArgPC<G, D>`.
+pub struct DigestRandomOracle<D: Digest> {
+    _digest: PhantomData<D>,
+}
 
-        // Initialize Fiat-Shamir rng
+impl<D: Digest> DigestRandomOracle<D> {
+    /// Initialize the Fiat-Shamir rng, bind it to the hash of the committer key and to
+    /// `items`, and sample the challenge z.
+    fn init_fs_rng<G: AffineCurve>(
+        key_hash: &[u8],
+        items: &[DLogItem<G>],
+    ) -> Result<
+        (
+            <InnerProductArgPC<G, D> as PolynomialCommitment<G::ScalarField>>::RandomOracle,
+            G::ScalarField,
+        ),
+        Error,
+    > {
         let fs_rng_init_seed = {
             let mut seed_builder = <<InnerProductArgPC<G, D> as PolynomialCommitment<
                 G::ScalarField,
             >>::RandomOracle as FiatShamirRng>::Seed::new();
-            seed_builder.add_bytes(&Self::PROTOCOL_NAME)?;
-            seed_builder.add_bytes(&vk.hash)?;
+            seed_builder.add_bytes(&<Self as DLogAccumulatorRandomOracle<G>>::PROTOCOL_NAME)?;
+            seed_builder.add_bytes(&key_hash)?;
 
             // NOTE: We assume the number of accumulators to be clear from the context.
             // As we use constant length encoding of field elements, we may use add_bytes()
             // without producing collisions in the serialization procedure.
-            seed_builder.add_bytes(&previous_accumulators)?;
+            seed_builder.add_bytes(&items)?;
             seed_builder.finalize()
         };
         let mut fs_rng = <InnerProductArgPC<G, D> as PolynomialCommitment<G::ScalarField>>::RandomOracle::from_seed(fs_rng_init_seed);
@@ -196,7 +292,34 @@ impl<G: AffineCurve, D: Digest> DLogItemAccumulator<G, D> {
         // Sample a new challenge z
         let z = fs_rng.squeeze_128_bits_challenge::<G::ScalarField>();
 
-        let comms_values = previous_accumulators
+        Ok((fs_rng, z))
+    }
+}
+
+impl<G: AffineCurve, D: Digest> DLogAccumulatorRandomOracle<G> for DigestRandomOracle<D> {
+    const PROTOCOL_NAME: &'static [u8] = b"DL-ACC-2021";
+
+    fn open_check_polys(ck: &CommitterKey<G>, items: Vec<DLogItem<G>>) -> Result<Proof<G>, Error> {
+        let (mut fs_rng, z) = Self::init_fs_rng(&ck.hash, items.as_slice())?;
+
+        // Collect xi_s from the accumulators
+        let xi_s = items.into_iter().map(|acc| acc.xi_s).collect::<Vec<_>>();
+
+        // Compute multi-poly single-point opening proof for the G_f's, i.e.
+        // the commitments of the item polys.
+        InnerProductArgPC::<G, D>::open_check_polys(&ck, xi_s.iter(), z, &mut fs_rng)
+    }
+
+    fn succinct_check(
+        vk: &VerifierKey<G>,
+        items: Vec<DLogItem<G>>,
+        proof: &Proof<G>,
+    ) -> Result<Option<SuccinctCheckPolynomial<G::ScalarField>>, Error> {
+        let poly_time = start_timer!(|| "Compute Bullet Polys evaluations");
+
+        let (mut fs_rng, z) = Self::init_fs_rng(&vk.hash, items.as_slice())?;
+
+        let comms_values = items
             .into_par_iter()
             .enumerate()
             .map(|(i, acc)| {
@@ -247,16 +370,178 @@ impl<G: AffineCurve, D: Digest> DLogItemAccumulator<G, D> {
             comms.iter(),
             z,
             values,
-            &proof.pc_proof,
+            proof,
+            &mut fs_rng,
+        );
+
+        end_timer!(check_time);
+
+        xi_s
+    }
+}
+
+/// The algebraic random oracle `FieldBasedFiatShamirRng<S>`, verifiable in a circuit over
+/// `G::BaseField` by `DLogItemAccumulatorGadget` of `r1cs_crypto::nizk::accumulators`.
+/// The transcript is personalized by the protocol name and the hash of the committer key,
+/// and absorbs the G_final's (as points) and the xi's (packed as non-native elements) of
+/// the items. The opening proof is the one of `AlgebraicInnerProductArgPC<G, S>`.
+pub struct AlgebraicRandomOracle<S: FieldBasedSponge> {
+    _sponge: PhantomData<S>,
+}
+
+impl<S: FieldBasedSponge> AlgebraicRandomOracle<S> {
+    /// Initialize the Fiat-Shamir rng, bind it to the hash of the committer key and to
+    /// `items`, and sample the challenge z. Returns the rng, z and the values of the
+    /// succinct check polynomials of `items` at z, which are absorbed too.
+    fn init_fs_rng<G>(
+        key_hash: &[u8],
+        items: &[DLogItem<G>],
+    ) -> Result<
+        (
+            FieldBasedFiatShamirRng<S>,
+            G::ScalarField,
+            Vec<G::ScalarField>,
+        ),
+        Error,
+    >
+    where
+        G: EndoMulCurve + ToConstraintField<<G as AffineCurve>::BaseField>,
+        G::BaseField: PrimeField,
+        S: FieldBasedSponge<Data = G::BaseField>,
+    {
+        if items.is_empty() {
+            return Err(Error::IncorrectInputLength(
+                "No items to accumulate".to_owned(),
+            ));
+        }
+
+        if items.iter().any(|item| item.g_final.comm.len() != 1) {
+            return Err(Error::IncorrectInputLength(
+                "The G_final of an item must consist of a single segment".to_owned(),
+            ));
+        }
+
+        let personalization = [
+            <Self as DLogAccumulatorRandomOracle<G>>::PROTOCOL_NAME,
+            key_hash,
+        ]
+        .iter()
+        .map(|bytes| <[u8] as ToConstraintField<G::BaseField>>::to_field_elements(bytes))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| Error::IncorrectInputLength(e.to_string()))?
+        .concat();
+        let mut fs_rng = FieldBasedFiatShamirRng::<S>::init(Some(personalization.as_slice()));
+        for item in items.iter() {
+            fs_rng.absorb(item.g_final.comm.as_slice())?;
+            fs_rng.absorb_nonnative(item.xi_s.0.as_slice())?;
+        }
+
+        // Sample the challenge z and compute the values of the succinct check polynomials
+        let (_, z) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let values = items
+            .iter()
+            .map(|item| item.xi_s.evaluate(z))
+            .collect::<Vec<_>>();
+        fs_rng.absorb_nonnative(values.as_slice())?;
+
+        Ok((fs_rng, z, values))
+    }
+}
+
+impl<G, S> DLogAccumulatorRandomOracle<G> for AlgebraicRandomOracle<S>
+where
+    G: EndoMulCurve + ToConstraintField<<G as AffineCurve>::BaseField>,
+    G::BaseField: PrimeField,
+    S: FieldBasedSponge<Data = G::BaseField>,
+{
+    const PROTOCOL_NAME: &'static [u8] = b"DL-ACC-ALG-2021";
+
+    fn open_check_polys(ck: &CommitterKey<G>, items: Vec<DLogItem<G>>) -> Result<Proof<G>, Error> {
+        let (mut fs_rng, z, _) = Self::init_fs_rng(&ck.hash, items.as_slice())?;
+        let polys = items
+            .iter()
+            .map(|acc| acc.xi_s.compute_coeffs())
+            .collect::<Vec<_>>();
+        let rands = vec![vec![G::ScalarField::zero()]; polys.len()];
+
+        AlgebraicInnerProductArgPC::<G, S>::open(
+            ck,
+            polys.as_slice(),
+            rands.as_slice(),
+            z,
+            &mut fs_rng,
+            None,
+        )
+    }
+
+    fn succinct_check(
+        vk: &VerifierKey<G>,
+        items: Vec<DLogItem<G>>,
+        proof: &Proof<G>,
+    ) -> Result<Option<SuccinctCheckPolynomial<G::ScalarField>>, Error> {
+        let (mut fs_rng, z, values) = Self::init_fs_rng(&vk.hash, items.as_slice())?;
+        let commitments = items
+            .into_iter()
+            .map(|acc| acc.g_final.comm)
+            .collect::<Vec<_>>();
+
+        AlgebraicInnerProductArgPC::<G, S>::succinct_check(
+            vk,
+            commitments.as_slice(),
+            z,
+            values.as_slice(),
+            proof,
             &mut fs_rng,
         )
-        .map_err(|e| {
-            end_timer!(check_time);
+    }
+}
+
+/// The dlog accumulator over `G`, for keys hashed by `D`. The random oracle `RO` of the
+/// accumulation argument defaults to the one of `InnerProductArgPC<G, D>`; accumulation
+/// proofs to be verified in-circuit use `AlgebraicRandomOracle` instead.
+pub struct DLogItemAccumulator<G: AffineCurve, D: Digest, RO = DigestRandomOracle<D>> {
+    _digest: PhantomData<D>,
+    _group: PhantomData<G>,
+    _random_oracle: PhantomData<RO>,
+}
+
+impl<G, D, RO> DLogItemAccumulator<G, D, RO>
+where
+    G: AffineCurve,
+    D: Digest,
+    RO: DLogAccumulatorRandomOracle<G>,
+{
+    /// The personalization string for this protocol. Used to personalize the
+    /// Fiat-Shamir rng.
+    pub const PROTOCOL_NAME: &'static [u8] = RO::PROTOCOL_NAME;
+
+    pub fn get_instance() -> Self {
+        Self {
+            _group: PhantomData,
+            _digest: PhantomData,
+            _random_oracle: PhantomData,
+        }
+    }
+
+    /// This implementation handles the succinct verification of an aggregation proof
+    /// for dlog "items".
+    /// Recall that in the special situation of dlog items, the accumulated item
+    /// is part of the proof itself. However, as we use size-optimized proofs, the
+    /// xi_s are recomputed from the proof and returned by the verifier (if successful).
+    pub fn succinct_verify_accumulated_items(
+        vk: &VerifierKey<G>,
+        previous_accumulators: Vec<DLogItem<G>>,
+        proof: &AccumulationProof<G>,
+    ) -> Result<Option<DLogItem<G>>, Error> {
+        let succinct_time = start_timer!(|| "Succinct verify accumulate");
+
+        // Succinctly verify the dlog opening proof,
+        // and get the new reduction polynomial (the new xi's).
+        let xi_s = RO::succinct_check(vk, previous_accumulators, &proof.pc_proof).map_err(|e| {
             end_timer!(succinct_time);
             e
         })?;
 
-        end_timer!(check_time);
         end_timer!(succinct_time);
 
         if xi_s.is_some() {
@@ -273,7 +558,12 @@ impl<G: AffineCurve, D: Digest> DLogItemAccumulator<G, D> {
     }
 }
 
-impl<G: AffineCurve, D: Digest> ItemAccumulator for DLogItemAccumulator<G, D> {
+impl<G, D, RO> ItemAccumulator for DLogItemAccumulator<G, D, RO>
+where
+    G: AffineCurve,
+    D: Digest,
+    RO: DLogAccumulatorRandomOracle<G>,
+{
     type AccumulatorProverKey = CommitterKey<G>;
     type AccumulatorVerifierKey = VerifierKey<G>;
     type AccumulationProof = AccumulationProof<G>;
@@ -286,69 +576,7 @@ impl<G: AffineCurve, D: Digest> ItemAccumulator for DLogItemAccumulator<G, D> {
         accumulators: &[Self::Item],
         rng: &mut R,
     ) -> Result<bool, Error> {
-        let check_time = start_timer!(|| "Check accumulators");
-
-        let final_comm_keys = accumulators
-            .iter()
-            .flat_map(|acc| acc.g_final.comm.clone())
-            .collect::<Vec<_>>();
-        let xi_s_vec = accumulators
-            .iter()
-            .map(|acc| acc.xi_s.clone())
-            .collect::<Vec<_>>();
-
-        let batching_time = start_timer!(|| "Combine check polynomials and final comm keys");
-
-        // Sample the batching challenge (using a cryptographically secure rng)
-        let random_scalar = G::ScalarField::rand(rng);
-        let mut batching_chal = G::ScalarField::one();
-
-        // Collect the powers of the batching challenge in a vector
-        let mut batching_chal_pows = vec![G::ScalarField::zero(); xi_s_vec.len()];
-        for i in 0..batching_chal_pows.len() {
-            batching_chal_pows[i] = batching_chal;
-            batching_chal *= &random_scalar;
-        }
-
-        // Compute the linear combination of the reduction polys,
-        //  h_bar(X) = sum_k lambda^k * h(xi's[k],X).
-        let combined_check_poly = batching_chal_pows
-            .par_iter()
-            .zip(xi_s_vec)
-            .map(|(&chal, xi_s)| {
-                Polynomial::from_coefficients_vec(xi_s.compute_scaled_coeffs(-chal))
-            })
-            .reduce(Polynomial::zero, |acc, scaled_poly| &acc + &scaled_poly);
-        end_timer!(batching_time);
-
-        // The dlog "hard part", checking that G_bar = sum_k lambda^k * G_f[k] == Comm(h_bar(X))
-        // The equation to check would be:
-        // lambda_1 * gfin_1 + ... + lambda_n * gfin_n - combined_h_1 * g_vk_1 - ... - combined_h_m * g_vk_m = 0
-        // Where combined_h_i = lambda_1 * h_1_i + ... + lambda_n * h_n_i
-        // We do final verification and the batching of the GFin in a single MSM
-        let hard_time = start_timer!(|| "Batch verify hard parts");
-        let final_val = InnerProductArgPC::<G, D>::cm_commit(
-            // The vk might be oversized, but the VariableBaseMSM function, will "trim"
-            // the bases in order to be as big as the scalars vector, so no need to explicitly
-            // trim the vk here.
-            &[final_comm_keys.as_slice(), vk.comm_key.as_slice()].concat(),
-            &[
-                batching_chal_pows.as_slice(),
-                combined_check_poly.coeffs.as_slice(),
-            ]
-            .concat(),
-            None,
-            None,
-        )
-        .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
-        end_timer!(hard_time);
-
-        if !ProjectiveCurve::is_zero(&final_val) {
-            end_timer!(check_time);
-            return Ok(false);
-        }
-        end_timer!(check_time);
-        Ok(true)
+        check_dlog_items(vk, accumulators, rng)
     }
 
     /// Accumulate dlog "items" via the dlog amortization strategy:
@@ -363,40 +591,15 @@ impl<G: AffineCurve, D: Digest> ItemAccumulator for DLogItemAccumulator<G, D> {
     ) -> Result<(Self::Item, Self::AccumulationProof), Error> {
         let accumulate_time = start_timer!(|| "Accumulate");
 
-        // Initialize Fiat-Shamir rng
-        let fs_rng_init_seed = {
-            let mut seed_builder = <<InnerProductArgPC<G, D> as PolynomialCommitment<
-                G::ScalarField,
-            >>::RandomOracle as FiatShamirRng>::Seed::new();
-            seed_builder.add_bytes(&Self::PROTOCOL_NAME)?;
-            seed_builder.add_bytes(&ck.hash)?;
-            // TODO: Shall we decompose this further when passing it to the seed builder ?
-            seed_builder.add_bytes(&accumulators)?;
-            seed_builder.finalize()
-        };
-        let mut fs_rng = <InnerProductArgPC<G, D> as PolynomialCommitment<G::ScalarField>>::RandomOracle::from_seed(fs_rng_init_seed);
-
-        // Sample a new challenge z
-        let z = fs_rng.squeeze_128_bits_challenge::<G::ScalarField>();
-
-        // Collect xi_s from the accumulators
-        let xi_s = accumulators
-            .into_iter()
-            .map(|acc| acc.xi_s)
-            .collect::<Vec<_>>();
-
         let poly_time = start_timer!(|| "Open Bullet Polys");
 
         // Compute multi-poly single-point opening proof for the G_f's, i.e.
         // the commitments of the item polys.
-        let opening_proof =
-            InnerProductArgPC::<G, D>::open_check_polys(&ck, xi_s.iter(), z, &mut fs_rng).map_err(
-                |e| {
-                    end_timer!(poly_time);
-                    end_timer!(accumulate_time);
-                    e
-                },
-            )?;
+        let opening_proof = RO::open_check_polys(ck, accumulators).map_err(|e| {
+            end_timer!(poly_time);
+            end_timer!(accumulate_time);
+            e
+        })?;
 
         end_timer!(poly_time);
 
@@ -800,10 +1003,11 @@ mod test {
 
     // We sample random instances of multi-point multi-poly dlog opening proofs,
     // produce aggregation proofs for their dlog items and fully verify these aggregation proofs.
-    fn accumulation_test<G, D>() -> Result<(), Error>
+    fn accumulation_test<G, D, RO>() -> Result<(), Error>
     where
         G: AffineCurve,
         D: Digest,
+        RO: DLogAccumulatorRandomOracle<G>,
     {
         let rng = &mut thread_rng();
         let max_degree = rand::distributions::Uniform::from(2..=128).sample(rng);
@@ -886,20 +1090,31 @@ mod test {
 
             // provide aggregation proof of the extracted dlog items
             let (_, proof) =
-                DLogItemAccumulator::<G, D>::accumulate_items(&ck, accumulators.clone())?;
+                DLogItemAccumulator::<G, D, RO>::accumulate_items(&ck, accumulators.clone())?;
 
             test_canonical_serialize_deserialize(true, &proof);
 
             // Verifier side
             let dummy = DLogItem::<G>::default();
-            assert!(DLogItemAccumulator::<G, D>::verify_accumulated_items(
+            assert!(DLogItemAccumulator::<G, D, RO>::verify_accumulated_items(
                 &dummy,
                 &vk,
                 // Actually the verifier should recompute the accumulators with the succinct verification
-                accumulators,
+                accumulators.clone(),
                 &proof,
                 rng
             )?);
+
+            // The proof does not verify for other items
+            if num_proofs > 1 {
+                assert!(!DLogItemAccumulator::<G, D, RO>::verify_accumulated_items(
+                    &dummy,
+                    &vk,
+                    accumulators[1..].to_vec(),
+                    &proof,
+                    rng
+                )?);
+            }
         }
         Ok(())
     }
@@ -998,11 +1213,20 @@ mod test {
     }
 
     use algebra::curves::tweedle::{dee::Affine as TweedleDee, dum::Affine as TweedleDum};
+    use primitives::{TweedleFqPoseidonHash, TweedleFrPoseidonHash};
 
     #[test]
     fn test_tweedle_accumulate_verify() {
-        accumulation_test::<TweedleDee, Blake2s>().unwrap();
-        accumulation_test::<TweedleDum, Blake2s>().unwrap();
+        accumulation_test::<TweedleDee, Blake2s, DigestRandomOracle<Blake2s>>().unwrap();
+        accumulation_test::<TweedleDum, Blake2s, DigestRandomOracle<Blake2s>>().unwrap();
+    }
+
+    #[test]
+    fn test_tweedle_algebraic_accumulate_verify() {
+        accumulation_test::<TweedleDee, Blake2s, AlgebraicRandomOracle<TweedleFqPoseidonHash>>()
+            .unwrap();
+        accumulation_test::<TweedleDum, Blake2s, AlgebraicRandomOracle<TweedleFrPoseidonHash>>()
+            .unwrap();
     }
 
     #[test]
@@ -0,0 +1,848 @@
+//! The amortization strategy for the inner sumcheck of (coboundary) Marlin as separate
+//! public aggregation/accumulation scheme according to [BCMS20](https://eprint.iacr.org/2020/499).
+//! Given the R1CS matrices A, B, C of a circuit, indexed by the elements of the domain H,
+//! the inner sumcheck reduces to the evaluation of the circuit polynomial
+//!     T_eta(X,Y) = eta_A * A(X,Y) + eta_B * B(X,Y) + eta_C * C(X,Y),
+//! with
+//!     M(X,Y) = Sum_{r,c} M[r][c] * L_r(X) * L_c(Y),
+//! where L_i is the Lagrange polynomial of the i-th element of H.
+//! An inner sumcheck item is a triple (alpha, eta, C) and satisfies the (non-efficient)
+//! predicate if C is the commitment of the univariate polynomial T_eta(alpha, Y).
+//!
+//! Items are accumulated by the following protocol:
+//!     1. the verifier samples a random beta, and the prover commits to the polynomials
+//!     A(X,beta), B(X,beta), C(X,beta),
+//!     2. the verifier samples a new alpha' and eta', and the prover commits to the
+//!     polynomial T_eta'(alpha',Y),
+//!     3. the prover shows (by a batched dlog opening proof) that
+//!         T_eta(alpha, beta) = Sum_M eta_M * M(alpha, beta)
+//!     for each of the previous items as well as for the new one, (alpha', eta', C').
+//! The new item (alpha', eta', C') is part of the accumulation, while the dlog opening
+//! proof leaves a dlog item (see `DLogItemAccumulator`).
+use crate::darlin::accumulators::{
+    dlog::{DLogItem, DLogItemAccumulator},
+    ItemAccumulator,
+};
+use algebra::polynomial::DensePolynomial as Polynomial;
+use algebra::{
+    get_best_evaluation_domain, serialize::*, to_bytes, AffineCurve, EvaluationDomain, Field,
+    ProjectiveCurve, SemanticallyValid, ToBytes, UniformRand,
+};
+use digest::Digest;
+use poly_commit::{
+    ipa_pc::{BatchProof, Commitment, CommitterKey, InnerProductArgPC, VerifierKey},
+    rng::{FiatShamirRng, FiatShamirRngSeed},
+    Error, Evaluations, LabeledCommitment, LabeledPolynomial, PolynomialCommitment, QuerySet,
+};
+use rand::RngCore;
+use rayon::prelude::*;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// A sparse R1CS matrix in row major order, i.e. for each row the (non-zero) entries together
+/// with their column index. This is the same representation as the one of the Marlin `Index`.
+pub type Matrix<F> = Vec<Vec<(F, usize)>>;
+
+/// The labels of the matrix polynomials A(X,beta), B(X,beta), C(X,beta).
+const MATRIX_POLY_LABELS: [&str; 3] = ["a_beta", "b_beta", "c_beta"];
+
+/// An item of the inner sumcheck accumulator.
+#[derive(Clone, Debug, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct InnerSumcheckItem<G: AffineCurve> {
+    /// The point at which the row variable of the circuit polynomial is fixed.
+    pub(crate) alpha: G::ScalarField,
+
+    /// The randomizers eta_A, eta_B, eta_C of the circuit polynomial.
+    pub(crate) etas: Vec<G::ScalarField>,
+
+    /// Commitment of T_eta(alpha, Y).
+    pub(crate) c: Commitment<G>,
+}
+
+impl<G: AffineCurve> SemanticallyValid for InnerSumcheckItem<G> {
+    fn is_valid(&self) -> bool {
+        self.alpha.is_valid()
+            && self.etas.len() == 3
+            && self.etas.is_valid()
+            && self.c.is_valid()
+            && self.c.shifted_comm.is_none()
+    }
+}
+
+impl<G: AffineCurve> Default for InnerSumcheckItem<G> {
+    fn default() -> Self {
+        Self {
+            alpha: G::ScalarField::zero(),
+            etas: vec![G::ScalarField::zero(); 3],
+            c: Commitment::<G>::default(),
+        }
+    }
+}
+
+impl<G: AffineCurve> ToBytes for InnerSumcheckItem<G> {
+    fn write<W: Write>(&self, writer: W) -> std::io::Result<()> {
+        use std::io::{Error, ErrorKind};
+
+        self.serialize_without_metadata(writer)
+            .map_err(|e| Error::new(ErrorKind::Other, format! {"{:?}", e}))
+    }
+}
+
+impl<G: AffineCurve> InnerSumcheckItem<G> {
+    /// Generates a random (but valid) item for the circuit of `key`, for test purposes only.
+    pub fn generate_random<D: Digest, R: RngCore>(key: &InnerSumcheckKey<G>, rng: &mut R) -> Self {
+        let alpha = G::ScalarField::rand(rng);
+        let etas = (0..3)
+            .map(|_| G::ScalarField::rand(rng))
+            .collect::<Vec<_>>();
+        let poly = LabeledPolynomial::new(
+            "item".to_owned(),
+            key.circuit_poly(&etas, alpha),
+            None,
+            None,
+        );
+        let (comms, _) = InnerProductArgPC::<G, D>::commit(&key.dlog_key, &[poly], None).unwrap();
+        Self {
+            alpha,
+            etas,
+            c: comms[0].commitment().clone(),
+        }
+    }
+}
+
+/// The key of the inner sumcheck accumulator: the R1CS matrices of the circuit and the dlog
+/// committer key. The latter is enough for the succinct verifier, while proving and deciding
+/// items requires the matrices.
+/// The key either borrows the matrices and the dlog committer key (see `new()`), or owns
+/// them (see `new_owned()`), e.g. to be stored in an `OwnedFinalDarlinPCDVerifierKey`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""), Debug(bound = ""), PartialEq(bound = ""))]
+pub struct InnerSumcheckKey<'a, G: AffineCurve> {
+    matrices: [Cow<'a, Matrix<G::ScalarField>>; 3],
+    // Determined by the matrices
+    #[derivative(PartialEq = "ignore")]
+    domain_h: Box<dyn EvaluationDomain<G::ScalarField>>,
+    pub(crate) dlog_key: Cow<'a, CommitterKey<G>>,
+}
+
+impl<'a, G: AffineCurve> Eq for InnerSumcheckKey<'a, G> {}
+
+/// An `InnerSumcheckKey` owning the matrices and the dlog committer key.
+pub type OwnedInnerSumcheckKey<G> = InnerSumcheckKey<'static, G>;
+
+impl<G: AffineCurve> OwnedInnerSumcheckKey<G> {
+    /// Build the key from the (square) matrices `a`, `b`, `c` of the circuit, as produced by
+    /// the Marlin indexer, and the dlog committer key, taking ownership of them.
+    pub fn new_owned(
+        a: Matrix<G::ScalarField>,
+        b: Matrix<G::ScalarField>,
+        c: Matrix<G::ScalarField>,
+        dlog_key: CommitterKey<G>,
+    ) -> Result<Self, Error> {
+        Self::from_parts(
+            [Cow::Owned(a), Cow::Owned(b), Cow::Owned(c)],
+            Cow::Owned(dlog_key),
+        )
+    }
+}
+
+impl<'a, G: AffineCurve> InnerSumcheckKey<'a, G> {
+    /// Build the key from the (square) matrices `a`, `b`, `c` of the circuit, as produced by
+    /// the Marlin indexer, and the dlog committer key.
+    pub fn new(
+        a: &'a Matrix<G::ScalarField>,
+        b: &'a Matrix<G::ScalarField>,
+        c: &'a Matrix<G::ScalarField>,
+        dlog_key: &'a CommitterKey<G>,
+    ) -> Result<Self, Error> {
+        Self::from_parts(
+            [Cow::Borrowed(a), Cow::Borrowed(b), Cow::Borrowed(c)],
+            Cow::Borrowed(dlog_key),
+        )
+    }
+
+    /// A copy of the key owning the matrices and the dlog committer key.
+    pub fn to_owned_key(&self) -> OwnedInnerSumcheckKey<G> {
+        InnerSumcheckKey {
+            matrices: [
+                Cow::Owned((*self.matrices[0]).clone()),
+                Cow::Owned((*self.matrices[1]).clone()),
+                Cow::Owned((*self.matrices[2]).clone()),
+            ],
+            domain_h: self.domain_h.clone(),
+            dlog_key: Cow::Owned((*self.dlog_key).clone()),
+        }
+    }
+
+    fn from_parts(
+        matrices: [Cow<'a, Matrix<G::ScalarField>>; 3],
+        dlog_key: Cow<'a, CommitterKey<G>>,
+    ) -> Result<Self, Error> {
+        let [a, b, c] = &matrices;
+        let num_rows = a.len();
+        if num_rows == 0 || b.len() != num_rows || c.len() != num_rows {
+            return Err(Error::IncorrectInputLength(
+                "Matrices must be non-empty and have the same number of rows".to_owned(),
+            ));
+        }
+
+        let domain_h = get_best_evaluation_domain::<G::ScalarField>(num_rows).ok_or_else(|| {
+            Error::IncorrectInputLength(format!("No evaluation domain of size {}", num_rows))
+        })?;
+
+        if matrices
+            .iter()
+            .flat_map(|m| m.iter().flatten())
+            .any(|(_, col)| *col >= domain_h.size())
+        {
+            return Err(Error::IncorrectInputLength(
+                "Column index exceeds the size of the domain".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            matrices,
+            domain_h,
+            dlog_key,
+        })
+    }
+
+    /// The evaluations of T_eta(alpha, Y) over H.
+    fn circuit_poly_evals(
+        &self,
+        etas: &[G::ScalarField],
+        alpha: G::ScalarField,
+    ) -> Vec<G::ScalarField> {
+        let lagrange_at_alpha = self.domain_h.evaluate_all_lagrange_coefficients(alpha);
+        let mut evals = vec![G::ScalarField::zero(); self.domain_h.size()];
+        for (matrix, eta) in self.matrices.iter().zip(etas) {
+            for (row, l_alpha) in matrix.iter().zip(lagrange_at_alpha.iter()) {
+                let scale = *l_alpha * eta;
+                for (val, col) in row.iter() {
+                    evals[*col] += &(scale * val);
+                }
+            }
+        }
+        evals
+    }
+
+    /// The polynomial T_eta(alpha, Y).
+    fn circuit_poly(
+        &self,
+        etas: &[G::ScalarField],
+        alpha: G::ScalarField,
+    ) -> Polynomial<G::ScalarField> {
+        Polynomial::from_coefficients_vec(self.domain_h.ifft(&self.circuit_poly_evals(etas, alpha)))
+    }
+
+    /// The polynomials M(X, beta), for M = A, B, C.
+    fn matrix_polys(&self, beta: G::ScalarField) -> Vec<Polynomial<G::ScalarField>> {
+        let lagrange_at_beta = self.domain_h.evaluate_all_lagrange_coefficients(beta);
+        self.matrices
+            .par_iter()
+            .map(|matrix| {
+                let mut evals = matrix
+                    .iter()
+                    .map(|row| {
+                        row.iter().fold(G::ScalarField::zero(), |acc, (val, col)| {
+                            acc + &(lagrange_at_beta[*col] * val)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                evals.resize(self.domain_h.size(), G::ScalarField::zero());
+                Polynomial::from_coefficients_vec(self.domain_h.ifft(&evals))
+            })
+            .collect()
+    }
+}
+
+/// The key is serialized as its matrices and dlog committer key.
+impl<'a, G: AffineCurve> CanonicalSerialize for InnerSumcheckKey<'a, G> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        for matrix in self.matrices.iter() {
+            CanonicalSerialize::serialize(&**matrix, &mut writer)?;
+        }
+        CanonicalSerialize::serialize(&*self.dlog_key, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.matrices
+            .iter()
+            .map(|matrix| matrix.serialized_size())
+            .sum::<usize>()
+            + self.dlog_key.serialized_size()
+    }
+}
+
+impl<G: AffineCurve> CanonicalDeserialize for OwnedInnerSumcheckKey<G> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let a = CanonicalDeserialize::deserialize(&mut reader)?;
+        let b = CanonicalDeserialize::deserialize(&mut reader)?;
+        let c = CanonicalDeserialize::deserialize(&mut reader)?;
+        let dlog_key = CanonicalDeserialize::deserialize(&mut reader)?;
+        Self::new_owned(a, b, c, dlog_key).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+/// The inner sumcheck keys of the two nodes preceding a final Darlin, i.e. of the
+/// pre-previous node in G1 and of the previous node in G2.
+pub type DualInnerSumcheckKey<'a, G1, G2> =
+    (&'a InnerSumcheckKey<'a, G1>, &'a InnerSumcheckKey<'a, G2>);
+
+/// The accumulation proof of the inner sumcheck accumulator.
+#[derive(Clone, Debug, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct InnerSumcheckAccumulationProof<G: AffineCurve> {
+    /// The commitments of A(X,beta), B(X,beta), C(X,beta), and of the new T_eta(alpha,Y).
+    pub commitments: Vec<Vec<Commitment<G>>>,
+    /// The evaluations of A(X,beta), B(X,beta), C(X,beta) at the alphas of the previous
+    /// items and at the new alpha.
+    pub evaluations: Vec<G::ScalarField>,
+    /// The batched dlog opening proof.
+    pub pc_proof: BatchProof<G>,
+}
+
+/// The result of an inner sumcheck accumulation: the new item and its accumulation proof.
+pub type InnerSumcheckAccumulation<G> = (InnerSumcheckItem<G>, InnerSumcheckAccumulationProof<G>);
+
+pub struct InnerSumcheckItemAccumulator<'a, G: AffineCurve, D: Digest> {
+    _lifetime: PhantomData<&'a ()>,
+    _group: PhantomData<G>,
+    _digest: PhantomData<D>,
+}
+
+impl<'a, G: AffineCurve, D: Digest> InnerSumcheckItemAccumulator<'a, G, D> {
+    /// The personalization string for this protocol. Used to personalize the
+    /// Fiat-Shamir rng.
+    pub const PROTOCOL_NAME: &'static [u8] = b"IS-ACC-2021";
+
+    pub fn get_instance() -> Self {
+        Self {
+            _lifetime: PhantomData,
+            _group: PhantomData,
+            _digest: PhantomData,
+        }
+    }
+
+    fn init_fs_rng(
+        vk: &VerifierKey<G>,
+        accumulators: &[InnerSumcheckItem<G>],
+    ) -> Result<
+        <InnerProductArgPC<G, D> as PolynomialCommitment<G::ScalarField>>::RandomOracle,
+        Error,
+    > {
+        let fs_rng_init_seed = {
+            let mut seed_builder = <<InnerProductArgPC<G, D> as PolynomialCommitment<
+                G::ScalarField,
+            >>::RandomOracle as FiatShamirRng>::Seed::new();
+            seed_builder.add_bytes(&Self::PROTOCOL_NAME)?;
+            seed_builder.add_bytes(&vk.hash)?;
+            // As for the dlog accumulator, the number of items is assumed to be clear
+            // from the context.
+            seed_builder.add_bytes(&accumulators.to_vec())?;
+            seed_builder.finalize()
+        };
+        Ok(<InnerProductArgPC<G, D> as PolynomialCommitment<
+            G::ScalarField,
+        >>::RandomOracle::from_seed(fs_rng_init_seed))
+    }
+
+    /// The query set of the accumulation proof. The previous items and the new item are
+    /// queried at beta, while the matrix polynomials are queried at the alphas of all of them.
+    fn get_query_set<'b>(
+        alphas: &[G::ScalarField],
+        beta: G::ScalarField,
+    ) -> QuerySet<'b, G::ScalarField> {
+        let num_items = alphas.len() - 1;
+        let mut query_set = QuerySet::new();
+        for (i, alpha) in alphas.iter().enumerate() {
+            let (item_label, alpha_label) = Self::item_labels(i, num_items);
+            query_set.insert((item_label, ("beta".to_owned(), beta)));
+            for label in MATRIX_POLY_LABELS.iter() {
+                query_set.insert((label.to_string(), (alpha_label.clone(), *alpha)));
+            }
+        }
+        query_set
+    }
+
+    /// The label of the i-th item and of its alpha, where the last one is the new item.
+    fn item_labels(i: usize, num_items: usize) -> (String, String) {
+        if i == num_items {
+            ("new_item".to_owned(), "new_alpha".to_owned())
+        } else {
+            (format!("item_{}", i), format!("alpha_{}", i))
+        }
+    }
+
+    /// The succinct verification of an accumulation proof for inner sumcheck items. Returns
+    /// the new item and the dlog item of the opening proof, if successful.
+    /// Does not need the matrices of the circuit.
+    pub fn succinct_verify_accumulated_items(
+        vk: &VerifierKey<G>,
+        previous_accumulators: &[InnerSumcheckItem<G>],
+        proof: &InnerSumcheckAccumulationProof<G>,
+    ) -> Result<Option<(InnerSumcheckItem<G>, DLogItem<G>)>, Error> {
+        let succinct_time = start_timer!(|| "Succinct verify inner sumcheck accumulation");
+
+        let num_items = previous_accumulators.len();
+        if num_items == 0
+            || previous_accumulators.iter().any(|acc| acc.etas.len() != 3)
+            || proof.commitments.len() != 2
+            || proof.commitments[0].len() != 3
+            || proof.commitments[1].len() != 1
+            || proof.evaluations.len() != 3 * (num_items + 1)
+        {
+            end_timer!(succinct_time);
+            return Err(Error::IncorrectInputLength(
+                "Invalid items or accumulation proof".to_owned(),
+            ));
+        }
+
+        // Replay the Fiat-Shamir transform of the prover
+        let mut fs_rng = Self::init_fs_rng(vk, previous_accumulators)?;
+        let beta = fs_rng.squeeze_128_bits_challenge::<G::ScalarField>();
+        fs_rng.absorb(
+            &proof.commitments[0]
+                .iter()
+                .flat_map(|comm| to_bytes!(comm).unwrap())
+                .collect::<Vec<_>>(),
+        );
+        let new_alpha = fs_rng.squeeze_128_bits_challenge::<G::ScalarField>();
+        let new_etas = (0..3)
+            .map(|_| fs_rng.squeeze_128_bits_challenge::<G::ScalarField>())
+            .collect::<Vec<_>>();
+        fs_rng.absorb(&to_bytes!(proof.commitments[1][0]).unwrap());
+        fs_rng.absorb(
+            &proof
+                .evaluations
+                .iter()
+                .flat_map(|val| to_bytes!(val).unwrap())
+                .collect::<Vec<_>>(),
+        );
+
+        let new_acc = InnerSumcheckItem::<G> {
+            alpha: new_alpha,
+            etas: new_etas,
+            c: proof.commitments[1][0].clone(),
+        };
+
+        // The claimed evaluations: the ones of the matrix polynomials are given by the
+        // proof, while the ones of the T_eta(alpha, Y) follow from the former.
+        let items = previous_accumulators
+            .iter()
+            .chain(std::iter::once(&new_acc))
+            .collect::<Vec<_>>();
+        let alphas = items.iter().map(|acc| acc.alpha).collect::<Vec<_>>();
+        let query_set = Self::get_query_set(&alphas, beta);
+        let mut evaluations = Evaluations::new();
+        let mut labeled_comms = MATRIX_POLY_LABELS
+            .iter()
+            .zip(proof.commitments[0].iter())
+            .map(|(label, comm)| LabeledCommitment::new(label.to_string(), comm.clone(), None))
+            .collect::<Vec<_>>();
+        for (i, (acc, matrix_evals)) in items.iter().zip(proof.evaluations.chunks(3)).enumerate() {
+            let (item_label, _) = Self::item_labels(i, num_items);
+            let mut value = G::ScalarField::zero();
+            for ((label, eval), eta) in MATRIX_POLY_LABELS.iter().zip(matrix_evals).zip(&acc.etas) {
+                evaluations.insert((label.to_string(), acc.alpha), *eval);
+                value += &(*eta * eval);
+            }
+            evaluations.insert((item_label.clone(), beta), value);
+            labeled_comms.push(LabeledCommitment::new(item_label, acc.c.clone(), None));
+        }
+
+        // Succinctly verify the batched opening proof. A failing check is reported as an
+        // error by the polynomial commitment scheme.
+        let result = InnerProductArgPC::<G, D>::succinct_batch_check_individual_opening_challenges(
+            vk,
+            &labeled_comms,
+            &query_set,
+            &evaluations,
+            &proof.pc_proof,
+            &mut fs_rng,
+        );
+
+        end_timer!(succinct_time);
+
+        Ok(result.ok().map(|(xi_s, g_final)| {
+            let dlog_acc = DLogItem::<G> {
+                g_final: Commitment::<G> {
+                    comm: vec![g_final],
+                    shifted_comm: None,
+                },
+                xi_s,
+            };
+            (new_acc, dlog_acc)
+        }))
+    }
+}
+
+impl<'a, G: AffineCurve, D: Digest> ItemAccumulator for InnerSumcheckItemAccumulator<'a, G, D> {
+    type AccumulatorProverKey = InnerSumcheckKey<'a, G>;
+    type AccumulatorVerifierKey = InnerSumcheckKey<'a, G>;
+    type AccumulationProof = InnerSumcheckAccumulationProof<G>;
+    type Item = InnerSumcheckItem<G>;
+
+    /// Batch verification of inner sumcheck items: combine the polynomials T_eta(alpha, Y)
+    /// and their commitments, and check the combined commitment segment-wise.
+    fn check_items<R: RngCore>(
+        vk: &Self::AccumulatorVerifierKey,
+        accumulators: &[Self::Item],
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let check_time = start_timer!(|| "Check inner sumcheck accumulators");
+
+        if accumulators.iter().any(|acc| acc.etas.len() != 3) {
+            end_timer!(check_time);
+            return Err(Error::IncorrectInputLength(
+                "Items must have three etas".to_owned(),
+            ));
+        }
+
+        // Sample the batching challenge (using a cryptographically secure rng)
+        let random_scalar = G::ScalarField::rand(rng);
+        let mut batching_chal_pows = Vec::with_capacity(accumulators.len());
+        let mut batching_chal = G::ScalarField::one();
+        for _ in 0..accumulators.len() {
+            batching_chal_pows.push(batching_chal);
+            batching_chal *= &random_scalar;
+        }
+
+        // As T_eta(alpha, Y) is linear in its evaluations over H, we combine the
+        // latter and interpolate once.
+        let combined_evals = accumulators
+            .par_iter()
+            .zip(batching_chal_pows.par_iter())
+            .map(|(acc, chal)| {
+                vk.circuit_poly_evals(&acc.etas, acc.alpha)
+                    .into_iter()
+                    .map(|eval| eval * chal)
+                    .collect::<Vec<_>>()
+            })
+            .reduce(
+                || vec![G::ScalarField::zero(); vk.domain_h.size()],
+                |acc, evals| acc.iter().zip(evals).map(|(a, b)| *a + &b).collect(),
+            );
+        let combined_coeffs = vk.domain_h.ifft(&combined_evals);
+
+        // Check that each segment of the combined commitment is the commitment of the
+        // corresponding segment of the combined polynomial.
+        let segment_size = vk.dlog_key.comm_key.len();
+        let num_segments = accumulators
+            .iter()
+            .map(|acc| acc.c.comm.len())
+            .chain(std::iter::once(
+                (combined_coeffs.len() + segment_size - 1) / segment_size,
+            ))
+            .max()
+            .unwrap_or(0);
+        for j in 0..num_segments {
+            let (bases, scalars): (Vec<_>, Vec<_>) = accumulators
+                .iter()
+                .zip(batching_chal_pows.iter())
+                .filter_map(|(acc, chal)| acc.c.comm.get(j).map(|segment| (*segment, *chal)))
+                .unzip();
+            let combined_segment = if bases.is_empty() {
+                G::Projective::zero()
+            } else {
+                InnerProductArgPC::<G, D>::cm_commit(
+                    bases.as_slice(),
+                    scalars.as_slice(),
+                    None,
+                    None,
+                )
+                .map_err(|e| Error::IncorrectInputLength(e.to_string()))?
+            };
+
+            let coeffs = combined_coeffs
+                .iter()
+                .skip(j * segment_size)
+                .take(segment_size)
+                .cloned()
+                .collect::<Vec<_>>();
+            let expected_segment = if coeffs.is_empty() {
+                G::Projective::zero()
+            } else {
+                InnerProductArgPC::<G, D>::cm_commit(
+                    &vk.dlog_key.comm_key[..coeffs.len()],
+                    coeffs.as_slice(),
+                    None,
+                    None,
+                )
+                .map_err(|e| Error::IncorrectInputLength(e.to_string()))?
+            };
+
+            if combined_segment != expected_segment {
+                end_timer!(check_time);
+                return Ok(false);
+            }
+        }
+
+        end_timer!(check_time);
+        Ok(true)
+    }
+
+    /// Accumulate inner sumcheck items by means of the protocol described above. Returns the
+    /// new item and the accumulation proof.
+    fn accumulate_items(
+        ck: &Self::AccumulatorProverKey,
+        accumulators: Vec<Self::Item>,
+    ) -> Result<(Self::Item, Self::AccumulationProof), Error> {
+        let accumulate_time = start_timer!(|| "Accumulate inner sumcheck items");
+
+        let num_items = accumulators.len();
+        if num_items == 0 || accumulators.iter().any(|acc| acc.etas.len() != 3) {
+            end_timer!(accumulate_time);
+            return Err(Error::IncorrectInputLength(
+                "Invalid inner sumcheck items".to_owned(),
+            ));
+        }
+
+        let mut fs_rng = Self::init_fs_rng(&ck.dlog_key, accumulators.as_slice())?;
+
+        // Commit to the matrix polynomials restricted to Y = beta
+        let beta = fs_rng.squeeze_128_bits_challenge::<G::ScalarField>();
+        let matrix_polys = MATRIX_POLY_LABELS
+            .iter()
+            .zip(ck.matrix_polys(beta))
+            .map(|(label, poly)| LabeledPolynomial::new(label.to_string(), poly, None, None))
+            .collect::<Vec<_>>();
+        let (matrix_comms, matrix_rands) =
+            InnerProductArgPC::<G, D>::commit(&ck.dlog_key, &matrix_polys, None)?;
+        fs_rng.absorb(
+            &matrix_comms
+                .iter()
+                .flat_map(|comm| to_bytes!(comm.commitment()).unwrap())
+                .collect::<Vec<_>>(),
+        );
+
+        // Commit to the circuit polynomial of the new item
+        let new_alpha = fs_rng.squeeze_128_bits_challenge::<G::ScalarField>();
+        let new_etas = (0..3)
+            .map(|_| fs_rng.squeeze_128_bits_challenge::<G::ScalarField>())
+            .collect::<Vec<_>>();
+        let mut items = accumulators
+            .into_iter()
+            .chain(std::iter::once(InnerSumcheckItem::<G> {
+                alpha: new_alpha,
+                etas: new_etas,
+                c: Commitment::<G>::default(),
+            }))
+            .collect::<Vec<_>>();
+        let item_polys = items
+            .par_iter()
+            .enumerate()
+            .map(|(i, acc)| {
+                LabeledPolynomial::new(
+                    Self::item_labels(i, num_items).0,
+                    ck.circuit_poly(&acc.etas, acc.alpha),
+                    None,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        // The previous items are recommitted, so to obtain their randomnesses. Non-hiding
+        // commitments are deterministic, hence the commitments are the ones of the items if
+        // these are valid.
+        let (item_comms, item_rands) =
+            InnerProductArgPC::<G, D>::commit(&ck.dlog_key, &item_polys, None)?;
+        let new_comm = item_comms[num_items].commitment().clone();
+        fs_rng.absorb(&to_bytes!(new_comm).unwrap());
+
+        // Evaluate the matrix polynomials at the alphas
+        let alphas = items.iter().map(|acc| acc.alpha).collect::<Vec<_>>();
+        let evaluations = alphas
+            .iter()
+            .flat_map(|alpha| matrix_polys.iter().map(move |poly| poly.evaluate(*alpha)))
+            .collect::<Vec<_>>();
+        fs_rng.absorb(
+            &evaluations
+                .iter()
+                .flat_map(|val| to_bytes!(val).unwrap())
+                .collect::<Vec<_>>(),
+        );
+
+        // Open all the polynomials
+        let opening_time = start_timer!(|| "Open polynomials");
+        let query_set = Self::get_query_set(&alphas, beta);
+        let polynomials = [matrix_polys, item_polys].concat();
+        let comms = [matrix_comms.clone(), item_comms].concat();
+        let rands = [matrix_rands, item_rands].concat();
+        let pc_proof = InnerProductArgPC::<G, D>::batch_open(
+            &ck.dlog_key,
+            &polynomials,
+            &comms,
+            &query_set,
+            &mut fs_rng,
+            &rands,
+            None,
+        )
+        .map_err(|e| {
+            end_timer!(opening_time);
+            end_timer!(accumulate_time);
+            e
+        })?;
+        end_timer!(opening_time);
+
+        let mut new_acc = items.pop().unwrap();
+        new_acc.c = new_comm.clone();
+
+        let accumulation_proof = InnerSumcheckAccumulationProof::<G> {
+            commitments: vec![
+                matrix_comms
+                    .into_iter()
+                    .map(|comm| comm.commitment().clone())
+                    .collect(),
+                vec![new_comm],
+            ],
+            evaluations,
+            pc_proof,
+        };
+
+        end_timer!(accumulate_time);
+
+        Ok((new_acc, accumulation_proof))
+    }
+
+    /// Full verification of an aggregation proof for inner sumcheck items, where
+    /// `current_acc` is the accumulated item as returned by `accumulate_items()`.
+    /// Calls the succinct verifier and then decides both the new item and the dlog item
+    /// of the opening proof.
+    fn verify_accumulated_items<R: RngCore>(
+        current_acc: &Self::Item,
+        vk: &Self::AccumulatorVerifierKey,
+        previous_accumulators: Vec<Self::Item>,
+        proof: &Self::AccumulationProof,
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        let check_acc_time = start_timer!(|| "Verify inner sumcheck accumulation");
+
+        // Succinct part
+        let new_accs = Self::succinct_verify_accumulated_items(
+            &vk.dlog_key,
+            previous_accumulators.as_slice(),
+            proof,
+        )
+        .map_err(|e| {
+            end_timer!(check_acc_time);
+            e
+        })?;
+        let (new_acc, dlog_acc) = match new_accs {
+            Some(new_accs) => new_accs,
+            None => {
+                end_timer!(check_acc_time);
+                return Ok(false);
+            }
+        };
+        if &new_acc != current_acc {
+            end_timer!(check_acc_time);
+            return Ok(false);
+        }
+
+        // Hard parts: decide the new item and the dlog item
+        let hard_time = start_timer!(|| "Inner sumcheck and DLOG hard parts");
+        let result = Self::check_items::<R>(vk, &[new_acc], rng).and_then(|result| {
+            if result {
+                DLogItemAccumulator::<G, D>::check_items::<R>(&vk.dlog_key, &[dlog_acc], rng)
+            } else {
+                Ok(false)
+            }
+        });
+        end_timer!(hard_time);
+        let result = result.map_err(|e| {
+            end_timer!(check_acc_time);
+            e
+        })?;
+
+        end_timer!(check_acc_time);
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::darlin::tests::random_matrix;
+    use blake2::Blake2s;
+    use rand::thread_rng;
+
+    fn accumulation_test<G: AffineCurve, D: Digest>() -> Result<(), Error> {
+        let rng = &mut thread_rng();
+
+        // Use a segment size smaller than the domain, so to test segmentation too
+        let num_rows = 64;
+        let pp = InnerProductArgPC::<G, D>::setup(num_rows)?;
+        let (ck, vk) = InnerProductArgPC::<G, D>::trim(&pp, num_rows / 4 - 1)?;
+        let matrices = (0..3)
+            .map(|_| random_matrix::<G::ScalarField, _>(num_rows, rng))
+            .collect::<Vec<_>>();
+        let key = InnerSumcheckKey::<G>::new(&matrices[0], &matrices[1], &matrices[2], &ck)?;
+        let verifier_key =
+            InnerSumcheckKey::<G>::new(&matrices[0], &matrices[1], &matrices[2], &vk)?;
+
+        for num_items in 1..5 {
+            let items = (0..num_items)
+                .map(|_| InnerSumcheckItem::<G>::generate_random::<D, _>(&key, rng))
+                .collect::<Vec<_>>();
+            assert!(items.is_valid());
+            assert!(InnerSumcheckItemAccumulator::<G, D>::check_items(
+                &verifier_key,
+                &items,
+                rng
+            )?);
+
+            let (new_item, proof) =
+                InnerSumcheckItemAccumulator::<G, D>::accumulate_items(&key, items.clone())?;
+            assert!(new_item.is_valid());
+            assert!(
+                InnerSumcheckItemAccumulator::<G, D>::verify_accumulated_items(
+                    &new_item,
+                    &verifier_key,
+                    items.clone(),
+                    &proof,
+                    rng
+                )?
+            );
+
+            // Wrong new item
+            let mut wrong_item = new_item.clone();
+            wrong_item.alpha = G::ScalarField::rand(rng);
+            assert!(
+                !InnerSumcheckItemAccumulator::<G, D>::verify_accumulated_items(
+                    &wrong_item,
+                    &verifier_key,
+                    items.clone(),
+                    &proof,
+                    rng
+                )?
+            );
+            assert!(!InnerSumcheckItemAccumulator::<G, D>::check_items(
+                &verifier_key,
+                &[wrong_item],
+                rng
+            )?);
+
+            // Wrong evaluation
+            let mut wrong_proof = proof.clone();
+            wrong_proof.evaluations[0] += &G::ScalarField::one();
+            assert!(
+                !InnerSumcheckItemAccumulator::<G, D>::verify_accumulated_items(
+                    &new_item,
+                    &verifier_key,
+                    items,
+                    &wrong_proof,
+                    rng
+                )?
+            );
+        }
+        Ok(())
+    }
+
+    use algebra::curves::tweedle::{dee::Affine as TweedleDee, dum::Affine as TweedleDum};
+
+    #[test]
+    fn test_tweedle_inner_sumcheck_accumulate_verify() {
+        accumulation_test::<TweedleDee, Blake2s>().unwrap();
+        accumulation_test::<TweedleDum, Blake2s>().unwrap();
+    }
+}
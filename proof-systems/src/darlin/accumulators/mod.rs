@@ -12,6 +12,7 @@ use poly_commit::{ipa_pc::Proof, Error};
 use rand::RngCore;
 
 pub mod dlog;
+pub mod inner_sumcheck;
 
 /// General struct of an aggregation proof. Typically, such proof stems from an
 /// interactive oracle protocol (IOP) and a polynomial commitment scheme.
@@ -0,0 +1,744 @@
+//! The dlog/IPA polynomial commitment scheme of `poly_commit::ipa_pc`, with the algebraic
+//! random oracle `FieldBasedFiatShamirRng` in place of the digest based one, for curves
+//! with an efficient endomorphism, e.g. the Tweedle cycle. It is the native counterpart of
+//! `InnerProductArgPCGadget` of `r1cs_crypto::nizk::ipa_pc`, hence its opening proofs are
+//! verifiable in a circuit over `G::BaseField`.
+//! Keys, commitments, proofs and succinct check polynomials are the ones of
+//! `poly_commit::ipa_pc`, but the scheme is restricted to opening (segmented) commitments
+//! without degree bounds at a single point, batched with a 128 bit challenge lambda:
+//!     C = sum_i lambda^i * sum_j point^{j * segment_size} * C_{i,j}.
+//! All challenges are squeezed as endomorphism representations, so that their
+//! multiplications with points are done by `endo_mul()` in-circuit.
+//! Openings at several points are reduced to a single point by the technique of
+//! [BDFG20](https://eprint.iacr.org/2020/081): given the polynomials p_{j,i} to be opened
+//! at x_j, and the batched P_j = sum_i lambda^i * p_{j,i}, the prover commits to
+//!     h(X) = sum_j (P_j(X) - P_j(x_j)) / (X - x_j),
+//! and opens L(X) = sum_j P_j(X) / (z - x_j) - h(X) at a random z.
+use crate::darlin::fiat_shamir::FieldBasedFiatShamirRng;
+use algebra::{
+    AffineCurve, EndoMulCurve, Field, PrimeField, ProjectiveCurve, ToConstraintField, UniformRand,
+    VariableBaseMSM,
+};
+use poly_commit::{
+    ipa_pc::{CommitterKey, Proof, SuccinctCheckPolynomial, VerifierKey},
+    Error,
+};
+use primitives::crh::FieldBasedSponge;
+use rand::RngCore;
+use std::marker::PhantomData;
+
+/// The multi-point opening proof: the commitment of h(X) and the dlog opening proof of L(X)
+/// at z.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiPointProof<G: AffineCurve> {
+    /// The (segmented) commitment of h(X).
+    pub h_comm: Vec<G>,
+    /// The dlog opening proof of L(X) at z.
+    pub proof: Proof<G>,
+}
+
+pub struct AlgebraicInnerProductArgPC<G: EndoMulCurve, S: FieldBasedSponge> {
+    _group: PhantomData<G>,
+    _sponge: PhantomData<S>,
+}
+
+impl<G, S> AlgebraicInnerProductArgPC<G, S>
+where
+    G: EndoMulCurve + ToConstraintField<<G as AffineCurve>::BaseField>,
+    G::BaseField: PrimeField,
+    S: FieldBasedSponge<Data = G::BaseField>,
+{
+    fn check_segment_size(segment_size: usize) -> Result<usize, Error> {
+        if !segment_size.is_power_of_two() {
+            return Err(Error::IncorrectInputLength(format!(
+                "Segment size {} is not a power of two",
+                segment_size
+            )));
+        }
+        Ok(segment_size.trailing_zeros() as usize)
+    }
+
+    fn msm(bases: &[G], scalars: &[G::ScalarField]) -> Result<G::Projective, Error> {
+        if scalars.is_empty() {
+            return Ok(G::Projective::zero());
+        }
+        let scalars = scalars
+            .iter()
+            .map(|scalar| scalar.into_repr())
+            .collect::<Vec<_>>();
+        VariableBaseMSM::multi_scalar_mul(&bases[..scalars.len()], scalars.as_slice())
+            .map_err(|e| Error::IncorrectInputLength(e.to_string()))
+    }
+
+    fn evaluate(coeffs: &[G::ScalarField], point: G::ScalarField) -> G::ScalarField {
+        coeffs
+            .iter()
+            .rev()
+            .fold(G::ScalarField::zero(), |acc, coeff| acc * &point + coeff)
+    }
+
+    fn inner_product(a: &[G::ScalarField], b: &[G::ScalarField]) -> G::ScalarField {
+        a.iter()
+            .zip(b)
+            .fold(G::ScalarField::zero(), |acc, (a, b)| acc + &(*a * b))
+    }
+
+    /// The linear combination sum_i lambda^i * polys[i], in Horner form.
+    fn combine(polys: &[&[G::ScalarField]], lambda: G::ScalarField) -> Vec<G::ScalarField> {
+        let mut combined = Vec::new();
+        for poly in polys.iter().rev() {
+            combined.iter_mut().for_each(|c| *c *= &lambda);
+            if combined.len() < poly.len() {
+                combined.resize(poly.len(), G::ScalarField::zero());
+            }
+            for (combined, c) in combined.iter_mut().zip(poly.iter()) {
+                *combined += c;
+            }
+        }
+        combined
+    }
+
+    /// The quotient (p(X) - p(point)) / (X - point), by synthetic division.
+    fn divide_by_linear(coeffs: &[G::ScalarField], point: G::ScalarField) -> Vec<G::ScalarField> {
+        let mut quotient = vec![G::ScalarField::zero(); coeffs.len().saturating_sub(1)];
+        let mut acc = G::ScalarField::zero();
+        for i in (1..coeffs.len()).rev() {
+            acc = acc * &point + &coeffs[i];
+            quotient[i - 1] = acc;
+        }
+        quotient
+    }
+
+    /// Commit to the polynomial with coefficients `coeffs`, segment-wise, and hiding if
+    /// `rng` is given. Returns the commitments to the segments and their randomness.
+    pub fn commit(
+        ck: &CommitterKey<G>,
+        coeffs: &[G::ScalarField],
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<(Vec<G>, Vec<G::ScalarField>), Error> {
+        let segment_size = ck.comm_key.len();
+        Self::check_segment_size(segment_size)?;
+
+        let mut segments = coeffs.chunks(segment_size).collect::<Vec<_>>();
+        if segments.is_empty() {
+            segments.push(&[]);
+        }
+
+        let mut commitments = Vec::with_capacity(segments.len());
+        let mut rands = Vec::with_capacity(segments.len());
+        for segment in segments {
+            let rand = match rng.as_mut() {
+                Some(rng) => G::ScalarField::rand(rng),
+                None => G::ScalarField::zero(),
+            };
+            let mut commitment = Self::msm(ck.comm_key.as_slice(), segment)?;
+            commitment += &ck.s.mul(rand);
+            commitments.push(commitment);
+            rands.push(rand);
+        }
+
+        Ok((
+            G::Projective::batch_normalization_into_affine(commitments),
+            rands,
+        ))
+    }
+
+    /// Produce the dlog opening proof of `polys` at `point`, given the randomness `rands`
+    /// of their commitments as returned by `commit()`. The proof is hiding if `rng` is
+    /// given, which is required if the commitments are.
+    pub fn open(
+        ck: &CommitterKey<G>,
+        polys: &[Vec<G::ScalarField>],
+        rands: &[Vec<G::ScalarField>],
+        point: G::ScalarField,
+        fs_rng: &mut FieldBasedFiatShamirRng<S>,
+        mut rng: Option<&mut dyn RngCore>,
+    ) -> Result<Proof<G>, Error> {
+        let segment_size = ck.comm_key.len();
+        Self::check_segment_size(segment_size)?;
+
+        if polys.is_empty()
+            || polys.len() != rands.len()
+            || polys.iter().zip(rands.iter()).any(|(poly, rands)| {
+                rands.len() != std::cmp::max(1, (poly.len() + segment_size - 1) / segment_size)
+            })
+        {
+            return Err(Error::IncorrectInputLength(
+                "Invalid polynomials or randomness".to_owned(),
+            ));
+        }
+
+        if rng.is_none() && rands.iter().flatten().any(|rand| !rand.is_zero()) {
+            return Err(Error::IncorrectInputLength(
+                "Hiding commitments require a hiding opening proof".to_owned(),
+            ));
+        }
+
+        // Batch everything with powers of lambda and of point^segment_size
+        let (_, lambda) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let point_to_segment_size = point.pow(&[segment_size as u64]);
+        let mut combined_poly = vec![G::ScalarField::zero(); segment_size];
+        let mut combined_rand = G::ScalarField::zero();
+        let mut combined_v = G::ScalarField::zero();
+        let mut lambda_power = G::ScalarField::one();
+        for (poly, poly_rands) in polys.iter().zip(rands.iter()) {
+            let mut coeff = lambda_power;
+            for segment in poly.chunks(segment_size) {
+                for (combined, c) in combined_poly.iter_mut().zip(segment) {
+                    *combined += &(coeff * c);
+                }
+                coeff *= &point_to_segment_size;
+            }
+            let mut coeff = lambda_power;
+            for rand in poly_rands.iter() {
+                combined_rand += &(coeff * rand);
+                coeff *= &point_to_segment_size;
+            }
+            combined_v += &(lambda_power * &Self::evaluate(poly, point));
+            lambda_power *= &lambda;
+        }
+
+        let mut combined_commitment = Self::msm(ck.comm_key.as_slice(), &combined_poly)?;
+        combined_commitment += &ck.s.mul(combined_rand);
+        fs_rng.absorb(&[combined_commitment.into_affine()])?;
+        fs_rng.absorb_nonnative(&[point, combined_v])?;
+
+        // Hide with a random polynomial vanishing at point
+        let (hiding_comm, rand) = match rng.as_mut() {
+            Some(rng) => {
+                let mut hiding_poly = (0..segment_size)
+                    .map(|_| G::ScalarField::rand(rng))
+                    .collect::<Vec<_>>();
+                let hiding_poly_at_point = Self::evaluate(&hiding_poly, point);
+                hiding_poly[0] -= &hiding_poly_at_point;
+                let hiding_rand = G::ScalarField::rand(rng);
+                let mut hiding_comm = Self::msm(ck.comm_key.as_slice(), &hiding_poly)?;
+                hiding_comm += &ck.s.mul(hiding_rand);
+                let hiding_comm = hiding_comm.into_affine();
+
+                fs_rng.absorb(&[hiding_comm])?;
+                let (_, hiding_challenge) = fs_rng.squeeze_endo_challenge::<G>()?;
+                for (combined, c) in combined_poly.iter_mut().zip(hiding_poly.iter()) {
+                    *combined += &(hiding_challenge * c);
+                }
+                combined_rand += &(hiding_challenge * &hiding_rand);
+                fs_rng.absorb_nonnative(&[combined_rand])?;
+                (Some(hiding_comm), Some(combined_rand))
+            }
+            None => (None, None),
+        };
+
+        // The generator for the inner product value, h' = xi_0 * H
+        let (_, xi_0) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let h_prime = ck.h.mul(xi_0).into_affine();
+
+        // The reduction rounds
+        let mut coeffs = combined_poly;
+        let mut z = Vec::with_capacity(segment_size);
+        let mut point_power = G::ScalarField::one();
+        for _ in 0..segment_size {
+            z.push(point_power);
+            point_power *= &point;
+        }
+        let mut comm_key = ck.comm_key.clone();
+        let (mut l_vec, mut r_vec) = (vec![], vec![]);
+        while coeffs.len() > 1 {
+            let half = coeffs.len() / 2;
+            let (coeffs_l, coeffs_r) = coeffs.split_at(half);
+            let (z_l, z_r) = z.split_at(half);
+            let (key_l, key_r) = comm_key.split_at(half);
+
+            let mut l = Self::msm(key_l, coeffs_r)?;
+            l += &h_prime.mul(Self::inner_product(coeffs_r, z_l));
+            let mut r = Self::msm(key_r, coeffs_l)?;
+            r += &h_prime.mul(Self::inner_product(coeffs_l, z_r));
+            let lr = G::Projective::batch_normalization_into_affine(vec![l, r]);
+            fs_rng.absorb(lr.as_slice())?;
+            let (_, xi) = fs_rng.squeeze_endo_challenge::<G>()?;
+            let xi_inv = xi
+                .inverse()
+                .ok_or_else(|| Error::IncorrectInputLength("Zero challenge".to_owned()))?;
+
+            let new_coeffs = (0..half)
+                .map(|i| coeffs_l[i] + &(xi_inv * &coeffs_r[i]))
+                .collect();
+            let new_z = (0..half).map(|i| z_l[i] + &(xi * &z_r[i])).collect();
+            let new_comm_key = (0..half)
+                .map(|i| {
+                    let mut key = key_r[i].mul(xi);
+                    key.add_assign_mixed(&key_l[i]);
+                    key
+                })
+                .collect();
+            coeffs = new_coeffs;
+            z = new_z;
+            comm_key = G::Projective::batch_normalization_into_affine(new_comm_key);
+
+            l_vec.push(lr[0]);
+            r_vec.push(lr[1]);
+        }
+
+        Ok(Proof {
+            l_vec,
+            r_vec,
+            final_comm_key: comm_key[0],
+            c: coeffs[0],
+            hiding_comm,
+            rand,
+        })
+    }
+
+    /// Succinctly verify `proof`, opening the (segmented) `commitments` at `point` to
+    /// `values`. Returns the succinct check polynomial if the verification succeeds, which
+    /// together with `proof.final_comm_key` is the dlog item to be accumulated, and `None`
+    /// otherwise.
+    pub fn succinct_check(
+        vk: &VerifierKey<G>,
+        commitments: &[Vec<G>],
+        point: G::ScalarField,
+        values: &[G::ScalarField],
+        proof: &Proof<G>,
+        fs_rng: &mut FieldBasedFiatShamirRng<S>,
+    ) -> Result<Option<SuccinctCheckPolynomial<G::ScalarField>>, Error> {
+        let segment_size = vk.comm_key.len();
+        let log_d = Self::check_segment_size(segment_size)?;
+
+        if commitments.is_empty()
+            || commitments.len() != values.len()
+            || commitments.iter().any(|segments| segments.is_empty())
+        {
+            return Err(Error::IncorrectInputLength(
+                "Invalid commitments or values".to_owned(),
+            ));
+        }
+
+        if proof.l_vec.len() != log_d || proof.r_vec.len() != log_d {
+            return Err(Error::IncorrectInputLength(format!(
+                "Expected {} reduction rounds, found {} left and {} right cross terms",
+                log_d,
+                proof.l_vec.len(),
+                proof.r_vec.len()
+            )));
+        }
+
+        if proof.hiding_comm.is_some() != proof.rand.is_some() {
+            return Err(Error::IncorrectInputLength(
+                "Hiding commitment and randomness must be both present or absent".to_owned(),
+            ));
+        }
+
+        // Collapse the segments of each commitment and batch commitments and values with
+        // lambda, in Horner form
+        let (_, lambda) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let point_to_segment_size = point.pow(&[segment_size as u64]);
+        let mut combined_commitment = G::Projective::zero();
+        let mut combined_v = G::ScalarField::zero();
+        for (segments, value) in commitments.iter().zip(values.iter()).rev() {
+            let mut collapsed = G::Projective::zero();
+            for segment in segments.iter().rev() {
+                collapsed.mul_assign(point_to_segment_size);
+                collapsed.add_assign_mixed(segment);
+            }
+            combined_commitment.mul_assign(lambda);
+            combined_commitment += &collapsed;
+            combined_v = combined_v * &lambda + value;
+        }
+
+        fs_rng.absorb(&[combined_commitment.into_affine()])?;
+        fs_rng.absorb_nonnative(&[point, combined_v])?;
+
+        // Remove the hiding randomness
+        if let (Some(hiding_comm), Some(rand)) = (proof.hiding_comm, proof.rand) {
+            fs_rng.absorb(&[hiding_comm])?;
+            let (_, hiding_challenge) = fs_rng.squeeze_endo_challenge::<G>()?;
+            fs_rng.absorb_nonnative(&[rand])?;
+
+            combined_commitment += &hiding_comm.mul(hiding_challenge);
+            combined_commitment -= &vk.s.mul(rand);
+        }
+
+        // The commitment of the first round C' = C + v * h'
+        let (_, xi_0) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let h_prime = vk.h.mul(xi_0).into_affine();
+        let mut round_commitment = combined_commitment;
+        round_commitment += &h_prime.mul(combined_v);
+
+        // The reduction rounds: C' = C' + xi^-1 * L + xi * R
+        let mut xi_s = Vec::with_capacity(log_d);
+        for (l, r) in proof.l_vec.iter().zip(proof.r_vec.iter()) {
+            fs_rng.absorb(&[*l, *r])?;
+            let (_, xi) = fs_rng.squeeze_endo_challenge::<G>()?;
+            let xi_inv = match xi.inverse() {
+                Some(xi_inv) => xi_inv,
+                None => return Ok(None),
+            };
+
+            round_commitment += &l.mul(xi_inv);
+            round_commitment += &r.mul(xi);
+            xi_s.push(xi);
+        }
+
+        // The final check C' == c * G_f + c * h(point) * h'
+        let check_poly = SuccinctCheckPolynomial(xi_s);
+        let v_prime = check_poly.evaluate(point) * &proof.c;
+        let mut expected = proof.final_comm_key.mul(proof.c);
+        expected += &h_prime.mul(v_prime);
+
+        if round_commitment == expected {
+            Ok(Some(check_poly))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Produce the multi-point opening proof of `queries`, each of them consisting of a point
+    /// and the polynomials to be opened at it, committed non-hiding by `commit()`.
+    /// The values of the polynomials are absorbed, in the order of `queries`, before
+    /// sampling lambda.
+    pub fn multi_point_open(
+        ck: &CommitterKey<G>,
+        queries: &[(G::ScalarField, Vec<&[G::ScalarField]>)],
+        fs_rng: &mut FieldBasedFiatShamirRng<S>,
+    ) -> Result<MultiPointProof<G>, Error> {
+        let segment_size = ck.comm_key.len();
+        Self::check_segment_size(segment_size)?;
+
+        if queries.is_empty() || queries.iter().any(|(_, polys)| polys.is_empty()) {
+            return Err(Error::IncorrectInputLength("Invalid queries".to_owned()));
+        }
+
+        let values = queries
+            .iter()
+            .flat_map(|(point, polys)| polys.iter().map(move |poly| Self::evaluate(poly, *point)))
+            .collect::<Vec<_>>();
+        fs_rng.absorb_nonnative(values.as_slice())?;
+
+        // Batch the polynomials of each point, and compute h(X)
+        let (_, lambda) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let combined_polys = queries
+            .iter()
+            .map(|(point, polys)| (*point, Self::combine(polys, lambda)))
+            .collect::<Vec<_>>();
+        let mut h = Vec::new();
+        for (point, poly) in combined_polys.iter() {
+            let quotient = Self::divide_by_linear(poly, *point);
+            if h.len() < quotient.len() {
+                h.resize(quotient.len(), G::ScalarField::zero());
+            }
+            for (h, q) in h.iter_mut().zip(quotient.iter()) {
+                *h += q;
+            }
+        }
+        let (h_comm, _) = Self::commit(ck, &h, None)?;
+        fs_rng.absorb(h_comm.as_slice())?;
+
+        // Open L(X) at z
+        let (_, z) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let mut l = h.iter().map(|h| -*h).collect::<Vec<_>>();
+        for (point, poly) in combined_polys.iter() {
+            let coeff = (z - point).inverse().ok_or_else(|| {
+                Error::IncorrectInputLength("Challenge z is a query point".to_owned())
+            })?;
+            if l.len() < poly.len() {
+                l.resize(poly.len(), G::ScalarField::zero());
+            }
+            for (l, c) in l.iter_mut().zip(poly.iter()) {
+                *l += &(coeff * c);
+            }
+        }
+        let rands = vec![vec![
+            G::ScalarField::zero();
+            std::cmp::max(
+                1,
+                (l.len() + segment_size - 1) / segment_size
+            )
+        ]];
+        let proof = Self::open(ck, &[l], rands.as_slice(), z, fs_rng, None)?;
+
+        Ok(MultiPointProof { h_comm, proof })
+    }
+
+    /// Succinctly verify the multi-point opening `proof` of `queries`, each of them consisting
+    /// of a point and the (segmented) commitments to be opened at it together with their
+    /// values. Returns the succinct check polynomial of the dlog opening proof of L(X), if
+    /// the verification succeeds, and `None` otherwise.
+    pub fn multi_point_succinct_check(
+        vk: &VerifierKey<G>,
+        queries: &[(G::ScalarField, Vec<(&[G], G::ScalarField)>)],
+        proof: &MultiPointProof<G>,
+        fs_rng: &mut FieldBasedFiatShamirRng<S>,
+    ) -> Result<Option<SuccinctCheckPolynomial<G::ScalarField>>, Error> {
+        if queries.is_empty()
+            || queries.iter().any(|(_, openings)| {
+                openings.is_empty() || openings.iter().any(|(segments, _)| segments.is_empty())
+            })
+            || proof.h_comm.is_empty()
+        {
+            return Err(Error::IncorrectInputLength(
+                "Invalid queries or proof".to_owned(),
+            ));
+        }
+
+        let values = queries
+            .iter()
+            .flat_map(|(_, openings)| openings.iter().map(|(_, value)| *value))
+            .collect::<Vec<_>>();
+        fs_rng.absorb_nonnative(values.as_slice())?;
+
+        // Batch the commitments and the values of each point, segment-wise
+        let (_, lambda) = fs_rng.squeeze_endo_challenge::<G>()?;
+        let mut combined_queries = Vec::with_capacity(queries.len());
+        for (point, openings) in queries.iter() {
+            let num_segments = openings
+                .iter()
+                .map(|(segments, _)| segments.len())
+                .max()
+                .unwrap();
+            let mut combined_segments = vec![G::Projective::zero(); num_segments];
+            let mut combined_value = G::ScalarField::zero();
+            for (segments, value) in openings.iter().rev() {
+                for (k, combined) in combined_segments.iter_mut().enumerate() {
+                    combined.mul_assign(lambda);
+                    if let Some(segment) = segments.get(k) {
+                        combined.add_assign_mixed(segment);
+                    }
+                }
+                combined_value = combined_value * &lambda + value;
+            }
+            combined_queries.push((*point, combined_segments, combined_value));
+        }
+
+        fs_rng.absorb(proof.h_comm.as_slice())?;
+        let (_, z) = fs_rng.squeeze_endo_challenge::<G>()?;
+
+        // The commitment of L(X), segment-wise, and its value at z
+        let num_segments = combined_queries
+            .iter()
+            .map(|(_, segments, _)| segments.len())
+            .chain(std::iter::once(proof.h_comm.len()))
+            .max()
+            .unwrap();
+        let mut l_comm = vec![G::Projective::zero(); num_segments];
+        for (l, h) in l_comm.iter_mut().zip(proof.h_comm.iter()) {
+            *l -= &h.into_projective();
+        }
+        let mut l_value = G::ScalarField::zero();
+        for (point, segments, value) in combined_queries.iter() {
+            let coeff = match (z - point).inverse() {
+                Some(coeff) => coeff,
+                None => return Ok(None),
+            };
+            for (l, segment) in l_comm.iter_mut().zip(segments.iter()) {
+                let mut term = *segment;
+                term.mul_assign(coeff);
+                *l += &term;
+            }
+            l_value += &(coeff * value);
+        }
+
+        Self::succinct_check(
+            vk,
+            &[G::Projective::batch_normalization_into_affine(l_comm)],
+            z,
+            &[l_value],
+            &proof.proof,
+            fs_rng,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::darlin::tests::get_keys;
+    use algebra::curves::tweedle::dee::Affine as TweedleDee;
+    use blake2::Blake2s;
+    use poly_commit::{ipa_pc::InnerProductArgPC, PolynomialCommitment};
+    use primitives::TweedleFqPoseidonHash;
+    use rand::{thread_rng, Rng};
+
+    type IPA = AlgebraicInnerProductArgPC<TweedleDee, TweedleFqPoseidonHash>;
+    type FS = FieldBasedFiatShamirRng<TweedleFqPoseidonHash>;
+
+    #[test]
+    fn test_tweedle_algebraic_ipa_open_succinct_check() {
+        let rng = &mut thread_rng();
+        let segment_size = 1 << 4;
+        let params = InnerProductArgPC::<TweedleDee, Blake2s>::setup(segment_size - 1).unwrap();
+        let (ck, vk, _, _) = get_keys::<TweedleDee, TweedleDee, Blake2s>(&params, &params);
+
+        for &hiding in [false, true].iter() {
+            // Polynomials of various lengths, some of them with several segments
+            let polys = (0..3)
+                .map(|_| {
+                    (0..rng.gen_range(0..3 * segment_size))
+                        .map(|_| <TweedleDee as AffineCurve>::ScalarField::rand(rng))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let mut commitments = Vec::new();
+            let mut rands = Vec::new();
+            for poly in polys.iter() {
+                let (comms, poly_rands) = IPA::commit(
+                    &ck,
+                    poly,
+                    if hiding {
+                        Some(&mut *rng as &mut dyn RngCore)
+                    } else {
+                        None
+                    },
+                )
+                .unwrap();
+                commitments.push(comms);
+                rands.push(poly_rands);
+            }
+            let point = <TweedleDee as AffineCurve>::ScalarField::rand(rng);
+            let values = polys
+                .iter()
+                .map(|poly| IPA::evaluate(poly, point))
+                .collect::<Vec<_>>();
+
+            let proof = IPA::open(
+                &ck,
+                &polys,
+                &rands,
+                point,
+                &mut FS::init(None),
+                if hiding {
+                    Some(&mut *rng as &mut dyn RngCore)
+                } else {
+                    None
+                },
+            )
+            .unwrap();
+            assert_eq!(proof.hiding_comm.is_some(), hiding);
+
+            let check_poly = IPA::succinct_check(
+                &vk,
+                &commitments,
+                point,
+                &values,
+                &proof,
+                &mut FS::init(None),
+            )
+            .unwrap()
+            .unwrap();
+            // The final committer key is the commitment of the succinct check polynomial
+            assert_eq!(
+                IPA::msm(&ck.comm_key, &check_poly.compute_coeffs())
+                    .unwrap()
+                    .into_affine(),
+                proof.final_comm_key
+            );
+
+            // Wrong value
+            let mut wrong_values = values.clone();
+            wrong_values[0] += &<TweedleDee as AffineCurve>::ScalarField::one();
+            assert!(IPA::succinct_check(
+                &vk,
+                &commitments,
+                point,
+                &wrong_values,
+                &proof,
+                &mut FS::init(None)
+            )
+            .unwrap()
+            .is_none());
+
+            // Different random oracle
+            assert!(IPA::succinct_check(
+                &vk,
+                &commitments,
+                point,
+                &values,
+                &proof,
+                &mut FS::init(Some(&[<TweedleDee as AffineCurve>::BaseField::one()]))
+            )
+            .unwrap()
+            .is_none());
+        }
+    }
+
+    #[test]
+    fn test_tweedle_algebraic_ipa_multi_point_open_succinct_check() {
+        let rng = &mut thread_rng();
+        let segment_size = 1 << 4;
+        let params = InnerProductArgPC::<TweedleDee, Blake2s>::setup(segment_size - 1).unwrap();
+        let (ck, vk, _, _) = get_keys::<TweedleDee, TweedleDee, Blake2s>(&params, &params);
+
+        // Three points, with polynomials of various lengths
+        let queries = (0..3)
+            .map(|i| {
+                let point = <TweedleDee as AffineCurve>::ScalarField::rand(rng);
+                let polys = (0..i + 1)
+                    .map(|_| {
+                        (0..rng.gen_range(1..3 * segment_size))
+                            .map(|_| <TweedleDee as AffineCurve>::ScalarField::rand(rng))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                (point, polys)
+            })
+            .collect::<Vec<_>>();
+        let commitments = queries
+            .iter()
+            .map(|(_, polys)| {
+                polys
+                    .iter()
+                    .map(|poly| IPA::commit(&ck, poly, None).unwrap().0)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut values = queries
+            .iter()
+            .map(|(point, polys)| {
+                polys
+                    .iter()
+                    .map(|poly| IPA::evaluate(poly, *point))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let proof = IPA::multi_point_open(
+            &ck,
+            &queries
+                .iter()
+                .map(|(point, polys)| (*point, polys.iter().map(|p| p.as_slice()).collect()))
+                .collect::<Vec<_>>(),
+            &mut FS::init(None),
+        )
+        .unwrap();
+
+        let succinct_check = |values: &[Vec<<TweedleDee as AffineCurve>::ScalarField>]| {
+            let verifier_queries = queries
+                .iter()
+                .zip(commitments.iter())
+                .zip(values.iter())
+                .map(|(((point, _), comms), values)| {
+                    (
+                        *point,
+                        comms
+                            .iter()
+                            .map(|c| c.as_slice())
+                            .zip(values.iter().cloned())
+                            .collect(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            IPA::multi_point_succinct_check(&vk, &verifier_queries, &proof, &mut FS::init(None))
+                .unwrap()
+        };
+
+        let check_poly = succinct_check(&values).unwrap();
+        assert_eq!(
+            IPA::msm(&ck.comm_key, &check_poly.compute_coeffs())
+                .unwrap()
+                .into_affine(),
+            proof.proof.final_comm_key
+        );
+
+        // Wrong value
+        values[2][1] += &<TweedleDee as AffineCurve>::ScalarField::one();
+        assert!(succinct_check(&values).is_none());
+    }
+}
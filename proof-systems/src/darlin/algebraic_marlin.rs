@@ -0,0 +1,801 @@
+//! A coboundary Marlin SNARK over the dlog commitment scheme `AlgebraicInnerProductArgPC`,
+//! hence with the algebraic random oracle `FieldBasedFiatShamirRng`. It is the native
+//! counterpart of `MarlinVerifierGadget` of `r1cs_crypto::nizk::marlin`, i.e. its proofs are
+//! verifiable in a circuit over `G::BaseField`, as needed by the merge nodes of a Darlin
+//! proof tree.
+//! Given the R1CS matrices A, B, C of a circuit, and the assignment z = (x, w) of its public
+//! inputs x and witnesses w, the prover shows that (A * z) o (B * z) = C * z as follows:
+//!     1. It commits to w(X), such that z(X) = x(X) + v_X(X) * w(X) over the domain H,
+//!     and to z_A(X), z_B(X), the polynomials of A * z and B * z over H.
+//!     2. Given the challenges eta_A, eta_B, eta_C and alpha, it commits to
+//!         t(X) = sum_M eta_M * M(alpha, X),
+//!     and proves the outer sumcheck
+//!         sum_{X in H} l_alpha(X) * (eta_A * z_A + eta_B * z_B + eta_C * z_A * z_B)(X)
+//!             - t(X) * z(X) = 0,
+//!     by the coboundary polynomial g_1(X) and the quotient h_1(X).
+//!     3. Given the challenge beta, it proves the inner sumcheck over the domain K
+//!         t(beta) = sum_M eta_M * M(alpha, beta),
+//!     using the index polynomials row_M, col_M, row_col_M and val_M of the sparse
+//!     matrices, by the coboundary polynomial g_2(X) and the quotient h_2(X).
+//!     4. Given the challenge gamma, it opens all polynomials at beta, g_H * beta, gamma
+//!     and g_K * gamma by a single multi-point opening proof.
+//! Here M(X, Y) = sum_{r, c} M[r][c] * L_r(X) * L_c(Y) and l_alpha(X) = sum_{h in H}
+//! L_h(alpha) * L_h(X), with L_h the Lagrange polynomials of H.
+//! Differently from the `marlin` crate, the proofs are not zero-knowledge, and the
+//! polynomials are committed without degree bounds, which the coboundary sumchecks do not
+//! need.
+use crate::darlin::{
+    accumulators::{
+        dlog::{check_dlog_items, DLogItem},
+        inner_sumcheck::Matrix,
+    },
+    algebraic_ipa_pc::{AlgebraicInnerProductArgPC, MultiPointProof},
+    fiat_shamir::FieldBasedFiatShamirRng,
+};
+use algebra::polynomial::DensePolynomial as Polynomial;
+use algebra::{
+    batch_inversion, get_best_evaluation_domain, AffineCurve, EndoMulCurve, EvaluationDomain,
+    Field, PrimeField, ToConstraintField,
+};
+use poly_commit::{
+    ipa_pc::{Commitment, CommitterKey, VerifierKey},
+    Error,
+};
+use primitives::crh::FieldBasedSponge;
+use r1cs_core::{ConstraintSynthesizer, ConstraintSystem, Index as VarIndex, SynthesisMode};
+use rand::RngCore;
+use std::marker::PhantomData;
+
+/// The number of polynomials committed by the prover: w, z_A, z_B, t, g_1, h_1, g_2, h_2.
+pub const NUM_PROVER_POLYS: usize = 8;
+
+/// The number of index polynomials: row_M, col_M, row_col_M, val_M, for M = A, B, C.
+pub const NUM_INDEX_POLYS: usize = 12;
+
+/// The number of evaluations of a proof:
+///     w, z_A, z_B, t, g_1, h_1 at beta,
+///     g_1 at g_H * beta,
+///     g_2, h_2 and the index polynomials at gamma,
+///     g_2 at g_K * gamma.
+pub const NUM_EVALUATIONS: usize = 22;
+
+/// The sizes of a circuit and of its domains.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct IndexInfo {
+    /// The number of public inputs, including the constant one.
+    pub num_inputs: usize,
+    /// The number of witnesses.
+    pub num_witnesses: usize,
+    /// The number of constraints.
+    pub num_constraints: usize,
+    /// The size of the domain X of the public inputs.
+    pub domain_x_size: usize,
+    /// The size of the domain H, indexing the rows and the columns of the matrices.
+    pub domain_h_size: usize,
+    /// The size of the domain K, indexing the non-zero entries of the matrices.
+    pub domain_k_size: usize,
+}
+
+impl IndexInfo {
+    /// The domains X, H and K.
+    pub fn domains<F: PrimeField>(&self) -> Result<[Box<dyn EvaluationDomain<F>>; 3], Error> {
+        let domain = |size: usize| {
+            get_best_evaluation_domain::<F>(size)
+                .filter(|domain| size.is_power_of_two() && domain.size() == size)
+                .ok_or_else(|| {
+                    Error::IncorrectInputLength(format!("No evaluation domain of size {}", size))
+                })
+        };
+        Ok([
+            domain(self.domain_x_size)?,
+            domain(self.domain_h_size)?,
+            domain(self.domain_k_size)?,
+        ])
+    }
+}
+
+/// The verifier key of a circuit.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexVerifierKey<G: AffineCurve> {
+    /// The sizes of the circuit and of its domains.
+    pub index_info: IndexInfo,
+    /// The (segmented) commitments of the index polynomials, in the order row_M, col_M,
+    /// row_col_M, val_M, for M = A, B, C.
+    pub index_comms: Vec<Vec<G>>,
+}
+
+/// The prover key of a circuit.
+#[derive(Clone)]
+pub struct IndexProverKey<G: AffineCurve> {
+    /// The verifier key.
+    pub index_vk: IndexVerifierKey<G>,
+    /// The matrices A, B, C, with rows indexed by the constraints and columns by the
+    /// positions of the variables in H.
+    pub matrices: [Matrix<G::ScalarField>; 3],
+    /// The index polynomials, in the order of `index_vk.index_comms`.
+    pub index_polys: Vec<Polynomial<G::ScalarField>>,
+}
+
+/// A proof of `AlgebraicMarlin`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proof<G: AffineCurve> {
+    /// The (segmented) commitments of w, z_A, z_B, t, g_1, h_1, g_2, h_2.
+    pub commitments: Vec<Vec<G>>,
+    /// The evaluations, in the order of `NUM_EVALUATIONS`.
+    pub evaluations: Vec<G::ScalarField>,
+    /// The multi-point opening proof of the evaluations.
+    pub pc_proof: MultiPointProof<G>,
+}
+
+pub struct AlgebraicMarlin<G: EndoMulCurve, S: FieldBasedSponge> {
+    _group: PhantomData<G>,
+    _sponge: PhantomData<S>,
+}
+
+impl<G, S> AlgebraicMarlin<G, S>
+where
+    G: EndoMulCurve + ToConstraintField<<G as AffineCurve>::BaseField>,
+    G::BaseField: PrimeField,
+    S: FieldBasedSponge<Data = G::BaseField>,
+{
+    pub const PROTOCOL_NAME: &'static [u8] = b"COBOUNDARY-MARLIN-ALG-2021";
+
+    /// The position in H of the variable `index`, the public inputs being placed on the
+    /// subdomain X.
+    fn column(
+        domain_h: &dyn EvaluationDomain<G::ScalarField>,
+        domain_x_size: usize,
+        index: VarIndex,
+    ) -> Result<usize, Error> {
+        let position = match index {
+            VarIndex::Input(i) => i,
+            VarIndex::Aux(i) => domain_x_size + i,
+        };
+        domain_h
+            .reindex_by_subdomain(domain_x_size, position)
+            .map_err(|e| Error::IncorrectInputLength(e.to_string()))
+    }
+
+    /// The polynomial p(c * X).
+    fn shift(poly: &Polynomial<G::ScalarField>, c: G::ScalarField) -> Polynomial<G::ScalarField> {
+        let mut power = G::ScalarField::one();
+        Polynomial::from_coefficients_vec(
+            poly.coeffs
+                .iter()
+                .map(|coeff| {
+                    let shifted = *coeff * &power;
+                    power *= &c;
+                    shifted
+                })
+                .collect(),
+        )
+    }
+
+    /// The personalization of the random oracle, binding it to the protocol name, the hash
+    /// of the dlog committer key and the verifier key of the circuit.
+    pub fn personalization(
+        key_hash: &[u8],
+        index_vk: &IndexVerifierKey<G>,
+    ) -> Result<Vec<G::BaseField>, Error> {
+        let info = &index_vk.index_info;
+        let mut bytes = Self::PROTOCOL_NAME.to_vec();
+        bytes.extend_from_slice(key_hash);
+        for size in [
+            info.num_inputs,
+            info.num_witnesses,
+            info.num_constraints,
+            info.domain_x_size,
+            info.domain_h_size,
+            info.domain_k_size,
+        ]
+        .iter()
+        {
+            bytes.extend_from_slice(&(*size as u64).to_le_bytes());
+        }
+
+        let mut personalization =
+            <[u8] as ToConstraintField<G::BaseField>>::to_field_elements(bytes.as_slice())
+                .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
+        for comm in index_vk.index_comms.iter().flatten() {
+            personalization.append(
+                &mut comm
+                    .to_field_elements()
+                    .map_err(|e| Error::IncorrectInputLength(e.to_string()))?,
+            );
+        }
+        Ok(personalization)
+    }
+
+    /// Index `circuit`, committing to its index polynomials by `ck`.
+    pub fn index<C: ConstraintSynthesizer<G::ScalarField>>(
+        ck: &CommitterKey<G>,
+        circuit: C,
+    ) -> Result<(IndexProverKey<G>, IndexVerifierKey<G>), Error> {
+        let mut cs = ConstraintSystem::<G::ScalarField>::new(SynthesisMode::Setup);
+        circuit
+            .generate_constraints(&mut cs)
+            .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
+
+        let domain_x_size = cs.num_inputs.next_power_of_two();
+        let domain_h_size =
+            std::cmp::max(cs.num_constraints, domain_x_size + cs.num_aux).next_power_of_two();
+        let domain_h = IndexInfo {
+            num_inputs: cs.num_inputs,
+            num_witnesses: cs.num_aux,
+            num_constraints: cs.num_constraints,
+            domain_x_size,
+            domain_h_size,
+            domain_k_size: 1,
+        }
+        .domains::<G::ScalarField>()?[1]
+            .clone_and_box();
+
+        let mut matrices = [Vec::new(), Vec::new(), Vec::new()];
+        for (matrix, cs_matrix) in matrices.iter_mut().zip([&cs.at, &cs.bt, &cs.ct].iter()) {
+            for cs_row in cs_matrix.iter() {
+                let mut row = Vec::with_capacity(cs_row.len());
+                for (val, index) in cs_row.iter() {
+                    row.push((
+                        *val,
+                        Self::column(domain_h.as_ref(), domain_x_size, *index)?,
+                    ));
+                }
+                matrix.push(row);
+            }
+        }
+
+        let num_non_zero = matrices
+            .iter()
+            .map(|matrix| matrix.iter().map(|row| row.len()).sum::<usize>())
+            .max()
+            .unwrap_or(0);
+        let index_info = IndexInfo {
+            num_inputs: cs.num_inputs,
+            num_witnesses: cs.num_aux,
+            num_constraints: cs.num_constraints,
+            domain_x_size,
+            domain_h_size,
+            domain_k_size: std::cmp::max(num_non_zero, 1).next_power_of_two(),
+        };
+        let [_, domain_h, domain_k] = index_info.domains::<G::ScalarField>()?;
+
+        // The index polynomials over K. Padding entries have row = col = 1 and val = 0.
+        let h_elems = domain_h.elements().collect::<Vec<_>>();
+        let n_square_inv = domain_h.size_inv().square();
+        let mut index_polys = Vec::with_capacity(NUM_INDEX_POLYS);
+        for matrix in matrices.iter() {
+            let mut evals = vec![Vec::with_capacity(domain_k.size()); 4];
+            for (r, row) in matrix.iter().enumerate() {
+                for (val, c) in row.iter() {
+                    let row_col = h_elems[r] * &h_elems[*c];
+                    evals[0].push(h_elems[r]);
+                    evals[1].push(h_elems[*c]);
+                    evals[2].push(row_col);
+                    evals[3].push(row_col * val * &n_square_inv);
+                }
+            }
+            for (i, evals) in evals.iter_mut().enumerate() {
+                let padding = if i < 3 {
+                    G::ScalarField::one()
+                } else {
+                    G::ScalarField::zero()
+                };
+                evals.resize(domain_k.size(), padding);
+                index_polys.push(Polynomial::from_coefficients_vec(domain_k.ifft(evals)));
+            }
+        }
+
+        let index_comms = index_polys
+            .iter()
+            .map(|poly| {
+                AlgebraicInnerProductArgPC::<G, S>::commit(ck, &poly.coeffs, None).map(|c| c.0)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let index_vk = IndexVerifierKey {
+            index_info,
+            index_comms,
+        };
+        let index_pk = IndexProverKey {
+            index_vk: index_vk.clone(),
+            matrices,
+            index_polys,
+        };
+        Ok((index_pk, index_vk))
+    }
+
+    /// Produce a proof for `circuit`, which must have been indexed by `index_pk`.
+    pub fn prove<C: ConstraintSynthesizer<G::ScalarField>>(
+        ck: &CommitterKey<G>,
+        index_pk: &IndexProverKey<G>,
+        circuit: C,
+    ) -> Result<Proof<G>, Error> {
+        let info = index_pk.index_vk.index_info;
+        let [domain_x, domain_h, domain_k] = info.domains::<G::ScalarField>()?;
+
+        let mut cs = ConstraintSystem::<G::ScalarField>::new(SynthesisMode::Prove {
+            construct_matrices: false,
+        });
+        circuit
+            .generate_constraints(&mut cs)
+            .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
+        if cs.num_inputs != info.num_inputs
+            || cs.num_aux != info.num_witnesses
+            || cs.num_constraints != info.num_constraints
+        {
+            return Err(Error::IncorrectInputLength(
+                "The circuit does not match the index".to_owned(),
+            ));
+        }
+
+        // The assignment z over H, and A * z, B * z
+        let mut z_evals = vec![G::ScalarField::zero(); domain_h.size()];
+        for (i, x) in cs.input_assignment.iter().enumerate() {
+            z_evals[Self::column(domain_h.as_ref(), info.domain_x_size, VarIndex::Input(i))?] = *x;
+        }
+        for (i, w) in cs.aux_assignment.iter().enumerate() {
+            z_evals[Self::column(domain_h.as_ref(), info.domain_x_size, VarIndex::Aux(i))?] = *w;
+        }
+        let mut mz_evals = index_pk
+            .matrices
+            .iter()
+            .map(|matrix| {
+                let mut evals = matrix
+                    .iter()
+                    .map(|row| {
+                        row.iter().fold(G::ScalarField::zero(), |acc, (val, col)| {
+                            acc + &(z_evals[*col] * val)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                evals.resize(domain_h.size(), G::ScalarField::zero());
+                evals
+            })
+            .collect::<Vec<_>>();
+        let z_c_evals = mz_evals.pop().unwrap();
+        let z_b_evals = mz_evals.pop().unwrap();
+        let z_a_evals = mz_evals.pop().unwrap();
+        if z_a_evals
+            .iter()
+            .zip(z_b_evals.iter())
+            .zip(z_c_evals.iter())
+            .any(|((a, b), c)| *a * b != *c)
+        {
+            return Err(Error::IncorrectInputLength(
+                "The assignment does not satisfy the circuit".to_owned(),
+            ));
+        }
+
+        let mut x_evals = cs.input_assignment.clone();
+        x_evals.resize(domain_x.size(), G::ScalarField::zero());
+        let x_poly = Polynomial::from_coefficients_vec(domain_x.ifft(&x_evals));
+        let z_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&z_evals));
+        let (w_poly, remainder) = (&z_poly - &x_poly)
+            .divide_by_vanishing_poly(&domain_x)
+            .ok_or_else(|| Error::IncorrectInputLength("Division by v_X failed".to_owned()))?;
+        if !remainder.is_zero() {
+            return Err(Error::IncorrectInputLength(
+                "z(X) does not agree with x(X) on X".to_owned(),
+            ));
+        }
+        let z_a_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&z_a_evals));
+        let z_b_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&z_b_evals));
+
+        let mut fs_rng = FieldBasedFiatShamirRng::<S>::init(Some(
+            Self::personalization(&ck.hash, &index_pk.index_vk)?.as_slice(),
+        ));
+        fs_rng.absorb_nonnative(&cs.input_assignment[1..])?;
+
+        // First round
+        let mut commitments = Vec::with_capacity(NUM_PROVER_POLYS);
+        for poly in [&w_poly, &z_a_poly, &z_b_poly].iter() {
+            commitments.push(AlgebraicInnerProductArgPC::<G, S>::commit(ck, &poly.coeffs, None)?.0);
+        }
+        fs_rng.absorb(commitments.concat().as_slice())?;
+        let mut etas = Vec::with_capacity(3);
+        for _ in 0..3 {
+            etas.push(fs_rng.squeeze_endo_challenge::<G>()?.1);
+        }
+        let (_, alpha) = fs_rng.squeeze_endo_challenge::<G>()?;
+
+        // Second round: the outer sumcheck
+        let l_alpha_evals = domain_h.evaluate_all_lagrange_coefficients(alpha);
+        let mut t_evals = vec![G::ScalarField::zero(); domain_h.size()];
+        for (matrix, eta) in index_pk.matrices.iter().zip(etas.iter()) {
+            for (row, l_alpha) in matrix.iter().zip(l_alpha_evals.iter()) {
+                let scale = *l_alpha * eta;
+                for (val, col) in row.iter() {
+                    t_evals[*col] += &(scale * val);
+                }
+            }
+        }
+
+        // g_1 is the running sum of the outer sumcheck polynomial over H
+        let mut g_1_evals = Vec::with_capacity(domain_h.size());
+        let mut sum = G::ScalarField::zero();
+        for (((l_alpha, z_a), z_b), (t, z)) in l_alpha_evals
+            .iter()
+            .zip(z_a_evals.iter())
+            .zip(z_b_evals.iter())
+            .zip(t_evals.iter().zip(z_evals.iter()))
+        {
+            g_1_evals.push(sum);
+            sum += &(*l_alpha * &(etas[0] * z_a + &(etas[1] * z_b) + &(etas[2] * z_a * z_b))
+                - &(*t * z));
+        }
+        if !sum.is_zero() {
+            return Err(Error::IncorrectInputLength(
+                "The outer sumcheck does not vanish".to_owned(),
+            ));
+        }
+
+        let l_alpha_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&l_alpha_evals));
+        let t_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&t_evals));
+        let g_1_poly = Polynomial::from_coefficients_vec(domain_h.ifft(&g_1_evals));
+        let mut combined = Polynomial::zero();
+        combined += (etas[0], &z_a_poly);
+        combined += (etas[1], &z_b_poly);
+        combined += (etas[2], &(&z_a_poly * &z_b_poly));
+        let mut numerator = &(&l_alpha_poly * &combined) - &(&t_poly * &z_poly);
+        numerator -= &Self::shift(&g_1_poly, domain_h.group_gen());
+        numerator += &g_1_poly;
+        let (h_1_poly, remainder) = numerator
+            .divide_by_vanishing_poly(&domain_h)
+            .ok_or_else(|| Error::IncorrectInputLength("Division by v_H failed".to_owned()))?;
+        if !remainder.is_zero() {
+            return Err(Error::IncorrectInputLength(
+                "The outer sumcheck polynomial is not divisible by v_H".to_owned(),
+            ));
+        }
+
+        for poly in [&t_poly, &g_1_poly, &h_1_poly].iter() {
+            commitments.push(AlgebraicInnerProductArgPC::<G, S>::commit(ck, &poly.coeffs, None)?.0);
+        }
+        fs_rng.absorb(commitments[3..6].concat().as_slice())?;
+        let (_, beta) = fs_rng.squeeze_endo_challenge::<G>()?;
+
+        // Third round: the inner sumcheck
+        let v_h_alpha_beta = domain_h.evaluate_vanishing_polynomial(alpha)
+            * &domain_h.evaluate_vanishing_polynomial(beta);
+        let t_at_beta = t_poly.evaluate(beta);
+        let t_at_beta_by_k = t_at_beta * &domain_k.size_inv();
+        let index_polys = &index_pk.index_polys;
+
+        let mut f_evals = vec![G::ScalarField::zero(); domain_k.size()];
+        for (m, eta) in etas.iter().enumerate() {
+            let row_evals = domain_k.fft(&index_polys[4 * m].coeffs);
+            let col_evals = domain_k.fft(&index_polys[4 * m + 1].coeffs);
+            let val_evals = domain_k.fft(&index_polys[4 * m + 3].coeffs);
+            let mut denominators = row_evals
+                .iter()
+                .zip(col_evals.iter())
+                .map(|(row, col)| (alpha - row) * &(beta - col))
+                .collect::<Vec<_>>();
+            if denominators.iter().any(|d| d.is_zero()) {
+                return Err(Error::IncorrectInputLength(
+                    "Challenge alpha or beta is in H".to_owned(),
+                ));
+            }
+            batch_inversion(&mut denominators);
+            for ((f, val), d_inv) in f_evals.iter_mut().zip(val_evals).zip(denominators) {
+                *f += &(*eta * &val * &d_inv * &v_h_alpha_beta);
+            }
+        }
+
+        // g_2 is the running sum of f - t(beta) / |K| over K
+        let mut g_2_evals = Vec::with_capacity(domain_k.size());
+        let mut sum = G::ScalarField::zero();
+        for f in f_evals.iter() {
+            g_2_evals.push(sum);
+            sum += &(*f - &t_at_beta_by_k);
+        }
+        if !sum.is_zero() {
+            return Err(Error::IncorrectInputLength(
+                "The inner sumcheck does not match t(beta)".to_owned(),
+            ));
+        }
+        let g_2_poly = Polynomial::from_coefficients_vec(domain_k.ifft(&g_2_evals));
+
+        // d_M(X) = alpha * beta - alpha * col_M(X) - beta * row_M(X) + row_col_M(X), and
+        //     b(X) = d_A * d_B * d_C,
+        //     a(X) = v_H(alpha) * v_H(beta) * sum_M eta_M * val_M * prod_{N != M} d_N.
+        let d_polys = (0..3)
+            .map(|m| {
+                let mut d = Polynomial::from_coefficients_vec(vec![alpha * &beta]);
+                d += (-alpha, &index_polys[4 * m + 1]);
+                d += (-beta, &index_polys[4 * m]);
+                d += &index_polys[4 * m + 2];
+                d
+            })
+            .collect::<Vec<_>>();
+        let b_poly = &(&d_polys[0] * &d_polys[1]) * &d_polys[2];
+        let mut a_poly = Polynomial::zero();
+        for (m, eta) in etas.iter().enumerate() {
+            let others = &d_polys[(m + 1) % 3] * &d_polys[(m + 2) % 3];
+            a_poly += (*eta * &v_h_alpha_beta, &(&index_polys[4 * m + 3] * &others));
+        }
+        let mut g_2_diff = Self::shift(&g_2_poly, domain_k.group_gen());
+        g_2_diff -= &g_2_poly;
+        g_2_diff += &Polynomial::from_coefficients_vec(vec![t_at_beta_by_k]);
+        let mut numerator = &b_poly * &g_2_diff;
+        numerator -= &a_poly;
+        let (h_2_poly, remainder) = numerator
+            .divide_by_vanishing_poly(&domain_k)
+            .ok_or_else(|| Error::IncorrectInputLength("Division by v_K failed".to_owned()))?;
+        if !remainder.is_zero() {
+            return Err(Error::IncorrectInputLength(
+                "The inner sumcheck polynomial is not divisible by v_K".to_owned(),
+            ));
+        }
+
+        for poly in [&g_2_poly, &h_2_poly].iter() {
+            commitments.push(AlgebraicInnerProductArgPC::<G, S>::commit(ck, &poly.coeffs, None)?.0);
+        }
+        fs_rng.absorb(commitments[6..8].concat().as_slice())?;
+        let (_, gamma) = fs_rng.squeeze_endo_challenge::<G>()?;
+
+        // Fourth round: the multi-point opening
+        let mut gamma_polys = vec![g_2_poly.coeffs.as_slice(), h_2_poly.coeffs.as_slice()];
+        gamma_polys.extend(index_polys.iter().map(|poly| poly.coeffs.as_slice()));
+        let queries = [
+            (
+                beta,
+                [&w_poly, &z_a_poly, &z_b_poly, &t_poly, &g_1_poly, &h_1_poly]
+                    .iter()
+                    .map(|poly| poly.coeffs.as_slice())
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                domain_h.group_gen() * &beta,
+                vec![g_1_poly.coeffs.as_slice()],
+            ),
+            (gamma, gamma_polys),
+            (
+                domain_k.group_gen() * &gamma,
+                vec![g_2_poly.coeffs.as_slice()],
+            ),
+        ];
+        let evaluations = queries
+            .iter()
+            .flat_map(|(point, polys)| {
+                polys
+                    .iter()
+                    .map(move |poly| Polynomial::from_coefficients_slice(poly).evaluate(*point))
+            })
+            .collect::<Vec<_>>();
+        let pc_proof =
+            AlgebraicInnerProductArgPC::<G, S>::multi_point_open(ck, &queries, &mut fs_rng)?;
+
+        Ok(Proof {
+            commitments,
+            evaluations,
+            pc_proof,
+        })
+    }
+
+    /// Succinctly verify `proof` for `public_input`, i.e. verify it up to the dlog hard part.
+    /// Returns the dlog item to be accumulated if the verification succeeds, and `None`
+    /// otherwise.
+    pub fn succinct_verify(
+        vk: &VerifierKey<G>,
+        index_vk: &IndexVerifierKey<G>,
+        public_input: &[G::ScalarField],
+        proof: &Proof<G>,
+    ) -> Result<Option<DLogItem<G>>, Error> {
+        let info = index_vk.index_info;
+        if public_input.len() + 1 != info.num_inputs {
+            return Err(Error::IncorrectInputLength(format!(
+                "Expected {} public inputs, found {}",
+                info.num_inputs - 1,
+                public_input.len()
+            )));
+        }
+        if index_vk.index_comms.len() != NUM_INDEX_POLYS
+            || proof.commitments.len() != NUM_PROVER_POLYS
+            || proof.commitments.iter().any(|comm| comm.is_empty())
+            || proof.evaluations.len() != NUM_EVALUATIONS
+        {
+            return Err(Error::IncorrectInputLength(
+                "Invalid verifier key or proof".to_owned(),
+            ));
+        }
+        let [domain_x, domain_h, domain_k] = info.domains::<G::ScalarField>()?;
+
+        let mut fs_rng = FieldBasedFiatShamirRng::<S>::init(Some(
+            Self::personalization(&vk.hash, index_vk)?.as_slice(),
+        ));
+        fs_rng.absorb_nonnative(public_input)?;
+        fs_rng.absorb(proof.commitments[..3].concat().as_slice())?;
+        let mut etas = Vec::with_capacity(3);
+        for _ in 0..3 {
+            etas.push(fs_rng.squeeze_endo_challenge::<G>()?.1);
+        }
+        let (_, alpha) = fs_rng.squeeze_endo_challenge::<G>()?;
+        fs_rng.absorb(proof.commitments[3..6].concat().as_slice())?;
+        let (_, beta) = fs_rng.squeeze_endo_challenge::<G>()?;
+        fs_rng.absorb(proof.commitments[6..].concat().as_slice())?;
+        let (_, gamma) = fs_rng.squeeze_endo_challenge::<G>()?;
+
+        let evals = &proof.evaluations;
+        let (w, z_a, z_b, t, g_1, h_1) =
+            (evals[0], evals[1], evals[2], evals[3], evals[4], evals[5]);
+        let g_1_shifted = evals[6];
+        let (g_2, h_2) = (evals[7], evals[8]);
+        let index_evals = &evals[9..21];
+        let g_2_shifted = evals[21];
+
+        // The outer sumcheck
+        let v_h_alpha = domain_h.evaluate_vanishing_polynomial(alpha);
+        let v_h_beta = domain_h.evaluate_vanishing_polynomial(beta);
+        let mut x_evals = vec![G::ScalarField::one()];
+        x_evals.extend_from_slice(public_input);
+        let x_at_beta = domain_x
+            .evaluate_all_lagrange_coefficients(beta)
+            .iter()
+            .zip(x_evals.iter())
+            .fold(G::ScalarField::zero(), |acc, (l, x)| acc + &(*l * x));
+        let z_at_beta = x_at_beta + &(domain_x.evaluate_vanishing_polynomial(beta) * &w);
+        // l_alpha(beta) = (beta * v_H(alpha) - alpha * v_H(beta)) / (|H| * (alpha - beta))
+        let l_alpha_at_beta = match (domain_h.size_as_field_element() * &(alpha - &beta)).inverse()
+        {
+            Some(inv) => (beta * &v_h_alpha - &(alpha * &v_h_beta)) * &inv,
+            None => return Ok(None),
+        };
+        let outer_lhs = l_alpha_at_beta
+            * &(etas[0] * &z_a + &(etas[1] * &z_b) + &(etas[2] * &z_a * &z_b))
+            - &(t * &z_at_beta);
+        let outer_rhs = g_1_shifted - &g_1 + &(h_1 * &v_h_beta);
+        if outer_lhs != outer_rhs {
+            return Ok(None);
+        }
+
+        // The inner sumcheck
+        let d_evals = (0..3)
+            .map(|m| {
+                alpha * &beta - &(alpha * &index_evals[4 * m + 1]) - &(beta * &index_evals[4 * m])
+                    + &index_evals[4 * m + 2]
+            })
+            .collect::<Vec<_>>();
+        let b = d_evals[0] * &d_evals[1] * &d_evals[2];
+        let a = (0..3).fold(G::ScalarField::zero(), |acc, m| {
+            acc + &(etas[m]
+                * &index_evals[4 * m + 3]
+                * &d_evals[(m + 1) % 3]
+                * &d_evals[(m + 2) % 3])
+        }) * &v_h_alpha
+            * &v_h_beta;
+        let inner_lhs = b * &(g_2_shifted - &g_2 + &(t * &domain_k.size_inv())) - &a;
+        if inner_lhs != h_2 * &domain_k.evaluate_vanishing_polynomial(gamma) {
+            return Ok(None);
+        }
+
+        // The multi-point opening
+        let comms = &proof.commitments;
+        let mut gamma_openings = vec![(comms[6].as_slice(), g_2), (comms[7].as_slice(), h_2)];
+        gamma_openings.extend(
+            index_vk
+                .index_comms
+                .iter()
+                .map(|comm| comm.as_slice())
+                .zip(index_evals.iter().cloned()),
+        );
+        let queries = [
+            (
+                beta,
+                comms[..6]
+                    .iter()
+                    .map(|comm| comm.as_slice())
+                    .zip(evals[..6].iter().cloned())
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                domain_h.group_gen() * &beta,
+                vec![(comms[4].as_slice(), g_1_shifted)],
+            ),
+            (gamma, gamma_openings),
+            (
+                domain_k.group_gen() * &gamma,
+                vec![(comms[6].as_slice(), g_2_shifted)],
+            ),
+        ];
+        let xi_s = AlgebraicInnerProductArgPC::<G, S>::multi_point_succinct_check(
+            vk,
+            &queries,
+            &proof.pc_proof,
+            &mut fs_rng,
+        )?;
+
+        Ok(xi_s.map(|xi_s| DLogItem {
+            g_final: Commitment {
+                comm: vec![proof.pc_proof.proof.final_comm_key],
+                shifted_comm: None,
+            },
+            xi_s,
+        }))
+    }
+
+    /// Fully verify `proof` for `public_input`, deciding the dlog item of the succinct
+    /// verification.
+    pub fn verify<R: RngCore>(
+        vk: &VerifierKey<G>,
+        index_vk: &IndexVerifierKey<G>,
+        public_input: &[G::ScalarField],
+        proof: &Proof<G>,
+        rng: &mut R,
+    ) -> Result<bool, Error> {
+        match Self::succinct_verify(vk, index_vk, public_input, proof)? {
+            Some(item) => check_dlog_items(vk, &[item], rng),
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::darlin::tests::{get_keys, simple_marlin::Circuit};
+    use algebra::{curves::tweedle::dee::Affine as TweedleDee, UniformRand};
+    use blake2::Blake2s;
+    use poly_commit::{ipa_pc::InnerProductArgPC, PolynomialCommitment};
+    use primitives::TweedleFqPoseidonHash;
+    use rand::thread_rng;
+
+    type Marlin = AlgebraicMarlin<TweedleDee, TweedleFqPoseidonHash>;
+    type Fr = <TweedleDee as AffineCurve>::ScalarField;
+
+    #[test]
+    fn test_tweedle_algebraic_marlin_prove_verify() {
+        let rng = &mut thread_rng();
+
+        for &(segment_size, num_constraints) in [(1 << 4, 1 << 5), (1 << 8, 1 << 6)].iter() {
+            let params = InnerProductArgPC::<TweedleDee, Blake2s>::setup(segment_size - 1).unwrap();
+            let (ck, vk, _, _) = get_keys::<TweedleDee, TweedleDee, Blake2s>(&params, &params);
+
+            let (index_pk, index_vk) = Marlin::index(
+                &ck,
+                Circuit::<Fr> {
+                    a: None,
+                    b: None,
+                    num_constraints,
+                    num_variables: num_constraints,
+                },
+            )
+            .unwrap();
+
+            let a = Fr::rand(rng);
+            let b = Fr::rand(rng);
+            let c = a * &b;
+            let d = c * &b;
+            let proof = Marlin::prove(
+                &ck,
+                &index_pk,
+                Circuit {
+                    a: Some(a),
+                    b: Some(b),
+                    num_constraints,
+                    num_variables: num_constraints,
+                },
+            )
+            .unwrap();
+            assert_eq!(proof.commitments.len(), NUM_PROVER_POLYS);
+            assert_eq!(proof.evaluations.len(), NUM_EVALUATIONS);
+
+            assert!(Marlin::verify(&vk, &index_vk, &[c, d], &proof, rng).unwrap());
+
+            // Wrong public input
+            assert!(Marlin::succinct_verify(&vk, &index_vk, &[c, c], &proof)
+                .unwrap()
+                .is_none());
+
+            // Corrupted evaluation
+            let mut wrong_proof = proof.clone();
+            wrong_proof.evaluations[NUM_EVALUATIONS - 1] += &Fr::one();
+            assert!(!Marlin::verify(&vk, &index_vk, &[c, d], &wrong_proof, rng).unwrap());
+
+            // Corrupted commitment
+            let mut wrong_proof = proof.clone();
+            wrong_proof.commitments[0] = wrong_proof.commitments[1].clone();
+            assert!(!Marlin::verify(&vk, &index_vk, &[c, d], &wrong_proof, rng).unwrap());
+        }
+    }
+}
@@ -1,6 +1,12 @@
 //! The proof data struct (and its components) of a final Darlin, i.e. last node of
 //! our conversion/exiting chain.
-use crate::darlin::{accumulators::dlog::DLogItem, pcd::simple_marlin::MarlinProof};
+use crate::darlin::{
+    accumulators::{
+        dlog::DLogItem,
+        inner_sumcheck::{DualInnerSumcheckKey, InnerSumcheckItem},
+    },
+    pcd::simple_marlin::MarlinProof,
+};
 use algebra::{
     serialize::*, AffineCurve, PrimeField, ProjectiveCurve, SemanticallyValid, ToBits,
     ToConstraintField, UniformRand,
@@ -13,20 +19,249 @@ use rand::RngCore;
 
 /// The `FinalDarlinDeferredData`, assuming that the final node is in G1.
 /// This node serves an ordinary Marlin proof plus the dlog accumulators
-/// passed from the previous two nodes of the conversion chain.
-/// Note: Later the struct could include more elements as we might want to defer
-/// addional algebraic checks over G1::BaseField.
-#[derive(Default, Clone, Debug, Eq, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+/// passed from the previous two nodes of the conversion chain, and the
+/// inner sumcheck accumulators of these nodes, if they defer their inner
+/// sumchecks.
+/// Note: without inner sumcheck accumulators, the deferred data is serialized as in
+/// versions not supporting them, i.e. as the two dlog accumulators only. Otherwise the
+/// serialization is prefixed by `INNER_SUMCHECK_MARKER` bytes (see `inner_sumcheck_marker()`).
+#[derive(Default, Clone, Debug, Eq, PartialEq)]
 pub struct FinalDarlinDeferredData<G1: AffineCurve, G2: AffineCurve> {
     // the dlog accumulator from the previous node, a Rainbow-Marlin node in G2
     pub(crate) previous_acc: DLogItem<G2>,
     // the dlog accumulator from the pre-previous node, a Rainbow-Marlin node in G1
     pub(crate) pre_previous_acc: DLogItem<G1>,
+    // the inner sumcheck accumulators from the previous node (possibly none)
+    pub(crate) previous_inner_sumcheck_accs: Vec<InnerSumcheckItem<G2>>,
+    // the inner sumcheck accumulators from the pre-previous node (possibly none)
+    pub(crate) pre_previous_inner_sumcheck_accs: Vec<InnerSumcheckItem<G1>>,
 }
 
 impl<G1: AffineCurve, G2: AffineCurve> SemanticallyValid for FinalDarlinDeferredData<G1, G2> {
     fn is_valid(&self) -> bool {
-        self.previous_acc.is_valid() && self.pre_previous_acc.is_valid()
+        self.previous_acc.is_valid()
+            && self.pre_previous_acc.is_valid()
+            && self.previous_inner_sumcheck_accs.is_valid()
+            && self.pre_previous_inner_sumcheck_accs.is_valid()
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> FinalDarlinDeferredData<G1, G2> {
+    /// The value of the bytes prefixing the serialization of deferred data with inner
+    /// sumcheck accumulators.
+    pub const INNER_SUMCHECK_MARKER: u8 = 0xFF;
+
+    /// The deferred data from the dlog accumulators of the previous and pre-previous node,
+    /// as built by the circuit of the final node.
+    pub fn new(previous_acc: DLogItem<G2>, pre_previous_acc: DLogItem<G1>) -> Self {
+        Self {
+            previous_acc,
+            pre_previous_acc,
+            previous_inner_sumcheck_accs: vec![],
+            pre_previous_inner_sumcheck_accs: vec![],
+        }
+    }
+
+    /// Add the inner sumcheck accumulators of the previous and pre-previous node, if these
+    /// defer their inner sumchecks.
+    pub fn with_inner_sumcheck_accs(
+        mut self,
+        previous_inner_sumcheck_accs: Vec<InnerSumcheckItem<G2>>,
+        pre_previous_inner_sumcheck_accs: Vec<InnerSumcheckItem<G1>>,
+    ) -> Self {
+        self.previous_inner_sumcheck_accs = previous_inner_sumcheck_accs;
+        self.pre_previous_inner_sumcheck_accs = pre_previous_inner_sumcheck_accs;
+        self
+    }
+
+    /// The dlog accumulator from the previous node, in G2.
+    pub fn previous_acc(&self) -> &DLogItem<G2> {
+        &self.previous_acc
+    }
+
+    /// The dlog accumulator from the pre-previous node, in G1.
+    pub fn pre_previous_acc(&self) -> &DLogItem<G1> {
+        &self.pre_previous_acc
+    }
+
+    /// The inner sumcheck accumulators from the previous node, in G2.
+    pub fn previous_inner_sumcheck_accs(&self) -> &[InnerSumcheckItem<G2>] {
+        self.previous_inner_sumcheck_accs.as_slice()
+    }
+
+    /// The inner sumcheck accumulators from the pre-previous node, in G1.
+    pub fn pre_previous_inner_sumcheck_accs(&self) -> &[InnerSumcheckItem<G1>] {
+        self.pre_previous_inner_sumcheck_accs.as_slice()
+    }
+
+    pub(crate) fn has_inner_sumcheck_accs(&self) -> bool {
+        !(self.previous_inner_sumcheck_accs.is_empty()
+            && self.pre_previous_inner_sumcheck_accs.is_empty())
+    }
+
+    /// The prefix of the serialization of deferred data with inner sumcheck accumulators:
+    /// `INNER_SUMCHECK_MARKER` bytes as many as the (compressed, if `compressed`) encoding
+    /// of a point of G2. As the field element of such encoding would exceed the modulus,
+    /// this is not a valid encoding of the G_final of `previous_acc`, by which the legacy
+    /// serialization starts.
+    fn inner_sumcheck_marker(compressed: bool) -> Vec<u8> {
+        let size = if compressed {
+            G2::zero().serialized_size()
+        } else {
+            G2::zero().uncompressed_size()
+        };
+        vec![Self::INNER_SUMCHECK_MARKER; size]
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> CanonicalSerialize for FinalDarlinDeferredData<G1, G2> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        if !self.has_inner_sumcheck_accs() {
+            CanonicalSerialize::serialize(&self.previous_acc, &mut writer)?;
+            return CanonicalSerialize::serialize(&self.pre_previous_acc, &mut writer);
+        }
+
+        writer.write_all(Self::inner_sumcheck_marker(true).as_slice())?;
+        CanonicalSerialize::serialize(&self.previous_acc, &mut writer)?;
+        CanonicalSerialize::serialize(&self.pre_previous_acc, &mut writer)?;
+        CanonicalSerialize::serialize(&self.previous_inner_sumcheck_accs, &mut writer)?;
+        CanonicalSerialize::serialize(&self.pre_previous_inner_sumcheck_accs, &mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        let mut size =
+            self.previous_acc.serialized_size() + self.pre_previous_acc.serialized_size();
+        if self.has_inner_sumcheck_accs() {
+            size += Self::inner_sumcheck_marker(true).len()
+                + self.previous_inner_sumcheck_accs.serialized_size()
+                + self.pre_previous_inner_sumcheck_accs.serialized_size();
+        }
+        size
+    }
+
+    fn serialize_without_metadata<W: Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), SerializationError> {
+        if !self.has_inner_sumcheck_accs() {
+            CanonicalSerialize::serialize_without_metadata(&self.previous_acc, &mut writer)?;
+            return CanonicalSerialize::serialize_without_metadata(
+                &self.pre_previous_acc,
+                &mut writer,
+            );
+        }
+
+        writer.write_all(Self::inner_sumcheck_marker(true).as_slice())?;
+        CanonicalSerialize::serialize_without_metadata(&self.previous_acc, &mut writer)?;
+        CanonicalSerialize::serialize_without_metadata(&self.pre_previous_acc, &mut writer)?;
+        CanonicalSerialize::serialize_without_metadata(
+            &self.previous_inner_sumcheck_accs,
+            &mut writer,
+        )?;
+        CanonicalSerialize::serialize_without_metadata(
+            &self.pre_previous_inner_sumcheck_accs,
+            &mut writer,
+        )
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        if !self.has_inner_sumcheck_accs() {
+            CanonicalSerialize::serialize_uncompressed(&self.previous_acc, &mut writer)?;
+            return CanonicalSerialize::serialize_uncompressed(&self.pre_previous_acc, &mut writer);
+        }
+
+        writer.write_all(Self::inner_sumcheck_marker(false).as_slice())?;
+        CanonicalSerialize::serialize_uncompressed(&self.previous_acc, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(&self.pre_previous_acc, &mut writer)?;
+        CanonicalSerialize::serialize_uncompressed(
+            &self.previous_inner_sumcheck_accs,
+            &mut writer,
+        )?;
+        CanonicalSerialize::serialize_uncompressed(
+            &self.pre_previous_inner_sumcheck_accs,
+            &mut writer,
+        )
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        let mut size =
+            self.previous_acc.uncompressed_size() + self.pre_previous_acc.uncompressed_size();
+        if self.has_inner_sumcheck_accs() {
+            size += Self::inner_sumcheck_marker(false).len()
+                + self.previous_inner_sumcheck_accs.uncompressed_size()
+                + self.pre_previous_inner_sumcheck_accs.uncompressed_size();
+        }
+        size
+    }
+}
+
+/// Deserializes a `FinalDarlinDeferredData` from `$reader` by means of `$deserialize`, one
+/// of the functions of `CanonicalDeserialize`, given the prefix `$marker` of the
+/// serialization with inner sumcheck accumulators. Otherwise the legacy serialization,
+/// i.e. without inner sumcheck accumulators, is expected.
+macro_rules! deserialize_deferred_data {
+    ($reader:expr, $marker:expr, $deserialize:path) => {{
+        let mut reader = $reader;
+        let marker = $marker;
+        let mut prefix = vec![0u8; marker.len()];
+        reader.read_exact(prefix.as_mut_slice())?;
+
+        if prefix == marker {
+            let deferred = Self {
+                previous_acc: $deserialize(&mut reader)?,
+                pre_previous_acc: $deserialize(&mut reader)?,
+                previous_inner_sumcheck_accs: $deserialize(&mut reader)?,
+                pre_previous_inner_sumcheck_accs: $deserialize(&mut reader)?,
+            };
+            // Without inner sumcheck accumulators the legacy serialization must be used
+            if !deferred.has_inner_sumcheck_accs() {
+                return Err(SerializationError::InvalidData);
+            }
+            Ok(deferred)
+        } else {
+            // The prefix belongs to the G_final of `previous_acc`
+            let mut reader = prefix.as_slice().chain(reader);
+            Ok(Self::new(
+                $deserialize(&mut reader)?,
+                $deserialize(&mut reader)?,
+            ))
+        }
+    }};
+}
+
+impl<G1: AffineCurve, G2: AffineCurve> CanonicalDeserialize for FinalDarlinDeferredData<G1, G2> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        deserialize_deferred_data!(
+            reader,
+            Self::inner_sumcheck_marker(true),
+            CanonicalDeserialize::deserialize
+        )
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        deserialize_deferred_data!(
+            reader,
+            Self::inner_sumcheck_marker(true),
+            CanonicalDeserialize::deserialize_unchecked
+        )
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        deserialize_deferred_data!(
+            reader,
+            Self::inner_sumcheck_marker(false),
+            CanonicalDeserialize::deserialize_uncompressed
+        )
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        deserialize_deferred_data!(
+            reader,
+            Self::inner_sumcheck_marker(false),
+            CanonicalDeserialize::deserialize_uncompressed_unchecked
+        )
     }
 }
 
@@ -91,10 +326,29 @@ where
         };
 
         // Return accumulators in deferred struct
-        Self {
-            previous_acc: acc_g2,
-            pre_previous_acc: acc_g1,
-        }
+        Self::new(acc_g2, acc_g1)
+    }
+
+    // generates random FinalDarlinDeferredData with a random (but valid) inner sumcheck
+    // accumulator for each of the previous nodes, whose keys are `inner_sumcheck_keys`.
+    // For test purposes only.
+    pub fn generate_random_with_inner_sumcheck_accs<R: RngCore, D: Digest>(
+        rng: &mut R,
+        committer_key_g1: &DLogCommitterKey<G1>,
+        committer_key_g2: &DLogCommitterKey<G2>,
+        inner_sumcheck_keys: DualInnerSumcheckKey<G1, G2>,
+    ) -> Self {
+        let (key_g1, key_g2) = inner_sumcheck_keys;
+        let deferred = Self::generate_random::<R, D>(rng, committer_key_g1, committer_key_g2);
+        let previous_inner_sumcheck_acc =
+            InnerSumcheckItem::<G2>::generate_random::<D, R>(key_g2, rng);
+        let pre_previous_inner_sumcheck_acc =
+            InnerSumcheckItem::<G1>::generate_random::<D, R>(key_g1, rng);
+
+        deferred.with_inner_sumcheck_accs(
+            vec![previous_inner_sumcheck_acc],
+            vec![pre_previous_inner_sumcheck_acc],
+        )
     }
 }
 
@@ -171,6 +425,39 @@ where
         }
         fes.append(&mut xi_s_bits.to_field_elements()?);
 
+        // Convert the inner sumcheck accumulators of the previous node, if any. Their
+        // commitments consist of native field elements, while alpha and the etas are
+        // non-native: we serialize them all to bits and pack them into native field elements.
+        if !self.previous_inner_sumcheck_accs.is_empty() {
+            let mut scalars_bits = Vec::new();
+            for acc in self.previous_inner_sumcheck_accs.iter() {
+                for c in acc.c.comm.iter() {
+                    fes.append(&mut c.to_field_elements()?);
+                }
+                for fe in std::iter::once(&acc.alpha).chain(acc.etas.iter()) {
+                    scalars_bits.append(&mut fe.write_bits());
+                }
+            }
+            fes.append(&mut scalars_bits.to_field_elements()?);
+        }
+
+        // Convert the inner sumcheck accumulators of the pre-previous node, if any. Here
+        // alpha and the etas are native, while we serialize the commitments to bits and
+        // pack them into native field elements.
+        if !self.pre_previous_inner_sumcheck_accs.is_empty() {
+            let mut comms_bits = Vec::new();
+            for acc in self.pre_previous_inner_sumcheck_accs.iter() {
+                fes.push(acc.alpha);
+                fes.extend_from_slice(acc.etas.as_slice());
+                for c in acc.c.comm.iter() {
+                    for fe in c.to_field_elements()? {
+                        comms_bits.append(&mut fe.write_bits());
+                    }
+                }
+            }
+            fes.append(&mut comms_bits.to_field_elements()?);
+        }
+
         Ok(fes)
     }
 }
@@ -0,0 +1,77 @@
+//! An algebraic Fiat-Shamir random oracle, i.e. a transcript built on top of a
+//! `FieldBasedSponge`. Differently from the digest based `FiatShamirRng` of `poly_commit`,
+//! it is efficiently reproducible in a circuit over the field of the sponge, its gadget
+//! being `FiatShamirRngGadget` of `r1cs_crypto::nizk::fiat_shamir`.
+//! Elements of the sponge field are absorbed as they are, while non-native field
+//! elements are absorbed by packing their big-endian bit representation into chunks of
+//! `CAPACITY` bits each. Challenges are squeezed as `CHALLENGE_SIZE` bits, meant to be used
+//! as endomorphism representation of scalars (see `EndoMulCurve`).
+use algebra::{EndoMulCurve, FpParameters, FromBits, PrimeField, ToBits, ToConstraintField};
+use poly_commit::Error;
+use primitives::crh::FieldBasedSponge;
+
+/// The bit length of the challenges squeezed out of the random oracle.
+pub const CHALLENGE_SIZE: usize = 128;
+
+pub struct FieldBasedFiatShamirRng<S: FieldBasedSponge>(S);
+
+impl<ConstraintF, S> FieldBasedFiatShamirRng<S>
+where
+    ConstraintF: PrimeField,
+    S: FieldBasedSponge<Data = ConstraintF>,
+{
+    /// Initialize the random oracle, domain separated by `personalization` if specified.
+    pub fn init(personalization: Option<&[ConstraintF]>) -> Self {
+        Self(S::init(personalization))
+    }
+
+    /// Absorb `elems`, e.g. curve points, by means of their representation as elements
+    /// of the sponge field.
+    pub fn absorb<T: ToConstraintField<ConstraintF>>(&mut self, elems: &[T]) -> Result<(), Error> {
+        let mut fes = Vec::new();
+        for elem in elems.iter() {
+            fes.append(
+                &mut elem
+                    .to_field_elements()
+                    .map_err(|e| Error::IncorrectInputLength(e.to_string()))?,
+            );
+        }
+        self.0.absorb(fes.as_slice());
+        Ok(())
+    }
+
+    /// Absorb the non-native field elements `elems`: their big-endian bit representations
+    /// are concatenated and packed into chunks of `CAPACITY` bits.
+    pub fn absorb_nonnative<SimulationF: PrimeField>(
+        &mut self,
+        elems: &[SimulationF],
+    ) -> Result<(), Error> {
+        let bits = elems
+            .iter()
+            .flat_map(|elem| elem.write_bits())
+            .collect::<Vec<_>>();
+        let fes = bits
+            .chunks(ConstraintF::Params::CAPACITY as usize)
+            .map(|chunk| ConstraintF::read_bits(chunk.to_vec()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
+        self.0.absorb(fes.as_slice());
+        Ok(())
+    }
+
+    /// Squeeze a challenge of `CHALLENGE_SIZE` bits.
+    pub fn squeeze_128_bits_challenge(&mut self) -> Vec<bool> {
+        self.0.squeeze_bits(CHALLENGE_SIZE)
+    }
+
+    /// Squeeze a challenge of `CHALLENGE_SIZE` bits, returning both its endomorphism
+    /// representation and the equivalent scalar of `G`.
+    pub fn squeeze_endo_challenge<G: EndoMulCurve<BaseField = ConstraintF>>(
+        &mut self,
+    ) -> Result<(Vec<bool>, G::ScalarField), Error> {
+        let bits = self.squeeze_128_bits_challenge();
+        let scalar = G::endo_rep_to_scalar(bits.clone())
+            .map_err(|e| Error::IncorrectInputLength(e.to_string()))?;
+        Ok((bits, scalar))
+    }
+}
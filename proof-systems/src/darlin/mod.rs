@@ -12,15 +12,23 @@
 //!     - `pcd`: Proof carrying data from the verifier point of view.
 //!     - `proof_aggregator`: utilities for proof post-processing, such as batch
 //!     verification and aggregation of their dlog hard parts.
+//!     - `fiat_shamir`, `algebraic_ipa_pc`: an algebraic random oracle and the dlog
+//!     commitment scheme using it, whose proofs are verifiable in-circuit.
+//!     - `algebraic_marlin`: a coboundary Marlin SNARK on top of `algebraic_ipa_pc`, for
+//!     proofs which are verified in-circuit, e.g. by the merge nodes of a proof tree.
 pub mod accumulators;
+pub mod algebraic_ipa_pc;
+pub mod algebraic_marlin;
 pub mod data_structures;
 pub mod error;
+pub mod fiat_shamir;
 pub mod pcd;
 pub mod proof_aggregator;
 
 pub mod tests;
 
 use crate::darlin::{
+    accumulators::inner_sumcheck::DualInnerSumcheckKey,
     data_structures::*,
     error::FinalDarlinError,
     pcd::{
@@ -147,11 +155,13 @@ where
     }
 
     /// Fully verify a `FinalDarlinProof` from the PCDCircuit `C`, using the PCD implementation for
-    /// the FinalDarlinPCD.
+    /// the FinalDarlinPCD. The inner sumcheck keys of the previous nodes are needed only if
+    /// these defer their inner sumchecks.
     pub fn verify<R: RngCore>(
         index_vk: &FinalDarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>,
         pc_vk_g1: &DLogVerifierKey<G1>,
         pc_vk_g2: &DLogVerifierKey<G2>,
+        inner_sumcheck_keys: Option<DualInnerSumcheckKey<G1, G2>>,
         usr_ins: &[G1::ScalarField],
         proof: &FinalDarlinProof<G1, G2, D>,
         rng: &mut R,
@@ -161,6 +171,7 @@ where
         let final_darlin_pcd_vk = FinalDarlinPCDVerifierKey::<G1, G2, D> {
             final_darlin_vk: index_vk,
             dlog_vks: (pc_vk_g1, pc_vk_g2),
+            inner_sumcheck_keys,
         };
 
         let res = final_darlin_pcd.verify(&final_darlin_pcd_vk, rng)?;
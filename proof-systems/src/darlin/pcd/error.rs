@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 #[derive(Debug)]
 pub enum PCDError {
     FailedSuccinctVerification(String),
@@ -24,3 +26,74 @@ impl std::fmt::Display for PCDError {
 }
 
 impl std::error::Error for PCDError {}
+
+/// Errors of the `PCDTree` driver, locating the failure within the tree.
+#[derive(Debug)]
+pub enum PCDTreeError {
+    NoLeaves,
+    MissingVerifierKey {
+        level: usize,
+        error: PCDError,
+    },
+    FailedLeafVerification {
+        leaf: usize,
+        error: PCDError,
+    },
+    FailedPadding {
+        level: usize,
+        error: PCDError,
+    },
+    FailedMerge {
+        level: usize,
+        index: usize,
+        leaves: Range<usize>,
+        error: PCDError,
+    },
+    FailedNodeVerification {
+        level: usize,
+        index: usize,
+        leaves: Range<usize>,
+        error: PCDError,
+    },
+    FailedFinalization(PCDError),
+}
+
+impl std::fmt::Display for PCDTreeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PCDTreeError::NoLeaves => write!(f, "No leaves to build the tree from"),
+            PCDTreeError::MissingVerifierKey { level, error } => {
+                write!(f, "Unable to retrieve vk of level {}: {}", level, error)
+            }
+            PCDTreeError::FailedLeafVerification { leaf, error } => {
+                write!(f, "Verification of leaf {} failed: {}", leaf, error)
+            }
+            PCDTreeError::FailedPadding { level, error } => {
+                write!(f, "Padding of level {} failed: {}", level, error)
+            }
+            PCDTreeError::FailedMerge {
+                level,
+                index,
+                leaves,
+                error,
+            } => write!(
+                f,
+                "Merge of node {} at level {} (leaves {:?}) failed: {}",
+                index, level, leaves, error
+            ),
+            PCDTreeError::FailedNodeVerification {
+                level,
+                index,
+                leaves,
+                error,
+            } => write!(
+                f,
+                "Verification of node {} at level {} (leaves {:?}) failed: {}",
+                index, level, leaves, error
+            ),
+            PCDTreeError::FailedFinalization(err) => write!(f, "Finalization failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PCDTreeError {}
@@ -3,22 +3,32 @@
 //! Marlin proof plus the dlog accumulators of the previous and pre-previous node.
 use crate::darlin::{
     accumulators::dlog::{DLogItem, DualDLogItem, DualDLogItemAccumulator},
+    accumulators::inner_sumcheck::{
+        DualInnerSumcheckKey, InnerSumcheckItemAccumulator, OwnedInnerSumcheckKey,
+    },
     accumulators::ItemAccumulator,
     data_structures::*,
     pcd::{error::PCDError, PCD},
     FinalDarlin, FinalDarlinVerifierKey,
 };
-use algebra::{AffineCurve, ToConstraintField};
+use algebra::{serialize::*, AffineCurve, SemanticallyValid, ToConstraintField};
 use digest::Digest;
 use poly_commit::{
     ipa_pc::{Commitment, InnerProductArgPC, VerifierKey as DLogVerifierKey},
     rng::FiatShamirRng,
 };
+use rand::RngCore;
 use std::marker::PhantomData;
 
 /// As every PCD, the `FinalDarlinPCD` comes as a proof plus "statement".
 #[derive(Derivative)]
-#[derivative(Clone(bound = ""))]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
 pub struct FinalDarlinPCD<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> {
     /// A `FinalDarlinProof` is a Marlin proof plus deferred dlog accumulators
     pub final_darlin_proof: FinalDarlinProof<G1, G2, D>,
@@ -47,11 +57,22 @@ where
     }
 }
 
+impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> SemanticallyValid
+    for FinalDarlinPCD<'a, G1, G2, D>
+{
+    fn is_valid(&self) -> bool {
+        self.final_darlin_proof.is_valid() && self.usr_ins.is_valid()
+    }
+}
+
 /// To verify the PCD of a final Darlin we only need the `FinalDarlinVerifierKey` (or, the
 /// IOP verifier key) of the final circuit and the two dlog committer keys for G1 and G2.
+/// If the previous nodes defer their inner sumchecks, deciding the deferred inner sumcheck
+/// items needs the inner sumcheck keys of the circuits of these nodes too.
 pub struct FinalDarlinPCDVerifierKey<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> {
     pub final_darlin_vk: &'a FinalDarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>,
     pub dlog_vks: (&'a DLogVerifierKey<G1>, &'a DLogVerifierKey<G2>),
+    pub inner_sumcheck_keys: Option<DualInnerSumcheckKey<'a, G1, G2>>,
 }
 
 impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest>
@@ -63,6 +84,67 @@ impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest>
     }
 }
 
+/// The owned counterpart of `FinalDarlinPCDVerifierKey`, e.g. to persist the keys
+/// or to send them over the wire. The inner sumcheck keys, if any, own the R1CS matrices
+/// of the circuits.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct OwnedFinalDarlinPCDVerifierKey<G1: AffineCurve, G2: AffineCurve, D: Digest> {
+    pub final_darlin_vk: FinalDarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>,
+    pub dlog_vks: (DLogVerifierKey<G1>, DLogVerifierKey<G2>),
+    pub inner_sumcheck_keys: Option<(OwnedInnerSumcheckKey<G1>, OwnedInnerSumcheckKey<G2>)>,
+}
+
+impl<G1: AffineCurve, G2: AffineCurve, D: Digest> OwnedFinalDarlinPCDVerifierKey<G1, G2, D> {
+    /// Borrow `self` as the `FinalDarlinPCDVerifierKey` needed by the verifier.
+    pub fn as_pcd_vk(&self) -> FinalDarlinPCDVerifierKey<'_, G1, G2, D> {
+        FinalDarlinPCDVerifierKey {
+            final_darlin_vk: &self.final_darlin_vk,
+            dlog_vks: (&self.dlog_vks.0, &self.dlog_vks.1),
+            inner_sumcheck_keys: self
+                .inner_sumcheck_keys
+                .as_ref()
+                .map(|(key_g1, key_g2)| (key_g1, key_g2)),
+        }
+    }
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest>
+    From<&FinalDarlinPCDVerifierKey<'a, G1, G2, D>> for OwnedFinalDarlinPCDVerifierKey<G1, G2, D>
+{
+    fn from(vk: &FinalDarlinPCDVerifierKey<'a, G1, G2, D>) -> Self {
+        Self {
+            final_darlin_vk: vk.final_darlin_vk.clone(),
+            dlog_vks: (vk.dlog_vks.0.clone(), vk.dlog_vks.1.clone()),
+            inner_sumcheck_keys: vk
+                .inner_sumcheck_keys
+                .map(|(key_g1, key_g2)| (key_g1.to_owned_key(), key_g2.to_owned_key())),
+        }
+    }
+}
+
+impl<G1: AffineCurve, G2: AffineCurve, D: Digest> SemanticallyValid
+    for OwnedFinalDarlinPCDVerifierKey<G1, G2, D>
+{
+    fn is_valid(&self) -> bool {
+        self.final_darlin_vk.is_valid()
+            && self.dlog_vks.0.is_valid()
+            && self.dlog_vks.1.is_valid()
+            && self
+                .inner_sumcheck_keys
+                .as_ref()
+                .map_or(true, |(key_g1, key_g2)| {
+                    key_g1.dlog_key.is_valid() && key_g2.dlog_key.is_valid()
+                })
+    }
+}
+
 impl<'a, G1, G2, D> PCD for FinalDarlinPCD<'a, G1, G2, D>
 where
     G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
@@ -129,4 +211,59 @@ where
             vec![self.final_darlin_proof.deferred.previous_acc.clone()],
         ))
     }
+
+    /// Decides the dlog items, as well as the inner sumcheck items deferred by the
+    /// previous nodes, if any. The latter are decided using the inner sumcheck keys of
+    /// `vk`, whose absence is an error in such case.
+    fn hard_verify<R: RngCore>(
+        &self,
+        acc: <Self::PCDAccumulator as ItemAccumulator>::Item,
+        vk: &Self::PCDVerifierKey,
+        rng: &mut R,
+    ) -> Result<bool, PCDError> {
+        let hard_time = start_timer!(|| "Finalized Darlin hard verifier");
+
+        let result =
+            DualDLogItemAccumulator::<G1, G2, D>::check_items::<R>(vk.as_ref(), &[acc], rng)
+                .map_err(|e| {
+                    end_timer!(hard_time);
+                    PCDError::FailedHardVerification(e.to_string())
+                })?;
+        if !result {
+            end_timer!(hard_time);
+            return Ok(false);
+        }
+
+        let deferred = &self.final_darlin_proof.deferred;
+        if !deferred.has_inner_sumcheck_accs() {
+            end_timer!(hard_time);
+            return Ok(true);
+        }
+
+        let (key_g1, key_g2) = vk.inner_sumcheck_keys.ok_or_else(|| {
+            end_timer!(hard_time);
+            PCDError::FailedHardVerification("Missing inner sumcheck keys".to_owned())
+        })?;
+
+        let result = InnerSumcheckItemAccumulator::<G1, D>::check_items::<R>(
+            key_g1,
+            deferred.pre_previous_inner_sumcheck_accs.as_slice(),
+            rng,
+        )
+        .and_then(|result| {
+            if result {
+                InnerSumcheckItemAccumulator::<G2, D>::check_items::<R>(
+                    key_g2,
+                    deferred.previous_inner_sumcheck_accs.as_slice(),
+                    rng,
+                )
+            } else {
+                Ok(false)
+            }
+        })
+        .map_err(|e| PCDError::FailedHardVerification(e.to_string()));
+
+        end_timer!(hard_time);
+        result
+    }
 }
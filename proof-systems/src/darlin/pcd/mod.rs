@@ -2,7 +2,8 @@
 //! following basic elements:
 //!     - trait for recursive circuits,
 //!     - verifier trait for proof carrying data, and their implementation
-//!     for SimpleMarlin and FinalDarlin PCDs.
+//!     for SimpleMarlin and FinalDarlin PCDs,
+//!     - a driver for merging a set of PCDs in a tree-like fashion.
 use crate::darlin::{
     accumulators::{
         dlog::{DualDLogItem, DualDLogItemAccumulator},
@@ -11,11 +12,12 @@ use crate::darlin::{
     data_structures::FinalDarlinDeferredData,
     pcd::{
         error::PCDError,
+        final_darlin::OwnedFinalDarlinPCDVerifierKey,
         final_darlin::{FinalDarlinPCD, FinalDarlinPCDVerifierKey},
         simple_marlin::{SimpleMarlinPCD, SimpleMarlinPCDVerifierKey},
     },
 };
-use algebra::{AffineCurve, ToConstraintField, UniformRand};
+use algebra::{serialize::*, AffineCurve, SemanticallyValid, ToConstraintField, UniformRand};
 use digest::Digest;
 use poly_commit::{
     ipa_pc::{
@@ -31,6 +33,7 @@ use std::fmt::Debug;
 pub mod error;
 pub mod final_darlin;
 pub mod simple_marlin;
+pub mod tree;
 
 /// Configuration parameters for the PCD scheme: for now, just the size of the
 /// committer key to be used throughout the PCD scheme.
@@ -139,7 +142,12 @@ pub trait PCD: Sized + Send + Sync {
 }
 
 #[derive(Derivative)]
-#[derivative(Clone(bound = ""))]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
 /// Achieve polymorphism for PCD via an enumerable. This provides nice APIs for
 /// the proof aggregation implementation and testing.
 pub enum GeneralPCD<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> {
@@ -147,6 +155,122 @@ pub enum GeneralPCD<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> {
     FinalDarlin(FinalDarlinPCD<'a, G1, G2, D>),
 }
 
+/// A `GeneralPCD` is serialized as a tag byte, identifying the type of the PCD,
+/// followed by the serialization of the PCD itself.
+pub const SIMPLE_MARLIN_PCD_TAG: u8 = 0;
+pub const FINAL_DARLIN_PCD_TAG: u8 = 1;
+
+impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> CanonicalSerialize
+    for GeneralPCD<'a, G1, G2, D>
+{
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        match self {
+            Self::SimpleMarlin(simple_marlin) => {
+                CanonicalSerialize::serialize(&SIMPLE_MARLIN_PCD_TAG, &mut writer)?;
+                CanonicalSerialize::serialize(simple_marlin, &mut writer)
+            }
+            Self::FinalDarlin(final_darlin) => {
+                CanonicalSerialize::serialize(&FINAL_DARLIN_PCD_TAG, &mut writer)?;
+                CanonicalSerialize::serialize(final_darlin, &mut writer)
+            }
+        }
+    }
+
+    fn serialized_size(&self) -> usize {
+        1 + match self {
+            Self::SimpleMarlin(simple_marlin) => simple_marlin.serialized_size(),
+            Self::FinalDarlin(final_darlin) => final_darlin.serialized_size(),
+        }
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        match self {
+            Self::SimpleMarlin(simple_marlin) => {
+                CanonicalSerialize::serialize_uncompressed(&SIMPLE_MARLIN_PCD_TAG, &mut writer)?;
+                CanonicalSerialize::serialize_uncompressed(simple_marlin, &mut writer)
+            }
+            Self::FinalDarlin(final_darlin) => {
+                CanonicalSerialize::serialize_uncompressed(&FINAL_DARLIN_PCD_TAG, &mut writer)?;
+                CanonicalSerialize::serialize_uncompressed(final_darlin, &mut writer)
+            }
+        }
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        1 + match self {
+            Self::SimpleMarlin(simple_marlin) => simple_marlin.uncompressed_size(),
+            Self::FinalDarlin(final_darlin) => final_darlin.uncompressed_size(),
+        }
+    }
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> CanonicalDeserialize
+    for GeneralPCD<'a, G1, G2, D>
+{
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        match u8::deserialize(&mut reader)? {
+            SIMPLE_MARLIN_PCD_TAG => Ok(Self::SimpleMarlin(CanonicalDeserialize::deserialize(
+                &mut reader,
+            )?)),
+            FINAL_DARLIN_PCD_TAG => Ok(Self::FinalDarlin(CanonicalDeserialize::deserialize(
+                &mut reader,
+            )?)),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        match u8::deserialize_unchecked(&mut reader)? {
+            SIMPLE_MARLIN_PCD_TAG => Ok(Self::SimpleMarlin(
+                CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+            )),
+            FINAL_DARLIN_PCD_TAG => Ok(Self::FinalDarlin(
+                CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+            )),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        match u8::deserialize_uncompressed(&mut reader)? {
+            SIMPLE_MARLIN_PCD_TAG => Ok(Self::SimpleMarlin(
+                CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+            )),
+            FINAL_DARLIN_PCD_TAG => Ok(Self::FinalDarlin(
+                CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+            )),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+
+    #[inline]
+    fn deserialize_uncompressed_unchecked<R: Read>(
+        mut reader: R,
+    ) -> Result<Self, SerializationError> {
+        match u8::deserialize_uncompressed_unchecked(&mut reader)? {
+            SIMPLE_MARLIN_PCD_TAG => Ok(Self::SimpleMarlin(
+                CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?,
+            )),
+            FINAL_DARLIN_PCD_TAG => Ok(Self::FinalDarlin(
+                CanonicalDeserialize::deserialize_uncompressed_unchecked(&mut reader)?,
+            )),
+            _ => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<'a, G1: AffineCurve, G2: AffineCurve, D: Digest> SemanticallyValid
+    for GeneralPCD<'a, G1, G2, D>
+{
+    fn is_valid(&self) -> bool {
+        match self {
+            Self::SimpleMarlin(simple_marlin) => simple_marlin.is_valid(),
+            Self::FinalDarlin(final_darlin) => final_darlin.is_valid(),
+        }
+    }
+}
+
 // Testing functions
 impl<'a, G1, G2, D> GeneralPCD<'a, G1, G2, D>
 where
@@ -195,6 +319,10 @@ where
 /// with a standard Marlin Verifier key. Let's introduce a new type just to be clean.
 pub type DualPCDVerifierKey<'a, G1, G2, D> = FinalDarlinPCDVerifierKey<'a, G1, G2, D>;
 
+/// The owned counterpart of `DualPCDVerifierKey`, e.g. for a verifier service accepting
+/// both kinds of `GeneralPCD`.
+pub type OwnedDualPCDVerifierKey<G1, G2, D> = OwnedFinalDarlinPCDVerifierKey<G1, G2, D>;
+
 impl<'a, G1, G2, D> PCD for GeneralPCD<'a, G1, G2, D>
 where
     G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
@@ -221,4 +349,22 @@ where
             Self::FinalDarlin(final_darlin) => final_darlin.succinct_verify(vk),
         }
     }
+
+    fn hard_verify<R: RngCore>(
+        &self,
+        acc: <Self::PCDAccumulator as ItemAccumulator>::Item,
+        vk: &Self::PCDVerifierKey,
+        rng: &mut R,
+    ) -> Result<bool, PCDError> {
+        match self {
+            Self::SimpleMarlin(_) => <Self::PCDAccumulator as ItemAccumulator>::check_items::<R>(
+                vk.as_ref(),
+                &[acc],
+                rng,
+            )
+            .map_err(|e| PCDError::FailedHardVerification(e.to_string())),
+            // Decides the deferred inner sumchecks too
+            Self::FinalDarlin(final_darlin) => final_darlin.hard_verify(acc, vk, rng),
+        }
+    }
 }
@@ -77,7 +77,13 @@ impl<G: AffineCurve, D: Digest> SemanticallyValid for MarlinProof<G, D> {
 }
 
 #[derive(Derivative)]
-#[derivative(Clone(bound = ""))]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
 pub struct SimpleMarlinPCD<'a, G: AffineCurve, D: Digest> {
     pub proof: MarlinProof<G, D>,
     pub usr_ins: Vec<G::ScalarField>,
@@ -104,6 +110,12 @@ where
     }
 }
 
+impl<'a, G: AffineCurve, D: Digest> SemanticallyValid for SimpleMarlinPCD<'a, G, D> {
+    fn is_valid(&self) -> bool {
+        self.proof.is_valid() && self.usr_ins.is_valid()
+    }
+}
+
 /// To verify the PCD of a simple Marlin we only need the `MarlinVerifierKey` (or, the
 /// IOP verifier key) of the circuit, and the two dlog committer keys for G1 and G2.
 pub struct SimpleMarlinPCDVerifierKey<'a, G: AffineCurve, D: Digest>(
@@ -119,6 +131,42 @@ impl<'a, G: AffineCurve, D: Digest> AsRef<DLogVerifierKey<G>>
     }
 }
 
+/// The owned counterpart of `SimpleMarlinPCDVerifierKey`, e.g. to persist the keys
+/// or to send them over the wire.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Debug(bound = ""),
+    Eq(bound = ""),
+    PartialEq(bound = "")
+)]
+#[derive(CanonicalSerialize, CanonicalDeserialize)]
+pub struct OwnedSimpleMarlinPCDVerifierKey<G: AffineCurve, D: Digest>(
+    pub MarlinVerifierKey<G::ScalarField, InnerProductArgPC<G, D>>,
+    pub DLogVerifierKey<G>,
+);
+
+impl<G: AffineCurve, D: Digest> OwnedSimpleMarlinPCDVerifierKey<G, D> {
+    /// Borrow `self` as the `SimpleMarlinPCDVerifierKey` needed by the verifier.
+    pub fn as_pcd_vk(&self) -> SimpleMarlinPCDVerifierKey<'_, G, D> {
+        SimpleMarlinPCDVerifierKey(&self.0, &self.1)
+    }
+}
+
+impl<'a, G: AffineCurve, D: Digest> From<&SimpleMarlinPCDVerifierKey<'a, G, D>>
+    for OwnedSimpleMarlinPCDVerifierKey<G, D>
+{
+    fn from(vk: &SimpleMarlinPCDVerifierKey<'a, G, D>) -> Self {
+        Self(vk.0.clone(), vk.1.clone())
+    }
+}
+
+impl<G: AffineCurve, D: Digest> SemanticallyValid for OwnedSimpleMarlinPCDVerifierKey<G, D> {
+    fn is_valid(&self) -> bool {
+        self.0.is_valid() && self.1.is_valid()
+    }
+}
+
 impl<'a, G, D> PCD for SimpleMarlinPCD<'a, G, D>
 where
     G: AffineCurve,
@@ -0,0 +1,219 @@
+//! A driver for tree-like PCD schemes: a set of base PCDs (the leaves) is merged pairwise,
+//! level by level, until a single node is left, which is then wrapped into a FinalDarlinPCD
+//! by a finalizing node. The nodes of a level are independent of each other, and hence are
+//! proven in parallel.
+//! How a node is proven, i.e. the recursive circuits, their proving keys and the verifier
+//! keys of each level, is left to a `PCDTreeProver`.
+use crate::darlin::pcd::{
+    error::{PCDError, PCDTreeError},
+    final_darlin::FinalDarlinPCD,
+    PCD,
+};
+use algebra::AffineCurve;
+use digest::Digest;
+use rand::thread_rng;
+use rayon::prelude::*;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// The node prover of a `PCDTree`. The leaves of the tree are at level 0, and a merger
+/// node of level `level + 1` is proven from two nodes of level `level`.
+pub trait PCDTreeProver<'a, G1: AffineCurve, G2: AffineCurve, D: Digest + 'a>: Sync {
+    /// The PCD of a node of the tree (leaves and mergers).
+    type NodePCD: PCD;
+
+    /// Return the verifier key of the nodes at `level`.
+    fn verifier_key(
+        &self,
+        level: usize,
+    ) -> Result<<Self::NodePCD as PCD>::PCDVerifierKey, PCDError>;
+
+    /// Return a node at `level`, to be merged with the last node of the level if their
+    /// number is odd.
+    fn padding(&self, level: usize) -> Result<Self::NodePCD, PCDError>;
+
+    /// Prove a node at level `level + 1`, merging the nodes `left` and `right` of
+    /// `level`, whose verifier key is `vk`.
+    fn merge(
+        &self,
+        level: usize,
+        vk: &<Self::NodePCD as PCD>::PCDVerifierKey,
+        left: Self::NodePCD,
+        right: Self::NodePCD,
+    ) -> Result<Self::NodePCD, PCDError>;
+
+    /// Prove the final node of the tree from `root`, the only node of `level`.
+    fn finalize(
+        &self,
+        level: usize,
+        vk: &<Self::NodePCD as PCD>::PCDVerifierKey,
+        root: Self::NodePCD,
+    ) -> Result<FinalDarlinPCD<'a, G1, G2, D>, PCDError>;
+}
+
+/// Schedules the nodes of a PCD tree over a set of base PCDs.
+pub struct PCDTree<'a, G1, G2, D, P>
+where
+    G1: AffineCurve,
+    G2: AffineCurve,
+    D: Digest + 'a,
+    P: PCDTreeProver<'a, G1, G2, D>,
+{
+    prover: P,
+    _lifetime: PhantomData<&'a ()>,
+    _g1: PhantomData<G1>,
+    _g2: PhantomData<G2>,
+    _digest: PhantomData<D>,
+}
+
+impl<'a, G1, G2, D, P> PCDTree<'a, G1, G2, D, P>
+where
+    G1: AffineCurve,
+    G2: AffineCurve,
+    D: Digest + 'a,
+    P: PCDTreeProver<'a, G1, G2, D>,
+    <P::NodePCD as PCD>::PCDVerifierKey: Sync,
+{
+    pub fn new(prover: P) -> Self {
+        Self {
+            prover,
+            _lifetime: PhantomData,
+            _g1: PhantomData,
+            _g2: PhantomData,
+            _digest: PhantomData,
+        }
+    }
+
+    /// The range of leaves below the node at position `index` of `level`. Nodes which
+    /// are (or descend only from) padding have an empty range.
+    pub fn leaves_below(level: usize, index: usize, num_leaves: usize) -> Range<usize> {
+        let start = (index << level).min(num_leaves);
+        let end = ((index + 1) << level).min(num_leaves);
+        start..end
+    }
+
+    /// Fully verify `nodes` against `vk`, in parallel. Returns the lowest index of the
+    /// failing nodes, if any, together with its error.
+    fn find_failing_node(
+        nodes: &[P::NodePCD],
+        vk: &<P::NodePCD as PCD>::PCDVerifierKey,
+    ) -> Option<(usize, PCDError)> {
+        nodes
+            .par_iter()
+            .map(|node| node.verify(vk, &mut thread_rng()))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .enumerate()
+            .find_map(|(index, result)| match result {
+                Ok(true) => None,
+                Ok(false) => Some((
+                    index,
+                    PCDError::FailedHardVerification("Hard check failed".to_owned()),
+                )),
+                Err(error) => Some((index, error)),
+            })
+    }
+
+    /// Fully verify the `leaves`, then merge them up to a single node and finalize it.
+    /// Every merged node is fully verified against the verifier key of its level before
+    /// being merged again (or finalized).
+    /// If more nodes of a level fail, the error reports the one with the lowest index,
+    /// together with the leaves below it.
+    pub fn prove(
+        &self,
+        leaves: Vec<P::NodePCD>,
+    ) -> Result<FinalDarlinPCD<'a, G1, G2, D>, PCDTreeError> {
+        let tree_time = start_timer!(|| "Prove PCD tree");
+
+        let num_leaves = leaves.len();
+        if num_leaves == 0 {
+            end_timer!(tree_time);
+            return Err(PCDTreeError::NoLeaves);
+        }
+
+        let mut vk = self
+            .prover
+            .verifier_key(0)
+            .map_err(|error| PCDTreeError::MissingVerifierKey { level: 0, error })?;
+
+        let verification_time = start_timer!(|| "Verify leaves");
+        let failed_leaf = Self::find_failing_node(&leaves, &vk);
+        end_timer!(verification_time);
+
+        if let Some((leaf, error)) = failed_leaf {
+            end_timer!(tree_time);
+            return Err(PCDTreeError::FailedLeafVerification { leaf, error });
+        }
+
+        let mut level = 0;
+        let mut nodes = leaves;
+        while nodes.len() > 1 {
+            let level_time = start_timer!(|| format!("Merge nodes of level {}", level));
+
+            if nodes.len() % 2 == 1 {
+                let padding = self
+                    .prover
+                    .padding(level)
+                    .map_err(|error| PCDTreeError::FailedPadding { level, error })?;
+                nodes.push(padding);
+            }
+
+            let mut pairs = Vec::with_capacity(nodes.len() / 2);
+            let mut nodes_iter = nodes.into_iter();
+            while let (Some(left), Some(right)) = (nodes_iter.next(), nodes_iter.next()) {
+                pairs.push((left, right));
+            }
+
+            let prover = &self.prover;
+            nodes = pairs
+                .into_par_iter()
+                .map(|(left, right)| prover.merge(level, &vk, left, right))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .enumerate()
+                .map(|(index, result)| {
+                    result.map_err(|error| PCDTreeError::FailedMerge {
+                        level: level + 1,
+                        index,
+                        leaves: Self::leaves_below(level + 1, index, num_leaves),
+                        error,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            level += 1;
+            vk = self
+                .prover
+                .verifier_key(level)
+                .map_err(|error| PCDTreeError::MissingVerifierKey { level, error })?;
+
+            // Verify the new nodes, so that an invalid merge is located here rather than
+            // when merging the node again
+            let verification_time = start_timer!(|| format!("Verify nodes of level {}", level));
+            let failed_node = Self::find_failing_node(&nodes, &vk);
+            end_timer!(verification_time);
+
+            if let Some((index, error)) = failed_node {
+                end_timer!(level_time);
+                end_timer!(tree_time);
+                return Err(PCDTreeError::FailedNodeVerification {
+                    level,
+                    index,
+                    leaves: Self::leaves_below(level, index, num_leaves),
+                    error,
+                });
+            }
+
+            end_timer!(level_time);
+        }
+
+        let root = nodes.pop().unwrap();
+        let final_pcd = self
+            .prover
+            .finalize(level, &vk, root)
+            .map_err(PCDTreeError::FailedFinalization);
+
+        end_timer!(tree_time);
+        final_pcd
+    }
+}
@@ -1,8 +1,13 @@
 //! Utilities for proof post-processing of `GeneralPCD`, i.e. SimpleMarlin and
-//! FinalDarlin PCD, using batch verification and aggregation of their dlog hard parts.
+//! FinalDarlin PCD, using batch verification and aggregation of their dlog hard parts,
+//! and of the inner sumchecks deferred by FinalDarlin PCDs.
 use crate::darlin::{
     accumulators::{
         dlog::{DLogItem, DLogItemAccumulator},
+        inner_sumcheck::{
+            DualInnerSumcheckKey, InnerSumcheckAccumulation, InnerSumcheckItem,
+            InnerSumcheckItemAccumulator, InnerSumcheckKey,
+        },
         AccumulationProof, ItemAccumulator,
     },
     pcd::{DualPCDVerifierKey, GeneralPCD, PCD},
@@ -50,6 +55,7 @@ where
             let vk = DualPCDVerifierKey::<G1, G2, D> {
                 final_darlin_vk: vk,
                 dlog_vks: (g1_ck, g2_ck),
+                inner_sumcheck_keys: None,
             };
             // No need to trim the vk here to the specific segment size used
             // to generate the proof for this pcd, as the IPA succinct_check
@@ -81,9 +87,178 @@ where
     }
 }
 
+/// The accumulations of the inner sumcheck items deferred by the FinalDarlin PCDs, over
+/// G1 (from the pre-previous nodes) and G2 (from the previous nodes). There is one
+/// accumulation for each distinct inner sumcheck key of the PCDs deferring items, in
+/// order of first appearance among the PCDs.
+pub type DualInnerSumcheckAccumulation<G1, G2> = (
+    Vec<InnerSumcheckAccumulation<G1>>,
+    Vec<InnerSumcheckAccumulation<G2>>,
+);
+
+/// The inner sumcheck items deferred by a set of PCDs, grouped by the key of their circuit.
+type InnerSumcheckItemsByKey<'a, G> = Vec<(&'a InnerSumcheckKey<'a, G>, Vec<InnerSumcheckItem<G>>)>;
+
+/// Add `items` to the group of `key` in `groups`, creating it if needed.
+fn add_inner_sumcheck_items<'a, G: AffineCurve>(
+    groups: &mut InnerSumcheckItemsByKey<'a, G>,
+    key: &'a InnerSumcheckKey<'a, G>,
+    items: &[InnerSumcheckItem<G>],
+) {
+    if items.is_empty() {
+        return;
+    }
+    match groups
+        .iter_mut()
+        .find(|(group_key, _)| std::ptr::eq(*group_key, key) || *group_key == key)
+    {
+        Some((_, group_items)) => group_items.extend_from_slice(items),
+        None => groups.push((key, items.to_vec())),
+    }
+}
+
+/// Collect the inner sumcheck items deferred by the FinalDarlin PCDs among `pcds`, over
+/// G1 (from the pre-previous nodes) and G2 (from the previous nodes), grouped by the keys
+/// `inner_sumcheck_keys` of the PCDs deferring them, in order of first appearance.
+/// In case of failure, returns the indices of the PCDs deferring items without keys.
+fn get_inner_sumcheck_accumulators<'a, G1, G2, D: Digest>(
+    pcds: &[GeneralPCD<G1, G2, D>],
+    inner_sumcheck_keys: &[Option<DualInnerSumcheckKey<'a, G1, G2>>],
+) -> Result<
+    (
+        InnerSumcheckItemsByKey<'a, G1>,
+        InnerSumcheckItemsByKey<'a, G2>,
+    ),
+    Option<Vec<usize>>,
+>
+where
+    G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
+    G2: AffineCurve<BaseField = <G1 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G1 as AffineCurve>::ScalarField>,
+{
+    if pcds.len() != inner_sumcheck_keys.len() {
+        return Err(None);
+    }
+
+    let mut accs_g1 = Vec::new();
+    let mut accs_g2 = Vec::new();
+    let mut failing_indices = Vec::new();
+    for (i, (pcd, keys)) in pcds
+        .iter()
+        .zip(inner_sumcheck_keys.iter().copied())
+        .enumerate()
+    {
+        let deferred = match pcd {
+            GeneralPCD::FinalDarlin(final_darlin) => &final_darlin.final_darlin_proof.deferred,
+            _ => continue,
+        };
+        if !deferred.has_inner_sumcheck_accs() {
+            continue;
+        }
+        match keys {
+            Some((key_g1, key_g2)) => {
+                add_inner_sumcheck_items(
+                    &mut accs_g1,
+                    key_g1,
+                    deferred.pre_previous_inner_sumcheck_accs(),
+                );
+                add_inner_sumcheck_items(
+                    &mut accs_g2,
+                    key_g2,
+                    deferred.previous_inner_sumcheck_accs(),
+                );
+            }
+            None => failing_indices.push(i),
+        }
+    }
+
+    if failing_indices.is_empty() {
+        Ok((accs_g1, accs_g2))
+    } else {
+        Err(Some(failing_indices))
+    }
+}
+
+/// Accumulate the inner sumcheck items `accs` of the circuit of `key`. Returns the
+/// accumulation together with the dlog item of its opening proof, to be accumulated
+/// with the dlog items of the PCDs.
+fn accumulate_inner_sumcheck_items<G: AffineCurve, D: Digest>(
+    key: &InnerSumcheckKey<G>,
+    accs: Vec<InnerSumcheckItem<G>>,
+) -> Result<(InnerSumcheckAccumulation<G>, DLogItem<G>), Option<Vec<usize>>> {
+    let (new_acc, proof) =
+        InnerSumcheckItemAccumulator::<G, D>::accumulate_items(key, accs.clone())
+            .map_err(|_| None)?;
+    let (_, dlog_acc) = InnerSumcheckItemAccumulator::<G, D>::succinct_verify_accumulated_items(
+        &key.dlog_key,
+        accs.as_slice(),
+        &proof,
+    )
+    .map_err(|_| None)?
+    .ok_or(None)?;
+
+    Ok(((new_acc, proof), dlog_acc))
+}
+
+/// Succinctly verify the `accumulation` of the inner sumcheck items `accs` of the circuit
+/// of `key`, and decide its new item. Returns the verdict, and the dlog item of the opening
+/// proof if the verdict is positive, to be decided with the dlog items of the PCDs.
+fn verify_inner_sumcheck_accumulation<G: AffineCurve, D: Digest, R: RngCore>(
+    key: &InnerSumcheckKey<G>,
+    accs: Vec<InnerSumcheckItem<G>>,
+    accumulation: &InnerSumcheckAccumulation<G>,
+    rng: &mut R,
+) -> Result<(bool, Option<DLogItem<G>>), Option<Vec<usize>>> {
+    let (current_acc, proof) = accumulation;
+
+    let (new_acc, dlog_acc) =
+        match InnerSumcheckItemAccumulator::<G, D>::succinct_verify_accumulated_items(
+            &key.dlog_key,
+            accs.as_slice(),
+            proof,
+        )
+        .map_err(|_| None)?
+        {
+            Some(new_accs) => new_accs,
+            None => return Ok((false, None)),
+        };
+    if &new_acc != current_acc {
+        return Ok((false, None));
+    }
+
+    let result = InnerSumcheckItemAccumulator::<G, D>::check_items::<R>(key, &[new_acc], rng)
+        .map_err(|_| None)?;
+
+    Ok((result, if result { Some(dlog_acc) } else { None }))
+}
+
+/// Verify the `accumulations` of the groups `accs` of inner sumcheck items, one for each
+/// group. Returns the verdict, and the dlog items of their opening proofs if positive.
+fn verify_inner_sumcheck_accumulations<G: AffineCurve, D: Digest, R: RngCore>(
+    accs: InnerSumcheckItemsByKey<'_, G>,
+    accumulations: &[InnerSumcheckAccumulation<G>],
+    rng: &mut R,
+) -> Result<(bool, Vec<DLogItem<G>>), Option<Vec<usize>>> {
+    if accs.len() != accumulations.len() {
+        return Ok((false, vec![]));
+    }
+
+    let mut dlog_accs = Vec::with_capacity(accs.len());
+    for ((key, items), accumulation) in accs.into_iter().zip(accumulations) {
+        match verify_inner_sumcheck_accumulation::<G, D, R>(key, items, accumulation, rng)? {
+            (true, dlog_acc) => dlog_accs.extend(dlog_acc),
+            (false, _) => return Ok((false, vec![])),
+        }
+    }
+    Ok((true, dlog_accs))
+}
+
 /// Given a set of PCDs, their corresponding Marlin verification keys, and the DLogCommitterKey(s)
 /// from both groups of our EC cycle, compute and return an accumulation proof(s) for
 /// the dlog accumulators/"items".
+/// The PCDs must not defer inner sumcheck items: see `accumulate_proofs_with_inner_sumchecks()`
+/// for these.
 /// In case of failure, returns the indices of the proofs which caused it (if possible).
 /// The PCDs are allowed to use different size restrictions of the DLogCommitterKey
 /// `g1_ck` and `g2_ck`.
@@ -93,6 +268,42 @@ pub fn accumulate_proofs<G1, G2, D: Digest>(
     g1_ck: &DLogCommitterKey<G1>,
     g2_ck: &DLogCommitterKey<G2>,
 ) -> Result<(Option<AccumulationProof<G1>>, Option<AccumulationProof<G2>>), Option<Vec<usize>>>
+where
+    G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
+    G2: AffineCurve<BaseField = <G1 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G1 as AffineCurve>::ScalarField>,
+{
+    accumulate_proofs_with_inner_sumchecks::<G1, G2, D>(
+        pcds,
+        vks,
+        &vec![None; pcds.len()],
+        g1_ck,
+        g2_ck,
+    )
+    .map(|(acc_proof_g1, acc_proof_g2, _)| (acc_proof_g1, acc_proof_g2))
+}
+
+/// As `accumulate_proofs()`, but the FinalDarlin PCDs may defer the inner sumchecks of the
+/// previous nodes. These are accumulated too, using `inner_sumcheck_keys`, i.e. for each of
+/// the PCDs (and aligned with `vks`) the keys of the circuits of the nodes preceding it, if it
+/// defers inner sumcheck items. The items of the PCDs with the same keys are accumulated
+/// together, and the dlog items of the opening proofs of their accumulations are accumulated
+/// with the ones of the PCDs.
+pub fn accumulate_proofs_with_inner_sumchecks<'a, G1, G2, D: Digest>(
+    pcds: &[GeneralPCD<G1, G2, D>],
+    vks: &[MarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>],
+    inner_sumcheck_keys: &[Option<DualInnerSumcheckKey<'a, G1, G2>>],
+    g1_ck: &DLogCommitterKey<G1>,
+    g2_ck: &DLogCommitterKey<G2>,
+) -> Result<
+    (
+        Option<AccumulationProof<G1>>,
+        Option<AccumulationProof<G2>>,
+        DualInnerSumcheckAccumulation<G1, G2>,
+    ),
+    Option<Vec<usize>>,
+>
 where
     G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
         + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
@@ -102,12 +313,41 @@ where
     let accumulation_time = start_timer!(|| "Accumulate proofs");
 
     // Get accumulators from pcds
-    let (accs_g1, accs_g2) =
-        get_accumulators::<G1, G2, D>(pcds, vks, g1_ck, g2_ck).map_err(|e| {
+    let (mut accs_g1, mut accs_g2) = get_accumulators::<G1, G2, D>(pcds, vks, g1_ck, g2_ck)
+        .map_err(|e| {
+            end_timer!(accumulation_time);
+            e
+        })?;
+
+    // Accumulate the deferred inner sumcheck items, if any
+    let (inner_sumcheck_accs_g1, inner_sumcheck_accs_g2) =
+        get_inner_sumcheck_accumulators::<G1, G2, D>(pcds, inner_sumcheck_keys).map_err(|e| {
             end_timer!(accumulation_time);
             e
         })?;
 
+    let mut inner_sumcheck_accumulation_g1 = Vec::with_capacity(inner_sumcheck_accs_g1.len());
+    for (key, items) in inner_sumcheck_accs_g1 {
+        let (accumulation, dlog_acc) = accumulate_inner_sumcheck_items::<G1, D>(key, items)
+            .map_err(|e| {
+                end_timer!(accumulation_time);
+                e
+            })?;
+        inner_sumcheck_accumulation_g1.push(accumulation);
+        accs_g1.push(dlog_acc);
+    }
+
+    let mut inner_sumcheck_accumulation_g2 = Vec::with_capacity(inner_sumcheck_accs_g2.len());
+    for (key, items) in inner_sumcheck_accs_g2 {
+        let (accumulation, dlog_acc) = accumulate_inner_sumcheck_items::<G2, D>(key, items)
+            .map_err(|e| {
+                end_timer!(accumulation_time);
+                e
+            })?;
+        inner_sumcheck_accumulation_g2.push(accumulation);
+        accs_g2.push(dlog_acc);
+    }
+
     // Create accumulation proofs
     let acc_proof_g1 = if accs_g1.is_empty() {
         None
@@ -137,12 +377,21 @@ where
 
     end_timer!(accumulation_time);
 
-    Ok((acc_proof_g1, acc_proof_g2))
+    Ok((
+        acc_proof_g1,
+        acc_proof_g2,
+        (
+            inner_sumcheck_accumulation_g1,
+            inner_sumcheck_accumulation_g2,
+        ),
+    ))
 }
 
 /// Verifies a set of PCDs which is augmented by an accumulation proof for their
 /// dlog items. (This is cheaper than batch verification, as it doesn't need to
 /// do any batching of witnesses.)
+/// The PCDs must not defer inner sumcheck items: see
+/// `verify_aggregated_proofs_with_inner_sumchecks()` for these.
 /// In case of failure, returns the indices of the proofs which caused it (if possible).
 /// The PCDs are allowed to use different size restrictions of the DLogCommitterKey
 /// `g1_ck` and `g2_ck`.
@@ -155,6 +404,41 @@ pub fn verify_aggregated_proofs<G1, G2, D: Digest, R: RngCore>(
     g2_vk: &DLogVerifierKey<G2>,
     rng: &mut R,
 ) -> Result<bool, Option<Vec<usize>>>
+where
+    G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
+    G2: AffineCurve<BaseField = <G1 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G1 as AffineCurve>::ScalarField>,
+{
+    verify_aggregated_proofs_with_inner_sumchecks::<G1, G2, D, R>(
+        pcds,
+        vks,
+        &vec![None; pcds.len()],
+        accumulation_proof_g1,
+        accumulation_proof_g2,
+        &(vec![], vec![]),
+        g1_vk,
+        g2_vk,
+        rng,
+    )
+}
+
+/// As `verify_aggregated_proofs()`, but the FinalDarlin PCDs may defer the inner sumchecks
+/// of the previous nodes. Their accumulations, as returned by
+/// `accumulate_proofs_with_inner_sumchecks()`, are verified too, deciding their new items
+/// by means of `inner_sumcheck_keys`, i.e. the keys of each of the PCDs (see
+/// `accumulate_proofs_with_inner_sumchecks()`).
+pub fn verify_aggregated_proofs_with_inner_sumchecks<'a, G1, G2, D: Digest, R: RngCore>(
+    pcds: &[GeneralPCD<G1, G2, D>],
+    vks: &[MarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>],
+    inner_sumcheck_keys: &[Option<DualInnerSumcheckKey<'a, G1, G2>>],
+    accumulation_proof_g1: &Option<AccumulationProof<G1>>,
+    accumulation_proof_g2: &Option<AccumulationProof<G2>>,
+    inner_sumcheck_accumulation: &DualInnerSumcheckAccumulation<G1, G2>,
+    g1_vk: &DLogVerifierKey<G1>,
+    g2_vk: &DLogVerifierKey<G2>,
+    rng: &mut R,
+) -> Result<bool, Option<Vec<usize>>>
 where
     G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
         + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
@@ -164,12 +448,46 @@ where
     let verification_time = start_timer!(|| "Verify aggregated proofs");
 
     // Do the succinct verification of the PCDs and get their accumulators
-    let (accs_g1, accs_g2) =
-        get_accumulators::<G1, G2, D>(pcds, vks, g1_vk, g2_vk).map_err(|e| {
+    let (mut accs_g1, mut accs_g2) = get_accumulators::<G1, G2, D>(pcds, vks, g1_vk, g2_vk)
+        .map_err(|e| {
             end_timer!(verification_time);
             e
         })?;
 
+    // Verify the accumulations of the deferred inner sumcheck items, if any, and
+    // collect the dlog items of their opening proofs
+    let (inner_sumcheck_accs_g1, inner_sumcheck_accs_g2) =
+        get_inner_sumcheck_accumulators::<G1, G2, D>(pcds, inner_sumcheck_keys).map_err(|e| {
+            end_timer!(verification_time);
+            e
+        })?;
+
+    let (result_inner_sumcheck_g1, dlog_accs_g1) = verify_inner_sumcheck_accumulations::<G1, D, R>(
+        inner_sumcheck_accs_g1,
+        &inner_sumcheck_accumulation.0,
+        rng,
+    )
+    .map_err(|e| {
+        end_timer!(verification_time);
+        e
+    })?;
+    let (result_inner_sumcheck_g2, dlog_accs_g2) = verify_inner_sumcheck_accumulations::<G2, D, R>(
+        inner_sumcheck_accs_g2,
+        &inner_sumcheck_accumulation.1,
+        rng,
+    )
+    .map_err(|e| {
+        end_timer!(verification_time);
+        e
+    })?;
+
+    if !(result_inner_sumcheck_g1 && result_inner_sumcheck_g2) {
+        end_timer!(verification_time);
+        return Ok(false);
+    }
+    accs_g1.extend(dlog_accs_g1);
+    accs_g2.extend(dlog_accs_g2);
+
     // fully verify the dlog aggregation proof in G1, if present.
     let result_accumulate_g1 = if accumulation_proof_g1.is_some() {
         let dummy_g1 = DLogItem::<G1>::default();
@@ -214,6 +532,8 @@ where
 /// Batch verification of PCDs consisting of FinalDarlin/SimpleMarlin PCDs.
 /// The succinct parts are processed in serial, the dlog items (in both of the groups G1
 /// and G2) are verified in batch.
+/// The PCDs must not defer inner sumcheck items: see
+/// `batch_verify_proofs_with_inner_sumchecks()` for these.
 /// In case of failure, returns the indices of the proofs which caused it (if possible).
 /// The PCDs are allowed to use different size restrictions of the DLogCommitterKey
 /// `g1_ck` and `g2_ck`.
@@ -224,6 +544,33 @@ pub fn batch_verify_proofs<G1, G2, D: Digest, R: RngCore>(
     g2_vk: &DLogVerifierKey<G2>,
     rng: &mut R,
 ) -> Result<bool, Option<Vec<usize>>>
+where
+    G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
+    G2: AffineCurve<BaseField = <G1 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G1 as AffineCurve>::ScalarField>,
+{
+    batch_verify_proofs_with_inner_sumchecks::<G1, G2, D, R>(
+        pcds,
+        vks,
+        &vec![None; pcds.len()],
+        g1_vk,
+        g2_vk,
+        rng,
+    )
+}
+
+/// As `batch_verify_proofs()`, but the FinalDarlin PCDs may defer the inner sumchecks of
+/// the previous nodes. Their items are verified in batch too, for each of the distinct
+/// `inner_sumcheck_keys` (see `accumulate_proofs_with_inner_sumchecks()`).
+pub fn batch_verify_proofs_with_inner_sumchecks<'a, G1, G2, D: Digest, R: RngCore>(
+    pcds: &[GeneralPCD<G1, G2, D>],
+    vks: &[MarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>],
+    inner_sumcheck_keys: &[Option<DualInnerSumcheckKey<'a, G1, G2>>],
+    g1_vk: &DLogVerifierKey<G1>,
+    g2_vk: &DLogVerifierKey<G2>,
+    rng: &mut R,
+) -> Result<bool, Option<Vec<usize>>>
 where
     G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
         + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
@@ -239,6 +586,13 @@ where
             e
         })?;
 
+    // Collect the deferred inner sumcheck items, if any
+    let (inner_sumcheck_accs_g1, inner_sumcheck_accs_g2) =
+        get_inner_sumcheck_accumulators::<G1, G2, D>(pcds, inner_sumcheck_keys).map_err(|e| {
+            end_timer!(verification_time);
+            e
+        })?;
+
     // Verify accumulators (hard part)
     let result_g1 = if accs_g1.is_empty() {
         true
@@ -258,7 +612,28 @@ where
         })?
     };
 
+    // Verify the deferred inner sumcheck items, if any
+    let mut result_inner_sumcheck = true;
+    for (key, items) in inner_sumcheck_accs_g1 {
+        result_inner_sumcheck &= InnerSumcheckItemAccumulator::<G1, D>::check_items::<R>(
+            key, &items, rng,
+        )
+        .map_err(|_| {
+            end_timer!(verification_time);
+            None
+        })?;
+    }
+    for (key, items) in inner_sumcheck_accs_g2 {
+        result_inner_sumcheck &= InnerSumcheckItemAccumulator::<G2, D>::check_items::<R>(
+            key, &items, rng,
+        )
+        .map_err(|_| {
+            end_timer!(verification_time);
+            None
+        })?;
+    }
+
     end_timer!(verification_time);
 
-    Ok(result_g1 && result_g2)
+    Ok(result_g1 && result_g2 && result_inner_sumcheck)
 }
@@ -2,7 +2,7 @@
 //! a simple quadratic relation, allocates a given instance of `FinalDarlinDeferredData`,
 //! and wires it to the outside via system inputs.
 use crate::darlin::{
-    accumulators::ItemAccumulator,
+    accumulators::{inner_sumcheck::DualInnerSumcheckKey, ItemAccumulator},
     data_structures::FinalDarlinDeferredData,
     pcd::{error::PCDError, final_darlin::FinalDarlinPCD, PCDCircuit, PCDParameters, PCD},
     FinalDarlin, FinalDarlinProverKey, FinalDarlinVerifierKey,
@@ -345,6 +345,79 @@ pub fn generate_test_data<'a, G1: AffineCurve, G2: AffineCurve, D: Digest + 'a,
     Vec<FinalDarlinPCD<'a, G1, G2, D>>,
     Vec<FinalDarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>>,
 )
+where
+    G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
+    G2: AffineCurve<BaseField = <G1 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G1 as AffineCurve>::ScalarField>,
+{
+    generate_test_data_for_deferred::<G1, G2, D, R>(
+        num_constraints,
+        segment_size,
+        params_g1,
+        params_g2,
+        None,
+        num_proofs,
+        rng,
+    )
+}
+
+/// As `generate_test_data()`, but the PCDs carry deferred inner sumcheck items for the
+/// circuits of `inner_sumcheck_keys`, which must use the same segment size.
+#[allow(dead_code)]
+pub fn generate_test_data_with_inner_sumchecks<
+    'a,
+    G1: AffineCurve,
+    G2: AffineCurve,
+    D: Digest + 'a,
+    R: RngCore,
+>(
+    num_constraints: usize,
+    segment_size: usize,
+    params_g1: &UniversalParams<G1>,
+    params_g2: &UniversalParams<G2>,
+    inner_sumcheck_keys: DualInnerSumcheckKey<G1, G2>,
+    num_proofs: usize,
+    rng: &mut R,
+) -> (
+    Vec<FinalDarlinPCD<'a, G1, G2, D>>,
+    Vec<FinalDarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>>,
+)
+where
+    G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
+    G2: AffineCurve<BaseField = <G1 as AffineCurve>::ScalarField>
+        + ToConstraintField<<G1 as AffineCurve>::ScalarField>,
+{
+    generate_test_data_for_deferred::<G1, G2, D, R>(
+        num_constraints,
+        segment_size,
+        params_g1,
+        params_g2,
+        Some(inner_sumcheck_keys),
+        num_proofs,
+        rng,
+    )
+}
+
+fn generate_test_data_for_deferred<
+    'a,
+    G1: AffineCurve,
+    G2: AffineCurve,
+    D: Digest + 'a,
+    R: RngCore,
+>(
+    num_constraints: usize,
+    segment_size: usize,
+    params_g1: &UniversalParams<G1>,
+    params_g2: &UniversalParams<G2>,
+    inner_sumcheck_keys: Option<DualInnerSumcheckKey<G1, G2>>,
+    num_proofs: usize,
+    rng: &mut R,
+) -> (
+    Vec<FinalDarlinPCD<'a, G1, G2, D>>,
+    Vec<FinalDarlinVerifierKey<G1::ScalarField, InnerProductArgPC<G1, D>>>,
+)
 where
     G1: AffineCurve<BaseField = <G2 as AffineCurve>::ScalarField>
         + ToConstraintField<<G2 as AffineCurve>::ScalarField>,
@@ -357,11 +430,21 @@ where
     let (committer_key_g2, _) = config.universal_setup::<_, D>(params_g2).unwrap();
 
     // Generate random (but valid) deferred data
-    let dummy_deferred = FinalDarlinDeferredData::<G1, G2>::generate_random::<R, D>(
-        rng,
-        &committer_key_g1,
-        &committer_key_g2,
-    );
+    let dummy_deferred = match inner_sumcheck_keys {
+        Some(inner_sumcheck_keys) => {
+            FinalDarlinDeferredData::<G1, G2>::generate_random_with_inner_sumcheck_accs::<R, D>(
+                rng,
+                &committer_key_g1,
+                &committer_key_g2,
+                inner_sumcheck_keys,
+            )
+        }
+        None => FinalDarlinDeferredData::<G1, G2>::generate_random::<R, D>(
+            rng,
+            &committer_key_g1,
+            &committer_key_g2,
+        ),
+    };
 
     let info = CircuitInfo::<G1, G2> {
         num_constraints,
@@ -1,5 +1,6 @@
 //! Test suite for PCD post processing (batch-verification, aggregation)
-use algebra::AffineCurve;
+use crate::darlin::accumulators::inner_sumcheck::Matrix;
+use algebra::{AffineCurve, Field};
 use digest::Digest;
 use poly_commit::{
     ipa_pc::{
@@ -8,6 +9,7 @@ use poly_commit::{
     },
     PCUniversalParams, PolynomialCommitment,
 };
+use rand::Rng;
 
 pub mod final_darlin;
 pub mod simple_marlin;
@@ -32,17 +34,44 @@ pub fn get_keys<G1: AffineCurve, G2: AffineCurve, D: Digest>(
     (ck_g1, vk_g1, ck_g2, vk_g2)
 }
 
+#[allow(dead_code)]
+/// A random sparse square matrix with `size` rows, e.g. to build an `InnerSumcheckKey`
+pub fn random_matrix<F: Field, R: Rng>(size: usize, rng: &mut R) -> Matrix<F> {
+    (0..size)
+        .map(|_| {
+            (0..rng.gen_range(0..4))
+                .map(|_| (F::rand(rng), rng.gen_range(0..size)))
+                .collect()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::darlin::data_structures::FinalDarlinProof;
+    use crate::darlin::data_structures::{FinalDarlinDeferredData, FinalDarlinProof};
     use crate::darlin::{
-        pcd::GeneralPCD,
-        proof_aggregator::{accumulate_proofs, batch_verify_proofs, verify_aggregated_proofs},
+        accumulators::inner_sumcheck::InnerSumcheckKey,
+        pcd::{
+            error::{PCDError, PCDTreeError},
+            final_darlin::{FinalDarlinPCD, FinalDarlinPCDVerifierKey},
+            simple_marlin::{OwnedSimpleMarlinPCDVerifierKey, SimpleMarlinPCDVerifierKey},
+            tree::{PCDTree, PCDTreeProver},
+            GeneralPCD, OwnedDualPCDVerifierKey, PCD,
+        },
+        proof_aggregator::{
+            accumulate_proofs, accumulate_proofs_with_inner_sumchecks, batch_verify_proofs,
+            batch_verify_proofs_with_inner_sumchecks, verify_aggregated_proofs,
+            verify_aggregated_proofs_with_inner_sumchecks,
+        },
         tests::{
-            final_darlin::generate_test_data as generate_final_darlin_test_data,
+            final_darlin::{
+                generate_test_data as generate_final_darlin_test_data,
+                generate_test_data_with_inner_sumchecks as generate_final_darlin_test_data_with_inner_sumchecks,
+            },
             simple_marlin::generate_test_data as generate_simple_marlin_test_data,
         },
+        FinalDarlinVerifierKey,
     };
     use algebra::{
         curves::tweedle::{dee::Affine as DeeAffine, dum::Affine as DumAffine},
@@ -700,6 +729,319 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_inner_sumcheck_proof_aggregator() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+        // Set params
+        let num_proofs = 3;
+        let segment_size = 1 << 6;
+        // Enough constraints for the deferred inner sumcheck items as system inputs
+        let num_constraints = 1 << 8;
+
+        //Generate keys
+        let params_g1 = TestIPAPCDee::setup(segment_size - 1).unwrap();
+        let params_g2 = TestIPAPCDum::setup(segment_size - 1).unwrap();
+
+        let (committer_key_g1, verifier_key_g1, committer_key_g2, verifier_key_g2) =
+            get_keys::<_, _, Blake2s>(&params_g1, &params_g2);
+
+        // The circuits of the previous nodes, deferring their inner sumchecks, and
+        // some other circuits. Their domain exceeds the segment size.
+        let num_rows = 2 * segment_size;
+        let matrices_g1 = (0..6)
+            .map(|_| random_matrix::<<DeeAffine as AffineCurve>::ScalarField, _>(num_rows, rng))
+            .collect::<Vec<_>>();
+        let matrices_g2 = (0..6)
+            .map(|_| random_matrix::<<DumAffine as AffineCurve>::ScalarField, _>(num_rows, rng))
+            .collect::<Vec<_>>();
+        let key_g1 = InnerSumcheckKey::new(
+            &matrices_g1[0],
+            &matrices_g1[1],
+            &matrices_g1[2],
+            &committer_key_g1,
+        )
+        .unwrap();
+        let key_g2 = InnerSumcheckKey::new(
+            &matrices_g2[0],
+            &matrices_g2[1],
+            &matrices_g2[2],
+            &committer_key_g2,
+        )
+        .unwrap();
+        let other_key_g1 = InnerSumcheckKey::new(
+            &matrices_g1[3],
+            &matrices_g1[4],
+            &matrices_g1[5],
+            &committer_key_g1,
+        )
+        .unwrap();
+        let other_key_g2 = InnerSumcheckKey::new(
+            &matrices_g2[3],
+            &matrices_g2[4],
+            &matrices_g2[5],
+            &committer_key_g2,
+        )
+        .unwrap();
+        let keys = (&key_g1, &key_g2);
+        let other_keys = (&other_key_g1, &other_key_g2);
+
+        // Generate FinalDarlin PCDs deferring inner sumcheck items for the circuits of
+        // `keys`, and for the ones of `other_keys`
+        let (pcds, vks) = generate_final_darlin_test_data_with_inner_sumchecks::<_, _, Blake2s, _>(
+            num_constraints - 1,
+            segment_size,
+            &params_g1,
+            &params_g2,
+            keys,
+            num_proofs,
+            rng,
+        );
+        let deferred = &pcds[0].final_darlin_proof.deferred;
+        assert!(!deferred.pre_previous_inner_sumcheck_accs.is_empty());
+        assert!(!deferred.previous_inner_sumcheck_accs.is_empty());
+        assert!(pcds[0].final_darlin_proof.is_valid());
+        test_canonical_serialize_deserialize(true, &pcds[0].final_darlin_proof);
+
+        let (other_pcds, other_vks) =
+            generate_final_darlin_test_data_with_inner_sumchecks::<_, _, Blake2s, _>(
+                num_constraints - 1,
+                segment_size,
+                &params_g1,
+                &params_g2,
+                other_keys,
+                num_proofs,
+                rng,
+            );
+
+        // The deferred inner sumchecks are decided by the verifier of the PCD, and
+        // need the keys of the previous circuits
+        let mut vk = FinalDarlinPCDVerifierKey::<DeeAffine, DumAffine, Blake2s> {
+            final_darlin_vk: &vks[0],
+            dlog_vks: (&verifier_key_g1, &verifier_key_g2),
+            inner_sumcheck_keys: Some(keys),
+        };
+        assert!(pcds[0].verify(&vk, rng).unwrap());
+
+        // The owned verifier key keeps the inner sumcheck keys, also when restored from bytes
+        let owned_vk = OwnedDualPCDVerifierKey::<DeeAffine, DumAffine, Blake2s>::from(&vk);
+        assert!(owned_vk.is_valid());
+        test_canonical_serialize_deserialize(true, &owned_vk);
+        let mut owned_vk_bytes = Vec::new();
+        owned_vk.serialize(&mut owned_vk_bytes).unwrap();
+        let owned_vk = OwnedDualPCDVerifierKey::<DeeAffine, DumAffine, Blake2s>::deserialize(
+            owned_vk_bytes.as_slice(),
+        )
+        .unwrap();
+        assert!(owned_vk.inner_sumcheck_keys.is_some());
+        assert!(pcds[0].verify(&owned_vk.as_pcd_vk(), rng).unwrap());
+
+        let wrong_owned_vk = OwnedDualPCDVerifierKey::<DeeAffine, DumAffine, Blake2s> {
+            inner_sumcheck_keys: Some((other_key_g1.to_owned_key(), other_key_g2.to_owned_key())),
+            ..owned_vk.clone()
+        };
+        assert!(!pcds[0].verify(&wrong_owned_vk.as_pcd_vk(), rng).unwrap());
+
+        vk.inner_sumcheck_keys = Some(other_keys);
+        assert!(!pcds[0].verify(&vk, rng).unwrap());
+        vk.inner_sumcheck_keys = None;
+        assert!(pcds[0].verify(&vk, rng).is_err());
+
+        // Aggregate the PCDs of both circuits, each one with the keys of its previous circuits
+        let pcds = pcds
+            .into_iter()
+            .chain(other_pcds)
+            .map(GeneralPCD::FinalDarlin)
+            .collect::<Vec<_>>();
+        let vks = vks.into_iter().chain(other_vks).collect::<Vec<_>>();
+        let inner_sumcheck_keys = (0..2 * num_proofs)
+            .map(|i| Some(if i < num_proofs { keys } else { other_keys }))
+            .collect::<Vec<_>>();
+        let wrong_inner_sumcheck_keys = (0..2 * num_proofs)
+            .map(|i| Some(if i < num_proofs { other_keys } else { keys }))
+            .collect::<Vec<_>>();
+        let mut missing_inner_sumcheck_keys = inner_sumcheck_keys.clone();
+        missing_inner_sumcheck_keys[num_proofs] = None;
+        let all_indices = (0..2 * num_proofs).collect::<Vec<_>>();
+
+        // Accumulate PCDs, together with their inner sumcheck items: the items of the PCDs
+        // of the same circuit are accumulated together
+        let (proof_g1, proof_g2, inner_sumcheck_accumulation) =
+            accumulate_proofs_with_inner_sumchecks::<_, _, Blake2s>(
+                &pcds,
+                &vks,
+                &inner_sumcheck_keys,
+                &committer_key_g1,
+                &committer_key_g2,
+            )
+            .unwrap();
+        assert_eq!(inner_sumcheck_accumulation.0.len(), 2);
+        assert_eq!(inner_sumcheck_accumulation.1.len(), 2);
+        assert_eq!(
+            accumulate_proofs_with_inner_sumchecks::<_, _, Blake2s>(
+                &pcds,
+                &vks,
+                &missing_inner_sumcheck_keys,
+                &committer_key_g1,
+                &committer_key_g2,
+            )
+            .unwrap_err(),
+            Some(vec![num_proofs])
+        );
+        // PCDs deferring inner sumchecks can't be accumulated without keys
+        assert_eq!(
+            accumulate_proofs::<_, _, Blake2s>(&pcds, &vks, &committer_key_g1, &committer_key_g2)
+                .unwrap_err(),
+            Some(all_indices.clone())
+        );
+
+        // Verify accumulation
+        assert!(
+            verify_aggregated_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &inner_sumcheck_keys,
+                &proof_g1,
+                &proof_g2,
+                &inner_sumcheck_accumulation,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap()
+        );
+
+        // Wrong inner sumcheck keys
+        assert!(
+            !verify_aggregated_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &wrong_inner_sumcheck_keys,
+                &proof_g1,
+                &proof_g2,
+                &inner_sumcheck_accumulation,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap()
+        );
+
+        // Missing inner sumcheck keys
+        assert_eq!(
+            verify_aggregated_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &missing_inner_sumcheck_keys,
+                &proof_g1,
+                &proof_g2,
+                &inner_sumcheck_accumulation,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap_err(),
+            Some(vec![num_proofs])
+        );
+        assert_eq!(
+            verify_aggregated_proofs::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &proof_g1,
+                &proof_g2,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap_err(),
+            Some(all_indices.clone())
+        );
+
+        // Missing inner sumcheck accumulations
+        let mut wrong_inner_sumcheck_accumulation = inner_sumcheck_accumulation.clone();
+        wrong_inner_sumcheck_accumulation.0.pop();
+        assert!(
+            !verify_aggregated_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &inner_sumcheck_keys,
+                &proof_g1,
+                &proof_g2,
+                &wrong_inner_sumcheck_accumulation,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap()
+        );
+
+        // Wrong new inner sumcheck item
+        let mut wrong_inner_sumcheck_accumulation = inner_sumcheck_accumulation.clone();
+        wrong_inner_sumcheck_accumulation.1[1].0.alpha =
+            <DumAffine as AffineCurve>::ScalarField::rand(rng);
+        assert!(
+            !verify_aggregated_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &inner_sumcheck_keys,
+                &proof_g1,
+                &proof_g2,
+                &wrong_inner_sumcheck_accumulation,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap()
+        );
+
+        // Batch verification decides the inner sumcheck items too
+        assert!(
+            batch_verify_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &inner_sumcheck_keys,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap()
+        );
+        assert!(
+            !batch_verify_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &wrong_inner_sumcheck_keys,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            batch_verify_proofs_with_inner_sumchecks::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &missing_inner_sumcheck_keys,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap_err(),
+            Some(vec![num_proofs])
+        );
+        assert_eq!(
+            batch_verify_proofs::<_, _, Blake2s, _>(
+                &pcds,
+                &vks,
+                &verifier_key_g1,
+                &verifier_key_g2,
+                rng
+            )
+            .unwrap_err(),
+            Some(all_indices)
+        );
+    }
+
     #[ignore]
     #[test]
     fn test_final_darlin_size() {
@@ -781,4 +1123,370 @@ mod test {
             proof.deferred.previous_acc.xi_s.serialized_size()
         );
     }
+
+    #[test]
+    fn test_final_darlin_deferred_data_serialization() {
+        type DeferredData = FinalDarlinDeferredData<DeeAffine, DumAffine>;
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+        let segment_size = 1 << 6;
+
+        let params_g1 = TestIPAPCDee::setup(segment_size - 1).unwrap();
+        let params_g2 = TestIPAPCDum::setup(segment_size - 1).unwrap();
+        let (committer_key_g1, _, committer_key_g2, _) =
+            get_keys::<_, _, Blake2s>(&params_g1, &params_g2);
+
+        // Without inner sumcheck accumulators, the serialization is the legacy one, i.e.
+        // the one of the dlog accumulators only
+        let (mut pcds, _) = generate_final_darlin_test_data::<_, _, Blake2s, _>(
+            segment_size - 1,
+            segment_size,
+            &params_g1,
+            &params_g2,
+            1,
+            rng,
+        );
+        let proof = pcds.pop().unwrap().final_darlin_proof;
+
+        let mut legacy_bytes = Vec::new();
+        proof.proof.serialize(&mut legacy_bytes).unwrap();
+        proof
+            .deferred
+            .previous_acc
+            .serialize(&mut legacy_bytes)
+            .unwrap();
+        proof
+            .deferred
+            .pre_previous_acc
+            .serialize(&mut legacy_bytes)
+            .unwrap();
+
+        let mut proof_bytes = Vec::new();
+        proof.serialize(&mut proof_bytes).unwrap();
+        assert_eq!(proof_bytes, legacy_bytes);
+        assert_eq!(proof.serialized_size(), legacy_bytes.len());
+        assert_eq!(
+            FinalDarlinProof::<DeeAffine, DumAffine, Blake2s>::deserialize(legacy_bytes.as_slice())
+                .unwrap(),
+            proof
+        );
+
+        let mut legacy_bytes = Vec::new();
+        proof
+            .deferred
+            .previous_acc
+            .serialize_uncompressed(&mut legacy_bytes)
+            .unwrap();
+        proof
+            .deferred
+            .pre_previous_acc
+            .serialize_uncompressed(&mut legacy_bytes)
+            .unwrap();
+        assert_eq!(
+            DeferredData::deserialize_uncompressed(legacy_bytes.as_slice()).unwrap(),
+            proof.deferred
+        );
+
+        // With inner sumcheck accumulators, the serialization is prefixed by the marker
+        let num_rows = 2 * segment_size;
+        let matrices_g1 = (0..3)
+            .map(|_| random_matrix::<<DeeAffine as AffineCurve>::ScalarField, _>(num_rows, rng))
+            .collect::<Vec<_>>();
+        let matrices_g2 = (0..3)
+            .map(|_| random_matrix::<<DumAffine as AffineCurve>::ScalarField, _>(num_rows, rng))
+            .collect::<Vec<_>>();
+        let key_g1 = InnerSumcheckKey::new(
+            &matrices_g1[0],
+            &matrices_g1[1],
+            &matrices_g1[2],
+            &committer_key_g1,
+        )
+        .unwrap();
+        let key_g2 = InnerSumcheckKey::new(
+            &matrices_g2[0],
+            &matrices_g2[1],
+            &matrices_g2[2],
+            &committer_key_g2,
+        )
+        .unwrap();
+
+        let deferred = DeferredData::generate_random_with_inner_sumcheck_accs::<_, Blake2s>(
+            rng,
+            &committer_key_g1,
+            &committer_key_g2,
+            (&key_g1, &key_g2),
+        );
+        assert!(deferred.is_valid());
+        test_canonical_serialize_deserialize(true, &deferred);
+
+        let marker_len = DumAffine::zero().serialized_size();
+        let mut deferred_bytes = Vec::new();
+        deferred.serialize(&mut deferred_bytes).unwrap();
+        assert!(deferred_bytes[..marker_len]
+            .iter()
+            .all(|byte| *byte == DeferredData::INNER_SUMCHECK_MARKER));
+
+        // The marker must not be followed by empty inner sumcheck accumulators
+        let mut bytes = deferred_bytes[..marker_len].to_vec();
+        proof.deferred.serialize(&mut bytes).unwrap();
+        proof
+            .deferred
+            .previous_inner_sumcheck_accs
+            .serialize(&mut bytes)
+            .unwrap();
+        proof
+            .deferred
+            .pre_previous_inner_sumcheck_accs
+            .serialize(&mut bytes)
+            .unwrap();
+        assert!(DeferredData::deserialize(bytes.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_general_pcd_serialization() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+        let segment_size = 1 << 6;
+
+        let params_g1 = TestIPAPCDee::setup(segment_size - 1).unwrap();
+        let params_g2 = TestIPAPCDum::setup(segment_size - 1).unwrap();
+        let (_, verifier_key_g1, _, verifier_key_g2) =
+            get_keys::<_, _, Blake2s>(&params_g1, &params_g2);
+
+        let (mut simple_marlin_pcds, simple_marlin_vks) =
+            generate_simple_marlin_test_data::<_, Blake2s, _>(
+                segment_size - 1,
+                segment_size,
+                &params_g1,
+                1,
+                rng,
+            );
+        let (mut final_darlin_pcds, final_darlin_vks) =
+            generate_final_darlin_test_data::<_, _, Blake2s, _>(
+                segment_size - 1,
+                segment_size,
+                &params_g1,
+                &params_g2,
+                1,
+                rng,
+            );
+
+        let pcds = vec![
+            GeneralPCD::SimpleMarlin(simple_marlin_pcds.pop().unwrap()),
+            GeneralPCD::FinalDarlin(final_darlin_pcds.pop().unwrap()),
+        ];
+        let index_vks = [&simple_marlin_vks[0], &final_darlin_vks[0]];
+
+        for (pcd, index_vk) in pcds.iter().zip(index_vks.iter()) {
+            assert!(pcd.is_valid());
+            test_canonical_serialize_deserialize(true, pcd);
+
+            let vk = OwnedDualPCDVerifierKey::<DeeAffine, DumAffine, Blake2s> {
+                final_darlin_vk: (*index_vk).clone(),
+                dlog_vks: (verifier_key_g1.clone(), verifier_key_g2.clone()),
+                inner_sumcheck_keys: None,
+            };
+            assert!(vk.is_valid());
+            test_canonical_serialize_deserialize(true, &vk);
+
+            // Both the PCD and its vk can be restored from bytes, without knowing the
+            // type of the PCD in advance
+            let mut vk_bytes = Vec::new();
+            vk.serialize(&mut vk_bytes).unwrap();
+            let vk = OwnedDualPCDVerifierKey::<DeeAffine, DumAffine, Blake2s>::deserialize(
+                vk_bytes.as_slice(),
+            )
+            .unwrap();
+
+            let mut pcd_bytes = Vec::new();
+            pcd.serialize(&mut pcd_bytes).unwrap();
+            let pcd =
+                GeneralPCD::<DeeAffine, DumAffine, Blake2s>::deserialize(pcd_bytes.as_slice())
+                    .unwrap();
+
+            assert!(pcd.verify(&vk.as_pcd_vk(), rng).unwrap());
+        }
+
+        // Unknown tags must be rejected
+        let mut pcd_bytes = Vec::new();
+        pcds[0].serialize(&mut pcd_bytes).unwrap();
+        pcd_bytes[0] = 2;
+        assert!(
+            GeneralPCD::<DeeAffine, DumAffine, Blake2s>::deserialize(pcd_bytes.as_slice()).is_err()
+        );
+
+        let simple_marlin_vk = OwnedSimpleMarlinPCDVerifierKey::<DeeAffine, Blake2s>::from(
+            &SimpleMarlinPCDVerifierKey(&simple_marlin_vks[0], &verifier_key_g1),
+        );
+        assert!(simple_marlin_vk.is_valid());
+        test_canonical_serialize_deserialize(true, &simple_marlin_vk);
+        if let GeneralPCD::SimpleMarlin(simple_marlin) = &pcds[0] {
+            assert!(simple_marlin
+                .verify(&simple_marlin_vk.as_pcd_vk(), rng)
+                .unwrap());
+        }
+    }
+
+    /// A `PCDTreeProver` whose mergers just forward the left child, and whose padding
+    /// is a PCD different from the leaves. If `strict`, merging two different PCDs fails.
+    /// If `corrupt_padded`, merging a node with the padding gives an invalid node.
+    struct TestTreeProver<'a> {
+        index_vk: &'a FinalDarlinVerifierKey<
+            <DeeAffine as AffineCurve>::ScalarField,
+            InnerProductArgPC<DeeAffine, Blake2s>,
+        >,
+        dlog_vks: (
+            &'a DLogVerifierKey<DeeAffine>,
+            &'a DLogVerifierKey<DumAffine>,
+        ),
+        padding: FinalDarlinPCD<'a, DeeAffine, DumAffine, Blake2s>,
+        strict: bool,
+        corrupt_padded: bool,
+    }
+
+    type TestPCDTree<'a> = PCDTree<'a, DeeAffine, DumAffine, Blake2s, TestTreeProver<'a>>;
+
+    impl<'a> PCDTreeProver<'a, DeeAffine, DumAffine, Blake2s> for TestTreeProver<'a> {
+        type NodePCD = FinalDarlinPCD<'a, DeeAffine, DumAffine, Blake2s>;
+
+        fn verifier_key(
+            &self,
+            _level: usize,
+        ) -> Result<FinalDarlinPCDVerifierKey<'a, DeeAffine, DumAffine, Blake2s>, PCDError>
+        {
+            Ok(FinalDarlinPCDVerifierKey {
+                final_darlin_vk: self.index_vk,
+                dlog_vks: self.dlog_vks,
+                inner_sumcheck_keys: None,
+            })
+        }
+
+        fn padding(&self, _level: usize) -> Result<Self::NodePCD, PCDError> {
+            Ok(self.padding.clone())
+        }
+
+        fn merge(
+            &self,
+            _level: usize,
+            _vk: &FinalDarlinPCDVerifierKey<'a, DeeAffine, DumAffine, Blake2s>,
+            mut left: Self::NodePCD,
+            right: Self::NodePCD,
+        ) -> Result<Self::NodePCD, PCDError> {
+            if self.strict && left.usr_ins != right.usr_ins {
+                return Err(PCDError::MissingUserInputs(
+                    "Mismatching children".to_owned(),
+                ));
+            }
+            if self.corrupt_padded && right.usr_ins == self.padding.usr_ins {
+                left.usr_ins[0] += &<DeeAffine as AffineCurve>::ScalarField::one();
+            }
+            Ok(left)
+        }
+
+        fn finalize(
+            &self,
+            _level: usize,
+            _vk: &FinalDarlinPCDVerifierKey<'a, DeeAffine, DumAffine, Blake2s>,
+            root: Self::NodePCD,
+        ) -> Result<FinalDarlinPCD<'a, DeeAffine, DumAffine, Blake2s>, PCDError> {
+            Ok(root)
+        }
+    }
+
+    #[test]
+    fn test_pcd_tree() {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+
+        let segment_size = 1 << 6;
+        let num_leaves = 5;
+
+        let params_g1 = TestIPAPCDee::setup(segment_size - 1).unwrap();
+        let params_g2 = TestIPAPCDum::setup(segment_size - 1).unwrap();
+        let (_, verifier_key_g1, _, verifier_key_g2) =
+            get_keys::<_, _, Blake2s>(&params_g1, &params_g2);
+
+        let (mut pcds, vks) = generate_final_darlin_test_data::<_, _, Blake2s, _>(
+            segment_size - 1,
+            segment_size,
+            &params_g1,
+            &params_g2,
+            num_leaves,
+            rng,
+        );
+        let (mut paddings, _) = generate_final_darlin_test_data::<_, _, Blake2s, _>(
+            segment_size - 1,
+            segment_size,
+            &params_g1,
+            &params_g2,
+            1,
+            rng,
+        );
+
+        let padding = paddings.pop().unwrap();
+        let prover = TestTreeProver {
+            index_vk: &vks[0],
+            dlog_vks: (&verifier_key_g1, &verifier_key_g2),
+            padding: padding.clone(),
+            strict: false,
+            corrupt_padded: false,
+        };
+        let vk = prover.verifier_key(0).unwrap();
+
+        // Odd levels are padded and the root is finalized
+        let final_pcd = TestPCDTree::new(prover).prove(pcds.clone()).unwrap();
+        assert!(final_pcd.verify(&vk, rng).unwrap());
+
+        // The failing merge is located in the tree
+        let tree = TestPCDTree::new(TestTreeProver {
+            index_vk: &vks[0],
+            dlog_vks: (&verifier_key_g1, &verifier_key_g2),
+            padding: padding.clone(),
+            strict: true,
+            corrupt_padded: false,
+        });
+        match tree.prove(pcds.clone()) {
+            Err(PCDTreeError::FailedMerge {
+                level,
+                index,
+                leaves,
+                ..
+            }) => {
+                assert_eq!(level, 1);
+                assert_eq!(index, 2);
+                assert_eq!(leaves, 4..5);
+            }
+            _ => panic!("Expected a failed merge"),
+        }
+
+        // The invalid merged node is located in the tree
+        let corrupting_tree = TestPCDTree::new(TestTreeProver {
+            index_vk: &vks[0],
+            dlog_vks: (&verifier_key_g1, &verifier_key_g2),
+            padding,
+            strict: false,
+            corrupt_padded: true,
+        });
+        match corrupting_tree.prove(pcds.clone()) {
+            Err(PCDTreeError::FailedNodeVerification {
+                level,
+                index,
+                leaves,
+                ..
+            }) => {
+                assert_eq!(level, 1);
+                assert_eq!(index, 2);
+                assert_eq!(leaves, 4..5);
+            }
+            _ => panic!("Expected a failed node verification"),
+        }
+
+        // The failing leaf is located in the tree
+        pcds[3].usr_ins[0] += &<DeeAffine as AffineCurve>::ScalarField::rand(rng);
+        match tree.prove(pcds) {
+            Err(PCDTreeError::FailedLeafVerification { leaf, .. }) => assert_eq!(leaf, 3),
+            _ => panic!("Expected a failed leaf verification"),
+        }
+
+        assert!(matches!(tree.prove(vec![]), Err(PCDTreeError::NoLeaves)));
+    }
 }
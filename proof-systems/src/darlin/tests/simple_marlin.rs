@@ -21,10 +21,10 @@ use std::ops::MulAssign;
 /// dummy witness variables are allocated.
 #[derive(Copy, Clone)]
 pub(crate) struct Circuit<F: Field> {
-    a: Option<F>,
-    b: Option<F>,
-    num_constraints: usize,
-    num_variables: usize,
+    pub(crate) a: Option<F>,
+    pub(crate) b: Option<F>,
+    pub(crate) num_constraints: usize,
+    pub(crate) num_variables: usize,
 }
 
 impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for Circuit<ConstraintF> {
@@ -1,6 +1,11 @@
 //! An implementation of the [Groth-Maller][GM17] simulation extractable zkSNARK.
 //! [GM17]: https://eprint.iacr.org/2017/540
-use algebra::{bytes::ToBytes, PairingEngine};
+use algebra::{
+    bytes::{FromBytes, ToBytes},
+    serialize::*,
+    AffineCurve, FromBytesChecked, PairingEngine, SemanticallyValid,
+};
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 use r1cs_core::SynthesisError;
 use serde::{Deserialize, Serialize};
 use std::io::{self, Read, Result as IoResult, Write};
@@ -23,7 +28,7 @@ mod test;
 pub use self::{generator::*, prover::*, verifier::*};
 
 /// A proof in the GM17 SNARK.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Proof<E: PairingEngine> {
     pub a: E::G1Affine,
     pub b: E::G2Affine,
@@ -39,6 +44,42 @@ impl<E: PairingEngine> ToBytes for Proof<E> {
     }
 }
 
+impl<E: PairingEngine> FromBytes for Proof<E> {
+    /// Doesn't perform group membership check for deserialized points
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let a = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let b = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let c = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(Proof { a, b, c })
+    }
+}
+
+impl<E: PairingEngine> SemanticallyValid for Proof<E> {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.a.is_valid()
+            && !self.a.is_zero()
+            && self.b.is_valid()
+            && !self.b.is_zero()
+            && self.c.is_valid()
+            && !self.c.is_zero()
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Proof<E> {
+    #[inline]
+    fn read_checked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let a = read_affine_checked::<E::G1Affine, _>("A", true, &mut reader)?;
+        let b = read_affine_checked::<E::G2Affine, _>("B", true, &mut reader)?;
+        let c = read_affine_checked::<E::G1Affine, _>("C", true, &mut reader)?;
+        Ok(Proof { a, b, c })
+    }
+}
+
 impl<E: PairingEngine> PartialEq for Proof<E> {
     fn eq(&self, other: &Self) -> bool {
         self.a == other.a && self.b == other.b && self.c == other.c
@@ -55,23 +96,68 @@ impl<E: PairingEngine> Default for Proof<E> {
     }
 }
 
-impl<E: PairingEngine> Proof<E> {
-    /// Serialize the proof into bytes, for storage on disk or transmission
-    /// over the network.
-    pub fn write<W: Write>(&self, mut _writer: W) -> io::Result<()> {
-        // TODO: implement serialization
-        unimplemented!()
-    }
+fn read_affine_checked<G: AffineCurve, R: Read>(
+    name: &str,
+    zero_check: bool,
+    mut reader: R,
+) -> IoResult<G> {
+    G::read_checked(&mut reader)
+        .map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid point {}: {}", name, e),
+            )
+        })
+        .and_then(|p| {
+            if zero_check && p.is_zero() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid point {}: point at infinity", name),
+                ));
+            }
+            Ok(p)
+        })
+}
 
-    /// Deserialize the proof from bytes.
-    pub fn read<R: Read>(mut _reader: R) -> io::Result<Self> {
-        // TODO: implement serialization
-        unimplemented!()
+fn read_affine_vec_checked<G: AffineCurve, R: Read>(
+    name: &str,
+    zero_check: bool,
+    mut reader: R,
+) -> IoResult<Vec<G>> {
+    let len = reader.read_u32::<BigEndian>()? as usize;
+    let mut v = vec![];
+    for i in 0..len {
+        let g = read_affine_checked(format!("{}[{}]", name, i).as_str(), zero_check, &mut reader)?;
+        v.push(g);
+    }
+    Ok(v)
+}
+
+fn read_affine_vec<G: AffineCurve, R: Read>(mut reader: R) -> IoResult<Vec<G>> {
+    let len = reader.read_u32::<BigEndian>()? as usize;
+    let mut v = vec![];
+    for i in 0..len {
+        let g = G::read(&mut reader).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid point {}: {}", i, e),
+            )
+        })?;
+        v.push(g);
+    }
+    Ok(v)
+}
+
+fn write_affine_vec<G: AffineCurve, W: Write>(v: &[G], mut writer: W) -> IoResult<()> {
+    writer.write_u32::<BigEndian>(v.len() as u32)?;
+    for g in v {
+        g.write(&mut writer)?;
     }
+    Ok(())
 }
 
 /// A verification key in the GM17 SNARK.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize)]
 pub struct VerifyingKey<E: PairingEngine> {
     pub h_g2: E::G2Affine,
     pub g_alpha_g1: E::G1Affine,
@@ -88,10 +174,72 @@ impl<E: PairingEngine> ToBytes for VerifyingKey<E> {
         self.h_beta_g2.write(&mut writer)?;
         self.g_gamma_g1.write(&mut writer)?;
         self.h_gamma_g2.write(&mut writer)?;
-        for q in &self.query {
-            q.write(&mut writer)?;
-        }
-        Ok(())
+        write_affine_vec(self.query.as_slice(), &mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for VerifyingKey<E> {
+    /// Doesn't perform group membership check for deserialized points
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let h_g2 = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_alpha_g1 = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_beta_g2 = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_gamma_g1 = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_gamma_g2 = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let query = read_affine_vec::<E::G1Affine, _>(&mut reader)?;
+
+        Ok(VerifyingKey {
+            h_g2,
+            g_alpha_g1,
+            h_beta_g2,
+            g_gamma_g1,
+            h_gamma_g2,
+            query,
+        })
+    }
+}
+
+impl<E: PairingEngine> SemanticallyValid for VerifyingKey<E> {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.h_g2.is_valid()
+            && !self.h_g2.is_zero()
+            && self.g_alpha_g1.is_valid()
+            && !self.g_alpha_g1.is_zero()
+            && self.h_beta_g2.is_valid()
+            && !self.h_beta_g2.is_zero()
+            && self.g_gamma_g1.is_valid()
+            && !self.g_gamma_g1.is_zero()
+            && self.h_gamma_g2.is_valid()
+            && !self.h_gamma_g2.is_zero()
+            && !self.query.iter().any(|p| !p.is_valid())
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for VerifyingKey<E> {
+    #[inline]
+    fn read_checked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let h_g2 = read_affine_checked::<E::G2Affine, _>("h_g2", true, &mut reader)?;
+        let g_alpha_g1 = read_affine_checked::<E::G1Affine, _>("g_alpha_g1", true, &mut reader)?;
+        let h_beta_g2 = read_affine_checked::<E::G2Affine, _>("h_beta_g2", true, &mut reader)?;
+        let g_gamma_g1 = read_affine_checked::<E::G1Affine, _>("g_gamma_g1", true, &mut reader)?;
+        let h_gamma_g2 = read_affine_checked::<E::G2Affine, _>("h_gamma_g2", true, &mut reader)?;
+        let query = read_affine_vec_checked::<E::G1Affine, _>("query", false, &mut reader)?;
+
+        Ok(VerifyingKey {
+            h_g2,
+            g_alpha_g1,
+            h_beta_g2,
+            g_gamma_g1,
+            h_gamma_g2,
+            query,
+        })
     }
 }
 
@@ -119,23 +267,8 @@ impl<E: PairingEngine> PartialEq for VerifyingKey<E> {
     }
 }
 
-impl<E: PairingEngine> VerifyingKey<E> {
-    /// Serialize the verification key into bytes, for storage on disk
-    /// or transmission over the network.
-    pub fn write<W: Write>(&self, mut _writer: W) -> io::Result<()> {
-        // TODO: implement serialization
-        unimplemented!()
-    }
-
-    /// Deserialize the verification key from bytes.
-    pub fn read<R: Read>(mut _reader: R) -> io::Result<Self> {
-        // TODO: implement serialization
-        unimplemented!()
-    }
-}
-
 /// Full public (prover and verifier) parameters for the GM17 zkSNARK.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Parameters<E: PairingEngine> {
     pub vk: VerifyingKey<E>,
     pub a_query: Vec<E::G1Affine>,
@@ -164,23 +297,116 @@ impl<E: PairingEngine> PartialEq for Parameters<E> {
     }
 }
 
-impl<E: PairingEngine> Parameters<E> {
-    /// Serialize the parameters to bytes.
-    pub fn write<W: Write>(&self, mut _writer: W) -> io::Result<()> {
-        // TODO: implement serialization
-        unimplemented!()
+impl<E: PairingEngine> ToBytes for Parameters<E> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.vk.write(&mut writer)?;
+        write_affine_vec(self.a_query.as_slice(), &mut writer)?;
+        write_affine_vec(self.b_query.as_slice(), &mut writer)?;
+        write_affine_vec(self.c_query_1.as_slice(), &mut writer)?;
+        write_affine_vec(self.c_query_2.as_slice(), &mut writer)?;
+        self.g_gamma_z.write(&mut writer)?;
+        self.h_gamma_z.write(&mut writer)?;
+        self.g_ab_gamma_z.write(&mut writer)?;
+        self.g_gamma2_z2.write(&mut writer)?;
+        write_affine_vec(self.g_gamma2_z_t.as_slice(), &mut writer)
     }
+}
 
-    /// Deserialize the public parameters from bytes.
-    pub fn read<R: Read>(mut _reader: R, _checked: bool) -> io::Result<Self> {
-        // TODO: implement serialization
-        unimplemented!()
+impl<E: PairingEngine> FromBytes for Parameters<E> {
+    /// Doesn't perform group membership check for deserialized points
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let vk = VerifyingKey::<E>::read(&mut reader)?;
+        let a_query = read_affine_vec::<E::G1Affine, _>(&mut reader)?;
+        let b_query = read_affine_vec::<E::G2Affine, _>(&mut reader)?;
+        let c_query_1 = read_affine_vec::<E::G1Affine, _>(&mut reader)?;
+        let c_query_2 = read_affine_vec::<E::G1Affine, _>(&mut reader)?;
+        let g_gamma_z = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_gamma_z = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_ab_gamma_z = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_gamma2_z2 = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_gamma2_z_t = read_affine_vec::<E::G1Affine, _>(&mut reader)?;
+
+        Ok(Parameters {
+            vk,
+            a_query,
+            b_query,
+            c_query_1,
+            c_query_2,
+            g_gamma_z,
+            h_gamma_z,
+            g_ab_gamma_z,
+            g_gamma2_z2,
+            g_gamma2_z_t,
+        })
+    }
+}
+
+impl<E: PairingEngine> SemanticallyValid for Parameters<E> {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.vk.is_valid()
+            && !self.a_query.iter().any(|p| !p.is_valid())
+            && !self.b_query.iter().any(|p| !p.is_valid())
+            && !self.c_query_1.iter().any(|p| !p.is_valid())
+            && !self.c_query_2.iter().any(|p| !p.is_valid())
+            && self.g_gamma_z.is_valid()
+            && !self.g_gamma_z.is_zero()
+            && self.h_gamma_z.is_valid()
+            && !self.h_gamma_z.is_zero()
+            && self.g_ab_gamma_z.is_valid()
+            && !self.g_ab_gamma_z.is_zero()
+            && self.g_gamma2_z2.is_valid()
+            && !self.g_gamma2_z2.is_zero()
+            && !self
+                .g_gamma2_z_t
+                .iter()
+                .any(|p| !p.is_valid() || p.is_zero())
+    }
+}
+
+impl<E: PairingEngine> FromBytesChecked for Parameters<E> {
+    #[inline]
+    fn read_checked<R: Read>(mut reader: R) -> IoResult<Self> {
+        let vk = VerifyingKey::<E>::read_checked(&mut reader)?;
+
+        // NOTE: the a, b and c queries also contain polynomials that evaluate to zero,
+        // therefore the zero check is disabled.
+        let a_query = read_affine_vec_checked::<E::G1Affine, _>("a_query", false, &mut reader)?;
+        let b_query = read_affine_vec_checked::<E::G2Affine, _>("b_query", false, &mut reader)?;
+        let c_query_1 = read_affine_vec_checked::<E::G1Affine, _>("c_query_1", false, &mut reader)?;
+        let c_query_2 = read_affine_vec_checked::<E::G1Affine, _>("c_query_2", false, &mut reader)?;
+        let g_gamma_z = read_affine_checked::<E::G1Affine, _>("g_gamma_z", true, &mut reader)?;
+        let h_gamma_z = read_affine_checked::<E::G2Affine, _>("h_gamma_z", true, &mut reader)?;
+        let g_ab_gamma_z =
+            read_affine_checked::<E::G1Affine, _>("g_ab_gamma_z", true, &mut reader)?;
+        let g_gamma2_z2 = read_affine_checked::<E::G1Affine, _>("g_gamma2_z2", true, &mut reader)?;
+        let g_gamma2_z_t =
+            read_affine_vec_checked::<E::G1Affine, _>("g_gamma2_z_t", true, &mut reader)?;
+
+        Ok(Parameters {
+            vk,
+            a_query,
+            b_query,
+            c_query_1,
+            c_query_2,
+            g_gamma_z,
+            h_gamma_z,
+            g_ab_gamma_z,
+            g_gamma2_z2,
+            g_gamma2_z_t,
+        })
     }
 }
 
 /// Preprocessed verification key parameters that enable faster verification
 /// at the expense of larger size in memory.
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PreparedVerifyingKey<E: PairingEngine> {
     pub vk: VerifyingKey<E>,
     pub g_alpha: E::G1Affine,
@@ -228,10 +454,38 @@ impl<E: PairingEngine> ToBytes for PreparedVerifyingKey<E> {
         self.g_gamma_pc.write(&mut writer)?;
         self.h_gamma_pc.write(&mut writer)?;
         self.h_pc.write(&mut writer)?;
-        for q in &self.query {
-            q.write(&mut writer)?;
-        }
-        Ok(())
+        write_affine_vec(self.query.as_slice(), &mut writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PreparedVerifyingKey<E> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let vk = VerifyingKey::<E>::read(&mut reader)?;
+        let g_alpha = E::G1Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_beta = E::G2Affine::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_alpha_h_beta_ml =
+            E::Fqk::read(&mut reader).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let g_gamma_pc = E::G1Prepared::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_gamma_pc = E::G2Prepared::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let h_pc = E::G2Prepared::read(&mut reader)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let query = read_affine_vec::<E::G1Affine, _>(&mut reader)?;
+
+        Ok(PreparedVerifyingKey {
+            vk,
+            g_alpha,
+            h_beta,
+            g_alpha_h_beta_ml,
+            g_gamma_pc,
+            h_gamma_pc,
+            h_pc,
+            query,
+        })
     }
 }
 
@@ -33,11 +33,13 @@ mod test {
     use super::*;
     use crate::gm17::{
         create_random_proof, generate_random_parameters, prepare_verifying_key, verify_proof,
+        Parameters, Proof, VerifyingKey,
     };
 
     use algebra::{
         curves::{bls12_377::Bls12_377, mnt4753::MNT4, mnt6753::MNT6, sw6::SW6},
-        PairingEngine, UniformRand,
+        serialize::test_canonical_serialize_deserialize,
+        to_bytes, FromBytes, FromBytesChecked, PairingEngine, ToBytes, UniformRand,
     };
     use rand::thread_rng;
     use std::ops::MulAssign;
@@ -72,6 +74,66 @@ mod test {
         }
     }
 
+    fn test_serialize_deserialize<E: PairingEngine>() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<E, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let a = <E as PairingEngine>::Fr::rand(rng);
+        let b = <E as PairingEngine>::Fr::rand(rng);
+        let c = a * &b;
+
+        let proof = create_random_proof(
+            MySillyCircuit {
+                a: Some(a),
+                b: Some(b),
+            },
+            &params,
+            rng,
+        )
+        .unwrap();
+
+        // CanonicalSerialize/CanonicalDeserialize, including malleability checks
+        test_canonical_serialize_deserialize(true, &proof);
+        test_canonical_serialize_deserialize(true, &params.vk);
+        test_canonical_serialize_deserialize(true, &params);
+
+        // ToBytes/FromBytes
+        let params_serialized = to_bytes!(params).unwrap();
+        assert_eq!(
+            params,
+            Parameters::<E>::read(params_serialized.as_slice()).unwrap()
+        );
+        let params_deserialized =
+            Parameters::<E>::read_checked(params_serialized.as_slice()).unwrap();
+        assert_eq!(params, params_deserialized);
+
+        let vk_serialized = to_bytes!(params.vk).unwrap();
+        let vk_deserialized = VerifyingKey::<E>::read_checked(vk_serialized.as_slice()).unwrap();
+        assert_eq!(params.vk, vk_deserialized);
+
+        let proof_serialized = to_bytes!(proof).unwrap();
+        let proof_deserialized = Proof::<E>::read_checked(proof_serialized.as_slice()).unwrap();
+        assert_eq!(proof, proof_deserialized);
+
+        // Truncated inputs must be rejected
+        assert!(Proof::<E>::read_checked(&proof_serialized[..proof_serialized.len() - 1]).is_err());
+        assert!(VerifyingKey::<E>::read(&vk_serialized[..vk_serialized.len() - 1]).is_err());
+
+        let pvk = prepare_verifying_key(&vk_deserialized).unwrap();
+        assert!(verify_proof(&pvk, &proof_deserialized, &[c]).unwrap());
+    }
+
+    #[test]
+    fn serialize_deserialize() {
+        test_serialize_deserialize::<Bls12_377>();
+        test_serialize_deserialize::<SW6>();
+        test_serialize_deserialize::<MNT4>();
+        test_serialize_deserialize::<MNT6>();
+    }
+
     #[test]
     fn prove_verify() {
         test_prove_and_verify::<Bls12_377>();
@@ -38,8 +38,8 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
 mod test {
     use super::*;
     use crate::groth16::{
-        create_proof_no_zk, create_random_proof, generate_random_parameters, prepare_verifying_key,
-        verify_proof, Parameters, PreparedVerifyingKey, Proof, VerifyingKey,
+        batch_verify_proofs, create_proof_no_zk, create_random_proof, generate_random_parameters,
+        prepare_verifying_key, verify_proof, Parameters, PreparedVerifyingKey, Proof, VerifyingKey,
     };
 
     use algebra::{to_bytes, FromBytes, PairingEngine, ToBytes, UniformRand};
@@ -87,6 +87,73 @@ mod test {
         }
     }
 
+    fn batch_prove_and_verify<E: PairingEngine>() {
+        let rng = &mut thread_rng();
+
+        let params =
+            generate_random_parameters::<E, _, _>(MySillyCircuit { a: None, b: None }, rng)
+                .unwrap();
+
+        let pvk = prepare_verifying_key::<E>(&params.vk);
+
+        let mut proofs_and_inputs = (0..10)
+            .map(|_| {
+                let a = E::Fr::rand(rng);
+                let b = E::Fr::rand(rng);
+                let c = a * &b;
+
+                let proof = create_random_proof(
+                    MySillyCircuit {
+                        a: Some(a),
+                        b: Some(b),
+                    },
+                    &params,
+                    rng,
+                )
+                .unwrap();
+                (proof, vec![c])
+            })
+            .collect::<Vec<_>>();
+
+        // Empty batch and honest batch must pass
+        assert!(batch_verify_proofs(&pvk, &[], rng).unwrap().is_empty());
+        assert!(batch_verify_proofs(&pvk, &proofs_and_inputs, rng)
+            .unwrap()
+            .is_empty());
+
+        // A single wrong public input must be located
+        let c = proofs_and_inputs[3].1[0];
+        proofs_and_inputs[3].1[0] = E::Fr::rand(rng);
+        assert_eq!(
+            batch_verify_proofs(&pvk, &proofs_and_inputs, rng).unwrap(),
+            vec![3]
+        );
+        proofs_and_inputs[3].1[0] = c;
+
+        // Swapping the proofs of two instances must make both of them fail
+        let proof = proofs_and_inputs[5].0.clone();
+        proofs_and_inputs[5].0 = proofs_and_inputs[7].0.clone();
+        proofs_and_inputs[7].0 = proof;
+        assert_eq!(
+            batch_verify_proofs(&pvk, &proofs_and_inputs, rng).unwrap(),
+            vec![5, 7]
+        );
+
+        // Malformed public inputs are reported together with the invalid proofs
+        proofs_and_inputs[2].1.push(E::Fr::rand(rng));
+        proofs_and_inputs[8].1.clear();
+        assert_eq!(
+            batch_verify_proofs(&pvk, &proofs_and_inputs, rng).unwrap(),
+            vec![2, 5, 7, 8]
+        );
+
+        // A batch of invalid proofs only
+        assert_eq!(
+            batch_verify_proofs(&pvk, &proofs_and_inputs[5..9], rng).unwrap(),
+            vec![0, 2, 3]
+        );
+    }
+
     fn serialize_deserialize<E: PairingEngine>() {
         let rng = &mut thread_rng();
 
@@ -136,6 +203,7 @@ mod test {
     fn bls12_377_groth16_test() {
         prove_and_verify::<algebra::curves::bls12_377::Bls12_377>(true);
         prove_and_verify::<algebra::curves::bls12_377::Bls12_377>(false);
+        batch_prove_and_verify::<algebra::curves::bls12_377::Bls12_377>();
         serialize_deserialize::<algebra::curves::bls12_377::Bls12_377>();
     }
 
@@ -143,6 +211,7 @@ mod test {
     fn sw6_groth16_test() {
         prove_and_verify::<algebra::curves::sw6::SW6>(true);
         prove_and_verify::<algebra::curves::sw6::SW6>(false);
+        batch_prove_and_verify::<algebra::curves::sw6::SW6>();
         serialize_deserialize::<algebra::curves::sw6::SW6>();
     }
 
@@ -150,6 +219,7 @@ mod test {
     fn mnt4753_groth16_test() {
         prove_and_verify::<algebra::curves::mnt4753::MNT4>(true);
         prove_and_verify::<algebra::curves::mnt4753::MNT4>(false);
+        batch_prove_and_verify::<algebra::curves::mnt4753::MNT4>();
         serialize_deserialize::<algebra::curves::mnt4753::MNT4>();
     }
 
@@ -157,6 +227,7 @@ mod test {
     fn mnt6753_groth16_test() {
         prove_and_verify::<algebra::curves::mnt6753::MNT6>(true);
         prove_and_verify::<algebra::curves::mnt6753::MNT6>(false);
+        batch_prove_and_verify::<algebra::curves::mnt6753::MNT6>();
         serialize_deserialize::<algebra::curves::mnt6753::MNT6>();
     }
 
@@ -164,6 +235,7 @@ mod test {
     fn bn_382_groth16_test() {
         prove_and_verify::<algebra::curves::bn_382::Bn382>(true);
         prove_and_verify::<algebra::curves::bn_382::Bn382>(false);
+        batch_prove_and_verify::<algebra::curves::bn_382::Bn382>();
         serialize_deserialize::<algebra::curves::bn_382::Bn382>();
     }
 }
@@ -1,4 +1,8 @@
-use algebra::{AffineCurve, PairingEngine, PrimeField, ProjectiveCurve};
+use algebra::{
+    msm::VariableBaseMSM, AffineCurve, Field, PairingEngine, PrimeField, ProjectiveCurve,
+    UniformRand,
+};
+use rand::RngCore;
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
 
@@ -50,3 +54,147 @@ pub fn verify_proof<E: PairingEngine>(
 
     Ok(test == pvk.alpha_g1_beta_g2)
 }
+
+/// Verify a batch of proofs, all of them belonging to the same `pvk`.
+/// The single verification equations are combined by means of random linear
+/// combinations, so that the whole batch costs a single multi-Miller loop and
+/// a single final exponentiation:
+/// Sum_i r_i * (A_i * B_i) + (Sum_i r_i * inputs_i) * (-gamma) + (Sum_i r_i * C_i) * (-delta)
+///     = (Sum_i r_i) * (alpha * beta)
+/// where the aggregation of the inputs and of the C_i is done via multi-scalar
+/// multiplication.
+/// If the combined check fails, the failing proofs are located by bisection: each half
+/// of a failing batch is checked again (with fresh random coefficients), down to single
+/// proofs, so that locating `k` invalid proofs among `n` costs `O(k * log(n))` batch checks.
+/// Return the indices, in increasing order, of the proofs which do not verify, including
+/// the ones whose number of public inputs does not match `pvk`: hence the batch verifies
+/// iff the returned vector is empty. As such, an empty batch trivially verifies.
+pub fn batch_verify_proofs<E: PairingEngine, R: RngCore>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(Proof<E>, Vec<E::Fr>)],
+    rng: &mut R,
+) -> Result<Vec<usize>, SynthesisError> {
+    if pvk.gamma_abc_g1.is_empty() {
+        return Err(SynthesisError::MalformedVerifyingKey);
+    }
+
+    // The proofs which do not come with the number of public inputs expected by the vk
+    // fail, while the others are checked in batch.
+    let num_inputs = pvk.gamma_abc_g1.len() - 1;
+    let (well_formed, mut failing): (Vec<usize>, Vec<usize>) =
+        (0..proofs_and_inputs.len()).partition(|&i| proofs_and_inputs[i].1.len() == num_inputs);
+
+    if !well_formed.is_empty() {
+        find_failing_proofs(
+            pvk,
+            proofs_and_inputs,
+            &well_formed,
+            false,
+            &mut failing,
+            rng,
+        )?;
+    }
+
+    failing.sort_unstable();
+    Ok(failing)
+}
+
+/// Push to `failing` the indices of the proofs among `indices` which do not verify.
+/// If `known_to_fail`, the combined check of the proofs at `indices` is known to fail
+/// already, hence it is skipped.
+fn find_failing_proofs<E: PairingEngine, R: RngCore>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(Proof<E>, Vec<E::Fr>)],
+    indices: &[usize],
+    known_to_fail: bool,
+    failing: &mut Vec<usize>,
+    rng: &mut R,
+) -> Result<(), SynthesisError> {
+    if !known_to_fail && batch_check(pvk, proofs_and_inputs, indices, rng)? {
+        return Ok(());
+    }
+
+    if indices.len() == 1 {
+        failing.push(indices[0]);
+        return Ok(());
+    }
+
+    // Valid proofs satisfy any linear combination of their verification equations,
+    // hence if the first half verifies, the failure is in the second one.
+    let (first, second) = indices.split_at(indices.len() / 2);
+    let num_failing = failing.len();
+    find_failing_proofs(pvk, proofs_and_inputs, first, false, failing, rng)?;
+    find_failing_proofs(
+        pvk,
+        proofs_and_inputs,
+        second,
+        failing.len() == num_failing,
+        failing,
+        rng,
+    )
+}
+
+/// Check the random linear combination of the verification equations of the proofs at
+/// `indices`, whose public inputs are assumed to be well formed with respect to `pvk`.
+fn batch_check<E: PairingEngine, R: RngCore>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(Proof<E>, Vec<E::Fr>)],
+    indices: &[usize],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    let num_inputs = pvk.gamma_abc_g1.len() - 1;
+    let batch = indices
+        .iter()
+        .map(|&i| &proofs_and_inputs[i])
+        .collect::<Vec<_>>();
+
+    // Sample the random coefficients of the linear combination. The first one
+    // can be set to one without loss of soundness.
+    let mut r_s = vec![E::Fr::one()];
+    r_s.extend((1..batch.len()).map(|_| E::Fr::rand(rng)));
+    let r_sum = r_s.iter().fold(E::Fr::zero(), |acc, r| acc + r);
+
+    // Aggregate the public inputs: the coefficient of the j-th input base is
+    // Sum_i r_i * inputs_i[j], while the one of the constant base is Sum_i r_i.
+    let mut input_scalars = vec![r_sum];
+    input_scalars.extend((0..num_inputs).map(|j| {
+        batch
+            .iter()
+            .zip(r_s.iter())
+            .fold(E::Fr::zero(), |acc, ((_, public_inputs), r)| {
+                acc + &(public_inputs[j] * r)
+            })
+    }));
+    let input_scalars = input_scalars
+        .into_iter()
+        .map(|s| s.into_repr())
+        .collect::<Vec<_>>();
+    let g_ic = VariableBaseMSM::multi_scalar_mul(pvk.gamma_abc_g1.as_slice(), &input_scalars)?;
+
+    // Aggregate the C_i
+    let r_s_repr = r_s.iter().map(|r| r.into_repr()).collect::<Vec<_>>();
+    let c_s = batch.iter().map(|(proof, _)| proof.c).collect::<Vec<_>>();
+    let c_acc = VariableBaseMSM::multi_scalar_mul(c_s.as_slice(), &r_s_repr)?;
+
+    // Randomize the A_i
+    let a_s = E::G1Projective::batch_normalization_into_affine(
+        batch
+            .iter()
+            .zip(r_s_repr.iter())
+            .map(|((proof, _), r)| proof.a.mul(*r))
+            .collect(),
+    );
+
+    let mut pairs = a_s
+        .into_iter()
+        .zip(batch.iter())
+        .map(|(a, (proof, _))| (a.into(), proof.b.into()))
+        .collect::<Vec<_>>();
+    pairs.push((g_ic.into_affine().into(), pvk.gamma_g2_neg_pc.clone()));
+    pairs.push((c_acc.into_affine().into(), pvk.delta_g2_neg_pc.clone()));
+
+    let qap = E::miller_loop(pairs.iter())?;
+    let test = E::final_exponentiation(&qap)?;
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(r_sum.into_repr()))
+}
@@ -0,0 +1,809 @@
+//! Gadgets for the hash to curve construction of `algebra::hash_to_curve`, with
+//! `expand_message_xmd` instantiated with SHA-256.
+use crate::{crh::FixedLengthCRHGadget, prf::sha256::sha256};
+use algebra::{
+    curves::{models::SWModelParameters, short_weierstrass_jacobian::GroupAffine},
+    hash_to_curve::{
+        check_domain_separation_tag, HashToField, MapToCurve, SWUParameters, SvdWParameters,
+    },
+    BitIterator, Field, PrimeField, QuadExtField, SquareRootField,
+};
+use primitives::crh::hash_to_curve::{HashToCurveCRH, HashToCurveCRHParameters};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::{
+        fp::FpGadget,
+        quadratic_extension::{QuadExtFieldGadget, QuadExtParametersGadget},
+        SquareRootFieldGadget,
+    },
+    groups::curves::short_weierstrass::AffineGadget,
+    prelude::*,
+};
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// The byte length of a SHA-256 digest.
+const SHA256_OUTPUT_BYTES: usize = 32;
+
+/// The byte length of a SHA-256 block.
+const SHA256_BLOCK_BYTES: usize = 64;
+
+fn enforce_sha256_bytes<ConstraintF, CS>(
+    cs: CS,
+    input: &[UInt8],
+) -> Result<Vec<UInt8>, SynthesisError>
+where
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let input_bits = input
+        .iter()
+        .flat_map(|byte| byte.to_bits_be())
+        .collect::<Vec<_>>();
+    Ok(sha256(cs, &input_bits)?
+        .chunks(8)
+        .map(UInt8::from_bits_be)
+        .collect())
+}
+
+/// Enforce `expand_message_xmd` with SHA-256, expanding `msg` to `len_in_bytes` bytes
+/// domain separated by the constant `dst`.
+pub fn enforce_expand_message_xmd_sha256<ConstraintF, CS>(
+    mut cs: CS,
+    msg: &[UInt8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<UInt8>, SynthesisError>
+where
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    check_domain_separation_tag(dst).map_err(|e| SynthesisError::Other(e.to_string()))?;
+    let ell = (len_in_bytes + SHA256_OUTPUT_BYTES - 1) / SHA256_OUTPUT_BYTES;
+    if len_in_bytes == 0 || ell > 255 {
+        return Err(SynthesisError::Other(format!(
+            "cannot expand to {} bytes",
+            len_in_bytes
+        )));
+    }
+
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+    let dst_prime = UInt8::constant_vec(&dst_prime);
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let mut msg_prime = UInt8::constant_vec(&[0u8; SHA256_BLOCK_BYTES]);
+    msg_prime.extend_from_slice(msg);
+    msg_prime.extend(UInt8::constant_vec(&(len_in_bytes as u16).to_be_bytes()));
+    msg_prime.push(UInt8::constant(0u8));
+    msg_prime.extend_from_slice(&dst_prime);
+    let b_0 = enforce_sha256_bytes(cs.ns(|| "b_0"), &msg_prime)?;
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_BYTES);
+    let mut b_i = b_0.clone();
+    for i in 1..=ell {
+        let mut preimage = if i == 1 {
+            b_0.clone()
+        } else {
+            b_0.iter()
+                .zip(b_i.iter())
+                .enumerate()
+                .map(|(j, (b0, bi))| {
+                    b0.xor(cs.ns(|| format!("b_0 xor b_{} byte {}", i - 1, j)), bi)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+        preimage.push(UInt8::constant(i as u8));
+        preimage.extend_from_slice(&dst_prime);
+        b_i = enforce_sha256_bytes(cs.ns(|| format!("b_{}", i)), &preimage)?;
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// The gadget counterpart of `HashToField`.
+pub trait HashToFieldGadget<F: HashToField, ConstraintF: PrimeField>: Sized {
+    /// Enforce the reduction of `F::uniform_bytes_len()` bytes to an element of `F`.
+    fn enforce_from_uniform_bytes<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        bytes: &[UInt8],
+    ) -> Result<Self, SynthesisError>;
+
+    /// Return a `Boolean` which is true if and only if `HashToField::sgn0` of `self` is.
+    fn enforce_sgn0<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Boolean, SynthesisError>;
+}
+
+impl<F: PrimeField> HashToFieldGadget<F, F> for FpGadget<F> {
+    /// The big endian bytes are packed into a linear combination, whose coefficients
+    /// are the powers of two reduced modulo the field characteristic.
+    fn enforce_from_uniform_bytes<CS: ConstraintSystemAbstract<F>>(
+        mut cs: CS,
+        bytes: &[UInt8],
+    ) -> Result<Self, SynthesisError> {
+        if bytes.len() != F::uniform_bytes_len() {
+            return Err(SynthesisError::Other(format!(
+                "expected {} uniform bytes, found {}",
+                F::uniform_bytes_len(),
+                bytes.len()
+            )));
+        }
+        let mut result = Self::zero(cs.ns(|| "zero"))?;
+        let mut coeff = F::one();
+        for (i, bit) in bytes
+            .iter()
+            .rev()
+            .flat_map(|byte| byte.to_bits_le())
+            .enumerate()
+        {
+            result = result.conditionally_add_constant(
+                cs.ns(|| format!("add bit {}", i)),
+                &bit,
+                coeff,
+            )?;
+            coeff.double_in_place();
+        }
+        Ok(result)
+    }
+
+    fn enforce_sgn0<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        cs: CS,
+    ) -> Result<Boolean, SynthesisError> {
+        self.is_odd(cs)
+    }
+}
+
+impl<P, ConstraintF> HashToFieldGadget<QuadExtField<P>, ConstraintF>
+    for QuadExtFieldGadget<P, ConstraintF>
+where
+    P: QuadExtParametersGadget<ConstraintF>,
+    P::BaseField: HashToField,
+    P::BaseFieldGadget: HashToFieldGadget<P::BaseField, ConstraintF>,
+    ConstraintF: PrimeField + SquareRootField,
+{
+    fn enforce_from_uniform_bytes<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        bytes: &[UInt8],
+    ) -> Result<Self, SynthesisError> {
+        if bytes.len() != QuadExtField::<P>::uniform_bytes_len() {
+            return Err(SynthesisError::Other(format!(
+                "expected {} uniform bytes, found {}",
+                QuadExtField::<P>::uniform_bytes_len(),
+                bytes.len()
+            )));
+        }
+        let (c0_bytes, c1_bytes) = bytes.split_at(P::BaseField::uniform_bytes_len());
+        let c0 = P::BaseFieldGadget::enforce_from_uniform_bytes(cs.ns(|| "c0"), c0_bytes)?;
+        let c1 = P::BaseFieldGadget::enforce_from_uniform_bytes(cs.ns(|| "c1"), c1_bytes)?;
+        Ok(Self::new(c0, c1))
+    }
+
+    /// `sgn0(c0)`, or `sgn0(c1)` if `c0` is zero.
+    fn enforce_sgn0<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Boolean, SynthesisError> {
+        let zero = P::BaseFieldGadget::zero(cs.ns(|| "zero"))?;
+        let c0_sgn0 = self.c0.enforce_sgn0(cs.ns(|| "sgn0(c0)"))?;
+        let c0_is_zero = self.c0.is_eq(cs.ns(|| "c0 is zero"), &zero)?;
+        let c1_sgn0 = self.c1.enforce_sgn0(cs.ns(|| "sgn0(c1)"))?;
+        let c0_is_zero_and_c1_sgn0 =
+            Boolean::and(cs.ns(|| "c0 is zero and sgn0(c1)"), &c0_is_zero, &c1_sgn0)?;
+        Boolean::or(cs.ns(|| "sgn0"), &c0_sgn0, &c0_is_zero_and_c1_sgn0)
+    }
+}
+
+/// The gadget counterpart of `MapToCurve`, over the curve defined by `P`.
+pub trait MapToCurveGadget<P, ConstraintF, F>
+where
+    P: SWModelParameters,
+    P::BaseField: SquareRootField + HashToField,
+    ConstraintF: PrimeField,
+    F: SquareRootFieldGadget<P::BaseField, ConstraintF>
+        + HashToFieldGadget<P::BaseField, ConstraintF>,
+{
+    /// The native map.
+    type Map: MapToCurve<GroupAffine<P>>;
+
+    /// Enforce the map of `u` to the curve. Differently from the native map, the
+    /// neutral element is never output: the (negligibly likely) inputs mapped to it
+    /// make the constraint system unsatisfiable.
+    fn enforce_map_to_curve<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        u: &F,
+    ) -> Result<AffineGadget<P, ConstraintF, F>, SynthesisError>;
+}
+
+/// Enforce `g(x) = x^3 + a * x + b`.
+fn enforce_curve_equation<F, ConstraintF, FG, CS>(
+    mut cs: CS,
+    x: &FG,
+    a: &F,
+    b: &F,
+) -> Result<FG, SynthesisError>
+where
+    F: Field,
+    ConstraintF: PrimeField,
+    FG: FieldGadget<F, ConstraintF>,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    x.square(cs.ns(|| "x^2"))?
+        .add_constant(cs.ns(|| "x^2 + a"), a)?
+        .mul(cs.ns(|| "x^3 + a * x"), x)?
+        .add_constant(cs.ns(|| "x^3 + a * x + b"), b)
+}
+
+/// Enforce `inv0(x)`, i.e. the inverse of `x` if non zero, zero otherwise.
+fn enforce_inverse_or_zero<F, ConstraintF, FG, CS>(mut cs: CS, x: &FG) -> Result<FG, SynthesisError>
+where
+    F: Field,
+    ConstraintF: PrimeField,
+    FG: FieldGadget<F, ConstraintF>,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let zero = FG::zero(cs.ns(|| "zero"))?;
+    let one = FG::one(cs.ns(|| "one"))?;
+    let is_zero = x.is_eq(cs.ns(|| "x == 0"), &zero)?;
+    let non_zero = FG::conditionally_select(cs.ns(|| "x or one"), &is_zero, &one, x)?;
+    let inverse = non_zero.inverse(cs.ns(|| "inverse"))?;
+    FG::conditionally_select(cs.ns(|| "inv0(x)"), &is_zero, &zero, &inverse)
+}
+
+/// Enforce the evaluation at `x` of the polynomial with constant, non empty, `coeffs`,
+/// from the constant term on.
+fn enforce_polynomial<F, ConstraintF, FG, CS>(
+    mut cs: CS,
+    coeffs: &[F],
+    x: &FG,
+) -> Result<FG, SynthesisError>
+where
+    F: Field,
+    ConstraintF: PrimeField,
+    FG: FieldGadget<F, ConstraintF>,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let (leading, rest) = coeffs
+        .split_last()
+        .ok_or_else(|| SynthesisError::Other("empty polynomial".to_owned()))?;
+    let mut result = FG::from_value(cs.ns(|| "leading coefficient"), leading);
+    for (i, coeff) in rest.iter().rev().enumerate() {
+        result = if i == 0 {
+            x.mul_by_constant(cs.ns(|| format!("mul {}", i)), leading)?
+        } else {
+            result.mul(cs.ns(|| format!("mul {}", i)), x)?
+        };
+        result = result.add_constant(cs.ns(|| format!("add {}", i)), coeff)?;
+    }
+    Ok(result)
+}
+
+/// Enforce the square root of `x` whose `sgn0` is `sign`.
+fn enforce_sqrt_with_sgn0<F, ConstraintF, FG, CS>(
+    mut cs: CS,
+    x: &FG,
+    sign: &Boolean,
+) -> Result<FG, SynthesisError>
+where
+    F: SquareRootField + HashToField,
+    ConstraintF: PrimeField,
+    FG: SquareRootFieldGadget<F, ConstraintF> + HashToFieldGadget<F, ConstraintF>,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let root = x.sqrt(cs.ns(|| "root"))?;
+    let neg_root = root.negate(cs.ns(|| "-root"))?;
+    let root_sgn0 = root.enforce_sgn0(cs.ns(|| "sgn0(root)"))?;
+    let flip = Boolean::xor(cs.ns(|| "sgn0(root) != sign"), &root_sgn0, sign)?;
+    FG::conditionally_select(cs.ns(|| "signed root"), &flip, &neg_root, &root)
+}
+
+/// Gadget for the simplified SWU map `SWUMap`, composed with the isogeny.
+pub struct SWUMapGadget<P: SWUParameters>(PhantomData<P>);
+
+impl<P, ConstraintF, F> MapToCurveGadget<P, ConstraintF, F> for SWUMapGadget<P>
+where
+    P: SWUParameters,
+    P::BaseField: SquareRootField + HashToField,
+    ConstraintF: PrimeField,
+    F: SquareRootFieldGadget<P::BaseField, ConstraintF>
+        + HashToFieldGadget<P::BaseField, ConstraintF>,
+{
+    type Map = algebra::hash_to_curve::SWUMap<P>;
+
+    fn enforce_map_to_curve<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        u: &F,
+    ) -> Result<AffineGadget<P, ConstraintF, F>, SynthesisError> {
+        let a = P::ISOGENOUS_COEFF_A;
+        let b = P::ISOGENOUS_COEFF_B;
+        let z = P::SWU_Z;
+        let one = P::BaseField::one();
+
+        // tv1 = inv0(Z^2 * u^4 + Z * u^2)
+        let z_u2 = u
+            .square(cs.ns(|| "u^2"))?
+            .mul_by_constant(cs.ns(|| "Z * u^2"), &z)?;
+        let tv1 = z_u2
+            .square(cs.ns(|| "Z^2 * u^4"))?
+            .add(cs.ns(|| "Z^2 * u^4 + Z * u^2"), &z_u2)?;
+        let tv1 = enforce_inverse_or_zero(cs.ns(|| "tv1"), &tv1)?;
+
+        // x1 = (-B / A) * (1 + tv1), or B / (Z * A) if tv1 == 0
+        let zero = F::zero(cs.ns(|| "zero"))?;
+        let tv1_is_zero = tv1.is_eq(cs.ns(|| "tv1 == 0"), &zero)?;
+        let x1 = tv1
+            .add_constant(cs.ns(|| "1 + tv1"), &one)?
+            .mul_by_constant(
+                cs.ns(|| "(-B * A^-1) * (1 + tv1)"),
+                &(-b * &a.inverse().unwrap()),
+            )?;
+        let exceptional_x1 = F::from_value(
+            cs.ns(|| "B * (Z * A)^-1"),
+            &(b * &(z * &a).inverse().unwrap()),
+        );
+        let x1 = F::conditionally_select(cs.ns(|| "x1"), &tv1_is_zero, &exceptional_x1, &x1)?;
+
+        // If g(x1) is square, then x = x1, otherwise x = Z * u^2 * x1
+        let gx1 = enforce_curve_equation(cs.ns(|| "g(x1)"), &x1, &a, &b)?;
+        let x2 = z_u2.mul(cs.ns(|| "x2"), &x1)?;
+        let gx2 = enforce_curve_equation(cs.ns(|| "g(x2)"), &x2, &a, &b)?;
+        let gx1_is_square = gx1.is_square(cs.ns(|| "g(x1) is square"))?;
+        let x = F::conditionally_select(cs.ns(|| "x"), &gx1_is_square, &x1, &x2)?;
+        let gx = F::conditionally_select(cs.ns(|| "g(x)"), &gx1_is_square, &gx1, &gx2)?;
+        let u_sgn0 = u.enforce_sgn0(cs.ns(|| "sgn0(u)"))?;
+        let y = enforce_sqrt_with_sgn0(cs.ns(|| "y"), &gx, &u_sgn0)?;
+
+        // Apply the isogeny
+        let x_num = enforce_polynomial(cs.ns(|| "x_num"), P::ISOGENY_X_NUM, &x)?;
+        let x_den = enforce_polynomial(cs.ns(|| "x_den"), P::ISOGENY_X_DEN, &x)?;
+        let y_num = enforce_polynomial(cs.ns(|| "y_num"), P::ISOGENY_Y_NUM, &x)?;
+        let y_den = enforce_polynomial(cs.ns(|| "y_den"), P::ISOGENY_Y_DEN, &x)?;
+        let x_den_inv = x_den.inverse(cs.ns(|| "x_den inverse"))?;
+        let y_den_inv = y_den.inverse(cs.ns(|| "y_den inverse"))?;
+        let iso_x = x_num.mul(cs.ns(|| "x_num * x_den^-1"), &x_den_inv)?;
+        let iso_y = y
+            .mul(cs.ns(|| "y * y_num"), &y_num)?
+            .mul(cs.ns(|| "y * y_num * y_den^-1"), &y_den_inv)?;
+
+        Ok(AffineGadget::new(iso_x, iso_y, Boolean::constant(false)))
+    }
+}
+
+/// Gadget for the Shallue-van de Woestijne map `SvdWMap`.
+pub struct SvdWMapGadget<P: SvdWParameters>(PhantomData<P>);
+
+impl<P, ConstraintF, F> MapToCurveGadget<P, ConstraintF, F> for SvdWMapGadget<P>
+where
+    P: SvdWParameters,
+    P::BaseField: SquareRootField + HashToField,
+    ConstraintF: PrimeField,
+    F: SquareRootFieldGadget<P::BaseField, ConstraintF>
+        + HashToFieldGadget<P::BaseField, ConstraintF>,
+{
+    type Map = algebra::hash_to_curve::SvdWMap<P>;
+
+    fn enforce_map_to_curve<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        u: &F,
+    ) -> Result<AffineGadget<P, ConstraintF, F>, SynthesisError> {
+        let (c1, c2, c3, c4) = P::svdw_constants();
+        let one = P::BaseField::one();
+
+        let tv1 = u
+            .square(cs.ns(|| "u^2"))?
+            .mul_by_constant(cs.ns(|| "u^2 * c1"), &c1)?;
+        let tv2 = tv1.add_constant(cs.ns(|| "1 + u^2 * c1"), &one)?;
+        let tv1 = tv1
+            .negate(cs.ns(|| "-u^2 * c1"))?
+            .add_constant(cs.ns(|| "1 - u^2 * c1"), &one)?;
+        let tv3 = tv1.mul(cs.ns(|| "tv1 * tv2"), &tv2)?;
+        let tv3 = enforce_inverse_or_zero(cs.ns(|| "tv3"), &tv3)?;
+        let tv4 = u
+            .mul(cs.ns(|| "u * tv1"), &tv1)?
+            .mul(cs.ns(|| "u * tv1 * tv3"), &tv3)?
+            .mul_by_constant(cs.ns(|| "u * tv1 * tv3 * c3"), &c3)?;
+
+        let x1 = tv4
+            .negate(cs.ns(|| "-tv4"))?
+            .add_constant(cs.ns(|| "x1"), &c2)?;
+        let x2 = tv4.add_constant(cs.ns(|| "x2"), &c2)?;
+        let x3 = tv2
+            .square(cs.ns(|| "tv2^2"))?
+            .mul(cs.ns(|| "tv2^2 * tv3"), &tv3)?
+            .square(cs.ns(|| "(tv2^2 * tv3)^2"))?
+            .mul_by_constant(cs.ns(|| "(tv2^2 * tv3)^2 * c4"), &c4)?
+            .add_constant(cs.ns(|| "x3"), &P::SVDW_Z)?;
+
+        // The first among x1, x2 and x3 with g(x) square is taken
+        let gx1 = enforce_curve_equation(cs.ns(|| "g(x1)"), &x1, &P::COEFF_A, &P::COEFF_B)?;
+        let gx2 = enforce_curve_equation(cs.ns(|| "g(x2)"), &x2, &P::COEFF_A, &P::COEFF_B)?;
+        let gx3 = enforce_curve_equation(cs.ns(|| "g(x3)"), &x3, &P::COEFF_A, &P::COEFF_B)?;
+        let gx1_is_square = gx1.is_square(cs.ns(|| "g(x1) is square"))?;
+        let gx2_is_square = gx2.is_square(cs.ns(|| "g(x2) is square"))?;
+        let x = F::conditionally_select(cs.ns(|| "x2 or x3"), &gx2_is_square, &x2, &x3)?;
+        let x = F::conditionally_select(cs.ns(|| "x"), &gx1_is_square, &x1, &x)?;
+        let gx = F::conditionally_select(cs.ns(|| "g(x2) or g(x3)"), &gx2_is_square, &gx2, &gx3)?;
+        let gx = F::conditionally_select(cs.ns(|| "g(x)"), &gx1_is_square, &gx1, &gx)?;
+
+        let u_sgn0 = u.enforce_sgn0(cs.ns(|| "sgn0(u)"))?;
+        let y = enforce_sqrt_with_sgn0(cs.ns(|| "y"), &gx, &u_sgn0)?;
+
+        Ok(AffineGadget::new(x, y, Boolean::constant(false)))
+    }
+}
+
+/// Enforce the multiplication of `p` by the effective cofactor `h_eff`, by double and add
+/// over the bits of the (constant) `h_eff`.
+fn enforce_clear_cofactor<P, ConstraintF, F, CS>(
+    mut cs: CS,
+    p: &AffineGadget<P, ConstraintF, F>,
+    h_eff: &[u64],
+) -> Result<AffineGadget<P, ConstraintF, F>, SynthesisError>
+where
+    P: SWModelParameters,
+    ConstraintF: PrimeField,
+    F: FieldGadget<P::BaseField, ConstraintF>,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let mut result = p.clone();
+    for (i, bit) in BitIterator::new(h_eff)
+        .skip_while(|b| !b)
+        .skip(1)
+        .enumerate()
+    {
+        result.double_in_place(cs.ns(|| format!("double {}", i)))?;
+        if bit {
+            result = result.add(cs.ns(|| format!("add {}", i)), p)?;
+        }
+    }
+    Ok(result)
+}
+
+/// Gadget for the hash to curve construction of `MapToCurveBasedHasher`, with
+/// `expand_message_xmd` instantiated with SHA-256 and the map enforced by `M`.
+pub struct HashToCurveGadget<P, ConstraintF, F, M> {
+    _parameters: PhantomData<P>,
+    _engine: PhantomData<ConstraintF>,
+    _field: PhantomData<F>,
+    _map: PhantomData<M>,
+}
+
+impl<P, ConstraintF, F, M> HashToCurveGadget<P, ConstraintF, F, M>
+where
+    P: SWModelParameters,
+    P::BaseField: SquareRootField + HashToField,
+    ConstraintF: PrimeField,
+    F: SquareRootFieldGadget<P::BaseField, ConstraintF>
+        + HashToFieldGadget<P::BaseField, ConstraintF>,
+    M: MapToCurveGadget<P, ConstraintF, F>,
+{
+    fn enforce_hash_to_field<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        dst: &[u8],
+        msg: &[UInt8],
+        count: usize,
+    ) -> Result<Vec<F>, SynthesisError> {
+        let len = P::BaseField::uniform_bytes_len();
+        let uniform_bytes =
+            enforce_expand_message_xmd_sha256(cs.ns(|| "expand message"), msg, dst, count * len)?;
+        uniform_bytes
+            .chunks(len)
+            .enumerate()
+            .map(|(i, chunk)| F::enforce_from_uniform_bytes(cs.ns(|| format!("u_{}", i)), chunk))
+            .collect()
+    }
+
+    /// Enforce `hash_to_curve` of `msg` with domain separation tag `dst`.
+    pub fn enforce_hash<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        dst: &[u8],
+        msg: &[UInt8],
+    ) -> Result<AffineGadget<P, ConstraintF, F>, SynthesisError> {
+        let u = Self::enforce_hash_to_field(cs.ns(|| "hash to field"), dst, msg, 2)?;
+        let q_0 = M::enforce_map_to_curve(cs.ns(|| "map u_0"), &u[0])?;
+        let q_1 = M::enforce_map_to_curve(cs.ns(|| "map u_1"), &u[1])?;
+        let q = q_0.add(cs.ns(|| "q_0 + q_1"), &q_1)?;
+        enforce_clear_cofactor(
+            cs.ns(|| "clear cofactor"),
+            &q,
+            <M::Map as MapToCurve<GroupAffine<P>>>::H_EFF,
+        )
+    }
+
+    /// Enforce `encode_to_curve` of `msg` with domain separation tag `dst`.
+    pub fn enforce_encode<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        dst: &[u8],
+        msg: &[UInt8],
+    ) -> Result<AffineGadget<P, ConstraintF, F>, SynthesisError> {
+        let u = Self::enforce_hash_to_field(cs.ns(|| "hash to field"), dst, msg, 1)?;
+        let q = M::enforce_map_to_curve(cs.ns(|| "map u_0"), &u[0])?;
+        enforce_clear_cofactor(
+            cs.ns(|| "clear cofactor"),
+            &q,
+            <M::Map as MapToCurve<GroupAffine<P>>>::H_EFF,
+        )
+    }
+}
+
+/// The parameters of `HashToCurveCRH` are public constants of the circuit.
+#[derive(Clone)]
+pub struct HashToCurveCRHParametersGadget {
+    pub dst: Vec<u8>,
+}
+
+impl<ConstraintF: Field> AllocGadget<HashToCurveCRHParameters, ConstraintF>
+    for HashToCurveCRHParametersGadget
+{
+    fn alloc<FN, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        _cs: CS,
+        value_gen: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<HashToCurveCRHParameters>,
+    {
+        let dst = value_gen()?.borrow().dst.clone();
+        Ok(HashToCurveCRHParametersGadget { dst })
+    }
+
+    fn alloc_input<FN, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        _cs: CS,
+        value_gen: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        FN: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<HashToCurveCRHParameters>,
+    {
+        let dst = value_gen()?.borrow().dst.clone();
+        Ok(HashToCurveCRHParametersGadget { dst })
+    }
+}
+
+pub struct HashToCurveCRHGadget<P, ConstraintF, F, M> {
+    _parameters: PhantomData<P>,
+    _engine: PhantomData<ConstraintF>,
+    _field: PhantomData<F>,
+    _map: PhantomData<M>,
+}
+
+impl<P, ConstraintF, F, M> FixedLengthCRHGadget<HashToCurveCRH<P, M::Map>, ConstraintF>
+    for HashToCurveCRHGadget<P, ConstraintF, F, M>
+where
+    P: SWModelParameters,
+    P::BaseField: SquareRootField + HashToField,
+    ConstraintF: PrimeField,
+    F: SquareRootFieldGadget<P::BaseField, ConstraintF>
+        + HashToFieldGadget<P::BaseField, ConstraintF>,
+    M: MapToCurveGadget<P, ConstraintF, F>,
+{
+    type OutputGadget = AffineGadget<P, ConstraintF, F>;
+    type ParametersGadget = HashToCurveCRHParametersGadget;
+
+    fn check_evaluation_gadget<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        parameters: &Self::ParametersGadget,
+        input: &[UInt8],
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        use primitives::crh::FixedLengthCRH;
+
+        if input.len() * 8 > HashToCurveCRH::<P, M::Map>::INPUT_SIZE_BITS {
+            return Err(SynthesisError::Other(format!(
+                "incorrect input length {} for HashToCurveCRH",
+                input.len()
+            )));
+        }
+        HashToCurveGadget::<P, ConstraintF, F, M>::enforce_hash(cs, &parameters.dst, input)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::{
+        curves::{
+            bls12_377::g2::Bls12_377G2Parameters, bn_382::g1::Bn382G1Parameters,
+            tweedle::dee::TweedledeeParameters,
+        },
+        fields::{bls12_377::Fq as Bls12_377Fq, bn_382::Fq as Bn382Fq, tweedle::Fq},
+        hash_to_curve::{
+            expand_message_xmd, hash_to_field, HashToCurve, MapToCurveBasedHasher, SWUMap,
+        },
+        ProjectiveCurve, UniformRand,
+    };
+    use primitives::crh::FixedLengthCRH;
+    use r1cs_core::{ConstraintSystem, ConstraintSystemDebugger, SynthesisMode};
+    use r1cs_std::instantiated::{
+        bls12_377::Fq2Gadget as Bls12_377Fq2Gadget, bn_382::FqGadget as Bn382FqGadget,
+        tweedle::FqGadget,
+    };
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use sha2::Sha256;
+
+    const TEST_DST: &[u8] = b"GINGER-V01-TEST-HASH-TO-CURVE";
+
+    /// Check the map gadget `M` against the native map, on zero and random inputs.
+    fn map_to_curve_gadget_test<P, ConstraintF, F, M>()
+    where
+        P: SWModelParameters,
+        P::BaseField: SquareRootField + HashToField,
+        ConstraintF: PrimeField,
+        F: SquareRootFieldGadget<P::BaseField, ConstraintF>
+            + HashToFieldGadget<P::BaseField, ConstraintF>,
+        M: MapToCurveGadget<P, ConstraintF, F>,
+    {
+        let rng = &mut XorShiftRng::seed_from_u64(1234567890u64);
+        let mut inputs = vec![P::BaseField::zero(), P::BaseField::one()];
+        for _ in 0..5 {
+            inputs.push(P::BaseField::rand(rng));
+        }
+
+        for (i, u) in inputs.iter().enumerate() {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let u_g = F::alloc(cs.ns(|| "alloc u"), || Ok(*u)).unwrap();
+            let p_g = M::enforce_map_to_curve(cs.ns(|| "map to curve"), &u_g).unwrap();
+            let expected = <M::Map as MapToCurve<GroupAffine<P>>>::map_to_curve(u);
+            assert_eq!(p_g.x.get_value().unwrap(), expected.x, "input {}", i);
+            assert_eq!(p_g.y.get_value().unwrap(), expected.y, "input {}", i);
+            if !cs.is_satisfied() {
+                println!("{:?}", cs.which_is_unsatisfied());
+            }
+            assert!(cs.is_satisfied());
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd_sha256_gadget() {
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+        let msg = b"abcdef0123456789";
+        let msg_g = UInt8::alloc_vec(cs.ns(|| "alloc msg"), msg).unwrap();
+        for &len in [32usize, 96, 100].iter() {
+            let expanded = enforce_expand_message_xmd_sha256(
+                cs.ns(|| format!("expand to {}", len)),
+                &msg_g,
+                TEST_DST,
+                len,
+            )
+            .unwrap();
+            assert_eq!(
+                expanded
+                    .iter()
+                    .map(|byte| byte.get_value().unwrap())
+                    .collect::<Vec<_>>(),
+                expand_message_xmd::<Sha256>(msg, TEST_DST, len).unwrap()
+            );
+        }
+        assert!(cs.is_satisfied());
+
+        // Empty tags and too long outputs are rejected
+        assert!(enforce_expand_message_xmd_sha256(cs.ns(|| "empty dst"), &msg_g, b"", 32).is_err());
+        assert!(enforce_expand_message_xmd_sha256(
+            cs.ns(|| "too long"),
+            &msg_g,
+            TEST_DST,
+            255 * 32 + 1
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hash_to_field_gadget() {
+        let mut cs = ConstraintSystem::<Bls12_377Fq>::new(SynthesisMode::Debug);
+        let msg = b"abc";
+        let expected = hash_to_field::<QuadExtField<_>, Sha256>(msg, TEST_DST, 2).unwrap();
+        let msg_g = UInt8::alloc_vec(cs.ns(|| "alloc msg"), msg).unwrap();
+        let u = HashToCurveGadget::<
+            Bls12_377G2Parameters,
+            Bls12_377Fq,
+            Bls12_377Fq2Gadget,
+            SvdWMapGadget<Bls12_377G2Parameters>,
+        >::enforce_hash_to_field(cs.ns(|| "hash to field"), TEST_DST, &msg_g, 2)
+        .unwrap();
+        assert_eq!(u[0].get_value().unwrap(), expected[0]);
+        assert_eq!(u[1].get_value().unwrap(), expected[1]);
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_swu_map_gadget() {
+        map_to_curve_gadget_test::<
+            Bn382G1Parameters,
+            Bn382Fq,
+            Bn382FqGadget,
+            SWUMapGadget<Bn382G1Parameters>,
+        >();
+        map_to_curve_gadget_test::<
+            TweedledeeParameters,
+            Fq,
+            FqGadget,
+            SWUMapGadget<TweedledeeParameters>,
+        >();
+    }
+
+    #[test]
+    fn test_svdw_map_gadget() {
+        map_to_curve_gadget_test::<
+            TweedledeeParameters,
+            Fq,
+            FqGadget,
+            SvdWMapGadget<TweedledeeParameters>,
+        >();
+        map_to_curve_gadget_test::<
+            Bls12_377G2Parameters,
+            Bls12_377Fq,
+            Bls12_377Fq2Gadget,
+            SvdWMapGadget<Bls12_377G2Parameters>,
+        >();
+    }
+
+    type TestCRH = HashToCurveCRH<TweedledeeParameters, SWUMap<TweedledeeParameters>>;
+    type TestCRHGadget = HashToCurveCRHGadget<
+        TweedledeeParameters,
+        Fq,
+        FqGadget,
+        SWUMapGadget<TweedledeeParameters>,
+    >;
+
+    #[test]
+    fn test_hash_to_curve_crh_gadget() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+
+        let params = TestCRH::setup(rng).unwrap();
+        let input = b"Hi, I am a hash to curve input!";
+        let expected = TestCRH::evaluate(&params, input).unwrap().into_affine();
+
+        let params_g =
+            <TestCRHGadget as FixedLengthCRHGadget<TestCRH, Fq>>::ParametersGadget::alloc(
+                cs.ns(|| "alloc params"),
+                || Ok(&params),
+            )
+            .unwrap();
+        let input_g = UInt8::alloc_vec(cs.ns(|| "alloc input"), input).unwrap();
+        let output_g =
+            TestCRHGadget::check_evaluation_gadget(cs.ns(|| "evaluate"), &params_g, &input_g)
+                .unwrap();
+        assert_eq!(output_g.x.get_value().unwrap(), expected.x);
+        assert_eq!(output_g.y.get_value().unwrap(), expected.y);
+        assert!(cs.is_satisfied());
+
+        // Inputs longer than `INPUT_SIZE_BITS` are rejected
+        let long_input_g = UInt8::alloc_vec(cs.ns(|| "alloc long input"), &[0u8; 129]).unwrap();
+        assert!(TestCRHGadget::check_evaluation_gadget(
+            cs.ns(|| "evaluate long"),
+            &params_g,
+            &long_input_g
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_hash_to_curve_swu_gadget() {
+        let mut cs = ConstraintSystem::<Bn382Fq>::new(SynthesisMode::Debug);
+        let msg = b"abc";
+        let hasher = MapToCurveBasedHasher::<
+            GroupAffine<Bn382G1Parameters>,
+            SWUMap<Bn382G1Parameters>,
+            Sha256,
+        >::new(TEST_DST)
+        .unwrap();
+        let expected = hasher.encode(msg).unwrap();
+
+        let msg_g = UInt8::alloc_vec(cs.ns(|| "alloc msg"), msg).unwrap();
+        let p_g = HashToCurveGadget::<
+            Bn382G1Parameters,
+            Bn382Fq,
+            Bn382FqGadget,
+            SWUMapGadget<Bn382G1Parameters>,
+        >::enforce_encode(cs.ns(|| "encode"), TEST_DST, &msg_g)
+        .unwrap();
+        assert_eq!(p_g.x.get_value().unwrap(), expected.x);
+        assert_eq!(p_g.y.get_value().unwrap(), expected.y);
+        assert!(cs.is_satisfied());
+    }
+}
@@ -1,7 +1,7 @@
-use algebra::Field;
+use algebra::{Field, PrimeField};
 use std::fmt::Debug;
 
-use primitives::crh::{FieldBasedHash, FixedLengthCRH};
+use primitives::crh::{FieldBasedHash, FieldBasedSponge, FixedLengthCRH};
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 
 use r1cs_std::prelude::*;
@@ -16,6 +16,11 @@ pub use self::sbox::*;
 pub mod poseidon;
 pub use self::poseidon::*;
 
+pub mod rescue;
+
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
+
 pub trait FixedLengthCRHGadget<H: FixedLengthCRH, ConstraintF: Field>: Sized {
     type OutputGadget: EqGadget<ConstraintF>
         + ToBytesGadget<ConstraintF>
@@ -44,6 +49,42 @@ pub trait FieldBasedHashGadget<H: FieldBasedHash<Data = ConstraintF>, Constraint
     ) -> Result<Self::DataGadget, SynthesisError>;
 }
 
+/// Gadget for a FieldBasedSponge: absorbing and squeezing the same elements in the same
+/// order, it gives the same outputs of the native sponge.
+pub trait FieldBasedSpongeGadget<S: FieldBasedSponge<Data = ConstraintF>, ConstraintF: PrimeField>:
+    Sized
+{
+    type DataGadget: FieldGadget<ConstraintF, ConstraintF>;
+
+    /// Initialize the sponge to a null state, or with `personalization` if specified.
+    fn init<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        personalization: Option<&[ConstraintF]>,
+    ) -> Result<Self, SynthesisError>;
+
+    /// Enforce the absorption of `elems` into the sponge.
+    fn enforce_absorb<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        elems: &[Self::DataGadget],
+    ) -> Result<(), SynthesisError>;
+
+    /// Enforce the squeezing of `num` field elements out of the sponge.
+    fn enforce_squeeze<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        num: usize,
+    ) -> Result<Vec<Self::DataGadget>, SynthesisError>;
+
+    /// Enforce the squeezing of `num_bits` bits out of the sponge: for each squeezed field
+    /// element, only the `CAPACITY` least significant bits, in big-endian order, are taken.
+    fn enforce_squeeze_bits<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError>;
+}
+
 pub trait FieldHasherGadget<
     H: FieldBasedHash<Data = ConstraintF>,
     ConstraintF: Field,
@@ -59,13 +100,16 @@ pub trait FieldHasherGadget<
 
 #[cfg(test)]
 mod test {
-    use crate::FieldBasedHashGadget;
-    use algebra::PrimeField;
-    use primitives::FieldBasedHash;
+    use crate::{FieldBasedHashGadget, FieldBasedSpongeGadget};
+    use algebra::{FpParameters, PrimeField};
+    use primitives::{FieldBasedHash, FieldBasedSponge};
     use r1cs_core::{
         ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
     };
-    use r1cs_std::{alloc::AllocGadget, fields::fp::FpGadget};
+    use r1cs_std::{
+        alloc::AllocGadget,
+        fields::{fp::FpGadget, FieldGadget},
+    };
 
     pub(crate) fn constant_length_field_based_hash_gadget_native_test<
         F: PrimeField,
@@ -104,4 +148,79 @@ mod test {
         }
         assert!(cs.is_satisfied());
     }
+
+    pub(crate) fn field_based_sponge_gadget_native_test<
+        F: PrimeField,
+        S: FieldBasedSponge<Data = F>,
+        SG: FieldBasedSpongeGadget<S, F, DataGadget = FpGadget<F>>,
+    >(
+        inputs: Vec<F>,
+    ) {
+        let personalization = [F::from(7u32)];
+        for (i, personalization) in [None, Some(&personalization[..])].iter().enumerate() {
+            let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+
+            let mut sponge = S::init(*personalization);
+            let mut sponge_g = SG::init(cs.ns(|| "init sponge"), *personalization).unwrap();
+
+            // Interleave absorptions and squeezings of different lengths
+            let mut step = 0;
+            let mut absorbed = 0;
+            while absorbed < inputs.len() {
+                let num_absorbed = (step % 3 + 1).min(inputs.len() - absorbed);
+                let elems = &inputs[absorbed..absorbed + num_absorbed];
+                let elems_g = elems
+                    .iter()
+                    .enumerate()
+                    .map(|(j, elem)| {
+                        FpGadget::<F>::alloc(
+                            cs.ns(|| format!("alloc input {}_{}", step, j)),
+                            || Ok(elem),
+                        )
+                        .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                sponge.absorb(elems);
+                sponge_g
+                    .enforce_absorb(cs.ns(|| format!("absorb {}", step)), elems_g.as_slice())
+                    .unwrap();
+                absorbed += num_absorbed;
+
+                let num_squeezed = step % 4;
+                let outputs = sponge.squeeze(num_squeezed);
+                let outputs_g = sponge_g
+                    .enforce_squeeze(cs.ns(|| format!("squeeze {}", step)), num_squeezed)
+                    .unwrap();
+                assert_eq!(
+                    outputs,
+                    outputs_g
+                        .iter()
+                        .map(|output_g| output_g.get_value().unwrap())
+                        .collect::<Vec<_>>(),
+                    "Different squeezed elements at step {}, personalization {}",
+                    step,
+                    i
+                );
+                step += 1;
+            }
+
+            let num_bits = F::Params::CAPACITY as usize + 3;
+            let bits = sponge.squeeze_bits(num_bits);
+            let bits_g = sponge_g
+                .enforce_squeeze_bits(cs.ns(|| "squeeze bits"), num_bits)
+                .unwrap();
+            assert_eq!(
+                bits,
+                bits_g
+                    .iter()
+                    .map(|bit_g| bit_g.get_value().unwrap())
+                    .collect::<Vec<_>>()
+            );
+
+            if !cs.is_satisfied() {
+                println!("{:?}", cs.which_is_unsatisfied());
+            }
+            assert!(cs.is_satisfied());
+        }
+    }
 }
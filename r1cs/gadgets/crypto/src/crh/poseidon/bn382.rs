@@ -1,4 +1,7 @@
-use crate::crh::{poseidon::PoseidonHashGadget, sbox::QuinticSBoxGadget};
+use crate::crh::{
+    poseidon::{PoseidonHashGadget, PoseidonSpongeGadget},
+    sbox::QuinticSBoxGadget,
+};
 use algebra::fields::bn_382::{Fq as BN382Fq, Fr as BN382Fr};
 use primitives::crh::parameters::{
     BN382FqPoseidonParameters, BN382FqQuinticSbox, BN382FrPoseidonParameters, BN382FrQuinticSbox,
@@ -12,6 +15,13 @@ pub type BN382FqPoseidonHashGadget = PoseidonHashGadget<
     BN382FqQuinticSBoxGadget,
 >;
 
+pub type BN382FqPoseidonSpongeGadget = PoseidonSpongeGadget<
+    BN382Fq,
+    BN382FqPoseidonParameters,
+    BN382FqQuinticSbox,
+    BN382FqQuinticSBoxGadget,
+>;
+
 type BN382FrQuinticSBoxGadget = QuinticSBoxGadget<BN382Fr, BN382FrQuinticSbox>;
 pub type BN382FrPoseidonHashGadget = PoseidonHashGadget<
     BN382Fr,
@@ -19,3 +29,10 @@ pub type BN382FrPoseidonHashGadget = PoseidonHashGadget<
     BN382FrQuinticSbox,
     BN382FrQuinticSBoxGadget,
 >;
+
+pub type BN382FrPoseidonSpongeGadget = PoseidonSpongeGadget<
+    BN382Fr,
+    BN382FrPoseidonParameters,
+    BN382FrQuinticSbox,
+    BN382FrQuinticSBoxGadget,
+>;
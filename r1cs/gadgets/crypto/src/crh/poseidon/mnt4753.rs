@@ -1,6 +1,12 @@
-use crate::crh::{poseidon::PoseidonHashGadget, sbox::InverseSBoxGadget};
+use crate::crh::{
+    poseidon::{PoseidonHashGadget, PoseidonSpongeGadget},
+    sbox::InverseSBoxGadget,
+};
 use algebra::fields::mnt4753::Fr as MNT4753Fr;
-use primitives::crh::parameters::{MNT4753PoseidonParameters, MNT4InversePoseidonSBox};
+use primitives::crh::parameters::{
+    MNT4753PoseidonParameters, MNT4753PoseidonParametersRate4, MNT4753PoseidonParametersRate8,
+    MNT4InversePoseidonSBox, MNT4InversePoseidonSBoxRate4, MNT4InversePoseidonSBoxRate8,
+};
 
 type MNT4InverseSBoxGadget = InverseSBoxGadget<MNT4753Fr, MNT4InversePoseidonSBox>;
 pub type MNT4PoseidonHashGadget = PoseidonHashGadget<
@@ -9,3 +15,26 @@ pub type MNT4PoseidonHashGadget = PoseidonHashGadget<
     MNT4InversePoseidonSBox,
     MNT4InverseSBoxGadget,
 >;
+
+pub type MNT4PoseidonSpongeGadget = PoseidonSpongeGadget<
+    MNT4753Fr,
+    MNT4753PoseidonParameters,
+    MNT4InversePoseidonSBox,
+    MNT4InverseSBoxGadget,
+>;
+
+type MNT4InverseSBoxGadgetRate4 = InverseSBoxGadget<MNT4753Fr, MNT4InversePoseidonSBoxRate4>;
+pub type MNT4PoseidonHashGadgetRate4 = PoseidonHashGadget<
+    MNT4753Fr,
+    MNT4753PoseidonParametersRate4,
+    MNT4InversePoseidonSBoxRate4,
+    MNT4InverseSBoxGadgetRate4,
+>;
+
+type MNT4InverseSBoxGadgetRate8 = InverseSBoxGadget<MNT4753Fr, MNT4InversePoseidonSBoxRate8>;
+pub type MNT4PoseidonHashGadgetRate8 = PoseidonHashGadget<
+    MNT4753Fr,
+    MNT4753PoseidonParametersRate8,
+    MNT4InversePoseidonSBoxRate8,
+    MNT4InverseSBoxGadgetRate8,
+>;
@@ -1,6 +1,12 @@
-use crate::crh::{poseidon::PoseidonHashGadget, sbox::InverseSBoxGadget};
+use crate::crh::{
+    poseidon::{PoseidonHashGadget, PoseidonSpongeGadget},
+    sbox::InverseSBoxGadget,
+};
 use algebra::fields::mnt6753::Fr as MNT6753Fr;
-use primitives::crh::parameters::{MNT6753PoseidonParameters, MNT6InversePoseidonSBox};
+use primitives::crh::parameters::{
+    MNT6753PoseidonParameters, MNT6753PoseidonParametersRate4, MNT6753PoseidonParametersRate8,
+    MNT6InversePoseidonSBox, MNT6InversePoseidonSBoxRate4, MNT6InversePoseidonSBoxRate8,
+};
 
 type MNT6InverseSBoxGadget = InverseSBoxGadget<MNT6753Fr, MNT6InversePoseidonSBox>;
 pub type MNT6PoseidonHashGadget = PoseidonHashGadget<
@@ -9,3 +15,26 @@ pub type MNT6PoseidonHashGadget = PoseidonHashGadget<
     MNT6InversePoseidonSBox,
     MNT6InverseSBoxGadget,
 >;
+
+pub type MNT6PoseidonSpongeGadget = PoseidonSpongeGadget<
+    MNT6753Fr,
+    MNT6753PoseidonParameters,
+    MNT6InversePoseidonSBox,
+    MNT6InverseSBoxGadget,
+>;
+
+type MNT6InverseSBoxGadgetRate4 = InverseSBoxGadget<MNT6753Fr, MNT6InversePoseidonSBoxRate4>;
+pub type MNT6PoseidonHashGadgetRate4 = PoseidonHashGadget<
+    MNT6753Fr,
+    MNT6753PoseidonParametersRate4,
+    MNT6InversePoseidonSBoxRate4,
+    MNT6InverseSBoxGadgetRate4,
+>;
+
+type MNT6InverseSBoxGadgetRate8 = InverseSBoxGadget<MNT6753Fr, MNT6InversePoseidonSBoxRate8>;
+pub type MNT6PoseidonHashGadgetRate8 = PoseidonHashGadget<
+    MNT6753Fr,
+    MNT6753PoseidonParametersRate8,
+    MNT6InversePoseidonSBoxRate8,
+    MNT6InverseSBoxGadgetRate8,
+>;
@@ -1,9 +1,13 @@
-use crate::crh::{FieldBasedHashGadget, SBoxGadget};
-use algebra::PrimeField;
-use primitives::crh::poseidon::{PoseidonHash, PoseidonParameters};
+use crate::crh::{FieldBasedHashGadget, FieldBasedSpongeGadget, SBoxGadget};
+use algebra::{FpParameters, PrimeField};
+use primitives::crh::{
+    poseidon::{PoseidonHash, PoseidonParameters},
+    FieldBasedSponge,
+};
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 use r1cs_std::{
     alloc::ConstantGadget,
+    bits::{boolean::Boolean, ToBitsGadget},
     fields::{fp::FpGadget, FieldGadget},
 };
 use std::marker::PhantomData;
@@ -273,10 +277,172 @@ where
     }
 }
 
+/// Gadget for the duplex sponge implemented by PoseidonHash.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct PoseidonSpongeGadget<
+    ConstraintF: PrimeField,
+    P: PoseidonParameters<Fr = ConstraintF>,
+    SB: SBox<Field = ConstraintF, Parameters = P>,
+    SBG: SBoxGadget<ConstraintF, SB>,
+> {
+    state: Vec<FpGadget<ConstraintF>>,
+    pending: Vec<FpGadget<ConstraintF>>,
+    // Index of the next element of the state to be squeezed, if squeezing
+    squeeze_idx: Option<usize>,
+    // Number of permutations enforced so far, used to name them
+    num_perms: usize,
+    _parameters: PhantomData<P>,
+    _sbox: PhantomData<SB>,
+    _sbox_gadget: PhantomData<SBG>,
+}
+
+impl<ConstraintF, P, SB, SBG> PoseidonSpongeGadget<ConstraintF, P, SB, SBG>
+where
+    ConstraintF: PrimeField,
+    P: PoseidonParameters<Fr = ConstraintF>,
+    SB: SBox<Field = ConstraintF, Parameters = P>,
+    SBG: SBoxGadget<ConstraintF, SB>,
+{
+    fn enforce_permutation<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+    ) -> Result<(), SynthesisError> {
+        let perm_idx = self.num_perms;
+
+        // Add the pending elements, if any, to the state
+        for (j, input) in self.pending.iter().enumerate() {
+            self.state[j].add_in_place(cs.ns(|| format!("add_input_{}_{}", perm_idx, j)), input)?;
+        }
+        self.pending.clear();
+
+        PoseidonHashGadget::<ConstraintF, P, SB, SBG>::poseidon_perm(
+            cs.ns(|| format!("poseidon_perm_{}", perm_idx)),
+            &mut self.state,
+        )?;
+        self.num_perms += 1;
+
+        Ok(())
+    }
+}
+
+impl<ConstraintF, P, SB, SBG> FieldBasedSpongeGadget<PoseidonHash<ConstraintF, P, SB>, ConstraintF>
+    for PoseidonSpongeGadget<ConstraintF, P, SB, SBG>
+where
+    ConstraintF: PrimeField,
+    P: PoseidonParameters<Fr = ConstraintF>,
+    SB: SBox<Field = ConstraintF, Parameters = P>,
+    SBG: SBoxGadget<ConstraintF, SB>,
+{
+    type DataGadget = FpGadget<ConstraintF>;
+
+    fn init<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        personalization: Option<&[ConstraintF]>,
+    ) -> Result<Self, SynthesisError> {
+        // The initial state depends only on constants, so it can be computed natively
+        let native_sponge =
+            <PoseidonHash<ConstraintF, P, SB> as FieldBasedSponge>::init(personalization);
+        let state = native_sponge
+            .get_state()
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| {
+                FpGadget::<ConstraintF>::from_value(cs.ns(|| format!("hardcode_state_{}", i)), elem)
+            })
+            .collect();
+
+        Ok(Self {
+            state,
+            pending: Vec::with_capacity(P::R),
+            squeeze_idx: None,
+            num_perms: 0,
+            _parameters: PhantomData,
+            _sbox: PhantomData,
+            _sbox_gadget: PhantomData,
+        })
+    }
+
+    fn enforce_absorb<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        elems: &[Self::DataGadget],
+    ) -> Result<(), SynthesisError> {
+        if elems.is_empty() {
+            return Ok(());
+        }
+
+        self.squeeze_idx = None;
+        for elem in elems.iter() {
+            self.pending.push(elem.clone());
+            if self.pending.len() == P::R {
+                self.enforce_permutation(&mut cs)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn enforce_squeeze<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        num: usize,
+    ) -> Result<Vec<Self::DataGadget>, SynthesisError> {
+        // Switch to squeezing mode, absorbing the pending elements (zero padded) if any
+        let mut squeeze_idx = match self.squeeze_idx {
+            Some(squeeze_idx) => squeeze_idx,
+            None => {
+                if !self.pending.is_empty() {
+                    self.enforce_permutation(&mut cs)?;
+                }
+                0
+            }
+        };
+
+        let mut outputs = Vec::with_capacity(num);
+        for _ in 0..num {
+            if squeeze_idx == P::R {
+                self.enforce_permutation(&mut cs)?;
+                squeeze_idx = 0;
+            }
+            outputs.push(self.state[squeeze_idx].clone());
+            squeeze_idx += 1;
+        }
+        self.squeeze_idx = Some(squeeze_idx);
+
+        Ok(outputs)
+    }
+
+    fn enforce_squeeze_bits<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        let capacity = ConstraintF::Params::CAPACITY as usize;
+        let skipped_bits = ConstraintF::Params::MODULUS_BITS as usize - capacity;
+        let num_elems = (num_bits + capacity - 1) / capacity;
+
+        let mut bits = Vec::with_capacity(num_elems * capacity);
+        for (i, elem) in self
+            .enforce_squeeze(cs.ns(|| "squeeze field elements"), num_elems)?
+            .into_iter()
+            .enumerate()
+        {
+            let elem_bits = elem.to_bits_strict(cs.ns(|| format!("elem_{}_to_bits", i)))?;
+            bits.extend_from_slice(&elem_bits[skipped_bits..]);
+        }
+        bits.truncate(num_bits);
+
+        Ok(bits)
+    }
+}
+
 #[cfg(test)]
 mod test {
 
-    use crate::crh::test::constant_length_field_based_hash_gadget_native_test;
+    use crate::crh::test::{
+        constant_length_field_based_hash_gadget_native_test, field_based_sponge_gadget_native_test,
+    };
     use algebra::PrimeField;
 
     pub(crate) fn generate_inputs<F: PrimeField>(num: usize) -> Vec<F> {
@@ -359,4 +525,154 @@ mod test {
             );
         }
     }
+
+    #[cfg(feature = "mnt4_753")]
+    #[test]
+    fn poseidon_mnt4_753_rate_4_gadget_native_test() {
+        use crate::MNT4PoseidonHashGadgetRate4;
+
+        for ins in 1..=5 {
+            constant_length_field_based_hash_gadget_native_test::<_, _, MNT4PoseidonHashGadgetRate4>(
+                generate_inputs(ins),
+            );
+        }
+    }
+
+    #[cfg(feature = "mnt4_753")]
+    #[test]
+    fn poseidon_mnt4_753_rate_8_gadget_native_test() {
+        use crate::MNT4PoseidonHashGadgetRate8;
+
+        for ins in 1..=9 {
+            constant_length_field_based_hash_gadget_native_test::<_, _, MNT4PoseidonHashGadgetRate8>(
+                generate_inputs(ins),
+            );
+        }
+    }
+
+    #[cfg(feature = "mnt6_753")]
+    #[test]
+    fn poseidon_mnt6_753_rate_4_gadget_native_test() {
+        use crate::MNT6PoseidonHashGadgetRate4;
+
+        for ins in 1..=5 {
+            constant_length_field_based_hash_gadget_native_test::<_, _, MNT6PoseidonHashGadgetRate4>(
+                generate_inputs(ins),
+            );
+        }
+    }
+
+    #[cfg(feature = "mnt6_753")]
+    #[test]
+    fn poseidon_mnt6_753_rate_8_gadget_native_test() {
+        use crate::MNT6PoseidonHashGadgetRate8;
+
+        for ins in 1..=9 {
+            constant_length_field_based_hash_gadget_native_test::<_, _, MNT6PoseidonHashGadgetRate8>(
+                generate_inputs(ins),
+            );
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn crh_tweedle_fr_rate_4_primitive_gadget_test() {
+        use crate::TweedleFrPoseidonHashGadgetRate4;
+
+        for ins in 1..=5 {
+            constant_length_field_based_hash_gadget_native_test::<
+                _,
+                _,
+                TweedleFrPoseidonHashGadgetRate4,
+            >(generate_inputs(ins));
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn crh_tweedle_fr_rate_8_primitive_gadget_test() {
+        use crate::TweedleFrPoseidonHashGadgetRate8;
+
+        for ins in 1..=9 {
+            constant_length_field_based_hash_gadget_native_test::<
+                _,
+                _,
+                TweedleFrPoseidonHashGadgetRate8,
+            >(generate_inputs(ins));
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn crh_tweedle_fq_rate_4_primitive_gadget_test() {
+        use crate::TweedleFqPoseidonHashGadgetRate4;
+
+        for ins in 1..=5 {
+            constant_length_field_based_hash_gadget_native_test::<
+                _,
+                _,
+                TweedleFqPoseidonHashGadgetRate4,
+            >(generate_inputs(ins));
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn crh_tweedle_fq_rate_8_primitive_gadget_test() {
+        use crate::TweedleFqPoseidonHashGadgetRate8;
+
+        for ins in 1..=9 {
+            constant_length_field_based_hash_gadget_native_test::<
+                _,
+                _,
+                TweedleFqPoseidonHashGadgetRate8,
+            >(generate_inputs(ins));
+        }
+    }
+
+    #[cfg(feature = "mnt4_753")]
+    #[test]
+    fn poseidon_mnt4_753_sponge_gadget_native_test() {
+        use crate::MNT4PoseidonSpongeGadget;
+
+        field_based_sponge_gadget_native_test::<_, _, MNT4PoseidonSpongeGadget>(generate_inputs(
+            10,
+        ));
+    }
+
+    #[cfg(feature = "mnt6_753")]
+    #[test]
+    fn poseidon_mnt6_753_sponge_gadget_native_test() {
+        use crate::MNT6PoseidonSpongeGadget;
+
+        field_based_sponge_gadget_native_test::<_, _, MNT6PoseidonSpongeGadget>(generate_inputs(
+            10,
+        ));
+    }
+
+    #[cfg(feature = "bn_382")]
+    #[test]
+    fn poseidon_bn382_sponge_gadget_native_test() {
+        use crate::{BN382FqPoseidonSpongeGadget, BN382FrPoseidonSpongeGadget};
+
+        field_based_sponge_gadget_native_test::<_, _, BN382FrPoseidonSpongeGadget>(
+            generate_inputs(10),
+        );
+        field_based_sponge_gadget_native_test::<_, _, BN382FqPoseidonSpongeGadget>(
+            generate_inputs(10),
+        );
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn poseidon_tweedle_sponge_gadget_native_test() {
+        use crate::{TweedleFqPoseidonSpongeGadget, TweedleFrPoseidonSpongeGadget};
+
+        field_based_sponge_gadget_native_test::<_, _, TweedleFrPoseidonSpongeGadget>(
+            generate_inputs(10),
+        );
+        field_based_sponge_gadget_native_test::<_, _, TweedleFqPoseidonSpongeGadget>(
+            generate_inputs(10),
+        );
+    }
 }
@@ -1,8 +1,14 @@
-use crate::crh::{poseidon::PoseidonHashGadget, sbox::QuinticSBoxGadget};
+use crate::crh::{
+    poseidon::{PoseidonHashGadget, PoseidonSpongeGadget},
+    sbox::QuinticSBoxGadget,
+};
 use algebra::fields::tweedle::{Fq, Fr};
 use primitives::crh::parameters::{
-    TweedleFqPoseidonParameters, TweedleFqQuinticSbox, TweedleFrPoseidonParameters,
-    TweedleFrQuinticSbox,
+    TweedleFqPoseidonParameters, TweedleFqPoseidonParametersRate4,
+    TweedleFqPoseidonParametersRate8, TweedleFqQuinticSbox, TweedleFqQuinticSboxRate4,
+    TweedleFqQuinticSboxRate8, TweedleFrPoseidonParameters, TweedleFrPoseidonParametersRate4,
+    TweedleFrPoseidonParametersRate8, TweedleFrQuinticSbox, TweedleFrQuinticSboxRate4,
+    TweedleFrQuinticSboxRate8,
 };
 
 type TweedleFqQuinticSboxGadget = QuinticSBoxGadget<Fq, TweedleFqQuinticSbox>;
@@ -13,6 +19,13 @@ pub type TweedleFqPoseidonHashGadget = PoseidonHashGadget<
     TweedleFqQuinticSboxGadget,
 >;
 
+pub type TweedleFqPoseidonSpongeGadget = PoseidonSpongeGadget<
+    Fq,
+    TweedleFqPoseidonParameters,
+    TweedleFqQuinticSbox,
+    TweedleFqQuinticSboxGadget,
+>;
+
 type TweedleFrQuinticSboxGadget = QuinticSBoxGadget<Fr, TweedleFrQuinticSbox>;
 pub type TweedleFrPoseidonHashGadget = PoseidonHashGadget<
     Fr,
@@ -21,5 +34,44 @@ pub type TweedleFrPoseidonHashGadget = PoseidonHashGadget<
     TweedleFrQuinticSboxGadget,
 >;
 
+pub type TweedleFrPoseidonSpongeGadget = PoseidonSpongeGadget<
+    Fr,
+    TweedleFrPoseidonParameters,
+    TweedleFrQuinticSbox,
+    TweedleFrQuinticSboxGadget,
+>;
+
+type TweedleFqQuinticSboxGadgetRate4 = QuinticSBoxGadget<Fq, TweedleFqQuinticSboxRate4>;
+pub type TweedleFqPoseidonHashGadgetRate4 = PoseidonHashGadget<
+    Fq,
+    TweedleFqPoseidonParametersRate4,
+    TweedleFqQuinticSboxRate4,
+    TweedleFqQuinticSboxGadgetRate4,
+>;
+
+type TweedleFqQuinticSboxGadgetRate8 = QuinticSBoxGadget<Fq, TweedleFqQuinticSboxRate8>;
+pub type TweedleFqPoseidonHashGadgetRate8 = PoseidonHashGadget<
+    Fq,
+    TweedleFqPoseidonParametersRate8,
+    TweedleFqQuinticSboxRate8,
+    TweedleFqQuinticSboxGadgetRate8,
+>;
+
+type TweedleFrQuinticSboxGadgetRate4 = QuinticSBoxGadget<Fr, TweedleFrQuinticSboxRate4>;
+pub type TweedleFrPoseidonHashGadgetRate4 = PoseidonHashGadget<
+    Fr,
+    TweedleFrPoseidonParametersRate4,
+    TweedleFrQuinticSboxRate4,
+    TweedleFrQuinticSboxGadgetRate4,
+>;
+
+type TweedleFrQuinticSboxGadgetRate8 = QuinticSBoxGadget<Fr, TweedleFrQuinticSboxRate8>;
+pub type TweedleFrPoseidonHashGadgetRate8 = PoseidonHashGadget<
+    Fr,
+    TweedleFrPoseidonParametersRate8,
+    TweedleFrQuinticSboxRate8,
+    TweedleFrQuinticSboxGadgetRate8,
+>;
+
 pub mod density_optimized;
 pub use density_optimized::*;
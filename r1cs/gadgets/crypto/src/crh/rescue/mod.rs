@@ -0,0 +1,196 @@
+use crate::crh::{FieldBasedHashGadget, SBoxGadget};
+use algebra::PrimeField;
+use primitives::{
+    crh::rescue::{RescueHash, RescueParameters},
+    SBox,
+};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    alloc::ConstantGadget,
+    fields::{fp::FpGadget, FieldGadget},
+};
+use std::marker::PhantomData;
+
+#[cfg(feature = "tweedle")]
+pub mod tweedle;
+#[cfg(feature = "tweedle")]
+pub use self::tweedle::*;
+
+pub struct RescueHashGadget<
+    ConstraintF: PrimeField,
+    P: RescueParameters<Fr = ConstraintF>,
+    SB: SBox<Field = ConstraintF, Parameters = P>,
+    SBInv: SBox<Field = ConstraintF, Parameters = P>,
+    SBG: SBoxGadget<ConstraintF, SB>,
+    SBInvG: SBoxGadget<ConstraintF, SBInv>,
+> {
+    _field: PhantomData<ConstraintF>,
+    _parameters: PhantomData<P>,
+    _sbox: PhantomData<SB>,
+    _inverse_sbox: PhantomData<SBInv>,
+    _sbox_gadget: PhantomData<SBG>,
+    _inverse_sbox_gadget: PhantomData<SBInvG>,
+}
+
+impl<ConstraintF, P, SB, SBInv, SBG, SBInvG>
+    RescueHashGadget<ConstraintF, P, SB, SBInv, SBG, SBInvG>
+where
+    ConstraintF: PrimeField,
+    P: RescueParameters<Fr = ConstraintF>,
+    SB: SBox<Field = ConstraintF, Parameters = P>,
+    SBInv: SBox<Field = ConstraintF, Parameters = P>,
+    SBG: SBoxGadget<ConstraintF, SB>,
+    SBInvG: SBoxGadget<ConstraintF, SBInv>,
+{
+    fn rescue_perm<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        state: &mut [FpGadget<ConstraintF>],
+    ) -> Result<(), SynthesisError> {
+        // index that goes over the round constants
+        let mut round_cst_idx = 0;
+
+        for i in 0..P::R_N {
+            // First half: S-Box, matrix mix and round constants
+            for (j, d) in state.iter_mut().enumerate() {
+                SBG::apply(cs.ns(|| format!("S-Box_{}_{}", i, j)), d)?;
+            }
+            Self::matrix_mix(cs.ns(|| format!("rescue_mix_matrix_{}_0", i)), state)?;
+            Self::add_round_constants(
+                cs.ns(|| format!("add_round_cst_{}_0", i)),
+                state,
+                &mut round_cst_idx,
+            )?;
+
+            // Second half: inverse S-Box, matrix mix and round constants
+            for (j, d) in state.iter_mut().enumerate() {
+                SBInvG::apply(cs.ns(|| format!("inverse_S-Box_{}_{}", i, j)), d)?;
+            }
+            Self::matrix_mix(cs.ns(|| format!("rescue_mix_matrix_{}_1", i)), state)?;
+            Self::add_round_constants(
+                cs.ns(|| format!("add_round_cst_{}_1", i)),
+                state,
+                &mut round_cst_idx,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn add_round_constants<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        state: &mut [FpGadget<ConstraintF>],
+        round_cst_idx: &mut usize,
+    ) -> Result<(), SynthesisError> {
+        for d in state.iter_mut() {
+            d.add_constant_in_place(
+                cs.ns(|| format!("add_constant_{}", round_cst_idx)),
+                &P::ROUND_CST[*round_cst_idx],
+            )?;
+            *round_cst_idx += 1;
+        }
+        Ok(())
+    }
+
+    fn matrix_mix<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        state: &mut [FpGadget<ConstraintF>],
+    ) -> Result<(), SynthesisError> {
+        // Check that the length of the state vector is t
+        assert_eq!(state.len(), P::T);
+
+        let mut new_state = Vec::with_capacity(P::T);
+        for (i, row) in P::MDS_CST.chunks(P::T).enumerate() {
+            let mut elem = FpGadget::<ConstraintF>::from_value(
+                cs.ns(|| format!("hardcode_new_state_elem_{}", i)),
+                &P::ZERO,
+            );
+            for (j, (x, m)) in state.iter().zip(row.iter()).enumerate() {
+                let prod =
+                    x.mul_by_constant(cs.ns(|| format!("partial_product_{}_{}", i, j)), m)?;
+                elem.add_in_place(cs.ns(|| format!("add_partial_product_{}_{}", i, j)), &prod)?;
+            }
+            new_state.push(elem);
+        }
+        state.clone_from_slice(new_state.as_slice());
+
+        Ok(())
+    }
+}
+
+impl<ConstraintF, P, SB, SBInv, SBG, SBInvG>
+    FieldBasedHashGadget<RescueHash<ConstraintF, P, SB, SBInv>, ConstraintF>
+    for RescueHashGadget<ConstraintF, P, SB, SBInv, SBG, SBInvG>
+where
+    ConstraintF: PrimeField,
+    P: RescueParameters<Fr = ConstraintF>,
+    SB: SBox<Field = ConstraintF, Parameters = P>,
+    SBInv: SBox<Field = ConstraintF, Parameters = P>,
+    SBG: SBoxGadget<ConstraintF, SB>,
+    SBInvG: SBoxGadget<ConstraintF, SBInv>,
+{
+    type DataGadget = FpGadget<ConstraintF>;
+
+    fn enforce_hash_constant_length<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        input: &[Self::DataGadget],
+    ) -> Result<Self::DataGadget, SynthesisError> {
+        if input.is_empty() {
+            return Err(SynthesisError::Other(
+                "Input data array does not contain any data".to_owned(),
+            ));
+        }
+
+        let mut state = P::AFTER_ZERO_PERM
+            .iter()
+            .enumerate()
+            .map(|(i, elem)| {
+                FpGadget::<ConstraintF>::from_value(cs.ns(|| format!("hardcode_state_{}", i)), elem)
+            })
+            .collect::<Vec<_>>();
+
+        // Absorb the input in chunks of rate elements, the last one being implicitly
+        // padded with zeros, applying a permutation after each of them
+        for (i, chunk) in input.chunks(P::R).enumerate() {
+            for (j, (s, elem)) in state.iter_mut().zip(chunk.iter()).enumerate() {
+                s.add_in_place(cs.ns(|| format!("add_input_{}_{}", i, j)), elem)?;
+            }
+            Self::rescue_perm(cs.ns(|| format!("rescue_perm_{}", i)), &mut state)?;
+        }
+
+        // return the first element of the state vector as the hash digest
+        Ok(state[0].clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::crh::test::constant_length_field_based_hash_gadget_native_test;
+    use algebra::PrimeField;
+
+    fn generate_inputs<F: PrimeField>(num: usize) -> Vec<F> {
+        (1..=num).map(|i| F::from(i as u32)).collect()
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn rescue_tweedle_fr_gadget_native_test() {
+        use crate::crh::rescue::TweedleFrRescueHashGadget;
+
+        for ins in 1..=3 {
+            constant_length_field_based_hash_gadget_native_test::<_, _, TweedleFrRescueHashGadget>(
+                generate_inputs(ins),
+            );
+        }
+    }
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn rescue_tweedle_fq_gadget_native_test() {
+        use crate::crh::rescue::TweedleFqRescueHashGadget;
+
+        for ins in 1..=3 {
+            constant_length_field_based_hash_gadget_native_test::<_, _, TweedleFqRescueHashGadget>(
+                generate_inputs(ins),
+            );
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::crh::{
+    rescue::RescueHashGadget,
+    sbox::{QuinticRootSBoxGadget, QuinticSBoxGadget},
+};
+use algebra::fields::tweedle::{Fq, Fr};
+use primitives::crh::rescue::{
+    TweedleFqRescueInverseQuinticSBox, TweedleFqRescueParameters, TweedleFqRescueQuinticSBox,
+    TweedleFrRescueInverseQuinticSBox, TweedleFrRescueParameters, TweedleFrRescueQuinticSBox,
+};
+
+type TweedleFqRescueQuinticSBoxGadget = QuinticSBoxGadget<Fq, TweedleFqRescueQuinticSBox>;
+type TweedleFqRescueInverseQuinticSBoxGadget =
+    QuinticRootSBoxGadget<Fq, TweedleFqRescueInverseQuinticSBox>;
+pub type TweedleFqRescueHashGadget = RescueHashGadget<
+    Fq,
+    TweedleFqRescueParameters,
+    TweedleFqRescueQuinticSBox,
+    TweedleFqRescueInverseQuinticSBox,
+    TweedleFqRescueQuinticSBoxGadget,
+    TweedleFqRescueInverseQuinticSBoxGadget,
+>;
+
+type TweedleFrRescueQuinticSBoxGadget = QuinticSBoxGadget<Fr, TweedleFrRescueQuinticSBox>;
+type TweedleFrRescueInverseQuinticSBoxGadget =
+    QuinticRootSBoxGadget<Fr, TweedleFrRescueInverseQuinticSBox>;
+pub type TweedleFrRescueHashGadget = RescueHashGadget<
+    Fr,
+    TweedleFrRescueParameters,
+    TweedleFrRescueQuinticSBox,
+    TweedleFrRescueInverseQuinticSBox,
+    TweedleFrRescueQuinticSBoxGadget,
+    TweedleFrRescueInverseQuinticSBoxGadget,
+>;
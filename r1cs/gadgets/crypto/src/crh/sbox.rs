@@ -81,3 +81,31 @@ impl<ConstraintF: PrimeField, SB: SBox<Field = ConstraintF>> SBoxGadget<Constrai
         Ok(())
     }
 }
+
+pub struct QuinticRootSBoxGadget<ConstraintF: PrimeField, SB: SBox<Field = ConstraintF>> {
+    _field: PhantomData<ConstraintF>,
+    _sbox: PhantomData<SB>,
+}
+
+impl<ConstraintF: PrimeField, SB: SBox<Field = ConstraintF>> SBoxGadget<ConstraintF, SB>
+    for QuinticRootSBoxGadget<ConstraintF, SB>
+{
+    // Enforce S(X) = X^(1/5), SB being the native S-Box computing it:
+    // the root Y is allocated and Y^5 = X is enforced.
+    fn apply<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        x: &mut FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        let y = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc y"), || {
+            let mut state = vec![x.get_value().get()?];
+            SB::apply_partial(&mut state);
+            Ok(state[0])
+        })?;
+
+        let y4 = y.square(cs.ns(|| "y^2"))?.square(cs.ns(|| "y^4"))?;
+        y4.mul_equals(cs.ns(|| "y^5 = x"), &y, x)?;
+
+        *x = y;
+        Ok(())
+    }
+}
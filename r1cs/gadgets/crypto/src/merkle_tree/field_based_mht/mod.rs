@@ -1,4 +1,4 @@
-use algebra::{Field, FpParameters, PrimeField};
+use algebra::{Field, FpParameters, PrimeField, SemanticallyValid};
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 use r1cs_std::{fields::fp::FpGadget, prelude::*};
 
@@ -169,10 +169,11 @@ where
         should_enforce: &Boolean,
         height: usize,
     ) -> Result<(), SynthesisError> {
-        if leaves.len() != 2_usize.pow(height as u32) {
-            return Err(SynthesisError::Other(
-                "Leaves number must be a power of 2".to_owned(),
-            ));
+        if leaves.len() != P::MERKLE_ARITY.pow(height as u32) {
+            return Err(SynthesisError::Other(format!(
+                "Leaves number must be a power of {}",
+                P::MERKLE_ARITY
+            )));
         }
 
         let mut prev_level_nodes = leaves.to_vec();
@@ -180,16 +181,13 @@ where
         for level in 0..height {
             let mut curr_level_nodes = vec![];
 
-            //Iterate over all nodes in a level. We assume their number to be even (e.g a power of two)
+            //Iterate over all nodes in a level. We assume their number to be a power of the arity
 
-            for (i, nodes) in prev_level_nodes.chunks(2).enumerate() {
+            for (i, children) in prev_level_nodes.chunks(P::MERKLE_ARITY).enumerate() {
                 //Compute parent hash
-                let mut children = vec![];
-                children.push(nodes[0].clone());
-                children.push(nodes[1].clone());
                 let parent_hash = HGadget::enforce_hash_constant_length(
                     cs.ns(|| format!("hash_children_pair_{}_of_level_{}", i, level)),
-                    children.as_slice(),
+                    children,
                 )?;
                 curr_level_nodes.push(parent_hash);
             }
@@ -402,156 +400,1127 @@ where
     }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::crh::MNT4PoseidonHashGadget;
-    use algebra::fields::mnt4753::Fr;
-    use primitives::crh::MNT4PoseidonHash;
-    use r1cs_core::{
-        ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
-    };
-    use r1cs_std::instantiated::mnt6_753::FqGadget;
-    use rand::{Rng, SeedableRng};
-    use rand_xorshift::XorShiftRng;
-
-    #[derive(Clone)]
-    struct MNT4753FieldBasedMerkleTreeParams;
+/// Gadget for a `FieldBasedMHTPath` of a Merkle Tree of arbitrary arity: for each level,
+/// it holds the siblings of the node and the little-endian bits of its position among them.
+/// NOTE: `P::MERKLE_ARITY` is required to be a power of 2.
+#[derive(Derivative)]
+#[derivative(
+    PartialEq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Eq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Clone(bound = "P: FieldBasedMerkleTreeParameters")
+)]
+pub struct FieldBasedMHTPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    path: Vec<(Vec<HGadget::DataGadget>, Vec<Boolean>)>,
+}
 
-    impl FieldBasedMerkleTreeParameters for MNT4753FieldBasedMerkleTreeParams {
-        type Data = Fr;
-        type H = MNT4PoseidonHash;
-        const MERKLE_ARITY: usize = 2;
-        const ZERO_NODE_CST: Option<
-            FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
-        > = None;
+impl<P, HGadget, ConstraintF> FieldBasedMHTPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    /// Return the number of bits needed to represent the position of a node
+    /// among its siblings, i.e. log2(P::MERKLE_ARITY).
+    fn position_bits_len() -> Result<usize, SynthesisError> {
+        if P::MERKLE_ARITY < 2 || !P::MERKLE_ARITY.is_power_of_two() {
+            return Err(SynthesisError::Other(format!(
+                "Merkle arity must be a power of 2. Found: {}",
+                P::MERKLE_ARITY
+            )));
+        }
+        Ok(P::MERKLE_ARITY.trailing_zeros() as usize)
     }
 
-    type MNT4753FieldBasedMerkleTree = NaiveMerkleTree<MNT4753FieldBasedMerkleTreeParams>;
-
-    type HG = MNT4PoseidonHashGadget;
-
-    const TEST_HEIGHT: usize = 5;
+    fn check_siblings_len(siblings: &[ConstraintF]) -> Result<(), SynthesisError> {
+        if siblings.len() != P::MERKLE_ARITY - 1 {
+            return Err(SynthesisError::Other(format!(
+                "Each level of the path must contain {} siblings. Found: {}",
+                P::MERKLE_ARITY - 1,
+                siblings.len()
+            )));
+        }
+        Ok(())
+    }
 
-    fn check_merkle_paths(leaves: &[Fr], use_bad_root: bool) -> bool {
-        let mut tree = MNT4753FieldBasedMerkleTree::new(TEST_HEIGHT);
-        tree.append(leaves).unwrap();
-        let root = tree.root().unwrap();
-        let mut satisfied = true;
+    /// Return the little-endian bits of the positions of the nodes in the path,
+    /// starting from the leaf level.
+    pub fn get_position_bits(&self) -> Vec<Boolean> {
+        self.path
+            .iter()
+            .flat_map(|(_, bits)| bits.iter().cloned())
+            .collect()
+    }
 
-        //Merkle Path Gadget test
-        for (i, leaf) in leaves.iter().enumerate() {
-            let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
-            let proof = tree.generate_proof(i, leaf).unwrap();
-            assert!(proof.verify(TEST_HEIGHT, &leaf, &root).unwrap());
+    pub fn enforce_leaf_index_bits<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        leaf_index_bits: &[Boolean],
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_enforce_leaf_index_bits(cs, leaf_index_bits, &Boolean::Constant(true))
+    }
 
-            // Allocate Merkle Tree Root
-            let root = FqGadget::alloc(&mut cs.ns(|| format!("new_digest_{}", i)), || {
-                if use_bad_root {
-                    Ok(Fr::zero())
-                } else {
-                    Ok(root)
-                }
-            })
-            .unwrap();
+    /// Enforce that the position bits of `self` are equal to `leaf_index_bits`, given in
+    /// big-endian, if `should_enforce` is True, otherwise enforce nothing.
+    pub fn conditionally_enforce_leaf_index_bits<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        leaf_index_bits: &[Boolean],
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        for (i, (path_bit, leaf_index_bit)) in self
+            .get_position_bits()
+            .iter()
+            .zip(leaf_index_bits.iter().rev())
+            .enumerate()
+        {
+            path_bit.conditional_enforce_equal(
+                cs.ns(|| format!("index_equality_{}", i)),
+                leaf_index_bit,
+                should_enforce,
+            )?;
+        }
 
-            // Allocate Leaf
-            let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+        Ok(())
+    }
 
-            // Allocate Merkle Tree Path
-            let cw = FieldBasedBinaryMerkleTreePathGadget::<_, HG, _>::alloc(
-                &mut cs.ns(|| format!("new_witness_{}", i)),
-                || Ok(proof),
-            )
-            .unwrap();
+    /// Place `node` among `siblings` at the position given by `position_bits`,
+    /// returning the P::MERKLE_ARITY children to be hashed together.
+    fn enforce_children<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        node: &HGadget::DataGadget,
+        siblings: &[HGadget::DataGadget],
+        position_bits: &[Boolean],
+    ) -> Result<Vec<HGadget::DataGadget>, SynthesisError> {
+        let mut children = Vec::with_capacity(P::MERKLE_ARITY);
+
+        // True if the position of the node is smaller than the index of the current child
+        let mut is_before = Boolean::Constant(false);
+
+        for j in 0..P::MERKLE_ARITY {
+            // Enforce the selector of child j, i.e. position == j
+            let selector_bits = position_bits
+                .iter()
+                .enumerate()
+                .map(|(k, bit)| if (j >> k) & 1 == 1 { *bit } else { bit.not() })
+                .collect::<Vec<_>>();
+            let is_node = Boolean::kary_and(
+                cs.ns(|| format!("is node the child {}", j)),
+                selector_bits.as_slice(),
+            )?;
 
-            // Check_membership test
-            cw.check_membership(
-                &mut cs.ns(|| format!("check_membership_{}", i)),
-                &root,
-                &leaf_g,
-            )
-            .unwrap();
+            // If the node comes before the child j, then the child j is the sibling j - 1,
+            // otherwise, if it comes after, it's the sibling j.
+            let sibling = if j == 0 {
+                siblings[0].clone()
+            } else if j == P::MERKLE_ARITY - 1 {
+                siblings[j - 1].clone()
+            } else {
+                HGadget::DataGadget::conditionally_select(
+                    cs.ns(|| format!("choose sibling for child {}", j)),
+                    &is_before,
+                    &siblings[j - 1],
+                    &siblings[j],
+                )?
+            };
+
+            children.push(HGadget::DataGadget::conditionally_select(
+                cs.ns(|| format!("choose child {}", j)),
+                &is_node,
+                node,
+                &sibling,
+            )?);
+
+            // Selectors are one-hot, so a xor is enough to update the flag
+            is_before = Boolean::xor(
+                cs.ns(|| format!("is node before child {}", j + 1)),
+                &is_before,
+                &is_node,
+            )?;
+        }
 
-            // Enforce Merkle Path test
-            let root_1 = cw
-                .enforce_root_from_leaf(
-                    &mut cs.ns(|| format!("enforce_root_from_leaf_{}", i)),
-                    &leaf_g,
-                )
-                .unwrap();
+        Ok(children)
+    }
+}
 
-            root.enforce_equal(
-                &mut cs.ns(|| format!("check_{} root == root_1", i)),
-                &root_1,
-            )
-            .unwrap();
+impl<P, HGadget, ConstraintF>
+    FieldBasedMerkleTreePathGadget<FieldBasedMHTPath<P>, P::H, HGadget, ConstraintF>
+    for FieldBasedMHTPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: PrimeField,
+{
+    fn length(&self) -> usize {
+        self.path.len()
+    }
 
-            // Enforce leaf_index check
-            let fe_index = Fr::from(i as u32);
-            let fe_index_g =
-                FqGadget::alloc(cs.ns(|| format!("alloc_index_{}", i)), || Ok(fe_index)).unwrap();
+    /// Enforces correct reconstruction of the root of the Merkle Tree
+    /// from `self` and `leaf`.
+    fn enforce_root_from_leaf<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        leaf: &HGadget::DataGadget,
+    ) -> Result<HGadget::DataGadget, SynthesisError> {
+        let mut previous_hash = (*leaf).clone();
 
-            cw.enforce_leaf_index(
-                &mut cs.ns(|| format!("enforce_leaf_index_{}", i)),
-                &fe_index_g,
-            )
-            .unwrap();
+        for (i, (siblings, position_bits)) in self.path.iter().enumerate() {
+            let children = Self::enforce_children(
+                cs.ns(|| format!("enforce children {}", i)),
+                &previous_hash,
+                siblings.as_slice(),
+                position_bits.as_slice(),
+            )?;
 
-            if !cs.is_satisfied() {
-                satisfied = false;
-                println!(
-                    "Unsatisfied constraint: {}",
-                    cs.which_is_unsatisfied().unwrap()
-                );
-            }
+            previous_hash = HGadget::enforce_hash_constant_length(
+                cs.ns(|| format!("hash_inner_node_{}", i)),
+                children.as_slice(),
+            )?;
         }
 
-        satisfied
+        Ok(previous_hash)
     }
 
-    fn check_leaves(leaves: &[Fr], use_bad_root: bool) -> bool {
-        let mut tree = MNT4753FieldBasedMerkleTree::new(TEST_HEIGHT);
-        tree.append(leaves).unwrap();
-        let root = tree.root().unwrap();
+    /// Given a field element `leaf_index` representing the position of a leaf in a
+    /// Merkle Tree, enforce that the leaf index corresponding to `self` path is the
+    /// same of `leaf_index`.
+    fn conditionally_enforce_leaf_index<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        leaf_index: &FpGadget<ConstraintF>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let leaf_index_bits = leaf_index.to_bits_with_length_restriction(
+            cs.ns(|| "get leaf index bits"),
+            (ConstraintF::Params::MODULUS_BITS as usize)
+                - self.path.len() * Self::position_bits_len()?,
+        )?;
 
-        //Merkle Tree Gadget test
-        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+        self.conditionally_enforce_leaf_index_bits(
+            cs.ns(|| "enforce leaf index bits"),
+            leaf_index_bits.as_slice(),
+            should_enforce,
+        )?;
 
-        // Allocate Merkle Tree Root
-        let root = FqGadget::alloc(&mut cs.ns(|| "root_digest_{}"), || {
-            if use_bad_root {
-                Ok(Fr::zero())
-            } else {
-                Ok(root)
-            }
-        })
-        .unwrap();
+        Ok(())
+    }
+}
 
-        //Alloc leaves
-        let mut leaves_g = vec![];
-        for (i, leaf) in leaves.iter().enumerate() {
-            leaves_g
-                .push(FqGadget::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaf)).unwrap());
+impl<P, HGadget, ConstraintF> AllocGadget<FieldBasedMHTPath<P>, ConstraintF>
+    for FieldBasedMHTPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTPath<P>>,
+    {
+        let position_bits_len = Self::position_bits_len()?;
+        let mut path = Vec::new();
+        for (i, &(ref siblings, position)) in
+            value_gen()?.borrow().get_raw_path().iter().enumerate()
+        {
+            Self::check_siblings_len(siblings)?;
+            let mut siblings_g = Vec::with_capacity(siblings.len());
+            for (j, sibling) in siblings.iter().enumerate() {
+                siblings_g.push(HGadget::DataGadget::alloc(
+                    cs.ns(|| format!("sibling_hash_{}_{}", i, j)),
+                    || Ok(sibling),
+                )?);
+            }
+            let mut position_bits = Vec::with_capacity(position_bits_len);
+            for j in 0..position_bits_len {
+                position_bits.push(Boolean::alloc(
+                    cs.ns(|| format!("position_bit_{}_{}", i, j)),
+                    || Ok((position >> j) & 1 == 1),
+                )?);
+            }
+            path.push((siblings_g, position_bits));
         }
+        Ok(FieldBasedMHTPathGadget { path })
+    }
 
-        //Check MR from leaves
-        FieldBasedMerkleTreeGadget::<MNT4753FieldBasedMerkleTreeParams, HG, Fr>::check_leaves(
-            &mut cs.ns(|| "check all leaves belong to MT"),
-            &leaves_g,
-            &root,
-            TEST_HEIGHT,
-        )
-        .unwrap();
-
-        if !cs.is_satisfied() {
-            println!(
-                "Unsatisfied constraint: {}",
-                cs.which_is_unsatisfied().unwrap()
-            );
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTPath<P>>,
+    {
+        let position_bits_len = Self::position_bits_len()?;
+        let mut path = Vec::new();
+        for (i, &(ref siblings, position)) in
+            value_gen()?.borrow().get_raw_path().iter().enumerate()
+        {
+            Self::check_siblings_len(siblings)?;
+            let mut siblings_g = Vec::with_capacity(siblings.len());
+            for (j, sibling) in siblings.iter().enumerate() {
+                siblings_g.push(HGadget::DataGadget::alloc_input(
+                    cs.ns(|| format!("sibling_hash_{}_{}", i, j)),
+                    || Ok(sibling),
+                )?);
+            }
+            let mut position_bits = Vec::with_capacity(position_bits_len);
+            for j in 0..position_bits_len {
+                position_bits.push(Boolean::alloc_input(
+                    cs.ns(|| format!("position_bit_{}_{}", i, j)),
+                    || Ok((position >> j) & 1 == 1),
+                )?);
+            }
+            path.push((siblings_g, position_bits));
         }
-
-        cs.is_satisfied()
+        Ok(FieldBasedMHTPathGadget { path })
+    }
+}
+
+impl<P, HGadget, ConstraintF> ConstantGadget<FieldBasedMHTPath<P>, ConstraintF>
+    for FieldBasedMHTPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn from_value<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value: &FieldBasedMHTPath<P>,
+    ) -> Self {
+        let position_bits_len = Self::position_bits_len().unwrap();
+        let mut path = Vec::new();
+        for (i, (siblings, position)) in value.get_raw_path().iter().enumerate() {
+            let siblings_g = siblings
+                .iter()
+                .enumerate()
+                .map(|(j, sibling)| {
+                    HGadget::DataGadget::from_value(
+                        cs.ns(|| format!("hardcode sibling {}_{}", i, j)),
+                        sibling,
+                    )
+                })
+                .collect::<Vec<_>>();
+            let position_bits = (0..position_bits_len)
+                .map(|j| Boolean::Constant((position >> j) & 1 == 1))
+                .collect::<Vec<_>>();
+            path.push((siblings_g, position_bits));
+        }
+        Self { path }
+    }
+
+    fn get_constant(&self) -> FieldBasedMHTPath<P> {
+        let mut path = Vec::new();
+        for (siblings_g, position_bits_g) in self.path.iter() {
+            let siblings = siblings_g
+                .iter()
+                .map(|sibling_g| sibling_g.get_constant())
+                .collect::<Vec<_>>();
+            let position = position_bits_g
+                .iter()
+                .enumerate()
+                .fold(0usize, |position, (j, bit_g)| {
+                    position | ((bit_g.get_value().unwrap() as usize) << j)
+                });
+            path.push((siblings, position))
+        }
+        FieldBasedMHTPath::<P>::new(path)
+    }
+}
+
+impl<P, HGadget, ConstraintF> EqGadget<ConstraintF>
+    for FieldBasedMHTPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn is_eq<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Boolean, SynthesisError> {
+        let mut v = Vec::new();
+        let len = self.path.len();
+        if self.path.len() != other.path.len() {
+            return Err(SynthesisError::Other(format!(
+                "Paths length must be the same. Self len:{}, Other len: {}",
+                self.path.len(),
+                other.path.len()
+            )));
+        }
+        for i in 0..len {
+            let b1_i = self.path[i]
+                .0
+                .is_eq(cs.ns(|| format!("b1_{}", i)), &other.path[i].0)?;
+            let b2_i = self.path[i]
+                .1
+                .is_eq(cs.ns(|| format!("b2_{}", i)), &other.path[i].1)?;
+            let b_i = Boolean::and(cs.ns(|| format!("b1_{} && b2_{}", i, i)), &b1_i, &b2_i)?;
+            v.push(b_i);
+        }
+        Boolean::kary_and(cs.ns(|| "is eq final"), v.as_slice())
+    }
+
+    fn conditional_enforce_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let len = self.path.len();
+        if self.path.len() != other.path.len() {
+            return Err(SynthesisError::Other(format!(
+                "Paths length must be the same. Self len:{}, Other len: {}",
+                self.path.len(),
+                other.path.len()
+            )));
+        }
+        for i in 0..len {
+            self.path[i].0.conditional_enforce_equal(
+                cs.ns(|| format!("conditional_eq_1_{}", i)),
+                &other.path[i].0,
+                should_enforce,
+            )?;
+            self.path[i].1.conditional_enforce_equal(
+                cs.ns(|| format!("conditional_eq_2_{}", i)),
+                &other.path[i].1,
+                should_enforce,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn conditional_enforce_not_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let len = self.path.len();
+        if self.path.len() != other.path.len() {
+            return Err(SynthesisError::Other(format!(
+                "Paths length must be the same. Self len:{}, Other len: {}",
+                self.path.len(),
+                other.path.len()
+            )));
+        }
+        for i in 0..len {
+            self.path[i].0.conditional_enforce_not_equal(
+                cs.ns(|| format!("conditional_neq_1_{}", i)),
+                &other.path[i].0,
+                should_enforce,
+            )?;
+            self.path[i].1.conditional_enforce_not_equal(
+                cs.ns(|| format!("conditional_neq_2_{}", i)),
+                &other.path[i].1,
+                should_enforce,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Gadget for a `FieldBasedMHTNonMembershipProof` on a binary Merkle Tree: it enforces
+/// that the leaf at the position given by the underlying path is the pre-computed empty leaf.
+#[derive(Derivative)]
+#[derivative(
+    PartialEq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Eq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Clone(bound = "P: FieldBasedMerkleTreeParameters")
+)]
+pub struct FieldBasedBinaryMHTNonMembershipProofGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    path: FieldBasedBinaryMerkleTreePathGadget<P, HGadget, ConstraintF>,
+}
+
+impl<P, HGadget, ConstraintF> FieldBasedBinaryMHTNonMembershipProofGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: PrimeField,
+{
+    /// Return the Merkle Path of the empty leaf.
+    pub fn get_path(&self) -> &FieldBasedBinaryMerkleTreePathGadget<P, HGadget, ConstraintF> {
+        &self.path
+    }
+
+    /// Enforce that the leaf at the position given by `self` is empty in the Merkle Tree
+    /// with root `expected_root`.
+    pub fn check_non_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        expected_root: &HGadget::DataGadget,
+    ) -> Result<(), SynthesisError> {
+        self.path.check_non_membership(cs, expected_root)
+    }
+
+    /// Enforce that the leaf at the position given by `self` is empty in the Merkle Tree
+    /// with root `expected_root` if `should_enforce` is True, otherwise enforce nothing.
+    pub fn conditionally_check_non_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        expected_root: &HGadget::DataGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.path
+            .conditionally_check_non_membership(cs, expected_root, should_enforce)
+    }
+
+    /// Given a field element `leaf_index` representing the position of a leaf in a
+    /// Merkle Tree, enforce that the index of the empty leaf is the same of `leaf_index`.
+    pub fn enforce_leaf_index<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        leaf_index: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        self.path.enforce_leaf_index(cs, leaf_index)
+    }
+
+    /// Given a field element `leaf_index` representing the position of a leaf in a
+    /// Merkle Tree, enforce that the index of the empty leaf is the same of `leaf_index`
+    /// if `should_enforce` is True, otherwise enforce nothing.
+    pub fn conditionally_enforce_leaf_index<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        leaf_index: &FpGadget<ConstraintF>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.path
+            .conditionally_enforce_leaf_index(cs, leaf_index, should_enforce)
+    }
+}
+
+impl<P, HGadget, ConstraintF>
+    AllocGadget<FieldBasedMHTNonMembershipProof<FieldBasedBinaryMHTPath<P>>, ConstraintF>
+    for FieldBasedBinaryMHTNonMembershipProofGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTNonMembershipProof<FieldBasedBinaryMHTPath<P>>>,
+    {
+        let path = FieldBasedBinaryMerkleTreePathGadget::alloc(cs.ns(|| "alloc path"), || {
+            value_gen().map(|proof| proof.borrow().get_path().clone())
+        })?;
+        Ok(Self { path })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTNonMembershipProof<FieldBasedBinaryMHTPath<P>>>,
+    {
+        let path = FieldBasedBinaryMerkleTreePathGadget::alloc_input(
+            cs.ns(|| "alloc input path"),
+            || value_gen().map(|proof| proof.borrow().get_path().clone()),
+        )?;
+        Ok(Self { path })
+    }
+}
+
+/// Gadget for a `FieldBasedMHTNonMembershipProof` on a Merkle Tree of arbitrary arity: it enforces
+/// that the leaf at the position given by the underlying path is the pre-computed empty leaf.
+#[derive(Derivative)]
+#[derivative(
+    PartialEq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Eq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Clone(bound = "P: FieldBasedMerkleTreeParameters")
+)]
+pub struct FieldBasedMHTNonMembershipProofGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    path: FieldBasedMHTPathGadget<P, HGadget, ConstraintF>,
+}
+
+impl<P, HGadget, ConstraintF> FieldBasedMHTNonMembershipProofGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: PrimeField,
+{
+    /// Return the Merkle Path of the empty leaf.
+    pub fn get_path(&self) -> &FieldBasedMHTPathGadget<P, HGadget, ConstraintF> {
+        &self.path
+    }
+
+    /// Enforce that the leaf at the position given by `self` is empty in the Merkle Tree
+    /// with root `expected_root`.
+    pub fn check_non_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        expected_root: &HGadget::DataGadget,
+    ) -> Result<(), SynthesisError> {
+        self.path.check_non_membership(cs, expected_root)
+    }
+
+    /// Enforce that the leaf at the position given by `self` is empty in the Merkle Tree
+    /// with root `expected_root` if `should_enforce` is True, otherwise enforce nothing.
+    pub fn conditionally_check_non_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        expected_root: &HGadget::DataGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.path
+            .conditionally_check_non_membership(cs, expected_root, should_enforce)
+    }
+
+    /// Given a field element `leaf_index` representing the position of a leaf in a
+    /// Merkle Tree, enforce that the index of the empty leaf is the same of `leaf_index`.
+    pub fn enforce_leaf_index<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        leaf_index: &FpGadget<ConstraintF>,
+    ) -> Result<(), SynthesisError> {
+        self.path.enforce_leaf_index(cs, leaf_index)
+    }
+
+    /// Given a field element `leaf_index` representing the position of a leaf in a
+    /// Merkle Tree, enforce that the index of the empty leaf is the same of `leaf_index`
+    /// if `should_enforce` is True, otherwise enforce nothing.
+    pub fn conditionally_enforce_leaf_index<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        leaf_index: &FpGadget<ConstraintF>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.path
+            .conditionally_enforce_leaf_index(cs, leaf_index, should_enforce)
+    }
+}
+
+impl<P, HGadget, ConstraintF>
+    AllocGadget<FieldBasedMHTNonMembershipProof<FieldBasedMHTPath<P>>, ConstraintF>
+    for FieldBasedMHTNonMembershipProofGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTNonMembershipProof<FieldBasedMHTPath<P>>>,
+    {
+        let path = FieldBasedMHTPathGadget::alloc(cs.ns(|| "alloc path"), || {
+            value_gen().map(|proof| proof.borrow().get_path().clone())
+        })?;
+        Ok(Self { path })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTNonMembershipProof<FieldBasedMHTPath<P>>>,
+    {
+        let path = FieldBasedMHTPathGadget::alloc_input(cs.ns(|| "alloc input path"), || {
+            value_gen().map(|proof| proof.borrow().get_path().clone())
+        })?;
+        Ok(Self { path })
+    }
+}
+
+/// Gadget for a `FieldBasedMHTMultiPath` of a Merkle Tree of arbitrary arity. The indices
+/// of the opened leaves (and so the shape of the multi-path) are hardcoded in the circuit,
+/// while the nodes are allocated: the root is reconstructed by hashing each inner node
+/// only once, even if it's shared between the paths of more leaves.
+#[derive(Derivative)]
+#[derivative(
+    PartialEq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Eq(bound = "P: FieldBasedMerkleTreeParameters"),
+    Clone(bound = "P: FieldBasedMerkleTreeParameters")
+)]
+pub struct FieldBasedMHTMultiPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    leaf_indices: Vec<usize>,
+    nodes: Vec<HGadget::DataGadget>,
+    height: usize,
+}
+
+impl<P, HGadget, ConstraintF> FieldBasedMHTMultiPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: PrimeField,
+{
+    /// Return the indices of the leaves opened by `self`, sorted and without duplicates
+    pub fn leaf_indices(&self) -> &[usize] {
+        self.leaf_indices.as_slice()
+    }
+
+    /// Return the height of the Merkle Tree `self` refers to
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Enforces correct reconstruction of the root of the Merkle Tree from `self`
+    /// and `leaves`, given in the same order of `self.leaf_indices()`.
+    pub fn enforce_root_from_leaves<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        leaves: &[HGadget::DataGadget],
+    ) -> Result<HGadget::DataGadget, SynthesisError> {
+        if leaves.len() != self.leaf_indices.len() || leaves.is_empty() {
+            return Err(SynthesisError::Other(format!(
+                "Expected {} leaves, found {}",
+                self.leaf_indices.len(),
+                leaves.len()
+            )));
+        }
+
+        let mut known = self
+            .leaf_indices
+            .iter()
+            .cloned()
+            .zip(leaves.iter().cloned())
+            .collect::<Vec<_>>();
+        let mut nodes = self.nodes.iter();
+
+        for level in 0..self.height {
+            let mut next_known = Vec::new();
+            let mut i = 0;
+            while i < known.len() {
+                let parent = known[i].0 / P::MERKLE_ARITY;
+                let mut children = Vec::with_capacity(P::MERKLE_ARITY);
+                for child in parent * P::MERKLE_ARITY..(parent + 1) * P::MERKLE_ARITY {
+                    if i < known.len() && known[i].0 == child {
+                        children.push(known[i].1.clone());
+                        i += 1;
+                    } else {
+                        let node = nodes.next().ok_or_else(|| {
+                            SynthesisError::Other("Not enough nodes in the multi-path".to_owned())
+                        })?;
+                        children.push(node.clone());
+                    }
+                }
+
+                let parent_hash = HGadget::enforce_hash_constant_length(
+                    cs.ns(|| format!("hash_inner_node_{}_{}", level, parent)),
+                    children.as_slice(),
+                )?;
+                next_known.push((parent, parent_hash));
+            }
+            known = next_known;
+        }
+
+        if nodes.next().is_some() || known.len() != 1 || known[0].0 != 0 {
+            return Err(SynthesisError::Other(
+                "Multi-path inconsistent with the specified leaf indices".to_owned(),
+            ));
+        }
+
+        Ok(known.pop().unwrap().1)
+    }
+
+    /// Enforce that `leaves`, given in the same order of `self.leaf_indices()`,
+    /// belong to the Merkle Tree with root `root`.
+    pub fn check_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        root: &HGadget::DataGadget,
+        leaves: &[HGadget::DataGadget],
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_membership(cs, root, leaves, &Boolean::Constant(true))
+    }
+
+    /// Enforce that `leaves`, given in the same order of `self.leaf_indices()`,
+    /// belong to the Merkle Tree with root `root` if `should_enforce` is True,
+    /// otherwise enforce nothing.
+    pub fn conditionally_check_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        root: &HGadget::DataGadget,
+        leaves: &[HGadget::DataGadget],
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let computed_root = self.enforce_root_from_leaves(cs.ns(|| "reconstruct root"), leaves)?;
+
+        root.conditional_enforce_equal(
+            &mut cs.ns(|| "root_is_last"),
+            &computed_root,
+            should_enforce,
+        )
+    }
+
+    /// Allocate a multi-path opening the leaves at `leaf_indices`, sorted and without
+    /// duplicates, in a Merkle Tree of height `height`. Unlike `alloc`, the shape of the
+    /// multi-path is fixed by `leaf_indices` and `height`, and only its nodes are allocated
+    /// (as witnesses) from the value returned by `value_gen`: thus, `value_gen` may fail
+    /// when generating the parameters. Otherwise, the value must be a multi-path for
+    /// `leaf_indices` and `height`.
+    pub fn alloc_with_shape<F, T, CS>(
+        cs: CS,
+        leaf_indices: &[usize],
+        height: usize,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTMultiPath<P>>,
+        CS: ConstraintSystemAbstract<ConstraintF>,
+    {
+        Self::alloc_with_shape_internal(cs, leaf_indices, height, value_gen, |cs, i, node| {
+            HGadget::DataGadget::alloc(cs.ns(|| format!("node_{}", i)), || {
+                node.ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+    }
+
+    fn alloc_with_shape_internal<F, T, FN, CS>(
+        mut cs: CS,
+        leaf_indices: &[usize],
+        height: usize,
+        value_gen: F,
+        alloc_node: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTMultiPath<P>>,
+        FN: Fn(&mut CS, usize, Option<ConstraintF>) -> Result<HGadget::DataGadget, SynthesisError>,
+        CS: ConstraintSystemAbstract<ConstraintF>,
+    {
+        // The shape must be valid on its own
+        let num_nodes = get_multi_path_node_positions(P::MERKLE_ARITY, height, leaf_indices).len();
+        let shape = FieldBasedMHTMultiPath::<P>::new(
+            leaf_indices.to_vec(),
+            vec![ConstraintF::zero(); num_nodes],
+            height,
+        );
+        if !shape.is_valid() {
+            return Err(SynthesisError::Other("Invalid multi-path".to_owned()));
+        }
+
+        let nodes_values = match value_gen() {
+            Ok(value) => {
+                let multi_path = value.borrow();
+                if multi_path.leaf_indices() != leaf_indices
+                    || multi_path.height() != height
+                    || !multi_path.is_valid()
+                {
+                    return Err(SynthesisError::Other(
+                        "Multi-path inconsistent with the specified leaf indices and height"
+                            .to_owned(),
+                    ));
+                }
+                Some(multi_path.get_nodes().to_vec())
+            }
+            Err(_) => None,
+        };
+
+        let mut nodes = Vec::with_capacity(num_nodes);
+        for i in 0..num_nodes {
+            let node = nodes_values.as_ref().map(|nodes_values| nodes_values[i]);
+            nodes.push(alloc_node(&mut cs, i, node)?);
+        }
+
+        Ok(Self {
+            leaf_indices: leaf_indices.to_vec(),
+            nodes,
+            height,
+        })
+    }
+
+    fn alloc_internal<F, T, FN, CS>(
+        cs: CS,
+        value_gen: F,
+        alloc_node: FN,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTMultiPath<P>>,
+        FN: Fn(&mut CS, usize, Option<ConstraintF>) -> Result<HGadget::DataGadget, SynthesisError>,
+        CS: ConstraintSystemAbstract<ConstraintF>,
+    {
+        let value = value_gen()?;
+        let multi_path = value.borrow();
+        Self::alloc_with_shape_internal(
+            cs,
+            multi_path.leaf_indices(),
+            multi_path.height(),
+            || Ok(multi_path),
+            alloc_node,
+        )
+    }
+}
+
+impl<P, HGadget, ConstraintF> AllocGadget<FieldBasedMHTMultiPath<P>, ConstraintF>
+    for FieldBasedMHTMultiPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: PrimeField,
+{
+    /// NOTE: the indices of the leaves are hardcoded, so `value_gen` must
+    /// return a value even when generating the parameters: use `alloc_with_shape`
+    /// to fix them independently of the value.
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTMultiPath<P>>,
+    {
+        Self::alloc_internal(cs, value_gen, |cs, i, node| {
+            HGadget::DataGadget::alloc(cs.ns(|| format!("node_{}", i)), || {
+                node.ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+    }
+
+    /// NOTE: the indices of the leaves are hardcoded, so `value_gen` must
+    /// return a value even when generating the parameters.
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<FieldBasedMHTMultiPath<P>>,
+    {
+        Self::alloc_internal(cs, value_gen, |cs, i, node| {
+            HGadget::DataGadget::alloc_input(cs.ns(|| format!("node_{}", i)), || {
+                node.ok_or(SynthesisError::AssignmentMissing)
+            })
+        })
+    }
+}
+
+impl<P, HGadget, ConstraintF> ConstantGadget<FieldBasedMHTMultiPath<P>, ConstraintF>
+    for FieldBasedMHTMultiPathGadget<P, HGadget, ConstraintF>
+where
+    P: FieldBasedMerkleTreeParameters<Data = ConstraintF>,
+    HGadget: FieldBasedHashGadget<P::H, ConstraintF>,
+    ConstraintF: Field,
+{
+    fn from_value<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value: &FieldBasedMHTMultiPath<P>,
+    ) -> Self {
+        let nodes = value
+            .get_nodes()
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                HGadget::DataGadget::from_value(cs.ns(|| format!("hardcode node {}", i)), node)
+            })
+            .collect::<Vec<_>>();
+        Self {
+            leaf_indices: value.leaf_indices().to_vec(),
+            nodes,
+            height: value.height(),
+        }
+    }
+
+    fn get_constant(&self) -> FieldBasedMHTMultiPath<P> {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|node_g| node_g.get_constant())
+            .collect::<Vec<_>>();
+        FieldBasedMHTMultiPath::<P>::new(self.leaf_indices.clone(), nodes, self.height)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::crh::{
+        MNT4PoseidonHashGadget, TweedleFrPoseidonHashGadget, TweedleFrPoseidonHashGadgetRate4,
+        TweedleFrPoseidonHashGadgetRate8,
+    };
+    use algebra::fields::{mnt4753::Fr, tweedle::Fr as TweedleFr};
+    use primitives::{
+        crh::{
+            MNT4PoseidonHash, TweedleFrBatchPoseidonHash, TweedleFrBatchPoseidonHashRate4,
+            TweedleFrBatchPoseidonHashRate8, TweedleFrPoseidonHash, TweedleFrPoseidonHashRate4,
+            TweedleFrPoseidonHashRate8,
+        },
+        merkle_tree::{
+            TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS, TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4,
+            TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8,
+        },
+    };
+    use r1cs_core::{
+        ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
+    };
+    use r1cs_std::instantiated::mnt6_753::FqGadget;
+    use rand::{seq::SliceRandom, Rng, RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+    use std::collections::HashMap;
+
+    #[derive(Clone)]
+    struct MNT4753FieldBasedMerkleTreeParams;
+
+    impl FieldBasedMerkleTreeParameters for MNT4753FieldBasedMerkleTreeParams {
+        type Data = Fr;
+        type H = MNT4PoseidonHash;
+        const MERKLE_ARITY: usize = 2;
+        const ZERO_NODE_CST: Option<
+            FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+        > = None;
+    }
+
+    type MNT4753FieldBasedMerkleTree = NaiveMerkleTree<MNT4753FieldBasedMerkleTreeParams>;
+
+    type HG = MNT4PoseidonHashGadget;
+
+    const TEST_HEIGHT: usize = 5;
+
+    fn check_merkle_paths(leaves: &[Fr], use_bad_root: bool) -> bool {
+        let mut tree = MNT4753FieldBasedMerkleTree::new(TEST_HEIGHT);
+        tree.append(leaves).unwrap();
+        let root = tree.root().unwrap();
+        let mut satisfied = true;
+
+        //Merkle Path Gadget test
+        for (i, leaf) in leaves.iter().enumerate() {
+            let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+            let proof = tree.generate_proof(i, leaf).unwrap();
+            assert!(proof.verify(TEST_HEIGHT, &leaf, &root).unwrap());
+
+            // Allocate Merkle Tree Root
+            let root = FqGadget::alloc(&mut cs.ns(|| format!("new_digest_{}", i)), || {
+                if use_bad_root {
+                    Ok(Fr::zero())
+                } else {
+                    Ok(root)
+                }
+            })
+            .unwrap();
+
+            // Allocate Leaf
+            let leaf_g = FqGadget::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+
+            // Allocate Merkle Tree Path
+            let cw = FieldBasedBinaryMerkleTreePathGadget::<_, HG, _>::alloc(
+                &mut cs.ns(|| format!("new_witness_{}", i)),
+                || Ok(proof),
+            )
+            .unwrap();
+
+            // Check_membership test
+            cw.check_membership(
+                &mut cs.ns(|| format!("check_membership_{}", i)),
+                &root,
+                &leaf_g,
+            )
+            .unwrap();
+
+            // Enforce Merkle Path test
+            let root_1 = cw
+                .enforce_root_from_leaf(
+                    &mut cs.ns(|| format!("enforce_root_from_leaf_{}", i)),
+                    &leaf_g,
+                )
+                .unwrap();
+
+            root.enforce_equal(
+                &mut cs.ns(|| format!("check_{} root == root_1", i)),
+                &root_1,
+            )
+            .unwrap();
+
+            // Enforce leaf_index check
+            let fe_index = Fr::from(i as u32);
+            let fe_index_g =
+                FqGadget::alloc(cs.ns(|| format!("alloc_index_{}", i)), || Ok(fe_index)).unwrap();
+
+            cw.enforce_leaf_index(
+                &mut cs.ns(|| format!("enforce_leaf_index_{}", i)),
+                &fe_index_g,
+            )
+            .unwrap();
+
+            if !cs.is_satisfied() {
+                satisfied = false;
+                println!(
+                    "Unsatisfied constraint: {}",
+                    cs.which_is_unsatisfied().unwrap()
+                );
+            }
+        }
+
+        satisfied
+    }
+
+    fn check_leaves(leaves: &[Fr], use_bad_root: bool) -> bool {
+        let mut tree = MNT4753FieldBasedMerkleTree::new(TEST_HEIGHT);
+        tree.append(leaves).unwrap();
+        let root = tree.root().unwrap();
+
+        //Merkle Tree Gadget test
+        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+        // Allocate Merkle Tree Root
+        let root = FqGadget::alloc(&mut cs.ns(|| "root_digest_{}"), || {
+            if use_bad_root {
+                Ok(Fr::zero())
+            } else {
+                Ok(root)
+            }
+        })
+        .unwrap();
+
+        //Alloc leaves
+        let mut leaves_g = vec![];
+        for (i, leaf) in leaves.iter().enumerate() {
+            leaves_g
+                .push(FqGadget::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaf)).unwrap());
+        }
+
+        //Check MR from leaves
+        FieldBasedMerkleTreeGadget::<MNT4753FieldBasedMerkleTreeParams, HG, Fr>::check_leaves(
+            &mut cs.ns(|| "check all leaves belong to MT"),
+            &leaves_g,
+            &root,
+            TEST_HEIGHT,
+        )
+        .unwrap();
+
+        if !cs.is_satisfied() {
+            println!(
+                "Unsatisfied constraint: {}",
+                cs.which_is_unsatisfied().unwrap()
+            );
+        }
+
+        cs.is_satisfied()
     }
 
     #[test]
@@ -613,4 +1582,523 @@ mod test {
         assert!(!check_merkle_paths(&leaves, true));
         assert!(!check_leaves(&leaves, true));
     }
+
+    #[test]
+    fn non_membership_test() {
+        use crate::crh::TweedleFrPoseidonHashGadget;
+        use algebra::fields::tweedle::Fr as TweedleFr;
+        use primitives::{
+            crh::{TweedleFrBatchPoseidonHash, TweedleFrPoseidonHash},
+            merkle_tree::TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS,
+        };
+
+        #[derive(Clone, Debug)]
+        struct TweedleFrFieldBasedMerkleTreeParams;
+        impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type Data = TweedleFr;
+            type H = TweedleFrPoseidonHash;
+            const MERKLE_ARITY: usize = 2;
+            const ZERO_NODE_CST: Option<
+                FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+            > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS);
+        }
+        impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+            type BH = TweedleFrBatchPoseidonHash;
+        }
+
+        type NonMembershipProofGadget = FieldBasedBinaryMHTNonMembershipProofGadget<
+            TweedleFrFieldBasedMerkleTreeParams,
+            TweedleFrPoseidonHashGadget,
+            TweedleFr,
+        >;
+
+        let mut rng = XorShiftRng::seed_from_u64(9174123u64);
+
+        // Fill half of the leaves of a sparse Merkle Tree at random positions
+        let mut tree =
+            FieldBasedSparseMHT::<TweedleFrFieldBasedMerkleTreeParams>::init(TEST_HEIGHT as u8);
+        let num_leaves = 1u32 << TEST_HEIGHT;
+        let mut leaves = HashMap::new();
+        while leaves.len() < (num_leaves / 2) as usize {
+            leaves.insert(rng.gen_range(0..num_leaves), rng.gen());
+        }
+        tree.insert_leaves(leaves).unwrap();
+        tree.finalize_in_place().unwrap();
+        let root = tree.root().unwrap();
+
+        for i in 0..num_leaves {
+            let is_empty = tree.is_leaf_empty(i).unwrap();
+            let path = if is_empty {
+                tree.get_non_membership_proof(i).unwrap().get_path().clone()
+            } else {
+                tree.get_merkle_path(i).unwrap()
+            };
+            let proof = FieldBasedMHTNonMembershipProof::new(path);
+
+            for should_enforce in [true, false].iter() {
+                let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+
+                // Allocate Merkle Tree Root
+                let root_g =
+                    FpGadget::<TweedleFr>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+
+                // Allocate non-membership proof
+                let proof_g =
+                    NonMembershipProofGadget::alloc(cs.ns(|| "alloc proof"), || Ok(&proof))
+                        .unwrap();
+
+                // Check non-membership
+                let should_enforce_g =
+                    Boolean::alloc(cs.ns(|| "alloc should_enforce"), || Ok(*should_enforce))
+                        .unwrap();
+                proof_g
+                    .conditionally_check_non_membership(
+                        cs.ns(|| "check non membership"),
+                        &root_g,
+                        &should_enforce_g,
+                    )
+                    .unwrap();
+
+                // Enforce leaf index
+                let leaf_index_g =
+                    FpGadget::<TweedleFr>::alloc(cs.ns(|| "alloc leaf index"), || {
+                        Ok(TweedleFr::from(i))
+                    })
+                    .unwrap();
+                proof_g
+                    .enforce_leaf_index(cs.ns(|| "enforce leaf index"), &leaf_index_g)
+                    .unwrap();
+
+                assert_eq!(cs.is_satisfied(), is_empty || !should_enforce);
+            }
+        }
+
+        // A tree without pre-computed empty nodes is not supported
+        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+        let mut tree = MNT4753FieldBasedMerkleTree::new(TEST_HEIGHT);
+        tree.append(&[Fr::zero()]).unwrap();
+        let path = tree.generate_proof(0, &Fr::zero()).unwrap();
+        let root_g = FqGadget::alloc(cs.ns(|| "alloc root"), || Ok(Fr::zero())).unwrap();
+        let path_g =
+            FieldBasedBinaryMerkleTreePathGadget::<_, HG, _>::alloc(cs.ns(|| "alloc path"), || {
+                Ok(path)
+            })
+            .unwrap();
+        assert!(path_g
+            .check_non_membership(cs.ns(|| "check non membership"), &root_g)
+            .is_err());
+    }
+
+    /// Fill half of the leaves of a sparse Merkle Tree at random positions, then check
+    /// membership, non-membership and leaf index gadgets for all the leaves.
+    fn check_merkle_paths_arity<P, HG, F, R>(height: usize, rng: &mut R)
+    where
+        P: BatchFieldBasedMerkleTreeParameters<Data = F>,
+        HG: FieldBasedHashGadget<P::H, F, DataGadget = FpGadget<F>>,
+        F: PrimeField,
+        R: RngCore,
+    {
+        let mut tree = FieldBasedSparseMHT::<P, InMemorySMTStorage<F>, FieldBasedMHTPath<P>>::init(
+            height as u8,
+        );
+        let num_leaves = P::MERKLE_ARITY.pow(height as u32) as u32;
+        let mut leaves = HashMap::new();
+        while leaves.len() < (num_leaves / 2) as usize {
+            leaves.insert(rng.gen_range(0..num_leaves), F::rand(rng));
+        }
+        tree.insert_leaves(leaves.clone()).unwrap();
+        tree.finalize_in_place().unwrap();
+        let root = tree.root().unwrap();
+
+        let all_leaves = (0..num_leaves)
+            .map(|i| *leaves.get(&i).unwrap_or(&F::zero()))
+            .collect::<Vec<_>>();
+
+        for (i, leaf) in all_leaves.iter().enumerate() {
+            let path = tree.get_merkle_path(i as u32).unwrap();
+            assert!(path.verify(height, leaf, &root).unwrap());
+
+            for use_bad_root in [false, true].iter() {
+                let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+
+                // Allocate Merkle Tree Root
+                let root_g = FpGadget::<F>::alloc(cs.ns(|| "alloc root"), || {
+                    if *use_bad_root {
+                        Ok(F::zero())
+                    } else {
+                        Ok(root)
+                    }
+                })
+                .unwrap();
+
+                // Allocate Leaf
+                let leaf_g = FpGadget::<F>::alloc(cs.ns(|| "alloc leaf"), || Ok(leaf)).unwrap();
+
+                // Allocate Merkle Tree Path
+                let path_g =
+                    FieldBasedMHTPathGadget::<P, HG, F>::alloc(cs.ns(|| "alloc path"), || {
+                        Ok(&path)
+                    })
+                    .unwrap();
+                assert_eq!(path_g.length(), height);
+
+                // Check_membership test
+                path_g
+                    .check_membership(cs.ns(|| "check membership"), &root_g, &leaf_g)
+                    .unwrap();
+
+                // Enforce leaf_index check
+                let leaf_index_g =
+                    FpGadget::<F>::alloc(cs.ns(|| "alloc leaf index"), || Ok(F::from(i as u32)))
+                        .unwrap();
+                path_g
+                    .enforce_leaf_index(cs.ns(|| "enforce leaf index"), &leaf_index_g)
+                    .unwrap();
+
+                assert_eq!(cs.is_satisfied(), !use_bad_root);
+            }
+
+            // Wrong leaf index
+            let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+            let path_g =
+                FieldBasedMHTPathGadget::<P, HG, F>::alloc(cs.ns(|| "alloc path"), || Ok(&path))
+                    .unwrap();
+            let wrong_leaf_index_g = FpGadget::<F>::alloc(cs.ns(|| "alloc leaf index"), || {
+                Ok(F::from(((i + 1) % num_leaves as usize) as u32))
+            })
+            .unwrap();
+            path_g
+                .enforce_leaf_index(cs.ns(|| "enforce leaf index"), &wrong_leaf_index_g)
+                .unwrap();
+            assert!(!cs.is_satisfied());
+
+            // Hardcoded path
+            let path_g =
+                FieldBasedMHTPathGadget::<P, HG, F>::from_value(cs.ns(|| "hardcode path"), &path);
+            assert_eq!(path, path_g.get_constant());
+
+            // Non membership test
+            let is_empty = tree.is_leaf_empty(i as u32).unwrap();
+            let proof = if is_empty {
+                tree.get_non_membership_proof(i as u32).unwrap()
+            } else {
+                FieldBasedMHTNonMembershipProof::new(path)
+            };
+
+            let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+            let root_g = FpGadget::<F>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+            let proof_g = FieldBasedMHTNonMembershipProofGadget::<P, HG, F>::alloc(
+                cs.ns(|| "alloc proof"),
+                || Ok(&proof),
+            )
+            .unwrap();
+            proof_g
+                .check_non_membership(cs.ns(|| "check non membership"), &root_g)
+                .unwrap();
+            let leaf_index_g =
+                FpGadget::<F>::alloc(cs.ns(|| "alloc leaf index"), || Ok(F::from(i as u32)))
+                    .unwrap();
+            proof_g
+                .enforce_leaf_index(cs.ns(|| "enforce leaf index"), &leaf_index_g)
+                .unwrap();
+            assert_eq!(cs.is_satisfied(), is_empty);
+        }
+
+        // Check MR from all the leaves
+        let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+        let root_g = FpGadget::<F>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+        let leaves_g = all_leaves
+            .iter()
+            .enumerate()
+            .map(|(i, leaf)| {
+                FpGadget::<F>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaf)).unwrap()
+            })
+            .collect::<Vec<_>>();
+        FieldBasedMerkleTreeGadget::<P, HG, F>::check_leaves(
+            cs.ns(|| "check all leaves belong to MT"),
+            &leaves_g,
+            &root_g,
+            height,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+    }
+
+    #[derive(Clone, Debug)]
+    struct TweedleFrFieldBasedMerkleTreeParams;
+    impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+        type Data = TweedleFr;
+        type H = TweedleFrPoseidonHash;
+        const MERKLE_ARITY: usize = 2;
+        const ZERO_NODE_CST: Option<
+            FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+        > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS);
+    }
+    impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParams {
+        type BH = TweedleFrBatchPoseidonHash;
+    }
+
+    #[derive(Clone, Debug)]
+    struct TweedleFrFieldBasedMerkleTreeParamsArity4;
+    impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParamsArity4 {
+        type Data = TweedleFr;
+        type H = TweedleFrPoseidonHashRate4;
+        const MERKLE_ARITY: usize = 4;
+        const ZERO_NODE_CST: Option<
+            FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+        > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_4);
+    }
+    impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParamsArity4 {
+        type BH = TweedleFrBatchPoseidonHashRate4;
+    }
+
+    #[derive(Clone, Debug)]
+    struct TweedleFrFieldBasedMerkleTreeParamsArity8;
+    impl FieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParamsArity8 {
+        type Data = TweedleFr;
+        type H = TweedleFrPoseidonHashRate8;
+        const MERKLE_ARITY: usize = 8;
+        const ZERO_NODE_CST: Option<
+            FieldBasedMerkleTreePrecomputedZeroConstants<'static, Self::H>,
+        > = Some(TWEEDLE_DEE_MHT_POSEIDON_PARAMETERS_ARITY_8);
+    }
+    impl BatchFieldBasedMerkleTreeParameters for TweedleFrFieldBasedMerkleTreeParamsArity8 {
+        type BH = TweedleFrBatchPoseidonHashRate8;
+    }
+
+    #[test]
+    fn merkle_paths_arity_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(9174123u64);
+
+        check_merkle_paths_arity::<
+            TweedleFrFieldBasedMerkleTreeParams,
+            TweedleFrPoseidonHashGadget,
+            _,
+            _,
+        >(TEST_HEIGHT, rng);
+        check_merkle_paths_arity::<
+            TweedleFrFieldBasedMerkleTreeParamsArity4,
+            TweedleFrPoseidonHashGadgetRate4,
+            _,
+            _,
+        >(3, rng);
+        check_merkle_paths_arity::<
+            TweedleFrFieldBasedMerkleTreeParamsArity8,
+            TweedleFrPoseidonHashGadgetRate8,
+            _,
+            _,
+        >(2, rng);
+    }
+
+    /// Open random subsets of the leaves of an append only Merkle Tree and check the
+    /// multi-path gadget, comparing its cost with the one of independent path gadgets.
+    fn check_merkle_multi_paths<P, HG, F, R>(height: usize, rng: &mut R)
+    where
+        P: BatchFieldBasedMerkleTreeParameters<Data = F>,
+        HG: FieldBasedHashGadget<P::H, F, DataGadget = FpGadget<F>>,
+        F: PrimeField,
+        R: RngCore,
+    {
+        let num_leaves = P::MERKLE_ARITY.pow(height as u32);
+        let mut tree = FieldBasedAppendOnlyMHT::<P>::init(height, num_leaves).unwrap();
+        let mut leaves = Vec::with_capacity(num_leaves);
+        for _ in 0..num_leaves / 2 {
+            let leaf = F::rand(rng);
+            tree.append(leaf).unwrap();
+            leaves.push(leaf);
+        }
+        leaves.resize(num_leaves, F::zero());
+        tree.finalize_in_place().unwrap();
+        let root = tree.root().unwrap();
+
+        let all_indices = (0..num_leaves).collect::<Vec<_>>();
+        for &num_opened_leaves in [1, 2, num_leaves / 2, num_leaves].iter() {
+            let mut indices = all_indices
+                .choose_multiple(rng, num_opened_leaves)
+                .cloned()
+                .collect::<Vec<_>>();
+            indices.sort_unstable();
+            let opened_leaves = indices.iter().map(|&i| leaves[i]).collect::<Vec<_>>();
+            let multi_path = tree.get_multi_path(&indices).unwrap();
+            assert!(multi_path.verify(height, &opened_leaves, &root).unwrap());
+
+            for use_bad_root in [false, true].iter() {
+                let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+
+                // Allocate Merkle Tree Root
+                let root_g = FpGadget::<F>::alloc(cs.ns(|| "alloc root"), || {
+                    if *use_bad_root {
+                        Ok(F::zero())
+                    } else {
+                        Ok(root)
+                    }
+                })
+                .unwrap();
+
+                // Allocate Leaves
+                let leaves_g = opened_leaves
+                    .iter()
+                    .enumerate()
+                    .map(|(i, leaf)| {
+                        FpGadget::<F>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaf))
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+
+                // Allocate Merkle Tree Multi Path
+                let multi_path_g = FieldBasedMHTMultiPathGadget::<P, HG, F>::alloc(
+                    cs.ns(|| "alloc multi path"),
+                    || Ok(&multi_path),
+                )
+                .unwrap();
+                assert_eq!(multi_path_g.leaf_indices(), indices.as_slice());
+                assert_eq!(multi_path_g.height(), height);
+
+                // Check_membership test
+                let num_constraints = cs.num_constraints();
+                multi_path_g
+                    .check_membership(cs.ns(|| "check membership"), &root_g, &leaves_g)
+                    .unwrap();
+                let multi_path_constraints = cs.num_constraints() - num_constraints;
+                assert_eq!(cs.is_satisfied(), !use_bad_root);
+
+                // Check membership with independent paths
+                let num_constraints = cs.num_constraints();
+                for (i, (&idx, leaf_g)) in indices.iter().zip(leaves_g.iter()).enumerate() {
+                    let path = tree.get_merkle_path(idx).unwrap();
+                    let path_g = FieldBasedMHTPathGadget::<P, HG, F>::alloc(
+                        cs.ns(|| format!("alloc path {}", i)),
+                        || Ok(&path),
+                    )
+                    .unwrap();
+                    path_g
+                        .check_membership(
+                            cs.ns(|| format!("check membership {}", i)),
+                            &root_g,
+                            leaf_g,
+                        )
+                        .unwrap();
+                }
+                let paths_constraints = cs.num_constraints() - num_constraints;
+                assert!(multi_path_constraints < paths_constraints);
+            }
+
+            // Wrong leaf, with and without enforcing the check
+            let wrong_leaf_idx = rng.gen_range(0..num_opened_leaves);
+            for should_enforce in [false, true].iter() {
+                let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+                let root_g = FpGadget::<F>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+                let leaves_g = opened_leaves
+                    .iter()
+                    .enumerate()
+                    .map(|(i, leaf)| {
+                        FpGadget::<F>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || {
+                            if i == wrong_leaf_idx {
+                                Ok(*leaf + F::one())
+                            } else {
+                                Ok(*leaf)
+                            }
+                        })
+                        .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                let multi_path_g = FieldBasedMHTMultiPathGadget::<P, HG, F>::alloc(
+                    cs.ns(|| "alloc multi path"),
+                    || Ok(&multi_path),
+                )
+                .unwrap();
+                multi_path_g
+                    .conditionally_check_membership(
+                        cs.ns(|| "conditionally check membership"),
+                        &root_g,
+                        &leaves_g,
+                        &Boolean::Constant(*should_enforce),
+                    )
+                    .unwrap();
+                assert_eq!(cs.is_satisfied(), !should_enforce);
+
+                // Wrong number of leaves
+                assert!(multi_path_g
+                    .check_membership(
+                        cs.ns(|| "check membership with less leaves"),
+                        &root_g,
+                        &leaves_g[1..]
+                    )
+                    .is_err());
+            }
+
+            // Hardcoded multi path
+            let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+            let multi_path_g = FieldBasedMHTMultiPathGadget::<P, HG, F>::from_value(
+                cs.ns(|| "hardcode multi path"),
+                &multi_path,
+            );
+            assert_eq!(multi_path, multi_path_g.get_constant());
+
+            // Shape fixed by the leaf indices and the height: the same circuit is obtained
+            // when generating the parameters, i.e. without any multi-path
+            let mut num_constraints = Vec::new();
+            for &mode in [SynthesisMode::Setup, SynthesisMode::Debug].iter() {
+                let mut cs = ConstraintSystem::<F>::new(mode);
+                let root_g = FpGadget::<F>::alloc(cs.ns(|| "alloc root"), || Ok(root)).unwrap();
+                let leaves_g = opened_leaves
+                    .iter()
+                    .enumerate()
+                    .map(|(i, leaf)| {
+                        FpGadget::<F>::alloc(cs.ns(|| format!("alloc leaf_{}", i)), || Ok(leaf))
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+                let multi_path_g = FieldBasedMHTMultiPathGadget::<P, HG, F>::alloc_with_shape(
+                    cs.ns(|| "alloc multi path"),
+                    &indices,
+                    height,
+                    || {
+                        if mode == SynthesisMode::Setup {
+                            Err(SynthesisError::AssignmentMissing)
+                        } else {
+                            Ok(&multi_path)
+                        }
+                    },
+                )
+                .unwrap();
+                multi_path_g
+                    .check_membership(cs.ns(|| "check membership"), &root_g, &leaves_g)
+                    .unwrap();
+                if mode == SynthesisMode::Debug {
+                    assert!(cs.is_satisfied());
+                }
+                num_constraints.push(cs.num_constraints());
+            }
+            assert_eq!(num_constraints[0], num_constraints[1]);
+
+            // A multi-path of different shape must be rejected
+            let mut cs = ConstraintSystem::<F>::new(SynthesisMode::Debug);
+            assert!(FieldBasedMHTMultiPathGadget::<P, HG, F>::alloc_with_shape(
+                cs.ns(|| "alloc multi path of wrong height"),
+                &indices,
+                height + 1,
+                || Ok(&multi_path),
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    fn merkle_multi_paths_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(9174123u64);
+
+        check_merkle_multi_paths::<
+            TweedleFrFieldBasedMerkleTreeParams,
+            TweedleFrPoseidonHashGadget,
+            _,
+            _,
+        >(TEST_HEIGHT, rng);
+        check_merkle_multi_paths::<
+            TweedleFrFieldBasedMerkleTreeParamsArity4,
+            TweedleFrPoseidonHashGadgetRate4,
+            _,
+            _,
+        >(3, rng);
+    }
 }
@@ -46,6 +46,46 @@ pub trait FieldBasedMerkleTreePathGadget<
         root.conditional_enforce_equal(&mut cs.ns(|| "root_is_last"), expected_root, should_enforce)
     }
 
+    /// Enforce that the leaf at the position given by `self` path is empty, i.e. that the
+    /// root reconstructed from `self` and the pre-computed empty leaf is equal to `expected_root`.
+    fn check_non_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        expected_root: &HGadget::DataGadget,
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_non_membership(cs, expected_root, &Boolean::Constant(true))
+    }
+
+    /// Enforce that the leaf at the position given by `self` path is empty, i.e. that the
+    /// root reconstructed from `self` and the pre-computed empty leaf is equal to `expected_root`,
+    /// if `should_enforce` is True, otherwise enforce nothing.
+    fn conditionally_check_non_membership<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        expected_root: &HGadget::DataGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let empty_leaf = match <P::Parameters as FieldBasedMerkleTreeParameters>::ZERO_NODE_CST {
+            Some(zero_node_cst) if self.length() < zero_node_cst.nodes.len() => {
+                zero_node_cst.nodes[0]
+            }
+            _ => {
+                return Err(SynthesisError::Other(
+                    "Empty leaf not available for the specified Merkle Tree".to_owned(),
+                ))
+            }
+        };
+        let empty_leaf_g =
+            HGadget::DataGadget::from_value(cs.ns(|| "hardcode empty leaf"), &empty_leaf);
+
+        self.conditionally_check_membership(
+            cs.ns(|| "check membership of empty leaf"),
+            expected_root,
+            &empty_leaf_g,
+            should_enforce,
+        )
+    }
+
     /// Enforce correct reconstruction of the root of the Merkle Tree
     /// from `self` path and `leaf`.
     fn enforce_root_from_leaf<CS: ConstraintSystemAbstract<ConstraintF>>(
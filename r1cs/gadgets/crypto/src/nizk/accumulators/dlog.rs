@@ -0,0 +1,311 @@
+//! The gadget of the dlog accumulator `DLogItemAccumulator` of `proof_systems::darlin`.
+//! A dlog item consists of the final committer key `G_f` and the reduction challenges
+//! `xi_s` of a dlog opening proof. Items are accumulated by opening their succinct check
+//! polynomials, committed by `G_f`, at a common challenge `z`, and the new item is the one
+//! of the resulting (batched) opening proof.
+//!
+//! As the items stem from the dlog commitment scheme over `G`, their verification is
+//! enforced in a circuit over `G::BaseField`, i.e. the scalar field of the other curve of
+//! the cycle. Fiat-Shamir is performed by means of `FiatShamirRngGadget`, hence the gadget
+//! verifies the accumulation proofs of `DLogItemAccumulator` with the random oracle
+//! `AlgebraicRandomOracle`, and agrees with it on the new item.
+use crate::{
+    crh::FieldBasedSpongeGadget,
+    nizk::{
+        accumulators::ItemAccumulatorGadget,
+        fiat_shamir::FiatShamirRngGadget,
+        ipa_pc::{
+            enforce_endo_rep_to_scalar, IPAProofGadget, IPAVerifierKeyGadget,
+            InnerProductArgPCGadget, SuccinctCheckPolynomialGadget,
+        },
+    },
+};
+use algebra::{
+    curves::short_weierstrass_jacobian::GroupProjective, EndoMulParameters, PrimeField,
+    ToConstraintField,
+};
+use primitives::crh::FieldBasedSponge;
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::fp::FpGadget, groups::EndoMulCurveGadget, prelude::*,
+    to_field_gadget_vec::ToConstraintFieldGadget,
+};
+use std::marker::PhantomData;
+
+/// The protocol name of the dlog accumulator with the algebraic random oracle, used to
+/// personalize the random oracle.
+pub const PROTOCOL_NAME: &[u8] = b"DL-ACC-ALG-2021";
+
+/// The gadget of `DLogItem`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "GG: Clone"))]
+pub struct DLogItemGadget<P: EndoMulParameters, GG>
+where
+    P::BaseField: PrimeField,
+{
+    /// The final committer key of the dlog reduction.
+    pub g_final: GG,
+    /// The challenges of the dlog reduction.
+    pub xi_s: SuccinctCheckPolynomialGadget<P::ScalarField, P::BaseField>,
+}
+
+pub struct DLogItemAccumulatorGadget<ConstraintF, P, GG, S, SG>
+where
+    ConstraintF: PrimeField,
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    GG: EndoMulCurveGadget<GroupProjective<P>, ConstraintF>
+        + GroupGadget<GroupProjective<P>, ConstraintF, Value = GroupProjective<P>>
+        + ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+    S: FieldBasedSponge<Data = ConstraintF>,
+    SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+{
+    _field: PhantomData<ConstraintF>,
+    _parameters: PhantomData<P>,
+    _group_gadget: PhantomData<GG>,
+    _sponge: PhantomData<S>,
+    _sponge_gadget: PhantomData<SG>,
+}
+
+impl<ConstraintF, P, GG, S, SG> ItemAccumulatorGadget<ConstraintF>
+    for DLogItemAccumulatorGadget<ConstraintF, P, GG, S, SG>
+where
+    ConstraintF: PrimeField,
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    GG: EndoMulCurveGadget<GroupProjective<P>, ConstraintF>
+        + GroupGadget<GroupProjective<P>, ConstraintF, Value = GroupProjective<P>>
+        + ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+    S: FieldBasedSponge<Data = ConstraintF>,
+    SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+{
+    type AccumulatorVerifierKeyGadget = IPAVerifierKeyGadget<GG>;
+    /// The accumulation proof of the dlog accumulator carries the dlog opening proof only.
+    type AccumulationProofGadget = IPAProofGadget<P, GG>;
+    type ItemGadget = DLogItemGadget<P, GG>;
+
+    fn enforce_succinct_verify_accumulated_items<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        vk: &Self::AccumulatorVerifierKeyGadget,
+        previous_items: &[Self::ItemGadget],
+        proof: &Self::AccumulationProofGadget,
+    ) -> Result<Self::ItemGadget, SynthesisError> {
+        if previous_items.is_empty() {
+            return Err(SynthesisError::Other("No items to accumulate".to_owned()));
+        }
+
+        // Initialize the random oracle, personalized by the protocol name and the hash of
+        // the committer key, and bind it to the items.
+        let personalization = [PROTOCOL_NAME, vk.hash.as_slice()]
+            .iter()
+            .map(|bytes| <[u8] as ToConstraintField<ConstraintF>>::to_field_elements(bytes))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| SynthesisError::Other(e.to_string()))?
+            .concat();
+        let mut fs_rng = FiatShamirRngGadget::<ConstraintF, S, SG>::init(
+            cs.ns(|| "init fs rng"),
+            Some(personalization.as_slice()),
+        )?;
+        for (i, item) in previous_items.iter().enumerate() {
+            fs_rng.enforce_absorb(
+                cs.ns(|| format!("absorb g_final {}", i)),
+                std::slice::from_ref(&item.g_final),
+            )?;
+            fs_rng.enforce_absorb_nonnative(
+                cs.ns(|| format!("absorb xi_s {}", i)),
+                item.xi_s.0.as_slice(),
+            )?;
+        }
+
+        // Sample the challenge z and compute the values of the succinct check polynomials
+        let z_bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze z"))?;
+        let z = enforce_endo_rep_to_scalar::<P, _, _>(cs.ns(|| "z to scalar"), z_bits.as_slice())?;
+        let values = previous_items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                item.xi_s
+                    .evaluate(cs.ns(|| format!("evaluate xi_s {} at z", i)), &z)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        fs_rng.enforce_absorb_nonnative(cs.ns(|| "absorb values"), values.as_slice())?;
+
+        // Succinctly verify the opening of the G_f's, obtaining the new xi_s
+        let commitments = previous_items
+            .iter()
+            .map(|item| vec![item.g_final.clone()])
+            .collect::<Vec<_>>();
+        let xi_s = InnerProductArgPCGadget::<ConstraintF, P, GG>::succinct_check(
+            cs.ns(|| "succinct check"),
+            vk,
+            commitments.as_slice(),
+            &z,
+            values.as_slice(),
+            proof,
+            &mut fs_rng,
+        )?;
+
+        Ok(DLogItemGadget {
+            g_final: proof.final_comm_key.clone(),
+            xi_s,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::*;
+    use crate::{
+        nizk::ipa_pc::test::{alloc_point, alloc_proof, alloc_scalar, alloc_vk, SEGMENT_SIZE},
+        TweedleFqPoseidonSpongeGadget,
+    };
+    use algebra::{
+        curves::tweedle::{
+            dee::{Affine, TweedledeeParameters},
+            dum::Affine as DumAffine,
+        },
+        fields::tweedle::{Fq, Fr},
+        ProjectiveCurve, UniformRand,
+    };
+    use blake2::Blake2s;
+    use poly_commit::{
+        ipa_pc::{CommitterKey, InnerProductArgPC, Proof, VerifierKey},
+        PolynomialCommitment,
+    };
+    use primitives::TweedleFqPoseidonHash;
+    use proof_systems::darlin::{
+        accumulators::{
+            dlog::{AlgebraicRandomOracle, DLogItem, DLogItemAccumulator},
+            ItemAccumulator,
+        },
+        data_structures::FinalDarlinDeferredData,
+        tests::get_keys,
+    };
+    use r1cs_core::{ConstraintSystem, ConstraintSystemDebugger, SynthesisMode};
+    use r1cs_std::instantiated::tweedle::TweedleDeeGadget;
+    use rand::{thread_rng, Rng};
+
+    type DLogAccumulator =
+        DLogItemAccumulator<Affine, Blake2s, AlgebraicRandomOracle<TweedleFqPoseidonHash>>;
+    type DLogAccumulatorGadget = DLogItemAccumulatorGadget<
+        Fq,
+        TweedledeeParameters,
+        TweedleDeeGadget,
+        TweedleFqPoseidonHash,
+        TweedleFqPoseidonSpongeGadget,
+    >;
+
+    /// Random items over Tweedle Dee, the ones of the pre-previous node of random
+    /// `FinalDarlinDeferredData`.
+    fn random_items<R: Rng>(
+        ck_g1: &CommitterKey<Affine>,
+        ck_g2: &CommitterKey<DumAffine>,
+        num_items: usize,
+        rng: &mut R,
+    ) -> Vec<DLogItem<Affine>> {
+        (0..num_items)
+            .map(|_| {
+                FinalDarlinDeferredData::<Affine, DumAffine>::generate_random::<_, Blake2s>(
+                    rng, ck_g1, ck_g2,
+                )
+                .pre_previous_acc()
+                .clone()
+            })
+            .collect()
+    }
+
+    fn enforce_accumulation(
+        vk: &VerifierKey<Affine>,
+        items: &[DLogItem<Affine>],
+        proof: &Proof<Affine>,
+    ) -> (bool, Option<(Affine, Vec<Fr>)>) {
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+
+        let vk = alloc_vk(&mut cs, vk);
+        let items = items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let g_final =
+                    alloc_point(&mut cs, &format!("g_final {}", i), &item.g_final().comm[0]);
+                let xi_s = item
+                    .xi_s()
+                    .0
+                    .iter()
+                    .enumerate()
+                    .map(|(j, xi)| alloc_scalar(&mut cs, &format!("item {} xi {}", i, j), xi))
+                    .collect();
+                DLogItemGadget {
+                    g_final,
+                    xi_s: SuccinctCheckPolynomialGadget(xi_s),
+                }
+            })
+            .collect::<Vec<_>>();
+        let proof = alloc_proof(&mut cs, proof);
+
+        let new_item = DLogAccumulatorGadget::enforce_succinct_verify_accumulated_items(
+            cs.ns(|| "verify accumulation"),
+            &vk,
+            items.as_slice(),
+            &proof,
+        )
+        .ok()
+        .map(|item| {
+            (
+                item.g_final.get_value().unwrap().into_affine(),
+                item.xi_s.get_value().unwrap(),
+            )
+        });
+
+        (cs.is_satisfied(), new_item)
+    }
+
+    #[test]
+    fn tweedle_dlog_accumulator_gadget_test() {
+        let rng = &mut thread_rng();
+        let params_g1 = InnerProductArgPC::<Affine, Blake2s>::setup(SEGMENT_SIZE - 1).unwrap();
+        let params_g2 = InnerProductArgPC::<DumAffine, Blake2s>::setup(SEGMENT_SIZE - 1).unwrap();
+        let (ck_g1, vk_g1, ck_g2, _) =
+            get_keys::<Affine, DumAffine, Blake2s>(&params_g1, &params_g2);
+
+        for &num_items in [1, 3].iter() {
+            let items = random_items(&ck_g1, &ck_g2, num_items, rng);
+
+            // Accumulate natively, and check the native and gadget verifiers agree
+            let (_, proof) = DLogAccumulator::accumulate_items(&ck_g1, items.clone()).unwrap();
+            assert!(DLogAccumulator::verify_accumulated_items(
+                &DLogItem::default(),
+                &vk_g1,
+                items.clone(),
+                &proof,
+                rng
+            )
+            .unwrap());
+            let new_item =
+                DLogAccumulator::succinct_verify_accumulated_items(&vk_g1, items.clone(), &proof)
+                    .unwrap()
+                    .unwrap();
+
+            let (satisfied, new_item_g) = enforce_accumulation(&vk_g1, &items, &proof.pc_proof);
+            assert!(satisfied);
+            assert_eq!(
+                new_item_g.unwrap(),
+                (new_item.g_final().comm[0], new_item.xi_s().0.clone())
+            );
+
+            // Wrong items
+            let wrong_items = random_items(&ck_g1, &ck_g2, num_items, rng);
+            assert!(!enforce_accumulation(&vk_g1, &wrong_items, &proof.pc_proof).0);
+
+            // Wrong committer key hash
+            let mut wrong_vk = vk_g1.clone();
+            wrong_vk.hash[0] ^= 1;
+            assert!(!enforce_accumulation(&wrong_vk, &items, &proof.pc_proof).0);
+
+            // Wrong final coefficient
+            let wrong_proof = Proof {
+                c: Fr::rand(rng),
+                ..proof.pc_proof
+            };
+            assert!(!enforce_accumulation(&vk_g1, &items, &wrong_proof).0);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Gadgets for the accumulation schemes of `proof_systems::darlin::accumulators`, i.e. the
+//! in-circuit counterpart of the succinct part of `ItemAccumulator::verify_accumulated_items()`,
+//! as needed for recursive aggregation.
+use algebra::PrimeField;
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+
+pub mod dlog;
+
+/// The gadget of an `ItemAccumulator`: enforces the succinct verification of an
+/// accumulation proof, outputting the new item. Deciding the new item, i.e. checking the
+/// non-efficient predicate, is left to the next step of the recursion (or to the native
+/// verifier at the end of it).
+pub trait ItemAccumulatorGadget<ConstraintF: PrimeField> {
+    type AccumulatorVerifierKeyGadget;
+    type AccumulationProofGadget;
+    type ItemGadget;
+
+    /// Enforce the succinct verification of `proof`, the accumulation proof for
+    /// `previous_items`, and return the new item.
+    fn enforce_succinct_verify_accumulated_items<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        vk: &Self::AccumulatorVerifierKeyGadget,
+        previous_items: &[Self::ItemGadget],
+        proof: &Self::AccumulationProofGadget,
+    ) -> Result<Self::ItemGadget, SynthesisError>;
+}
@@ -0,0 +1,189 @@
+//! An algebraic Fiat-Shamir random oracle, i.e. a transcript built on top of the gadget
+//! of a `FieldBasedSponge`, to be used by in-circuit verifiers of recursive proofs.
+//! Elements of the constraint field are absorbed as they are, while non-native field
+//! elements are absorbed by packing their big-endian bit representation into chunks of
+//! `CAPACITY` bits each. Challenges are squeezed as `CHALLENGE_SIZE` bits, meant to be used
+//! as endomorphism representation of scalars (see `EndoMulCurveGadget`).
+//! Its native counterpart is `FieldBasedFiatShamirRng` of `proof_systems::darlin::fiat_shamir`.
+use crate::crh::FieldBasedSpongeGadget;
+use algebra::{FpParameters, PrimeField};
+use primitives::crh::FieldBasedSponge;
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::{fp::FpGadget, nonnative::nonnative_field_gadget::NonNativeFieldGadget},
+    prelude::*,
+    to_field_gadget_vec::ToConstraintFieldGadget,
+};
+use std::marker::PhantomData;
+
+/// The bit length of the challenges squeezed out of the random oracle.
+pub const CHALLENGE_SIZE: usize = 128;
+
+pub struct FiatShamirRngGadget<
+    ConstraintF: PrimeField,
+    S: FieldBasedSponge<Data = ConstraintF>,
+    SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+> {
+    sponge: SG,
+    _field: PhantomData<ConstraintF>,
+    _sponge: PhantomData<S>,
+}
+
+impl<ConstraintF, S, SG> FiatShamirRngGadget<ConstraintF, S, SG>
+where
+    ConstraintF: PrimeField,
+    S: FieldBasedSponge<Data = ConstraintF>,
+    SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+{
+    /// Initialize the random oracle, domain separated by `personalization` if specified.
+    pub fn init<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        personalization: Option<&[ConstraintF]>,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            sponge: SG::init(cs, personalization)?,
+            _field: PhantomData,
+            _sponge: PhantomData,
+        })
+    }
+
+    /// Enforce the absorption of `elems`, e.g. curve points, by means of their
+    /// representation as constraint field elements.
+    pub fn enforce_absorb<CS, T>(&mut self, mut cs: CS, elems: &[T]) -> Result<(), SynthesisError>
+    where
+        CS: ConstraintSystemAbstract<ConstraintF>,
+        T: ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+    {
+        let mut fes = Vec::new();
+        for (i, elem) in elems.iter().enumerate() {
+            fes.append(&mut elem.to_field_gadget_elements(cs.ns(|| format!("elem {} to fes", i)))?);
+        }
+        self.sponge
+            .enforce_absorb(cs.ns(|| "absorb fes"), fes.as_slice())
+    }
+
+    /// Enforce the absorption of the non-native field elements `elems`: their big-endian bit
+    /// representations are concatenated and packed into chunks of `CAPACITY` bits.
+    pub fn enforce_absorb_nonnative<CS, SimulationF>(
+        &mut self,
+        mut cs: CS,
+        elems: &[NonNativeFieldGadget<SimulationF, ConstraintF>],
+    ) -> Result<(), SynthesisError>
+    where
+        CS: ConstraintSystemAbstract<ConstraintF>,
+        SimulationF: PrimeField,
+    {
+        let mut bits = Vec::new();
+        for (i, elem) in elems.iter().enumerate() {
+            bits.append(&mut elem.to_bits_strict(cs.ns(|| format!("elem {} to bits", i)))?);
+        }
+
+        let fes = bits
+            .chunks(ConstraintF::Params::CAPACITY as usize)
+            .enumerate()
+            .map(|(i, chunk)| FpGadget::from_bits(cs.ns(|| format!("pack chunk {}", i)), chunk))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.sponge
+            .enforce_absorb(cs.ns(|| "absorb packed bits"), fes.as_slice())
+    }
+
+    /// Enforce the squeezing of a challenge of `CHALLENGE_SIZE` bits.
+    pub fn enforce_squeeze_128_bits_challenge<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &mut self,
+        cs: CS,
+    ) -> Result<Vec<Boolean>, SynthesisError> {
+        self.sponge.enforce_squeeze_bits(cs, CHALLENGE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::UniformRand;
+    use proof_systems::darlin::fiat_shamir::FieldBasedFiatShamirRng;
+    use r1cs_core::{ConstraintSystem, ConstraintSystemDebugger, SynthesisMode};
+    use rand::thread_rng;
+
+    #[cfg(feature = "tweedle")]
+    #[test]
+    fn tweedle_fiat_shamir_rng_gadget_test() {
+        use crate::TweedleFqPoseidonSpongeGadget;
+        use algebra::{
+            curves::tweedle::dee::Projective,
+            fields::tweedle::{Fq, Fr},
+        };
+        use primitives::TweedleFqPoseidonHash;
+        use r1cs_std::instantiated::tweedle::TweedleDeeGadget;
+
+        let rng = &mut thread_rng();
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+
+        let native_elems = (0..3).map(|_| Fq::rand(rng)).collect::<Vec<_>>();
+        let points = (0..2).map(|_| Projective::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        let mut fs_rng = FieldBasedFiatShamirRng::<TweedleFqPoseidonHash>::init(None);
+        fs_rng.absorb(&native_elems).unwrap();
+        fs_rng.absorb(&points).unwrap();
+        fs_rng.absorb_nonnative(&scalars).unwrap();
+        let challenges = [
+            fs_rng.squeeze_128_bits_challenge(),
+            fs_rng.squeeze_128_bits_challenge(),
+        ];
+
+        let mut fs_rng_g = FiatShamirRngGadget::<
+            Fq,
+            TweedleFqPoseidonHash,
+            TweedleFqPoseidonSpongeGadget,
+        >::init(cs.ns(|| "init fs rng"), None)
+        .unwrap();
+
+        let native_elems_g = native_elems
+            .iter()
+            .enumerate()
+            .map(|(i, fe)| FpGadget::<Fq>::alloc(cs.ns(|| format!("alloc elem {}", i)), || Ok(fe)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let points_g = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| TweedleDeeGadget::alloc(cs.ns(|| format!("alloc point {}", i)), || Ok(p)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let scalars_g = scalars
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                NonNativeFieldGadget::<Fr, Fq>::alloc(
+                    cs.ns(|| format!("alloc scalar {}", i)),
+                    || Ok(s),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        fs_rng_g
+            .enforce_absorb(cs.ns(|| "absorb native elems"), &native_elems_g)
+            .unwrap();
+        fs_rng_g
+            .enforce_absorb(cs.ns(|| "absorb points"), &points_g)
+            .unwrap();
+        fs_rng_g
+            .enforce_absorb_nonnative(cs.ns(|| "absorb scalars"), &scalars_g)
+            .unwrap();
+        for (i, challenge) in challenges.iter().enumerate() {
+            let challenge_g = fs_rng_g
+                .enforce_squeeze_128_bits_challenge(cs.ns(|| format!("squeeze challenge {}", i)))
+                .unwrap();
+            assert_eq!(challenge_g.len(), CHALLENGE_SIZE);
+            assert_eq!(
+                challenge_g
+                    .iter()
+                    .map(|b| b.get_value().unwrap())
+                    .collect::<Vec<_>>(),
+                *challenge
+            );
+        }
+        assert!(cs.is_satisfied());
+    }
+}
@@ -0,0 +1,995 @@
+//! Gadgets for the succinct verifier of the dlog/IPA polynomial commitment scheme of
+//! `poly_commit::ipa_pc` over curves with an efficient endomorphism, e.g. the Tweedle
+//! cycle. The circuit is defined over the base field of the curve, so that points are
+//! native while scalars are simulated by `NonNativeFieldGadget`s. The succinct verifier
+//! does everything but checking the final committer key `G_f` against the reduction
+//! challenges, which is left to the dlog accumulator (see `proof_systems::darlin`).
+//!
+//! Fiat-Shamir is performed via `FiatShamirRngGadget`, squeezing 128 bit challenges
+//! which are used as endomorphism representation of scalars. Hence the gadgets verify
+//! the proofs of `AlgebraicInnerProductArgPC` of `proof_systems::darlin::algebraic_ipa_pc`,
+//! the native prover using the same algebraic random oracle, and not the ones of
+//! `poly_commit::ipa_pc`, whose random oracle is digest based. Multi-point opening proofs
+//! are verified by `multi_point_succinct_check()`, as needed by the Marlin verifier gadget
+//! of `nizk::marlin`.
+use crate::nizk::fiat_shamir::FiatShamirRngGadget;
+use algebra::{
+    curves::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    EndoMulCurve, EndoMulParameters, Field, Group, PrimeField,
+};
+use primitives::crh::FieldBasedSponge;
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::{fp::FpGadget, nonnative::nonnative_field_gadget::NonNativeFieldGadget},
+    groups::EndoMulCurveGadget,
+    prelude::*,
+    to_field_gadget_vec::ToConstraintFieldGadget,
+    Assignment,
+};
+use std::marker::PhantomData;
+
+use crate::crh::FieldBasedSpongeGadget;
+
+type ScalarGadget<P, ConstraintF> =
+    NonNativeFieldGadget<<P as algebra::ModelParameters>::ScalarField, ConstraintF>;
+
+/// Enforce the conversion of an endomorphism representation `bits`, as used by
+/// `EndoMulCurveGadget::endo_mul()`, into the equivalent scalar. This is the gadget of
+/// `EndoMulCurve::endo_rep_to_scalar()`.
+pub fn enforce_endo_rep_to_scalar<P, ConstraintF, CS>(
+    mut cs: CS,
+    bits: &[Boolean],
+) -> Result<ScalarGadget<P, ConstraintF>, SynthesisError>
+where
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let mut bits = bits.to_vec();
+    if bits.len() % 2 == 1 {
+        bits.push(Boolean::constant(false));
+    }
+
+    if bits.len() > P::LAMBDA {
+        Err(SynthesisError::Other(
+            "Endo mul bits length exceeds LAMBDA".to_owned(),
+        ))?
+    }
+
+    // Denoting by n the number of pairs of bits, the scalar is a * ENDO_SCALAR + b with
+    //      a = 2^{n+1} + sum_i 2^i * b_{2i+1} * (2 * b_{2i} - 1),
+    //      b = 2^{n+1} + sum_i 2^i * (1 - b_{2i+1}) * (2 * b_{2i} - 1),
+    // i.e. a = 2^{n+1} + 2 * A - B and b = 2^{n+1} + 2 * C - D, where A, B, C, D are
+    // packed from the (big-endian) sequences of b_{2i+1} AND b_{2i}, b_{2i+1},
+    // (NOT b_{2i+1}) AND b_{2i} and NOT b_{2i+1}.
+    let n = bits.len() / 2;
+    let mut a_bits = Vec::with_capacity(n);
+    let mut b_bits = Vec::with_capacity(n);
+    let mut c_bits = Vec::with_capacity(n);
+    let mut d_bits = Vec::with_capacity(n);
+    for i in (0..n).rev() {
+        a_bits.push(Boolean::and(
+            cs.ns(|| format!("b_{} AND b_{}", 2 * i + 1, 2 * i)),
+            &bits[2 * i + 1],
+            &bits[2 * i],
+        )?);
+        b_bits.push(bits[2 * i + 1]);
+        c_bits.push(Boolean::and(
+            cs.ns(|| format!("NOT b_{} AND b_{}", 2 * i + 1, 2 * i)),
+            &bits[2 * i + 1].not(),
+            &bits[2 * i],
+        )?);
+        d_bits.push(bits[2 * i + 1].not());
+    }
+
+    let mut pack = |name: &str, bits: &[Boolean]| {
+        ScalarGadget::<P, ConstraintF>::from_bits(cs.ns(|| format!("pack {}", name)), bits)
+    };
+    let a_packed = pack("A", a_bits.as_slice())?;
+    let b_packed = pack("B", b_bits.as_slice())?;
+    let c_packed = pack("C", c_bits.as_slice())?;
+    let d_packed = pack("D", d_bits.as_slice())?;
+
+    // a * ENDO_SCALAR + b = 2^{n+1} * (ENDO_SCALAR + 1) + 2 * ENDO_SCALAR * A
+    //                          - ENDO_SCALAR * B + 2 * C - D
+    let endo_scalar = P::ENDO_SCALAR;
+    let two_pow = P::ScalarField::from(2u64).pow(&[(n + 1) as u64]);
+    let terms = [
+        (a_packed, endo_scalar.double()),
+        (b_packed, -endo_scalar),
+        (c_packed, P::ScalarField::from(2u64)),
+        (d_packed, -P::ScalarField::one()),
+    ];
+    let mut result = ScalarGadget::<P, ConstraintF>::from_value(
+        cs.ns(|| "hardcode offset"),
+        &(two_pow * &(endo_scalar + &P::ScalarField::one())),
+    );
+    for (i, (packed, coeff)) in terms.iter().enumerate() {
+        let term = packed.mul_by_constant(cs.ns(|| format!("term {} by constant", i)), coeff)?;
+        result = result.add(cs.ns(|| format!("add term {}", i)), &term)?;
+    }
+
+    Ok(result)
+}
+
+/// The succinct check polynomial of the dlog reduction
+///     h(X) = (1 + xi_d * X) * (1 + xi_{d-1} * X^2) * ... * (1 + xi_1 * X^{2^{d-1}}),
+/// given by its challenges xi_1, ..., xi_d.
+#[derive(Derivative)]
+#[derivative(Clone(bound = ""))]
+pub struct SuccinctCheckPolynomialGadget<SimulationF: PrimeField, ConstraintF: PrimeField>(
+    pub Vec<NonNativeFieldGadget<SimulationF, ConstraintF>>,
+);
+
+impl<SimulationF, ConstraintF> SuccinctCheckPolynomialGadget<SimulationF, ConstraintF>
+where
+    SimulationF: PrimeField,
+    ConstraintF: PrimeField,
+{
+    pub fn get_value(&self) -> Option<Vec<SimulationF>> {
+        self.0.iter().map(|xi| xi.get_value()).collect()
+    }
+
+    /// Enforce the evaluation of the polynomial at `point`.
+    pub fn evaluate<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        point: &NonNativeFieldGadget<SimulationF, ConstraintF>,
+    ) -> Result<NonNativeFieldGadget<SimulationF, ConstraintF>, SynthesisError> {
+        let log_d = self.0.len();
+
+        // point^{2^j}, j = 0, ..., d - 1
+        let mut point_powers = vec![point.clone()];
+        for j in 1..log_d {
+            let power = point_powers[j - 1].square(cs.ns(|| format!("point^(2^{})", j)))?;
+            point_powers.push(power);
+        }
+
+        let mut product = NonNativeFieldGadget::one(cs.ns(|| "one"))?;
+        for (i, xi) in self.0.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("factor {}", i));
+            let factor = xi
+                .mul(
+                    cs.ns(|| "xi * point^(2^(d-i-1))"),
+                    &point_powers[log_d - i - 1],
+                )?
+                .add_constant(cs.ns(|| "add one"), &SimulationF::one())?;
+            product = product.mul(cs.ns(|| "update product"), &factor)?;
+        }
+
+        Ok(product)
+    }
+}
+
+/// The parts of the dlog verifier key needed by the succinct verifier.
+#[derive(Clone)]
+pub struct IPAVerifierKeyGadget<GG> {
+    /// The length of the committer key, i.e. the maximum number of coefficients of a
+    /// segment. Must be a power of two.
+    pub segment_size: usize,
+    /// The generator for the inner product value.
+    pub h: GG,
+    /// The generator for the hiding randomness.
+    pub s: GG,
+    /// The hash of the committer key, bound to the random oracle of the accumulator.
+    pub hash: Vec<u8>,
+}
+
+/// The dlog opening proof.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "GG: Clone"))]
+pub struct IPAProofGadget<P: EndoMulParameters, GG>
+where
+    P::BaseField: PrimeField,
+{
+    /// The left and right cross terms of the reduction rounds.
+    pub l_vec: Vec<GG>,
+    pub r_vec: Vec<GG>,
+    /// The committer key after the last reduction round.
+    pub final_comm_key: GG,
+    /// The coefficient after the last reduction round.
+    pub c: ScalarGadget<P, P::BaseField>,
+    /// The commitment to the hiding polynomial and the combined randomness, for
+    /// hiding commitments.
+    pub hiding_comm: Option<GG>,
+    pub rand: Option<ScalarGadget<P, P::BaseField>>,
+}
+
+/// The multi-point opening proof: the commitment of h(X) and the dlog opening proof of L(X)
+/// at z.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "GG: Clone"))]
+pub struct MultiPointProofGadget<P: EndoMulParameters, GG>
+where
+    P::BaseField: PrimeField,
+{
+    /// The (segmented) commitment of h(X).
+    pub h_comm: Vec<GG>,
+    /// The dlog opening proof of L(X) at z.
+    pub proof: IPAProofGadget<P, GG>,
+}
+
+pub struct InnerProductArgPCGadget<ConstraintF, P, GG>
+where
+    ConstraintF: PrimeField,
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    GG: EndoMulCurveGadget<GroupProjective<P>, ConstraintF>
+        + GroupGadget<GroupProjective<P>, ConstraintF, Value = GroupProjective<P>>
+        + ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+{
+    _field: PhantomData<ConstraintF>,
+    _parameters: PhantomData<P>,
+    _group_gadget: PhantomData<GG>,
+}
+
+impl<ConstraintF, P, GG> InnerProductArgPCGadget<ConstraintF, P, GG>
+where
+    ConstraintF: PrimeField,
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    GG: EndoMulCurveGadget<GroupProjective<P>, ConstraintF>
+        + GroupGadget<GroupProjective<P>, ConstraintF, Value = GroupProjective<P>>
+        + ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+{
+    fn mul_by_scalar<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        base: &GG,
+        scalar: &ScalarGadget<P, ConstraintF>,
+    ) -> Result<GG, SynthesisError> {
+        let mut bits = scalar.to_bits_strict(cs.ns(|| "scalar to bits"))?;
+        bits.reverse();
+        base.mul_bits(cs.ns(|| "base * scalar"), bits.iter())
+    }
+
+    /// Enforce the succinct verification of `proof`, opening the (segmented) `commitments`
+    /// at `point` to `values`, batched with a 128 bit challenge lambda. Returns the succinct
+    /// check polynomial, which together with `proof.final_comm_key` is the dlog item to be
+    /// accumulated.
+    pub fn succinct_check<CS, S, SG>(
+        mut cs: CS,
+        vk: &IPAVerifierKeyGadget<GG>,
+        commitments: &[Vec<GG>],
+        point: &ScalarGadget<P, ConstraintF>,
+        values: &[ScalarGadget<P, ConstraintF>],
+        proof: &IPAProofGadget<P, GG>,
+        fs_rng: &mut FiatShamirRngGadget<ConstraintF, S, SG>,
+    ) -> Result<SuccinctCheckPolynomialGadget<P::ScalarField, ConstraintF>, SynthesisError>
+    where
+        CS: ConstraintSystemAbstract<ConstraintF>,
+        S: FieldBasedSponge<Data = ConstraintF>,
+        SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+    {
+        if !vk.segment_size.is_power_of_two() {
+            return Err(SynthesisError::Other(format!(
+                "Segment size {} is not a power of two",
+                vk.segment_size
+            )));
+        }
+        let log_d = vk.segment_size.trailing_zeros() as usize;
+
+        if commitments.is_empty()
+            || commitments.len() != values.len()
+            || commitments.iter().any(|segments| segments.is_empty())
+        {
+            return Err(SynthesisError::Other(
+                "Invalid commitments or values".to_owned(),
+            ));
+        }
+
+        if proof.l_vec.len() != log_d || proof.r_vec.len() != log_d {
+            return Err(SynthesisError::Other(format!(
+                "Expected {} reduction rounds, found {} left and {} right cross terms",
+                log_d,
+                proof.l_vec.len(),
+                proof.r_vec.len()
+            )));
+        }
+
+        if proof.hiding_comm.is_some() != proof.rand.is_some() {
+            return Err(SynthesisError::Other(
+                "Hiding commitment and randomness must be both present or absent".to_owned(),
+            ));
+        }
+
+        // Collapse the segments of each commitment, i.e.
+        //      C = sum_j point^{j * segment_size} * C_j,
+        // to be opened at the same values.
+        let segmented = commitments.iter().any(|segments| segments.len() > 1);
+        let point_to_segment_size_bits = if segmented {
+            let mut power = point.clone();
+            for j in 0..log_d {
+                power = power.square(cs.ns(|| format!("point^(2^{})", j + 1)))?;
+            }
+            let mut bits = power.to_bits_strict(cs.ns(|| "point^segment_size to bits"))?;
+            bits.reverse();
+            bits
+        } else {
+            vec![]
+        };
+        let mut collapsed_commitments = Vec::with_capacity(commitments.len());
+        for (i, segments) in commitments.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("collapse segments of commitment {}", i));
+            let mut collapsed = segments.last().unwrap().clone();
+            for (j, segment) in segments.iter().enumerate().rev().skip(1) {
+                collapsed = collapsed
+                    .mul_bits(
+                        cs.ns(|| format!("acc * point^segment_size {}", j)),
+                        point_to_segment_size_bits.iter(),
+                    )?
+                    .add(cs.ns(|| format!("add segment {}", j)), segment)?;
+            }
+            collapsed_commitments.push(collapsed);
+        }
+
+        // Batch commitments and values with lambda, in Horner form
+        //      C = C_0 + lambda * (C_1 + lambda * (... + lambda * C_{n-1})),
+        //      v = v_0 + lambda * (v_1 + lambda * (... + lambda * v_{n-1})).
+        let lambda_bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze lambda"))?;
+        let lambda = enforce_endo_rep_to_scalar::<P, _, _>(
+            cs.ns(|| "lambda to scalar"),
+            lambda_bits.as_slice(),
+        )?;
+        let mut combined_commitment = collapsed_commitments.last().unwrap().clone();
+        let mut combined_v = values.last().unwrap().clone();
+        for i in (0..values.len() - 1).rev() {
+            let mut cs = cs.ns(|| format!("batch opening {}", i));
+            combined_commitment = combined_commitment
+                .endo_mul(cs.ns(|| "acc commitment * lambda"), lambda_bits.as_slice())?
+                .add(cs.ns(|| "add commitment"), &collapsed_commitments[i])?;
+            combined_v = combined_v
+                .mul(cs.ns(|| "acc value * lambda"), &lambda)?
+                .add(cs.ns(|| "add value"), &values[i])?;
+        }
+
+        fs_rng.enforce_absorb(
+            cs.ns(|| "absorb combined commitment"),
+            &[combined_commitment.clone()],
+        )?;
+        fs_rng.enforce_absorb_nonnative(
+            cs.ns(|| "absorb point and combined value"),
+            &[point.clone(), combined_v.clone()],
+        )?;
+
+        // Remove the hiding randomness, i.e.
+        //      C = C + hiding_challenge * hiding_comm - rand * S
+        if let (Some(hiding_comm), Some(rand)) = (proof.hiding_comm.as_ref(), proof.rand.as_ref()) {
+            let mut cs = cs.ns(|| "remove hiding");
+            fs_rng.enforce_absorb(
+                cs.ns(|| "absorb hiding comm"),
+                std::slice::from_ref(hiding_comm),
+            )?;
+            let hiding_challenge_bits =
+                fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze hiding challenge"))?;
+            fs_rng.enforce_absorb_nonnative(cs.ns(|| "absorb rand"), std::slice::from_ref(rand))?;
+
+            let hiding_term = hiding_comm.endo_mul(
+                cs.ns(|| "hiding_comm * hiding_challenge"),
+                hiding_challenge_bits.as_slice(),
+            )?;
+            let rand_term = Self::mul_by_scalar(cs.ns(|| "S * rand"), &vk.s, rand)?;
+            combined_commitment = combined_commitment
+                .add(cs.ns(|| "add hiding term"), &hiding_term)?
+                .sub(cs.ns(|| "sub rand term"), &rand_term)?;
+        }
+
+        // The generator for the inner product value, h' = xi_0 * H, and the
+        // commitment of the first round C' = C + v * h'.
+        let xi_0_bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze xi_0"))?;
+        let h_prime =
+            vk.h.endo_mul(cs.ns(|| "h' = H * xi_0"), xi_0_bits.as_slice())?;
+        let v_times_h_prime = Self::mul_by_scalar(cs.ns(|| "v * h'"), &h_prime, &combined_v)?;
+        let mut round_commitment =
+            combined_commitment.add(cs.ns(|| "C' = C + v * h'"), &v_times_h_prime)?;
+
+        // The reduction rounds: C' = C' + xi^-1 * L + xi * R. The sponge state already binds
+        // the previous challenge, hence only L and R are absorbed.
+        let mut xi_s = Vec::with_capacity(log_d);
+        for (i, (l, r)) in proof.l_vec.iter().zip(proof.r_vec.iter()).enumerate() {
+            let mut cs = cs.ns(|| format!("round {}", i));
+            fs_rng.enforce_absorb(cs.ns(|| "absorb L and R"), &[l.clone(), r.clone()])?;
+            let xi_bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze xi"))?;
+
+            // Witness L * xi^-1, and check it against L via endo mul
+            let l_div_xi = GG::alloc(cs.ns(|| "alloc L * xi^-1"), || {
+                let bits = xi_bits
+                    .iter()
+                    .map(|b| b.get_value().get())
+                    .collect::<Result<Vec<_>, _>>()?;
+                let xi = GroupAffine::<P>::endo_rep_to_scalar(bits)
+                    .map_err(|e| SynthesisError::Other(e.to_string()))?;
+                Ok(l.get_value().get()?.mul(&xi.inverse().get()?))
+            })?;
+            l_div_xi
+                .endo_mul(cs.ns(|| "(L * xi^-1) * xi"), xi_bits.as_slice())?
+                .enforce_equal(cs.ns(|| "(L * xi^-1) * xi == L"), l)?;
+
+            let r_times_xi = r.endo_mul(cs.ns(|| "xi * R"), xi_bits.as_slice())?;
+            round_commitment = round_commitment
+                .add(cs.ns(|| "add L * xi^-1"), &l_div_xi)?
+                .add(cs.ns(|| "add xi * R"), &r_times_xi)?;
+
+            xi_s.push(enforce_endo_rep_to_scalar::<P, _, _>(
+                cs.ns(|| "xi to scalar"),
+                xi_bits.as_slice(),
+            )?);
+        }
+
+        // The final check C' == c * G_f + c * h(point) * h'
+        let check_poly = SuccinctCheckPolynomialGadget(xi_s);
+        let v_prime = check_poly
+            .evaluate(cs.ns(|| "evaluate check poly at point"), point)?
+            .mul(cs.ns(|| "v' = h(point) * c"), &proof.c)?;
+        let v_prime_times_h_prime = Self::mul_by_scalar(cs.ns(|| "v' * h'"), &h_prime, &v_prime)?;
+        let expected = Self::mul_by_scalar(cs.ns(|| "c * G_f"), &proof.final_comm_key, &proof.c)?
+            .add(cs.ns(|| "c * G_f + v' * h'"), &v_prime_times_h_prime)?;
+        round_commitment.enforce_equal(cs.ns(|| "final check"), &expected)?;
+
+        Ok(check_poly)
+    }
+
+    /// Enforce the succinct verification of the multi-point opening `proof` of `queries`,
+    /// each of them consisting of a point and the (segmented) commitments to be opened at
+    /// it together with their values. Returns the succinct check polynomial of the dlog
+    /// opening proof of L(X).
+    pub fn multi_point_succinct_check<CS, S, SG>(
+        mut cs: CS,
+        vk: &IPAVerifierKeyGadget<GG>,
+        queries: &[(
+            ScalarGadget<P, ConstraintF>,
+            Vec<(&[GG], ScalarGadget<P, ConstraintF>)>,
+        )],
+        proof: &MultiPointProofGadget<P, GG>,
+        fs_rng: &mut FiatShamirRngGadget<ConstraintF, S, SG>,
+    ) -> Result<SuccinctCheckPolynomialGadget<P::ScalarField, ConstraintF>, SynthesisError>
+    where
+        CS: ConstraintSystemAbstract<ConstraintF>,
+        S: FieldBasedSponge<Data = ConstraintF>,
+        SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+    {
+        if queries.is_empty()
+            || queries.iter().any(|(_, openings)| {
+                openings.is_empty() || openings.iter().any(|(segments, _)| segments.is_empty())
+            })
+            || proof.h_comm.is_empty()
+        {
+            return Err(SynthesisError::Other("Invalid queries or proof".to_owned()));
+        }
+
+        let values = queries
+            .iter()
+            .flat_map(|(_, openings)| openings.iter().map(|(_, value)| value.clone()))
+            .collect::<Vec<_>>();
+        fs_rng.enforce_absorb_nonnative(cs.ns(|| "absorb values"), values.as_slice())?;
+
+        // Batch the commitments and the values of each point with lambda, segment-wise and
+        // in Horner form. Segments missing in the last commitments are skipped instead of
+        // being added as zero.
+        let lambda_bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze lambda"))?;
+        let lambda = enforce_endo_rep_to_scalar::<P, _, _>(
+            cs.ns(|| "lambda to scalar"),
+            lambda_bits.as_slice(),
+        )?;
+        let mut combined_queries = Vec::with_capacity(queries.len());
+        for (i, (point, openings)) in queries.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("batch query {}", i));
+            let num_segments = openings
+                .iter()
+                .map(|(segments, _)| segments.len())
+                .max()
+                .unwrap();
+            let mut combined_segments: Vec<Option<GG>> = vec![None; num_segments];
+            let mut combined_value: Option<ScalarGadget<P, ConstraintF>> = None;
+            for (j, (segments, value)) in openings.iter().enumerate().rev() {
+                for (k, combined) in combined_segments.iter_mut().enumerate() {
+                    *combined = match (combined.take(), segments.get(k)) {
+                        (Some(acc), segment) => {
+                            let acc = acc.endo_mul(
+                                cs.ns(|| format!("acc segment {} * lambda {}", k, j)),
+                                lambda_bits.as_slice(),
+                            )?;
+                            match segment {
+                                Some(segment) => Some(acc.add(
+                                    cs.ns(|| format!("add segment {} of opening {}", k, j)),
+                                    segment,
+                                )?),
+                                None => Some(acc),
+                            }
+                        }
+                        (None, segment) => segment.cloned(),
+                    };
+                }
+                combined_value = Some(match combined_value {
+                    Some(acc) => acc
+                        .mul(cs.ns(|| format!("acc value * lambda {}", j)), &lambda)?
+                        .add(cs.ns(|| format!("add value {}", j)), value)?,
+                    None => value.clone(),
+                });
+            }
+            combined_queries.push((
+                point,
+                combined_segments
+                    .into_iter()
+                    .map(|segment| segment.unwrap())
+                    .collect::<Vec<_>>(),
+                combined_value.unwrap(),
+            ));
+        }
+
+        fs_rng.enforce_absorb(cs.ns(|| "absorb h_comm"), proof.h_comm.as_slice())?;
+        let z_bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze z"))?;
+        let z = enforce_endo_rep_to_scalar::<P, _, _>(cs.ns(|| "z to scalar"), z_bits.as_slice())?;
+
+        // The commitment of L(X) = sum_j P_j(X) / (z - x_j) - h(X), segment-wise, and its
+        // value at z
+        let num_segments = combined_queries
+            .iter()
+            .map(|(_, segments, _)| segments.len())
+            .chain(std::iter::once(proof.h_comm.len()))
+            .max()
+            .unwrap();
+        let mut l_comm: Vec<Option<GG>> = vec![None; num_segments];
+        let mut l_value: Option<ScalarGadget<P, ConstraintF>> = None;
+        for (i, (point, segments, value)) in combined_queries.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("add query {} to L", i));
+            let coeff = z
+                .sub(cs.ns(|| "z - point"), point)?
+                .inverse(cs.ns(|| "1 / (z - point)"))?;
+            let mut coeff_bits = coeff.to_bits_strict(cs.ns(|| "coeff to bits"))?;
+            coeff_bits.reverse();
+            for (k, (l, segment)) in l_comm.iter_mut().zip(segments.iter()).enumerate() {
+                let term = segment.mul_bits(
+                    cs.ns(|| format!("segment {} * coeff", k)),
+                    coeff_bits.iter(),
+                )?;
+                *l = Some(match l.take() {
+                    Some(acc) => acc.add(cs.ns(|| format!("add term {}", k)), &term)?,
+                    None => term,
+                });
+            }
+            let term = coeff.mul(cs.ns(|| "value * coeff"), value)?;
+            l_value = Some(match l_value {
+                Some(acc) => acc.add(cs.ns(|| "add value term"), &term)?,
+                None => term,
+            });
+        }
+        let mut l_segments = Vec::with_capacity(num_segments);
+        for (k, l) in l_comm.into_iter().enumerate() {
+            l_segments.push(match (l, proof.h_comm.get(k)) {
+                (Some(l), Some(h)) => l.sub(cs.ns(|| format!("L segment {} - h", k)), h)?,
+                (Some(l), None) => l,
+                (None, Some(h)) => h.negate(cs.ns(|| format!("L segment {} = -h", k)))?,
+                (None, None) => unreachable!(),
+            });
+        }
+
+        Self::succinct_check(
+            cs.ns(|| "succinct check of L"),
+            vk,
+            &[l_segments],
+            &z,
+            &[l_value.unwrap()],
+            &proof.proof,
+            fs_rng,
+        )
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+pub(crate) mod test {
+    use super::*;
+    use crate::TweedleFqPoseidonSpongeGadget;
+    use algebra::{
+        curves::tweedle::dee::{Affine, TweedledeeParameters},
+        fields::tweedle::{Fq, Fr},
+        AffineCurve, UniformRand,
+    };
+    use blake2::Blake2s;
+    use poly_commit::{
+        ipa_pc::{CommitterKey, InnerProductArgPC, Proof, VerifierKey},
+        PolynomialCommitment,
+    };
+    use primitives::TweedleFqPoseidonHash;
+    use proof_systems::darlin::{
+        algebraic_ipa_pc::{AlgebraicInnerProductArgPC, MultiPointProof},
+        fiat_shamir::FieldBasedFiatShamirRng,
+    };
+    use r1cs_core::{ConstraintSystem, ConstraintSystemDebugger, SynthesisMode};
+    use r1cs_std::instantiated::tweedle::TweedleDeeGadget;
+    use rand::{thread_rng, Rng, RngCore};
+
+    pub(crate) const SEGMENT_SIZE: usize = 8;
+
+    type IPA = AlgebraicInnerProductArgPC<Affine, TweedleFqPoseidonHash>;
+    type FS = FieldBasedFiatShamirRng<TweedleFqPoseidonHash>;
+    pub(crate) type FSGadget =
+        FiatShamirRngGadget<Fq, TweedleFqPoseidonHash, TweedleFqPoseidonSpongeGadget>;
+    type IPAGadget = InnerProductArgPCGadget<Fq, TweedledeeParameters, TweedleDeeGadget>;
+
+    /// The dlog keys for polynomials of `SEGMENT_SIZE` coefficients.
+    fn get_keys() -> (CommitterKey<Affine>, VerifierKey<Affine>) {
+        let params = InnerProductArgPC::<Affine, Blake2s>::setup(SEGMENT_SIZE - 1).unwrap();
+        InnerProductArgPC::<Affine, Blake2s>::trim(&params, SEGMENT_SIZE - 1).unwrap()
+    }
+
+    pub(crate) fn alloc_vk(
+        cs: &mut ConstraintSystem<Fq>,
+        vk: &VerifierKey<Affine>,
+    ) -> IPAVerifierKeyGadget<TweedleDeeGadget> {
+        IPAVerifierKeyGadget {
+            segment_size: vk.comm_key.len(),
+            h: TweedleDeeGadget::from_value(cs.ns(|| "hardcode h"), &vk.h.into_projective()),
+            s: TweedleDeeGadget::from_value(cs.ns(|| "hardcode s"), &vk.s.into_projective()),
+            hash: vk.hash.clone(),
+        }
+    }
+
+    pub(crate) fn alloc_point(
+        cs: &mut ConstraintSystem<Fq>,
+        name: &str,
+        point: &Affine,
+    ) -> TweedleDeeGadget {
+        TweedleDeeGadget::alloc(cs.ns(|| format!("alloc {}", name)), || {
+            Ok(point.into_projective())
+        })
+        .unwrap()
+    }
+
+    pub(crate) fn alloc_scalar(
+        cs: &mut ConstraintSystem<Fq>,
+        name: &str,
+        scalar: &Fr,
+    ) -> ScalarGadget<TweedledeeParameters, Fq> {
+        ScalarGadget::<TweedledeeParameters, Fq>::alloc(cs.ns(|| format!("alloc {}", name)), || {
+            Ok(scalar)
+        })
+        .unwrap()
+    }
+
+    pub(crate) fn alloc_proof(
+        cs: &mut ConstraintSystem<Fq>,
+        proof: &Proof<Affine>,
+    ) -> IPAProofGadget<TweedledeeParameters, TweedleDeeGadget> {
+        let alloc_points = |cs: &mut ConstraintSystem<Fq>, name: &str, points: &[Affine]| {
+            points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| alloc_point(cs, &format!("{} {}", name, i), p))
+                .collect::<Vec<_>>()
+        };
+        IPAProofGadget {
+            l_vec: alloc_points(cs, "L", &proof.l_vec),
+            r_vec: alloc_points(cs, "R", &proof.r_vec),
+            final_comm_key: alloc_point(cs, "G_f", &proof.final_comm_key),
+            c: alloc_scalar(cs, "c", &proof.c),
+            hiding_comm: proof
+                .hiding_comm
+                .as_ref()
+                .map(|hiding_comm| alloc_point(cs, "hiding comm", hiding_comm)),
+            rand: proof
+                .rand
+                .as_ref()
+                .map(|rand| alloc_scalar(cs, "rand", rand)),
+        }
+    }
+
+    fn evaluate(coeffs: &[Fr], point: Fr) -> Fr {
+        coeffs
+            .iter()
+            .rev()
+            .fold(Fr::zero(), |acc, coeff| acc * &point + coeff)
+    }
+
+    fn succinct_check(
+        vk: &VerifierKey<Affine>,
+        commitments: &[Vec<Affine>],
+        point: Fr,
+        values: &[Fr],
+        proof: &Proof<Affine>,
+    ) -> (bool, Option<SuccinctCheckPolynomialGadget<Fr, Fq>>) {
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+
+        let vk = alloc_vk(&mut cs, vk);
+        let commitments = commitments
+            .iter()
+            .enumerate()
+            .map(|(i, segments)| {
+                segments
+                    .iter()
+                    .enumerate()
+                    .map(|(j, segment)| {
+                        alloc_point(&mut cs, &format!("commitment {} segment {}", i, j), segment)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let point = alloc_scalar(&mut cs, "point", &point);
+        let values = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| alloc_scalar(&mut cs, &format!("value {}", i), value))
+            .collect::<Vec<_>>();
+        let proof = alloc_proof(&mut cs, proof);
+
+        let mut fs_rng = FSGadget::init(cs.ns(|| "init fs rng"), None).unwrap();
+        let check_poly = IPAGadget::succinct_check(
+            cs.ns(|| "succinct check"),
+            &vk,
+            commitments.as_slice(),
+            &point,
+            values.as_slice(),
+            &proof,
+            &mut fs_rng,
+        )
+        .ok();
+
+        (cs.is_satisfied(), check_poly)
+    }
+
+    #[test]
+    fn endo_rep_to_scalar_gadget_test() {
+        let rng = &mut thread_rng();
+        for &len in [2usize, 7, 128].iter() {
+            let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+            let bits = (0..len).map(|_| rng.gen()).collect::<Vec<bool>>();
+            let bits_g = bits
+                .iter()
+                .enumerate()
+                .map(|(i, b)| Boolean::alloc(cs.ns(|| format!("alloc bit {}", i)), || Ok(b)))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let scalar_g = enforce_endo_rep_to_scalar::<TweedledeeParameters, _, _>(
+                cs.ns(|| "endo rep to scalar"),
+                &bits_g,
+            )
+            .unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(
+                scalar_g.get_value().unwrap(),
+                Affine::endo_rep_to_scalar(bits).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn tweedle_ipa_succinct_check_gadget_test() {
+        let rng = &mut thread_rng();
+        let (ck, vk) = get_keys();
+
+        for &hiding in [false, true].iter() {
+            // A polynomial with two segments and one with a single, shorter segment
+            let polys = vec![
+                (0..2 * SEGMENT_SIZE)
+                    .map(|_| Fr::rand(rng))
+                    .collect::<Vec<_>>(),
+                (0..SEGMENT_SIZE - 3)
+                    .map(|_| Fr::rand(rng))
+                    .collect::<Vec<_>>(),
+            ];
+            let mut commitments = Vec::new();
+            let mut rands = Vec::new();
+            for poly in polys.iter() {
+                let (comms, poly_rands) = IPA::commit(
+                    &ck,
+                    poly,
+                    if hiding {
+                        Some(&mut *rng as &mut dyn RngCore)
+                    } else {
+                        None
+                    },
+                )
+                .unwrap();
+                commitments.push(comms);
+                rands.push(poly_rands);
+            }
+            let point = Fr::rand(rng);
+            let values = polys
+                .iter()
+                .map(|poly| evaluate(poly, point))
+                .collect::<Vec<_>>();
+
+            // The proof of the native prover, succinctly verified both natively and in-circuit
+            let proof = IPA::open(
+                &ck,
+                &polys,
+                &rands,
+                point,
+                &mut FS::init(None),
+                if hiding {
+                    Some(&mut *rng as &mut dyn RngCore)
+                } else {
+                    None
+                },
+            )
+            .unwrap();
+            let xi_s = IPA::succinct_check(
+                &vk,
+                &commitments,
+                point,
+                &values,
+                &proof,
+                &mut FS::init(None),
+            )
+            .unwrap()
+            .unwrap();
+
+            let (satisfied, check_poly) = succinct_check(&vk, &commitments, point, &values, &proof);
+            assert!(satisfied);
+            let check_poly = check_poly.unwrap();
+            assert_eq!(check_poly.get_value().unwrap(), xi_s.0);
+
+            // Wrong values
+            let mut wrong_values = values.clone();
+            wrong_values[1] += &Fr::one();
+            assert!(!succinct_check(&vk, &commitments, point, &wrong_values, &proof).0);
+
+            // Wrong final coefficient
+            let wrong_proof = Proof {
+                c: Fr::rand(rng),
+                ..proof
+            };
+            assert!(!succinct_check(&vk, &commitments, point, &values, &wrong_proof).0);
+        }
+    }
+
+    pub(crate) fn alloc_multi_point_proof(
+        cs: &mut ConstraintSystem<Fq>,
+        proof: &MultiPointProof<Affine>,
+    ) -> MultiPointProofGadget<TweedledeeParameters, TweedleDeeGadget> {
+        MultiPointProofGadget {
+            h_comm: proof
+                .h_comm
+                .iter()
+                .enumerate()
+                .map(|(i, h)| alloc_point(cs, &format!("h_comm {}", i), h))
+                .collect(),
+            proof: alloc_proof(cs, &proof.proof),
+        }
+    }
+
+    #[test]
+    fn tweedle_ipa_multi_point_succinct_check_gadget_test() {
+        let rng = &mut thread_rng();
+        let (ck, vk) = get_keys();
+
+        // Two points, the first with polynomials of different numbers of segments
+        let queries = vec![
+            (
+                Fr::rand(rng),
+                vec![
+                    (0..3 * SEGMENT_SIZE)
+                        .map(|_| Fr::rand(rng))
+                        .collect::<Vec<_>>(),
+                    (0..SEGMENT_SIZE - 1)
+                        .map(|_| Fr::rand(rng))
+                        .collect::<Vec<_>>(),
+                ],
+            ),
+            (
+                Fr::rand(rng),
+                vec![(0..2 * SEGMENT_SIZE)
+                    .map(|_| Fr::rand(rng))
+                    .collect::<Vec<_>>()],
+            ),
+        ];
+        let commitments = queries
+            .iter()
+            .map(|(_, polys)| {
+                polys
+                    .iter()
+                    .map(|poly| IPA::commit(&ck, poly, None).unwrap().0)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let values = queries
+            .iter()
+            .map(|(point, polys)| {
+                polys
+                    .iter()
+                    .map(|poly| evaluate(poly, *point))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let proof = IPA::multi_point_open(
+            &ck,
+            &queries
+                .iter()
+                .map(|(point, polys)| (*point, polys.iter().map(|p| p.as_slice()).collect()))
+                .collect::<Vec<_>>(),
+            &mut FS::init(None),
+        )
+        .unwrap();
+
+        let multi_point_succinct_check = |values: &[Vec<Fr>]| {
+            let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+            let vk_g = alloc_vk(&mut cs, &vk);
+            let commitments_g = commitments
+                .iter()
+                .enumerate()
+                .map(|(i, comms)| {
+                    comms
+                        .iter()
+                        .enumerate()
+                        .map(|(j, segments)| {
+                            segments
+                                .iter()
+                                .enumerate()
+                                .map(|(k, segment)| {
+                                    alloc_point(
+                                        &mut cs,
+                                        &format!("commitment {} {} segment {}", i, j, k),
+                                        segment,
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            let mut queries_g = Vec::new();
+            for (i, ((point, _), values)) in queries.iter().zip(values.iter()).enumerate() {
+                let point = alloc_scalar(&mut cs, &format!("point {}", i), point);
+                let values = values
+                    .iter()
+                    .enumerate()
+                    .map(|(j, value)| alloc_scalar(&mut cs, &format!("value {} {}", i, j), value))
+                    .collect::<Vec<_>>();
+                queries_g.push((point, values));
+            }
+            let queries_g = queries_g
+                .into_iter()
+                .zip(commitments_g.iter())
+                .map(|((point, values), comms)| {
+                    (
+                        point,
+                        comms
+                            .iter()
+                            .map(|c| c.as_slice())
+                            .zip(values.into_iter())
+                            .collect::<Vec<_>>(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let proof_g = alloc_multi_point_proof(&mut cs, &proof);
+
+            let mut fs_rng = FSGadget::init(cs.ns(|| "init fs rng"), None).unwrap();
+            let check_poly = IPAGadget::multi_point_succinct_check(
+                cs.ns(|| "multi-point succinct check"),
+                &vk_g,
+                queries_g.as_slice(),
+                &proof_g,
+                &mut fs_rng,
+            )
+            .ok();
+            (cs.is_satisfied(), check_poly)
+        };
+
+        // The proof of the native prover, succinctly verified both natively and in-circuit
+        let verifier_queries = queries
+            .iter()
+            .zip(commitments.iter())
+            .zip(values.iter())
+            .map(|(((point, _), comms), values)| {
+                (
+                    *point,
+                    comms
+                        .iter()
+                        .map(|c| c.as_slice())
+                        .zip(values.iter().cloned())
+                        .collect(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let xi_s =
+            IPA::multi_point_succinct_check(&vk, &verifier_queries, &proof, &mut FS::init(None))
+                .unwrap()
+                .unwrap();
+
+        let (satisfied, check_poly) = multi_point_succinct_check(&values);
+        assert!(satisfied);
+        assert_eq!(check_poly.unwrap().get_value().unwrap(), xi_s.0);
+
+        // Wrong value
+        let mut wrong_values = values.clone();
+        wrong_values[0][1] += &Fr::one();
+        assert!(!multi_point_succinct_check(&wrong_values).0);
+    }
+}
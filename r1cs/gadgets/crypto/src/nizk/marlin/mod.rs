@@ -0,0 +1,565 @@
+//! The gadget of the succinct verifier of `AlgebraicMarlin` of
+//! `proof_systems::darlin::algebraic_marlin`, i.e. of coboundary Marlin over the dlog
+//! commitment scheme of `proof_systems::darlin::algebraic_ipa_pc`, as needed by the merge
+//! nodes of a Darlin proof tree. The verifier key of the circuit is hardcoded, while the
+//! dlog verifier key, the public inputs and the proof are gadgets.
+//!
+//! As for `InnerProductArgPCGadget`, the circuit is defined over the base field of the
+//! curve, hence the evaluations and public inputs are simulated by `NonNativeFieldGadget`s,
+//! and Fiat-Shamir is performed via `FiatShamirRngGadget`, squeezing the challenges as
+//! endomorphism representations. The succinct verifier returns the dlog item of the
+//! multi-point opening proof, whose hard part is left to the dlog accumulator.
+use crate::{
+    crh::FieldBasedSpongeGadget,
+    nizk::{
+        accumulators::dlog::DLogItemGadget,
+        fiat_shamir::FiatShamirRngGadget,
+        ipa_pc::{
+            enforce_endo_rep_to_scalar, IPAVerifierKeyGadget, InnerProductArgPCGadget,
+            MultiPointProofGadget,
+        },
+    },
+};
+use algebra::{
+    curves::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    AffineCurve, EndoMulParameters, EvaluationDomain, Field, PrimeField, ToConstraintField,
+};
+use primitives::crh::FieldBasedSponge;
+use proof_systems::darlin::algebraic_marlin::{
+    AlgebraicMarlin, IndexVerifierKey, NUM_EVALUATIONS, NUM_INDEX_POLYS, NUM_PROVER_POLYS,
+};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::{fp::FpGadget, nonnative::nonnative_field_gadget::NonNativeFieldGadget},
+    groups::EndoMulCurveGadget,
+    prelude::*,
+    to_field_gadget_vec::ToConstraintFieldGadget,
+};
+use std::marker::PhantomData;
+
+type ScalarGadget<P, ConstraintF> =
+    NonNativeFieldGadget<<P as algebra::ModelParameters>::ScalarField, ConstraintF>;
+
+/// The gadget of a proof of `AlgebraicMarlin`.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "GG: Clone"))]
+pub struct ProofGadget<P: EndoMulParameters, GG>
+where
+    P::BaseField: PrimeField,
+{
+    /// The (segmented) commitments of w, z_A, z_B, t, g_1, h_1, g_2, h_2.
+    pub commitments: Vec<Vec<GG>>,
+    /// The evaluations, in the order of `NUM_EVALUATIONS`.
+    pub evaluations: Vec<ScalarGadget<P, P::BaseField>>,
+    /// The multi-point opening proof of the evaluations.
+    pub pc_proof: MultiPointProofGadget<P, GG>,
+}
+
+pub struct MarlinVerifierGadget<ConstraintF, P, GG, S, SG>
+where
+    ConstraintF: PrimeField,
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    GG: EndoMulCurveGadget<GroupProjective<P>, ConstraintF>
+        + GroupGadget<GroupProjective<P>, ConstraintF, Value = GroupProjective<P>>
+        + ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+    S: FieldBasedSponge<Data = ConstraintF>,
+    SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+{
+    _field: PhantomData<ConstraintF>,
+    _parameters: PhantomData<P>,
+    _group_gadget: PhantomData<GG>,
+    _sponge: PhantomData<S>,
+    _sponge_gadget: PhantomData<SG>,
+}
+
+impl<ConstraintF, P, GG, S, SG> MarlinVerifierGadget<ConstraintF, P, GG, S, SG>
+where
+    ConstraintF: PrimeField,
+    P: EndoMulParameters<BaseField = ConstraintF>,
+    GroupAffine<P>: ToConstraintField<ConstraintF>,
+    GG: EndoMulCurveGadget<GroupProjective<P>, ConstraintF>
+        + GroupGadget<GroupProjective<P>, ConstraintF, Value = GroupProjective<P>>
+        + ToConstraintFieldGadget<ConstraintF, FieldGadget = FpGadget<ConstraintF>>,
+    S: FieldBasedSponge<Data = ConstraintF>,
+    SG: FieldBasedSpongeGadget<S, ConstraintF, DataGadget = FpGadget<ConstraintF>>,
+{
+    /// Enforce the squeezing of a challenge, returning the scalar of its endomorphism
+    /// representation.
+    fn squeeze_challenge<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        fs_rng: &mut FiatShamirRngGadget<ConstraintF, S, SG>,
+    ) -> Result<ScalarGadget<P, ConstraintF>, SynthesisError> {
+        let bits = fs_rng.enforce_squeeze_128_bits_challenge(cs.ns(|| "squeeze bits"))?;
+        enforce_endo_rep_to_scalar::<P, _, _>(cs.ns(|| "bits to scalar"), bits.as_slice())
+    }
+
+    /// Enforce the evaluation of the vanishing polynomial X^size - 1 of a domain of
+    /// `size` elements, a power of two, at `point`.
+    fn evaluate_vanishing_polynomial<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        size: usize,
+        point: &ScalarGadget<P, ConstraintF>,
+    ) -> Result<ScalarGadget<P, ConstraintF>, SynthesisError> {
+        let mut power = point.clone();
+        for j in 0..size.trailing_zeros() {
+            power = power.square(cs.ns(|| format!("point^(2^{})", j + 1)))?;
+        }
+        power.add_constant(cs.ns(|| "point^size - 1"), &-P::ScalarField::one())
+    }
+
+    /// Enforce the succinct verification of `proof` for `public_input`, given the verifier
+    /// key `index_vk` of the circuit. Returns the dlog item to be accumulated.
+    pub fn succinct_verify<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        dlog_vk: &IPAVerifierKeyGadget<GG>,
+        index_vk: &IndexVerifierKey<GroupAffine<P>>,
+        public_input: &[ScalarGadget<P, ConstraintF>],
+        proof: &ProofGadget<P, GG>,
+    ) -> Result<DLogItemGadget<P, GG>, SynthesisError> {
+        let info = index_vk.index_info;
+        if public_input.len() + 1 != info.num_inputs {
+            return Err(SynthesisError::Other(format!(
+                "Expected {} public inputs, found {}",
+                info.num_inputs - 1,
+                public_input.len()
+            )));
+        }
+        if index_vk.index_comms.len() != NUM_INDEX_POLYS
+            || index_vk.index_comms.iter().any(|comm| comm.is_empty())
+            || proof.commitments.len() != NUM_PROVER_POLYS
+            || proof.commitments.iter().any(|comm| comm.is_empty())
+            || proof.evaluations.len() != NUM_EVALUATIONS
+        {
+            return Err(SynthesisError::Other(
+                "Invalid verifier key or proof".to_owned(),
+            ));
+        }
+        let [domain_x, domain_h, domain_k] = info
+            .domains::<P::ScalarField>()
+            .map_err(|e| SynthesisError::Other(e.to_string()))?;
+
+        // Initialize the random oracle, personalized by the dlog and the circuit verifier
+        // keys, which are constants of the circuit.
+        let personalization =
+            AlgebraicMarlin::<GroupAffine<P>, S>::personalization(&dlog_vk.hash, index_vk)
+                .map_err(|e| SynthesisError::Other(e.to_string()))?;
+        let mut fs_rng = FiatShamirRngGadget::<ConstraintF, S, SG>::init(
+            cs.ns(|| "init fs rng"),
+            Some(personalization.as_slice()),
+        )?;
+        fs_rng.enforce_absorb_nonnative(cs.ns(|| "absorb public input"), public_input)?;
+
+        fs_rng.enforce_absorb(
+            cs.ns(|| "absorb first round commitments"),
+            proof.commitments[..3].concat().as_slice(),
+        )?;
+        let mut etas = Vec::with_capacity(3);
+        for i in 0..3 {
+            etas.push(Self::squeeze_challenge(
+                cs.ns(|| format!("squeeze eta {}", i)),
+                &mut fs_rng,
+            )?);
+        }
+        let alpha = Self::squeeze_challenge(cs.ns(|| "squeeze alpha"), &mut fs_rng)?;
+        fs_rng.enforce_absorb(
+            cs.ns(|| "absorb second round commitments"),
+            proof.commitments[3..6].concat().as_slice(),
+        )?;
+        let beta = Self::squeeze_challenge(cs.ns(|| "squeeze beta"), &mut fs_rng)?;
+        fs_rng.enforce_absorb(
+            cs.ns(|| "absorb third round commitments"),
+            proof.commitments[6..].concat().as_slice(),
+        )?;
+        let gamma = Self::squeeze_challenge(cs.ns(|| "squeeze gamma"), &mut fs_rng)?;
+
+        let evals = &proof.evaluations;
+        let (w, z_a, z_b, t, g_1, h_1) = (
+            &evals[0], &evals[1], &evals[2], &evals[3], &evals[4], &evals[5],
+        );
+        let g_1_shifted = &evals[6];
+        let (g_2, h_2) = (&evals[7], &evals[8]);
+        let index_evals = &evals[9..21];
+        let g_2_shifted = &evals[21];
+
+        // The outer sumcheck
+        let v_h_alpha =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_H(alpha)"), domain_h.size(), &alpha)?;
+        let v_h_beta =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_H(beta)"), domain_h.size(), &beta)?;
+        let v_x_beta =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_X(beta)"), domain_x.size(), &beta)?;
+
+        // x(beta) = v_X(beta) / |X| * sum_i x_i * g_i / (beta - g_i), with x_0 = 1
+        let mut x_at_beta = NonNativeFieldGadget::zero(cs.ns(|| "zero"))?;
+        for (i, g_i) in domain_x.elements().take(info.num_inputs).enumerate() {
+            let mut cs = cs.ns(|| format!("lagrange coefficient {}", i));
+            let coeff = beta
+                .add_constant(cs.ns(|| "beta - g_i"), &-g_i)?
+                .inverse(cs.ns(|| "1 / (beta - g_i)"))?
+                .mul_by_constant(
+                    cs.ns(|| "g_i / (|X| * (beta - g_i))"),
+                    &(g_i * &domain_x.size_inv()),
+                )?;
+            let term = if i == 0 {
+                coeff
+            } else {
+                coeff.mul(cs.ns(|| "x_i * coeff"), &public_input[i - 1])?
+            };
+            x_at_beta = x_at_beta.add(cs.ns(|| "add term"), &term)?;
+        }
+        let x_at_beta = x_at_beta.mul(cs.ns(|| "x(beta)"), &v_x_beta)?;
+        let z_at_beta = v_x_beta
+            .mul(cs.ns(|| "v_X(beta) * w(beta)"), w)?
+            .add(cs.ns(|| "z(beta)"), &x_at_beta)?;
+
+        // l_alpha(beta) = (beta * v_H(alpha) - alpha * v_H(beta)) / (|H| * (alpha - beta))
+        let l_alpha_at_beta = {
+            let numerator = beta.mul(cs.ns(|| "beta * v_H(alpha)"), &v_h_alpha)?.sub(
+                cs.ns(|| "beta * v_H(alpha) - alpha * v_H(beta)"),
+                &alpha.mul(cs.ns(|| "alpha * v_H(beta)"), &v_h_beta)?,
+            )?;
+            let denominator = alpha
+                .sub(cs.ns(|| "alpha - beta"), &beta)?
+                .mul_by_constant(
+                    cs.ns(|| "|H| * (alpha - beta)"),
+                    &domain_h.size_as_field_element(),
+                )?;
+            numerator.mul(
+                cs.ns(|| "l_alpha(beta)"),
+                &denominator.inverse(cs.ns(|| "1 / (|H| * (alpha - beta))"))?,
+            )?
+        };
+
+        let combined = etas[0]
+            .mul(cs.ns(|| "eta_A * z_A"), z_a)?
+            .add(
+                cs.ns(|| "add eta_B * z_B"),
+                &etas[1].mul(cs.ns(|| "eta_B * z_B"), z_b)?,
+            )?
+            .add(
+                cs.ns(|| "add eta_C * z_A * z_B"),
+                &etas[2]
+                    .mul(cs.ns(|| "eta_C * z_A"), z_a)?
+                    .mul(cs.ns(|| "eta_C * z_A * z_B"), z_b)?,
+            )?;
+        let outer_lhs = l_alpha_at_beta
+            .mul(cs.ns(|| "l_alpha(beta) * combined"), &combined)?
+            .sub(
+                cs.ns(|| "outer lhs"),
+                &t.mul(cs.ns(|| "t(beta) * z(beta)"), &z_at_beta)?,
+            )?;
+        let outer_rhs = g_1_shifted
+            .sub(cs.ns(|| "g_1(g_H * beta) - g_1(beta)"), g_1)?
+            .add(
+                cs.ns(|| "outer rhs"),
+                &h_1.mul(cs.ns(|| "h_1(beta) * v_H(beta)"), &v_h_beta)?,
+            )?;
+        outer_lhs.enforce_equal(cs.ns(|| "outer sumcheck"), &outer_rhs)?;
+
+        // The inner sumcheck, with
+        //     d_M = alpha * beta - alpha * col_M - beta * row_M + row_col_M,
+        //     b = d_A * d_B * d_C,
+        //     a = v_H(alpha) * v_H(beta) * sum_M eta_M * val_M * prod_{N != M} d_N.
+        let alpha_beta = alpha.mul(cs.ns(|| "alpha * beta"), &beta)?;
+        let mut d_evals = Vec::with_capacity(3);
+        for m in 0..3 {
+            let mut cs = cs.ns(|| format!("d_{}", m));
+            d_evals.push(
+                alpha_beta
+                    .sub(
+                        cs.ns(|| "sub alpha * col"),
+                        &alpha.mul(cs.ns(|| "alpha * col"), &index_evals[4 * m + 1])?,
+                    )?
+                    .sub(
+                        cs.ns(|| "sub beta * row"),
+                        &beta.mul(cs.ns(|| "beta * row"), &index_evals[4 * m])?,
+                    )?
+                    .add(cs.ns(|| "add row_col"), &index_evals[4 * m + 2])?,
+            );
+        }
+        let b = d_evals[0]
+            .mul(cs.ns(|| "d_A * d_B"), &d_evals[1])?
+            .mul(cs.ns(|| "b"), &d_evals[2])?;
+        let mut a = NonNativeFieldGadget::zero(cs.ns(|| "zero sum"))?;
+        for (m, eta) in etas.iter().enumerate() {
+            let mut cs = cs.ns(|| format!("a term {}", m));
+            let term = eta
+                .mul(cs.ns(|| "eta * val"), &index_evals[4 * m + 3])?
+                .mul(cs.ns(|| "times first d"), &d_evals[(m + 1) % 3])?
+                .mul(cs.ns(|| "times second d"), &d_evals[(m + 2) % 3])?;
+            a = a.add(cs.ns(|| "add term"), &term)?;
+        }
+        let a = a
+            .mul(cs.ns(|| "times v_H(alpha)"), &v_h_alpha)?
+            .mul(cs.ns(|| "a"), &v_h_beta)?;
+        let inner_lhs = g_2_shifted
+            .sub(cs.ns(|| "g_2(g_K * gamma) - g_2(gamma)"), g_2)?
+            .add(
+                cs.ns(|| "add t(beta) / |K|"),
+                &t.mul_by_constant(cs.ns(|| "t(beta) / |K|"), &domain_k.size_inv())?,
+            )?
+            .mul(cs.ns(|| "times b"), &b)?
+            .sub(cs.ns(|| "inner lhs"), &a)?;
+        let v_k_gamma =
+            Self::evaluate_vanishing_polynomial(cs.ns(|| "v_K(gamma)"), domain_k.size(), &gamma)?;
+        let inner_rhs = h_2.mul(cs.ns(|| "inner rhs"), &v_k_gamma)?;
+        inner_lhs.enforce_equal(cs.ns(|| "inner sumcheck"), &inner_rhs)?;
+
+        // The multi-point opening
+        let index_comms = index_vk
+            .index_comms
+            .iter()
+            .enumerate()
+            .map(|(i, segments)| {
+                segments
+                    .iter()
+                    .enumerate()
+                    .map(|(j, segment)| {
+                        GG::from_value(
+                            cs.ns(|| format!("hardcode index comm {} segment {}", i, j)),
+                            &segment.into_projective(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let comms = &proof.commitments;
+        let mut gamma_openings = vec![
+            (comms[6].as_slice(), g_2.clone()),
+            (comms[7].as_slice(), h_2.clone()),
+        ];
+        gamma_openings.extend(
+            index_comms
+                .iter()
+                .map(|comm| comm.as_slice())
+                .zip(index_evals.iter().cloned()),
+        );
+        let queries = [
+            (
+                beta.clone(),
+                comms[..6]
+                    .iter()
+                    .map(|comm| comm.as_slice())
+                    .zip(evals[..6].iter().cloned())
+                    .collect::<Vec<_>>(),
+            ),
+            (
+                beta.mul_by_constant(cs.ns(|| "g_H * beta"), &domain_h.group_gen())?,
+                vec![(comms[4].as_slice(), g_1_shifted.clone())],
+            ),
+            (gamma.clone(), gamma_openings),
+            (
+                gamma.mul_by_constant(cs.ns(|| "g_K * gamma"), &domain_k.group_gen())?,
+                vec![(comms[6].as_slice(), g_2_shifted.clone())],
+            ),
+        ];
+        let xi_s = InnerProductArgPCGadget::<ConstraintF, P, GG>::multi_point_succinct_check(
+            cs.ns(|| "multi-point succinct check"),
+            dlog_vk,
+            &queries,
+            &proof.pc_proof,
+            &mut fs_rng,
+        )?;
+
+        Ok(DLogItemGadget {
+            g_final: proof.pc_proof.proof.final_comm_key.clone(),
+            xi_s,
+        })
+    }
+}
+
+#[cfg(all(test, feature = "tweedle"))]
+mod test {
+    use super::*;
+    use crate::nizk::ipa_pc::test::{
+        alloc_multi_point_proof, alloc_point, alloc_scalar, alloc_vk, SEGMENT_SIZE,
+    };
+    use crate::TweedleFqPoseidonSpongeGadget;
+    use algebra::{
+        curves::tweedle::dee::{Affine, TweedledeeParameters},
+        fields::tweedle::{Fq, Fr},
+        ProjectiveCurve, UniformRand,
+    };
+    use blake2::Blake2s;
+    use poly_commit::{
+        ipa_pc::{CommitterKey, InnerProductArgPC, VerifierKey},
+        PolynomialCommitment,
+    };
+    use primitives::TweedleFqPoseidonHash;
+    use proof_systems::darlin::algebraic_marlin::Proof;
+    use r1cs_core::{
+        ConstraintSynthesizer, ConstraintSystem, ConstraintSystemDebugger, SynthesisMode,
+    };
+    use r1cs_std::instantiated::tweedle::TweedleDeeGadget;
+    use rand::thread_rng;
+
+    type Marlin = AlgebraicMarlin<Affine, TweedleFqPoseidonHash>;
+    type MarlinGadget = MarlinVerifierGadget<
+        Fq,
+        TweedledeeParameters,
+        TweedleDeeGadget,
+        TweedleFqPoseidonHash,
+        TweedleFqPoseidonSpongeGadget,
+    >;
+
+    /// A circuit with two public inputs c, d, enforced to satisfy (c, d) = a * (b, b^2) for
+    /// the witnesses a, b. The first constraint is repeated to reach `num_constraints`.
+    struct TestCircuit {
+        a: Option<Fr>,
+        b: Option<Fr>,
+        num_constraints: usize,
+    }
+
+    impl ConstraintSynthesizer<Fr> for TestCircuit {
+        fn generate_constraints<CS: ConstraintSystemAbstract<Fr>>(
+            self,
+            cs: &mut CS,
+        ) -> Result<(), SynthesisError> {
+            let a = cs.alloc(|| "a", || self.a.ok_or(SynthesisError::AssignmentMissing))?;
+            let b = cs.alloc(|| "b", || self.b.ok_or(SynthesisError::AssignmentMissing))?;
+            let c = cs.alloc_input(
+                || "c",
+                || {
+                    let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+                    Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * &b)
+                },
+            )?;
+            let d = cs.alloc_input(
+                || "d",
+                || {
+                    let b = self.b.ok_or(SynthesisError::AssignmentMissing)?;
+                    Ok(self.a.ok_or(SynthesisError::AssignmentMissing)? * &b * &b)
+                },
+            )?;
+            for i in 0..self.num_constraints - 1 {
+                cs.enforce(
+                    || format!("a * b = c {}", i),
+                    |lc| lc + a,
+                    |lc| lc + b,
+                    |lc| lc + c,
+                );
+            }
+            cs.enforce(|| "c * b = d", |lc| lc + c, |lc| lc + b, |lc| lc + d);
+            Ok(())
+        }
+    }
+
+    /// The dlog keys for polynomials of `SEGMENT_SIZE` coefficients.
+    fn get_keys() -> (CommitterKey<Affine>, VerifierKey<Affine>) {
+        let params = InnerProductArgPC::<Affine, Blake2s>::setup(SEGMENT_SIZE - 1).unwrap();
+        InnerProductArgPC::<Affine, Blake2s>::trim(&params, SEGMENT_SIZE - 1).unwrap()
+    }
+
+    fn alloc_marlin_proof(
+        cs: &mut ConstraintSystem<Fq>,
+        proof: &Proof<Affine>,
+    ) -> ProofGadget<TweedledeeParameters, TweedleDeeGadget> {
+        ProofGadget {
+            commitments: proof
+                .commitments
+                .iter()
+                .enumerate()
+                .map(|(i, segments)| {
+                    segments
+                        .iter()
+                        .enumerate()
+                        .map(|(j, segment)| {
+                            alloc_point(cs, &format!("commitment {} segment {}", i, j), segment)
+                        })
+                        .collect()
+                })
+                .collect(),
+            evaluations: proof
+                .evaluations
+                .iter()
+                .enumerate()
+                .map(|(i, eval)| alloc_scalar(cs, &format!("evaluation {}", i), eval))
+                .collect(),
+            pc_proof: alloc_multi_point_proof(cs, &proof.pc_proof),
+        }
+    }
+
+    fn succinct_verify(
+        vk: &VerifierKey<Affine>,
+        index_vk: &IndexVerifierKey<Affine>,
+        public_input: &[Fr],
+        proof: &Proof<Affine>,
+    ) -> (
+        bool,
+        Option<DLogItemGadget<TweedledeeParameters, TweedleDeeGadget>>,
+    ) {
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+
+        let vk = alloc_vk(&mut cs, vk);
+        let public_input = public_input
+            .iter()
+            .enumerate()
+            .map(|(i, x)| alloc_scalar(&mut cs, &format!("public input {}", i), x))
+            .collect::<Vec<_>>();
+        let proof = alloc_marlin_proof(&mut cs, proof);
+
+        let item = MarlinGadget::succinct_verify(
+            cs.ns(|| "succinct verify"),
+            &vk,
+            index_vk,
+            public_input.as_slice(),
+            &proof,
+        )
+        .ok();
+
+        (cs.is_satisfied(), item)
+    }
+
+    #[test]
+    fn tweedle_marlin_succinct_verify_gadget_test() {
+        let rng = &mut thread_rng();
+        let (ck, vk) = get_keys();
+        let num_constraints = 8;
+
+        let (index_pk, index_vk) = Marlin::index(
+            &ck,
+            TestCircuit {
+                a: None,
+                b: None,
+                num_constraints,
+            },
+        )
+        .unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let public_input = [a * &b, a * &b * &b];
+        let proof = Marlin::prove(
+            &ck,
+            &index_pk,
+            TestCircuit {
+                a: Some(a),
+                b: Some(b),
+                num_constraints,
+            },
+        )
+        .unwrap();
+
+        // The proof of the native prover, succinctly verified both natively and in-circuit
+        let item = Marlin::succinct_verify(&vk, &index_vk, &public_input, &proof)
+            .unwrap()
+            .unwrap();
+        let (satisfied, item_g) = succinct_verify(&vk, &index_vk, &public_input, &proof);
+        assert!(satisfied);
+        let item_g = item_g.unwrap();
+        assert_eq!(
+            item_g.g_final.get_value().unwrap().into_affine(),
+            item.g_final().comm[0]
+        );
+        assert_eq!(item_g.xi_s.get_value().unwrap(), item.xi_s().0);
+
+        // Wrong public input
+        let wrong_public_input = [public_input[0], public_input[0]];
+        assert!(!succinct_verify(&vk, &index_vk, &wrong_public_input, &proof).0);
+
+        // Corrupted evaluation
+        let mut wrong_proof = proof.clone();
+        wrong_proof.evaluations[0] += &Fr::one();
+        assert!(!succinct_verify(&vk, &index_vk, &public_input, &wrong_proof).0);
+    }
+}
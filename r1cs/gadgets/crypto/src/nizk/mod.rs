@@ -5,6 +5,15 @@ use r1cs_std::prelude::*;
 pub mod gm17;
 pub mod groth16;
 
+#[cfg(feature = "darlin")]
+pub mod accumulators;
+#[cfg(feature = "darlin")]
+pub mod fiat_shamir;
+#[cfg(feature = "darlin")]
+pub mod ipa_pc;
+#[cfg(feature = "darlin")]
+pub mod marlin;
+
 pub trait NIZK {
     type Circuit;
     type AssignedCircuit;
@@ -0,0 +1,321 @@
+//! Circuits for the [Keccak-f[1600]](https://keccak.team/keccak_specs_summary.html) permutation
+//! and the Keccak-256 hash function (i.e. the sponge with rate 1088 bits and the original `0x01`
+//! padding byte), built on top of the `UInt64` gadget.
+
+use algebra::PrimeField;
+use primitives::crh::{keccak::Keccak256CRH, FixedLengthCRH};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::boolean::Boolean;
+use r1cs_std::uint64::UInt64;
+use r1cs_std::uint8::UInt8;
+
+use crate::{crh::FixedLengthCRHGadget, prf::DigestGadget};
+
+#[allow(clippy::unreadable_literal)]
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// The rotation offsets of the rho step, for the lane at position `x + 5 * y`
+const ROTATION_OFFSETS: [usize; 25] = [
+    0, 1, 62, 28, 27, 36, 44, 6, 55, 20, 3, 10, 43, 25, 39, 41, 45, 15, 21, 8, 18, 2, 61, 56, 14,
+];
+
+/// The rate of Keccak-256, in bytes
+const RATE_BYTES: usize = 136;
+
+/// The Keccak-f[1600] permutation, applied to a state of 25 lanes, the lane at position
+/// `x + 5 * y` being `state[x + 5 * y]`.
+pub fn keccak_f1600<ConstraintF, CS>(
+    mut cs: CS,
+    state: &[UInt64],
+) -> Result<Vec<UInt64>, SynthesisError>
+where
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    assert_eq!(state.len(), 25);
+
+    let mut a = state.to_vec();
+    for (i, round_constant) in ROUND_CONSTANTS.iter().enumerate() {
+        let cs = &mut cs.ns(|| format!("round {}", i));
+
+        // Theta step:
+        // C[x] := A[x, 0] xor A[x, 1] xor A[x, 2] xor A[x, 3] xor A[x, 4]
+        // D[x] := C[x - 1] xor (C[x + 1] leftrotate 1)
+        // A[x, y] := A[x, y] xor D[x]
+        let mut c = Vec::with_capacity(5);
+        for x in 0..5 {
+            let mut c_x = a[x].clone();
+            for y in 1..5 {
+                c_x = c_x.xor(
+                    cs.ns(|| format!("c[{}] xor a[{}, {}]", x, x, y)),
+                    &a[x + 5 * y],
+                )?;
+            }
+            c.push(c_x);
+        }
+        for x in 0..5 {
+            let d_x = c[(x + 4) % 5].xor(
+                cs.ns(|| format!("compute d[{}]", x)),
+                &c[(x + 1) % 5].rotl(1),
+            )?;
+            for y in 0..5 {
+                a[x + 5 * y] =
+                    a[x + 5 * y].xor(cs.ns(|| format!("a[{}, {}] xor d[{}]", x, y, x)), &d_x)?;
+            }
+        }
+
+        // Rho and pi steps:
+        // B[y, 2x + 3y] := A[x, y] leftrotate r[x, y]
+        let mut b = a.clone();
+        for x in 0..5 {
+            for y in 0..5 {
+                b[y + 5 * ((2 * x + 3 * y) % 5)] = a[x + 5 * y].rotl(ROTATION_OFFSETS[x + 5 * y]);
+            }
+        }
+
+        // Chi step:
+        // A[x, y] := B[x, y] xor ((not B[x + 1, y]) and B[x + 2, y])
+        for x in 0..5 {
+            for y in 0..5 {
+                let tmp = b[(x + 1) % 5 + 5 * y].not().and(
+                    cs.ns(|| {
+                        format!(
+                            "not b[{}, {}] and b[{}, {}]",
+                            (x + 1) % 5,
+                            y,
+                            (x + 2) % 5,
+                            y
+                        )
+                    }),
+                    &b[(x + 2) % 5 + 5 * y],
+                )?;
+                a[x + 5 * y] =
+                    b[x + 5 * y].xor(cs.ns(|| format!("chi for a[{}, {}]", x, y)), &tmp)?;
+            }
+        }
+
+        // Iota step: xor with a constant is free
+        a[0] = a[0].xor(
+            cs.ns(|| "xor round constant"),
+            &UInt64::constant(*round_constant),
+        )?;
+    }
+
+    Ok(a)
+}
+
+/// Keccak-256 of a byte string. The output is the 32 bytes long digest.
+pub fn keccak256<ConstraintF, CS>(mut cs: CS, input: &[UInt8]) -> Result<Vec<UInt8>, SynthesisError>
+where
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    // pad10*1 padding, with the 0x01 domain separation byte of the original Keccak
+    let mut padded = input.to_vec();
+    let padding_len = RATE_BYTES - input.len() % RATE_BYTES;
+    let mut padding = vec![0u8; padding_len];
+    padding[0] |= 0x01;
+    padding[padding_len - 1] |= 0x80;
+    padded.extend(UInt8::constant_vec(&padding));
+    assert!(padded.len() % RATE_BYTES == 0);
+
+    // Absorbing phase
+    let mut state = vec![UInt64::constant(0); 25];
+    for (i, block) in padded.chunks(RATE_BYTES).enumerate() {
+        let cs = &mut cs.ns(|| format!("block {}", i));
+
+        for (j, lane) in block.chunks(8).enumerate() {
+            let lane_bits = lane
+                .iter()
+                .flat_map(|byte| byte.to_bits_le())
+                .collect::<Vec<Boolean>>();
+            state[j] = state[j].xor(
+                cs.ns(|| format!("absorb lane {}", j)),
+                &UInt64::from_bits_le(&lane_bits),
+            )?;
+        }
+        state = keccak_f1600(cs.ns(|| "keccak-f"), &state)?;
+    }
+
+    // Squeezing phase: the digest fits in the first 4 lanes
+    Ok(state[..4]
+        .iter()
+        .flat_map(|lane| lane.to_bits_le())
+        .collect::<Vec<_>>()
+        .chunks(8)
+        .map(UInt8::from_bits_le)
+        .collect())
+}
+
+/// Gadget for `Keccak256CRH`: the input bytes are hashed as they are, and the output is the
+/// 32 bytes long digest.
+pub struct Keccak256CRHGadget;
+
+impl<ConstraintF: PrimeField> FixedLengthCRHGadget<Keccak256CRH, ConstraintF>
+    for Keccak256CRHGadget
+{
+    type OutputGadget = DigestGadget<32>;
+    type ParametersGadget = ();
+
+    fn check_evaluation_gadget<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        _parameters: &Self::ParametersGadget,
+        input: &[UInt8],
+    ) -> Result<Self::OutputGadget, SynthesisError> {
+        if input.len() * 8 > Keccak256CRH::INPUT_SIZE_BITS {
+            return Err(SynthesisError::Other(format!(
+                "incorrect input length {} for Keccak256CRH",
+                input.len()
+            )));
+        }
+        Ok(DigestGadget(keccak256(cs, input)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algebra::fields::bls12_381::Fr;
+    use r1cs_std::{alloc::AllocGadget, eq::EqGadget};
+
+    use r1cs_core::{ConstraintSystem, ConstraintSystemDebugger, SynthesisMode};
+    use rand::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    fn digest_value(digest: &[UInt8]) -> Vec<u8> {
+        digest
+            .iter()
+            .map(|byte| byte.get_value().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_keccak_f1600() {
+        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+        let state = (0..25)
+            .map(|i| {
+                <UInt64 as AllocGadget<u64, Fr>>::alloc(
+                    cs.ns(|| format!("alloc lane {}", i)),
+                    || Ok(0u64),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let permuted = keccak_f1600(cs.ns(|| "keccak-f"), &state).unwrap();
+
+        assert!(cs.is_satisfied());
+        assert_eq!(cs.num_constraints() - 25 * 64, 153600);
+        // Known answer for the all-zero state
+        assert_eq!(permuted[0].get_value().unwrap(), 0xf1258f7940e1dde7);
+        assert_eq!(permuted[24].get_value().unwrap(), 0xeaf1ff7b5ceca249);
+    }
+
+    /// Tests circuit satisfiability and correctness of the hash function on single and
+    /// multiple blocks, including padding
+    #[test]
+    fn native_test() {
+        use sha3::{Digest, Keccak256};
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        // Inputs around the lengths at which the padding spills over another block
+        for input_len in (0..4).chain(134..138).chain(Some(300)) {
+            let data: Vec<u8> = (0..input_len).map(|_| rng.next_u32() as u8).collect();
+            let hash_result = Keccak256::digest(&data);
+
+            let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+            let input = UInt8::alloc_vec(cs.ns(|| "alloc input"), &data).unwrap();
+            let r = keccak256(&mut cs, &input).unwrap();
+
+            assert!(cs.is_satisfied());
+            assert_eq!(digest_value(&r), hash_result.to_vec());
+        }
+    }
+
+    #[test]
+    fn compare_against_test_vectors() {
+        let test_inputs = ["", "abc", "The quick brown fox jumps over the lazy dog"];
+
+        let test_outputs = [
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+        ];
+
+        for (test_input, test_output) in test_inputs.iter().zip(test_outputs.iter()) {
+            let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+            let input = UInt8::alloc_vec(cs.ns(|| "alloc input"), test_input.as_bytes()).unwrap();
+            let r = keccak256(&mut cs, &input).unwrap();
+
+            assert!(cs.is_satisfied());
+            assert_eq!(digest_value(&r), hex::decode(test_output).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_keccak256_crh_gadget() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+        let mut input = [0u8; 64];
+        rng.fill_bytes(&mut input);
+        let input_gadget = UInt8::alloc_vec(cs.ns(|| "alloc input"), &input).unwrap();
+
+        let primitive_result = Keccak256CRH::evaluate(&(), &input).unwrap();
+        let gadget_result =
+            <Keccak256CRHGadget as FixedLengthCRHGadget<Keccak256CRH, Fr>>::check_evaluation_gadget(
+                cs.ns(|| "gadget evaluation"),
+                &(),
+                &input_gadget,
+            )
+            .unwrap();
+        assert_eq!(digest_value(&gadget_result.0), primitive_result.to_vec());
+
+        let expected_result = <DigestGadget<32> as AllocGadget<[u8; 32], Fr>>::alloc(
+            cs.ns(|| "alloc expected result"),
+            || Ok(primitive_result),
+        )
+        .unwrap();
+        gadget_result
+            .enforce_equal(cs.ns(|| "check result"), &expected_result)
+            .unwrap();
+        assert!(cs.is_satisfied());
+
+        // Inputs longer than `INPUT_SIZE_BITS` are rejected
+        let too_long_input = UInt8::constant_vec(&[0u8; 65]);
+        assert!(
+            <Keccak256CRHGadget as FixedLengthCRHGadget<Keccak256CRH, Fr>>::check_evaluation_gadget(
+                cs.ns(|| "too long input"),
+                &(),
+                &too_long_input,
+            )
+            .is_err()
+        );
+    }
+}
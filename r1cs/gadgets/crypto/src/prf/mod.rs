@@ -1,5 +1,5 @@
-use algebra::Field;
-use std::fmt::Debug;
+use algebra::{Field, PrimeField};
+use std::{borrow::Borrow, fmt::Debug};
 
 use primitives::prf::PRF;
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
@@ -7,8 +7,10 @@ use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 use r1cs_std::prelude::*;
 
 pub mod blake2s;
+pub mod keccak;
 pub mod ripemd160;
 pub mod sha256;
+pub mod sha512;
 
 pub trait PRFGadget<P: PRF, ConstraintF: Field> {
     type OutputGadget: EqGadget<ConstraintF>
@@ -25,3 +27,179 @@ pub trait PRFGadget<P: PRF, ConstraintF: Field> {
         input: &[UInt8],
     ) -> Result<Self::OutputGadget, SynthesisError>;
 }
+
+/// The `LEN` bytes output of a hash function, e.g. of the SHA-512 or Keccak-256 CRH gadgets.
+#[derive(Clone, Debug)]
+pub struct DigestGadget<const LEN: usize>(pub Vec<UInt8>);
+
+impl<const LEN: usize> PartialEq for DigestGadget<LEN> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const LEN: usize> Eq for DigestGadget<LEN> {}
+
+impl<ConstraintF: PrimeField, const LEN: usize> EqGadget<ConstraintF> for DigestGadget<LEN> {
+    #[inline]
+    fn is_eq<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        other: &Self,
+    ) -> Result<Boolean, SynthesisError> {
+        self.0.is_eq(cs, &other.0)
+    }
+
+    #[inline]
+    fn conditional_enforce_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.0.conditional_enforce_equal(cs, &other.0, condition)
+    }
+
+    #[inline]
+    fn conditional_enforce_not_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+        other: &Self,
+        condition: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        self.0
+            .as_slice()
+            .conditional_enforce_not_equal(cs, other.0.as_slice(), condition)
+    }
+}
+
+impl<ConstraintF: PrimeField, const LEN: usize> ToBytesGadget<ConstraintF> for DigestGadget<LEN> {
+    #[inline]
+    fn to_bytes<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        _cs: CS,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        Ok(self.0.clone())
+    }
+
+    #[inline]
+    fn to_bytes_strict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        self.to_bytes(cs)
+    }
+}
+
+impl<ConstraintF: PrimeField, const LEN: usize> CondSelectGadget<ConstraintF>
+    for DigestGadget<LEN>
+{
+    fn conditionally_select<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        cond: &Boolean,
+        true_value: &Self,
+        false_value: &Self,
+    ) -> Result<Self, SynthesisError> {
+        if true_value.0.len() != LEN || false_value.0.len() != LEN {
+            return Err(SynthesisError::Other(format!(
+                "cannot select between digests of length {} and {}, expected {}",
+                true_value.0.len(),
+                false_value.0.len(),
+                LEN
+            )));
+        }
+        let bytes = true_value
+            .0
+            .iter()
+            .zip(false_value.0.iter())
+            .enumerate()
+            .map(|(i, (t, f))| {
+                UInt8::conditionally_select(cs.ns(|| format!("select byte {}", i)), cond, t, f)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(DigestGadget(bytes))
+    }
+
+    fn cost() -> usize {
+        LEN * <UInt8 as CondSelectGadget<ConstraintF>>::cost()
+    }
+}
+
+impl<ConstraintF: PrimeField, const LEN: usize> AllocGadget<[u8; LEN], ConstraintF>
+    for DigestGadget<LEN>
+{
+    #[inline]
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<[u8; LEN]>,
+    {
+        let value = value_gen().map(|val| *val.borrow()).unwrap_or([0u8; LEN]);
+        Ok(DigestGadget(UInt8::alloc_vec(cs, &value)?))
+    }
+
+    #[inline]
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<[u8; LEN]>,
+    {
+        let value = value_gen().map(|val| *val.borrow()).unwrap_or([0u8; LEN]);
+        Ok(DigestGadget(UInt8::alloc_input_vec(cs, &value)?))
+    }
+}
+
+/// For hash functions whose output is a `Vec<u8>`: its length must be `LEN`.
+impl<ConstraintF: PrimeField, const LEN: usize> AllocGadget<Vec<u8>, ConstraintF>
+    for DigestGadget<LEN>
+{
+    #[inline]
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Vec<u8>>,
+    {
+        let value = digest_value::<_, _, LEN>(value_gen)?;
+        Ok(DigestGadget(UInt8::alloc_vec(cs, &value)?))
+    }
+
+    #[inline]
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        value_gen: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Vec<u8>>,
+    {
+        let value = digest_value::<_, _, LEN>(value_gen)?;
+        Ok(DigestGadget(UInt8::alloc_input_vec(cs, &value)?))
+    }
+}
+
+/// Return the value of a `Vec<u8>` digest of length `LEN`, or `LEN` zero bytes if it is
+/// not available (e.g. when generating the parameters of a circuit).
+fn digest_value<F, T, const LEN: usize>(value_gen: F) -> Result<Vec<u8>, SynthesisError>
+where
+    F: FnOnce() -> Result<T, SynthesisError>,
+    T: Borrow<Vec<u8>>,
+{
+    match value_gen() {
+        Ok(value) if value.borrow().len() != LEN => Err(SynthesisError::Other(format!(
+            "digest of length {}, expected {}",
+            value.borrow().len(),
+            LEN
+        ))),
+        Ok(value) => Ok(value.borrow().clone()),
+        Err(_) => Ok(vec![0u8; LEN]),
+    }
+}
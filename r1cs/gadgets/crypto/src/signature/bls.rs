@@ -0,0 +1,270 @@
+//! Gadget for the verification of aggregated BLS signatures of (a subset of) a committee on the
+//! same message, e.g. for the validation of committee certificates.
+use algebra::{Field, PairingEngine, ProjectiveCurve};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::prelude::*;
+
+use std::marker::PhantomData;
+
+pub struct BlsAggregateVerificationGadget<
+    PairingE: PairingEngine,
+    ConstraintF: Field,
+    P: PairingGadget<PairingE, ConstraintF>,
+> {
+    _pairing_engine: PhantomData<PairingE>,
+    _field: PhantomData<ConstraintF>,
+    _pairing_gadget: PhantomData<P>,
+}
+
+impl<PairingE, ConstraintF, P> BlsAggregateVerificationGadget<PairingE, ConstraintF, P>
+where
+    PairingE: PairingEngine,
+    ConstraintF: Field,
+    P: PairingGadget<PairingE, ConstraintF>,
+{
+    /// Return `G + sum_{i: signers[i]} pks[i]`, with `G` the generator of G1.
+    /// The offset by `G` allows to use incomplete arithmetics, as the sum never hits the
+    /// neutral element, and `pks[i] = +-(partial sum)` happens with negligible probability for
+    /// honestly generated keys.
+    fn aggregate_public_keys_with_offset<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        pks: &[P::G1Gadget],
+        signers: &[Boolean],
+    ) -> Result<P::G1Gadget, SynthesisError> {
+        if pks.len() != signers.len() {
+            return Err(SynthesisError::Other(format!(
+                "Number of public keys: {}, number of signers flags: {}",
+                pks.len(),
+                signers.len()
+            )));
+        }
+
+        let mut aggregated_pk = P::G1Gadget::from_value(
+            cs.ns(|| "hardcode generator"),
+            &PairingE::G1Projective::prime_subgroup_generator(),
+        );
+        for (i, (pk, is_signer)) in pks.iter().zip(signers.iter()).enumerate() {
+            let sum = aggregated_pk.add(cs.ns(|| format!("add pk {}", i)), pk)?;
+            aggregated_pk = P::G1Gadget::conditionally_select(
+                cs.ns(|| format!("select pk {}", i)),
+                is_signer,
+                &sum,
+                &aggregated_pk,
+            )?;
+        }
+        Ok(aggregated_pk)
+    }
+
+    /// Enforce the verification of `signature`, the aggregation of the signatures of
+    /// `pks[i]` for which `signers[i]` is true, on the message whose hash to G2 is
+    /// `message_hash`. Returns a Boolean enforced to be `true` if signature verification is
+    /// successful, and `false` otherwise.
+    /// The message is hashed outside the circuit, hence `message_hash` is meant to be
+    /// exposed as public input. The public keys are assumed to be non-trivial elements of
+    /// the prime order subgroup, whose proofs of possession have been verified.
+    /// The subgroup membership of `signature` is not enforced either, unlike in
+    /// `Bls::verify`: it is a precondition for the caller, e.g. by exposing `signature`
+    /// as public input checked outside the circuit, as otherwise a prover may pass a
+    /// point of G2 outside the prime order subgroup.
+    pub fn enforce_aggregate_signature_verdict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        pks: &[P::G1Gadget],
+        signers: &[Boolean],
+        message_hash: &P::G2Gadget,
+        signature: &P::G2Gadget,
+    ) -> Result<Boolean, SynthesisError> {
+        // With apk the aggregated public key, e(apk, H(m)) = e(G, sig) is checked as
+        //      e(G + apk, H(m)) * e(-G, sig) * e(-G, H(m)) = 1
+        let aggregated_pk = Self::aggregate_public_keys_with_offset(
+            cs.ns(|| "aggregate public keys"),
+            pks,
+            signers,
+        )?;
+        let minus_generator = P::G1Gadget::from_value(
+            cs.ns(|| "hardcode -generator"),
+            &-PairingE::G1Projective::prime_subgroup_generator(),
+        );
+
+        let aggregated_pk_prep = P::prepare_g1(cs.ns(|| "prepare apk"), &aggregated_pk)?;
+        let minus_generator_prep = P::prepare_g1(cs.ns(|| "prepare -G"), &minus_generator)?;
+        let message_hash_prep = P::prepare_g2(cs.ns(|| "prepare H(m)"), message_hash)?;
+        let signature_prep = P::prepare_g2(cs.ns(|| "prepare sig"), signature)?;
+
+        let result = P::product_of_pairings(
+            cs.ns(|| "product of pairings"),
+            &[
+                aggregated_pk_prep,
+                minus_generator_prep.clone(),
+                minus_generator_prep,
+            ],
+            &[message_hash_prep.clone(), signature_prep, message_hash_prep],
+        )?;
+        let one = P::GTGadget::one(cs.ns(|| "alloc one"))?;
+        result.is_eq(cs.ns(|| "product of pairings == 1"), &one)
+    }
+
+    /// Enforce the verification of the aggregated `signature` to be successful.
+    pub fn enforce_aggregate_signature_verification<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        pks: &[P::G1Gadget],
+        signers: &[Boolean],
+        message_hash: &P::G2Gadget,
+        signature: &P::G2Gadget,
+    ) -> Result<(), SynthesisError> {
+        Self::conditionally_enforce_aggregate_signature_verification(
+            cs,
+            pks,
+            signers,
+            message_hash,
+            signature,
+            &Boolean::Constant(true),
+        )
+    }
+
+    /// Enforce or not enforce, according to `should_enforce` value, the verification of the
+    /// aggregated `signature` to be successful.
+    pub fn conditionally_enforce_aggregate_signature_verification<
+        CS: ConstraintSystemAbstract<ConstraintF>,
+    >(
+        mut cs: CS,
+        pks: &[P::G1Gadget],
+        signers: &[Boolean],
+        message_hash: &P::G2Gadget,
+        signature: &P::G2Gadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let is_verified = Self::enforce_aggregate_signature_verdict(
+            cs.ns(|| "is sig verified"),
+            pks,
+            signers,
+            message_hash,
+            signature,
+        )?;
+        is_verified.conditional_enforce_equal(
+            cs.ns(|| "conditional verify signature"),
+            &Boolean::constant(true),
+            should_enforce,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        curves::bls12_377::{Bls12_377, G1Projective, G2Projective},
+        fields::bls12_377::Fq,
+        AffineCurve,
+    };
+    use primitives::signature::{
+        bls::{Bls, BlsHashToCurve},
+        SignatureScheme,
+    };
+    use r1cs_core::{
+        ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
+    };
+    use r1cs_std::{instantiated::bls12_377::PairingGadget as Bls12_377PairingGadget, prelude::*};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::signature::bls::BlsAggregateVerificationGadget;
+
+    type BlsGadget = BlsAggregateVerificationGadget<Bls12_377, Fq, Bls12_377PairingGadget>;
+    type G1Gadget = <Bls12_377PairingGadget as PairingGadget<Bls12_377, Fq>>::G1Gadget;
+    type G2Gadget = <Bls12_377PairingGadget as PairingGadget<Bls12_377, Fq>>::G2Gadget;
+
+    #[test]
+    fn bls12_377_aggregate_verification_gadget_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am a committee certificate!".as_bytes();
+
+        let keys = (0..4)
+            .map(|_| Bls::<Bls12_377>::keygen(&(), rng).unwrap())
+            .collect::<Vec<_>>();
+        let signers = [true, false, true, true];
+        let sigs = keys
+            .iter()
+            .zip(signers.iter())
+            .filter(|(_, is_signer)| **is_signer)
+            .map(|((_, sk), _)| Bls::<Bls12_377>::sign(&(), sk, message, rng).unwrap())
+            .collect::<Vec<_>>();
+        let aggregated_sig = Bls::<Bls12_377>::aggregate_signatures(&sigs).unwrap();
+        let message_hash = Bls12_377::hash_to_g2(Bls12_377::SIG_DST, message).unwrap();
+
+        for (test_signers, expected_verdict) in [
+            (signers, true),
+            ([true, true, true, true], false),
+            ([true, false, false, true], false),
+        ]
+        .iter()
+        {
+            let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+
+            let pks_g = keys
+                .iter()
+                .enumerate()
+                .map(|(i, (pk, _))| {
+                    G1Gadget::alloc(cs.ns(|| format!("alloc pk {}", i)), || {
+                        Ok(pk.into_projective())
+                    })
+                    .unwrap()
+                })
+                .collect::<Vec<_>>();
+            let signers_g = test_signers
+                .iter()
+                .enumerate()
+                .map(|(i, is_signer)| {
+                    Boolean::alloc(cs.ns(|| format!("alloc signer flag {}", i)), || {
+                        Ok(*is_signer)
+                    })
+                    .unwrap()
+                })
+                .collect::<Vec<_>>();
+            let message_hash_g = G2Gadget::alloc_input(cs.ns(|| "alloc message hash"), || {
+                Ok(G2Projective::from(message_hash))
+            })
+            .unwrap();
+            let sig_g = G2Gadget::alloc_input(cs.ns(|| "alloc sig"), || {
+                Ok(G2Projective::from(aggregated_sig))
+            })
+            .unwrap();
+
+            let verdict = BlsGadget::enforce_aggregate_signature_verdict(
+                cs.ns(|| "sig verdict"),
+                &pks_g,
+                &signers_g,
+                &message_hash_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert_eq!(verdict.get_value().unwrap(), *expected_verdict);
+            assert!(cs.is_satisfied());
+
+            BlsGadget::enforce_aggregate_signature_verification(
+                cs.ns(|| "sig verification"),
+                &pks_g,
+                &signers_g,
+                &message_hash_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert_eq!(cs.is_satisfied(), *expected_verdict);
+        }
+
+        // Mismatching number of public keys and signers flags
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+        let pk_g =
+            G1Gadget::alloc(cs.ns(|| "alloc pk"), || Ok(G1Projective::from(keys[0].0))).unwrap();
+        let sig_g = G2Gadget::alloc(cs.ns(|| "alloc sig"), || {
+            Ok(G2Projective::from(aggregated_sig))
+        })
+        .unwrap();
+        assert!(BlsGadget::enforce_aggregate_signature_verdict(
+            cs.ns(|| "sig verdict"),
+            &[pk_g],
+            &[],
+            &sig_g,
+            &sig_g,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,617 @@
+//! Gadget for the verification of ECDSA signatures (with SHA-256 message digest) over a
+//! short Weierstrass curve, e.g. secp256k1, whose base and scalar fields are simulated by
+//! non-native arithmetics.
+use algebra::{
+    curves::short_weierstrass_jacobian::GroupProjective as SWProjective, Field, Group, PrimeField,
+    ProjectiveCurve, SWModelParameters, SquareRootField, ToBits,
+};
+use primitives::signature::ecdsa::{Ecdsa, EcdsaSig};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::nonnative::nonnative_field_gadget::NonNativeFieldGadget,
+    groups::nonnative::GroupAffineNonNativeGadget, prelude::*,
+};
+
+use crate::{
+    prf::sha256::sha256,
+    signature::{
+        bits_are_eq_constant, conditionally_select_bits, fixed_base_exceptional_scalars,
+        SigVerifyGadget,
+    },
+};
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "P: SWModelParameters, ConstraintF: PrimeField"),
+    Clone(bound = "P: SWModelParameters, ConstraintF: PrimeField")
+)]
+pub struct EcdsaSigGadget<P: SWModelParameters, ConstraintF: PrimeField> {
+    pub r: NonNativeFieldGadget<P::ScalarField, ConstraintF>,
+    pub s: NonNativeFieldGadget<P::ScalarField, ConstraintF>,
+}
+
+impl<P, ConstraintF> AllocGadget<EcdsaSig<P>, ConstraintF> for EcdsaSigGadget<P, ConstraintF>
+where
+    P: SWModelParameters,
+    ConstraintF: PrimeField,
+{
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<EcdsaSig<P>>,
+    {
+        let (r, s) = match f() {
+            Ok(sig) => {
+                let sig = sig.borrow();
+                (Ok(sig.r), Ok(sig.s))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let r = NonNativeFieldGadget::alloc(cs.ns(|| "alloc r"), || r)?;
+        let s = NonNativeFieldGadget::alloc(cs.ns(|| "alloc s"), || s)?;
+        Ok(Self { r, s })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<EcdsaSig<P>>,
+    {
+        let (r, s) = match f() {
+            Ok(sig) => {
+                let sig = sig.borrow();
+                (Ok(sig.r), Ok(sig.s))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let r = NonNativeFieldGadget::alloc_input(cs.ns(|| "alloc r"), || r)?;
+        let s = NonNativeFieldGadget::alloc_input(cs.ns(|| "alloc s"), || s)?;
+        Ok(Self { r, s })
+    }
+}
+
+pub struct EcdsaVerificationGadget<
+    P: SWModelParameters<BaseField = SimulationF>,
+    ConstraintF: PrimeField,
+    SimulationF: PrimeField + SquareRootField,
+> {
+    _params: PhantomData<P>,
+    _field: PhantomData<ConstraintF>,
+    _simulation_field: PhantomData<SimulationF>,
+}
+
+impl<P, ConstraintF, SimulationF> EcdsaVerificationGadget<P, ConstraintF, SimulationF>
+where
+    P: SWModelParameters<BaseField = SimulationF>,
+    ConstraintF: PrimeField,
+    SimulationF: PrimeField + SquareRootField,
+{
+    /// Enforce the verdict on `signature` given the message digest `e`.
+    fn enforce_digest_verdict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        public_key: &GroupAffineNonNativeGadget<P, ConstraintF, SimulationF>,
+        e: &NonNativeFieldGadget<P::ScalarField, ConstraintF>,
+        signature: &EcdsaSigGadget<P, ConstraintF>,
+    ) -> Result<Boolean, SynthesisError> {
+        // r != 0, s != 0: if this is not the case, the verdict is false and we continue
+        // with r = 1, resp. s = 1, to keep the circuit satisfiable.
+        let zero = NonNativeFieldGadget::<P::ScalarField, ConstraintF>::zero(cs.ns(|| "zero"))?;
+        let one = NonNativeFieldGadget::<P::ScalarField, ConstraintF>::one(cs.ns(|| "one"))?;
+        let r_is_zero = signature.r.is_eq(cs.ns(|| "r == 0"), &zero)?;
+        let s_is_zero = signature.s.is_eq(cs.ns(|| "s == 0"), &zero)?;
+        let r = NonNativeFieldGadget::conditionally_select(
+            cs.ns(|| "r or 1"),
+            &r_is_zero,
+            &one,
+            &signature.r,
+        )?;
+        let s = NonNativeFieldGadget::conditionally_select(
+            cs.ns(|| "s or 1"),
+            &s_is_zero,
+            &one,
+            &signature.s,
+        )?;
+
+        // Similarly, a trivial public key is replaced by the generator
+        let g = GroupAffineNonNativeGadget::<P, ConstraintF, SimulationF>::from_value(
+            cs.ns(|| "hardcode generator"),
+            &SWProjective::<P>::prime_subgroup_generator(),
+        );
+        let pk = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "pk or generator"),
+            &public_key.infinity,
+            &g,
+            public_key,
+        )?;
+
+        // w := s^-1, u1 := e * w, u2 := r * w
+        let w = s.inverse(cs.ns(|| "w := s^-1"))?;
+        let u1 = e.mul(cs.ns(|| "u1 := e * w"), &w)?;
+        let u2 = r.mul(cs.ns(|| "u2 := r * w"), &w)?;
+        let u1_bits = u1.to_bits_strict(cs.ns(|| "u1 to bits"))?;
+        let u2_bits = u2.to_bits_strict(cs.ns(|| "u2 to bits"))?;
+
+        // A := u1 * G. If u1 is in the exceptional set of the fixed base scalar
+        // multiplication, the latter is performed with a safe substitute and A is then
+        // replaced by the hardcoded u1 * G. For u1 = 0, i.e. e = 0, A is trivial and R := B
+        // below.
+        let u1_exceptional_scalars =
+            fixed_base_exceptional_scalars::<P::ScalarField>(u1_bits.len());
+        let u1_substitute = (1u64..)
+            .map(P::ScalarField::from)
+            .find(|scalar| !u1_exceptional_scalars.contains(scalar))
+            .unwrap();
+        let u1_is_exceptional_scalar = u1_exceptional_scalars
+            .iter()
+            .enumerate()
+            .map(|(i, scalar)| {
+                bits_are_eq_constant(
+                    cs.ns(|| format!("u1 == exceptional scalar {}", i)),
+                    &u1_bits,
+                    scalar,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let u1_is_zero = u1_is_exceptional_scalar[0];
+        let u1_is_exceptional = Boolean::kary_and(
+            cs.ns(|| "u1 is not exceptional"),
+            &u1_is_exceptional_scalar
+                .iter()
+                .map(|b| b.not())
+                .collect::<Vec<_>>(),
+        )?
+        .not();
+        let mut u1_bits = conditionally_select_bits(
+            cs.ns(|| "u1 or substitute"),
+            &u1_is_exceptional,
+            &u1_substitute.write_bits(),
+            &u1_bits,
+        )?;
+        u1_bits.reverse();
+
+        let mut u1_times_g =
+            GroupAffineNonNativeGadget::<P, ConstraintF, SimulationF>::mul_bits_fixed_base(
+                &SWProjective::<P>::prime_subgroup_generator(),
+                cs.ns(|| "u1 * G"),
+                &u1_bits,
+            )?;
+        for (i, (scalar, is_scalar)) in u1_exceptional_scalars
+            .iter()
+            .zip(u1_is_exceptional_scalar.iter())
+            .enumerate()
+            .skip(1)
+        {
+            let scalar_times_g = GroupAffineNonNativeGadget::from_value(
+                cs.ns(|| format!("hardcode exceptional scalar {} * G", i)),
+                &SWProjective::<P>::prime_subgroup_generator().mul(scalar),
+            );
+            u1_times_g = GroupAffineNonNativeGadget::conditionally_select(
+                cs.ns(|| format!("u1 * G or exceptional scalar {} * G", i)),
+                is_scalar,
+                &scalar_times_g,
+                &u1_times_g,
+            )?;
+        }
+
+        // B := u2 * pk. As r and s are non-zero, u2 is in the exceptional set of the
+        // variable base scalar multiplication iff u2 = -1 or u2 = -2. In these cases the
+        // multiplication is performed with u2 = 1, and B is replaced by -pk, resp. -2 * pk.
+        let minus_one = -P::ScalarField::one();
+        let minus_two = minus_one.double();
+        let u2_is_minus_one = bits_are_eq_constant(cs.ns(|| "u2 == -1"), &u2_bits, &minus_one)?;
+        let u2_is_minus_two = bits_are_eq_constant(cs.ns(|| "u2 == -2"), &u2_bits, &minus_two)?;
+        let u2_is_exceptional = Boolean::or(
+            cs.ns(|| "u2 is exceptional"),
+            &u2_is_minus_one,
+            &u2_is_minus_two,
+        )?;
+        let mut u2_bits = conditionally_select_bits(
+            cs.ns(|| "u2 or 1"),
+            &u2_is_exceptional,
+            &P::ScalarField::one().write_bits(),
+            &u2_bits,
+        )?;
+        u2_bits.reverse();
+
+        let u2_times_pk = pk.mul_bits(cs.ns(|| "u2 * pk"), u2_bits.iter())?;
+        let minus_pk = pk.negate(cs.ns(|| "-pk"))?;
+        let mut minus_two_times_pk = minus_pk.clone();
+        minus_two_times_pk.double_in_place(cs.ns(|| "-2 * pk"))?;
+        let u2_times_pk = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "u2 * pk or -pk"),
+            &u2_is_minus_one,
+            &minus_pk,
+            &u2_times_pk,
+        )?;
+        let u2_times_pk = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "u2 * pk or -2 * pk"),
+            &u2_is_minus_two,
+            &minus_two_times_pk,
+            &u2_times_pk,
+        )?;
+
+        // R := A + B, treating the exceptional cases of the incomplete addition explicitly:
+        // if x(A) == x(B) then either B = A, and R = 2 * A, or B = -A, and R is trivial.
+        // In both cases we add 2 * A instead of B, whose x-coordinate differs from the one
+        // of A as the curve has prime order.
+        let mut two_times_u1_times_g = u1_times_g.clone();
+        two_times_u1_times_g.double_in_place(cs.ns(|| "2 * A"))?;
+        let same_x = u1_times_g
+            .x
+            .is_eq(cs.ns(|| "x(A) == x(B)"), &u2_times_pk.x)?;
+        let same_y = u1_times_g
+            .y
+            .is_eq(cs.ns(|| "y(A) == y(B)"), &u2_times_pk.y)?;
+        let addend = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "B or 2 * A"),
+            &same_x,
+            &two_times_u1_times_g,
+            &u2_times_pk,
+        )?;
+        let sum = u1_times_g.add(cs.ns(|| "A + B"), &addend)?;
+        let claimed_r = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "A + B or 2 * A"),
+            &same_x,
+            &two_times_u1_times_g,
+            &sum,
+        )?;
+        // If u1 = 0, then R := B, which is non-trivial.
+        let claimed_r = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "R := A + B or B"),
+            &u1_is_zero,
+            &u2_times_pk,
+            &claimed_r,
+        )?;
+        let claimed_r_is_zero = Boolean::kary_and(
+            cs.ns(|| "R is trivial"),
+            &[same_x, same_y.not(), u1_is_zero.not()],
+        )?;
+
+        // Check x(R) mod n == r
+        let claimed_r_x_bits = claimed_r.x.to_bits_strict(cs.ns(|| "x(R) to bits"))?;
+        let claimed_r_x = NonNativeFieldGadget::<P::ScalarField, ConstraintF>::from_bits(
+            cs.ns(|| "x(R) mod n"),
+            &claimed_r_x_bits,
+        )?;
+        let is_verified = claimed_r_x.is_eq(cs.ns(|| "x(R) mod n == r"), &signature.r)?;
+
+        // The signature is valid iff x(R) mod n == r and none of the exceptional cases
+        // above occurred
+        Boolean::kary_and(
+            cs.ns(|| "is verified and no exceptional case"),
+            &[
+                is_verified,
+                r_is_zero.not(),
+                s_is_zero.not(),
+                public_key.infinity.not(),
+                claimed_r_is_zero.not(),
+            ],
+        )
+    }
+}
+
+// The public key is assumed to be non-trivial, while the curve is assumed to be of prime
+// order (as secp256k1): the on-curve check performed when allocating the public key gadget
+// is then enough to guarantee it belongs to the prime order group.
+//
+// Zero r or s, a trivial public key and a trivial R lead to a false verdict, the circuit
+// staying satisfiable, as in the native verification.
+// The same holds for the exceptional inputs of the incomplete scalar multiplications and
+// addition, e.g. e = 0 mod n, u2 = -1 or u2 * pk = -u1 * G: they are replaced by safe
+// substitutes and the results are corrected accordingly, so that the verdict always agrees
+// with the native one.
+impl<P, ConstraintF, SimulationF> SigVerifyGadget<Ecdsa<P>, ConstraintF>
+    for EcdsaVerificationGadget<P, ConstraintF, SimulationF>
+where
+    P: SWModelParameters<BaseField = SimulationF>,
+    ConstraintF: PrimeField,
+    SimulationF: PrimeField + SquareRootField,
+{
+    type ParametersGadget = ();
+    type PublicKeyGadget = GroupAffineNonNativeGadget<P, ConstraintF, SimulationF>;
+    type SignatureGadget = EcdsaSigGadget<P, ConstraintF>;
+
+    fn enforce_signature_verdict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        _parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        // e := SHA-256(message), truncated to the length of the scalar field modulus
+        let message_bits = message
+            .iter()
+            .flat_map(|byte| byte.to_bits_be())
+            .collect::<Vec<_>>();
+        let digest_bits = sha256(cs.ns(|| "message digest"), &message_bits)?;
+        let e = NonNativeFieldGadget::<P::ScalarField, ConstraintF>::from_bits(
+            cs.ns(|| "digest to scalar"),
+            &digest_bits[..P::ScalarField::size_in_bits().min(digest_bits.len())],
+        )?;
+
+        Self::enforce_digest_verdict(cs.ns(|| "digest verdict"), public_key, &e, signature)
+    }
+
+    fn conditionally_enforce_signature_verification<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let is_verified = Self::enforce_signature_verdict(
+            cs.ns(|| "is sig verified"),
+            parameters,
+            public_key,
+            message,
+            signature,
+        )?;
+        is_verified.conditional_enforce_equal(
+            cs.ns(|| "conditional verify signature"),
+            &Boolean::constant(true),
+            should_enforce,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        curves::{secp256k1::Secp256k1Parameters, short_weierstrass_jacobian::GroupProjective},
+        fields::{secp256k1::Fr, tweedle::Fr as TweedleFr},
+        Field, Group, PrimeField, ProjectiveCurve, UniformRand,
+    };
+    use primitives::signature::{
+        ecdsa::{Ecdsa, EcdsaSig},
+        SignatureScheme,
+    };
+    use r1cs_core::{
+        ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
+    };
+    use r1cs_std::{fields::nonnative::nonnative_field_gadget::NonNativeFieldGadget, prelude::*};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    use crate::signature::{
+        ecdsa::EcdsaVerificationGadget, fixed_base_exceptional_scalars, SigVerifyGadget,
+    };
+
+    type EcdsaSecp256k1 = Ecdsa<Secp256k1Parameters>;
+    type EcdsaSecp256k1Gadget = EcdsaVerificationGadget<
+        Secp256k1Parameters,
+        TweedleFr,
+        <Secp256k1Parameters as algebra::ModelParameters>::BaseField,
+    >;
+
+    #[test]
+    fn ecdsa_secp256k1_gadget_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an ECDSA signature!".as_bytes();
+
+        let (pk, sk) = EcdsaSecp256k1::keygen(&(), rng).unwrap();
+        let sig = EcdsaSecp256k1::sign(&(), &sk, message, rng).unwrap();
+
+        for (test_message, expected_verdict) in [
+            (message, true),
+            ("Hi, I am a forged message".as_bytes(), false),
+        ]
+        .iter()
+        {
+            let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+
+            let pk_g = <EcdsaSecp256k1Gadget as SigVerifyGadget<EcdsaSecp256k1, TweedleFr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc pk"),
+                || Ok(pk),
+            )
+            .unwrap();
+            let sig_g = <EcdsaSecp256k1Gadget as SigVerifyGadget<EcdsaSecp256k1, TweedleFr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc sig"),
+                || Ok(sig),
+            )
+            .unwrap();
+            let message_g = UInt8::alloc_vec(cs.ns(|| "alloc message"), test_message).unwrap();
+
+            let verdict = EcdsaSecp256k1Gadget::enforce_signature_verdict(
+                cs.ns(|| "sig verdict"),
+                &(),
+                &pk_g,
+                &message_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert_eq!(verdict.get_value().unwrap(), *expected_verdict);
+            assert!(cs.is_satisfied());
+
+            EcdsaSecp256k1Gadget::enforce_signature_verification(
+                cs.ns(|| "sig verification"),
+                &(),
+                &pk_g,
+                &message_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert_eq!(cs.is_satisfied(), *expected_verdict);
+        }
+    }
+
+    fn check_verdict(
+        pk: <EcdsaSecp256k1 as SignatureScheme>::PublicKey,
+        sig: EcdsaSig<Secp256k1Parameters>,
+        message: &[u8],
+    ) -> bool {
+        let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+
+        let pk_g = <EcdsaSecp256k1Gadget as SigVerifyGadget<EcdsaSecp256k1, TweedleFr>>::PublicKeyGadget::alloc(
+            cs.ns(|| "alloc pk"),
+            || Ok(pk),
+        )
+        .unwrap();
+        let sig_g = <EcdsaSecp256k1Gadget as SigVerifyGadget<EcdsaSecp256k1, TweedleFr>>::SignatureGadget::alloc(
+            cs.ns(|| "alloc sig"),
+            || Ok(sig),
+        )
+        .unwrap();
+        let message_g = UInt8::alloc_vec(cs.ns(|| "alloc message"), message).unwrap();
+
+        let verdict = EcdsaSecp256k1Gadget::enforce_signature_verdict(
+            cs.ns(|| "sig verdict"),
+            &(),
+            &pk_g,
+            &message_g,
+            &sig_g,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+
+        let verdict = verdict.get_value().unwrap();
+        assert_eq!(
+            verdict,
+            EcdsaSecp256k1::verify(&(), &pk, message, &sig).unwrap()
+        );
+        verdict
+    }
+
+    #[test]
+    fn ecdsa_secp256k1_gadget_zero_sig_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an ECDSA signature!".as_bytes();
+
+        let (pk, sk) = EcdsaSecp256k1::keygen(&(), rng).unwrap();
+        let sig = EcdsaSecp256k1::sign(&(), &sk, message, rng).unwrap();
+
+        // Signatures with r = 0 or s = 0 must be rejected
+        for (r, s) in [(Fr::zero(), sig.s), (sig.r, Fr::zero())].iter() {
+            assert!(!check_verdict(pk, EcdsaSig { r: *r, s: *s }, message));
+        }
+    }
+
+    #[test]
+    fn ecdsa_secp256k1_gadget_exceptional_addition_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an ECDSA signature!".as_bytes();
+        let e = EcdsaSecp256k1::digest_to_scalar(message);
+        let g = GroupProjective::<Secp256k1Parameters>::prime_subgroup_generator();
+
+        // u1 * G = u2 * pk, i.e. R = 2 * u1 * G: for a nonce k, choose sk := e/r and
+        // s := 2e/k, so that u1 = u2 * sk = k/2.
+        let k = Fr::rand(rng);
+        let r = EcdsaSecp256k1::x_to_scalar(&g.mul(&k));
+        let sk = e * &r.inverse().unwrap();
+        let s = e.double() * &k.inverse().unwrap();
+        assert!(check_verdict(g.mul(&sk), EcdsaSig { r, s }, message));
+
+        // u1 * G = -u2 * pk, i.e. R is trivial: choose sk := -e/r.
+        let r = Fr::rand(rng);
+        let s = Fr::rand(rng);
+        let sk = -(e * &r.inverse().unwrap());
+        assert!(!check_verdict(g.mul(&sk), EcdsaSig { r, s }, message));
+    }
+
+    #[test]
+    fn ecdsa_secp256k1_gadget_exceptional_scalars_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an ECDSA signature!".as_bytes();
+        let e = EcdsaSecp256k1::digest_to_scalar(message);
+        let g = GroupProjective::<Secp256k1Parameters>::prime_subgroup_generator();
+
+        // For a nonce k and r := x(k * G) mod n, choose s as a function of r so that u1 or
+        // u2 is exceptional. The signature is valid for sk := (k - u1)/u2, as then
+        // u1 * G + u2 * pk = k * G, and invalid for a random public key.
+        let check_exceptional_sig = |s_from_r: &dyn Fn(Fr) -> Fr, rng: &mut XorShiftRng| {
+            let k = Fr::rand(rng);
+            let r = EcdsaSecp256k1::x_to_scalar(&g.mul(&k));
+            let s = s_from_r(r);
+            let w = s.inverse().unwrap();
+            let (u1, u2) = (e * &w, r * &w);
+            let sk = (k - &u1) * &u2.inverse().unwrap();
+            assert!(check_verdict(g.mul(&sk), EcdsaSig { r, s }, message));
+
+            let (pk, _) = EcdsaSecp256k1::keygen(&(), rng).unwrap();
+            assert!(!check_verdict(pk, EcdsaSig { r, s }, message));
+        };
+
+        // u1 in the exceptional set of the fixed base scalar multiplication, i.e. s := e/u1
+        for u1 in fixed_base_exceptional_scalars::<Fr>(Fr::size_in_bits())
+            .into_iter()
+            .skip(1)
+        {
+            check_exceptional_sig(&|_| e * &u1.inverse().unwrap(), rng);
+        }
+
+        // u2 = -1, resp. u2 = -2, i.e. s := -r, resp. s := -r/2
+        check_exceptional_sig(&|r| -r, rng);
+        check_exceptional_sig(&|r| -(r * &Fr::one().double().inverse().unwrap()), rng);
+    }
+
+    #[test]
+    fn ecdsa_secp256k1_gadget_zero_digest_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let g = GroupProjective::<Secp256k1Parameters>::prime_subgroup_generator();
+
+        // e = 0 can't be obtained from a message, hence we test the verdict given the digest,
+        // which for u1 = 0 amounts to x(u2 * pk) mod n == r. For a nonce k and
+        // r := x(k * G) mod n, the signature is valid for sk := k/u2.
+        let k = Fr::rand(rng);
+        let r = EcdsaSecp256k1::x_to_scalar(&g.mul(&k));
+        let s = Fr::rand(rng);
+        let u2 = r * &s.inverse().unwrap();
+        let (random_pk, _) = EcdsaSecp256k1::keygen(&(), rng).unwrap();
+
+        for (pk, expected_verdict) in [
+            (g.mul(&(k * &u2.inverse().unwrap())), true),
+            (random_pk, false),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                EcdsaSecp256k1::x_to_scalar(&pk.mul(&u2)) == r,
+                *expected_verdict
+            );
+
+            let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+            let pk_g = <EcdsaSecp256k1Gadget as SigVerifyGadget<EcdsaSecp256k1, TweedleFr>>::PublicKeyGadget::alloc(
+                cs.ns(|| "alloc pk"),
+                || Ok(*pk),
+            )
+            .unwrap();
+            let sig_g = <EcdsaSecp256k1Gadget as SigVerifyGadget<EcdsaSecp256k1, TweedleFr>>::SignatureGadget::alloc(
+                cs.ns(|| "alloc sig"),
+                || Ok(EcdsaSig { r, s }),
+            )
+            .unwrap();
+            let e_g =
+                NonNativeFieldGadget::<Fr, TweedleFr>::alloc(
+                    cs.ns(|| "alloc e"),
+                    || Ok(Fr::zero()),
+                )
+                .unwrap();
+
+            let verdict = EcdsaSecp256k1Gadget::enforce_digest_verdict(
+                cs.ns(|| "digest verdict"),
+                &pk_g,
+                &e_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert!(cs.is_satisfied());
+            assert_eq!(verdict.get_value().unwrap(), *expected_verdict);
+        }
+    }
+}
@@ -0,0 +1,947 @@
+//! Gadget for the verification of Ed25519 signatures, simulating the arithmetics of
+//! edwards25519 by non-native field gadgets. Points are allocated in their twisted Edwards
+//! form, from which their RFC 8032 encodings are computed, and mapped to the birationally
+//! equivalent short Weierstrass model for the scalar multiplications.
+use algebra::{
+    curves::ed25519::{Ed25519Parameters, SWEd25519Projective, TEEd25519Affine},
+    fields::ed25519::{fq::Fq, fr::Fr},
+    AffineCurve, Field, Group, MontgomeryModelParameters, PrimeField, ProjectiveCurve,
+    TEModelParameters, ToBits,
+};
+use primitives::signature::ed25519::{Ed25519, Ed25519Sig};
+use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
+use r1cs_std::{
+    fields::nonnative::nonnative_field_gadget::NonNativeFieldGadget,
+    groups::nonnative::GroupAffineNonNativeGadget, prelude::*,
+};
+
+use crate::{
+    prf::sha512::sha512,
+    signature::{
+        bits_are_eq_constant, conditionally_select_bits, fixed_base_exceptional_scalars,
+        SigVerifyGadget,
+    },
+};
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+/// A point of edwards25519 in affine twisted Edwards coordinates.
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "ConstraintF: PrimeField"),
+    Clone(bound = "ConstraintF: PrimeField")
+)]
+pub struct Ed25519PointGadget<ConstraintF: PrimeField> {
+    pub x: NonNativeFieldGadget<Fq, ConstraintF>,
+    pub y: NonNativeFieldGadget<Fq, ConstraintF>,
+}
+
+impl<ConstraintF: PrimeField> Ed25519PointGadget<ConstraintF> {
+    pub fn get_value(&self) -> Option<TEEd25519Affine> {
+        match (self.x.get_value(), self.y.get_value()) {
+            (Some(x), Some(y)) => Some(TEEd25519Affine::new(x, y)),
+            _ => None,
+        }
+    }
+
+    /// Complete addition in twisted Edwards coordinates, with a = -1:
+    ///     (x3, y3) = ((x1 * y2 + y1 * x2)/(1 + d * x1 * x2 * y1 * y2),
+    ///                 (y1 * y2 + x1 * x2)/(1 - d * x1 * x2 * y1 * y2)).
+    /// As d is not a square, the denominators never vanish for points on the curve.
+    pub fn add<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let x1_y2 = self.x.mul(cs.ns(|| "x1 * y2"), &other.y)?;
+        let y1_x2 = self.y.mul(cs.ns(|| "y1 * x2"), &other.x)?;
+        let x1_x2 = self.x.mul(cs.ns(|| "x1 * x2"), &other.x)?;
+        let y1_y2 = self.y.mul(cs.ns(|| "y1 * y2"), &other.y)?;
+        let d_x1_x2_y1_y2 = x1_x2
+            .mul(cs.ns(|| "x1 * x2 * y1 * y2"), &y1_y2)?
+            .mul_by_constant(
+                cs.ns(|| "d * x1 * x2 * y1 * y2"),
+                &<Ed25519Parameters as TEModelParameters>::COEFF_D,
+            )?;
+
+        let x_num = x1_y2.add(cs.ns(|| "x1 * y2 + y1 * x2"), &y1_x2)?;
+        let x_den =
+            d_x1_x2_y1_y2.add_constant(cs.ns(|| "1 + d * x1 * x2 * y1 * y2"), &Fq::one())?;
+        let x = x_num.mul(
+            cs.ns(|| "x3"),
+            &x_den.inverse(cs.ns(|| "(1 + d * x1 * x2 * y1 * y2)^-1"))?,
+        )?;
+
+        let y_num = y1_y2.add(cs.ns(|| "y1 * y2 + x1 * x2"), &x1_x2)?;
+        let y_den = d_x1_x2_y1_y2
+            .negate(cs.ns(|| "-d * x1 * x2 * y1 * y2"))?
+            .add_constant(cs.ns(|| "1 - d * x1 * x2 * y1 * y2"), &Fq::one())?;
+        let y = y_num.mul(
+            cs.ns(|| "y3"),
+            &y_den.inverse(cs.ns(|| "(1 - d * x1 * x2 * y1 * y2)^-1"))?,
+        )?;
+
+        Ok(Self { x, y })
+    }
+
+    pub fn double<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Self, SynthesisError> {
+        self.add(cs, self)
+    }
+
+    pub fn negate<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Self, SynthesisError> {
+        Ok(Self {
+            x: self.x.negate(cs.ns(|| "-x"))?,
+            y: self.y.clone(),
+        })
+    }
+
+    /// Return a Boolean which is true iff the point is the identity (0, 1).
+    pub fn is_zero<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Boolean, SynthesisError> {
+        let zero = NonNativeFieldGadget::<Fq, ConstraintF>::zero(cs.ns(|| "zero"))?;
+        let one = NonNativeFieldGadget::<Fq, ConstraintF>::one(cs.ns(|| "one"))?;
+        let x_is_zero = self.x.is_eq(cs.ns(|| "x == 0"), &zero)?;
+        let y_is_one = self.y.is_eq(cs.ns(|| "y == 1"), &one)?;
+        Boolean::and(cs.ns(|| "x == 0 and y == 1"), &x_is_zero, &y_is_one)
+    }
+
+    /// The RFC 8032 encoding of the point, i.e. the little endian encoding of y, with the
+    /// most significant bit set to the least significant bit of x.
+    pub fn encode<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Vec<UInt8>, SynthesisError> {
+        let x_bits = self.x.to_bits_strict(cs.ns(|| "x to bits"))?;
+        let mut encoding_bits = self.y.to_bits_strict(cs.ns(|| "y to bits"))?;
+        encoding_bits.reverse();
+        encoding_bits.push(*x_bits.last().unwrap());
+
+        Ok(encoding_bits.chunks(8).map(UInt8::from_bits_le).collect())
+    }
+
+    /// Map the point to the short Weierstrass model of the curve, via the Montgomery one:
+    ///     (u, v) = ((1 + y)/(1 - y), u/x),
+    ///     (x', y') = (u/B + A/3B, v/B).
+    /// The map is not defined for the points with x = 0, i.e. the identity and the point of
+    /// order 2: the constraints are not satisfiable for them.
+    pub fn to_sw<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<GroupAffineNonNativeGadget<Ed25519Parameters, ConstraintF, Fq>, SynthesisError>
+    {
+        let a = <Ed25519Parameters as MontgomeryModelParameters>::COEFF_A;
+        let b_inv = <Ed25519Parameters as MontgomeryModelParameters>::COEFF_B
+            .inverse()
+            .unwrap();
+        let a_div_3b = a * &b_inv * &Fq::from(3u8).inverse().unwrap();
+
+        let one_plus_y = self.y.add_constant(cs.ns(|| "1 + y"), &Fq::one())?;
+        let one_minus_y = self
+            .y
+            .negate(cs.ns(|| "-y"))?
+            .add_constant(cs.ns(|| "1 - y"), &Fq::one())?;
+        let one_minus_y_inv = one_minus_y.inverse(cs.ns(|| "(1 - y)^-1"))?;
+        let u = one_plus_y.mul(cs.ns(|| "u := (1 + y) * (1 - y)^-1"), &one_minus_y_inv)?;
+        let x_inv = self.x.inverse(cs.ns(|| "x^-1"))?;
+        let v = u.mul(cs.ns(|| "v := u * x^-1"), &x_inv)?;
+
+        let sw_x = u
+            .mul_by_constant(cs.ns(|| "u * B^-1"), &b_inv)?
+            .add_constant(cs.ns(|| "u * B^-1 + A * (3B)^-1"), &a_div_3b)?;
+        let sw_y = v.mul_by_constant(cs.ns(|| "v * B^-1"), &b_inv)?;
+
+        Ok(GroupAffineNonNativeGadget::new(
+            sw_x,
+            sw_y,
+            Boolean::constant(false),
+        ))
+    }
+}
+
+impl<ConstraintF: PrimeField> CondSelectGadget<ConstraintF> for Ed25519PointGadget<ConstraintF> {
+    fn conditionally_select<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        cond: &Boolean,
+        first: &Self,
+        second: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let x = NonNativeFieldGadget::conditionally_select(
+            cs.ns(|| "select x"),
+            cond,
+            &first.x,
+            &second.x,
+        )?;
+        let y = NonNativeFieldGadget::conditionally_select(
+            cs.ns(|| "select y"),
+            cond,
+            &first.y,
+            &second.y,
+        )?;
+        Ok(Self { x, y })
+    }
+
+    fn cost() -> usize {
+        2 * <NonNativeFieldGadget<Fq, ConstraintF> as CondSelectGadget<ConstraintF>>::cost()
+    }
+}
+
+impl<ConstraintF: PrimeField> ConstantGadget<TEEd25519Affine, ConstraintF>
+    for Ed25519PointGadget<ConstraintF>
+{
+    fn from_value<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        value: &TEEd25519Affine,
+    ) -> Self {
+        let x = NonNativeFieldGadget::from_value(cs.ns(|| "hardcode x"), &value.x);
+        let y = NonNativeFieldGadget::from_value(cs.ns(|| "hardcode y"), &value.y);
+        Self { x, y }
+    }
+
+    fn get_constant(&self) -> TEEd25519Affine {
+        TEEd25519Affine::new(self.x.get_constant(), self.y.get_constant())
+    }
+}
+
+impl<ConstraintF: PrimeField> AllocGadget<TEEd25519Affine, ConstraintF>
+    for Ed25519PointGadget<ConstraintF>
+{
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TEEd25519Affine>,
+    {
+        let point = Self::alloc_without_check(cs.ns(|| "alloc without check"), f)?;
+
+        // Check that a * x^2 + y^2 = 1 + d * x^2 * y^2, with a = -1
+        let x2 = point.x.mul(cs.ns(|| "x^2"), &point.x)?;
+        let y2 = point.y.mul(cs.ns(|| "y^2"), &point.y)?;
+        let lhs = y2.sub(cs.ns(|| "y^2 - x^2"), &x2)?;
+        let rhs = x2
+            .mul(cs.ns(|| "x^2 * y^2"), &y2)?
+            .mul_by_constant(
+                cs.ns(|| "d * x^2 * y^2"),
+                &<Ed25519Parameters as TEModelParameters>::COEFF_D,
+            )?
+            .add_constant(cs.ns(|| "1 + d * x^2 * y^2"), &Fq::one())?;
+        lhs.enforce_equal(cs.ns(|| "on curve check"), &rhs)?;
+
+        Ok(point)
+    }
+
+    fn alloc_without_check<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TEEd25519Affine>,
+    {
+        let (x, y) = match f() {
+            Ok(point) => {
+                let point = point.borrow();
+                (Ok(point.x), Ok(point.y))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let x = NonNativeFieldGadget::alloc(cs.ns(|| "alloc x"), || x)?;
+        let y = NonNativeFieldGadget::alloc(cs.ns(|| "alloc y"), || y)?;
+        Ok(Self { x, y })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<TEEd25519Affine>,
+    {
+        let (x, y) = match f() {
+            Ok(point) => {
+                let point = point.borrow();
+                (Ok(point.x), Ok(point.y))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let x = NonNativeFieldGadget::alloc_input(cs.ns(|| "alloc x"), || x)?;
+        let y = NonNativeFieldGadget::alloc_input(cs.ns(|| "alloc y"), || y)?;
+        Ok(Self { x, y })
+    }
+}
+
+#[derive(Derivative)]
+#[derivative(
+    Debug(bound = "ConstraintF: PrimeField"),
+    Clone(bound = "ConstraintF: PrimeField")
+)]
+pub struct Ed25519SigGadget<ConstraintF: PrimeField> {
+    pub r: Ed25519PointGadget<ConstraintF>,
+    pub s: NonNativeFieldGadget<Fr, ConstraintF>,
+}
+
+impl<ConstraintF: PrimeField> AllocGadget<Ed25519Sig, ConstraintF>
+    for Ed25519SigGadget<ConstraintF>
+{
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Ed25519Sig>,
+    {
+        let (r, s) = match f() {
+            Ok(sig) => {
+                let sig = sig.borrow();
+                (Ok(sig.r), Ok(sig.s))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let r = Ed25519PointGadget::alloc(cs.ns(|| "alloc r"), || r)?;
+        let s = NonNativeFieldGadget::alloc(cs.ns(|| "alloc s"), || s)?;
+        Ok(Self { r, s })
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<Ed25519Sig>,
+    {
+        let (r, s) = match f() {
+            Ok(sig) => {
+                let sig = sig.borrow();
+                (Ok(sig.r), Ok(sig.s))
+            }
+            _ => (
+                Err(SynthesisError::AssignmentMissing),
+                Err(SynthesisError::AssignmentMissing),
+            ),
+        };
+
+        let r = Ed25519PointGadget::alloc_input(cs.ns(|| "alloc r"), || r)?;
+        let s = NonNativeFieldGadget::alloc_input(cs.ns(|| "alloc s"), || s)?;
+        Ok(Self { r, s })
+    }
+}
+
+pub struct Ed25519VerificationGadget<ConstraintF: PrimeField> {
+    _field: PhantomData<ConstraintF>,
+}
+
+impl<ConstraintF: PrimeField> Ed25519VerificationGadget<ConstraintF> {
+    /// Enforce the verdict on `signature` given the challenge `k`.
+    fn enforce_challenge_verdict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        public_key: &Ed25519PointGadget<ConstraintF>,
+        k: &NonNativeFieldGadget<Fr, ConstraintF>,
+        signature: &Ed25519SigGadget<ConstraintF>,
+    ) -> Result<Boolean, SynthesisError> {
+        let zero = NonNativeFieldGadget::<Fq, ConstraintF>::zero(cs.ns(|| "zero"))?;
+        let b = Ed25519PointGadget::from_value(
+            cs.ns(|| "hardcode generator"),
+            &TEEd25519Affine::prime_subgroup_generator(),
+        );
+
+        // A != O and [L]A = O. The points of edwards25519 form a group isomorphic to
+        // Z/8 x Z/L, hence A = [8]A' + T for some point A' and a unique T such that [8]T = O:
+        // A is in the prime order subgroup iff T = O. As the twisted Edwards arithmetics is
+        // complete, such A' and T exist for any A and the circuit stays satisfiable.
+        let a_div_8 = Ed25519PointGadget::alloc(cs.ns(|| "alloc A' := [8^-2 mod L][8]A"), || {
+            public_key
+                .get_value()
+                .map(|a| {
+                    a.scale_by_cofactor()
+                        .into_affine()
+                        .mul_by_cofactor_inv()
+                        .into_affine()
+                        .mul_by_cofactor_inv()
+                        .into_affine()
+                })
+                .ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let mut eight_a_div_8 = a_div_8;
+        for i in 0..3 {
+            eight_a_div_8 = eight_a_div_8.double(cs.ns(|| format!("A' double {}", i)))?;
+        }
+        let torsion = public_key.add(
+            cs.ns(|| "T := A - [8]A'"),
+            &eight_a_div_8.negate(cs.ns(|| "-[8]A'"))?,
+        )?;
+        let mut eight_torsion = torsion.clone();
+        for i in 0..3 {
+            eight_torsion = eight_torsion.double(cs.ns(|| format!("T double {}", i)))?;
+        }
+        eight_torsion
+            .is_zero(cs.ns(|| "[8]T == O"))?
+            .enforce_equal(cs.ns(|| "enforce [8]T == O"), &Boolean::constant(true))?;
+
+        // If T = O, then A is of prime order and A = O iff x(A) = 0. An invalid A is replaced
+        // by the generator to keep the circuit satisfiable.
+        let a_is_valid = Boolean::and(
+            cs.ns(|| "T == O and x(A) != 0"),
+            &torsion.is_zero(cs.ns(|| "T == O"))?,
+            &public_key.x.is_eq(cs.ns(|| "x(A) == 0"), &zero)?.not(),
+        )?;
+        let a = Ed25519PointGadget::conditionally_select(
+            cs.ns(|| "A or generator"),
+            &a_is_valid,
+            public_key,
+            &b,
+        )?
+        .to_sw(cs.ns(|| "A to SW"))?;
+
+        // [8]R is in the prime order subgroup, hence it is trivial, i.e. R is of small order,
+        // iff x([8]R) = 0. In this case [8]R is replaced by the generator.
+        let mut r = signature.r.clone();
+        for i in 0..3 {
+            r = r.double(cs.ns(|| format!("R double {}", i)))?;
+        }
+        let r_is_small_order = r.x.is_eq(cs.ns(|| "x([8]R) == 0"), &zero)?;
+        let r = Ed25519PointGadget::conditionally_select(
+            cs.ns(|| "[8]R or generator"),
+            &r_is_small_order,
+            &b,
+            &r,
+        )?
+        .to_sw(cs.ns(|| "[8]R to SW"))?;
+
+        // [8S mod L]B. If 8S mod L is in the exceptional set of the fixed base scalar
+        // multiplication, the latter is performed with a safe substitute and the result is
+        // replaced by the hardcoded [8S mod L]B. For S = 0 the verdict is false.
+        let eight = Fr::from(8u8);
+        let s_bits = signature
+            .s
+            .mul_by_constant(cs.ns(|| "8S"), &eight)?
+            .to_bits_strict(cs.ns(|| "8S to bits"))?;
+        let s_exceptional_scalars = fixed_base_exceptional_scalars::<Fr>(s_bits.len());
+        let s_substitute = (1u64..)
+            .map(Fr::from)
+            .find(|scalar| !s_exceptional_scalars.contains(scalar))
+            .unwrap();
+        let s_is_exceptional_scalar = s_exceptional_scalars
+            .iter()
+            .enumerate()
+            .map(|(i, scalar)| {
+                bits_are_eq_constant(
+                    cs.ns(|| format!("8S == exceptional scalar {}", i)),
+                    &s_bits,
+                    scalar,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let s_is_zero = s_is_exceptional_scalar[0];
+        let s_is_exceptional = Boolean::kary_and(
+            cs.ns(|| "8S is not exceptional"),
+            &s_is_exceptional_scalar
+                .iter()
+                .map(|b| b.not())
+                .collect::<Vec<_>>(),
+        )?
+        .not();
+        let mut s_bits = conditionally_select_bits(
+            cs.ns(|| "8S or substitute"),
+            &s_is_exceptional,
+            &s_substitute.write_bits(),
+            &s_bits,
+        )?;
+        s_bits.reverse();
+
+        let mut s_times_b =
+            GroupAffineNonNativeGadget::<Ed25519Parameters, ConstraintF, Fq>::mul_bits_fixed_base(
+                &SWEd25519Projective::prime_subgroup_generator(),
+                cs.ns(|| "[8S]B"),
+                &s_bits,
+            )?;
+        for (i, (scalar, is_scalar)) in s_exceptional_scalars
+            .iter()
+            .zip(s_is_exceptional_scalar.iter())
+            .enumerate()
+            .skip(1)
+        {
+            let scalar_times_b = GroupAffineNonNativeGadget::from_value(
+                cs.ns(|| format!("hardcode exceptional scalar {} * B", i)),
+                &SWEd25519Projective::prime_subgroup_generator().mul(scalar),
+            );
+            s_times_b = GroupAffineNonNativeGadget::conditionally_select(
+                cs.ns(|| format!("[8S]B or exceptional scalar {} * B", i)),
+                is_scalar,
+                &scalar_times_b,
+                &s_times_b,
+            )?;
+        }
+
+        // [8k mod L]A. The variable base scalar multiplication is performed with 8k = 1 if
+        // 8k mod L is in its exceptional set, i.e. 0, -1 or -2, and the result is then
+        // corrected to O (handled below), -A, resp. -2A.
+        let k_bits = k
+            .mul_by_constant(cs.ns(|| "8k"), &eight)?
+            .to_bits_strict(cs.ns(|| "8k to bits"))?;
+        let minus_one = -Fr::one();
+        let minus_two = minus_one.double();
+        let k_is_zero = bits_are_eq_constant(cs.ns(|| "8k == 0"), &k_bits, &Fr::zero())?;
+        let k_is_minus_one = bits_are_eq_constant(cs.ns(|| "8k == -1"), &k_bits, &minus_one)?;
+        let k_is_minus_two = bits_are_eq_constant(cs.ns(|| "8k == -2"), &k_bits, &minus_two)?;
+        let k_is_exceptional = Boolean::kary_and(
+            cs.ns(|| "8k is not exceptional"),
+            &[k_is_zero.not(), k_is_minus_one.not(), k_is_minus_two.not()],
+        )?
+        .not();
+        let mut k_bits = conditionally_select_bits(
+            cs.ns(|| "8k or 1"),
+            &k_is_exceptional,
+            &Fr::one().write_bits(),
+            &k_bits,
+        )?;
+        k_bits.reverse();
+
+        let k_times_a = a.mul_bits(cs.ns(|| "[8k]A"), k_bits.iter())?;
+        let minus_a = a.negate(cs.ns(|| "-A"))?;
+        let mut minus_two_a = minus_a.clone();
+        minus_two_a.double_in_place(cs.ns(|| "-2A"))?;
+        let k_times_a = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "[8k]A or -A"),
+            &k_is_minus_one,
+            &minus_a,
+            &k_times_a,
+        )?;
+        let k_times_a = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "[8k]A or -2A"),
+            &k_is_minus_two,
+            &minus_two_a,
+            &k_times_a,
+        )?;
+
+        // [8]R + [8k]A. If x([8]R) == x([8k]A), i.e. [8]R = +-[8k]A, the verdict is false
+        // and we add [2][8]R instead, whose x-coordinate differs from the one of [8]R as the
+        // latter has prime order. If 8k = 0 the sum is [8]R.
+        let same_x = r.x.is_eq(cs.ns(|| "x([8]R) == x([8k]A)"), &k_times_a.x)?;
+        let mut two_r = r.clone();
+        two_r.double_in_place(cs.ns(|| "[2][8]R"))?;
+        let addend = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "[8k]A or [2][8]R"),
+            &same_x,
+            &two_r,
+            &k_times_a,
+        )?;
+        let sum = r.add(cs.ns(|| "[8]R + [8k]A"), &addend)?;
+        let rhs = GroupAffineNonNativeGadget::conditionally_select(
+            cs.ns(|| "[8]R + [8k]A or [8]R"),
+            &k_is_zero,
+            &r,
+            &sum,
+        )?;
+        let is_exceptional_sum =
+            Boolean::and(cs.ns(|| "[8]R == +-[8k]A"), &same_x, &k_is_zero.not())?;
+
+        let is_verified = s_times_b.is_eq(cs.ns(|| "[8S]B == [8]R + [8k]A"), &rhs)?;
+        Boolean::kary_and(
+            cs.ns(|| "is verified and no exceptional case"),
+            &[
+                is_verified,
+                a_is_valid,
+                r_is_small_order.not(),
+                s_is_zero.not(),
+                is_exceptional_sum.not(),
+            ],
+        )
+    }
+}
+
+// As the native verification, the gadget checks the cofactored equation
+// [8][S]B = [8]R + [8][k]A, here computed as [8S mod L]B = [8]R + [8k mod L]A, and rejects
+// public keys which are trivial or not in the prime order subgroup (the ones derived by
+// `Ed25519::keygen` are valid).
+//
+// The point arithmetics over non-native fields being incomplete, the exceptional inputs of
+// the scalar multiplications are replaced by safe substitutes, and the circuit is always
+// satisfiable. Differently from the native verification, the verdict is false if R is of
+// small order, if [8]R = +-[8k]A or if S = 0 mod L, even if the cofactored equation holds.
+// This happens with negligible probability for honestly generated signatures.
+impl<ConstraintF: PrimeField> SigVerifyGadget<Ed25519, ConstraintF>
+    for Ed25519VerificationGadget<ConstraintF>
+{
+    type ParametersGadget = ();
+    type PublicKeyGadget = Ed25519PointGadget<ConstraintF>;
+    type SignatureGadget = Ed25519SigGadget<ConstraintF>;
+
+    fn enforce_signature_verdict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        _parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError> {
+        // k := SHA-512(R || A || message) mod L
+        let mut hash_input = signature.r.encode(cs.ns(|| "encode R"))?;
+        hash_input.extend(public_key.encode(cs.ns(|| "encode A"))?);
+        hash_input.extend_from_slice(message);
+        let hash_input_bits = hash_input
+            .iter()
+            .flat_map(|byte| byte.to_bits_be())
+            .collect::<Vec<_>>();
+        let digest_bits = sha512(cs.ns(|| "SHA-512(R || A || message)"), &hash_input_bits)?;
+
+        // The digest is the little endian encoding of a 512 bits integer, which is reduced by
+        // packing it into chunks of 248 bits, starting from the least significant one.
+        let digest_bits_be = digest_bits
+            .chunks(8)
+            .rev()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut k = NonNativeFieldGadget::<Fr, ConstraintF>::zero(cs.ns(|| "zero"))?;
+        let mut chunk_base = Fr::one();
+        for (i, chunk) in digest_bits_be.rchunks(248).enumerate() {
+            let chunk = NonNativeFieldGadget::<Fr, ConstraintF>::from_bits(
+                cs.ns(|| format!("pack digest chunk {}", i)),
+                chunk,
+            )?;
+            let chunk =
+                chunk.mul_by_constant(cs.ns(|| format!("shift chunk {}", i)), &chunk_base)?;
+            k = k.add(cs.ns(|| format!("add chunk {}", i)), &chunk)?;
+            chunk_base *= &Fr::from(2u8).pow([248u64]);
+        }
+
+        Self::enforce_challenge_verdict(cs.ns(|| "challenge verdict"), public_key, &k, signature)
+    }
+
+    fn conditionally_enforce_signature_verification<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let is_verified = Self::enforce_signature_verdict(
+            cs.ns(|| "is sig verified"),
+            parameters,
+            public_key,
+            message,
+            signature,
+        )?;
+        is_verified.conditional_enforce_equal(
+            cs.ns(|| "conditional verify signature"),
+            &Boolean::constant(true),
+            should_enforce,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algebra::{
+        curves::ed25519::{SWEd25519Projective, TEEd25519Affine},
+        fields::{
+            ed25519::{fq::Fq, fr::Fr},
+            tweedle::Fr as TweedleFr,
+        },
+        AffineCurve, Field, FpParameters, PrimeField, ProjectiveCurve, UniformRand,
+    };
+    use primitives::signature::{
+        ed25519::{Ed25519, Ed25519Sig},
+        SignatureScheme,
+    };
+    use r1cs_core::{
+        ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
+    };
+    use r1cs_std::{fields::nonnative::nonnative_field_gadget::NonNativeFieldGadget, prelude::*};
+    use rand::{Rng, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    use crate::signature::{
+        ed25519::{Ed25519PointGadget, Ed25519SigGadget, Ed25519VerificationGadget},
+        fixed_base_exceptional_scalars, SigVerifyGadget,
+    };
+
+    type Ed25519Gadget = Ed25519VerificationGadget<TweedleFr>;
+
+    #[test]
+    fn ed25519_point_gadget_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+
+        // The generator is mapped to the generator of the short Weierstrass model
+        let generator = Ed25519PointGadget::alloc(cs.ns(|| "alloc generator"), || {
+            Ok(TEEd25519Affine::prime_subgroup_generator())
+        })
+        .unwrap();
+        let sw_generator = generator.to_sw(cs.ns(|| "generator to SW")).unwrap();
+        assert_eq!(
+            sw_generator.get_value().unwrap(),
+            SWEd25519Projective::prime_subgroup_generator()
+        );
+
+        // Encoding is consistent with the native one
+        let (pk, _) = Ed25519::keygen(&(), rng).unwrap();
+        let pk_g = Ed25519PointGadget::alloc(cs.ns(|| "alloc pk"), || Ok(pk)).unwrap();
+        let encoding = pk_g.encode(cs.ns(|| "encode pk")).unwrap();
+        assert_eq!(
+            encoding
+                .iter()
+                .map(|byte| byte.get_value().unwrap())
+                .collect::<Vec<_>>(),
+            Ed25519::encode_point(&pk).to_vec()
+        );
+        assert!(cs.is_satisfied());
+
+        // Points not on the curve are rejected
+        let invalid_point = TEEd25519Affine::new(pk.x.double(), pk.y);
+        Ed25519PointGadget::alloc(cs.ns(|| "alloc invalid point"), || Ok(invalid_point)).unwrap();
+        assert!(!cs.is_satisfied());
+    }
+
+    #[test]
+    fn ed25519_gadget_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an Ed25519 signature!".as_bytes();
+
+        let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+        let sig = Ed25519::sign(&(), &sk, message, rng).unwrap();
+
+        for (test_message, expected_verdict) in [
+            (message, true),
+            ("Hi, I am a forged message".as_bytes(), false),
+        ]
+        .iter()
+        {
+            let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+
+            let pk_g = Ed25519PointGadget::alloc(cs.ns(|| "alloc pk"), || Ok(pk)).unwrap();
+            let sig_g = Ed25519SigGadget::alloc(cs.ns(|| "alloc sig"), || Ok(sig)).unwrap();
+            let message_g = UInt8::alloc_vec(cs.ns(|| "alloc message"), test_message).unwrap();
+
+            let verdict = Ed25519Gadget::enforce_signature_verdict(
+                cs.ns(|| "sig verdict"),
+                &(),
+                &pk_g,
+                &message_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert_eq!(verdict.get_value().unwrap(), *expected_verdict);
+            assert!(cs.is_satisfied());
+
+            Ed25519Gadget::enforce_signature_verification(
+                cs.ns(|| "sig verification"),
+                &(),
+                &pk_g,
+                &message_g,
+                &sig_g,
+            )
+            .unwrap();
+            assert_eq!(cs.is_satisfied(), *expected_verdict);
+        }
+    }
+
+    /// Return a point of edwards25519 of order 8.
+    fn small_order_point<R: Rng>(rng: &mut R) -> TEEd25519Affine {
+        loop {
+            if let Some(point) = TEEd25519Affine::get_point_from_x_and_parity(Fq::rand(rng), true) {
+                let torsion = point.mul(<Fr as PrimeField>::Params::MODULUS);
+                if !torsion.double().double().is_zero() {
+                    return torsion.into_affine();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ed25519_gadget_cofactored_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an Ed25519 signature!".as_bytes();
+
+        let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+        let torsion = small_order_point(rng);
+
+        // A signature whose R has a small order component is accepted by the cofactored
+        // verification
+        let (s, _) = Ed25519::expand_secret_key(&sk);
+        let r = Fr::rand(rng);
+        let r_point = (TEEd25519Affine::prime_subgroup_generator().mul(r)
+            + &torsion.into_projective())
+            .into_affine();
+        let k = Ed25519::challenge(&r_point, &pk, message);
+        let sig = Ed25519Sig {
+            r: r_point,
+            s: r + &(k * &s),
+        };
+        assert!(Ed25519::verify(&(), &pk, message, &sig).unwrap());
+
+        let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+        let pk_g = Ed25519PointGadget::alloc(cs.ns(|| "alloc pk"), || Ok(pk)).unwrap();
+        let sig_g = Ed25519SigGadget::alloc(cs.ns(|| "alloc sig"), || Ok(sig)).unwrap();
+        let message_g = UInt8::alloc_vec(cs.ns(|| "alloc message"), message).unwrap();
+        Ed25519Gadget::enforce_signature_verification(
+            cs.ns(|| "sig verification"),
+            &(),
+            &pk_g,
+            &message_g,
+            &sig_g,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+
+        // Public keys out of the prime order subgroup, or trivial, are rejected, as in the
+        // native verification
+        let sig = Ed25519::sign(&(), &sk, message, rng).unwrap();
+        for wrong_pk in [
+            (pk.into_projective() + &torsion.into_projective()).into_affine(),
+            torsion,
+            TEEd25519Affine::zero(),
+        ]
+        .iter()
+        {
+            assert!(!Ed25519::verify(&(), wrong_pk, message, &sig).unwrap());
+            assert!(!check_verdict(*wrong_pk, sig, message));
+        }
+    }
+
+    /// Return the verdict of the gadget on `sig`, checking that the circuit is satisfiable.
+    fn check_verdict(pk: TEEd25519Affine, sig: Ed25519Sig, message: &[u8]) -> bool {
+        let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+        let pk_g = Ed25519PointGadget::alloc(cs.ns(|| "alloc pk"), || Ok(pk)).unwrap();
+        let sig_g = Ed25519SigGadget::alloc(cs.ns(|| "alloc sig"), || Ok(sig)).unwrap();
+        let message_g = UInt8::alloc_vec(cs.ns(|| "alloc message"), message).unwrap();
+        let verdict = Ed25519Gadget::enforce_signature_verdict(
+            cs.ns(|| "sig verdict"),
+            &(),
+            &pk_g,
+            &message_g,
+            &sig_g,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+        verdict.get_value().unwrap()
+    }
+
+    /// Return the verdict of the gadget on `sig` given the challenge `k`, checking that the
+    /// circuit is satisfiable.
+    fn check_challenge_verdict(pk: TEEd25519Affine, sig: Ed25519Sig, k: Fr) -> bool {
+        let mut cs = ConstraintSystem::<TweedleFr>::new(SynthesisMode::Debug);
+        let pk_g = Ed25519PointGadget::alloc(cs.ns(|| "alloc pk"), || Ok(pk)).unwrap();
+        let sig_g = Ed25519SigGadget::alloc(cs.ns(|| "alloc sig"), || Ok(sig)).unwrap();
+        let k_g = NonNativeFieldGadget::alloc(cs.ns(|| "alloc k"), || Ok(k)).unwrap();
+        let verdict = Ed25519Gadget::enforce_challenge_verdict(
+            cs.ns(|| "challenge verdict"),
+            &pk_g,
+            &k_g,
+            &sig_g,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+        verdict.get_value().unwrap()
+    }
+
+    #[test]
+    fn ed25519_gadget_small_order_r_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let message = "Hi, I am an Ed25519 signature!".as_bytes();
+
+        let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+        let (s, _) = Ed25519::expand_secret_key(&sk);
+
+        // R of small order and S := k * s satisfy the cofactored equation: the native
+        // verification accepts the signature, while the gadget rejects it.
+        let r_point = small_order_point(rng);
+        let k = Ed25519::challenge(&r_point, &pk, message);
+        let sig = Ed25519Sig {
+            r: r_point,
+            s: k * &s,
+        };
+        assert!(Ed25519::verify(&(), &pk, message, &sig).unwrap());
+        assert!(!check_verdict(pk, sig, message));
+
+        // Both reject it if the cofactored equation does not hold
+        let sig = Ed25519Sig {
+            r: r_point,
+            s: Fr::rand(rng),
+        };
+        assert!(!Ed25519::verify(&(), &pk, message, &sig).unwrap());
+        assert!(!check_verdict(pk, sig, message));
+    }
+
+    #[test]
+    fn ed25519_gadget_exceptional_scalars_test() {
+        let rng = &mut XorShiftRng::seed_from_u64(1231275789u64);
+        let (pk, sk) = Ed25519::keygen(&(), rng).unwrap();
+        let (s, _) = Ed25519::expand_secret_key(&sk);
+        let b = TEEd25519Affine::prime_subgroup_generator();
+        let eight_inv = Fr::from(8u8).inverse().unwrap();
+
+        // For a challenge k and S, the signature with R := [S - k * s]B satisfies the
+        // cofactored equation, and it is accepted by the gadget unless one of the documented
+        // exceptions occurs
+        let sig_for = |k: Fr, s_sig: Fr| Ed25519Sig {
+            r: b.mul(s_sig - &(k * &s)).into_affine(),
+            s: s_sig,
+        };
+
+        // 8k in {0, -1, -2}
+        for eight_k in [Fr::zero(), -Fr::one(), -Fr::one().double()].iter() {
+            let k = *eight_k * &eight_inv;
+            assert!(check_challenge_verdict(pk, sig_for(k, Fr::rand(rng)), k));
+            assert!(!check_challenge_verdict(
+                pk,
+                sig_for(k + &Fr::one(), Fr::rand(rng)),
+                k
+            ));
+        }
+
+        // 8S in the exceptional set of the fixed base scalar multiplication: apart from
+        // S = 0, for which R = -[k]A, these are handled as the other scalars
+        let k = Fr::rand(rng);
+        for (i, eight_s) in fixed_base_exceptional_scalars::<Fr>(Fr::size_in_bits())
+            .into_iter()
+            .enumerate()
+        {
+            let s_sig = eight_s * &eight_inv;
+            assert_eq!(check_challenge_verdict(pk, sig_for(k, s_sig), k), i != 0);
+            assert!(!check_challenge_verdict(
+                pk,
+                sig_for(k + &Fr::one(), s_sig),
+                k
+            ));
+        }
+
+        // [8]R = +-[8k]A: the verdict is false, even if the cofactored equation holds, as it
+        // does for R = [k]A and S = 2k * s
+        let r_point = pk.mul(k).into_affine();
+        let sig = Ed25519Sig {
+            r: r_point,
+            s: k.double() * &s,
+        };
+        assert!(!check_challenge_verdict(pk, sig, k));
+        let sig = Ed25519Sig {
+            r: -r_point,
+            s: Fr::rand(rng),
+        };
+        assert!(!check_challenge_verdict(pk, sig, k));
+    }
+}
@@ -1,9 +1,13 @@
-use algebra::{Field, PrimeField};
+use algebra::{Field, PrimeField, ToBits};
 use primitives::signature::{FieldBasedSignatureScheme, SignatureScheme};
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 use r1cs_std::prelude::*;
 use r1cs_std::to_field_gadget_vec::ToConstraintFieldGadget;
 
+pub mod bls;
+pub mod ecdsa;
+#[cfg(feature = "ed25519")]
+pub mod ed25519;
 pub mod schnorr;
 
 pub trait SigRandomizePkGadget<S: SignatureScheme, ConstraintF: Field> {
@@ -69,3 +73,130 @@ pub trait FieldBasedSigGadget<S: FieldBasedSignatureScheme, ConstraintF: PrimeFi
         should_enforce: &Boolean,
     ) -> Result<(), SynthesisError>;
 }
+
+/// Gadget for the verification of a (non field based) signature scheme `S` over a byte
+/// `message`, e.g. ECDSA over a curve whose fields are simulated by non-native arithmetics.
+///
+/// The circuit is satisfiable for any input, and the verdict agrees with `S::verify`, with
+/// the exception of the inputs that an implementation explicitly documents as rejected.
+/// E.g. the Ed25519 gadget rejects signatures whose R is of small order, or such that
+/// [8]R = +-[8k]A, or with S = 0 mod L, which might be accepted by the native cofactored
+/// verification.
+pub trait SigVerifyGadget<S: SignatureScheme, ConstraintF: PrimeField> {
+    type ParametersGadget: AllocGadget<S::Parameters, ConstraintF> + Clone;
+    type PublicKeyGadget: AllocGadget<S::PublicKey, ConstraintF> + Clone;
+    type SignatureGadget: AllocGadget<S::Signature, ConstraintF> + Clone;
+
+    /// Enforce `signature` verification with `public_key` on `message`, returning a Boolean
+    /// enforced to be `true` if signature verification is successful, and `false` otherwise.
+    fn enforce_signature_verdict<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<Boolean, SynthesisError>;
+
+    /// Enforce `signature` verification with `public_key` on `message` to be successful.
+    fn enforce_signature_verification<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+    ) -> Result<(), SynthesisError> {
+        Self::conditionally_enforce_signature_verification(
+            cs,
+            parameters,
+            public_key,
+            message,
+            signature,
+            &Boolean::Constant(true),
+        )
+    }
+
+    /// Enforce or not enforce, according to `should_enforce` value, `signature` verification with
+    /// `public_key` on `message` to be successful.
+    fn conditionally_enforce_signature_verification<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        parameters: &Self::ParametersGadget,
+        public_key: &Self::PublicKeyGadget,
+        message: &[UInt8],
+        signature: &Self::SignatureGadget,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError>;
+}
+
+/// The scalars whose bits, of length `num_bits`, are in the exceptional set of
+/// `mul_bits_fixed_base()`, as described in `check_mul_bits_fixed_base_inputs()`. The
+/// conditions involving the two leading bits of the scalar are covered for all their values.
+/// The first element is always zero.
+pub(crate) fn fixed_base_exceptional_scalars<F: PrimeField>(num_bits: usize) -> Vec<F> {
+    // The bits are padded to even length by `mul_bits_fixed_base()`
+    let num_bits = num_bits + num_bits % 2;
+
+    let one = F::one();
+    let two = one.double();
+    let two_inv = two.inverse().unwrap();
+    let two_to_n = two.pow(&[num_bits as u64]);
+    let three = two + &one;
+    let three_times_two_to_n_minus_one = three * &(two_to_n - &one);
+
+    let mut scalars = vec![F::zero(), three_times_two_to_n_minus_one];
+    if num_bits >= F::size_in_bits() {
+        scalars.push(three_times_two_to_n_minus_one * &two_inv);
+        for msb_val in 0..4u64 {
+            scalars.push((F::from(msb_val) * &two_to_n - &three) * &two_inv);
+        }
+    }
+    scalars
+}
+
+/// Return a Boolean which is true iff the big endian `bits` represent `value`. The length
+/// of `bits` must be the one of the modulus of `F`.
+pub(crate) fn bits_are_eq_constant<F, ConstraintF, CS>(
+    mut cs: CS,
+    bits: &[Boolean],
+    value: &F,
+) -> Result<Boolean, SynthesisError>
+where
+    F: PrimeField,
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    let value_bits = value.write_bits();
+    assert_eq!(bits.len(), value_bits.len());
+
+    let literals = bits
+        .iter()
+        .zip(value_bits)
+        .map(|(bit, value_bit)| if value_bit { *bit } else { bit.not() })
+        .collect::<Vec<_>>();
+    Boolean::kary_and(cs.ns(|| "bits == value"), &literals)
+}
+
+/// Return `constant_bits` if `cond` is true, `bits` otherwise.
+pub(crate) fn conditionally_select_bits<ConstraintF, CS>(
+    mut cs: CS,
+    cond: &Boolean,
+    constant_bits: &[bool],
+    bits: &[Boolean],
+) -> Result<Vec<Boolean>, SynthesisError>
+where
+    ConstraintF: PrimeField,
+    CS: ConstraintSystemAbstract<ConstraintF>,
+{
+    constant_bits
+        .iter()
+        .zip(bits)
+        .enumerate()
+        .map(|(i, (constant_bit, bit))| {
+            Boolean::conditionally_select(
+                cs.ns(|| format!("select bit {}", i)),
+                cond,
+                &Boolean::constant(*constant_bit),
+                bit,
+            )
+        })
+        .collect()
+}
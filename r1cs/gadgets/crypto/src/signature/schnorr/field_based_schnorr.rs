@@ -839,4 +839,71 @@ mod test {
             assert!(!cs.is_satisfied());
         }
     }
+
+    #[test]
+    fn tweedle_musig2_schnorr_gadget_test() {
+        use crate::crh::TweedleFqPoseidonHashGadget;
+        use algebra::{curves::tweedle::dee::Projective as DeeProjective, fields::tweedle::Fq};
+        use primitives::{crh::TweedleFqPoseidonHash, signature::schnorr::field_based_musig2::*};
+        use r1cs_std::instantiated::tweedle::TweedleDeeGadget;
+
+        type SchnorrTweedle =
+            FieldBasedSchnorrSignatureScheme<Fq, DeeProjective, TweedleFqPoseidonHash>;
+        type MuSig2Tweedle = FieldBasedSchnorrMuSig2<Fq, DeeProjective, TweedleFqPoseidonHash>;
+        type SchnorrTweedleGadget = FieldBasedSchnorrSigVerificationGadget<
+            Fq,
+            DeeProjective,
+            TweedleDeeGadget,
+            TweedleFqPoseidonHash,
+            TweedleFqPoseidonHashGadget,
+        >;
+
+        let rng = &mut thread_rng();
+        let (pks, sks): (Vec<_>, Vec<_>) = (0..4).map(|_| SchnorrTweedle::keygen(rng)).unzip();
+        let key_agg_ctx = MuSig2Tweedle::key_aggregation(&pks).unwrap();
+        let message: Fq = rng.gen();
+
+        // Sign, retrying with fresh nonces if the aggregated signature is too long
+        let sig = loop {
+            let (secret_nonces, public_nonces): (Vec<_>, Vec<_>) =
+                (0..4).map(|_| MuSig2Tweedle::nonce_gen(rng)).unzip();
+            let aggregated_nonce = MuSig2Tweedle::nonce_aggregation(&public_nonces).unwrap();
+            let session = MuSig2Tweedle::session(&key_agg_ctx, &aggregated_nonce, message).unwrap();
+            let partial_sigs = sks
+                .iter()
+                .zip(secret_nonces)
+                .map(|(sk, secret_nonce)| {
+                    MuSig2Tweedle::partial_sign(&key_agg_ctx, &session, sk, secret_nonce).unwrap()
+                })
+                .collect::<Vec<_>>();
+            if let Ok(sig) = MuSig2Tweedle::partial_sig_aggregation(&session, &partial_sigs) {
+                break sig;
+            }
+        };
+
+        let mut cs = ConstraintSystem::<Fq>::new(SynthesisMode::Debug);
+        let sig_g = <SchnorrTweedleGadget as FieldBasedSigGadget<SchnorrTweedle, Fq>>::SignatureGadget::alloc(
+            cs.ns(|| "alloc sig"),
+            || Ok(sig)
+        ).unwrap();
+        let pk_g = <SchnorrTweedleGadget as FieldBasedSigGadget<SchnorrTweedle, Fq>>::PublicKeyGadget::alloc(
+            cs.ns(|| "alloc aggregated pk"),
+            || Ok(key_agg_ctx.aggregated_pk())
+        ).unwrap();
+        let message_g =
+            <SchnorrTweedleGadget as FieldBasedSigGadget<SchnorrTweedle, Fq>>::DataGadget::alloc(
+                cs.ns(|| "alloc message"),
+                || Ok(message),
+            )
+            .unwrap();
+
+        SchnorrTweedleGadget::enforce_signature_verification(
+            cs.ns(|| "verify sig"),
+            &pk_g,
+            &sig_g,
+            message_g,
+        )
+        .unwrap();
+        assert!(cs.is_satisfied());
+    }
 }
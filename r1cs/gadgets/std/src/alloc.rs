@@ -132,6 +132,31 @@ impl<I, ConstraintF: Field, A: AllocGadget<I, ConstraintF>> AllocGadget<[I], Con
     }
 }
 
+/// Allocating the unit type is a no-op, e.g. for the parameters of parameterless gadgets.
+impl<ConstraintF: Field> AllocGadget<(), ConstraintF> for () {
+    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        _cs: CS,
+        _f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<()>,
+    {
+        Ok(())
+    }
+
+    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+        _cs: CS,
+        _f: F,
+    ) -> Result<Self, SynthesisError>
+    where
+        F: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<()>,
+    {
+        Ok(())
+    }
+}
+
 /// Get a Gadget from the corresponding constant. At low level, the constant
 /// will be the coefficient of the CS::one() variable.
 pub trait ConstantGadget<V, ConstraintF: Field>
@@ -716,6 +716,68 @@ impl Boolean {
 
         Ok(())
     }
+
+    /// Asserts that this bit_gadget representation, interpreted in
+    /// big endian, is smaller or equal than `element`.
+    pub fn enforce_smaller_or_equal_than<ConstraintF, CS, E>(
+        mut cs: CS,
+        bits: &[Self],
+        element: E,
+    ) -> Result<(), SynthesisError>
+    where
+        ConstraintF: Field,
+        CS: ConstraintSystemAbstract<ConstraintF>,
+        E: AsRef<[u64]>,
+    {
+        // Big endian bits of `element`, without the leading zeros
+        let b_bits = BitIterator::new(element)
+            .skip_while(|b| !b)
+            .collect::<Vec<_>>();
+
+        // `bits` can't represent any integer bigger than `element`
+        if bits.len() < b_bits.len() {
+            return Ok(());
+        }
+
+        // The bits exceeding the length of `element` must all be zero
+        let num_extra_bits = bits.len() - b_bits.len();
+        for (i, should_be_zero) in bits[..num_extra_bits].iter().enumerate() {
+            should_be_zero.enforce_equal(
+                cs.ns(|| format!("Check {}-th extra bit is zero", i)),
+                &Boolean::constant(false),
+            )?;
+        }
+
+        // Runs of ones in `element`, handled as in `enforce_in_field`
+        let mut last_run = Boolean::constant(true);
+        let mut current_run = vec![];
+
+        let mut run_i = 0;
+        let mut nand_i = 0;
+
+        for (a, b) in bits[num_extra_bits..].iter().zip(b_bits) {
+            if b {
+                current_run.push(*a);
+            } else {
+                if !current_run.is_empty() {
+                    current_run.push(last_run);
+                    last_run = Self::kary_and(cs.ns(|| format!("run {}", run_i)), &current_run)?;
+                    run_i += 1;
+                    current_run.truncate(0);
+                }
+
+                // If all the previous bits equal the ones of `element`,
+                // `a` must be false.
+                Self::enforce_nand(cs.ns(|| format!("nand {}", nand_i)), &[last_run, *a])?;
+                nand_i += 1;
+            }
+        }
+
+        // A trailing run of ones in `element` needs no further check: whatever
+        // the value of the corresponding bits, the result is still smaller or
+        // equal than `element`.
+        Ok(())
+    }
 }
 
 impl PartialEq for Boolean {
@@ -0,0 +1,1393 @@
+//! Macros implementing the gadgets for unsigned integers of a fixed bit length N over
+//! a prime constraint field, shared by `UInt8`, `UInt16`, `UInt32`, `UInt64` and `UInt128`.
+//! Besides elementary gadgets (such as alloc, toBytes, select, etc.) the integers support
+//!     - the bitwise operations and, or, xor, not, and the shifts/rotations shl, shr,
+//!     rotl, rotr (the latter four being for free),
+//!     - addition, subtraction and multiplication, either modulo 2^N or enforcing that
+//!     no overflow (resp. underflow) occurs,
+//!     - comparisons,
+//!     - conversions from and to `FpGadget`.
+
+/// Implements `addmany` for the unsigned integer gadgets, either aggregating the resulting
+/// equality constraint in a `MultiEq` root or enforcing it directly in any constraint system.
+macro_rules! impl_uint_addmany {
+    ($bit_size:expr, MultiEq) => {
+        /// Perform addition modulo 2^N of several integers, aggregating the resulting
+        /// equality constraint in the `MultiEq`.
+        pub fn addmany<ConstraintF, CS, M>(
+            mut cs: M,
+            operands: &[Self],
+        ) -> Result<Self, SynthesisError>
+        where
+            ConstraintF: PrimeField,
+            CS: ConstraintSystemAbstract<ConstraintF>,
+            M: ConstraintSystemAbstract<ConstraintF, Root = $crate::eq::MultiEq<ConstraintF, CS>>,
+        {
+            assert!(!operands.is_empty());
+
+            if operands.len() == 1 {
+                return Ok(operands[0].clone());
+            }
+
+            let (lc, result_value, num_bits) = Self::sum(operands, CS::one());
+
+            // The value of the actual result is modulo 2^N
+            let modular_value = result_value.map(|(lo, _)| lo);
+
+            if operands.iter().all(Self::is_constant) && modular_value.is_some() {
+                // We can just return a constant, rather than
+                // unpacking the result into allocated bits.
+                return Ok(Self::constant(modular_value.unwrap()));
+            }
+
+            let (mut result_bits, result_lc) =
+                Self::alloc_result_bits(&mut cs, result_value, num_bits)?;
+
+            // Enforce equality between the sum and result by aggregating it
+            // in the MultiEq
+            cs.get_root().enforce_equal(num_bits, &lc, &result_lc);
+
+            // Discard carry bits that we don't care about
+            result_bits.truncate($bit_size);
+
+            Ok(Self {
+                bits: result_bits,
+                value: modular_value,
+            })
+        }
+    };
+    ($bit_size:expr, ConstraintSystem) => {
+        /// Perform addition modulo 2^N of several integers, enforcing the resulting
+        /// equality constraint directly in `cs`.
+        pub fn addmany<ConstraintF, CS>(
+            mut cs: CS,
+            operands: &[Self],
+        ) -> Result<Self, SynthesisError>
+        where
+            ConstraintF: PrimeField,
+            CS: ConstraintSystemAbstract<ConstraintF>,
+        {
+            assert!(!operands.is_empty());
+
+            if operands.len() == 1 {
+                return Ok(operands[0].clone());
+            }
+
+            let (lc, result_value, num_bits) = Self::sum(operands, CS::one());
+
+            // The value of the actual result is modulo 2^N
+            let modular_value = result_value.map(|(lo, _)| lo);
+
+            if operands.iter().all(Self::is_constant) && modular_value.is_some() {
+                // We can just return a constant, rather than
+                // unpacking the result into allocated bits.
+                return Ok(Self::constant(modular_value.unwrap()));
+            }
+
+            // Allocate each bit of the result, subtracting it from the linear
+            // combination to ensure the sums balance out
+            let mut lc = lc;
+            let mut result_bits = Vec::with_capacity(num_bits);
+            let mut coeff = ConstraintF::one();
+            for i in 0..num_bits {
+                let b = AllocatedBit::alloc(cs.ns(|| format!("result bit_gadget {}", i)), || {
+                    result_value
+                        .map(|(lo, hi)| {
+                            if i < $bit_size {
+                                (lo >> i) & 1 == 1
+                            } else {
+                                (hi >> (i - $bit_size)) & 1 == 1
+                            }
+                        })
+                        .get()
+                })?;
+                lc = lc - (coeff, b.get_variable());
+                result_bits.push(Boolean::from(b));
+                coeff.double_in_place();
+            }
+
+            // Enforce that the linear combination equals zero
+            cs.enforce(|| "modular addition", |lc| lc, |lc| lc, |_| lc);
+
+            // Discard carry bits that we don't care about
+            result_bits.truncate($bit_size);
+
+            Ok(Self {
+                bits: result_bits,
+                value: modular_value,
+            })
+        }
+    };
+}
+
+macro_rules! impl_uint_gadget {
+    ($type_name:ident, $bit_size:expr, $native_type:ident) => {
+        impl_uint_gadget!($type_name, $bit_size, $native_type, MultiEq);
+    };
+    ($type_name:ident, $bit_size:expr, $native_type:ident, $addmany_root:ident) => {
+        pub use self::uint_impl::$type_name;
+
+        mod uint_impl {
+            use algebra::{Field, FpParameters, PrimeField};
+            use r1cs_core::{
+                ConstraintSystemAbstract, LinearCombination, Namespace, SynthesisError, Variable,
+            };
+            use std::{borrow::Borrow, cmp::Ordering};
+
+            use crate::{
+                boolean::AllocatedBit, fields::fp::FpGadget, prelude::*,
+                to_field_gadget_vec::ToConstraintFieldGadget, Assignment,
+            };
+
+            /// Represents an interpretation of a fixed number of `Boolean` objects as an
+            /// unsigned integer.
+            #[derive(Clone, Debug)]
+            pub struct $type_name {
+                // Least significant bit first
+                pub bits: Vec<Boolean>,
+                pub value: Option<$native_type>,
+            }
+
+            impl $type_name {
+                pub fn get_value(&self) -> Option<$native_type> {
+                    self.value
+                }
+
+                /// Construct a constant from a native integer
+                pub fn constant(value: $native_type) -> Self {
+                    let bits = (0..$bit_size)
+                        .map(|i| Boolean::constant((value >> i) & 1 == 1))
+                        .collect();
+
+                    Self {
+                        bits,
+                        value: Some(value),
+                    }
+                }
+
+                /// Turns this integer into its little-endian bit order representation.
+                pub fn to_bits_le(&self) -> Vec<Boolean> {
+                    self.bits.clone()
+                }
+
+                /// Turns this integer into its big-endian bit order representation.
+                pub fn to_bits_be(&self) -> Vec<Boolean> {
+                    self.bits.iter().rev().cloned().collect()
+                }
+
+                /// Same as `to_bits_le`: kept, taking a reference, for backward compatibility.
+                #[allow(clippy::wrong_self_convention)]
+                pub fn into_bits_le(&self) -> Vec<Boolean> {
+                    self.to_bits_le()
+                }
+
+                /// Same as `to_bits_be`: kept, taking a reference, for backward compatibility.
+                #[allow(clippy::wrong_self_convention)]
+                pub fn into_bits_be(&self) -> Vec<Boolean> {
+                    self.to_bits_be()
+                }
+
+                /// Converts a little-endian bit order representation of bits into an
+                /// integer.
+                pub fn from_bits_le(bits: &[Boolean]) -> Self {
+                    assert_eq!(bits.len(), $bit_size);
+
+                    let value = bits.iter().rev().try_fold(0, |acc: $native_type, b| {
+                        b.get_value().map(|b| (acc << 1) | $native_type::from(b))
+                    });
+
+                    Self {
+                        bits: bits.to_vec(),
+                        value,
+                    }
+                }
+
+                /// Converts a big-endian bit order representation of bits into an
+                /// integer.
+                pub fn from_bits_be(bits: &[Boolean]) -> Self {
+                    let mut bits = bits.to_vec();
+                    bits.reverse();
+                    Self::from_bits_le(&bits)
+                }
+
+                pub fn rotl(&self, by: usize) -> Self {
+                    let by = by % $bit_size;
+
+                    let new_bits = self
+                        .bits
+                        .iter()
+                        .skip($bit_size - by)
+                        .chain(self.bits.iter())
+                        .take($bit_size)
+                        .cloned()
+                        .collect();
+
+                    Self {
+                        bits: new_bits,
+                        value: self.value.map(|v| v.rotate_left(by as u32)),
+                    }
+                }
+
+                pub fn rotr(&self, by: usize) -> Self {
+                    let by = by % $bit_size;
+
+                    let new_bits = self
+                        .bits
+                        .iter()
+                        .skip(by)
+                        .chain(self.bits.iter())
+                        .take($bit_size)
+                        .cloned()
+                        .collect();
+
+                    Self {
+                        bits: new_bits,
+                        value: self.value.map(|v| v.rotate_right(by as u32)),
+                    }
+                }
+
+                pub fn shl(&self, by: usize) -> Self {
+                    let by = by % $bit_size;
+
+                    let fill = Boolean::constant(false);
+
+                    let new_bits = Some(&fill)
+                        .into_iter()
+                        .cycle() // The `by` least significant bits will be zeros
+                        .take(by)
+                        .chain(self.bits.iter()) // The bits are least significant first
+                        .take($bit_size) // Discard the bits lost during the shift
+                        .cloned()
+                        .collect();
+
+                    Self {
+                        bits: new_bits,
+                        value: self.value.map(|v| v << by),
+                    }
+                }
+
+                pub fn shr(&self, by: usize) -> Self {
+                    let by = by % $bit_size;
+
+                    let fill = Boolean::constant(false);
+
+                    let new_bits = self
+                        .bits
+                        .iter() // The bits are least significant first
+                        .skip(by) // Skip the bits that will be lost during the shift
+                        .chain(Some(&fill).into_iter().cycle()) // Rest will be zeros
+                        .take($bit_size)
+                        .cloned()
+                        .collect();
+
+                    Self {
+                        bits: new_bits,
+                        value: self.value.map(|v| v >> by),
+                    }
+                }
+
+                /// NOT this integer, i.e. flip all of its bits
+                pub fn not(&self) -> Self {
+                    Self {
+                        bits: self.bits.iter().map(|b| b.not()).collect(),
+                        value: self.value.map(|v| !v),
+                    }
+                }
+
+                /// XOR this integer with another one
+                pub fn xor<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: Field,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.bitwise_op(
+                        cs,
+                        other,
+                        "xor",
+                        |a, b| a ^ b,
+                        |cs, a, b| Boolean::xor(cs, a, b),
+                    )
+                }
+
+                /// OR this integer with another one
+                pub fn or<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: Field,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.bitwise_op(
+                        cs,
+                        other,
+                        "or",
+                        |a, b| a | b,
+                        |cs, a, b| Boolean::or(cs, a, b),
+                    )
+                }
+
+                /// AND this integer with another one
+                pub fn and<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: Field,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.bitwise_op(
+                        cs,
+                        other,
+                        "and",
+                        |a, b| a & b,
+                        |cs, a, b| Boolean::and(cs, a, b),
+                    )
+                }
+
+                fn bitwise_op<ConstraintF, CS, F, G>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                    op_name: &str,
+                    native_op: F,
+                    bit_op: G,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: Field,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                    F: Fn($native_type, $native_type) -> $native_type,
+                    G: Fn(
+                        Namespace<'_, ConstraintF, CS::Root>,
+                        &Boolean,
+                        &Boolean,
+                    ) -> Result<Boolean, SynthesisError>,
+                {
+                    let new_value = match (self.value, other.value) {
+                        (Some(a), Some(b)) => Some(native_op(a, b)),
+                        _ => None,
+                    };
+
+                    let bits = self
+                        .bits
+                        .iter()
+                        .zip(other.bits.iter())
+                        .enumerate()
+                        .map(|(i, (a, b))| {
+                            bit_op(cs.ns(|| format!("{} of bit_gadget {}", op_name, i)), a, b)
+                        })
+                        .collect::<Result<_, _>>()?;
+
+                    Ok(Self {
+                        bits,
+                        value: new_value,
+                    })
+                }
+
+                impl_uint_addmany!($bit_size, $addmany_root);
+
+                /// Perform addition modulo 2^N of `self` and `other`.
+                pub fn add_mod<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.add_internal(cs, other, true)
+                }
+
+                /// Perform addition of `self` and `other`, enforcing that the sum is smaller
+                /// than 2^N. Returns an `Unsatisfiable` error if the values of the operands
+                /// are known and their sum overflows.
+                pub fn checked_add<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.add_internal(cs, other, false)
+                }
+
+                fn add_internal<ConstraintF, CS>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                    allow_overflow: bool,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    let operands = [self.clone(), other.clone()];
+                    let (lc, result_value, num_bits) = Self::sum(&operands, CS::one());
+
+                    if !allow_overflow && result_value.map_or(false, |(_, hi)| hi != 0) {
+                        return Err(SynthesisError::Unsatisfiable);
+                    }
+                    let modular_value = result_value.map(|(lo, _)| lo);
+
+                    if self.is_constant() && other.is_constant() && modular_value.is_some() {
+                        return Ok(Self::constant(modular_value.unwrap()));
+                    }
+
+                    // If no overflow is allowed, allocating exactly N bits for the result
+                    // enforces the sum to be smaller than 2^N.
+                    let num_bits = if allow_overflow { num_bits } else { $bit_size };
+                    let (mut result_bits, result_lc) =
+                        Self::alloc_result_bits(cs.ns(|| "alloc result"), result_value, num_bits)?;
+
+                    cs.enforce(|| "enforce sum", |_| lc, |lc| lc + CS::one(), |_| result_lc);
+
+                    // Discard the carry bit
+                    result_bits.truncate($bit_size);
+
+                    Ok(Self {
+                        bits: result_bits,
+                        value: modular_value,
+                    })
+                }
+
+                /// Perform subtraction modulo 2^N of `other` from `self`.
+                pub fn sub_mod<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    Ok(self.sub_with_borrow(cs, other)?.0)
+                }
+
+                /// Perform subtraction of `other` from `self`, enforcing that `other` is
+                /// smaller or equal than `self`. Returns an `Unsatisfiable` error if the values
+                /// of the operands are known and the subtraction underflows.
+                pub fn checked_sub<ConstraintF, CS>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    let result_value = match (self.value, other.value) {
+                        (Some(a), Some(b)) => {
+                            Some(a.checked_sub(b).ok_or(SynthesisError::Unsatisfiable)?)
+                        }
+                        _ => None,
+                    };
+
+                    if self.is_constant() && other.is_constant() && result_value.is_some() {
+                        return Ok(Self::constant(result_value.unwrap()));
+                    }
+
+                    let (result_bits, result_lc) = Self::alloc_result_bits(
+                        cs.ns(|| "alloc result"),
+                        result_value.map(|v| (v, 0)),
+                        $bit_size,
+                    )?;
+
+                    // self = other + result, with the result of N bits, implies self >= other.
+                    let self_lc = Self::lc(&self.bits, CS::one());
+                    let other_lc = Self::lc(&other.bits, CS::one());
+                    cs.enforce(
+                        || "enforce difference",
+                        |_| self_lc - &other_lc,
+                        |lc| lc + CS::one(),
+                        |_| result_lc,
+                    );
+
+                    Ok(Self {
+                        bits: result_bits,
+                        value: result_value,
+                    })
+                }
+
+                // Enforce the subtraction modulo 2^N of `other` from `self`, returning the
+                // result together with the borrow, i.e. a `Boolean` which is true if and only
+                // if `self` is smaller than `other`.
+                fn sub_with_borrow<ConstraintF, CS>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                ) -> Result<(Self, Boolean), SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    let result_value = match (self.value, other.value) {
+                        (Some(a), Some(b)) => Some(a.overflowing_sub(b)),
+                        _ => None,
+                    };
+
+                    if self.is_constant() && other.is_constant() {
+                        if let Some((diff, borrow)) = result_value {
+                            return Ok((Self::constant(diff), Boolean::constant(borrow)));
+                        }
+                    }
+
+                    let (result_bits, result_lc) = Self::alloc_result_bits(
+                        cs.ns(|| "alloc result"),
+                        result_value.map(|(diff, _)| (diff, 0)),
+                        $bit_size,
+                    )?;
+                    let borrow = AllocatedBit::alloc(cs.ns(|| "alloc borrow"), || {
+                        result_value.map(|(_, borrow)| borrow).get()
+                    })?;
+
+                    // self + 2^N * borrow = other + result. As both sides are smaller than
+                    // 2^(N+1), the borrow is set if and only if self < other.
+                    let two_to_n = ConstraintF::from(2u64).pow(&[$bit_size]);
+                    let self_lc = Self::lc(&self.bits, CS::one());
+                    let other_lc = Self::lc(&other.bits, CS::one());
+                    cs.enforce(
+                        || "enforce difference with borrow",
+                        |_| self_lc - &other_lc + (two_to_n, borrow.get_variable()),
+                        |lc| lc + CS::one(),
+                        |_| result_lc,
+                    );
+
+                    Ok((
+                        Self {
+                            bits: result_bits,
+                            value: result_value.map(|(diff, _)| diff),
+                        },
+                        Boolean::from(borrow),
+                    ))
+                }
+
+                /// Perform multiplication modulo 2^N of `self` and `other`.
+                pub fn mul_mod<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.mul_internal(cs, other, true)
+                }
+
+                /// Perform multiplication of `self` and `other`, enforcing that the product
+                /// is smaller than 2^N. Returns an `Unsatisfiable` error if the values of the
+                /// operands are known and their product overflows.
+                pub fn checked_mul<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.mul_internal(cs, other, false)
+                }
+
+                // Both operands are split into two limbs of N/2 bits, x = x_0 + 2^(N/2) * x_1,
+                // so that
+                //      self * other = t + 2^N * self_1 * other_1,
+                // with t = self_0 * other_0 + 2^(N/2) * (self_0 * other_1 + self_1 * other_0)
+                // being of at most 3N/2 + 2 bits. This way none of the enforced quantities
+                // exceeds 3N/2 + 2 bits, and the product of two 128 bits integers can be
+                // handled by 255 bits constraint fields too.
+                // The product modulo 2^N is given by the N least significant bits of t, while
+                // there is no overflow if and only if self_1 * other_1 = 0 and t < 2^N.
+                fn mul_internal<ConstraintF, CS>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                    allow_overflow: bool,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    const HALF: usize = $bit_size / 2;
+                    assert!($bit_size + HALF + 2 < ConstraintF::Params::CAPACITY as usize);
+
+                    if !allow_overflow {
+                        if let (Some(a), Some(b)) = (self.value, other.value) {
+                            if a.checked_mul(b).is_none() {
+                                return Err(SynthesisError::Unsatisfiable);
+                            }
+                        }
+                    }
+
+                    let modular_value = match (self.value, other.value) {
+                        (Some(a), Some(b)) => Some(a.wrapping_mul(b)),
+                        _ => None,
+                    };
+
+                    if self.is_constant() && other.is_constant() && modular_value.is_some() {
+                        return Ok(Self::constant(modular_value.unwrap()));
+                    }
+
+                    // Partial products self_0 * other_1, self_1 * other_0, and t (as a pair of
+                    // low and high N bits)
+                    let partial_values = match (self.value, other.value) {
+                        (Some(a), Some(b)) => {
+                            let mask = $native_type::MAX >> HALF;
+                            let (a_0, a_1) = (a & mask, a >> HALF);
+                            let (b_0, b_1) = (b & mask, b >> HALF);
+                            let (a_0_b_1, a_1_b_0) = (a_0 * b_1, a_1 * b_0);
+
+                            let (mid, mid_carry) = a_0_b_1.overflowing_add(a_1_b_0);
+                            let t_hi = (mid >> HALF) | ($native_type::from(mid_carry) << HALF);
+                            let (t_lo, carry) = (mid << HALF).overflowing_add(a_0 * b_0);
+
+                            Some((a_0_b_1, a_1_b_0, (t_lo, t_hi + $native_type::from(carry))))
+                        }
+                        _ => None,
+                    };
+
+                    let (self_0, self_1) = (
+                        Self::lc(&self.bits[..HALF], CS::one()),
+                        Self::lc(&self.bits[HALF..], CS::one()),
+                    );
+                    let (other_0, other_1) = (
+                        Self::lc(&other.bits[..HALF], CS::one()),
+                        Self::lc(&other.bits[HALF..], CS::one()),
+                    );
+
+                    let self_0_other_1 = cs.alloc(
+                        || "alloc self_0 * other_1",
+                        || {
+                            partial_values
+                                .map(|(v, _, _)| ConstraintF::from(u128::from(v)))
+                                .get()
+                        },
+                    )?;
+                    cs.enforce(
+                        || "enforce self_0 * other_1",
+                        |lc| lc + &self_0,
+                        |lc| lc + &other_1,
+                        |lc| lc + self_0_other_1,
+                    );
+
+                    let self_1_other_0 = cs.alloc(
+                        || "alloc self_1 * other_0",
+                        || {
+                            partial_values
+                                .map(|(_, v, _)| ConstraintF::from(u128::from(v)))
+                                .get()
+                        },
+                    )?;
+                    cs.enforce(
+                        || "enforce self_1 * other_0",
+                        |lc| lc + &self_1,
+                        |lc| lc + &other_0,
+                        |lc| lc + self_1_other_0,
+                    );
+
+                    // If no overflow is allowed, allocating exactly N bits for t enforces it
+                    // to be smaller than 2^N.
+                    let num_bits = if allow_overflow {
+                        $bit_size + HALF + 2
+                    } else {
+                        $bit_size
+                    };
+                    let (mut result_bits, t_lc) = Self::alloc_result_bits(
+                        cs.ns(|| "alloc result"),
+                        partial_values.map(|(_, _, t)| t),
+                        num_bits,
+                    )?;
+
+                    // self_0 * other_0 = t - 2^(N/2) * (self_0 * other_1 + self_1 * other_0)
+                    let two_to_half = ConstraintF::from(2u64).pow(&[HALF as u64]);
+                    cs.enforce(
+                        || "enforce self_0 * other_0",
+                        |lc| lc + &self_0,
+                        |lc| lc + &other_0,
+                        |_| t_lc - (two_to_half, self_0_other_1) - (two_to_half, self_1_other_0),
+                    );
+
+                    if !allow_overflow {
+                        cs.enforce(
+                            || "enforce self_1 * other_1 = 0",
+                            |lc| lc + &self_1,
+                            |lc| lc + &other_1,
+                            |lc| lc,
+                        );
+                    }
+
+                    // Discard the bits exceeding 2^N
+                    result_bits.truncate($bit_size);
+
+                    Ok(Self {
+                        bits: result_bits,
+                        value: modular_value,
+                    })
+                }
+
+                /// Enforce that `self` is less (resp. greater) than `other` if `ordering` is
+                /// `Ordering::Less` (resp. `Ordering::Greater`). If `should_also_check_equality`
+                /// is set, equality is allowed too.
+                pub fn enforce_cmp<ConstraintF, CS>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                    ordering: Ordering,
+                    should_also_check_equality: bool,
+                ) -> Result<(), SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    self.is_cmp(
+                        cs.ns(|| "compare"),
+                        other,
+                        ordering,
+                        should_also_check_equality,
+                    )?
+                    .enforce_equal(cs.ns(|| "enforce comparison"), &Boolean::constant(true))
+                }
+
+                /// Return a `Boolean` which is true if and only if `self` is less (resp.
+                /// greater) than `other`, when `ordering` is `Ordering::Less` (resp.
+                /// `Ordering::Greater`). If `should_also_check_equality` is set, the `Boolean`
+                /// is true on equality too.
+                pub fn is_cmp<ConstraintF, CS>(
+                    &self,
+                    mut cs: CS,
+                    other: &Self,
+                    ordering: Ordering,
+                    should_also_check_equality: bool,
+                ) -> Result<Boolean, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    let (left, right) = match ordering {
+                        Ordering::Less => (self, other),
+                        Ordering::Greater => (other, self),
+                        Ordering::Equal => {
+                            return Err(SynthesisError::Other(
+                                "Equality must be checked with EqGadget".to_owned(),
+                            ))
+                        }
+                    };
+
+                    // left <= right if and only if !(right < left), and right < left if and
+                    // only if right - left borrows
+                    if should_also_check_equality {
+                        let (_, borrow) = right.sub_with_borrow(cs.ns(|| "right - left"), left)?;
+                        Ok(borrow.not())
+                    } else {
+                        let (_, borrow) = left.sub_with_borrow(cs.ns(|| "left - right"), right)?;
+                        Ok(borrow)
+                    }
+                }
+
+                /// Enforce the conversion of a field element into an integer, i.e. that the
+                /// field element is smaller than 2^N.
+                pub fn from_fp_gadget<ConstraintF, CS>(
+                    mut cs: CS,
+                    fe: &FpGadget<ConstraintF>,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    let num_bits = ConstraintF::Params::MODULUS_BITS as usize;
+                    assert!($bit_size < num_bits);
+
+                    // The unpacking of fe into N bits enforces it to be smaller than 2^N
+                    let mut bits = fe.to_bits_with_length_restriction(
+                        cs.ns(|| "fe to bits"),
+                        num_bits - $bit_size,
+                    )?;
+                    bits.reverse();
+
+                    Ok(Self::from_bits_le(&bits))
+                }
+
+                /// Pack this integer into a field element.
+                pub fn to_fp_gadget<ConstraintF, CS>(
+                    &self,
+                    cs: CS,
+                ) -> Result<FpGadget<ConstraintF>, SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    assert!($bit_size <= ConstraintF::Params::CAPACITY as usize);
+                    FpGadget::from_bits(cs, &self.to_bits_be())
+                }
+
+                fn is_constant(&self) -> bool {
+                    self.bits.iter().all(|b| b.is_constant())
+                }
+
+                // Linear combination of `bits`, interpreted in little-endian order
+                fn lc<ConstraintF: PrimeField>(
+                    bits: &[Boolean],
+                    one: Variable,
+                ) -> LinearCombination<ConstraintF> {
+                    let mut lc = LinearCombination::zero();
+                    let mut coeff = ConstraintF::one();
+                    for bit in bits {
+                        lc = lc + &bit.lc(one, coeff);
+                        coeff.double_in_place();
+                    }
+                    lc
+                }
+
+                // Return the linear combination of the sum of `operands`, together with its
+                // value, as a pair of low and high N bits, and the number of bits needed to
+                // represent it.
+                fn sum<ConstraintF: PrimeField>(
+                    operands: &[Self],
+                    one: Variable,
+                ) -> (
+                    LinearCombination<ConstraintF>,
+                    Option<($native_type, $native_type)>,
+                    usize,
+                ) {
+                    // The sum is smaller than operands.len() * 2^N
+                    let num_bits = $bit_size
+                        + (0usize.leading_zeros() - (operands.len() - 1).leading_zeros()) as usize;
+                    // Make sure that the sum does not overflow the constraint field
+                    assert!(num_bits < ConstraintF::Params::CAPACITY as usize);
+                    assert!(num_bits <= 2 * $bit_size);
+
+                    let mut lc = LinearCombination::zero();
+                    let mut value: Option<($native_type, $native_type)> = Some((0, 0));
+                    for op in operands {
+                        value = match (value, op.value) {
+                            (Some((lo, hi)), Some(v)) => {
+                                let (lo, carry) = lo.overflowing_add(v);
+                                Some((lo, hi + $native_type::from(carry)))
+                            }
+                            // If any of our operands have unknown value, we won't
+                            // know the value of the result
+                            _ => None,
+                        };
+                        lc = lc + Self::lc(&op.bits, one);
+                    }
+
+                    (lc, value, num_bits)
+                }
+
+                // Allocate the `num_bits` least significant bits of `value`, given as a pair
+                // of low and high N bits, returning them in little-endian order together with
+                // their linear combination.
+                fn alloc_result_bits<ConstraintF, CS>(
+                    mut cs: CS,
+                    value: Option<($native_type, $native_type)>,
+                    num_bits: usize,
+                ) -> Result<(Vec<Boolean>, LinearCombination<ConstraintF>), SynthesisError>
+                where
+                    ConstraintF: PrimeField,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                {
+                    let mut bits = Vec::with_capacity(num_bits);
+                    let mut lc = LinearCombination::zero();
+                    let mut coeff = ConstraintF::one();
+                    for i in 0..num_bits {
+                        let b = AllocatedBit::alloc(cs.ns(|| format!("result bit {}", i)), || {
+                            value
+                                .map(|(lo, hi)| {
+                                    if i < $bit_size {
+                                        (lo >> i) & 1 == 1
+                                    } else {
+                                        (hi >> (i - $bit_size)) & 1 == 1
+                                    }
+                                })
+                                .get()
+                        })?;
+                        lc += (coeff, b.get_variable());
+                        bits.push(Boolean::from(b));
+                        coeff.double_in_place();
+                    }
+
+                    Ok((bits, lc))
+                }
+            }
+
+            impl<ConstraintF: Field> ToBytesGadget<ConstraintF> for $type_name {
+                #[inline]
+                fn to_bytes<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    &self,
+                    _cs: CS,
+                ) -> Result<Vec<UInt8>, SynthesisError> {
+                    let value_chunks = self.value.map(|val| val.to_le_bytes());
+
+                    Ok(self
+                        .bits
+                        .chunks(8)
+                        .enumerate()
+                        .map(|(i, chunk8)| UInt8 {
+                            bits: chunk8.to_vec(),
+                            value: value_chunks.map(|chunks| chunks[i]),
+                        })
+                        .collect())
+                }
+
+                fn to_bytes_strict<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    &self,
+                    cs: CS,
+                ) -> Result<Vec<UInt8>, SynthesisError> {
+                    self.to_bytes(cs)
+                }
+            }
+
+            impl<ConstraintF: PrimeField> ToConstraintFieldGadget<ConstraintF> for $type_name {
+                type FieldGadget = FpGadget<ConstraintF>;
+
+                fn to_field_gadget_elements<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    &self,
+                    cs: CS,
+                ) -> Result<Vec<Self::FieldGadget>, SynthesisError> {
+                    Ok(vec![self.to_fp_gadget(cs)?])
+                }
+            }
+
+            impl PartialEq for $type_name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.value.is_some() && other.value.is_some() && self.value == other.value
+                }
+            }
+
+            impl Eq for $type_name {}
+
+            impl<ConstraintF: Field> EqGadget<ConstraintF> for $type_name {
+                fn is_eq<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                ) -> Result<Boolean, SynthesisError> {
+                    self.bits.as_slice().is_eq(cs, &other.bits)
+                }
+
+                fn conditional_enforce_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                    should_enforce: &Boolean,
+                ) -> Result<(), SynthesisError> {
+                    self.bits
+                        .conditional_enforce_equal(cs, &other.bits, should_enforce)
+                }
+
+                fn conditional_enforce_not_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    &self,
+                    cs: CS,
+                    other: &Self,
+                    should_enforce: &Boolean,
+                ) -> Result<(), SynthesisError> {
+                    self.bits
+                        .conditional_enforce_not_equal(cs, &other.bits, should_enforce)
+                }
+            }
+
+            impl<ConstraintF: Field> AllocGadget<$native_type, ConstraintF> for $type_name {
+                fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+                    cs: CS,
+                    value_gen: F,
+                ) -> Result<Self, SynthesisError>
+                where
+                    F: FnOnce() -> Result<T, SynthesisError>,
+                    T: Borrow<$native_type>,
+                {
+                    Self::alloc_bits(cs, value_gen, false)
+                }
+
+                fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
+                    cs: CS,
+                    value_gen: F,
+                ) -> Result<Self, SynthesisError>
+                where
+                    F: FnOnce() -> Result<T, SynthesisError>,
+                    T: Borrow<$native_type>,
+                {
+                    Self::alloc_bits(cs, value_gen, true)
+                }
+            }
+
+            impl $type_name {
+                fn alloc_bits<ConstraintF, CS, F, T>(
+                    mut cs: CS,
+                    value_gen: F,
+                    as_input: bool,
+                ) -> Result<Self, SynthesisError>
+                where
+                    ConstraintF: Field,
+                    CS: ConstraintSystemAbstract<ConstraintF>,
+                    F: FnOnce() -> Result<T, SynthesisError>,
+                    T: Borrow<$native_type>,
+                {
+                    let value = value_gen().map(|val| *val.borrow()).ok();
+
+                    let bits = (0..$bit_size)
+                        .map(|i| {
+                            let cs = cs.ns(|| format!("allocated bit_gadget {}", i));
+                            let bit = || value.map(|val| (val >> i) & 1 == 1).get();
+                            let bit = if as_input {
+                                AllocatedBit::alloc_input(cs, bit)?
+                            } else {
+                                AllocatedBit::alloc(cs, bit)?
+                            };
+                            Ok(Boolean::from(bit))
+                        })
+                        .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+                    Ok(Self { bits, value })
+                }
+            }
+
+            impl<ConstraintF: Field> CondSelectGadget<ConstraintF> for $type_name {
+                fn conditionally_select<CS: ConstraintSystemAbstract<ConstraintF>>(
+                    mut cs: CS,
+                    cond: &Boolean,
+                    true_value: &Self,
+                    false_value: &Self,
+                ) -> Result<Self, SynthesisError> {
+                    let bits = true_value
+                        .bits
+                        .iter()
+                        .zip(&false_value.bits)
+                        .enumerate()
+                        .map(|(i, (t, f))| {
+                            Boolean::conditionally_select(
+                                &mut cs.ns(|| format!("bit {}", i)),
+                                cond,
+                                t,
+                                f,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+                    let value = cond.get_value().and_then(|cond| {
+                        if cond {
+                            true_value.get_value()
+                        } else {
+                            false_value.get_value()
+                        }
+                    });
+
+                    Ok(Self { bits, value })
+                }
+
+                fn cost() -> usize {
+                    $bit_size * <Boolean as CondSelectGadget<ConstraintF>>::cost()
+                }
+            }
+        }
+    };
+}
+
+// Tests for the arithmetic, bitwise, comparison and conversion gadgets shared by all
+// the unsigned integers.
+#[cfg(all(test, feature = "bls12_381"))]
+macro_rules! uint_gadget_tests {
+    ($type_name:ident, $bit_size:expr, $native_type:ident) => {
+        mod uint_test {
+            use super::$type_name;
+            use crate::{fields::fp::FpGadget, prelude::*};
+            use algebra::{fields::bls12_381::Fr, Field};
+            use r1cs_core::{
+                ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
+            };
+            use rand::{Rng, SeedableRng};
+            use rand_xorshift::XorShiftRng;
+            use std::cmp::Ordering;
+
+            const NUM_SAMPLES: usize = 50;
+
+            // Allocate through `AllocGadget`, as `UInt32` and `UInt64` also keep a deprecated
+            // inherent `alloc` taking an `Option`.
+            fn alloc<CS: ConstraintSystemAbstract<Fr>>(cs: CS, value: $native_type) -> $type_name {
+                <$type_name as AllocGadget<$native_type, Fr>>::alloc(cs, || Ok(value)).unwrap()
+            }
+
+            fn flip_bit(cs: &mut ConstraintSystem<Fr>, path: &str) {
+                if cs.get(path).is_zero() {
+                    cs.set(path, Fr::one());
+                } else {
+                    cs.set(path, Fr::zero());
+                }
+            }
+
+            #[test]
+            fn test_add_mod() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..NUM_SAMPLES {
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+                    let a: $native_type = rng.gen();
+                    let b: $native_type = rng.gen();
+                    let c: $native_type = rng.gen();
+
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b);
+                    let c_g = $type_name::constant(c);
+
+                    let r = a_g.add_mod(cs.ns(|| "a + b"), &b_g).unwrap();
+                    let r = r.add_mod(cs.ns(|| "a + b + c"), &c_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), a.wrapping_add(b).wrapping_add(c));
+                    assert!(cs.is_satisfied());
+
+                    // Constants are added for free
+                    let num_constraints = cs.num_constraints();
+                    let r = c_g.add_mod(cs.ns(|| "c + c"), &c_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), c.wrapping_add(c));
+                    assert_eq!(num_constraints, cs.num_constraints());
+
+                    flip_bit(&mut cs, "a + b/alloc result/result bit 0/boolean");
+                    assert!(!cs.is_satisfied());
+                }
+            }
+
+            #[test]
+            fn test_checked_add() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..NUM_SAMPLES {
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+                    let a: $native_type = rng.gen_range(0..$native_type::MAX);
+                    let b: $native_type = rng.gen_range(0..=$native_type::MAX - a - 1);
+
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b);
+
+                    let r = a_g.checked_add(cs.ns(|| "a + b"), &b_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), a + b);
+                    assert!(cs.is_satisfied());
+
+                    // Setting the highest bit of the operands makes the sum overflow
+                    let path = format!("alloc a/allocated bit_gadget {}/boolean", $bit_size - 1);
+                    cs.set(&path, Fr::one());
+                    let path = format!("alloc b/allocated bit_gadget {}/boolean", $bit_size - 1);
+                    cs.set(&path, Fr::one());
+                    assert!(!cs.is_satisfied());
+
+                    // Overflowing values are detected at synthesis time
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+                    let a_g = alloc(cs.ns(|| "alloc a"), $native_type::MAX);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b + 1);
+                    assert!(a_g.checked_add(cs.ns(|| "a + b"), &b_g).is_err());
+                }
+            }
+
+            #[test]
+            fn test_sub() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..NUM_SAMPLES {
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+                    let a: $native_type = rng.gen();
+                    let b: $native_type = rng.gen();
+                    let (a, b) = if a >= b { (a, b) } else { (b, a) };
+
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b);
+
+                    let r = a_g.checked_sub(cs.ns(|| "a - b"), &b_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), a - b);
+                    let r = b_g.sub_mod(cs.ns(|| "b - a"), &a_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), b.wrapping_sub(a));
+                    assert!(cs.is_satisfied());
+
+                    flip_bit(&mut cs, "b - a/alloc result/result bit 0/boolean");
+                    assert!(!cs.is_satisfied());
+                    flip_bit(&mut cs, "b - a/alloc result/result bit 0/boolean");
+
+                    if a != b {
+                        // Swapping the operands makes the subtraction underflow
+                        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+                        let a_g = alloc(cs.ns(|| "alloc a"), a);
+                        let b_g = alloc(cs.ns(|| "alloc b"), b);
+                        assert!(b_g.checked_sub(cs.ns(|| "b - a"), &a_g).is_err());
+                    }
+                }
+            }
+
+            #[test]
+            fn test_mul() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+                let half_max = $native_type::MAX >> ($bit_size / 2);
+
+                for _ in 0..NUM_SAMPLES {
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+                    let a: $native_type = rng.gen();
+                    let b: $native_type = rng.gen();
+
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b);
+
+                    let r = a_g.mul_mod(cs.ns(|| "a * b"), &b_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), a.wrapping_mul(b));
+                    let r = a_g
+                        .mul_mod(cs.ns(|| "a * b constant"), &$type_name::constant(b))
+                        .unwrap();
+                    assert_eq!(r.get_value().unwrap(), a.wrapping_mul(b));
+                    assert!(cs.is_satisfied());
+
+                    flip_bit(&mut cs, "a * b/alloc result/result bit 0/boolean");
+                    assert!(!cs.is_satisfied());
+
+                    // a_small * b_small < 2^N
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+                    let (a_small, b_small) = (a & half_max, b & half_max);
+                    let a_g = alloc(cs.ns(|| "alloc a"), a_small);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b_small);
+
+                    let r = a_g.checked_mul(cs.ns(|| "a * b"), &b_g).unwrap();
+                    assert_eq!(r.get_value().unwrap(), a_small * b_small);
+                    assert!(cs.is_satisfied());
+
+                    // Setting the highest bit of both operands makes the product overflow
+                    let path = format!("alloc a/allocated bit_gadget {}/boolean", $bit_size - 1);
+                    cs.set(&path, Fr::one());
+                    let path = format!("alloc b/allocated bit_gadget {}/boolean", $bit_size - 1);
+                    cs.set(&path, Fr::one());
+                    assert!(!cs.is_satisfied());
+
+                    // Overflowing values are detected at synthesis time
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+                    let a_g = alloc(cs.ns(|| "alloc a"), $native_type::MAX);
+                    let b_g = alloc(cs.ns(|| "alloc b"), 2);
+                    assert!(a_g.checked_mul(cs.ns(|| "a * b"), &b_g).is_err());
+                }
+            }
+
+            #[test]
+            fn test_cmp() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for i in 0..NUM_SAMPLES {
+                    let a: $native_type = rng.gen();
+                    // Check equal operands too
+                    let b: $native_type = if i % 5 == 0 { a } else { rng.gen() };
+
+                    for &(ordering, should_also_check_equality) in &[
+                        (Ordering::Less, false),
+                        (Ordering::Less, true),
+                        (Ordering::Greater, false),
+                        (Ordering::Greater, true),
+                    ] {
+                        let expected =
+                            a.cmp(&b) == ordering || (should_also_check_equality && a == b);
+
+                        let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+                        let a_g = alloc(cs.ns(|| "alloc a"), a);
+                        let b_g = alloc(cs.ns(|| "alloc b"), b);
+                        let is_cmp = a_g
+                            .is_cmp(
+                                cs.ns(|| "is cmp"),
+                                &b_g,
+                                ordering,
+                                should_also_check_equality,
+                            )
+                            .unwrap();
+                        assert_eq!(is_cmp.get_value().unwrap(), expected);
+                        assert!(cs.is_satisfied());
+
+                        a_g.enforce_cmp(
+                            cs.ns(|| "enforce cmp"),
+                            &b_g,
+                            ordering,
+                            should_also_check_equality,
+                        )
+                        .unwrap();
+                        assert_eq!(cs.is_satisfied(), expected);
+                    }
+
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+                    assert!(a_g
+                        .is_cmp(cs.ns(|| "is cmp"), &a_g, Ordering::Equal, true)
+                        .is_err());
+                }
+            }
+
+            #[test]
+            fn test_bitwise() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..NUM_SAMPLES {
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+                    let a: $native_type = rng.gen();
+                    let b: $native_type = rng.gen();
+                    let by = rng.gen_range(0..$bit_size);
+
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+                    let b_g = alloc(cs.ns(|| "alloc b"), b);
+
+                    let check = |r: $type_name, expected: $native_type| {
+                        assert_eq!(r.get_value().unwrap(), expected);
+                        assert_eq!(
+                            $type_name::from_bits_le(&r.to_bits_le())
+                                .get_value()
+                                .unwrap(),
+                            expected
+                        );
+                    };
+
+                    check(a_g.and(cs.ns(|| "a and b"), &b_g).unwrap(), a & b);
+                    check(a_g.or(cs.ns(|| "a or b"), &b_g).unwrap(), a | b);
+                    check(a_g.xor(cs.ns(|| "a xor b"), &b_g).unwrap(), a ^ b);
+                    check(a_g.not(), !a);
+                    check(a_g.shl(by), a << by);
+                    check(a_g.shr(by), a >> by);
+                    check(a_g.rotl(by), a.rotate_left(by as u32));
+                    check(a_g.rotr(by), a.rotate_right(by as u32));
+                    assert!(cs.is_satisfied());
+
+                    let bytes = a_g.to_bytes(cs.ns(|| "a to bytes")).unwrap();
+                    for (byte, expected) in bytes.iter().zip(a.to_le_bytes().iter()) {
+                        assert_eq!(byte.get_value().unwrap(), *expected);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_fp_gadget_conversion() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..NUM_SAMPLES {
+                    let mut cs = ConstraintSystem::<Fr>::new(SynthesisMode::Debug);
+
+                    let a: $native_type = rng.gen();
+                    let a_g = alloc(cs.ns(|| "alloc a"), a);
+
+                    let fe = a_g.to_fp_gadget(cs.ns(|| "to fp gadget")).unwrap();
+                    assert_eq!(fe.get_value().unwrap(), Fr::from(u128::from(a)));
+
+                    let a_g_again =
+                        $type_name::from_fp_gadget(cs.ns(|| "from fp gadget"), &fe).unwrap();
+                    assert_eq!(a_g_again.get_value().unwrap(), a);
+                    a_g.enforce_equal(cs.ns(|| "enforce equal"), &a_g_again)
+                        .unwrap();
+                    assert!(cs.is_satisfied());
+
+                    // A field element equal to 2^N doesn't fit
+                    let two_to_n = Fr::from(2u64).pow(&[$bit_size]);
+                    let fe = FpGadget::<Fr>::alloc(cs.ns(|| "alloc 2^N"), || Ok(two_to_n)).unwrap();
+                    $type_name::from_fp_gadget(cs.ns(|| "2^N from fp gadget"), &fe).unwrap();
+                    assert!(!cs.is_satisfied());
+                }
+            }
+        }
+    };
+}
@@ -2,7 +2,12 @@ use crate::bits::{boolean::Boolean, uint8::UInt8};
 use algebra::Field;
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 
+#[macro_use]
+mod macros;
+
 pub mod boolean;
+pub mod uint128;
+pub mod uint16;
 pub mod uint32;
 pub mod uint64;
 pub mod uint8;
@@ -124,6 +129,19 @@ pub trait ToCompressedBitsGadget<ConstraintF: Field> {
     ) -> Result<Vec<Boolean>, SynthesisError>;
 }
 
+pub trait FromCompressedBitsGadget<ConstraintF: Field>
+where
+    Self: Sized,
+{
+    /// Enforce decompression of an element from its compressed form, as produced by
+    /// `ToCompressedBits` (or `ToCompressedBitsGadget`), i.e. the x coordinate followed
+    /// by the flags, including the parity of the y coordinate.
+    fn from_compressed<CS: ConstraintSystemAbstract<ConstraintF>>(
+        cs: CS,
+        compressed: &[Boolean],
+    ) -> Result<Self, SynthesisError>;
+}
+
 impl<ConstraintF: Field> ToBytesGadget<ConstraintF> for [UInt8] {
     fn to_bytes<CS: ConstraintSystemAbstract<ConstraintF>>(
         &self,
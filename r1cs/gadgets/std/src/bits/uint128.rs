@@ -0,0 +1,7 @@
+//! A module for representing 128 bit unsigned integers over a prime constraint field
+//! (see `impl_uint_gadget` for the supported operations).
+
+impl_uint_gadget!(UInt128, 128, u128);
+
+#[cfg(all(test, feature = "bls12_381"))]
+uint_gadget_tests!(UInt128, 128, u128);
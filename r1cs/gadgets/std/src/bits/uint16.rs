@@ -0,0 +1,7 @@
+//! A module for representing 16 bit unsigned integers over a prime constraint field
+//! (see `impl_uint_gadget` for the supported operations).
+
+impl_uint_gadget!(UInt16, 16, u16);
+
+#[cfg(all(test, feature = "bls12_381"))]
+uint_gadget_tests!(UInt16, 16, u16);
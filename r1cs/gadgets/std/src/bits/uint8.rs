@@ -1,24 +1,15 @@
+//! A module for representing 8 bit unsigned integers over a prime constraint field.
+//! Besides the operations shared by all the unsigned integer gadgets (see `impl_uint_gadget`),
+//! provides functions to allocate and convert vectors of bytes.
 use algebra::{Field, FpParameters, PrimeField, ToConstraintField};
 
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 
-use crate::{boolean::AllocatedBit, fields::fp::FpGadget, prelude::*, Assignment};
-use std::borrow::Borrow;
+use crate::{fields::fp::FpGadget, prelude::*, Assignment};
 
-/// Represents an interpretation of 8 `Boolean` objects as an
-/// unsigned integer.
-#[derive(Clone, Debug)]
-pub struct UInt8 {
-    // Least significant bit_gadget first
-    pub(crate) bits: Vec<Boolean>,
-    pub(crate) value: Option<u8>,
-}
+impl_uint_gadget!(UInt8, 8, u8);
 
 impl UInt8 {
-    pub fn get_value(&self) -> Option<u8> {
-        self.value
-    }
-
     /// Construct a constant vector of `UInt8` from a vector of `u8`
     pub fn constant_vec(values: &[u8]) -> Vec<Self> {
         let mut result = Vec::new();
@@ -28,28 +19,6 @@ impl UInt8 {
         result
     }
 
-    /// Construct a constant `UInt8` from a `u8`
-    pub fn constant(value: u8) -> Self {
-        let mut bits = Vec::with_capacity(8);
-
-        let mut tmp = value;
-        for _ in 0..8 {
-            // If last bit is one, push one.
-            if tmp & 1 == 1 {
-                bits.push(Boolean::constant(true))
-            } else {
-                bits.push(Boolean::constant(false))
-            }
-
-            tmp >>= 1;
-        }
-
-        Self {
-            bits,
-            value: Some(value),
-        }
-    }
-
     pub fn alloc_vec<ConstraintF, CS, T>(
         mut cs: CS,
         values: &[T],
@@ -123,262 +92,6 @@ impl UInt8 {
             .map(Self::from_bits_le)
             .collect())
     }
-
-    /// Turns this `UInt8` into its big-endian byte order representation.
-    pub fn into_bits_be(&self) -> Vec<Boolean> {
-        self.bits.iter().rev().cloned().collect()
-    }
-
-    /// Turns this `UInt8` into its little-endian byte order representation.
-    /// LSB-first means that we can easily get the corresponding field element
-    /// via double and add.
-    pub fn into_bits_le(&self) -> Vec<Boolean> {
-        self.bits.to_vec()
-    }
-
-    /// Converts a little-endian byte order representation of bits into a
-    /// `UInt8`.
-    pub fn from_bits_le(bits: &[Boolean]) -> Self {
-        assert_eq!(bits.len(), 8);
-
-        let bits = bits.to_vec();
-
-        let mut value = Some(0u8);
-        for b in bits.iter().rev() {
-            value.as_mut().map(|v| *v <<= 1);
-
-            match *b {
-                Boolean::Constant(b) => {
-                    if b {
-                        value.as_mut().map(|v| *v |= 1);
-                    }
-                }
-                Boolean::Is(ref b) => match b.get_value() {
-                    Some(true) => {
-                        value.as_mut().map(|v| *v |= 1);
-                    }
-                    Some(false) => {}
-                    None => value = None,
-                },
-                Boolean::Not(ref b) => match b.get_value() {
-                    Some(false) => {
-                        value.as_mut().map(|v| *v |= 1);
-                    }
-                    Some(true) => {}
-                    None => value = None,
-                },
-            }
-        }
-
-        Self { bits, value }
-    }
-
-    /// XOR this `UInt8` with another `UInt8`
-    pub fn xor<ConstraintF, CS>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError>
-    where
-        ConstraintF: Field,
-        CS: ConstraintSystemAbstract<ConstraintF>,
-    {
-        let new_value = match (self.value, other.value) {
-            (Some(a), Some(b)) => Some(a ^ b),
-            _ => None,
-        };
-
-        let bits = self
-            .bits
-            .iter()
-            .zip(other.bits.iter())
-            .enumerate()
-            .map(|(i, (a, b))| Boolean::xor(cs.ns(|| format!("xor of bit_gadget {}", i)), a, b))
-            .collect::<Result<_, _>>()?;
-
-        Ok(Self {
-            bits,
-            value: new_value,
-        })
-    }
-
-    /// OR this `UInt8` with another `UInt8`
-    pub fn or<ConstraintF, CS>(&self, mut cs: CS, other: &Self) -> Result<Self, SynthesisError>
-    where
-        ConstraintF: Field,
-        CS: ConstraintSystemAbstract<ConstraintF>,
-    {
-        let new_value = match (self.value, other.value) {
-            (Some(a), Some(b)) => Some(a | b),
-            _ => None,
-        };
-
-        let bits = self
-            .bits
-            .iter()
-            .zip(other.bits.iter())
-            .enumerate()
-            .map(|(i, (a, b))| Boolean::or(cs.ns(|| format!("or of bit_gadget {}", i)), a, b))
-            .collect::<Result<_, _>>()?;
-
-        Ok(Self {
-            bits,
-            value: new_value,
-        })
-    }
-}
-
-impl PartialEq for UInt8 {
-    fn eq(&self, other: &Self) -> bool {
-        self.value.is_some() && other.value.is_some() && self.value == other.value
-    }
-}
-
-impl Eq for UInt8 {}
-
-impl<ConstraintF: Field> EqGadget<ConstraintF> for UInt8 {
-    fn is_eq<CS: ConstraintSystemAbstract<ConstraintF>>(
-        &self,
-        cs: CS,
-        other: &Self,
-    ) -> Result<Boolean, SynthesisError> {
-        self.bits.as_slice().is_eq(cs, &other.bits)
-    }
-
-    fn conditional_enforce_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
-        &self,
-        cs: CS,
-        other: &Self,
-        condition: &Boolean,
-    ) -> Result<(), SynthesisError> {
-        self.bits
-            .conditional_enforce_equal(cs, &other.bits, condition)
-    }
-
-    fn conditional_enforce_not_equal<CS: ConstraintSystemAbstract<ConstraintF>>(
-        &self,
-        cs: CS,
-        other: &Self,
-        condition: &Boolean,
-    ) -> Result<(), SynthesisError> {
-        self.bits
-            .conditional_enforce_not_equal(cs, &other.bits, condition)
-    }
-}
-
-impl<ConstraintF: Field> AllocGadget<u8, ConstraintF> for UInt8 {
-    fn alloc<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
-        mut cs: CS,
-        value_gen: F,
-    ) -> Result<Self, SynthesisError>
-    where
-        F: FnOnce() -> Result<T, SynthesisError>,
-        T: Borrow<u8>,
-    {
-        let value = value_gen().map(|val| *val.borrow());
-        let values = match value {
-            Ok(mut val) => {
-                let mut v = Vec::with_capacity(8);
-
-                for _ in 0..8 {
-                    v.push(Some(val & 1 == 1));
-                    val >>= 1;
-                }
-
-                v
-            }
-            _ => vec![None; 8],
-        };
-
-        let bits = values
-            .into_iter()
-            .enumerate()
-            .map(|(i, v)| {
-                Ok(Boolean::from(AllocatedBit::alloc(
-                    &mut cs.ns(|| format!("allocated bit_gadget {}", i)),
-                    || v.ok_or(SynthesisError::AssignmentMissing),
-                )?))
-            })
-            .collect::<Result<Vec<_>, SynthesisError>>()?;
-
-        Ok(Self {
-            bits,
-            value: value.ok(),
-        })
-    }
-
-    fn alloc_input<F, T, CS: ConstraintSystemAbstract<ConstraintF>>(
-        mut cs: CS,
-        value_gen: F,
-    ) -> Result<Self, SynthesisError>
-    where
-        F: FnOnce() -> Result<T, SynthesisError>,
-        T: Borrow<u8>,
-    {
-        let value = value_gen().map(|val| *val.borrow());
-        let values = match value {
-            Ok(mut val) => {
-                let mut v = Vec::with_capacity(8);
-                for _ in 0..8 {
-                    v.push(Some(val & 1 == 1));
-                    val >>= 1;
-                }
-
-                v
-            }
-            _ => vec![None; 8],
-        };
-
-        let bits = values
-            .into_iter()
-            .enumerate()
-            .map(|(i, v)| {
-                Ok(Boolean::from(AllocatedBit::alloc_input(
-                    &mut cs.ns(|| format!("allocated bit_gadget {}", i)),
-                    || v.ok_or(SynthesisError::AssignmentMissing),
-                )?))
-            })
-            .collect::<Result<Vec<_>, SynthesisError>>()?;
-
-        Ok(Self {
-            bits,
-            value: value.ok(),
-        })
-    }
-}
-
-impl<ConstraintF: Field> CondSelectGadget<ConstraintF> for UInt8 {
-    fn conditionally_select<CS: ConstraintSystemAbstract<ConstraintF>>(
-        mut cs: CS,
-        cond: &Boolean,
-        true_value: &Self,
-        false_value: &Self,
-    ) -> Result<Self, SynthesisError> {
-        let selected_bits = true_value
-            .bits
-            .iter()
-            .zip(&false_value.bits)
-            .enumerate()
-            .map(|(i, (t, f))| {
-                Boolean::conditionally_select(&mut cs.ns(|| format!("bit {}", i)), cond, t, f)
-            });
-        let mut bits = [Boolean::Constant(false); 8];
-        for (result, new) in bits.iter_mut().zip(selected_bits) {
-            *result = new?;
-        }
-
-        let value = cond.get_value().and_then(|cond| {
-            if cond {
-                true_value.get_value()
-            } else {
-                false_value.get_value()
-            }
-        });
-        Ok(Self {
-            bits: bits.to_vec(),
-            value,
-        })
-    }
-
-    fn cost() -> usize {
-        8 * <Boolean as CondSelectGadget<ConstraintF>>::cost()
-    }
 }
 
 #[cfg(all(test, feature = "bls12_381"))]
@@ -621,3 +334,6 @@ mod test {
         }
     }
 }
+
+#[cfg(all(test, feature = "bls12_381"))]
+uint_gadget_tests!(UInt8, 8, u8);
@@ -622,6 +622,61 @@ impl<P: CubicExtParametersGadget<ConstraintF>, ConstraintF: PrimeField + SquareR
     }
 }
 
+impl<P, ConstraintF> SquareRootFieldGadget<CubicExtField<P>, ConstraintF>
+    for CubicExtFieldGadget<P, ConstraintF>
+where
+    P: CubicExtParametersGadget<ConstraintF>,
+    P::BaseField: SquareRootField,
+    P::BaseFieldGadget: SquareRootFieldGadget<P::BaseField, ConstraintF>,
+    CubicExtField<P>: SquareRootField,
+    ConstraintF: PrimeField + SquareRootField,
+{
+    /// Consistently with `CubicExtField::is_odd`, `self` is odd if its last non
+    /// zero coefficient, starting from `c2`, is odd.
+    fn is_odd<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Boolean, SynthesisError> {
+        let zero = P::BaseFieldGadget::zero(cs.ns(|| "zero"))?;
+        let c2_is_odd = self.c2.is_odd(cs.ns(|| "c2 is odd"))?;
+        let c2_is_zero = self.c2.is_eq(cs.ns(|| "c2 is zero"), &zero)?;
+        let c1_is_odd = self.c1.is_odd(cs.ns(|| "c1 is odd"))?;
+        let c1_is_zero = self.c1.is_eq(cs.ns(|| "c1 is zero"), &zero)?;
+        let c0_is_odd = self.c0.is_odd(cs.ns(|| "c0 is odd"))?;
+
+        // c1 is odd, or c1 is zero and c0 is odd
+        let c1_c0_is_odd = {
+            let c1_is_zero_and_c0_is_odd = Boolean::and(
+                cs.ns(|| "c1 is zero and c0 is odd"),
+                &c1_is_zero,
+                &c0_is_odd,
+            )?;
+            Boolean::or(
+                cs.ns(|| "c1 c0 is odd"),
+                &c1_is_odd,
+                &c1_is_zero_and_c0_is_odd,
+            )?
+        };
+        let c2_is_zero_and_c1_c0_is_odd = Boolean::and(
+            cs.ns(|| "c2 is zero and c1 c0 is odd"),
+            &c2_is_zero,
+            &c1_c0_is_odd,
+        )?;
+        Boolean::or(cs.ns(|| "is odd"), &c2_is_odd, &c2_is_zero_and_c1_c0_is_odd)
+    }
+
+    fn quadratic_non_residue() -> CubicExtField<P> {
+        let mut c0 = P::BaseField::zero();
+        loop {
+            let nr = CubicExtField::<P>::new(c0, P::BaseField::one(), P::BaseField::zero());
+            if nr.legendre().is_qnr() {
+                return nr;
+            }
+            c0 += &P::BaseField::one();
+        }
+    }
+}
+
 impl<P: CubicExtParametersGadget<ConstraintF>, ConstraintF: PrimeField + SquareRootField> PartialEq
     for CubicExtFieldGadget<P, ConstraintF>
 {
@@ -1,11 +1,11 @@
-use algebra::{bytes::ToBytes, FpParameters, PrimeField};
+use algebra::{bytes::ToBytes, FpParameters, PrimeField, SquareRootField};
 use r1cs_core::{
     ConstraintSystemAbstract,
     ConstraintVar::{self, *},
     LinearCombination, SynthesisError,
 };
 
-use std::borrow::Borrow;
+use std::{borrow::Borrow, cmp::Ordering};
 
 use crate::{boolean::AllocatedBit, prelude::*, Assignment};
 
@@ -115,6 +115,222 @@ impl<F: PrimeField> FpGadget<F> {
 
         Ok(bytes)
     }
+
+    /// Reconstruct a field element from its *big endian* bit representation, enforcing
+    /// it to be canonical, i.e. smaller than the modulus. Unlike `from_bits`, `bits` can
+    /// be longer than CAPACITY, e.g. as the output of `to_bits_strict`.
+    pub fn from_bits_strict<CS: ConstraintSystemAbstract<F>>(
+        mut cs: CS,
+        bits: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        // Less than MODULUS_BITS bits can't represent an integer bigger than the modulus
+        if bits.len() >= F::Params::MODULUS_BITS as usize {
+            Boolean::enforce_in_field::<_, _, F>(cs.ns(|| "enforce in field"), bits)?;
+        }
+
+        let mut num = Self::zero(cs.ns(|| "alloc_lc"))?;
+        let mut coeff = F::one();
+        for (j, bit) in bits.iter().rev().enumerate() {
+            num = num.conditionally_add_constant(cs.ns(|| format!("add_bit_{}", j)), bit, coeff)?;
+            coeff.double_in_place();
+        }
+
+        let variable = Self::alloc(cs.ns(|| "variable"), || num.get_value().get())?;
+
+        // num * 1 = variable
+        cs.enforce(
+            || "packing constraint",
+            |lc| lc,
+            |lc| lc,
+            |lc| &variable.variable - &num.variable + lc,
+        );
+        Ok(variable)
+    }
+
+    /// Enforce that `self` is in the range [0, 2^`num_bits`), by unpacking it into
+    /// `num_bits` bits. Costs `num_bits + 1` constraints if `num_bits` is smaller than
+    /// `MODULUS_BITS`, nothing otherwise.
+    pub fn enforce_in_range<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        num_bits: usize,
+    ) -> Result<(), SynthesisError> {
+        let modulus_bits = F::Params::MODULUS_BITS as usize;
+
+        // Any field element is smaller than 2^MODULUS_BITS
+        if num_bits >= modulus_bits {
+            return Ok(());
+        }
+
+        // Less than MODULUS_BITS bits can't be packed into an integer bigger than the
+        // modulus, so the decomposition is unique and no further check is needed.
+        self.to_bits_with_length_restriction(cs.ns(|| "unpack"), modulus_bits - num_bits)?;
+        Ok(())
+    }
+
+    /// Enforce that `self` is smaller than `constant`.
+    /// Unpacking `self` with `to_bits` is enough here: a non canonical decomposition
+    /// of `self` would represent an integer bigger than the modulus, and hence bigger
+    /// than `constant` too.
+    pub fn enforce_smaller_than_constant<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        constant: &F,
+    ) -> Result<(), SynthesisError> {
+        if constant.is_zero() {
+            return Err(SynthesisError::Other(
+                "No field element is smaller than zero".to_owned(),
+            ));
+        }
+        let bits = self.to_bits(cs.ns(|| "to bits"))?;
+        Boolean::enforce_smaller_or_equal_than(
+            cs.ns(|| "enforce smaller or equal than constant - 1"),
+            &bits,
+            (*constant - &F::one()).into_repr(),
+        )
+    }
+
+    /// Enforce that `self` is smaller or equal than (p-1)/2, with p the modulus.
+    pub fn enforce_smaller_or_equal_than_mod_minus_one_div_two<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<(), SynthesisError> {
+        let bits = self.to_bits(cs.ns(|| "to bits"))?;
+        Boolean::enforce_smaller_or_equal_than(
+            cs.ns(|| "enforce smaller or equal than (p-1) div 2"),
+            &bits,
+            F::Params::MODULUS_MINUS_ONE_DIV_TWO,
+        )
+    }
+
+    /// Enforce that `self` is less (resp. greater) than `other` if `ordering` is
+    /// `Ordering::Less` (resp. `Ordering::Greater`). If `should_also_check_equality`
+    /// is set, equality is allowed too.
+    /// Both `self` and `other` are enforced to be smaller or equal than (p-1)/2.
+    pub fn enforce_cmp<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        ordering: Ordering,
+        should_also_check_equality: bool,
+    ) -> Result<(), SynthesisError> {
+        self.enforce_smaller_or_equal_than_mod_minus_one_div_two(
+            cs.ns(|| "self smaller or equal than (p-1) div 2"),
+        )?;
+        other.enforce_smaller_or_equal_than_mod_minus_one_div_two(
+            cs.ns(|| "other smaller or equal than (p-1) div 2"),
+        )?;
+        self.enforce_cmp_unchecked(
+            cs.ns(|| "enforce cmp"),
+            other,
+            ordering,
+            should_also_check_equality,
+        )
+    }
+
+    /// Same as `enforce_cmp`, but without enforcing `self` and `other` to be smaller
+    /// or equal than (p-1)/2: it's up to the caller to guarantee it, as otherwise
+    /// the result is meaningless.
+    pub fn enforce_cmp_unchecked<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        ordering: Ordering,
+        should_also_check_equality: bool,
+    ) -> Result<(), SynthesisError> {
+        let (left, right) = Self::process_cmp_inputs(self, other, ordering)?;
+
+        // As left and right are in [0, (p-1)/2], right - left is in [-(p-1)/2, (p-1)/2],
+        // with the negative values wrapping around to [(p+1)/2, p-1].
+        let diff = right.sub(cs.ns(|| "right - left"), left)?;
+        if should_also_check_equality {
+            diff.enforce_smaller_or_equal_than_mod_minus_one_div_two(
+                cs.ns(|| "right - left smaller or equal than (p-1) div 2"),
+            )
+        } else {
+            // right - left - 1 is in [-(p+1)/2, (p-3)/2], with the negative values
+            // wrapping around to [(p-1)/2, p-1].
+            diff.sub_constant(cs.ns(|| "right - left - 1"), &F::one())?
+                .enforce_smaller_than_constant(
+                    cs.ns(|| "right - left - 1 smaller than (p-1) div 2"),
+                    &F::from_repr(F::Params::MODULUS_MINUS_ONE_DIV_TWO),
+                )
+        }
+    }
+
+    /// Return a `Boolean` which is true if and only if `self` is less (resp. greater)
+    /// than `other`, when `ordering` is `Ordering::Less` (resp. `Ordering::Greater`).
+    /// If `should_also_check_equality` is set, the `Boolean` is true on equality too.
+    /// Both `self` and `other` are enforced to be smaller or equal than (p-1)/2.
+    pub fn is_cmp<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        ordering: Ordering,
+        should_also_check_equality: bool,
+    ) -> Result<Boolean, SynthesisError> {
+        self.enforce_smaller_or_equal_than_mod_minus_one_div_two(
+            cs.ns(|| "self smaller or equal than (p-1) div 2"),
+        )?;
+        other.enforce_smaller_or_equal_than_mod_minus_one_div_two(
+            cs.ns(|| "other smaller or equal than (p-1) div 2"),
+        )?;
+        self.is_cmp_unchecked(
+            cs.ns(|| "is cmp"),
+            other,
+            ordering,
+            should_also_check_equality,
+        )
+    }
+
+    /// Same as `is_cmp`, but without enforcing `self` and `other` to be smaller
+    /// or equal than (p-1)/2: it's up to the caller to guarantee it, as otherwise
+    /// the result is meaningless.
+    pub fn is_cmp_unchecked<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+        ordering: Ordering,
+        should_also_check_equality: bool,
+    ) -> Result<Boolean, SynthesisError> {
+        let (left, right) = Self::process_cmp_inputs(self, other, ordering)?;
+
+        // left <= right if and only if !(right < left)
+        if should_also_check_equality {
+            Ok(right
+                .is_smaller_than_unchecked(cs.ns(|| "right < left"), left)?
+                .not())
+        } else {
+            left.is_smaller_than_unchecked(cs.ns(|| "left < right"), right)
+        }
+    }
+
+    fn process_cmp_inputs<'a>(
+        a: &'a Self,
+        b: &'a Self,
+        ordering: Ordering,
+    ) -> Result<(&'a Self, &'a Self), SynthesisError> {
+        match ordering {
+            Ordering::Less => Ok((a, b)),
+            Ordering::Greater => Ok((b, a)),
+            Ordering::Equal => Err(SynthesisError::Other(
+                "Equality must be checked with EqGadget".to_owned(),
+            )),
+        }
+    }
+
+    // Requires both `self` and `other` to be smaller or equal than (p-1)/2.
+    fn is_smaller_than_unchecked<CS: ConstraintSystemAbstract<F>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Boolean, SynthesisError> {
+        // 2 * (self - other) is in [-(p-1), p-1] and it's even as an integer. It
+        // wraps around the modulus, becoming odd, if and only if self < other.
+        self.sub(cs.ns(|| "self - other"), other)?
+            .double(cs.ns(|| "2 * (self - other)"))?
+            .is_odd(cs.ns(|| "is odd"))
+    }
 }
 
 impl<F: PrimeField> FieldGadget<F, F> for FpGadget<F> {
@@ -375,6 +591,21 @@ impl<F: PrimeField> FieldGadget<F, F> for FpGadget<F> {
     }
 }
 
+impl<F: PrimeField + SquareRootField> SquareRootFieldGadget<F, F> for FpGadget<F> {
+    #[inline]
+    fn is_odd<CS: ConstraintSystemAbstract<F>>(&self, cs: CS) -> Result<Boolean, SynthesisError> {
+        FpGadget::is_odd(self, cs)
+    }
+
+    fn quadratic_non_residue() -> F {
+        let mut nr = F::one();
+        while !nr.legendre().is_qnr() {
+            nr += &F::one();
+        }
+        nr
+    }
+}
+
 impl<F: PrimeField> PartialEq for FpGadget<F> {
     fn eq(&self, other: &Self) -> bool {
         self.value.is_some() && other.value.is_some() && self.value == other.value
@@ -1,5 +1,5 @@
 // use std::ops::{Mul, MulAssign};
-use algebra::{BitIterator, Field};
+use algebra::{BitIterator, Field, PrimeField, SquareRootField};
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 use std::fmt::Debug;
 
@@ -286,10 +286,107 @@ pub trait FieldGadget<F: Field, ConstraintF: Field>:
     fn cost_of_inv() -> usize;
 }
 
+/// The gadget counterpart of `SquareRootField`. Square roots are witnessed and enforced by
+/// squaring, choosing among the two roots the one with the requested parity as defined by
+/// `Field::is_odd`, i.e. consistently with point compression in `algebra::bits`.
+pub trait SquareRootFieldGadget<F: SquareRootField, ConstraintF: PrimeField>:
+    FieldGadget<F, ConstraintF>
+{
+    /// Return a `Boolean` which is true if and only if `self` is odd according
+    /// to `Field::is_odd`.
+    fn is_odd<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Boolean, SynthesisError>;
+
+    /// Return a fixed quadratic non residue of `F`, used by `is_square`.
+    fn quadratic_non_residue() -> F;
+
+    /// Enforce and return the square root of `self` whose parity is `parity`.
+    /// Returns an error if the value of `self` is known but it is not a square,
+    /// and makes the constraint system unsatisfiable if `self` is zero and
+    /// `parity` is true.
+    fn sqrt_with_parity<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        parity: &Boolean,
+    ) -> Result<Self, SynthesisError> {
+        let root = Self::alloc(cs.ns(|| "alloc root"), || {
+            let root = self
+                .get_value()
+                .get()?
+                .sqrt()
+                .ok_or(SynthesisError::Unsatisfiable)?;
+            if root.is_odd() ^ parity.get_value().get()? {
+                Ok(-root)
+            } else {
+                Ok(root)
+            }
+        })?;
+        root.square_equals(cs.ns(|| "root^2 == self"), self)?;
+        root.is_odd(cs.ns(|| "root parity"))?
+            .enforce_equal(cs.ns(|| "enforce root parity"), parity)?;
+        Ok(root)
+    }
+
+    /// Enforce and return the canonical square root of `self`, i.e. the even one.
+    fn sqrt<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        cs: CS,
+    ) -> Result<Self, SynthesisError> {
+        self.sqrt_with_parity(cs, &Boolean::constant(false))
+    }
+
+    /// Enforce and return the canonical square root of `self` if `cond` is true,
+    /// zero otherwise. `self` is not required to be a square if `cond` is false.
+    fn conditional_sqrt<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+        cond: &Boolean,
+    ) -> Result<Self, SynthesisError> {
+        let zero = Self::zero(cs.ns(|| "zero"))?;
+        let to_be_rooted = Self::conditionally_select(cs.ns(|| "select"), cond, self, &zero)?;
+        to_be_rooted.sqrt(cs.ns(|| "sqrt"))
+    }
+
+    /// Return a `Boolean` which is true if and only if `self` is a square
+    /// (zero included), i.e. if its Legendre symbol is not -1.
+    fn is_square<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Boolean, SynthesisError> {
+        let is_square = Boolean::alloc(cs.ns(|| "alloc is square"), || {
+            Ok(!self.get_value().get()?.legendre().is_qnr())
+        })?;
+
+        // A non zero `self` is a square if and only if `self` times a non residue
+        // is not: enforce the existence of a root of the one selected by `is_square`.
+        let self_times_nr =
+            self.mul_by_constant(cs.ns(|| "self * nr"), &Self::quadratic_non_residue())?;
+        let square = Self::conditionally_select(
+            cs.ns(|| "select square"),
+            &is_square,
+            self,
+            &self_times_nr,
+        )?;
+        square.sqrt(cs.ns(|| "sqrt"))?;
+
+        // Zero is a square too, so `is_square` can be false only if `self` is not zero
+        let zero = Self::zero(cs.ns(|| "zero"))?;
+        self.conditional_enforce_not_equal(
+            cs.ns(|| "self != 0 if not square"),
+            &zero,
+            &is_square.not(),
+        )?;
+
+        Ok(is_square)
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use crate::{fields::fp::FpGadget, prelude::*};
-    use algebra::{leading_zeros, BitIterator, Field, PrimeField, UniformRand};
+    use algebra::{leading_zeros, BitIterator, Field, PrimeField, SquareRootField, UniformRand};
     use r1cs_core::{
         ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
     };
@@ -782,4 +879,317 @@ pub(crate) mod tests {
             );
         }
     }
+
+    #[allow(dead_code)]
+    pub(crate) fn range_fp_gadget_test<ConstraintF: PrimeField>() {
+        use crate::algebra::FpParameters;
+
+        let modulus_bits = ConstraintF::Params::MODULUS_BITS as usize;
+
+        let check_in_range = |value: ConstraintF, num_bits: usize| -> bool {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let value_g =
+                FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc value"), || Ok(value)).unwrap();
+            value_g
+                .enforce_in_range(cs.ns(|| "enforce in range"), num_bits)
+                .unwrap();
+            cs.is_satisfied()
+        };
+
+        let two = ConstraintF::from(2u64);
+        for &num_bits in [0, 1, 2, 63, 64, 65, modulus_bits - 1].iter() {
+            let pow = two.pow([num_bits as u64]);
+            assert!(check_in_range(ConstraintF::zero(), num_bits));
+            assert!(check_in_range(pow - &ConstraintF::one(), num_bits));
+            assert!(!check_in_range(pow, num_bits));
+            assert!(!check_in_range(-ConstraintF::one(), num_bits));
+        }
+
+        // Any field element is in range when num_bits >= MODULUS_BITS
+        assert!(check_in_range(-ConstraintF::one(), modulus_bits));
+        assert!(check_in_range(-ConstraintF::one(), modulus_bits + 1));
+    }
+
+    #[allow(dead_code)]
+    pub(crate) fn smaller_than_constant_fp_gadget_test<ConstraintF: PrimeField>() {
+        use crate::algebra::FpParameters;
+
+        let mut rng = thread_rng();
+
+        let check_smaller_than = |value: ConstraintF, constant: ConstraintF| -> bool {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let value_g =
+                FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc value"), || Ok(value)).unwrap();
+            value_g
+                .enforce_smaller_than_constant(cs.ns(|| "enforce smaller than"), &constant)
+                .unwrap();
+            cs.is_satisfied()
+        };
+
+        let one = ConstraintF::one();
+        let half = ConstraintF::from_repr(ConstraintF::Params::MODULUS_MINUS_ONE_DIV_TWO);
+        let constants = vec![
+            one,
+            one.double(),
+            half,
+            half + &one,
+            -one,
+            ConstraintF::rand(&mut rng),
+        ];
+        for constant in constants {
+            assert!(check_smaller_than(ConstraintF::zero(), constant));
+            assert!(check_smaller_than(constant - &one, constant));
+            assert!(!check_smaller_than(constant, constant));
+            assert!(!check_smaller_than(-one, constant));
+        }
+
+        // Nothing is smaller than zero
+        let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+        let zero_g =
+            FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc zero"), || Ok(ConstraintF::zero()))
+                .unwrap();
+        assert!(zero_g
+            .enforce_smaller_than_constant(
+                cs.ns(|| "enforce smaller than zero"),
+                &ConstraintF::zero()
+            )
+            .is_err());
+    }
+
+    #[allow(dead_code)]
+    pub(crate) fn cmp_fp_gadget_test<ConstraintF: PrimeField>() {
+        use crate::algebra::FpParameters;
+        use std::cmp::Ordering;
+
+        let mut rng = thread_rng();
+
+        // Check both the enforce and the is variants, returning whether the
+        // enforcing constraint system is satisfied and the computed verdict,
+        // if the constraint system computing it is satisfied
+        let cmp = |a: ConstraintF,
+                   b: ConstraintF,
+                   ordering: Ordering,
+                   should_also_check_equality: bool,
+                   checked: bool|
+         -> (bool, Option<bool>) {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let a_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+            let b_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc b"), || Ok(b)).unwrap();
+            if checked {
+                a_g.enforce_cmp(
+                    cs.ns(|| "enforce cmp"),
+                    &b_g,
+                    ordering,
+                    should_also_check_equality,
+                )
+            } else {
+                a_g.enforce_cmp_unchecked(
+                    cs.ns(|| "enforce cmp"),
+                    &b_g,
+                    ordering,
+                    should_also_check_equality,
+                )
+            }
+            .unwrap();
+            let enforced = cs.is_satisfied();
+
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let a_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(a)).unwrap();
+            let b_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc b"), || Ok(b)).unwrap();
+            let verdict = if checked {
+                a_g.is_cmp(
+                    cs.ns(|| "is cmp"),
+                    &b_g,
+                    ordering,
+                    should_also_check_equality,
+                )
+            } else {
+                a_g.is_cmp_unchecked(
+                    cs.ns(|| "is cmp"),
+                    &b_g,
+                    ordering,
+                    should_also_check_equality,
+                )
+            }
+            .unwrap();
+
+            (enforced, verdict.get_value().filter(|_| cs.is_satisfied()))
+        };
+
+        let one = ConstraintF::one();
+        let half = ConstraintF::from_repr(ConstraintF::Params::MODULUS_MINUS_ONE_DIV_TWO);
+        let random = loop {
+            let r = ConstraintF::rand(&mut rng);
+            if r < half {
+                break r;
+            }
+        };
+
+        // Pairs (a, b) with a < b <= (p-1)/2
+        let pairs = [
+            (ConstraintF::zero(), one),
+            (ConstraintF::zero(), half),
+            (half - &one, half),
+            (random, half),
+        ];
+        for checked in [true, false].iter() {
+            for &(a, b) in pairs.iter() {
+                for &eq in [true, false].iter() {
+                    assert_eq!(cmp(a, b, Ordering::Less, eq, *checked), (true, Some(true)));
+                    assert_eq!(
+                        cmp(b, a, Ordering::Greater, eq, *checked),
+                        (true, Some(true))
+                    );
+                    assert_eq!(
+                        cmp(b, a, Ordering::Less, eq, *checked),
+                        (false, Some(false))
+                    );
+                    assert_eq!(
+                        cmp(a, b, Ordering::Greater, eq, *checked),
+                        (false, Some(false))
+                    );
+                }
+                for &(ordering, x) in [(Ordering::Less, a), (Ordering::Greater, b)].iter() {
+                    assert_eq!(cmp(x, x, ordering, true, *checked), (true, Some(true)));
+                    assert_eq!(cmp(x, x, ordering, false, *checked), (false, Some(false)));
+                }
+            }
+        }
+
+        // Operands bigger than (p-1)/2 make the checked variants unsatisfiable
+        for &big in [half + &one, -one].iter() {
+            assert_eq!(
+                cmp(ConstraintF::zero(), big, Ordering::Less, false, true),
+                (false, None)
+            );
+            assert_eq!(cmp(big, one, Ordering::Greater, true, true), (false, None));
+        }
+
+        // Equality must be checked with EqGadget
+        let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+        let a_g = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc a"), || Ok(one)).unwrap();
+        assert!(a_g
+            .is_cmp_unchecked(cs.ns(|| "is cmp"), &a_g, Ordering::Equal, true)
+            .is_err());
+    }
+
+    #[allow(dead_code)]
+    pub(crate) fn sqrt_field_gadget_test<
+        FE: SquareRootField,
+        ConstraintF: PrimeField,
+        F: SquareRootFieldGadget<FE, ConstraintF>,
+    >() {
+        let mut rng = thread_rng();
+
+        // Parity
+        let values = vec![
+            FE::zero(),
+            FE::one(),
+            -FE::one(),
+            FE::one().double(),
+            FE::rand(&mut rng),
+            FE::rand(&mut rng),
+        ];
+        let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+        for (i, value) in values.into_iter().enumerate() {
+            let value_g = F::alloc(cs.ns(|| format!("alloc value {}", i)), || Ok(value)).unwrap();
+            let is_odd = value_g
+                .is_odd(cs.ns(|| format!("value {} is odd", i)))
+                .unwrap();
+            assert_eq!(value.is_odd(), is_odd.get_value().unwrap());
+        }
+        assert!(cs.is_satisfied());
+
+        // Square roots of squares, with both parities
+        for i in 0..5 {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let root = if i == 0 {
+                FE::zero()
+            } else {
+                FE::rand(&mut rng)
+            };
+            let square = root.square();
+            let square_g = F::alloc(cs.ns(|| "alloc square"), || Ok(square)).unwrap();
+
+            let even_root = square_g
+                .sqrt(cs.ns(|| "sqrt"))
+                .unwrap()
+                .get_value()
+                .unwrap();
+            assert!(!even_root.is_odd());
+            assert!(even_root == root || even_root == -root);
+
+            if !root.is_zero() {
+                let odd_root = square_g
+                    .sqrt_with_parity(cs.ns(|| "odd sqrt"), &Boolean::constant(true))
+                    .unwrap()
+                    .get_value()
+                    .unwrap();
+                assert_eq!(odd_root, -even_root);
+            }
+
+            let is_square = square_g.is_square(cs.ns(|| "is square")).unwrap();
+            assert!(is_square.get_value().unwrap());
+            assert!(cs.is_satisfied());
+
+            // Maliciously claiming that square is not a square must fail
+            cs.set("is square/alloc is square/boolean", ConstraintF::zero());
+            assert!(!cs.is_satisfied());
+        }
+
+        // Non squares
+        let non_residue = F::quadratic_non_residue();
+        assert!(non_residue.legendre().is_qnr());
+        for _ in 0..5 {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+            let non_square = FE::rand(&mut rng).square() * &non_residue;
+            if non_square.is_zero() {
+                continue;
+            }
+            let non_square_g = F::alloc(cs.ns(|| "alloc non square"), || Ok(non_square)).unwrap();
+
+            // Synthesis errors out when rooting a non square, leaving the cs unusable
+            {
+                let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+                let non_square_g =
+                    F::alloc(cs.ns(|| "alloc non square"), || Ok(non_square)).unwrap();
+                assert!(non_square_g.sqrt(cs.ns(|| "sqrt")).is_err());
+            }
+
+            let is_square = non_square_g.is_square(cs.ns(|| "is square")).unwrap();
+            assert!(!is_square.get_value().unwrap());
+
+            // A non square can't be rooted conditionally, unless the condition is false
+            let cond_root = non_square_g
+                .conditional_sqrt(cs.ns(|| "conditional sqrt"), &Boolean::constant(false))
+                .unwrap();
+            assert!(cond_root.get_value().unwrap().is_zero());
+            assert!(cs.is_satisfied());
+
+            // Maliciously claiming that non square is a square must fail
+            cs.set("is square/alloc is square/boolean", ConstraintF::one());
+            assert!(!cs.is_satisfied());
+        }
+
+        // Conditional square root of a square
+        let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+        let square = FE::rand(&mut rng).square();
+        let square_g = F::alloc(cs.ns(|| "alloc square"), || Ok(square)).unwrap();
+        let cond = Boolean::alloc(cs.ns(|| "alloc cond"), || Ok(true)).unwrap();
+        let cond_root = square_g
+            .conditional_sqrt(cs.ns(|| "conditional sqrt"), &cond)
+            .unwrap()
+            .get_value()
+            .unwrap();
+        assert_eq!(cond_root.square(), square);
+        assert!(!cond_root.is_odd());
+        assert!(cs.is_satisfied());
+
+        // Zero is a square
+        let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+        let zero_g = F::zero(cs.ns(|| "zero")).unwrap();
+        let is_square = zero_g.is_square(cs.ns(|| "is square")).unwrap();
+        assert!(is_square.get_value().unwrap());
+        assert!(cs.is_satisfied());
+    }
 }
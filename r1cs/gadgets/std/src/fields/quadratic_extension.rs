@@ -488,6 +488,44 @@ impl<P: QuadExtParametersGadget<ConstraintF>, ConstraintF: PrimeField + SquareRo
     }
 }
 
+impl<P, ConstraintF> SquareRootFieldGadget<QuadExtField<P>, ConstraintF>
+    for QuadExtFieldGadget<P, ConstraintF>
+where
+    P: QuadExtParametersGadget<ConstraintF>,
+    P::BaseField: SquareRootField,
+    P::BaseFieldGadget: SquareRootFieldGadget<P::BaseField, ConstraintF>,
+    ConstraintF: PrimeField + SquareRootField,
+{
+    /// Consistently with `QuadExtField::is_odd`, `self` is odd if `c1` is odd, or
+    /// if `c1` is zero and `c0` is odd.
+    fn is_odd<CS: ConstraintSystemAbstract<ConstraintF>>(
+        &self,
+        mut cs: CS,
+    ) -> Result<Boolean, SynthesisError> {
+        let zero = P::BaseFieldGadget::zero(cs.ns(|| "zero"))?;
+        let c1_is_odd = self.c1.is_odd(cs.ns(|| "c1 is odd"))?;
+        let c1_is_zero = self.c1.is_eq(cs.ns(|| "c1 is zero"), &zero)?;
+        let c0_is_odd = self.c0.is_odd(cs.ns(|| "c0 is odd"))?;
+        let c1_is_zero_and_c0_is_odd = Boolean::and(
+            cs.ns(|| "c1 is zero and c0 is odd"),
+            &c1_is_zero,
+            &c0_is_odd,
+        )?;
+        Boolean::or(cs.ns(|| "is odd"), &c1_is_odd, &c1_is_zero_and_c0_is_odd)
+    }
+
+    fn quadratic_non_residue() -> QuadExtField<P> {
+        let mut c0 = P::BaseField::zero();
+        loop {
+            let nr = QuadExtField::<P>::new(c0, P::BaseField::one());
+            if nr.legendre().is_qnr() {
+                return nr;
+            }
+            c0 += &P::BaseField::one();
+        }
+    }
+}
+
 impl<P: QuadExtParametersGadget<ConstraintF>, ConstraintF: PrimeField + SquareRootField> PartialEq
     for QuadExtFieldGadget<P, ConstraintF>
 {
@@ -1435,7 +1435,8 @@ where
 }
 
 use crate::fields::fp::FpGadget;
-use crate::ToCompressedBitsGadget;
+use crate::{FromCompressedBitsGadget, ToCompressedBitsGadget};
+use algebra::{FpParameters, SquareRootField};
 impl<ConstraintF> ToCompressedBitsGadget<ConstraintF> for CompressAffinePointGadget<ConstraintF>
 where
     ConstraintF: PrimeField,
@@ -1454,3 +1455,64 @@ where
         Ok(compressed_bits)
     }
 }
+
+impl<P, ConstraintF> FromCompressedBitsGadget<ConstraintF>
+    for AffineGadget<P, ConstraintF, FpGadget<ConstraintF>>
+where
+    P: SWModelParameters<BaseField = ConstraintF>,
+    ConstraintF: PrimeField + SquareRootField,
+{
+    /// Enforce decompression of a point from the big endian bits of its x coordinate,
+    /// followed by the infinity flag and the parity of the y coordinate.
+    /// Both the curve equation and the parity of y are enforced, while membership to
+    /// the prime order subgroup is not: it's up to the caller to enforce it, if needed.
+    fn from_compressed<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        compressed: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let x_len = ConstraintF::Params::MODULUS_BITS as usize;
+        if compressed.len() != x_len + 2 {
+            return Err(SynthesisError::Other(format!(
+                "Invalid compressed point length. Expected: {}, found: {}",
+                x_len + 2,
+                compressed.len()
+            )));
+        }
+        let infinity = compressed[x_len];
+        let parity = compressed[x_len + 1];
+
+        let x = FpGadget::<ConstraintF>::from_bits_strict(
+            cs.ns(|| "read x coordinate"),
+            &compressed[..x_len],
+        )?;
+
+        // The point at infinity is compressed with x = 0 and parity = 0
+        let zero = FpGadget::<ConstraintF>::zero(cs.ns(|| "zero"))?;
+        x.conditional_enforce_equal(cs.ns(|| "x == 0 if infinity"), &zero, &infinity)?;
+
+        // y^2 = x^3 + a*x + b, or y = 0 if infinity, which in turn enforces parity = 0
+        let x3_plus_ax_plus_b = x
+            .square(cs.ns(|| "x^2"))?
+            .add_constant(cs.ns(|| "x^2 + a"), &P::COEFF_A)?
+            .mul(cs.ns(|| "x^3 + a*x"), &x)?
+            .add_constant(cs.ns(|| "x^3 + a*x + b"), &P::COEFF_B)?;
+        let y_squared = FpGadget::<ConstraintF>::conditionally_select(
+            cs.ns(|| "select y^2"),
+            &infinity,
+            &zero,
+            &x3_plus_ax_plus_b,
+        )?;
+        let y = y_squared.sqrt_with_parity(cs.ns(|| "y"), &parity)?;
+
+        // Comply with the representation of the point at infinity used by the gadget
+        let one = FpGadget::<ConstraintF>::one(cs.ns(|| "one"))?;
+        let y = FpGadget::<ConstraintF>::conditionally_select(
+            cs.ns(|| "select y"),
+            &infinity,
+            &one,
+            &y,
+        )?;
+
+        Ok(Self::new(x, y, infinity))
+    }
+}
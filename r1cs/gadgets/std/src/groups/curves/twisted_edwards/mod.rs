@@ -3,12 +3,12 @@ use algebra::{
         twisted_edwards_extended::GroupAffine as TEAffine, MontgomeryModelParameters,
         TEModelParameters,
     },
-    BitIterator, Field,
+    BitIterator, Field, FpParameters, PrimeField, SquareRootField,
 };
 
 use r1cs_core::{ConstraintSystemAbstract, SynthesisError};
 
-use crate::prelude::*;
+use crate::{fields::fp::FpGadget, prelude::*, Assignment, FromCompressedBitsGadget};
 
 use std::{borrow::Borrow, marker::PhantomData};
 
@@ -1504,6 +1504,58 @@ where
     }
 }
 
+impl<P, ConstraintF> FromCompressedBitsGadget<ConstraintF>
+    for AffineGadget<P, ConstraintF, FpGadget<ConstraintF>>
+where
+    P: TEModelParameters<BaseField = ConstraintF>,
+    ConstraintF: PrimeField + SquareRootField,
+{
+    /// Enforce decompression of a point from the big endian bits of its x coordinate,
+    /// followed by the parity of the y coordinate.
+    /// Both the curve equation and the parity of y are enforced, while membership to
+    /// the prime order subgroup is not: it's up to the caller to enforce it, if needed.
+    /// The curve is assumed to be complete, i.e. d to be a non square.
+    fn from_compressed<CS: ConstraintSystemAbstract<ConstraintF>>(
+        mut cs: CS,
+        compressed: &[Boolean],
+    ) -> Result<Self, SynthesisError> {
+        let x_len = ConstraintF::Params::MODULUS_BITS as usize;
+        if compressed.len() != x_len + 1 {
+            return Err(SynthesisError::Other(format!(
+                "Invalid compressed point length. Expected: {}, found: {}",
+                x_len + 1,
+                compressed.len()
+            )));
+        }
+        let parity = compressed[x_len];
+
+        let x = FpGadget::<ConstraintF>::from_bits_strict(
+            cs.ns(|| "read x coordinate"),
+            &compressed[..x_len],
+        )?;
+
+        // y^2 = (1 - a*x^2)/(1 - d*x^2), where the denominator is never zero
+        // for complete curves.
+        let x2 = x.square(cs.ns(|| "x^2"))?;
+        let numerator = x2
+            .mul_by_constant(cs.ns(|| "a*x^2"), &P::COEFF_A)?
+            .negate(cs.ns(|| "-a*x^2"))?
+            .add_constant(cs.ns(|| "1 - a*x^2"), &ConstraintF::one())?;
+        let denominator = x2
+            .mul_by_constant(cs.ns(|| "d*x^2"), &P::COEFF_D)?
+            .negate(cs.ns(|| "-d*x^2"))?
+            .add_constant(cs.ns(|| "1 - d*x^2"), &ConstraintF::one())?;
+        let y_squared = FpGadget::<ConstraintF>::alloc(cs.ns(|| "alloc y^2"), || {
+            Ok(numerator.get_value().get()? * &denominator.get_value().get()?.inverse().get()?)
+        })?;
+        y_squared.mul_equals(cs.ns(|| "check y^2"), &denominator, &numerator)?;
+
+        let y = y_squared.sqrt_with_parity(cs.ns(|| "y"), &parity)?;
+
+        Ok(Self::new(x, y))
+    }
+}
+
 #[cfg(test)]
 #[allow(dead_code)]
 pub(crate) fn test<ConstraintF, P, GG>()
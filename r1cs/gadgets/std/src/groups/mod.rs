@@ -473,8 +473,8 @@ pub(crate) fn scalar_bits_to_constant_length<
 #[cfg(test)]
 pub(crate) mod test {
     use algebra::{
-        BigInteger, EndoMulCurve, Field, FpParameters, Group, PrimeField, ProjectiveCurve, ToBits,
-        UniformRand,
+        AffineCurve, BigInteger, EndoMulCurve, Field, FpParameters, Group, PrimeField,
+        ProjectiveCurve, ToBits, UniformRand,
     };
     use r1cs_core::{
         ConstraintSystem, ConstraintSystemAbstract, ConstraintSystemDebugger, SynthesisMode,
@@ -482,6 +482,7 @@ pub(crate) mod test {
 
     use crate::groups::EndoMulCurveGadget;
     use crate::prelude::*;
+    use crate::FromCompressedBitsGadget;
     use rand::thread_rng;
 
     #[allow(dead_code)]
@@ -830,4 +831,57 @@ pub(crate) mod test {
 
         assert_eq!(r_native, r);
     }
+
+    #[allow(dead_code)]
+    pub(crate) fn from_compressed_test<ConstraintF, G, A, GG>()
+    where
+        ConstraintF: Field,
+        G: Group,
+        A: AffineCurve + Into<G>,
+        GG: GroupGadget<G, ConstraintF, Value = G> + FromCompressedBitsGadget<ConstraintF>,
+    {
+        let mut rng = thread_rng();
+
+        let mut points = vec![A::zero(), A::prime_subgroup_generator()];
+        for _ in 0..5 {
+            points.push(A::Projective::rand(&mut rng).into_affine());
+        }
+
+        for (i, point) in points.into_iter().enumerate() {
+            let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+
+            let compressed = point.compress();
+            let compressed_len = compressed.len();
+            let compressed_g =
+                Vec::<Boolean>::alloc(cs.ns(|| "alloc compressed"), || Ok(compressed)).unwrap();
+            let point_g = GG::from_compressed(cs.ns(|| "decompress"), &compressed_g).unwrap();
+            assert_eq!(point_g.get_value().unwrap(), point.into(), "point {}", i);
+            assert!(cs.is_satisfied());
+
+            // Flipping the parity of the y coordinate must be detected
+            let parity_path = format!("alloc compressed/value_{}/boolean", compressed_len - 1);
+            let parity = cs.get(&parity_path);
+            cs.set(&parity_path, ConstraintF::one() - &parity);
+            assert!(!cs.is_satisfied());
+            cs.set(&parity_path, parity);
+            assert!(cs.is_satisfied());
+
+            // Changing the x coordinate must be detected too
+            let x_bit_path = format!("alloc compressed/value_{}/boolean", compressed_len / 2);
+            let x_bit = cs.get(&x_bit_path);
+            cs.set(&x_bit_path, ConstraintF::one() - &x_bit);
+            assert!(!cs.is_satisfied());
+        }
+
+        // A wrong length is rejected
+        let mut cs = ConstraintSystem::<ConstraintF>::new(SynthesisMode::Debug);
+        let compressed = A::prime_subgroup_generator().compress();
+        let compressed_g =
+            Vec::<Boolean>::alloc(
+                cs.ns(|| "alloc compressed"),
+                || Ok(compressed[1..].to_vec()),
+            )
+            .unwrap();
+        assert!(GG::from_compressed(cs.ns(|| "decompress"), &compressed_g).is_err());
+    }
 }
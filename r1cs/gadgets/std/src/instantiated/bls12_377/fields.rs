@@ -19,9 +19,14 @@ fn bls12_377_field_gadgets_test() {
     even_odd_fp_gadget_test::<Fq>();
     from_bits_fp_gadget_test::<Fq>();
     bit_fp_gadgets_test::<Fq>();
+    range_fp_gadget_test::<Fq>();
+    smaller_than_constant_fp_gadget_test::<Fq>();
+    cmp_fp_gadget_test::<Fq>();
+    sqrt_field_gadget_test::<_, Fq, FqGadget>();
 
     field_test::<_, Fq, Fq2Gadget>();
     frobenius_tests::<Fq2, Fq, Fq2Gadget>(13);
+    sqrt_field_gadget_test::<Fq2, Fq, Fq2Gadget>();
 
     field_test::<_, Fq, Fq6Gadget>();
     frobenius_tests::<Fq6, Fq, Fq6Gadget>(13);
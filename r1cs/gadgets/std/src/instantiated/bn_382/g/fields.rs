@@ -15,4 +15,7 @@ fn bn382_g_field_gadgets_test() {
     even_odd_fp_gadget_test::<Fr>();
     from_bits_fp_gadget_test::<Fr>();
     bit_fp_gadgets_test::<Fr>();
+    range_fp_gadget_test::<Fr>();
+    smaller_than_constant_fp_gadget_test::<Fr>();
+    cmp_fp_gadget_test::<Fr>();
 }
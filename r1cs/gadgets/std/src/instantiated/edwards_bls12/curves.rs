@@ -8,4 +8,6 @@ pub type EdwardsBlsGadget = AffineGadget<EdwardsParameters, Fq, FqGadget>;
 #[test]
 fn test() {
     crate::groups::curves::twisted_edwards::test::<_, EdwardsParameters, EdwardsBlsGadget>();
+    type Affine = algebra::curves::twisted_edwards_extended::GroupAffine<EdwardsParameters>;
+    crate::groups::test::from_compressed_test::<_, Affine, Affine, EdwardsBlsGadget>();
 }
@@ -13,4 +13,7 @@ fn test() {
     even_odd_fp_gadget_test::<Fq>();
     from_bits_fp_gadget_test::<Fq>();
     bit_fp_gadgets_test::<Fq>();
+    range_fp_gadget_test::<Fq>();
+    smaller_than_constant_fp_gadget_test::<Fq>();
+    cmp_fp_gadget_test::<Fq>();
 }
@@ -8,4 +8,6 @@ pub type JubJubGadget = AffineGadget<JubJubParameters, Fq, FqGadget>;
 #[test]
 fn test() {
     crate::groups::curves::twisted_edwards::test::<Fq, _, JubJubGadget>();
+    type Affine = algebra::curves::twisted_edwards_extended::GroupAffine<JubJubParameters>;
+    crate::groups::test::from_compressed_test::<_, Affine, Affine, JubJubGadget>();
 }
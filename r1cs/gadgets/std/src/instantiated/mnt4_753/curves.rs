@@ -9,8 +9,11 @@ pub type G2PreparedGadget = mnt4::G2PreparedGadget<Parameters>;
 
 #[test]
 fn test() {
+    use algebra::curves::mnt4753::G1Affine;
+
     crate::groups::test::group_test_with_incomplete_add::<_, _, G1Gadget>();
     crate::groups::test::group_test_with_incomplete_add::<_, _, G2Gadget>();
     crate::groups::test::mul_bits_test::<_, _, G1Gadget>();
     crate::groups::test::mul_bits_test::<_, _, G2Gadget>();
+    crate::groups::test::from_compressed_test::<_, _, G1Affine, G1Gadget>();
 }
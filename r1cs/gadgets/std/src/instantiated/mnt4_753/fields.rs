@@ -18,6 +18,9 @@ fn mnt4_753_field_gadgets_test() {
     even_odd_fp_gadget_test::<Fq>();
     from_bits_fp_gadget_test::<Fq>();
     bit_fp_gadgets_test::<Fq>();
+    range_fp_gadget_test::<Fq>();
+    smaller_than_constant_fp_gadget_test::<Fq>();
+    cmp_fp_gadget_test::<Fq>();
 
     field_test::<_, Fq, Fq2Gadget>();
     frobenius_tests::<Fq2, Fq, Fq2Gadget>(13);
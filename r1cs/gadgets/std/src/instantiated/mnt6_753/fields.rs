@@ -18,9 +18,13 @@ fn mnt6_753_field_gadgets_test() {
     even_odd_fp_gadget_test::<Fq>();
     from_bits_fp_gadget_test::<Fq>();
     bit_fp_gadgets_test::<Fq>();
+    range_fp_gadget_test::<Fq>();
+    smaller_than_constant_fp_gadget_test::<Fq>();
+    cmp_fp_gadget_test::<Fq>();
 
     field_test::<_, Fq, Fq3Gadget>();
     frobenius_tests::<Fq3, Fq, Fq3Gadget>(13);
+    sqrt_field_gadget_test::<Fq3, Fq, Fq3Gadget>();
 
     field_test::<_, Fq, Fq6Gadget>();
     frobenius_tests::<Fq6, Fq, Fq6Gadget>(13);
@@ -12,14 +12,20 @@ pub type TweedleDumGadget = AffineGadget<TweedledumParameters, Fr, FrGadget>;
 
 #[test]
 fn test_dee() {
+    use algebra::curves::tweedle::dee::Affine as DeeAffine;
+
     crate::groups::test::group_test_with_incomplete_add::<_, _, TweedleDeeGadget>();
     crate::groups::test::mul_bits_test::<_, _, TweedleDeeGadget>();
     crate::groups::test::endo_mul_test::<_, _, TweedleDeeGadget>();
+    crate::groups::test::from_compressed_test::<_, _, DeeAffine, TweedleDeeGadget>();
 }
 
 #[test]
 fn test_dum() {
+    use algebra::curves::tweedle::dum::Affine as DumAffine;
+
     crate::groups::test::group_test_with_incomplete_add::<_, _, TweedleDumGadget>();
     crate::groups::test::mul_bits_test::<_, _, TweedleDumGadget>();
     crate::groups::test::endo_mul_test::<_, _, TweedleDumGadget>();
+    crate::groups::test::from_compressed_test::<_, _, DumAffine, TweedleDumGadget>();
 }
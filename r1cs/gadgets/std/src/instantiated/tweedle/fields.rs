@@ -14,6 +14,10 @@ fn test_fq() {
     even_odd_fp_gadget_test::<Fq>();
     from_bits_fp_gadget_test::<Fq>();
     bit_fp_gadgets_test::<Fq>();
+    range_fp_gadget_test::<Fq>();
+    smaller_than_constant_fp_gadget_test::<Fq>();
+    cmp_fp_gadget_test::<Fq>();
+    sqrt_field_gadget_test::<_, Fq, FqGadget>();
 }
 
 #[test]
@@ -26,4 +30,8 @@ fn test_fr() {
     even_odd_fp_gadget_test::<Fr>();
     from_bits_fp_gadget_test::<Fr>();
     bit_fp_gadgets_test::<Fr>();
+    range_fp_gadget_test::<Fr>();
+    smaller_than_constant_fp_gadget_test::<Fr>();
+    cmp_fp_gadget_test::<Fr>();
+    sqrt_field_gadget_test::<_, Fr, FrGadget>();
 }
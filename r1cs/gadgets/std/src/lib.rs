@@ -45,7 +45,6 @@
     clippy::many_single_char_names
 )]
 
-
 #[macro_use]
 extern crate algebra;
 #[macro_use]
@@ -72,11 +71,11 @@ pub mod prelude {
     pub use crate::{
         alloc::*,
         bits::{
-            boolean::Boolean, uint32::UInt32, uint8::UInt8, FromBitsGadget, ToBitsGadget,
-            ToBytesGadget,
+            boolean::Boolean, uint128::UInt128, uint16::UInt16, uint32::UInt32, uint64::UInt64,
+            uint8::UInt8, FromBitsGadget, ToBitsGadget, ToBytesGadget,
         },
         eq::*,
-        fields::{cubic_extension::*, quadratic_extension::*, FieldGadget},
+        fields::{cubic_extension::*, quadratic_extension::*, FieldGadget, SquareRootFieldGadget},
         groups::GroupGadget,
         pairing::PairingGadget,
         select::*,